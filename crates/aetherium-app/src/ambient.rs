@@ -0,0 +1,39 @@
+use anyhow::Result;
+use aetherium_bloom::{AetheriumBloom, AetheriumBloomBuilder, StateStream};
+
+/// One command-line flag applied to the builder; unknown flags are reported and ignored
+fn apply_flag(builder: AetheriumBloomBuilder, stream: &mut Option<StateStream>, flag: &str) -> AetheriumBloomBuilder {
+    let (name, value) = flag.split_once('=').unwrap_or((flag, ""));
+    match name {
+        // The same seed plays the same piece
+        "--seed" => match value.parse() {
+            Ok(seed) => builder.seed(seed),
+            Err(_) => invalid(builder, flag),
+        },
+        "--population" => match value.parse() {
+            Ok(count) => builder.initial_population(count),
+            Err(_) => invalid(builder, flag),
+        },
+        // Send the state stream to HOST:PORT over UDP, for visuals elsewhere; repeatable
+        "--stream" if !value.is_empty() => {
+            *stream = Some(stream.take().unwrap_or_default().udp(value));
+            builder
+        }
+        _ => invalid(builder, flag),
+    }
+}
+
+fn invalid(builder: AetheriumBloomBuilder, flag: &str) -> AetheriumBloomBuilder {
+    eprintln!("Ignoring unrecognized option '{flag}' (expected --seed=N, --population=COUNT or --stream=HOST:PORT)");
+    builder
+}
+
+fn main() -> Result<()> {
+    let mut stream = None;
+    let builder = std::env::args().skip(1).fold(AetheriumBloom::builder(), |builder, flag| apply_flag(builder, &mut stream, &flag));
+    match stream {
+        Some(stream) => builder.state_stream(stream),
+        None => builder,
+    }.run_ambient()?;
+    Ok(())
+}
@@ -0,0 +1,105 @@
+use std::path::Path;
+use std::time::Duration;
+use anyhow::Result;
+use aetherium_bloom::mods::default_mod_dir;
+use aetherium_bloom::simulation::{run_benchmark, SnapshotDiff, BENCH_PRESETS, BENCH_SEED};
+use aetherium_bloom::{default_crash_directory, default_matrix_file, read_snapshot, AetheriumBloom, AetheriumBloomBuilder, AttractMode, FullscreenMode, KeyBindings, Locale, ScreensaverCommand, TutorialMode};
+
+/// One command-line flag applied to the builder; unknown flags are reported and ignored
+fn apply_flag(builder: AetheriumBloomBuilder, flag: &str) -> AetheriumBloomBuilder {
+    let (name, value) = flag.split_once('=').unwrap_or((flag, ""));
+    match name {
+        // A self-running exhibit from the start, or once nobody has touched it for SECONDS
+        "--attract" => builder.attract(AttractMode::Always),
+        "--attract-after" => match value.parse() {
+            Ok(seconds) => builder.attract(AttractMode::AfterIdle(Duration::from_secs(seconds))),
+            Err(_) => invalid(builder, flag),
+        },
+        // Borderless fullscreen, or exclusive fullscreen at WIDTHxHEIGHT[@HZ]
+        "--fullscreen" => builder.fullscreen(FullscreenMode::Borderless),
+        "--resolution" => match parse_resolution(value) {
+            Some(mode) => builder.fullscreen(mode),
+            None => invalid(builder, flag),
+        },
+        // Monitor to open on, counted left to right from 0
+        "--monitor" => match value.parse() {
+            Ok(index) => builder.monitor(index),
+            Err(_) => invalid(builder, flag),
+        },
+        // Relaunch after a crash, picking up from the last autosave
+        "--supervise" => builder.supervise(true),
+        // Simulate MINUTES of the organism's life before showing it
+        "--warm-start" => match value.parse::<f32>() {
+            Ok(minutes) if minutes.is_finite() && minutes >= 0.0 => builder.warm_start(Duration::from_secs_f32(minutes * 60.0)),
+            _ => invalid(builder, flag),
+        },
+        // Garden layout to start from and save the garden editor's designs to
+        "--garden" if !value.is_empty() => builder.garden_layout(value),
+        // No window: only the simulation and the synth (see `main`)
+        "--ambient" => builder,
+        _ => invalid(builder, flag),
+    }
+}
+
+fn invalid(builder: AetheriumBloomBuilder, flag: &str) -> AetheriumBloomBuilder {
+    eprintln!("Ignoring unrecognized option '{flag}' (expected --attract, --attract-after=SECONDS, --fullscreen, --resolution=WIDTHxHEIGHT[@HZ], --monitor=INDEX, --supervise, --warm-start=MINUTES, --garden=PATH, --ambient, --bench-sim or --diff-snapshots=BEFORE,AFTER)");
+    builder
+}
+
+fn parse_resolution(value: &str) -> Option<FullscreenMode> {
+    let (size, refresh) = value.split_once('@').map_or((value, None), |(size, hz)| (size, Some(hz)));
+    let (width, height) = size.split_once('x')?;
+    let refresh_hz = refresh.map(str::parse).transpose().ok()?;
+    Some(FullscreenMode::Exclusive { width: width.parse().ok()?, height: height.parse().ok()?, refresh_hz })
+}
+
+/// Time the headless simulation at each preset population, without a window, GPU or audio
+fn bench_sim() {
+    println!("Benchmarking the simulation from seed {BENCH_SEED} ({} build)", if cfg!(debug_assertions) { "debug" } else { "release" });
+    for preset in BENCH_PRESETS {
+        print!("{}", run_benchmark(preset));
+    }
+}
+
+/// Print how the world in one saved snapshot differs from another
+fn diff_snapshots(paths: &str) -> Result<()> {
+    let Some((before, after)) = paths.split_once(',') else {
+        anyhow::bail!("--diff-snapshots expects two snapshot files: --diff-snapshots=BEFORE,AFTER");
+    };
+    let (before, after) = (Path::new(before), Path::new(after));
+    println!("Comparing {} with {}", before.display(), after.display());
+    print!("{}", SnapshotDiff::between(&read_snapshot(before)?, &read_snapshot(after)?));
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    if std::env::args().skip(1).any(|flag| flag == "--bench-sim") {
+        bench_sim();
+        return Ok(());
+    }
+    if let Some(paths) = std::env::args().skip(1).find_map(|flag| flag.strip_prefix("--diff-snapshots=").map(str::to_owned)) {
+        return diff_snapshots(&paths);
+    }
+    let builder = AetheriumBloom::builder()
+        .locale(Locale::from_env())
+        .tutorial(TutorialMode::first_run())
+        .key_bindings_file(KeyBindings::default_file())
+        .modulation_file(default_matrix_file())
+        .crash_directory(default_crash_directory())
+        .mods_directory(default_mod_dir())
+        .garden_layout("garden.ron")
+        .journal_directory("sessions");
+    // A screensaver host passes its own arguments (/s, /p HWND, /c, -root, -window-id ID)
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if let Some(command) = ScreensaverCommand::parse(&args) {
+        builder.run_screensaver(command)?;
+        return Ok(());
+    }
+    let builder = args.iter().fold(builder, |builder, flag| apply_flag(builder, flag));
+    if args.iter().any(|flag| flag == "--ambient") {
+        builder.run_ambient()?;
+    } else {
+        builder.run()?;
+    }
+    Ok(())
+}
@@ -0,0 +1,231 @@
+// === ADAPTIVE AUDIO BUFFER MANAGEMENT ===
+// Tracks output buffer health between the cpal callback and the synthesis
+// thread, and adapts the fill target: grow on underruns (crackling), shrink
+// back down after a stable period so latency never creeps upward.
+
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+/// Fill target bounds in samples (mono, one sample per output frame)
+pub const MIN_TARGET_FILL: usize = 1024;
+pub const MAX_TARGET_FILL: usize = 12288;
+pub const INITIAL_TARGET_FILL: usize = 2048;
+
+/// Hard cap on queued samples - anything beyond this is dropped as an overrun
+pub const MAX_BUFFER_SIZE: usize = 16384;
+
+const GROWTH_FACTOR: f32 = 1.5;
+const SHRINK_FACTOR: f32 = 0.9;
+const MIN_CHUNK_SIZE: usize = 256;
+
+/// How long playback must run without underruns before the target shrinks
+const STABLE_PERIOD: Duration = Duration::from_secs(5);
+
+/// Lock-free counters shared by the output callback and the synthesis thread
+#[derive(Debug)]
+pub struct AudioBufferHealth {
+    fill_level: AtomicUsize,
+    target_fill: AtomicUsize,
+    underruns: AtomicU64,
+    overruns: AtomicU64,
+    contended: AtomicU64, // Output callbacks that found the buffer locked and played silence
+    primed: AtomicBool, // Underruns only count once synthesis has started
+}
+
+/// Point-in-time buffer statistics for the HUD and analysis data
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct AudioBufferStats {
+    pub fill_level: usize,
+    pub target_fill: usize,
+    pub latency_ms: f32,
+    pub underruns: u64,
+    pub overruns: u64,
+    pub contended: u64,
+}
+
+impl AudioBufferHealth {
+    pub fn new() -> Self {
+        Self {
+            fill_level: AtomicUsize::new(0),
+            target_fill: AtomicUsize::new(INITIAL_TARGET_FILL),
+            underruns: AtomicU64::new(0),
+            overruns: AtomicU64::new(0),
+            contended: AtomicU64::new(0),
+            primed: AtomicBool::new(false),
+        }
+    }
+
+    /// Called from the output callback after draining samples
+    pub fn record_callback(&self, remaining: usize, starved: bool) {
+        self.fill_level.store(remaining, Ordering::Relaxed);
+        if starved && self.primed.load(Ordering::Relaxed) {
+            self.underruns.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Called from the output callback when the synthesis thread held the buffer
+    pub fn record_contention(&self) {
+        self.contended.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Called from the synthesis thread after pushing samples
+    pub fn record_push(&self, fill_level: usize, dropped: usize) {
+        self.fill_level.store(fill_level, Ordering::Relaxed);
+        self.primed.store(true, Ordering::Relaxed);
+        if dropped > 0 {
+            self.overruns.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Queued samples were discarded (e.g. device switch) - expect a refill gap
+    pub fn reset_fill(&self) {
+        self.fill_level.store(0, Ordering::Relaxed);
+        self.primed.store(false, Ordering::Relaxed);
+    }
+
+    pub fn underruns(&self) -> u64 {
+        self.underruns.load(Ordering::Relaxed)
+    }
+
+    fn publish_target(&self, target_fill: usize) {
+        self.target_fill.store(target_fill, Ordering::Relaxed);
+    }
+
+    pub fn stats(&self, sample_rate: f32) -> AudioBufferStats {
+        let fill_level = self.fill_level.load(Ordering::Relaxed);
+        AudioBufferStats {
+            fill_level,
+            target_fill: self.target_fill.load(Ordering::Relaxed),
+            latency_ms: if sample_rate > 0.0 { fill_level as f32 / sample_rate * 1000.0 } else { 0.0 },
+            underruns: self.underruns.load(Ordering::Relaxed),
+            overruns: self.overruns.load(Ordering::Relaxed),
+            contended: self.contended.load(Ordering::Relaxed),
+        }
+    }
+}
+
+impl Default for AudioBufferHealth {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Synthesis-side controller deciding how much audio to generate per tick
+#[derive(Debug)]
+pub struct AdaptiveBufferController {
+    target_fill: usize,
+    seen_underruns: u64,
+    stable_since: Instant,
+}
+
+impl AdaptiveBufferController {
+    pub fn new(now: Instant) -> Self {
+        Self {
+            target_fill: INITIAL_TARGET_FILL,
+            seen_underruns: 0,
+            stable_since: now,
+        }
+    }
+
+    pub fn target_fill(&self) -> usize {
+        self.target_fill
+    }
+
+    /// Adapt the fill target to the latest underrun count
+    pub fn adjust(&mut self, total_underruns: u64, now: Instant) {
+        if total_underruns > self.seen_underruns {
+            // Crackling - trade latency for stability
+            self.seen_underruns = total_underruns;
+            self.target_fill = ((self.target_fill as f32 * GROWTH_FACTOR) as usize).min(MAX_TARGET_FILL);
+            self.stable_since = now;
+        } else if now.duration_since(self.stable_since) >= STABLE_PERIOD {
+            // Stable for a while - claw latency back
+            self.target_fill = ((self.target_fill as f32 * SHRINK_FACTOR) as usize).max(MIN_TARGET_FILL);
+            self.stable_since = now;
+        }
+    }
+
+    /// Samples to generate now; chunks scale with the target so refills stay smooth
+    pub fn chunk_size(&self, fill_level: usize) -> usize {
+        if fill_level >= self.target_fill {
+            return 0;
+        }
+        let max_chunk = (self.target_fill / 2).max(MIN_CHUNK_SIZE);
+        (self.target_fill - fill_level).min(max_chunk)
+    }
+
+    /// Adjust against shared health and publish the resulting target
+    pub fn update(&mut self, health: &AudioBufferHealth, now: Instant) {
+        self.adjust(health.underruns(), now);
+        health.publish_target(self.target_fill);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_target_grows_on_underrun_and_shrinks_when_stable() {
+        let start = Instant::now();
+        let mut controller = AdaptiveBufferController::new(start);
+
+        controller.adjust(1, start);
+        let grown = controller.target_fill();
+        assert!(grown > INITIAL_TARGET_FILL);
+
+        // No new underruns, but not stable long enough yet
+        controller.adjust(1, start + Duration::from_secs(1));
+        assert_eq!(controller.target_fill(), grown);
+
+        controller.adjust(1, start + STABLE_PERIOD + Duration::from_secs(1));
+        assert!(controller.target_fill() < grown);
+    }
+
+    #[test]
+    fn test_target_stays_within_bounds() {
+        let start = Instant::now();
+        let mut controller = AdaptiveBufferController::new(start);
+
+        for underruns in 1..50 {
+            controller.adjust(underruns, start);
+        }
+        assert_eq!(controller.target_fill(), MAX_TARGET_FILL);
+
+        let mut now = start;
+        for _ in 0..100 {
+            now += STABLE_PERIOD;
+            controller.adjust(49, now);
+        }
+        assert_eq!(controller.target_fill(), MIN_TARGET_FILL);
+    }
+
+    #[test]
+    fn test_chunk_size_never_overfills() {
+        let controller = AdaptiveBufferController::new(Instant::now());
+        let target = controller.target_fill();
+
+        assert_eq!(controller.chunk_size(target), 0);
+        assert_eq!(controller.chunk_size(target + 100), 0);
+        assert!(controller.chunk_size(0) <= target);
+        assert_eq!(controller.chunk_size(target - 10), 10);
+    }
+
+    #[test]
+    fn test_underruns_ignored_until_primed() {
+        let health = AudioBufferHealth::new();
+        health.record_callback(0, true);
+        assert_eq!(health.underruns(), 0);
+
+        health.record_push(512, 0);
+        health.record_callback(0, true);
+        assert_eq!(health.underruns(), 1);
+
+        // Lock contention plays silence but is tracked separately from underruns
+        health.record_contention();
+        let stats = health.stats(48_000.0);
+        assert_eq!(stats.fill_level, 0);
+        assert_eq!(stats.underruns, 1);
+        assert_eq!(stats.contended, 1);
+    }
+}
@@ -0,0 +1,144 @@
+// === ENVIRONMENT CROSSFADE ===
+// Environments used to swap synthesis graphs between one sample and the next,
+// so a jump from Meditative to Electronica landed as a click mid-bar. Now a
+// change waits for the next beat and then fades between the two graphs at
+// equal power over a configurable time. A change that arrives mid-fade starts
+// from whichever side is louder, keeping its level continuous.
+
+use std::f32::consts::FRAC_PI_2;
+use super::AudioEnvironment;
+
+/// Two environments and how far the sound has moved from one to the other
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnvironmentBlend {
+    pub from: AudioEnvironment,
+    pub to: AudioEnvironment,
+    pub mix: f32, // 0 = all `from`, 1 = all `to`
+}
+
+impl EnvironmentBlend {
+    /// One environment, not fading
+    pub fn steady(environment: AudioEnvironment) -> Self {
+        Self { from: environment.clone(), to: environment, mix: 1.0 }
+    }
+
+    pub fn is_fading(&self) -> bool {
+        self.from != self.to && self.mix < 1.0
+    }
+
+    /// Equal-power gains of `from` and `to`
+    pub fn gains(&self) -> (f32, f32) {
+        let angle = self.mix.clamp(0.0, 1.0) * FRAC_PI_2;
+        (angle.cos(), angle.sin())
+    }
+
+    /// Whichever environment is louder right now
+    pub fn dominant(&self) -> &AudioEnvironment {
+        if self.mix >= 0.5 { &self.to } else { &self.from }
+    }
+
+    /// Blend a per-environment setting the way the sound is blended
+    pub fn lerp(&self, value: impl Fn(&AudioEnvironment) -> f32) -> f32 {
+        if !self.is_fading() {
+            return value(&self.to);
+        }
+        let (from_gain, to_gain) = self.gains();
+        (value(&self.from) * from_gain + value(&self.to) * to_gain) / (from_gain + to_gain)
+    }
+}
+
+/// Beat-aligned fades from one environment to the next
+pub struct EnvironmentCrossfade {
+    blend: EnvironmentBlend,
+    pending: Option<AudioEnvironment>, // Waiting for the next beat
+    last_phase: Option<f32>,
+}
+
+impl EnvironmentCrossfade {
+    pub fn new(environment: AudioEnvironment) -> Self {
+        Self { blend: EnvironmentBlend::steady(environment), pending: None, last_phase: None }
+    }
+
+    pub fn blend(&self) -> &EnvironmentBlend {
+        &self.blend
+    }
+
+    /// Ask for `environment`; the fade starts on the next beat
+    pub fn request(&mut self, environment: AudioEnvironment) {
+        self.pending = (environment != self.blend.to).then_some(environment);
+    }
+
+    /// Follow the beat's phase (0-1 per beat) and start a queued fade when it wraps
+    pub fn on_beat_phase(&mut self, phase: f32) {
+        let wrapped = self.last_phase.is_some_and(|last| phase < last);
+        self.last_phase = Some(phase);
+        if !wrapped {
+            return;
+        }
+        let Some(target) = self.pending.take() else {
+            return;
+        };
+        self.blend = if self.blend.is_fading() && target == self.blend.from {
+            // Turning back: run the same fade in reverse
+            EnvironmentBlend { from: self.blend.to.clone(), to: target, mix: 1.0 - self.blend.mix }
+        } else {
+            // Fade out from the louder side at the level it has now
+            let (from_gain, to_gain) = self.blend.gains();
+            let (from, level) = if to_gain >= from_gain { (self.blend.to.clone(), to_gain) } else { (self.blend.from.clone(), from_gain) };
+            EnvironmentBlend { from, to: target, mix: level.clamp(0.0, 1.0).acos() / FRAC_PI_2 }
+        };
+    }
+
+    /// Move the fade on by `dt` seconds of a `duration`-second crossfade
+    pub fn advance(&mut self, dt: f32, duration: f32) -> &EnvironmentBlend {
+        if self.blend.mix < 1.0 {
+            self.blend.mix = if duration > 0.0 { (self.blend.mix + dt / duration).min(1.0) } else { 1.0 };
+        }
+        &self.blend
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fades_start_on_the_beat_at_equal_power() {
+        let mut crossfade = EnvironmentCrossfade::new(AudioEnvironment::Meditative);
+        crossfade.on_beat_phase(0.5);
+        crossfade.request(AudioEnvironment::Electronica);
+        crossfade.on_beat_phase(0.9);
+        assert!(!crossfade.advance(0.1, 2.0).is_fading(), "waits for the beat");
+
+        crossfade.on_beat_phase(0.05);
+        let blend = crossfade.advance(1.0, 2.0).clone();
+        assert!(blend.is_fading() && (blend.mix - 0.5).abs() < 1e-6);
+        let (from_gain, to_gain) = blend.gains();
+        assert!((from_gain.powi(2) + to_gain.powi(2) - 1.0).abs() < 1e-6);
+        assert!((blend.lerp(|environment| if *environment == AudioEnvironment::Meditative { 0.0 } else { 1.0 }) - 0.5).abs() < 1e-6);
+
+        // Turning back mid-fade reverses it without a jump
+        crossfade.advance(0.4, 2.0);
+        let (meditative, electronica) = crossfade.blend().gains();
+        crossfade.request(AudioEnvironment::Meditative);
+        crossfade.on_beat_phase(0.5);
+        crossfade.on_beat_phase(0.1);
+        assert_eq!(crossfade.blend().from, AudioEnvironment::Electronica);
+        let (from_gain, to_gain) = crossfade.blend().gains();
+        assert!((from_gain - electronica).abs() < 1e-5 && (to_gain - meditative).abs() < 1e-5);
+
+        // A third environment fades in under the louder side, which keeps its level
+        crossfade.request(AudioEnvironment::HiveMind);
+        crossfade.on_beat_phase(0.6);
+        crossfade.on_beat_phase(0.2);
+        assert_eq!(crossfade.blend().from, AudioEnvironment::Electronica);
+        assert!((crossfade.blend().gains().0 - electronica).abs() < 1e-5);
+        crossfade.request(AudioEnvironment::Meditative);
+        crossfade.on_beat_phase(0.7);
+        crossfade.on_beat_phase(0.3);
+
+        // Zero seconds is a hard switch, still on the beat
+        let blend = crossfade.advance(0.0, 0.0);
+        assert!(!blend.is_fading() && *blend.dominant() == AudioEnvironment::Meditative);
+    }
+}
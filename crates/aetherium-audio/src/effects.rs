@@ -3,7 +3,8 @@
 // When llamas achieve transcendence, audio reality tears apart
 
 use std::collections::VecDeque;
-use crate::mathematics::BeatState;
+use aetherium_sim::mathematics::BeatState;
+use super::{AudioEnvironment, StereoFrame};
 
 /// Main reality distortion processor - warps audio through consciousness
 pub struct RealityDistortionProcessor {
@@ -667,4 +668,333 @@ impl BiquadFilter {
 
         output
     }
-}
\ No newline at end of file
+}
+// === SPACE SIMULATION ===
+// A feedback-delay-network reverb and a tempo-synced ping-pong delay. Both
+// take their decay and wet levels from the audio environment and glide to new
+// settings, so environment changes never click.
+
+/// Mutually prime FDN line lengths in milliseconds
+const FDN_DELAYS_MS: [f32; 4] = [29.7, 37.1, 41.1, 43.7];
+/// Longest tempo-synced delay the ping-pong buffers can hold
+const MAX_DELAY_SECONDS: f32 = 2.0;
+/// Delay times in beats: dotted eighth on the left, quarter on the right
+const DELAY_BEATS: [f32; 2] = [0.75, 0.5];
+/// Seconds for wet/decay changes to settle
+const SPACE_GLIDE_SECONDS: f32 = 1.0;
+
+/// Reverb and delay character of one audio environment
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpaceSettings {
+    pub reverb_decay: f32, // RT60 in seconds
+    pub reverb_wet: f32,
+    pub damping: f32,      // 0 = bright tail, 1 = dark tail
+    pub delay_feedback: f32,
+    pub delay_wet: f32,
+}
+
+impl SpaceSettings {
+    pub fn for_environment(environment: &AudioEnvironment) -> Self {
+        let (reverb_decay, reverb_wet, damping, delay_feedback, delay_wet) = match environment {
+            AudioEnvironment::Environmental => (1.8, 0.18, 0.3, 0.25, 0.10),
+            AudioEnvironment::Meditative => (0.6, 0.12, 0.5, 0.20, 0.08),  // Small room
+            AudioEnvironment::Psychedelic => (2.5, 0.25, 0.3, 0.45, 0.20),
+            AudioEnvironment::Electronica => (1.2, 0.15, 0.4, 0.35, 0.18),
+            AudioEnvironment::HiveMind => (4.0, 0.30, 0.2, 0.50, 0.22),
+            AudioEnvironment::RealityTear => (9.0, 0.55, 0.1, 0.70, 0.35), // Huge wash
+        };
+        Self { reverb_decay, reverb_wet, damping, delay_feedback, delay_wet }
+    }
+}
+
+/// One-pole smoothing coefficient reaching ~63% of a change in `seconds`
+fn glide_coefficient(seconds: f32, sample_rate: f32) -> f32 {
+    1.0 - (-1.0 / (seconds * sample_rate)).exp()
+}
+
+/// Four-line feedback delay network with a Hadamard mixing matrix
+pub struct FdnReverb {
+    lines: [Vec<f32>; 4],
+    positions: [usize; 4],
+    lowpass: [f32; 4],     // Damping filter state per line
+    gains: [f32; 4],
+    target_gains: [f32; 4],
+    damping: f32,
+    target_damping: f32,
+    wet: f32,
+    target_wet: f32,
+    glide: f32,
+    sample_rate: f32,
+}
+
+impl FdnReverb {
+    pub fn new(sample_rate: f32) -> Self {
+        let lines = FDN_DELAYS_MS.map(|ms| vec![0.0; ((ms * 0.001 * sample_rate) as usize).max(1)]);
+        let mut reverb = Self {
+            lines,
+            positions: [0; 4],
+            lowpass: [0.0; 4],
+            gains: [0.0; 4],
+            target_gains: [0.0; 4],
+            damping: 0.0,
+            target_damping: 0.0,
+            wet: 0.0,
+            target_wet: 0.0,
+            glide: glide_coefficient(SPACE_GLIDE_SECONDS, sample_rate),
+            sample_rate,
+        };
+        reverb.set_environment(&AudioEnvironment::Environmental);
+        reverb.gains = reverb.target_gains;
+        reverb.damping = reverb.target_damping;
+        reverb.wet = reverb.target_wet;
+        reverb
+    }
+
+    pub fn set_environment(&mut self, environment: &AudioEnvironment) {
+        let settings = SpaceSettings::for_environment(environment);
+        self.set_decay(settings.reverb_decay);
+        self.target_damping = settings.damping;
+        self.target_wet = settings.reverb_wet;
+    }
+
+    /// Line gains giving a 60 dB decay in `rt60` seconds
+    fn set_decay(&mut self, rt60: f32) {
+        for (gain, line) in self.target_gains.iter_mut().zip(&self.lines) {
+            *gain = 10f32.powf(-3.0 * line.len() as f32 / (rt60.max(0.05) * self.sample_rate));
+        }
+    }
+
+    pub fn process(&mut self, frame: StereoFrame) -> StereoFrame {
+        self.damping += (self.target_damping - self.damping) * self.glide;
+        self.wet += (self.target_wet - self.wet) * self.glide;
+
+        for (gain, target) in self.gains.iter_mut().zip(self.target_gains) {
+            *gain += (target - *gain) * self.glide;
+        }
+        for ((lowpass, line), &position) in self.lowpass.iter_mut().zip(&self.lines).zip(&self.positions) {
+            *lowpass += (line[position] - *lowpass) * (1.0 - self.damping * 0.9);
+        }
+        let outputs = self.lowpass;
+
+        // Orthonormal Hadamard mix keeps the network lossless before the line gains
+        let [a, b, c, d] = outputs;
+        let mixed = [a + b + c + d, a - b + c - d, a + b - c - d, a - b - c + d].map(|x| x * 0.5);
+        let input = (frame[0] + frame[1]) * 0.5;
+        for (((line, position), mixed), gain) in self.lines.iter_mut().zip(&mut self.positions).zip(mixed).zip(self.gains) {
+            line[*position] = input + mixed * gain;
+            *position = (*position + 1) % line.len();
+        }
+
+        let left = (outputs[0] + outputs[2]) * 0.5;
+        let right = (outputs[1] + outputs[3]) * 0.5;
+        [frame[0] + left * self.wet, frame[1] + right * self.wet]
+    }
+}
+
+/// Ping-pong delay whose echo times follow the beat tempo
+pub struct StereoDelay {
+    buffers: [Vec<f32>; 2],
+    write_position: usize,
+    delay_samples: [f32; 2],
+    target_delay_samples: [f32; 2],
+    feedback: f32,
+    target_feedback: f32,
+    wet: f32,
+    target_wet: f32,
+    glide: f32,
+    time_glide: f32,
+    sample_rate: f32,
+}
+
+impl StereoDelay {
+    pub fn new(sample_rate: f32) -> Self {
+        let length = (MAX_DELAY_SECONDS * sample_rate) as usize + 2;
+        let mut delay = Self {
+            buffers: [vec![0.0; length], vec![0.0; length]],
+            write_position: 0,
+            delay_samples: [0.0; 2],
+            target_delay_samples: [0.0; 2],
+            feedback: 0.0,
+            target_feedback: 0.0,
+            wet: 0.0,
+            target_wet: 0.0,
+            glide: glide_coefficient(SPACE_GLIDE_SECONDS, sample_rate),
+            time_glide: glide_coefficient(0.5, sample_rate), // Slow enough that tempo drift never warbles
+            sample_rate,
+        };
+        delay.set_tempo(120.0);
+        delay.set_environment(&AudioEnvironment::Environmental);
+        delay.delay_samples = delay.target_delay_samples;
+        delay.feedback = delay.target_feedback;
+        delay.wet = delay.target_wet;
+        delay
+    }
+
+    pub fn set_tempo(&mut self, bpm: f32) {
+        let bpm = if bpm.is_finite() { bpm.clamp(40.0, 300.0) } else { 120.0 };
+        let beat_samples = 60.0 / bpm * self.sample_rate;
+        let longest = (self.buffers[0].len() - 2) as f32;
+        self.target_delay_samples = DELAY_BEATS.map(|beats| (beats * beat_samples).clamp(1.0, longest));
+    }
+
+    pub fn set_environment(&mut self, environment: &AudioEnvironment) {
+        let settings = SpaceSettings::for_environment(environment);
+        self.target_feedback = settings.delay_feedback;
+        self.target_wet = settings.delay_wet;
+    }
+
+    /// Linearly interpolated read `delay` samples behind the write head
+    fn read(&self, channel: usize, delay: f32) -> f32 {
+        let buffer = &self.buffers[channel];
+        let position = self.write_position as f32 - delay + buffer.len() as f32;
+        let index = position as usize % buffer.len();
+        let fraction = position.fract();
+        buffer[index] * (1.0 - fraction) + buffer[(index + 1) % buffer.len()] * fraction
+    }
+
+    pub fn process(&mut self, frame: StereoFrame) -> StereoFrame {
+        self.feedback += (self.target_feedback - self.feedback) * self.glide;
+        self.wet += (self.target_wet - self.wet) * self.glide;
+        for (delay, target) in self.delay_samples.iter_mut().zip(self.target_delay_samples) {
+            *delay += (target - *delay) * self.time_glide;
+        }
+
+        let left = self.read(0, self.delay_samples[0]);
+        let right = self.read(1, self.delay_samples[1]);
+
+        // The input enters on the left and each echo crosses to the other side
+        let input = (frame[0] + frame[1]) * 0.5;
+        self.buffers[0][self.write_position] = input + right * self.feedback;
+        self.buffers[1][self.write_position] = left * self.feedback;
+        self.write_position = (self.write_position + 1) % self.buffers[0].len();
+
+        [frame[0] + left * self.wet, frame[1] + right * self.wet]
+    }
+}
+
+/// How far the synth ducks under external music, like a compressor keyed by it
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DuckingSettings {
+    pub threshold_db: f32,     // External level where ducking starts, in dBFS
+    pub ratio: f32,            // Compression ratio above the threshold
+    pub max_reduction_db: f32, // Deepest cut, so the synth stays faintly audible
+    pub attack: f32,           // Seconds to duck when the music comes in
+    pub release: f32,          // Seconds to recover when it drops out
+}
+
+impl Default for DuckingSettings {
+    fn default() -> Self {
+        Self { threshold_db: -36.0, ratio: 4.0, max_reduction_db: 18.0, attack: 0.01, release: 0.5 }
+    }
+}
+
+/// Compressor on the synth output whose detector listens to an external key signal
+pub struct SidechainCompressor {
+    settings: DuckingSettings,
+    envelope: f32, // Smoothed key level, linear
+    reduction_db: f32,
+    attack: f32,
+    release: f32,
+    sample_rate: f32,
+}
+
+impl SidechainCompressor {
+    pub fn new(sample_rate: f32) -> Self {
+        let mut compressor = Self { settings: DuckingSettings::default(), envelope: 0.0, reduction_db: 0.0, attack: 0.0, release: 0.0, sample_rate };
+        compressor.set_settings(DuckingSettings::default());
+        compressor
+    }
+
+    pub fn set_settings(&mut self, settings: DuckingSettings) {
+        self.settings = settings;
+        self.attack = glide_coefficient(settings.attack.max(0.0001), self.sample_rate);
+        self.release = glide_coefficient(settings.release.max(0.0001), self.sample_rate);
+    }
+
+    /// Duck `frame` by however loud `key` has been recently
+    pub fn process(&mut self, frame: StereoFrame, key: f32) -> StereoFrame {
+        let key = if key.is_finite() { key.abs() } else { 0.0 };
+        let glide = if key > self.envelope { self.attack } else { self.release };
+        self.envelope += (key - self.envelope) * glide;
+
+        let level_db = 20.0 * self.envelope.max(1e-6).log10();
+        let over = (level_db - self.settings.threshold_db).max(0.0);
+        self.reduction_db = (over * (1.0 - 1.0 / self.settings.ratio.max(1.0))).min(self.settings.max_reduction_db);
+        let gain = 10f32.powf(-self.reduction_db / 20.0);
+        frame.map(|channel| channel * gain)
+    }
+
+    /// Current cut in dB, 0 when the key is quiet
+    pub fn reduction_db(&self) -> f32 {
+        self.reduction_db
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Samples until an impulse response stays below -60 dB
+    fn tail_length(reverb: &mut FdnReverb) -> usize {
+        reverb.process([1.0, 1.0]);
+        let mut last_loud = 0;
+        for i in 1..48_000 * 12 {
+            let [left, right] = reverb.process([0.0, 0.0]);
+            if left.abs().max(right.abs()) > 1e-3 * reverb.wet {
+                last_loud = i;
+            }
+        }
+        last_loud
+    }
+
+    #[test]
+    fn test_reverb_tail_follows_environment() {
+        let mut small_room = FdnReverb::new(48_000.0);
+        small_room.set_environment(&AudioEnvironment::Meditative);
+        let mut wash = FdnReverb::new(48_000.0);
+        wash.set_environment(&AudioEnvironment::RealityTear);
+        for _ in 0..48_000 * 4 {
+            small_room.process([0.0, 0.0]);
+            wash.process([0.0, 0.0]);
+        }
+
+        let (room_tail, wash_tail) = (tail_length(&mut small_room), tail_length(&mut wash));
+        assert!(room_tail > 0 && room_tail < 48_000, "room tail {}", room_tail);
+        assert!(wash_tail > 48_000 * 2 && wash_tail > room_tail * 4, "wash tail {} vs room tail {}", wash_tail, room_tail);
+    }
+
+    #[test]
+    fn test_delay_echoes_on_the_beat_and_ping_pongs() {
+        let mut delay = StereoDelay::new(48_000.0);
+        delay.set_tempo(120.0); // 0.5 s per beat
+        let response: Vec<StereoFrame> = std::iter::once(delay.process([1.0, 1.0]))
+            .chain((1..48_000).map(|_| delay.process([0.0, 0.0])))
+            .collect();
+
+        let loudest = |channel: usize| (1..response.len())
+            .max_by(|&a, &b| response[a][channel].abs().total_cmp(&response[b][channel].abs()))
+            .unwrap();
+        assert!(loudest(0).abs_diff(18_000) <= 1, "left echo at {}", loudest(0));
+        assert!(loudest(1).abs_diff(30_000) <= 1, "right echo at {}", loudest(1));
+        assert!(response.iter().all(|frame| frame.iter().all(|s| s.is_finite() && s.abs() <= 1.0)));
+    }
+
+    #[test]
+    fn test_sidechain_ducks_under_loud_key_and_recovers() {
+        let mut ducker = SidechainCompressor::new(48_000.0);
+        assert_eq!(ducker.process([0.5, 0.5], 0.0), [0.5, 0.5], "silence leaves the synth alone");
+
+        let mut ducked = [0.0; 2];
+        for _ in 0..4_800 {
+            ducked = ducker.process([0.5, 0.5], 1.0); // Full-scale music for 0.1 s
+        }
+        let settings = DuckingSettings::default();
+        assert!((ducker.reduction_db() - settings.max_reduction_db).abs() < 0.5, "cut {} dB", ducker.reduction_db());
+        assert!(ducked[0] > 0.0 && ducked[0] < 0.1);
+
+        for _ in 0..48_000 * 4 {
+            ducker.process([0.5, 0.5], 0.0);
+        }
+        assert!(ducker.reduction_db() < 0.5, "recovered to {} dB", ducker.reduction_db());
+    }
+}
@@ -5,6 +5,7 @@
 use std::collections::HashMap;
 use glam::Vec2;
 use super::AudioEnvironment;
+use super::crossfade::EnvironmentBlend;
 
 /// Manages territorial audio zones and environmental soundscapes
 pub struct AudioEnvironmentZones {
@@ -29,7 +30,7 @@ pub struct AudioEnvironmentZones {
 struct TerritorialZone {
     center: Vec2,
     radius: f32,
-    dominant_species: Option<crate::core::events::LlamaSpecies>,
+    dominant_species: Option<aetherium_sim::core::events::LlamaSpecies>,
     consciousness_density: f32,
     territory_age: f32,
     audio_signature: TerritoryAudioSignature,
@@ -270,7 +271,7 @@ impl AudioEnvironmentZones {
     pub fn process_sample(&mut self,
                          input_sample: f32,
                          sample_time: f64,
-                         environment: &AudioEnvironment) -> f32 {
+                         environment: &EnvironmentBlend) -> f32 {
 
         let mut sample = input_sample;
 
@@ -434,7 +435,7 @@ impl AmbientSoundscapeGenerator {
         self.cosmic_background.update(cosmic_time, total_consciousness);
     }
 
-    fn process_sample(&mut self, input_sample: f32, sample_time: f64, environment: &AudioEnvironment) -> f32 {
+    fn process_sample(&mut self, input_sample: f32, sample_time: f64, environment: &EnvironmentBlend) -> f32 {
         let mut sample = input_sample;
 
         // Generate ambient layers
@@ -442,15 +443,15 @@ impl AmbientSoundscapeGenerator {
         let crystal_sample = self.crystal_resonance.generate_sample(sample_time) * self.mix_levels[1];
         let cosmic_sample = self.cosmic_background.generate_sample(sample_time) * self.mix_levels[2];
 
-        // Mix ambient layers based on environment
-        let ambient_intensity = match environment {
+        // Mix ambient layers based on environment, gliding between them through a crossfade
+        let ambient_intensity = environment.lerp(|environment| match environment {
             AudioEnvironment::Environmental => 0.6,
             AudioEnvironment::Meditative => 0.4,
             AudioEnvironment::Psychedelic => 0.3,
             AudioEnvironment::Electronica => 0.1,
             AudioEnvironment::HiveMind => 0.2,
             AudioEnvironment::RealityTear => 0.05,
-        };
+        });
 
         sample += (wind_sample + crystal_sample + cosmic_sample) * ambient_intensity;
         sample
@@ -0,0 +1,37 @@
+// === AUDIO ERRORS ===
+// Failures opening devices, streams and samples. The organism never stops on
+// one of these; it degrades to visual-only mode and keeps retrying the device.
+
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum AudioError {
+    #[error("no audio output device available")]
+    NoAudioDevice,
+
+    #[error("audio output device '{0}' not found")]
+    AudioDeviceNotFound(String),
+
+    #[error("failed to enumerate audio devices: {0}")]
+    AudioDevices(#[from] cpal::DevicesError),
+
+    #[error("failed to query audio output config: {0}")]
+    AudioConfig(#[from] cpal::DefaultStreamConfigError),
+
+    #[error("unsupported audio sample format {0:?}")]
+    UnsupportedSampleFormat(cpal::SampleFormat),
+
+    #[error("failed to build audio stream: {0}")]
+    AudioStream(#[from] cpal::BuildStreamError),
+
+    #[error("failed to start audio stream: {0}")]
+    AudioPlay(#[from] cpal::PlayStreamError),
+
+    #[error("failed to spawn audio synthesis thread: {0}")]
+    AudioThread(#[from] std::io::Error),
+
+    #[error("failed to load sample {}: {source}", path.display())]
+    Sample { path: std::path::PathBuf, source: hound::Error },
+}
+
+pub type Result<T, E = AudioError> = std::result::Result<T, E>;
@@ -0,0 +1,221 @@
+// === HARMONY ENGINE ===
+// Music theory for the synthesizer: consciousness-derived frequencies snap to
+// the selected scale, a chord progression advances on every beat drop, and
+// hive coherence decides how consonant the chord pad sounds.
+
+use std::f32::consts::TAU;
+
+/// Root of every scale (A2); other octaves follow from it
+const ROOT_HZ: f32 = 110.0;
+/// Pop/EDM staple: I - V - vi - IV
+const PROGRESSION: [usize; 4] = [0, 4, 5, 3];
+/// Seconds for chord tones to glide to the next chord
+const GLIDE_SECONDS: f32 = 0.25;
+
+/// Scale the melody and chords are drawn from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Scale {
+    Major,
+    #[default]
+    NaturalMinor,
+    Dorian,
+    Lydian,
+    PentatonicMinor,
+}
+
+impl Scale {
+    pub const ALL: [Scale; 5] = [Self::Major, Self::NaturalMinor, Self::Dorian, Self::Lydian, Self::PentatonicMinor];
+
+    /// Semitones above the root for each degree
+    pub fn intervals(self) -> &'static [i32] {
+        match self {
+            Self::Major => &[0, 2, 4, 5, 7, 9, 11],
+            Self::NaturalMinor => &[0, 2, 3, 5, 7, 8, 10],
+            Self::Dorian => &[0, 2, 3, 5, 7, 9, 10],
+            Self::Lydian => &[0, 2, 4, 6, 7, 9, 11],
+            Self::PentatonicMinor => &[0, 3, 5, 7, 10],
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Major => "MAJOR",
+            Self::NaturalMinor => "MINOR",
+            Self::Dorian => "DORIAN",
+            Self::Lydian => "LYDIAN",
+            Self::PentatonicMinor => "PENTATONIC",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|&scale| scale == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    /// Semitones above the root of a degree, wrapping into higher octaves
+    fn degree_semitones(self, degree: usize) -> i32 {
+        let intervals = self.intervals();
+        intervals[degree % intervals.len()] + 12 * (degree / intervals.len()) as i32
+    }
+
+    /// Nearest in-scale frequency to `frequency`
+    pub fn quantize(self, frequency: f32) -> f32 {
+        if !(frequency.is_finite() && frequency > 0.0) {
+            return frequency;
+        }
+        let semitones = 12.0 * (frequency / ROOT_HZ).log2();
+        let octave = (semitones / 12.0).floor();
+        let within = semitones - octave * 12.0;
+
+        // The next octave's root is a candidate too, so B rounds up to C
+        let nearest = self.intervals().iter()
+            .map(|&interval| interval as f32)
+            .chain(std::iter::once(12.0))
+            .min_by(|a, b| (a - within).abs().total_cmp(&(b - within).abs()))
+            .unwrap_or(0.0);
+        semitone_to_hz(octave * 12.0 + nearest)
+    }
+}
+
+fn semitone_to_hz(semitones: f32) -> f32 {
+    ROOT_HZ * 2f32.powf(semitones / 12.0)
+}
+
+struct ChordVoice {
+    frequency: f32,
+    target: f32,
+    phase: f32,
+    gain: f32,
+}
+
+/// Scale quantizer plus a gliding chord pad
+pub struct HarmonyEngine {
+    scale: Scale,
+    chord_step: usize,
+    coherence: f32, // 0 = dissonant cluster, 1 = pure triad
+    voices: [ChordVoice; 5],
+    sample_rate: f32,
+}
+
+impl HarmonyEngine {
+    pub fn new(sample_rate: f32) -> Self {
+        let voice = || ChordVoice { frequency: ROOT_HZ, target: ROOT_HZ, phase: 0.0, gain: 0.0 };
+        let mut engine = Self {
+            scale: Scale::default(),
+            chord_step: 0,
+            coherence: 0.5,
+            voices: [voice(), voice(), voice(), voice(), voice()],
+            sample_rate,
+        };
+        engine.retarget();
+        for voice in &mut engine.voices {
+            voice.frequency = voice.target;
+        }
+        engine
+    }
+
+    pub fn scale(&self) -> Scale {
+        self.scale
+    }
+
+    pub fn set_scale(&mut self, scale: Scale) {
+        if scale != self.scale {
+            self.scale = scale;
+            self.retarget();
+        }
+    }
+
+    pub fn set_coherence(&mut self, coherence: f32) {
+        self.coherence = if coherence.is_finite() { coherence.clamp(0.0, 1.0) } else { 0.5 };
+        self.retarget();
+    }
+
+    /// Move to the next chord of the progression (called on beat drops)
+    pub fn advance_chord(&mut self) {
+        self.chord_step = (self.chord_step + 1) % PROGRESSION.len();
+        self.retarget();
+    }
+
+    pub fn quantize(&self, frequency: f32) -> f32 {
+        self.scale.quantize(frequency)
+    }
+
+    /// Chord tones in Hz: root, third, fifth, then the tension tones
+    pub fn chord_frequencies(&self) -> [f32; 5] {
+        self.voices.each_ref().map(|voice| voice.target)
+    }
+
+    fn retarget(&mut self) {
+        let degree = PROGRESSION[self.chord_step];
+        let tone = |step: usize| semitone_to_hz(self.scale.degree_semitones(degree + step) as f32 + 12.0);
+        let root = self.scale.degree_semitones(degree) as f32 + 12.0;
+
+        // Stacked thirds within the scale, always consonant
+        let triad = [(tone(0), 0.4), (tone(2), 0.3), (tone(4), 0.3)];
+        // A minor second and a tritone above the root fade in as coherence falls
+        let tension = 1.0 - self.coherence;
+        let cluster = [(semitone_to_hz(root + 1.0), tension * 0.25), (semitone_to_hz(root + 6.0), tension * 0.2)];
+
+        for (voice, (target, gain)) in self.voices.iter_mut().zip(triad.into_iter().chain(cluster)) {
+            voice.target = target;
+            voice.gain = gain;
+        }
+    }
+
+    /// Next sample of the chord pad
+    pub fn next_chord_sample(&mut self) -> f32 {
+        let glide = 1.0 - (-1.0 / (GLIDE_SECONDS * self.sample_rate)).exp();
+        let mut sample = 0.0;
+        for voice in &mut self.voices {
+            voice.frequency += (voice.target - voice.frequency) * glide;
+            voice.phase = (voice.phase + voice.frequency / self.sample_rate).fract();
+            sample += (voice.phase * TAU).sin() * voice.gain;
+        }
+        sample
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn semitones_from_root(frequency: f32) -> f32 {
+        12.0 * (frequency / ROOT_HZ).log2()
+    }
+
+    #[test]
+    fn test_quantize_lands_on_scale_degrees() {
+        for scale in Scale::ALL {
+            for i in 0..200 {
+                let frequency = 60.0 + i as f32 * 7.3;
+                let snapped = scale.quantize(frequency);
+                let semitones = semitones_from_root(snapped).round() as i32;
+                assert!(scale.intervals().contains(&semitones.rem_euclid(12)),
+                        "{:?}: {} Hz snapped to {} Hz", scale, frequency, snapped);
+                assert!((semitones_from_root(snapped) - semitones_from_root(frequency)).abs() <= 2.0);
+            }
+        }
+        assert_eq!(Scale::Major.quantize(220.0), 220.0);
+        assert!(Scale::Major.quantize(f32::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_progression_and_coherence_shape_the_chord() {
+        let mut harmony = HarmonyEngine::new(48_000.0);
+        harmony.set_scale(Scale::Major);
+        harmony.set_coherence(1.0);
+        let tonic = harmony.chord_frequencies();
+        assert!(harmony.voices[3..].iter().all(|voice| voice.gain == 0.0));
+
+        harmony.advance_chord();
+        assert_ne!(harmony.chord_frequencies()[0], tonic[0]);
+        for _ in 1..PROGRESSION.len() {
+            harmony.advance_chord();
+        }
+        assert_eq!(harmony.chord_frequencies(), tonic);
+
+        harmony.set_coherence(0.0);
+        assert!(harmony.voices[3..].iter().all(|voice| voice.gain > 0.0));
+        assert!((0..4800).map(|_| harmony.next_chord_sample()).all(|s| s.is_finite() && s.abs() <= 1.5));
+    }
+}
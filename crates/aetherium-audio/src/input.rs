@@ -0,0 +1,112 @@
+// === EXTERNAL AUDIO INPUT TAP ===
+// Listens to music playing outside the organism so the synth can duck under
+// it. The tap opens a capture stream on a loopback source or an explicit
+// input and publishes only the peak level of each block; the synthesis thread
+// reads that level as the key of its sidechain compressor. Nothing captured
+// is ever played back.
+
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+
+use cpal::{Device, Sample, SizedSample, Stream, StreamConfig, FromSample};
+use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+use tracing::{error, info};
+
+use crate::error::{AudioError, Result};
+
+/// Where the external music comes from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExternalSource {
+    /// What the system is playing: the default output on Windows, a "monitor" input elsewhere
+    Loopback,
+    /// A capture device by name, e.g. a line-in fed by the DJ mixer; `None` is the default input
+    Input(Option<String>),
+}
+
+/// Peak level of the latest captured block, shared lock-free with the synthesis thread
+#[derive(Debug, Default)]
+pub struct ExternalLevel(AtomicU32);
+
+impl ExternalLevel {
+    pub fn get(&self) -> f32 {
+        f32::from_bits(self.0.load(Ordering::Relaxed))
+    }
+
+    pub fn set(&self, level: f32) {
+        self.0.store(level.to_bits(), Ordering::Relaxed);
+    }
+}
+
+/// A running capture stream and the level it publishes
+pub struct ExternalInput {
+    _stream: Stream,
+    level: Arc<ExternalLevel>,
+    device_name: String,
+}
+
+impl ExternalInput {
+    pub fn open(source: &ExternalSource) -> Result<Self> {
+        let (device, loopback) = find_source_device(source)?;
+        let device_name = device.name().unwrap_or_else(|_| "Unknown device".to_string());
+        // WASAPI captures an output device when an input stream is built on it
+        let config = if loopback { device.default_output_config()? } else { device.default_input_config()? };
+        let level = Arc::new(ExternalLevel::default());
+
+        let stream = match config.sample_format() {
+            cpal::SampleFormat::F32 => build_tap::<f32>(&device, &config.into(), level.clone())?,
+            cpal::SampleFormat::I16 => build_tap::<i16>(&device, &config.into(), level.clone())?,
+            cpal::SampleFormat::U16 => build_tap::<u16>(&device, &config.into(), level.clone())?,
+            format => return Err(AudioError::UnsupportedSampleFormat(format)),
+        };
+        stream.play()?;
+        info!(target: "audio", "🎧 Listening to external audio on '{}'", device_name);
+        Ok(Self { _stream: stream, level, device_name })
+    }
+
+    pub fn level(&self) -> Arc<ExternalLevel> {
+        self.level.clone()
+    }
+
+    pub fn device_name(&self) -> &str {
+        &self.device_name
+    }
+}
+
+/// The capture device for `source`, and whether it is an output opened for loopback
+fn find_source_device(source: &ExternalSource) -> Result<(Device, bool)> {
+    let host = cpal::default_host();
+    match source {
+        ExternalSource::Loopback if cfg!(target_os = "windows") => {
+            Ok((host.default_output_device().ok_or(AudioError::NoAudioDevice)?, true))
+        }
+        // PulseAudio and PipeWire expose each output's mix as a "monitor" input
+        ExternalSource::Loopback => host
+            .input_devices()?
+            .find(|device| device.name().is_ok_and(|name| name.to_lowercase().contains("monitor")))
+            .map(|device| (device, false))
+            .ok_or_else(|| AudioError::AudioDeviceNotFound("loopback monitor".to_string())),
+        ExternalSource::Input(Some(name)) => host
+            .input_devices()?
+            .find(|device| device.name().is_ok_and(|n| n == *name))
+            .map(|device| (device, false))
+            .ok_or_else(|| AudioError::AudioDeviceNotFound(name.clone())),
+        ExternalSource::Input(None) => Ok((host.default_input_device().ok_or(AudioError::NoAudioDevice)?, false)),
+    }
+}
+
+fn build_tap<T>(device: &Device, config: &StreamConfig, level: Arc<ExternalLevel>) -> Result<Stream>
+where
+    T: Sample + SizedSample + Send + 'static,
+    f32: FromSample<T>,
+{
+    let stream = device.build_input_stream(
+        config,
+        move |data: &[T], _: &cpal::InputCallbackInfo| {
+            let peak = data.iter().fold(0.0f32, |peak, &sample| peak.max(f32::from_sample(sample).abs()));
+            level.set(peak);
+        },
+        move |err| error!(target: "audio", "External audio input error: {}", err),
+        None,
+    )?;
+    Ok(stream)
+}
@@ -0,0 +1,706 @@
+// === PSYCHEDELIC AUDIO CONSCIOUSNESS LAYER ===
+// "Maximum Decibels, Minimum Code" - The Minter Agent
+//
+// Transforms consciousness levels into pure sonic chaos through:
+// • Procedural synthesis driven by mathematical consciousness patterns
+// • Species-specific sonic signatures (Disco Llamas vs Quantum Sheep)
+// • Hive mind collective harmonics that emerge from llama interactions
+// • Reality distortion audio effects synchronized with visual glitches
+// • Environmental audio zones that respond to territorial dynamics
+// • Meta-consciousness observer interventions through frequency manipulation
+
+
+pub mod synthesis;
+pub mod buffer;
+pub mod crossfade;
+// pub mod consciousness; // Temporarily disabled due to type conflicts
+pub mod effects;
+pub mod environment;
+pub mod error;
+pub mod harmony;
+pub mod input;
+pub mod loudness;
+pub mod mixer;
+pub mod onset;
+pub mod safety;
+pub mod sampler;
+pub mod scope;
+pub mod signature;
+pub mod surround;
+pub mod voices;
+pub mod worker;
+
+use cpal::{Device, Stream, StreamConfig, Sample, FromSample, SizedSample};
+use cpal::traits::{HostTrait, DeviceTrait, StreamTrait};
+use std::sync::{Arc, Mutex, PoisonError, TryLockError};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::collections::VecDeque;
+use glam::Vec2;
+use tracing::{error, info, warn};
+
+// Use local BeatState for audio processing
+use crate::error::{AudioError, Result};
+use aetherium_sim::mathematics::beat_engine::BeatState;
+use aetherium_sim::entities::SpeciesType;
+use aetherium_sim::params;
+
+/// Left/right pair; the effects chain works in stereo frames
+pub type StereoFrame = [f32; 2];
+
+#[derive(Debug, Clone)]
+pub struct CompatLlamaRenderData {
+    pub position: Vec2,
+    pub color_wavelength: Vec2,
+    pub trip_intensity: f32,
+    pub reality_distortion: f32,
+    pub species: SpeciesType,
+    pub valence: f32, // -1..1 mood, souring or sweetening its species' voice
+    pub arousal: f32, // 0..1 agitation, quickening and driving its species' voice
+}
+
+#[derive(Debug, Clone)]
+pub enum CompatChaosEvent {
+    RealityTear { strength: f32, position: Vec2 },
+    LlamaSpawned { consciousness: f32 },
+    CrystalHarvested,
+    DeathEcho { consciousness: f32 }, // A dead llama left an echo
+}
+
+pub use synthesis::{PsychedelicSynthesizer, AudioWaveform, OscillatorBank};
+pub use effects::{RealityDistortionProcessor, FrequencyMangler, TemporalEcho, FdnReverb, StereoDelay, SpaceSettings, DuckingSettings, SidechainCompressor};
+pub use input::{ExternalInput, ExternalLevel, ExternalSource};
+pub use environment::{AudioEnvironmentZones, MetaObserverAudio};
+pub use safety::{AudioSafetyLimiter, VolumeEnvelope, FrequencyGuard};
+pub use loudness::{LoudnessMeter, LoudnessNormalizer, LoudnessSettings, TruePeakLimiter};
+pub use buffer::{AudioBufferHealth, AudioBufferStats, AdaptiveBufferController};
+pub use crossfade::{EnvironmentBlend, EnvironmentCrossfade};
+pub use harmony::{HarmonyEngine, Scale};
+pub use mixer::{BusControl, MixBus, Mixer, MixerControls};
+pub use sampler::{SampleBank, SampleBankPaths, SampleBuffer, SampleCue, SamplePlayer, Wavetable, WavetableBank, WavetableShape};
+pub use signature::{ConsciousnessAudioMapper, EffectSends, Envelope, SpeciesSonicSignature};
+pub use surround::{ChannelLayout, OutputFrame, SurroundConfig, MAX_OUTPUT_CHANNELS};
+pub use scope::{ScopeTap, SCOPE_SAMPLES, SPECTRUM_BANDS};
+pub use voices::{SoundEvent, VoiceId, VoiceManager, VoiceStats, MAX_EVENT_VOICES};
+pub use worker::{AudioSnapshot, AudioCommand, AudioWorkerHandle, SynthesisAnalysis};
+
+
+// Export new user control types - they will be defined below
+
+/// Main audio consciousness engine - the heart of psychedelic audio chaos
+///
+/// Synthesis runs on a dedicated thread (see `worker`); this side owns the
+/// output device and publishes consciousness state once per frame.
+pub struct AudioConsciousnessEngine {
+    worker: AudioWorkerHandle,
+
+    // Audio hardware interface
+    _device: Device,
+    stream: Option<Stream>,
+    device_name: String,
+    preferred_device: Option<String>, // None = follow the system default output
+    stream_error: Arc<AtomicBool>,    // Raised by the cpal error callback
+    device_state: AudioDeviceState,
+    last_device_check: f64,
+    surround: SurroundConfig, // Channel layout the stream was opened for
+
+    // Real-time audio state
+    audio_buffer: Arc<Mutex<VecDeque<OutputFrame>>>,
+    buffer_health: Arc<AudioBufferHealth>,
+    sample_rate: f32,
+
+    // User controls for audio parameters
+    controls: AudioControls,
+    cursor_position: Vec2,
+    idle_gain: f32, // Output attenuation while the organism is idle
+    external_input: Option<ExternalInput>, // Music the synth ducks under
+}
+
+/// How often the engine polls for default-device changes (seconds)
+const DEVICE_CHECK_INTERVAL: f64 = 2.0;
+
+/// Output device description for device selection
+#[derive(Debug, Clone, PartialEq)]
+pub struct AudioDeviceInfo {
+    pub name: String,
+    pub is_default: bool,
+}
+
+/// Health of the audio output stream
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AudioDeviceState {
+    Active,        // Stream running on the selected device
+    Reconnecting,  // Stream failed - rebuild pending
+    Unavailable,   // No usable output device found
+}
+
+/// User-controllable audio modes that change synthesis and effects
+#[derive(Debug, Clone, PartialEq)]
+pub enum AudioMode {
+    Mellow,   // Gentle, ambient synthesis with soft effects
+    Active,   // Balanced synthesis with moderate chaos
+    Chaotic,  // Extreme synthesis with maximum reality distortion
+}
+
+impl AudioMode {
+    pub fn from_key_char(c: char) -> Option<Self> {
+        match c.to_ascii_lowercase() {
+            'm' => Some(Self::Mellow),
+            'a' => Some(Self::Active),
+            'c' => Some(Self::Chaotic),
+            _ => None,
+        }
+    }
+
+    pub fn to_string(&self) -> &'static str {
+        match self {
+            Self::Mellow => "MELLOW",
+            Self::Active => "ACTIVE",
+            Self::Chaotic => "CHAOTIC",
+        }
+    }
+}
+
+impl Default for AudioMode {
+    fn default() -> Self {
+        Self::Active
+    }
+}
+
+/// User-controllable audio parameters
+#[derive(Debug, Clone)]
+pub struct AudioControls {
+    pub mode: AudioMode,
+    pub volume: f32,        // 0.0 to 1.0
+    pub speed: f32,         // 0.1 to 3.0 tempo multiplier for beats and envelopes; pitch and sample playback stay put
+    pub enabled: bool,      // Master audio on/off
+    pub scale: Scale,       // Scale melodies and chords are quantized to
+    pub mixer: MixerControls,
+    pub ducking: DuckingSettings, // Applies while an external source is being listened to
+    pub environment_crossfade: f32, // Seconds one environment takes to fade into the next, starting on a beat
+    pub loudness: LoudnessSettings, // Loudness target and true-peak ceiling of the output
+    pub filter_cutoff: f32,  // Hz of the low-pass over the llama synth; the maximum leaves it open
+}
+
+impl Default for AudioControls {
+    fn default() -> Self {
+        Self {
+            mode: AudioMode::default(),
+            volume: 0.7,      // 70% default volume
+            speed: 1.0,       // Normal speed
+            enabled: true,    // Audio enabled by default
+            scale: Scale::default(),
+            mixer: MixerControls::default(),
+            ducking: DuckingSettings::default(),
+            environment_crossfade: params::ENVIRONMENT_CROSSFADE_SECONDS.default,
+            loudness: LoudnessSettings::default(),
+            filter_cutoff: params::FILTER_CUTOFF_HZ.default,
+        }
+    }
+}
+
+impl AudioControls {
+    pub fn adjust_volume(&mut self, delta: f32) {
+        self.volume = (self.volume + delta).clamp(0.0, 1.0);
+    }
+
+    pub fn adjust_speed(&mut self, delta: f32) {
+        self.speed = (self.speed + delta).clamp(0.1, 3.0);
+    }
+
+    pub fn toggle_enabled(&mut self) {
+        self.enabled = !self.enabled;
+    }
+
+    pub fn set_mode(&mut self, mode: AudioMode) {
+        self.mode = mode;
+    }
+
+    pub fn cycle_scale(&mut self) {
+        self.scale = self.scale.next();
+    }
+}
+
+/// Audio environment types - from zen to full EDM chaos
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AudioEnvironment {
+    Environmental,    // Nature sounds, ambient textures
+    Meditative,      // New age, massage therapy vibes
+    Psychedelic,     // Mid-range consciousness exploration
+    Electronica,     // Full EDM chaos - the spirit animal
+    HiveMind,        // Collective consciousness harmonics
+    RealityTear,     // When consciousness breaks reality
+}
+
+impl AudioEnvironment {
+    pub fn from_consciousness_level(total_consciousness: f32, llama_count: usize) -> Self {
+        let consciousness_per_llama = if llama_count > 0 {
+            total_consciousness / llama_count as f32
+        } else {
+            0.0
+        };
+
+        match (total_consciousness, consciousness_per_llama, llama_count) {
+            (_, _, count) if count > 50 => Self::HiveMind,
+            (total, _, _) if total > 200.0 => Self::RealityTear,
+            (total, avg, _) if total > 100.0 || avg > 15.0 => Self::Electronica,
+            (total, avg, _) if total > 30.0 || avg > 5.0 => Self::Psychedelic,
+            (total, _, _) if total > 10.0 => Self::Meditative,
+            _ => Self::Environmental,
+        }
+    }
+}
+
+impl AudioConsciousnessEngine {
+    pub fn new() -> Result<Self> {
+        // Initialize audio hardware with CPAL
+        let device = find_output_device(None)?;
+        let device_name = device.name().unwrap_or_else(|_| "Unknown device".to_string());
+
+        // Create audio buffer for real-time synthesis
+        let audio_buffer = Arc::new(Mutex::new(VecDeque::with_capacity(8192)));
+        let buffer_health = Arc::new(AudioBufferHealth::new());
+        let stream_error = Arc::new(AtomicBool::new(false));
+
+        // Build and start the audio stream
+        let surround = SurroundConfig::default();
+        let (stream, sample_rate) = open_output_stream(&device, surround, audio_buffer.clone(), buffer_health.clone(), stream_error.clone())?;
+
+        // Start the synthesis thread that keeps the buffer filled
+        let worker = AudioWorkerHandle::spawn(sample_rate, audio_buffer.clone(), buffer_health.clone())?;
+
+        Ok(Self {
+            worker,
+            _device: device,
+            stream: Some(stream),
+            device_name,
+            preferred_device: None,
+            stream_error,
+            device_state: AudioDeviceState::Active,
+            last_device_check: 0.0,
+            surround,
+            audio_buffer,
+            buffer_health,
+            sample_rate,
+            controls: AudioControls::default(),
+            cursor_position: Vec2::ZERO,
+            idle_gain: 1.0,
+            external_input: None,
+        })
+    }
+
+    /// Enumerate available output devices on the default host
+    pub fn list_devices() -> Vec<AudioDeviceInfo> {
+        let host = cpal::default_host();
+        let default_name = host.default_output_device().and_then(|device| device.name().ok());
+
+        match host.output_devices() {
+            Ok(devices) => devices
+                .filter_map(|device| device.name().ok())
+                .map(|name| AudioDeviceInfo {
+                    is_default: default_name.as_deref() == Some(name.as_str()),
+                    name,
+                })
+                .collect(),
+            Err(e) => {
+                warn!(target: "audio", "🔇 Failed to enumerate audio devices: {}", e);
+                Vec::new()
+            }
+        }
+    }
+
+    /// Select an output device by name, or `None` to follow the system default
+    pub fn set_device(&mut self, name: Option<&str>) -> Result<()> {
+        let device = find_output_device(name)?;
+        self.preferred_device = name.map(str::to_string);
+        self.switch_to_device(device)
+    }
+
+    /// Name of the device currently receiving audio
+    pub fn current_device_name(&self) -> &str {
+        &self.device_name
+    }
+
+    pub fn device_state(&self) -> AudioDeviceState {
+        self.device_state
+    }
+
+    /// Tear down the current stream and start a new one on `device`
+    fn switch_to_device(&mut self, device: Device) -> Result<()> {
+        // Release the old stream before reopening - some backends hold the device exclusively
+        self.stream = None;
+
+        let (stream, sample_rate) = match open_output_stream(&device, self.surround, self.audio_buffer.clone(), self.buffer_health.clone(), self.stream_error.clone()) {
+            Ok(opened) => opened,
+            Err(e) => {
+                self.device_state = AudioDeviceState::Reconnecting;
+                return Err(e);
+            }
+        };
+
+        if sample_rate != self.sample_rate {
+            self.sample_rate = sample_rate;
+            self.worker.send(AudioCommand::SampleRateChanged(sample_rate));
+        }
+
+        // Samples queued for the old device are stale now
+        self.audio_buffer.lock().unwrap_or_else(PoisonError::into_inner).clear();
+        self.buffer_health.reset_fill();
+
+        self.device_name = device.name().unwrap_or_else(|_| "Unknown device".to_string());
+        self._device = device;
+        self.stream = Some(stream);
+        self.stream_error.store(false, Ordering::Relaxed);
+        self.device_state = AudioDeviceState::Active;
+        info!(target: "audio", "🔊 Audio output: {}", self.device_name);
+        Ok(())
+    }
+
+    /// Render for `config`'s speaker layout, reopening the stream with its channel count
+    pub fn set_surround(&mut self, config: SurroundConfig) -> Result<()> {
+        let device = find_output_device(self.preferred_device.as_deref())?;
+        self.surround = config;
+        self.worker.send(AudioCommand::SetSurround(config));
+        info!(target: "audio", "🔈 Output layout: {}", config.layout.name());
+        // A failed reopen is retried with the new layout by the device monitor
+        self.switch_to_device(device)
+    }
+
+    pub fn surround(&self) -> &SurroundConfig {
+        &self.surround
+    }
+
+    /// Rebuild the stream after device errors and follow default-device changes
+    fn monitor_output_device(&mut self, cosmic_time: f64) {
+        if self.stream_error.swap(false, Ordering::Relaxed) {
+            warn!(target: "audio", "🔇 Audio stream error on '{}' - rebuilding output stream", self.device_name);
+            self.device_state = AudioDeviceState::Reconnecting;
+        }
+
+        if cosmic_time - self.last_device_check < DEVICE_CHECK_INTERVAL {
+            return;
+        }
+        self.last_device_check = cosmic_time;
+
+        let needs_rebuild = match self.device_state {
+            // Follow the system default (e.g. headphones unplugged) unless the user pinned a device
+            AudioDeviceState::Active => self.preferred_device.is_none()
+                && cpal::default_host()
+                    .default_output_device()
+                    .and_then(|device| device.name().ok())
+                    .is_some_and(|name| name != self.device_name),
+            AudioDeviceState::Reconnecting | AudioDeviceState::Unavailable => true,
+        };
+
+        if !needs_rebuild {
+            return;
+        }
+
+        // A pinned device that vanished falls back to the system default
+        let preferred = self.preferred_device.clone();
+        let result = find_output_device(preferred.as_deref())
+            .or_else(|_| find_output_device(None))
+            .and_then(|device| self.switch_to_device(device));
+
+        if let Err(e) = result {
+            if self.device_state != AudioDeviceState::Unavailable {
+                warn!(target: "audio", "🔇 No usable audio output ({}) - retrying every {:.0}s", e, DEVICE_CHECK_INTERVAL);
+            }
+            self.device_state = AudioDeviceState::Unavailable;
+        }
+    }
+
+    /// Main update - publishes consciousness state to the synthesis thread
+    pub fn update(&mut self,
+                  cosmic_time: f64,
+                  beat_state: &BeatState,
+                  llama_data: &[CompatLlamaRenderData],
+                  total_consciousness: f32,
+                  hive_coherence: f32) {
+
+        // Recover from unplugged/changed output devices
+        self.monitor_output_device(cosmic_time);
+
+        self.worker.publish(AudioSnapshot {
+            cosmic_time,
+            beat_state: beat_state.clone(),
+            llamas: llama_data.to_vec(),
+            total_consciousness,
+            hive_coherence,
+            cursor_position: self.cursor_position,
+            controls: self.controls.clone(),
+            idle_gain: self.idle_gain,
+        });
+    }
+
+    /// Handle chaos events from the main simulation
+    pub fn handle_chaos_event(&mut self, event: &CompatChaosEvent) {
+        self.worker.send(AudioCommand::Chaos(event.clone()));
+    }
+
+    /// Play a species' one-shot sample (or its synthesized stand-in)
+    pub fn play_cue(&mut self, species: SpeciesType, cue: SampleCue, gain: f32) {
+        self.worker.send(AudioCommand::PlayCue { species, cue, gain });
+    }
+
+    /// Replace a species' sonic signature on the synthesis thread
+    pub fn set_sonic_signature(&mut self, species: SpeciesType, signature: SpeciesSonicSignature) {
+        self.worker.send(AudioCommand::SetSonicSignature { species, signature });
+    }
+
+    /// Hand a loaded sample bank to the synthesis thread
+    pub fn load_sample_bank(&mut self, species: SpeciesType, bank: SampleBank) {
+        self.worker.send(AudioCommand::LoadSampleBank { species, bank });
+    }
+
+    /// Listen to external music and duck the synth under it
+    pub fn enable_ducking(&mut self, source: &ExternalSource) -> Result<()> {
+        let input = ExternalInput::open(source)?;
+        self.worker.send(AudioCommand::SetSidechain(Some(input.level())));
+        self.external_input = Some(input);
+        Ok(())
+    }
+
+    /// Stop listening; the synth returns to full level over the release time
+    pub fn disable_ducking(&mut self) {
+        if self.external_input.take().is_some() {
+            self.worker.send(AudioCommand::SetSidechain(None));
+            info!(target: "audio", "🎧 External audio ducking off");
+        }
+    }
+
+    /// Name of the device being listened to, if ducking is on
+    pub fn ducking_source(&self) -> Option<&str> {
+        self.external_input.as_ref().map(ExternalInput::device_name)
+    }
+
+    /// Close the low-pass over the llama synth down to `hz`, or open it with the maximum
+    pub fn set_filter_cutoff(&mut self, hz: f32) {
+        self.controls.filter_cutoff = params::FILTER_CUTOFF_HZ.clamp(hz);
+    }
+
+    /// Emit a latency calibration click through the normal output path
+    pub fn play_calibration_click(&mut self) {
+        self.worker.send(AudioCommand::CalibrationClick);
+    }
+
+    /// Update cursor position for environmental audio responsiveness
+    /// Quieten the output while the organism is idle (1.0 = full volume)
+    pub fn set_idle_gain(&mut self, gain: f32) {
+        self.idle_gain = gain.clamp(0.0, 1.0);
+    }
+
+    pub fn update_cursor_position(&mut self, cursor_position: Vec2) {
+        self.cursor_position = cursor_position;
+    }
+
+    /// Get current audio analysis data for visualization
+    pub fn get_audio_analysis(&self) -> AudioAnalysisData {
+        let analysis = self.worker.analysis();
+        AudioAnalysisData {
+            current_environment: analysis.current_environment,
+            bass_level: analysis.bass_level,
+            treble_level: analysis.treble_level,
+            consciousness_frequency: analysis.consciousness_frequency,
+            reality_distortion_amount: analysis.reality_distortion_amount,
+            hive_mind_coherence: analysis.hive_mind_coherence,
+            ducking_db: analysis.ducking_db,
+            loudness_lufs: analysis.loudness_lufs,
+            loudness_gain_db: analysis.loudness_gain_db,
+            heard_beat: analysis.heard_beat,
+            heard_onsets: analysis.heard_onsets,
+            event_voices: analysis.event_voices,
+            output_device: self.device_name.clone(),
+            device_state: self.device_state,
+            buffer_stats: self.buffer_health.stats(self.sample_rate),
+        }
+    }
+
+    /// The latest output waveform, oldest sample first, and its spectrum
+    pub fn scope(&self) -> ([f32; SCOPE_SAMPLES], [f32; SPECTRUM_BANDS]) {
+        let waveform = self.worker.waveform();
+        (waveform, scope::spectrum(&waveform, self.sample_rate))
+    }
+
+    /// User control methods
+    pub fn get_controls(&self) -> &AudioControls {
+        &self.controls
+    }
+
+    pub fn get_controls_mut(&mut self) -> &mut AudioControls {
+        &mut self.controls
+    }
+
+    pub fn set_audio_mode(&mut self, mode: AudioMode) {
+        info!(target: "audio", "🎵 Audio mode changed to: {}", mode.to_string());
+        self.controls.set_mode(mode);
+    }
+
+    pub fn adjust_volume(&mut self, delta: f32) {
+        self.controls.adjust_volume(delta);
+        info!(target: "audio", "🔊 Volume: {:.0}%", self.controls.volume * 100.0);
+    }
+
+    pub fn adjust_speed(&mut self, delta: f32) {
+        self.controls.adjust_speed(delta);
+        info!(target: "audio", "⚡ Speed: {:.1}x", self.controls.speed);
+    }
+
+    pub fn toggle_audio(&mut self) {
+        self.controls.toggle_enabled();
+        let status = if self.controls.enabled { "ENABLED" } else { "DISABLED" };
+        info!(target: "audio", "🎵 Audio: {}", status);
+    }
+
+    pub fn cycle_scale(&mut self) {
+        self.controls.cycle_scale();
+        info!(target: "audio", "🎼 Scale: {}", self.controls.scale.name());
+    }
+
+    pub fn set_bus_gain(&mut self, bus: MixBus, gain: f32) {
+        self.controls.mixer.set_gain(bus, gain);
+        info!(target: "audio", "🎚️ {} bus: {:.0}%", bus.name(), self.controls.mixer.bus(bus).gain * 100.0);
+    }
+
+    pub fn set_bus_muted(&mut self, bus: MixBus, muted: bool) {
+        self.controls.mixer.set_muted(bus, muted);
+        info!(target: "audio", "🎚️ {} bus: {}", bus.name(), if muted { "MUTED" } else { "ON" });
+    }
+
+    pub fn toggle_bus_mute(&mut self, bus: MixBus) {
+        let muted = !self.controls.mixer.bus(bus).muted;
+        self.set_bus_muted(bus, muted);
+    }
+}
+
+/// Audio analysis data for visual synchronization
+#[derive(Debug, Clone)]
+pub struct AudioAnalysisData {
+    pub current_environment: AudioEnvironment,
+    pub bass_level: f32,
+    pub treble_level: f32,
+    pub consciousness_frequency: f32,
+    pub reality_distortion_amount: f32,
+    pub hive_mind_coherence: f32,
+    pub ducking_db: f32, // How far the synth is currently ducked under external music
+    pub loudness_lufs: Option<f32>, // Integrated loudness of the output over the last half minute
+    pub loudness_gain_db: f32,      // Gain the normalizer applies to reach the loudness target
+    pub heard_beat: f32,            // Onset envelope of the finished output, 0-1: the beat as it sounds
+    pub heard_onsets: u64,          // Onsets found in the output so far
+    pub event_voices: VoiceStats,   // Event sounds playing, dropped and cut short by the voice manager
+    pub output_device: String,
+    pub device_state: AudioDeviceState,
+    pub buffer_stats: AudioBufferStats,
+}
+
+/// Find an output device by name, or the host default when `name` is `None`
+fn find_output_device(name: Option<&str>) -> Result<Device> {
+    let host = cpal::default_host();
+    match name {
+        Some(name) => host
+            .output_devices()?
+            .find(|device| device.name().is_ok_and(|n| n == name))
+            .ok_or_else(|| AudioError::AudioDeviceNotFound(name.to_string())),
+        None => host
+            .default_output_device()
+            .ok_or(AudioError::NoAudioDevice),
+    }
+}
+
+/// The device's default config, widened to `channels` when the device supports that at the same rate and format
+fn output_config_with_channels(device: &Device, channels: usize) -> Result<cpal::SupportedStreamConfig> {
+    let default = device.default_output_config()?;
+    if channels <= default.channels() as usize {
+        return Ok(default);
+    }
+
+    let widened = device.supported_output_configs().ok().and_then(|mut configs| {
+        configs.find(|config| {
+            config.channels() as usize == channels
+                && config.sample_format() == default.sample_format()
+                && (config.min_sample_rate()..=config.max_sample_rate()).contains(&default.sample_rate())
+        })
+    });
+    match widened {
+        Some(config) => Ok(config.with_sample_rate(default.sample_rate())),
+        None => {
+            warn!(target: "audio", "🔈 Output device has no {}-channel mode - folding down to {} channels", channels, default.channels());
+            Ok(default)
+        }
+    }
+}
+
+/// Open and start an output stream on `device`, returning it with its sample rate
+fn open_output_stream(
+    device: &Device,
+    surround: SurroundConfig,
+    audio_buffer: Arc<Mutex<VecDeque<OutputFrame>>>,
+    buffer_health: Arc<AudioBufferHealth>,
+    stream_error: Arc<AtomicBool>,
+) -> Result<(Stream, f32)> {
+    let config = output_config_with_channels(device, surround.layout.channel_count())?;
+    let sample_rate = config.sample_rate().0 as f32;
+
+    let stream = match config.sample_format() {
+        cpal::SampleFormat::F32 => build_stream::<f32>(device, &config.into(), surround, audio_buffer, buffer_health, stream_error)?,
+        cpal::SampleFormat::I16 => build_stream::<i16>(device, &config.into(), surround, audio_buffer, buffer_health, stream_error)?,
+        cpal::SampleFormat::U16 => build_stream::<u16>(device, &config.into(), surround, audio_buffer, buffer_health, stream_error)?,
+        format => return Err(AudioError::UnsupportedSampleFormat(format)),
+    };
+
+    stream.play()?;
+    Ok((stream, sample_rate))
+}
+
+// Helper function to build audio stream
+fn build_stream<T>(
+    device: &Device,
+    config: &StreamConfig,
+    surround: SurroundConfig,
+    audio_buffer: Arc<Mutex<VecDeque<OutputFrame>>>,
+    buffer_health: Arc<AudioBufferHealth>,
+    stream_error: Arc<AtomicBool>,
+) -> Result<Stream>
+where
+    T: Sample + FromSample<f32> + SizedSample + Send + 'static,
+{
+    let channels = config.channels as usize;
+
+    let stream = device.build_output_stream(
+        config,
+        move |data: &mut [T], _: &cpal::OutputCallbackInfo| {
+            // Never block or panic on the real-time thread: if the synthesis
+            // thread holds the buffer, play one callback of silence instead
+            let mut buffer = match audio_buffer.try_lock() {
+                Ok(buffer) => buffer,
+                Err(TryLockError::Poisoned(poisoned)) => poisoned.into_inner(),
+                Err(TryLockError::WouldBlock) => {
+                    data.fill(T::EQUILIBRIUM);
+                    buffer_health.record_contention();
+                    return;
+                }
+            };
+            let mut starved = false;
+
+            for frame in data.chunks_mut(channels) {
+                let output = buffer.pop_front().unwrap_or_else(|| {
+                    starved = true;
+                    [0.0; MAX_OUTPUT_CHANNELS]
+                });
+
+                // Any channel count works: mono gets the downmix, too few channels the fold-down
+                for (channel, channel_sample) in frame.iter_mut().enumerate() {
+                    *channel_sample = T::from_sample(surround.device_sample(&output, channels, channel));
+                }
+            }
+
+            buffer_health.record_callback(buffer.len(), starved);
+        },
+        move |err| {
+            error!(target: "audio", "Audio stream error: {}", err);
+            stream_error.store(true, Ordering::Relaxed);
+        },
+        None,
+    )?;
+
+    Ok(stream)
+}
\ No newline at end of file
@@ -0,0 +1,343 @@
+// === LOUDNESS NORMALIZATION ===
+// The synth grows louder as the herd's consciousness grows, and an
+// installation left running for days would slowly creep up with it. This
+// meters the program the way ITU-R BS.1770 does: K-weighted, in 400 ms blocks
+// every 100 ms, gated at -70 LUFS and again 10 LU under the ungated level,
+// over the last half minute. A slow automatic gain steers that loudness
+// toward a target, and the safety limiter's true-peak ceiling catches
+// whatever the gain cannot. Channels share one gain so the stereo image holds.
+
+use std::collections::VecDeque;
+use aetherium_sim::params;
+
+/// Seconds of blocks the integrated loudness is measured over
+pub const LOUDNESS_WINDOW_SECONDS: f32 = 30.0;
+/// Loudest boost the gain applies to a quiet program, in dB
+const MAX_BOOST_DB: f32 = 6.0;
+/// Deepest cut the gain applies to a loud program, in dB
+const MAX_CUT_DB: f32 = 24.0;
+/// How fast the gain moves, in dB per second, slow enough not to pump with the music
+const GAIN_SLEW_DB_PER_SECOND: f32 = 1.0;
+/// Blocks quieter than this never count toward the integrated loudness
+const ABSOLUTE_GATE_LUFS: f64 = -70.0;
+/// Blocks this far under the ungated loudness are left out as well
+const RELATIVE_GATE_LU: f64 = 10.0;
+/// Hops of 100 ms in one 400 ms block
+const HOPS_PER_BLOCK: usize = 4;
+/// Oversampling the true-peak detector looks between samples with
+const OVERSAMPLING: usize = 4;
+/// Taps of each interpolation phase; the ceiling looks ahead half of them
+const TRUE_PEAK_TAPS: usize = 8;
+
+/// Loudness target and output ceiling of the safety stage
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LoudnessSettings {
+    pub normalize: bool,           // Steer the program toward the target
+    pub target_lufs: f32,          // Integrated loudness to hold the program at
+    pub true_peak_ceiling_db: f32, // No output sample or inter-sample peak goes above this, in dBTP
+}
+
+impl Default for LoudnessSettings {
+    fn default() -> Self {
+        Self {
+            normalize: true,
+            target_lufs: params::LOUDNESS_TARGET_LUFS.default,
+            true_peak_ceiling_db: params::TRUE_PEAK_CEILING_DB.default,
+        }
+    }
+}
+
+/// Loudness of a mean square, in LUFS
+fn lufs(power: f64) -> f64 {
+    -0.691 + 10.0 * power.max(1e-12).log10()
+}
+
+/// One second-order section
+#[derive(Debug, Clone, Copy)]
+struct Biquad {
+    b: [f64; 3],
+    a: [f64; 2],
+    state: [f64; 2],
+}
+
+impl Biquad {
+    fn process(&mut self, input: f64) -> f64 {
+        let output = self.b[0] * input + self.state[0];
+        self.state[0] = self.b[1] * input - self.a[0] * output + self.state[1];
+        self.state[1] = self.b[2] * input - self.a[1] * output;
+        output
+    }
+}
+
+/// The BS.1770 K-weighting: a high shelf for the head, then a high-pass under the bass
+#[derive(Debug, Clone, Copy)]
+struct KWeighting {
+    shelf: Biquad,
+    high_pass: Biquad,
+}
+
+impl KWeighting {
+    /// Coefficients for any sample rate, matching the standard's tables at 48 kHz
+    fn new(sample_rate: f32) -> Self {
+        let sample_rate = sample_rate as f64;
+        let (f0, gain_db, q) = (1681.974450955533, 3.999843853973347, 0.7071752369554196);
+        let k = (std::f64::consts::PI * f0 / sample_rate).tan();
+        let vh = 10f64.powf(gain_db / 20.0);
+        let vb = vh.powf(0.4996667741545416);
+        let a0 = 1.0 + k / q + k * k;
+        let shelf = Biquad {
+            b: [(vh + vb * k / q + k * k) / a0, 2.0 * (k * k - vh) / a0, (vh - vb * k / q + k * k) / a0],
+            a: [2.0 * (k * k - 1.0) / a0, (1.0 - k / q + k * k) / a0],
+            state: [0.0; 2],
+        };
+
+        let (f0, q) = (38.13547087602444, 0.5003270373238773);
+        let k = (std::f64::consts::PI * f0 / sample_rate).tan();
+        let a0 = 1.0 + k / q + k * k;
+        let high_pass = Biquad {
+            b: [1.0, -2.0, 1.0],
+            a: [2.0 * (k * k - 1.0) / a0, (1.0 - k / q + k * k) / a0],
+            state: [0.0; 2],
+        };
+        Self { shelf, high_pass }
+    }
+
+    fn process(&mut self, input: f32) -> f64 {
+        self.high_pass.process(self.shelf.process(input as f64))
+    }
+}
+
+/// Gated BS.1770 loudness of a stereo program over a sliding window
+#[derive(Debug, Clone)]
+pub struct LoudnessMeter {
+    filters: [KWeighting; 2],
+    hop_samples: usize,
+    hop_power: f64,            // Summed channel power of the hop so far
+    hop_count: usize,
+    hops: VecDeque<f64>,       // Mean power of the latest hops, one block's worth
+    blocks: VecDeque<f64>,     // Mean power of every block in the window
+    max_blocks: usize,
+    integrated: Option<f32>,
+}
+
+impl LoudnessMeter {
+    pub fn new(sample_rate: f32) -> Self {
+        let hop_samples = ((sample_rate * 0.1).round() as usize).max(1);
+        let max_blocks = (LOUDNESS_WINDOW_SECONDS / 0.1) as usize;
+        Self {
+            filters: [KWeighting::new(sample_rate); 2],
+            hop_samples,
+            hop_power: 0.0,
+            hop_count: 0,
+            hops: VecDeque::with_capacity(HOPS_PER_BLOCK),
+            blocks: VecDeque::with_capacity(max_blocks),
+            max_blocks,
+            integrated: None,
+        }
+    }
+
+    /// Meter one stereo frame; returns true when a new block finished
+    pub fn push(&mut self, frame: [f32; 2]) -> bool {
+        for (filter, &sample) in self.filters.iter_mut().zip(&frame) {
+            let weighted = filter.process(sample);
+            self.hop_power += weighted * weighted;
+        }
+        self.hop_count += 1;
+        if self.hop_count < self.hop_samples {
+            return false;
+        }
+
+        if self.hops.len() == HOPS_PER_BLOCK {
+            self.hops.pop_front();
+        }
+        self.hops.push_back(self.hop_power / self.hop_count as f64);
+        self.hop_power = 0.0;
+        self.hop_count = 0;
+        if self.hops.len() < HOPS_PER_BLOCK {
+            return false;
+        }
+
+        if self.blocks.len() == self.max_blocks {
+            self.blocks.pop_front();
+        }
+        self.blocks.push_back(self.hops.iter().sum::<f64>() / HOPS_PER_BLOCK as f64);
+        self.integrated = self.gated_loudness();
+        true
+    }
+
+    /// Loudness of the latest 400 ms block
+    pub fn momentary_lufs(&self) -> Option<f32> {
+        self.blocks.back().map(|&power| lufs(power) as f32)
+    }
+
+    /// Gated loudness over the window; None while everything is below the absolute gate
+    pub fn integrated_lufs(&self) -> Option<f32> {
+        self.integrated
+    }
+
+    fn gated_loudness(&self) -> Option<f32> {
+        let mean = |threshold: f64| {
+            let (sum, count) = self.blocks.iter()
+                .filter(|&&power| lufs(power) > threshold)
+                .fold((0.0, 0usize), |(sum, count), power| (sum + power, count + 1));
+            (count > 0).then(|| sum / count as f64)
+        };
+        let ungated = mean(ABSOLUTE_GATE_LUFS)?;
+        let relative_gate = (lufs(ungated) - RELATIVE_GATE_LU).max(ABSOLUTE_GATE_LUFS);
+        mean(relative_gate).map(|power| lufs(power) as f32)
+    }
+}
+
+/// Automatic gain steering a stereo program toward the loudness target
+#[derive(Debug, Clone)]
+pub struct LoudnessNormalizer {
+    meter: LoudnessMeter,
+    settings: LoudnessSettings,
+    gain_db: f32,
+    slew_per_sample: f32,
+}
+
+impl LoudnessNormalizer {
+    pub fn new(sample_rate: f32) -> Self {
+        Self {
+            meter: LoudnessMeter::new(sample_rate),
+            settings: LoudnessSettings::default(),
+            gain_db: 0.0,
+            slew_per_sample: GAIN_SLEW_DB_PER_SECOND / sample_rate,
+        }
+    }
+
+    pub fn set_settings(&mut self, settings: LoudnessSettings) {
+        self.settings = settings;
+    }
+
+    /// Meter a frame of the program and return the gain to apply to it
+    pub fn process(&mut self, frame: [f32; 2]) -> f32 {
+        self.meter.push(frame);
+        // Silence says nothing about how loud the music is, so the gain holds through it
+        let target_db = match (self.settings.normalize, self.meter.integrated_lufs()) {
+            (false, _) => 0.0,
+            (true, Some(loudness)) => (self.settings.target_lufs - loudness).clamp(-MAX_CUT_DB, MAX_BOOST_DB),
+            (true, None) => self.gain_db,
+        };
+        self.gain_db += (target_db - self.gain_db).clamp(-self.slew_per_sample, self.slew_per_sample);
+        10f32.powf(self.gain_db / 20.0)
+    }
+
+    pub fn integrated_lufs(&self) -> Option<f32> {
+        self.meter.integrated_lufs()
+    }
+
+    /// Gain currently applied, in dB
+    pub fn gain_db(&self) -> f32 {
+        self.gain_db
+    }
+}
+
+/// Hard ceiling on the true peak: looks between samples through 4x oversampling
+/// and pulls the gain down before a peak gets out, recovering over `release`
+#[derive(Debug, Clone)]
+pub struct TruePeakLimiter {
+    history: [f32; TRUE_PEAK_TAPS], // Newest last; the sample going out sits in the middle
+    phases: [[f32; TRUE_PEAK_TAPS]; OVERSAMPLING - 1],
+    ceiling: f32,
+    gain: f32,
+    release: f32,                   // Share of the way back to unity gain per sample
+    previous_interval_peak: f32,
+}
+
+impl TruePeakLimiter {
+    pub fn new(sample_rate: f32) -> Self {
+        let center = TRUE_PEAK_TAPS / 2 - 1;
+        let half_span = TRUE_PEAK_TAPS as f32 / 2.0;
+        // Hann-windowed sinc taps for the points a quarter, half and three quarters past the center
+        let phases = std::array::from_fn(|phase| {
+            let fraction = (phase + 1) as f32 / OVERSAMPLING as f32;
+            std::array::from_fn(|tap| {
+                let x = center as f32 + fraction - tap as f32;
+                let sinc = if x == 0.0 { 1.0 } else { (std::f32::consts::PI * x).sin() / (std::f32::consts::PI * x) };
+                let window = 0.5 * (1.0 + (std::f32::consts::PI * x / half_span).cos());
+                sinc * window
+            })
+        });
+        Self {
+            history: [0.0; TRUE_PEAK_TAPS],
+            phases,
+            ceiling: 10f32.powf(LoudnessSettings::default().true_peak_ceiling_db / 20.0),
+            gain: 1.0,
+            release: 1.0 - (-1.0 / (sample_rate * 0.05)).exp(), // 50 ms
+            previous_interval_peak: 0.0,
+        }
+    }
+
+    pub fn set_ceiling_db(&mut self, ceiling_db: f32) {
+        self.ceiling = 10f32.powf(ceiling_db / 20.0);
+    }
+
+    /// Take a sample in and give the one from half the taps ago out, under the ceiling
+    pub fn process(&mut self, input: f32) -> f32 {
+        self.history.rotate_left(1);
+        self.history[TRUE_PEAK_TAPS - 1] = input;
+
+        let center = TRUE_PEAK_TAPS / 2 - 1;
+        let sample = self.history[center];
+        let interval_peak = self.phases.iter()
+            .map(|taps| taps.iter().zip(&self.history).map(|(tap, value)| tap * value).sum::<f32>().abs())
+            .fold(sample.abs().max(self.history[center + 1].abs()), f32::max);
+        let peak = interval_peak.max(self.previous_interval_peak);
+        self.previous_interval_peak = interval_peak;
+
+        let needed = if peak > self.ceiling { self.ceiling / peak } else { 1.0 };
+        self.gain = if needed < self.gain { needed } else { self.gain + (needed - self.gain) * self.release };
+        (sample * self.gain).clamp(-self.ceiling, self.ceiling)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_RATE: f32 = 48_000.0;
+
+    fn sine(amplitude: f32, seconds: f32) -> impl Iterator<Item = f32> {
+        (0..(SAMPLE_RATE * seconds) as usize)
+            .map(move |i| (i as f32 / SAMPLE_RATE * 997.0 * std::f32::consts::TAU).sin() * amplitude)
+    }
+
+    #[test]
+    fn test_meter_reads_a_reference_tone_and_gain_steers_toward_target() {
+        // A 997 Hz tone peaking at -20 dBFS in both channels measures -20 LUFS
+        let mut meter = LoudnessMeter::new(SAMPLE_RATE);
+        sine(0.1, 3.0).for_each(|sample| { meter.push([sample, sample]); });
+        let loudness = meter.integrated_lufs().unwrap();
+        assert!((loudness + 20.0).abs() < 0.2, "measured {loudness} LUFS");
+
+        // Ten seconds at the slew rate is enough to pull a program 8 LU over the target down onto it
+        let mut normalizer = LoudnessNormalizer::new(SAMPLE_RATE);
+        normalizer.set_settings(LoudnessSettings { target_lufs: -28.0, ..LoudnessSettings::default() });
+        sine(0.1, 10.0).for_each(|sample| { normalizer.process([sample, sample]); });
+        assert!((normalizer.gain_db() + 8.0).abs() < 0.2, "gain {} dB", normalizer.gain_db());
+
+        // Nothing gets past the true-peak ceiling, not even a full-scale tone
+        let mut limiter = TruePeakLimiter::new(SAMPLE_RATE);
+        let ceiling = 10f32.powf(-1.0 / 20.0);
+        assert!(sine(1.0, 0.5).map(|sample| limiter.process(sample)).all(|sample| sample.abs() <= ceiling));
+    }
+
+    #[test]
+    fn test_limiter_catches_peaks_between_samples() {
+        // A quarter-rate tone sampled 45 degrees off its crests: every sample sits at
+        // 0.71, under the ceiling, while the waveform between them reaches 1.0
+        let mut limiter = TruePeakLimiter::new(SAMPLE_RATE);
+        let ceiling = 10f32.powf(-1.0 / 20.0);
+        let tone = (0..4800).map(|i| (i as f32 * std::f32::consts::FRAC_PI_2 + std::f32::consts::FRAC_PI_4).sin());
+        let output: Vec<f32> = tone.map(|sample| limiter.process(sample)).collect();
+
+        assert!(limiter.gain < ceiling * 1.02, "gain {} never reduced for the inter-sample peak", limiter.gain);
+        assert!(limiter.gain > ceiling * 0.9, "gain {} reduced far more than needed", limiter.gain);
+        // The samples never reached the clamp, so the gain alone keeps the true peak under the ceiling
+        let sample_peak = output[TRUE_PEAK_TAPS..].iter().fold(0.0f32, |peak, sample| peak.max(sample.abs()));
+        assert!(sample_peak < ceiling);
+        assert!(sample_peak / std::f32::consts::FRAC_1_SQRT_2 <= ceiling * 1.02, "true peak {}", sample_peak / std::f32::consts::FRAC_1_SQRT_2);
+    }
+}
@@ -0,0 +1,168 @@
+// === MIX BUSES ===
+// The synthesis chain renders four buses instead of one sample, so each can
+// be turned down or muted on its own. Every bus has its own peak limiter,
+// so one loud bus cannot drive the others into the master safety limiter.
+
+use aetherium_sim::params;
+
+/// Output ceiling of each bus limiter
+const BUS_CEILING: f32 = 0.8;
+/// Seconds for a bus limiter to recover after a peak
+const LIMITER_RELEASE_SECONDS: f32 = 0.2;
+/// Seconds for gain and mute changes to settle, short enough to feel instant
+const GAIN_GLIDE_SECONDS: f32 = 0.02;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MixBus {
+    LlamaSynth,    // Mood music, species voices and their distortion
+    Ambient,       // Environment zones and soundscape
+    ChaosSfx,      // One-shot event cues
+    HiveHarmonics, // Chord pad shaped by hive coherence
+}
+
+impl MixBus {
+    pub const ALL: [MixBus; 4] = [Self::LlamaSynth, Self::Ambient, Self::ChaosSfx, Self::HiveHarmonics];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::LlamaSynth => "LLAMA SYNTH",
+            Self::Ambient => "AMBIENT",
+            Self::ChaosSfx => "CHAOS SFX",
+            Self::HiveHarmonics => "HIVE HARMONICS",
+        }
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// User-facing state of one bus
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BusControl {
+    pub gain: f32, // 0.0 to 1.0
+    pub muted: bool,
+}
+
+impl Default for BusControl {
+    fn default() -> Self {
+        Self { gain: 1.0, muted: false }
+    }
+}
+
+/// Gain and mute for every bus, carried to the synthesis thread with the audio controls
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MixerControls {
+    buses: [BusControl; 4], // Indexed like MixBus::ALL
+}
+
+impl MixerControls {
+    pub fn bus(&self, bus: MixBus) -> BusControl {
+        self.buses[bus.index()]
+    }
+
+    pub fn set_gain(&mut self, bus: MixBus, gain: f32) {
+        let gain = params::BUS_GAIN.clamp(gain);
+        self.buses[bus.index()].gain = gain;
+    }
+
+    pub fn set_muted(&mut self, bus: MixBus, muted: bool) {
+        self.buses[bus.index()].muted = muted;
+    }
+
+    pub fn toggle_mute(&mut self, bus: MixBus) {
+        let control = &mut self.buses[bus.index()];
+        control.muted = !control.muted;
+    }
+
+    /// Gain actually applied to the bus, zero while muted
+    pub fn effective_gain(&self, bus: MixBus) -> f32 {
+        let control = self.bus(bus);
+        if control.muted { 0.0 } else { control.gain }
+    }
+}
+
+/// Peak limiter with an instant attack and exponential release
+struct BusLimiter {
+    envelope: f32,
+    release: f32,
+}
+
+impl BusLimiter {
+    fn new(sample_rate: f32) -> Self {
+        Self { envelope: 0.0, release: (-1.0 / (LIMITER_RELEASE_SECONDS * sample_rate)).exp() }
+    }
+
+    fn process(&mut self, sample: f32) -> f32 {
+        let sample = if sample.is_finite() { sample } else { 0.0 };
+        self.envelope = sample.abs().max(self.envelope * self.release);
+        if self.envelope > BUS_CEILING {
+            sample * BUS_CEILING / self.envelope
+        } else {
+            sample
+        }
+    }
+}
+
+/// Applies smoothed per-bus gain and limiting
+pub struct Mixer {
+    limiters: [BusLimiter; 4],
+    gains: [f32; 4],
+    glide: f32,
+}
+
+impl Mixer {
+    pub fn new(sample_rate: f32) -> Self {
+        Self {
+            limiters: std::array::from_fn(|_| BusLimiter::new(sample_rate)),
+            gains: [1.0; 4],
+            glide: 1.0 - (-1.0 / (GAIN_GLIDE_SECONDS * sample_rate)).exp(),
+        }
+    }
+
+    /// Limit and fade one sample of every bus, in `MixBus::ALL` order
+    pub fn process(&mut self, buses: [f32; 4], controls: &MixerControls) -> [f32; 4] {
+        let mut out = [0.0; 4];
+        for bus in MixBus::ALL {
+            let i = bus.index();
+            self.gains[i] += (controls.effective_gain(bus) - self.gains[i]) * self.glide;
+            out[i] = self.limiters[i].process(buses[i]) * self.gains[i];
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_muted_bus_is_silent_and_others_pass() {
+        let mut mixer = Mixer::new(48_000.0);
+        let mut controls = MixerControls::default();
+        controls.toggle_mute(MixBus::ChaosSfx);
+        controls.set_gain(MixBus::Ambient, 0.5);
+
+        let mut last = [0.0; 4];
+        for _ in 0..48_000 {
+            last = mixer.process([0.3, 0.2, 0.7, 0.0], &controls);
+        }
+        assert!((last[0] - 0.3).abs() < 1e-4 && (last[1] - 0.1).abs() < 1e-4, "mixed {:?}", last);
+        assert!(last[2].abs() < 1e-4);
+
+        controls.toggle_mute(MixBus::ChaosSfx);
+        assert_eq!(controls.effective_gain(MixBus::ChaosSfx), 1.0);
+    }
+
+    #[test]
+    fn test_bus_limiter_holds_the_ceiling() {
+        let mut mixer = Mixer::new(48_000.0);
+        let controls = MixerControls::default();
+        for i in 0..4800 {
+            let loud = (i as f32 * 0.05).sin() * 4.0;
+            let mixed = mixer.process([loud, 0.0, f32::NAN, 0.0], &controls);
+            assert!(mixed[0].abs() <= BUS_CEILING + 1e-6, "sample {} = {}", i, mixed[0]);
+            assert_eq!(mixed[2], 0.0);
+        }
+    }
+}
@@ -0,0 +1,162 @@
+// === ONSET DETECTION ===
+// Listens to the finished output, after the effects, ducking and limiting,
+// and finds the moments something new starts: spectral flux over a sliding
+// Hann window, compared against the recent average so quiet passages still
+// have onsets and dense ones are not all onset. Echoes, reverb swells and
+// environment effects that make rhythms of their own are heard here too,
+// which the beat engine cannot know about. Each onset kicks an envelope that
+// decays like a struck note, for visuals that pulse with what is heard.
+
+use std::collections::VecDeque;
+use std::f32::consts::TAU;
+use super::scope::fft;
+
+/// Samples the spectrum is taken over; a power of two for the FFT
+pub const ONSET_WINDOW: usize = 1024;
+/// Samples between spectra
+const ONSET_HOP: usize = 512;
+/// Seconds of flux the threshold averages over
+const FLUX_MEMORY_SECONDS: f32 = 0.5;
+/// How far flux must rise above its recent average to count as an onset
+const THRESHOLD_RATIO: f32 = 1.6;
+/// Flux below this is never an onset, so noise in near-silence stays quiet
+const MIN_FLUX: f32 = 0.004;
+/// Shortest time between onsets; a 16th at 150 BPM still gets through
+const MIN_ONSET_INTERVAL: f32 = 0.09;
+/// Seconds the envelope takes to fall to about a third after an onset
+const ENVELOPE_DECAY_SECONDS: f32 = 0.2;
+/// Gain of the log compression applied to magnitudes before the flux is taken
+const COMPRESSION: f32 = 1000.0;
+
+/// Finds onsets in a stream of stereo output frames
+pub struct OnsetDetector {
+    sample_rate: f32,
+    samples: [f32; ONSET_WINDOW], // Ring of mono samples
+    cursor: usize,                // Next slot to write, i.e. the oldest sample
+    since_hop: usize,
+    hann: Vec<f32>,
+    previous: Vec<f32>,       // Compressed magnitudes of the last spectrum
+    flux_history: VecDeque<f32>,
+    since_onset: f32,         // Seconds
+    envelope: f32,
+    envelope_decay: f32,      // Per sample
+    onsets: u64,
+}
+
+impl OnsetDetector {
+    pub fn new(sample_rate: f32) -> Self {
+        Self {
+            sample_rate,
+            samples: [0.0; ONSET_WINDOW],
+            cursor: 0,
+            since_hop: 0,
+            hann: (0..ONSET_WINDOW).map(|i| 0.5 - 0.5 * (TAU * i as f32 / (ONSET_WINDOW - 1) as f32).cos()).collect(),
+            previous: vec![0.0; ONSET_WINDOW / 2],
+            flux_history: VecDeque::new(),
+            since_onset: f32::MAX,
+            envelope: 0.0,
+            envelope_decay: (-1.0 / (ENVELOPE_DECAY_SECONDS * sample_rate)).exp(),
+            onsets: 0,
+        }
+    }
+
+    /// Listen to one output frame; true when an onset was found on it
+    pub fn push(&mut self, frame: [f32; 2]) -> bool {
+        self.samples[self.cursor] = (frame[0] + frame[1]) * 0.5;
+        self.cursor = (self.cursor + 1) % ONSET_WINDOW;
+        self.envelope *= self.envelope_decay;
+        self.since_onset += 1.0 / self.sample_rate;
+        self.since_hop += 1;
+        if self.since_hop < ONSET_HOP {
+            return false;
+        }
+        self.since_hop = 0;
+        self.analyze()
+    }
+
+    /// The onset envelope: 1 at a strong onset, decaying towards 0 until the next
+    pub fn envelope(&self) -> f32 {
+        self.envelope
+    }
+
+    /// Onsets found so far
+    pub fn onsets(&self) -> u64 {
+        self.onsets
+    }
+
+    /// Take the spectrum of the window and decide whether its flux marks an onset
+    fn analyze(&mut self) -> bool {
+        let mut re: Vec<f32> = (0..ONSET_WINDOW)
+            .map(|i| self.samples[(self.cursor + i) % ONSET_WINDOW] * self.hann[i])
+            .collect();
+        let mut im = vec![0.0; ONSET_WINDOW];
+        fft(&mut re, &mut im);
+
+        // A full-scale sine peaks at a quarter of the window length under the Hann window
+        let scale = ONSET_WINDOW as f32 * 0.25;
+        let mut flux = 0.0;
+        for (bin, previous) in self.previous.iter_mut().enumerate() {
+            let magnitude = (re[bin] * re[bin] + im[bin] * im[bin]).sqrt() / scale;
+            let compressed = (1.0 + COMPRESSION * magnitude).ln() / (1.0 + COMPRESSION).ln();
+            flux += (compressed - *previous).max(0.0);
+            *previous = compressed;
+        }
+        flux /= self.previous.len() as f32;
+
+        let average = if self.flux_history.is_empty() {
+            0.0
+        } else {
+            self.flux_history.iter().sum::<f32>() / self.flux_history.len() as f32
+        };
+        let threshold = (average * THRESHOLD_RATIO).max(MIN_FLUX);
+        let memory = (FLUX_MEMORY_SECONDS * self.sample_rate / ONSET_HOP as f32).ceil() as usize;
+        if self.flux_history.len() >= memory {
+            self.flux_history.pop_front();
+        }
+        self.flux_history.push_back(flux);
+
+        if flux <= threshold || self.since_onset < MIN_ONSET_INTERVAL {
+            return false;
+        }
+        // Twice the threshold or more is a full-strength onset
+        let strength = (flux / threshold - 1.0).clamp(0.0, 1.0).mul_add(0.5, 0.5);
+        self.envelope = self.envelope.max(strength);
+        self.since_onset = 0.0;
+        self.onsets += 1;
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_onsets_follow_hits_and_not_steady_tones() {
+        let sample_rate = 48_000.0;
+        let mut detector = OnsetDetector::new(sample_rate);
+
+        // A steady tone is one onset when it starts, then nothing
+        for i in 0..(sample_rate as usize * 2) {
+            let sample = (TAU * 220.0 * i as f32 / sample_rate).sin() * 0.3;
+            detector.push([sample, sample]);
+        }
+        assert_eq!(detector.onsets(), 1);
+
+        // Noise bursts twice a second over the tone, as a drum pattern might land
+        let mut detector = OnsetDetector::new(sample_rate);
+        let mut rng = fastrand::Rng::with_seed(7);
+        let mut peak_envelope = 0.0f32;
+        let beat = sample_rate as usize / 2;
+        for i in 0..(beat * 8) {
+            let tone = (TAU * 220.0 * i as f32 / sample_rate).sin() * 0.1;
+            let since_hit = (i % beat) as f32 / sample_rate;
+            let hit = if i >= beat { (rng.f32() * 2.0 - 1.0) * 0.5 * (-since_hit * 40.0).exp() } else { 0.0 };
+            detector.push([tone + hit, tone + hit]);
+            peak_envelope = peak_envelope.max(detector.envelope());
+        }
+        assert_eq!(detector.onsets(), 1 + 7, "the tone starting, then every hit");
+        assert!(peak_envelope > 0.5);
+        assert!(detector.envelope() < 0.2, "the envelope decays between hits");
+    }
+}
@@ -3,6 +3,7 @@
 // "Maximum chaos, safe decibels" - The Minter Agent
 
 use std::collections::VecDeque;
+use crate::loudness::TruePeakLimiter;
 
 /// Audio safety limiter - ensures safe listening levels while maintaining psychedelic intensity
 pub struct AudioSafetyLimiter {
@@ -27,6 +28,9 @@ pub struct AudioSafetyLimiter {
     emergency_limiter_active: bool,
     emergency_gain_reduction: f32,
     safety_violation_count: u32,
+
+    // Last word on the output level, inter-sample peaks included
+    true_peak_limiter: TruePeakLimiter,
 }
 
 /// Volume envelope processor for smooth gain changes
@@ -168,12 +172,19 @@ impl AudioSafetyLimiter {
             emergency_limiter_active: false,
             emergency_gain_reduction: 1.0,
             safety_violation_count: 0,
+            true_peak_limiter: TruePeakLimiter::new(sample_rate),
         }
     }
 
+    /// Set the ceiling no output peak, between samples or on them, may pass
+    pub fn set_true_peak_ceiling_db(&mut self, ceiling_db: f32) {
+        self.true_peak_limiter.set_ceiling_db(ceiling_db);
+    }
+
     /// Main safety processing - ensures audio is safe while preserving character
     pub fn limit_sample(&mut self, input_sample: f32) -> f32 {
-        let mut safe_sample = input_sample;
+        // NaN or infinity would poison every filter state downstream - treat it as silence
+        let mut safe_sample = if input_sample.is_finite() { input_sample } else { 0.0 };
 
         // First, apply frequency guard to remove dangerous frequencies
         safe_sample = self.frequency_guard.process_sample(safe_sample);
@@ -200,7 +211,8 @@ impl AudioSafetyLimiter {
             safe_sample *= self.emergency_gain_reduction;
         }
 
-        safe_sample
+        // True-peak ceiling last, so nothing above it ever reaches the speakers
+        self.true_peak_limiter.process(safe_sample)
     }
 
     fn check_safety_violations(&mut self) {
@@ -0,0 +1,469 @@
+// === WAVETABLES AND SAMPLE PLAYBACK ===
+// Single-cycle wavetables that morph with consciousness, and a small one-shot
+// sampler for event cues. Species can be given their own WAV sample banks;
+// cues without a sample fall back to a wavetable-synthesized sound.
+
+use std::collections::HashMap;
+use std::f32::consts::TAU;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, OnceLock};
+use serde::{Deserialize, Serialize};
+
+use aetherium_sim::entities::SpeciesType;
+use super::voices::VoiceId;
+use crate::error::{AudioError, Result};
+
+/// Samples per single-cycle wavetable
+pub const WAVETABLE_SIZE: usize = 2048;
+/// One-shot voices playing at once; the oldest is stolen beyond this
+pub const MAX_SAMPLE_VOICES: usize = 12;
+/// A cue cannot restart on the same species within this many seconds
+const RETRIGGER_GUARD_SECONDS: f32 = 0.08;
+/// Seconds a stolen voice takes to fade out rather than click
+const STOLEN_FADE_SECONDS: f32 = 0.01;
+
+// === WAVETABLES ===
+
+/// Single-cycle waveform, sampled with linear interpolation
+#[derive(Debug, Clone)]
+pub struct Wavetable {
+    samples: Box<[f32]>,
+}
+
+impl Wavetable {
+    /// Additive table from harmonic amplitudes (index 0 = fundamental), normalized to ±1
+    pub fn from_harmonics(amplitudes: &[f32]) -> Self {
+        let mut samples: Vec<f32> = (0..WAVETABLE_SIZE)
+            .map(|i| {
+                let cycle = i as f32 / WAVETABLE_SIZE as f32;
+                amplitudes.iter().enumerate()
+                    .map(|(h, amplitude)| (cycle * TAU * (h + 1) as f32).sin() * amplitude)
+                    .sum()
+            })
+            .collect();
+
+        let peak = samples.iter().fold(0.0f32, |peak, s| peak.max(s.abs()));
+        if peak > 0.0 {
+            samples.iter_mut().for_each(|s| *s /= peak);
+        }
+        Self { samples: samples.into_boxed_slice() }
+    }
+
+    /// Value at `phase` cycles (any real number, wrapped to one cycle)
+    pub fn lookup(&self, phase: f32) -> f32 {
+        let position = phase.rem_euclid(1.0) * WAVETABLE_SIZE as f32;
+        let index = position as usize % WAVETABLE_SIZE;
+        let next = (index + 1) % WAVETABLE_SIZE;
+        let fraction = position.fract();
+        self.samples[index] + (self.samples[next] - self.samples[index]) * fraction
+    }
+}
+
+/// Built-in wavetables, ordered from pure to bright so morphing sweeps timbre
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WavetableShape {
+    Glass,  // Sine with a whisper of octave
+    Hollow, // Odd harmonics, clarinet-like
+    Choir,  // Formant-ish cluster around the 3rd-5th harmonics
+    Organ,  // Drawbar stack
+}
+
+impl WavetableShape {
+    pub const ALL: [WavetableShape; 4] = [Self::Glass, Self::Hollow, Self::Choir, Self::Organ];
+
+    fn harmonics(self) -> &'static [f32] {
+        match self {
+            Self::Glass => &[1.0, 0.12],
+            Self::Hollow => &[1.0, 0.0, 0.33, 0.0, 0.2, 0.0, 0.14, 0.0, 0.11],
+            Self::Choir => &[0.6, 0.3, 0.8, 0.9, 0.5, 0.15, 0.08],
+            Self::Organ => &[1.0, 0.8, 0.0, 0.6, 0.0, 0.0, 0.0, 0.4],
+        }
+    }
+
+    /// Shared table, built once on first use
+    pub fn table(self) -> &'static Wavetable {
+        static TABLES: OnceLock<Vec<Wavetable>> = OnceLock::new();
+        let tables = TABLES.get_or_init(|| {
+            Self::ALL.iter().map(|shape| Wavetable::from_harmonics(shape.harmonics())).collect()
+        });
+        &tables[self as usize]
+    }
+
+    /// Blend across the shapes in order: 0.0 = Glass, 1.0 = Organ
+    pub fn morph(position: f32, phase: f32) -> f32 {
+        let scaled = position.clamp(0.0, 1.0) * (Self::ALL.len() - 1) as f32;
+        let lower = (scaled as usize).min(Self::ALL.len() - 2);
+        let blend = scaled - lower as f32;
+        let a = Self::ALL[lower].table().lookup(phase);
+        let b = Self::ALL[lower + 1].table().lookup(phase);
+        a + (b - a) * blend
+    }
+}
+
+/// Morphing wavetable oscillators at fixed ratios of a shared fundamental
+pub struct WavetableBank {
+    voices: Vec<WavetableVoice>,
+    sample_rate: f32,
+}
+
+struct WavetableVoice {
+    ratio: f32,
+    gain: f32,
+    phase: f32,
+}
+
+impl WavetableBank {
+    /// `partials` are (frequency ratio, gain) pairs
+    pub fn new(sample_rate: f32, partials: &[(f32, f32)]) -> Self {
+        let voices = partials.iter()
+            .map(|&(ratio, gain)| WavetableVoice { ratio, gain, phase: fastrand::f32() })
+            .collect();
+        Self { voices, sample_rate }
+    }
+
+    /// Slow pad: root, fifth and octave, slightly detuned
+    pub fn new_pad(sample_rate: f32) -> Self {
+        Self::new(sample_rate, &[(1.0, 0.5), (1.498, 0.3), (2.003, 0.2)])
+    }
+
+    pub fn next_sample(&mut self, frequency: f32, morph: f32) -> f32 {
+        let mut sample = 0.0;
+        for voice in &mut self.voices {
+            sample += WavetableShape::morph(morph, voice.phase) * voice.gain;
+            voice.phase = (voice.phase + frequency * voice.ratio / self.sample_rate).fract();
+        }
+        sample
+    }
+}
+
+// === SAMPLE PLAYBACK ===
+
+/// Mono PCM audio at its own sample rate
+#[derive(Debug, Clone)]
+pub struct SampleBuffer {
+    frames: Arc<[f32]>,
+    sample_rate: f32,
+}
+
+impl SampleBuffer {
+    pub fn new(frames: Vec<f32>, sample_rate: f32) -> Self {
+        Self { frames: frames.into(), sample_rate }
+    }
+
+    /// Load a WAV file (integer or float PCM), downmixing to mono
+    pub fn load_wav(path: &Path) -> Result<Self> {
+        let wav_error = |source| AudioError::Sample { path: path.to_path_buf(), source };
+        let mut reader = hound::WavReader::open(path).map_err(wav_error)?;
+        let spec = reader.spec();
+        if spec.sample_rate == 0 {
+            return Err(wav_error(hound::Error::FormatError("sample rate of zero")));
+        }
+
+        let interleaved: Vec<f32> = match spec.sample_format {
+            hound::SampleFormat::Float => reader.samples::<f32>().collect::<std::result::Result<_, _>>(),
+            hound::SampleFormat::Int => {
+                let scale = 1.0 / (1u64 << (spec.bits_per_sample - 1)) as f32;
+                reader.samples::<i32>().map(|s| s.map(|s| s as f32 * scale)).collect()
+            }
+        }.map_err(wav_error)?;
+
+        let channels = spec.channels.max(1) as usize;
+        let frames = interleaved.chunks(channels)
+            .map(|frame| frame.iter().sum::<f32>() / channels as f32)
+            .collect();
+        Ok(Self::new(frames, spec.sample_rate as f32))
+    }
+
+    pub fn duration(&self) -> f32 {
+        self.frames.len() as f32 / self.sample_rate
+    }
+
+    /// Linear-interpolated value at a fractional frame position
+    fn frame_at(&self, position: f64) -> f32 {
+        let index = position as usize;
+        let a = self.frames.get(index).copied().unwrap_or(0.0);
+        let b = self.frames.get(index + 1).copied().unwrap_or(0.0);
+        a + (b - a) * position.fract() as f32
+    }
+}
+
+/// Moments in the simulation that can trigger a one-shot sample
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SampleCue {
+    Spawn,
+    BassDrop,
+    CrystalHarvest,
+    WarDeclared,  // Stinger when one species goes to war on another
+    TreatySigned, // Stinger when two species make peace
+}
+
+impl SampleCue {
+    /// Wavetable stand-in used when a species has no sample for the cue
+    fn synthesize(self, sample_rate: f32) -> SampleBuffer {
+        // (start Hz, end Hz, seconds, morph, decay rate)
+        let (start, end, seconds, morph, decay): (f32, f32, f32, f32, f32) = match self {
+            Self::Spawn => (660.0, 990.0, 0.35, 0.1, 9.0),
+            Self::BassDrop => (110.0, 41.0, 0.9, 0.75, 4.0),
+            Self::CrystalHarvest => (1760.0, 1760.0, 0.6, 0.0, 7.0),
+            Self::WarDeclared => (220.0, 82.0, 0.7, 0.9, 5.0),
+            Self::TreatySigned => (440.0, 880.0, 1.2, 0.0, 3.0),
+        };
+
+        let length = (seconds * sample_rate) as usize;
+        let mut phase = 0.0f32;
+        let frames = (0..length)
+            .map(|i| {
+                let t = i as f32 / sample_rate;
+                let progress = t / seconds;
+                let frequency = start * (end / start).powf(progress);
+                phase = (phase + frequency / sample_rate).fract();
+                let attack = (t / 0.005).min(1.0); // Avoid a click at the start
+                WavetableShape::morph(morph, phase) * attack * (-t * decay).exp() * 0.5
+            })
+            .collect();
+        SampleBuffer::new(frames, sample_rate)
+    }
+}
+
+/// Paths to a species' WAV files; any left out use the synthesized cue
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SampleBankPaths {
+    pub spawn: Option<PathBuf>,
+    pub bass_drop: Option<PathBuf>,
+    pub crystal_harvest: Option<PathBuf>,
+    pub war_declared: Option<PathBuf>,
+    pub treaty_signed: Option<PathBuf>,
+}
+
+impl SampleBankPaths {
+    pub fn load(&self) -> Result<SampleBank> {
+        let load = |path: &Option<PathBuf>| path.as_deref().map(SampleBuffer::load_wav).transpose();
+        Ok(SampleBank {
+            spawn: load(&self.spawn)?,
+            bass_drop: load(&self.bass_drop)?,
+            crystal_harvest: load(&self.crystal_harvest)?,
+            war_declared: load(&self.war_declared)?,
+            treaty_signed: load(&self.treaty_signed)?,
+        })
+    }
+}
+
+/// Loaded samples for one species
+#[derive(Debug, Clone, Default)]
+pub struct SampleBank {
+    pub spawn: Option<SampleBuffer>,
+    pub bass_drop: Option<SampleBuffer>,
+    pub crystal_harvest: Option<SampleBuffer>,
+    pub war_declared: Option<SampleBuffer>,
+    pub treaty_signed: Option<SampleBuffer>,
+}
+
+impl SampleBank {
+    pub fn get(&self, cue: SampleCue) -> Option<&SampleBuffer> {
+        match cue {
+            SampleCue::Spawn => self.spawn.as_ref(),
+            SampleCue::BassDrop => self.bass_drop.as_ref(),
+            SampleCue::CrystalHarvest => self.crystal_harvest.as_ref(),
+            SampleCue::WarDeclared => self.war_declared.as_ref(),
+            SampleCue::TreatySigned => self.treaty_signed.as_ref(),
+        }
+    }
+}
+
+struct SampleVoice {
+    buffer: SampleBuffer,
+    position: f64, // In source frames
+    step: f64,     // Source frames per output sample
+    gain: f32,
+    species: SpeciesType,
+    cue: SampleCue,
+    age: f32,      // Seconds since the voice started
+    id: VoiceId,
+    fade: f32,     // 1 while playing; falls to 0 once the voice is stolen
+    stolen: bool,
+}
+
+/// Polyphonic one-shot player mixed into the synthesis output
+pub struct SamplePlayer {
+    banks: HashMap<SpeciesType, SampleBank>,
+    fallbacks: HashMap<SampleCue, SampleBuffer>,
+    voices: Vec<SampleVoice>,
+    sample_rate: f32,
+}
+
+impl SamplePlayer {
+    pub fn new(sample_rate: f32) -> Self {
+        let fallbacks = [SampleCue::Spawn, SampleCue::BassDrop, SampleCue::CrystalHarvest, SampleCue::WarDeclared, SampleCue::TreatySigned]
+            .into_iter()
+            .map(|cue| (cue, cue.synthesize(sample_rate)))
+            .collect();
+
+        Self {
+            banks: HashMap::new(),
+            fallbacks,
+            voices: Vec::with_capacity(MAX_SAMPLE_VOICES),
+            sample_rate,
+        }
+    }
+
+    /// Follow an output device change: loaded banks are kept, fallbacks re-rendered
+    pub fn set_sample_rate(&mut self, sample_rate: f32) {
+        let banks = std::mem::take(&mut self.banks);
+        *self = Self::new(sample_rate);
+        self.banks = banks;
+    }
+
+    pub fn set_bank(&mut self, species: SpeciesType, bank: SampleBank) {
+        self.banks.insert(species, bank);
+    }
+
+    pub fn active_voices(&self) -> usize {
+        self.voices.len()
+    }
+
+    /// Seconds the cue lasts when played for `species`
+    pub fn cue_seconds(&self, species: &SpeciesType, cue: SampleCue) -> f32 {
+        let buffer = self.banks.get(species).and_then(|bank| bank.get(cue)).unwrap_or(&self.fallbacks[&cue]);
+        buffer.frames.len() as f32 / buffer.sample_rate
+    }
+
+    /// Start a one-shot for `species` as voice `id`; ignored if the same cue just started
+    pub fn trigger(&mut self, species: SpeciesType, cue: SampleCue, gain: f32, id: VoiceId) {
+        let just_started = self.voices.iter()
+            .any(|voice| voice.species == species && voice.cue == cue && voice.age < RETRIGGER_GUARD_SECONDS);
+        if just_started {
+            return;
+        }
+
+        let buffer = match self.banks.get(&species).and_then(|bank| bank.get(cue)) {
+            Some(buffer) => buffer.clone(),
+            None => self.fallbacks[&cue].clone(),
+        };
+
+        if self.voices.len() >= MAX_SAMPLE_VOICES {
+            self.voices.remove(0); // Voices are kept oldest first
+        }
+        self.voices.push(SampleVoice {
+            step: buffer.sample_rate as f64 / self.sample_rate as f64,
+            buffer,
+            position: 0.0,
+            gain: gain.clamp(0.0, 1.0),
+            species,
+            cue,
+            age: 0.0,
+            id,
+            fade: 1.0,
+            stolen: false,
+        });
+    }
+
+    /// Fade out a voice the voice manager gave to another sound
+    pub fn steal(&mut self, id: VoiceId) {
+        for voice in self.voices.iter_mut().filter(|voice| voice.id == id) {
+            voice.stolen = true;
+        }
+    }
+
+    pub fn next_sample(&mut self) -> f32 {
+        let dt = 1.0 / self.sample_rate;
+        let mut sample = 0.0;
+        for voice in &mut self.voices {
+            if voice.stolen {
+                voice.fade = (voice.fade - dt / STOLEN_FADE_SECONDS).max(0.0);
+            }
+            sample += voice.buffer.frame_at(voice.position) * voice.gain * voice.fade;
+            voice.position += voice.step;
+            voice.age += dt;
+        }
+        self.voices.retain(|voice| (voice.position as usize) < voice.buffer.frames.len() && voice.fade > 0.0);
+        sample
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wavetable_lookup_interpolates_and_wraps() {
+        let table = Wavetable::from_harmonics(&[1.0]);
+        assert!(table.lookup(0.0).abs() < 1e-6);
+        assert!((table.lookup(0.25) - 1.0).abs() < 1e-3);
+        assert!((table.lookup(1.25) - table.lookup(0.25)).abs() < 1e-6);
+        assert!((table.lookup(-0.75) - table.lookup(0.25)).abs() < 1e-4);
+        for shape in WavetableShape::ALL {
+            assert!((0..100).all(|i| shape.table().lookup(i as f32 / 100.0).abs() <= 1.0));
+        }
+    }
+
+    #[test]
+    fn test_load_wav_downmixes_to_mono() {
+        let path = std::env::temp_dir().join(format!("aetherium_sampler_{}.wav", std::process::id()));
+        let spec = hound::WavSpec { channels: 2, sample_rate: 22_050, bits_per_sample: 16, sample_format: hound::SampleFormat::Int };
+        let mut writer = hound::WavWriter::create(&path, spec).unwrap();
+        for (left, right) in [(i16::MAX, 0), (-16384, -16384)] {
+            writer.write_sample(left).unwrap();
+            writer.write_sample(right).unwrap();
+        }
+        writer.finalize().unwrap();
+
+        let buffer = SampleBuffer::load_wav(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+        assert_eq!(buffer.sample_rate, 22_050.0);
+        assert_eq!(buffer.frames.len(), 2);
+        assert!((buffer.frames[0] - 0.5).abs() < 1e-3);
+        assert!((buffer.frames[1] + 0.5).abs() < 1e-3);
+
+        let missing = SampleBankPaths { spawn: Some(path), ..SampleBankPaths::default() };
+        assert!(matches!(missing.load(), Err(AudioError::Sample { .. })));
+    }
+
+    #[test]
+    fn test_load_wav_rejects_a_zero_sample_rate() {
+        let path = std::env::temp_dir().join(format!("aetherium_sampler_rate_{}.wav", std::process::id()));
+        let spec = hound::WavSpec { channels: 1, sample_rate: 22_050, bits_per_sample: 16, sample_format: hound::SampleFormat::Int };
+        let mut writer = hound::WavWriter::create(&path, spec).unwrap();
+        writer.write_sample(i16::MAX).unwrap();
+        writer.finalize().unwrap();
+        // Zero the fmt chunk's sample rate field, as a broken encoder might
+        let mut bytes = std::fs::read(&path).unwrap();
+        bytes[24..28].fill(0);
+        std::fs::write(&path, bytes).unwrap();
+
+        let loaded = SampleBuffer::load_wav(&path);
+        std::fs::remove_file(&path).ok();
+        assert!(matches!(loaded, Err(AudioError::Sample { .. })));
+    }
+
+    #[test]
+    fn test_sample_player_resamples_and_limits_voices() {
+        let mut player = SamplePlayer::new(48_000.0);
+        player.set_bank(SpeciesType::DiscoLlama, SampleBank {
+            spawn: Some(SampleBuffer::new(vec![1.0; 100], 24_000.0)),
+            ..SampleBank::default()
+        });
+
+        // Half-rate source plays for twice as many output samples
+        player.trigger(SpeciesType::DiscoLlama, SampleCue::Spawn, 1.0, VoiceId::default());
+        let played = std::iter::from_fn(|| (player.active_voices() > 0).then(|| player.next_sample())).count();
+        assert_eq!(played, 200);
+
+        // Re-triggering the same cue at once is debounced
+        player.trigger(SpeciesType::DiscoLlama, SampleCue::Spawn, 1.0, VoiceId::default());
+        player.trigger(SpeciesType::DiscoLlama, SampleCue::Spawn, 1.0, VoiceId::default());
+        assert_eq!(player.active_voices(), 1);
+
+        // Long overlapping one-shots steal the oldest voice at the cap
+        player.set_bank(SpeciesType::QuantumSheep, SampleBank {
+            crystal_harvest: Some(SampleBuffer::new(vec![0.1; 96_000], 48_000.0)),
+            ..SampleBank::default()
+        });
+        for _ in 0..MAX_SAMPLE_VOICES * 2 {
+            player.trigger(SpeciesType::QuantumSheep, SampleCue::CrystalHarvest, 0.5, VoiceId::default());
+            for _ in 0..(RETRIGGER_GUARD_SECONDS * 48_000.0) as usize + 1 {
+                player.next_sample();
+            }
+        }
+        assert_eq!(player.active_voices(), MAX_SAMPLE_VOICES);
+    }
+}
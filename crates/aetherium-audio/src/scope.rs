@@ -0,0 +1,134 @@
+// === SCOPE TAP ===
+// A window onto the synthesizer's final output for the sound visualization:
+// the synthesis thread keeps the most recent mono samples, and the render
+// thread turns a copy of them into a waveform and a log-spaced spectrum. The
+// tap sits where samples are generated, so it runs ahead of what is heard by
+// the output buffer latency.
+
+use std::f32::consts::TAU;
+
+/// Samples in the scope window; a power of two for the FFT
+pub const SCOPE_SAMPLES: usize = 1024;
+/// Log-spaced spectrum bands from LOWEST_BAND_HZ up to HIGHEST_BAND_HZ
+pub const SPECTRUM_BANDS: usize = 32;
+const LOWEST_BAND_HZ: f32 = 40.0;
+const HIGHEST_BAND_HZ: f32 = 16_000.0;
+/// Levels this far below full scale read as silence
+const SPECTRUM_FLOOR_DB: f32 = -72.0;
+
+/// Most recent output samples, written by the synthesis thread
+pub struct ScopeTap {
+    samples: [f32; SCOPE_SAMPLES],
+    cursor: usize, // Next slot to write, i.e. the oldest sample
+}
+
+impl Default for ScopeTap {
+    fn default() -> Self {
+        Self { samples: [0.0; SCOPE_SAMPLES], cursor: 0 }
+    }
+}
+
+impl ScopeTap {
+    pub fn push(&mut self, frame: [f32; 2]) {
+        self.samples[self.cursor] = (frame[0] + frame[1]) * 0.5;
+        self.cursor = (self.cursor + 1) % SCOPE_SAMPLES;
+    }
+
+    /// The window, oldest sample first
+    pub fn window(&self) -> [f32; SCOPE_SAMPLES] {
+        let mut window = [0.0; SCOPE_SAMPLES];
+        let (newer, older) = self.samples.split_at(self.cursor);
+        window[..older.len()].copy_from_slice(older);
+        window[older.len()..].copy_from_slice(newer);
+        window
+    }
+}
+
+/// In-place radix-2 FFT; both slices must have the same power-of-two length
+pub fn fft(re: &mut [f32], im: &mut [f32]) {
+    let n = re.len();
+    debug_assert!(n.is_power_of_two() && im.len() == n);
+
+    // Bit-reversal permutation
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            re.swap(i, j);
+            im.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let angle = -TAU / len as f32;
+        for start in (0..n).step_by(len) {
+            for k in 0..len / 2 {
+                let (sin, cos) = (angle * k as f32).sin_cos();
+                let (a, b) = (start + k, start + k + len / 2);
+                let (tr, ti) = (re[b] * cos - im[b] * sin, re[b] * sin + im[b] * cos);
+                re[b] = re[a] - tr;
+                im[b] = im[a] - ti;
+                re[a] += tr;
+                im[a] += ti;
+            }
+        }
+        len <<= 1;
+    }
+}
+
+/// Hann-windowed spectrum of `window` in SPECTRUM_BANDS log-spaced bands, each 0 (silent) to 1 (full scale)
+pub fn spectrum(window: &[f32; SCOPE_SAMPLES], sample_rate: f32) -> [f32; SPECTRUM_BANDS] {
+    let mut re: Vec<f32> = window.iter().enumerate()
+        .map(|(i, sample)| sample * (0.5 - 0.5 * (TAU * i as f32 / (SCOPE_SAMPLES - 1) as f32).cos()))
+        .collect();
+    let mut im = vec![0.0; SCOPE_SAMPLES];
+    fft(&mut re, &mut im);
+
+    let bin_hz = sample_rate / SCOPE_SAMPLES as f32;
+    let nyquist_bin = SCOPE_SAMPLES / 2;
+    let ratio = HIGHEST_BAND_HZ / LOWEST_BAND_HZ;
+    let mut bands = [0.0; SPECTRUM_BANDS];
+    for (band, level) in bands.iter_mut().enumerate() {
+        let low = LOWEST_BAND_HZ * ratio.powf(band as f32 / SPECTRUM_BANDS as f32);
+        let high = LOWEST_BAND_HZ * ratio.powf((band + 1) as f32 / SPECTRUM_BANDS as f32);
+        let first = ((low / bin_hz) as usize).clamp(1, nyquist_bin);
+        let last = ((high / bin_hz) as usize).clamp(first + 1, nyquist_bin + 1);
+        let peak = (first..last.min(nyquist_bin)).map(|bin| (re[bin] * re[bin] + im[bin] * im[bin]).sqrt()).fold(0.0, f32::max);
+        // A full-scale sine peaks at a quarter of the window length under the Hann window
+        let db = 20.0 * (peak / (SCOPE_SAMPLES as f32 * 0.25)).max(1e-9).log10();
+        *level = (1.0 - db / SPECTRUM_FLOOR_DB).clamp(0.0, 1.0);
+    }
+    bands
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sine_lands_in_its_band() {
+        let sample_rate = 48_000.0;
+        let mut tap = ScopeTap::default();
+        for i in 0..SCOPE_SAMPLES + 100 {
+            let sample = (TAU * 1000.0 * i as f32 / sample_rate).sin() * 0.5;
+            tap.push([sample, sample]);
+        }
+        let window = tap.window();
+        assert!((window[SCOPE_SAMPLES - 1] - (TAU * 1000.0 * (SCOPE_SAMPLES + 99) as f32 / sample_rate).sin() * 0.5).abs() < 1e-4, "newest sample last");
+
+        let bands = spectrum(&window, sample_rate);
+        let loudest = (0..SPECTRUM_BANDS).max_by(|&a, &b| bands[a].total_cmp(&bands[b])).unwrap();
+        let ratio = HIGHEST_BAND_HZ / LOWEST_BAND_HZ;
+        let low = LOWEST_BAND_HZ * ratio.powf(loudest as f32 / SPECTRUM_BANDS as f32);
+        let high = LOWEST_BAND_HZ * ratio.powf((loudest + 1) as f32 / SPECTRUM_BANDS as f32);
+        assert!(low <= 1050.0 && high >= 950.0, "1 kHz peaked in {low:.0}-{high:.0} Hz");
+        assert!(bands[loudest] > 0.8 && bands[0] < 0.5);
+        assert_eq!(spectrum(&[0.0; SCOPE_SAMPLES], sample_rate), [0.0; SPECTRUM_BANDS]);
+    }
+}
@@ -0,0 +1,345 @@
+// === SPECIES SONIC SIGNATURES ===
+// Every species carries a small synth voice described entirely by data:
+// waveform, register, detune, envelope and effect sends. The voice swells in
+// while its species is alive and fades out when the last of them is gone.
+// The species' shared mood colors the voice: agitation quickens the vibrato
+// and drives it harder, unhappiness pulls its oscillators sour.
+
+use std::collections::HashMap;
+use glam::Vec2;
+use serde::{Deserialize, Serialize};
+
+use aetherium_sim::entities::{Affect, SpeciesType};
+use aetherium_sim::mathematics::beat_engine::BeatState;
+use aetherium_sim::params::{self, Param};
+use super::{AudioWaveform, CompatLlamaRenderData, Scale};
+
+/// Register reference: A in octave 4 is concert pitch
+const A4_HZ: f32 = 440.0;
+/// Species members needed for a voice to reach full level
+const FULL_PRESENCE_COUNT: f32 = 4.0;
+/// Shared echo line fed by the echo sends
+const ECHO_SECONDS: f32 = 0.375;
+const ECHO_FEEDBACK: f32 = 0.35;
+/// Seconds a voice takes to follow most of a change in its species' mood
+const MOOD_GLIDE_SECONDS: f32 = 1.0;
+
+/// How a voice fades in when its species appears and out when it disappears
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Envelope {
+    pub attack: f32,  // Seconds to full level
+    pub release: f32, // Seconds back to silence
+}
+
+/// Amount of the voice sent through each effect, 0..1
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct EffectSends {
+    pub drive: f32, // Soft saturation
+    pub echo: f32,  // Feedback delay shared by all species
+}
+
+/// Sound design for one species' voice
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SpeciesSonicSignature {
+    pub waveform: AudioWaveform,
+    pub base_octave: i32,    // The voice sounds the scale root (A) in this octave
+    pub detune_cents: f32,   // Spread between the voice's two oscillators
+    pub vibrato_cents: f32,
+    pub vibrato_hz: f32,
+    pub envelope: Envelope,
+    pub sends: EffectSends,
+    pub level: f32,
+}
+
+impl SpeciesSonicSignature {
+    /// The built-in signature of a species, a starting point for custom designs
+    pub fn for_species(species: SpeciesType) -> Self {
+        match species {
+            SpeciesType::DiscoLlama => Self {
+                waveform: AudioWaveform::Sawtooth,
+                base_octave: 4,
+                detune_cents: 14.0,
+                vibrato_cents: 8.0,
+                vibrato_hz: 5.0,
+                envelope: Envelope { attack: 0.3, release: 1.5 },
+                sends: EffectSends { drive: 0.2, echo: 0.35 },
+                level: 0.05,
+            },
+            SpeciesType::QuantumSheep => Self {
+                waveform: AudioWaveform::ConsciousnessFractal,
+                base_octave: 5,
+                detune_cents: 6.0,
+                vibrato_cents: 20.0,
+                vibrato_hz: 0.7,
+                envelope: Envelope { attack: 1.5, release: 3.0 },
+                sends: EffectSends { drive: 0.0, echo: 0.6 },
+                level: 0.04,
+            },
+            SpeciesType::HypnoCamel => Self {
+                waveform: AudioWaveform::Square,
+                base_octave: 1,
+                detune_cents: 4.0,
+                vibrato_cents: 0.0,
+                vibrato_hz: 0.0,
+                envelope: Envelope { attack: 0.05, release: 0.8 },
+                sends: EffectSends { drive: 0.5, echo: 0.1 },
+                level: 0.07,
+            },
+            SpeciesType::BassDropVicuna => Self {
+                waveform: AudioWaveform::Sawtooth,
+                base_octave: 0,
+                detune_cents: 10.0,
+                vibrato_cents: 0.0,
+                vibrato_hz: 0.0,
+                envelope: Envelope { attack: 0.02, release: 0.6 },
+                sends: EffectSends { drive: 0.7, echo: 0.05 },
+                level: 0.06,
+            },
+        }
+    }
+
+    /// Copy with every parameter forced into a range the synth can play safely
+    pub fn sanitized(&self) -> Self {
+        let finite = |value: f32, param: Param| {
+            if value.is_finite() { param.clamp(value) } else { param.default }
+        };
+        Self {
+            waveform: self.waveform.clone(),
+            base_octave: params::BASE_OCTAVE.clamp(self.base_octave as f32) as i32,
+            detune_cents: finite(self.detune_cents, params::DETUNE_CENTS),
+            vibrato_cents: finite(self.vibrato_cents, params::VIBRATO_CENTS),
+            vibrato_hz: finite(self.vibrato_hz, params::VIBRATO_HZ),
+            envelope: Envelope {
+                attack: finite(self.envelope.attack, params::ENVELOPE_SECONDS),
+                release: finite(self.envelope.release, params::ENVELOPE_SECONDS),
+            },
+            sends: EffectSends {
+                drive: finite(self.sends.drive, params::EFFECT_SEND),
+                echo: finite(self.sends.echo, params::EFFECT_SEND),
+            },
+            level: finite(self.level, params::VOICE_LEVEL),
+        }
+    }
+
+    /// Unquantized pitch of the voice in Hz
+    pub fn base_frequency(&self) -> f32 {
+        A4_HZ * 2f32.powi(self.base_octave - 4)
+    }
+}
+
+struct SpeciesVoice {
+    signature: SpeciesSonicSignature,
+    phases: [f32; 2], // In cycles, one per detuned oscillator
+    vibrato_phase: f32,
+    gain: f32,
+    mood: Affect,  // Average mood of the species' living members
+    heard: Affect, // Mood the voice is currently sounding, gliding towards `mood`
+}
+
+impl SpeciesVoice {
+    fn new(signature: SpeciesSonicSignature) -> Self {
+        Self { signature, phases: [0.0, 0.25], vibrato_phase: 0.0, gain: 0.0, mood: Affect::default(), heard: Affect::default() }
+    }
+
+    /// Dry and echo-send contributions of the next sample, the envelope running `speed` times as fast
+    fn next_sample(&mut self, count: u32, scale: Scale, sample_rate: f32, speed: f32) -> (f32, f32) {
+        let glide = 1.0 - (-1.0 / (MOOD_GLIDE_SECONDS * sample_rate)).exp();
+        self.heard.valence += (self.mood.valence - self.heard.valence) * glide;
+        self.heard.arousal += (self.mood.arousal - self.heard.arousal) * glide;
+        let (arousal, sorrow) = (self.heard.arousal, (-self.heard.valence).max(0.0));
+
+        let signature = &self.signature;
+        let presence = (count as f32 / FULL_PRESENCE_COUNT).min(1.0);
+        let target = signature.level * presence;
+        let seconds = if target > self.gain { signature.envelope.attack } else { signature.envelope.release } / speed;
+        self.gain += (target - self.gain) * (1.0 - (-1.0 / (seconds * sample_rate)).exp());
+        if self.gain < 1e-5 && target == 0.0 {
+            self.gain = 0.0;
+            return (0.0, 0.0);
+        }
+
+        let vibrato_hz = signature.vibrato_hz * (0.75 + arousal * 0.5);
+        self.vibrato_phase = (self.vibrato_phase + vibrato_hz / sample_rate).fract();
+        let vibrato = (self.vibrato_phase * std::f32::consts::TAU).sin() * signature.vibrato_cents * (0.5 + arousal);
+        let detune_cents = signature.detune_cents * (1.0 + sorrow * 2.0);
+        let frequency = scale.quantize(signature.base_frequency());
+
+        let mut raw = 0.0;
+        for (phase, spread) in self.phases.iter_mut().zip([-0.5, 0.5]) {
+            let cents = vibrato + detune_cents * spread;
+            *phase = (*phase + frequency * 2f32.powf(cents / 1200.0) / sample_rate).fract();
+            raw += signature.waveform.sample(*phase) * 0.5;
+        }
+
+        let drive = 1.0 + (signature.sends.drive + arousal * 0.3).min(1.0) * 4.0;
+        let shaped = (raw * drive).tanh() / drive.tanh();
+        let voiced = shaped * self.gain;
+        (voiced, voiced * signature.sends.echo)
+    }
+}
+
+/// Turns the living species into their sonic signatures on top of the synth mix
+pub struct ConsciousnessAudioMapper {
+    voices: HashMap<SpeciesType, SpeciesVoice>,
+    scale: Scale,
+    echo: Vec<f32>,
+    echo_position: usize,
+    sample_rate: f32,
+    speed: f32, // The speed control; voice envelopes run this much faster
+}
+
+impl ConsciousnessAudioMapper {
+    pub fn new(sample_rate: f32) -> Self {
+        let voices = SpeciesType::ALL
+            .into_iter()
+            .map(|species| {
+                let signature = SpeciesSonicSignature::for_species(species);
+                (species, SpeciesVoice::new(signature))
+            })
+            .collect();
+
+        Self {
+            voices,
+            scale: Scale::default(),
+            echo: vec![0.0; (ECHO_SECONDS * sample_rate) as usize + 1],
+            echo_position: 0,
+            sample_rate,
+            speed: 1.0,
+        }
+    }
+
+    /// Rebuild the rate-dependent state, keeping every signature
+    pub fn set_sample_rate(&mut self, sample_rate: f32) {
+        let signatures: Vec<_> = self.voices.drain()
+            .map(|(species, voice)| (species, voice.signature))
+            .collect();
+        *self = Self::new(sample_rate);
+        for (species, signature) in signatures {
+            self.set_signature(species, signature);
+        }
+    }
+
+    pub fn set_signature(&mut self, species: SpeciesType, signature: SpeciesSonicSignature) {
+        let voice = self.voices.entry(species).or_insert_with(|| SpeciesVoice::new(signature.sanitized()));
+        voice.signature = signature.sanitized();
+    }
+
+    pub fn signature(&self, species: &SpeciesType) -> Option<&SpeciesSonicSignature> {
+        self.voices.get(species).map(|voice| &voice.signature)
+    }
+
+    pub fn set_scale(&mut self, scale: Scale) {
+        self.scale = scale;
+    }
+
+    /// Follow the speed control: voices swell in and out faster or slower, at the same pitch
+    pub fn set_speed(&mut self, speed: f32) {
+        self.speed = speed.max(0.01);
+    }
+
+    /// Take each species' average mood; a species with nobody left keeps its last one as it fades
+    pub fn update(&mut self, _time: f64, _beat: &BeatState, llamas: &[CompatLlamaRenderData]) {
+        for (species, voice) in self.voices.iter_mut() {
+            let members: Vec<_> = llamas.iter().filter(|llama| llama.species == *species).collect();
+            if members.is_empty() {
+                continue;
+            }
+            let count = members.len() as f32;
+            voice.mood = Affect {
+                valence: (members.iter().map(|llama| llama.valence).sum::<f32>() / count).clamp(-1.0, 1.0),
+                arousal: (members.iter().map(|llama| llama.arousal).sum::<f32>() / count).clamp(0.0, 1.0),
+            };
+        }
+    }
+
+    /// Mix every species voice, sized by its population, into `sample`
+    pub fn apply_species_modulation(&mut self, sample: f32, _time: f64, _positions: &[Vec2], counts: &HashMap<SpeciesType, u32>) -> f32 {
+        let (stems, echoed) = self.species_stems(counts);
+        sample + stems.iter().sum::<f32>() + echoed
+    }
+
+    /// Each species voice on its own, in `SpeciesType::ALL` order, plus the shared echo return
+    pub fn species_stems(&mut self, counts: &HashMap<SpeciesType, u32>) -> ([f32; SpeciesType::ALL.len()], f32) {
+        let mut stems = [0.0; SpeciesType::ALL.len()];
+        let mut echo_send = 0.0;
+        for (stem, species) in stems.iter_mut().zip(&SpeciesType::ALL) {
+            let Some(voice) = self.voices.get_mut(species) else { continue };
+            let count = counts.get(species).copied().unwrap_or(0);
+            let (voiced, send) = voice.next_sample(count, self.scale, self.sample_rate, self.speed);
+            *stem = voiced;
+            echo_send += send;
+        }
+
+        let echoed = self.echo[self.echo_position];
+        self.echo[self.echo_position] = echo_send + echoed * ECHO_FEEDBACK;
+        self.echo_position = (self.echo_position + 1) % self.echo.len();
+
+        (stems, echoed)
+    }
+
+    /// Pitch in Hz of the loudest species voice as it is sounding now, or the scale's A4 while every voice is silent
+    pub fn get_fundamental_frequency(&self) -> f32 {
+        let loudest = self.voices.values().filter(|voice| voice.gain > 0.0).max_by(|a, b| a.gain.total_cmp(&b.gain));
+        self.scale.quantize(loudest.map_or(A4_HZ, |voice| voice.signature.base_frequency()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_voice_follows_its_species_population() {
+        let mut mapper = ConsciousnessAudioMapper::new(48_000.0);
+        let silent = HashMap::new();
+        assert!((0..4800).all(|_| mapper.apply_species_modulation(0.0, 0.0, &[], &silent) == 0.0));
+        assert_eq!(mapper.get_fundamental_frequency(), Scale::default().quantize(A4_HZ));
+
+        // Every species has a voice of its own; camels and vicunas are no longer one
+        assert!(SpeciesType::ALL.iter().all(|species| mapper.signature(species).is_some()));
+        assert_ne!(mapper.signature(&SpeciesType::HypnoCamel), mapper.signature(&SpeciesType::BassDropVicuna));
+        let camels = HashMap::from([(SpeciesType::HypnoCamel, 4)]);
+        let peak = (0..48_000)
+            .map(|_| mapper.apply_species_modulation(0.0, 0.0, &[], &camels))
+            .fold(0.0f32, |peak, s| { assert!(s.is_finite()); peak.max(s.abs()) });
+        assert!(peak > 0.02 && peak < 0.5, "peak {}", peak);
+
+        // The fundamental follows the voice that is sounding
+        let camel_pitch = Scale::default().quantize(mapper.signature(&SpeciesType::HypnoCamel).unwrap().base_frequency());
+        assert_eq!(mapper.get_fundamental_frequency(), camel_pitch);
+
+        // Release and echo tail die away once the species is gone
+        let tail: Vec<f32> = (0..192_000).map(|_| mapper.apply_species_modulation(0.0, 0.0, &[], &silent)).collect();
+        assert!(tail[tail.len() - 4800..].iter().all(|s| s.abs() < 1e-3));
+    }
+
+    #[test]
+    fn test_speed_stretches_envelopes_not_pitch() {
+        let camels = HashMap::from([(SpeciesType::HypnoCamel, 4)]);
+        let (mut normal, mut doubled) = (ConsciousnessAudioMapper::new(48_000.0), ConsciousnessAudioMapper::new(48_000.0));
+        doubled.set_speed(2.0);
+        (0..2400).for_each(|_| { normal.species_stems(&camels); });
+        (0..1200).for_each(|_| { doubled.species_stems(&camels); });
+        let gain = |mapper: &ConsciousnessAudioMapper| mapper.voices[&SpeciesType::HypnoCamel].gain;
+        assert!((gain(&normal) - gain(&doubled)).abs() < 1e-3, "half the time at double speed reaches the same level");
+        assert_eq!(normal.get_fundamental_frequency(), doubled.get_fundamental_frequency());
+    }
+
+    #[test]
+    fn test_custom_signature_is_sanitized() {
+        let mut mapper = ConsciousnessAudioMapper::new(48_000.0);
+        let wild = SpeciesSonicSignature {
+            base_octave: 40,
+            level: f32::NAN,
+            envelope: Envelope { attack: 0.0, release: -1.0 },
+            ..SpeciesSonicSignature::for_species(SpeciesType::DiscoLlama)
+        };
+        mapper.set_signature(SpeciesType::DiscoLlama, wild);
+        mapper.set_sample_rate(44_100.0);
+
+        let signature = mapper.signature(&SpeciesType::DiscoLlama).unwrap();
+        assert_eq!(signature.base_octave, 7);
+        assert_eq!(signature.level, 0.0);
+        assert!(signature.envelope.attack > 0.0 && signature.envelope.release > 0.0);
+    }
+}
@@ -0,0 +1,178 @@
+// === SURROUND OUTPUT ===
+// Multichannel rigs can give every species its own speakers. In a surround
+// layout each species' voice is rendered as a separate stem and sent to the
+// channels its route names, while the rest of the mix (mood music, ambience,
+// hive pad and cues) stays on the front pair. Devices with fewer channels than
+// the layout get an equal-power fold-down, so nothing is ever lost.
+
+use aetherium_sim::entities::SpeciesType;
+
+/// Most output channels a frame can carry
+pub const MAX_OUTPUT_CHANNELS: usize = 8;
+
+/// One frame of output, laid out by the active `ChannelLayout`; stereo uses the first two channels
+pub type OutputFrame = [f32; MAX_OUTPUT_CHANNELS];
+
+/// Speaker arrangement the output is rendered for, channels in WAVE order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ChannelLayout {
+    #[default]
+    Stereo,     // Front left, front right
+    Quad,       // 4.0: front left, front right, rear left, rear right
+    Surround51, // 5.1: front left, front right, center, LFE, rear left, rear right
+}
+
+impl ChannelLayout {
+    pub fn channel_count(self) -> usize {
+        match self {
+            Self::Stereo => 2,
+            Self::Quad => 4,
+            Self::Surround51 => 6,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Stereo => "STEREO",
+            Self::Quad => "4.0",
+            Self::Surround51 => "5.1",
+        }
+    }
+
+    /// Left and right weights of `channel` when folding down to stereo
+    fn stereo_fold(self, channel: usize) -> (f32, f32) {
+        const SIDE: (f32, f32) = (1.0, 0.0);
+        const CENTER: (f32, f32) = (std::f32::consts::FRAC_1_SQRT_2, std::f32::consts::FRAC_1_SQRT_2);
+        let side = |left: bool| if left { SIDE } else { (SIDE.1, SIDE.0) };
+        match (self, channel) {
+            (Self::Surround51, 2 | 3) => CENTER,
+            (Self::Surround51, 4) => side(true),
+            (Self::Surround51, 5) => side(false),
+            (_, channel) => side(channel % 2 == 0),
+        }
+    }
+}
+
+/// Which channels each species' voice plays from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SurroundConfig {
+    pub layout: ChannelLayout,
+    routes: [u8; SpeciesType::ALL.len()], // Channel bitmask per species, in `SpeciesType::ALL` order
+}
+
+impl Default for SurroundConfig {
+    fn default() -> Self {
+        Self::for_layout(ChannelLayout::Stereo)
+    }
+}
+
+impl SurroundConfig {
+    /// Disco llamas up front, quantum sheep behind, hypno camels all around the
+    /// room and the bass-dropping vicunas everywhere (4.0) or in the center and subwoofer (5.1)
+    pub fn for_layout(layout: ChannelLayout) -> Self {
+        let routes = match layout {
+            ChannelLayout::Stereo => [0b11, 0b11, 0b11, 0b11],
+            ChannelLayout::Quad => [0b0011, 0b1100, 0b1111, 0b1111],
+            ChannelLayout::Surround51 => [0b00_0011, 0b11_0000, 0b11_0011, 0b00_1100],
+        };
+        Self { layout, routes }
+    }
+
+    /// Send `species` to `channels`; channels the layout does not have are ignored
+    pub fn with_species(mut self, species: SpeciesType, channels: &[usize]) -> Self {
+        let count = self.layout.channel_count();
+        self.routes[species.to_index()] = channels.iter()
+            .filter(|&&channel| channel < count)
+            .fold(0, |mask, &channel| mask | 1 << channel);
+        self
+    }
+
+    /// Channels `species` plays from, lowest first
+    pub fn channels(&self, species: &SpeciesType) -> Vec<usize> {
+        let mask = self.routes[species.to_index()];
+        (0..self.layout.channel_count()).filter(|&channel| mask & 1 << channel != 0).collect()
+    }
+
+    pub fn is_surround(&self) -> bool {
+        self.layout != ChannelLayout::Stereo
+    }
+
+    /// Add one sample of each species' stem to its channels, at equal power across them
+    pub fn route_stems(&self, stems: [f32; SpeciesType::ALL.len()], frame: &mut OutputFrame) {
+        for (&mask, stem) in self.routes.iter().zip(stems) {
+            let count = mask.count_ones();
+            if count == 0 {
+                continue;
+            }
+            let share = stem / (count as f32).sqrt();
+            for (channel, sample) in frame.iter_mut().enumerate().take(self.layout.channel_count()) {
+                if mask & 1 << channel != 0 {
+                    *sample += share;
+                }
+            }
+        }
+    }
+
+    /// The frame folded down to a stereo pair
+    pub fn fold_to_stereo(&self, frame: &OutputFrame) -> [f32; 2] {
+        frame.iter().take(self.layout.channel_count()).enumerate().fold([0.0; 2], |[left, right], (channel, &sample)| {
+            let (to_left, to_right) = self.layout.stereo_fold(channel);
+            [left + sample * to_left, right + sample * to_right]
+        })
+    }
+
+    /// Sample for `channel` of a device with `device_channels` channels
+    pub fn device_sample(&self, frame: &OutputFrame, device_channels: usize, channel: usize) -> f32 {
+        let layout_channels = self.layout.channel_count();
+        if device_channels == 1 {
+            let [left, right] = self.fold_to_stereo(frame);
+            return (left + right) * 0.5;
+        }
+        if device_channels < layout_channels {
+            // Too few speakers for the layout: everything folds onto the front pair
+            return match channel {
+                0 | 1 => self.fold_to_stereo(frame)[channel],
+                _ => 0.0,
+            };
+        }
+        match channel {
+            channel if channel < layout_channels => frame[channel],
+            // Extra channels beyond stereo get the center, as they always have
+            _ if !self.is_surround() => (frame[0] + frame[1]) * 0.5,
+            _ => 0.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stems_follow_their_routes_and_fold_down() {
+        let config = SurroundConfig::for_layout(ChannelLayout::Surround51)
+            .with_species(SpeciesType::QuantumSheep, &[4, 5, 9]);
+        assert_eq!(config.channels(&SpeciesType::QuantumSheep), vec![4, 5]);
+        assert_eq!(config.channels(&SpeciesType::BassDropVicuna), vec![2, 3]);
+        assert_eq!(config.channels(&SpeciesType::HypnoCamel), vec![0, 1, 4, 5], "camels keep their own route");
+
+        let mut frame: OutputFrame = [0.0; MAX_OUTPUT_CHANNELS];
+        frame[0] = 0.1; // The bed on the front left
+        config.route_stems([0.0, 0.4, 0.0, 0.0], &mut frame);
+        let share = 0.4 / 2f32.sqrt();
+        assert_eq!(&frame[..6], &[0.1, 0.0, 0.0, 0.0, share, share]);
+
+        // A 6-channel device plays the layout as is; a stereo one gets the fold-down
+        assert_eq!(config.device_sample(&frame, 6, 4), share);
+        assert_eq!(config.device_sample(&frame, 8, 7), 0.0);
+        let [left, right] = config.fold_to_stereo(&frame);
+        assert!((left - (0.1 + share)).abs() < 1e-6 && (right - share).abs() < 1e-6);
+        assert_eq!(config.device_sample(&frame, 2, 1), right);
+
+        // Stereo keeps the old behavior: extra device channels carry the center
+        let stereo = SurroundConfig::default();
+        let pair = [0.2, 0.4, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0];
+        assert_eq!(stereo.device_sample(&pair, 4, 3), (0.2 + 0.4) * 0.5);
+        assert_eq!(stereo.device_sample(&pair, 1, 0), (0.2 + 0.4) * 0.5);
+    }
+}
@@ -3,14 +3,66 @@
 // Transforms mathematical consciousness into raw audio chaos
 
 use std::collections::HashMap;
+use std::f32::consts::TAU;
 use primes::{PrimeSet, Sieve};
+use serde::{Deserialize, Serialize};
 
-use super::CompatLlamaSpecies;
-use crate::mathematics::BeatState;
+use aetherium_sim::entities::SpeciesType;
+use super::harmony::{HarmonyEngine, Scale};
+use super::sampler::WavetableBank;
+use aetherium_sim::mathematics::BeatState;
 use super::AudioEnvironment;
+use super::crossfade::EnvironmentBlend;
+use super::voices::{SoundEvent, VoiceId, VoiceManager, VoiceStats};
+
+/// Seconds a death echo's swell takes to rise
+const ECHO_SWELL_RISE: f32 = 0.6;
+/// Time constant, in seconds, of the swell dying away
+const ECHO_SWELL_DECAY: f32 = 1.5;
+/// Seconds a spawn burst and a crystal chime hold their voices
+const SPAWN_BURST_SECONDS: f32 = 0.3;
+const CRYSTAL_CHIME_SECONDS: f32 = 0.5;
+/// Time constant, in seconds, of a swell fading out once its voice is stolen
+const STOLEN_FADE_SECONDS: f32 = 0.01;
+/// Partials of a spawn burst and a crystal chime, as (frequency ratio, level)
+const SPAWN_BURST_PARTIALS: [(f32, f32); 3] = [(1.0, 1.0), (2.0, 0.5), (3.0, 0.33)];
+const CRYSTAL_CHIME_PARTIALS: [(f32, f32); 3] = [(1.0, 1.0), (2.76, 0.4), (5.4, 0.2)];
+
+/// A short decaying tone held by an event voice: a spawn burst or a crystal chime
+struct EventTone {
+    voice: VoiceId,
+    frequency: f32,
+    partials: [(f32, f32); 3],
+    level: f32,
+    decay: f32,   // Time constant, in seconds, of the tone dying away
+    elapsed: f32, // Seconds since it started on the sample clock, which sets its pitch
+    age: f32,     // Seconds since it started on the tempo clock, which sets its envelope
+    fade: f32,    // 1 while its voice is held; falls to 0 once the voice is stolen or ends
+}
+
+impl EventTone {
+    fn new(voice: VoiceId, frequency: f32, partials: [(f32, f32); 3], level: f32, decay: f32) -> Self {
+        Self { voice, frequency, partials, level, decay, elapsed: 0.0, age: 0.0, fade: 1.0 }
+    }
+
+    /// The next sample, with the envelope running `speed` times as fast as the sample clock
+    fn next_sample(&mut self, sounding: bool, sample_rate: f32, speed: f32) -> f32 {
+        let dt = 1.0 / sample_rate;
+        if !sounding {
+            self.fade = (self.fade - dt / STOLEN_FADE_SECONDS).max(0.0);
+        }
+        let tone: f32 = self.partials.iter()
+            .map(|&(ratio, level)| (self.elapsed * self.frequency * ratio * std::f32::consts::TAU).sin() * level)
+            .sum();
+        let sample = tone * self.level * (-self.age / self.decay).exp() * self.fade;
+        self.elapsed += dt;
+        self.age += dt * speed;
+        sample
+    }
+}
 
 /// Core waveform types for psychedelic synthesis
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum AudioWaveform {
     Sine,
     Sawtooth,
@@ -20,6 +72,24 @@ pub enum AudioWaveform {
     ConsciousnessFractal,  // Special fractal waveform based on consciousness patterns
 }
 
+impl AudioWaveform {
+    /// One sample at `cycle` (0..1 through the period), in -1..1
+    pub fn sample(&self, cycle: f32) -> f32 {
+        let cycle = cycle.rem_euclid(1.0);
+        match self {
+            Self::Sine => (cycle * TAU).sin(),
+            Self::Sawtooth => cycle * 2.0 - 1.0,
+            Self::Square => if cycle < 0.5 { 1.0 } else { -1.0 },
+            Self::Triangle => if cycle < 0.5 { cycle * 4.0 - 1.0 } else { 3.0 - cycle * 4.0 },
+            Self::Noise => (fastrand::f32() - 0.5) * 2.0,
+            Self::ConsciousnessFractal => {
+                let phase = cycle * TAU;
+                (phase.sin() + (phase * 3.0).sin() * 0.3 + (phase * 7.0).sin() * 0.1) * 0.6
+            },
+        }
+    }
+}
+
 /// Multi-oscillator bank for complex timbres
 pub struct OscillatorBank {
     oscillators: Vec<Oscillator>,
@@ -46,6 +116,12 @@ pub struct PsychedelicSynthesizer {
     quantum_sheep_bank: OscillatorBank,
     bassdrop_vicuna_bank: OscillatorBank,
 
+    // Morphing wavetable pad under the mood music
+    wavetable_pad: WavetableBank,
+
+    // Scale quantization and the beat-drop chord progression
+    harmony: HarmonyEngine,
+
     // Environment-specific synthesis parameters
     environment_configs: HashMap<AudioEnvironment, EnvironmentSynthConfig>,
 
@@ -65,6 +141,14 @@ pub struct PsychedelicSynthesizer {
     reality_break_trigger: bool,
     hive_mind_emergence_trigger: bool,
     edm_mode_active: bool,
+    echo_swell: f32,     // Peak level of the swell for llamas that just died
+    echo_swell_age: f32, // Seconds since the latest death echo
+    echo_voice: Option<VoiceId>,
+    event_tones: Vec<EventTone>, // Spawn bursts and crystal chimes still ringing
+    envelope_speed: f32,         // The speed control; event sound envelopes run this much faster
+
+    // Which event sounds may start, and which give way to them
+    voices: VoiceManager,
 }
 
 #[derive(Debug, Clone)]
@@ -87,6 +171,8 @@ impl PsychedelicSynthesizer {
             disco_llama_bank: OscillatorBank::new_disco(),
             quantum_sheep_bank: OscillatorBank::new_quantum(),
             bassdrop_vicuna_bank: OscillatorBank::new_bassdrop(),
+            wavetable_pad: WavetableBank::new_pad(sample_rate),
+            harmony: HarmonyEngine::new(sample_rate),
             environment_configs: HashMap::new(),
             master_phase: 0.0,
             bass_accumulator: 0.0,
@@ -99,6 +185,12 @@ impl PsychedelicSynthesizer {
             reality_break_trigger: false,
             hive_mind_emergence_trigger: false,
             edm_mode_active: false,
+            echo_swell: 0.0,
+            echo_swell_age: 0.0,
+            echo_voice: None,
+            event_tones: Vec::new(),
+            envelope_speed: 1.0,
+            voices: VoiceManager::default(),
         };
 
         synthesizer.initialize_environment_configs();
@@ -179,39 +271,51 @@ impl PsychedelicSynthesizer {
         });
     }
 
-    /// Generate a single audio sample using procedural mood music generation
+    /// Generate a single audio sample using procedural mood music generation;
+    /// mid-crossfade both environments' graphs play at equal power. Tones are
+    /// pitched on `sample_time`, real seconds, while note changes and modulation
+    /// follow `tempo_time`, which runs at the speed control's rate
     pub fn generate_sample(&mut self,
                           sample_time: f64,
+                          tempo_time: f64,
                           beat_state: &BeatState,
-                          environment: &AudioEnvironment,
+                          environment: &EnvironmentBlend,
                           total_consciousness: f32,
-                          species_counts: &HashMap<CompatLlamaSpecies, u32>) -> f32 {
+                          species_counts: &HashMap<SpeciesType, u32>) -> f32 {
 
         self.master_phase += 1.0 / self.sample_rate as f64;
 
         // Get environment configuration
-        let config = self.environment_configs.get(environment)
-            .unwrap_or(&self.environment_configs[&AudioEnvironment::Environmental])
-            .clone();
+        let config = self.blended_config(environment);
         let base_frequency = config.base_frequency;
 
         // Calculate consciousness-driven base frequency
         let consciousness_factor = (total_consciousness / 100.0).min(2.0);
         let base_freq = base_frequency * (1.0 + consciousness_factor * 0.5);
 
-        // Apply mathematical modulation
-        let modulated_freq = self.apply_mathematical_modulation(base_freq, sample_time, beat_state);
+        // Apply mathematical modulation, then snap to the selected scale
+        let modulated_freq = self.apply_mathematical_modulation(base_freq, tempo_time, beat_state);
+        let modulated_freq = self.harmony.quantize(modulated_freq);
 
         // Generate procedural mood music based on environment
-        let mood_music = self.generate_mood_music(modulated_freq, sample_time, environment, total_consciousness, beat_state);
+        let mood_music = if environment.is_fading() && mood_index(&environment.from) != mood_index(&environment.to) {
+            let (from_gain, to_gain) = environment.gains();
+            self.generate_mood_music(modulated_freq, sample_time, tempo_time, &environment.from, total_consciousness, beat_state) * from_gain
+                + self.generate_mood_music(modulated_freq, sample_time, tempo_time, &environment.to, total_consciousness, beat_state) * to_gain
+        } else {
+            self.generate_mood_music(modulated_freq, sample_time, tempo_time, &environment.to, total_consciousness, beat_state)
+        };
 
         // Generate species-specific contributions
         let mut sample = mood_music;
 
+        // Wavetable pad an octave down, morphing from glassy to organ as consciousness rises
+        sample += self.wavetable_pad.next_sample(modulated_freq * 0.5, consciousness_factor * 0.5) * 0.06;
+
         // Species add subtle accents to the mood music (reduced levels)
 
         // Disco Llamas: Melodic accents
-        if let Some(&disco_count) = species_counts.get(&CompatLlamaSpecies::Disco) {
+        if let Some(&disco_count) = species_counts.get(&SpeciesType::DiscoLlama) {
             if disco_count > 0 {
                 let disco_contribution = self.disco_llama_bank.generate_sample(
                     modulated_freq * 1.0, // Fundamental frequency
@@ -223,7 +327,7 @@ impl PsychedelicSynthesizer {
         }
 
         // Quantum Sheep: High-frequency harmonics
-        if let Some(&quantum_count) = species_counts.get(&CompatLlamaSpecies::Quantum) {
+        if let Some(&quantum_count) = species_counts.get(&SpeciesType::QuantumSheep) {
             if quantum_count > 0 {
                 let quantum_contribution = self.quantum_sheep_bank.generate_sample(
                     modulated_freq * 2.0, // Higher harmonics
@@ -235,7 +339,7 @@ impl PsychedelicSynthesizer {
         }
 
         // BassDrop Vicunas: Bass enhancement
-        if let Some(&bass_count) = species_counts.get(&CompatLlamaSpecies::BassDrop) {
+        if let Some(&bass_count) = species_counts.get(&SpeciesType::BassDropVicuna) {
             if bass_count > 0 {
                 let bass_contribution = self.bassdrop_vicuna_bank.generate_sample(
                     modulated_freq * 0.25, // Sub-bass
@@ -253,7 +357,7 @@ impl PsychedelicSynthesizer {
         sample = self.apply_musical_effects(sample, &config, beat_state);
 
         // Handle special triggers
-        sample = self.handle_special_triggers(sample, sample_time);
+        sample = self.handle_special_triggers(sample, tempo_time);
 
         // Update accumulators for analysis
         self.update_audio_analysis(sample);
@@ -262,18 +366,37 @@ impl PsychedelicSynthesizer {
         sample.clamp(-1.0, 1.0)
     }
 
-    fn apply_mathematical_modulation(&mut self, base_freq: f32, sample_time: f64, beat_state: &BeatState) -> f32 {
+    /// Synthesis settings for the blend, interpolated while it fades
+    fn blended_config(&self, blend: &EnvironmentBlend) -> EnvironmentSynthConfig {
+        let config = |environment: &AudioEnvironment| self.environment_configs.get(environment)
+            .unwrap_or(&self.environment_configs[&AudioEnvironment::Environmental]);
+        if !blend.is_fading() {
+            return config(&blend.to).clone();
+        }
+        EnvironmentSynthConfig {
+            base_frequency: blend.lerp(|environment| config(environment).base_frequency),
+            harmonic_count: blend.lerp(|environment| config(environment).harmonic_count as f32).round() as usize,
+            noise_level: blend.lerp(|environment| config(environment).noise_level),
+            distortion_amount: blend.lerp(|environment| config(environment).distortion_amount),
+            reverb_amount: blend.lerp(|environment| config(environment).reverb_amount),
+            bass_boost: blend.lerp(|environment| config(environment).bass_boost),
+            treble_boost: blend.lerp(|environment| config(environment).treble_boost),
+            chaos_factor: blend.lerp(|environment| config(environment).chaos_factor),
+        }
+    }
+
+    fn apply_mathematical_modulation(&mut self, base_freq: f32, tempo_time: f64, beat_state: &BeatState) -> f32 {
         // Prime number modulation
-        self.prime_harmonic_index = ((sample_time * 0.1) as usize) % 1000;
+        self.prime_harmonic_index = ((tempo_time * 0.1) as usize) % 1000;
         let prime = self.prime_sieve.iter().nth(self.prime_harmonic_index).unwrap_or(2);
         let prime_factor = (prime % 12) as f32 / 12.0;
 
         // Golden ratio modulation
         let golden_ratio = 1.618033988749;
-        self.golden_ratio_modulator = ((sample_time * golden_ratio * 0.05) % 1.0) as f32;
+        self.golden_ratio_modulator = ((tempo_time * golden_ratio * 0.05) % 1.0) as f32;
 
         // Fibonacci sequence modulation
-        let fib_factor = self.get_fibonacci_factor(sample_time);
+        let fib_factor = self.get_fibonacci_factor(tempo_time);
 
         // Combine mathematical modulations
         let math_modulation = 1.0 +
@@ -287,9 +410,9 @@ impl PsychedelicSynthesizer {
         base_freq * math_modulation * beat_modulation
     }
 
-    fn get_fibonacci_factor(&mut self, sample_time: f64) -> f32 {
+    fn get_fibonacci_factor(&mut self, tempo_time: f64) -> f32 {
         // Update Fibonacci position based on time
-        let new_position = ((sample_time * 0.2) as usize) % 20; // Cycle through first 20 Fibonacci numbers
+        let new_position = ((tempo_time * 0.2) as usize) % 20; // Cycle through first 20 Fibonacci numbers
         if new_position != self.fibonacci_sequence_position {
             self.fibonacci_sequence_position = new_position;
         }
@@ -386,12 +509,12 @@ impl PsychedelicSynthesizer {
         musical
     }
 
-    fn handle_special_triggers(&mut self, sample: f32, sample_time: f64) -> f32 {
+    fn handle_special_triggers(&mut self, sample: f32, tempo_time: f64) -> f32 {
         let mut result = sample;
 
         // Reality break trigger
         if self.reality_break_trigger {
-            let break_intensity = ((sample_time * 20.0).sin() * 0.5 + 0.5) as f32;
+            let break_intensity = ((tempo_time * 20.0).sin() * 0.5 + 0.5) as f32;
             result *= 1.0 + break_intensity * 2.0;
             result += (fastrand::f32() - 0.5) * break_intensity * 0.5;
 
@@ -407,6 +530,28 @@ impl PsychedelicSynthesizer {
             self.hive_mind_emergence_trigger = false;
         }
 
+        // Death echoes: a low minor third that swells in and dies away, or quickly out once stolen
+        if self.echo_swell > 0.0 {
+            if self.echo_voice.is_some_and(|voice| !self.voices.is_sounding(voice, self.master_phase)) {
+                self.echo_swell *= (-1.0 / (STOLEN_FADE_SECONDS * self.sample_rate)).exp();
+            }
+            let rise = (self.echo_swell_age / ECHO_SWELL_RISE).min(1.0);
+            let envelope = rise * self.echo_swell_fade();
+            let tone = self.generate_sine_wave(110.0, self.master_phase) + self.generate_sine_wave(130.8, self.master_phase) * 0.6;
+            result += tone * envelope * self.echo_swell * 0.15;
+            self.echo_swell_age += self.envelope_speed / self.sample_rate;
+            if rise >= 1.0 && envelope < 0.001 {
+                self.echo_swell = 0.0;
+            }
+        }
+
+        // Spawn bursts and chimes ring out while their voices are held, and fade fast once stolen
+        for tone in &mut self.event_tones {
+            let sounding = self.voices.is_sounding(tone.voice, self.master_phase);
+            result += tone.next_sample(sounding, self.sample_rate, self.envelope_speed);
+        }
+        self.event_tones.retain(|tone| tone.fade > 0.0);
+
         // EDM mode
         if self.edm_mode_active {
             // Add aggressive sidechain compression simulation
@@ -460,22 +605,16 @@ impl PsychedelicSynthesizer {
     }
 
     /// Generate ambient mood music using Brian Eno-inspired techniques
-    fn generate_mood_music(&mut self, _base_freq: f32, sample_time: f64, environment: &AudioEnvironment, consciousness: f32, _beat_state: &BeatState) -> f32 {
-        match environment {
-            AudioEnvironment::Environmental | AudioEnvironment::Meditative => {
-                self.generate_eno_ambient_mellow(sample_time, consciousness)
-            },
-            AudioEnvironment::Psychedelic | AudioEnvironment::Electronica => {
-                self.generate_eno_ambient_active(sample_time, consciousness)
-            },
-            AudioEnvironment::HiveMind | AudioEnvironment::RealityTear => {
-                self.generate_eno_ambient_chaotic(sample_time, consciousness)
-            },
+    fn generate_mood_music(&mut self, _base_freq: f32, sample_time: f64, tempo_time: f64, environment: &AudioEnvironment, consciousness: f32, _beat_state: &BeatState) -> f32 {
+        match mood_index(environment) {
+            0 => self.generate_eno_ambient_mellow(sample_time, tempo_time, consciousness),
+            1 => self.generate_eno_ambient_active(sample_time, tempo_time, consciousness),
+            _ => self.generate_eno_ambient_chaotic(sample_time, tempo_time, consciousness),
         }
     }
 
     /// Mellow Mood: Dorian mode, slow tempo (60-80 BPM), gentle harmonics
-    fn generate_eno_ambient_mellow(&mut self, sample_time: f64, consciousness: f32) -> f32 {
+    fn generate_eno_ambient_mellow(&mut self, sample_time: f64, tempo_time: f64, consciousness: f32) -> f32 {
         // C Major scale frequencies (pleasant, familiar, soothing)
         let c_major_freqs = [261.63, 293.66, 329.63, 349.23, 392.00, 440.00, 493.88]; // C4-B4
 
@@ -485,9 +624,9 @@ impl PsychedelicSynthesizer {
         let loop3_period = 29.916; // seconds
 
         // Select notes based on loop positions (creates slowly evolving combinations)
-        let note1_index = ((sample_time / loop1_period) as usize) % c_major_freqs.len();
-        let note2_index = ((sample_time / loop2_period) as usize) % c_major_freqs.len();
-        let note3_index = ((sample_time / loop3_period) as usize) % c_major_freqs.len();
+        let note1_index = ((tempo_time / loop1_period) as usize) % c_major_freqs.len();
+        let note2_index = ((tempo_time / loop2_period) as usize) % c_major_freqs.len();
+        let note3_index = ((tempo_time / loop3_period) as usize) % c_major_freqs.len();
 
         // Generate simple sine wave tones
         let tone1 = (sample_time * c_major_freqs[note1_index] * std::f64::consts::TAU).sin() as f32;
@@ -504,7 +643,7 @@ impl PsychedelicSynthesizer {
     }
 
     /// Active Mood: Major modes (Ionian/Mixolydian), dynamic tempo (120-140 BPM)
-    fn generate_eno_ambient_active(&mut self, sample_time: f64, consciousness: f32) -> f32 {
+    fn generate_eno_ambient_active(&mut self, sample_time: f64, tempo_time: f64, consciousness: f32) -> f32 {
         // A Minor scale frequencies (more active, slightly more complex)
         let a_minor_freqs = [440.00, 493.88, 523.25, 587.33, 659.25, 698.46, 783.99]; // A4-G5
 
@@ -515,10 +654,10 @@ impl PsychedelicSynthesizer {
         let loop4_period = 27.666; // seconds - extra layer for more activity
 
         // Select notes based on loop positions
-        let note1_index = ((sample_time / loop1_period) as usize) % a_minor_freqs.len();
-        let note2_index = ((sample_time / loop2_period) as usize) % a_minor_freqs.len();
-        let note3_index = ((sample_time / loop3_period) as usize) % a_minor_freqs.len();
-        let note4_index = ((sample_time / loop4_period) as usize) % a_minor_freqs.len();
+        let note1_index = ((tempo_time / loop1_period) as usize) % a_minor_freqs.len();
+        let note2_index = ((tempo_time / loop2_period) as usize) % a_minor_freqs.len();
+        let note3_index = ((tempo_time / loop3_period) as usize) % a_minor_freqs.len();
+        let note4_index = ((tempo_time / loop4_period) as usize) % a_minor_freqs.len();
 
         // Generate simple sine wave tones
         let tone1 = (sample_time * a_minor_freqs[note1_index] * std::f64::consts::TAU).sin() as f32;
@@ -536,7 +675,7 @@ impl PsychedelicSynthesizer {
     }
 
     /// Chaotic Mood: Controlled mathematical chaos, complex polyrhythms
-    fn generate_eno_ambient_chaotic(&mut self, sample_time: f64, consciousness: f32) -> f32 {
+    fn generate_eno_ambient_chaotic(&mut self, sample_time: f64, tempo_time: f64, consciousness: f32) -> f32 {
         // Pentatonic scale frequencies (can't sound bad, but more unpredictable)
         let pentatonic_freqs = [261.63, 293.66, 329.63, 392.00, 440.00]; // C D E G A
 
@@ -548,11 +687,11 @@ impl PsychedelicSynthesizer {
         let loop5_period = 31.0; // seconds - 5 layers for controlled chaos
 
         // Select notes based on loop positions
-        let note1_index = ((sample_time / loop1_period) as usize) % pentatonic_freqs.len();
-        let note2_index = ((sample_time / loop2_period) as usize) % pentatonic_freqs.len();
-        let note3_index = ((sample_time / loop3_period) as usize) % pentatonic_freqs.len();
-        let note4_index = ((sample_time / loop4_period) as usize) % pentatonic_freqs.len();
-        let note5_index = ((sample_time / loop5_period) as usize) % pentatonic_freqs.len();
+        let note1_index = ((tempo_time / loop1_period) as usize) % pentatonic_freqs.len();
+        let note2_index = ((tempo_time / loop2_period) as usize) % pentatonic_freqs.len();
+        let note3_index = ((tempo_time / loop3_period) as usize) % pentatonic_freqs.len();
+        let note4_index = ((tempo_time / loop4_period) as usize) % pentatonic_freqs.len();
+        let note5_index = ((tempo_time / loop5_period) as usize) % pentatonic_freqs.len();
 
         // Generate simple sine wave tones
         let tone1 = (sample_time * pentatonic_freqs[note1_index] * std::f64::consts::TAU).sin() as f32;
@@ -606,17 +745,52 @@ impl PsychedelicSynthesizer {
         }
     }
 
+    /// Ask for a voice to play an event sound for `seconds` of tempo time; None when it must stay quiet
+    pub fn admit_voice(&mut self, event: SoundEvent, seconds: f32) -> Option<VoiceId> {
+        self.voices.admit(event, self.master_phase, seconds / self.envelope_speed)
+    }
+
+    /// Follow the speed control: event sound envelopes and their voices shorten or
+    /// stretch with the tempo while their pitch stays put
+    pub fn set_speed(&mut self, speed: f32) {
+        self.envelope_speed = speed.max(0.01);
+    }
+
+    /// Voices stolen since the last call, for the sample player to fade out
+    pub fn take_stolen_voices(&mut self) -> Vec<VoiceId> {
+        self.voices.take_stolen()
+    }
+
+    pub fn voice_stats(&self) -> VoiceStats {
+        self.voices.stats()
+    }
+
     // Public trigger methods for events
+    /// A brief harmonic burst, higher and louder for more conscious llamas
     pub fn trigger_spawn_sound(&mut self, consciousness: f32) {
-        // Trigger a brief harmonic burst based on consciousness level
-        let burst_intensity = consciousness.min(1.0);
-        self.chaos_intensity = (self.chaos_intensity + burst_intensity * 0.2).min(1.0);
+        let Some(voice) = self.admit_voice(SoundEvent::SpawnBurst, SPAWN_BURST_SECONDS) else { return };
+        let burst_intensity = consciousness.clamp(0.0, 1.0);
+        let frequency = self.harmony.quantize(220.0 * (1.0 + burst_intensity));
+        self.event_tones.push(EventTone::new(voice, frequency, SPAWN_BURST_PARTIALS, 0.04 + burst_intensity * 0.04, SPAWN_BURST_SECONDS / 3.0));
     }
 
+    /// A bright bell-like sparkle with inharmonic overtones
     pub fn trigger_crystal_chime(&mut self) {
-        // Add a brief crystalline chime sound
-        // This would typically involve adding a decaying sine wave at a higher frequency
-        self.treble_accumulator = (self.treble_accumulator + 0.5).min(1.0);
+        let Some(voice) = self.admit_voice(SoundEvent::CrystalChime, CRYSTAL_CHIME_SECONDS) else { return };
+        let frequency = self.harmony.quantize(1760.0);
+        self.event_tones.push(EventTone::new(voice, frequency, CRYSTAL_CHIME_PARTIALS, 0.04, CRYSTAL_CHIME_SECONDS / 3.0));
+    }
+
+    /// Swell for a dead llama's echo, louder for llamas that held more consciousness
+    pub fn trigger_echo_swell(&mut self, consciousness: f32) {
+        let Some(voice) = self.admit_voice(SoundEvent::DeathEcho, ECHO_SWELL_RISE + ECHO_SWELL_DECAY * 3.0) else { return };
+        self.echo_voice = Some(voice);
+        self.echo_swell = (self.echo_swell * self.echo_swell_fade() + 0.3 + consciousness.min(2.0) * 0.35).min(1.0);
+        self.echo_swell_age = 0.0;
+    }
+
+    fn echo_swell_fade(&self) -> f32 {
+        (-(self.echo_swell_age - ECHO_SWELL_RISE).max(0.0) / ECHO_SWELL_DECAY).exp()
     }
 
     pub fn trigger_hive_mind_emergence(&mut self) {
@@ -627,6 +801,25 @@ impl PsychedelicSynthesizer {
         self.reality_break_trigger = true;
     }
 
+    pub fn set_scale(&mut self, scale: Scale) {
+        self.harmony.set_scale(scale);
+    }
+
+    pub fn set_hive_coherence(&mut self, coherence: f32) {
+        self.harmony.set_coherence(coherence);
+    }
+
+    /// Next sample of the chord pad, consonant while the hive is coherent;
+    /// rendered apart from `generate_sample` so it gets its own mix bus
+    pub fn next_hive_sample(&mut self) -> f32 {
+        self.harmony.next_chord_sample() * 0.08
+    }
+
+    /// Next chord of the progression
+    pub fn advance_chord(&mut self) {
+        self.harmony.advance_chord();
+    }
+
     pub fn activate_edm_mode(&mut self) {
         self.edm_mode_active = true;
     }
@@ -645,6 +838,15 @@ impl PsychedelicSynthesizer {
     }
 }
 
+/// Which mood generator an environment plays: mellow, active or chaotic
+fn mood_index(environment: &AudioEnvironment) -> usize {
+    match environment {
+        AudioEnvironment::Environmental | AudioEnvironment::Meditative => 0,
+        AudioEnvironment::Psychedelic | AudioEnvironment::Electronica => 1,
+        AudioEnvironment::HiveMind | AudioEnvironment::RealityTear => 2,
+    }
+}
+
 impl OscillatorBank {
     fn new_disco() -> Self {
         Self {
@@ -779,4 +981,71 @@ impl OscillatorBank {
             },
         }) * oscillator.amplitude
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RATE: f32 = 8000.0;
+
+    /// Sign changes in `samples`, a stand-in for their pitch
+    fn zero_crossings(samples: &[f32]) -> usize {
+        samples.windows(2).filter(|pair| (pair[0] < 0.0) != (pair[1] < 0.0)).count()
+    }
+
+    /// Half a second of the full synth from `start` seconds in, with the tempo clock at `speed`
+    fn render(environment: AudioEnvironment, start: f64, speed: f64) -> Vec<f32> {
+        fastrand::seed(7);
+        let mut synthesizer = PsychedelicSynthesizer::new(RATE);
+        let beat = BeatState { is_beat_drop: false, intensity: 0.5, phase: 0.0, prime_factor: 0.0, cosmic_frequency: 1.0, tempo_bpm: 120.0 };
+        let blend = EnvironmentBlend::steady(environment);
+        let herd = HashMap::from([(SpeciesType::DiscoLlama, 5), (SpeciesType::QuantumSheep, 3), (SpeciesType::BassDropVicuna, 2)]);
+        (0..4000).map(|i| {
+            let sample_time = start + i as f64 / RATE as f64;
+            synthesizer.generate_sample(sample_time, sample_time * speed, &beat, &blend, 50.0, &herd)
+        }).collect()
+    }
+
+    #[test]
+    fn test_tempo_moves_the_notes_without_transposing_them() {
+        // Double speed early on, before any loop reaches its next note: the pitch must hold
+        for environment in [AudioEnvironment::Psychedelic, AudioEnvironment::HiveMind] {
+            let normal = zero_crossings(&render(environment.clone(), 1.0, 1.0));
+            let doubled = zero_crossings(&render(environment.clone(), 1.0, 2.0));
+            let ratio = doubled as f32 / normal as f32;
+            assert!(normal > 0 && (0.9..1.1).contains(&ratio), "{:?}: {} crossings became {}", environment, normal, doubled);
+        }
+
+        // By twelve seconds in double speed has already reached the next note of the first loop
+        let mut synthesizer = PsychedelicSynthesizer::new(RATE);
+        let mut mellow = |start: f64, speed: f64| -> Vec<f32> {
+            (0..400).map(|i| {
+                let sample_time = start + i as f64 / RATE as f64;
+                synthesizer.generate_eno_ambient_mellow(sample_time, sample_time * speed, 1.0)
+            }).collect()
+        };
+        assert_ne!(mellow(12.0, 1.0), mellow(12.0, 2.0));
+    }
+
+    #[test]
+    fn test_stolen_chimes_stop_sounding() {
+        let mut synthesizer = PsychedelicSynthesizer::new(RATE);
+        // Three chimes fill their share of the pool; a fourth steals the oldest
+        for _ in 0..4 {
+            synthesizer.trigger_crystal_chime();
+            synthesizer.master_phase += 0.1;
+        }
+        let stolen = synthesizer.take_stolen_voices();
+        assert_eq!((stolen.len(), synthesizer.event_tones.len()), (1, 4));
+
+        // Within the steal fade the stolen chime is gone and the rest ring on
+        synthesizer.master_phase = 0.35;
+        for _ in 0..(RATE * STOLEN_FADE_SECONDS) as usize + 1 {
+            synthesizer.handle_special_triggers(0.0, 0.0);
+        }
+        assert_eq!(synthesizer.event_tones.len(), 3);
+        assert!(synthesizer.event_tones.iter().all(|tone| tone.voice != stolen[0] && tone.fade == 1.0));
+        assert!(synthesizer.handle_special_triggers(0.0, 0.0).abs() > 0.0);
+    }
+}
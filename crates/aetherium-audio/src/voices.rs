@@ -0,0 +1,179 @@
+// === EVENT VOICE MANAGER ===
+// Decides which event sounds get to play. A busy war can fire dozens of cues
+// a second, and played together they smear into mush and pump the limiter.
+// Every event sound asks for a voice first. The same event is refused while
+// its cooldown runs, a few voices of one event at most sound together, and
+// the whole pool is capped. When the pool is full, a new sound steals the
+// oldest voice of the lowest priority that does not outrank it, or is dropped.
+// Stolen voices, sampled or synthesized, fade out over a few milliseconds
+// instead of clicking.
+
+use std::collections::HashMap;
+use super::sampler::SampleCue;
+
+/// Event voices sounding at once across every event
+pub const MAX_EVENT_VOICES: usize = 8;
+
+/// A sound the synthesis reacts to an event with
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SoundEvent {
+    Cue(SampleCue), // A species' one-shot sample
+    SpawnBurst,     // Harmonic burst of a new llama
+    CrystalChime,   // Sparkle of a harvested crystal
+    DeathEcho,      // Low swell of a dead llama's echo
+    RealityTear,    // Distortion burst of a tear in reality
+}
+
+impl SoundEvent {
+    /// (priority, cooldown seconds, voices of this event at once)
+    fn rules(self) -> (u8, f32, usize) {
+        match self {
+            Self::Cue(SampleCue::WarDeclared) | Self::Cue(SampleCue::TreatySigned) => (4, 1.0, 1),
+            Self::Cue(SampleCue::BassDrop) | Self::RealityTear => (3, 0.2, 2),
+            Self::DeathEcho => (2, 0.3, 2),
+            Self::Cue(SampleCue::Spawn) | Self::SpawnBurst => (1, 0.06, 3),
+            Self::Cue(SampleCue::CrystalHarvest) | Self::CrystalChime => (0, 0.08, 3),
+        }
+    }
+
+    /// Higher priorities steal from lower ones when the pool is full
+    pub fn priority(self) -> u8 {
+        self.rules().0
+    }
+
+    /// Seconds after it starts before the same event can start again
+    pub fn cooldown(self) -> f32 {
+        self.rules().1
+    }
+
+    /// Voices of this event that can sound together
+    pub fn max_voices(self) -> usize {
+        self.rules().2
+    }
+}
+
+/// Handle of an admitted voice
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct VoiceId(u64);
+
+/// What the voice manager has let through and held back
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct VoiceStats {
+    pub active: usize,
+    pub refused: u64, // Sounds dropped by a cooldown or a pool of higher priorities
+    pub stolen: u64,  // Voices cut short for a newer or more important sound
+}
+
+struct ManagedVoice {
+    id: VoiceId,
+    event: SoundEvent,
+    ends: f64, // Synthesis time the sound finishes on its own
+}
+
+/// Polyphony caps, priorities and cooldowns for event sounds
+#[derive(Default)]
+pub struct VoiceManager {
+    voices: Vec<ManagedVoice>, // Oldest first
+    last_start: HashMap<SoundEvent, f64>,
+    stolen: Vec<VoiceId>,      // Stolen since the player last took them
+    next_id: u64,
+    refused: u64,
+    stolen_total: u64,
+}
+
+impl VoiceManager {
+    /// Ask for a voice to play `event` for `seconds` from `now`; None if it must not sound
+    pub fn admit(&mut self, event: SoundEvent, now: f64, seconds: f32) -> Option<VoiceId> {
+        self.voices.retain(|voice| voice.ends > now);
+
+        let cooling = self.last_start.get(&event).is_some_and(|&start| now - start < event.cooldown() as f64);
+        if cooling {
+            self.refused += 1;
+            return None;
+        }
+
+        // Too many of this event: the oldest makes way. A full pool: the oldest of
+        // the lowest priority that does not outrank the newcomer makes way
+        let victim = if self.voices.iter().filter(|voice| voice.event == event).count() >= event.max_voices() {
+            self.voices.iter().position(|voice| voice.event == event)
+        } else if self.voices.len() >= MAX_EVENT_VOICES {
+            let lowest = self.voices.iter()
+                .map(|voice| voice.event.priority())
+                .filter(|&priority| priority <= event.priority())
+                .min();
+            let Some(lowest) = lowest else {
+                self.refused += 1;
+                return None;
+            };
+            self.voices.iter().position(|voice| voice.event.priority() == lowest)
+        } else {
+            None
+        };
+        if let Some(index) = victim {
+            self.stolen.push(self.voices.remove(index).id);
+            self.stolen_total += 1;
+        }
+
+        let id = VoiceId(self.next_id);
+        self.next_id += 1;
+        self.voices.push(ManagedVoice { id, event, ends: now + seconds as f64 });
+        self.last_start.insert(event, now);
+        Some(id)
+    }
+
+    /// True while the voice plays and has not been stolen
+    pub fn is_sounding(&self, id: VoiceId, now: f64) -> bool {
+        self.voices.iter().any(|voice| voice.id == id && voice.ends > now)
+    }
+
+    /// Voices stolen since the last call, for the player to fade out
+    pub fn take_stolen(&mut self) -> Vec<VoiceId> {
+        std::mem::take(&mut self.stolen)
+    }
+
+    pub fn stats(&self) -> VoiceStats {
+        VoiceStats { active: self.voices.len(), refused: self.refused, stolen: self.stolen_total }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cooldowns_caps_and_priority_stealing() {
+        let mut manager = VoiceManager::default();
+        let chime = SoundEvent::CrystalChime;
+
+        // A second chime inside the cooldown is refused
+        let first = manager.admit(chime, 0.0, 10.0).unwrap();
+        assert!(manager.admit(chime, 0.01, 10.0).is_none());
+
+        // The fourth chime takes over the oldest one's voice
+        manager.admit(chime, 1.0, 10.0).unwrap();
+        manager.admit(chime, 2.0, 10.0).unwrap();
+        manager.admit(chime, 3.0, 10.0).unwrap();
+        assert_eq!(manager.take_stolen(), vec![first]);
+        assert!(!manager.is_sounding(first, 3.0));
+
+        // Fill the pool; a tear steals a chime, never a war stinger
+        let war = manager.admit(SoundEvent::Cue(SampleCue::WarDeclared), 3.0, 10.0).unwrap();
+        for (i, event) in [SoundEvent::DeathEcho, SoundEvent::DeathEcho, SoundEvent::SpawnBurst, SoundEvent::SpawnBurst].into_iter().enumerate() {
+            manager.admit(event, 3.5 + i as f64, 10.0).unwrap();
+        }
+        assert_eq!(manager.stats().active, MAX_EVENT_VOICES);
+        manager.admit(SoundEvent::RealityTear, 8.0, 10.0).unwrap();
+        assert!(manager.is_sounding(war, 8.0));
+        assert_eq!(manager.stats().stolen, 2);
+
+        // Once the chimes are all gone, a chime cannot push out anything that outranks it
+        manager.admit(SoundEvent::RealityTear, 8.5, 10.0).unwrap();
+        manager.admit(SoundEvent::SpawnBurst, 9.0, 10.0).unwrap();
+        assert!(manager.admit(chime, 9.5, 10.0).is_none());
+        assert_eq!(manager.stats().refused, 2);
+
+        // Finished voices free their slots
+        assert!(manager.admit(chime, 100.0, 1.0).is_some());
+        assert_eq!(manager.stats().active, 1);
+    }
+}
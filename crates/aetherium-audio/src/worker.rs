@@ -0,0 +1,658 @@
+// === DEDICATED AUDIO SYNTHESIS THREAD ===
+// Synthesis runs here, paced by the output buffer fill level rather than the
+// render loop. The render thread only publishes consciousness snapshots and
+// discrete commands through lock-free SPSC ring buffers.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use glam::Vec2;
+use rtrb::{Consumer, Producer, RingBuffer};
+use tracing::warn;
+
+use aetherium_sim::entities::SpeciesType;
+use aetherium_sim::mathematics::beat_engine::BeatState;
+use aetherium_sim::params;
+use super::buffer::{AdaptiveBufferController, AudioBufferHealth, MAX_BUFFER_SIZE};
+use super::crossfade::EnvironmentCrossfade;
+use super::{
+    AudioControls, AudioEnvironment, AudioEnvironmentZones, AudioMode, AudioSafetyLimiter,
+    CompatChaosEvent, CompatLlamaRenderData, ConsciousnessAudioMapper,
+    PsychedelicSynthesizer, RealityDistortionProcessor, SampleBank, SampleCue, SamplePlayer,
+    SpeciesSonicSignature,
+};
+use super::effects::{FdnReverb, SidechainCompressor, StereoDelay};
+use super::input::ExternalLevel;
+use super::loudness::LoudnessNormalizer;
+use super::onset::OnsetDetector;
+use super::mixer::{MixBus, Mixer};
+use super::scope::{ScopeTap, SCOPE_SAMPLES};
+use super::surround::{OutputFrame, SurroundConfig, MAX_OUTPUT_CHANNELS};
+use super::voices::{SoundEvent, VoiceStats};
+
+/// Snapshots only need to bridge a few frames - the worker always jumps to the newest
+const SNAPSHOT_CAPACITY: usize = 8;
+const COMMAND_CAPACITY: usize = 256;
+
+/// How long the worker sleeps between buffer top-ups
+const WORKER_TICK: Duration = Duration::from_millis(2);
+
+/// Level of one-shot sample cues relative to the synthesis mix
+const SAMPLE_CUE_GAIN: f32 = 0.5;
+/// Seconds a reality tear's distortion burst holds its voice
+const REALITY_TEAR_SECONDS: f32 = 1.0;
+
+/// Latency calibration click: short decaying 1 kHz blip
+const CLICK_DURATION: f32 = 0.015;
+const CLICK_FREQUENCY: f32 = 1000.0;
+const CLICK_AMPLITUDE: f32 = 0.5;
+
+/// Per-sample smoothing of idle attenuation; snapshots arrive in frame-sized steps
+const IDLE_GAIN_GLIDE: f32 = 0.0005;
+
+/// Consciousness state published by the render thread once per frame
+#[derive(Debug, Clone)]
+pub struct AudioSnapshot {
+    pub cosmic_time: f64,
+    pub beat_state: BeatState,
+    pub llamas: Vec<CompatLlamaRenderData>,
+    pub total_consciousness: f32,
+    pub hive_coherence: f32, // Share of the population bound into hive minds, 0..1
+    pub cursor_position: Vec2,
+    pub controls: AudioControls,
+    pub idle_gain: f32, // Output attenuation while the organism is idle
+}
+
+/// Discrete events forwarded to the synthesis thread
+#[derive(Debug, Clone)]
+pub enum AudioCommand {
+    Chaos(CompatChaosEvent),
+    SampleRateChanged(f32),
+    CalibrationClick, // Mixed in at the next generated sample
+    PlayCue { species: SpeciesType, cue: SampleCue, gain: f32 },
+    LoadSampleBank { species: SpeciesType, bank: SampleBank },
+    SetSonicSignature { species: SpeciesType, signature: SpeciesSonicSignature },
+    SetSidechain(Option<Arc<ExternalLevel>>), // Level of the external music to duck under
+    SetSurround(SurroundConfig),              // Channel layout and species routing of the output
+}
+
+/// Synthesis-side analysis values, read back by the engine for visualization
+#[derive(Debug, Clone)]
+pub struct SynthesisAnalysis {
+    pub current_environment: AudioEnvironment,
+    pub bass_level: f32,
+    pub treble_level: f32,
+    pub consciousness_frequency: f32,
+    pub reality_distortion_amount: f32,
+    pub hive_mind_coherence: f32,
+    pub ducking_db: f32,
+    pub loudness_lufs: Option<f32>,
+    pub loudness_gain_db: f32,
+    pub heard_beat: f32,
+    pub heard_onsets: u64,
+    pub event_voices: VoiceStats,
+}
+
+impl Default for SynthesisAnalysis {
+    fn default() -> Self {
+        Self {
+            current_environment: AudioEnvironment::Environmental,
+            bass_level: 0.0,
+            treble_level: 0.0,
+            consciousness_frequency: 432.0,
+            reality_distortion_amount: 0.0,
+            hive_mind_coherence: 0.0,
+            ducking_db: 0.0,
+            loudness_lufs: None,
+            loudness_gain_db: 0.0,
+            heard_beat: 0.0,
+            heard_onsets: 0,
+            event_voices: VoiceStats::default(),
+        }
+    }
+}
+
+/// Render-thread side of the audio worker
+pub struct AudioWorkerHandle {
+    snapshots: Producer<AudioSnapshot>,
+    commands: Producer<AudioCommand>,
+    analysis: Arc<Mutex<SynthesisAnalysis>>,
+    waveform: Arc<Mutex<[f32; SCOPE_SAMPLES]>>,
+    running: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl AudioWorkerHandle {
+    /// Spawn the synthesis thread feeding `audio_buffer`
+    pub fn spawn(
+        sample_rate: f32,
+        audio_buffer: Arc<Mutex<VecDeque<OutputFrame>>>,
+        buffer_health: Arc<AudioBufferHealth>,
+    ) -> std::io::Result<Self> {
+        let (snapshots, snapshot_rx) = RingBuffer::new(SNAPSHOT_CAPACITY);
+        let (commands, command_rx) = RingBuffer::new(COMMAND_CAPACITY);
+        let analysis = Arc::new(Mutex::new(SynthesisAnalysis::default()));
+        let waveform = Arc::new(Mutex::new([0.0; SCOPE_SAMPLES]));
+        let running = Arc::new(AtomicBool::new(true));
+
+        let worker = AudioWorker::new(sample_rate, buffer_health);
+        let thread = {
+            let analysis = analysis.clone();
+            let waveform = waveform.clone();
+            let running = running.clone();
+            thread::Builder::new()
+                .name("aetherium-audio".to_string())
+                .spawn(move || worker.run(snapshot_rx, command_rx, audio_buffer, analysis, waveform, running))?
+        };
+
+        Ok(Self {
+            snapshots,
+            commands,
+            analysis,
+            waveform,
+            running,
+            thread: Some(thread),
+        })
+    }
+
+    /// Publish the latest consciousness state; dropped if the worker is behind
+    pub fn publish(&mut self, snapshot: AudioSnapshot) {
+        let _ = self.snapshots.push(snapshot);
+    }
+
+    pub fn send(&mut self, command: AudioCommand) {
+        if self.commands.push(command).is_err() {
+            warn!(target: "audio", "🔇 Audio command queue full - dropping command");
+        }
+    }
+
+    pub fn analysis(&self) -> SynthesisAnalysis {
+        self.analysis.lock().unwrap_or_else(PoisonError::into_inner).clone()
+    }
+
+    /// Most recent mono output samples, oldest first
+    pub fn waveform(&self) -> [f32; SCOPE_SAMPLES] {
+        *self.waveform.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Drop for AudioWorkerHandle {
+    fn drop(&mut self) {
+        self.running.store(false, Ordering::Relaxed);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// Synthesis chain state owned by the audio thread
+struct AudioWorker {
+    synthesizer: PsychedelicSynthesizer,
+    sampler: SamplePlayer, // One-shot event cues, mixed in after the effects chain
+    consciousness_mapper: ConsciousnessAudioMapper,
+    distortion_processor: RealityDistortionProcessor,
+    environment_zones: AudioEnvironmentZones,
+    mixer: Mixer,
+    reverb: FdnReverb,
+    stereo_delay: StereoDelay,
+    ducker: SidechainCompressor,
+    sidechain: Option<Arc<ExternalLevel>>,
+    loudness: LoudnessNormalizer, // Holds the program at the loudness target, all channels alike
+    safety_limiters: [AudioSafetyLimiter; MAX_OUTPUT_CHANNELS], // One per output channel
+    surround: SurroundConfig, // Where species stems go in surround layouts
+    scope: ScopeTap, // What the sound visualization shows
+    onsets: OnsetDetector, // Hears the beat in the finished output, effects and all
+
+    // Output buffer pacing
+    buffer_health: Arc<AudioBufferHealth>,
+    buffer_controller: AdaptiveBufferController,
+
+    sample_rate: f32,
+    audio_time: f64,  // Independent audio time tracking
+    tempo_time: f64,  // Musical time, advancing at the speed control's rate so tempo never transposes
+    controls: AudioControls,
+    beat_state: Option<BeatState>,
+
+    // Consciousness tracking for audio generation
+    total_consciousness: f32,
+    llama_positions: Vec<Vec2>,
+    species_counts: HashMap<SpeciesType, u32>,
+
+    // Audio environment states
+    current_environment: AudioEnvironment,
+    crossfade: EnvironmentCrossfade, // What the synth actually plays, fading on the beat
+
+    // Chords advance on the rising edge of each beat drop
+    hive_coherence: f32,
+    in_beat_drop: bool,
+
+    // Two one-pole stages of the modulated low-pass over the llama synth
+    synth_lowpass: [f32; 2],
+
+    // Idle attenuation: target from the snapshot, smoothed per sample
+    idle_gain: f32,
+    target_idle_gain: f32,
+
+    // AV latency calibration click playback position, in samples
+    click_position: Option<usize>,
+}
+
+impl AudioWorker {
+    fn new(sample_rate: f32, buffer_health: Arc<AudioBufferHealth>) -> Self {
+        Self {
+            synthesizer: PsychedelicSynthesizer::new(sample_rate),
+            sampler: SamplePlayer::new(sample_rate),
+            consciousness_mapper: ConsciousnessAudioMapper::new(sample_rate),
+            distortion_processor: RealityDistortionProcessor::new(sample_rate),
+            environment_zones: AudioEnvironmentZones::new(),
+            mixer: Mixer::new(sample_rate),
+            reverb: FdnReverb::new(sample_rate),
+            stereo_delay: StereoDelay::new(sample_rate),
+            ducker: SidechainCompressor::new(sample_rate),
+            sidechain: None,
+            loudness: LoudnessNormalizer::new(sample_rate),
+            safety_limiters: std::array::from_fn(|_| AudioSafetyLimiter::new(sample_rate)),
+            surround: SurroundConfig::default(),
+            scope: ScopeTap::default(),
+            onsets: OnsetDetector::new(sample_rate),
+            buffer_health,
+            buffer_controller: AdaptiveBufferController::new(Instant::now()),
+            sample_rate,
+            audio_time: 0.0,
+            tempo_time: 0.0,
+            controls: AudioControls::default(),
+            beat_state: None,
+            total_consciousness: 0.0,
+            llama_positions: Vec::new(),
+            species_counts: HashMap::new(),
+            current_environment: AudioEnvironment::Environmental,
+            crossfade: EnvironmentCrossfade::new(AudioEnvironment::Environmental),
+            hive_coherence: 0.0,
+            in_beat_drop: false,
+            synth_lowpass: [0.0; 2],
+            idle_gain: 1.0,
+            target_idle_gain: 1.0,
+            click_position: None,
+        }
+    }
+
+    fn run(
+        mut self,
+        mut snapshots: Consumer<AudioSnapshot>,
+        mut commands: Consumer<AudioCommand>,
+        audio_buffer: Arc<Mutex<VecDeque<OutputFrame>>>,
+        analysis: Arc<Mutex<SynthesisAnalysis>>,
+        waveform: Arc<Mutex<[f32; SCOPE_SAMPLES]>>,
+        running: Arc<AtomicBool>,
+    ) {
+        while running.load(Ordering::Relaxed) {
+            while let Ok(command) = commands.pop() {
+                self.apply_command(command);
+            }
+            for voice in self.synthesizer.take_stolen_voices() {
+                self.sampler.steal(voice);
+            }
+
+            // Skip stale frames - only the newest state matters
+            let mut latest = None;
+            while let Ok(snapshot) = snapshots.pop() {
+                latest = Some(snapshot);
+            }
+            if let Some(snapshot) = latest {
+                self.apply_snapshot(snapshot);
+            }
+
+            if self.beat_state.is_some() {
+                self.generate_audio_samples(&audio_buffer);
+
+                if let Ok(mut shared) = analysis.try_lock() {
+                    *shared = self.analysis();
+                }
+                if let Ok(mut shared) = waveform.try_lock() {
+                    *shared = self.scope.window();
+                }
+            }
+
+            thread::sleep(WORKER_TICK);
+        }
+    }
+
+    fn apply_command(&mut self, command: AudioCommand) {
+        match command {
+            AudioCommand::Chaos(event) => match event {
+                CompatChaosEvent::LlamaSpawned { consciousness } => {
+                    self.synthesizer.trigger_spawn_sound(consciousness);
+                },
+                CompatChaosEvent::RealityTear { strength, .. } => {
+                    if self.synthesizer.admit_voice(SoundEvent::RealityTear, REALITY_TEAR_SECONDS).is_some() {
+                        self.distortion_processor.trigger_reality_tear(strength);
+                    }
+                },
+                CompatChaosEvent::CrystalHarvested => {
+                    self.synthesizer.trigger_crystal_chime();
+                },
+                CompatChaosEvent::DeathEcho { consciousness } => {
+                    self.synthesizer.trigger_echo_swell(consciousness);
+                },
+            },
+            AudioCommand::SampleRateChanged(sample_rate) => {
+                self.buffer_controller = AdaptiveBufferController::new(Instant::now());
+
+                // Recreate the sample-rate dependent processors after a device change
+                self.sample_rate = sample_rate;
+                self.synthesizer = PsychedelicSynthesizer::new(sample_rate);
+                self.sampler.set_sample_rate(sample_rate);
+                self.consciousness_mapper.set_sample_rate(sample_rate);
+                self.distortion_processor = RealityDistortionProcessor::new(sample_rate);
+                self.mixer = Mixer::new(sample_rate);
+                self.reverb = FdnReverb::new(sample_rate);
+                self.stereo_delay = StereoDelay::new(sample_rate);
+                self.ducker = SidechainCompressor::new(sample_rate);
+                self.ducker.set_settings(self.controls.ducking);
+                self.loudness = LoudnessNormalizer::new(sample_rate);
+                self.onsets = OnsetDetector::new(sample_rate);
+                self.safety_limiters = std::array::from_fn(|_| AudioSafetyLimiter::new(sample_rate));
+                self.apply_loudness_settings();
+            },
+            AudioCommand::CalibrationClick => {
+                self.click_position = Some(0);
+            },
+            AudioCommand::PlayCue { species, cue, gain } => {
+                let seconds = self.sampler.cue_seconds(&species, cue);
+                if let Some(voice) = self.synthesizer.admit_voice(SoundEvent::Cue(cue), seconds) {
+                    self.sampler.trigger(species, cue, gain, voice);
+                }
+            },
+            AudioCommand::LoadSampleBank { species, bank } => {
+                self.sampler.set_bank(species, bank);
+            },
+            AudioCommand::SetSonicSignature { species, signature } => {
+                self.consciousness_mapper.set_signature(species, signature);
+            },
+            AudioCommand::SetSidechain(level) => {
+                self.sidechain = level;
+            },
+            AudioCommand::SetSurround(config) => {
+                self.surround = config;
+            },
+        }
+    }
+
+    fn apply_snapshot(&mut self, snapshot: AudioSnapshot) {
+        self.total_consciousness = snapshot.total_consciousness;
+        self.controls = snapshot.controls;
+        self.ducker.set_settings(self.controls.ducking);
+        self.apply_loudness_settings();
+        self.target_idle_gain = snapshot.idle_gain;
+
+        // Harmony follows the selected scale, the hive and the beat drops
+        self.hive_coherence = snapshot.hive_coherence;
+        self.synthesizer.set_scale(self.controls.scale);
+        self.consciousness_mapper.set_scale(self.controls.scale);
+        self.synthesizer.set_hive_coherence(snapshot.hive_coherence);
+        if snapshot.beat_state.is_beat_drop && !self.in_beat_drop {
+            self.synthesizer.advance_chord();
+        }
+        self.in_beat_drop = snapshot.beat_state.is_beat_drop;
+
+        // Update llama tracking for spatial audio
+        self.llama_positions.clear();
+        self.species_counts.clear();
+        for llama in &snapshot.llamas {
+            self.llama_positions.push(llama.position);
+            *self.species_counts.entry(llama.species).or_insert(0) += 1;
+        }
+
+        // Determine current audio environment
+        let new_environment = AudioEnvironment::from_consciousness_level(
+            snapshot.total_consciousness,
+            snapshot.llamas.len(),
+        );
+        self.handle_environment_transition(new_environment);
+        self.crossfade.request(self.get_effective_environment_for_mode());
+        self.crossfade.on_beat_phase(snapshot.beat_state.phase);
+
+        // Update all audio subsystems
+        self.environment_zones.update_cursor_position(snapshot.cursor_position);
+        self.consciousness_mapper.update(snapshot.cosmic_time, &snapshot.beat_state, &snapshot.llamas);
+        self.distortion_processor.update(snapshot.cosmic_time, &snapshot.beat_state);
+        self.environment_zones.update(snapshot.cosmic_time, &self.llama_positions);
+
+        self.beat_state = Some(snapshot.beat_state);
+    }
+
+    fn handle_environment_transition(&mut self, new_environment: AudioEnvironment) {
+        if new_environment != self.current_environment {
+            // Trigger environment-specific audio events
+            match new_environment {
+                AudioEnvironment::HiveMind => {
+                    // Massive bass drop when hive mind emerges
+                    self.synthesizer.trigger_hive_mind_emergence();
+                },
+                AudioEnvironment::RealityTear => {
+                    // Reality breaking sound when consciousness overloads
+                    self.distortion_processor.trigger_reality_break();
+                },
+                AudioEnvironment::Electronica => {
+                    // Full EDM mode activation
+                    self.synthesizer.activate_edm_mode();
+                },
+                _ => {}
+            }
+
+            self.current_environment = new_environment;
+        }
+    }
+
+    fn generate_audio_samples(&mut self, audio_buffer: &Arc<Mutex<VecDeque<OutputFrame>>>) {
+        let beat_state = match &self.beat_state {
+            Some(beat_state) => beat_state.clone(),
+            None => return,
+        };
+
+        // Check current buffer level to determine how many samples to generate
+        // A panic elsewhere while holding the lock leaves the samples intact, so keep going
+        let current_buffer_size = audio_buffer.lock().unwrap_or_else(PoisonError::into_inner).len();
+
+        // Adapt the fill target to recent underruns, then top up toward it
+        self.buffer_controller.update(&self.buffer_health, Instant::now());
+        let buffer_size = self.buffer_controller.chunk_size(current_buffer_size);
+        if buffer_size == 0 {
+            return; // Buffer is full enough
+        }
+        let mut samples = Vec::with_capacity(buffer_size);
+
+        // If audio is disabled, generate silence
+        if !self.controls.enabled {
+            samples.resize(buffer_size, [0.0; MAX_OUTPUT_CHANNELS]);
+        } else {
+            // The speed control stretches musical time only; pitch keeps to the sample clock
+            let speed_factor = self.controls.speed as f64;
+            self.synthesizer.set_speed(self.controls.speed);
+            self.consciousness_mapper.set_speed(self.controls.speed);
+            let distortion_intensity = self.get_distortion_intensity_for_mode();
+            // A coefficient of one passes the synth through untouched while the filter is open
+            let lowpass = if self.controls.filter_cutoff >= params::FILTER_CUTOFF_HZ.max { 1.0 } else {
+                1.0 - (-std::f32::consts::TAU * self.controls.filter_cutoff / self.sample_rate).exp()
+            };
+
+            // The space follows the louder environment, gliding on its own, and the echoes follow the beat
+            let space = self.crossfade.blend().dominant().clone();
+            self.reverb.set_environment(&space);
+            self.stereo_delay.set_environment(&space);
+            self.stereo_delay.set_tempo(beat_state.tempo_bpm * self.controls.speed);
+            // Block-rate key; the compressor's attack and release smooth it per sample
+            let external_level = self.sidechain.as_ref().map_or(0.0, |level| level.get());
+
+            for i in 0..buffer_size {
+                // Use independent audio time for continuous sample generation
+                let sample_time = self.audio_time + i as f64 / self.sample_rate as f64;
+                let tempo_time = self.tempo_time + i as f64 / self.sample_rate as f64 * speed_factor;
+                let environment = self.crossfade.advance(1.0 / self.sample_rate, self.controls.environment_crossfade).clone();
+
+                // Generate base consciousness-driven audio
+                let base_sample = self.synthesizer.generate_sample(
+                    sample_time,
+                    tempo_time,
+                    &beat_state,
+                    &environment,
+                    self.total_consciousness,
+                    &self.species_counts,
+                );
+
+                // Apply species-specific modulations; in surround each species keeps its own stem
+                let (stems, echoed) = self.consciousness_mapper.species_stems(&self.species_counts);
+                let modulated_sample = if self.surround.is_surround() {
+                    base_sample + echoed
+                } else {
+                    base_sample + stems.iter().sum::<f32>() + echoed
+                };
+
+                // Apply reality distortion effects with mode-based intensity
+                let distorted_sample = self.distortion_processor.process_sample_with_intensity(
+                    modulated_sample,
+                    tempo_time,
+                    &beat_state,
+                    distortion_intensity,
+                );
+
+                // Modulated low-pass over the synth, 12 dB per octave
+                let [first, second] = &mut self.synth_lowpass;
+                *first += (distorted_sample - *first) * lowpass;
+                *second += (*first - *second) * lowpass;
+                let distorted_sample = *second;
+
+                // Apply environmental effects; whatever they add is the ambient bus
+                let environmental_sample = self.environment_zones.process_sample(
+                    distorted_sample,
+                    sample_time,
+                    &environment,
+                );
+                let ambient = environmental_sample - distorted_sample;
+
+                let cues = self.sampler.next_sample() * SAMPLE_CUE_GAIN;
+                let hive = self.synthesizer.next_hive_sample();
+                let [llama, ambient, chaos, hive] = self.mixer.process([distorted_sample, ambient, cues, hive], &self.controls.mixer);
+
+                // Echoes feed the reverb so the repeats sit inside the room
+                let spatial = self.reverb.process(self.stereo_delay.process([llama + ambient + hive; 2]));
+
+                // Normalize loudness ahead of the user volume, so the volume control still means something;
+                // cues and the click stay dry
+                let dry = chaos + self.next_click_sample();
+                let program = spatial.map(|channel| channel + dry);
+                let loudness_gain = self.loudness.process(program);
+                self.idle_gain += (self.target_idle_gain - self.idle_gain) * IDLE_GAIN_GLIDE;
+                let volume = self.controls.volume * self.idle_gain * loudness_gain;
+                let volume_adjusted = program.map(|channel| channel * volume);
+
+                // Make room for external music; stems follow the bed's bus gain, volume and ducking
+                let ducked = self.ducker.process(volume_adjusted, external_level);
+                let mut frame = [0.0; MAX_OUTPUT_CHANNELS];
+                frame[..2].copy_from_slice(&ducked);
+                if self.surround.is_surround() {
+                    let ducking = 10f32.powf(-self.ducker.reduction_db() / 20.0);
+                    let stem_gain = self.controls.mixer.effective_gain(MixBus::LlamaSynth) * volume * ducking;
+                    self.surround.route_stems(stems.map(|stem| stem * stem_gain), &mut frame);
+                }
+
+                // Final safety limiting, per channel
+                for (sample, limiter) in frame.iter_mut().zip(&mut self.safety_limiters) {
+                    *sample = limiter.limit_sample(*sample);
+                }
+                samples.push(frame);
+            }
+        }
+
+        for frame in &samples {
+            let stereo = self.surround.fold_to_stereo(frame);
+            self.scope.push(stereo);
+            self.onsets.push(stereo);
+        }
+
+        // Push to audio buffer for playback
+        let mut buffer = audio_buffer.lock().unwrap_or_else(PoisonError::into_inner);
+        let mut dropped = 0;
+        for sample in samples {
+            buffer.push_back(sample);
+
+            // Keep buffer size reasonable
+            if buffer.len() > MAX_BUFFER_SIZE {
+                buffer.pop_front();
+                dropped += 1;
+            }
+        }
+        self.buffer_health.record_push(buffer.len(), dropped);
+        drop(buffer);
+
+        // The sample clock only advances by what was actually produced
+        self.audio_time += buffer_size as f64 / self.sample_rate as f64;
+        self.tempo_time += buffer_size as f64 / self.sample_rate as f64 * self.controls.speed as f64;
+    }
+
+    /// Next sample of the calibration click, or silence when none is playing
+    fn next_click_sample(&mut self) -> f32 {
+        let position = match self.click_position {
+            Some(position) => position,
+            None => return 0.0,
+        };
+
+        let t = position as f32 / self.sample_rate;
+        if t >= CLICK_DURATION {
+            self.click_position = None;
+            return 0.0;
+        }
+        self.click_position = Some(position + 1);
+
+        let envelope = 1.0 - t / CLICK_DURATION;
+        (t * CLICK_FREQUENCY * std::f32::consts::TAU).sin() * envelope * CLICK_AMPLITUDE
+    }
+
+    fn analysis(&self) -> SynthesisAnalysis {
+        SynthesisAnalysis {
+            current_environment: self.current_environment.clone(),
+            bass_level: self.synthesizer.get_bass_level(),
+            treble_level: self.synthesizer.get_treble_level(),
+            consciousness_frequency: self.consciousness_mapper.get_fundamental_frequency(),
+            reality_distortion_amount: self.distortion_processor.get_distortion_level(),
+            hive_mind_coherence: self.hive_coherence,
+            ducking_db: self.ducker.reduction_db(),
+            loudness_lufs: self.loudness.integrated_lufs(),
+            loudness_gain_db: self.loudness.gain_db(),
+            heard_beat: self.onsets.envelope(),
+            heard_onsets: self.onsets.onsets(),
+            event_voices: self.synthesizer.voice_stats(),
+        }
+    }
+
+    fn apply_loudness_settings(&mut self) {
+        self.loudness.set_settings(self.controls.loudness);
+        for limiter in &mut self.safety_limiters {
+            limiter.set_true_peak_ceiling_db(self.controls.loudness.true_peak_ceiling_db);
+        }
+    }
+
+    /// Map user audio mode to environment-like behavior
+    fn get_effective_environment_for_mode(&self) -> AudioEnvironment {
+        match self.controls.mode {
+            AudioMode::Mellow => AudioEnvironment::Meditative,
+            AudioMode::Active => {
+                // Use natural environment progression
+                self.current_environment.clone()
+            },
+            AudioMode::Chaotic => AudioEnvironment::Electronica,
+        }
+    }
+
+    /// Get distortion intensity based on user mode
+    fn get_distortion_intensity_for_mode(&self) -> f32 {
+        match self.controls.mode {
+            AudioMode::Mellow => 0.2,   // Minimal distortion
+            AudioMode::Active => 1.0,   // Natural distortion progression
+            AudioMode::Chaotic => 2.0,  // Maximum chaos
+        }
+    }
+}
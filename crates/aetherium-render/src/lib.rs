@@ -0,0 +1,8 @@
+// AetheriumBloom rendering
+// Everything between the simulation and the GPU: vertex generation for the
+// world, effects and HUD overlays, the visual themes and their shaders, the
+// reality-tear distortion pass and the dynamic vertex buffer. Colors pass
+// through the simulation crate's safety math before they reach a vertex.
+
+pub mod reality;
+pub mod rendering;
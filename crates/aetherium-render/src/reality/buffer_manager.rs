@@ -4,6 +4,14 @@
 use anyhow::Result;
 use wgpu::*;
 use std::collections::VecDeque;
+use tracing::{debug, warn};
+
+/// Share of max_capacity at which the buffer reports pressure
+pub const NEAR_CAPACITY_RATIO: f32 = 0.9;
+/// Share under which the pressure warning re-arms
+const PRESSURE_REARM_RATIO: f32 = 0.75;
+/// Frames between usage telemetry lines
+const REPORT_INTERVAL_FRAMES: u64 = 300;
 
 /// Configuration for buffer management behavior
 #[derive(Debug, Clone)]
@@ -110,6 +118,33 @@ impl VertexUsageStats {
     }
 }
 
+/// Snapshot of vertex buffer usage for the HUD, telemetry and embedding apps
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BufferUsageReport {
+    /// Vertices the buffer holds now, and the most it may ever hold
+    pub capacity: usize,
+    pub max_capacity: usize,
+    /// Vertices drawn last frame, and the most drawn in the recent history window
+    pub last_frame_vertices: usize,
+    pub peak_vertices: usize,
+    /// Times the buffer has been reallocated, and the size of the live allocation
+    pub resize_count: u32,
+    pub bytes_allocated: u64,
+    pub circuit_breaker_active: bool,
+}
+
+impl BufferUsageReport {
+    /// Recent peak as a share of max_capacity
+    pub fn fill_ratio(&self) -> f32 {
+        self.peak_vertices as f32 / self.max_capacity.max(1) as f32
+    }
+
+    /// True when frames are about to be truncated at the ceiling, or already are
+    pub fn near_limit(&self) -> bool {
+        self.circuit_breaker_active || self.fill_ratio() >= NEAR_CAPACITY_RATIO
+    }
+}
+
 /// Dynamic vertex buffer that can grow based on usage patterns
 pub struct DynamicVertexBuffer {
     buffer: Option<Buffer>,
@@ -119,6 +154,11 @@ pub struct DynamicVertexBuffer {
     vertex_stride: u64,
     /// Circuit breaker: prevents allocation beyond safe limits
     circuit_breaker_active: bool,
+    last_frame_vertices: usize,
+    resize_count: u32,
+    frames: u64,
+    /// Set once pressure has been reported, cleared when usage falls back
+    pressure_reported: bool,
 }
 
 impl DynamicVertexBuffer {
@@ -130,6 +170,10 @@ impl DynamicVertexBuffer {
             config,
             vertex_stride,
             circuit_breaker_active: false,
+            last_frame_vertices: 0,
+            resize_count: 0,
+            frames: 0,
+            pressure_reported: false,
         }
     }
 
@@ -137,6 +181,8 @@ impl DynamicVertexBuffer {
     pub fn ensure_capacity(&mut self, device: &Device, required_vertices: usize) -> Result<()> {
         // Update usage statistics
         self.usage_stats.update_usage(required_vertices, self.config.usage_history_frames);
+        self.last_frame_vertices = required_vertices;
+        self.frames += 1;
 
         // Check if we need to resize
         let needs_resize = self.buffer.is_none() ||
@@ -147,6 +193,13 @@ impl DynamicVertexBuffer {
             self.resize_buffer(device, required_vertices)?;
         }
 
+        if self.frames.is_multiple_of(REPORT_INTERVAL_FRAMES) {
+            let report = self.usage_report();
+            debug!(target: "render", "🧮 Vertex buffer: {} drawn, peak {} of {} ({} max), {} resizes, {:.1} MB",
+                   report.last_frame_vertices, report.peak_vertices, report.capacity, report.max_capacity,
+                   report.resize_count, report.bytes_allocated as f64 / (1024.0 * 1024.0));
+        }
+
         Ok(())
     }
 
@@ -166,7 +219,7 @@ impl DynamicVertexBuffer {
 
         // Circuit breaker check
         if new_capacity > self.config.max_capacity {
-            println!("WARNING: Buffer resize blocked by circuit breaker: requested {} > max {}",
+            warn!(target: "render", "WARNING: Buffer resize blocked by circuit breaker: requested {} > max {}",
                       new_capacity, self.config.max_capacity);
             self.circuit_breaker_active = true;
             return Ok(()); // Don't fail, just use existing buffer
@@ -181,12 +234,13 @@ impl DynamicVertexBuffer {
             mapped_at_creation: false,
         });
 
-        println!("Resized vertex buffer: {} -> {} vertices ({} bytes)",
+        debug!(target: "render", "Resized vertex buffer: {} -> {} vertices ({} bytes)",
                   self.current_capacity, new_capacity, buffer_size);
 
         self.buffer = Some(new_buffer);
         self.current_capacity = new_capacity;
         self.circuit_breaker_active = false;
+        self.resize_count += 1;
 
         Ok(())
     }
@@ -223,14 +277,40 @@ impl DynamicVertexBuffer {
         &self.usage_stats
     }
 
+    /// Capacity, usage, resizes and allocated bytes as of the last frame
+    pub fn usage_report(&self) -> BufferUsageReport {
+        BufferUsageReport {
+            capacity: self.current_capacity,
+            max_capacity: self.config.max_capacity,
+            last_frame_vertices: self.last_frame_vertices,
+            peak_vertices: self.usage_stats.get_peak_usage(),
+            resize_count: self.resize_count,
+            bytes_allocated: self.buffer.as_ref().map_or(0, |buffer| buffer.size()),
+            circuit_breaker_active: self.circuit_breaker_active,
+        }
+    }
+
+    /// The usage report, once each time usage first comes near max_capacity
+    pub fn take_pressure_warning(&mut self) -> Option<BufferUsageReport> {
+        let report = self.usage_report();
+        if report.near_limit() && !self.pressure_reported {
+            self.pressure_reported = true;
+            return Some(report);
+        }
+        if !report.circuit_breaker_active && report.fill_ratio() < PRESSURE_REARM_RATIO {
+            self.pressure_reported = false;
+        }
+        None
+    }
+
     /// Validate vertex count against current capacity
     pub fn validate_vertex_count(&self, vertex_count: usize) -> Result<usize> {
         if self.circuit_breaker_active && vertex_count > self.current_capacity {
-            println!("Vertex count {} exceeds capacity {} with circuit breaker active, truncating",
+            warn!(target: "render", "Vertex count {} exceeds capacity {} with circuit breaker active, truncating",
                       vertex_count, self.current_capacity);
             Ok(self.current_capacity)
         } else if vertex_count > self.current_capacity {
-            println!("Vertex count {} exceeds capacity {}, truncating",
+            warn!(target: "render", "Vertex count {} exceeds capacity {}, truncating",
                       vertex_count, self.current_capacity);
             Ok(self.current_capacity)
         } else {
@@ -250,6 +330,17 @@ pub struct VertexBudgetManager {
     category_usage: std::collections::HashMap<String, usize>,
     /// Frame usage history for adaptive budgeting
     frame_history: VecDeque<usize>,
+    /// Vertices refused per category this frame
+    category_clipped: std::collections::HashMap<String, usize>,
+}
+
+/// One render category's share of the frame budget
+#[derive(Debug, Clone, PartialEq)]
+pub struct BudgetCategoryUsage {
+    pub category: String,
+    pub used: usize,
+    pub budget: usize,
+    pub clipped: usize, // Vertices asked for beyond the budget and not drawn
 }
 
 impl VertexBudgetManager {
@@ -259,6 +350,7 @@ impl VertexBudgetManager {
             category_budgets: std::collections::HashMap::new(),
             category_usage: std::collections::HashMap::new(),
             frame_history: VecDeque::with_capacity(60),
+            category_clipped: std::collections::HashMap::new(),
         }
     }
 
@@ -276,8 +368,9 @@ impl VertexBudgetManager {
         let allocated = vertex_count.min(available);
 
         if allocated < vertex_count {
-            println!("Vertex allocation limited for {}: requested {}, allocated {}",
+            debug!(target: "render", "Vertex allocation limited for {}: requested {}, allocated {}",
                        category, vertex_count, allocated);
+            *self.category_clipped.entry(category.to_string()).or_insert(0) += vertex_count - allocated;
         }
 
         self.category_usage.insert(category.to_string(), current_usage + allocated);
@@ -294,6 +387,21 @@ impl VertexBudgetManager {
         }
 
         self.category_usage.clear();
+        self.category_clipped.clear();
+    }
+
+    /// Usage, budget and clipping of every budgeted category in the current frame, by name
+    pub fn category_report(&self) -> Vec<BudgetCategoryUsage> {
+        let mut report: Vec<BudgetCategoryUsage> = self.category_budgets.iter()
+            .map(|(category, &budget)| BudgetCategoryUsage {
+                category: category.clone(),
+                used: self.category_usage.get(category).copied().unwrap_or(0),
+                budget,
+                clipped: self.category_clipped.get(category).copied().unwrap_or(0),
+            })
+            .collect();
+        report.sort_by(|a, b| a.category.cmp(&b.category));
+        report
     }
 
     /// Get average vertex usage over recent frames
@@ -309,4 +417,36 @@ impl VertexBudgetManager {
     pub fn get_total_budget(&self) -> usize {
         self.total_budget
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pressure_is_reported_once_and_budget_clipping_is_named() {
+        let config = BufferConfig { initial_capacity: 1000, max_capacity: 2000, ..BufferConfig::default() };
+        let mut buffer = DynamicVertexBuffer::new(config, 48);
+        buffer.usage_stats.update_usage(1900, 60);
+        assert!(buffer.usage_report().near_limit());
+        assert_eq!(buffer.take_pressure_warning().map(|report| report.peak_vertices), Some(1900));
+        assert_eq!(buffer.take_pressure_warning(), None, "reported once until usage falls back");
+
+        buffer.usage_stats = VertexUsageStats::new(60);
+        buffer.usage_stats.update_usage(500, 60);
+        assert_eq!(buffer.take_pressure_warning(), None);
+        buffer.usage_stats.update_usage(1950, 60);
+        assert!(buffer.take_pressure_warning().is_some(), "re-armed after usage fell back");
+
+        let mut budget = VertexBudgetManager::new(1000);
+        budget.set_category_budget("llamas", 600);
+        budget.set_category_budget("effects", 100);
+        budget.check_allocation("llamas", 800);
+        budget.check_allocation("effects", 50);
+        let report = budget.category_report();
+        assert_eq!(report[0], BudgetCategoryUsage { category: "effects".to_string(), used: 50, budget: 100, clipped: 0 });
+        assert_eq!(report[1], BudgetCategoryUsage { category: "llamas".to_string(), used: 600, budget: 600, clipped: 200 });
+        budget.start_frame();
+        assert_eq!(budget.category_report()[1].clipped, 0);
+    }
+}
@@ -3,15 +3,20 @@ pub mod fractals;
 pub mod colors;
 pub mod chaos;
 pub mod buffer_manager;
+pub mod text;
+pub mod themes;
+#[cfg(feature = "shader-hot-reload")]
+pub mod shader_reload;
 
 use wgpu::*;
-use crate::consciousness::LlamaRenderData;
+use aetherium_sim::consciousness::LlamaRenderData;
 
 pub use renderer::PsychedelicRenderer;
 pub use colors::ColorConsciousness;
 pub use fractals::FractalGenerator;
 pub use chaos::ChaosEffects;
-pub use buffer_manager::{DynamicVertexBuffer, VertexBudgetManager, BufferConfig};
+pub use buffer_manager::{DynamicVertexBuffer, VertexBudgetManager, BufferConfig, BufferUsageReport, BudgetCategoryUsage};
+pub use themes::{ThemePipelines, VisualTheme};
 
 #[derive(Debug, Clone)]
 pub struct RenderData {
@@ -22,6 +27,10 @@ pub struct RenderData {
     pub beat_intensity: f32,
 }
 
+/// Added to a llama body's species_id to mark it for the species skin; overlays, points and
+/// everything else keep whole ids and are left untextured
+pub const SKINNED_SPECIES: f32 = 0.25;
+
 #[repr(C)]
 #[derive(Debug, Copy, Clone, bytemuck::Pod, bytemuck::Zeroable)]
 pub struct Vertex {
@@ -1,6 +1,7 @@
 use anyhow::Result;
 use wgpu::*;
-use crate::core::events::LlamaSpecies;
+use tracing::{debug, error, trace, warn};
+use aetherium_sim::core::events::LlamaSpecies;
 use crate::reality::{RenderData, Vertex, UniformData, create_llama_geometry, hsv_to_rgb, DynamicVertexBuffer, VertexBudgetManager, BufferConfig};
 
 pub struct PsychedelicRenderer {
@@ -134,12 +135,12 @@ impl PsychedelicRenderer {
         let allocated_vertices = self.budget_manager.check_allocation("llamas", estimated_total);
         let max_llamas = allocated_vertices / estimated_vertices_per_llama;
 
-        println!("Rendering {} llamas (limited to {} by budget), estimated {} vertices",
+        trace!(target: "render", "Rendering {} llamas (limited to {} by budget), estimated {} vertices",
                    render_data.llamas.len(), max_llamas, estimated_total);
 
         for (i, llama) in render_data.llamas.iter().enumerate() {
             if i >= max_llamas {
-                println!("Llama rendering limited by vertex budget at {}/{}", i, render_data.llamas.len());
+                debug!(target: "render", "Llama rendering limited by vertex budget at {}/{}", i, render_data.llamas.len());
                 break;
             }
 
@@ -174,23 +175,23 @@ impl PsychedelicRenderer {
 
         // Ensure buffer capacity and validate vertex count
         if let Err(e) = self.dynamic_vertex_buffer.ensure_capacity(device, vertices.len()) {
-            eprintln!("Failed to ensure buffer capacity: {}", e);
+            error!(target: "render", "Failed to ensure buffer capacity: {}", e);
             return Err(SurfaceError::Lost);
         }
 
         let validated_vertex_count = match self.dynamic_vertex_buffer.validate_vertex_count(vertices.len()) {
             Ok(count) => count,
             Err(e) => {
-                eprintln!("Vertex validation failed: {}", e);
+                error!(target: "render", "Vertex validation failed: {}", e);
                 return Err(SurfaceError::Lost);
             }
         };
 
         // Truncate vertices if validation reduced the count
         if validated_vertex_count < vertices.len() {
+            warn!(target: "render", "Vertices truncated from {} to {} due to capacity limits",
+                  vertices.len(), validated_vertex_count);
             vertices.truncate(validated_vertex_count);
-            println!("Vertices truncated from {} to {} due to capacity limits",
-                      vertices.len(), validated_vertex_count);
         }
 
         // Update vertex buffer
@@ -198,7 +199,7 @@ impl PsychedelicRenderer {
             if let Some(buffer) = self.dynamic_vertex_buffer.get_buffer() {
                 queue.write_buffer(buffer, 0, bytemuck::cast_slice(&vertices));
             } else {
-                eprintln!("No vertex buffer available for rendering");
+                error!(target: "render", "No vertex buffer available for rendering");
                 return Err(SurfaceError::Lost);
             }
         }
@@ -250,7 +251,7 @@ impl PsychedelicRenderer {
                     render_pass.set_vertex_buffer(0, buffer.slice(..));
                     render_pass.draw(0..vertices.len() as u32, 0..1);
                 } else {
-                    println!("No vertex buffer available for render pass");
+                    error!(target: "render", "No vertex buffer available for render pass");
                 }
             }
         }
@@ -0,0 +1,105 @@
+// === SHADER HOT RELOAD ===
+// Watches the WGSL sources in the crate tree so the organism can rebuild its
+// pipelines while running. A shader that fails validation never replaces the
+// pipeline that is already on screen.
+
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver};
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use tracing::warn;
+use wgpu::{Device, ErrorFilter};
+
+/// The shader sources as they sit in the source tree, re-read on every change
+pub const SHADER_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/reality/shaders");
+
+/// Longest shader error shown on the HUD, in characters
+const MAX_NOTICE_LEN: usize = 90;
+
+pub struct ShaderWatcher {
+    _watcher: RecommendedWatcher, // Dropping the watcher stops the notifications
+    changes: Receiver<PathBuf>,
+    dir: PathBuf,
+}
+
+impl ShaderWatcher {
+    /// Start watching `*.wgsl` files directly inside `dir`
+    pub fn new(dir: impl Into<PathBuf>) -> notify::Result<Self> {
+        let dir = dir.into();
+        let (sender, changes) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(move |result: notify::Result<notify::Event>| match result {
+            Ok(event) if matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_)) => {
+                for path in event.paths.into_iter().filter(|path| is_wgsl(path)) {
+                    let _ = sender.send(path);
+                }
+            }
+            Ok(_) => {}
+            Err(e) => warn!(target: "render", "👁️ Shader watcher error: {}", e),
+        })?;
+        watcher.watch(&dir, RecursiveMode::NonRecursive)?;
+
+        Ok(Self { _watcher: watcher, changes, dir })
+    }
+
+    /// File names of the shaders changed since the last poll, each listed once
+    pub fn changed_shaders(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.changes.try_iter()
+            .filter_map(|path| path.file_name().map(|name| name.to_string_lossy().into_owned()))
+            .collect();
+        names.sort();
+        names.dedup();
+        names
+    }
+
+    /// Current source of a watched shader
+    pub fn read_shader(&self, name: &str) -> std::io::Result<String> {
+        std::fs::read_to_string(self.dir.join(name))
+    }
+}
+
+fn is_wgsl(path: &Path) -> bool {
+    path.extension().is_some_and(|extension| extension == "wgsl")
+}
+
+/// Run `build` inside a validation error scope, so a broken shader comes back as
+/// an error message instead of reaching the uncaptured error handler
+pub fn build_validated<T>(device: &Device, build: impl FnOnce() -> T) -> Result<T, String> {
+    device.push_error_scope(ErrorFilter::Validation);
+    let built = build();
+    match pollster::block_on(device.pop_error_scope()) {
+        None => Ok(built),
+        Some(wgpu::Error::Validation { description, .. }) => Err(description),
+        Some(error) => Err(error.to_string()),
+    }
+}
+
+/// One HUD line out of a multi-line wgpu/naga error report
+pub fn error_notice(name: &str, description: &str) -> String {
+    let detail = description.lines()
+        .map(str::trim)
+        .find(|line| line.starts_with("error:"))
+        .or_else(|| description.lines().map(str::trim).find(|line| !line.is_empty()))
+        .unwrap_or("validation failed");
+    let detail = detail.trim_start_matches("error:").trim();
+
+    let mut notice = format!("SHADER {} KEPT OLD PIPELINE: {}", name, detail);
+    if notice.chars().count() > MAX_NOTICE_LEN {
+        notice = notice.chars().take(MAX_NOTICE_LEN - 3).collect::<String>() + "...";
+    }
+    notice
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_notice_picks_the_error_line() {
+        let report = "Shader 'Shader' parsing error: expected ';'\n\n   ┌─ wgsl:12:5\n\nerror: expected ';', found '}'";
+        let notice = error_notice("psychedelic.wgsl", report);
+        assert_eq!(notice, "SHADER psychedelic.wgsl KEPT OLD PIPELINE: expected ';', found '}'");
+
+        let long = error_notice("psychedelic.wgsl", &"x".repeat(500));
+        assert_eq!(long.chars().count(), MAX_NOTICE_LEN);
+        assert!(long.ends_with("..."));
+    }
+}
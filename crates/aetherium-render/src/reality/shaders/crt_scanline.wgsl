@@ -0,0 +1,81 @@
+// CRT SCANLINE THEME
+// Gentle barrel curvature, scanlines, an RGB shadow mask and a vignette.
+// The rolling bar drifts slowly instead of flickering.
+
+struct VertexInput {
+    @location(0) position: vec3<f32>,
+    @location(1) color: vec3<f32>,
+    @location(2) uv: vec2<f32>,
+    @location(3) species_id: f32,  // 0=Disco, 1=Quantum, 2=Hypno, 3=Fractal, 4=BassDrop
+    @location(4) consciousness: f32,
+    @location(5) trip_intensity: f32,
+}
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) color: vec3<f32>,
+    @location(1) uv: vec2<f32>,
+    @location(2) world_pos: vec2<f32>,
+    @location(3) species_id: f32,
+    @location(4) consciousness: f32,
+    @location(5) trip_intensity: f32,
+}
+
+struct Uniforms {
+    time: f32,
+    reality_distortion: f32,
+    consciousness_level: f32,
+    beat_intensity: f32,
+    screen_resolution: vec2<f32>,
+    beat_frequency: f32,
+    cosmic_phase: f32,
+}
+
+@group(0) @binding(0)
+var<uniform> uniforms: Uniforms;
+
+const CURVATURE: f32 = 0.06;
+
+@vertex
+fn vs_main(input: VertexInput) -> VertexOutput {
+    var out: VertexOutput;
+
+    // Barrel distortion pulls the picture in toward the corners of the tube
+    let p = input.position.xy;
+    let curved = p * (1.0 - CURVATURE * dot(p, p) * 0.5);
+
+    out.clip_position = vec4<f32>(curved, input.position.z, 1.0);
+    out.color = input.color;
+    out.uv = input.uv;
+    out.world_pos = curved;
+    out.species_id = input.species_id;
+    out.consciousness = input.consciousness;
+    out.trip_intensity = input.trip_intensity;
+    return out;
+}
+
+@fragment
+fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
+    // clip_position is the framebuffer pixel in the fragment stage
+    let pixel = input.clip_position.xy;
+
+    let scanline = 0.78 + 0.22 * sin(pixel.y * 3.14159);
+    let mask_column = u32(pixel.x) % 3u;
+    var mask = vec3<f32>(0.85);
+    if (mask_column == 0u) {
+        mask.r = 1.0;
+    } else if (mask_column == 1u) {
+        mask.g = 1.0;
+    } else {
+        mask.b = 1.0;
+    }
+
+    // Roll bar travels the screen once every ten seconds
+    let roll_y = fract(uniforms.time * 0.1) * 2.4 - 1.2;
+    let roll = 1.0 + 0.06 * (1.0 - smoothstep(0.0, 0.15, abs(input.world_pos.y - roll_y)));
+
+    let vignette = 1.0 - smoothstep(0.6, 1.5, length(input.world_pos * vec2<f32>(0.9, 1.1)));
+    let phosphor = input.color * vec3<f32>(0.95, 1.0, 0.92);
+
+    return vec4<f32>(phosphor * mask * scanline * roll * vignette, 1.0);
+}
@@ -0,0 +1,106 @@
+// Refractive consciousness crystals: the world is drawn to a backdrop texture
+// first, copied to the target, and each crystal is then drawn as a faceted
+// diamond that bends the backdrop behind it and glints facet by facet in time
+// with its resonance.
+
+struct CrystalUniforms {
+    screen: vec2<f32>,  // Target size in pixels
+    intensity: f32,     // Safety intensity limit, scales tint and glints
+    glint_gain: f32,    // Brightest a glint adds at full sparkle
+}
+
+@group(0) @binding(0) var backdrop: texture_2d<f32>;
+@group(0) @binding(1) var backdrop_sampler: sampler;
+@group(0) @binding(2) var<uniform> crystals: CrystalUniforms;
+
+// --- Backdrop copy ---
+
+struct BlitOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+}
+
+// One triangle covering the whole screen
+@vertex
+fn vs_blit(@builtin(vertex_index) index: u32) -> BlitOutput {
+    let corner = vec2<f32>(f32((index << 1u) & 2u), f32(index & 2u));
+    var out: BlitOutput;
+    out.clip_position = vec4<f32>(corner * 2.0 - 1.0, 0.0, 1.0);
+    out.uv = vec2<f32>(corner.x, 1.0 - corner.y);
+    return out;
+}
+
+@fragment
+fn fs_blit(in: BlitOutput) -> @location(0) vec4<f32> {
+    return textureSample(backdrop, backdrop_sampler, in.uv);
+}
+
+// --- Crystals ---
+
+struct CrystalInstance {
+    @location(0) center: vec2<f32>, // Clip space
+    @location(1) size: vec2<f32>,   // Half extents in clip space
+    @location(2) color: vec3<f32>,
+    @location(3) energy: f32,
+    @location(4) look: vec4<f32>,   // Glint phase (cycles), refraction, facets, sparkle
+}
+
+struct CrystalOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) local: vec2<f32>, // -1..1 across the diamond
+    @location(1) color: vec3<f32>,
+    @location(2) energy: f32,
+    @location(3) look: vec4<f32>,
+    @location(4) size: vec2<f32>,
+}
+
+@vertex
+fn vs_crystal(@builtin(vertex_index) index: u32, crystal: CrystalInstance) -> CrystalOutput {
+    // Two triangles: top, right, bottom and top, left, bottom
+    var corners = array<vec2<f32>, 6>(
+        vec2<f32>(0.0, 1.0), vec2<f32>(1.0, 0.0), vec2<f32>(0.0, -1.0),
+        vec2<f32>(0.0, 1.0), vec2<f32>(-1.0, 0.0), vec2<f32>(0.0, -1.0),
+    );
+    let local = corners[index % 6u];
+    var out: CrystalOutput;
+    out.clip_position = vec4<f32>(crystal.center + local * crystal.size, 0.0, 1.0);
+    out.local = local;
+    out.color = crystal.color;
+    out.energy = crystal.energy;
+    out.look = crystal.look;
+    out.size = crystal.size;
+    return out;
+}
+
+@fragment
+fn fs_crystal(in: CrystalOutput) -> @location(0) vec4<f32> {
+    let tau = 6.2831853;
+    let glint_phase = in.look.x;
+    let refraction = in.look.y;
+    let facets = max(in.look.z, 1.0);
+    let sparkle = in.look.w;
+
+    // 0 at the heart of the diamond, 1 on its edge
+    let edge = clamp(abs(in.local.x) + abs(in.local.y), 0.0, 1.0);
+
+    // Which facet this pixel is on, and the way that facet leans
+    let angle = atan2(in.local.y, in.local.x);
+    let facet = floor((angle / tau + 0.5) * facets);
+    let lean = (facet + 0.5) / facets * tau - 3.1415927;
+    let normal = vec2<f32>(cos(lean), sin(lean));
+
+    // Bend what is behind: thickest in the middle, along the facet's lean
+    let bend = normal * refraction * (1.0 - edge) * in.size * vec2<f32>(0.5, -0.5);
+    let uv = in.clip_position.xy / crystals.screen - bend;
+    let behind = textureSample(backdrop, backdrop_sampler, clamp(uv, vec2<f32>(0.0), vec2<f32>(1.0))).rgb;
+
+    // Each facet catches the light in turn as the resonance comes round
+    let turn = fract(glint_phase + facet / facets);
+    let glint = pow(max(1.0 - abs(turn - 0.5) * 4.0, 0.0), 6.0) * sparkle * (1.0 - edge * 0.5);
+
+    let tint = in.color * crystals.intensity;
+    let rim = smoothstep(0.7, 1.0, edge);
+    let body = behind * mix(vec3<f32>(1.0), in.color, 0.35) + tint * (0.2 + rim * 0.5 + in.energy * 0.05);
+    let color = body + mix(tint, vec3<f32>(1.0), 0.5) * glint * crystals.glint_gain * crystals.intensity;
+    return vec4<f32>(min(color, vec3<f32>(1.0)), 1.0);
+}
@@ -0,0 +1,67 @@
+// Screen-space reality distortion: the scene is drawn to a texture first, then
+// resampled here with UVs bent around reality tears and rippled by emergence.
+
+struct DistortionUniforms {
+    wells: array<vec4<f32>, 8>, // uv center xy, radius (uv), displacement strength
+    well_count: u32,
+    time: f32,
+    swirl: f32,     // Whole-screen ripple amplitude in uv
+    ripple_hz: f32, // Kept well below the flash-rate limit
+    aspect: f32,    // Width over height
+    _pad0: f32,
+    _pad1: f32,
+    _pad2: f32,
+}
+
+@group(0) @binding(0) var scene: texture_2d<f32>;
+@group(0) @binding(1) var scene_sampler: sampler;
+@group(0) @binding(2) var<uniform> distortion: DistortionUniforms;
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+}
+
+// One triangle covering the whole screen
+@vertex
+fn vs_main(@builtin(vertex_index) index: u32) -> VertexOutput {
+    let corner = vec2<f32>(f32((index << 1u) & 2u), f32(index & 2u));
+    var out: VertexOutput;
+    out.clip_position = vec4<f32>(corner * 2.0 - 1.0, 0.0, 1.0);
+    out.uv = vec2<f32>(corner.x, 1.0 - corner.y);
+    return out;
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    let tau = 6.2831853;
+    let phase = distortion.time * distortion.ripple_hz * tau;
+    var uv = in.uv;
+
+    // Each tear is a lens: space twists around it and is pulled towards its center
+    for (var i = 0u; i < min(distortion.well_count, 8u); i = i + 1u) {
+        let well = distortion.wells[i];
+        var offset = uv - well.xy;
+        offset.x = offset.x * distortion.aspect;
+        let dist = length(offset);
+        if (dist < well.z && dist > 0.0001) {
+            let falloff = 1.0 - smoothstep(0.0, well.z, dist);
+            let ripple = 1.0 + 0.3 * sin(phase - dist / well.z * tau);
+            let angle = well.w * falloff * 8.0;
+            let twisted = vec2<f32>(
+                offset.x * cos(angle) - offset.y * sin(angle),
+                offset.x * sin(angle) + offset.y * cos(angle),
+            );
+            let pulled = twisted - normalize(twisted) * well.w * falloff * ripple;
+            uv = well.xy + vec2<f32>(pulled.x / distortion.aspect, pulled.y);
+        }
+    }
+
+    // Emergence makes the whole world breathe
+    uv = uv + distortion.swirl * vec2<f32>(
+        sin(uv.y * 12.0 + phase),
+        cos(uv.x * 12.0 + phase * 0.7),
+    );
+
+    return textureSample(scene, scene_sampler, clamp(uv, vec2<f32>(0.0), vec2<f32>(1.0)));
+}
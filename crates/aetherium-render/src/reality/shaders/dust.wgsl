@@ -0,0 +1,119 @@
+// Consciousness dust: tens of thousands of motes that live entirely on the GPU.
+// The compute entry point advects them through a slow ambient current stirred
+// by the beat, by the wakes of moving llamas and by the world's force fields; the render entry points draw
+// each mote as one max-blended point, clamped to the safety-scaled brightness.
+
+struct DustUniforms {
+    stirrers: array<vec4<f32>, 16>, // World position xy, velocity xy of the fastest llamas
+    view: vec4<f32>,                // Visible world rectangle: min xy, max xy
+    fields: array<vec4<f32>, 8>,    // Force field position xy, radius, strength; unused slots have zero radius
+    field_shapes: array<vec4<f32>, 8>, // Kind (0 well, 1 vortex, 2 wind), wind direction xy
+    stirrer_count: u32,
+    particle_count: u32,
+    time: f32,
+    dt: f32,
+    beat: f32,       // Smoothed beat intensity, 0-1
+    brightness: f32, // Already scaled by the safety intensity limit
+    world: vec2<f32>,
+}
+
+struct Mote {
+    position: vec2<f32>,
+    velocity: vec2<f32>,
+    seed: f32,
+    glow: f32,
+    _pad: vec2<f32>,
+}
+
+@group(0) @binding(0) var<uniform> dust: DustUniforms;
+@group(0) @binding(1) var<storage, read_write> motes: array<Mote>;
+
+const STIR_RADIUS: f32 = 90.0;
+const TAU: f32 = 6.2831853;
+// Matching the simulation's force fields in physics.rs
+const FIELD_CORE: f32 = 24.0;
+const VORTEX_INWARD: f32 = 0.25;
+// Motes drift with a share of the acceleration a llama would feel
+const FIELD_FLOW: f32 = 0.4;
+
+fn field_acceleration(p: vec2<f32>, field: vec4<f32>, shape: vec4<f32>) -> vec2<f32> {
+    let offset = field.xy - p;
+    let dist = length(offset);
+    if (field.z <= 0.0 || dist >= field.z) {
+        return vec2<f32>(0.0, 0.0);
+    }
+    let falloff = (1.0 - dist / field.z) * field.w;
+    let core = min(dist / FIELD_CORE, 1.0);
+    let inward = offset / max(dist, 1.0);
+    if (shape.x < 0.5) {
+        return inward * falloff * core;
+    }
+    if (shape.x < 1.5) {
+        return (vec2<f32>(inward.y, -inward.x) + inward * VORTEX_INWARD) * falloff * core;
+    }
+    return shape.yz * falloff;
+}
+
+@compute @workgroup_size(64)
+fn cs_main(@builtin(global_invocation_id) id: vec3<u32>) {
+    let index = id.x;
+    if (index >= dust.particle_count) {
+        return;
+    }
+    var mote = motes[index];
+    let p = mote.position;
+
+    // A slowly curling current that quickens on the beat
+    let phase = dust.time * 0.2 + mote.seed * TAU;
+    var flow = vec2<f32>(sin(p.y * 0.011 + phase), cos(p.x * 0.009 - phase * 0.7)) * (6.0 + 18.0 * dust.beat);
+
+    // Llamas drag motes along in their wake and part them as they pass
+    var glow = 0.0;
+    for (var i = 0u; i < min(dust.stirrer_count, 16u); i = i + 1u) {
+        let stirrer = dust.stirrers[i];
+        let offset = p - stirrer.xy;
+        let dist = length(offset);
+        if (dist < STIR_RADIUS) {
+            let falloff = 1.0 - dist / STIR_RADIUS;
+            flow = flow + stirrer.zw * falloff * 0.8 + offset / max(dist, 1.0) * falloff * 20.0;
+            glow = max(glow, falloff);
+        }
+    }
+
+    // Wells, vortexes and winds carry the motes along with the llamas
+    for (var i = 0u; i < 8u; i = i + 1u) {
+        flow = flow + field_acceleration(p, dust.fields[i], dust.field_shapes[i]) * FIELD_FLOW;
+    }
+
+    // Motes ease into the local flow, so wakes linger after a llama has gone
+    let ease = min(dust.dt * 1.5, 1.0);
+    mote.velocity = mix(mote.velocity, flow, ease);
+    let moved = p + mote.velocity * dust.dt;
+    mote.position = (moved % dust.world + dust.world) % dust.world;
+    mote.glow = mix(mote.glow, glow, ease);
+    motes[index] = mote;
+}
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) color: vec3<f32>,
+}
+
+@vertex
+fn vs_main(@location(0) position: vec2<f32>, @location(1) velocity: vec2<f32>, @location(2) seed_glow: vec2<f32>) -> VertexOutput {
+    let size = max(dust.view.zw - dust.view.xy, vec2<f32>(1.0));
+    let uv = (position - dust.view.xy) / size;
+    var out: VertexOutput;
+    out.clip_position = vec4<f32>(uv.x * 2.0 - 1.0, 1.0 - uv.y * 2.0, 0.0, 1.0);
+
+    let hue = seed_glow.x + dust.time * 0.02;
+    let tint = 0.5 + 0.5 * cos(TAU * (vec3<f32>(hue) + vec3<f32>(0.0, 0.33, 0.67)));
+    let shimmer = min(length(velocity) / 60.0, 1.0);
+    out.color = min(tint * dust.brightness * (0.35 + 0.35 * seed_glow.y + 0.3 * shimmer), vec3<f32>(dust.brightness));
+    return out;
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    return vec4<f32>(in.color, 1.0);
+}
@@ -0,0 +1,32 @@
+// Color grading: the finished world is drawn to a scene texture and every
+// pixel is looked up in a 3D LUT on its way to the screen. The LUT is blended
+// on the CPU from the audio environment's look and the night look, so this
+// pass only ever sees one.
+
+@group(0) @binding(0) var scene: texture_2d<f32>;
+@group(0) @binding(1) var linear_sampler: sampler;
+@group(0) @binding(2) var lut: texture_3d<f32>;
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+}
+
+// One triangle covering the whole screen
+@vertex
+fn vs_main(@builtin(vertex_index) index: u32) -> VertexOutput {
+    let corner = vec2<f32>(f32((index << 1u) & 2u), f32(index & 2u));
+    var out: VertexOutput;
+    out.clip_position = vec4<f32>(corner * 2.0 - 1.0, 0.0, 1.0);
+    out.uv = vec2<f32>(corner.x, 1.0 - corner.y);
+    return out;
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    let color = clamp(textureSample(scene, linear_sampler, in.uv).rgb, vec3<f32>(0.0), vec3<f32>(1.0));
+    // Sample texel centers so the ends of the LUT map to black and white exactly
+    let size = f32(textureDimensions(lut).x);
+    let coord = color * (size - 1.0) / size + 0.5 / size;
+    return vec4<f32>(textureSample(lut, linear_sampler, coord).rgb, 1.0);
+}
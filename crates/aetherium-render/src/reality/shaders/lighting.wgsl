@@ -0,0 +1,104 @@
+// 2D lighting: a full-screen pass multiplied over the world. Conscious llamas
+// and crystals are point lights, walls and pillars cast soft shadows from
+// them, and everything out of reach sinks towards the ambient level. The pass
+// only ever darkens what is under it, so it stays inside the luminance budget
+// the world was checked against.
+
+const MAX_LIGHTS: u32 = 32u;
+const MAX_OCCLUDERS: u32 = 32u;
+// Points sampled across each light for soft shadow edges
+const SHADOW_SAMPLES: i32 = 5;
+
+struct LightingUniforms {
+    lights: array<vec4<f32>, 32>,  // World xy, reach, strength
+    colors: array<vec4<f32>, 32>,  // Light color, unused
+    walls: array<vec4<f32>, 32>,   // Start xy, end xy
+    pillars: array<vec4<f32>, 32>, // Center xy, radius, unused
+    view: vec4<f32>,               // Visible world rectangle: min xy, max xy
+    screen: vec2<f32>,             // Target size in pixels
+    ambient: f32,                  // Light level nothing reaches
+    softness: f32,                 // Radius of each light's disc, in world units
+    light_count: u32,
+    wall_count: u32,
+    pillar_count: u32,
+    _padding: u32,
+}
+
+@group(0) @binding(0) var<uniform> lighting: LightingUniforms;
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+}
+
+// One triangle covering the whole screen
+@vertex
+fn vs_main(@builtin(vertex_index) index: u32) -> VertexOutput {
+    let corner = vec2<f32>(f32((index << 1u) & 2u), f32(index & 2u));
+    var out: VertexOutput;
+    out.clip_position = vec4<f32>(corner * 2.0 - 1.0, 0.0, 1.0);
+    return out;
+}
+
+fn cross2(a: vec2<f32>, b: vec2<f32>) -> f32 {
+    return a.x * b.y - a.y * b.x;
+}
+
+// Whether the segments a-b and c-d cross
+fn crosses(a: vec2<f32>, b: vec2<f32>, c: vec2<f32>, d: vec2<f32>) -> bool {
+    let ab = b - a;
+    let cd = d - c;
+    return cross2(ab, c - a) * cross2(ab, d - a) < 0.0 && cross2(cd, a - c) * cross2(cd, b - c) < 0.0;
+}
+
+fn blocked(p: vec2<f32>, light: vec2<f32>) -> bool {
+    for (var i = 0u; i < min(lighting.wall_count, MAX_OCCLUDERS); i++) {
+        let wall = lighting.walls[i];
+        if crosses(p, light, wall.xy, wall.zw) {
+            return true;
+        }
+    }
+    for (var i = 0u; i < min(lighting.pillar_count, MAX_OCCLUDERS); i++) {
+        let pillar = lighting.pillars[i];
+        let along = light - p;
+        let t = clamp(dot(pillar.xy - p, along) / max(dot(along, along), 1e-4), 0.0, 1.0);
+        if distance(p + along * t, pillar.xy) < pillar.z {
+            return true;
+        }
+    }
+    return false;
+}
+
+// Share of the light's disc visible from `p`
+fn visibility(p: vec2<f32>, light: vec2<f32>) -> f32 {
+    if lighting.wall_count == 0u && lighting.pillar_count == 0u {
+        return 1.0;
+    }
+    let across = normalize(vec2<f32>(light.y - p.y, p.x - light.x) + vec2<f32>(1e-5, 0.0)) * lighting.softness;
+    var seen = 0.0;
+    for (var s = 0; s < SHADOW_SAMPLES; s++) {
+        let offset = f32(s) / f32(SHADOW_SAMPLES - 1) * 2.0 - 1.0;
+        if !blocked(p, light + across * offset) {
+            seen += 1.0;
+        }
+    }
+    return seen / f32(SHADOW_SAMPLES);
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    let uv = in.clip_position.xy / lighting.screen;
+    let p = mix(lighting.view.xy, lighting.view.zw, uv);
+
+    var light = vec3<f32>(0.0);
+    for (var i = 0u; i < min(lighting.light_count, MAX_LIGHTS); i++) {
+        let source = lighting.lights[i];
+        let falloff = 1.0 - distance(p, source.xy) / max(source.z, 1.0);
+        if falloff <= 0.0 {
+            continue;
+        }
+        light += lighting.colors[i].rgb * source.w * falloff * falloff * visibility(p, source.xy);
+    }
+
+    let level = lighting.ambient + (1.0 - lighting.ambient) * min(light, vec3<f32>(1.0));
+    return vec4<f32>(level, 1.0);
+}
@@ -0,0 +1,78 @@
+// NEON WIREFRAME THEME
+// Only the edges of each primitive glow, like tubes of neon against the void.
+// Shares vertex layout, uniforms and entry points with psychedelic.wgsl.
+
+struct VertexInput {
+    @location(0) position: vec3<f32>,
+    @location(1) color: vec3<f32>,
+    @location(2) uv: vec2<f32>,
+    @location(3) species_id: f32,  // 0=Disco, 1=Quantum, 2=Hypno, 3=Fractal, 4=BassDrop
+    @location(4) consciousness: f32,
+    @location(5) trip_intensity: f32,
+}
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) color: vec3<f32>,
+    @location(1) uv: vec2<f32>,
+    @location(2) world_pos: vec2<f32>,
+    @location(3) species_id: f32,
+    @location(4) consciousness: f32,
+    @location(5) trip_intensity: f32,
+}
+
+struct Uniforms {
+    time: f32,
+    reality_distortion: f32,
+    consciousness_level: f32,
+    beat_intensity: f32,
+    screen_resolution: vec2<f32>,
+    beat_frequency: f32,
+    cosmic_phase: f32,
+}
+
+@group(0) @binding(0)
+var<uniform> uniforms: Uniforms;
+
+@vertex
+fn vs_main(input: VertexInput) -> VertexOutput {
+    var out: VertexOutput;
+    out.clip_position = vec4<f32>(input.position.xy, input.position.z, 1.0);
+    out.color = input.color;
+    out.uv = input.uv;
+    out.world_pos = input.position.xy;
+    out.species_id = input.species_id;
+    out.consciousness = input.consciousness;
+    out.trip_intensity = input.trip_intensity;
+    return out;
+}
+
+// HUD text and other flat fills carry the same uv on every corner; they stay solid
+fn is_flat(uv: vec2<f32>) -> bool {
+    let variation = fwidth(uv);
+    return variation.x + variation.y < 1e-5;
+}
+
+@fragment
+fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
+    if (is_flat(input.uv)) {
+        return vec4<f32>(input.color, 1.0);
+    }
+
+    // Distance to the nearest quad edge in uv space, widened with consciousness
+    let edge = min(min(input.uv.x, input.uv.y), min(1.0 - input.uv.x, 1.0 - input.uv.y));
+    let width = 0.04 + clamp(input.consciousness, 0.0, 2.0) * 0.02;
+    let tube = 1.0 - smoothstep(0.0, width, edge);
+    let halo = (1.0 - smoothstep(0.0, width * 4.0, edge)) * 0.35;
+
+    // Saturate toward the brightest channel so every species reads as neon
+    let peak = max(max(input.color.r, input.color.g), max(input.color.b, 0.001));
+    let neon = clamp(input.color / peak, vec3<f32>(0.0), vec3<f32>(1.0));
+
+    // Slow hum along the tube, well under any flicker rate
+    let hum = 0.9 + 0.1 * sin(uniforms.time * 1.5 + input.world_pos.x * 4.0 + input.species_id);
+    let glow = tube + halo;
+    let color = mix(neon * 0.6, vec3<f32>(1.0), tube * 0.3) * glow * hum;
+
+    return vec4<f32>(color, clamp(glow, 0.0, 1.0));
+}
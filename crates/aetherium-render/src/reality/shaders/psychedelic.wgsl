@@ -34,6 +34,17 @@ struct Uniforms {
 @group(0) @binding(0)
 var<uniform> uniforms: Uniforms;
 
+// Noise-grown species skins, one tile per species id side by side (see rendering/skins.rs)
+@group(0) @binding(1)
+var skin_atlas: texture_2d<f32>;
+@group(0) @binding(2)
+var skin_sampler: sampler;
+
+const SKIN_SLOTS: f32 = 5.0;
+const SKIN_TILE_SIZE: f32 = 128.0;
+// Llama bodies carry this fraction on their species id (SKINNED_SPECIES in reality/mod.rs)
+const SKINNED_SPECIES: f32 = 0.25;
+
 @vertex
 fn vs_main(input: VertexInput) -> VertexOutput {
     var out: VertexOutput;
@@ -131,6 +142,9 @@ fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
     // SPECIES-SPECIFIC VISUAL MADNESS
     color = apply_species_specific_effects(color, input.uv, input.world_pos, input.species_id, input.consciousness, input.trip_intensity);
 
+    // Animated surface detail from the species' skin
+    color = apply_species_skin(color, input.uv, input.species_id, input.trip_intensity);
+
     // Global psychedelic effects layer
     color = apply_global_psychedelic_layer(color, input.uv, input.world_pos);
 
@@ -332,8 +346,8 @@ fn apply_bass_drop_effects(base_color: vec3<f32>, uv: vec2<f32>, world_pos: vec2
     let spectrum_height = sin(freq_x * 20.0 + uniforms.time * 8.0) * uniforms.beat_intensity * trip_intensity;
     let spectrum_bar = step(1.0 - uv.y, spectrum_height);
 
-    // Beat-reactive strobe
-    let strobe_intensity = step(0.8, sin(uniforms.time * uniforms.beat_frequency * 4.0)) * uniforms.beat_intensity;
+    // Beat-reactive swell: eased rather than stepped so it never strobes
+    let strobe_intensity = smoothstep(0.5, 1.0, sin(uniforms.time * uniforms.beat_frequency)) * uniforms.beat_intensity;
 
     // Sub-bass rumble effect
     let rumble_pattern = sin(world_pos.x * 0.005 + world_pos.y * 0.003 + uniforms.time * 2.0);
@@ -342,11 +356,34 @@ fn apply_bass_drop_effects(base_color: vec3<f32>, uv: vec2<f32>, world_pos: vec2
     color += vec3<f32>(bass_wave * trip_intensity * 0.3);
     color += vec3<f32>(spectrum_bar * trip_intensity);
     color *= (1.0 + strobe_intensity * 0.5);
-    color = mix(color, vec3<f32>(1.0, 0.0, 0.0), rumble_intensity * 0.2);
+    // Rumble leans crimson-magenta, never pure red, to stay clear of red-flash protection
+    color = mix(color, vec3<f32>(0.85, 0.35, 0.55), clamp(rumble_intensity, 0.0, 1.0) * 0.2);
 
     return color;
 }
 
+fn apply_species_skin(base_color: vec3<f32>, uv: vec2<f32>, species_id: f32, trip_intensity: f32) -> vec3<f32> {
+    if (fract(species_id) < SKINNED_SPECIES * 0.5) {
+        return base_color;
+    }
+    // The lookup swirls slowly around the body's center and drifts, faster while tripping
+    let slot = clamp(floor(species_id), 0.0, SKIN_SLOTS - 1.0);
+    let angle = uniforms.time * (0.15 + trip_intensity * 0.2) + slot;
+    let rotation = mat2x2<f32>(cos(angle), sin(angle), -sin(angle), cos(angle));
+    let drift = vec2<f32>(uniforms.time * 0.04, uniforms.time * 0.025);
+    let tile_uv = fract(rotation * (uv - 0.5) * 1.5 + 0.5 + drift);
+
+    // Stay half a texel inside the tile so filtering never bleeds in a neighbor's skin
+    let inset = 0.5 / SKIN_TILE_SIZE;
+    let atlas_uv = vec2<f32>((slot + clamp(tile_uv.x, inset, 1.0 - inset)) / SKIN_SLOTS, tile_uv.y);
+    let skin = textureSampleLevel(skin_atlas, skin_sampler, atlas_uv, 0.0);
+
+    // Patterned in the skin's colors, never brighter than the vertex color already was
+    let brightness = max(base_color.r, max(base_color.g, base_color.b));
+    let textured = mix(base_color, skin.rgb * brightness, 0.4);
+    return mix(base_color, textured, skin.a);
+}
+
 // =============================================================================
 // GLOBAL PSYCHEDELIC EFFECT FUNCTIONS
 // =============================================================================
@@ -0,0 +1,89 @@
+// SOFT WATERCOLOR THEME
+// Pastel washes with feathered edges and paper grain. Motion is a slow drift,
+// nothing pulses with the beat.
+
+struct VertexInput {
+    @location(0) position: vec3<f32>,
+    @location(1) color: vec3<f32>,
+    @location(2) uv: vec2<f32>,
+    @location(3) species_id: f32,  // 0=Disco, 1=Quantum, 2=Hypno, 3=Fractal, 4=BassDrop
+    @location(4) consciousness: f32,
+    @location(5) trip_intensity: f32,
+}
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) color: vec3<f32>,
+    @location(1) uv: vec2<f32>,
+    @location(2) world_pos: vec2<f32>,
+    @location(3) species_id: f32,
+    @location(4) consciousness: f32,
+    @location(5) trip_intensity: f32,
+}
+
+struct Uniforms {
+    time: f32,
+    reality_distortion: f32,
+    consciousness_level: f32,
+    beat_intensity: f32,
+    screen_resolution: vec2<f32>,
+    beat_frequency: f32,
+    cosmic_phase: f32,
+}
+
+@group(0) @binding(0)
+var<uniform> uniforms: Uniforms;
+
+@vertex
+fn vs_main(input: VertexInput) -> VertexOutput {
+    var out: VertexOutput;
+
+    // Pigment bleeds outward slowly, each species at its own pace
+    let drift = vec2<f32>(
+        sin(input.position.y * 3.0 + uniforms.time * 0.4 + input.species_id),
+        cos(input.position.x * 3.0 + uniforms.time * 0.3)
+    ) * 0.004 * clamp(input.trip_intensity, 0.0, 1.0);
+
+    out.clip_position = vec4<f32>(input.position.xy + drift, input.position.z, 1.0);
+    out.color = input.color;
+    out.uv = input.uv;
+    out.world_pos = input.position.xy;
+    out.species_id = input.species_id;
+    out.consciousness = input.consciousness;
+    out.trip_intensity = input.trip_intensity;
+    return out;
+}
+
+// HUD text and other flat fills carry the same uv on every corner; they stay solid
+fn is_flat(uv: vec2<f32>) -> bool {
+    let variation = fwidth(uv);
+    return variation.x + variation.y < 1e-5;
+}
+
+fn hash(p: vec2<f32>) -> f32 {
+    return fract(sin(dot(p, vec2<f32>(127.1, 311.7))) * 43758.5453);
+}
+
+fn paper_grain(world_pos: vec2<f32>) -> f32 {
+    let cell = world_pos * uniforms.screen_resolution * 0.25;
+    let fibre = hash(floor(cell)) * 0.6 + hash(floor(cell * 0.37)) * 0.4;
+    return 0.9 + fibre * 0.1;
+}
+
+@fragment
+fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
+    let grain = paper_grain(input.world_pos);
+    if (is_flat(input.uv)) {
+        return vec4<f32>(input.color * grain, 1.0);
+    }
+
+    // Feathered wash: dense in the middle, pooling pigment just inside the edge
+    let from_center = length(input.uv - vec2<f32>(0.5)) * 2.0;
+    let wash = 1.0 - smoothstep(0.45, 1.0, from_center);
+    let pooling = smoothstep(0.55, 0.85, from_center) * (1.0 - smoothstep(0.85, 1.0, from_center)) * 0.25;
+
+    let pastel = mix(input.color, vec3<f32>(0.95, 0.93, 0.88), 0.35);
+    let color = (pastel * (1.0 - pooling) + input.color * pooling) * grain;
+
+    return vec4<f32>(color, (wash + pooling) * 0.7);
+}
@@ -0,0 +1,85 @@
+// VECTOR FIELD THEME
+// Every primitive is drawn as streaks following a slowly turning flow field,
+// tinted by the local flow direction.
+
+struct VertexInput {
+    @location(0) position: vec3<f32>,
+    @location(1) color: vec3<f32>,
+    @location(2) uv: vec2<f32>,
+    @location(3) species_id: f32,  // 0=Disco, 1=Quantum, 2=Hypno, 3=Fractal, 4=BassDrop
+    @location(4) consciousness: f32,
+    @location(5) trip_intensity: f32,
+}
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) color: vec3<f32>,
+    @location(1) uv: vec2<f32>,
+    @location(2) world_pos: vec2<f32>,
+    @location(3) species_id: f32,
+    @location(4) consciousness: f32,
+    @location(5) trip_intensity: f32,
+}
+
+struct Uniforms {
+    time: f32,
+    reality_distortion: f32,
+    consciousness_level: f32,
+    beat_intensity: f32,
+    screen_resolution: vec2<f32>,
+    beat_frequency: f32,
+    cosmic_phase: f32,
+}
+
+@group(0) @binding(0)
+var<uniform> uniforms: Uniforms;
+
+@vertex
+fn vs_main(input: VertexInput) -> VertexOutput {
+    var out: VertexOutput;
+    out.clip_position = vec4<f32>(input.position.xy, input.position.z, 1.0);
+    out.color = input.color;
+    out.uv = input.uv;
+    out.world_pos = input.position.xy;
+    out.species_id = input.species_id;
+    out.consciousness = input.consciousness;
+    out.trip_intensity = input.trip_intensity;
+    return out;
+}
+
+// HUD text and other flat fills carry the same uv on every corner; they stay solid
+fn is_flat(uv: vec2<f32>) -> bool {
+    let variation = fwidth(uv);
+    return variation.x + variation.y < 1e-5;
+}
+
+// Smooth, divergence-free-looking flow that turns over tens of seconds
+fn flow_angle(p: vec2<f32>) -> f32 {
+    let t = uniforms.time * 0.15;
+    return sin(p.x * 2.3 + t) * 1.7 + cos(p.y * 1.9 - t * 0.8) * 1.3 + sin((p.x + p.y) * 1.1 + t * 0.5);
+}
+
+@fragment
+fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
+    if (is_flat(input.uv)) {
+        return vec4<f32>(input.color, 1.0);
+    }
+
+    let angle = flow_angle(input.world_pos);
+    let direction = vec2<f32>(cos(angle), sin(angle));
+    let across = vec2<f32>(-direction.y, direction.x);
+
+    // Streaks run along the flow; spacing tightens with trip intensity
+    let density = 60.0 + clamp(input.trip_intensity, 0.0, 1.0) * 40.0;
+    let stripe = fract(dot(input.world_pos, across) * density);
+    let streak = 1.0 - smoothstep(0.08, 0.22, abs(stripe - 0.5));
+
+    // Fade toward the primitive's edge so shapes stay readable
+    let from_center = length(input.uv - vec2<f32>(0.5)) * 2.0;
+    let body = 1.0 - smoothstep(0.7, 1.0, from_center);
+
+    let direction_tint = vec3<f32>(0.5) + 0.5 * vec3<f32>(direction.x, direction.y, -direction.x);
+    let color = mix(input.color, direction_tint, 0.3) * (0.35 + streak * 0.65);
+
+    return vec4<f32>(color, body * (0.4 + streak * 0.6));
+}
@@ -0,0 +1,177 @@
+// === BUILT-IN BITMAP TEXT ===
+// Tiny 5x7 uppercase bitmap font rendered as solid quads, so start-up screens
+// can show text without pulling in a font rasterizer. Lowercase input is
+// drawn uppercase; unknown characters fall back to '?'.
+
+use glam::{Vec2, Vec3};
+use super::Vertex;
+
+pub const GLYPH_WIDTH: usize = 5;
+pub const GLYPH_HEIGHT: usize = 7;
+/// Horizontal cells per character including one column of spacing
+pub const GLYPH_ADVANCE: usize = 6;
+
+/// Glyph rows top to bottom, bit 4 = leftmost column
+const GLYPHS: &[(char, [u8; GLYPH_HEIGHT])] = &[
+    ('A', [0b01110, 0b10001, 0b10001, 0b11111, 0b10001, 0b10001, 0b10001]),
+    ('B', [0b11110, 0b10001, 0b10001, 0b11110, 0b10001, 0b10001, 0b11110]),
+    ('C', [0b01110, 0b10001, 0b10000, 0b10000, 0b10000, 0b10001, 0b01110]),
+    ('D', [0b11110, 0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b11110]),
+    ('E', [0b11111, 0b10000, 0b10000, 0b11110, 0b10000, 0b10000, 0b11111]),
+    ('F', [0b11111, 0b10000, 0b10000, 0b11110, 0b10000, 0b10000, 0b10000]),
+    ('G', [0b01110, 0b10001, 0b10000, 0b10111, 0b10001, 0b10001, 0b01111]),
+    ('H', [0b10001, 0b10001, 0b10001, 0b11111, 0b10001, 0b10001, 0b10001]),
+    ('I', [0b01110, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0b01110]),
+    ('J', [0b00111, 0b00010, 0b00010, 0b00010, 0b00010, 0b10010, 0b01100]),
+    ('K', [0b10001, 0b10010, 0b10100, 0b11000, 0b10100, 0b10010, 0b10001]),
+    ('L', [0b10000, 0b10000, 0b10000, 0b10000, 0b10000, 0b10000, 0b11111]),
+    ('M', [0b10001, 0b11011, 0b10101, 0b10101, 0b10001, 0b10001, 0b10001]),
+    ('N', [0b10001, 0b10001, 0b11001, 0b10101, 0b10011, 0b10001, 0b10001]),
+    ('O', [0b01110, 0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b01110]),
+    ('P', [0b11110, 0b10001, 0b10001, 0b11110, 0b10000, 0b10000, 0b10000]),
+    ('Q', [0b01110, 0b10001, 0b10001, 0b10001, 0b10101, 0b10010, 0b01101]),
+    ('R', [0b11110, 0b10001, 0b10001, 0b11110, 0b10100, 0b10010, 0b10001]),
+    ('S', [0b01111, 0b10000, 0b10000, 0b01110, 0b00001, 0b00001, 0b11110]),
+    ('T', [0b11111, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100]),
+    ('U', [0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b01110]),
+    ('V', [0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b01010, 0b00100]),
+    ('W', [0b10001, 0b10001, 0b10001, 0b10101, 0b10101, 0b10101, 0b01010]),
+    ('X', [0b10001, 0b10001, 0b01010, 0b00100, 0b01010, 0b10001, 0b10001]),
+    ('Y', [0b10001, 0b10001, 0b10001, 0b01010, 0b00100, 0b00100, 0b00100]),
+    ('Z', [0b11111, 0b00001, 0b00010, 0b00100, 0b01000, 0b10000, 0b11111]),
+    ('0', [0b01110, 0b10001, 0b10011, 0b10101, 0b11001, 0b10001, 0b01110]),
+    ('1', [0b00100, 0b01100, 0b00100, 0b00100, 0b00100, 0b00100, 0b01110]),
+    ('2', [0b01110, 0b10001, 0b00001, 0b00010, 0b00100, 0b01000, 0b11111]),
+    ('3', [0b11111, 0b00010, 0b00100, 0b00010, 0b00001, 0b10001, 0b01110]),
+    ('4', [0b00010, 0b00110, 0b01010, 0b10010, 0b11111, 0b00010, 0b00010]),
+    ('5', [0b11111, 0b10000, 0b11110, 0b00001, 0b00001, 0b10001, 0b01110]),
+    ('6', [0b00110, 0b01000, 0b10000, 0b11110, 0b10001, 0b10001, 0b01110]),
+    ('7', [0b11111, 0b00001, 0b00010, 0b00100, 0b01000, 0b01000, 0b01000]),
+    ('8', [0b01110, 0b10001, 0b10001, 0b01110, 0b10001, 0b10001, 0b01110]),
+    ('9', [0b01110, 0b10001, 0b10001, 0b01111, 0b00001, 0b00010, 0b01100]),
+    (' ', [0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b00000]),
+    ('.', [0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b01100, 0b01100]),
+    (',', [0b00000, 0b00000, 0b00000, 0b00000, 0b01100, 0b00100, 0b01000]),
+    ('-', [0b00000, 0b00000, 0b00000, 0b11111, 0b00000, 0b00000, 0b00000]),
+    ('!', [0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0b00000, 0b00100]),
+    ('?', [0b01110, 0b10001, 0b00001, 0b00010, 0b00100, 0b00000, 0b00100]),
+    (':', [0b00000, 0b01100, 0b01100, 0b00000, 0b01100, 0b01100, 0b00000]),
+    ('\'', [0b00100, 0b00100, 0b01000, 0b00000, 0b00000, 0b00000, 0b00000]),
+    ('(', [0b00010, 0b00100, 0b01000, 0b01000, 0b01000, 0b00100, 0b00010]),
+    (')', [0b01000, 0b00100, 0b00010, 0b00010, 0b00010, 0b00100, 0b01000]),
+    ('/', [0b00000, 0b00001, 0b00010, 0b00100, 0b01000, 0b10000, 0b00000]),
+    ('[', [0b01110, 0b01000, 0b01000, 0b01000, 0b01000, 0b01000, 0b01110]),
+    (']', [0b01110, 0b00010, 0b00010, 0b00010, 0b00010, 0b00010, 0b01110]),
+    ('%', [0b11000, 0b11001, 0b00010, 0b00100, 0b01000, 0b10011, 0b00011]),
+];
+
+fn glyph_rows(c: char) -> [u8; GLYPH_HEIGHT] {
+    let upper = c.to_ascii_uppercase();
+    GLYPHS.iter()
+        .find(|(glyph, _)| *glyph == upper)
+        .or_else(|| GLYPHS.iter().find(|(glyph, _)| *glyph == '?'))
+        .map(|(_, rows)| *rows)
+        .unwrap_or([0; GLYPH_HEIGHT])
+}
+
+/// Width in screen pixels of a single line of text
+pub fn text_width(text: &str, pixel_size: f32) -> f32 {
+    let chars = text.chars().count();
+    if chars == 0 {
+        return 0.0;
+    }
+    (chars * GLYPH_ADVANCE - 1) as f32 * pixel_size
+}
+
+/// Height in screen pixels of a single line of text
+pub fn text_height(pixel_size: f32) -> f32 {
+    GLYPH_HEIGHT as f32 * pixel_size
+}
+
+/// Push a solid rectangle given in screen pixels (origin top-left)
+pub fn push_rect(vertices: &mut Vec<Vertex>, min: Vec2, max: Vec2, color: Vec3, screen_size: Vec2) {
+    let to_ndc = |p: Vec2| [p.x / screen_size.x * 2.0 - 1.0, 1.0 - p.y / screen_size.y * 2.0, 0.0];
+    let corner = |p: Vec2| Vertex {
+        position: to_ndc(p),
+        color: color.into(),
+        uv: [0.5, 0.5],
+        species_id: 0.0,
+        consciousness: 0.0,
+        trip_intensity: 0.0,
+    };
+
+    let top_right = Vec2::new(max.x, min.y);
+    let bottom_left = Vec2::new(min.x, max.y);
+    vertices.extend([
+        corner(min), corner(top_right), corner(bottom_left),
+        corner(top_right), corner(max), corner(bottom_left),
+    ]);
+}
+
+/// Push a line of text with its top-left corner at `origin` (screen pixels)
+pub fn push_text(vertices: &mut Vec<Vertex>, text: &str, origin: Vec2, pixel_size: f32, color: Vec3, screen_size: Vec2) {
+    for (index, c) in text.chars().enumerate() {
+        let glyph_x = origin.x + (index * GLYPH_ADVANCE) as f32 * pixel_size;
+
+        for (row, bits) in glyph_rows(c).iter().enumerate() {
+            let y = origin.y + row as f32 * pixel_size;
+
+            // Merge horizontal runs of lit pixels into single quads
+            let mut column = 0;
+            while column < GLYPH_WIDTH {
+                if bits & (1 << (GLYPH_WIDTH - 1 - column)) == 0 {
+                    column += 1;
+                    continue;
+                }
+                let run_start = column;
+                while column < GLYPH_WIDTH && bits & (1 << (GLYPH_WIDTH - 1 - column)) != 0 {
+                    column += 1;
+                }
+                push_rect(
+                    vertices,
+                    Vec2::new(glyph_x + run_start as f32 * pixel_size, y),
+                    Vec2::new(glyph_x + column as f32 * pixel_size, y + pixel_size),
+                    color,
+                    screen_size,
+                );
+            }
+        }
+    }
+}
+
+/// Push a line of text horizontally centered on `center_x`
+pub fn push_text_centered(vertices: &mut Vec<Vertex>, text: &str, center_x: f32, top: f32, pixel_size: f32, color: Vec3, screen_size: Vec2) {
+    let origin = Vec2::new(center_x - text_width(text, pixel_size) / 2.0, top);
+    push_text(vertices, text, origin, pixel_size, color, screen_size);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_text_quads_and_centering() {
+        let screen = Vec2::new(200.0, 100.0);
+        assert_eq!(text_width("", 2.0), 0.0);
+        assert_eq!(text_width("HOH", 2.0), 34.0);
+        assert_eq!(text_height(2.0), 14.0);
+
+        // 'I' lights one run in each of its seven rows, 'L' too: fourteen quads
+        let mut vertices = Vec::new();
+        push_text(&mut vertices, "IL", Vec2::ZERO, 2.0, Vec3::ONE, screen);
+        assert_eq!(vertices.len(), 14 * 6);
+        assert_eq!(vertices[0].position, [-1.0 + 2.0 * 2.0 / 200.0, 1.0, 0.0], "the top row starts one column in");
+
+        // Lowercase draws the same as uppercase
+        let mut lower = Vec::new();
+        push_text(&mut lower, "il", Vec2::ZERO, 2.0, Vec3::ONE, screen);
+        assert_eq!(lower.len(), vertices.len());
+
+        // "HOH" is inked edge to edge, so centering on the middle of the screen balances it around x = 0
+        vertices.clear();
+        push_text_centered(&mut vertices, "HOH", 100.0, 10.0, 2.0, Vec3::ONE, screen);
+        let xs = vertices.iter().map(|vertex| vertex.position[0]);
+        let (left, right) = xs.fold((f32::MAX, f32::MIN), |(left, right), x| (left.min(x), right.max(x)));
+        assert!((left + right).abs() < 1e-5 && right > 0.0);
+    }
+}
@@ -0,0 +1,185 @@
+// === VISUAL THEMES ===
+// Alternative looks for long-running installations. Every theme is a WGSL
+// shader with the same vertex layout, uniforms and entry points, so switching
+// only swaps the pipeline; the vertex data built each frame is shared.
+
+use wgpu::*;
+use super::Vertex;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VisualTheme {
+    #[default]
+    Psychedelic,
+    NeonWireframe,
+    SoftWatercolor,
+    CrtScanline,
+    VectorField,
+}
+
+impl VisualTheme {
+    /// Every theme, in hotkey cycling order
+    pub const ALL: [VisualTheme; 5] = [
+        Self::Psychedelic,
+        Self::NeonWireframe,
+        Self::SoftWatercolor,
+        Self::CrtScanline,
+        Self::VectorField,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Psychedelic => "PSYCHEDELIC",
+            Self::NeonWireframe => "NEON WIREFRAME",
+            Self::SoftWatercolor => "SOFT WATERCOLOR",
+            Self::CrtScanline => "CRT SCANLINE",
+            Self::VectorField => "VECTOR FIELD",
+        }
+    }
+
+    /// File name in `src/reality/shaders`
+    pub fn shader_file(self) -> &'static str {
+        match self {
+            Self::Psychedelic => "psychedelic.wgsl",
+            Self::NeonWireframe => "neon_wireframe.wgsl",
+            Self::SoftWatercolor => "soft_watercolor.wgsl",
+            Self::CrtScanline => "crt_scanline.wgsl",
+            Self::VectorField => "vector_field.wgsl",
+        }
+    }
+
+    /// Shader source compiled into the binary
+    pub fn shader_source(self) -> &'static str {
+        match self {
+            Self::Psychedelic => include_str!("shaders/psychedelic.wgsl"),
+            Self::NeonWireframe => include_str!("shaders/neon_wireframe.wgsl"),
+            Self::SoftWatercolor => include_str!("shaders/soft_watercolor.wgsl"),
+            Self::CrtScanline => include_str!("shaders/crt_scanline.wgsl"),
+            Self::VectorField => include_str!("shaders/vector_field.wgsl"),
+        }
+    }
+
+    pub fn from_shader_file(file: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|theme| theme.shader_file() == file)
+    }
+
+    pub fn next(self) -> Self {
+        Self::ALL[(self.index() + 1) % Self::ALL.len()]
+    }
+
+    fn index(self) -> usize {
+        Self::ALL.iter().position(|&theme| theme == self).unwrap_or(0)
+    }
+}
+
+/// Build a theme's render pipeline from WGSL source against the shared layout
+pub fn create_theme_pipeline(device: &Device, layout: &PipelineLayout, format: TextureFormat, theme: VisualTheme, shader_source: &str) -> RenderPipeline {
+    let shader = device.create_shader_module(ShaderModuleDescriptor {
+        label: Some(theme.shader_file()),
+        source: ShaderSource::Wgsl(shader_source.into()),
+    });
+
+    device.create_render_pipeline(&RenderPipelineDescriptor {
+        label: Some(theme.name()),
+        layout: Some(layout),
+        vertex: VertexState {
+            module: &shader,
+            entry_point: "vs_main",
+            buffers: &[Vertex::desc()],
+            compilation_options: Default::default(),
+        },
+        fragment: Some(FragmentState {
+            module: &shader,
+            entry_point: "fs_main",
+            targets: &[Some(ColorTargetState {
+                format,
+                blend: Some(BlendState::ALPHA_BLENDING),
+                write_mask: ColorWrites::ALL,
+            })],
+            compilation_options: Default::default(),
+        }),
+        primitive: PrimitiveState::default(),
+        depth_stencil: None,
+        multisample: MultisampleState::default(),
+        multiview: None,
+    })
+}
+
+/// One render pipeline per theme plus the one currently on screen
+pub struct ThemePipelines {
+    layout: PipelineLayout,
+    format: TextureFormat,
+    pipelines: Vec<RenderPipeline>, // Indexed like VisualTheme::ALL
+    active: VisualTheme,
+}
+
+impl ThemePipelines {
+    pub fn new(device: &Device, layout: PipelineLayout, format: TextureFormat, active: VisualTheme) -> Self {
+        let pipelines = VisualTheme::ALL.iter()
+            .map(|&theme| create_theme_pipeline(device, &layout, format, theme, theme.shader_source()))
+            .collect();
+
+        Self { layout, format, pipelines, active }
+    }
+
+    pub fn active(&self) -> VisualTheme {
+        self.active
+    }
+
+    pub fn set_active(&mut self, theme: VisualTheme) {
+        self.active = theme;
+    }
+
+    /// Switch to the next theme and return it
+    pub fn cycle(&mut self) -> VisualTheme {
+        self.active = self.active.next();
+        self.active
+    }
+
+    /// Pipeline for the active theme
+    pub fn pipeline(&self) -> &RenderPipeline {
+        &self.pipelines[self.active.index()]
+    }
+
+    /// Compile a replacement pipeline for `theme` without installing it
+    pub fn build(&self, device: &Device, theme: VisualTheme, shader_source: &str) -> RenderPipeline {
+        create_theme_pipeline(device, &self.layout, self.format, theme, shader_source)
+    }
+
+    pub fn replace(&mut self, theme: VisualTheme, pipeline: RenderPipeline) {
+        self.pipelines[theme.index()] = pipeline;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cycle_visits_every_theme() {
+        let mut theme = VisualTheme::default();
+        let mut seen = Vec::new();
+        for _ in 0..VisualTheme::ALL.len() {
+            seen.push(theme);
+            assert_eq!(VisualTheme::from_shader_file(theme.shader_file()), Some(theme));
+            theme = theme.next();
+        }
+        assert_eq!(theme, VisualTheme::default());
+        assert_eq!(seen, VisualTheme::ALL);
+    }
+
+    #[test]
+    fn test_theme_shaders_validate() {
+        for theme in VisualTheme::ALL {
+            let module = naga::front::wgsl::parse_str(theme.shader_source())
+                .unwrap_or_else(|e| panic!("{}: {}", theme.shader_file(), e.emit_to_string(theme.shader_source())));
+            naga::valid::Validator::new(naga::valid::ValidationFlags::all(), naga::valid::Capabilities::empty())
+                .validate(&module)
+                .unwrap_or_else(|e| panic!("{}: {:?}", theme.shader_file(), e));
+
+            for entry_point in ["vs_main", "fs_main"] {
+                assert!(module.entry_points.iter().any(|ep| ep.name == entry_point),
+                        "{} is missing {}", theme.shader_file(), entry_point);
+            }
+        }
+    }
+}
@@ -0,0 +1,312 @@
+// === REFRACTIVE CRYSTALS ===
+// Consciousness crystals get their own sub-pass instead of flat diamonds.
+// The world is drawn to a backdrop texture first; this pass copies it to the
+// target and draws each crystal as a faceted lens that bends the backdrop
+// behind it, with facets glinting in turn at the crystal's resonance. Every
+// crystal type has its own refraction, facet count and sparkle. Glints are
+// capped well below the flash-rate limit and scaled by the intensity limit.
+
+use glam::{Vec2, Vec3};
+use wgpu::*;
+use aetherium_sim::engine::SafetyConfig;
+use aetherium_sim::simulation::{ConsciousnessCrystal, CrystalType};
+use super::viewport::{Viewport, WORLD_SIZE};
+
+/// Brightest a glint adds at full sparkle and intensity
+const GLINT_GAIN: f32 = 0.45;
+/// Crystals the instance buffer holds before it has to grow
+const INITIAL_CAPACITY: usize = 64;
+
+/// How one crystal type bends and catches the light
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CrystalLook {
+    pub refraction: f32, // How far the backdrop is bent, as a share of the crystal's size
+    pub facets: f32,     // Facets around the diamond, each glinting in turn
+    pub sparkle: f32,    // Glint strength, 0-1
+}
+
+impl CrystalLook {
+    /// Resonance crystals are clear lenses, chaos crystals shatter light into many
+    /// glinting shards, memory crystals are milky, social ones warm and quantum ones
+    /// bend space hardest
+    pub fn for_type(crystal_type: &CrystalType) -> Self {
+        match crystal_type {
+            CrystalType::Resonance => Self { refraction: 0.5, facets: 6.0, sparkle: 0.6 },
+            CrystalType::Chaos => Self { refraction: 0.7, facets: 12.0, sparkle: 1.0 },
+            CrystalType::Memory => Self { refraction: 0.25, facets: 4.0, sparkle: 0.3 },
+            CrystalType::Social => Self { refraction: 0.4, facets: 8.0, sparkle: 0.7 },
+            CrystalType::Quantum => Self { refraction: 1.0, facets: 5.0, sparkle: 0.5 },
+        }
+    }
+}
+
+/// Uniform data matching `crystal.wgsl`
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct CrystalUniforms {
+    pub screen: [f32; 2],
+    pub intensity: f32,
+    pub glint_gain: f32,
+}
+
+impl CrystalUniforms {
+    pub fn new(width: u32, height: u32, safety: &SafetyConfig) -> Self {
+        Self {
+            screen: [width.max(1) as f32, height.max(1) as f32],
+            intensity: safety.visual_intensity_limit.clamp(0.0, 1.0),
+            glint_gain: GLINT_GAIN,
+        }
+    }
+}
+
+/// One crystal as the shader draws it
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct CrystalInstance {
+    pub center: [f32; 2], // Clip space
+    pub size: [f32; 2],   // Half extents in clip space
+    pub color: [f32; 3],
+    pub energy: f32,
+    pub look: [f32; 4],   // Glint phase in cycles, refraction, facets, sparkle
+}
+
+impl CrystalInstance {
+    /// `crystal` as seen through `viewport` at `time`, in its already safety-checked `color`
+    pub fn new(crystal: &ConsciousnessCrystal, color: Vec3, viewport: &Viewport, safety: &SafetyConfig, time: f32) -> Self {
+        let clip = [crystal.position.x / WORLD_SIZE.x * 2.0 - 1.0, 1.0 - crystal.position.y / WORLD_SIZE.y * 2.0, 0.0];
+        let center = viewport.project(clip);
+        let zoom = WORLD_SIZE / (viewport.max - viewport.min).max(Vec2::ONE);
+        let half = (8.0 + crystal.visual_intensity * 12.0) / WORLD_SIZE.x;
+
+        // Glints follow the resonance that pulses the crystal, never faster than a quarter of the flash limit
+        let glint_hz = (crystal.resonance_frequency * 0.1 / std::f32::consts::TAU).min(safety.max_flash_rate * 0.25);
+        let look = CrystalLook::for_type(&crystal.crystal_type);
+        Self {
+            center: [center[0], center[1]],
+            size: (Vec2::splat(half) * zoom).to_array(),
+            color: color.to_array(),
+            energy: crystal.consciousness_energy,
+            look: [(time * glint_hz).fract(), look.refraction, look.facets, look.sparkle],
+        }
+    }
+}
+
+struct Backdrop {
+    view: TextureView,
+    bind_group: BindGroup,
+    size: (u32, u32),
+}
+
+/// Backdrop texture plus the pipelines that copy it and draw the crystals over it
+pub struct CrystalPass {
+    blit_pipeline: RenderPipeline,
+    crystal_pipeline: RenderPipeline,
+    layout: BindGroupLayout,
+    sampler: Sampler,
+    uniform_buffer: Buffer,
+    instances: Buffer,
+    capacity: usize,
+    count: u32,
+    format: TextureFormat,
+    backdrop: Option<Backdrop>, // Created on first use and on resize
+}
+
+impl CrystalPass {
+    pub fn new(device: &Device, format: TextureFormat) -> Self {
+        let shader = device.create_shader_module(ShaderModuleDescriptor {
+            label: Some("crystal.wgsl"),
+            source: ShaderSource::Wgsl(include_str!("../reality/shaders/crystal.wgsl").into()),
+        });
+
+        let layout = device.create_bind_group_layout(&BindGroupLayoutDescriptor {
+            label: Some("Crystal Bind Group Layout"),
+            entries: &[
+                BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: ShaderStages::FRAGMENT,
+                    ty: BindingType::Texture {
+                        sample_type: TextureSampleType::Float { filterable: true },
+                        view_dimension: TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: ShaderStages::FRAGMENT,
+                    ty: BindingType::Sampler(SamplerBindingType::Filtering),
+                    count: None,
+                },
+                BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: ShaderStages::FRAGMENT,
+                    ty: BindingType::Buffer {
+                        ty: BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&PipelineLayoutDescriptor {
+            label: Some("Crystal Pipeline Layout"),
+            bind_group_layouts: &[&layout],
+            push_constant_ranges: &[],
+        });
+
+        let pipeline = |label: &str, vertex: &str, fragment: &str, buffers: &[VertexBufferLayout]| {
+            device.create_render_pipeline(&RenderPipelineDescriptor {
+                label: Some(label),
+                layout: Some(&pipeline_layout),
+                vertex: VertexState {
+                    module: &shader,
+                    entry_point: vertex,
+                    buffers,
+                    compilation_options: Default::default(),
+                },
+                fragment: Some(FragmentState {
+                    module: &shader,
+                    entry_point: fragment,
+                    targets: &[Some(ColorTargetState {
+                        format,
+                        blend: None,
+                        write_mask: ColorWrites::ALL,
+                    })],
+                    compilation_options: Default::default(),
+                }),
+                primitive: PrimitiveState::default(),
+                depth_stencil: None,
+                multisample: MultisampleState::default(),
+                multiview: None,
+            })
+        };
+        let blit_pipeline = pipeline("Crystal Backdrop Pipeline", "vs_blit", "fs_blit", &[]);
+        let crystal_pipeline = pipeline("Crystal Pipeline", "vs_crystal", "fs_crystal", &[VertexBufferLayout {
+            array_stride: std::mem::size_of::<CrystalInstance>() as BufferAddress,
+            step_mode: VertexStepMode::Instance,
+            attributes: &vertex_attr_array![0 => Float32x2, 1 => Float32x2, 2 => Float32x3, 3 => Float32, 4 => Float32x4],
+        }]);
+
+        let sampler = device.create_sampler(&SamplerDescriptor {
+            label: Some("Crystal Sampler"),
+            address_mode_u: AddressMode::ClampToEdge,
+            address_mode_v: AddressMode::ClampToEdge,
+            mag_filter: FilterMode::Linear,
+            min_filter: FilterMode::Linear,
+            ..Default::default()
+        });
+
+        let uniform_buffer = device.create_buffer(&BufferDescriptor {
+            label: Some("Crystal Uniform Buffer"),
+            size: std::mem::size_of::<CrystalUniforms>() as u64,
+            usage: BufferUsages::UNIFORM | BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        Self {
+            blit_pipeline,
+            crystal_pipeline,
+            layout,
+            sampler,
+            uniform_buffer,
+            instances: create_instance_buffer(device, INITIAL_CAPACITY),
+            capacity: INITIAL_CAPACITY,
+            count: 0,
+            format,
+            backdrop: None,
+        }
+    }
+
+    /// Upload this frame's crystals and return the backdrop the world should be drawn into
+    pub fn prepare(&mut self, device: &Device, queue: &Queue, instances: &[CrystalInstance], uniforms: &CrystalUniforms) -> &TextureView {
+        queue.write_buffer(&self.uniform_buffer, 0, bytemuck::bytes_of(uniforms));
+        if instances.len() > self.capacity {
+            self.capacity = instances.len().next_power_of_two();
+            self.instances = create_instance_buffer(device, self.capacity);
+        }
+        queue.write_buffer(&self.instances, 0, bytemuck::cast_slice(instances));
+        self.count = instances.len() as u32;
+
+        let size = (uniforms.screen[0] as u32, uniforms.screen[1] as u32);
+        if self.backdrop.as_ref().is_none_or(|backdrop| backdrop.size != size) {
+            let texture = device.create_texture(&TextureDescriptor {
+                label: Some("Crystal Backdrop Texture"),
+                size: Extent3d { width: size.0, height: size.1, depth_or_array_layers: 1 },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: TextureDimension::D2,
+                format: self.format,
+                usage: TextureUsages::RENDER_ATTACHMENT | TextureUsages::TEXTURE_BINDING,
+                view_formats: &[],
+            });
+            let view = texture.create_view(&TextureViewDescriptor::default());
+            let bind_group = device.create_bind_group(&BindGroupDescriptor {
+                label: Some("Crystal Bind Group"),
+                layout: &self.layout,
+                entries: &[
+                    BindGroupEntry { binding: 0, resource: BindingResource::TextureView(&view) },
+                    BindGroupEntry { binding: 1, resource: BindingResource::Sampler(&self.sampler) },
+                    BindGroupEntry { binding: 2, resource: self.uniform_buffer.as_entire_binding() },
+                ],
+            });
+            self.backdrop = Some(Backdrop { view, bind_group, size });
+        }
+
+        &self.backdrop.as_ref().expect("backdrop was just created").view
+    }
+
+    /// Copy the backdrop to the target and draw the crystals over it; call after `prepare` and the backdrop pass
+    pub fn draw<'pass>(&'pass self, render_pass: &mut RenderPass<'pass>) {
+        let Some(backdrop) = &self.backdrop else { return };
+        render_pass.set_bind_group(0, &backdrop.bind_group, &[]);
+        render_pass.set_pipeline(&self.blit_pipeline);
+        render_pass.draw(0..3, 0..1);
+        if self.count > 0 {
+            render_pass.set_pipeline(&self.crystal_pipeline);
+            render_pass.set_vertex_buffer(0, self.instances.slice(..));
+            render_pass.draw(0..6, 0..self.count);
+        }
+    }
+}
+
+fn create_instance_buffer(device: &Device, capacity: usize) -> Buffer {
+    device.create_buffer(&BufferDescriptor {
+        label: Some("Crystal Instance Buffer"),
+        size: (capacity * std::mem::size_of::<CrystalInstance>()) as u64,
+        usage: BufferUsages::VERTEX | BufferUsages::COPY_DST,
+        mapped_at_creation: false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crystals_glint_within_the_safety_limits() {
+        let mut crystal = ConsciousnessCrystal::new(Vec2::new(600.0, 400.0), CrystalType::Quantum);
+        crystal.resonance_frequency = 400.0; // Far faster than any safe flash rate
+        let safe = SafetyConfig::safe_mode();
+        let instance = CrystalInstance::new(&crystal, Vec3::ONE, &Viewport::full(), &safe, 0.0);
+        assert_eq!(instance.center, [0.0, 0.0]);
+        assert_eq!(instance.look[1..], [1.0, 5.0, 0.5]);
+
+        // One second on, the glint has moved by no more than a quarter of the flash limit
+        let later = CrystalInstance::new(&crystal, Vec3::ONE, &Viewport::full(), &safe, 1.0);
+        assert!((later.look[0] - instance.look[0]).abs() <= safe.max_flash_rate * 0.25 + 1e-6);
+
+        // Zooming in on the crystal makes it bigger
+        let zoomed = Viewport { min: Vec2::new(300.0, 200.0), max: Vec2::new(900.0, 600.0) };
+        assert!(CrystalInstance::new(&crystal, Vec3::ONE, &zoomed, &safe, 0.0).size[0] > instance.size[0]);
+        assert!(CrystalUniforms::new(800, 600, &safe).intensity < CrystalUniforms::new(800, 600, &SafetyConfig::default()).intensity);
+    }
+
+    #[test]
+    fn test_crystal_shader_validates() {
+        crate::rendering::validate_wgsl(include_str!("../reality/shaders/crystal.wgsl"));
+        assert_eq!(std::mem::size_of::<CrystalUniforms>(), 16);
+        assert_eq!(std::mem::size_of::<CrystalInstance>(), 48);
+    }
+}
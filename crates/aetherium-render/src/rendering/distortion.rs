@@ -0,0 +1,244 @@
+// === REALITY DISTORTION PASS ===
+// Bends the rendered world around reality tears. When anything needs warping
+// the world is drawn to an offscreen texture and resampled onto the screen
+// through a distortion field; otherwise frames go straight to the surface.
+// Strength scales with SafetyConfig's intensity limit and the ripple stays far
+// below the flash-rate limit, so distortion moves pixels without strobing.
+
+use glam::Vec2;
+use wgpu::*;
+use aetherium_sim::engine::SafetyConfig;
+use aetherium_sim::simulation::RealityTear;
+use super::viewport::{Viewport, WORLD_SIZE};
+
+/// Tears that bend space at once; the most intense win
+pub const MAX_DISTORTION_WELLS: usize = 8;
+/// Largest displacement a tear causes at full intensity, in uv units
+const MAX_DISPLACEMENT: f32 = 0.04;
+/// Largest whole-screen ripple from emergence, in uv units
+const MAX_SWIRL: f32 = 0.006;
+/// A tear bends space out to this multiple of its size
+const WELL_REACH: f32 = 4.0;
+/// Faded tears no longer bend anything
+const MIN_TEAR_INTENSITY: f32 = 0.2;
+
+/// Uniform data matching `distortion.wgsl`
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct DistortionUniforms {
+    pub wells: [[f32; 4]; MAX_DISTORTION_WELLS], // uv center xy, radius (uv), displacement strength
+    pub well_count: u32,
+    pub time: f32,
+    pub swirl: f32,
+    pub ripple_hz: f32,
+    pub aspect: f32,
+    pub _padding: [f32; 3],
+}
+
+impl DistortionUniforms {
+    /// Distortion field for the current tears and emergence, as seen through `viewport`
+    pub fn build(tears: &[RealityTear], emergence: f32, safety: &SafetyConfig, viewport: &Viewport, aspect: f32, time: f32) -> Self {
+        let limit = safety.visual_intensity_limit.clamp(0.0, 1.0);
+        let zoom = WORLD_SIZE.y / (viewport.max.y - viewport.min.y).max(1.0);
+
+        let mut strongest: Vec<&RealityTear> = tears.iter().filter(|tear| tear.intensity >= MIN_TEAR_INTENSITY).collect();
+        strongest.sort_by(|a, b| b.intensity.total_cmp(&a.intensity));
+
+        let mut uniforms = Self {
+            wells: [[0.0; 4]; MAX_DISTORTION_WELLS],
+            well_count: 0,
+            time,
+            swirl: emergence.clamp(0.0, 1.0) * MAX_SWIRL * limit,
+            ripple_hz: (safety.max_flash_rate * 0.25).min(0.75), // Slow breathing, never a flicker
+            aspect: aspect.max(0.1),
+            _padding: [0.0; 3],
+        };
+        for (well, tear) in uniforms.wells.iter_mut().zip(strongest) {
+            let clip = viewport.project([tear.position.x / WORLD_SIZE.x * 2.0 - 1.0, 1.0 - tear.position.y / WORLD_SIZE.y * 2.0, 0.0]);
+            let center = Vec2::new(clip[0] * 0.5 + 0.5, 0.5 - clip[1] * 0.5);
+            let radius = tear.size * WELL_REACH / WORLD_SIZE.y * zoom; // In vertical uv units, like the shader
+            *well = [center.x, center.y, radius, tear.intensity.min(1.0) * MAX_DISPLACEMENT * limit];
+            uniforms.well_count += 1;
+        }
+        uniforms
+    }
+
+    /// False when the field would not move any pixel, so the pass can be skipped
+    pub fn is_active(&self) -> bool {
+        let bending = self.wells[..self.well_count as usize].iter().any(|well| well[3] > 1e-4);
+        bending || self.swirl > 1e-5
+    }
+}
+
+struct SceneTarget {
+    view: TextureView,
+    bind_group: BindGroup,
+    size: (u32, u32),
+}
+
+/// Offscreen scene texture plus the pipeline that warps it onto the screen
+pub struct DistortionPass {
+    pipeline: RenderPipeline,
+    layout: BindGroupLayout,
+    sampler: Sampler,
+    uniform_buffer: Buffer,
+    format: TextureFormat,
+    target: Option<SceneTarget>, // Created on first use and on resize
+}
+
+impl DistortionPass {
+    pub fn new(device: &Device, format: TextureFormat) -> Self {
+        let shader = device.create_shader_module(ShaderModuleDescriptor {
+            label: Some("distortion.wgsl"),
+            source: ShaderSource::Wgsl(include_str!("../reality/shaders/distortion.wgsl").into()),
+        });
+
+        let layout = device.create_bind_group_layout(&BindGroupLayoutDescriptor {
+            label: Some("Distortion Bind Group Layout"),
+            entries: &[
+                BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: ShaderStages::FRAGMENT,
+                    ty: BindingType::Texture {
+                        sample_type: TextureSampleType::Float { filterable: true },
+                        view_dimension: TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: ShaderStages::FRAGMENT,
+                    ty: BindingType::Sampler(SamplerBindingType::Filtering),
+                    count: None,
+                },
+                BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: ShaderStages::FRAGMENT,
+                    ty: BindingType::Buffer {
+                        ty: BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&PipelineLayoutDescriptor {
+            label: Some("Distortion Pipeline Layout"),
+            bind_group_layouts: &[&layout],
+            push_constant_ranges: &[],
+        });
+
+        let pipeline = device.create_render_pipeline(&RenderPipelineDescriptor {
+            label: Some("Distortion Pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: VertexState {
+                module: &shader,
+                entry_point: "vs_main",
+                buffers: &[],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(FragmentState {
+                module: &shader,
+                entry_point: "fs_main",
+                targets: &[Some(ColorTargetState {
+                    format,
+                    blend: None,
+                    write_mask: ColorWrites::ALL,
+                })],
+                compilation_options: Default::default(),
+            }),
+            primitive: PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: MultisampleState::default(),
+            multiview: None,
+        });
+
+        let sampler = device.create_sampler(&SamplerDescriptor {
+            label: Some("Distortion Sampler"),
+            address_mode_u: AddressMode::ClampToEdge,
+            address_mode_v: AddressMode::ClampToEdge,
+            mag_filter: FilterMode::Linear,
+            min_filter: FilterMode::Linear,
+            ..Default::default()
+        });
+
+        let uniform_buffer = device.create_buffer(&BufferDescriptor {
+            label: Some("Distortion Uniform Buffer"),
+            size: std::mem::size_of::<DistortionUniforms>() as u64,
+            usage: BufferUsages::UNIFORM | BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        Self { pipeline, layout, sampler, uniform_buffer, format, target: None }
+    }
+
+    /// Upload this frame's field and return the texture the world should be drawn into
+    pub fn prepare(&mut self, device: &Device, queue: &Queue, width: u32, height: u32, uniforms: &DistortionUniforms) -> &TextureView {
+        queue.write_buffer(&self.uniform_buffer, 0, bytemuck::bytes_of(uniforms));
+
+        let size = (width.max(1), height.max(1));
+        if self.target.as_ref().is_none_or(|target| target.size != size) {
+            let texture = device.create_texture(&TextureDescriptor {
+                label: Some("Distortion Scene Texture"),
+                size: Extent3d { width: size.0, height: size.1, depth_or_array_layers: 1 },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: TextureDimension::D2,
+                format: self.format,
+                usage: TextureUsages::RENDER_ATTACHMENT | TextureUsages::TEXTURE_BINDING,
+                view_formats: &[],
+            });
+            let view = texture.create_view(&TextureViewDescriptor::default());
+            let bind_group = device.create_bind_group(&BindGroupDescriptor {
+                label: Some("Distortion Bind Group"),
+                layout: &self.layout,
+                entries: &[
+                    BindGroupEntry { binding: 0, resource: BindingResource::TextureView(&view) },
+                    BindGroupEntry { binding: 1, resource: BindingResource::Sampler(&self.sampler) },
+                    BindGroupEntry { binding: 2, resource: self.uniform_buffer.as_entire_binding() },
+                ],
+            });
+            self.target = Some(SceneTarget { view, bind_group, size });
+        }
+
+        &self.target.as_ref().expect("scene target was just created").view
+    }
+
+    /// Draw the warped scene; call after `prepare` and the scene pass
+    pub fn draw<'pass>(&'pass self, render_pass: &mut RenderPass<'pass>) {
+        let Some(target) = &self.target else { return };
+        render_pass.set_pipeline(&self.pipeline);
+        render_pass.set_bind_group(0, &target.bind_group, &[]);
+        render_pass.draw(0..3, 0..1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aetherium_sim::simulation::TearType;
+
+    #[test]
+    fn test_field_follows_tears_and_safety() {
+        let tear = RealityTear { position: Vec2::new(600.0, 400.0), size: 30.0, intensity: 1.0, age: 0.0, tear_type: TearType::Static };
+        let full = DistortionUniforms::build(std::slice::from_ref(&tear), 0.0, &SafetyConfig::default(), &Viewport::full(), 1.5, 0.0);
+        assert_eq!(full.well_count, 1);
+        assert!((full.wells[0][0] - 0.5).abs() < 1e-5 && (full.wells[0][1] - 0.5).abs() < 1e-5);
+        assert!(full.is_active());
+
+        let safe = DistortionUniforms::build(&[tear], 0.0, &SafetyConfig::safe_mode(), &Viewport::full(), 1.5, 0.0);
+        assert!(safe.wells[0][3] < full.wells[0][3]);
+        assert!(safe.ripple_hz < SafetyConfig::safe_mode().max_flash_rate);
+
+        assert!(!DistortionUniforms::build(&[], 0.0, &SafetyConfig::default(), &Viewport::full(), 1.5, 0.0).is_active());
+    }
+
+    #[test]
+    fn test_distortion_shader_validates() {
+        crate::rendering::validate_wgsl(include_str!("../reality/shaders/distortion.wgsl"));
+        assert_eq!(std::mem::size_of::<DistortionUniforms>(), 160);
+    }
+}
@@ -0,0 +1,288 @@
+// === CONSCIOUSNESS DUST ===
+// An ambient field of motes that gives the world depth behind the llamas.
+// The motes live in a GPU buffer and a compute pass advects them every frame
+// through a current stirred by the beat, by the fastest llamas' wakes and by
+// the world's force fields, so the CPU only uploads a handful of uniforms. They are drawn as
+// max-blended points: a mote can lift a dark pixel to its own dim brightness, which never
+// exceeds SafetyConfig's intensity limit, but never adds to a lit one. The dust is drawn
+// after the frame's safety analysis, so it must not be able to brighten what was checked.
+
+use glam::Vec2;
+use wgpu::*;
+use aetherium_sim::engine::SafetyConfig;
+use aetherium_sim::entities::Llama;
+use aetherium_sim::mathematics::{ForceFields, ForceKind, MAX_FORCE_FIELDS};
+use super::viewport::{Viewport, WORLD_SIZE};
+
+/// Motes in the field
+pub const DUST_PARTICLES: u32 = 32_768;
+/// Llamas whose wakes stir the dust at once; the fastest win
+pub const MAX_DUST_STIRRERS: usize = 16;
+/// Brightness of a mote at full intensity, before glow and shimmer
+const DUST_BRIGHTNESS: f32 = 0.18;
+/// How much the beat brightens the field; kept small so the dust never pulses
+const BEAT_SWELL: f32 = 0.25;
+/// Longest step the motes take in one frame, in seconds
+const MAX_STEP: f32 = 0.1;
+const WORKGROUP_SIZE: u32 = 64;
+
+/// Uniform data matching `dust.wgsl`
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct DustUniforms {
+    pub stirrers: [[f32; 4]; MAX_DUST_STIRRERS], // World position xy, velocity xy
+    pub view: [f32; 4],                          // Visible world rectangle: min xy, max xy
+    pub fields: [[f32; 4]; MAX_FORCE_FIELDS],    // Force field position xy, radius, strength as felt now; unused slots have zero radius
+    pub field_shapes: [[f32; 4]; MAX_FORCE_FIELDS], // Kind (0 well, 1 vortex, 2 wind), wind direction xy, unused
+    pub stirrer_count: u32,
+    pub particle_count: u32,
+    pub time: f32,
+    pub dt: f32,
+    pub beat: f32,
+    pub brightness: f32,
+    pub world: [f32; 2],
+}
+
+impl DustUniforms {
+    /// Stirring and brightness for this frame, as seen through `viewport`; `dt` is set by the pass
+    pub fn build(llamas: &[Llama], beat: f32, safety: &SafetyConfig, viewport: &Viewport, time: f32) -> Self {
+        let beat = beat.clamp(0.0, 1.0);
+        let mut fastest: Vec<&Llama> = llamas.iter().filter(|llama| llama.velocity.is_finite()).collect();
+        fastest.sort_by(|a, b| b.velocity.length_squared().total_cmp(&a.velocity.length_squared()));
+
+        let mut uniforms = Self {
+            stirrers: [[0.0; 4]; MAX_DUST_STIRRERS],
+            view: [viewport.min.x, viewport.min.y, viewport.max.x, viewport.max.y],
+            fields: [[0.0; 4]; MAX_FORCE_FIELDS],
+            field_shapes: [[0.0; 4]; MAX_FORCE_FIELDS],
+            stirrer_count: 0,
+            particle_count: DUST_PARTICLES,
+            time,
+            dt: 0.0,
+            beat,
+            brightness: DUST_BRIGHTNESS * (1.0 + beat * BEAT_SWELL) * safety.visual_intensity_limit.clamp(0.0, 1.0),
+            world: WORLD_SIZE.to_array(),
+        };
+        for (stirrer, llama) in uniforms.stirrers.iter_mut().zip(fastest) {
+            *stirrer = [llama.position.x, llama.position.y, llama.velocity.x, llama.velocity.y];
+            uniforms.stirrer_count += 1;
+        }
+        uniforms
+    }
+
+    /// Let the world's force fields push the motes too
+    pub fn with_force_fields(mut self, force_fields: &ForceFields) -> Self {
+        let slots = self.fields.iter_mut().zip(self.field_shapes.iter_mut());
+        for ((slot, shape), field) in slots.zip(force_fields.fields()) {
+            *slot = [field.position.x, field.position.y, field.radius, field.strength * field.presence()];
+            *shape = match field.kind {
+                ForceKind::GravityWell => [0.0; 4],
+                ForceKind::Vortex => [1.0, 0.0, 0.0, 0.0],
+                ForceKind::Wind { direction } => [2.0, direction.x, direction.y, 0.0],
+            };
+        }
+        self
+    }
+}
+
+/// One mote as stored in the GPU buffer and read back as a vertex
+#[repr(C)]
+#[derive(Debug, Copy, Clone, bytemuck::Pod, bytemuck::Zeroable)]
+struct Mote {
+    position: [f32; 2],
+    velocity: [f32; 2],
+    seed: f32,
+    glow: f32,
+    _padding: [f32; 2],
+}
+
+/// Motes scattered evenly over the world, with well-mixed seeds and no hidden randomness
+fn scatter_motes(count: u32) -> Vec<Mote> {
+    (0..count).map(|i| {
+        // Golden-ratio sequences cover the plane without clumping
+        let u = (i as f32 * 0.618_034).fract();
+        let v = (i as f32 * 0.754_878 + 0.5).fract();
+        Mote {
+            position: (Vec2::new(u, v) * WORLD_SIZE).to_array(),
+            velocity: [0.0; 2],
+            seed: (i as f32 * 0.569_840).fract(),
+            glow: 0.0,
+            _padding: [0.0; 2],
+        }
+    }).collect()
+}
+
+/// The mote buffer plus the compute pipeline that moves it and the point pipeline that draws it
+pub struct DustPass {
+    compute_pipeline: ComputePipeline,
+    render_pipeline: RenderPipeline,
+    bind_group: BindGroup,
+    uniform_buffer: Buffer,
+    motes: Buffer,
+    last_time: Option<f32>,
+}
+
+impl DustPass {
+    pub fn new(device: &Device, format: TextureFormat) -> Self {
+        let shader = device.create_shader_module(ShaderModuleDescriptor {
+            label: Some("dust.wgsl"),
+            source: ShaderSource::Wgsl(include_str!("../reality/shaders/dust.wgsl").into()),
+        });
+
+        let layout = device.create_bind_group_layout(&BindGroupLayoutDescriptor {
+            label: Some("Dust Bind Group Layout"),
+            entries: &[
+                BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: ShaderStages::COMPUTE | ShaderStages::VERTEX,
+                    ty: BindingType::Buffer {
+                        ty: BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: ShaderStages::COMPUTE,
+                    ty: BindingType::Buffer {
+                        ty: BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&PipelineLayoutDescriptor {
+            label: Some("Dust Pipeline Layout"),
+            bind_group_layouts: &[&layout],
+            push_constant_ranges: &[],
+        });
+
+        let compute_pipeline = device.create_compute_pipeline(&ComputePipelineDescriptor {
+            label: Some("Dust Compute Pipeline"),
+            layout: Some(&pipeline_layout),
+            module: &shader,
+            entry_point: "cs_main",
+            compilation_options: Default::default(),
+        });
+
+        // Motes are read straight from the storage buffer as vertices
+        let render_pipeline = device.create_render_pipeline(&RenderPipelineDescriptor {
+            label: Some("Dust Render Pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: VertexState {
+                module: &shader,
+                entry_point: "vs_main",
+                buffers: &[VertexBufferLayout {
+                    array_stride: std::mem::size_of::<Mote>() as BufferAddress,
+                    step_mode: VertexStepMode::Vertex,
+                    attributes: &vertex_attr_array![0 => Float32x2, 1 => Float32x2, 2 => Float32x2],
+                }],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(FragmentState {
+                module: &shader,
+                entry_point: "fs_main",
+                targets: &[Some(ColorTargetState {
+                    format,
+                    blend: Some(BlendState {
+                        color: BlendComponent { src_factor: BlendFactor::One, dst_factor: BlendFactor::One, operation: BlendOperation::Max },
+                        alpha: BlendComponent::OVER,
+                    }),
+                    write_mask: ColorWrites::ALL,
+                })],
+                compilation_options: Default::default(),
+            }),
+            primitive: PrimitiveState { topology: PrimitiveTopology::PointList, ..Default::default() },
+            depth_stencil: None,
+            multisample: MultisampleState::default(),
+            multiview: None,
+        });
+
+        let uniform_buffer = device.create_buffer(&BufferDescriptor {
+            label: Some("Dust Uniform Buffer"),
+            size: std::mem::size_of::<DustUniforms>() as u64,
+            usage: BufferUsages::UNIFORM | BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let scattered = scatter_motes(DUST_PARTICLES);
+        let motes = device.create_buffer(&BufferDescriptor {
+            label: Some("Dust Mote Buffer"),
+            size: std::mem::size_of_val(scattered.as_slice()) as u64,
+            usage: BufferUsages::STORAGE | BufferUsages::VERTEX,
+            mapped_at_creation: true,
+        });
+        motes.slice(..).get_mapped_range_mut().copy_from_slice(bytemuck::cast_slice(&scattered));
+        motes.unmap();
+
+        let bind_group = device.create_bind_group(&BindGroupDescriptor {
+            label: Some("Dust Bind Group"),
+            layout: &layout,
+            entries: &[
+                BindGroupEntry { binding: 0, resource: uniform_buffer.as_entire_binding() },
+                BindGroupEntry { binding: 1, resource: motes.as_entire_binding() },
+            ],
+        });
+
+        Self { compute_pipeline, render_pipeline, bind_group, uniform_buffer, motes, last_time: None }
+    }
+
+    /// Upload this frame's uniforms and advect every mote; record before the pass that draws them
+    pub fn dispatch(&mut self, queue: &Queue, encoder: &mut CommandEncoder, mut uniforms: DustUniforms) {
+        uniforms.dt = self.last_time.map_or(0.0, |last| (uniforms.time - last).clamp(0.0, MAX_STEP));
+        self.last_time = Some(uniforms.time);
+        queue.write_buffer(&self.uniform_buffer, 0, bytemuck::bytes_of(&uniforms));
+
+        let mut compute_pass = encoder.begin_compute_pass(&ComputePassDescriptor {
+            label: Some("Dust Compute Pass"),
+            timestamp_writes: None,
+        });
+        compute_pass.set_pipeline(&self.compute_pipeline);
+        compute_pass.set_bind_group(0, &self.bind_group, &[]);
+        compute_pass.dispatch_workgroups(DUST_PARTICLES.div_ceil(WORKGROUP_SIZE), 1, 1);
+    }
+
+    /// Draw every mote as a max-blended point; call after `dispatch`
+    pub fn draw<'pass>(&'pass self, render_pass: &mut RenderPass<'pass>) {
+        render_pass.set_pipeline(&self.render_pipeline);
+        render_pass.set_bind_group(0, &self.bind_group, &[]);
+        render_pass.set_vertex_buffer(0, self.motes.slice(..));
+        render_pass.draw(0..DUST_PARTICLES, 0..1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aetherium_sim::entities::SpeciesType;
+
+    #[test]
+    fn test_dust_follows_fastest_llamas_under_the_safety_cap() {
+        let mut llamas: Vec<Llama> = (0..20).map(|i| Llama::new_with_species(Vec2::new(i as f32 * 50.0, 400.0), SpeciesType::DiscoLlama)).collect();
+        for (i, llama) in llamas.iter_mut().enumerate() {
+            llama.velocity = Vec2::new(i as f32, 0.0);
+        }
+        let full = DustUniforms::build(&llamas, 1.0, &SafetyConfig::default(), &Viewport::full(), 2.0);
+        assert_eq!(full.stirrer_count as usize, MAX_DUST_STIRRERS);
+        assert_eq!(full.stirrers[0], [950.0, 400.0, 19.0, 0.0]);
+        assert!(full.brightness <= DUST_BRIGHTNESS * (1.0 + BEAT_SWELL));
+
+        let safe = DustUniforms::build(&llamas, 1.0, &SafetyConfig::safe_mode(), &Viewport::full(), 2.0);
+        assert!(safe.brightness < full.brightness);
+        assert_eq!(DustUniforms::build(&[], 0.0, &SafetyConfig::default(), &Viewport::full(), 0.0).stirrer_count, 0);
+
+        let motes = scatter_motes(DUST_PARTICLES);
+        assert!(motes.iter().all(|mote| mote.position[0] < WORLD_SIZE.x && mote.position[1] < WORLD_SIZE.y));
+    }
+
+    #[test]
+    fn test_dust_shader_validates() {
+        crate::rendering::validate_wgsl(include_str!("../reality/shaders/dust.wgsl"));
+        assert_eq!(std::mem::size_of::<DustUniforms>(), 560);
+        assert_eq!(std::mem::size_of::<Mote>(), 32);
+    }
+}
@@ -2,8 +2,8 @@
 // Extracted from simple.rs for better modularity
 
 use glam::{Vec2, Vec3};
-use crate::entities::Llama;
-use crate::simulation::{consciousness_systems::DigitalEcosystem, meta_consciousness::MetaConsciousnessFramework};
+use aetherium_sim::entities::Llama;
+use aetherium_sim::simulation::{consciousness_systems::DigitalEcosystem, meta_consciousness::MetaConsciousnessFramework};
 
 /// Reality Distortion Engine - advanced visual effects and space-time manipulation
 #[derive(Debug)]
@@ -0,0 +1,204 @@
+// === CONSCIOUSNESS FIELD OVERLAY ===
+// Makes the environmental consciousness layer observable. The field is a
+// coarse grid the llamas charge and that diffuses and decays on its own;
+// three views read it differently: a heatmap tints every charged cell from
+// cool blue through green to gold, contour lines trace rings of equal charge
+// with a brightness pulse running out from the peaks, and a flow view points
+// an arrow up the gradient in every cell, towards where consciousness pools.
+// Like the other maps it is dim, never red and sits underneath the llamas.
+
+use glam::{Vec2, Vec3};
+use aetherium_sim::engine::safety::hsv_to_rgb_vec3;
+use aetherium_sim::simulation::ConsciousnessField;
+use crate::reality::Vertex;
+use super::warfare_overlay::overlay_vertex;
+
+/// Charge drawn at full strength; the field caps a cell at this
+const FULL_CHARGE: f32 = 2.0;
+/// Brightness of a fully charged heatmap cell
+const HEAT_INTENSITY: f32 = 0.3;
+/// Heatmap cells under this share of full charge are not drawn
+const MIN_VISIBLE_SHARE: f32 = 0.05;
+/// Charges the contour lines are traced at
+const CONTOUR_LEVELS: [f32; 5] = [0.2, 0.4, 0.8, 1.2, 1.6];
+/// Half the width of a contour line or arrow shaft
+const LINE_THICKNESS: f32 = 1.0;
+/// Brightness of a contour line at the crest of its pulse
+const CONTOUR_INTENSITY: f32 = 0.35;
+/// Pulses per second running from the highest ring to the lowest
+const CONTOUR_PULSE_SPEED: f32 = 0.5;
+/// Brightness of the steepest arrow
+const FLOW_INTENSITY: f32 = 0.35;
+/// Slope, in charge per cell, drawn as a full-length arrow
+const FULL_SLOPE: f32 = 0.2;
+/// Flatter cells than this share of a full slope get no arrow
+const MIN_VISIBLE_SLOPE: f32 = 0.05;
+
+/// How the consciousness field is drawn
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FieldView {
+    #[default]
+    Off,
+    Heatmap, // Tinted cells, cool to warm
+    Contour, // Pulsing lines of equal charge
+    Flow,    // Arrows up the gradient
+}
+
+impl FieldView {
+    /// Every view, in hotkey cycling order
+    pub const ALL: [FieldView; 4] = [Self::Off, Self::Heatmap, Self::Contour, Self::Flow];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Off => "OFF",
+            Self::Heatmap => "HEATMAP",
+            Self::Contour => "CONTOUR",
+            Self::Flow => "FLOW",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|&view| view == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+}
+
+/// Cool blue for a faint charge through green to gold for a full one; never near red
+fn charge_color(share: f32) -> Vec3 {
+    hsv_to_rgb_vec3(Vec3::new(240.0 - 180.0 * share.clamp(0.0, 1.0), 0.7, 1.0))
+}
+
+/// The field in `view`; draw before the llamas so it sits underneath
+pub fn push_field_overlay(vertices: &mut Vec<Vertex>, field: &ConsciousnessField, view: FieldView, background: Vec3, time: f32) {
+    match view {
+        FieldView::Off => {}
+        FieldView::Heatmap => push_heatmap(vertices, field, background),
+        FieldView::Contour => push_contours(vertices, field, background, time),
+        FieldView::Flow => push_flow(vertices, field, background),
+    }
+}
+
+fn cell_size(field: &ConsciousnessField) -> Vec2 {
+    Vec2::new(field.width, field.height) / field.grid_size.max(1) as f32
+}
+
+fn charge(field: &ConsciousnessField, column: usize, row: usize) -> f32 {
+    field.consciousness_density[row][column]
+}
+
+fn push_quad(vertices: &mut Vec<Vertex>, corners: [Vec2; 4], color: Vec3) {
+    let [a, b, c, d] = corners.map(|corner| overlay_vertex(corner, color));
+    vertices.extend([a, b, c, a, c, d]);
+}
+
+fn push_line(vertices: &mut Vec<Vertex>, start: Vec2, end: Vec2, color: Vec3) {
+    let normal = (end - start).perp().normalize_or_zero() * LINE_THICKNESS;
+    push_quad(vertices, [start - normal, start + normal, end + normal, end - normal], color);
+}
+
+fn push_heatmap(vertices: &mut Vec<Vertex>, field: &ConsciousnessField, background: Vec3) {
+    let size = cell_size(field);
+    for row in 0..field.grid_size {
+        for column in 0..field.grid_size {
+            let share = (charge(field, column, row) / FULL_CHARGE).min(1.0);
+            if share < MIN_VISIBLE_SHARE {
+                continue;
+            }
+            let color = background + charge_color(share) * HEAT_INTENSITY * share;
+            let min = Vec2::new(column as f32, row as f32) * size;
+            let max = min + size;
+            push_quad(vertices, [min, Vec2::new(max.x, min.y), max, Vec2::new(min.x, max.y)], color);
+        }
+    }
+}
+
+/// Marching squares over the cell centers, once per contour level
+fn push_contours(vertices: &mut Vec<Vertex>, field: &ConsciousnessField, background: Vec3, time: f32) {
+    let size = cell_size(field);
+    let center = |column: usize, row: usize| (Vec2::new(column as f32, row as f32) + 0.5) * size;
+    for (level_index, &level) in CONTOUR_LEVELS.iter().enumerate() {
+        // The pulse starts at the top ring and runs outward down the levels
+        let phase = (time * CONTOUR_PULSE_SPEED + level_index as f32 / CONTOUR_LEVELS.len() as f32).fract();
+        let pulse = 0.35 + 0.65 * (0.5 + 0.5 * (phase * std::f32::consts::TAU).cos());
+        let color = background + charge_color(level / FULL_CHARGE) * CONTOUR_INTENSITY * pulse;
+        for row in 0..field.grid_size.saturating_sub(1) {
+            for column in 0..field.grid_size.saturating_sub(1) {
+                // Corners clockwise from the top left, then the edges between them in the same order
+                let corners = [(column, row), (column + 1, row), (column + 1, row + 1), (column, row + 1)]
+                    .map(|(column, row)| (center(column, row), charge(field, column, row)));
+                let crossings: Vec<Vec2> = (0..4).filter_map(|edge| {
+                    let ((from, a), (to, b)) = (corners[edge], corners[(edge + 1) % 4]);
+                    ((a < level) != (b < level)).then(|| from.lerp(to, (level - a) / (b - a)))
+                }).collect();
+                for pair in crossings.chunks_exact(2) {
+                    push_line(vertices, pair[0], pair[1], color);
+                }
+            }
+        }
+    }
+}
+
+/// One arrow per cell along the slope, towards more consciousness
+fn push_flow(vertices: &mut Vec<Vertex>, field: &ConsciousnessField, background: Vec3) {
+    let size = cell_size(field);
+    let last = field.grid_size.saturating_sub(1);
+    for row in 0..field.grid_size {
+        for column in 0..field.grid_size {
+            // Central differences, one-sided at the edges
+            let (left, right) = (column.saturating_sub(1), (column + 1).min(last));
+            let (up, down) = (row.saturating_sub(1), (row + 1).min(last));
+            let slope = Vec2::new(
+                (charge(field, right, row) - charge(field, left, row)) / (right - left).max(1) as f32,
+                (charge(field, column, down) - charge(field, column, up)) / (down - up).max(1) as f32,
+            );
+            let steepness = (slope.length() / FULL_SLOPE).min(1.0);
+            if steepness < MIN_VISIBLE_SLOPE {
+                continue;
+            }
+            let direction = slope.normalize();
+            let middle = (Vec2::new(column as f32, row as f32) + 0.5) * size;
+            let reach = direction * size.min_element() * 0.45 * (0.4 + 0.6 * steepness);
+            let (tail, tip) = (middle - reach, middle + reach);
+            let color = background + charge_color(charge(field, column, row) / FULL_CHARGE) * FLOW_INTENSITY * steepness;
+            push_line(vertices, tail, tip, color);
+            let head = reach.length() * 0.5;
+            let [a, b, c] = [tip, tip - direction * head + direction.perp() * head * 0.6, tip - direction * head - direction.perp() * head * 0.6]
+                .map(|corner| overlay_vertex(corner, color));
+            vertices.extend([a, b, c]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aetherium_sim::engine::is_dangerous_red;
+
+    #[test]
+    fn test_each_view_draws_a_charged_spot_dimly() {
+        let mut field = ConsciousnessField::new(1200.0, 800.0, 40);
+        for row in field.consciousness_density.iter_mut() {
+            row.fill(0.0);
+        }
+        let mut vertices = Vec::new();
+        for view in FieldView::ALL {
+            push_field_overlay(&mut vertices, &field, view, Vec3::ZERO, 0.0);
+        }
+        assert!(vertices.is_empty(), "an empty field draws nothing");
+
+        field.add_consciousness_at(Vec2::new(610.0, 410.0), 2.0);
+        field.update(1.0);
+        let mut view = FieldView::Off;
+        for _ in 0..3 {
+            view = view.next();
+            vertices.clear();
+            push_field_overlay(&mut vertices, &field, view, Vec3::ZERO, 0.7);
+            assert!(!vertices.is_empty() && vertices.len() % 3 == 0, "{} shows the spot", view.name());
+            assert!(vertices.iter().all(|vertex| {
+                let color = Vec3::from(vertex.color);
+                color.max_element() <= CONTOUR_INTENSITY && !is_dangerous_red(color)
+            }));
+        }
+        assert_eq!(view.next(), FieldView::Off);
+    }
+}
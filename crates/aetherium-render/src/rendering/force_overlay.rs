@@ -0,0 +1,98 @@
+// === FORCE FIELD OVERLAY ===
+// Draws the world's force fields as streamlines: thin lines traced along the
+// combined pull of every field, seeded on a ring around each well and vortex
+// and across the upwind edge of each wind. A brightness ripple runs along
+// each line in the direction of the force, so the currents read as moving.
+// Lines are dim and tinted by the kind of field that seeded them, fade in and
+// out with their field, and sit underneath the llamas like the other maps.
+
+use glam::{Vec2, Vec3};
+use aetherium_sim::engine::safety::hsv_to_rgb_vec3;
+use aetherium_sim::mathematics::{ForceField, ForceFields, ForceKind};
+use crate::reality::Vertex;
+use super::warfare_overlay::overlay_vertex;
+
+/// Streamlines seeded per field
+const SEEDS_PER_FIELD: usize = 12;
+/// Steps along each streamline and their length in world units
+const STREAM_STEPS: usize = 40;
+const STREAM_STEP: f32 = 10.0;
+/// Half the width of a streamline
+const STREAM_THICKNESS: f32 = 1.2;
+/// Brightness of a streamline at the crest of its ripple
+const STREAM_INTENSITY: f32 = 0.35;
+/// World units between ripple crests, and how fast they travel
+const RIPPLE_LENGTH: f32 = 120.0;
+const RIPPLE_SPEED: f32 = 60.0;
+
+/// Violet wells, cyan vortexes, green winds; never near red
+fn field_color(kind: ForceKind) -> Vec3 {
+    let hue = match kind {
+        ForceKind::GravityWell => 265.0,
+        ForceKind::Vortex => 185.0,
+        ForceKind::Wind { .. } => 120.0,
+    };
+    hsv_to_rgb_vec3(Vec3::new(hue, 0.6, 1.0))
+}
+
+/// Where a field's streamlines start
+fn seeds(field: &ForceField) -> Vec<Vec2> {
+    match field.kind {
+        ForceKind::Wind { direction } => {
+            let upwind = field.position - direction * field.radius * 0.8;
+            (0..SEEDS_PER_FIELD).map(|i| {
+                let across = i as f32 / (SEEDS_PER_FIELD - 1) as f32 * 2.0 - 1.0;
+                upwind + direction.perp() * across * field.radius * 0.6
+            }).collect()
+        }
+        _ => (0..SEEDS_PER_FIELD).map(|i| {
+            let angle = i as f32 / SEEDS_PER_FIELD as f32 * std::f32::consts::TAU;
+            field.position + Vec2::from_angle(angle) * field.radius * 0.9
+        }).collect(),
+    }
+}
+
+/// Streamlines of every field; draw before the llamas so they sit underneath
+pub fn push_force_overlay(vertices: &mut Vec<Vertex>, force_fields: &ForceFields, background: Vec3, time: f32) {
+    for field in force_fields.fields() {
+        let color = field_color(field.kind) * STREAM_INTENSITY * field.presence();
+        for seed in seeds(field) {
+            let line = force_fields.streamline(seed, STREAM_STEPS, STREAM_STEP);
+            for (step, pair) in line.windows(2).enumerate() {
+                let (start, end) = (pair[0], pair[1]);
+                let travelled = step as f32 * STREAM_STEP;
+                let ripple = 0.5 + 0.5 * ((travelled - time * RIPPLE_SPEED) / RIPPLE_LENGTH * std::f32::consts::TAU).cos();
+                // Lines thin out toward their end so the ripple seems to dissolve
+                let tail = 1.0 - step as f32 / STREAM_STEPS as f32;
+                let segment_color = background + color * (0.3 + 0.7 * ripple) * tail;
+                let normal = (end - start).perp().normalize_or_zero() * STREAM_THICKNESS;
+                let [a, b, c, d] = [start - normal, start + normal, end + normal, end - normal].map(|corner| overlay_vertex(corner, segment_color));
+                vertices.extend([a, b, c, a, c, d]);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aetherium_sim::engine::is_dangerous_red;
+
+    #[test]
+    fn test_streamlines_follow_fields_dimly() {
+        let mut fields = ForceFields::default();
+        let mut vertices = Vec::new();
+        push_force_overlay(&mut vertices, &fields, Vec3::ZERO, 0.0);
+        assert!(vertices.is_empty());
+
+        fields.add(ForceField::vortex(Vec2::new(600.0, 400.0), 150.0, 60.0));
+        fields.add(ForceField::wind(Vec2::new(300.0, 300.0), 100.0, Vec2::new(40.0, 0.0)));
+        fields.update(5.0);
+        push_force_overlay(&mut vertices, &fields, Vec3::ZERO, 1.0);
+        assert!(!vertices.is_empty() && vertices.len() % 6 == 0);
+        assert!(vertices.iter().all(|vertex| {
+            let color = Vec3::from(vertex.color);
+            color.max_element() <= STREAM_INTENSITY && !is_dangerous_red(color)
+        }));
+    }
+}
@@ -0,0 +1,501 @@
+// === COLOR GRADING ===
+// Every audio environment has a look, a 3D LUT the finished world is graded
+// through: Meditative goes teal and orange, Electronica cold and punchy, a
+// RealityTear turns the world inside out. A simulated day cycle mixes in a
+// night look as well. Looks blend slowly into each other instead of cutting,
+// any of them can be replaced by a `.cube` file, and every LUT is pulled back
+// towards neutral until it neither shifts luminance nor steepens contrast past
+// a safe limit, scaled by the intensity limit.
+
+use glam::Vec3;
+use wgpu::*;
+use aetherium_sim::engine::SafetyConfig;
+
+/// Grid points per axis of the LUT the GPU samples; `.cube` files are resampled to it
+pub const GRADE_LUT_SIZE: usize = 17;
+/// Largest luminance shift a look may cause at full intensity
+const MAX_GRADE_SHIFT: f32 = 0.25;
+/// Steepest a look may make the luminance response, as a multiple of neutral
+const MAX_CONTRAST: f32 = 1.5;
+/// Share of the blend that moves from one look to the next per second
+const GRADE_RATE: f32 = 0.25;
+/// Share of the night look at midnight
+const NIGHT_DEPTH: f32 = 0.6;
+const LUMA: Vec3 = Vec3::new(0.2126, 0.7152, 0.0722);
+
+/// Which look a LUT provides: one per audio environment, plus night
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GradeMood {
+    Environmental,
+    Meditative,
+    Psychedelic,
+    Electronica,
+    HiveMind,
+    RealityTear,
+    Night,
+}
+
+impl GradeMood {
+    pub const ALL: [GradeMood; 7] = [
+        Self::Environmental, Self::Meditative, Self::Psychedelic, Self::Electronica, Self::HiveMind, Self::RealityTear, Self::Night,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Environmental => "environmental",
+            Self::Meditative => "meditative",
+            Self::Psychedelic => "psychedelic",
+            Self::Electronica => "electronica",
+            Self::HiveMind => "hive-mind",
+            Self::RealityTear => "reality-tear",
+            Self::Night => "night",
+        }
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    /// The built-in look
+    fn look(self) -> Lut3d {
+        let luma = |c: Vec3| c.dot(LUMA);
+        let saturate = |c: Vec3, amount: f32| Vec3::splat(luma(c)).lerp(c, amount);
+        Lut3d::from_fn(GRADE_LUT_SIZE, |c| match self {
+            // A touch of green in the mids, like daylight through leaves
+            Self::Environmental => c * Vec3::new(0.98, 1.03, 0.97),
+            // Teal shadows, orange highlights
+            Self::Meditative => c + Vec3::new(-0.05, 0.03, 0.06).lerp(Vec3::new(0.07, 0.02, -0.06), luma(c)),
+            Self::Psychedelic => saturate(c, 1.35),
+            // Cold and punchy
+            Self::Electronica => (c - 0.5) * 1.2 + 0.5 + Vec3::new(0.02, -0.03, 0.05),
+            // Golden, nearly one mind and one color
+            Self::HiveMind => saturate(c, 0.5) * Vec3::new(1.06, 0.98, 0.82),
+            Self::RealityTear => Vec3::ONE - c,
+            // Blue and dim
+            Self::Night => saturate(c, 0.7) * Vec3::new(0.75, 0.82, 1.0),
+        })
+    }
+}
+
+/// A 3D color lookup table, red varying fastest like `.cube` files
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lut3d {
+    size: usize,
+    entries: Vec<Vec3>,
+}
+
+impl Lut3d {
+    /// `size`³ grid points, each mapped through `grade` and clamped to 0-1
+    pub fn from_fn(size: usize, grade: impl Fn(Vec3) -> Vec3) -> Self {
+        let step = 1.0 / (size.max(2) - 1) as f32;
+        let entries = (0..size * size * size)
+            .map(|i| Vec3::new((i % size) as f32, (i / size % size) as f32, (i / (size * size)) as f32) * step)
+            .map(|color| grade(color).clamp(Vec3::ZERO, Vec3::ONE))
+            .collect();
+        Self { size, entries }
+    }
+
+    pub fn identity(size: usize) -> Self {
+        Self::from_fn(size, |color| color)
+    }
+
+    /// Parse an Adobe/Resolve `.cube` 3D LUT with the default 0-1 domain
+    pub fn parse_cube(source: &str) -> Result<Self, String> {
+        let mut size = None;
+        let mut entries = Vec::new();
+        for (number, line) in source.lines().enumerate().map(|(i, line)| (i + 1, line.trim())) {
+            if line.is_empty() || line.starts_with('#') || line.starts_with("TITLE") {
+                continue;
+            }
+            let mut words = line.split_whitespace();
+            let first = words.next().unwrap_or_default();
+            let numbers = |words: std::str::SplitWhitespace| -> Result<Vec<f32>, String> {
+                words.map(|word| word.parse::<f32>().ok().filter(|value| value.is_finite()).ok_or(format!("line {}: '{}' is not a number", number, word))).collect()
+            };
+            match first {
+                "LUT_3D_SIZE" => {
+                    let value: usize = words.next().and_then(|word| word.parse().ok()).ok_or(format!("line {}: LUT_3D_SIZE needs a size", number))?;
+                    if !(2..=65).contains(&value) {
+                        return Err(format!("line {}: LUT_3D_SIZE {} is outside 2-65", number, value));
+                    }
+                    size = Some(value);
+                }
+                "LUT_1D_SIZE" => return Err("1D LUTs are not supported".to_string()),
+                "DOMAIN_MIN" | "DOMAIN_MAX" => {
+                    let expected = if first == "DOMAIN_MIN" { 0.0 } else { 1.0 };
+                    if numbers(words)?.iter().any(|&value| value != expected) {
+                        return Err(format!("line {}: only the 0-1 domain is supported", number));
+                    }
+                }
+                _ => {
+                    let values = numbers(line.split_whitespace())?;
+                    let [r, g, b] = values[..] else {
+                        return Err(format!("line {}: expected three values", number));
+                    };
+                    entries.push(Vec3::new(r, g, b).clamp(Vec3::ZERO, Vec3::ONE));
+                }
+            }
+        }
+        let size = size.ok_or("missing LUT_3D_SIZE")?;
+        if entries.len() != size * size * size {
+            return Err(format!("expected {} entries for size {}, found {}", size * size * size, size, entries.len()));
+        }
+        Ok(Self { size, entries })
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// `color` graded, interpolating between the nearest grid points
+    pub fn sample(&self, color: Vec3) -> Vec3 {
+        let scaled = color.clamp(Vec3::ZERO, Vec3::ONE) * (self.size - 1) as f32;
+        let low = scaled.floor().min(Vec3::splat((self.size - 2) as f32));
+        let t = scaled - low;
+        let at = |r: usize, g: usize, b: usize| self.entries[r + g * self.size + b * self.size * self.size];
+        let (r, g, b) = (low.x as usize, low.y as usize, low.z as usize);
+        let along_r = |g: usize, b: usize| at(r, g, b).lerp(at(r + 1, g, b), t.x);
+        let along_g = |b: usize| along_r(g, b).lerp(along_r(g + 1, b), t.y);
+        along_g(b).lerp(along_g(b + 1), t.z)
+    }
+
+    pub fn resampled(&self, size: usize) -> Self {
+        Self::from_fn(size, |color| self.sample(color))
+    }
+
+    /// This look pulled towards neutral until it shifts luminance by at most
+    /// `MAX_GRADE_SHIFT` times `intensity` and steepens it by at most `MAX_CONTRAST`
+    pub fn limited(&self, intensity: f32) -> Self {
+        let neutral = Self::identity(self.size);
+        let luma = |c: Vec3| c.dot(LUMA);
+        let shift = self.entries.iter().zip(&neutral.entries)
+            .map(|(graded, plain)| (luma(*graded) - luma(*plain)).abs())
+            .fold(0.0, f32::max);
+        let strides = [1, self.size, self.size * self.size];
+        let gain = (0..self.entries.len()).flat_map(|i| (0..3).map(move |axis| (i, axis)))
+            .filter(|&(i, axis)| i / strides[axis] % self.size + 1 < self.size)
+            .map(|(i, axis)| {
+                let step = LUMA[axis] / (self.size - 1) as f32;
+                (luma(self.entries[i + strides[axis]]) - luma(self.entries[i])).abs() / step
+            })
+            .fold(0.0, f32::max);
+
+        // Mixing in `t` of the look moves luminance by t·shift and steepens it to at most (1-t) + t·gain
+        let mut t = 1.0f32;
+        if shift > 0.0 {
+            t = t.min(MAX_GRADE_SHIFT * intensity.clamp(0.0, 1.0) / shift);
+        }
+        if gain > MAX_CONTRAST {
+            t = t.min((MAX_CONTRAST - 1.0) / (gain - 1.0));
+        }
+        Self { size: self.size, entries: neutral.entries.iter().zip(&self.entries).map(|(plain, graded)| plain.lerp(*graded, t)).collect() }
+    }
+
+    fn rgba8(&self) -> Vec<u8> {
+        self.entries.iter().flat_map(|color| {
+            let [r, g, b] = (color.clamp(Vec3::ZERO, Vec3::ONE) * 255.0).round().to_array();
+            [r as u8, g as u8, b as u8, 255]
+        }).collect()
+    }
+}
+
+/// How far into the night a simulated day of `day_length` seconds is at `time`: 0 at noon, 1 at midnight
+pub fn night_amount(time: f32, day_length: f32) -> f32 {
+    let phase = (time / day_length.max(1.0)).fract();
+    0.5 - 0.5 * (phase * std::f32::consts::TAU).cos()
+}
+
+/// The looks, how much of each is showing, and the LUT they blend into
+#[derive(Debug)]
+pub struct ColorGrade {
+    looks: Vec<Lut3d>,   // Unlimited, by `GradeMood` index
+    limited: Vec<Lut3d>, // The looks after the safety limit for `intensity`
+    intensity: f32,
+    weights: [f32; 7],
+    blended: Lut3d,
+}
+
+impl ColorGrade {
+    pub fn new(safety: &SafetyConfig) -> Self {
+        let looks: Vec<Lut3d> = GradeMood::ALL.iter().map(|mood| mood.look()).collect();
+        let mut grade = Self {
+            limited: Vec::new(),
+            looks,
+            intensity: -1.0,
+            weights: [0.0; 7],
+            blended: Lut3d::identity(GRADE_LUT_SIZE),
+        };
+        grade.weights[GradeMood::Environmental.index()] = 1.0;
+        grade.relimit(safety.visual_intensity_limit);
+        grade
+    }
+
+    /// Replace a built-in look, e.g. with one parsed from a `.cube` file
+    pub fn set_look(&mut self, mood: GradeMood, lut: &Lut3d) {
+        self.looks[mood.index()] = lut.resampled(GRADE_LUT_SIZE);
+        self.relimit(self.intensity);
+    }
+
+    /// Ease towards `mood` with `night` (0-1) of the night look mixed in;
+    /// true when the blended LUT changed and needs uploading
+    pub fn update(&mut self, mood: GradeMood, night: f32, dt: f32, safety: &SafetyConfig) -> bool {
+        let relimited = safety.visual_intensity_limit != self.intensity;
+        if relimited {
+            self.relimit(safety.visual_intensity_limit);
+        }
+        let night = night.clamp(0.0, 1.0) * NIGHT_DEPTH;
+        let mut moved = false;
+        for (index, weight) in self.weights.iter_mut().enumerate() {
+            let target = if index == GradeMood::Night.index() {
+                night
+            } else if index == mood.index() {
+                1.0 - night
+            } else {
+                0.0
+            };
+            let step = (target - *weight).clamp(-GRADE_RATE * dt, GRADE_RATE * dt);
+            if step.abs() > 1e-5 {
+                *weight += step;
+                moved = true;
+            }
+        }
+        if moved || relimited {
+            self.blend();
+        }
+        moved || relimited
+    }
+
+    pub fn lut(&self) -> &Lut3d {
+        &self.blended
+    }
+
+    /// Share of each look in the current grade, by `GradeMood::ALL` order
+    pub fn weights(&self) -> [f32; 7] {
+        self.weights
+    }
+
+    fn relimit(&mut self, intensity: f32) {
+        self.intensity = intensity;
+        self.limited = self.looks.iter().map(|look| look.limited(intensity)).collect();
+        self.blend();
+    }
+
+    fn blend(&mut self) {
+        let total: f32 = self.weights.iter().sum::<f32>().max(1e-6);
+        for (i, entry) in self.blended.entries.iter_mut().enumerate() {
+            *entry = self.limited.iter().zip(self.weights).map(|(look, weight)| look.entries[i] * weight).sum::<Vec3>() / total;
+        }
+    }
+}
+
+struct SceneTarget {
+    view: TextureView,
+    bind_group: BindGroup,
+    size: (u32, u32),
+}
+
+/// Offscreen scene texture plus the pipeline that grades it onto the screen
+pub struct GradingPass {
+    pipeline: RenderPipeline,
+    layout: BindGroupLayout,
+    sampler: Sampler,
+    lut_texture: Texture,
+    lut_view: TextureView,
+    format: TextureFormat,
+    target: Option<SceneTarget>, // Created on first use and on resize
+}
+
+impl GradingPass {
+    pub fn new(device: &Device, format: TextureFormat) -> Self {
+        let shader = device.create_shader_module(ShaderModuleDescriptor {
+            label: Some("grading.wgsl"),
+            source: ShaderSource::Wgsl(include_str!("../reality/shaders/grading.wgsl").into()),
+        });
+
+        let layout = device.create_bind_group_layout(&BindGroupLayoutDescriptor {
+            label: Some("Grading Bind Group Layout"),
+            entries: &[
+                BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: ShaderStages::FRAGMENT,
+                    ty: BindingType::Texture {
+                        sample_type: TextureSampleType::Float { filterable: true },
+                        view_dimension: TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: ShaderStages::FRAGMENT,
+                    ty: BindingType::Sampler(SamplerBindingType::Filtering),
+                    count: None,
+                },
+                BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: ShaderStages::FRAGMENT,
+                    ty: BindingType::Texture {
+                        sample_type: TextureSampleType::Float { filterable: true },
+                        view_dimension: TextureViewDimension::D3,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+            ],
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&PipelineLayoutDescriptor {
+            label: Some("Grading Pipeline Layout"),
+            bind_group_layouts: &[&layout],
+            push_constant_ranges: &[],
+        });
+
+        let pipeline = device.create_render_pipeline(&RenderPipelineDescriptor {
+            label: Some("Grading Pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: VertexState {
+                module: &shader,
+                entry_point: "vs_main",
+                buffers: &[],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(FragmentState {
+                module: &shader,
+                entry_point: "fs_main",
+                targets: &[Some(ColorTargetState {
+                    format,
+                    blend: None,
+                    write_mask: ColorWrites::ALL,
+                })],
+                compilation_options: Default::default(),
+            }),
+            primitive: PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: MultisampleState::default(),
+            multiview: None,
+        });
+
+        let sampler = device.create_sampler(&SamplerDescriptor {
+            label: Some("Grading Sampler"),
+            address_mode_u: AddressMode::ClampToEdge,
+            address_mode_v: AddressMode::ClampToEdge,
+            address_mode_w: AddressMode::ClampToEdge,
+            mag_filter: FilterMode::Linear,
+            min_filter: FilterMode::Linear,
+            ..Default::default()
+        });
+
+        let side = GRADE_LUT_SIZE as u32;
+        let lut_texture = device.create_texture(&TextureDescriptor {
+            label: Some("Grading LUT Texture"),
+            size: Extent3d { width: side, height: side, depth_or_array_layers: side },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: TextureDimension::D3,
+            format: TextureFormat::Rgba8Unorm,
+            usage: TextureUsages::TEXTURE_BINDING | TextureUsages::COPY_DST,
+            view_formats: &[],
+        });
+        let lut_view = lut_texture.create_view(&TextureViewDescriptor::default());
+
+        Self { pipeline, layout, sampler, lut_texture, lut_view, format, target: None }
+    }
+
+    /// Upload the blended LUT; it must be `GRADE_LUT_SIZE` on a side
+    pub fn upload(&self, queue: &Queue, lut: &Lut3d) {
+        debug_assert_eq!(lut.size(), GRADE_LUT_SIZE);
+        let side = GRADE_LUT_SIZE as u32;
+        queue.write_texture(
+            ImageCopyTexture { texture: &self.lut_texture, mip_level: 0, origin: Origin3d::ZERO, aspect: TextureAspect::All },
+            &lut.rgba8(),
+            ImageDataLayout { offset: 0, bytes_per_row: Some(4 * side), rows_per_image: Some(side) },
+            Extent3d { width: side, height: side, depth_or_array_layers: side },
+        );
+    }
+
+    /// Return the texture the finished world should be drawn into
+    pub fn prepare(&mut self, device: &Device, width: u32, height: u32) -> &TextureView {
+        let size = (width.max(1), height.max(1));
+        if self.target.as_ref().is_none_or(|target| target.size != size) {
+            let texture = device.create_texture(&TextureDescriptor {
+                label: Some("Grading Scene Texture"),
+                size: Extent3d { width: size.0, height: size.1, depth_or_array_layers: 1 },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: TextureDimension::D2,
+                format: self.format,
+                usage: TextureUsages::RENDER_ATTACHMENT | TextureUsages::TEXTURE_BINDING,
+                view_formats: &[],
+            });
+            let view = texture.create_view(&TextureViewDescriptor::default());
+            let bind_group = device.create_bind_group(&BindGroupDescriptor {
+                label: Some("Grading Bind Group"),
+                layout: &self.layout,
+                entries: &[
+                    BindGroupEntry { binding: 0, resource: BindingResource::TextureView(&view) },
+                    BindGroupEntry { binding: 1, resource: BindingResource::Sampler(&self.sampler) },
+                    BindGroupEntry { binding: 2, resource: BindingResource::TextureView(&self.lut_view) },
+                ],
+            });
+            self.target = Some(SceneTarget { view, bind_group, size });
+        }
+
+        &self.target.as_ref().expect("scene target was just created").view
+    }
+
+    /// Draw the graded scene; call after `prepare` and the passes that draw into it
+    pub fn draw<'pass>(&'pass self, render_pass: &mut RenderPass<'pass>) {
+        let Some(target) = &self.target else { return };
+        render_pass.set_pipeline(&self.pipeline);
+        render_pass.set_bind_group(0, &target.bind_group, &[]);
+        render_pass.draw(0..3, 0..1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cube_files_parse_and_sample() {
+        let cube = "TITLE \"warm\"\n# red fastest\nLUT_3D_SIZE 2\nDOMAIN_MIN 0 0 0\nDOMAIN_MAX 1 1 1\n\
+                    0 0 0\n1 0 0\n0 1 0\n1 1 0\n0 0 1\n1 0 1\n0 1 1\n1 1 1\n";
+        let lut = Lut3d::parse_cube(cube).unwrap();
+        let color = Vec3::new(0.2, 0.5, 0.8);
+        assert!(lut.sample(color).distance(color) < 1e-5, "a 2-point identity LUT interpolates exactly");
+        assert!(lut.resampled(GRADE_LUT_SIZE).sample(color).distance(color) < 1e-5);
+
+        assert!(Lut3d::parse_cube("LUT_1D_SIZE 4\n").is_err());
+        assert!(Lut3d::parse_cube("LUT_3D_SIZE 2\n0 0 0\n").unwrap_err().contains("expected 8 entries"));
+        assert!(Lut3d::parse_cube("LUT_3D_SIZE 2\nDOMAIN_MAX 2 2 2\n").is_err());
+    }
+
+    #[test]
+    fn test_looks_are_limited_and_blend_slowly() {
+        // Inverting is pulled back to a gentle shift; safe mode is gentler still
+        let inverted = GradeMood::RealityTear.look();
+        let white = inverted.limited(1.0).sample(Vec3::ONE);
+        assert!((1.0 - white.dot(LUMA) - MAX_GRADE_SHIFT).abs() < 1e-3);
+        assert!(inverted.limited(0.0).sample(Vec3::ONE).distance(Vec3::ONE) < 1e-5);
+        let steep = Lut3d::from_fn(GRADE_LUT_SIZE, |c| (c - 0.5) * 4.0 + 0.5).limited(1.0);
+        let step = steep.sample(Vec3::splat(0.55)).dot(LUMA) - steep.sample(Vec3::splat(0.45)).dot(LUMA);
+        assert!(step <= 0.1 * MAX_CONTRAST + 1e-3);
+
+        // A new mood takes over at the grade rate, never in one frame
+        let safety = SafetyConfig::default();
+        let mut grade = ColorGrade::new(&safety);
+        assert!(grade.update(GradeMood::Meditative, 0.0, 1.0, &safety));
+        assert!((grade.weights()[GradeMood::Meditative.index()] - GRADE_RATE).abs() < 1e-5);
+        for _ in 0..10 {
+            grade.update(GradeMood::Meditative, 1.0, 1.0, &safety);
+        }
+        assert!((grade.weights()[GradeMood::Night.index()] - NIGHT_DEPTH).abs() < 1e-5);
+        assert!(!grade.update(GradeMood::Meditative, 1.0, 1.0, &safety), "settled grades need no upload");
+        assert!(night_amount(0.0, 600.0) < 1e-6 && (night_amount(300.0, 600.0) - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_grading_shader_validates() {
+        crate::rendering::validate_wgsl(include_str!("../reality/shaders/grading.wgsl"));
+    }
+}
@@ -0,0 +1,383 @@
+// === LIGHTING PASS ===
+// Gives the world depth: llamas past a consciousness threshold glow, crystals
+// light their surroundings, and walls and pillars throw soft shadows from
+// both. The pass is one full-screen triangle multiplied over the world before
+// the HUD, so it can only darken what the safety zone analyzer already
+// measured; the darkest it gets is scaled back by the intensity limit.
+// Which lights get the limited slots is sticky: a light keeps its slot until a
+// clearly brighter one wants it, and every light fades in and out over a
+// fraction of a second rather than popping as the ranking shifts.
+
+use glam::{Vec2, Vec3};
+use wgpu::*;
+use aetherium_sim::core::ecs::EntityId;
+use aetherium_sim::engine::SafetyConfig;
+use aetherium_sim::entities::Llama;
+use aetherium_sim::simulation::{ConsciousnessCrystal, Obstacles};
+use super::viewport::Viewport;
+use crate::reality::hsv_to_rgb;
+
+/// Lights drawn at once; the brightest win
+pub const MAX_LIGHTS: usize = 32;
+/// Walls and pillars that cast shadows, each
+pub const MAX_OCCLUDERS: usize = 32;
+/// Llamas start to glow above this consciousness and are fully lit at twice it
+const GLOW_CONSCIOUSNESS: f32 = 1.0;
+/// Darkest an unlit spot gets at full strength and intensity
+const DARKEST: f32 = 0.35;
+/// Radius of each light's disc; wider discs soften the shadows
+const LIGHT_SOFTNESS: f32 = 10.0;
+/// Seconds a light takes to fade fully in or out; also caps how fast any light's strength changes
+const LIGHT_FADE_SECONDS: f32 = 0.5;
+/// How many times brighter a newcomer must be than the dimmest lit light to take its slot
+const LIGHT_HYSTERESIS: f32 = 1.5;
+/// Longest step the fades take in one frame, in seconds
+const MAX_STEP: f32 = 0.1;
+
+/// Uniform data matching `lighting.wgsl`
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct LightingUniforms {
+    pub lights: [[f32; 4]; MAX_LIGHTS],     // World xy, reach, strength
+    pub colors: [[f32; 4]; MAX_LIGHTS],     // Light color, unused
+    pub walls: [[f32; 4]; MAX_OCCLUDERS],   // Start xy, end xy
+    pub pillars: [[f32; 4]; MAX_OCCLUDERS], // Center xy, radius, unused
+    pub view: [f32; 4],                     // Visible world rectangle: min xy, max xy
+    pub screen: [f32; 2],
+    pub ambient: f32,
+    pub softness: f32,
+    pub light_count: u32,
+    pub wall_count: u32,
+    pub pillar_count: u32,
+    pub _padding: u32,
+}
+
+/// What gives off a light, so the light keeps its slot from frame to frame
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LightSource {
+    Llama(EntityId),
+    Crystal(EntityId),
+}
+
+/// One light as its source gives it off
+#[derive(Debug, Clone, Copy)]
+struct Light {
+    position: Vec2,
+    reach: f32,
+    strength: f32,
+    color: Vec3,
+}
+
+impl Light {
+    /// How much of the world the light brightens; the brighter win the slots
+    fn score(&self) -> f32 {
+        self.strength * self.reach
+    }
+}
+
+/// A light holding a slot, fading towards its source's strength, or out once it lost the slot
+#[derive(Debug, Clone)]
+struct HeldLight {
+    source: LightSource,
+    light: Light,  // As last seen; kept while fading out after the source is gone
+    lit: bool,     // Still selected; false while fading out
+    shown: f32,    // Strength drawn this frame
+}
+
+/// The lights holding the limited slots, carried from frame to frame
+#[derive(Debug, Clone, Default)]
+pub struct LightSelection {
+    held: Vec<HeldLight>,
+    last_time: Option<f32>,
+}
+
+impl LightSelection {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Pick this frame's lights from index-aligned ids and components, and ease every held light's strength
+    pub fn update(&mut self, llamas: (&[EntityId], &[Llama]), crystals: (&[EntityId], &[ConsciousnessCrystal]),
+                  viewport: &Viewport, time: f32) {
+        let dt = self.last_time.map_or(0.0, |last| (time - last).clamp(0.0, MAX_STEP));
+        self.last_time = Some(time);
+
+        let glowing = llamas.0.iter().zip(llamas.1).filter(|(_, llama)| llama.position.is_finite()).filter_map(|(&id, llama)| {
+            let glow = ((llama.consciousness - GLOW_CONSCIOUSNESS) / GLOW_CONSCIOUSNESS).clamp(0.0, 1.0);
+            (glow > 0.0).then(|| (LightSource::Llama(id), Light {
+                position: llama.position,
+                reach: 60.0 + glow * 90.0,
+                strength: 0.4 + glow * 0.6,
+                color: hsv_to_rgb(llama.color.x.rem_euclid(360.0), llama.color.y.clamp(0.0, 1.0) * 0.5, 1.0),
+            }))
+        });
+        let shining = crystals.0.iter().zip(crystals.1).map(|(&id, crystal)| (LightSource::Crystal(id), Light {
+            position: crystal.position,
+            reach: 50.0 + crystal.harvest_radius,
+            strength: (0.5 + crystal.consciousness_energy * 0.25).min(1.0),
+            color: crystal.get_color().normalize_or_zero() * 3f32.sqrt(),
+        }));
+
+        // Only lights that reach into the view matter
+        let mut candidates: Vec<(LightSource, Light)> = glowing.chain(shining)
+            .filter(|(_, light)| light.position.clamp(viewport.min, viewport.max).distance(light.position) < light.reach)
+            .collect();
+        candidates.sort_by(|(_, a), (_, b)| b.score().total_cmp(&a.score()));
+
+        // Held lights follow their source, or lose their slot when it has gone dark or out of view
+        for held in &mut self.held {
+            match candidates.iter().find(|(source, _)| *source == held.source) {
+                Some((_, light)) => held.light = *light,
+                None => held.lit = false,
+            }
+        }
+        // Newcomers take free slots, brightest first, then wait for the slots of lights already fading
+        // out; with none left, one clearly brighter than the dimmest lit light sends it fading out
+        let mut freeing = self.held.iter().filter(|held| !held.lit).count();
+        for (source, light) in candidates {
+            // A light already fading out finishes doing so before it competes again
+            if self.held.iter().any(|held| held.source == source) {
+                continue;
+            }
+            if self.held.len() < MAX_LIGHTS {
+                self.held.push(HeldLight { source, light, lit: true, shown: 0.0 });
+                continue;
+            }
+            if freeing > 0 {
+                freeing -= 1;
+                continue;
+            }
+            let dimmest = self.held.iter_mut()
+                .filter(|held| held.lit)
+                .min_by(|a, b| a.light.score().total_cmp(&b.light.score()));
+            if let Some(dimmest) = dimmest.filter(|dimmest| light.score() > dimmest.light.score() * LIGHT_HYSTERESIS) {
+                dimmest.lit = false;
+            }
+        }
+
+        let step = dt / LIGHT_FADE_SECONDS;
+        for held in &mut self.held {
+            let target = if held.lit { held.light.strength } else { 0.0 };
+            held.shown += (target - held.shown).clamp(-step, step);
+        }
+        self.held.retain(|held| held.lit || held.shown > 0.0);
+    }
+}
+
+impl LightingUniforms {
+    /// The selected lights and the shadows for the world as seen through `viewport`; `strength` 0 leaves it unlit
+    pub fn build(selection: &LightSelection, obstacles: &Obstacles, strength: f32,
+                 safety: &SafetyConfig, viewport: &Viewport, width: u32, height: u32) -> Self {
+        let limit = safety.visual_intensity_limit.clamp(0.0, 1.0);
+        let lights: Vec<Light> = selection.held.iter()
+            .filter(|held| held.shown > 0.0)
+            .map(|held| Light { strength: held.shown, ..held.light })
+            .collect();
+
+        let mut uniforms = Self {
+            lights: [[0.0; 4]; MAX_LIGHTS],
+            colors: [[0.0; 4]; MAX_LIGHTS],
+            walls: [[0.0; 4]; MAX_OCCLUDERS],
+            pillars: [[0.0; 4]; MAX_OCCLUDERS],
+            view: [viewport.min.x, viewport.min.y, viewport.max.x, viewport.max.y],
+            screen: [width.max(1) as f32, height.max(1) as f32],
+            ambient: 1.0 - strength.clamp(0.0, 1.0) * (1.0 - DARKEST) * limit,
+            softness: LIGHT_SOFTNESS,
+            light_count: lights.len().min(MAX_LIGHTS) as u32,
+            wall_count: obstacles.walls.len().min(MAX_OCCLUDERS) as u32,
+            pillar_count: obstacles.pillars.len().min(MAX_OCCLUDERS) as u32,
+            _padding: 0,
+        };
+        for ((slot, color), light) in uniforms.lights.iter_mut().zip(&mut uniforms.colors).zip(&lights) {
+            *slot = [light.position.x, light.position.y, light.reach, light.strength];
+            *color = light.color.min(Vec3::ONE).extend(0.0).to_array();
+        }
+        for (slot, wall) in uniforms.walls.iter_mut().zip(&obstacles.walls) {
+            *slot = [wall.start.x, wall.start.y, wall.end.x, wall.end.y];
+        }
+        for (slot, pillar) in uniforms.pillars.iter_mut().zip(&obstacles.pillars) {
+            *slot = [pillar.center.x, pillar.center.y, pillar.radius, 0.0];
+        }
+        uniforms
+    }
+
+    /// False when the pass would leave every pixel as it is
+    pub fn is_active(&self) -> bool {
+        self.ambient < 1.0 - 1e-4
+    }
+}
+
+/// Pipeline that multiplies the light level over whatever is already drawn
+pub struct LightingPass {
+    pipeline: RenderPipeline,
+    bind_group: BindGroup,
+    uniform_buffer: Buffer,
+}
+
+impl LightingPass {
+    pub fn new(device: &Device, format: TextureFormat) -> Self {
+        let shader = device.create_shader_module(ShaderModuleDescriptor {
+            label: Some("lighting.wgsl"),
+            source: ShaderSource::Wgsl(include_str!("../reality/shaders/lighting.wgsl").into()),
+        });
+
+        let layout = device.create_bind_group_layout(&BindGroupLayoutDescriptor {
+            label: Some("Lighting Bind Group Layout"),
+            entries: &[BindGroupLayoutEntry {
+                binding: 0,
+                visibility: ShaderStages::FRAGMENT,
+                ty: BindingType::Buffer {
+                    ty: BufferBindingType::Uniform,
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            }],
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&PipelineLayoutDescriptor {
+            label: Some("Lighting Pipeline Layout"),
+            bind_group_layouts: &[&layout],
+            push_constant_ranges: &[],
+        });
+
+        // Multiply: the world keeps its color where lit and sinks to the ambient level elsewhere
+        let multiply = BlendState {
+            color: BlendComponent { src_factor: BlendFactor::Dst, dst_factor: BlendFactor::Zero, operation: BlendOperation::Add },
+            alpha: BlendComponent { src_factor: BlendFactor::Zero, dst_factor: BlendFactor::One, operation: BlendOperation::Add },
+        };
+        let pipeline = device.create_render_pipeline(&RenderPipelineDescriptor {
+            label: Some("Lighting Pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: VertexState {
+                module: &shader,
+                entry_point: "vs_main",
+                buffers: &[],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(FragmentState {
+                module: &shader,
+                entry_point: "fs_main",
+                targets: &[Some(ColorTargetState {
+                    format,
+                    blend: Some(multiply),
+                    write_mask: ColorWrites::ALL,
+                })],
+                compilation_options: Default::default(),
+            }),
+            primitive: PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: MultisampleState::default(),
+            multiview: None,
+        });
+
+        let uniform_buffer = device.create_buffer(&BufferDescriptor {
+            label: Some("Lighting Uniform Buffer"),
+            size: std::mem::size_of::<LightingUniforms>() as u64,
+            usage: BufferUsages::UNIFORM | BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let bind_group = device.create_bind_group(&BindGroupDescriptor {
+            label: Some("Lighting Bind Group"),
+            layout: &layout,
+            entries: &[BindGroupEntry { binding: 0, resource: uniform_buffer.as_entire_binding() }],
+        });
+
+        Self { pipeline, bind_group, uniform_buffer }
+    }
+
+    /// Upload this frame's lights and occluders
+    pub fn prepare(&self, queue: &Queue, uniforms: &LightingUniforms) {
+        queue.write_buffer(&self.uniform_buffer, 0, bytemuck::bytes_of(uniforms));
+    }
+
+    /// Light what is already drawn in this pass; call after `prepare` and the world, before the HUD
+    pub fn draw<'pass>(&'pass self, render_pass: &mut RenderPass<'pass>) {
+        render_pass.set_pipeline(&self.pipeline);
+        render_pass.set_bind_group(0, &self.bind_group, &[]);
+        render_pass.draw(0..3, 0..1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aetherium_sim::entities::SpeciesType;
+    use aetherium_sim::simulation::{CrystalType, Pillar, Wall};
+
+    #[test]
+    fn test_lights_follow_consciousness_and_safety() {
+        let mut bright = Llama::new_with_species(Vec2::new(600.0, 400.0), SpeciesType::DiscoLlama);
+        bright.consciousness = 3.0;
+        let mut dim = bright.clone();
+        dim.consciousness = 0.5;
+        let mut far = bright.clone();
+        far.position = Vec2::new(-500.0, 400.0);
+        let crystal = ConsciousnessCrystal::new(Vec2::new(300.0, 300.0), CrystalType::Social);
+        let obstacles = Obstacles { walls: vec![Wall::new(Vec2::ZERO, Vec2::new(100.0, 0.0))], pillars: vec![Pillar::new(Vec2::new(700.0, 400.0), 20.0)] };
+
+        let (llamas, crystals) = ([bright, dim, far], [crystal]);
+        let mut selection = LightSelection::new();
+        for frame in 0..20 {
+            selection.update((&[1, 2, 3], &llamas), (&[4], &crystals), &Viewport::full(), frame as f32 * 0.1);
+        }
+        let lit = LightingUniforms::build(&selection, &obstacles, 1.0, &SafetyConfig::default(), &Viewport::full(), 800, 600);
+        assert_eq!(lit.light_count, 2, "dim llamas and lights out of view are left out");
+        assert!(lit.lights.contains(&[600.0, 400.0, 150.0, 1.0]), "faded fully in");
+        assert_eq!((lit.wall_count, lit.pillar_count), (1, 1));
+        assert!((lit.ambient - DARKEST).abs() < 1e-6 && lit.is_active());
+
+        // Safe mode keeps the shadows shallower, and strength 0 switches the pass off
+        let safe = LightingUniforms::build(&LightSelection::new(), &obstacles, 1.0, &SafetyConfig::safe_mode(), &Viewport::full(), 800, 600);
+        assert!(safe.ambient > lit.ambient);
+        assert!(!LightingUniforms::build(&selection, &obstacles, 0.0, &SafetyConfig::default(), &Viewport::full(), 800, 600).is_active());
+    }
+
+    #[test]
+    fn test_lights_keep_their_slots_and_fade() {
+        let glowing = |i: usize, consciousness: f32| {
+            let mut llama = Llama::new_with_species(Vec2::new(30.0 + i as f32 * 30.0, 400.0), SpeciesType::DiscoLlama);
+            llama.consciousness = consciousness;
+            llama
+        };
+        let mut ids: Vec<EntityId> = (0..MAX_LIGHTS as EntityId).collect();
+        let mut llamas: Vec<Llama> = (0..MAX_LIGHTS).map(|i| glowing(i, 1.1)).collect();
+        let mut selection = LightSelection::new();
+        let mut time = 0.0;
+        let mut step = |selection: &mut LightSelection, ids: &[EntityId], llamas: &[Llama]| {
+            let before: Vec<(LightSource, f32)> = selection.held.iter().map(|held| (held.source, held.shown)).collect();
+            selection.update((ids, llamas), (&[], &[]), &Viewport::full(), time);
+            time += 1.0 / 60.0;
+            for held in &selection.held {
+                let shown = before.iter().find(|(source, _)| *source == held.source).map_or(0.0, |&(_, shown)| shown);
+                assert!((held.shown - shown).abs() <= MAX_STEP / LIGHT_FADE_SECONDS + 1e-6, "no light jumps in one frame");
+            }
+        };
+        for _ in 0..60 {
+            step(&mut selection, &ids, &llamas);
+        }
+
+        // A slightly brighter newcomer waits; a far brighter one takes the dimmest slot, gradually
+        ids.push(100);
+        llamas.push(glowing(0, 1.15));
+        step(&mut selection, &ids, &llamas);
+        assert!(selection.held.iter().all(|held| held.lit && held.source != LightSource::Llama(100)));
+        ids.push(101);
+        llamas.push(glowing(1, 3.0));
+        step(&mut selection, &ids, &llamas);
+        assert_eq!(selection.held.iter().filter(|held| !held.lit).count(), 1);
+        assert!(!selection.held.iter().any(|held| held.source == LightSource::Llama(101)), "only once the old light has faded");
+        for _ in 0..60 {
+            step(&mut selection, &ids, &llamas);
+        }
+        assert_eq!(selection.held.len(), MAX_LIGHTS);
+        assert!(selection.held.iter().any(|held| held.source == LightSource::Llama(101) && held.lit));
+        assert!(!selection.held.iter().any(|held| held.source == LightSource::Llama(100)));
+    }
+
+    #[test]
+    fn test_lighting_shader_validates() {
+        crate::rendering::validate_wgsl(include_str!("../reality/shaders/lighting.wgsl"));
+        assert_eq!(std::mem::size_of::<LightingUniforms>(), 4 * 32 * 16 + 48);
+    }
+}
@@ -0,0 +1,161 @@
+// === LEVEL OF DETAIL ===
+// How much of a llama is worth drawing at the size it appears on screen. Big
+// enough, it gets its silhouette, memory fragments and hive aura; a few
+// pixels tall, the extras go; smaller still, it is a single point. In a
+// crowded world the points merge further into density splats, one per cell
+// of a coarse grid, so ten thousand distant llamas cost a few hundred quads.
+// Llamas the camera does not show are not drawn at all, and their behavior
+// can be updated in turns (see `BehaviorStagger` in the simulation).
+
+use std::collections::BTreeMap;
+use glam::{Vec2, Vec3};
+use aetherium_sim::params::{LOD_DETAIL_PIXELS, LOD_POINT_PIXELS, LOD_SPLAT_POPULATION, LOD_STAGGER_TICKS};
+use crate::reality::Vertex;
+use super::viewport::WORLD_SIZE;
+
+/// How much of one llama is drawn
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DetailLevel {
+    Point,  // One dot, or a share of a density splat
+    Simple, // Silhouette only
+    Full,   // Silhouette, memory fragments and aura
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LodConfig {
+    pub point_pixels: f32,       // Llamas smaller than this on screen are points
+    pub detail_pixels: f32,      // Llamas smaller than this lose their memory fragments and aura
+    pub splat_population: usize, // Above this many llamas, points merge into density splats
+    pub stagger_ticks: u32,      // Off-screen llamas update their behavior once in this many ticks
+}
+
+impl Default for LodConfig {
+    fn default() -> Self {
+        Self {
+            point_pixels: LOD_POINT_PIXELS.default,
+            detail_pixels: LOD_DETAIL_PIXELS.default,
+            splat_population: LOD_SPLAT_POPULATION.default as usize,
+            stagger_ticks: LOD_STAGGER_TICKS.default as u32,
+        }
+    }
+}
+
+impl LodConfig {
+    /// Every llama at full detail, updated every tick
+    pub fn disabled() -> Self {
+        Self { point_pixels: 0.0, detail_pixels: 0.0, splat_population: LOD_SPLAT_POPULATION.max as usize, stagger_ticks: 1 }
+    }
+
+    /// Every value forced into its safe range
+    pub fn clamped(self) -> Self {
+        Self {
+            point_pixels: LOD_POINT_PIXELS.clamp(self.point_pixels),
+            detail_pixels: LOD_DETAIL_PIXELS.clamp(self.detail_pixels),
+            splat_population: LOD_SPLAT_POPULATION.clamp(self.splat_population as f32) as usize,
+            stagger_ticks: LOD_STAGGER_TICKS.clamp(self.stagger_ticks as f32) as u32,
+        }
+    }
+
+    /// Detail for a llama `pixels` across on screen
+    pub fn level(&self, pixels: f32) -> DetailLevel {
+        if pixels < self.point_pixels {
+            DetailLevel::Point
+        } else if pixels < self.detail_pixels {
+            DetailLevel::Simple
+        } else {
+            DetailLevel::Full
+        }
+    }
+}
+
+/// Running totals of the llamas inside one grid cell
+#[derive(Debug, Clone, Copy, Default)]
+struct Splat {
+    position: Vec2,
+    color: Vec3,
+    consciousness: f32,
+    count: u32,
+}
+
+/// Point-sized llamas gathered into one soft quad per grid cell
+#[derive(Debug, Clone)]
+pub struct DensitySplats {
+    cell: f32, // World units
+    cells: BTreeMap<(i32, i32), Splat>,
+}
+
+impl DensitySplats {
+    pub fn new(cell: f32) -> Self {
+        Self { cell: cell.max(1.0), cells: BTreeMap::new() }
+    }
+
+    pub fn add(&mut self, position: Vec2, color: Vec3, consciousness: f32) {
+        let key = ((position.x / self.cell).floor() as i32, (position.y / self.cell).floor() as i32);
+        let splat = self.cells.entry(key).or_default();
+        splat.position += position;
+        splat.color += color;
+        splat.consciousness += consciousness;
+        splat.count += 1;
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// One quad per occupied cell at its llamas' mean position and color, growing
+    /// with the square root of their number until it fills the cell
+    pub fn push_vertices(&self, vertices: &mut Vec<Vertex>) {
+        for splat in self.cells.values() {
+            let count = splat.count as f32;
+            let radius = (self.cell * 0.25 * count.sqrt()).min(self.cell * 0.75);
+            push_point(vertices, splat.position / count, radius, splat.color / count, splat.consciousness / count);
+        }
+    }
+}
+
+/// A square dot of `radius` world units
+pub fn push_point(vertices: &mut Vec<Vertex>, position: Vec2, radius: f32, color: Vec3, consciousness: f32) {
+    let x = (position.x / WORLD_SIZE.x) * 2.0 - 1.0;
+    let y = 1.0 - (position.y / WORLD_SIZE.y) * 2.0;
+    let s = radius / WORLD_SIZE.x;
+    let corner = |dx: f32, dy: f32| Vertex {
+        position: [x + dx * s, y + dy * s, 0.0],
+        color: color.to_array(),
+        uv: [(dx + 1.0) * 0.5, (dy + 1.0) * 0.5],
+        species_id: 0.0,
+        consciousness,
+        trip_intensity: 0.0,
+    };
+    vertices.extend([corner(-1.0, -1.0), corner(1.0, -1.0), corner(-1.0, 1.0), corner(1.0, -1.0), corner(1.0, 1.0), corner(-1.0, 1.0)]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detail_drops_with_size_and_crowds_merge() {
+        let lod = LodConfig::default();
+        assert_eq!(lod.level(2.0), DetailLevel::Point);
+        assert_eq!(lod.level(8.0), DetailLevel::Simple);
+        assert_eq!(lod.level(30.0), DetailLevel::Full);
+        assert_eq!(LodConfig::disabled().level(0.5), DetailLevel::Full);
+        assert_eq!(LodConfig { stagger_ticks: 0, ..lod }.clamped().stagger_ticks, 1);
+
+        // A thousand llamas in two cells become two quads
+        let mut splats = DensitySplats::new(40.0);
+        for i in 0..1000 {
+            let x = if i % 2 == 0 { 10.0 } else { 90.0 };
+            splats.add(Vec2::new(x, 10.0 + (i % 20) as f32), Vec3::splat(0.5), 1.0);
+        }
+        assert_eq!(splats.len(), 2);
+        let mut vertices = Vec::new();
+        splats.push_vertices(&mut vertices);
+        assert_eq!(vertices.len(), 12);
+        assert_eq!(vertices[0].color, [0.5; 3]);
+    }
+}
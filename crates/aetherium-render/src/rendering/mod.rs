@@ -0,0 +1,46 @@
+// Rendering module containing graphics and GPU systems
+
+pub mod crystals;
+pub mod distortion;
+pub mod dust;
+pub mod effects;
+pub mod field_overlay;
+pub mod force_overlay;
+pub mod grading;
+pub mod lighting;
+pub mod lod;
+pub mod palette;
+pub mod pheromone_overlay;
+pub mod uniforms;
+pub mod silhouettes;
+pub mod skins;
+pub mod sound_overlay;
+pub mod viewport;
+pub mod warfare_overlay;
+
+pub use crystals::{CrystalInstance, CrystalLook, CrystalPass, CrystalUniforms};
+pub use distortion::{DistortionPass, DistortionUniforms};
+pub use dust::{DustPass, DustUniforms, DUST_PARTICLES};
+pub use effects::*;
+pub use field_overlay::{FieldView, push_field_overlay};
+pub use force_overlay::push_force_overlay;
+pub use grading::{ColorGrade, GradeMood, GradingPass, Lut3d, GRADE_LUT_SIZE, night_amount};
+pub use lighting::{LightSelection, LightingPass, LightingUniforms, MAX_LIGHTS, MAX_OCCLUDERS};
+pub use lod::{DensitySplats, DetailLevel, LodConfig, push_point};
+pub use palette::{HarmonyScheme, PaletteConfig, PaletteDirector};
+pub use pheromone_overlay::push_pheromone_overlay;
+pub use uniforms::PsychedelicUniforms;
+pub use silhouettes::{SilhouettePose, silhouette_triangles, max_silhouette_triangle_count};
+pub use skins::{SkinAtlas, SkinTexture, SKIN_SEED, SKIN_SLOTS, SKIN_TILE_SIZE};
+pub use sound_overlay::{Spectrogram, push_sound_overlay};
+pub use viewport::{Viewport, WORLD_SIZE};
+pub use warfare_overlay::{SpeciesTerritory, desaturate, push_warfare_overlay, species_territories};
+
+/// Parse and validate a WGSL shader the way wgpu would, panicking with naga's report
+#[cfg(test)]
+pub(crate) fn validate_wgsl(source: &str) {
+    let module = naga::front::wgsl::parse_str(source).unwrap_or_else(|e| panic!("{}", e.emit_to_string(source)));
+    naga::valid::Validator::new(naga::valid::ValidationFlags::all(), naga::valid::Capabilities::empty())
+        .validate(&module)
+        .unwrap_or_else(|e| panic!("{:?}", e));
+}
\ No newline at end of file
@@ -0,0 +1,151 @@
+// === PALETTE DIRECTOR ===
+// One harmonic palette for the whole session, so llamas, zones, crystals and
+// signals stop shouting unrelated hues at each other. The palette is three
+// anchor hues around a base picked at startup: complementary while the herd
+// is dim, opening through split-complementary to triadic as its awareness
+// grows, and the base turns slowly around the wheel, faster in a coherent
+// hive. Every subsystem's color is pulled towards the nearest anchor, keeping
+// a little spread around it so neighbors on the same anchor still differ.
+
+use glam::Vec3;
+use aetherium_sim::engine::safety::{hsv_to_rgb_vec3, rgb_to_hsv};
+use aetherium_sim::params::{PALETTE_ROTATION, PALETTE_SPREAD, PALETTE_STRENGTH};
+
+/// Seconds the palette takes to follow most of a change in the herd's awareness
+const OPENING_SECONDS: f32 = 20.0;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PaletteConfig {
+    pub strength: f32, // 0 leaves every color alone, 1 pulls it fully into the palette
+    pub rotation: f32, // Degrees per minute the palette turns in a calm herd
+    pub spread: f32,   // Degrees a hue may keep from its anchor
+}
+
+impl Default for PaletteConfig {
+    fn default() -> Self {
+        Self { strength: PALETTE_STRENGTH.default, rotation: PALETTE_ROTATION.default, spread: PALETTE_SPREAD.default }
+    }
+}
+
+impl PaletteConfig {
+    /// Every subsystem keeps its own colors
+    pub fn disabled() -> Self {
+        Self { strength: 0.0, ..Self::default() }
+    }
+
+    /// Every value forced into its safe range
+    pub fn clamped(self) -> Self {
+        Self {
+            strength: PALETTE_STRENGTH.clamp(self.strength),
+            rotation: PALETTE_ROTATION.clamp(self.rotation),
+            spread: PALETTE_SPREAD.clamp(self.spread),
+        }
+    }
+}
+
+/// The named scheme the palette is closest to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HarmonyScheme {
+    Complementary,
+    SplitComplementary,
+    Triadic,
+}
+
+/// Session-wide palette every color is constrained to
+#[derive(Debug, Clone)]
+pub struct PaletteDirector {
+    config: PaletteConfig,
+    base_hue: f32, // Degrees
+    opening: f32,  // 0 complementary, 0.5 split-complementary, 1 triadic
+}
+
+impl PaletteDirector {
+    pub fn new(config: PaletteConfig, base_hue: f32) -> Self {
+        Self { config: config.clamped(), base_hue: base_hue.rem_euclid(360.0), opening: 0.0 }
+    }
+
+    pub fn config(&self) -> PaletteConfig {
+        self.config
+    }
+
+    pub fn set_config(&mut self, config: PaletteConfig) {
+        self.config = config.clamped();
+    }
+
+    /// Follow the herd: `awareness` (0-1, mean) opens the scheme, `coherence` (0-1) speeds the turning
+    pub fn update(&mut self, dt: f32, awareness: f32, coherence: f32) {
+        let follow = 1.0 - (-dt * 3.0 / OPENING_SECONDS).exp();
+        self.opening += (awareness.clamp(0.0, 1.0) - self.opening) * follow;
+        self.base_hue = (self.base_hue + self.config.rotation / 60.0 * (1.0 + coherence.clamp(0.0, 1.0)) * dt).rem_euclid(360.0);
+    }
+
+    /// The three anchor hues in degrees; complementary repeats its second anchor
+    pub fn anchors(&self) -> [f32; 3] {
+        let split = 180.0 - 60.0 * self.opening;
+        [self.base_hue, (self.base_hue + split).rem_euclid(360.0), (self.base_hue + 360.0 - split).rem_euclid(360.0)]
+    }
+
+    pub fn scheme(&self) -> HarmonyScheme {
+        match self.opening {
+            opening if opening < 0.25 => HarmonyScheme::Complementary,
+            opening if opening < 0.75 => HarmonyScheme::SplitComplementary,
+            _ => HarmonyScheme::Triadic,
+        }
+    }
+
+    /// `hue` (degrees) pulled towards the nearest anchor
+    pub fn hue(&self, hue: f32) -> f32 {
+        if self.config.strength <= 0.0 {
+            return hue;
+        }
+        let (anchor, offset) = self.anchors().into_iter()
+            .map(|anchor| (anchor, arc(anchor, hue)))
+            .min_by(|a, b| a.1.abs().total_cmp(&b.1.abs()))
+            .unwrap_or((hue, 0.0));
+        let target = anchor + offset.clamp(-self.config.spread, self.config.spread);
+        (hue + arc(hue, target) * self.config.strength).rem_euclid(360.0)
+    }
+
+    /// `color` with its hue pulled into the palette; saturation and brightness are kept
+    pub fn color(&self, color: Vec3) -> Vec3 {
+        if self.config.strength <= 0.0 {
+            return color;
+        }
+        let hsv = rgb_to_hsv(color);
+        hsv_to_rgb_vec3(Vec3::new(self.hue(hsv.x), hsv.y, hsv.z))
+    }
+}
+
+/// Signed degrees along the shorter way from `from` to `to`
+fn arc(from: f32, to: f32) -> f32 {
+    (to - from + 540.0).rem_euclid(360.0) - 180.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hues_gather_on_anchors_that_open_and_turn() {
+        let mut palette = PaletteDirector::new(PaletteConfig { strength: 1.0, rotation: 0.0, spread: 10.0 }, 0.0);
+        assert_eq!(palette.scheme(), HarmonyScheme::Complementary);
+        assert_eq!(palette.hue(5.0), 5.0);
+        assert!((palette.hue(40.0) - 10.0).abs() < 1e-3);
+        assert!((palette.hue(150.0) - 170.0).abs() < 1e-3);
+        assert!((palette.hue(355.0) - 355.0).abs() < 1e-3, "pulled across 0 the short way");
+
+        // An awake herd opens the palette to triadic
+        for _ in 0..6000 {
+            palette.update(1.0 / 60.0, 1.0, 0.0);
+        }
+        assert_eq!(palette.scheme(), HarmonyScheme::Triadic);
+        assert!((palette.anchors()[1] - 120.0).abs() < 1.0);
+
+        palette.set_config(PaletteConfig { rotation: 60.0, ..palette.config() });
+        palette.update(1.0, 0.0, 1.0);
+        assert!((palette.anchors()[0] - 2.0).abs() < 1e-3, "a coherent hive turns it twice as fast");
+
+        let red = Vec3::new(1.0, 0.0, 0.0);
+        assert_eq!(PaletteDirector::new(PaletteConfig::disabled(), 90.0).color(red), red);
+    }
+}
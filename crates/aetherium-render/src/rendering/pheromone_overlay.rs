@@ -0,0 +1,68 @@
+// === PHEROMONE OVERLAY ===
+// Makes the llamas' scent trails visible: each cell of the pheromone field is
+// tinted with the overlay colors of the species that marked it, mixed by how
+// much scent each left, and brightened as the scent builds up. Faint cells are
+// skipped, so only the trodden highways show. Like the warfare map it stays
+// dim and sits underneath the llamas.
+
+use glam::{Vec2, Vec3};
+use aetherium_sim::entities::SpeciesType;
+use aetherium_sim::simulation::PheromoneField;
+use crate::reality::Vertex;
+use super::warfare_overlay::{overlay_vertex, territory_color};
+
+/// Scent at which a cell is drawn at full brightness
+const FULL_SCENT: f32 = 2.0;
+/// Brightness of a fully scented cell
+const TRAIL_INTENSITY: f32 = 0.3;
+/// Cells with less scent than this are not drawn
+const MIN_VISIBLE_SCENT: f32 = 0.05;
+
+/// One quad per scented cell; draw before the llamas so the trails sit underneath
+pub fn push_pheromone_overlay(vertices: &mut Vec<Vertex>, field: &PheromoneField, background: Vec3) {
+    let size = field.cell_size();
+    for row in 0..field.rows() {
+        for column in 0..field.columns() {
+            let scent = field.cell(column, row);
+            let total: f32 = scent.iter().sum();
+            if total < MIN_VISIBLE_SCENT {
+                continue;
+            }
+            let hue: Vec3 = SpeciesType::ALL.iter()
+                .map(|&species| territory_color(species) * scent[species.to_index()])
+                .sum::<Vec3>() / total;
+            let color = background + hue * TRAIL_INTENSITY * (total / FULL_SCENT).min(1.0);
+
+            let min = Vec2::new(column as f32, row as f32) * size;
+            let max = min + Vec2::splat(size);
+            vertices.extend([
+                overlay_vertex(min, color),
+                overlay_vertex(Vec2::new(max.x, min.y), color),
+                overlay_vertex(max, color),
+                overlay_vertex(min, color),
+                overlay_vertex(max, color),
+                overlay_vertex(Vec2::new(min.x, max.y), color),
+            ]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aetherium_sim::engine::is_dangerous_red;
+
+    #[test]
+    fn test_only_scented_cells_are_drawn_dimly() {
+        let mut field = PheromoneField::new(Vec2::new(1200.0, 800.0));
+        let mut vertices = Vec::new();
+        push_pheromone_overlay(&mut vertices, &field, Vec3::ZERO);
+        assert!(vertices.is_empty());
+
+        field.deposit(Vec2::new(610.0, 410.0), SpeciesType::BassDropVicuna, 4.0);
+        push_pheromone_overlay(&mut vertices, &field, Vec3::ZERO);
+        assert_eq!(vertices.len(), 6);
+        let color = Vec3::from(vertices[0].color);
+        assert!(color.max_element() <= TRAIL_INTENSITY && !is_dangerous_red(color));
+    }
+}
@@ -0,0 +1,262 @@
+// Procedural species silhouettes - llama, sheep, camel and vicuna shapes built from
+// simple parts so each species reads at a glance, with legs and necks posed per frame
+
+use glam::Vec2;
+use aetherium_sim::entities::{AnimationFrame, SpeciesType};
+
+/// Segments used for every elliptical part
+const ELLIPSE_SEGMENTS: usize = 10;
+
+/// Per-frame deformation applied to a silhouette
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SilhouettePose {
+    pub leg_phase: f32,  // Walk cycle position in radians
+    pub leg_swing: f32,  // Maximum leg angle in radians
+    pub neck_bob: f32,   // -1.0 to 1.0, head dips/lifts with the beat
+    pub head_dip: f32,   // 0.0 = head up, 1.0 = head lowered to the ground
+    pub lean: f32,       // Whole-body rotation in radians (positive leans forward)
+    pub lift: f32,       // Vertical offset in local units
+    pub squash: f32,     // Vertical scale (1.0 = rest shape)
+    pub facing: f32,     // 1.0 faces right, -1.0 faces left
+}
+
+impl Default for SilhouettePose {
+    fn default() -> Self {
+        Self {
+            leg_phase: 0.0,
+            leg_swing: 0.0,
+            neck_bob: 0.0,
+            head_dip: 0.0,
+            lean: 0.0,
+            lift: 0.0,
+            squash: 1.0,
+            facing: 1.0,
+        }
+    }
+}
+
+impl SilhouettePose {
+    /// Pose from an animation frame, facing the given direction
+    pub fn from_animation(frame: &AnimationFrame, facing: f32) -> Self {
+        Self {
+            leg_phase: frame.leg_phase,
+            leg_swing: frame.leg_swing,
+            neck_bob: frame.neck_bob,
+            head_dip: frame.head_dip,
+            lean: frame.lean,
+            lift: frame.lift,
+            squash: frame.squash,
+            facing,
+        }
+    }
+}
+
+/// Body plan for one species in local space (x forward, y up, roughly -1..1)
+struct BodyPlan {
+    body: &'static [(Vec2, Vec2)], // Ellipses: center, radii
+    hip_y: f32,
+    leg_xs: [f32; 4],
+    leg_length: f32,
+    leg_width: f32,
+    neck: &'static [Vec2], // Joint chain from shoulder to head
+    neck_width: f32,
+    head_radii: Vec2,
+    ears: bool,
+}
+
+const LLAMA_BODY: &[(Vec2, Vec2)] = &[
+    (Vec2::new(-0.1, 0.0), Vec2::new(0.55, 0.3)),
+    (Vec2::new(-0.68, 0.12), Vec2::new(0.1, 0.08)), // Tail tuft
+];
+const LLAMA_NECK: &[Vec2] = &[Vec2::new(0.3, 0.1), Vec2::new(0.5, 0.7)];
+
+const SHEEP_BODY: &[(Vec2, Vec2)] = &[
+    (Vec2::new(-0.1, 0.0), Vec2::new(0.5, 0.32)),
+    (Vec2::new(-0.45, 0.12), Vec2::new(0.22, 0.22)), // Wool puffs
+    (Vec2::new(-0.15, 0.22), Vec2::new(0.24, 0.2)),
+    (Vec2::new(0.2, 0.15), Vec2::new(0.22, 0.2)),
+    (Vec2::new(-0.3, -0.15), Vec2::new(0.22, 0.18)),
+    (Vec2::new(0.1, -0.15), Vec2::new(0.22, 0.18)),
+];
+const SHEEP_NECK: &[Vec2] = &[Vec2::new(0.35, 0.05), Vec2::new(0.55, 0.12)];
+
+const CAMEL_BODY: &[(Vec2, Vec2)] = &[
+    (Vec2::new(-0.05, -0.05), Vec2::new(0.5, 0.25)),
+    (Vec2::new(-0.25, 0.2), Vec2::new(0.17, 0.2)), // Humps
+    (Vec2::new(0.15, 0.2), Vec2::new(0.17, 0.2)),
+];
+const CAMEL_NECK: &[Vec2] = &[Vec2::new(0.4, 0.0), Vec2::new(0.65, 0.3), Vec2::new(0.7, 0.55)];
+
+const VICUNA_BODY: &[(Vec2, Vec2)] = &[
+    (Vec2::new(-0.1, 0.0), Vec2::new(0.48, 0.24)),
+    (Vec2::new(0.3, -0.05), Vec2::new(0.16, 0.2)), // Chest bib
+];
+const VICUNA_NECK: &[Vec2] = &[Vec2::new(0.3, 0.1), Vec2::new(0.42, 0.45), Vec2::new(0.5, 0.8)];
+
+fn body_plan(species: &SpeciesType) -> BodyPlan {
+    match species {
+        SpeciesType::DiscoLlama => BodyPlan {
+            body: LLAMA_BODY,
+            hip_y: -0.15,
+            leg_xs: [-0.5, -0.3, 0.1, 0.3],
+            leg_length: 0.7,
+            leg_width: 0.1,
+            neck: LLAMA_NECK,
+            neck_width: 0.16,
+            head_radii: Vec2::new(0.2, 0.12),
+            ears: true,
+        },
+        SpeciesType::QuantumSheep => BodyPlan {
+            body: SHEEP_BODY,
+            hip_y: -0.25,
+            leg_xs: [-0.4, -0.2, 0.05, 0.25],
+            leg_length: 0.45,
+            leg_width: 0.09,
+            neck: SHEEP_NECK,
+            neck_width: 0.14,
+            head_radii: Vec2::new(0.18, 0.14),
+            ears: true,
+        },
+        SpeciesType::HypnoCamel => BodyPlan {
+            body: CAMEL_BODY,
+            hip_y: -0.2,
+            leg_xs: [-0.4, -0.25, 0.15, 0.3],
+            leg_length: 0.75,
+            leg_width: 0.09,
+            neck: CAMEL_NECK,
+            neck_width: 0.13,
+            head_radii: Vec2::new(0.17, 0.1),
+            ears: false,
+        },
+        SpeciesType::BassDropVicuna => BodyPlan {
+            body: VICUNA_BODY,
+            hip_y: -0.12,
+            leg_xs: [-0.45, -0.28, 0.12, 0.28],
+            leg_length: 0.8,
+            leg_width: 0.07,
+            neck: VICUNA_NECK,
+            neck_width: 0.11,
+            head_radii: Vec2::new(0.16, 0.1),
+            ears: true,
+        },
+    }
+}
+
+fn push_ellipse(triangles: &mut Vec<[Vec2; 3]>, center: Vec2, radii: Vec2) {
+    for i in 0..ELLIPSE_SEGMENTS {
+        let angle1 = (i as f32 / ELLIPSE_SEGMENTS as f32) * std::f32::consts::TAU;
+        let angle2 = ((i + 1) as f32 / ELLIPSE_SEGMENTS as f32) * std::f32::consts::TAU;
+        triangles.push([
+            center,
+            center + Vec2::new(angle1.cos(), angle1.sin()) * radii,
+            center + Vec2::new(angle2.cos(), angle2.sin()) * radii,
+        ]);
+    }
+}
+
+/// Thick line segment as two triangles
+fn push_limb(triangles: &mut Vec<[Vec2; 3]>, start: Vec2, end: Vec2, width: f32) {
+    let direction = (end - start).normalize_or_zero();
+    let side = direction.perp() * (width * 0.5);
+    triangles.push([start - side, start + side, end + side]);
+    triangles.push([start - side, end + side, end - side]);
+}
+
+/// Build a species silhouette as local-space triangles (x forward, y up)
+pub fn silhouette_triangles(species: &SpeciesType, pose: &SilhouettePose) -> Vec<[Vec2; 3]> {
+    let plan = body_plan(species);
+    let mut triangles = Vec::with_capacity(silhouette_triangle_count(species));
+
+    // Legs first so the body overlaps the hips; diagonal pairs move together
+    let leg_offsets = [0.0, std::f32::consts::PI, std::f32::consts::PI, 0.0];
+    for (leg_x, offset) in plan.leg_xs.iter().zip(leg_offsets) {
+        let angle = (pose.leg_phase + offset).sin() * pose.leg_swing;
+        let hip = Vec2::new(*leg_x, plan.hip_y);
+        let foot = hip + Vec2::new(angle.sin(), -angle.cos()) * plan.leg_length;
+        push_limb(&mut triangles, hip, foot, plan.leg_width);
+    }
+
+    for (center, radii) in plan.body {
+        push_ellipse(&mut triangles, *center, *radii);
+    }
+
+    // Neck chain - bobbing and dipping move every joint after the shoulder, more at the tip
+    let bob = pose.neck_bob.clamp(-1.0, 1.0) * 0.08;
+    let shoulder = plan.neck.first().copied().unwrap_or(Vec2::ZERO);
+    let ground_reach = Vec2::new(shoulder.x + 0.35, plan.hip_y - plan.leg_length + plan.head_radii.y);
+    let joints: Vec<Vec2> = plan.neck.iter().enumerate()
+        .map(|(i, joint)| {
+            let along = i as f32 / (plan.neck.len() - 1).max(1) as f32;
+            let dipped = joint.lerp(shoulder + (ground_reach - shoulder) * along, pose.head_dip.clamp(0.0, 1.0) * along);
+            dipped + Vec2::new(0.0, bob * along)
+        })
+        .collect();
+    for pair in joints.windows(2) {
+        push_limb(&mut triangles, pair[0], pair[1], plan.neck_width);
+    }
+
+    let head_center = *joints.last().unwrap_or(&Vec2::ZERO) + Vec2::new(plan.head_radii.x * 0.5, 0.0);
+    push_ellipse(&mut triangles, head_center, plan.head_radii);
+
+    if plan.ears {
+        let ear_base = head_center + Vec2::new(-plan.head_radii.x * 0.3, plan.head_radii.y * 0.6);
+        triangles.push([
+            ear_base,
+            ear_base + Vec2::new(0.08, 0.0),
+            ear_base + Vec2::new(0.0, 0.14),
+        ]);
+    }
+
+    // Whole-body deformation: squash, lean forward, lift, then mirror for facing
+    let (lean_sin, lean_cos) = (-pose.lean).sin_cos();
+    for triangle in &mut triangles {
+        for corner in triangle.iter_mut() {
+            let squashed = Vec2::new(corner.x, corner.y * pose.squash);
+            let leaned = Vec2::new(
+                squashed.x * lean_cos - squashed.y * lean_sin,
+                squashed.x * lean_sin + squashed.y * lean_cos,
+            );
+            *corner = Vec2::new(leaned.x * pose.facing.signum(), leaned.y + pose.lift);
+        }
+    }
+
+    triangles
+}
+
+/// Triangle count for a species silhouette (for vertex budgeting)
+pub fn silhouette_triangle_count(species: &SpeciesType) -> usize {
+    let plan = body_plan(species);
+    let legs = plan.leg_xs.len() * 2;
+    let body = plan.body.len() * ELLIPSE_SEGMENTS;
+    let neck = plan.neck.len().saturating_sub(1) * 2;
+    let head = ELLIPSE_SEGMENTS + if plan.ears { 1 } else { 0 };
+    legs + body + neck + head
+}
+
+/// Largest triangle count of any species silhouette
+pub fn max_silhouette_triangle_count() -> usize {
+    SpeciesType::ALL
+        .iter()
+        .map(silhouette_triangle_count)
+        .max()
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_triangle_count_matches_every_pose() {
+        let mirrored = SilhouettePose { facing: -1.0, leg_phase: 1.3, leg_swing: 0.6, neck_bob: -1.0, lean: 0.4, ..SilhouettePose::default() };
+        let extreme = SilhouettePose { head_dip: 1.0, squash: 0.0, neck_bob: 1.0, lift: 0.5, ..SilhouettePose::default() };
+        for species in SpeciesType::ALL {
+            for pose in [SilhouettePose::default(), mirrored, extreme] {
+                let triangles = silhouette_triangles(&species, &pose);
+                assert_eq!(triangles.len(), silhouette_triangle_count(&species), "{:?} in {:?}", species, pose);
+                assert!(triangles.iter().flatten().all(|corner| corner.is_finite()), "{:?} in {:?}", species, pose);
+            }
+            assert!(silhouette_triangle_count(&species) <= max_silhouette_triangle_count());
+        }
+    }
+}
@@ -0,0 +1,292 @@
+// === SPECIES SKINS ===
+// Llamas wear a living pattern instead of a flat color. At startup a tile of
+// seamless noise is grown for every shader species slot: glitter facets for
+// disco llamas, interference ripples for quantum sheep, warped hypnotic bands
+// for camels, ridged veins for the fractal memory fragments and stacked
+// strata for bass drop vicunas. Each tile is colored through its species' hue
+// range and packed side by side into one atlas. The psychedelic theme drifts
+// and swirls its lookup into the tile over time and lays it over the vertex
+// color; the alpha channel says how strongly, so a zero-alpha atlas turns the
+// skins off without touching a pipeline.
+
+use std::f32::consts::TAU;
+use glam::Vec2;
+use wgpu::*;
+use aetherium_sim::entities::SpeciesType;
+use crate::reality::hsv_to_rgb;
+
+/// Tiles in the atlas, one per shader species id (0=Disco, 1=Quantum, 2=Hypno, 3=Fractal, 4=BassDrop)
+pub const SKIN_SLOTS: usize = 5;
+/// Texels on a side of one tile; `psychedelic.wgsl` insets its lookups by half a texel of this
+pub const SKIN_TILE_SIZE: usize = 128;
+/// Seed every atlas grows from, so the same species always wears the same skin
+pub const SKIN_SEED: u32 = 0x5EED_B100;
+/// How far a skin pulls the vertex color toward its pattern, 0-1
+const SKIN_STRENGTH: f32 = 0.55;
+/// Hue range and saturation of the fractal slot, which no species owns; memory fragments use it
+const FRACTAL_PALETTE: ((f32, f32), f32) = ((180.0, 280.0), 0.7);
+
+/// Noise-grown pattern tiles for every species slot, as RGBA8 texels
+#[derive(Debug, Clone)]
+pub struct SkinAtlas {
+    texels: Vec<u8>, // Row-major, SKIN_SLOTS tiles wide and one tile high
+}
+
+impl SkinAtlas {
+    /// Grow every species' tile from `seed`
+    pub fn generate(seed: u32) -> Self {
+        let width = Self::width() as usize;
+        let mut texels = vec![0; width * SKIN_TILE_SIZE * 4];
+        for slot in 0..SKIN_SLOTS {
+            let ((hue_low, hue_high), saturation) = slot_palette(slot);
+            let slot_seed = seed.wrapping_add((slot as u32).wrapping_mul(0x9E37_79B9));
+            for y in 0..SKIN_TILE_SIZE {
+                for x in 0..SKIN_TILE_SIZE {
+                    let p = Vec2::new(x as f32, y as f32) / SKIN_TILE_SIZE as f32;
+                    let t = slot_pattern(slot, p, slot_seed).clamp(0.0, 1.0);
+                    let hue = (hue_low + (hue_high - hue_low) * t).rem_euclid(360.0);
+                    let color = hsv_to_rgb(hue, saturation, 0.4 + 0.6 * t);
+                    let offset = (y * width + slot * SKIN_TILE_SIZE + x) * 4;
+                    texels[offset..offset + 4].copy_from_slice(&[unit_byte(color.x), unit_byte(color.y), unit_byte(color.z), unit_byte(SKIN_STRENGTH)]);
+                }
+            }
+        }
+        Self { texels }
+    }
+
+    /// An atlas that leaves every vertex color as it is
+    pub fn blank() -> Self {
+        Self { texels: vec![0; Self::width() as usize * SKIN_TILE_SIZE * 4] }
+    }
+
+    pub fn width() -> u32 {
+        (SKIN_SLOTS * SKIN_TILE_SIZE) as u32
+    }
+
+    pub fn height() -> u32 {
+        SKIN_TILE_SIZE as u32
+    }
+
+    pub fn rgba8(&self) -> &[u8] {
+        &self.texels
+    }
+
+    /// One texel of a slot's tile
+    pub fn texel(&self, slot: usize, x: usize, y: usize) -> [u8; 4] {
+        let offset = (y * Self::width() as usize + slot * SKIN_TILE_SIZE + x) * 4;
+        [self.texels[offset], self.texels[offset + 1], self.texels[offset + 2], self.texels[offset + 3]]
+    }
+}
+
+fn unit_byte(value: f32) -> u8 {
+    (value.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+/// Hue range and saturation a slot is colored through, taken from the species that draws with it
+fn slot_palette(slot: usize) -> ((f32, f32), f32) {
+    SpeciesType::ALL.iter()
+        .find(|species| species.to_shader_id() as usize == slot)
+        .map_or(FRACTAL_PALETTE, |species| {
+            let config = species.get_base_config();
+            (config.base_hue_range, config.base_saturation)
+        })
+}
+
+/// The pattern of a slot at `p` in its tile, 0-1; every pattern wraps seamlessly at the tile's edges
+fn slot_pattern(slot: usize, p: Vec2, seed: u32) -> f32 {
+    let warp = fbm(p, 4, 4, seed);
+    match slot {
+        // Mirror-ball facets, a few of them glinting
+        0 => {
+            let cell = (p * 12.0).floor();
+            let facet = hash(cell.x as u32, cell.y as u32, seed);
+            let glint = if facet > 0.92 { 1.0 } else { 0.0 };
+            (0.25 + facet * 0.5 + glint * 0.5) * (0.7 + 0.3 * warp)
+        }
+        // Two interference waves bent by the noise
+        1 => {
+            let a = (TAU * (3.0 * p.x + 2.0 * p.y) + warp * 6.0).sin();
+            let b = (TAU * (2.0 * p.x - 3.0 * p.y) - warp * 4.0).cos();
+            0.5 + 0.5 * a * b
+        }
+        // Bands folded through the noise into hypnotic swirls
+        2 => 0.5 + 0.5 * (TAU * 4.0 * p.y + warp * 9.0).sin(),
+        // Ridged veins, sharpest where octaves agree
+        3 => {
+            let ridged = 1.0 - (fbm(p, 3, 5, seed ^ 0xA5A5) * 2.0 - 1.0).abs();
+            ridged * ridged
+        }
+        // Stacked strata, squared off like a spectrum display
+        _ => {
+            let strata = (TAU * 6.0 * p.y + warp * 3.0).sin();
+            0.5 + 0.5 * strata.signum() * strata.abs().sqrt()
+        }
+    }
+}
+
+/// A lattice value hashed to 0-1
+fn hash(x: u32, y: u32, seed: u32) -> f32 {
+    let mut h = x.wrapping_mul(0x8DA6_B343) ^ y.wrapping_mul(0xD816_3841) ^ seed;
+    h ^= h >> 15;
+    h = h.wrapping_mul(0x2C1B_3C6D);
+    h ^= h >> 12;
+    h = h.wrapping_mul(0x297A_2D39);
+    h ^= h >> 15;
+    (h & 0xFFFF) as f32 / 65535.0
+}
+
+/// Value noise over a lattice of `period` cells per tile, wrapping at the tile's edges
+fn value_noise(p: Vec2, period: u32, seed: u32) -> f32 {
+    let scaled = p * period as f32;
+    let cell = scaled.floor();
+    let f = scaled - cell;
+    let smooth = f * f * (Vec2::splat(3.0) - 2.0 * f);
+    let corner = |dx: u32, dy: u32| {
+        hash((cell.x as u32 + dx) % period, (cell.y as u32 + dy) % period, seed)
+    };
+    let top = corner(0, 0) + (corner(1, 0) - corner(0, 0)) * smooth.x;
+    let bottom = corner(0, 1) + (corner(1, 1) - corner(0, 1)) * smooth.x;
+    top + (bottom - top) * smooth.y
+}
+
+/// Octaves of value noise, each twice as fine and half as strong, normalized to 0-1
+fn fbm(p: Vec2, base_period: u32, octaves: u32, seed: u32) -> f32 {
+    let (mut total, mut weight, mut amplitude) = (0.0, 0.0, 1.0);
+    for octave in 0..octaves {
+        total += value_noise(p, base_period << octave, seed.wrapping_add(octave)) * amplitude;
+        weight += amplitude;
+        amplitude *= 0.5;
+    }
+    total / weight
+}
+
+/// The atlas on the GPU, bound beside the theme uniforms
+pub struct SkinTexture {
+    texture: Texture,
+    view: TextureView,
+    sampler: Sampler,
+    atlas: SkinAtlas,
+    enabled: bool,
+}
+
+impl SkinTexture {
+    pub fn new(device: &Device, queue: &Queue, atlas: SkinAtlas, enabled: bool) -> Self {
+        let texture = device.create_texture(&TextureDescriptor {
+            label: Some("Species Skin Atlas"),
+            size: Extent3d { width: SkinAtlas::width(), height: SkinAtlas::height(), depth_or_array_layers: 1 },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: TextureDimension::D2,
+            format: TextureFormat::Rgba8Unorm,
+            usage: TextureUsages::TEXTURE_BINDING | TextureUsages::COPY_DST,
+            view_formats: &[],
+        });
+        let view = texture.create_view(&TextureViewDescriptor::default());
+        let sampler = device.create_sampler(&SamplerDescriptor {
+            label: Some("Species Skin Sampler"),
+            address_mode_u: AddressMode::ClampToEdge,
+            address_mode_v: AddressMode::Repeat,
+            mag_filter: FilterMode::Linear,
+            min_filter: FilterMode::Linear,
+            ..Default::default()
+        });
+
+        let skins = Self { texture, view, sampler, atlas, enabled };
+        skins.upload(queue);
+        skins
+    }
+
+    /// Layout entries for the atlas and its sampler at `first_binding` and the one after
+    pub fn layout_entries(first_binding: u32) -> [BindGroupLayoutEntry; 2] {
+        [
+            BindGroupLayoutEntry {
+                binding: first_binding,
+                visibility: ShaderStages::FRAGMENT,
+                ty: BindingType::Texture {
+                    sample_type: TextureSampleType::Float { filterable: true },
+                    view_dimension: TextureViewDimension::D2,
+                    multisampled: false,
+                },
+                count: None,
+            },
+            BindGroupLayoutEntry {
+                binding: first_binding + 1,
+                visibility: ShaderStages::FRAGMENT,
+                ty: BindingType::Sampler(SamplerBindingType::Filtering),
+                count: None,
+            },
+        ]
+    }
+
+    /// Bind group entries matching `layout_entries`
+    pub fn bind_group_entries(&self, first_binding: u32) -> [BindGroupEntry<'_>; 2] {
+        [
+            BindGroupEntry { binding: first_binding, resource: BindingResource::TextureView(&self.view) },
+            BindGroupEntry { binding: first_binding + 1, resource: BindingResource::Sampler(&self.sampler) },
+        ]
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Show the grown skins, or swap in a blank atlas that leaves vertex colors flat
+    pub fn set_enabled(&mut self, queue: &Queue, enabled: bool) {
+        if self.enabled != enabled {
+            self.enabled = enabled;
+            self.upload(queue);
+        }
+    }
+
+    fn upload(&self, queue: &Queue) {
+        let blank;
+        let atlas = if self.enabled {
+            &self.atlas
+        } else {
+            blank = SkinAtlas::blank();
+            &blank
+        };
+        queue.write_texture(
+            ImageCopyTexture { texture: &self.texture, mip_level: 0, origin: Origin3d::ZERO, aspect: TextureAspect::All },
+            atlas.rgba8(),
+            ImageDataLayout { offset: 0, bytes_per_row: Some(4 * SkinAtlas::width()), rows_per_image: Some(SkinAtlas::height()) },
+            Extent3d { width: SkinAtlas::width(), height: SkinAtlas::height(), depth_or_array_layers: 1 },
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_skins_are_seamless_and_follow_species_palettes() {
+        let atlas = SkinAtlas::generate(SKIN_SEED);
+        assert_eq!(atlas.rgba8().len(), (SkinAtlas::width() * SkinAtlas::height() * 4) as usize);
+        assert_eq!(atlas.rgba8(), SkinAtlas::generate(SKIN_SEED).rgba8(), "the same seed grows the same skins");
+
+        for slot in 0..SKIN_SLOTS {
+            // Every tile carries detail rather than one flat color
+            let lumas: Vec<u32> = (0..SKIN_TILE_SIZE).map(|i| atlas.texel(slot, i, (i * 7) % SKIN_TILE_SIZE)[..3].iter().map(|&c| c as u32).sum()).collect();
+            assert!(lumas.iter().max().unwrap() - lumas.iter().min().unwrap() > 60, "slot {slot} is flat");
+            assert_eq!(atlas.texel(slot, 3, 5)[3], unit_byte(SKIN_STRENGTH));
+        }
+
+        // The noise wraps, so a tile's edge meets its opposite edge without a seam
+        for seed in [1, SKIN_SEED] {
+            for i in 0..16 {
+                let p = i as f32 / 16.0;
+                assert!((fbm(Vec2::new(0.0, p), 4, 4, seed) - fbm(Vec2::new(1.0, p), 4, 4, seed)).abs() < 1e-4);
+                assert!((fbm(Vec2::new(p, 0.0), 4, 4, seed) - fbm(Vec2::new(p, 1.0), 4, 4, seed)).abs() < 1e-4);
+            }
+        }
+
+        // Quantum sheep wear purple: blue and red outweigh green across their tile
+        let [r, g, b] = (0..SKIN_TILE_SIZE).fold([0u32; 3], |sum, x| {
+            let texel = atlas.texel(SpeciesType::QuantumSheep.to_shader_id() as usize, x, x);
+            [sum[0] + texel[0] as u32, sum[1] + texel[1] as u32, sum[2] + texel[2] as u32]
+        });
+        assert!(b > g && r > g);
+        assert!(SkinAtlas::blank().rgba8().iter().all(|&byte| byte == 0));
+    }
+}
@@ -0,0 +1,134 @@
+// === SOUND OVERLAY ===
+// Shows what the synthesizer is doing: the live waveform bends a ring around
+// the middle of the screen like an oscilloscope wrapped into a circle, and a
+// spectrogram strip along the bottom scrolls the recent spectrum from right
+// to left, low bands at the bottom. Colors stay in dim blues and cyans, and
+// the spectrogram decays instead of dropping so it never flickers.
+
+use std::collections::VecDeque;
+use glam::{Vec2, Vec3};
+use aetherium_sim::engine::safety::hsv_to_rgb_vec3;
+use crate::reality::Vertex;
+use super::viewport::WORLD_SIZE;
+
+/// Spectrum bands per column; the same as the audio scope's, which the app hands over as they are
+pub const SPECTRUM_BANDS: usize = 32;
+
+/// Spectrogram columns kept, one per rendered frame
+pub const SPECTROGRAM_COLUMNS: usize = 120;
+/// Ring segments, each one a short thick line
+const RING_SEGMENTS: usize = 160;
+const RING_RADIUS: f32 = 260.0;
+/// Ring displacement at full-scale output
+const RING_SWING: f32 = 90.0;
+const RING_THICKNESS: f32 = 2.0;
+/// Share of a band's level kept from one column to the next
+const SPECTRUM_DECAY: f32 = 0.85;
+/// Spectrogram strip, in screen units
+const STRIP_MIN: Vec2 = Vec2::new(20.0, 600.0);
+const STRIP_MAX: Vec2 = Vec2::new(1180.0, 690.0);
+
+/// Recent spectra, oldest first
+#[derive(Default)]
+pub struct Spectrogram {
+    columns: VecDeque<[f32; SPECTRUM_BANDS]>,
+}
+
+impl Spectrogram {
+    /// Add the newest spectrum; bands fall back slowly rather than dropping out
+    pub fn push(&mut self, spectrum: [f32; SPECTRUM_BANDS]) {
+        let previous = self.columns.back().copied().unwrap_or([0.0; SPECTRUM_BANDS]);
+        let mut column = [0.0; SPECTRUM_BANDS];
+        for (band, level) in column.iter_mut().enumerate() {
+            *level = spectrum[band].max(previous[band] * SPECTRUM_DECAY);
+        }
+        if self.columns.len() >= SPECTROGRAM_COLUMNS {
+            self.columns.pop_front();
+        }
+        self.columns.push_back(column);
+    }
+
+    pub fn clear(&mut self) {
+        self.columns.clear();
+    }
+
+    pub fn len(&self) -> usize {
+        self.columns.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.columns.is_empty()
+    }
+}
+
+fn to_clip(screen: Vec2) -> [f32; 3] {
+    [screen.x / WORLD_SIZE.x * 2.0 - 1.0, 1.0 - screen.y / WORLD_SIZE.y * 2.0, 0.0]
+}
+
+fn overlay_vertex(screen: Vec2, color: Vec3) -> Vertex {
+    Vertex { position: to_clip(screen), color: color.into(), uv: [0.5, 0.5], species_id: 0.0, consciousness: 0.2, trip_intensity: 0.0 }
+}
+
+fn push_quad(vertices: &mut Vec<Vertex>, corners: [Vec2; 4], color: Vec3) {
+    let [a, b, c, d] = corners.map(|corner| overlay_vertex(corner, color));
+    vertices.extend([a, b, c, a, c, d]);
+}
+
+/// Dim blue for silence up to soft cyan at full scale; never near red
+fn level_color(level: f32) -> Vec3 {
+    let level = level.clamp(0.0, 1.0);
+    hsv_to_rgb_vec3(Vec3::new(230.0 - level * 50.0, 0.7, 0.08 + level * 0.4))
+}
+
+/// Oscilloscope ring and spectrogram strip, in screen coordinates; draw with the HUD
+pub fn push_sound_overlay(vertices: &mut Vec<Vertex>, waveform: &[f32], spectrogram: &Spectrogram) {
+    if !waveform.is_empty() {
+        let center = WORLD_SIZE * 0.5;
+        let point = |segment: usize| {
+            let angle = segment as f32 / RING_SEGMENTS as f32 * std::f32::consts::TAU;
+            let sample = waveform[segment % RING_SEGMENTS * waveform.len() / RING_SEGMENTS].clamp(-1.0, 1.0);
+            (center + Vec2::new(angle.cos(), angle.sin()) * (RING_RADIUS + sample * RING_SWING), sample)
+        };
+        for segment in 0..RING_SEGMENTS {
+            let ((start, level), (end, _)) = (point(segment), point(segment + 1));
+            let normal = (end - start).perp().try_normalize().unwrap_or(Vec2::Y) * RING_THICKNESS;
+            push_quad(vertices, [start - normal, start + normal, end + normal, end - normal], level_color(0.3 + level.abs()));
+        }
+    }
+
+    let cell = Vec2::new((STRIP_MAX.x - STRIP_MIN.x) / SPECTROGRAM_COLUMNS as f32, (STRIP_MAX.y - STRIP_MIN.y) / SPECTRUM_BANDS as f32);
+    let first_column = SPECTROGRAM_COLUMNS - spectrogram.columns.len(); // The newest column sits at the right edge
+    for (index, column) in spectrogram.columns.iter().enumerate() {
+        let x = STRIP_MIN.x + (first_column + index) as f32 * cell.x;
+        for (band, &level) in column.iter().enumerate() {
+            let y = STRIP_MAX.y - (band + 1) as f32 * cell.y;
+            let (min, max) = (Vec2::new(x, y), Vec2::new(x + cell.x, y + cell.y));
+            push_quad(vertices, [min, Vec2::new(max.x, min.y), max, Vec2::new(min.x, max.y)], level_color(level));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aetherium_sim::engine::safety::is_dangerous_red;
+
+    #[test]
+    fn test_spectrogram_scrolls_and_decays_safely() {
+        let mut spectrogram = Spectrogram::default();
+        let mut loud = [0.0; SPECTRUM_BANDS];
+        loud[3] = 1.0;
+        spectrogram.push(loud);
+        spectrogram.push([0.0; SPECTRUM_BANDS]);
+        assert!((spectrogram.columns[1][3] - SPECTRUM_DECAY).abs() < 1e-6, "levels fall back gradually");
+        for _ in 0..SPECTROGRAM_COLUMNS * 2 {
+            spectrogram.push(loud);
+        }
+        assert_eq!(spectrogram.len(), SPECTROGRAM_COLUMNS);
+
+        let mut vertices = Vec::new();
+        push_sound_overlay(&mut vertices, &[1.0, -1.0, 0.5, 0.0], &spectrogram);
+        assert_eq!(vertices.len(), (RING_SEGMENTS + SPECTROGRAM_COLUMNS * SPECTRUM_BANDS) * 6);
+        assert!(vertices.iter().all(|vertex| !is_dangerous_red(Vec3::from(vertex.color)) && Vec3::from(vertex.color).max_element() <= 0.5));
+    }
+}
@@ -0,0 +1,90 @@
+// === VIEWPORTS ===
+// Every output window shows a rectangle of the shared 1200x800 world. The
+// frame is built once in whole-world clip space; each viewport then rescales
+// it so its rectangle fills the window, which lets several projectors or
+// displays tile one simulation.
+
+use glam::Vec2;
+use crate::reality::Vertex;
+
+pub use aetherium_sim::simulation::WORLD_SIZE;
+
+/// Rectangle of the world shown by one output, in world units (y down)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Viewport {
+    pub min: Vec2,
+    pub max: Vec2,
+}
+
+impl Default for Viewport {
+    fn default() -> Self {
+        Self::full()
+    }
+}
+
+impl Viewport {
+    /// The whole world
+    pub fn full() -> Self {
+        Self { min: Vec2::ZERO, max: WORLD_SIZE }
+    }
+
+    /// Side-by-side strips of the world, each as wide as its share of `widths`
+    /// (e.g. the pixel widths of a row of projectors, left to right)
+    pub fn columns(widths: &[u32]) -> Vec<Self> {
+        let total: u32 = widths.iter().sum();
+        if total == 0 {
+            return widths.iter().map(|_| Self::full()).collect();
+        }
+
+        let mut left = 0;
+        widths.iter().map(|&width| {
+            let min_x = WORLD_SIZE.x * left as f32 / total as f32;
+            left += width;
+            let max_x = WORLD_SIZE.x * left as f32 / total as f32;
+            Self { min: Vec2::new(min_x, 0.0), max: Vec2::new(max_x, WORLD_SIZE.y) }
+        }).collect()
+    }
+
+    pub fn is_full(&self) -> bool {
+        *self == Self::full()
+    }
+
+    /// Map a whole-world clip-space position into this viewport's clip space
+    pub fn project(&self, position: [f32; 3]) -> [f32; 3] {
+        let size = (self.max - self.min).max(Vec2::splat(1.0));
+        let center = (self.min + self.max) * 0.5;
+        // World x grows right like clip x; world y grows down, clip y up
+        let center_ndc = Vec2::new(center.x / WORLD_SIZE.x * 2.0 - 1.0, 1.0 - center.y / WORLD_SIZE.y * 2.0);
+        let scale = WORLD_SIZE / size;
+        [
+            (position[0] - center_ndc.x) * scale.x,
+            (position[1] - center_ndc.y) * scale.y,
+            position[2],
+        ]
+    }
+
+    /// Copy of a whole-world frame as this viewport sees it
+    pub fn project_vertices(&self, vertices: &[Vertex]) -> Vec<Vertex> {
+        vertices.iter().map(|vertex| Vertex { position: self.project(vertex.position), ..*vertex }).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_columns_tile_the_world_edge_to_edge() {
+        let columns = Viewport::columns(&[1920, 1920, 1920]);
+        assert_eq!(columns.len(), 3);
+        assert_eq!(columns[0].min.x, 0.0);
+        assert_eq!(columns[2].max.x, WORLD_SIZE.x);
+        assert_eq!(columns[0].max.x, columns[1].min.x);
+
+        // The middle strip's left edge lands on the window's left edge
+        let left_edge = columns[1].project([-1.0 / 3.0, 1.0, 0.0]);
+        assert!((left_edge[0] + 1.0).abs() < 1e-5 && (left_edge[1] - 1.0).abs() < 1e-5, "{:?}", left_edge);
+        assert_eq!(Viewport::full().project([0.25, -0.5, 0.0]), [0.25, -0.5, 0.0]);
+        assert!(Viewport::columns(&[0, 0]).iter().all(Viewport::is_full));
+    }
+}
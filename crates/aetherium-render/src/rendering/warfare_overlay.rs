@@ -0,0 +1,143 @@
+// === WARFARE OVERLAY ===
+// Makes the consciousness wars legible: each species' territory glows as a
+// soft field sized by its share of dominance, contested ground gets a
+// marching dashed border, and species under extinction pressure lose color.
+// Everything stays dim so the safety pipeline never has to step in.
+
+use glam::{Vec2, Vec3};
+use aetherium_sim::engine::safety::hsv_to_rgb_vec3;
+use aetherium_sim::engine::{WarfareState, species_index};
+use aetherium_sim::entities::{Llama, SpeciesType};
+use crate::reality::Vertex;
+use super::viewport::WORLD_SIZE;
+
+/// Brightness of a field's centre at full dominance
+const FIELD_INTENSITY: f32 = 0.25;
+/// Triangles per territory field
+const FIELD_SEGMENTS: usize = 24;
+/// Dashes around a conflict front
+const FRONT_DASHES: usize = 16;
+/// Radius of a conflict front at full intensity, in world units
+const FRONT_RADIUS: f32 = 90.0;
+/// Share of saturation removed at full extinction pressure
+const MAX_DESATURATION: f32 = 0.85;
+
+/// Ground held by one species: where its living members gather and how far they spread
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpeciesTerritory {
+    pub species: SpeciesType,
+    pub center: Vec2,
+    pub radius: f32, // World units
+}
+
+/// Overlay color of each species: cyan disco, violet sheep, amber camels, crimson vicunas
+pub fn territory_color(species: SpeciesType) -> Vec3 {
+    let hue = match species {
+        SpeciesType::DiscoLlama => 180.0,
+        SpeciesType::QuantumSheep => 285.0,
+        SpeciesType::HypnoCamel => 40.0,
+        SpeciesType::BassDropVicuna => 340.0,
+    };
+    hsv_to_rgb_vec3(Vec3::new(hue, 0.8, 1.0))
+}
+
+/// Territory of every species that still has living members
+pub fn species_territories(llamas: &[Llama]) -> Vec<SpeciesTerritory> {
+    SpeciesType::ALL.into_iter().filter_map(|species| {
+        let positions: Vec<Vec2> = llamas.iter()
+            .filter(|llama| llama.species == species && llama.consciousness > 0.1)
+            .map(|llama| llama.position)
+            .collect();
+        if positions.is_empty() {
+            return None;
+        }
+        let center = positions.iter().copied().sum::<Vec2>() / positions.len() as f32;
+        let spread = positions.iter().map(|position| position.distance(center)).sum::<f32>() / positions.len() as f32;
+        Some(SpeciesTerritory { species, center, radius: (spread * 1.5).clamp(60.0, 400.0) })
+    }).collect()
+}
+
+/// Fade a color towards grey as its species nears extinction
+pub fn desaturate(color: Vec3, extinction_pressure: f32) -> Vec3 {
+    let amount = extinction_pressure.clamp(0.0, 1.0) * MAX_DESATURATION;
+    let grey = Vec3::splat(color.dot(Vec3::new(0.299, 0.587, 0.114)));
+    color.lerp(grey, amount)
+}
+
+fn to_clip(world: Vec2) -> [f32; 3] {
+    [world.x / WORLD_SIZE.x * 2.0 - 1.0, 1.0 - world.y / WORLD_SIZE.y * 2.0, 0.0]
+}
+
+pub(super) fn overlay_vertex(world: Vec2, color: Vec3) -> Vertex {
+    Vertex { position: to_clip(world), color: color.into(), uv: [0.5, 0.5], species_id: 0.0, consciousness: 0.2, trip_intensity: 0.0 }
+}
+
+/// Territory fields and conflict fronts; draw before the llamas so they sit underneath.
+/// Field rims fade to `background` so the fields have no hard edge
+pub fn push_warfare_overlay(vertices: &mut Vec<Vertex>, warfare: &WarfareState, territories: &[SpeciesTerritory], background: Vec3, time: f32) {
+    for territory in territories {
+        let index = species_index(territory.species);
+        let dominance = warfare.territorial_dominance[index].clamp(0.0, 1.0);
+        let color = desaturate(territory_color(territory.species), warfare.extinction_pressure[index]);
+        let center_color = background + color * FIELD_INTENSITY * dominance;
+        let radius = territory.radius * (0.5 + dominance);
+
+        for i in 0..FIELD_SEGMENTS {
+            let rim = |step: usize| {
+                let angle = step as f32 / FIELD_SEGMENTS as f32 * std::f32::consts::TAU;
+                territory.center + Vec2::new(angle.cos(), angle.sin()) * radius
+            };
+            vertices.extend([
+                overlay_vertex(territory.center, center_color),
+                overlay_vertex(rim(i), background),
+                overlay_vertex(rim(i + 1), background),
+            ]);
+        }
+    }
+
+    // Conflict fronts: dashes in the attacker's and defender's colors march around the contested point
+    for conflict in &warfare.active_conflicts {
+        let radius = FRONT_RADIUS * (0.4 + conflict.conflict_intensity.clamp(0.0, 1.0) * 0.6);
+        let thickness = 4.0;
+        let march = time * 0.6;
+        for dash in 0..FRONT_DASHES {
+            let species = if dash % 2 == 0 { conflict.attacker_species } else { conflict.defender_species };
+            let color = territory_color(species) * 0.35;
+            let start = (dash as f32 + march) / FRONT_DASHES as f32 * std::f32::consts::TAU;
+            let end = start + 0.6 / FRONT_DASHES as f32 * std::f32::consts::TAU;
+            let point = |angle: f32, r: f32| conflict.territory_contested + Vec2::new(angle.cos(), angle.sin()) * r;
+
+            let (inner_start, outer_start) = (point(start, radius - thickness), point(start, radius + thickness));
+            let (inner_end, outer_end) = (point(end, radius - thickness), point(end, radius + thickness));
+            vertices.extend([
+                overlay_vertex(inner_start, color),
+                overlay_vertex(outer_start, color),
+                overlay_vertex(outer_end, color),
+                overlay_vertex(inner_start, color),
+                overlay_vertex(outer_end, color),
+                overlay_vertex(inner_end, color),
+            ]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_territories_and_desaturation() {
+        let llamas = vec![
+            Llama::new_with_species(Vec2::new(100.0, 100.0), SpeciesType::HypnoCamel),
+            Llama::new_with_species(Vec2::new(300.0, 100.0), SpeciesType::HypnoCamel),
+        ];
+        let territories = species_territories(&llamas);
+        assert_eq!(territories.len(), 1);
+        assert_eq!(territories[0].center, Vec2::new(200.0, 100.0));
+
+        let amber = territory_color(SpeciesType::HypnoCamel);
+        assert_eq!(desaturate(amber, 0.0), amber);
+        let faded = desaturate(amber, 1.0);
+        assert!(faded.max_element() - faded.min_element() < amber.max_element() - amber.min_element());
+    }
+}
@@ -3,8 +3,10 @@
 
 use glam::{Vec2, Vec3};
 use std::collections::HashMap;
+use crate::engine::safety::hsv_to_rgb_vec3;
 use crate::entities::Llama;
 use crate::simulation::consciousness_systems::DigitalEcosystem;
+use super::lexicon::{Intent, Lexicon, SYMBOL_COUNT, Understanding, sender_intent};
 
 // === EMERGENT COMMUNICATION SYSTEMS ===
 /// Visual language development between entities
@@ -15,6 +17,8 @@ pub struct EmergentCommunicationSystems {
     pub semantic_networks: SemanticNetworks,
     pub cultural_transmission: CulturalTransmission,
     pub active_communications: Vec<VisualMessage>,
+    pub lexicon: Lexicon,
+    understandings: Vec<Understanding>, // Drained by the organism and acted on
 }
 
 #[derive(Debug, Clone)]
@@ -379,10 +383,13 @@ impl EmergentCommunicationSystems {
             semantic_networks: SemanticNetworks::new(),
             cultural_transmission: CulturalTransmission::new(),
             active_communications: Vec::new(),
+            lexicon: Lexicon::new(),
+            understandings: Vec::new(),
         }
     }
 
-    pub fn update(&mut self, dt: f32, llamas: &[Llama], ecosystem: &DigitalEcosystem,
+    /// `crystals` are the positions llamas can point each other to
+    pub fn update(&mut self, dt: f32, llamas: &[Llama], crystals: &[Vec2], ecosystem: &DigitalEcosystem,
                   consciousness_level: f32, cosmic_time: f64) {
         // Update signal vocabulary evolution
         self.signal_vocabulary.update(dt, consciousness_level, cosmic_time);
@@ -400,7 +407,12 @@ impl EmergentCommunicationSystems {
         self.update_active_communications(dt, llamas, ecosystem, cosmic_time);
 
         // Generate new communications based on entity interactions
-        self.generate_new_communications(llamas, ecosystem, consciousness_level, cosmic_time);
+        self.generate_new_communications(llamas, crystals, ecosystem, consciousness_level, cosmic_time);
+    }
+
+    /// Receivers that understood a message since the last call, by index into the last llama slice
+    pub fn drain_understandings(&mut self) -> std::vec::Drain<'_, Understanding> {
+        self.understandings.drain(..)
     }
 
     fn update_active_communications(&mut self, dt: f32, llamas: &[Llama],
@@ -413,28 +425,38 @@ impl EmergentCommunicationSystems {
         self.active_communications.retain(|comm| !comm.is_completed());
     }
 
-    fn generate_new_communications(&mut self, llamas: &[Llama], ecosystem: &DigitalEcosystem,
+    fn generate_new_communications(&mut self, llamas: &[Llama], crystals: &[Vec2], ecosystem: &DigitalEcosystem,
                                   consciousness_level: f32, cosmic_time: f64) {
         // Generate communications based on llama interactions and consciousness level
         for (i, llama) in llamas.iter().enumerate() {
             if llama.consciousness > 0.7 && cosmic_time as f32 % 2.0 < 0.1 {
-                // High consciousness llamas attempt communication
-                if let Some(communication) = self.create_communication(i, llamas, ecosystem, cosmic_time) {
+                // High consciousness llamas attempt communication, in words when they have something to say
+                let utterance = sender_intent(llama, llamas, crystals)
+                    .map(|(intent, target)| (intent, target, self.lexicon.choose_symbol(llama.species, intent)));
+                if let Some(communication) = self.create_communication(i, llamas, ecosystem, cosmic_time, utterance) {
+                    if let Some((intent, target, symbol)) = utterance {
+                        for &receiver in &communication.receiver_ids {
+                            if self.lexicon.exchange(llama.species, llamas[receiver].species, symbol, intent) {
+                                self.understandings.push(Understanding { receiver, intent, target });
+                            }
+                        }
+                    }
                     self.active_communications.push(communication);
                 }
             }
         }
     }
 
-    fn create_communication(&self, sender_id: usize, llamas: &[Llama],
-                           ecosystem: &DigitalEcosystem, cosmic_time: f64) -> Option<VisualMessage> {
+    fn create_communication(&self, sender_id: usize, llamas: &[Llama], ecosystem: &DigitalEcosystem,
+                           cosmic_time: f64, utterance: Option<(Intent, Vec2, usize)>) -> Option<VisualMessage> {
         // Create a new visual message based on current context
         let sender = &llamas[sender_id];
 
-        // Find nearby llamas as potential receivers
+        // Find nearby llamas the sender can see as potential receivers
         let nearby_llamas: Vec<usize> = llamas.iter().enumerate()
             .filter(|(i, llama)| *i != sender_id &&
-                    (sender.position - llama.position).length() < 200.0)
+                    (sender.position - llama.position).length() < 200.0 &&
+                    ecosystem.obstacles.line_of_sight(sender.position, llama.position))
             .map(|(i, _)| i)
             .collect();
 
@@ -442,8 +464,14 @@ impl EmergentCommunicationSystems {
             return None;
         }
 
-        // Determine communication intent based on sender's state
-        let intent = if sender.consciousness > 0.9 {
+        // Determine communication intent based on sender's state; lexical messages say what they mean
+        let intent = if let Some((intent, _, _)) = utterance {
+            match intent {
+                Intent::Danger => CommunicationContext::Warning,
+                Intent::FoodHere => CommunicationContext::Informational,
+                Intent::JoinHive => CommunicationContext::Cooperation,
+            }
+        } else if sender.consciousness > 0.9 {
             CommunicationContext::Social
         } else if sender.prime_chaos_factor > 0.8 {
             CommunicationContext::Emotional
@@ -452,12 +480,20 @@ impl EmergentCommunicationSystems {
         };
 
         // Create signal based on available vocabulary
+        // Each lexical symbol has its own hue so the vocabulary is visible on screen
+        let (signal_id, current_color) = match utterance {
+            Some((_, _, symbol)) => {
+                let hue = 30.0 + symbol as f32 * 360.0 / SYMBOL_COUNT as f32; // Offset keeps symbols off pure red
+                (format!("symbol_{}", symbol), hsv_to_rgb_vec3(Vec3::new(hue, 0.6, 0.8)))
+            }
+            None => ("basic_greeting".to_string(), Vec3::new(sender.color.x, sender.color.y, 0.6)),
+        };
         let signal_sequence = vec![ActiveSignal {
-            signal_id: "basic_greeting".to_string(),
+            signal_id,
             current_intensity: sender.consciousness,
             position: sender.position,
             visual_state: VisualState {
-                current_color: Vec3::new(sender.color.x, sender.color.y, 0.6),
+                current_color,
                 current_size: 20.0 + sender.consciousness * 30.0,
                 current_rotation: 0.0,
                 movement_velocity: Vec2::ZERO,
@@ -0,0 +1,231 @@
+// === EMERGENT LEXICON ===
+// Gives signals a meaning. A sender picks the symbol its species most
+// associates with what it wants to say; each receiver reads the symbol
+// through its own species' lexicon. Understood messages reinforce the
+// mapping on both sides and change what the receiver does; misunderstood
+// ones only teach the receiver a little from context. Over time each species
+// settles on a vocabulary, and species that talk to each other converge.
+
+use glam::Vec2;
+use crate::engine::species_index;
+use crate::entities::{Llama, SpeciesType, SPECIES_COUNT};
+
+/// Distinct symbols a species can use
+pub const SYMBOL_COUNT: usize = 6;
+const INTENT_COUNT: usize = 3;
+/// Share of the evidence a symbol must carry for one intent to be read at all
+const MIN_CONFIDENCE: f32 = 0.45;
+/// Weight added to a symbol-intent pair when a message is understood
+const REINFORCEMENT: f32 = 0.3;
+/// Share of REINFORCEMENT a receiver learns from context after a misunderstanding
+const CONTEXT_LEARNING: f32 = 0.3;
+/// Competing meanings of an understood symbol keep this share of their weight
+const INHIBITION: f32 = 0.9;
+/// Chance a sender tries a random symbol instead of its best one
+const EXPLORATION: f32 = 0.1;
+/// How fast the recent success rate follows new exchanges
+const SUCCESS_SMOOTHING: f32 = 0.05;
+/// Speed change an understood message gives its receiver
+const RESPONSE_IMPULSE: f32 = 40.0;
+
+/// What a message means
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Intent {
+    Danger,   // Move away from the target
+    FoodHere, // A crystal waits at the target
+    JoinHive, // A hive gathers at the target
+}
+
+impl Intent {
+    pub const ALL: [Intent; INTENT_COUNT] = [Intent::Danger, Intent::FoodHere, Intent::JoinHive];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Intent::Danger => "DANGER",
+            Intent::FoodHere => "FOOD",
+            Intent::JoinHive => "JOIN",
+        }
+    }
+
+    fn index(self) -> usize {
+        match self {
+            Intent::Danger => 0,
+            Intent::FoodHere => 1,
+            Intent::JoinHive => 2,
+        }
+    }
+}
+
+/// A receiver that read a message correctly and will act on it
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Understanding {
+    pub receiver: usize, // Index into the llama slice the message was built from
+    pub intent: Intent,
+    pub target: Vec2,
+}
+
+impl Understanding {
+    /// Nudge the receiver: away from danger, towards food and gathering hives
+    pub fn apply(&self, llama: &mut Llama) {
+        let toward = (self.target - llama.position).normalize_or_zero();
+        let direction = if self.intent == Intent::Danger { -toward } else { toward };
+        llama.velocity += direction * RESPONSE_IMPULSE;
+    }
+}
+
+/// One species' reading of a symbol, for the debug overlay
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LexiconEntry {
+    pub symbol: usize,
+    pub intent: Intent,
+    pub confidence: f32, // Share of the symbol's weight on this intent
+}
+
+/// Symbol-meaning weights of every species plus how well they are getting through
+#[derive(Debug, Clone)]
+pub struct Lexicon {
+    weights: [[[f32; INTENT_COUNT]; SYMBOL_COUNT]; SPECIES_COUNT],
+    attempts: u32,
+    successes: u32,
+    recent_success: f32,
+}
+
+impl Default for Lexicon {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Lexicon {
+    /// Every symbol starts out meaning nothing in particular
+    pub fn new() -> Self {
+        Self {
+            weights: [[[1.0; INTENT_COUNT]; SYMBOL_COUNT]; SPECIES_COUNT],
+            attempts: 0,
+            successes: 0,
+            recent_success: 0.0,
+        }
+    }
+
+    /// Symbol a member of `species` uses to say `intent`; ties and exploration pick at random
+    pub fn choose_symbol(&self, species: SpeciesType, intent: Intent) -> usize {
+        if fastrand::f32() < EXPLORATION {
+            return fastrand::usize(0..SYMBOL_COUNT);
+        }
+        let weights = &self.weights[species_index(species)];
+        let best = weights.iter().map(|meanings| meanings[intent.index()]).fold(f32::MIN, f32::max);
+        let candidates: Vec<usize> = (0..SYMBOL_COUNT).filter(|&symbol| weights[symbol][intent.index()] >= best).collect();
+        candidates[fastrand::usize(0..candidates.len())]
+    }
+
+    /// What a member of `species` takes `symbol` to mean, if anything
+    pub fn interpret(&self, species: SpeciesType, symbol: usize) -> Option<LexiconEntry> {
+        let meanings = &self.weights[species_index(species)][symbol % SYMBOL_COUNT];
+        let total: f32 = meanings.iter().sum();
+        let (index, weight) = meanings.iter().copied().enumerate().fold((0, f32::MIN), |best, entry| if entry.1 > best.1 { entry } else { best });
+        let confidence = if total > 0.0 { weight / total } else { 0.0 };
+        (confidence >= MIN_CONFIDENCE).then_some(LexiconEntry { symbol, intent: Intent::ALL[index], confidence })
+    }
+
+    /// Settle one sender-receiver exchange; true when the receiver understood
+    pub fn exchange(&mut self, sender: SpeciesType, receiver: SpeciesType, symbol: usize, intent: Intent) -> bool {
+        let understood = self.interpret(receiver, symbol).is_some_and(|entry| entry.intent == intent);
+        if understood {
+            self.reinforce(sender, symbol, intent, REINFORCEMENT);
+            if receiver != sender {
+                self.reinforce(receiver, symbol, intent, REINFORCEMENT);
+            }
+            self.successes += 1;
+        } else {
+            self.reinforce(receiver, symbol, intent, REINFORCEMENT * CONTEXT_LEARNING);
+        }
+
+        self.attempts += 1;
+        let outcome = if understood { 1.0 } else { 0.0 };
+        self.recent_success += (outcome - self.recent_success) * SUCCESS_SMOOTHING;
+        understood
+    }
+
+    fn reinforce(&mut self, species: SpeciesType, symbol: usize, intent: Intent, amount: f32) {
+        let meanings = &mut self.weights[species_index(species)][symbol % SYMBOL_COUNT];
+        for (index, weight) in meanings.iter_mut().enumerate() {
+            if index == intent.index() {
+                *weight += amount;
+            } else {
+                *weight *= INHIBITION;
+            }
+        }
+    }
+
+    /// Every symbol `species` can currently read, strongest first
+    pub fn entries(&self, species: SpeciesType) -> Vec<LexiconEntry> {
+        let mut entries: Vec<LexiconEntry> = (0..SYMBOL_COUNT).filter_map(|symbol| self.interpret(species, symbol)).collect();
+        entries.sort_by(|a, b| b.confidence.total_cmp(&a.confidence));
+        entries
+    }
+
+    pub fn attempts(&self) -> u32 {
+        self.attempts
+    }
+
+    pub fn successes(&self) -> u32 {
+        self.successes
+    }
+
+    /// Smoothed share of recent exchanges that were understood
+    pub fn recent_success_rate(&self) -> f32 {
+        self.recent_success
+    }
+}
+
+/// What a llama has to say right now and where it points, most urgent first
+pub fn sender_intent(sender: &Llama, llamas: &[Llama], crystals: &[Vec2]) -> Option<(Intent, Vec2)> {
+    let threat = llamas.iter()
+        .filter(|other| other.species != sender.species && other.warfare_participation > 0.5)
+        .map(|other| other.position)
+        .find(|position| position.distance(sender.position) < 150.0);
+    if let Some(threat) = threat {
+        return Some((Intent::Danger, threat));
+    }
+
+    let food = crystals.iter().copied()
+        .filter(|position| position.distance(sender.position) < 250.0)
+        .min_by(|a, b| a.distance(sender.position).total_cmp(&b.distance(sender.position)));
+    if let Some(food) = food {
+        return Some((Intent::FoodHere, food));
+    }
+
+    sender.collective_id.map(|_| (Intent::JoinHive, sender.position))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_species_converge_on_a_shared_vocabulary() {
+        fastrand::seed(7);
+        let mut lexicon = Lexicon::new();
+        let species = [SpeciesType::DiscoLlama, SpeciesType::QuantumSheep];
+        assert!(lexicon.interpret(species[0], 0).is_none());
+
+        let mut understood_late = 0;
+        for round in 0..600 {
+            let intent = Intent::ALL[round % INTENT_COUNT];
+            let (sender, receiver) = (species[round % 2], species[(round / 2) % 2]);
+            let symbol = lexicon.choose_symbol(sender, intent);
+            if lexicon.exchange(sender, receiver, symbol, intent) && round >= 500 {
+                understood_late += 1;
+            }
+        }
+        assert!(understood_late > 70, "only {} of the last 100 exchanges understood", understood_late);
+        assert!(lexicon.recent_success_rate() > 0.7);
+        assert!(lexicon.entries(species[1]).len() >= INTENT_COUNT);
+
+        // An understood warning moves the receiver away from the threat
+        let mut llama = Llama::new(Vec2::new(100.0, 100.0));
+        llama.velocity = Vec2::ZERO;
+        Understanding { receiver: 0, intent: Intent::Danger, target: Vec2::new(150.0, 100.0) }.apply(&mut llama);
+        assert!(llama.velocity.x < 0.0);
+    }
+}
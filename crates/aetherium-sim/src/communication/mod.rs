@@ -0,0 +1,5 @@
+pub mod emergent_language;
+pub mod lexicon;
+
+pub use emergent_language::*;
+pub use lexicon::{Intent, Lexicon, LexiconEntry, Understanding};
@@ -4,11 +4,22 @@ pub mod crystals;
 use glam::Vec2;
 use crate::core::ecs::{World, EntityId};
 use crate::core::events::{EventBus, ChaosEvent, LlamaSpecies};
+use crate::entities::SpeciesType;
 use crate::mathematics::BeatState;
 
 pub use llama::{PsychedelicLlama, LlamaAI, ConsciousnessLevel};
 pub use crystals::{ConsciousnessCrystal, CrystalField};
 
+/// The species the rest of the engine knows a legacy llama as
+fn species_type(species: &LlamaSpecies) -> SpeciesType {
+    match species {
+        LlamaSpecies::Quantum => SpeciesType::QuantumSheep,
+        LlamaSpecies::Hypno => SpeciesType::HypnoCamel,
+        LlamaSpecies::BassDrop => SpeciesType::BassDropVicuna,
+        LlamaSpecies::Disco | LlamaSpecies::Fractal => SpeciesType::DiscoLlama,
+    }
+}
+
 pub struct LlamaManager {
     world: World,
     crystal_field: CrystalField,
@@ -94,8 +105,9 @@ impl LlamaManager {
 
         self.llama_count += 1;
         self.event_bus.publish(ChaosEvent::LlamaSpawned {
-            entity_id: entity,
-            consciousness: consciousness,
+            entity,
+            species: SpeciesType::DiscoLlama,
+            position,
         });
 
         entity
@@ -186,8 +198,10 @@ impl LlamaManager {
                 if let Some(crystal) = self.crystal_field.check_harvest(llama.position, 20.0) {
                     llama.consciousness_level += crystal.consciousness_value;
                     self.event_bus.publish(ChaosEvent::CrystalHarvested {
-                        llama_id: entity_id,
-                        crystal_type: crystal.crystal_type,
+                        llama: entity_id,
+                        species: species_type(&llama.species),
+                        position: llama.position,
+                        amount: crystal.consciousness_value,
                     });
                 }
 
@@ -220,11 +234,6 @@ impl LlamaManager {
 
             self.spawn_disco_llama(spawn_pos, 1.0);
         }
-
-        self.event_bus.publish(ChaosEvent::RealityTear {
-            position,
-            strength: 0.5,
-        });
     }
 
     pub fn llama_count(&self) -> usize {
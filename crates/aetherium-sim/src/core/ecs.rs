@@ -0,0 +1,323 @@
+use std::collections::HashMap;
+use std::any::{Any, TypeId};
+
+// Simplified ECS for maximum chaos, minimum abstraction
+// Entity ids are never reused, so references held across frames (hive members,
+// predation targets, social bonds) stay valid or resolve to nothing after a despawn
+pub type EntityId = u32;
+
+pub struct World {
+    next_entity_id: EntityId,
+    entities: Vec<EntityId>,
+    components: HashMap<TypeId, Box<dyn ComponentStorage>>,
+}
+
+impl World {
+    pub fn new() -> Self {
+        Self {
+            next_entity_id: 0,
+            entities: Vec::new(),
+            components: HashMap::new(),
+        }
+    }
+
+    /// An empty world whose first entity gets `next_entity_id`, so a rebuilt world never reissues older ids
+    pub fn starting_at(next_entity_id: EntityId) -> Self {
+        Self { next_entity_id, ..Self::new() }
+    }
+
+    /// The id the next spawned entity will get
+    pub fn next_entity_id(&self) -> EntityId {
+        self.next_entity_id
+    }
+
+    pub fn create_entity(&mut self) -> EntityId {
+        let id = self.next_entity_id;
+        self.next_entity_id += 1;
+        self.entities.push(id);
+        id
+    }
+
+    /// Create an entity with a single component
+    pub fn spawn<T: 'static>(&mut self, component: T) -> EntityId {
+        let entity = self.create_entity();
+        self.add_component(entity, component);
+        entity
+    }
+
+    /// Remove an entity and all of its components
+    pub fn despawn(&mut self, entity: EntityId) -> bool {
+        let Some(index) = self.entities.iter().position(|&id| id == entity) else {
+            return false;
+        };
+        self.entities.remove(index);
+        for storage in self.components.values_mut() {
+            storage.remove_entity(entity);
+        }
+        true
+    }
+
+    pub fn is_alive(&self, entity: EntityId) -> bool {
+        self.entities.contains(&entity)
+    }
+
+    pub fn entity_count(&self) -> usize {
+        self.entities.len()
+    }
+
+    pub fn add_component<T: 'static>(&mut self, entity: EntityId, component: T) {
+        let type_id = TypeId::of::<T>();
+        let storage = self.components
+            .entry(type_id)
+            .or_insert_with(|| Box::new(ComponentVec::<T>::new()));
+
+        storage.as_any_mut()
+            .downcast_mut::<ComponentVec<T>>()
+            .unwrap()
+            .insert(entity, component);
+    }
+
+    pub fn remove_component<T: 'static>(&mut self, entity: EntityId) -> Option<T> {
+        self.storage_mut::<T>()?.remove(entity)
+    }
+
+    pub fn get_component<T: 'static>(&self, entity: EntityId) -> Option<&T> {
+        self.storage::<T>()?.get(entity)
+    }
+
+    pub fn get_component_mut<T: 'static>(&mut self, entity: EntityId) -> Option<&mut T> {
+        self.storage_mut::<T>()?.get_mut(entity)
+    }
+
+    /// Mutable access to the same component on two different entities
+    pub fn get_pair_mut<T: 'static>(&mut self, a: EntityId, b: EntityId) -> Option<(&mut T, &mut T)> {
+        self.storage_mut::<T>()?.get_pair_mut(a, b)
+    }
+
+    /// Number of entities carrying component `T`
+    pub fn count<T: 'static>(&self) -> usize {
+        self.storage::<T>().map_or(0, |storage| storage.data.len())
+    }
+
+    /// All `T` components, densely packed in spawn order
+    pub fn components<T: 'static>(&self) -> &[T] {
+        self.storage::<T>().map_or(&[], |storage| &storage.data)
+    }
+
+    pub fn components_mut<T: 'static>(&mut self) -> &mut [T] {
+        match self.storage_mut::<T>() {
+            Some(storage) => &mut storage.data,
+            None => &mut [],
+        }
+    }
+
+    /// Entity ids parallel to `components::<T>()`
+    pub fn entities_with<T: 'static>(&self) -> &[EntityId] {
+        self.storage::<T>().map_or(&[], |storage| &storage.ids)
+    }
+
+    /// Ids and mutable components of `T`, index-aligned
+    pub fn components_with_ids_mut<T: 'static>(&mut self) -> (&[EntityId], &mut [T]) {
+        match self.storage_mut::<T>() {
+            Some(storage) => (&storage.ids, &mut storage.data),
+            None => (&[], &mut []),
+        }
+    }
+
+    /// Mutable access to two different component types at once (panics if A == B)
+    pub fn components_mut2<A: 'static, B: 'static>(&mut self) -> (&mut [A], &mut [B]) {
+        let [a, b] = self.components.get_disjoint_mut([&TypeId::of::<A>(), &TypeId::of::<B>()]);
+        let a = a.and_then(|storage| storage.as_any_mut().downcast_mut::<ComponentVec<A>>());
+        let b = b.and_then(|storage| storage.as_any_mut().downcast_mut::<ComponentVec<B>>());
+        (
+            a.map_or(&mut [], |storage| &mut storage.data),
+            b.map_or(&mut [], |storage| &mut storage.data),
+        )
+    }
+
+    /// Despawn every entity whose `T` component fails the predicate
+    pub fn retain<T: 'static>(&mut self, mut keep: impl FnMut(EntityId, &mut T) -> bool) {
+        let doomed: Vec<EntityId> = match self.storage_mut::<T>() {
+            Some(storage) => storage.ids.iter()
+                .zip(storage.data.iter_mut())
+                .filter_map(|(&id, component)| (!keep(id, component)).then_some(id))
+                .collect(),
+            None => return,
+        };
+        for entity in doomed {
+            self.despawn(entity);
+        }
+    }
+
+    pub fn query<T: 'static>(&self) -> Vec<(EntityId, &T)> {
+        match self.storage::<T>() {
+            Some(storage) => storage.iter().collect(),
+            None => Vec::new(),
+        }
+    }
+
+    pub fn query_mut<T: 'static>(&mut self) -> Box<dyn Iterator<Item = (EntityId, &mut T)> + '_> {
+        match self.storage_mut::<T>() {
+            Some(storage) => Box::new(storage.iter_mut()),
+            None => Box::new(std::iter::empty()),
+        }
+    }
+
+    fn storage<T: 'static>(&self) -> Option<&ComponentVec<T>> {
+        self.components.get(&TypeId::of::<T>())?
+            .as_any()
+            .downcast_ref::<ComponentVec<T>>()
+    }
+
+    fn storage_mut<T: 'static>(&mut self) -> Option<&mut ComponentVec<T>> {
+        self.components.get_mut(&TypeId::of::<T>())?
+            .as_any_mut()
+            .downcast_mut::<ComponentVec<T>>()
+    }
+}
+
+impl Default for World {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+trait ComponentStorage {
+    fn as_any(&self) -> &dyn Any;
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+    fn remove_entity(&mut self, entity: EntityId);
+}
+
+/// Dense component storage: data stays packed in spawn order (stable draw
+/// order, reproducible iteration), with an id → slot index for lookups
+struct ComponentVec<T> {
+    ids: Vec<EntityId>,
+    data: Vec<T>,
+    slots: HashMap<EntityId, usize>,
+}
+
+impl<T> ComponentVec<T> {
+    fn new() -> Self {
+        Self {
+            ids: Vec::new(),
+            data: Vec::new(),
+            slots: HashMap::new(),
+        }
+    }
+
+    fn insert(&mut self, entity: EntityId, component: T) {
+        if let Some(&slot) = self.slots.get(&entity) {
+            self.data[slot] = component;
+            return;
+        }
+        self.slots.insert(entity, self.data.len());
+        self.ids.push(entity);
+        self.data.push(component);
+    }
+
+    fn remove(&mut self, entity: EntityId) -> Option<T> {
+        let slot = self.slots.remove(&entity)?;
+        self.ids.remove(slot);
+        // Shift rather than swap so the remaining order is preserved
+        for (offset, id) in self.ids[slot..].iter().enumerate() {
+            self.slots.insert(*id, slot + offset);
+        }
+        Some(self.data.remove(slot))
+    }
+
+    fn get(&self, entity: EntityId) -> Option<&T> {
+        self.slots.get(&entity).map(|&slot| &self.data[slot])
+    }
+
+    fn get_mut(&mut self, entity: EntityId) -> Option<&mut T> {
+        let slot = *self.slots.get(&entity)?;
+        Some(&mut self.data[slot])
+    }
+
+    fn get_pair_mut(&mut self, a: EntityId, b: EntityId) -> Option<(&mut T, &mut T)> {
+        let slot_a = *self.slots.get(&a)?;
+        let slot_b = *self.slots.get(&b)?;
+        let [first, second] = self.data.get_disjoint_mut([slot_a, slot_b]).ok()?;
+        Some((first, second))
+    }
+
+    fn iter(&self) -> impl Iterator<Item = (EntityId, &T)> {
+        self.ids.iter().copied().zip(self.data.iter())
+    }
+
+    fn iter_mut(&mut self) -> impl Iterator<Item = (EntityId, &mut T)> {
+        self.ids.iter().copied().zip(self.data.iter_mut())
+    }
+}
+
+impl<T: 'static> ComponentStorage for ComponentVec<T> {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn remove_entity(&mut self, entity: EntityId) {
+        self.remove(entity);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_despawn_keeps_other_ids_valid() {
+        let mut world = World::new();
+        let a = world.spawn(1u32);
+        let b = world.spawn(2u32);
+        let c = world.spawn(3u32);
+
+        assert!(world.despawn(b));
+        assert!(!world.is_alive(b));
+        assert_eq!(world.get_component::<u32>(a), Some(&1));
+        assert_eq!(world.get_component::<u32>(b), None);
+        assert_eq!(world.get_component::<u32>(c), Some(&3));
+
+        // Order is preserved and ids are never handed out twice
+        assert_eq!(world.components::<u32>(), &[1, 3]);
+        assert_eq!(world.entities_with::<u32>(), &[a, c]);
+        assert!(world.spawn(4u32) > c);
+    }
+
+    #[test]
+    fn test_despawn_removes_every_component() {
+        let mut world = World::new();
+        let entity = world.spawn(1u32);
+        world.add_component(entity, "label");
+
+        world.despawn(entity);
+        assert_eq!(world.count::<u32>(), 0);
+        assert_eq!(world.count::<&str>(), 0);
+        assert_eq!(world.entity_count(), 0);
+    }
+
+    #[test]
+    fn test_retain_and_pair_access() {
+        let mut world = World::new();
+        let ids: Vec<EntityId> = (0..5u32).map(|n| world.spawn(n)).collect();
+        world.spawn(0.5f32);
+
+        world.retain::<u32>(|_, n| *n % 2 == 0);
+        assert_eq!(world.components::<u32>(), &[0, 2, 4]);
+        assert_eq!(world.count::<f32>(), 1);
+
+        let (a, b) = world.get_pair_mut::<u32>(ids[0], ids[4]).unwrap();
+        std::mem::swap(a, b);
+        assert_eq!(world.components::<u32>(), &[4, 2, 0]);
+        assert!(world.get_pair_mut::<u32>(ids[2], ids[2]).is_none());
+
+        let (numbers, floats) = world.components_mut2::<u32, f32>();
+        numbers[0] += 1;
+        floats[0] *= 2.0;
+        assert_eq!(world.components::<u32>()[0], 5);
+        assert_eq!(world.components::<f32>(), &[1.0]);
+    }
+}
@@ -0,0 +1,150 @@
+// === CHAOS EVENTS ===
+// Everything notable that happens in the organism is published once, as a
+// typed ChaosEvent, to the EventBus of whoever drives the simulation. The bus
+// keeps a short history for in-process peeking and fans every event out to
+// subscribers; library consumers call `subscribe()` and read the receiver at
+// their own pace. A subscriber that falls too far behind misses events rather
+// than stalling the simulation, and one that drops its receiver is forgotten.
+
+use std::collections::VecDeque;
+use std::sync::mpsc::{self, Receiver, SyncSender, TrySendError};
+use glam::Vec2;
+use crate::core::ecs::EntityId;
+use crate::engine::consciousness_multiplication::ObserverIntervention;
+use crate::engine::population::DespawnReason;
+use crate::entities::{HybridId, SpeciesType};
+
+/// Beat intensity that counts as a drop when the beat rises through it
+pub const BEAT_DROP_INTENSITY: f32 = 1.2;
+/// Events a subscriber may fall behind by before it starts missing them
+pub const SUBSCRIBER_CAPACITY: usize = 1024;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChaosEvent {
+    LlamaSpawned { entity: EntityId, species: SpeciesType, position: Vec2 },
+    LlamaDespawned { entity: EntityId, species: SpeciesType, position: Vec2, reason: DespawnReason },
+    HiveFormed { hive: EntityId, species: SpeciesType, members: usize },
+    HiveDissolved { hive: EntityId, members_left: usize },
+    SpeciesEmerged { hybrid: HybridId, name: String, parents: [SpeciesType; 2], hive: EntityId }, // A mixed hive became a hybrid species
+    ConflictStarted { attacker: SpeciesType, defender: SpeciesType, front: Vec2 },
+    ConflictEnded { attacker: SpeciesType, defender: SpeciesType, front: Vec2, victor: Option<SpeciesType> }, // None for truces, timeouts and forced peace
+    CrystalSpawned { crystal: EntityId, position: Vec2 },
+    CrystalHarvested { llama: EntityId, species: SpeciesType, position: Vec2, amount: f32 },
+    ConsciousnessGifted { donor: EntityId, recipient: Option<EntityId>, species: SpeciesType, position: Vec2, amount: f32 }, // Donor is the hive for pool payouts; no recipient when paid into the pool
+    TearOpened { tear: EntityId, position: Vec2 },
+    TearClosed { tear: EntityId, position: Vec2 },
+    EchoReleased { echo: EntityId, species: SpeciesType, position: Vec2, consciousness: f32 }, // A dead llama's fading echo
+    EchoCrystallized { echo: EntityId, crystal: EntityId, position: Vec2 },                   // An echo left a Memory crystal as it faded
+    ObserverIntervention(ObserverIntervention),
+    BeatDrop { intensity: f32, cosmic_time: f64 },
+}
+
+impl ChaosEvent {
+    /// A beat drop if the beat just rose through `BEAT_DROP_INTENSITY`
+    pub fn beat_drop(previous: f32, intensity: f32, cosmic_time: f64) -> Option<Self> {
+        (previous < BEAT_DROP_INTENSITY && intensity >= BEAT_DROP_INTENSITY)
+            .then_some(ChaosEvent::BeatDrop { intensity, cosmic_time })
+    }
+}
+
+// Legacy crystal and species kinds of the original llama manager
+#[derive(Debug, Clone)]
+pub enum CrystalType {
+    PurpleHaze,
+    NeonDream,
+    MathematicalHoney,
+    VoidSprinkles,
+    CosmicGiggle,
+}
+
+#[derive(Debug, Clone)]
+pub enum LlamaSpecies {
+    Disco,
+    Quantum,
+    Hypno,
+    Fractal,
+    BassDrop,
+}
+
+pub struct EventBus {
+    events: VecDeque<ChaosEvent>,
+    max_events: usize,
+    subscribers: Vec<SyncSender<ChaosEvent>>,
+}
+
+impl Default for EventBus {
+    fn default() -> Self {
+        Self::new(SUBSCRIBER_CAPACITY)
+    }
+}
+
+impl EventBus {
+    pub fn new(max_events: usize) -> Self {
+        Self {
+            events: VecDeque::with_capacity(max_events),
+            max_events,
+            subscribers: Vec::new(),
+        }
+    }
+
+    pub fn publish(&mut self, event: ChaosEvent) {
+        self.subscribers.retain(|subscriber| match subscriber.try_send(event.clone()) {
+            Ok(()) | Err(TrySendError::Full(_)) => true,
+            Err(TrySendError::Disconnected(_)) => false,
+        });
+        if self.events.len() >= self.max_events {
+            self.events.pop_front();
+        }
+        self.events.push_back(event);
+    }
+
+    /// Receive every event published from now on
+    pub fn subscribe(&mut self) -> Receiver<ChaosEvent> {
+        let (sender, receiver) = mpsc::sync_channel(SUBSCRIBER_CAPACITY);
+        self.subscribers.push(sender);
+        receiver
+    }
+
+    pub fn subscriber_count(&self) -> usize {
+        self.subscribers.len()
+    }
+
+    pub fn consume_events(&mut self) -> impl Iterator<Item = ChaosEvent> + '_ {
+        self.events.drain(..)
+    }
+
+    pub fn peek_events(&self) -> impl Iterator<Item = &ChaosEvent> {
+        self.events.iter()
+    }
+
+    pub fn clear(&mut self) {
+        self.events.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_subscribers_receive_events_and_dropped_ones_are_forgotten() {
+        let mut bus = EventBus::new(2);
+        let first = bus.subscribe();
+        let second = bus.subscribe();
+        drop(second);
+
+        bus.publish(ChaosEvent::ObserverIntervention(ObserverIntervention::Bless));
+        assert_eq!(bus.subscriber_count(), 1);
+        for drop_at in [1.0, 2.0] {
+            bus.publish(ChaosEvent::BeatDrop { intensity: 1.5, cosmic_time: drop_at });
+        }
+
+        let received: Vec<ChaosEvent> = first.try_iter().collect();
+        assert_eq!(received.len(), 3);
+        assert_eq!(received[0], ChaosEvent::ObserverIntervention(ObserverIntervention::Bless));
+        assert_eq!(bus.peek_events().count(), 2); // The history keeps only the newest
+
+        assert!(ChaosEvent::beat_drop(1.0, 1.3, 0.0).is_some());
+        assert!(ChaosEvent::beat_drop(1.3, 1.4, 0.0).is_none());
+    }
+}
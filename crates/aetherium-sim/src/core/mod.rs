@@ -0,0 +1,3 @@
+pub mod ecs;
+pub mod events;
+pub mod spatial;
@@ -0,0 +1,89 @@
+// === SPATIAL HASH ===
+// A uniform grid of buckets over world positions for "what is near here"
+// queries. Items are stored by their slot in a component array, so an index
+// is rebuilt from `World::components` each tick and answers refer straight
+// back into the same slice. Queries return every item in the cells a circle
+// touches, in slot order; callers still check the exact distance.
+
+use std::collections::HashMap;
+use glam::Vec2;
+
+#[derive(Debug, Clone)]
+pub struct SpatialHash {
+    cell_size: f32,
+    cells: HashMap<(i32, i32), Vec<usize>>,
+}
+
+impl SpatialHash {
+    pub fn new(cell_size: f32) -> Self {
+        Self { cell_size: cell_size.max(1.0), cells: HashMap::new() }
+    }
+
+    /// An index of `positions` by their slot
+    pub fn from_positions(cell_size: f32, positions: impl IntoIterator<Item = Vec2>) -> Self {
+        let mut hash = Self::new(cell_size);
+        hash.rebuild(positions);
+        hash
+    }
+
+    /// Replace the contents with `positions`, keeping bucket allocations
+    pub fn rebuild(&mut self, positions: impl IntoIterator<Item = Vec2>) {
+        for bucket in self.cells.values_mut() {
+            bucket.clear();
+        }
+        for (slot, position) in positions.into_iter().enumerate() {
+            self.insert(slot, position);
+        }
+    }
+
+    pub fn cell_size(&self) -> f32 {
+        self.cell_size
+    }
+
+    pub fn insert(&mut self, slot: usize, position: Vec2) {
+        self.cells.entry(self.cell(position)).or_default().push(slot);
+    }
+
+    /// Slots in the cells touched by the circle, sorted, into `out`
+    pub fn query_into(&self, center: Vec2, radius: f32, out: &mut Vec<usize>) {
+        out.clear();
+        let (min_x, min_y) = self.cell(center - Vec2::splat(radius));
+        let (max_x, max_y) = self.cell(center + Vec2::splat(radius));
+        for x in min_x..=max_x {
+            for y in min_y..=max_y {
+                if let Some(bucket) = self.cells.get(&(x, y)) {
+                    out.extend_from_slice(bucket);
+                }
+            }
+        }
+        out.sort_unstable(); // Buckets come out in hash order; callers rely on slot order
+    }
+
+    pub fn query(&self, center: Vec2, radius: f32) -> Vec<usize> {
+        let mut out = Vec::new();
+        self.query_into(center, radius, &mut out);
+        out
+    }
+
+    fn cell(&self, position: Vec2) -> (i32, i32) {
+        ((position.x / self.cell_size).floor() as i32, (position.y / self.cell_size).floor() as i32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_query_finds_everything_in_range_in_slot_order() {
+        let positions: Vec<Vec2> = (0..200).map(|_| Vec2::new(fastrand::f32() * 1200.0 - 100.0, fastrand::f32() * 800.0 - 100.0)).collect();
+        let hash = SpatialHash::from_positions(64.0, positions.iter().copied());
+        for center in [Vec2::ZERO, Vec2::new(600.0, 400.0), Vec2::new(1150.0, -50.0)] {
+            let candidates = hash.query(center, 60.0);
+            let expected: Vec<usize> = (0..positions.len()).filter(|&i| positions[i].distance(center) < 60.0).collect();
+            let found: Vec<usize> = candidates.iter().copied().filter(|&i| positions[i].distance(center) < 60.0).collect();
+            assert_eq!(found, expected);
+            assert!(candidates.windows(2).all(|pair| pair[0] < pair[1]));
+        }
+    }
+}
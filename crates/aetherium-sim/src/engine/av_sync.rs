@@ -0,0 +1,180 @@
+// === AUDIO-VISUAL SYNC CALIBRATION ===
+// Audio reaches the speakers later than pixels reach the screen (output
+// buffering, device latency). A user-tuned offset delays the beat→visual
+// pathway so beat-synced pulses land with the sound they belong to.
+
+use std::collections::VecDeque;
+
+/// Largest offset the user can dial in
+pub const MAX_AV_OFFSET_MS: f32 = 500.0;
+/// Offset change per key press
+pub const AV_OFFSET_STEP_MS: f32 = 5.0;
+/// Seconds between calibration clicks (well under the 3 Hz flash limit)
+pub const CALIBRATION_INTERVAL: f64 = 1.0;
+/// How long each calibration flash stays lit
+const CALIBRATION_FLASH_DURATION: f64 = 0.1;
+
+/// Time-stamped history of a signal, read back with a delay
+#[derive(Debug)]
+pub struct BeatDelayLine {
+    samples: VecDeque<(f64, f32)>,
+    max_delay: f64,
+}
+
+impl BeatDelayLine {
+    pub fn new(max_delay: f64) -> Self {
+        Self {
+            samples: VecDeque::new(),
+            max_delay,
+        }
+    }
+
+    pub fn push(&mut self, time: f64, value: f32) {
+        self.samples.push_back((time, value));
+
+        // Keep one sample older than the window so reads can still interpolate
+        let cutoff = time - self.max_delay;
+        while self.samples.len() > 2 && self.samples[1].0 <= cutoff {
+            self.samples.pop_front();
+        }
+    }
+
+    /// Value at `time`, linearly interpolated between recorded samples
+    pub fn sample(&self, time: f64) -> f32 {
+        let (first_time, first_value) = match self.samples.front() {
+            Some(&sample) => sample,
+            None => return 0.0,
+        };
+        if time <= first_time {
+            return first_value;
+        }
+
+        for pair in self.samples.iter().zip(self.samples.iter().skip(1)) {
+            let (&(t0, v0), &(t1, v1)) = pair;
+            if time <= t1 {
+                let t = if t1 > t0 { ((time - t0) / (t1 - t0)) as f32 } else { 1.0 };
+                return v0 + (v1 - v0) * t;
+            }
+        }
+
+        self.samples.back().map_or(0.0, |&(_, value)| value)
+    }
+}
+
+/// Calibration mode state and the user's audio offset
+#[derive(Debug)]
+pub struct AvSyncCalibrator {
+    offset_ms: f32,
+    active: bool,
+    next_click: f64,
+    last_click: Option<f64>,
+}
+
+impl AvSyncCalibrator {
+    pub fn new(offset_ms: f32) -> Self {
+        Self {
+            offset_ms: offset_ms.clamp(0.0, MAX_AV_OFFSET_MS),
+            active: false,
+            next_click: 0.0,
+            last_click: None,
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    pub fn offset_ms(&self) -> f32 {
+        self.offset_ms
+    }
+
+    /// Delay applied to the beat→visual pathway
+    pub fn offset_seconds(&self) -> f64 {
+        self.offset_ms as f64 / 1000.0
+    }
+
+    pub fn adjust_offset(&mut self, delta_ms: f32) -> f32 {
+        self.offset_ms = (self.offset_ms + delta_ms).clamp(0.0, MAX_AV_OFFSET_MS);
+        self.offset_ms
+    }
+
+    pub fn toggle(&mut self, now: f64) -> bool {
+        self.active = !self.active;
+        self.next_click = now;
+        self.last_click = None;
+        self.active
+    }
+
+    /// Returns true when a calibration click should be emitted this frame
+    pub fn update(&mut self, now: f64) -> bool {
+        if !self.active || now < self.next_click {
+            return false;
+        }
+        self.last_click = Some(now);
+        self.next_click = now + CALIBRATION_INTERVAL;
+        true
+    }
+
+    /// Brightness of the calibration marker (0.0 to 1.0), lit `offset` after each click
+    pub fn flash_level(&self, now: f64) -> f32 {
+        let click = match (self.active, self.last_click) {
+            (true, Some(click)) => click,
+            _ => return 0.0,
+        };
+
+        let since_flash = now - (click + self.offset_seconds());
+        if (0.0..CALIBRATION_FLASH_DURATION).contains(&since_flash) {
+            1.0
+        } else {
+            0.0
+        }
+    }
+}
+
+impl Default for AvSyncCalibrator {
+    fn default() -> Self {
+        Self::new(0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delay_line_interpolates_history() {
+        let mut line = BeatDelayLine::new(1.0);
+        line.push(0.0, 0.0);
+        line.push(0.1, 1.0);
+        line.push(0.2, 0.0);
+
+        assert!((line.sample(0.05) - 0.5).abs() < 1e-5);
+        assert!((line.sample(0.1) - 1.0).abs() < 1e-5);
+        assert_eq!(line.sample(5.0), 0.0);
+        assert_eq!(line.sample(-1.0), 0.0);
+    }
+
+    #[test]
+    fn test_delay_line_drops_old_samples() {
+        let mut line = BeatDelayLine::new(0.5);
+        for frame in 0..600 {
+            line.push(frame as f64 / 60.0, frame as f32);
+        }
+        // ~0.5s of history at 60 fps, plus the interpolation anchor
+        assert!(line.samples.len() <= 32);
+        assert!(line.sample(599.0 / 60.0 - 0.5) > 0.0);
+    }
+
+    #[test]
+    fn test_flash_lands_offset_after_click() {
+        let mut calibrator = AvSyncCalibrator::new(100.0);
+        calibrator.toggle(0.0);
+        assert!(calibrator.update(0.0));
+        assert!(!calibrator.update(0.5));
+
+        assert_eq!(calibrator.flash_level(0.05), 0.0);
+        assert_eq!(calibrator.flash_level(0.15), 1.0);
+        assert_eq!(calibrator.flash_level(0.25), 0.0);
+        assert!(calibrator.update(CALIBRATION_INTERVAL));
+    }
+}
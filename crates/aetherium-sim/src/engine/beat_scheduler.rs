@@ -0,0 +1,107 @@
+// === BEAT-QUANTIZED SCHEDULER ===
+// Events that fire whenever their cause happens to land drift mid-beat and
+// feel unsynced. Subsystems queue them here instead, to run on the next beat,
+// the next bar or the next beat drop, and the organism drains whatever is due
+// each tick from the beat engine's count. A queued beat drop that never comes
+// gives up after a few bars and fires on a downbeat, so nothing waits forever.
+
+use std::collections::VecDeque;
+
+/// Beats in one bar
+pub const BEATS_PER_BAR: u64 = 4;
+/// Bars an action waits for a beat drop before settling for the next downbeat
+const MAX_DROP_WAIT_BARS: u64 = 2;
+
+/// Musical boundary an action waits for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Quantize {
+    Beat,     // The next beat
+    Bar,      // The first beat of the next bar
+    BeatDrop, // The next beat drop, or a downbeat after a few bars without one
+}
+
+struct Pending<A> {
+    quantize: Quantize,
+    queued_beat: u64,
+    action: A,
+}
+
+/// Actions held back until their beat boundary
+pub struct BeatScheduler<A> {
+    pending: VecDeque<Pending<A>>,
+    beat: Option<u64>, // Beat count at the last update; None before the first
+}
+
+impl<A> Default for BeatScheduler<A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A> BeatScheduler<A> {
+    pub fn new() -> Self {
+        Self { pending: VecDeque::new(), beat: None }
+    }
+
+    /// Queue `action` to run at the next `quantize` boundary
+    pub fn schedule(&mut self, quantize: Quantize, action: A) {
+        let queued_beat = self.beat.unwrap_or(0);
+        self.pending.push_back(Pending { quantize, queued_beat, action });
+    }
+
+    /// Actions still waiting
+    pub fn pending(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Advance to `beat` (beats elapsed since the start) and return every action now due,
+    /// in the order they were queued; `beat_drop` is true on the tick a drop begins
+    pub fn update(&mut self, beat: u64, beat_drop: bool) -> Vec<A> {
+        let previous = self.beat.replace(beat).unwrap_or(beat);
+        let new_beat = beat > previous;
+        let new_bar = beat / BEATS_PER_BAR > previous / BEATS_PER_BAR;
+
+        let mut due = Vec::new();
+        let mut waiting = VecDeque::with_capacity(self.pending.len());
+        for pending in self.pending.drain(..) {
+            let fire = match pending.quantize {
+                Quantize::Beat => new_beat,
+                Quantize::Bar => new_bar,
+                Quantize::BeatDrop => beat_drop || (new_bar && beat >= pending.queued_beat + MAX_DROP_WAIT_BARS * BEATS_PER_BAR),
+            };
+            if fire {
+                due.push(pending.action);
+            } else {
+                waiting.push_back(pending);
+            }
+        }
+        self.pending = waiting;
+        due
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_actions_wait_for_their_boundary() {
+        let mut scheduler = BeatScheduler::new();
+        assert!(scheduler.update(5, false).is_empty());
+        scheduler.schedule(Quantize::Bar, "bar");
+        scheduler.schedule(Quantize::Beat, "beat");
+        scheduler.schedule(Quantize::BeatDrop, "drop");
+
+        assert!(scheduler.update(5, false).is_empty(), "nothing fires mid-beat");
+        assert_eq!(scheduler.update(6, false), vec!["beat"]);
+        assert!(scheduler.update(7, false).is_empty());
+        assert_eq!(scheduler.update(8, false), vec!["bar"]);
+        assert_eq!(scheduler.update(8, true), vec!["drop"]);
+        assert_eq!(scheduler.pending(), 0);
+
+        // A drop that never comes settles for a downbeat after a few bars
+        scheduler.schedule(Quantize::BeatDrop, "late");
+        assert!(scheduler.update(12, false).is_empty());
+        assert_eq!(scheduler.update(8 + MAX_DROP_WAIT_BARS * BEATS_PER_BAR, false), vec!["late"]);
+    }
+}
@@ -0,0 +1,189 @@
+use glam::Vec2;
+use fastrand;
+
+// Phase 2: Mathematical Chaos Engine Components
+
+/// A reading from the installation's physical surroundings, blended into the pure chaos dimension
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExternalEntropy {
+    pub value: f32,  // 0-1
+    pub weight: f32, // Share of the chaos dimension it replaces, 0-1
+}
+
+#[derive(Debug, Clone)]
+pub struct ChaosDecisionEngine {
+    pub dimensions: [f32; 11],          // 11D consciousness state space
+    pub prime_generators: Vec<u64>,     // Prime number chaos sources
+    pub quantum_fluctuations: f32,      // Uncertainty principle simulation
+    pub harmonic_resonance: [f32; 7],   // Musical mathematics integration
+    pub external_entropy: Option<ExternalEntropy>, // Real-world noise nudging the 11th dimension
+}
+
+impl ChaosDecisionEngine {
+    pub fn new() -> Self {
+        // Pre-calculated first 7 primes for performance
+        let prime_generators = vec![2, 3, 5, 7, 11, 13, 17];
+
+        Self {
+            dimensions: [fastrand::f32(); 11],
+            prime_generators,
+            quantum_fluctuations: fastrand::f32(),
+            harmonic_resonance: [fastrand::f32(); 7],
+            external_entropy: None,
+        }
+    }
+
+    pub fn update(&mut self, llama_data: LlamaSnapshot, beat_intensity: f32, cosmic_time: f64) {
+        // Update 11D consciousness state space based on llama state
+        self.dimensions[0] = (llama_data.color.x / 360.0 * std::f32::consts::TAU).sin();
+        self.dimensions[1] = llama_data.awareness_level;
+        self.dimensions[2] = llama_data.social_attraction;
+        self.dimensions[3] = llama_data.exploration_drive;
+        self.dimensions[4] = llama_data.reality_distortion;
+        self.dimensions[5] = beat_intensity;
+        self.dimensions[6] = llama_data.emotional_state;
+        self.dimensions[7] = llama_data.memory_intensity;
+        self.dimensions[8] = (cosmic_time as f32 * 0.1).sin();
+        self.dimensions[9] = llama_data.consciousness;
+        // Pure chaos injection, leaning towards the room's own noise when an entropy feed runs
+        let chaos = fastrand::f32();
+        self.dimensions[10] = match self.external_entropy {
+            Some(entropy) => chaos + (entropy.value.clamp(0.0, 1.0) - chaos) * entropy.weight.clamp(0.0, 1.0),
+            None => chaos,
+        };
+
+        // Update quantum fluctuations using prime chaos
+        let prime_chaos = self.calculate_prime_chaos(cosmic_time);
+        self.quantum_fluctuations = (self.quantum_fluctuations + prime_chaos * 0.1).clamp(0.0, 1.0);
+
+        // Update harmonic resonance layers
+        for (i, harmonic) in self.harmonic_resonance.iter_mut().enumerate() {
+            let prime_freq = self.prime_generators[i] as f32 / 100.0;
+            *harmonic = (cosmic_time as f32 * prime_freq * 0.01).sin() * beat_intensity;
+        }
+    }
+
+    pub fn calculate_prime_chaos(&self, cosmic_time: f64) -> f32 {
+        let mut chaos = 0.0;
+        for (i, &prime) in self.prime_generators.iter().enumerate() {
+            let freq = prime as f32 / 1000.0;
+            let phase = cosmic_time as f32 * freq + (i as f32 * 0.5);
+            chaos += (phase.sin() * phase.cos()).abs();
+        }
+        (chaos / self.prime_generators.len() as f32).clamp(0.0, 1.0)
+    }
+
+    pub fn get_decision_vector(&self) -> DecisionVector {
+        // Calculate behavior weights from 11D space
+        let movement_urgency = self.dimensions[1] + self.dimensions[5] + self.quantum_fluctuations;
+        let exploration_drive = self.dimensions[3] + self.dimensions[10] + self.harmonic_resonance[2];
+        let social_attraction = self.dimensions[2] + self.dimensions[6] * 0.5;
+        let chaos_acceptance = self.dimensions[10] + self.quantum_fluctuations + self.harmonic_resonance[6];
+
+        DecisionVector {
+            movement_urgency: movement_urgency.clamp(0.0, 1.0),
+            exploration_drive: exploration_drive.clamp(0.0, 1.0),
+            social_attraction: social_attraction.clamp(0.0, 1.0),
+            chaos_acceptance: chaos_acceptance.clamp(0.0, 1.0),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct DecisionVector {
+    pub movement_urgency: f32,
+    pub exploration_drive: f32,
+    pub social_attraction: f32,
+    pub chaos_acceptance: f32,
+}
+
+#[derive(Debug, Clone)]
+pub struct LlamaSnapshot {
+    pub color: Vec2,
+    pub awareness_level: f32,
+    pub social_attraction: f32,
+    pub exploration_drive: f32,
+    pub reality_distortion: f32,
+    pub emotional_state: f32,
+    pub memory_intensity: f32,
+    pub consciousness: f32,
+}
+
+#[derive(Debug, Clone)]
+pub struct AdvancedBeatEngine {
+    pub primary_rhythm: f32,            // Core mathematical heartbeat
+    pub harmonic_layers: Vec<f32>,      // Multiple overlapping rhythms
+    pub chaos_amplification: f32,       // Feedback loop strength
+    pub consciousness_coupling: f32,    // Beat-consciousness interaction
+    prime_list: Vec<u64>,               // Pre-calculated primes
+    time_accumulator: f64,
+}
+
+impl AdvancedBeatEngine {
+    pub fn new() -> Self {
+        Self {
+            primary_rhythm: 120.0, // BPM
+            harmonic_layers: vec![1.0, 0.5, 0.25, 0.75, 1.33], // Harmonic ratios
+            chaos_amplification: 0.3,
+            consciousness_coupling: 0.5,
+            prime_list: vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97], // First 25 primes
+            time_accumulator: 0.0,
+        }
+    }
+
+    pub fn update(&mut self, dt: f32, total_consciousness: f32) -> f32 {
+        self.time_accumulator += dt as f64;
+
+        // Calculate base beat from primary rhythm
+        let beat_phase = (self.time_accumulator * self.primary_rhythm as f64 / 60.0) % 1.0;
+        let base_beat = ((beat_phase as f32) * std::f32::consts::TAU).sin().abs();
+
+        // Add harmonic layers with prime number modulation
+        let mut harmonic_sum = 0.0f32;
+        for (i, &ratio) in self.harmonic_layers.iter().enumerate() {
+            let prime = self.prime_list[i % self.prime_list.len()];
+            let prime_mod = (prime % 17) as f32 / 17.0;
+            let harmonic_phase = (self.time_accumulator * self.primary_rhythm as f64 * ratio as f64 / 60.0) % 1.0;
+            let harmonic = ((harmonic_phase as f32) * std::f32::consts::TAU * prime_mod).sin().abs();
+            harmonic_sum += harmonic * (1.0 / (i + 1) as f32); // Decay higher harmonics
+        }
+        harmonic_sum /= self.harmonic_layers.len() as f32;
+
+        // Apply consciousness coupling
+        let consciousness_factor = 1.0 + total_consciousness * self.consciousness_coupling * 0.1;
+
+        // Apply chaos amplification
+        let chaos_factor = 1.0 + self.chaos_amplification * fastrand::f32();
+
+        // Combine all factors
+        let final_intensity = (base_beat * 0.6 + harmonic_sum * 0.4) * consciousness_factor * chaos_factor;
+
+        final_intensity.clamp(0.0, 2.0)
+    }
+
+    pub fn add_chaos_feedback(&mut self, chaos_amount: f32) {
+        self.chaos_amplification = (self.chaos_amplification + chaos_amount * 0.1).clamp(0.0, 1.0);
+        // Modulate harmonic layers with chaos
+        for harmonic in &mut self.harmonic_layers {
+            *harmonic *= 1.0 + chaos_amount * 0.05;
+        }
+    }
+
+    pub fn get_prime_factor(&self) -> u64 {
+        self.prime_list[0]
+    }
+
+    /// Position within the primary beat `delay` seconds ago, 0.0 to 1.0
+    pub fn beat_phase(&self, delay: f64) -> f32 {
+        ((self.time_accumulator - delay).max(0.0) * self.primary_rhythm as f64 / 60.0 % 1.0) as f32
+    }
+
+    /// Whole primary beats elapsed `delay` seconds ago
+    pub fn beat_count(&self, delay: f64) -> u64 {
+        ((self.time_accumulator - delay).max(0.0) * self.primary_rhythm as f64 / 60.0) as u64
+    }
+
+    pub fn get_time_accumulator(&self) -> f64 {
+        self.time_accumulator
+    }
+}
@@ -0,0 +1,209 @@
+// === CONSCIOUSNESS GRAPH EXPORT ===
+// A snapshot of the social structure of a run for offline analysis: every
+// llama is a node, hive minds and packs are groups of nodes, and edges are
+// the hive connection network, pack membership (member to leader) and
+// ongoing predation (predator to prey). The same snapshot is written as
+// GraphViz DOT, with hives as clusters, or as JSON for scripts and notebooks.
+
+use std::fmt::Write as _;
+use std::path::Path;
+use serde::Serialize;
+use crate::core::ecs::{EntityId, World};
+use crate::entities::Llama;
+use super::consciousness_multiplication::{ConsciousnessHierarchy, ConsciousnessPredation, HiveMind};
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GraphNode {
+    pub id: EntityId,
+    pub species: String,
+    pub consciousness: f32,
+    pub position: [f32; 2],
+}
+
+/// A hive mind or pack and the llamas in it
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GraphGroup {
+    pub id: EntityId,              // Hive or hierarchy entity
+    pub members: Vec<EntityId>,
+    pub strength: f32,             // Collective consciousness of a hive, collective strength of a pack
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EdgeKind {
+    HiveConnection, // Undirected link in a hive's connection network
+    PackMember,     // Member to pack leader
+    Predation,      // Predator to prey
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GraphEdge {
+    pub source: EntityId,
+    pub target: EntityId,
+    pub kind: EdgeKind,
+    pub weight: f32, // Absorption progress for predation, 1.0 otherwise
+}
+
+/// The consciousness network at one moment, with edges only between live llamas
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ConsciousnessGraph {
+    pub time: f32,
+    pub nodes: Vec<GraphNode>,
+    pub hives: Vec<GraphGroup>,
+    pub packs: Vec<GraphGroup>,
+    pub edges: Vec<GraphEdge>,
+}
+
+/// Output format, picked from the file extension when writing
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphFormat {
+    Dot,
+    Json,
+}
+
+impl GraphFormat {
+    /// `.json` is JSON; anything else is DOT
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some(extension) if extension.eq_ignore_ascii_case("json") => Self::Json,
+            _ => Self::Dot,
+        }
+    }
+}
+
+impl ConsciousnessGraph {
+    pub fn capture(world: &World, time: f32) -> Self {
+        let mut nodes: Vec<GraphNode> = world.query::<Llama>().into_iter()
+            .map(|(id, llama)| GraphNode {
+                id,
+                species: format!("{:?}", llama.species),
+                consciousness: llama.consciousness,
+                position: llama.position.into(),
+            })
+            .collect();
+        nodes.sort_by_key(|node| node.id);
+        let alive = |id: EntityId| world.get_component::<Llama>(id).is_some();
+        let mut edges = Vec::new();
+
+        let mut hives = Vec::new();
+        for (id, hive) in world.query::<HiveMind>() {
+            edges.extend(hive.connection_network.iter()
+                .filter(|&&(a, b)| alive(a) && alive(b))
+                .map(|&(source, target)| GraphEdge { source, target, kind: EdgeKind::HiveConnection, weight: 1.0 }));
+            let members = hive.member_entities.iter().copied().filter(|&member| alive(member)).collect();
+            hives.push(GraphGroup { id, members, strength: hive.collective_consciousness });
+        }
+
+        let mut packs = Vec::new();
+        for (id, pack) in world.query::<ConsciousnessHierarchy>() {
+            let members: Vec<EntityId> = pack.members.iter().copied().filter(|&member| alive(member)).collect();
+            if members.len() < 2 || !alive(pack.leader) {
+                continue; // Lone individuals are not a pack
+            }
+            edges.extend(members.iter()
+                .filter(|&&member| member != pack.leader)
+                .map(|&source| GraphEdge { source, target: pack.leader, kind: EdgeKind::PackMember, weight: 1.0 }));
+            packs.push(GraphGroup { id, members, strength: pack.collective_strength });
+        }
+
+        edges.extend(world.components::<ConsciousnessPredation>().iter()
+            .filter(|predation| alive(predation.predator_id) && alive(predation.prey_id))
+            .map(|predation| GraphEdge {
+                source: predation.predator_id,
+                target: predation.prey_id,
+                kind: EdgeKind::Predation,
+                weight: predation.absorption_progress,
+            }));
+
+        hives.sort_by_key(|group| group.id);
+        packs.sort_by_key(|group| group.id);
+        Self { time, nodes, hives, packs, edges }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("graph of plain numbers and strings always serializes")
+    }
+
+    /// GraphViz source; hives are clusters, predation edges are the only arrows
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph consciousness {\n    node [shape=circle, style=filled, fillcolor=\"#ddeeff\"];\n");
+        let _ = writeln!(dot, "    label=\"consciousness network at {:.1}s\";", self.time);
+        for hive in &self.hives {
+            let _ = writeln!(dot, "    subgraph cluster_hive_{} {{\n        label=\"hive {} ({:.2})\";", hive.id, hive.id, hive.strength);
+            for member in &hive.members {
+                let _ = writeln!(dot, "        {};", member);
+            }
+            dot.push_str("    }\n");
+        }
+        for node in &self.nodes {
+            let _ = writeln!(dot, "    {} [label=\"{}\\n{}\\n{:.2}\"];", node.id, node.id, node.species, node.consciousness);
+        }
+        for edge in &self.edges {
+            let attributes = match edge.kind {
+                EdgeKind::HiveConnection => "dir=none, color=\"#3366cc\"".to_string(),
+                EdgeKind::PackMember => "dir=none, style=dashed, color=\"#669966\"".to_string(),
+                EdgeKind::Predation => format!("color=\"#cc3333\", label=\"{:.2}\"", edge.weight),
+            };
+            let _ = writeln!(dot, "    {} -> {} [{}];", edge.source, edge.target, attributes);
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Write as JSON or DOT depending on the extension of `path`
+    pub fn write(&self, path: &Path) -> std::io::Result<()> {
+        let contents = match GraphFormat::from_path(path) {
+            GraphFormat::Json => self.to_json(),
+            GraphFormat::Dot => self.to_dot(),
+        };
+        std::fs::write(path, contents)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glam::Vec2;
+    use crate::entities::SpeciesType;
+
+    #[test]
+    fn test_graph_covers_hives_packs_and_predation() {
+        let mut world = World::new();
+        let llamas: Vec<EntityId> = (0..4)
+            .map(|i| world.spawn(Llama::new_with_species(Vec2::new(i as f32 * 10.0, 0.0), SpeciesType::DiscoLlama)))
+            .collect();
+        let gone = world.spawn(Llama::new_with_species(Vec2::ZERO, SpeciesType::QuantumSheep));
+        world.despawn(gone);
+        world.spawn(HiveMind {
+            member_entities: vec![llamas[0], llamas[1], gone],
+            collective_consciousness: 1.5,
+            hive_center: Vec2::new(5.0, 0.0),
+            connection_network: vec![(llamas[0], llamas[1]), (llamas[1], gone)],
+            shared_memories: Vec::new(),
+            collective_decision_weight: 0.8,
+            emergence_timestamp: 0.0,
+            pool: 0.0,
+        });
+        world.spawn(ConsciousnessPredation {
+            predator_id: llamas[2],
+            prey_id: llamas[3],
+            absorption_progress: 0.4,
+            resistance_strength: 0.5,
+            visual_effect_intensity: 0.0,
+        });
+
+        let graph = ConsciousnessGraph::capture(&world, 12.0);
+        assert_eq!(graph.nodes.len(), 4);
+        assert_eq!(graph.hives[0].members, vec![llamas[0], llamas[1]], "despawned members are left out");
+        let kinds: Vec<EdgeKind> = graph.edges.iter().map(|edge| edge.kind).collect();
+        assert_eq!(kinds, vec![EdgeKind::HiveConnection, EdgeKind::Predation]);
+
+        let dot = graph.to_dot();
+        assert!(dot.starts_with("digraph consciousness {") && dot.contains("subgraph cluster_hive_"));
+        assert!(dot.contains(&format!("{} -> {} [color", llamas[2], llamas[3])));
+        let json: serde_json::Value = serde_json::from_str(&graph.to_json()).unwrap();
+        assert_eq!(json["edges"][1]["kind"], "predation");
+        assert_eq!(GraphFormat::from_path(Path::new("run.JSON")), GraphFormat::Json);
+        assert_eq!(GraphFormat::from_path(Path::new("run.gv")), GraphFormat::Dot);
+    }
+}
@@ -0,0 +1,1254 @@
+// PHASE 5: CONSCIOUSNESS MULTIPLICATION - "When One Mind Becomes Legion"
+// Hierarchies, hive minds and predations are ECS entities that reference llamas
+// by EntityId, so removing a llama never re-targets them onto a different one.
+// Each process_* step below is a system over the shared World.
+
+use glam::Vec2;
+use fastrand;
+use tracing::{debug, info};
+use crate::core::ecs::{EntityId, World};
+use crate::core::events::{ChaosEvent, EventBus};
+use crate::simulation::{DigitalEcosystem, Obstacles};
+use crate::entities::{Llama, ConsciousnessLevel, Feeling, MemoryFragment, MemoryKind, SpeciesType, SPECIES_COUNT};
+use super::diplomacy::{Diplomacy, DiplomacyEvent, ALLY_SPOILS};
+use super::gifting::process_gifting;
+use super::hive_behavior::{HiveEvent, NegotiationOutcome, process_hive_behavior};
+use super::speciation::{Speciation, SpeciesEmergence};
+
+/// Largest pack a single hierarchy can recruit
+const MAX_PACK_SIZE: usize = 8;
+/// Minimum members for a hierarchy to awaken as a hive mind
+const HIVE_FORMATION_SIZE: usize = 9;
+/// Hive events kept for the app to drain; older ones are dropped
+const MAX_HIVE_EVENTS: usize = 64;
+/// Hive minds dissolve when fewer members than this remain
+pub(super) const HIVE_DISSOLVE_SIZE: usize = 5;
+/// Member memories weaker than this stay private instead of joining the hive's collective memory
+const SHARED_MEMORY_THRESHOLD: f32 = 0.3;
+
+/// A pack (or lone individual) of same-species or allied llamas, rebuilt each frame.
+/// The entity is keyed by its leader so it survives membership churn
+#[derive(Debug, Clone)]
+pub struct ConsciousnessHierarchy {
+    pub level: ConsciousnessLevel,
+    pub leader: EntityId,               // First member; identifies the hierarchy across frames
+    pub members: Vec<EntityId>,        // Llama entities that belong to this consciousness
+    pub collective_strength: f32,      // Combined consciousness power
+    pub territory_control: f32,        // Territorial influence 0.0-1.0
+    pub war_efficiency: f32,           // Combat effectiveness modifier
+    pub hive_connection_strength: f32, // How strongly hive members are connected
+    pub absorption_capacity: f32,      // Ability to absorb other consciousness
+}
+
+#[derive(Debug, Clone)]
+pub struct WarfareState {
+    pub species_populations: [u32; SPECIES_COUNT],        // Indexed by species_index
+    pub territorial_dominance: [f32; SPECIES_COUNT],      // Territory control per species 0.0-1.0
+    pub extinction_pressure: [f32; SPECIES_COUNT],        // Extinction threat level per species
+    pub consciousness_crystals_controlled: [u32; SPECIES_COUNT], // Resource control
+    pub active_conflicts: Vec<SpeciesConflict>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SpeciesConflict {
+    pub attacker_species: SpeciesType,
+    pub defender_species: SpeciesType,
+    pub conflict_intensity: f32,       // 0.0-1.0 intensity of the conflict
+    pub territory_contested: Vec2,     // Center point of contested territory
+    pub duration: f32,                 // How long the conflict has lasted
+    pub victory_threshold: f32,        // Consciousness advantage needed to win
+}
+
+impl SpeciesConflict {
+    /// The event announcing this conflict is over
+    fn ended(&self, victor: Option<SpeciesType>) -> ChaosEvent {
+        ChaosEvent::ConflictEnded {
+            attacker: self.attacker_species,
+            defender: self.defender_species,
+            front: self.territory_contested,
+            victor,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct HiveMind {
+    pub member_entities: Vec<EntityId>, // Llama entities in the hive
+    pub collective_consciousness: f32,  // Combined consciousness level
+    pub hive_center: Vec2,             // Geometric center of the hive
+    pub connection_network: Vec<(EntityId, EntityId)>, // Pairs of connected entities
+    pub shared_memories: Vec<MemoryFragment>, // Collective memory, passed on to members with mutation
+    pub collective_decision_weight: f32, // How much the hive influences individual decisions
+    pub emergence_timestamp: f32,      // When this hive mind formed
+    pub pool: f32,                     // Consciousness members gave to the hive, paid out to those struggling
+}
+
+#[derive(Debug, Clone)]
+pub struct ConsciousnessPredation {
+    pub predator_id: EntityId,
+    pub prey_id: EntityId,
+    pub absorption_progress: f32,      // 0.0-1.0 progress of consumption
+    pub resistance_strength: f32,      // How much the prey is fighting back
+    pub visual_effect_intensity: f32,  // Visual feedback for absorption event
+}
+
+#[derive(Debug, Clone)]
+pub struct MetaConsciousnessObserver {
+    pub observer_position: Vec2,
+    pub awareness_radius: f32,         // How far the observer can see
+    pub intervention_power: f32,       // Ability to influence consciousness wars
+    pub observation_intensity: f32,    // Current focus level
+    pub last_intervention: f32,        // Time since last intervention
+    pub autonomous: bool,              // False leaves the run purely emergent; manual interventions still work
+    pub intervention_rate: f32,        // Multiplier on the chance of acting once conditions call for it
+    pub consciousness_analysis: ConsciousnessAnalysis,
+}
+
+/// Ways the observer can reach into the consciousness wars
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ObserverIntervention {
+    Bless,        // Boost the species closest to extinction
+    ForcePeace,   // End all conflicts and calm everyone down
+    Scramble,     // Shuffle positions to break territorial deadlocks
+    Redistribute, // Even out consciousness between species
+}
+
+impl ObserverIntervention {
+    pub const ALL: [ObserverIntervention; 4] = [Self::Bless, Self::ForcePeace, Self::Scramble, Self::Redistribute];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Bless => "BLESSING",
+            Self::ForcePeace => "FORCED PEACE",
+            Self::Scramble => "REALITY SCRAMBLE",
+            Self::Redistribute => "REDISTRIBUTION",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ConsciousnessAnalysis {
+    pub total_individual_entities: u32,
+    pub total_pack_collectives: u32,
+    pub total_hive_minds: u32,
+    pub dominant_species: SpeciesType,
+    pub extinction_imminent: Option<SpeciesType>, // Species about to go extinct
+    pub consciousness_distribution: [f32; SPECIES_COUNT], // Consciousness per species
+    pub warfare_intensity: f32,       // Overall conflict level
+    pub ecosystem_stability: f32,     // 0.0-1.0 stability measure
+}
+
+/// Global Phase 5 state plus the system schedule; per-entity state lives in the World
+#[derive(Debug, Clone)]
+pub struct ConsciousnessMultiplicationSystem {
+    pub warfare_state: WarfareState,
+    pub meta_observer: MetaConsciousnessObserver,
+    pub evolution_pressure_accumulator: f32,
+    pub consciousness_crystal_spawn_rate: f32,
+    pub territorial_conflict_threshold: f32,
+    pub diplomacy: Diplomacy,
+    pub speciation: Speciation,  // Hybrid species that emerged from mixed hives
+    hive_events: Vec<HiveEvent>, // Collective hive actions since the last drain
+    resolved_fronts: Vec<Vec2>,  // Where conflicts were decided since the last drain
+    absorptions: Vec<(EntityId, EntityId)>, // Completed absorptions since the last drain, predator first
+    emergences: Vec<SpeciesEmergence>, // Hybrid species that emerged since the last drain
+}
+
+/// Position of a species in the per-species arrays of `WarfareState`
+pub fn species_index(species: SpeciesType) -> usize {
+    species.to_index()
+}
+
+/// Mean position of the listed llamas that still exist
+fn center_of(world: &World, members: &[EntityId]) -> Vec2 {
+    let positions: Vec<Vec2> = members.iter()
+        .filter_map(|&id| world.get_component::<Llama>(id))
+        .map(|llama| llama.position)
+        .collect();
+    if positions.is_empty() {
+        return Vec2::ZERO;
+    }
+    positions.iter().fold(Vec2::ZERO, |acc, &pos| acc + pos) / positions.len() as f32
+}
+
+impl ConsciousnessMultiplicationSystem {
+    pub fn new() -> Self {
+        Self {
+            warfare_state: WarfareState {
+                species_populations: [0; SPECIES_COUNT],
+                territorial_dominance: [1.0 / SPECIES_COUNT as f32; SPECIES_COUNT], // Start balanced
+                extinction_pressure: [0.0; SPECIES_COUNT],
+                consciousness_crystals_controlled: [0; SPECIES_COUNT],
+                active_conflicts: Vec::new(),
+            },
+            meta_observer: MetaConsciousnessObserver {
+                observer_position: Vec2::new(600.0, 400.0), // Center of screen
+                awareness_radius: 800.0,
+                intervention_power: 1.0,
+                observation_intensity: 0.5,
+                last_intervention: 0.0,
+                autonomous: true,
+                intervention_rate: 1.0,
+                consciousness_analysis: ConsciousnessAnalysis {
+                    total_individual_entities: 0,
+                    total_pack_collectives: 0,
+                    total_hive_minds: 0,
+                    dominant_species: SpeciesType::DiscoLlama,
+                    extinction_imminent: None,
+                    consciousness_distribution: [1.0 / SPECIES_COUNT as f32; SPECIES_COUNT],
+                    warfare_intensity: 0.0,
+                    ecosystem_stability: 1.0,
+                },
+            },
+            evolution_pressure_accumulator: 0.0,
+            consciousness_crystal_spawn_rate: 1.0,
+            territorial_conflict_threshold: 0.7,
+            diplomacy: Diplomacy::new(),
+            speciation: Speciation::new(),
+            hive_events: Vec::new(),
+            resolved_fronts: Vec::new(),
+            absorptions: Vec::new(),
+            emergences: Vec::new(),
+        }
+    }
+
+    /// One tick; walls and pillars and the chaos tuning come from `ecosystem`
+    pub fn update(&mut self, dt: f32, world: &mut World, ecosystem: &DigitalEcosystem, cosmic_time: f32, beat_intensity: f32, events: &mut EventBus) {
+        let (obstacles, tuning) = (&ecosystem.obstacles, &ecosystem.tuning);
+
+        // Update meta observer consciousness analysis
+        self.update_consciousness_analysis(world);
+
+        // Process consciousness hierarchy formation and dissolution
+        process_consciousness_hierarchies(world, &self.diplomacy);
+
+        // Handle hive mind emergence and collective behavior
+        process_hive_mind_emergence(world, dt, cosmic_time, tuning.hive_connection, events);
+
+        // Hives of allies that stay mixed give rise to hybrid species
+        for emergence in self.speciation.update(world, dt, cosmic_time) {
+            events.publish(ChaosEvent::SpeciesEmerged {
+                hybrid: emergence.hybrid,
+                name: emergence.name.clone(),
+                parents: emergence.parents,
+                hive: emergence.hive,
+            });
+            if self.emergences.len() < MAX_HIVE_EVENTS {
+                self.emergences.push(emergence);
+            }
+        }
+
+        // Hives act as one: hunting, beat pulses, sacrifice and negotiation
+        let hive_events = process_hive_behavior(world, dt, beat_intensity, self.warfare_state.extinction_pressure, cosmic_time);
+        for event in hive_events {
+            if let HiveEvent::Negotiation { species: [a, b], outcome: NegotiationOutcome::Truce, .. } = event {
+                // A truce ends the war between the two species and seals a treaty
+                self.warfare_state.active_conflicts.retain(|conflict| {
+                    let sides = [conflict.attacker_species, conflict.defender_species];
+                    let ended = sides == [a, b] || sides == [b, a];
+                    if ended {
+                        events.publish(conflict.ended(None));
+                    }
+                    !ended
+                });
+                self.diplomacy.sign_treaty(a, b, cosmic_time);
+            }
+            if self.hive_events.len() >= MAX_HIVE_EVENTS {
+                self.hive_events.remove(0);
+            }
+            self.hive_events.push(event);
+        }
+
+        // Execute consciousness predation events
+        for absorption in process_consciousness_predation(world, obstacles, tuning.predation_chance, dt) {
+            if self.absorptions.len() < MAX_HIVE_EVENTS {
+                self.absorptions.push(absorption);
+            }
+        }
+
+        // The generous share with struggling kin and allies, directly or through their hive
+        for gift in process_gifting(world, &self.diplomacy, obstacles, dt) {
+            events.publish(ChaosEvent::ConsciousnessGifted {
+                donor: gift.donor,
+                recipient: gift.recipient,
+                species: gift.species,
+                position: gift.position,
+                amount: gift.amount,
+            });
+        }
+
+        // Run species warfare and territorial conflicts
+        self.diplomacy.update(dt);
+        self.process_species_warfare(world.components_mut::<Llama>(), dt, cosmic_time, tuning.conflict_chance, events);
+
+        // Apply evolution pressure and extinction dynamics
+        self.process_evolution_pressure(world.components_mut::<Llama>(), dt);
+
+        // Meta-consciousness observer interventions
+        self.process_meta_observer_interventions(world.components_mut::<Llama>(), dt, cosmic_time, tuning.intervention_chance, events);
+
+        // Update warfare state and population tracking
+        self.update_warfare_state(world.components::<Llama>());
+    }
+
+    fn update_consciousness_analysis(&mut self, world: &World) {
+        let llamas = world.components::<Llama>();
+        let analysis = &mut self.meta_observer.consciousness_analysis;
+
+        // Count consciousness levels
+        analysis.total_individual_entities = llamas.iter()
+            .filter(|l| l.consciousness_level == ConsciousnessLevel::Individual)
+            .count() as u32;
+
+        analysis.total_pack_collectives = llamas.iter()
+            .filter(|l| l.consciousness_level == ConsciousnessLevel::Pack)
+            .count() as u32;
+
+        analysis.total_hive_minds = world.count::<HiveMind>() as u32;
+
+        // Calculate consciousness distribution by species
+        let mut species_consciousness = [0.0f32; SPECIES_COUNT];
+        let mut species_counts = [0u32; SPECIES_COUNT];
+
+        for llama in llamas {
+            let species_idx = species_index(llama.species);
+            species_consciousness[species_idx] += llama.consciousness;
+            species_counts[species_idx] += 1;
+        }
+
+        let total_consciousness: f32 = species_consciousness.iter().sum();
+        if total_consciousness > 0.0 {
+            analysis.consciousness_distribution = species_consciousness.map(|consciousness| consciousness / total_consciousness);
+        }
+
+        // Determine dominant species
+        let max_consciousness_idx = analysis.consciousness_distribution
+            .iter()
+            .enumerate()
+            .max_by(|a, b| a.1.partial_cmp(b.1).unwrap())
+            .map(|(i, _)| i)
+            .unwrap_or(0);
+
+        analysis.dominant_species = SpeciesType::from_index(max_consciousness_idx);
+
+        // Check for extinction threats
+        analysis.extinction_imminent = None;
+        for (i, &count) in species_counts.iter().enumerate() {
+            if count < 3 && count > 0 { // Less than 3 entities remaining
+                analysis.extinction_imminent = Some(SpeciesType::from_index(i));
+                break;
+            }
+        }
+
+        // Calculate warfare intensity
+        analysis.warfare_intensity = self.warfare_state.active_conflicts.len() as f32 * 0.2
+            + world.count::<ConsciousnessPredation>() as f32 * 0.1;
+        analysis.warfare_intensity = analysis.warfare_intensity.min(1.0);
+
+        // Calculate ecosystem stability
+        let consciousness_balance = 1.0 - (analysis.consciousness_distribution[0] - 0.33).abs()
+            - (analysis.consciousness_distribution[1] - 0.33).abs()
+            - (analysis.consciousness_distribution[2] - 0.33).abs();
+
+        analysis.ecosystem_stability = (consciousness_balance * 0.6 + (1.0 - analysis.warfare_intensity) * 0.4)
+            .clamp(0.0, 1.0);
+    }
+
+    fn process_species_warfare(&mut self, llamas: &mut [Llama], dt: f32, cosmic_time: f32, conflict_chance: f32, events: &mut EventBus) {
+        // Check for new territorial conflicts
+        for i in 0..llamas.len() {
+            for j in (i + 1)..llamas.len() {
+                let llama_a = &llamas[i];
+                let llama_b = &llamas[j];
+
+                // Different species can conflict
+                if self.diplomacy.may_fight(llama_a.species, llama_b.species, cosmic_time) {
+                    let distance = llama_a.position.distance(llama_b.position);
+                    let territorial_threshold = 100.0 - llama_a.social_attraction * 20.0;
+
+                    if distance < territorial_threshold &&
+                       llama_a.consciousness > 0.8 && llama_b.consciousness > 0.8 {
+
+                        // Check if conflict already exists
+                        let conflict_exists = self.warfare_state.active_conflicts.iter()
+                            .any(|c| (c.attacker_species == llama_a.species && c.defender_species == llama_b.species) ||
+                                     (c.attacker_species == llama_b.species && c.defender_species == llama_a.species));
+
+                        // The tuned chance per frame, up to twice that between old enemies
+                        let chance = conflict_chance * self.diplomacy.war_appetite(llama_a.species, llama_b.species);
+                        if !conflict_exists && fastrand::f32() < chance {
+                            let territory_center = (llama_a.position + llama_b.position) * 0.5;
+                            debug!(target: "warfare", "⚔️ {:?} vs {:?} conflict over {:?}",
+                                   llama_a.species, llama_b.species, territory_center);
+                            self.diplomacy.declare_war(llama_a.species, llama_b.species, territory_center);
+                            events.publish(ChaosEvent::ConflictStarted {
+                                attacker: llama_a.species,
+                                defender: llama_b.species,
+                                front: territory_center,
+                            });
+
+                            self.warfare_state.active_conflicts.push(SpeciesConflict {
+                                attacker_species: llama_a.species,
+                                defender_species: llama_b.species,
+                                conflict_intensity: 0.5 + fastrand::f32() * 0.5,
+                                territory_contested: territory_center,
+                                duration: 0.0,
+                                victory_threshold: 0.7 + fastrand::f32() * 0.3,
+                            });
+                        }
+                    }
+                }
+            }
+        }
+
+        // Update existing conflicts
+        self.warfare_state.active_conflicts.retain_mut(|conflict| {
+            conflict.duration += dt;
+
+            // Calculate species strength in the conflict area
+            let mut attacker_strength = 0.0f32;
+            let mut defender_strength = 0.0f32;
+            let conflict_radius = 150.0;
+
+            for llama in llamas.iter() {
+                let distance_to_conflict = llama.position.distance(conflict.territory_contested);
+                if distance_to_conflict < conflict_radius {
+                    let proximity_factor = 1.0 - (distance_to_conflict / conflict_radius);
+                    let contribution = llama.consciousness * proximity_factor;
+
+                    // Allies on the front fight alongside their side
+                    let (attacker, defender) = (conflict.attacker_species, conflict.defender_species);
+                    attacker_strength += contribution * self.diplomacy.support(llama.species, attacker, defender);
+                    defender_strength += contribution * self.diplomacy.support(llama.species, defender, attacker);
+                }
+            }
+
+            // Update conflict intensity based on relative strength
+            let strength_ratio = if defender_strength > 0.0 {
+                attacker_strength / defender_strength
+            } else {
+                attacker_strength + 1.0
+            };
+
+            conflict.conflict_intensity = (strength_ratio * 0.5 + conflict.conflict_intensity * 0.5)
+                .clamp(0.1, 2.0);
+
+            // Check for conflict resolution
+            let winner = if strength_ratio > conflict.victory_threshold {
+                Some((conflict.attacker_species, conflict.defender_species))
+            } else if (1.0 / strength_ratio) > conflict.victory_threshold {
+                Some((conflict.defender_species, conflict.attacker_species))
+            } else {
+                None
+            };
+
+            if let Some((victor, loser)) = winner {
+                debug!(target: "warfare", "🏆 {:?} defeated {:?} after {:.1}s", victor, loser, conflict.duration);
+                if self.resolved_fronts.len() < MAX_HIVE_EVENTS {
+                    self.resolved_fronts.push(conflict.territory_contested);
+                }
+                self.diplomacy.record_defeat(victor, loser, cosmic_time);
+                events.publish(conflict.ended(Some(victor)));
+                // Boost the winning species, weaken the losing species near the front
+                for llama in llamas.iter_mut() {
+                    let distance_to_conflict = llama.position.distance(conflict.territory_contested);
+                    if distance_to_conflict < conflict_radius {
+                        if llama.species == victor {
+                            llama.consciousness += 0.1;
+                            llama.territorial_dominance = (llama.territorial_dominance + 0.05).min(1.0);
+                            llama.warfare_participation = (llama.warfare_participation + 0.1).min(1.0);
+                        } else if self.diplomacy.support(llama.species, victor, loser) > 0.0 {
+                            // Allies who fought share the territory won
+                            llama.territorial_dominance = (llama.territorial_dominance + 0.05 * ALLY_SPOILS).min(1.0);
+                        } else if llama.species == loser {
+                            llama.consciousness = (llama.consciousness - 0.05).max(0.0);
+                            llama.extinction_pressure += 0.1;
+                            llama.warfare_participation = (llama.warfare_participation - 0.05).max(0.0);
+                        }
+                    }
+                }
+                return false; // End conflict
+            }
+
+            // Conflict continues if no clear winner and not too old
+            let continues = conflict.duration < 30.0; // Max 30 second conflicts
+            if !continues {
+                events.publish(conflict.ended(None));
+            }
+            continues
+        });
+
+        // Apply warfare effects to participating llamas
+        for llama in llamas.iter_mut() {
+            let mut in_warfare = false;
+            for conflict in &self.warfare_state.active_conflicts {
+                let distance_to_conflict = llama.position.distance(conflict.territory_contested);
+                if distance_to_conflict < 150.0 &&
+                   (llama.species == conflict.attacker_species || llama.species == conflict.defender_species) {
+                    in_warfare = true;
+                    llama.remember(conflict.territory_contested, MemoryKind::ConflictSite, conflict.conflict_intensity.min(1.0));
+                    llama.warfare_participation = (llama.warfare_participation + dt * 0.1).min(1.0);
+                    llama.feel(Feeling::WarfareFear, conflict.conflict_intensity.min(1.0) * dt * 0.5);
+                    break;
+                }
+            }
+
+            if !in_warfare {
+                llama.warfare_participation *= 0.95; // Decay warfare participation
+            }
+        }
+    }
+
+    fn process_evolution_pressure(&mut self, llamas: &mut [Llama], dt: f32) {
+        self.evolution_pressure_accumulator += dt;
+
+        // Apply evolution pressure every 5 seconds
+        if self.evolution_pressure_accumulator > 5.0 {
+            self.evolution_pressure_accumulator = 0.0;
+
+            // Calculate species fitness
+            let mut species_fitness = [0.0f32; SPECIES_COUNT];
+            let mut species_counts = [0u32; SPECIES_COUNT];
+
+            for llama in llamas.iter() {
+                if llama.consciousness > 0.1 {
+                    let species_idx = species_index(llama.species);
+                    species_fitness[species_idx] += llama.consciousness + llama.territorial_dominance - llama.extinction_pressure;
+                    species_counts[species_idx] += 1;
+                }
+            }
+
+            // Normalize fitness by population
+            for i in 0..SPECIES_COUNT {
+                if species_counts[i] > 0 {
+                    species_fitness[i] /= species_counts[i] as f32;
+                }
+            }
+
+            // Apply evolution pressure based on relative fitness
+            let max_fitness = species_fitness.iter().fold(0.0f32, |a, &b| a.max(b));
+            let min_fitness = species_fitness.iter().fold(f32::INFINITY, |a, &b| a.min(b));
+
+            if max_fitness > min_fitness {
+                for llama in llamas.iter_mut() {
+                    if llama.consciousness > 0.1 {
+                        let species_idx = species_index(llama.species);
+                        let relative_fitness = (species_fitness[species_idx] - min_fitness) / (max_fitness - min_fitness);
+
+                        if relative_fitness < 0.3 { // Low fitness species face extinction pressure
+                            llama.extinction_pressure += 0.2;
+                            llama.consciousness *= 0.95;
+                        } else if relative_fitness > 0.7 { // High fitness species thrive
+                            llama.extinction_pressure *= 0.8;
+                            llama.consciousness += 0.05;
+                            llama.territorial_dominance = (llama.territorial_dominance + 0.02).min(1.0);
+                        }
+                    }
+                }
+            }
+        }
+
+        // Handle extinction events
+        for llama in llamas.iter_mut() {
+            if llama.extinction_pressure > 0.8 {
+                llama.consciousness *= 0.9; // Accelerated consciousness loss
+                if llama.consciousness < 0.1 {
+                    llama.consciousness = 0.05; // Near extinction
+                    llama.extinction_pressure = 1.0;
+                }
+            }
+        }
+    }
+
+    fn process_meta_observer_interventions(&mut self, llamas: &mut [Llama], dt: f32, cosmic_time: f32, intervention_chance: f32, events: &mut EventBus) {
+        let observer = &mut self.meta_observer;
+        observer.last_intervention += dt;
+
+        // Observer analyzes the ecosystem and decides whether to intervene
+        let analysis = &observer.consciousness_analysis;
+
+        // Increase observation intensity based on warfare and instability
+        observer.observation_intensity = (analysis.warfare_intensity * 0.5 +
+                                        (1.0 - analysis.ecosystem_stability) * 0.5)
+                                        .clamp(0.1, 1.0);
+
+        // Decide on intervention
+        let should_intervene = observer.last_intervention > 10.0 && // At least 10 seconds between interventions
+                             (analysis.ecosystem_stability < 0.3 || // Very unstable
+                              analysis.extinction_imminent.is_some() || // Species about to go extinct
+                              analysis.warfare_intensity > 0.8); // Intense warfare
+
+        let extinction_imminent = analysis.extinction_imminent.is_some();
+
+        if observer.autonomous && should_intervene && fastrand::f32() < intervention_chance * observer.intervention_rate {
+            observer.last_intervention = 0.0;
+
+            // Left alone, the observer only blesses a species that is actually dying out
+            let intervention = ObserverIntervention::ALL[fastrand::u32(0..4) as usize];
+            if intervention != ObserverIntervention::Bless || extinction_imminent {
+                self.apply_intervention(llamas, intervention, events);
+            }
+        }
+
+        let observer = &mut self.meta_observer;
+        // Observer position slowly drifts to maintain omnipresence
+        observer.observer_position += Vec2::new(
+            (cosmic_time * 0.1).sin() * dt * 10.0,
+            (cosmic_time * 0.07).cos() * dt * 8.0
+        );
+
+        // Keep observer within screen bounds
+        observer.observer_position.x = observer.observer_position.x.clamp(100.0, 1100.0);
+        observer.observer_position.y = observer.observer_position.y.clamp(100.0, 700.0);
+    }
+
+    /// Collective hive actions since the last call
+    pub fn drain_hive_events(&mut self) -> std::vec::Drain<'_, HiveEvent> {
+        self.hive_events.drain(..)
+    }
+
+    /// Wars declared, treaties signed and alliances made or broken since the last call
+    pub fn drain_diplomacy_events(&mut self) -> std::vec::Drain<'_, DiplomacyEvent> {
+        self.diplomacy.drain_events()
+    }
+
+    /// Where conflicts ended with a victor since the last call
+    pub fn drain_resolved_fronts(&mut self) -> std::vec::Drain<'_, Vec2> {
+        self.resolved_fronts.drain(..)
+    }
+
+    /// Absorptions completed since the last call, as (predator, prey)
+    pub fn drain_absorptions(&mut self) -> std::vec::Drain<'_, (EntityId, EntityId)> {
+        self.absorptions.drain(..)
+    }
+
+    /// Hybrid species that emerged since the last call
+    pub fn drain_emergences(&mut self) -> std::vec::Drain<'_, SpeciesEmergence> {
+        self.emergences.drain(..)
+    }
+
+    /// Drop everything held by entity id, for when the world is replaced by one with other entities
+    pub fn forget_entities(&mut self) {
+        self.speciation.forget_hives();
+        self.hive_events.clear();
+        self.absorptions.clear();
+        self.emergences.clear();
+    }
+
+    /// Intervene now, whatever the observer would have decided
+    pub fn intervene(&mut self, world: &mut World, intervention: ObserverIntervention, events: &mut EventBus) {
+        info!(target: "warfare", "👁️ Meta-observer intervention: {}", intervention.name());
+        self.apply_intervention(world.components_mut::<Llama>(), intervention, events);
+        self.meta_observer.last_intervention = 0.0;
+    }
+
+    /// Boost one species back from the brink
+    pub fn bless_species(&mut self, world: &mut World, species: SpeciesType) {
+        bless(world.components_mut::<Llama>(), species);
+    }
+
+    /// End all conflicts and calm every llama
+    pub fn force_peace(&mut self, world: &mut World, events: &mut EventBus) {
+        self.apply_intervention(world.components_mut::<Llama>(), ObserverIntervention::ForcePeace, events);
+    }
+
+    /// Shuffle a share of the llamas to break territorial deadlocks
+    pub fn scramble(&mut self, world: &mut World, events: &mut EventBus) {
+        self.apply_intervention(world.components_mut::<Llama>(), ObserverIntervention::Scramble, events);
+    }
+
+    /// Even out consciousness between the species
+    pub fn redistribute(&mut self, world: &mut World, events: &mut EventBus) {
+        self.apply_intervention(world.components_mut::<Llama>(), ObserverIntervention::Redistribute, events);
+    }
+
+    pub fn autonomous_interventions(&self) -> bool {
+        self.meta_observer.autonomous
+    }
+
+    /// Turn the observer's own random interventions on or off
+    pub fn set_autonomous_interventions(&mut self, enabled: bool) {
+        self.meta_observer.autonomous = enabled;
+    }
+
+    /// Scale how readily the observer acts on its own (1.0 is the designed rate)
+    pub fn set_intervention_rate(&mut self, rate: f32) {
+        self.meta_observer.intervention_rate = rate.max(0.0);
+    }
+
+    /// The species a blessing targets: the one about to go extinct, else the smallest still alive
+    fn weakest_species(&self) -> SpeciesType {
+        self.meta_observer.consciousness_analysis.extinction_imminent.unwrap_or_else(|| {
+            let populations = self.warfare_state.species_populations;
+            SpeciesType::ALL
+                .into_iter()
+                .filter(|&species| populations[species_index(species)] > 0)
+                .min_by_key(|&species| populations[species_index(species)])
+                .unwrap_or(SpeciesType::DiscoLlama)
+        })
+    }
+
+    fn apply_intervention(&mut self, llamas: &mut [Llama], intervention: ObserverIntervention, events: &mut EventBus) {
+        events.publish(ChaosEvent::ObserverIntervention(intervention));
+        match intervention {
+            ObserverIntervention::Bless => bless(llamas, self.weakest_species()),
+            ObserverIntervention::ForcePeace => {
+                for conflict in self.warfare_state.active_conflicts.drain(..) {
+                    events.publish(conflict.ended(None));
+                }
+                for llama in llamas.iter_mut() {
+                    llama.warfare_participation *= 0.5;
+                    llama.feel(Feeling::Relief, 0.5);
+                }
+            }
+            ObserverIntervention::Scramble => {
+                for llama in llamas.iter_mut() {
+                    if fastrand::f32() < 0.3 {
+                        llama.position += Vec2::new(
+                            (fastrand::f32() - 0.5) * 200.0,
+                            (fastrand::f32() - 0.5) * 200.0
+                        );
+                        // Clamp to screen bounds
+                        llama.position.x = llama.position.x.clamp(50.0, 1150.0);
+                        llama.position.y = llama.position.y.clamp(50.0, 750.0);
+                    }
+                }
+            }
+            ObserverIntervention::Redistribute => {
+                let total_consciousness: f32 = llamas.iter()
+                    .filter(|l| l.consciousness > 0.1)
+                    .map(|l| l.consciousness).sum();
+
+                if total_consciousness > 0.0 {
+                    let target_per_species = total_consciousness / SPECIES_COUNT as f32;
+
+                    for species_type in SpeciesType::ALL {
+                        let species_llamas: Vec<&mut Llama> = llamas.iter_mut()
+                            .filter(|l| l.species == species_type && l.consciousness > 0.1)
+                            .collect();
+
+                        if !species_llamas.is_empty() {
+                            let current_total: f32 = species_llamas.iter()
+                                .map(|l| l.consciousness).sum();
+                            let adjustment = (target_per_species - current_total) / species_llamas.len() as f32;
+
+                            for llama in species_llamas {
+                                llama.consciousness = (llama.consciousness + adjustment * 0.5).max(0.5);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn update_warfare_state(&mut self, llamas: &[Llama]) {
+        // Update species populations
+        let mut populations = [0u32; SPECIES_COUNT];
+        for llama in llamas {
+            if llama.consciousness > 0.1 { // Only count living entities
+                populations[species_index(llama.species)] += 1;
+            }
+        }
+        self.warfare_state.species_populations = populations;
+
+        // Species extinction pressure is the average pressure on its living members
+        let mut pressure = [0.0f32; SPECIES_COUNT];
+        for llama in llamas {
+            if llama.consciousness > 0.1 {
+                pressure[species_index(llama.species)] += llama.extinction_pressure;
+            }
+        }
+        for (i, total) in pressure.into_iter().enumerate() {
+            self.warfare_state.extinction_pressure[i] = if populations[i] > 0 {
+                (total / populations[i] as f32).clamp(0.0, 1.0)
+            } else {
+                1.0 // Extinct
+            };
+        }
+
+        // Territory share per species from the llamas' individual dominance
+        let mut dominance = [0.0f32; SPECIES_COUNT];
+        for llama in llamas {
+            if llama.consciousness > 0.1 {
+                dominance[species_index(llama.species)] += llama.territorial_dominance;
+            }
+        }
+        let total_dominance: f32 = dominance.iter().sum();
+        self.warfare_state.territorial_dominance = if total_dominance > f32::EPSILON {
+            dominance.map(|share| share / total_dominance)
+        } else {
+            [1.0 / SPECIES_COUNT as f32; SPECIES_COUNT] // Nobody holds territory - balanced
+        };
+
+        // Update consciousness crystals controlled (placeholder - would need crystal system integration)
+        // This would be calculated based on territorial control near crystal formations
+        for i in 0..SPECIES_COUNT {
+            self.warfare_state.consciousness_crystals_controlled[i] =
+                (self.warfare_state.territorial_dominance[i] * 10.0) as u32;
+        }
+    }
+}
+
+/// Consciousness blessing: lift a species' living members and relieve their extinction pressure
+fn bless(llamas: &mut [Llama], species: SpeciesType) {
+    for llama in llamas.iter_mut() {
+        if llama.species == species && llama.consciousness > 0.1 {
+            llama.consciousness += 0.5;
+            llama.consciousness_level = ConsciousnessLevel::Pack; // Temporary boost
+            llama.extinction_pressure = 0.0;
+        }
+    }
+}
+
+impl Default for ConsciousnessMultiplicationSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Regroup llamas into packs and sync the hierarchy entities with the result
+fn process_consciousness_hierarchies(world: &mut World, diplomacy: &Diplomacy) {
+    let ids = world.entities_with::<Llama>().to_vec();
+    let llamas = world.components::<Llama>();
+
+    // Find entities that should form pack consciousness
+    let mut processed = vec![false; llamas.len()];
+    let mut hierarchies = Vec::new();
+
+    for i in 0..llamas.len() {
+        if processed[i] { continue; }
+
+        let mut pack_slots = vec![i];
+        let llama = &llamas[i];
+
+        // Find nearby llamas of the same or an allied species for pack formation
+        for j in (i + 1)..llamas.len() {
+            if processed[j] { continue; }
+
+            let other = &llamas[j];
+            if llama.species == other.species || diplomacy.allied(llama.species, other.species) {
+                let distance = llama.position.distance(other.position);
+                let pack_threshold = 80.0 + llama.social_attraction * 40.0 + llama.pack_reach_bonus(); // Social crystals widen it
+
+                if distance < pack_threshold && pack_slots.len() < MAX_PACK_SIZE {
+                    pack_slots.push(j);
+                    processed[j] = true;
+                }
+            }
+        }
+
+        processed[i] = true;
+
+        // Determine consciousness level based on pack size
+        let consciousness_level = if pack_slots.len() >= HIVE_FORMATION_SIZE {
+            ConsciousnessLevel::Hive
+        } else if pack_slots.len() >= 2 {
+            ConsciousnessLevel::Pack
+        } else {
+            ConsciousnessLevel::Individual
+        };
+
+        // Calculate collective strength
+        let collective_strength: f32 = pack_slots.iter()
+            .map(|&slot| llamas[slot].consciousness)
+            .sum();
+        let member_count = pack_slots.len() as f32;
+
+        hierarchies.push(ConsciousnessHierarchy {
+            level: consciousness_level,
+            leader: ids[i],
+            members: pack_slots.iter().map(|&slot| ids[slot]).collect(),
+            collective_strength,
+            territory_control: collective_strength / (member_count + 1.0),
+            war_efficiency: match consciousness_level {
+                ConsciousnessLevel::Individual => 1.0,
+                ConsciousnessLevel::Pack => 1.2 + (member_count - 2.0) * 0.1,
+                ConsciousnessLevel::Hive => 1.5 + (member_count - 9.0) * 0.05,
+                ConsciousnessLevel::Meta => 2.0,
+            },
+            hive_connection_strength: if consciousness_level == ConsciousnessLevel::Hive {
+                0.8 + fastrand::f32() * 0.2
+            } else {
+                0.0
+            },
+            absorption_capacity: collective_strength * 0.1,
+        });
+    }
+
+    // Reuse the entity of any hierarchy led by the same llama, despawn the rest
+    let mut previous: Vec<(EntityId, EntityId)> = world.query::<ConsciousnessHierarchy>()
+        .into_iter()
+        .map(|(entity, hierarchy)| (hierarchy.leader, entity))
+        .collect();
+
+    for hierarchy in hierarchies {
+        let entity = match previous.iter().position(|&(leader, _)| leader == hierarchy.leader) {
+            Some(index) => previous.swap_remove(index).1,
+            None => world.create_entity(),
+        };
+
+        // Update llama consciousness levels
+        let collective_id = if hierarchy.members.len() > 1 { Some(entity) } else { None };
+        for &member in &hierarchy.members {
+            if let Some(llama) = world.get_component_mut::<Llama>(member) {
+                llama.consciousness_level = hierarchy.level;
+                llama.collective_id = collective_id;
+            }
+        }
+
+        world.add_component(entity, hierarchy);
+    }
+
+    for (_, stale) in previous {
+        world.despawn(stale);
+    }
+}
+
+/// Awaken hive minds from hive-sized hierarchies and run the existing ones
+fn process_hive_mind_emergence(world: &mut World, dt: f32, cosmic_time: f32, connection_chance: f32, events: &mut EventBus) {
+    // Check for new hive mind formation
+    let new_hives: Vec<(Vec<EntityId>, f32)> = world.components::<ConsciousnessHierarchy>().iter()
+        .filter(|hierarchy| hierarchy.level == ConsciousnessLevel::Hive && hierarchy.members.len() >= HIVE_FORMATION_SIZE)
+        .filter(|hierarchy| !world.components::<HiveMind>().iter()
+            .any(|hive| hive.member_entities == hierarchy.members))
+        .map(|hierarchy| (hierarchy.members.clone(), hierarchy.collective_strength))
+        .collect();
+
+    for (members, collective_strength) in new_hives {
+        // Create connection network (each entity connected to 2-3 others)
+        let mut connection_network = Vec::new();
+        for (i, &member_a) in members.iter().enumerate() {
+            for &member_b in members.iter().skip(i + 1) {
+                if fastrand::f32() < connection_chance {
+                    connection_network.push((member_a, member_b));
+                }
+            }
+        }
+
+        // Collect shared memories from all members
+        let shared_memories: Vec<MemoryFragment> = members.iter()
+            .filter_map(|&id| world.get_component::<Llama>(id))
+            .flat_map(|llama| llama.memory_fragments.iter().copied())
+            .collect();
+
+        debug!(target: "warfare", "🧠 Hive mind awakened with {} members", members.len());
+        let species = members.first()
+            .and_then(|&id| world.get_component::<Llama>(id))
+            .map_or(SpeciesType::DiscoLlama, |llama| llama.species);
+        let member_count = members.len();
+        let hive = world.spawn(HiveMind {
+            hive_center: center_of(world, &members),
+            member_entities: members,
+            collective_consciousness: collective_strength,
+            connection_network,
+            shared_memories,
+            collective_decision_weight: 0.7 + fastrand::f32() * 0.3,
+            emergence_timestamp: cosmic_time,
+            pool: 0.0,
+        });
+        events.publish(ChaosEvent::HiveFormed { hive, species, members: member_count });
+    }
+
+    // Update existing hive minds
+    for hive_entity in world.entities_with::<HiveMind>().to_vec() {
+        let Some(hive) = world.get_component::<HiveMind>(hive_entity) else { continue };
+
+        // Members that were despawned simply drop out of the hive
+        let valid_members: Vec<EntityId> = hive.member_entities.iter()
+            .copied()
+            .filter(|&id| world.get_component::<Llama>(id).is_some())
+            .collect();
+
+        if valid_members.len() < HIVE_DISSOLVE_SIZE { // Hive dissolves if too few members
+            // Mark former hive members as individuals
+            for &member in &valid_members {
+                if let Some(llama) = world.get_component_mut::<Llama>(member) {
+                    llama.consciousness_level = ConsciousnessLevel::Individual;
+                    llama.collective_id = None;
+                    llama.hive_connection_strength = 0.0;
+                }
+            }
+            debug!(target: "warfare", "🧠 Hive mind dissolved ({} members left)", valid_members.len());
+            world.despawn(hive_entity);
+            events.publish(ChaosEvent::HiveDissolved { hive: hive_entity, members_left: valid_members.len() });
+            continue;
+        }
+
+        let decision_weight = hive.collective_decision_weight;
+        let shared_memories = hive.shared_memories.clone();
+
+        // Apply hive mind effects to members
+        for &member in &valid_members {
+            if let Some(llama) = world.get_component_mut::<Llama>(member) {
+                // Enhanced consciousness from collective
+                llama.consciousness += decision_weight * dt * 0.1;
+                llama.consciousness = llama.consciousness.min(2.0);
+
+                // Hive connection strength affects behavior
+                llama.hive_connection_strength = decision_weight;
+                llama.feel(Feeling::HiveBelonging, decision_weight.min(1.0) * dt * 0.2);
+
+                // Collective memory sharing - occasionally pass on a garbled copy of a shared memory
+                if fastrand::f32() < 0.01 && !shared_memories.is_empty() {
+                    let shared = shared_memories[fastrand::usize(0..shared_memories.len())].mutated();
+                    llama.remember(shared.position, shared.kind, shared.strength);
+                }
+            }
+        }
+
+        let hive_center = center_of(world, &valid_members);
+        let collective_consciousness = valid_members.iter()
+            .filter_map(|&id| world.get_component::<Llama>(id))
+            .map(|llama| llama.consciousness)
+            .sum();
+
+        // The collective remembers what its members still remember clearly
+        let shared_memories: Vec<MemoryFragment> = valid_members.iter()
+            .filter_map(|&id| world.get_component::<Llama>(id))
+            .flat_map(|llama| llama.memory_fragments.iter().copied())
+            .filter(|memory| memory.strength > SHARED_MEMORY_THRESHOLD)
+            .collect();
+
+        if let Some(hive) = world.get_component_mut::<HiveMind>(hive_entity) {
+            hive.shared_memories = shared_memories;
+            hive.connection_network.retain(|(a, b)| valid_members.contains(a) && valid_members.contains(b));
+            hive.member_entities = valid_members;
+            hive.hive_center = hive_center;
+            hive.collective_consciousness = collective_consciousness;
+        }
+    }
+}
+
+/// Start new absorptions between nearby llamas that can see each other and advance
+/// the active ones; returns the (predator, prey) pairs whose absorption completed
+fn process_consciousness_predation(world: &mut World, obstacles: &Obstacles, chance: f32, dt: f32) -> Vec<(EntityId, EntityId)> {
+    // Check for new predation events
+    let ids = world.entities_with::<Llama>();
+    let llamas = world.components::<Llama>();
+    let active = world.components::<ConsciousnessPredation>();
+    let mut new_predations: Vec<ConsciousnessPredation> = Vec::new();
+
+    for (i, predator) in llamas.iter().enumerate() {
+        if predator.consciousness <= 1.5 { continue; } // High consciousness can predator others
+
+        for (j, prey) in llamas.iter().enumerate() {
+            if i == j { continue; }
+
+            let distance = predator.position.distance(prey.position);
+            if distance < 30.0 && predator.consciousness > prey.consciousness * 1.5
+                && obstacles.line_of_sight(predator.position, prey.position) {
+                // Check if predation already exists
+                let (a, b) = (ids[i], ids[j]);
+                let predation_exists = active.iter().chain(new_predations.iter())
+                    .any(|p| (p.predator_id == a && p.prey_id == b) ||
+                             (p.predator_id == b && p.prey_id == a));
+
+                if !predation_exists && fastrand::f32() < chance {
+                    new_predations.push(ConsciousnessPredation {
+                        predator_id: a,
+                        prey_id: b,
+                        absorption_progress: 0.0,
+                        resistance_strength: prey.consciousness * 0.5,
+                        visual_effect_intensity: 1.0,
+                    });
+                }
+            }
+        }
+    }
+
+    for predation in new_predations {
+        debug!(target: "warfare", "🌀 Llama {} began absorbing llama {}", predation.predator_id, predation.prey_id);
+        let predator_position = match world.get_component_mut::<Llama>(predation.predator_id) {
+            Some(predator) => {
+                predator.predation_target = Some(predation.prey_id);
+                predator.position
+            }
+            None => continue,
+        };
+        if let Some(prey) = world.get_component_mut::<Llama>(predation.prey_id) {
+            prey.remember(predator_position, MemoryKind::PredatorEncounter, 1.0);
+        }
+        world.spawn(predation);
+    }
+
+    // Update active predations
+    let mut completed = Vec::new();
+    for predation_entity in world.entities_with::<ConsciousnessPredation>().to_vec() {
+        let Some(mut predation) = world.get_component::<ConsciousnessPredation>(predation_entity).cloned() else { continue };
+        let (predator_id, prey_id) = (predation.predator_id, predation.prey_id);
+
+        // Predations end as soon as either side has been despawned
+        let keep = match world.get_pair_mut::<Llama>(predator_id, prey_id) {
+            Some((predator, prey)) => advance_predation(&mut predation, predator, prey, dt),
+            None => false,
+        };
+
+        if keep {
+            if let Some(stored) = world.get_component_mut::<ConsciousnessPredation>(predation_entity) {
+                *stored = predation;
+            }
+        } else {
+            if predation.absorption_progress >= 1.0 {
+                completed.push((predator_id, prey_id));
+            }
+            if let Some(predator) = world.get_component_mut::<Llama>(predator_id) {
+                if predator.predation_target == Some(prey_id) {
+                    predator.predation_target = None;
+                }
+            }
+            world.despawn(predation_entity);
+        }
+    }
+    completed
+}
+
+/// One step of consciousness absorption; returns false once the predation is over
+fn advance_predation(predation: &mut ConsciousnessPredation, predator: &mut Llama, prey: &mut Llama, dt: f32) -> bool {
+    let predator_consciousness = predator.consciousness;
+    let prey_consciousness = prey.consciousness;
+
+    // Check if predation should continue
+    let distance = predator.position.distance(prey.position);
+    if distance > 50.0 || prey_consciousness < 0.1 {
+        return false; // End predation
+    }
+
+    // Progress absorption
+    let absorption_rate = (predator_consciousness - predation.resistance_strength) * dt * 0.1;
+    predation.absorption_progress += absorption_rate.max(0.0);
+
+    // Apply resistance degradation
+    predation.resistance_strength *= 0.99; // Resistance weakens over time
+    predation.visual_effect_intensity *= 0.98; // Effect fades
+
+    // Transfer consciousness
+    if predation.absorption_progress > 0.0 {
+        let transfer_amount = absorption_rate * 0.5;
+        predator.consciousness += transfer_amount;
+        prey.consciousness -= transfer_amount;
+        prey.consciousness = prey.consciousness.max(0.1);
+    }
+
+    // Complete absorption if progress reaches 1.0
+    if predation.absorption_progress >= 1.0 {
+        predator.consciousness += prey_consciousness * 0.8;
+        prey.consciousness = 0.05; // Nearly extinct
+        prey.extinction_pressure = 1.0;
+        return false; // Complete predation
+    }
+
+    true // Continue predation
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::ChaosTuning;
+
+    fn pack_of(world: &mut World, count: usize, origin: Vec2) -> Vec<EntityId> {
+        (0..count)
+            .map(|i| world.spawn(Llama::new_with_species(origin + Vec2::new(i as f32 * 5.0, 0.0), SpeciesType::DiscoLlama)))
+            .collect()
+    }
+
+    #[test]
+    fn test_hierarchy_entity_survives_member_despawn() {
+        let mut world = World::new();
+        let members = pack_of(&mut world, 4, Vec2::new(100.0, 100.0));
+
+        process_consciousness_hierarchies(&mut world, &Diplomacy::new());
+        let collective = world.get_component::<Llama>(members[0]).unwrap().collective_id;
+        assert!(collective.is_some());
+
+        world.despawn(members[2]);
+        process_consciousness_hierarchies(&mut world, &Diplomacy::new());
+
+        let hierarchy = world.get_component::<ConsciousnessHierarchy>(collective.unwrap()).unwrap();
+        assert_eq!(hierarchy.members, vec![members[0], members[1], members[3]]);
+        assert_eq!(world.get_component::<Llama>(members[3]).unwrap().collective_id, collective);
+    }
+
+    #[test]
+    fn test_hive_drops_despawned_members_and_dissolves() {
+        let mut world = World::new();
+        let members = pack_of(&mut world, 6, Vec2::new(300.0, 300.0));
+        let hive = world.spawn(HiveMind {
+            member_entities: members.clone(),
+            collective_consciousness: 0.0,
+            hive_center: Vec2::ZERO,
+            connection_network: vec![(members[0], members[5])],
+            shared_memories: Vec::new(),
+            collective_decision_weight: 0.8,
+            emergence_timestamp: 0.0,
+            pool: 0.0,
+        });
+
+        let mut events = EventBus::default();
+        world.despawn(members[5]);
+        process_hive_mind_emergence(&mut world, 1.0 / 60.0, 0.0, ChaosTuning::default().hive_connection, &mut events);
+        let state = world.get_component::<HiveMind>(hive).unwrap();
+        assert_eq!(state.member_entities, members[..5].to_vec());
+        assert!(state.connection_network.is_empty());
+
+        world.despawn(members[4]);
+        process_hive_mind_emergence(&mut world, 1.0 / 60.0, 0.0, ChaosTuning::default().hive_connection, &mut events);
+        assert!(!world.is_alive(hive));
+        assert_eq!(events.consume_events().collect::<Vec<_>>(), vec![ChaosEvent::HiveDissolved { hive, members_left: 4 }]);
+        assert_eq!(world.get_component::<Llama>(members[0]).unwrap().consciousness_level, ConsciousnessLevel::Individual);
+    }
+
+    #[test]
+    fn test_predation_ends_when_prey_is_despawned() {
+        let mut world = World::new();
+        let pair = pack_of(&mut world, 2, Vec2::new(500.0, 500.0));
+        world.get_component_mut::<Llama>(pair[0]).unwrap().predation_target = Some(pair[1]);
+        let predation = world.spawn(ConsciousnessPredation {
+            predator_id: pair[0],
+            prey_id: pair[1],
+            absorption_progress: 0.0,
+            resistance_strength: 0.0,
+            visual_effect_intensity: 1.0,
+        });
+
+        world.despawn(pair[1]);
+        process_consciousness_predation(&mut world, &Obstacles::default(), ChaosTuning::default().predation_chance, 1.0 / 60.0);
+        assert!(!world.is_alive(predation));
+        assert_eq!(world.get_component::<Llama>(pair[0]).unwrap().predation_target, None);
+    }
+
+    #[test]
+    fn test_manual_interventions_and_autonomy_toggle() {
+        let mut world = World::new();
+        pack_of(&mut world, 3, Vec2::new(200.0, 200.0));
+        let camel = world.spawn(Llama::new_with_species(Vec2::new(600.0, 400.0), SpeciesType::HypnoCamel));
+        let mut system = ConsciousnessMultiplicationSystem::new();
+        let mut events = EventBus::default();
+        system.update_warfare_state(world.components::<Llama>());
+
+        // Blessing finds the smallest species when none is flagged as dying out
+        let before = world.get_component::<Llama>(camel).unwrap().consciousness;
+        system.intervene(&mut world, ObserverIntervention::Bless, &mut events);
+        assert!(world.get_component::<Llama>(camel).unwrap().consciousness > before);
+
+        system.warfare_state.active_conflicts.push(SpeciesConflict {
+            attacker_species: SpeciesType::DiscoLlama,
+            defender_species: SpeciesType::HypnoCamel,
+            conflict_intensity: 1.0,
+            territory_contested: Vec2::ZERO,
+            duration: 0.0,
+            victory_threshold: 0.5,
+        });
+        system.force_peace(&mut world, &mut events);
+        assert!(system.warfare_state.active_conflicts.is_empty());
+        assert!(events.peek_events().any(|event| matches!(event, ChaosEvent::ConflictEnded { victor: None, .. })));
+
+        // With autonomy off the observer never acts, however dire things look
+        system.set_autonomous_interventions(false);
+        system.meta_observer.consciousness_analysis.warfare_intensity = 1.0;
+        for _ in 0..500 {
+            system.process_meta_observer_interventions(world.components_mut::<Llama>(), 1.0, 0.0, ChaosTuning::default().intervention_chance, &mut events);
+        }
+        assert!(system.meta_observer.last_intervention >= 500.0);
+    }
+}
@@ -0,0 +1,245 @@
+// === SPECIES DIPLOMACY ===
+// Species remember how their wars went. Every war declared and every defeat
+// sours the standing between two species into a grudge, which makes the next
+// war more likely; a common enemy warms a third species towards the victim
+// until the two become allies. Allies never fight each other, lend strength
+// to each other's fronts and share the spoils of victory. When two species
+// have both bled against each other they sign a treaty that keeps them out of
+// war for a while. Standings fade back towards neutral over time.
+
+use glam::Vec2;
+use tracing::info;
+use crate::entities::{SpeciesType, SPECIES_COUNT};
+use super::consciousness_multiplication::species_index;
+
+/// Standing lost by both sides when a war is declared
+const WAR_GRUDGE: f32 = 0.1;
+/// Standing the loser loses towards the victor on a defeat
+const DEFEAT_GRUDGE: f32 = 0.2;
+/// Standing a third species gains towards the victim of someone it resents
+const COMMON_ENEMY_WARMTH: f32 = 0.25;
+/// Standing under which a species counts the other as an enemy
+const ENEMY_STANDING: f32 = -0.3;
+/// Standing at which two species ally, and under which the alliance lapses
+const ALLIANCE_STANDING: f32 = 0.6;
+const ALLIANCE_LAPSE: f32 = 0.3;
+/// Defeats each side must have suffered against the other before a treaty
+const TREATY_LOSSES: u32 = 2;
+/// Seconds a treaty keeps two species out of war
+pub const TREATY_DURATION: f32 = 60.0;
+/// Standing both sides hold right after signing a treaty
+const TREATY_STANDING: f32 = 0.2;
+/// Standing drift back towards neutral, per second
+const STANDING_FADE: f32 = 0.005;
+/// Weight of an ally's llamas on a front compared to the warring species' own
+pub const ALLY_SUPPORT: f32 = 0.5;
+/// Share of the victor's territory gain that its allies on the front receive
+pub const ALLY_SPOILS: f32 = 0.5;
+/// Diplomacy events kept for the app to drain; older ones are dropped
+const MAX_DIPLOMACY_EVENTS: usize = 64;
+
+/// A turn in the relations between two species
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DiplomacyEvent {
+    WarDeclared { attacker: SpeciesType, defender: SpeciesType, front: Vec2 },
+    TreatySigned { species: [SpeciesType; 2], until: f32 }, // Cosmic time the treaty expires
+    AllianceFormed { species: [SpeciesType; 2] },
+    AllianceBroken { species: [SpeciesType; 2] },
+}
+
+/// Standings, defeats, treaties and alliances between every pair of species
+#[derive(Debug, Clone)]
+pub struct Diplomacy {
+    standing: [[f32; SPECIES_COUNT]; SPECIES_COUNT], // Symmetric: -1.0 sworn enemies to 1.0 close allies
+    defeats: [[u32; SPECIES_COUNT]; SPECIES_COUNT], // defeats[loser][victor] since the last treaty
+    treaty_until: [[f32; SPECIES_COUNT]; SPECIES_COUNT],
+    allied: [[bool; SPECIES_COUNT]; SPECIES_COUNT],
+    events: Vec<DiplomacyEvent>,
+}
+
+impl Default for Diplomacy {
+    fn default() -> Self {
+        Self {
+            standing: [[0.0; SPECIES_COUNT]; SPECIES_COUNT],
+            defeats: [[0; SPECIES_COUNT]; SPECIES_COUNT],
+            treaty_until: [[f32::NEG_INFINITY; SPECIES_COUNT]; SPECIES_COUNT],
+            allied: [[false; SPECIES_COUNT]; SPECIES_COUNT],
+            events: Vec::new(),
+        }
+    }
+}
+
+impl Diplomacy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// -1.0 for sworn enemies up to 1.0 for close allies
+    pub fn standing(&self, a: SpeciesType, b: SpeciesType) -> f32 {
+        self.standing[species_index(a)][species_index(b)]
+    }
+
+    /// How strongly the two species resent each other, 0.0-1.0
+    pub fn grudge(&self, a: SpeciesType, b: SpeciesType) -> f32 {
+        (-self.standing(a, b)).max(0.0)
+    }
+
+    pub fn allied(&self, a: SpeciesType, b: SpeciesType) -> bool {
+        a != b && self.allied[species_index(a)][species_index(b)]
+    }
+
+    /// True while a treaty between the two species holds
+    pub fn at_peace(&self, a: SpeciesType, b: SpeciesType, time: f32) -> bool {
+        time < self.treaty_until[species_index(a)][species_index(b)]
+    }
+
+    /// Whether the two species may go to war at all
+    pub fn may_fight(&self, a: SpeciesType, b: SpeciesType, time: f32) -> bool {
+        a != b && !self.allied(a, b) && !self.at_peace(a, b, time)
+    }
+
+    /// Chance multiplier for a new war: old grudges make it up to twice as likely
+    pub fn war_appetite(&self, a: SpeciesType, b: SpeciesType) -> f32 {
+        1.0 + self.grudge(a, b)
+    }
+
+    /// Weight of a llama of `species` on a front where `side` fights `enemy`
+    pub fn support(&self, species: SpeciesType, side: SpeciesType, enemy: SpeciesType) -> f32 {
+        if species == side {
+            1.0
+        } else if species != enemy && self.allied(species, side) && !self.allied(species, enemy) {
+            ALLY_SUPPORT
+        } else {
+            0.0
+        }
+    }
+
+    /// Record a new war; species that resent the attacker warm towards the defender
+    pub fn declare_war(&mut self, attacker: SpeciesType, defender: SpeciesType, front: Vec2) {
+        info!(target: "warfare", "⚔️ {:?} declared war on {:?}", attacker, defender);
+        self.shift(attacker, defender, -WAR_GRUDGE);
+        self.push(DiplomacyEvent::WarDeclared { attacker, defender, front });
+
+        for third in SpeciesType::ALL {
+            if third != attacker && third != defender && self.standing(third, attacker) < ENEMY_STANDING {
+                self.shift(third, defender, COMMON_ENEMY_WARMTH);
+            }
+        }
+    }
+
+    /// Record a decided war; mutual losses bring the two sides to a treaty
+    pub fn record_defeat(&mut self, victor: SpeciesType, loser: SpeciesType, time: f32) {
+        self.shift(victor, loser, -DEFEAT_GRUDGE);
+        let (v, l) = (species_index(victor), species_index(loser));
+        self.defeats[l][v] += 1;
+        if self.defeats[l][v] >= TREATY_LOSSES && self.defeats[v][l] >= TREATY_LOSSES {
+            self.sign_treaty(victor, loser, time);
+        }
+    }
+
+    /// End hostilities between two species for `TREATY_DURATION` and forgive old grudges
+    pub fn sign_treaty(&mut self, a: SpeciesType, b: SpeciesType, time: f32) {
+        if a == b || self.at_peace(a, b, time) {
+            return;
+        }
+        let (i, j) = (species_index(a), species_index(b));
+        let until = time + TREATY_DURATION;
+        self.treaty_until[i][j] = until;
+        self.treaty_until[j][i] = until;
+        self.defeats[i][j] = 0;
+        self.defeats[j][i] = 0;
+        let standing = self.standing[i][j].max(TREATY_STANDING);
+        self.shift(a, b, standing - self.standing[i][j]);
+        info!(target: "warfare", "🕊️ {:?} and {:?} signed a treaty", a, b);
+        self.push(DiplomacyEvent::TreatySigned { species: [a, b], until });
+    }
+
+    /// Let standings drift back towards neutral
+    pub fn update(&mut self, dt: f32) {
+        for a in SpeciesType::ALL {
+            for b in SpeciesType::ALL {
+                let (i, j) = (species_index(a), species_index(b));
+                if i < j {
+                    let standing = self.standing[i][j];
+                    let faded = standing - standing.signum() * (STANDING_FADE * dt).min(standing.abs());
+                    self.shift(a, b, faded - standing);
+                }
+            }
+        }
+    }
+
+    /// Diplomacy events since the last call
+    pub fn drain_events(&mut self) -> std::vec::Drain<'_, DiplomacyEvent> {
+        self.events.drain(..)
+    }
+
+    /// Change the standing of a pair on both sides, then form or break their alliance
+    fn shift(&mut self, a: SpeciesType, b: SpeciesType, delta: f32) {
+        if a == b {
+            return;
+        }
+        let (i, j) = (species_index(a), species_index(b));
+        let standing = (self.standing[i][j] + delta).clamp(-1.0, 1.0);
+        self.standing[i][j] = standing;
+        self.standing[j][i] = standing;
+
+        if !self.allied[i][j] && standing >= ALLIANCE_STANDING {
+            self.allied[i][j] = true;
+            self.allied[j][i] = true;
+            info!(target: "warfare", "🤝 {:?} and {:?} formed an alliance", a, b);
+            self.push(DiplomacyEvent::AllianceFormed { species: [a, b] });
+        } else if self.allied[i][j] && standing < ALLIANCE_LAPSE {
+            self.allied[i][j] = false;
+            self.allied[j][i] = false;
+            self.push(DiplomacyEvent::AllianceBroken { species: [a, b] });
+        }
+    }
+
+    fn push(&mut self, event: DiplomacyEvent) {
+        if self.events.len() >= MAX_DIPLOMACY_EVENTS {
+            self.events.remove(0);
+        }
+        self.events.push(event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_common_enemy_alliance_and_mutual_loss_treaty() {
+        let (disco, sheep, camel) = (SpeciesType::DiscoLlama, SpeciesType::QuantumSheep, SpeciesType::HypnoCamel);
+        let mut diplomacy = Diplomacy::new();
+
+        // Disco keeps beating the camels, who come to hate it
+        for _ in 0..3 {
+            diplomacy.declare_war(disco, camel, Vec2::ZERO);
+            diplomacy.record_defeat(disco, camel, 0.0);
+        }
+        assert!(diplomacy.grudge(disco, camel) > 0.5);
+        assert!(diplomacy.war_appetite(disco, camel) > 1.5);
+
+        // Each attack on the sheep pushes the camels and sheep together
+        for _ in 0..3 {
+            diplomacy.declare_war(disco, sheep, Vec2::ZERO);
+        }
+        assert!(diplomacy.allied(camel, sheep));
+        assert!(!diplomacy.may_fight(camel, sheep, 0.0));
+        assert_eq!(diplomacy.support(camel, sheep, disco), ALLY_SUPPORT);
+        assert_eq!(diplomacy.support(camel, disco, sheep), 0.0);
+
+        // The sheep have won twice as well: both sides have bled, so they sign
+        diplomacy.record_defeat(sheep, disco, 5.0);
+        diplomacy.record_defeat(sheep, disco, 6.0);
+        diplomacy.record_defeat(disco, sheep, 7.0);
+        assert!(!diplomacy.at_peace(disco, sheep, 7.0));
+        diplomacy.record_defeat(disco, sheep, 8.0);
+        assert!(diplomacy.at_peace(disco, sheep, 8.0 + TREATY_DURATION - 1.0));
+        assert!(diplomacy.may_fight(disco, sheep, 8.0 + TREATY_DURATION));
+
+        let events: Vec<_> = diplomacy.drain_events().collect();
+        assert!(events.contains(&DiplomacyEvent::AllianceFormed { species: [camel, sheep] }));
+        assert!(events.contains(&DiplomacyEvent::TreatySigned { species: [disco, sheep], until: 8.0 + TREATY_DURATION }));
+    }
+}
@@ -3,6 +3,8 @@
 
 use glam::Vec2;
 use std::collections::{VecDeque, HashMap};
+use super::hive_behavior::{HiveEvent, NegotiationOutcome};
+use crate::simulation::ZoneEvent;
 
 // === EVENT-DRIVEN ARCHITECTURE ===
 /// Synchronized event system for cascading effects
@@ -49,6 +51,7 @@ pub enum EventType {
     EnvironmentalShift,
     EmergenceThreshold,
     CulturalTransmission,
+    HiveCollective,  // A hive hunted, pulsed, sacrificed or negotiated as one
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -583,6 +586,78 @@ impl EventDrivenArchitecture {
         }
     }
 
+    /// Put a hive's collective action on the bus for the visual, audio and ecosystem systems
+    pub fn publish_hive_event(&mut self, event: &HiveEvent, cosmic_time: f64) {
+        let (kind, hives, strength, position) = match *event {
+            HiveEvent::HuntStarted { hive, .. } => ("hunt", vec![hive], 0.4, None),
+            HiveEvent::Pulse { hive, strength, .. } => ("pulse", vec![hive], strength * 0.8, None),
+            HiveEvent::Sacrifice { hive, position, .. } => ("sacrifice", vec![hive], 0.6, Some(position)),
+            HiveEvent::Negotiation { hives, outcome, .. } => {
+                let weight = match outcome {
+                    NegotiationOutcome::Truce => 0.3,
+                    NegotiationOutcome::Tribute { amount, .. } => amount.clamp(0.0, 0.8),
+                };
+                ("negotiation", hives.to_vec(), weight, None)
+            }
+        };
+
+        let hive_event = SystemEvent {
+            event_id: format!("hive_{}_{}_{}", kind, hives[0], (cosmic_time * 1000.0) as u64),
+            event_type: EventType::HiveCollective,
+            source_system: SystemComponent::ConsciousnessFramework,
+            target_systems: vec![
+                SystemComponent::VisualizationEngine,
+                SystemComponent::AudioProcessor,
+                SystemComponent::DigitalEcosystem,
+            ],
+            event_data: EventData {
+                primary_value: strength, // Kept under the intensity-spike filter
+                secondary_values: HashMap::new(),
+                vector_data: HashMap::new(),
+                entity_references: hives.iter().map(|&hive| hive as usize).collect(),
+                spatial_information: position,
+                temporal_information: None,
+            },
+            timestamp: cosmic_time,
+            priority: 0.6,
+            cascade_potential: 0.3,
+            synchronization_requirements: Vec::new(),
+        };
+        self.event_bus.pending_events.push_back(hive_event);
+    }
+
+    /// Announce an emergent territory zone appearing, merging or fading
+    pub fn publish_zone_event(&mut self, event: &ZoneEvent, cosmic_time: f64) {
+        let (kind, center, strength) = match *event {
+            ZoneEvent::Emerged { center, .. } => ("emerged", center, 0.5),
+            ZoneEvent::Merged { center, .. } => ("merged", center, 0.3),
+            ZoneEvent::Faded { center, .. } => ("faded", center, 0.2),
+        };
+
+        let zone_event = SystemEvent {
+            event_id: format!("zone_{}_{}", kind, (cosmic_time * 1000.0) as u64),
+            event_type: EventType::EnvironmentalShift,
+            source_system: SystemComponent::DigitalEcosystem,
+            target_systems: vec![
+                SystemComponent::VisualizationEngine,
+                SystemComponent::AudioProcessor,
+            ],
+            event_data: EventData {
+                primary_value: strength,
+                secondary_values: HashMap::new(),
+                vector_data: HashMap::new(),
+                entity_references: Vec::new(),
+                spatial_information: Some(center),
+                temporal_information: None,
+            },
+            timestamp: cosmic_time,
+            priority: 0.4,
+            cascade_potential: 0.2,
+            synchronization_requirements: Vec::new(),
+        };
+        self.event_bus.pending_events.push_back(zone_event);
+    }
+
     pub fn trigger_beat_cascade(&mut self, beat_intensity: f32, cosmic_time: f64) {
         // Manually trigger a beat drop cascade for synchronized effects
         let cascade_event = SystemEvent {
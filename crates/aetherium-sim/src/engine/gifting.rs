@@ -0,0 +1,185 @@
+// === CONSCIOUSNESS GIFTING ===
+// The cooperative counterpart to predation. A llama with consciousness to
+// spare now and then gives part of its surplus to a struggling llama of its
+// own species, or of an allied one, that it can see nearby; how often and how
+// much depends on its generosity. Hive members with nobody in need around pay
+// into their hive's pool instead, and the hive tops up any member that falls
+// low wherever it is. Every gift ties the two llamas with a social bond.
+
+use glam::Vec2;
+use crate::core::ecs::{EntityId, World};
+use crate::entities::{Llama, SpeciesType};
+use crate::simulation::Obstacles;
+use super::consciousness_multiplication::HiveMind;
+use super::diplomacy::Diplomacy;
+
+/// Consciousness a llama keeps for itself; only what lies above it is given away
+const GIFT_SURPLUS: f32 = 1.0;
+/// Llamas below this consciousness are struggling and receive gifts
+pub const STRUGGLING_CONSCIOUSNESS: f32 = 0.4;
+/// Farthest a gift is passed, in world units
+const GIFT_RANGE: f32 = 60.0;
+/// Gifts per second a fully generous llama with a surplus makes
+const GIFT_RATE: f32 = 0.5;
+/// Share of the surplus a fully generous llama gives at once
+const GIFT_SHARE: f32 = 0.5;
+/// Most consciousness a hive pool holds
+const MAX_HIVE_POOL: f32 = 5.0;
+/// Social bonds a llama keeps, as in its own behavior
+const MAX_BONDS: usize = 5;
+
+/// One gift of consciousness
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Gift {
+    pub donor: EntityId,             // A llama, or the hive entity for payouts from a pool
+    pub recipient: Option<EntityId>, // None when paid into the donor's hive pool
+    pub species: SpeciesType,
+    pub position: Vec2,
+    pub amount: f32,
+}
+
+/// Give surplus consciousness to struggling kin and allies in sight, or into hive pools,
+/// and pay struggling hive members out of their pool
+pub fn process_gifting(world: &mut World, diplomacy: &Diplomacy, obstacles: &Obstacles, dt: f32) -> Vec<Gift> {
+    let mut gifts = pay_out_hive_pools(world);
+
+    let ids = world.entities_with::<Llama>().to_vec();
+    let llamas = world.components::<Llama>();
+    let mut planned: Vec<(usize, Option<usize>, f32)> = Vec::new();
+    for (i, donor) in llamas.iter().enumerate() {
+        let surplus = donor.consciousness - GIFT_SURPLUS;
+        if surplus <= 0.0 || donor.predation_target.is_some() || fastrand::f32() >= donor.generosity * GIFT_RATE * dt {
+            continue;
+        }
+        let kin = |other: &Llama| other.species == donor.species || diplomacy.allied(donor.species, other.species);
+        let neediest = llamas.iter().enumerate()
+            .filter(|&(j, other)| j != i && other.consciousness < STRUGGLING_CONSCIOUSNESS && kin(other))
+            .filter(|(_, other)| other.position.distance(donor.position) < GIFT_RANGE && obstacles.line_of_sight(donor.position, other.position))
+            .min_by(|a, b| a.1.consciousness.total_cmp(&b.1.consciousness))
+            .map(|(j, _)| j);
+        let amount = surplus * GIFT_SHARE * donor.generosity;
+        if neediest.is_some() || donor.collective_id.is_some() {
+            planned.push((i, neediest, amount));
+        }
+    }
+
+    for (donor_index, recipient_index, amount) in planned {
+        let (donor_id, recipient_id) = (ids[donor_index], recipient_index.map(|j| ids[j]));
+        let Some(donor) = world.get_component::<Llama>(donor_id) else { continue };
+        let (species, position) = (donor.species, donor.position);
+        let given = match recipient_id {
+            Some(recipient_id) => {
+                let Some((donor, recipient)) = world.get_pair_mut::<Llama>(donor_id, recipient_id) else { continue };
+                // Enough to lift the recipient out of trouble, never more than offered
+                let given = amount.min(STRUGGLING_CONSCIOUSNESS * 2.0 - recipient.consciousness).max(0.0);
+                donor.consciousness -= given;
+                recipient.consciousness += given;
+                bond(donor, recipient_id);
+                bond(recipient, donor_id);
+                given
+            }
+            None => {
+                let Some(hive) = hive_of(world, donor_id) else { continue };
+                let Some(pool) = world.get_component_mut::<HiveMind>(hive).map(|hive| &mut hive.pool) else { continue };
+                let given = amount.min(MAX_HIVE_POOL - *pool).max(0.0);
+                *pool += given;
+                if let Some(donor) = world.get_component_mut::<Llama>(donor_id) {
+                    donor.consciousness -= given;
+                }
+                given
+            }
+        };
+        if given > 0.0 {
+            gifts.push(Gift { donor: donor_id, recipient: recipient_id, species, position, amount: given });
+        }
+    }
+    gifts
+}
+
+/// Top every struggling hive member back up from its hive's pool while the pool lasts
+fn pay_out_hive_pools(world: &mut World) -> Vec<Gift> {
+    let mut gifts = Vec::new();
+    for hive_entity in world.entities_with::<HiveMind>().to_vec() {
+        let Some(hive) = world.get_component::<HiveMind>(hive_entity) else { continue };
+        let (mut pool, members) = (hive.pool, hive.member_entities.clone());
+        for member in members {
+            let Some(llama) = world.get_component_mut::<Llama>(member) else { continue };
+            if pool <= 0.0 || llama.consciousness >= STRUGGLING_CONSCIOUSNESS {
+                continue;
+            }
+            let given = (STRUGGLING_CONSCIOUSNESS * 2.0 - llama.consciousness).min(pool);
+            llama.consciousness += given;
+            pool -= given;
+            gifts.push(Gift { donor: hive_entity, recipient: Some(member), species: llama.species, position: llama.position, amount: given });
+        }
+        if let Some(hive) = world.get_component_mut::<HiveMind>(hive_entity) {
+            hive.pool = pool;
+        }
+    }
+    gifts
+}
+
+fn hive_of(world: &World, llama: EntityId) -> Option<EntityId> {
+    world.query::<HiveMind>().into_iter()
+        .find(|(_, hive)| hive.member_entities.contains(&llama))
+        .map(|(entity, _)| entity)
+}
+
+fn bond(llama: &mut Llama, other: EntityId) {
+    if !llama.social_bonds.contains(&other) && llama.social_bonds.len() < MAX_BONDS {
+        llama.social_bonds.push(other);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn llama_at(world: &mut World, x: f32, species: SpeciesType, consciousness: f32) -> EntityId {
+        let mut llama = Llama::new_with_species(Vec2::new(x, 100.0), species);
+        llama.consciousness = consciousness;
+        llama.generosity = 1.0;
+        world.spawn(llama)
+    }
+
+    #[test]
+    fn test_generous_llamas_lift_struggling_kin() {
+        let mut world = World::new();
+        let donor = llama_at(&mut world, 100.0, SpeciesType::DiscoLlama, 2.0);
+        let struggling = llama_at(&mut world, 130.0, SpeciesType::DiscoLlama, 0.2);
+        let stranger = llama_at(&mut world, 120.0, SpeciesType::QuantumSheep, 0.1);
+
+        // Certain to give within a long enough tick
+        let gifts = process_gifting(&mut world, &Diplomacy::new(), &Obstacles::default(), 1.0 / (GIFT_RATE * 0.5));
+        assert_eq!(gifts.len(), 1);
+        assert_eq!((gifts[0].donor, gifts[0].recipient), (donor, Some(struggling)));
+        let (giver, receiver) = world.get_pair_mut::<Llama>(donor, struggling).unwrap();
+        assert!((giver.consciousness + receiver.consciousness - 2.2).abs() < 1e-5, "consciousness is passed on, not made");
+        assert!(receiver.consciousness >= STRUGGLING_CONSCIOUSNESS && receiver.social_bonds.contains(&donor));
+        assert!((world.get_component::<Llama>(stranger).unwrap().consciousness - 0.1).abs() < 1e-6, "other species go without");
+    }
+
+    #[test]
+    fn test_hive_pools_pay_out_to_struggling_members() {
+        let mut world = World::new();
+        let members = vec![llama_at(&mut world, 100.0, SpeciesType::HypnoCamel, 2.0), llama_at(&mut world, 900.0, SpeciesType::HypnoCamel, 0.1)];
+        let hive = world.spawn(HiveMind {
+            member_entities: members.clone(),
+            collective_consciousness: 2.1,
+            hive_center: Vec2::new(500.0, 100.0),
+            connection_network: Vec::new(),
+            shared_memories: Vec::new(),
+            collective_decision_weight: 0.8,
+            emergence_timestamp: 0.0,
+            pool: 0.0,
+        });
+        world.get_component_mut::<Llama>(members[0]).unwrap().collective_id = Some(hive);
+
+        // Too far to give directly, so the first gift goes into the pool and the next tick pays it out
+        process_gifting(&mut world, &Diplomacy::new(), &Obstacles::default(), 4.0);
+        assert!(world.get_component::<HiveMind>(hive).unwrap().pool > 0.0);
+        let payouts = process_gifting(&mut world, &Diplomacy::new(), &Obstacles::default(), 0.0);
+        assert_eq!(payouts[0].recipient, Some(members[1]));
+        assert!(world.get_component::<Llama>(members[1]).unwrap().consciousness > 0.1);
+    }
+}
@@ -0,0 +1,331 @@
+// === HIVE COLLECTIVE BEHAVIOR ===
+// What a hive mind does as one body beyond holding together: it hunts
+// crystals as a pack, pulses outward in unison on strong beats, sacrifices
+// its weakest member to feed the rest under extinction pressure, and
+// negotiates truces or tribute with rival hives it runs into.
+// Per-hive state lives in a HiveBehavior component on the hive entity.
+
+use glam::Vec2;
+use tracing::debug;
+use crate::core::ecs::{EntityId, World};
+use crate::entities::{Llama, SpeciesType, SPECIES_COUNT};
+use crate::simulation::ConsciousnessCrystal;
+use super::consciousness_multiplication::{HiveMind, HIVE_DISSOLVE_SIZE, species_index};
+
+/// Farthest crystal a hive will go after, in world units
+const HUNT_RANGE: f32 = 400.0;
+/// Crystals with less energy than this are not worth hunting
+const MIN_HUNT_ENERGY: f32 = 0.2;
+/// Steering towards the hunted crystal, in world units per second squared
+const HUNT_ACCELERATION: f32 = 60.0;
+/// Beat intensity that fires a pulse; the hive re-arms below PULSE_REARM
+const PULSE_BEAT: f32 = 0.8;
+const PULSE_REARM: f32 = 0.6;
+/// Outward kick given to every member on a pulse, in world units per second
+const PULSE_IMPULSE: f32 = 40.0;
+/// Species extinction pressure at which a hive starts sacrificing members
+const SACRIFICE_PRESSURE: f32 = 0.5;
+/// Seconds between two sacrifices of the same hive
+const SACRIFICE_COOLDOWN: f32 = 8.0;
+/// Share of the sacrificed member's consciousness the others receive
+const SACRIFICE_YIELD: f32 = 0.8;
+/// Hives closer than this negotiate, in world units
+const NEGOTIATION_RANGE: f32 = 300.0;
+/// Seconds before a hive negotiates again
+const NEGOTIATION_COOLDOWN: f32 = 15.0;
+/// Strength ratio under which rivals settle for a truce instead of tribute
+const TRUCE_RATIO: f32 = 1.5;
+/// Share of each weaker member's consciousness paid as tribute
+const TRIBUTE_SHARE: f32 = 0.1;
+
+/// Per-hive behavior state, stored next to the HiveMind component
+#[derive(Debug, Clone)]
+pub struct HiveBehavior {
+    pub hunt_target: Option<EntityId>, // Crystal entity being hunted
+    pub pulse_armed: bool,
+    pub last_sacrifice: f32,    // Cosmic time of the last sacrifice
+    pub last_negotiation: f32,  // Cosmic time of the last negotiation
+}
+
+impl Default for HiveBehavior {
+    fn default() -> Self {
+        Self {
+            hunt_target: None,
+            pulse_armed: true,
+            last_sacrifice: f32::NEG_INFINITY,
+            last_negotiation: f32::NEG_INFINITY,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NegotiationOutcome {
+    Truce,                                                  // Evenly matched: both stand down
+    Tribute { payer: EntityId, receiver: EntityId, amount: f32 }, // The weaker hive buys peace
+}
+
+/// Something a hive did as a collective
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HiveEvent {
+    HuntStarted { hive: EntityId, crystal: EntityId, species: SpeciesType },
+    Pulse { hive: EntityId, species: SpeciesType, strength: f32 },
+    Sacrifice { hive: EntityId, member: EntityId, species: SpeciesType, position: Vec2 },
+    Negotiation { hives: [EntityId; 2], species: [SpeciesType; 2], outcome: NegotiationOutcome },
+}
+
+/// Snapshot of one hive taken before anyone moves
+struct HiveView {
+    entity: EntityId,
+    species: SpeciesType,
+    center: Vec2,
+    strength: f32,
+    members: Vec<EntityId>,
+}
+
+fn hive_views(world: &World) -> Vec<HiveView> {
+    world.query::<HiveMind>().into_iter().filter_map(|(entity, hive)| {
+        let species = hive.member_entities.iter()
+            .find_map(|&member| world.get_component::<Llama>(member))?
+            .species;
+        Some(HiveView {
+            entity,
+            species,
+            center: hive.hive_center,
+            strength: hive.collective_consciousness,
+            members: hive.member_entities.clone(),
+        })
+    }).collect()
+}
+
+/// Run every hive's collective behavior for one tick
+pub fn process_hive_behavior(world: &mut World, dt: f32, beat_intensity: f32, extinction_pressure: [f32; SPECIES_COUNT], cosmic_time: f32) -> Vec<HiveEvent> {
+    let hives = hive_views(world);
+    for hive in &hives {
+        if world.get_component::<HiveBehavior>(hive.entity).is_none() {
+            world.add_component(hive.entity, HiveBehavior::default());
+        }
+    }
+
+    let mut events = Vec::new();
+    for hive in &hives {
+        hunt_crystals(world, hive, dt, &mut events);
+        pulse_on_beat(world, hive, beat_intensity, &mut events);
+        sacrifice_under_pressure(world, hive, extinction_pressure[species_index(hive.species)], cosmic_time, &mut events);
+    }
+    negotiate(world, &hives, cosmic_time, &mut events);
+    events
+}
+
+/// Chase the nearest charged crystal together
+fn hunt_crystals(world: &mut World, hive: &HiveView, dt: f32, events: &mut Vec<HiveEvent>) {
+    let Some(previous) = world.get_component::<HiveBehavior>(hive.entity).map(|behavior| behavior.hunt_target) else { return };
+    let current = previous
+        .and_then(|crystal| world.get_component::<ConsciousnessCrystal>(crystal).map(|c| (crystal, c)))
+        .filter(|(_, crystal)| crystal.consciousness_energy >= MIN_HUNT_ENERGY)
+        .map(|(crystal, c)| (crystal, c.position));
+
+    let target = current.or_else(|| {
+        world.query::<ConsciousnessCrystal>().into_iter()
+            .filter(|(_, crystal)| crystal.consciousness_energy >= MIN_HUNT_ENERGY)
+            .map(|(entity, crystal)| (entity, crystal.position))
+            .filter(|(_, position)| position.distance(hive.center) < HUNT_RANGE)
+            .min_by(|a, b| a.1.distance(hive.center).total_cmp(&b.1.distance(hive.center)))
+    });
+
+    if let Some(behavior) = world.get_component_mut::<HiveBehavior>(hive.entity) {
+        behavior.hunt_target = target.map(|(crystal, _)| crystal);
+    }
+    let Some((crystal, position)) = target else { return };
+    if previous != Some(crystal) {
+        events.push(HiveEvent::HuntStarted { hive: hive.entity, crystal, species: hive.species });
+    }
+
+    for &member in &hive.members {
+        if let Some(llama) = world.get_component_mut::<Llama>(member) {
+            llama.velocity += (position - llama.position).normalize_or_zero() * HUNT_ACCELERATION * dt;
+        }
+    }
+}
+
+/// Every member kicks outward at once on a strong beat; cohesion pulls them back in
+fn pulse_on_beat(world: &mut World, hive: &HiveView, beat_intensity: f32, events: &mut Vec<HiveEvent>) {
+    let Some(behavior) = world.get_component_mut::<HiveBehavior>(hive.entity) else { return };
+    if beat_intensity < PULSE_REARM {
+        behavior.pulse_armed = true;
+        return;
+    }
+    if beat_intensity < PULSE_BEAT || !behavior.pulse_armed {
+        return;
+    }
+    behavior.pulse_armed = false;
+
+    let strength = beat_intensity.min(1.0);
+    for &member in &hive.members {
+        if let Some(llama) = world.get_component_mut::<Llama>(member) {
+            llama.velocity += (llama.position - hive.center).normalize_or_zero() * PULSE_IMPULSE * strength;
+        }
+    }
+    events.push(HiveEvent::Pulse { hive: hive.entity, species: hive.species, strength });
+}
+
+/// Feed the weakest member to the rest while the species is dying out
+fn sacrifice_under_pressure(world: &mut World, hive: &HiveView, pressure: f32, cosmic_time: f32, events: &mut Vec<HiveEvent>) {
+    let ready = world.get_component::<HiveBehavior>(hive.entity)
+        .is_some_and(|behavior| cosmic_time - behavior.last_sacrifice >= SACRIFICE_COOLDOWN);
+    // Never sacrifice the hive below the size it needs to survive
+    if !ready || pressure < SACRIFICE_PRESSURE || hive.members.len() <= HIVE_DISSOLVE_SIZE + 1 {
+        return;
+    }
+
+    let Some((victim, consciousness, position)) = hive.members.iter()
+        .filter_map(|&member| world.get_component::<Llama>(member).map(|llama| (member, llama.consciousness, llama.position)))
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+    else { return };
+
+    if let Some(llama) = world.get_component_mut::<Llama>(victim) {
+        llama.consciousness = 0.05;
+        llama.extinction_pressure = 1.0; // Collected as extinct after the grace period
+    }
+    let survivors: Vec<EntityId> = hive.members.iter().copied().filter(|&member| member != victim).collect();
+    let share = consciousness * SACRIFICE_YIELD / survivors.len() as f32;
+    for &member in &survivors {
+        if let Some(llama) = world.get_component_mut::<Llama>(member) {
+            llama.consciousness = (llama.consciousness + share).min(2.0);
+            llama.extinction_pressure *= 0.7;
+        }
+    }
+    if let Some(stored) = world.get_component_mut::<HiveMind>(hive.entity) {
+        stored.member_entities.retain(|&member| member != victim);
+        stored.connection_network.retain(|&(a, b)| a != victim && b != victim);
+    }
+    if let Some(behavior) = world.get_component_mut::<HiveBehavior>(hive.entity) {
+        behavior.last_sacrifice = cosmic_time;
+    }
+
+    debug!(target: "warfare", "🩸 {:?} hive sacrificed a member to feed the collective", hive.species);
+    events.push(HiveEvent::Sacrifice { hive: hive.entity, member: victim, species: hive.species, position });
+}
+
+/// Rival hives that meet settle it: a truce when evenly matched, tribute otherwise
+fn negotiate(world: &mut World, hives: &[HiveView], cosmic_time: f32, events: &mut Vec<HiveEvent>) {
+    let ready = |world: &World, hive: EntityId| world.get_component::<HiveBehavior>(hive)
+        .is_some_and(|behavior| cosmic_time - behavior.last_negotiation >= NEGOTIATION_COOLDOWN);
+
+    for (i, a) in hives.iter().enumerate() {
+        for b in &hives[i + 1..] {
+            if a.species == b.species || a.center.distance(b.center) > NEGOTIATION_RANGE {
+                continue;
+            }
+            if !ready(world, a.entity) || !ready(world, b.entity) {
+                continue;
+            }
+
+            let (strong, weak) = if a.strength >= b.strength { (a, b) } else { (b, a) };
+            let outcome = if strong.strength <= weak.strength.max(f32::EPSILON) * TRUCE_RATIO {
+                for &member in a.members.iter().chain(&b.members) {
+                    if let Some(llama) = world.get_component_mut::<Llama>(member) {
+                        llama.warfare_participation *= 0.5;
+                    }
+                }
+                NegotiationOutcome::Truce
+            } else {
+                let mut amount = 0.0;
+                for &member in &weak.members {
+                    if let Some(llama) = world.get_component_mut::<Llama>(member) {
+                        let paid = llama.consciousness * TRIBUTE_SHARE;
+                        llama.consciousness -= paid;
+                        amount += paid;
+                    }
+                }
+                let share = amount / strong.members.len().max(1) as f32;
+                for &member in &strong.members {
+                    if let Some(llama) = world.get_component_mut::<Llama>(member) {
+                        llama.consciousness = (llama.consciousness + share).min(2.0);
+                    }
+                }
+                NegotiationOutcome::Tribute { payer: weak.entity, receiver: strong.entity, amount }
+            };
+
+            for hive in [a.entity, b.entity] {
+                if let Some(behavior) = world.get_component_mut::<HiveBehavior>(hive) {
+                    behavior.last_negotiation = cosmic_time;
+                }
+            }
+            debug!(target: "warfare", "🤝 {:?} and {:?} hives negotiated: {:?}", a.species, b.species, outcome);
+            events.push(HiveEvent::Negotiation { hives: [a.entity, b.entity], species: [a.species, b.species], outcome });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hive_of(world: &mut World, species: SpeciesType, count: usize, center: Vec2, strength: f32) -> EntityId {
+        let members: Vec<EntityId> = (0..count)
+            .map(|i| {
+                let angle = i as f32 / count as f32 * std::f32::consts::TAU;
+                world.spawn(Llama::new_with_species(center + Vec2::new(angle.cos(), angle.sin()) * 20.0, species))
+            })
+            .collect();
+        world.spawn(HiveMind {
+            member_entities: members,
+            collective_consciousness: strength,
+            hive_center: center,
+            connection_network: Vec::new(),
+            shared_memories: Vec::new(),
+            collective_decision_weight: 0.8,
+            emergence_timestamp: 0.0,
+            pool: 0.0,
+        })
+    }
+
+    #[test]
+    fn test_pulse_fires_once_per_beat_and_sacrifice_feeds_the_hive() {
+        let mut world = World::new();
+        let hive = hive_of(&mut world, SpeciesType::QuantumSheep, 9, Vec2::new(600.0, 400.0), 5.0);
+
+        let pulses = |events: &[HiveEvent]| events.iter().filter(|e| matches!(e, HiveEvent::Pulse { .. })).count();
+        assert_eq!(pulses(&process_hive_behavior(&mut world, 0.016, 0.9, [0.0; SPECIES_COUNT], 0.0)), 1);
+        assert_eq!(pulses(&process_hive_behavior(&mut world, 0.016, 0.9, [0.0; SPECIES_COUNT], 0.1)), 0);
+        process_hive_behavior(&mut world, 0.016, 0.1, [0.0; SPECIES_COUNT], 0.2);
+        assert_eq!(pulses(&process_hive_behavior(&mut world, 0.016, 0.95, [0.0; SPECIES_COUNT], 0.3)), 1);
+
+        let before: f32 = world.components::<Llama>().iter().map(|llama| llama.consciousness).sum();
+        let events = process_hive_behavior(&mut world, 0.016, 0.0, [0.0, 0.9, 0.0, 0.0], 1.0);
+        let Some(HiveEvent::Sacrifice { member, .. }) = events.iter().find(|e| matches!(e, HiveEvent::Sacrifice { .. })) else {
+            panic!("no sacrifice in {:?}", events);
+        };
+        assert!(world.get_component::<Llama>(*member).unwrap().is_extinct());
+        assert_eq!(world.get_component::<HiveMind>(hive).unwrap().member_entities.len(), 8);
+        let after: f32 = world.components::<Llama>().iter().map(|llama| llama.consciousness).sum();
+        assert!(after < before && after > before * 0.8);
+
+        // The next sacrifice waits for the cooldown
+        assert!(process_hive_behavior(&mut world, 0.016, 0.0, [0.0, 0.9, 0.0, 0.0], 2.0).is_empty());
+    }
+
+    #[test]
+    fn test_rival_hives_negotiate_truce_or_tribute() {
+        let mut world = World::new();
+        let disco = hive_of(&mut world, SpeciesType::DiscoLlama, 9, Vec2::new(300.0, 400.0), 9.0);
+        let camel = hive_of(&mut world, SpeciesType::HypnoCamel, 9, Vec2::new(500.0, 400.0), 2.0);
+
+        let events = process_hive_behavior(&mut world, 0.016, 0.0, [0.0; SPECIES_COUNT], 0.0);
+        let outcome = events.iter().find_map(|event| match event {
+            HiveEvent::Negotiation { outcome, .. } => Some(*outcome),
+            _ => None,
+        });
+        match outcome {
+            Some(NegotiationOutcome::Tribute { payer, receiver, amount }) => {
+                assert_eq!((payer, receiver), (camel, disco));
+                assert!(amount > 0.0);
+            }
+            other => panic!("expected tribute, got {:?}", other),
+        }
+
+        // No renegotiation during the cooldown
+        let again = process_hive_behavior(&mut world, 0.016, 0.0, [0.0; SPECIES_COUNT], 1.0);
+        assert!(!again.iter().any(|event| matches!(event, HiveEvent::Negotiation { .. })));
+    }
+}
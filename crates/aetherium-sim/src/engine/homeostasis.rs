@@ -0,0 +1,176 @@
+// === POPULATION HOMEOSTASIS ===
+// An optional controller that keeps every species' share of the population
+// inside a band. A species crowding past its ceiling feels a slowly rising
+// extinction pressure and is spawned less often; one shrinking below its
+// floor has its pressure eased and is spawned more often. The push grows with
+// how far a species strays outside its band, and is gentle enough that wars
+// and hives still swing the balance for a while before it is pulled back.
+
+use tracing::debug;
+use crate::entities::{Llama, SpeciesType, SPECIES_COUNT};
+use crate::params::{HOMEOSTASIS_SHARE, HOMEOSTASIS_STRENGTH};
+use super::consciousness_multiplication::species_index;
+
+/// Extinction pressure added per second to a species a full band-width over its ceiling, at full strength
+const PRESSURE_RATE: f32 = 0.08;
+/// Homeostasis alone never pushes a llama's extinction pressure past this
+const PRESSURE_CEILING: f32 = 0.85;
+/// Share of extinction pressure eased per second for a species a full band-width under its floor, at full strength
+const RELIEF_RATE: f32 = 0.5;
+/// Largest factor a species' spawn weight is scaled up or down by
+const MAX_SPAWN_BIAS: f32 = 4.0;
+/// Populations smaller than this are too small to balance
+const MIN_POPULATION: usize = 4;
+
+/// Share of the living population one species should hold
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PopulationBand {
+    pub min_share: f32,
+    pub max_share: f32,
+}
+
+impl PopulationBand {
+    pub fn new(min_share: f32, max_share: f32) -> Self {
+        let (min_share, max_share) = (HOMEOSTASIS_SHARE.clamp(min_share), HOMEOSTASIS_SHARE.clamp(max_share));
+        Self { min_share: min_share.min(max_share), max_share: min_share.max(max_share) }
+    }
+
+    /// How far a share lies outside the band, in band-widths: negative below it, positive above
+    pub fn deviation(&self, share: f32) -> f32 {
+        let width = (self.max_share - self.min_share).max(0.05);
+        if share < self.min_share {
+            (share - self.min_share) / width
+        } else if share > self.max_share {
+            (share - self.max_share) / width
+        } else {
+            0.0
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct HomeostasisConfig {
+    pub bands: [PopulationBand; SPECIES_COUNT], // Indexed by SpeciesType::to_index
+    pub strength: f32,                           // 0 only watches, 1 pushes hardest
+}
+
+impl Default for HomeostasisConfig {
+    fn default() -> Self {
+        Self { bands: [PopulationBand::new(0.1, 0.45); SPECIES_COUNT], strength: HOMEOSTASIS_STRENGTH.default }
+    }
+}
+
+impl HomeostasisConfig {
+    pub fn with_band(mut self, species: SpeciesType, min_share: f32, max_share: f32) -> Self {
+        self.bands[species.to_index()] = PopulationBand::new(min_share, max_share);
+        self
+    }
+
+    pub fn with_strength(mut self, strength: f32) -> Self {
+        self.strength = HOMEOSTASIS_STRENGTH.clamp(strength);
+        self
+    }
+}
+
+/// Measures the species shares each tick and leans on whoever is outside their band
+#[derive(Debug, Clone)]
+pub struct PopulationBalancer {
+    config: HomeostasisConfig,
+    shares: [f32; SPECIES_COUNT],
+    spawn_bias: [f32; SPECIES_COUNT],
+    outside: [bool; SPECIES_COUNT],
+}
+
+impl PopulationBalancer {
+    pub fn new(config: HomeostasisConfig) -> Self {
+        let config = HomeostasisConfig { strength: HOMEOSTASIS_STRENGTH.clamp(config.strength), ..config };
+        Self {
+            config,
+            shares: [1.0 / SPECIES_COUNT as f32; SPECIES_COUNT],
+            spawn_bias: [1.0; SPECIES_COUNT],
+            outside: [false; SPECIES_COUNT],
+        }
+    }
+
+    pub fn config(&self) -> &HomeostasisConfig {
+        &self.config
+    }
+
+    /// Each species' share of the living population at the last update
+    pub fn shares(&self) -> [f32; SPECIES_COUNT] {
+        self.shares
+    }
+
+    /// Nudge the extinction pressure of every species outside its band
+    pub fn update(&mut self, llamas: &mut [Llama], dt: f32) {
+        let mut counts = [0usize; SPECIES_COUNT];
+        for llama in llamas.iter().filter(|llama| llama.consciousness > 0.1) {
+            counts[species_index(llama.species)] += 1;
+        }
+        let total: usize = counts.iter().sum();
+        if total < MIN_POPULATION {
+            self.spawn_bias = [1.0; SPECIES_COUNT];
+            return;
+        }
+
+        let strength = self.config.strength;
+        let mut deviation = [0.0f32; SPECIES_COUNT];
+        for species in SpeciesType::ALL {
+            let i = species_index(species);
+            self.shares[i] = counts[i] as f32 / total as f32;
+            deviation[i] = self.config.bands[i].deviation(self.shares[i]).clamp(-1.0, 1.0);
+            self.spawn_bias[i] = MAX_SPAWN_BIAS.powf(-deviation[i] * strength);
+
+            let outside = deviation[i] != 0.0;
+            if outside != self.outside[i] {
+                debug!(target: "warfare", "⚖️ {:?} {} its population band at {:.0}%",
+                       species, if outside { "left" } else { "returned to" }, self.shares[i] * 100.0);
+                self.outside[i] = outside;
+            }
+        }
+
+        for llama in llamas.iter_mut() {
+            let push = deviation[species_index(llama.species)] * strength;
+            if push > 0.0 {
+                let raised = llama.extinction_pressure + PRESSURE_RATE * push * dt;
+                llama.extinction_pressure = raised.min(PRESSURE_CEILING.max(llama.extinction_pressure));
+            } else if push < 0.0 {
+                llama.extinction_pressure *= (1.0 + RELIEF_RATE * push * dt).max(0.0);
+            }
+        }
+    }
+
+    /// Base spawn weights leaned towards species under their band, still summing to 1
+    pub fn apply_spawn_weights(&self, weights: [f32; SPECIES_COUNT]) -> [f32; SPECIES_COUNT] {
+        let scaled = std::array::from_fn(|i| weights[i] * self.spawn_bias[i]);
+        let total: f32 = scaled.iter().sum();
+        if total > 0.0 { scaled.map(|weight: f32| weight / total) } else { weights }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glam::Vec2;
+
+    #[test]
+    fn test_dominant_species_is_pressured_and_rare_one_favored() {
+        let mut llamas: Vec<Llama> = (0..18).map(|_| Llama::new_with_species(Vec2::ZERO, SpeciesType::DiscoLlama)).collect();
+        llamas.push(Llama::new_with_species(Vec2::ZERO, SpeciesType::QuantumSheep));
+        llamas[18].extinction_pressure = 0.5;
+
+        let mut balancer = PopulationBalancer::new(HomeostasisConfig::default().with_strength(1.0));
+        for _ in 0..600 {
+            balancer.update(&mut llamas, 1.0 / 60.0);
+        }
+
+        assert!((balancer.shares()[SpeciesType::DiscoLlama.to_index()] - 18.0 / 19.0).abs() < 0.01);
+        assert!(llamas[0].extinction_pressure > 0.0 && llamas[0].extinction_pressure <= PRESSURE_CEILING);
+        assert!(llamas[18].extinction_pressure < 0.5);
+
+        let weights = balancer.apply_spawn_weights([0.25; SPECIES_COUNT]);
+        assert!((weights.iter().sum::<f32>() - 1.0).abs() < 1e-5);
+        assert!(weights[SpeciesType::QuantumSheep.to_index()] > 0.25);
+        assert!(weights[SpeciesType::DiscoLlama.to_index()] < 0.1);
+    }
+}
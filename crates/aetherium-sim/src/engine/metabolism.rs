@@ -0,0 +1,174 @@
+// === METABOLISM ===
+// An optional energy economy under the consciousness dynamics. Every llama
+// carries an energy reserve that living, moving and fighting burn down, and
+// that harvested crystals and rest inside meditative zones refill. Exhausted
+// llamas slow and dim; one left with nothing at all starves after a while and
+// is removed, so crystals and calm ground become resources species compete for.
+
+use tracing::debug;
+use crate::core::ecs::{EntityId, World};
+use crate::entities::Llama;
+use crate::params::{BASAL_DRAIN, CRYSTAL_RECHARGE, MOVEMENT_DRAIN, STARVATION_SECONDS, WARFARE_DRAIN, ZONE_RECHARGE};
+use crate::simulation::DigitalEcosystem;
+use super::population::{despawn_llama, DespawnReason, Despawned};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MetabolismConfig {
+    pub basal_drain: f32,      // Energy per second just for living
+    pub movement_drain: f32,   // Energy per 100 world units travelled
+    pub warfare_drain: f32,    // Energy per second at full warfare participation
+    pub crystal_recharge: f32, // Energy per unit of crystal harvested
+    pub zone_recharge: f32,    // Energy per second at the heart of a meditative zone
+    pub starvation_time: f32,  // Seconds a llama lasts with no energy before it dies
+}
+
+impl Default for MetabolismConfig {
+    fn default() -> Self {
+        Self {
+            basal_drain: BASAL_DRAIN.default,
+            movement_drain: MOVEMENT_DRAIN.default,
+            warfare_drain: WARFARE_DRAIN.default,
+            crystal_recharge: CRYSTAL_RECHARGE.default,
+            zone_recharge: ZONE_RECHARGE.default,
+            starvation_time: STARVATION_SECONDS.default,
+        }
+    }
+}
+
+impl MetabolismConfig {
+    pub fn with_drain(mut self, basal: f32, movement: f32, warfare: f32) -> Self {
+        self.basal_drain = BASAL_DRAIN.clamp(basal);
+        self.movement_drain = MOVEMENT_DRAIN.clamp(movement);
+        self.warfare_drain = WARFARE_DRAIN.clamp(warfare);
+        self
+    }
+
+    pub fn with_recharge(mut self, crystal: f32, zone: f32) -> Self {
+        self.crystal_recharge = CRYSTAL_RECHARGE.clamp(crystal);
+        self.zone_recharge = ZONE_RECHARGE.clamp(zone);
+        self
+    }
+
+    pub fn with_starvation_time(mut self, seconds: f32) -> Self {
+        self.starvation_time = STARVATION_SECONDS.clamp(seconds);
+        self
+    }
+
+    /// Every value forced into its safe range
+    fn clamped(self) -> Self {
+        Self::default()
+            .with_drain(self.basal_drain, self.movement_drain, self.warfare_drain)
+            .with_recharge(self.crystal_recharge, self.zone_recharge)
+            .with_starvation_time(self.starvation_time)
+    }
+}
+
+/// Burns and refills every llama's energy each tick and removes the starved
+#[derive(Debug, Clone)]
+pub struct Metabolism {
+    config: MetabolismConfig,
+    exhausted: usize,
+}
+
+impl Metabolism {
+    pub fn new(config: MetabolismConfig) -> Self {
+        Self { config: config.clamped(), exhausted: 0 }
+    }
+
+    pub fn config(&self) -> &MetabolismConfig {
+        &self.config
+    }
+
+    /// Llamas below the exhaustion threshold at the last update
+    pub fn exhausted(&self) -> usize {
+        self.exhausted
+    }
+
+    /// Spend and regain energy for one tick
+    pub fn update(&mut self, llamas: &mut [Llama], ecosystem: &DigitalEcosystem, dt: f32) {
+        let config = self.config;
+        for llama in llamas.iter_mut() {
+            let travelled = llama.velocity.length() * llama.metabolic_pace() * dt;
+            let burned = config.basal_drain * dt
+                + config.movement_drain * travelled / 100.0
+                + config.warfare_drain * llama.warfare_participation.clamp(0.0, 1.0) * dt;
+            let rest = ecosystem.get_territory_effects(llama.position).energy_recharge.max(0.0);
+            let gained = config.crystal_recharge * llama.energy_intake + config.zone_recharge * rest * dt;
+            llama.energy_intake = 0.0;
+            llama.energy = (llama.energy - burned + gained).clamp(0.0, 1.0);
+
+            if llama.energy > 0.0 {
+                llama.starving_time = 0.0;
+            } else {
+                llama.starving_time += dt;
+            }
+        }
+
+        let exhausted = llamas.iter().filter(|llama| llama.is_exhausted()).count();
+        if exhausted != self.exhausted && (exhausted == 0 || self.exhausted == 0) {
+            debug!(target: "warfare", "🔋 {} llamas exhausted", exhausted);
+        }
+        self.exhausted = exhausted;
+    }
+
+    /// Remove every llama that has gone without energy for the starvation time
+    pub fn collect_starved(&self, world: &mut World) -> Vec<Despawned> {
+        let starved: Vec<EntityId> = world.query::<Llama>().into_iter()
+            .filter(|(_, llama)| llama.starving_time >= self.config.starvation_time)
+            .map(|(entity, _)| entity)
+            .collect();
+        starved.into_iter()
+            .filter_map(|entity| despawn_llama(world, entity, DespawnReason::Exhausted))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glam::Vec2;
+    use crate::simulation::{TerritoryZone, ZoneType};
+
+    #[test]
+    fn test_energy_drains_recharges_and_starves() {
+        let mut world = World::new();
+        let mut ecosystem = DigitalEcosystem::new(&mut world);
+        ecosystem.territory_zones = vec![TerritoryZone {
+            center: Vec2::new(100.0, 100.0), radius: 80.0, zone_type: ZoneType::Meditative, strength: 1.0, age: 0.0, lifespan: None,
+        }];
+        let config = MetabolismConfig::default().with_drain(0.05, 0.1, 0.2).with_starvation_time(2.0);
+        let mut metabolism = Metabolism::new(config);
+
+        let mut runner = Llama::new(Vec2::new(900.0, 600.0));
+        runner.velocity = Vec2::new(100.0, 0.0);
+        runner.warfare_participation = 1.0;
+        let mut resting = Llama::new(Vec2::new(100.0, 100.0));
+        resting.velocity = Vec2::ZERO;
+        resting.energy = 0.5;
+        let runner = world.spawn(runner);
+        let resting = world.spawn(resting);
+
+        for _ in 0..60 {
+            metabolism.update(world.components_mut::<Llama>(), &ecosystem, 0.1);
+        }
+        let llama = |world: &World, entity| world.get_component::<Llama>(entity).unwrap().clone();
+        assert_eq!(llama(&world, runner).energy, 0.0);
+        assert!(llama(&world, runner).metabolic_pace() < 0.5);
+        assert!(llama(&world, resting).energy > 0.5, "meditative zones restore energy");
+        assert_eq!(metabolism.exhausted(), 1);
+
+        world.get_component_mut::<Llama>(runner).unwrap().energy_intake = 0.5;
+        metabolism.update(world.components_mut::<Llama>(), &ecosystem, 0.1);
+        assert!(llama(&world, runner).energy > 0.5, "harvested crystals restore energy");
+        assert_eq!(llama(&world, runner).starving_time, 0.0);
+
+        world.get_component_mut::<Llama>(runner).unwrap().energy = 0.0;
+        for _ in 0..25 {
+            metabolism.update(world.components_mut::<Llama>(), &ecosystem, 0.1);
+        }
+        let starved = metabolism.collect_starved(&mut world);
+        assert_eq!(starved.len(), 1);
+        assert_eq!((starved[0].entity, starved[0].reason), (runner, DespawnReason::Exhausted));
+        assert!(world.get_component::<Llama>(resting).is_some());
+    }
+}
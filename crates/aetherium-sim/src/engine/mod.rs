@@ -0,0 +1,35 @@
+// Engine module containing core engine systems
+
+pub mod av_sync;
+pub mod beat_scheduler;
+pub mod chaos_engine;
+pub mod consciousness_graph;
+pub mod consciousness_multiplication;
+pub mod diplomacy;
+pub mod event_system;
+pub mod gifting;
+pub mod hive_behavior;
+pub mod homeostasis;
+pub mod metabolism;
+pub mod modulation;
+pub mod population;
+pub mod safety;
+pub mod speciation;
+pub mod tuning;
+
+pub use av_sync::{AvSyncCalibrator, BeatDelayLine};
+pub use beat_scheduler::{BeatScheduler, Quantize, BEATS_PER_BAR};
+pub use chaos_engine::*;
+pub use consciousness_graph::{ConsciousnessGraph, EdgeKind, GraphEdge, GraphFormat, GraphGroup, GraphNode};
+pub use consciousness_multiplication::*;
+pub use diplomacy::{Diplomacy, DiplomacyEvent, TREATY_DURATION};
+pub use event_system::*;
+pub use gifting::{Gift, STRUGGLING_CONSCIOUSNESS, process_gifting};
+pub use hive_behavior::{HiveBehavior, HiveEvent, NegotiationOutcome};
+pub use homeostasis::{HomeostasisConfig, PopulationBalancer, PopulationBand};
+pub use metabolism::{Metabolism, MetabolismConfig};
+pub use modulation::{ModCurve, ModDestination, ModMatrix, ModOutputs, ModRoute, ModSource, ModSources, MAX_ROUTES};
+pub use population::{CullPolicy, DespawnReason, Despawned, DEFAULT_MAX_POPULATION, EXTINCTION_GRACE_PERIOD};
+pub use speciation::{Speciation, SpeciesEmergence, HYBRID_EMERGENCE_SECONDS};
+pub use tuning::ChaosTuning;
+pub use safety::{SafetyConfig, FlashTracker, FrameAnalyzer, FrameSummary, SafetyViolation, ZonedLuminanceAnalyzer, ZoneAnalysis, apply_safety_pipeline, calculate_luminance, limit_luminance_change, is_dangerous_red, rgb_to_hsv, hsv_to_rgb_vec3};
\ No newline at end of file
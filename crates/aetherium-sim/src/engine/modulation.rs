@@ -0,0 +1,217 @@
+// === MODULATION MATRIX ===
+// Routes live signals of the organism to the knobs it is drawn and heard
+// with, like the mod matrix of a synthesizer. A source is a 0-1 signal: the
+// beat, how bound into hives the herd is, how fast the cursor moves, the
+// synth's bass, the time of day. A destination is a -1..1 offset around the
+// organism's own behavior: extra spawns, a hue rotation, more or less reality
+// distortion, a closing low-pass filter. Each route shapes its source with a
+// curve and scales it by a signed depth; routes to the same destination add
+// up. With no routes every destination sits at zero and nothing changes.
+
+use serde::{Deserialize, Serialize};
+use crate::params::MOD_DEPTH;
+
+/// Routes a matrix may hold
+pub const MAX_ROUTES: usize = 16;
+
+/// A live signal, 0-1
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ModSource {
+    Beat,          // Intensity of the visual beat
+    HiveCoherence, // Share of the herd bound into hives
+    CursorSpeed,   // How fast the cursor moves, smoothed
+    AudioBass,     // Bass level of the synthesized output
+    TimeOfDay,     // 0 at noon, 1 at midnight
+}
+
+impl ModSource {
+    pub const ALL: [ModSource; 5] = [Self::Beat, Self::HiveCoherence, Self::CursorSpeed, Self::AudioBass, Self::TimeOfDay];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Beat => "beat",
+            Self::HiveCoherence => "hive-coherence",
+            Self::CursorSpeed => "cursor-speed",
+            Self::AudioBass => "audio-bass",
+            Self::TimeOfDay => "time-of-day",
+        }
+    }
+
+    fn index(self) -> usize {
+        Self::ALL.iter().position(|&source| source == self).unwrap_or(0)
+    }
+}
+
+/// A knob the organism offsets by the sum of its routes, -1..1
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ModDestination {
+    SpawnRate,    // Llamas spawned per second on top of clicks; only positive offsets spawn
+    HueOffset,    // Rotation of every llama's hue
+    Distortion,   // Added to the reality distortion the shaders see
+    FilterCutoff, // Low-pass over the llama synth; open at zero, negative offsets close it
+}
+
+impl ModDestination {
+    pub const ALL: [ModDestination; 4] = [Self::SpawnRate, Self::HueOffset, Self::Distortion, Self::FilterCutoff];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::SpawnRate => "spawn-rate",
+            Self::HueOffset => "hue-offset",
+            Self::Distortion => "distortion",
+            Self::FilterCutoff => "filter-cutoff",
+        }
+    }
+
+    fn index(self) -> usize {
+        Self::ALL.iter().position(|&destination| destination == self).unwrap_or(0)
+    }
+}
+
+/// Shape a route gives its source before the depth scales it
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ModCurve {
+    #[default]
+    Linear,
+    Exponential, // Quiet until the source runs high
+    Logarithmic, // Jumps up early, then levels off
+    SCurve,      // Smoothstep: gentle at both ends
+    Inverted,    // High when the source is low
+}
+
+impl ModCurve {
+    pub const ALL: [ModCurve; 5] = [Self::Linear, Self::Exponential, Self::Logarithmic, Self::SCurve, Self::Inverted];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Linear => "linear",
+            Self::Exponential => "exponential",
+            Self::Logarithmic => "logarithmic",
+            Self::SCurve => "s-curve",
+            Self::Inverted => "inverted",
+        }
+    }
+
+    /// The shaped value of a 0-1 input, itself 0-1
+    pub fn apply(self, x: f32) -> f32 {
+        let x = if x.is_finite() { x.clamp(0.0, 1.0) } else { 0.0 };
+        match self {
+            Self::Linear => x,
+            Self::Exponential => x * x,
+            Self::Logarithmic => x.sqrt(),
+            Self::SCurve => x * x * (3.0 - 2.0 * x),
+            Self::Inverted => 1.0 - x,
+        }
+    }
+}
+
+/// One source driving one destination
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ModRoute {
+    pub source: ModSource,
+    pub destination: ModDestination,
+    pub depth: f32, // -1..1; negative pushes the destination down
+    #[serde(default)]
+    pub curve: ModCurve,
+}
+
+impl ModRoute {
+    pub fn new(source: ModSource, destination: ModDestination, depth: f32) -> Self {
+        Self { source, destination, depth: MOD_DEPTH.clamp(depth), curve: ModCurve::Linear }
+    }
+
+    pub fn with_curve(mut self, curve: ModCurve) -> Self {
+        self.curve = curve;
+        self
+    }
+}
+
+/// This tick's value of every source
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ModSources([f32; ModSource::ALL.len()]);
+
+impl ModSources {
+    pub fn set(&mut self, source: ModSource, value: f32) {
+        self.0[source.index()] = value;
+    }
+
+    pub fn get(&self, source: ModSource) -> f32 {
+        self.0[source.index()]
+    }
+}
+
+/// Offset of every destination, -1..1
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ModOutputs([f32; ModDestination::ALL.len()]);
+
+impl ModOutputs {
+    pub fn get(&self, destination: ModDestination) -> f32 {
+        self.0[destination.index()]
+    }
+}
+
+/// Every route of the organism
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ModMatrix {
+    pub routes: Vec<ModRoute>,
+}
+
+impl ModMatrix {
+    /// Add a route; false once the matrix is full
+    pub fn add(&mut self, route: ModRoute) -> bool {
+        if self.routes.len() >= MAX_ROUTES {
+            return false;
+        }
+        self.routes.push(route);
+        true
+    }
+
+    /// Sum every route into its destination
+    pub fn evaluate(&self, sources: &ModSources) -> ModOutputs {
+        let mut outputs = ModOutputs::default();
+        for route in &self.routes {
+            outputs.0[route.destination.index()] += route.curve.apply(sources.get(route.source)) * MOD_DEPTH.clamp(route.depth);
+        }
+        outputs.0 = outputs.0.map(|value| value.clamp(-1.0, 1.0));
+        outputs
+    }
+
+    /// At most `MAX_ROUTES` routes, every depth inside its range, for matrices read from files
+    pub fn sanitized(mut self) -> Self {
+        self.routes.truncate(MAX_ROUTES);
+        for route in &mut self.routes {
+            route.depth = MOD_DEPTH.clamp(route.depth);
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_routes_shape_scale_and_sum_into_destinations() {
+        let mut sources = ModSources::default();
+        sources.set(ModSource::Beat, 0.5);
+        sources.set(ModSource::TimeOfDay, 1.0);
+        assert_eq!(ModMatrix::default().evaluate(&sources), ModOutputs::default());
+
+        let mut matrix = ModMatrix::default();
+        matrix.add(ModRoute::new(ModSource::Beat, ModDestination::Distortion, 0.8));
+        matrix.add(ModRoute::new(ModSource::Beat, ModDestination::Distortion, 0.4).with_curve(ModCurve::Exponential));
+        matrix.add(ModRoute::new(ModSource::TimeOfDay, ModDestination::FilterCutoff, -3.0));
+        let outputs = matrix.evaluate(&sources);
+        assert!((outputs.get(ModDestination::Distortion) - (0.4 + 0.1)).abs() < 1e-6);
+        assert_eq!(outputs.get(ModDestination::FilterCutoff), -1.0, "depth is clamped to -1");
+        assert_eq!(outputs.get(ModDestination::SpawnRate), 0.0);
+
+        assert_eq!(ModCurve::Inverted.apply(0.25), 0.75);
+        assert_eq!(ModCurve::SCurve.apply(0.5), 0.5);
+        assert_eq!(ModCurve::Linear.apply(f32::NAN), 0.0);
+
+        let full = ModMatrix { routes: vec![ModRoute::new(ModSource::Beat, ModDestination::HueOffset, 1.0); MAX_ROUTES + 3] }.sanitized();
+        assert_eq!(full.routes.len(), MAX_ROUTES);
+        assert!(!full.clone().add(ModRoute::new(ModSource::Beat, ModDestination::HueOffset, 1.0)));
+    }
+}
@@ -0,0 +1,201 @@
+// === POPULATION CONTROL ===
+// Llamas that stay extinct are garbage collected, and the population is held
+// under a cap so the simulation and vertex workload cannot grow without bound
+
+use glam::Vec2;
+use crate::core::ecs::{EntityId, World};
+use crate::core::events::ChaosEvent;
+use crate::engine::consciousness_multiplication::{ConsciousnessHierarchy, ConsciousnessPredation, HiveMind};
+use crate::entities::{Llama, SpeciesType};
+
+/// Default ceiling on live llamas
+pub const DEFAULT_MAX_POPULATION: usize = 256;
+/// Seconds a llama may stay extinct (and possibly recover) before it is removed
+pub const EXTINCTION_GRACE_PERIOD: f32 = 3.0;
+
+/// Which llamas make room when the population cap is reached
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CullPolicy {
+    #[default]
+    Oldest,  // Earliest spawned first
+    Weakest, // Lowest consciousness first
+}
+
+/// Why a llama left the world
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DespawnReason {
+    Extinct,       // Stayed extinct past the grace period
+    PopulationCap, // Culled to make room
+    Removed,       // Explicitly despawned by the host app
+    Exhausted,     // Starved after running out of energy
+}
+
+/// Record of a removed llama, turned into an event by the engine
+#[derive(Debug, Clone, PartialEq)]
+pub struct Despawned {
+    pub entity: EntityId,
+    pub species: SpeciesType,
+    pub position: Vec2,
+    pub reason: DespawnReason,
+    pub consciousness: f32, // Own and environmental consciousness it still held
+    pub hue: f32,
+    pub memory: f32,        // Memory intensity at death
+}
+
+impl Despawned {
+    pub fn event(&self) -> ChaosEvent {
+        ChaosEvent::LlamaDespawned { entity: self.entity, species: self.species, position: self.position, reason: self.reason }
+    }
+}
+
+/// Remove a llama and scrub every reference to it, so bonds, collectives and
+/// predations never point at a dead entity between system updates
+pub fn despawn_llama(world: &mut World, entity: EntityId, reason: DespawnReason) -> Option<Despawned> {
+    let llama = world.get_component::<Llama>(entity)?;
+    let despawned = Despawned {
+        entity,
+        species: llama.species,
+        position: llama.position,
+        reason,
+        consciousness: llama.consciousness + llama.environmental_consciousness,
+        hue: llama.color.x,
+        memory: llama.memory_intensity,
+    };
+    world.despawn(entity);
+
+    for other in world.components_mut::<Llama>() {
+        other.social_bonds.retain(|&bond| bond != entity);
+        if other.predation_target == Some(entity) {
+            other.predation_target = None;
+        }
+    }
+
+    world.retain::<ConsciousnessPredation>(|_, predation| {
+        predation.predator_id != entity && predation.prey_id != entity
+    });
+    for hierarchy in world.components_mut::<ConsciousnessHierarchy>() {
+        hierarchy.members.retain(|&member| member != entity);
+    }
+    world.retain::<ConsciousnessHierarchy>(|_, hierarchy| !hierarchy.members.is_empty());
+    for hive in world.components_mut::<HiveMind>() {
+        hive.member_entities.retain(|&member| member != entity);
+        hive.connection_network.retain(|&(a, b)| a != entity && b != entity);
+    }
+
+    Some(despawned)
+}
+
+/// Advance extinction timers and remove llamas that never recovered
+pub fn collect_extinct(world: &mut World, dt: f32) -> Vec<Despawned> {
+    for llama in world.components_mut::<Llama>() {
+        if llama.is_extinct() {
+            llama.extinct_time += dt;
+        } else {
+            llama.extinct_time = 0.0;
+        }
+    }
+
+    let expired: Vec<EntityId> = world.query::<Llama>().into_iter()
+        .filter(|(_, llama)| llama.extinct_time >= EXTINCTION_GRACE_PERIOD)
+        .map(|(entity, _)| entity)
+        .collect();
+
+    expired.into_iter()
+        .filter_map(|entity| despawn_llama(world, entity, DespawnReason::Extinct))
+        .collect()
+}
+
+/// Cull llamas until at most `max_population` remain
+pub fn enforce_population_cap(world: &mut World, max_population: usize, policy: CullPolicy) -> Vec<Despawned> {
+    let excess = world.count::<Llama>().saturating_sub(max_population);
+    if excess == 0 {
+        return Vec::new();
+    }
+
+    // Storage is in spawn order, so the oldest llamas come first
+    let mut candidates = world.query::<Llama>();
+    if policy == CullPolicy::Weakest {
+        candidates.sort_by(|a, b| a.1.consciousness.total_cmp(&b.1.consciousness));
+    }
+    let doomed: Vec<EntityId> = candidates.into_iter().take(excess).map(|(entity, _)| entity).collect();
+
+    doomed.into_iter()
+        .filter_map(|entity| despawn_llama(world, entity, DespawnReason::PopulationCap))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spawn_many(world: &mut World, count: usize) -> Vec<EntityId> {
+        (0..count)
+            .map(|i| world.spawn(Llama::new(Vec2::new(i as f32 * 50.0, 100.0))))
+            .collect()
+    }
+
+    #[test]
+    fn test_extinct_llamas_removed_after_grace_period() {
+        let mut world = World::new();
+        let ids = spawn_many(&mut world, 3);
+        let doomed = world.get_component_mut::<Llama>(ids[1]).unwrap();
+        doomed.consciousness = 0.05;
+        doomed.extinction_pressure = 1.0;
+
+        assert!(collect_extinct(&mut world, EXTINCTION_GRACE_PERIOD * 0.5).is_empty());
+        let removed = collect_extinct(&mut world, EXTINCTION_GRACE_PERIOD * 0.5);
+        assert_eq!(removed.len(), 1);
+        assert_eq!(removed[0].entity, ids[1]);
+        assert_eq!(removed[0].reason, DespawnReason::Extinct);
+        assert_eq!(world.entities_with::<Llama>(), &[ids[0], ids[2]]);
+    }
+
+    #[test]
+    fn test_population_cap_culls_by_policy() {
+        let mut world = World::new();
+        let ids = spawn_many(&mut world, 5);
+        world.get_component_mut::<Llama>(ids[3]).unwrap().consciousness = 0.01;
+
+        let culled = enforce_population_cap(&mut world, 4, CullPolicy::Weakest);
+        assert_eq!(culled.iter().map(|d| d.entity).collect::<Vec<_>>(), vec![ids[3]]);
+
+        let culled = enforce_population_cap(&mut world, 2, CullPolicy::Oldest);
+        assert_eq!(culled.iter().map(|d| d.entity).collect::<Vec<_>>(), vec![ids[0], ids[1]]);
+        assert_eq!(world.entities_with::<Llama>(), &[ids[2], ids[4]]);
+    }
+
+    #[test]
+    fn test_despawn_scrubs_references() {
+        let mut world = World::new();
+        let ids = spawn_many(&mut world, 2);
+        let survivor = world.get_component_mut::<Llama>(ids[0]).unwrap();
+        survivor.social_bonds.push(ids[1]);
+        survivor.predation_target = Some(ids[1]);
+        world.spawn(ConsciousnessPredation {
+            predator_id: ids[0],
+            prey_id: ids[1],
+            absorption_progress: 0.5,
+            resistance_strength: 0.1,
+            visual_effect_intensity: 1.0,
+        });
+        world.spawn(HiveMind {
+            member_entities: ids.clone(),
+            collective_consciousness: 2.0,
+            hive_center: Vec2::ZERO,
+            connection_network: vec![(ids[0], ids[1])],
+            shared_memories: Vec::new(),
+            collective_decision_weight: 0.8,
+            emergence_timestamp: 0.0,
+            pool: 0.0,
+        });
+
+        despawn_llama(&mut world, ids[1], DespawnReason::Removed);
+        let survivor = world.get_component::<Llama>(ids[0]).unwrap();
+        assert!(survivor.social_bonds.is_empty());
+        assert_eq!(survivor.predation_target, None);
+        assert_eq!(world.count::<ConsciousnessPredation>(), 0);
+        let hive = &world.components::<HiveMind>()[0];
+        assert_eq!(hive.member_entities, vec![ids[0]]);
+        assert!(hive.connection_network.is_empty());
+    }
+}
@@ -0,0 +1,652 @@
+// === CRITICAL SAFETY SYSTEMS FOR EPILEPSY PROTECTION ===
+// Extracted from simple.rs for better modularity
+
+use std::collections::VecDeque;
+use glam::{Vec2, Vec3};
+
+/// Luminance change that counts as a flash transition (5% threshold)
+pub const FLASH_LUMINANCE_THRESHOLD: f32 = 0.05;
+
+/// Slack for float noise when checking a change sits exactly at a limit
+const LIMIT_TOLERANCE: f32 = 1e-5;
+
+/// Simple safety configuration
+#[derive(Debug, Clone)]
+pub struct SafetyConfig {
+    pub visual_intensity_limit: f32,  // 0.0 to 1.0
+    pub max_flash_rate: f32,          // Hz (3.0 is international standard)
+    pub max_luminance_change: f32,    // 0.0 to 1.0 (0.1 is 10% standard)
+    pub red_flash_protection: bool,
+}
+
+impl Default for SafetyConfig {
+    fn default() -> Self {
+        Self {
+            visual_intensity_limit: 1.0,     // Full intensity by default
+            max_flash_rate: 3.0,             // International safety standard
+            max_luminance_change: 0.1,       // 10% WCAG standard
+            red_flash_protection: true,
+        }
+    }
+}
+
+impl SafetyConfig {
+    pub fn safe_mode() -> Self {
+        Self {
+            visual_intensity_limit: 0.5,     // 50% intensity
+            max_flash_rate: 2.0,             // Even more conservative
+            max_luminance_change: 0.05,      // 5% change
+            red_flash_protection: true,
+        }
+    }
+}
+
+/// Simple flash tracker to enforce rate limiting
+#[derive(Debug)]
+pub struct FlashTracker {
+    last_major_change: f64,
+    change_count_in_window: u32,
+    window_start_time: f64,
+    window_duration: f64,
+}
+
+impl FlashTracker {
+    pub fn new() -> Self {
+        Self {
+            last_major_change: 0.0,
+            change_count_in_window: 0,
+            window_start_time: 0.0,
+            window_duration: 1.0, // 1 second window
+        }
+    }
+
+    pub fn can_allow_flash(&mut self, current_time: f64, max_rate: f32) -> bool {
+        // Reset window if needed
+        if current_time - self.window_start_time > self.window_duration {
+            self.window_start_time = current_time;
+            self.change_count_in_window = 0;
+        }
+
+        // Check if we're under the rate limit
+        let flashes_per_second = self.change_count_in_window as f32 / self.window_duration as f32;
+        if flashes_per_second >= max_rate {
+            return false;
+        }
+
+        // Check minimum interval (1/3 Hz = 0.33 seconds for 3 Hz)
+        let min_interval = 1.0 / max_rate as f64;
+        if current_time - self.last_major_change < min_interval {
+            return false;
+        }
+
+        true
+    }
+
+    pub fn record_flash(&mut self, current_time: f64) {
+        self.last_major_change = current_time;
+        self.change_count_in_window += 1;
+    }
+}
+
+/// Summary of one rendered frame for safety analysis
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FrameSummary {
+    pub timestamp: f64,
+    pub average_color: Vec3,
+}
+
+impl FrameSummary {
+    pub fn new(timestamp: f64, average_color: Vec3) -> Self {
+        Self { timestamp, average_color }
+    }
+
+    /// Average a frame's colors into a summary (black frame if empty)
+    pub fn from_colors<I: IntoIterator<Item = Vec3>>(timestamp: f64, colors: I) -> Self {
+        let (sum, count) = colors.into_iter()
+            .fold((Vec3::ZERO, 0usize), |(sum, count), color| (sum + color, count + 1));
+        let average_color = if count > 0 { sum / count as f32 } else { Vec3::ZERO };
+        Self { timestamp, average_color }
+    }
+
+    pub fn luminance(&self) -> f32 {
+        calculate_luminance(&self.average_color)
+    }
+}
+
+/// A safety rule broken between two successive frames
+#[derive(Debug, Clone, PartialEq)]
+pub enum SafetyViolation {
+    FlashRate { flashes_per_second: f32, limit: f32 },
+    LuminanceDelta { delta: f32, limit: f32 },
+    RedFlash { luminance_delta: f32 },
+}
+
+/// Standalone frame-to-frame flash analysis against a SafetyConfig
+#[derive(Debug)]
+pub struct FrameAnalyzer {
+    config: SafetyConfig,
+    previous: Option<FrameSummary>,
+    flash_times: VecDeque<f64>,
+    window_duration: f64,
+}
+
+impl FrameAnalyzer {
+    pub fn new(config: SafetyConfig) -> Self {
+        Self {
+            config,
+            previous: None,
+            flash_times: VecDeque::new(),
+            window_duration: 1.0, // 1 second sliding window
+        }
+    }
+
+    pub fn config(&self) -> &SafetyConfig {
+        &self.config
+    }
+
+    pub fn set_config(&mut self, config: SafetyConfig) {
+        self.config = config;
+    }
+
+    /// Analyze the next frame against the previous one
+    pub fn analyze(&mut self, frame: FrameSummary) -> Vec<SafetyViolation> {
+        let mut violations = Vec::new();
+
+        let previous = match self.previous.replace(frame) {
+            Some(previous) => previous,
+            None => return violations, // Nothing to compare against yet
+        };
+
+        let delta = frame.luminance() - previous.luminance();
+        let is_flash = delta.abs() > FLASH_LUMINANCE_THRESHOLD;
+
+        if delta.abs() > self.config.max_luminance_change + LIMIT_TOLERANCE {
+            violations.push(SafetyViolation::LuminanceDelta {
+                delta,
+                limit: self.config.max_luminance_change,
+            });
+        }
+
+        // Slide the window forward and count flash transitions inside it
+        let cutoff = frame.timestamp - self.window_duration;
+        while self.flash_times.front().is_some_and(|&time| time <= cutoff) {
+            self.flash_times.pop_front();
+        }
+
+        if is_flash {
+            self.flash_times.push_back(frame.timestamp);
+
+            let flashes_per_second = self.flash_times.len() as f32 / self.window_duration as f32;
+            if flashes_per_second > self.config.max_flash_rate {
+                violations.push(SafetyViolation::FlashRate {
+                    flashes_per_second,
+                    limit: self.config.max_flash_rate,
+                });
+            }
+
+            if self.config.red_flash_protection
+                && (is_dangerous_red(frame.average_color) || is_dangerous_red(previous.average_color))
+            {
+                violations.push(SafetyViolation::RedFlash { luminance_delta: delta });
+            }
+        }
+
+        violations
+    }
+
+    /// Current flash rate over the sliding window
+    pub fn flash_rate(&self) -> f32 {
+        self.flash_times.len() as f32 / self.window_duration as f32
+    }
+
+    pub fn reset(&mut self) {
+        self.previous = None;
+        self.flash_times.clear();
+    }
+}
+
+/// Default screen binning for zoned luminance analysis
+pub const ZONE_COLUMNS: usize = 8;
+pub const ZONE_ROWS: usize = 6;
+
+/// Result of one zoned luminance pass
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ZoneAnalysis {
+    /// Global color multiplier to apply this frame (1.0 = untouched)
+    pub intensity_scale: f32,
+    /// Largest unscaled luminance increase of any region
+    pub worst_region_delta: f32,
+    pub regions_over_budget: usize,
+}
+
+/// Frame-level luminance budget across screen regions
+///
+/// Per-entity limits can't stop many entities brightening together, so the
+/// screen is binned into regions and the whole frame is dimmed whenever any
+/// region brightens faster than the budget. A flash needs an increase and a
+/// decrease, so bounding increases bounds flashes. The previous frame is
+/// remembered as displayed (after scaling), which also rate-limits recovery.
+#[derive(Debug)]
+pub struct ZonedLuminanceAnalyzer {
+    columns: usize,
+    rows: usize,
+    budget: f32,
+    previous: Option<Vec<f32>>,
+}
+
+impl ZonedLuminanceAnalyzer {
+    pub fn new(columns: usize, rows: usize, budget: f32) -> Self {
+        Self {
+            columns: columns.max(1),
+            rows: rows.max(1),
+            budget,
+            previous: None,
+        }
+    }
+
+    pub fn from_config(config: &SafetyConfig) -> Self {
+        Self::new(ZONE_COLUMNS, ZONE_ROWS, config.max_luminance_change)
+    }
+
+    pub fn set_budget(&mut self, budget: f32) {
+        self.budget = budget;
+    }
+
+    /// Average luminance per region, row-major
+    ///
+    /// Triangles are in NDC; each one's area is spread over the regions its
+    /// bounding box overlaps, and uncovered area shows the background color.
+    pub fn region_luminance<I>(&self, triangles: I, background: Vec3) -> Vec<f32>
+    where
+        I: IntoIterator<Item = ([Vec2; 3], Vec3)>,
+    {
+        let zone_count = self.columns * self.rows;
+        let zone_size = Vec2::new(2.0 / self.columns as f32, 2.0 / self.rows as f32);
+        let zone_area = zone_size.x * zone_size.y;
+
+        let mut lit = vec![0.0f32; zone_count];
+        let mut covered = vec![0.0f32; zone_count];
+
+        for (corners, color) in triangles {
+            let [a, b, c] = corners;
+            let area = 0.5 * ((b - a).perp_dot(c - a)).abs();
+            if area <= 0.0 || !area.is_finite() {
+                continue;
+            }
+
+            let min = a.min(b).min(c).clamp(Vec2::splat(-1.0), Vec2::splat(1.0));
+            let max = a.max(b).max(c).clamp(Vec2::splat(-1.0), Vec2::splat(1.0));
+            let box_area = (max.x - min.x) * (max.y - min.y);
+            if box_area <= 0.0 {
+                continue;
+            }
+
+            let luminance = calculate_luminance(&color);
+            let first_col = (((min.x + 1.0) / zone_size.x) as usize).min(self.columns - 1);
+            let last_col = (((max.x + 1.0) / zone_size.x) as usize).min(self.columns - 1);
+            let first_row = (((min.y + 1.0) / zone_size.y) as usize).min(self.rows - 1);
+            let last_row = (((max.y + 1.0) / zone_size.y) as usize).min(self.rows - 1);
+
+            for row in first_row..=last_row {
+                let zone_min_y = -1.0 + row as f32 * zone_size.y;
+                let overlap_y = max.y.min(zone_min_y + zone_size.y) - min.y.max(zone_min_y);
+                for column in first_col..=last_col {
+                    let zone_min_x = -1.0 + column as f32 * zone_size.x;
+                    let overlap_x = max.x.min(zone_min_x + zone_size.x) - min.x.max(zone_min_x);
+                    if overlap_x <= 0.0 || overlap_y <= 0.0 {
+                        continue;
+                    }
+
+                    let share = area * (overlap_x * overlap_y) / box_area;
+                    let zone = row * self.columns + column;
+                    lit[zone] += luminance * share;
+                    covered[zone] += share;
+                }
+            }
+        }
+
+        let background_luminance = calculate_luminance(&background);
+        lit.iter().zip(covered.iter()).map(|(&lit, &covered)| {
+            if covered >= zone_area {
+                // Overdraw - average what was drawn
+                lit / covered
+            } else {
+                (lit + background_luminance * (zone_area - covered)) / zone_area
+            }
+        }).collect()
+    }
+
+    /// Analyze a frame and decide how much to dim it
+    pub fn analyze<I>(&mut self, triangles: I, background: Vec3) -> ZoneAnalysis
+    where
+        I: IntoIterator<Item = ([Vec2; 3], Vec3)>,
+    {
+        let current = self.region_luminance(triangles, background);
+        let mut analysis = ZoneAnalysis {
+            intensity_scale: 1.0,
+            worst_region_delta: 0.0,
+            regions_over_budget: 0,
+        };
+
+        if let Some(previous) = &self.previous {
+            for (&now, &before) in current.iter().zip(previous.iter()) {
+                let delta = now - before;
+                analysis.worst_region_delta = analysis.worst_region_delta.max(delta);
+
+                if delta > self.budget + LIMIT_TOLERANCE {
+                    // Luminance is linear in color, so scaling hits the budget exactly
+                    analysis.regions_over_budget += 1;
+                    analysis.intensity_scale = analysis.intensity_scale.min((before + self.budget) / now);
+                }
+            }
+        }
+
+        let scale = analysis.intensity_scale.clamp(0.0, 1.0);
+        analysis.intensity_scale = scale;
+        self.previous = Some(current.into_iter().map(|luminance| luminance * scale).collect());
+        analysis
+    }
+
+    pub fn reset(&mut self) {
+        self.previous = None;
+    }
+}
+
+/// Run one entity color through the full safety pipeline
+///
+/// Order matters: flash gating, red protection and intensity limiting may all
+/// move the color, so luminance limiting runs last to bound the final change.
+pub fn apply_safety_pipeline(
+    color: Vec3,
+    previous_color: Vec3,
+    current_time: f64,
+    config: &SafetyConfig,
+    flash_tracker: &mut FlashTracker,
+) -> Vec3 {
+    let mut color = color;
+
+    // 1. Apply flash rate limiting
+    let color_change = calculate_luminance(&color) - calculate_luminance(&previous_color);
+    let is_major_change = color_change.abs() > FLASH_LUMINANCE_THRESHOLD;
+
+    if is_major_change {
+        if !flash_tracker.can_allow_flash(current_time, config.max_flash_rate) {
+            // Flash blocked - hold previous color
+            return previous_color;
+        }
+        flash_tracker.record_flash(current_time);
+    }
+
+    // 2. Apply red flash protection - shift dangerous red to safe orange
+    if config.red_flash_protection && is_dangerous_red(color) && is_major_change {
+        let hsv = rgb_to_hsv(color);
+        color = hsv_to_rgb_vec3(Vec3::new(30.0, hsv.y * 0.8, hsv.z));
+    }
+
+    // 3. Apply visual intensity limiting
+    if config.visual_intensity_limit < 1.0 {
+        let safe_color = Vec3::new(0.2, 0.2, 0.2); // Safe dim color
+        color = safe_color.lerp(color, config.visual_intensity_limit);
+    }
+
+    // 4. Apply luminance change limiting
+    limit_luminance_change(color, previous_color, config.max_luminance_change)
+}
+
+// Safety utility functions
+
+pub fn calculate_luminance(color: &Vec3) -> f32 {
+    // Standard luminance calculation (ITU-R BT.709)
+    0.2126 * color.x + 0.7152 * color.y + 0.0722 * color.z
+}
+
+pub fn limit_luminance_change(new_color: Vec3, old_color: Vec3, max_change: f32) -> Vec3 {
+    let old_luminance = calculate_luminance(&old_color);
+    let new_luminance = calculate_luminance(&new_color);
+    let luminance_change = (new_luminance - old_luminance).abs();
+
+    if luminance_change > max_change {
+        // Gradually transition to new color to stay within safe limits
+        let blend_factor = max_change / luminance_change;
+        old_color.lerp(new_color, blend_factor)
+    } else {
+        new_color
+    }
+}
+
+pub fn is_dangerous_red(color: Vec3) -> bool {
+    // Check for dangerous red flashes (high red content with low blue/green)
+    color.x > 0.8 && color.y < 0.3 && color.z < 0.3
+}
+
+pub fn rgb_to_hsv(rgb: Vec3) -> Vec3 {
+    let r = rgb.x;
+    let g = rgb.y;
+    let b = rgb.z;
+
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let delta = max - min;
+
+    let h = if delta == 0.0 {
+        0.0
+    } else if max == r {
+        60.0 * (((g - b) / delta) % 6.0)
+    } else if max == g {
+        60.0 * (((b - r) / delta) + 2.0)
+    } else {
+        60.0 * (((r - g) / delta) + 4.0)
+    };
+    // Magenta-side reds come out negative - wrap into 0..360
+    let h = if h < 0.0 { h + 360.0 } else { h };
+
+    let s = if max == 0.0 { 0.0 } else { delta / max };
+    let v = max;
+
+    Vec3::new(h, s, v)
+}
+
+pub fn hsv_to_rgb_vec3(hsv: Vec3) -> Vec3 {
+    let h = hsv.x;
+    let s = hsv.y;
+    let v = hsv.z;
+
+    let c = v * s;
+    let x = c * (1.0 - ((h / 60.0) % 2.0 - 1.0).abs());
+    let m = v - c;
+
+    let (r, g, b) = if h < 60.0 {
+        (c, x, 0.0)
+    } else if h < 120.0 {
+        (x, c, 0.0)
+    } else if h < 180.0 {
+        (0.0, c, x)
+    } else if h < 240.0 {
+        (0.0, x, c)
+    } else if h < 300.0 {
+        (x, 0.0, c)
+    } else {
+        (c, 0.0, x)
+    };
+
+    Vec3::new(r + m, g + m, b + m)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const FRAME_DT: f64 = 1.0 / 60.0;
+    const EPSILON: f32 = 1e-4;
+
+    fn color_strategy() -> impl Strategy<Value = Vec3> {
+        (0.0f32..=1.0, 0.0f32..=1.0, 0.0f32..=1.0).prop_map(|(r, g, b)| Vec3::new(r, g, b))
+    }
+
+    fn run_pipeline(colors: &[Vec3], config: &SafetyConfig) -> Vec<Vec3> {
+        let mut tracker = FlashTracker::new();
+        let mut previous = Vec3::new(0.1, 0.1, 0.1);
+        colors.iter().enumerate().map(|(frame, &color)| {
+            previous = apply_safety_pipeline(color, previous, frame as f64 * FRAME_DT, config, &mut tracker);
+            previous
+        }).collect()
+    }
+
+    #[test]
+    fn test_steady_frames_have_no_violations() {
+        let mut analyzer = FrameAnalyzer::new(SafetyConfig::default());
+        for frame in 0..120 {
+            let summary = FrameSummary::new(frame as f64 * FRAME_DT, Vec3::new(0.5, 0.2, 0.8));
+            assert!(analyzer.analyze(summary).is_empty());
+        }
+    }
+
+    #[test]
+    fn test_strobe_is_reported() {
+        let mut analyzer = FrameAnalyzer::new(SafetyConfig::default());
+        let mut violations = Vec::new();
+        for frame in 0..60 {
+            let color = if frame % 2 == 0 { Vec3::ZERO } else { Vec3::ONE };
+            violations.extend(analyzer.analyze(FrameSummary::new(frame as f64 * FRAME_DT, color)));
+        }
+
+        assert!(violations.iter().any(|v| matches!(v, SafetyViolation::FlashRate { .. })));
+        assert!(violations.iter().any(|v| matches!(v, SafetyViolation::LuminanceDelta { .. })));
+    }
+
+    #[test]
+    fn test_red_flash_is_reported() {
+        let mut analyzer = FrameAnalyzer::new(SafetyConfig::default());
+        analyzer.analyze(FrameSummary::new(0.0, Vec3::ZERO));
+        let violations = analyzer.analyze(FrameSummary::new(FRAME_DT, Vec3::new(1.0, 0.0, 0.0)));
+
+        assert!(violations.iter().any(|v| matches!(v, SafetyViolation::RedFlash { .. })));
+    }
+
+    #[test]
+    fn test_frame_summary_averages_colors() {
+        let summary = FrameSummary::from_colors(0.0, vec![Vec3::ZERO, Vec3::ONE]);
+        assert!((summary.average_color - Vec3::splat(0.5)).length() < EPSILON);
+        assert_eq!(FrameSummary::from_colors(0.0, Vec::new()).average_color, Vec3::ZERO);
+    }
+
+    fn full_screen(color: Vec3) -> Vec<([Vec2; 3], Vec3)> {
+        vec![
+            ([Vec2::new(-1.0, -1.0), Vec2::new(1.0, -1.0), Vec2::new(-1.0, 1.0)], color),
+            ([Vec2::new(1.0, -1.0), Vec2::new(1.0, 1.0), Vec2::new(-1.0, 1.0)], color),
+        ]
+    }
+
+    #[test]
+    fn test_zoned_analyzer_dims_full_screen_flash() {
+        let mut analyzer = ZonedLuminanceAnalyzer::new(ZONE_COLUMNS, ZONE_ROWS, 0.1);
+        analyzer.analyze(full_screen(Vec3::ZERO), Vec3::ZERO);
+
+        let analysis = analyzer.analyze(full_screen(Vec3::ONE), Vec3::ZERO);
+        assert_eq!(analysis.regions_over_budget, ZONE_COLUMNS * ZONE_ROWS);
+        assert!((analysis.intensity_scale - 0.1).abs() < EPSILON);
+
+        // Recovery is rate limited by the budget too
+        let analysis = analyzer.analyze(full_screen(Vec3::ONE), Vec3::ZERO);
+        assert!((analysis.intensity_scale - 0.2).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_zoned_analyzer_catches_local_flash() {
+        let mut analyzer = ZonedLuminanceAnalyzer::new(4, 4, 0.1);
+        analyzer.analyze(Vec::new(), Vec3::ZERO);
+
+        // A bright patch filling one corner region only
+        let corner = vec![
+            ([Vec2::new(-1.0, -1.0), Vec2::new(-0.5, -1.0), Vec2::new(-1.0, -0.5)], Vec3::ONE),
+            ([Vec2::new(-0.5, -1.0), Vec2::new(-0.5, -0.5), Vec2::new(-1.0, -0.5)], Vec3::ONE),
+        ];
+        let analysis = analyzer.analyze(corner, Vec3::ZERO);
+        assert_eq!(analysis.regions_over_budget, 1);
+        assert!(analysis.intensity_scale < 0.2);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_zoned_scaling_keeps_regions_within_budget(
+            frames in prop::collection::vec(prop::collection::vec(
+                (-1.0f32..1.0, -1.0f32..1.0, 0.05f32..0.8, color_strategy()), 0..12), 1..30),
+        ) {
+            let budget = 0.1;
+            let mut analyzer = ZonedLuminanceAnalyzer::new(4, 3, budget);
+            let mut previous: Option<Vec<f32>> = None;
+
+            for quads in frames {
+                let triangles: Vec<([Vec2; 3], Vec3)> = quads.iter().flat_map(|&(x, y, size, color)| {
+                    let (min, max) = (Vec2::new(x, y), Vec2::new(x + size, y + size));
+                    full_screen(color).into_iter().map(move |(corners, color)| {
+                        (corners.map(|corner| min + (corner + Vec2::ONE) * 0.5 * (max - min)), color)
+                    })
+                }).collect();
+
+                let analysis = analyzer.analyze(triangles.clone(), Vec3::ZERO);
+                let scaled = triangles.into_iter().map(|(corners, color)| (corners, color * analysis.intensity_scale));
+                let displayed = analyzer.region_luminance(scaled, Vec3::ZERO);
+
+                if let Some(previous) = &previous {
+                    for (now, before) in displayed.iter().zip(previous.iter()) {
+                        prop_assert!(now - before <= budget + EPSILON);
+                    }
+                }
+                previous = Some(displayed);
+            }
+        }
+
+        #[test]
+        fn prop_limited_luminance_change_is_bounded(
+            new in color_strategy(),
+            old in color_strategy(),
+            max_change in 0.01f32..0.5,
+        ) {
+            let limited = limit_luminance_change(new, old, max_change);
+            let delta = (calculate_luminance(&limited) - calculate_luminance(&old)).abs();
+            prop_assert!(delta <= max_change + EPSILON);
+        }
+
+        #[test]
+        fn prop_hsv_round_trip(color in color_strategy()) {
+            let round_trip = hsv_to_rgb_vec3(rgb_to_hsv(color));
+            prop_assert!((round_trip - color).abs().max_element() < EPSILON);
+        }
+
+        #[test]
+        fn prop_pipeline_output_never_violates_luminance(
+            colors in prop::collection::vec(color_strategy(), 1..240),
+            safe_mode in any::<bool>(),
+        ) {
+            let config = if safe_mode { SafetyConfig::safe_mode() } else { SafetyConfig::default() };
+            let outputs = run_pipeline(&colors, &config);
+
+            let mut previous = Vec3::new(0.1, 0.1, 0.1);
+            for output in outputs {
+                let delta = (calculate_luminance(&output) - calculate_luminance(&previous)).abs();
+                prop_assert!(delta <= config.max_luminance_change + EPSILON);
+                previous = output;
+            }
+        }
+
+        #[test]
+        fn prop_pipeline_output_passes_frame_analysis(
+            colors in prop::collection::vec(color_strategy(), 1..240),
+        ) {
+            let config = SafetyConfig::default();
+            let outputs = run_pipeline(&colors, &config);
+
+            let mut analyzer = FrameAnalyzer::new(config);
+            analyzer.analyze(FrameSummary::new(-FRAME_DT, Vec3::new(0.1, 0.1, 0.1)));
+            for (frame, output) in outputs.into_iter().enumerate() {
+                let violations = analyzer.analyze(FrameSummary::new(frame as f64 * FRAME_DT, output));
+                prop_assert!(
+                    violations.iter().all(|v| !matches!(v, SafetyViolation::FlashRate { .. } | SafetyViolation::LuminanceDelta { .. })),
+                    "unexpected violations: {:?}", violations
+                );
+            }
+        }
+    }
+}
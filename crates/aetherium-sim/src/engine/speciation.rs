@@ -0,0 +1,133 @@
+// === SPECIATION ===
+// Allied species pack together, so a hive can hold llamas of more than one
+// species. A hive that stays mixed for a minute gives rise to a hybrid
+// species: the two largest species among its members become the parents, the
+// hybrid is registered with traits blended by their shares, and every member
+// joins it, taking on the hybrid's colors and war efficiency.
+
+use std::collections::HashMap;
+use glam::Vec2;
+use tracing::info;
+use crate::core::ecs::{EntityId, World};
+use crate::entities::{HybridId, Llama, SpeciesRegistry, SpeciesType, SPECIES_COUNT};
+use super::consciousness_multiplication::{HiveMind, species_index};
+
+/// Seconds a hive must stay mixed before a hybrid species emerges from it
+pub const HYBRID_EMERGENCE_SECONDS: f32 = 60.0;
+/// Smallest share of the hive each parent species must hold for the hive to count as mixed
+const MIN_PARENT_SHARE: f32 = 0.25;
+
+/// A hybrid species that just emerged from a hive
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpeciesEmergence {
+    pub hybrid: HybridId,
+    pub name: String,
+    pub parents: [SpeciesType; 2], // Dominant parent first
+    pub hive: EntityId,
+    pub founders: Vec<EntityId>,
+}
+
+/// Tracks how long each hive has been mixed and registers the hybrids that emerge
+#[derive(Debug, Clone, Default)]
+pub struct Speciation {
+    pub registry: SpeciesRegistry,
+    mixed_for: HashMap<EntityId, f32>, // Seconds each mixed hive has stayed mixed
+}
+
+impl Speciation {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Advance every mixed hive's clock and turn the hives that have been mixed long enough into hybrid species
+    pub fn update(&mut self, world: &mut World, dt: f32, time: f32) -> Vec<SpeciesEmergence> {
+        let mut mixed_for = HashMap::new();
+        let mut ready = Vec::new();
+        for (hive, mind) in world.query::<HiveMind>() {
+            let Some((parents, dominant_share)) = parent_species(world, &mind.member_entities) else { continue };
+            let seconds = self.mixed_for.get(&hive).copied().unwrap_or(0.0) + dt;
+            if seconds >= HYBRID_EMERGENCE_SECONDS {
+                ready.push((hive, parents, dominant_share, mind.member_entities.clone()));
+            } else {
+                mixed_for.insert(hive, seconds);
+            }
+        }
+        self.mixed_for = mixed_for;
+
+        ready.into_iter().map(|(hive, parents, dominant_share, founders)| {
+            let hybrid = self.registry.register(parents, dominant_share, time);
+            info!(target: "warfare", "🧬 A new species emerged: the {} ({:?} x {:?}, {} founders)",
+                  hybrid.name, parents[0], parents[1], founders.len());
+            let traits = hybrid.traits();
+            let (low, high) = traits.config.base_hue_range;
+            for &founder in &founders {
+                if let Some(llama) = world.get_component_mut::<Llama>(founder) {
+                    llama.species = parents[0];
+                    llama.color = Vec2::new(low + fastrand::f32() * (high - low), traits.config.base_saturation);
+                    llama.war_efficiency = traits.config.war_efficiency;
+                    llama.hybrid = Some(traits.clone());
+                }
+            }
+            SpeciesEmergence { hybrid: traits.id, name: hybrid.name.clone(), parents, hive, founders }
+        }).collect()
+    }
+
+    /// Drop every hive's clock, keeping the registered hybrids; for when the world is swapped out
+    pub fn forget_hives(&mut self) {
+        self.mixed_for.clear();
+    }
+}
+
+/// The two largest species among the members and the larger one's share of both, if the hive is mixed
+fn parent_species(world: &World, members: &[EntityId]) -> Option<([SpeciesType; 2], f32)> {
+    let mut counts = [0usize; SPECIES_COUNT];
+    let mut total = 0;
+    for llama in members.iter().filter_map(|&member| world.get_component::<Llama>(member)) {
+        counts[species_index(llama.species)] += 1;
+        total += 1;
+    }
+    let mut ranked = SpeciesType::ALL;
+    ranked.sort_by_key(|&species| std::cmp::Reverse(counts[species_index(species)]));
+    let (first, second) = (counts[species_index(ranked[0])], counts[species_index(ranked[1])]);
+    (second as f32 >= total as f32 * MIN_PARENT_SHARE && second > 0)
+        .then(|| ([ranked[0], ranked[1]], first as f32 / (first + second) as f32))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mixed_hives_give_rise_to_hybrid_species() {
+        let mut world = World::new();
+        let mut members: Vec<EntityId> = (0..6)
+            .map(|i| world.spawn(Llama::new_with_species(Vec2::new(100.0 + i as f32 * 10.0, 100.0), SpeciesType::DiscoLlama)))
+            .collect();
+        members.extend((0..3).map(|i| world.spawn(Llama::new_with_species(Vec2::new(100.0, 110.0 + i as f32 * 10.0), SpeciesType::HypnoCamel))));
+        let hive = world.spawn(HiveMind {
+            member_entities: members.clone(),
+            collective_consciousness: 5.0,
+            hive_center: Vec2::new(120.0, 120.0),
+            connection_network: Vec::new(),
+            shared_memories: Vec::new(),
+            collective_decision_weight: 0.8,
+            emergence_timestamp: 0.0,
+            pool: 0.0,
+        });
+
+        let mut speciation = Speciation::new();
+        assert!(speciation.update(&mut world, HYBRID_EMERGENCE_SECONDS * 0.6, 10.0).is_empty(), "not mixed for long enough yet");
+        let emerged = speciation.update(&mut world, HYBRID_EMERGENCE_SECONDS * 0.6, 20.0);
+        assert_eq!(emerged.len(), 1);
+        assert_eq!((emerged[0].hive, emerged[0].parents), (hive, [SpeciesType::DiscoLlama, SpeciesType::HypnoCamel]));
+        assert_eq!(speciation.registry.hybrids().len(), 1);
+        for &member in &members {
+            let llama = world.get_component::<Llama>(member).unwrap();
+            assert_eq!(llama.species, SpeciesType::DiscoLlama);
+            assert_eq!(llama.hybrid.as_ref().map(|hybrid| hybrid.id), Some(emerged[0].hybrid));
+        }
+
+        // The hive now holds a single species, so nothing more emerges from it
+        assert!(speciation.update(&mut world, HYBRID_EMERGENCE_SECONDS, 90.0).is_empty());
+    }
+}
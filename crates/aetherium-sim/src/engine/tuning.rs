@@ -0,0 +1,89 @@
+// === CHAOS TUNING ===
+// The dice the emergent systems roll, gathered in one place: how often crystals
+// and reality tears appear, how much of the herd a mutation wave reaches, how
+// readily rivals go to war or the strong start absorbing the weak, how densely
+// a new hive is wired together and how eagerly the meta-observer steps in.
+// Every knob has a `Param` with its safe range, so a tuning panel or host can
+// move them live and a balance found by hand can be written back as defaults.
+
+use crate::params::{Param, CHAOS_CONFLICT_CHANCE, CHAOS_CRYSTAL_RATE, CHAOS_HIVE_CONNECTION, CHAOS_INTERVENTION_CHANCE, CHAOS_MUTATION_SHARE, CHAOS_PREDATION_CHANCE, CHAOS_TEAR_RATE};
+
+/// Probabilities of the stochastic events in the ecosystem and consciousness multiplication
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChaosTuning {
+    pub crystal_rate: f32,        // Crystals appearing per second, doubled at full beat
+    pub tear_rate: f32,           // Reality tears opening per second while chaos runs high
+    pub mutation_share: f32,      // Share of llamas a mutation wave changes
+    pub conflict_chance: f32,     // Per tick, that two rivals close together go to war
+    pub predation_chance: f32,    // Per tick, that a strong llama starts absorbing prey in reach
+    pub hive_connection: f32,     // That any two members of a new hive are linked
+    pub intervention_chance: f32, // Per tick, that the meta-observer acts once it wants to
+}
+
+impl Default for ChaosTuning {
+    fn default() -> Self {
+        let [crystal_rate, tear_rate, mutation_share, conflict_chance, predation_chance, hive_connection, intervention_chance] =
+            Self::PARAMS.map(|param| param.default);
+        Self { crystal_rate, tear_rate, mutation_share, conflict_chance, predation_chance, hive_connection, intervention_chance }
+    }
+}
+
+impl ChaosTuning {
+    /// Every knob's parameter, in the order of `values` and `set`
+    pub const PARAMS: [Param; 7] = [
+        CHAOS_CRYSTAL_RATE, CHAOS_TEAR_RATE, CHAOS_MUTATION_SHARE, CHAOS_CONFLICT_CHANCE,
+        CHAOS_PREDATION_CHANCE, CHAOS_HIVE_CONNECTION, CHAOS_INTERVENTION_CHANCE,
+    ];
+
+    pub fn values(&self) -> [f32; 7] {
+        [self.crystal_rate, self.tear_rate, self.mutation_share, self.conflict_chance,
+         self.predation_chance, self.hive_connection, self.intervention_chance]
+    }
+
+    /// Set the knob at `index` in `PARAMS`, clamped into its range
+    pub fn set(&mut self, index: usize, value: f32) {
+        let Some(param) = Self::PARAMS.get(index) else { return };
+        let value = param.clamp(value);
+        match index {
+            0 => self.crystal_rate = value,
+            1 => self.tear_rate = value,
+            2 => self.mutation_share = value,
+            3 => self.conflict_chance = value,
+            4 => self.predation_chance = value,
+            5 => self.hive_connection = value,
+            _ => self.intervention_chance = value,
+        }
+    }
+
+    /// Every knob forced into its safe range, for setters called while running
+    pub fn clamped(&self) -> Self {
+        let mut tuning = *self;
+        for (index, value) in self.values().into_iter().enumerate() {
+            tuning.set(index, value);
+        }
+        tuning
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_knobs_line_up_with_their_params_and_clamp() {
+        let tuning = ChaosTuning::default();
+        assert_eq!(tuning.values(), ChaosTuning::PARAMS.map(|param| param.default));
+        assert_eq!(tuning.mutation_share, CHAOS_MUTATION_SHARE.default);
+
+        let mut tuning = tuning;
+        for index in 0..ChaosTuning::PARAMS.len() {
+            tuning.set(index, f32::MAX);
+        }
+        assert_eq!(tuning.values(), ChaosTuning::PARAMS.map(|param| param.max));
+        tuning.conflict_chance = -1.0;
+        tuning.tear_rate = f32::NAN;
+        let clamped = tuning.clamped();
+        assert_eq!(clamped.conflict_chance, 0.0);
+        assert_eq!(clamped.tear_rate, CHAOS_TEAR_RATE.default);
+    }
+}
@@ -0,0 +1,128 @@
+// === LLAMA AFFECT ===
+// A llama's mood as two numbers: valence, how good it feels (-1 miserable to
+// 1 elated), and arousal, how stirred up it is (0 placid to 1 frantic).
+// Events push the mood around: a crystal harvest is joy, a nearby war is
+// fear, being held by a hive is belonging. Between events it drifts back to
+// the llama's temperament. The mood shows in how the llama moves, how its
+// color pulses and how its species' voice sounds.
+
+use serde::{Deserialize, Serialize};
+
+/// Seconds for a feeling to fade about two thirds of the way back to rest
+const SETTLE_SECONDS: f32 = 6.0;
+/// Arousal of the most volatile llama at rest; the calmest rests at zero
+const RESTING_AROUSAL: f32 = 0.4;
+/// Saturation pulse rate when placid and when frantic; well under the 3 Hz flash limit
+const PULSE_HZ_CALM: f32 = 0.25;
+const PULSE_HZ_FRANTIC: f32 = 1.5;
+const _: () = assert!(PULSE_HZ_FRANTIC < 3.0);
+/// Largest share of its saturation a llama's pulse swings by
+const PULSE_DEPTH: f32 = 0.25;
+
+/// Something that happened to a llama that it has feelings about
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Feeling {
+    CrystalJoy,    // Harvested a crystal
+    WarfareFear,   // Caught near a conflict between species
+    HiveBelonging, // Held by a hive mind
+    Relief,        // The observer forced a peace
+}
+
+impl Feeling {
+    /// Change of (valence, arousal) one unit of the feeling brings
+    fn push(self) -> (f32, f32) {
+        match self {
+            Feeling::CrystalJoy => (0.6, 0.35),
+            Feeling::WarfareFear => (-0.8, 0.9),
+            Feeling::HiveBelonging => (0.4, -0.3),
+            Feeling::Relief => (0.3, -0.6),
+        }
+    }
+}
+
+/// Valence and arousal of one llama
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Affect {
+    pub valence: f32, // -1..1, unhappy to happy
+    pub arousal: f32, // 0..1, calm to agitated
+}
+
+impl Default for Affect {
+    fn default() -> Self {
+        Self { valence: 0.0, arousal: RESTING_AROUSAL * 0.5 }
+    }
+}
+
+impl Affect {
+    /// A fresh mood for a llama of the given emotional volatility (0-1)
+    pub fn with_temperament(volatility: f32) -> Self {
+        Self { valence: 0.0, arousal: RESTING_AROUSAL * volatility }
+    }
+
+    /// React to a feeling; `amount` is how much of it, volatile llamas feel more
+    pub fn feel(&mut self, feeling: Feeling, amount: f32, volatility: f32) {
+        let (valence, arousal) = feeling.push();
+        let strength = amount * (0.5 + volatility);
+        self.valence = (self.valence + valence * strength).clamp(-1.0, 1.0);
+        self.arousal = (self.arousal + arousal * strength).clamp(0.0, 1.0);
+    }
+
+    /// Drift back towards the resting mood of a llama of this volatility
+    pub fn settle(&mut self, dt: f32, volatility: f32) {
+        let blend = 1.0 - (-dt / SETTLE_SECONDS).exp();
+        self.valence += (0.0 - self.valence) * blend;
+        self.arousal += (RESTING_AROUSAL * volatility - self.arousal) * blend;
+    }
+
+    /// The mood as one 0-1 number, for systems that only take a scalar
+    pub fn resonance(&self) -> f32 {
+        ((self.valence + 1.0) * 0.5 * (0.5 + self.arousal * 0.5)).clamp(0.0, 1.0)
+    }
+
+    /// Multiplier on movement speed: calm llamas amble, agitated ones hurry
+    pub fn pace(&self) -> f32 {
+        0.7 + self.arousal * 0.6
+    }
+
+    /// 0-1 strength of the skittish zigzag of an upset, agitated llama
+    pub fn jitter(&self) -> f32 {
+        (-self.valence).max(0.0) * self.arousal
+    }
+
+    /// Multiplier on saturation at `time`; `phase` keeps llamas from pulsing in unison
+    pub fn saturation_pulse(&self, time: f32, phase: f32) -> f32 {
+        let hz = PULSE_HZ_CALM + (PULSE_HZ_FRANTIC - PULSE_HZ_CALM) * self.arousal;
+        let depth = PULSE_DEPTH * (0.3 + 0.7 * self.valence.abs());
+        1.0 + depth * (time * hz * std::f32::consts::TAU + phase).sin()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_feelings_move_the_mood_and_fade() {
+        let mut affect = Affect::with_temperament(0.5);
+        let resting = affect;
+
+        affect.feel(Feeling::WarfareFear, 1.0, 0.5);
+        assert!(affect.valence < -0.5 && affect.arousal > 0.9);
+        assert!(affect.jitter() > 0.5 && affect.pace() > resting.pace());
+
+        affect.feel(Feeling::CrystalJoy, 2.0, 0.5);
+        assert!(affect.valence > 0.0 && affect.jitter() == 0.0);
+        assert!(affect.resonance() > resting.resonance());
+
+        for _ in 0..600 {
+            affect.settle(0.1, 0.5);
+        }
+        assert!((affect.valence - resting.valence).abs() < 0.01);
+        assert!((affect.arousal - resting.arousal).abs() < 0.01);
+
+        // Even a frantic pulse stays slow and shallow
+        let frantic = Affect { valence: -1.0, arousal: 1.0 };
+        let pulses: Vec<f32> = (0..1000).map(|i| frantic.saturation_pulse(i as f32 * 0.01, 0.0)).collect();
+        assert!(pulses.iter().all(|&p| (1.0 - PULSE_DEPTH..=1.0 + PULSE_DEPTH).contains(&p)));
+    }
+}
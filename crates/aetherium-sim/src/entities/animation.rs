@@ -0,0 +1,300 @@
+// Procedural animation state machine for entities
+// Each llama picks a behavioral state from its AI and consciousness, and the
+// state produces per-frame deformation parameters for the renderer
+
+use crate::entities::llama::Llama;
+use crate::entities::species::ConsciousnessLevel;
+
+/// Seconds to cross-fade between two states
+const BLEND_DURATION: f32 = 0.25;
+/// Minimum time in a calm state before switching to another calm state
+const MIN_DWELL_TIME: f32 = 0.5;
+/// Below this speed (pixels/second) an entity counts as standing still
+const STILL_SPEED: f32 = 12.0;
+
+/// Behavioral animation states, in rough priority order (lowest first)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnimationState {
+    Idle,
+    Grazing,
+    Dancing,
+    Fleeing,
+    Absorbing,
+    Transcending,
+}
+
+impl AnimationState {
+    /// States that interrupt immediately instead of waiting out the dwell time
+    fn is_urgent(&self) -> bool {
+        matches!(self, AnimationState::Fleeing | AnimationState::Absorbing | AnimationState::Transcending)
+    }
+}
+
+/// Everything the state machine needs to know about an entity this frame
+#[derive(Debug, Clone, Copy)]
+pub struct AnimationInputs {
+    pub speed: f32,
+    pub beat_intensity: f32,
+    pub beat_phase: f32, // 0.0 to 1.0 within the current beat
+    pub awareness: f32,
+    pub extinction_pressure: f32,
+    pub is_absorbing: bool,
+    pub is_transcendent: bool,
+    pub consciousness_level: ConsciousnessLevel,
+    pub calm_preference: f32, // 0.0 = restless, 1.0 = prefers grazing
+}
+
+impl AnimationInputs {
+    pub fn from_llama(llama: &Llama, beat_intensity: f32, beat_phase: f32) -> Self {
+        Self {
+            speed: llama.velocity.length(),
+            beat_intensity,
+            beat_phase,
+            awareness: llama.awareness_level,
+            extinction_pressure: llama.extinction_pressure,
+            is_absorbing: llama.predation_target.is_some(),
+            is_transcendent: llama.is_transcendent(),
+            consciousness_level: llama.consciousness_level,
+            calm_preference: 1.0 - llama.exploration_drive,
+        }
+    }
+}
+
+/// Per-frame deformation parameters consumed by the renderer
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AnimationFrame {
+    pub leg_phase: f32,  // Walk cycle position in radians
+    pub leg_swing: f32,  // Maximum leg angle in radians
+    pub neck_bob: f32,   // -1.0 to 1.0
+    pub head_dip: f32,   // 0.0 = head up, 1.0 = head at the ground
+    pub lean: f32,       // Body rotation in radians (positive leans forward)
+    pub lift: f32,       // Vertical offset in local units
+    pub squash: f32,     // Vertical scale (1.0 = rest shape)
+}
+
+impl AnimationFrame {
+    pub const REST: AnimationFrame = AnimationFrame {
+        leg_phase: 0.0,
+        leg_swing: 0.0,
+        neck_bob: 0.0,
+        head_dip: 0.0,
+        lean: 0.0,
+        lift: 0.0,
+        squash: 1.0,
+    };
+
+    fn lerp(&self, other: &AnimationFrame, t: f32) -> AnimationFrame {
+        let mix = |a: f32, b: f32| a + (b - a) * t;
+        AnimationFrame {
+            leg_phase: other.leg_phase, // Shared cycle - never blend phase
+            leg_swing: mix(self.leg_swing, other.leg_swing),
+            neck_bob: mix(self.neck_bob, other.neck_bob),
+            head_dip: mix(self.head_dip, other.head_dip),
+            lean: mix(self.lean, other.lean),
+            lift: mix(self.lift, other.lift),
+            squash: mix(self.squash, other.squash),
+        }
+    }
+}
+
+/// Per-entity animation state machine
+#[derive(Debug, Clone)]
+pub struct AnimationController {
+    state: AnimationState,
+    previous_state: AnimationState,
+    time_in_state: f32,
+    clock: f32,
+    leg_phase: f32,
+    last_inputs: Option<AnimationInputs>,
+}
+
+impl AnimationController {
+    pub fn new() -> Self {
+        Self {
+            state: AnimationState::Idle,
+            previous_state: AnimationState::Idle,
+            time_in_state: BLEND_DURATION, // Start fully blended
+            clock: fastrand::f32() * 10.0, // Desynchronize idle motion between entities
+            leg_phase: fastrand::f32() * std::f32::consts::TAU,
+            last_inputs: None,
+        }
+    }
+
+    pub fn state(&self) -> AnimationState {
+        self.state
+    }
+
+    /// Pick the state an entity should be in, ignoring dwell time
+    pub fn desired_state(&self, inputs: &AnimationInputs) -> AnimationState {
+        if inputs.is_transcendent || inputs.consciousness_level == ConsciousnessLevel::Meta {
+            AnimationState::Transcending
+        } else if inputs.is_absorbing {
+            AnimationState::Absorbing
+        } else if inputs.extinction_pressure > 0.5 {
+            AnimationState::Fleeing
+        } else if inputs.beat_intensity > 0.7
+            && (inputs.awareness > 0.5 || inputs.consciousness_level != ConsciousnessLevel::Individual)
+        {
+            AnimationState::Dancing
+        } else if inputs.speed < STILL_SPEED && inputs.calm_preference > 0.4 {
+            AnimationState::Grazing
+        } else {
+            AnimationState::Idle
+        }
+    }
+
+    /// Advance the state machine by one simulation step
+    pub fn update(&mut self, dt: f32, inputs: &AnimationInputs) {
+        self.clock += dt;
+        self.time_in_state += dt;
+
+        let desired = self.desired_state(inputs);
+        if desired != self.state && (desired.is_urgent() || self.time_in_state >= MIN_DWELL_TIME) {
+            self.previous_state = self.state;
+            self.state = desired;
+            self.time_in_state = 0.0;
+        }
+
+        // Walk cycle advances with ground speed; fleeing scrambles faster
+        let stride_rate = 3.0 + inputs.speed * 0.05;
+        let urgency = if self.state == AnimationState::Fleeing { 1.8 } else { 1.0 };
+        self.leg_phase = (self.leg_phase + stride_rate * urgency * dt) % std::f32::consts::TAU;
+
+        self.last_inputs = Some(*inputs);
+    }
+
+    /// Deformation for the current frame, cross-faded out of the previous state
+    pub fn frame(&self) -> AnimationFrame {
+        let inputs = match &self.last_inputs {
+            Some(inputs) => inputs,
+            None => return AnimationFrame::REST,
+        };
+
+        let current = self.state_frame(self.state, inputs);
+        let blend = (self.time_in_state / BLEND_DURATION).min(1.0);
+        if blend >= 1.0 {
+            return current;
+        }
+        self.state_frame(self.previous_state, inputs).lerp(&current, blend)
+    }
+
+    fn state_frame(&self, state: AnimationState, inputs: &AnimationInputs) -> AnimationFrame {
+        let t = self.clock;
+        let beat_wave = (inputs.beat_phase * std::f32::consts::TAU).sin();
+        let walk_swing = (0.1 + inputs.speed * 0.005).min(0.5);
+
+        let mut frame = AnimationFrame {
+            leg_phase: self.leg_phase,
+            ..AnimationFrame::REST
+        };
+
+        match state {
+            AnimationState::Idle => {
+                // Wandering with a slow breathing bob
+                frame.leg_swing = walk_swing;
+                frame.neck_bob = (t * 1.5).sin() * 0.3;
+                frame.squash = 1.0 + (t * 2.0).sin() * 0.02;
+            }
+            AnimationState::Grazing => {
+                // Head down, chewing, legs planted
+                frame.head_dip = 1.0;
+                frame.neck_bob = (t * 6.0).sin() * 0.3;
+                frame.leg_swing = walk_swing * 0.3;
+            }
+            AnimationState::Dancing => {
+                // Bounce and sway on the beat
+                frame.leg_swing = 0.4;
+                frame.neck_bob = beat_wave * inputs.beat_intensity.min(1.0);
+                frame.lift = beat_wave.abs() * 0.15;
+                frame.lean = (t * 4.0).sin() * 0.2;
+                frame.squash = 1.0 - beat_wave.abs() * 0.06;
+            }
+            AnimationState::Fleeing => {
+                // Long strides, leaning into the run, head forward
+                frame.leg_swing = 0.6;
+                frame.lean = 0.15;
+                frame.head_dip = 0.3;
+            }
+            AnimationState::Absorbing => {
+                // Planted and pulsing while draining a target
+                frame.squash = 1.0 + (t * 8.0).sin() * 0.08;
+                frame.neck_bob = -0.5;
+                frame.lean = 0.1;
+            }
+            AnimationState::Transcending => {
+                // Floating, legs tucked, slow drift
+                frame.lift = 0.2 + (t * 1.2).sin() * 0.05;
+                frame.lean = (t * 0.5).sin() * 0.3;
+                frame.squash = 1.05;
+                frame.neck_bob = (t * 0.8).sin() * 0.4;
+            }
+        }
+
+        frame
+    }
+}
+
+impl Default for AnimationController {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn calm_inputs() -> AnimationInputs {
+        AnimationInputs {
+            speed: 40.0,
+            beat_intensity: 0.2,
+            beat_phase: 0.0,
+            awareness: 0.3,
+            extinction_pressure: 0.0,
+            is_absorbing: false,
+            is_transcendent: false,
+            consciousness_level: ConsciousnessLevel::Individual,
+            calm_preference: 0.8,
+        }
+    }
+
+    #[test]
+    fn test_urgent_states_interrupt_immediately() {
+        let mut controller = AnimationController::new();
+        controller.update(1.0 / 60.0, &calm_inputs());
+        assert_eq!(controller.state(), AnimationState::Idle);
+
+        let fleeing = AnimationInputs { extinction_pressure: 0.9, ..calm_inputs() };
+        controller.update(1.0 / 60.0, &fleeing);
+        assert_eq!(controller.state(), AnimationState::Fleeing);
+    }
+
+    #[test]
+    fn test_calm_states_respect_dwell_time() {
+        let mut controller = AnimationController::new();
+        controller.update(1.0 / 60.0, &calm_inputs());
+
+        // Stop moving - grazing only starts after the dwell time
+        let still = AnimationInputs { speed: 0.0, ..calm_inputs() };
+        controller.update(0.1, &still);
+        assert_eq!(controller.state(), AnimationState::Idle);
+
+        controller.update(MIN_DWELL_TIME, &still);
+        assert_eq!(controller.state(), AnimationState::Grazing);
+    }
+
+    #[test]
+    fn test_frame_blends_between_states() {
+        let mut controller = AnimationController::new();
+        controller.update(MIN_DWELL_TIME, &AnimationInputs { speed: 0.0, ..calm_inputs() });
+        assert_eq!(controller.state(), AnimationState::Grazing);
+
+        // Partway through the cross-fade the head is only partway down
+        controller.update(BLEND_DURATION * 0.5, &AnimationInputs { speed: 0.0, ..calm_inputs() });
+        let head_dip = controller.frame().head_dip;
+        assert!(head_dip > 0.0 && head_dip < 1.0);
+
+        controller.update(BLEND_DURATION, &AnimationInputs { speed: 0.0, ..calm_inputs() });
+        assert_eq!(controller.frame().head_dip, 1.0);
+    }
+}
@@ -0,0 +1,127 @@
+// === CRYSTAL ABILITIES ===
+// Harvesting a crystal leaves the llama with a gift that depends on the kind
+// of crystal: Resonance keeps it humming, Chaos keeps prime chaos high, Social
+// widens the reach at which it packs up, Quantum lets even non-quantum species
+// tunnel. Gifts fade over a few seconds. Memory and Chaos crystals also act on
+// the world at harvest time; step_llamas handles those through `recall_crystals`
+// and by tearing reality at the harvest site.
+
+use glam::Vec2;
+use crate::entities::{Llama, MemoryKind};
+use crate::simulation::CrystalType;
+
+/// Seconds a freshly harvested ability lasts
+pub const ABILITY_SECONDS: f32 = 20.0;
+/// Extra pack reach at full Social strength, in world units
+const MAX_PACK_REACH_BONUS: f32 = 60.0;
+/// Longest quantum jump of a charged non-quantum llama, in world units
+const TUNNEL_DISTANCE: f32 = 80.0;
+/// Crystals further away than this are not revealed by a Memory crystal
+const RECALL_RADIUS: f32 = 600.0;
+
+/// A lasting effect of one harvested crystal
+#[derive(Debug, Clone, PartialEq)]
+pub struct CrystalAbility {
+    pub crystal_type: CrystalType,
+    pub strength: f32,  // 0-1
+    pub remaining: f32, // Seconds left
+}
+
+impl Llama {
+    /// Gain or refresh the ability of a crystal type; stronger harvests last at full strength
+    pub fn gain_crystal_ability(&mut self, crystal_type: CrystalType, harvested: f32) {
+        let strength = (harvested * 2.0).clamp(0.1, 1.0);
+        if let Some(ability) = self.crystal_abilities.iter_mut().find(|ability| ability.crystal_type == crystal_type) {
+            ability.strength = ability.strength.max(strength);
+            ability.remaining = ABILITY_SECONDS;
+            return;
+        }
+        self.crystal_abilities.push(CrystalAbility { crystal_type, strength, remaining: ABILITY_SECONDS });
+    }
+
+    /// Current strength of an ability, 0 when the llama does not hold it
+    pub fn ability_strength(&self, crystal_type: CrystalType) -> f32 {
+        self.crystal_abilities.iter()
+            .find(|ability| ability.crystal_type == crystal_type)
+            .map_or(0.0, |ability| ability.strength * (ability.remaining / ABILITY_SECONDS).min(1.0))
+    }
+
+    /// Extra distance at which this llama gathers others into a pack
+    pub fn pack_reach_bonus(&self) -> f32 {
+        self.ability_strength(CrystalType::Social) * MAX_PACK_REACH_BONUS
+    }
+
+    /// Apply held abilities for this tick and let them fade; runs after the chaos engine
+    /// has recomputed resonance and prime chaos so the boosts sit on top of them
+    pub fn apply_crystal_abilities(&mut self, dt: f32) {
+        self.harmonic_resonance += self.ability_strength(CrystalType::Resonance) * 0.5;
+        self.prime_chaos_factor = (self.prime_chaos_factor + self.ability_strength(CrystalType::Chaos) * 0.4).min(1.0);
+
+        // Charged non-quantum species flicker through space the way Quantum Sheep do
+        let charge = self.ability_strength(CrystalType::Quantum);
+        if charge > 0.0 && !self.species_config().quantum_affinity {
+            self.quantum_state = charge;
+            if fastrand::f32() < charge * dt * 0.5 {
+                let angle = fastrand::f32() * std::f32::consts::TAU;
+                self.position += Vec2::new(angle.cos(), angle.sin()) * TUNNEL_DISTANCE * charge;
+            }
+        }
+
+        for ability in &mut self.crystal_abilities {
+            ability.remaining -= dt;
+        }
+        self.crystal_abilities.retain(|ability| ability.remaining > 0.0);
+        if self.ability_strength(CrystalType::Quantum) == 0.0 && !self.species_config().quantum_affinity {
+            self.quantum_state = 0.0;
+        }
+    }
+
+    /// Memory crystals reveal where the other crystals are
+    pub fn recall_crystals(&mut self, crystal_positions: &[Vec2]) {
+        for &position in crystal_positions {
+            let distance = self.position.distance(position);
+            if distance > 1.0 && distance < RECALL_RADIUS {
+                self.remember(position, MemoryKind::CrystalFound, 0.6 * (1.0 - distance / RECALL_RADIUS) + 0.2);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::SpeciesType;
+    use crate::simulation::ConsciousnessCrystal;
+
+    fn harvest(llama: &mut Llama, crystal_type: CrystalType) {
+        let mut crystal = ConsciousnessCrystal::new(llama.position, crystal_type);
+        crystal.consciousness_energy = 2.0;
+        assert!(llama.try_harvest_crystal(&mut crystal));
+    }
+
+    #[test]
+    fn test_each_crystal_grants_its_own_ability() {
+        let mut llama = Llama::new_with_species(Vec2::new(400.0, 400.0), SpeciesType::DiscoLlama);
+        let reach = llama.pack_reach_bonus();
+        harvest(&mut llama, CrystalType::Social);
+        assert!(llama.pack_reach_bonus() > reach);
+
+        harvest(&mut llama, CrystalType::Quantum);
+        llama.harmonic_resonance = 0.0;
+        llama.prime_chaos_factor = 0.0;
+        harvest(&mut llama, CrystalType::Resonance);
+        harvest(&mut llama, CrystalType::Chaos);
+        llama.apply_crystal_abilities(0.1);
+        assert!(llama.harmonic_resonance > 0.0 && llama.prime_chaos_factor > 0.0);
+        assert!(llama.quantum_state > 0.0, "a disco llama should hold a quantum charge");
+
+        llama.memory_fragments.clear();
+        llama.recall_crystals(&[Vec2::new(700.0, 400.0), Vec2::new(1190.0, 790.0)]);
+        assert_eq!(llama.memory_fragments.len(), 1, "only nearby crystals are revealed");
+
+        llama.apply_crystal_abilities(ABILITY_SECONDS);
+        assert!(llama.crystal_abilities.is_empty());
+        assert_eq!(llama.pack_reach_bonus(), 0.0);
+        assert_eq!(llama.quantum_state, 0.0);
+    }
+}
@@ -0,0 +1,173 @@
+// === FLOCKING ===
+// Classic boid steering layered on top of each llama's own drives, so herds
+// move as herds instead of as independent random walks. Every llama keeps its
+// distance from whoever is crowding it, matches the heading of its own kind
+// and drifts towards their center; hypno camels circle that center instead of
+// closing on it. Each species weighs the three rules differently, and the
+// neighbors come from a spatial hash so a tick stays linear in the herd size.
+
+use glam::Vec2;
+use serde::{Deserialize, Serialize};
+use crate::core::spatial::SpatialHash;
+use crate::entities::{Llama, SpeciesType, SPECIES_COUNT};
+use crate::params::{FLOCK_ALIGNMENT, FLOCK_FORCE, FLOCK_RADIUS};
+
+/// Neighbors closer than this share of the flocking radius are pushed away
+const SEPARATION_SHARE: f32 = 0.4;
+/// Largest acceleration flocking alone gives a llama, in world units per second squared
+const MAX_FLOCK_FORCE: f32 = 90.0;
+
+/// How strongly one species follows each boid rule
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct FlockingWeights {
+    pub separation: f32, // Push away from crowding neighbors of any species
+    pub alignment: f32,  // Share of the velocity difference to kin matched per second
+    pub cohesion: f32,   // Pull towards the center of nearby kin
+    pub orbit: f32,      // Swirl around that center instead
+    pub radius: f32,     // How far a llama looks for its flock, in world units
+}
+
+impl FlockingWeights {
+    pub fn new(separation: f32, alignment: f32, cohesion: f32, orbit: f32, radius: f32) -> Self {
+        Self {
+            separation: FLOCK_FORCE.clamp(separation),
+            alignment: FLOCK_ALIGNMENT.clamp(alignment),
+            cohesion: FLOCK_FORCE.clamp(cohesion),
+            orbit: FLOCK_FORCE.clamp(orbit),
+            radius: FLOCK_RADIUS.clamp(radius),
+        }
+    }
+
+    fn clamped(self) -> Self {
+        Self::new(self.separation, self.alignment, self.cohesion, self.orbit, self.radius)
+    }
+
+    /// Disco llamas flock tightly, quantum sheep barely notice each other,
+    /// hypno camels orbit their herd and vicunas march in loose columns
+    pub fn for_species(species: SpeciesType) -> Self {
+        match species {
+            SpeciesType::DiscoLlama => Self::new(30.0, 0.8, 25.0, 0.0, 90.0),
+            SpeciesType::QuantumSheep => Self::new(20.0, 0.1, 4.0, 0.0, 60.0),
+            SpeciesType::HypnoCamel => Self::new(25.0, 0.3, 6.0, 30.0, 110.0),
+            SpeciesType::BassDropVicuna => Self::new(35.0, 0.5, 15.0, 0.0, 80.0),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlockingConfig {
+    pub weights: [FlockingWeights; SPECIES_COUNT], // Indexed by SpeciesType::to_index
+}
+
+impl Default for FlockingConfig {
+    fn default() -> Self {
+        Self { weights: SpeciesType::ALL.map(FlockingWeights::for_species) }
+    }
+}
+
+impl FlockingConfig {
+    /// No flocking at all; llamas move on their own drives alone
+    pub fn disabled() -> Self {
+        Self { weights: [FlockingWeights::new(0.0, 0.0, 0.0, 0.0, FLOCK_RADIUS.default); SPECIES_COUNT] }
+    }
+
+    pub fn with_species(mut self, species: SpeciesType, weights: FlockingWeights) -> Self {
+        self.weights[species.to_index()] = weights.clamped();
+        self
+    }
+
+    /// Every weight forced into its safe range, for setters called while running
+    pub fn clamped(&self) -> Self {
+        Self { weights: self.weights.map(|weights| weights.clamped()) }
+    }
+
+    /// Widest flocking radius of any species, the reach neighbor queries need
+    pub fn max_radius(&self) -> f32 {
+        self.weights.iter().map(|weights| weights.radius).fold(0.0, f32::max)
+    }
+}
+
+/// Steering acceleration the flock puts on `llamas[index]`; `index_of_llamas` holds every
+/// llama's slot by position, and `neighbors` is reused scratch space
+pub fn flocking_force(llamas: &[Llama], index: usize, index_of_llamas: &SpatialHash, config: &FlockingConfig, neighbors: &mut Vec<usize>) -> Vec2 {
+    let me = &llamas[index];
+    let weights = config.weights[me.species.to_index()];
+    index_of_llamas.query_into(me.position, weights.radius, neighbors);
+
+    let separation_radius = weights.radius * SEPARATION_SHARE;
+    let mut separation = Vec2::ZERO;
+    let (mut kin, mut kin_center, mut kin_velocity) = (0, Vec2::ZERO, Vec2::ZERO);
+    for &slot in neighbors.iter().filter(|&&slot| slot != index) {
+        let other = &llamas[slot];
+        let away = me.position - other.position;
+        let distance = away.length();
+        if distance >= weights.radius || distance < 0.01 {
+            continue;
+        }
+        if distance < separation_radius {
+            separation += away / distance * (1.0 - distance / separation_radius);
+        }
+        if other.species == me.species {
+            kin += 1;
+            kin_center += other.position;
+            kin_velocity += other.velocity;
+        }
+    }
+
+    let mut force = separation * weights.separation;
+    if kin > 0 {
+        let to_center = kin_center / kin as f32 - me.position;
+        let pull = (to_center.length() / weights.radius).min(1.0);
+        let inward = to_center.normalize_or_zero();
+        force += inward * pull * weights.cohesion;
+        force += inward.perp() * weights.orbit;
+        force += (kin_velocity / kin as f32 - me.velocity) * weights.alignment;
+    }
+    force.clamp_length_max(MAX_FLOCK_FORCE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn herd(species: SpeciesType, positions: &[Vec2], velocity: Vec2) -> Vec<Llama> {
+        positions.iter().map(|&position| {
+            let mut llama = Llama::new_with_species(position, species);
+            llama.velocity = velocity;
+            llama
+        }).collect()
+    }
+
+    #[test]
+    fn test_boid_rules_follow_species_weights() {
+        let config = FlockingConfig::default();
+        let mut scratch = Vec::new();
+        let force_on = |llamas: &[Llama], index: usize, scratch: &mut Vec<usize>| {
+            let hash = SpatialHash::from_positions(config.max_radius(), llamas.iter().map(|llama| llama.position));
+            flocking_force(llamas, index, &hash, &config, scratch)
+        };
+
+        // A straggler is pulled towards its herd and turned to their heading
+        let mut disco = herd(SpeciesType::DiscoLlama, &[Vec2::new(500.0, 400.0), Vec2::new(510.0, 420.0), Vec2::new(560.0, 400.0)], Vec2::new(0.0, 40.0));
+        disco[2].velocity = Vec2::ZERO;
+        let straggler = force_on(&disco, 2, &mut scratch);
+        assert!(straggler.x < 0.0 && straggler.y > 0.0, "{:?}", straggler);
+
+        // Two llamas on top of each other are pushed apart, whatever their species
+        let mut crowd = herd(SpeciesType::DiscoLlama, &[Vec2::new(600.0, 400.0)], Vec2::ZERO);
+        crowd.extend(herd(SpeciesType::QuantumSheep, &[Vec2::new(605.0, 400.0)], Vec2::ZERO));
+        assert!(force_on(&crowd, 0, &mut scratch).x < 0.0);
+        assert!(force_on(&crowd, 1, &mut scratch).x > 0.0);
+
+        // Camels circle their herd instead of closing in
+        let camels = herd(SpeciesType::HypnoCamel, &[Vec2::new(300.0, 300.0), Vec2::new(380.0, 300.0)], Vec2::ZERO);
+        let swirl = force_on(&camels, 0, &mut scratch);
+        assert!(swirl.y.abs() > swirl.x.abs(), "{:?}", swirl);
+
+        // Far-off llamas and a disabled config leave the herd alone
+        let apart = herd(SpeciesType::DiscoLlama, &[Vec2::new(100.0, 100.0), Vec2::new(900.0, 700.0)], Vec2::ZERO);
+        assert_eq!(force_on(&apart, 0, &mut scratch), Vec2::ZERO);
+        let hash = SpatialHash::from_positions(60.0, disco.iter().map(|llama| llama.position));
+        assert_eq!(flocking_force(&disco, 2, &hash, &FlockingConfig::disabled(), &mut scratch), Vec2::ZERO);
+    }
+}
@@ -2,10 +2,19 @@
 // Extracted from simple.rs for better modularity
 
 use glam::Vec2;
-use crate::entities::species::{SpeciesType, ConsciousnessLevel};
+use crate::core::ecs::EntityId;
+use crate::entities::affect::{Affect, Feeling};
+use crate::entities::species::{SpeciesType, SpeciesConfig, ConsciousnessLevel};
+use crate::entities::species_registry::HybridTraits;
+use crate::entities::animation::{AnimationController, AnimationInputs};
+use crate::entities::memory::{MemoryFragment, MemoryKind};
+use crate::entities::crystal_abilities::CrystalAbility;
 use crate::engine::ChaosDecisionEngine;
 use crate::simulation::{CrystalType, ZoneType, ConsciousnessCrystal, TerritoryEffects};
 
+/// Crystal energy a llama can carry before the metabolism turns it into energy
+const MAX_ENERGY_INTAKE: f32 = 4.0;
+
 /// Main llama entity with all consciousness and behavioral systems
 #[derive(Clone)]
 pub struct Llama {
@@ -18,19 +27,20 @@ pub struct Llama {
 
     // Phase 1: Consciousness Depth Enhancement
     pub awareness_level: f32,           // 0.0-1.0 consciousness depth
-    pub memory_fragments: Vec<Vec2>,    // Historical position memories
-    pub social_bonds: Vec<usize>,       // Connections to other llamas
+    pub memory_fragments: Vec<MemoryFragment>, // What happened where, fading over time
+    pub social_bonds: Vec<EntityId>,    // Connections to other llamas
     pub personality_matrix: [f32; 7],   // 7-dimensional personality traits
     pub reality_distortion: f32,        // Local space-time manipulation factor
 
     // Internal consciousness state
-    pub emotional_state: f32,           // Current emotional resonance
+    pub affect: Affect,                 // Valence and arousal, pushed around by what happens to it
     pub memory_intensity: f32,          // How strongly memories are forming
     pub social_attraction: f32,         // Desire to be near other llamas
     pub exploration_drive: f32,         // Tendency to seek new areas
 
     // Phase 2: Mathematical Chaos Engine
     pub species: SpeciesType,           // Species determines behavior patterns
+    pub hybrid: Option<HybridTraits>,   // Traits of the hybrid species it belongs to, in place of its species' base config
     pub chaos_engine: ChaosDecisionEngine, // 11D decision engine
     pub quantum_state: f32,             // Quantum superposition factor
     pub harmonic_resonance: f32,        // Musical mathematics coupling
@@ -38,20 +48,36 @@ pub struct Llama {
 
     // Phase 3: Ecosystem Emergence
     pub harvested_crystals: Vec<CrystalType>, // Types of crystals harvested
+    pub crystals_harvested: u32,        // Lifetime harvest count
+    pub crystal_abilities: Vec<CrystalAbility>, // Fading gifts of recently harvested crystals
     pub mutation_count: u32,            // How many mutations this llama has undergone
     pub environmental_consciousness: f32, // Consciousness absorbed from environment
     pub territory_affinity: Option<ZoneType>, // Preferred territory type
 
     // Phase 5: Consciousness Multiplication
     pub consciousness_level: ConsciousnessLevel, // Individual/Pack/Hive/Meta hierarchy
-    pub collective_id: Option<usize>,     // ID of collective consciousness if part of one
+    pub collective_id: Option<EntityId>,  // Hierarchy entity if part of a collective
     pub territorial_dominance: f32,       // Individual territorial control strength
     pub warfare_participation: f32,       // How actively engaged in species warfare
     pub absorption_resistance: f32,       // Resistance to consciousness predation
     pub hive_connection_strength: f32,    // Strength of connection to hive mind
-    pub predation_target: Option<usize>,  // Current target for consciousness absorption
+    pub predation_target: Option<EntityId>, // Current target for consciousness absorption
     pub extinction_pressure: f32,         // Environmental pressure affecting this entity
+    pub extinct_time: f32,                // Seconds spent extinct, collected after a grace period
     pub war_efficiency: f32,              // Combat effectiveness in consciousness warfare
+    pub generosity: f32,                  // 0-1 readiness to give surplus consciousness to struggling kin
+
+    // Procedural animation
+    pub animation: AnimationController,   // Behavioral animation state machine
+    pub tempo_bpm: f32,                   // The beat engine's primary rhythm, handed over each tick for disco llamas to step on
+
+    // Observer possession
+    pub possessed_steering: Option<Vec2>, // Direction the observer drives it in; None under its own control
+
+    // Metabolism
+    pub energy: f32,                      // 0-1 reserve spent moving and fighting
+    pub energy_intake: f32,               // Crystal energy harvested since the metabolism last ran
+    pub starving_time: f32,               // Seconds spent with no energy left
 }
 
 impl Llama {
@@ -97,13 +123,14 @@ impl Llama {
             reality_distortion: personality_matrix[5] * 0.5, // Based on reality sensitivity
 
             // Internal consciousness state
-            emotional_state: personality_matrix[4] * 0.7,     // Based on emotional volatility
+            affect: Affect::with_temperament(personality_matrix[4]), // Based on emotional volatility
             memory_intensity: personality_matrix[3] * 0.6,    // Based on memory strength
             social_attraction: personality_matrix[1] * 0.8,   // Based on sociability
             exploration_drive: personality_matrix[6] * 0.8,   // Based on exploration trait
 
             // Phase 2: Mathematical Chaos Engine
             species,
+            hybrid: None,
             chaos_engine: ChaosDecisionEngine::new(),
             quantum_state: if config.quantum_affinity { fastrand::f32() } else { 0.0 },
             harmonic_resonance: 0.0,
@@ -111,6 +138,8 @@ impl Llama {
 
             // Phase 3: Ecosystem Emergence
             harvested_crystals: Vec::new(),
+            crystals_harvested: 0,
+            crystal_abilities: Vec::new(),
             mutation_count: 0,
             environmental_consciousness: 0.0,
             territory_affinity: None,
@@ -124,7 +153,29 @@ impl Llama {
             hive_connection_strength: 0.0,
             predation_target: None,
             extinction_pressure: 0.0,
+            extinct_time: 0.0,
             war_efficiency: config.war_efficiency,
+            generosity: personality_matrix[1] * (1.0 - personality_matrix[2] * 0.5), // Sociable, tempered by chaos affinity
+
+            // Procedural animation
+            animation: AnimationController::new(),
+            tempo_bpm: 120.0,
+
+            // Observer possession
+            possessed_steering: None,
+
+            // Metabolism
+            energy: 1.0,
+            energy_intake: 0.0,
+            starving_time: 0.0,
+        }
+    }
+
+    /// Its hybrid species' traits if it has one, else its species' base config
+    pub fn species_config(&self) -> SpeciesConfig {
+        match &self.hybrid {
+            Some(hybrid) => hybrid.config.clone(),
+            None => self.species.get_base_config(),
         }
     }
 
@@ -135,69 +186,85 @@ impl Llama {
 
     /// Try to harvest a consciousness crystal
     pub fn try_harvest_crystal(&mut self, crystal: &mut ConsciousnessCrystal) -> bool {
-        let distance = self.position.distance(crystal.position);
-        if distance < crystal.harvest_radius {
-            let harvest_amount = crystal.get_harvest_amount();
-            if harvest_amount > 0.1 {
-                let harvested = crystal.harvest(harvest_amount);
-
-                // Apply crystal effects based on type
-                match crystal.crystal_type {
-                    CrystalType::Memory => {
-                        self.memory_intensity += harvested * 0.3;
-                        self.awareness_level += harvested * 0.2;
-                        if !self.memory_fragments.contains(&crystal.position) {
-                            self.memory_fragments.push(crystal.position);
-                        }
-                    },
-                    CrystalType::Social => {
-                        self.social_attraction += harvested * 0.4;
-                        self.emotional_state += harvested * 0.2;
-                    },
-                    CrystalType::Quantum => {
-                        if self.species.get_base_config().quantum_affinity {
-                            self.quantum_state += harvested * 0.3;
-                        }
-                        self.consciousness += harvested * 0.4;
-                    },
-                    CrystalType::Resonance => {
-                        self.harmonic_resonance += harvested * 0.5;
-                        self.trip_intensity += harvested * 0.3;
-                        self.consciousness += harvested * 0.2;
-                    },
-                    CrystalType::Chaos => {
-                        self.exploration_drive += harvested * 0.4;
-                        self.reality_distortion += harvested * 0.3;
-                        self.prime_chaos_factor += harvested * 0.2;
-                    },
-                }
-
-                // Store crystal type for mutations
-                if !self.harvested_crystals.contains(&crystal.crystal_type) {
-                    self.harvested_crystals.push(crystal.crystal_type.clone());
-                }
-
-                return true;
+        self.position.distance(crystal.position) < crystal.harvest_radius && self.absorb_crystal(crystal)
+    }
+
+    /// Harvest a crystal wherever it is; false when it has too little energy left
+    pub fn absorb_crystal(&mut self, crystal: &mut ConsciousnessCrystal) -> bool {
+        let harvest_amount = crystal.get_harvest_amount();
+        if harvest_amount > 0.1 {
+            let harvested = crystal.harvest(harvest_amount);
+
+            // Apply crystal effects based on type
+            match crystal.crystal_type {
+                CrystalType::Memory => {
+                    self.memory_intensity += harvested * 0.3;
+                    self.awareness_level += harvested * 0.2;
+                },
+                CrystalType::Social => {
+                    self.social_attraction += harvested * 0.4;
+                },
+                CrystalType::Quantum => {
+                    // Every species takes the charge; non-quantum ones tunnel while it lasts
+                    self.quantum_state = (self.quantum_state + harvested * 0.3) % 1.0;
+                    self.consciousness += harvested * 0.4;
+                },
+                CrystalType::Resonance => {
+                    self.harmonic_resonance += harvested * 0.5;
+                    self.trip_intensity += harvested * 0.3;
+                    self.consciousness += harvested * 0.2;
+                },
+                CrystalType::Chaos => {
+                    self.exploration_drive += harvested * 0.4;
+                    self.reality_distortion += harvested * 0.3;
+                    self.prime_chaos_factor += harvested * 0.2;
+                },
             }
+            // Every harvest is a joy; social crystals doubly so
+            let joy = if crystal.crystal_type == CrystalType::Social { harvested * 2.0 } else { harvested };
+            self.feel(Feeling::CrystalJoy, joy);
+            self.gain_crystal_ability(crystal.crystal_type.clone(), harvested);
+            self.energy_intake = (self.energy_intake + harvested).min(MAX_ENERGY_INTAKE);
+
+            // Memory crystals are remembered best
+            let vividness = if crystal.crystal_type == CrystalType::Memory { 1.0 } else { 0.5 + harvested };
+            self.remember(crystal.position, MemoryKind::CrystalFound, vividness);
+
+            // Store crystal type for mutations
+            self.crystals_harvested += 1;
+            if !self.harvested_crystals.contains(&crystal.crystal_type) {
+                self.harvested_crystals.push(crystal.crystal_type.clone());
+            }
+
+            return true;
         }
         false
     }
 
-    /// Apply territory effects to the llama
+    /// React to something that happened, as strongly as its emotional volatility makes it
+    pub fn feel(&mut self, feeling: Feeling, amount: f32) {
+        self.affect.feel(feeling, amount, self.personality_matrix[4]);
+    }
+
+    /// Apply territory effects to the llama; call after the behavior update, since
+    /// resonance and prime chaos are recomputed there and the zone lifts them as levels
     pub fn apply_territory_effects(&mut self, territory_effects: &TerritoryEffects, dt: f32) {
-        self.harmonic_resonance += territory_effects.harmonic_boost * dt * 0.1;
+        self.harmonic_resonance += territory_effects.harmonic_boost * 0.5;
         self.social_attraction += territory_effects.social_boost * dt * 0.05;
-        self.prime_chaos_factor += territory_effects.chaos_boost * dt * 0.08;
+        self.prime_chaos_factor += territory_effects.chaos_boost * 0.3;
         self.reality_distortion += territory_effects.reality_distortion_boost * dt * 0.06;
         self.memory_intensity += territory_effects.memory_boost * dt * 0.04;
         self.awareness_level += territory_effects.consciousness_growth_boost * dt * 0.02;
 
-        if self.species.get_base_config().quantum_affinity {
+        if self.species_config().quantum_affinity {
             self.quantum_state += territory_effects.quantum_boost * dt * 0.1;
         }
 
         self.exploration_drive += territory_effects.exploration_boost * dt * 0.03;
 
+        // Voids eat consciousness; time slowdown is applied by the caller to the llama's own dt
+        self.consciousness = (self.consciousness * (1.0 - territory_effects.consciousness_drain * dt * 0.1)).max(0.0);
+
         // Clamp values to reasonable ranges
         self.harmonic_resonance = self.harmonic_resonance.clamp(0.0, 2.0);
         self.social_attraction = self.social_attraction.clamp(0.0, 1.0);
@@ -207,7 +274,7 @@ impl Llama {
         self.awareness_level = self.awareness_level.clamp(0.0, 1.0);
         self.exploration_drive = self.exploration_drive.clamp(0.0, 1.0);
 
-        if self.species.get_base_config().quantum_affinity {
+        if self.species_config().quantum_affinity {
             self.quantum_state = self.quantum_state % 1.0;
         }
     }
@@ -231,7 +298,7 @@ impl Llama {
                 },
                 CrystalType::Quantum => {
                     // Quantum evolution (especially for non-quantum species)
-                    if !self.species.get_base_config().quantum_affinity {
+                    if !self.species_config().quantum_affinity {
                         self.quantum_state += mutation_strength * 0.2;
                     }
                     self.consciousness += mutation_strength * 0.5;
@@ -271,8 +338,14 @@ impl Llama {
 
     /// Main update method called from simulation loop
     pub fn update(&mut self, dt: f32, beat_intensity: f32, all_llamas: &[Llama],
-                  my_index: usize, cosmic_time: f64) {
-        self.update_behavior(dt, beat_intensity, all_llamas, my_index, cosmic_time);
+                  all_ids: &[EntityId], my_index: usize, cosmic_time: f64) {
+        self.update_behavior(dt, beat_intensity, all_llamas, all_ids, my_index, cosmic_time);
+    }
+
+    /// Advance the animation state machine from current behavior
+    pub fn update_animation(&mut self, dt: f32, beat_intensity: f32, beat_phase: f32) {
+        let inputs = AnimationInputs::from_llama(self, beat_intensity, beat_phase);
+        self.animation.update(dt, &inputs);
     }
 }
 
@@ -2,14 +2,25 @@
 // Extracted from simple.rs for better modularity
 
 use glam::Vec2;
-use crate::entities::{SpeciesType, Llama};
+use crate::core::ecs::EntityId;
+use crate::entities::{SpeciesType, Llama, MemoryKind};
+use crate::entities::movement::entrainment;
 use crate::engine::{LlamaSnapshot, DecisionVector};
 
+/// Vicuna saturation never exceeds this, keeping their reds out of red-flash range
+const BASS_DROP_MAX_SATURATION: f32 = 0.6;
+/// Below this much energy a llama is exhausted and starts to slow and dim
+const EXHAUSTION_THRESHOLD: f32 = 0.25;
+/// Share of its normal pace a llama keeps with no energy left
+const EXHAUSTED_PACE: f32 = 0.3;
+/// Random push on a terrified llama, per second
+const SKITTER_FORCE: f32 = 400.0;
+
 /// Comprehensive llama behavior system implementation
 impl Llama {
     /// Main behavior update function that orchestrates all llama behaviors
     pub fn update_behavior(&mut self, dt: f32, beat_intensity: f32, all_llamas: &[Llama],
-                          my_index: usize, cosmic_time: f64) {
+                          all_ids: &[EntityId], my_index: usize, cosmic_time: f64) {
         // === Phase 2: Mathematical Chaos Engine Update ===
         let snapshot = LlamaSnapshot {
             color: self.color,
@@ -17,7 +28,7 @@ impl Llama {
             social_attraction: self.social_attraction,
             exploration_drive: self.exploration_drive,
             reality_distortion: self.reality_distortion,
-            emotional_state: self.emotional_state,
+            emotional_state: self.affect.resonance(),
             memory_intensity: self.memory_intensity,
             consciousness: self.consciousness,
         };
@@ -31,13 +42,14 @@ impl Llama {
             self.quantum_state = (self.quantum_state + dt * 2.0 + self.prime_chaos_factor) % 1.0;
         }
 
-        // Update harmonic resonance
+        // Update harmonic resonance, then layer harvested crystal abilities on top
         self.harmonic_resonance = self.chaos_engine.harmonic_resonance.iter().sum::<f32>() / 7.0;
+        self.apply_crystal_abilities(dt);
 
         // Update all behavior systems
         self.update_consciousness_evolution(dt, beat_intensity, decision_vector);
         self.update_memory_system(dt, beat_intensity);
-        self.update_movement_behavior(dt, all_llamas, my_index, decision_vector, cosmic_time);
+        self.update_movement_behavior(dt, all_llamas, all_ids, my_index, decision_vector, cosmic_time);
         self.update_color_psychology(dt, decision_vector, cosmic_time);
         self.update_trip_intensity(beat_intensity, decision_vector);
     }
@@ -58,37 +70,46 @@ impl Llama {
             SpeciesType::DiscoLlama => 1.0,
             SpeciesType::QuantumSheep => 1.3,
             SpeciesType::HypnoCamel => 0.8,
+            SpeciesType::BassDropVicuna => 0.6 + beat_intensity * 0.6, // Wakes up on the drop
         };
         self.consciousness += total_growth * species_multiplier;
     }
 
     /// Memory formation and management system
     fn update_memory_system(&mut self, dt: f32, beat_intensity: f32) {
+        self.decay_memories(dt);
+
         // Form memories of interesting locations (high beat intensity or social interactions)
         let memory_threshold = 0.7 + self.personality_matrix[3] * 0.3; // Memory strength affects threshold
         if beat_intensity > memory_threshold || self.social_attraction > 0.8 {
             // Only store memory if this location is significantly different from existing memories
-            let should_remember = self.memory_fragments.is_empty() ||
-                self.memory_fragments.iter().all(|mem| mem.distance(self.position) > 50.0);
+            let should_remember = self.memory_fragments.iter().all(|mem| mem.position.distance(self.position) > 50.0);
 
-            if should_remember && self.memory_fragments.len() < 10 {
-                self.memory_fragments.push(self.position);
+            if should_remember {
+                self.remember(self.position, MemoryKind::Interesting, 0.5);
                 self.memory_intensity = (self.memory_intensity + 0.1).min(1.0);
             }
         }
     }
 
     /// Comprehensive movement behavior system
-    fn update_movement_behavior(&mut self, dt: f32, all_llamas: &[Llama], my_index: usize,
+    fn update_movement_behavior(&mut self, dt: f32, all_llamas: &[Llama], all_ids: &[EntityId], my_index: usize,
                                decision_vector: DecisionVector, cosmic_time: f64) {
+        // A possessed llama goes where the observer steers it and nowhere else
+        if self.possessed_steering.is_some() {
+            self.follow_steering();
+            self.update_position_and_boundaries(dt);
+            return;
+        }
+
         // Calculate movement forces
         let memory_influence = self.calculate_memory_influence();
         let exploration_force = self.calculate_exploration_force(decision_vector, cosmic_time);
-        let social_force = self.calculate_social_force(all_llamas, my_index, decision_vector);
+        let social_force = self.calculate_social_force(all_llamas, all_ids, my_index, decision_vector);
 
         // Apply species-specific movement patterns
         self.apply_species_movement(dt, memory_influence + exploration_force + social_force,
-                                  decision_vector, cosmic_time);
+                                  decision_vector, cosmic_time, entrainment(all_llamas, my_index, cosmic_time));
 
         // Apply reality distortion effects
         self.apply_reality_distortion_to_movement(dt);
@@ -96,22 +117,13 @@ impl Llama {
         // Update position and handle boundaries
         self.update_position_and_boundaries(dt);
 
-        // Update emotional state based on movement
-        self.update_emotional_state(dt, social_force, memory_influence);
+        // Feelings fade back towards the llama's temperament
+        self.affect.settle(dt, self.personality_matrix[4]);
     }
 
-    /// Calculate memory-driven movement influence
+    /// Calculate memory-driven movement influence: back to crystals, away from remembered danger
     fn calculate_memory_influence(&self) -> Vec2 {
-        let mut memory_influence = Vec2::ZERO;
-        if !self.memory_fragments.is_empty() && fastrand::f32() < self.memory_intensity * 0.1 {
-            // Sometimes return to interesting memories
-            let target_memory = &self.memory_fragments[fastrand::usize(0..self.memory_fragments.len())];
-            let to_memory = *target_memory - self.position;
-            if to_memory.length() > 10.0 {
-                memory_influence = to_memory.normalize() * 30.0 * self.personality_matrix[3]; // Memory strength
-            }
-        }
-        memory_influence
+        self.memory_steering() * 30.0 * self.personality_matrix[3] * self.memory_intensity.clamp(0.2, 1.0) // Memory strength
     }
 
     /// Calculate exploration force with 11D decision engine
@@ -142,11 +154,16 @@ impl Llama {
                     spiral_angle.sin() * exploration_strength * 0.5,
                 )
             },
+            SpeciesType::BassDropVicuna => {
+                // Vicunas sway side to side with the sub-bass and save their energy for the drop
+                let sway = (cosmic_time as f32 * 1.5).sin();
+                Vec2::new(sway * exploration_strength * 0.4, (fastrand::f32() - 0.5) * exploration_strength * 0.2)
+            },
         }
     }
 
     /// Calculate social forces with species interactions
-    fn calculate_social_force(&mut self, all_llamas: &[Llama], my_index: usize,
+    fn calculate_social_force(&mut self, all_llamas: &[Llama], all_ids: &[EntityId], my_index: usize,
                              decision_vector: DecisionVector) -> Vec2 {
         let mut social_force = Vec2::ZERO;
         let mut nearby_count = 0;
@@ -173,9 +190,10 @@ impl Llama {
                 }
 
                 // Form social bonds with species consideration
-                if distance < 60.0 && !self.social_bonds.contains(&i) && self.social_bonds.len() < 5 {
+                let other_id = all_ids[i];
+                if distance < 60.0 && !self.social_bonds.contains(&other_id) && self.social_bonds.len() < 5 {
                     if interaction_strength > 0.5 { // Only bond with compatible species
-                        self.social_bonds.push(i);
+                        self.social_bonds.push(other_id);
                     }
                 }
             }
@@ -191,38 +209,25 @@ impl Llama {
         social_force
     }
 
-    /// Apply species-specific movement patterns
+    /// Move by the species' own movement model on the beat it was handed, then turn
+    /// with any hypno camels nearby (`entrainment` radians per second)
     fn apply_species_movement(&mut self, dt: f32, total_force: Vec2,
-                             decision_vector: DecisionVector, cosmic_time: f64) {
+                             decision_vector: DecisionVector, cosmic_time: f64, entrainment: f32) {
         let personality_velocity_mod = 1.0 + self.personality_matrix[6] * 0.5;
         let decision_velocity_mod = 1.0 + decision_vector.movement_urgency * 0.3;
         let chaos_velocity_mod = 1.0 + self.prime_chaos_factor * 0.2;
 
-        let total_velocity_mod = personality_velocity_mod * decision_velocity_mod * chaos_velocity_mod;
+        // Mood sets the gait: agitation hurries it, fear adds a skittish zigzag
+        let affect_velocity_mod = self.affect.pace();
+        let skitter = Vec2::new(fastrand::f32() - 0.5, fastrand::f32() - 0.5) * self.affect.jitter() * SKITTER_FORCE;
+        let total_force = total_force + skitter;
 
-        // Species-specific movement patterns
-        match self.species {
-            SpeciesType::DiscoLlama => {
-                self.velocity += total_force * dt * total_velocity_mod;
-            },
-            SpeciesType::QuantumSheep => {
-                // Quantum sheep have probabilistic movement
-                if fastrand::f32() < self.quantum_state {
-                    // Quantum tunneling - sudden position shifts
-                    let quantum_jump = Vec2::new(
-                        (fastrand::f32() - 0.5) * 100.0,
-                        (fastrand::f32() - 0.5) * 100.0,
-                    );
-                    self.velocity += (total_force + quantum_jump) * dt * total_velocity_mod;
-                } else {
-                    self.velocity += total_force * dt * total_velocity_mod * 0.7; // Slower normal movement
-                }
-            },
-            SpeciesType::HypnoCamel => {
-                // Hypno camels have rhythmic movement
-                let hypno_rhythm = (cosmic_time as f32 * 3.0).sin() * 0.5 + 0.5;
-                self.velocity += total_force * dt * total_velocity_mod * hypno_rhythm;
-            },
+        let total_velocity_mod = personality_velocity_mod * decision_velocity_mod * chaos_velocity_mod * affect_velocity_mod;
+
+        let movement = self.species_config().movement;
+        movement.apply(self, total_force, total_velocity_mod, dt, cosmic_time, self.tempo_bpm);
+        if entrainment != 0.0 {
+            self.velocity = Vec2::from_angle(entrainment * dt).rotate(self.velocity);
         }
     }
 
@@ -237,6 +242,7 @@ impl Llama {
             SpeciesType::DiscoLlama => 1.0,
             SpeciesType::QuantumSheep => 1.5 + self.quantum_state * 0.5,
             SpeciesType::HypnoCamel => 0.7,
+            SpeciesType::BassDropVicuna => 0.9,
         };
 
         self.reality_distortion = (base_distortion + chaos_distortion + harmonic_distortion) * species_distortion_mod;
@@ -259,8 +265,8 @@ impl Llama {
         // Apply velocity damping
         self.velocity *= 0.98;
 
-        // Update position
-        self.position += self.velocity * dt;
+        // Update position; exhausted llamas cover less ground
+        self.position += self.velocity * dt * self.metabolic_pace();
 
         // Wrap around screen with reality distortion effects
         let wrap_margin = if self.reality_distortion > 0.3 { 50.0 } else { 0.0 };
@@ -270,20 +276,11 @@ impl Llama {
         if self.position.y > 800.0 + wrap_margin { self.position.y = -wrap_margin; }
     }
 
-    /// Update emotional state based on movement and social interactions
-    fn update_emotional_state(&mut self, dt: f32, social_force: Vec2, memory_influence: Vec2) {
-        let emotional_volatility = self.personality_matrix[4];
-        let emotion_change = (emotional_volatility * 0.1) +
-                           (social_force.length() * 0.01) +
-                           (memory_influence.length() * 0.005);
-        self.emotional_state = ((self.emotional_state + emotion_change).sin() + 1.0) * 0.5; // Keep in 0-1 range
-    }
-
     /// Enhanced color psychology with mathematical chaos
     fn update_color_psychology(&mut self, dt: f32, decision_vector: DecisionVector, cosmic_time: f64) {
         // Color reflects emotional state, consciousness, personality, and 11D chaos
         let base_hue_shift = 1.0 + self.personality_matrix[0] * 2.0;
-        let emotional_hue_offset = self.emotional_state * 60.0;
+        let emotional_hue_offset = self.affect.resonance() * 60.0;
         let chaos_hue_offset = self.prime_chaos_factor * 120.0; // Prime chaos affects hue dramatically
         let harmonic_hue_offset = self.harmonic_resonance * 40.0;
 
@@ -310,6 +307,13 @@ impl Llama {
                 self.color.x = (hypno_base + rhythm_shift + harmonic_hue_offset * dt) % 360.0;
                 self.color.y = (0.7 + (cosmic_time as f32 * 3.0).sin().abs() * 0.3).clamp(0.0, 1.0);
             },
+            SpeciesType::BassDropVicuna => {
+                // Vicunas stay crimson-magenta; saturation is capped so they never become a pure red
+                let (low, high) = self.species_config().base_hue_range;
+                let drift = (cosmic_time as f32 * 0.7 + self.personality_matrix[0] * 6.0).sin() * 0.5 + 0.5;
+                self.color.x = low + (high - low) * drift;
+                self.color.y = (0.45 + harmonic_hue_offset * 0.002).clamp(0.45, BASS_DROP_MAX_SATURATION);
+            },
         }
     }
 
@@ -328,21 +332,26 @@ impl Llama {
     }
 
     /// Social bonding behavior system
-    pub fn update_social_bonds(&mut self, all_llamas: &[Llama], my_index: usize) {
+    pub fn update_social_bonds(&mut self, all_llamas: &[Llama], all_ids: &[EntityId], my_index: usize) {
+        let bonded = |bond: EntityId| all_ids.iter().position(|&id| id == bond).map(|slot| &all_llamas[slot]);
+
         // Remove bonds that are too far away or no longer exist
-        self.social_bonds.retain(|&bond_idx| {
-            if bond_idx >= all_llamas.len() || bond_idx == my_index {
+        let my_id = all_ids[my_index];
+        let position = self.position;
+        self.social_bonds.retain(|&bond| {
+            if bond == my_id {
                 return false;
             }
 
-            let distance = self.position.distance(all_llamas[bond_idx].position);
-            distance < 150.0 // Maximum bond distance
+            match bonded(bond) {
+                Some(other) => position.distance(other.position) < 150.0, // Maximum bond distance
+                None => false,
+            }
         });
 
         // Strengthen existing bonds over time
-        for &bond_idx in &self.social_bonds {
-            if bond_idx < all_llamas.len() {
-                let other = &all_llamas[bond_idx];
+        for &bond in &self.social_bonds {
+            if let Some(other) = bonded(bond) {
                 let distance = self.position.distance(other.position);
 
                 // Bonds strengthen when llamas are near each other
@@ -559,6 +568,23 @@ impl Llama {
         self.harmonic_resonance > 0.8
     }
 
+    /// Check if llama has been driven to extinction (warfare or predation)
+    pub fn is_extinct(&self) -> bool {
+        self.consciousness < 0.1 && self.extinction_pressure >= 1.0
+    }
+
+    /// Whether the llama has run low enough on energy to slow and dim
+    pub fn is_exhausted(&self) -> bool {
+        self.energy < EXHAUSTION_THRESHOLD
+    }
+
+    /// 1 while rested, falling to `EXHAUSTED_PACE` as an exhausted llama's energy runs out;
+    /// scales both its speed and its brightness
+    pub fn metabolic_pace(&self) -> f32 {
+        let reserve = (self.energy / EXHAUSTION_THRESHOLD).clamp(0.0, 1.0);
+        EXHAUSTED_PACE + (1.0 - EXHAUSTED_PACE) * reserve
+    }
+
     /// Calculate influence radius for consciousness effects
     pub fn get_consciousness_influence_radius(&self) -> f32 {
         let base_radius = 50.0;
@@ -0,0 +1,157 @@
+// === SPATIAL MEMORY ===
+// Llamas remember what happened where: crystals they found, predators that
+// came for them, fronts of the consciousness wars. Memories fade at a rate
+// that depends on what they are about, pull the llama back to good places and
+// push it away from bad ones, and travel between hive members in slightly
+// garbled form.
+
+use glam::Vec2;
+use crate::entities::Llama;
+
+/// Most memories a single llama holds; the weakest is forgotten first
+pub const MAX_MEMORIES: usize = 10;
+/// Memories of the same kind closer than this merge into one
+const MERGE_RADIUS: f32 = 50.0;
+/// Memories weaker than this are forgotten
+const FORGET_THRESHOLD: f32 = 0.05;
+/// Distance within which bad memories push a llama away
+const AVOID_RADIUS: f32 = 200.0;
+/// How far a memory drifts each time it is passed on, in world units
+const SHARE_DRIFT: f32 = 25.0;
+/// Share of its strength a memory keeps when passed on
+const SHARE_FIDELITY: f32 = 0.7;
+/// Chance a passed-on memory loses what it was about
+const SHARE_CONFUSION: f32 = 0.05;
+
+/// What a memory is about
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MemoryKind {
+    Interesting,       // A strong beat or a social moment happened here
+    CrystalFound,      // A crystal was harvested here
+    PredatorEncounter, // Something tried to absorb this llama here
+    ConflictSite,      // A species war was fought here
+}
+
+impl MemoryKind {
+    /// Pull (positive) or push (negative) a memory exerts on movement
+    pub fn valence(self) -> f32 {
+        match self {
+            MemoryKind::Interesting => 0.3,
+            MemoryKind::CrystalFound => 1.0,
+            MemoryKind::PredatorEncounter => -1.0,
+            MemoryKind::ConflictSite => -0.8,
+        }
+    }
+
+    /// Seconds for a memory of this kind to lose half its strength
+    pub fn half_life(self) -> f32 {
+        match self {
+            MemoryKind::Interesting => 30.0,
+            MemoryKind::CrystalFound => 60.0,
+            MemoryKind::PredatorEncounter => 90.0, // Danger is remembered longest
+            MemoryKind::ConflictSite => 45.0,
+        }
+    }
+}
+
+/// One remembered place
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MemoryFragment {
+    pub position: Vec2,
+    pub kind: MemoryKind,
+    pub strength: f32, // 0-1, fades with time
+}
+
+impl MemoryFragment {
+    pub fn new(position: Vec2, kind: MemoryKind, strength: f32) -> Self {
+        Self { position, kind, strength: strength.clamp(0.0, 1.0) }
+    }
+
+    /// The memory as another hive member receives it: weaker, displaced, sometimes confused
+    pub fn mutated(&self) -> Self {
+        let drift = Vec2::new(fastrand::f32() - 0.5, fastrand::f32() - 0.5) * 2.0 * SHARE_DRIFT;
+        let kind = if fastrand::f32() < SHARE_CONFUSION { MemoryKind::Interesting } else { self.kind };
+        Self::new(self.position + drift, kind, self.strength * SHARE_FIDELITY)
+    }
+}
+
+impl Llama {
+    /// Remember `kind` at `position`, refreshing a nearby memory of the same kind instead of duplicating it
+    pub fn remember(&mut self, position: Vec2, kind: MemoryKind, strength: f32) {
+        let fragment = MemoryFragment::new(position, kind, strength);
+        if let Some(existing) = self.memory_fragments.iter_mut()
+            .find(|memory| memory.kind == kind && memory.position.distance(position) < MERGE_RADIUS) {
+            existing.strength = existing.strength.max(fragment.strength);
+            return;
+        }
+
+        if self.memory_fragments.len() >= MAX_MEMORIES {
+            let weakest = self.memory_fragments.iter().enumerate()
+                .min_by(|a, b| a.1.strength.total_cmp(&b.1.strength))
+                .map(|(index, memory)| (index, memory.strength));
+            match weakest {
+                Some((index, weakest)) if weakest < fragment.strength => { self.memory_fragments.swap_remove(index); }
+                _ => return, // Everything held is stronger than the new memory
+            }
+        }
+        self.memory_fragments.push(fragment);
+    }
+
+    /// Fade every memory by its kind's half-life and forget the faint ones
+    pub fn decay_memories(&mut self, dt: f32) {
+        for memory in &mut self.memory_fragments {
+            memory.strength *= 0.5f32.powf(dt / memory.kind.half_life());
+        }
+        self.memory_fragments.retain(|memory| memory.strength >= FORGET_THRESHOLD);
+    }
+
+    /// Unit-bounded steering from memory: towards remembered crystals, away from nearby danger
+    pub fn memory_steering(&self) -> Vec2 {
+        let steering: Vec2 = self.memory_fragments.iter().map(|memory| {
+            let offset = memory.position - self.position;
+            let distance = offset.length();
+            if distance < 10.0 {
+                return Vec2::ZERO;
+            }
+            let valence = memory.kind.valence();
+            if valence > 0.0 {
+                offset / distance * valence * memory.strength / (1.0 + distance / AVOID_RADIUS)
+            } else if distance < AVOID_RADIUS {
+                offset / distance * valence * memory.strength * (1.0 - distance / AVOID_RADIUS)
+            } else {
+                Vec2::ZERO
+            }
+        }).sum();
+        steering.clamp_length_max(1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_memories_merge_fade_and_steer() {
+        let mut llama = Llama::new(Vec2::new(500.0, 400.0));
+        llama.remember(Vec2::new(700.0, 400.0), MemoryKind::CrystalFound, 0.6);
+        llama.remember(Vec2::new(720.0, 400.0), MemoryKind::CrystalFound, 0.9);
+        assert_eq!(llama.memory_fragments.len(), 1);
+        assert_eq!(llama.memory_fragments[0].strength, 0.9);
+        assert!(llama.memory_steering().x > 0.0, "a remembered crystal should pull");
+
+        // A fresh predator memory right behind the crystal outweighs it
+        llama.remember(Vec2::new(600.0, 400.0), MemoryKind::PredatorEncounter, 1.0);
+        assert!(llama.memory_steering().x < 0.0, "nearby danger should push back");
+
+        // Crystal memories fade faster than danger
+        llama.decay_memories(MemoryKind::CrystalFound.half_life());
+        let strength = |kind| llama.memory_fragments.iter().find(|memory| memory.kind == kind).map(|memory| memory.strength);
+        assert!((strength(MemoryKind::CrystalFound).unwrap() - 0.45).abs() < 1e-4);
+        assert!(strength(MemoryKind::PredatorEncounter).unwrap() > 0.6);
+        llama.decay_memories(600.0);
+        assert!(llama.memory_fragments.is_empty());
+
+        let shared = MemoryFragment::new(Vec2::ZERO, MemoryKind::ConflictSite, 1.0).mutated();
+        assert!(shared.strength < 1.0 && shared.position.length() <= SHARE_DRIFT * 2.0_f32.sqrt());
+    }
+}
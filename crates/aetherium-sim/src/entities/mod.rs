@@ -0,0 +1,27 @@
+// Entities module containing llamas, species, and consciousness systems
+
+pub mod affect;
+pub mod animation;
+pub mod crystal_abilities;
+pub mod flocking;
+pub mod llama;
+pub mod llama_behavior;
+pub mod memory;
+pub mod movement;
+pub mod naming;
+pub mod possession;
+pub mod shockwave;
+pub mod species;
+pub mod species_registry;
+
+pub use llama::Llama;
+pub use affect::{Affect, Feeling};
+pub use memory::{MemoryFragment, MemoryKind};
+pub use movement::{DiscoStrut, Drift, HypnoOrbit, MovementModel, QuantumHop};
+pub use naming::generate_name;
+pub use crystal_abilities::CrystalAbility;
+pub use flocking::{FlockingConfig, FlockingWeights, flocking_force};
+pub use shockwave::{bass_drop_shockwave, thump};
+pub use animation::{AnimationController, AnimationFrame, AnimationInputs, AnimationState};
+pub use species::{SpeciesType, SpeciesConfig, ConsciousnessLevel, SPECIES_COUNT};
+pub use species_registry::{HybridId, HybridSpecies, HybridTraits, SpeciesRegistry};
\ No newline at end of file
@@ -0,0 +1,177 @@
+// === SPECIES MOVEMENT MODELS ===
+// How each species turns the forces acting on it into motion. Quantum sheep
+// barely drift and instead hop: every so often a sheep vanishes and reappears
+// somewhere in a probability cloud ahead of where it was heading. Hypno
+// camels wander in slow spirals, always turning, and llamas near a camel are
+// drawn into turning with it. Disco llamas strut: a sharp step on every
+// subdivision of the beat, then a glide into a pose until the next. Vicunas
+// simply drift, their bursts come from the bass drop shockwave. Each model's
+// numbers live in `SpeciesConfig`, so hybrids inherit a parent's gait.
+
+use std::f32::consts::TAU;
+use glam::Vec2;
+use super::Llama;
+
+/// Forces integrated straight into velocity
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Drift {
+    pub response: f32, // Share of the force taken into velocity
+    pub damping: f32,  // Share of velocity kept each update
+}
+
+/// Discrete teleporting hops with a slow drift in between
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct QuantumHop {
+    pub hops_per_second: f32, // Mean hop rate at half quantum state; higher states hop more
+    pub hop_distance: f32,    // How far ahead, along the forces, the cloud is centered
+    pub cloud_radius: f32,    // Spread of the landing spot, densest at the center
+    pub drift: f32,           // Share of the force taken into velocity between hops
+}
+
+/// Constant turning that winds in and out into spirals, entraining neighbors
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HypnoOrbit {
+    pub turn_rate: f32,        // Radians per second at the tightest point of the spiral
+    pub spiral_period: f32,    // Seconds for the spiral to wind in and back out
+    pub response: f32,         // Share of the force taken into velocity
+    pub max_speed: f32,        // Camels never hurry
+    pub entrain_radius: f32,   // Llamas this close turn with the camel
+    pub entrain_strength: f32, // Share of the camel's turn they take on right beside it
+}
+
+/// Beat-quantized steps with glides between them
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DiscoStrut {
+    pub steps_per_beat: f32, // Subdivision a step lands on
+    pub step_speed: f32,     // Speed a step pushes off with
+    pub glide: f32,          // Share of speed kept per 60th of a second between steps
+}
+
+/// A species' way of moving
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MovementModel {
+    Drift(Drift),
+    QuantumHop(QuantumHop),
+    HypnoOrbit(HypnoOrbit),
+    DiscoStrut(DiscoStrut),
+}
+
+impl MovementModel {
+    /// Move `llama` under `force` for `dt` seconds; `pace` scales how eagerly it goes
+    /// and `tempo_bpm` is the beat engine's primary rhythm
+    pub fn apply(&self, llama: &mut Llama, force: Vec2, pace: f32, dt: f32, cosmic_time: f64, tempo_bpm: f32) {
+        match *self {
+            Self::Drift(drift) => {
+                llama.velocity += force * dt * pace * drift.response;
+                llama.velocity *= drift.damping;
+            }
+            Self::QuantumHop(hop) => hop.apply(llama, force, pace, dt),
+            Self::HypnoOrbit(orbit) => {
+                llama.velocity += force * dt * pace * orbit.response;
+                let turn = orbit.turn_rate_at(cosmic_time, orbit_phase(llama)) * dt;
+                llama.velocity = Vec2::from_angle(turn).rotate(llama.velocity).clamp_length_max(orbit.max_speed * pace);
+            }
+            Self::DiscoStrut(strut) => {
+                if strut.steps_between(cosmic_time - dt as f64, cosmic_time, tempo_bpm) > 0 {
+                    // Push off along where it was going, bent by what pulls at it
+                    let heading = (llama.velocity + force).try_normalize()
+                        .unwrap_or_else(|| Vec2::from_angle(fastrand::f32() * TAU));
+                    llama.velocity = heading * strut.step_speed * pace;
+                } else {
+                    llama.velocity *= strut.glide.powf(dt * 60.0);
+                }
+            }
+        }
+    }
+}
+
+impl QuantumHop {
+    fn apply(&self, llama: &mut Llama, force: Vec2, pace: f32, dt: f32) {
+        llama.velocity += force * dt * pace * self.drift;
+        if fastrand::f32() < self.hops_per_second * dt * (0.5 + llama.quantum_state) {
+            llama.position += force.normalize_or_zero() * self.hop_distance + cloud_offset(self.cloud_radius);
+            llama.velocity *= 0.5; // Part of its momentum stays behind
+        }
+    }
+}
+
+impl HypnoOrbit {
+    /// Turning speed at this point of the spiral, for a camel `phase` radians into its winding
+    pub fn turn_rate_at(&self, cosmic_time: f64, phase: f32) -> f32 {
+        let winding = (cosmic_time as f32 * TAU / self.spiral_period.max(0.1) + phase).cos();
+        self.turn_rate * (0.6 + 0.4 * winding)
+    }
+}
+
+impl DiscoStrut {
+    /// Steps that land after `from` up to and including `to` at `tempo_bpm`
+    pub fn steps_between(&self, from: f64, to: f64, tempo_bpm: f32) -> u32 {
+        let step = |time: f64| (time * tempo_bpm as f64 / 60.0 * self.steps_per_beat as f64).floor();
+        (step(to) - step(from)).max(0.0) as u32
+    }
+}
+
+/// Camels wind their spirals out of step with each other
+fn orbit_phase(llama: &Llama) -> f32 {
+    llama.personality_matrix[6] * TAU
+}
+
+/// A random offset within `radius`, more likely near the center
+fn cloud_offset(radius: f32) -> Vec2 {
+    let spread = || fastrand::f32() + fastrand::f32() - 1.0;
+    Vec2::new(spread(), spread()) * radius
+}
+
+/// Radians per second the hypno camels around `all_llamas[my_index]` turn it by
+pub fn entrainment(all_llamas: &[Llama], my_index: usize, cosmic_time: f64) -> f32 {
+    let position = all_llamas[my_index].position;
+    all_llamas.iter().enumerate()
+        .filter(|&(i, _)| i != my_index)
+        .filter_map(|(_, other)| match other.species_config().movement {
+            MovementModel::HypnoOrbit(orbit) => {
+                let closeness = 1.0 - other.position.distance(position) / orbit.entrain_radius.max(1.0);
+                (closeness > 0.0).then(|| orbit.turn_rate_at(cosmic_time, orbit_phase(other)) * orbit.entrain_strength * closeness)
+            }
+            _ => None,
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::SpeciesType;
+
+    #[test]
+    fn test_sheep_hop_camels_entrain_and_disco_steps_on_the_beat() {
+        // A sheep with hops every tick jumps ahead rather than gliding
+        let mut sheep = Llama::new_with_species(Vec2::new(600.0, 400.0), SpeciesType::QuantumSheep);
+        sheep.velocity = Vec2::new(0.0, 40.0);
+        let hop = QuantumHop { hops_per_second: 1000.0, hop_distance: 80.0, cloud_radius: 0.0, drift: 0.0 };
+        MovementModel::QuantumHop(hop).apply(&mut sheep, Vec2::X, 1.0, 1.0 / 60.0, 0.0, 120.0);
+        assert_eq!(sheep.position, Vec2::new(680.0, 400.0));
+        assert_eq!(sheep.velocity, Vec2::new(0.0, 20.0)); // Half its momentum stays behind
+
+        // A camel turns the llama beside it but not one across the field
+        let camel = Llama::new_with_species(Vec2::new(100.0, 100.0), SpeciesType::HypnoCamel);
+        let near = Llama::new(Vec2::new(130.0, 100.0));
+        let far = Llama::new(Vec2::new(900.0, 700.0));
+        let herd = [camel, near, far];
+        assert!(entrainment(&herd, 1, 0.0) > 0.0);
+        assert_eq!(entrainment(&herd, 2, 0.0), 0.0);
+
+        // Two steps a beat: four a second at 120 BPM, six at 180, on the grid
+        let MovementModel::DiscoStrut(strut) = SpeciesType::DiscoLlama.get_base_config().movement else { panic!("disco llamas strut") };
+        assert_eq!(strut.steps_between(0.0, 1.0, 120.0), 4);
+        assert_eq!(strut.steps_between(0.0, 1.0, 180.0), 6);
+        assert_eq!(strut.steps_between(0.26, 0.49, 120.0), 0);
+        let mut disco = Llama::new(Vec2::ZERO);
+        disco.velocity = Vec2::new(3.0, 0.0);
+        MovementModel::DiscoStrut(strut).apply(&mut disco, Vec2::ZERO, 1.0, 0.02, 0.25, 120.0);
+        assert_eq!(disco.velocity, Vec2::new(strut.step_speed, 0.0));
+        // The same moment is between steps once the beat slows
+        disco.velocity = Vec2::new(3.0, 0.0);
+        MovementModel::DiscoStrut(strut).apply(&mut disco, Vec2::ZERO, 1.0, 0.02, 0.25, 90.0);
+        assert!(disco.velocity.x < 3.0);
+    }
+}
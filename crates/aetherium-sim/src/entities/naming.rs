@@ -0,0 +1,25 @@
+// === LLAMA NAMES ===
+// Procedural names built from syllables, flavored by species: disco llamas get
+// bright, bouncy names, quantum sheep uncertain ones, hypno camels slow and
+// drawn-out ones, and bass drop vicunas heavy ones. Names are for people to
+// tell llamas apart; nothing in the simulation reads them.
+
+use crate::entities::SpeciesType;
+
+const SHARED_MIDDLES: [&str; 8] = ["la", "ma", "ri", "zo", "ne", "lu", "ka", "vi"];
+
+fn syllables(species: SpeciesType) -> (&'static [&'static str], &'static [&'static str]) {
+    match species {
+        SpeciesType::DiscoLlama => (&["Glim", "Disc", "Boo", "Spark", "Fun", "Glo", "Twi"], &["zle", "ette", "by", "ster", "kaboo", "ly"]),
+        SpeciesType::QuantumSheep => (&["Qua", "Ebb", "Phi", "Sup", "Ent", "Bo", "Schro"], &["ton", "rix", "ling", "dinger", "sen", "um"]),
+        SpeciesType::HypnoCamel => (&["Som", "Mes", "Dro", "Lul", "Oom", "Ha", "Swa"], &["moor", "aloo", "yne", "rah", "undra", "oon"]),
+        SpeciesType::BassDropVicuna => (&["Thum", "Dub", "Wub", "Bom", "Rum", "Gro", "Sub"], &["bo", "stomp", "grum", "dok", "rok", "bass"]),
+    }
+}
+
+/// A fresh name such as "Glimlazle" or "Thumzobass"
+pub fn generate_name(species: SpeciesType) -> String {
+    let (starts, ends) = syllables(species);
+    let middle = if fastrand::f32() < 0.6 { SHARED_MIDDLES[fastrand::usize(..SHARED_MIDDLES.len())] } else { "" };
+    format!("{}{}{}", starts[fastrand::usize(..starts.len())], middle, ends[fastrand::usize(..ends.len())])
+}
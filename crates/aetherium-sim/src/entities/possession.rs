@@ -0,0 +1,113 @@
+// === POSSESSION ===
+// The observer can step into a llama and drive it. A possessed llama drops its
+// own movement drives and goes where it is steered, while its consciousness,
+// memories and colors keep evolving as usual. Its species abilities become
+// deliberate actions, and letting go leaves it enlightened in proportion to
+// the time it carried the observer.
+
+use glam::Vec2;
+use crate::entities::Llama;
+use crate::simulation::{ConsciousnessCrystal, CrystalType};
+
+/// Top speed of a possessed llama, in world units per second
+pub const POSSESSED_SPEED: f32 = 180.0;
+/// A possessed llama reaches crystals this far away, well past the usual harvest radius
+pub const POSSESSED_HARVEST_REACH: f32 = 90.0;
+/// Length of a deliberate quantum tunnel, in world units
+const TUNNEL_JUMP: f32 = 140.0;
+/// Consciousness gained per second of possession, up to the cap
+const ENLIGHTENMENT_PER_SECOND: f32 = 0.02;
+const MAX_ENLIGHTENMENT: f32 = 1.5;
+
+impl Llama {
+    pub fn is_possessed(&self) -> bool {
+        self.possessed_steering.is_some()
+    }
+
+    /// Hand movement over to the observer, standing still until steered
+    pub fn possess(&mut self) {
+        self.possessed_steering = Some(Vec2::ZERO);
+    }
+
+    /// Steer a possessed llama; longer than unit directions are shortened
+    pub fn steer(&mut self, direction: Vec2) {
+        if let Some(steering) = &mut self.possessed_steering {
+            *steering = direction.clamp_length_max(1.0);
+        }
+    }
+
+    /// Return the llama to its own control; returns the consciousness it gained
+    pub fn release_possession(&mut self, seconds_possessed: f32) -> f32 {
+        if self.possessed_steering.take().is_none() {
+            return 0.0;
+        }
+        let enlightenment = (seconds_possessed.max(0.0) * ENLIGHTENMENT_PER_SECOND).min(MAX_ENLIGHTENMENT);
+        self.consciousness += enlightenment;
+        self.awareness_level = (self.awareness_level + enlightenment * 0.5).min(1.0);
+        enlightenment
+    }
+
+    /// Quantum Sheep always can; other species only while holding a Quantum crystal charge
+    pub fn can_tunnel(&self) -> bool {
+        self.species_config().quantum_affinity || self.ability_strength(CrystalType::Quantum) > 0.0
+    }
+
+    /// Jump ahead along the steering direction; false when the llama cannot tunnel
+    pub fn quantum_tunnel(&mut self) -> bool {
+        if !self.can_tunnel() {
+            return false;
+        }
+        let heading = self.possessed_steering
+            .and_then(|steering| steering.try_normalize())
+            .or_else(|| self.velocity.try_normalize())
+            .unwrap_or(Vec2::X);
+        self.position += heading * TUNNEL_JUMP;
+        self.position.x = self.position.x.rem_euclid(1200.0);
+        self.position.y = self.position.y.rem_euclid(800.0);
+        self.quantum_state = 1.0;
+        true
+    }
+
+    /// Reach for the nearest crystal within possession reach; returns its type when harvested
+    pub fn harvest_nearest(&mut self, crystals: &mut [ConsciousnessCrystal]) -> Option<CrystalType> {
+        let crystal = crystals.iter_mut()
+            .filter(|crystal| crystal.position.distance(self.position) < POSSESSED_HARVEST_REACH.max(crystal.harvest_radius))
+            .min_by(|a, b| a.position.distance(self.position).total_cmp(&b.position.distance(self.position)))?;
+        self.absorb_crystal(crystal).then(|| crystal.crystal_type.clone())
+    }
+
+    /// Replace the llama's own drives with the observer's steering for this tick
+    pub(crate) fn follow_steering(&mut self) {
+        if let Some(steering) = self.possessed_steering {
+            self.velocity = steering * POSSESSED_SPEED;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::SpeciesType;
+
+    #[test]
+    fn test_possessed_llama_follows_steering_and_wakes_up_on_release() {
+        let mut llama = Llama::new_with_species(Vec2::new(400.0, 400.0), SpeciesType::DiscoLlama);
+        llama.possess();
+        llama.steer(Vec2::new(3.0, 0.0));
+        llama.update(0.1, 0.0, &[], &[], 0, 0.0);
+        assert!(llama.position.x > 410.0 && (llama.position.y - 400.0).abs() < 1e-3, "it moves only where steered");
+
+        assert!(!llama.quantum_tunnel(), "a disco llama has no quantum charge to tunnel with");
+        let mut crystals = vec![ConsciousnessCrystal::new(Vec2::new(470.0, 400.0), CrystalType::Quantum)];
+        crystals[0].consciousness_energy = 2.0;
+        assert_eq!(llama.harvest_nearest(&mut crystals), Some(CrystalType::Quantum));
+        let before = llama.position;
+        assert!(llama.quantum_tunnel());
+        assert!(llama.position.x > before.x + 100.0);
+
+        let consciousness = llama.consciousness;
+        assert!(llama.release_possession(30.0) > 0.0);
+        assert!(llama.consciousness > consciousness && !llama.is_possessed());
+        assert_eq!(llama.release_possession(30.0), 0.0, "only a possessed llama can be released");
+    }
+}
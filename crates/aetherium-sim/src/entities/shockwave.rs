@@ -0,0 +1,79 @@
+// === BASS DROP SHOCKWAVES ===
+// On every beat drop each BassDrop Vicuna thumps the ground: nearby llamas of
+// any other species are knocked back along the line from the vicuna, harder the
+// closer they stand, while the vicuna itself lurches forward with the drop. A
+// possessed vicuna can also thump on its own, off the beat.
+
+use glam::Vec2;
+use crate::entities::{Llama, SpeciesType};
+
+/// Distance a shockwave reaches, in world units
+pub const SHOCKWAVE_RADIUS: f32 = 160.0;
+/// Velocity given to a llama standing right next to a vicuna at full drop intensity
+const KNOCKBACK_SPEED: f32 = 300.0;
+/// Forward lurch of the vicuna itself at full intensity
+const LURCH_SPEED: f32 = 120.0;
+
+/// Knock llamas away from every vicuna; returns how many vicunas thumped
+pub fn bass_drop_shockwave(llamas: &mut [Llama], intensity: f32) -> usize {
+    let intensity = intensity.clamp(0.0, 1.0);
+    let sources: Vec<Vec2> = llamas.iter()
+        .filter(|llama| llama.species == SpeciesType::BassDropVicuna)
+        .map(|llama| llama.position)
+        .collect();
+
+    for llama in llamas.iter_mut() {
+        if llama.species == SpeciesType::BassDropVicuna {
+            let heading = llama.velocity.try_normalize().unwrap_or(Vec2::X);
+            llama.velocity += heading * LURCH_SPEED * intensity;
+            continue;
+        }
+
+        llama.velocity += sources.iter().map(|&source| knockback(llama.position, source, intensity)).sum::<Vec2>();
+    }
+
+    sources.len()
+}
+
+/// A single vicuna thumps on command; only the llamas around it feel it
+pub fn thump(llamas: &mut [Llama], source: usize, intensity: f32) {
+    let Some(origin) = llamas.get(source).map(|llama| llama.position) else { return };
+    let intensity = intensity.clamp(0.0, 1.0);
+    for (i, llama) in llamas.iter_mut().enumerate() {
+        if i != source && llama.species != SpeciesType::BassDropVicuna {
+            llama.velocity += knockback(llama.position, origin, intensity);
+        }
+    }
+}
+
+fn knockback(position: Vec2, source: Vec2, intensity: f32) -> Vec2 {
+    let away = position - source;
+    let distance = away.length();
+    if distance >= SHOCKWAVE_RADIUS {
+        return Vec2::ZERO;
+    }
+    let direction = away.try_normalize().unwrap_or(Vec2::Y); // Standing on the vicuna: thrown straight down
+    direction * KNOCKBACK_SPEED * intensity * (1.0 - distance / SHOCKWAVE_RADIUS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shockwave_knocks_neighbors_away() {
+        let mut llamas = vec![
+            Llama::new_with_species(Vec2::new(500.0, 400.0), SpeciesType::BassDropVicuna),
+            Llama::new_with_species(Vec2::new(540.0, 400.0), SpeciesType::DiscoLlama),
+            Llama::new_with_species(Vec2::new(900.0, 400.0), SpeciesType::QuantumSheep),
+        ];
+        for llama in &mut llamas {
+            llama.velocity = Vec2::ZERO;
+        }
+
+        assert_eq!(bass_drop_shockwave(&mut llamas, 1.0), 1);
+        assert!(llamas[1].velocity.x > KNOCKBACK_SPEED * 0.5, "the neighbor should be thrown away from the vicuna");
+        assert_eq!(llamas[2].velocity, Vec2::ZERO, "llamas out of reach are untouched");
+        assert!(llamas[0].velocity.length() > 0.0, "the vicuna lurches with the drop");
+    }
+}
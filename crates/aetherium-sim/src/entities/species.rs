@@ -1,13 +1,30 @@
 // Species types and behavior patterns
 // Extracted from simple.rs for better modularity
 
-#[derive(Debug, Clone, PartialEq, Copy)]
+use serde::{Deserialize, Serialize};
+use super::movement::{DiscoStrut, Drift, HypnoOrbit, MovementModel, QuantumHop};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Copy, Serialize, Deserialize)]
 pub enum SpeciesType {
     DiscoLlama,
     QuantumSheep,
     HypnoCamel,
+    BassDropVicuna,
 }
 
+impl SpeciesType {
+    /// Every species, in index order
+    pub const ALL: [SpeciesType; SPECIES_COUNT] = [
+        SpeciesType::DiscoLlama,
+        SpeciesType::QuantumSheep,
+        SpeciesType::HypnoCamel,
+        SpeciesType::BassDropVicuna,
+    ];
+}
+
+/// Number of species, the length of every per-species array
+pub const SPECIES_COUNT: usize = 4;
+
 impl SpeciesType {
     /// Get species-specific base configuration
     pub fn get_base_config(&self) -> SpeciesConfig {
@@ -19,6 +36,7 @@ impl SpeciesType {
                 velocity_modifier: 100.0,
                 war_efficiency: 1.0,
                 quantum_affinity: false,
+                movement: MovementModel::DiscoStrut(DiscoStrut { steps_per_beat: 2.0, step_speed: 110.0, glide: 0.9 }),
             },
             SpeciesType::QuantumSheep => SpeciesConfig {
                 base_hue_range: (270.0, 330.0), // Purple range
@@ -27,6 +45,7 @@ impl SpeciesType {
                 velocity_modifier: 50.0,
                 war_efficiency: 1.3,
                 quantum_affinity: true,
+                movement: MovementModel::QuantumHop(QuantumHop { hops_per_second: 0.8, hop_distance: 60.0, cloud_radius: 25.0, drift: 0.4 }),
             },
             SpeciesType::HypnoCamel => SpeciesConfig {
                 base_hue_range: (30.0, 60.0), // Orange/yellow range
@@ -35,6 +54,23 @@ impl SpeciesType {
                 velocity_modifier: 75.0,
                 war_efficiency: 0.8,
                 quantum_affinity: false,
+                movement: MovementModel::HypnoOrbit(HypnoOrbit {
+                    turn_rate: 1.2,
+                    spiral_period: 8.0,
+                    response: 0.5,
+                    max_speed: 55.0,
+                    entrain_radius: 90.0,
+                    entrain_strength: 0.4,
+                }),
+            },
+            SpeciesType::BassDropVicuna => SpeciesConfig {
+                base_hue_range: (320.0, 350.0), // Crimson-magenta: reads as red but never trips red-flash protection
+                base_saturation: 0.6,
+                consciousness_modifier: 0.9,
+                velocity_modifier: 60.0,
+                war_efficiency: 1.1,
+                quantum_affinity: false,
+                movement: MovementModel::Drift(Drift { response: 0.6, damping: 0.99 }), // Bursts come from the shockwave
             },
         }
     }
@@ -46,6 +82,7 @@ impl SpeciesType {
             (SpeciesType::DiscoLlama, SpeciesType::DiscoLlama) => 1.0,
             (SpeciesType::QuantumSheep, SpeciesType::QuantumSheep) => 1.2,
             (SpeciesType::HypnoCamel, SpeciesType::HypnoCamel) => 0.9,
+            (SpeciesType::BassDropVicuna, SpeciesType::BassDropVicuna) => 1.1,
 
             // Cross-species interactions
             (SpeciesType::DiscoLlama, SpeciesType::QuantumSheep) |
@@ -56,6 +93,15 @@ impl SpeciesType {
 
             (SpeciesType::QuantumSheep, SpeciesType::HypnoCamel) |
             (SpeciesType::HypnoCamel, SpeciesType::QuantumSheep) => 0.4,
+
+            (SpeciesType::BassDropVicuna, SpeciesType::DiscoLlama) |
+            (SpeciesType::DiscoLlama, SpeciesType::BassDropVicuna) => 0.7, // Both live on the beat
+
+            (SpeciesType::BassDropVicuna, SpeciesType::QuantumSheep) |
+            (SpeciesType::QuantumSheep, SpeciesType::BassDropVicuna) => 0.3,
+
+            (SpeciesType::BassDropVicuna, SpeciesType::HypnoCamel) |
+            (SpeciesType::HypnoCamel, SpeciesType::BassDropVicuna) => 0.5,
         }
     }
 
@@ -65,6 +111,7 @@ impl SpeciesType {
             SpeciesType::DiscoLlama => 0.0,
             SpeciesType::QuantumSheep => 1.0,
             SpeciesType::HypnoCamel => 2.0,
+            SpeciesType::BassDropVicuna => 4.0, // 3 is the unused Fractal slot
         }
     }
 
@@ -74,6 +121,7 @@ impl SpeciesType {
             SpeciesType::DiscoLlama => 0,
             SpeciesType::QuantumSheep => 1,
             SpeciesType::HypnoCamel => 2,
+            SpeciesType::BassDropVicuna => 3,
         }
     }
 
@@ -82,7 +130,8 @@ impl SpeciesType {
         match index {
             0 => SpeciesType::DiscoLlama,
             1 => SpeciesType::QuantumSheep,
-            _ => SpeciesType::HypnoCamel,
+            2 => SpeciesType::HypnoCamel,
+            _ => SpeciesType::BassDropVicuna,
         }
     }
 }
@@ -96,6 +145,7 @@ pub struct SpeciesConfig {
     pub velocity_modifier: f32,
     pub war_efficiency: f32,
     pub quantum_affinity: bool,
+    pub movement: MovementModel, // Gait and its parameters; see `movement`
 }
 
 /// Consciousness hierarchy levels for evolution
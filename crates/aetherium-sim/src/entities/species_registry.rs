@@ -0,0 +1,137 @@
+// === SPECIES REGISTRY ===
+// The four founding species are fixed, but hybrids are not: when a hive of
+// allied species stays mixed long enough, a new species emerges from it. The
+// registry holds every hybrid of the session with its generated name, its
+// parents and its traits, blended from the parents' configs by how many of
+// each the founding hive held. A hybrid llama keeps its dominant parent as its
+// `SpeciesType` for the per-species systems (warfare, diplomacy, audio voices)
+// and carries the hybrid's traits in place of that species' base config.
+
+use super::species::{SpeciesConfig, SpeciesType};
+
+/// Identifies a hybrid species in the registry
+pub type HybridId = u32;
+
+/// Traits a hybrid llama carries instead of its species' base config
+#[derive(Debug, Clone)]
+pub struct HybridTraits {
+    pub id: HybridId,
+    pub config: SpeciesConfig,
+}
+
+/// A species that emerged from a mixed hive
+#[derive(Debug, Clone)]
+pub struct HybridSpecies {
+    pub id: HybridId,
+    pub name: String,
+    pub parents: [SpeciesType; 2], // Dominant parent first; hybrid llamas count as it
+    pub config: SpeciesConfig,     // Parents' configs blended by their share of the founding hive
+    pub emerged_at: f32,
+}
+
+impl HybridSpecies {
+    pub fn traits(&self) -> HybridTraits {
+        HybridTraits { id: self.id, config: self.config.clone() }
+    }
+}
+
+/// Every hybrid species that has emerged this session
+#[derive(Debug, Clone, Default)]
+pub struct SpeciesRegistry {
+    hybrids: Vec<HybridSpecies>,
+}
+
+impl SpeciesRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a hybrid of `parents`, the dominant one holding `dominant_share` (0.5-1.0) of the founders
+    pub fn register(&mut self, parents: [SpeciesType; 2], dominant_share: f32, time: f32) -> &HybridSpecies {
+        let id = self.hybrids.len() as HybridId;
+        let config = SpeciesConfig::blend(&parents[0].get_base_config(), &parents[1].get_base_config(), 1.0 - dominant_share);
+        let name = self.unique_name(hybrid_name(parents));
+        self.hybrids.push(HybridSpecies { id, name, parents, config, emerged_at: time });
+        &self.hybrids[id as usize]
+    }
+
+    pub fn get(&self, id: HybridId) -> Option<&HybridSpecies> {
+        self.hybrids.get(id as usize)
+    }
+
+    pub fn hybrids(&self) -> &[HybridSpecies] {
+        &self.hybrids
+    }
+
+    /// Name a llama's species, hybrid or founding
+    pub fn species_name(&self, species: SpeciesType, hybrid: Option<HybridId>) -> String {
+        if let Some(hybrid) = hybrid.and_then(|id| self.get(id)) {
+            return hybrid.name.clone();
+        }
+        let (prefix, animal) = name_parts(species);
+        format!("{} {}", prefix, animal)
+    }
+
+    /// A second hybrid of the same parents gets a numeral
+    fn unique_name(&self, name: String) -> String {
+        let taken = self.hybrids.iter().filter(|hybrid| hybrid.name.starts_with(&name)).count();
+        match taken {
+            0 => name,
+            n => format!("{} {}", name, ["II", "III", "IV", "V", "VI", "VII", "VIII", "IX", "X"].get(n - 1).copied().unwrap_or("Nova")),
+        }
+    }
+}
+
+impl SpeciesConfig {
+    /// Traits `weight` of the way from `self` to `other`
+    pub fn blend(&self, other: &SpeciesConfig, weight: f32) -> SpeciesConfig {
+        let mix = |a: f32, b: f32| a + (b - a) * weight;
+        SpeciesConfig {
+            base_hue_range: (mix(self.base_hue_range.0, other.base_hue_range.0), mix(self.base_hue_range.1, other.base_hue_range.1)),
+            base_saturation: mix(self.base_saturation, other.base_saturation),
+            consciousness_modifier: mix(self.consciousness_modifier, other.consciousness_modifier),
+            velocity_modifier: mix(self.velocity_modifier, other.velocity_modifier),
+            war_efficiency: mix(self.war_efficiency, other.war_efficiency),
+            quantum_affinity: if weight < 0.5 { self.quantum_affinity } else { other.quantum_affinity },
+            movement: if weight < 0.5 { self.movement } else { other.movement },
+        }
+    }
+}
+
+fn name_parts(species: SpeciesType) -> (&'static str, &'static str) {
+    match species {
+        SpeciesType::DiscoLlama => ("Disco", "Llama"),
+        SpeciesType::QuantumSheep => ("Quantum", "Sheep"),
+        SpeciesType::HypnoCamel => ("Hypno", "Camel"),
+        SpeciesType::BassDropVicuna => ("BassDrop", "Vicuna"),
+    }
+}
+
+/// A portmanteau of the parents' prefixes on the second parent's animal: Disco and Hypno Camel make a "Disno Camel"
+fn hybrid_name(parents: [SpeciesType; 2]) -> String {
+    let (first, _) = name_parts(parents[0]);
+    let (second, animal) = name_parts(parents[1]);
+    let head: String = first.chars().take(first.chars().count().div_ceil(2)).collect();
+    let tail: String = second.chars().skip(second.chars().count().div_ceil(2)).collect();
+    format!("{}{} {}", head, tail, animal)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hybrids_blend_their_parents_and_get_unique_names() {
+        let mut registry = SpeciesRegistry::new();
+        let parents = [SpeciesType::QuantumSheep, SpeciesType::HypnoCamel];
+        let first = registry.register(parents, 0.75, 10.0).clone();
+        assert_eq!(first.name, "Quanno Camel");
+        assert!((first.config.war_efficiency - (1.3 * 0.75 + 0.8 * 0.25)).abs() < 1e-5);
+        assert!(first.config.quantum_affinity, "the dominant parent's quantum affinity carries over");
+
+        let second = registry.register(parents, 0.6, 20.0).clone();
+        assert_eq!((second.id, second.name.as_str()), (1, "Quanno Camel II"));
+        assert_eq!(registry.species_name(SpeciesType::QuantumSheep, Some(1)), "Quanno Camel II");
+        assert_eq!(registry.species_name(SpeciesType::QuantumSheep, None), "Quantum Sheep");
+    }
+}
@@ -0,0 +1,14 @@
+// AetheriumBloom simulation core
+// Llamas, species, the ecosystem, hive minds, warfare and diplomacy, emergent
+// communication and the photosensitivity safety math, with nothing that needs
+// a GPU, a window or an audio device. `simulation::HeadlessSimulation` runs it
+// from a seed; the `aetherium_bloom` crate wraps it in the full organism.
+
+pub mod communication;
+pub mod consciousness;
+pub mod core;
+pub mod engine;
+pub mod entities;
+pub mod mathematics;
+pub mod params;
+pub mod simulation;
@@ -7,6 +7,7 @@ pub struct BeatState {
     pub phase: f32,
     pub prime_factor: f32,
     pub cosmic_frequency: f32,
+    pub tempo_bpm: f32,
 }
 
 pub struct BeatEngine {
@@ -62,6 +63,7 @@ impl BeatEngine {
             phase,
             prime_factor,
             cosmic_frequency,
+            tempo_bpm: self.cosmic_tempo,
         }
     }
 
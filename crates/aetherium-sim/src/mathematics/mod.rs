@@ -0,0 +1,7 @@
+pub mod beat_engine;
+pub mod physics;
+pub mod dimensions;
+
+pub use beat_engine::{BeatEngine, BeatState};
+pub use physics::{ForceField, ForceFields, ForceKind, RealityField, MAX_FIELD_ACCELERATION, MAX_FORCE_FIELDS};
+pub use dimensions::ElevenDimensionalSpace;
@@ -266,4 +266,192 @@ impl RealityField {
             tear.position.y >= 0.0 && tear.position.y <= screen_height
         });
     }
-}
\ No newline at end of file
+}
+
+// === FORCE FIELDS ===
+// Large-scale currents laid over the whole plane: gravity wells that pull
+// (or, with negative strength, push), vortexes that swirl things around a
+// center, and winds that blow one way across a region. They act on llama
+// velocities next to flocking and trails, and on the dust motes, so the
+// ecosystem gets tides and eddies to choreograph against. Fields placed by
+// events can be given a lifespan; every field fades in, and timed ones fade
+// out, so nothing snaps on or off under the llamas' feet.
+
+/// Most fields alive at once; adding more drops the oldest
+pub const MAX_FORCE_FIELDS: usize = 8;
+/// Strongest acceleration all fields together may apply, in world units per second squared
+pub const MAX_FIELD_ACCELERATION: f32 = 240.0;
+/// Seconds a new field takes to reach full strength, and a timed one to fade away
+const FIELD_FADE_SECONDS: f32 = 1.5;
+/// Distance from a well or vortex center inside which the pull softens, so nothing is flung out of the core
+const FIELD_CORE: f32 = 24.0;
+/// Share of a vortex's strength that pulls inward, keeping orbits from spiralling out
+const VORTEX_INWARD: f32 = 0.25;
+
+/// The shape of a force field
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ForceKind {
+    GravityWell,
+    Vortex,                    // Turns from +x toward +y for positive strength
+    Wind { direction: Vec2 },  // Normalized
+}
+
+/// One force field on the plane
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ForceField {
+    pub kind: ForceKind,
+    pub position: Vec2,
+    pub radius: f32,            // Nothing is felt beyond this
+    pub strength: f32,          // Acceleration at full strength; negative wells repel and vortexes turn clockwise
+    pub lifespan: Option<f32>,  // Seconds until it is gone, or forever
+    pub age: f32,
+}
+
+impl ForceField {
+    pub fn new(kind: ForceKind, position: Vec2, radius: f32, strength: f32) -> Self {
+        Self { kind, position, radius: radius.max(1.0), strength, lifespan: None, age: 0.0 }
+    }
+
+    pub fn gravity_well(position: Vec2, radius: f32, strength: f32) -> Self {
+        Self::new(ForceKind::GravityWell, position, radius, strength)
+    }
+
+    pub fn vortex(position: Vec2, radius: f32, strength: f32) -> Self {
+        Self::new(ForceKind::Vortex, position, radius, strength)
+    }
+
+    /// Wind blowing along `direction` within `radius` of `position`; its strength is the length of `direction`
+    pub fn wind(position: Vec2, radius: f32, direction: Vec2) -> Self {
+        let kind = ForceKind::Wind { direction: direction.normalize_or_zero() };
+        Self::new(kind, position, radius, direction.length())
+    }
+
+    /// Fade away after `seconds`
+    pub fn with_lifespan(mut self, seconds: f32) -> Self {
+        self.lifespan = Some(seconds.max(0.0));
+        self
+    }
+
+    /// How much of its strength the field has right now, easing in after placement and out before expiring
+    pub fn presence(&self) -> f32 {
+        let fade_in = (self.age / FIELD_FADE_SECONDS).min(1.0);
+        let fade_out = self.lifespan.map_or(1.0, |lifespan| ((lifespan - self.age) / FIELD_FADE_SECONDS).clamp(0.0, 1.0));
+        fade_in * fade_out
+    }
+
+    pub fn expired(&self) -> bool {
+        self.lifespan.is_some_and(|lifespan| self.age >= lifespan)
+    }
+
+    /// Acceleration this field applies at `position`
+    pub fn acceleration_at(&self, position: Vec2) -> Vec2 {
+        let offset = self.position - position;
+        let distance = offset.length();
+        if distance >= self.radius {
+            return Vec2::ZERO;
+        }
+        let falloff = (1.0 - distance / self.radius) * self.presence();
+        let core = (distance / FIELD_CORE).min(1.0);
+        let inward = offset / distance.max(1.0);
+        match self.kind {
+            ForceKind::GravityWell => inward * self.strength * falloff * core,
+            ForceKind::Vortex => (-inward.perp() + inward * VORTEX_INWARD) * self.strength * falloff * core,
+            ForceKind::Wind { direction } => direction * self.strength * falloff,
+        }
+    }
+}
+
+/// Every force field on the plane
+#[derive(Debug, Clone, Default)]
+pub struct ForceFields {
+    fields: Vec<ForceField>,
+}
+
+impl ForceFields {
+    /// Place a field, dropping the oldest when the plane is full
+    pub fn add(&mut self, field: ForceField) {
+        if self.fields.len() >= MAX_FORCE_FIELDS {
+            self.fields.remove(0);
+        }
+        self.fields.push(field);
+    }
+
+    pub fn fields(&self) -> &[ForceField] {
+        &self.fields
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.fields.clear();
+    }
+
+    /// Age the fields and let expired ones go
+    pub fn update(&mut self, dt: f32) {
+        for field in &mut self.fields {
+            field.age += dt;
+        }
+        self.fields.retain(|field| !field.expired());
+    }
+
+    /// Acceleration all fields together apply at `position`
+    pub fn acceleration_at(&self, position: Vec2) -> Vec2 {
+        let total: Vec2 = self.fields.iter().map(|field| field.acceleration_at(position)).sum();
+        total.clamp_length_max(MAX_FIELD_ACCELERATION)
+    }
+
+    /// Points along the line the forces trace from `seed`, `step` apart, ending early where they die out
+    pub fn streamline(&self, seed: Vec2, steps: usize, step: f32) -> Vec<Vec2> {
+        let mut points = vec![seed];
+        let mut position = seed;
+        for _ in 0..steps {
+            // Midpoint integration keeps vortex lines from spiralling outward
+            let direction = self.acceleration_at(position).normalize_or_zero();
+            let midpoint = position + direction * step * 0.5;
+            let direction = self.acceleration_at(midpoint).normalize_or_zero();
+            if direction == Vec2::ZERO {
+                break;
+            }
+            position += direction * step;
+            points.push(position);
+        }
+        points
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_force_fields_pull_swirl_blow_and_fade() {
+        let mut fields = ForceFields::default();
+        fields.add(ForceField::gravity_well(Vec2::new(100.0, 100.0), 200.0, 80.0));
+        fields.add(ForceField::vortex(Vec2::new(600.0, 100.0), 200.0, 80.0));
+        fields.add(ForceField::wind(Vec2::new(100.0, 600.0), 200.0, Vec2::new(0.0, 50.0)).with_lifespan(4.0));
+        assert_eq!(fields.acceleration_at(Vec2::new(150.0, 100.0)), Vec2::ZERO, "fields fade in from nothing");
+
+        fields.update(2.0);
+        let pull = fields.acceleration_at(Vec2::new(150.0, 100.0));
+        assert!(pull.x < 0.0 && pull.y.abs() < 1e-4, "wells pull toward their center");
+        let swirl = fields.acceleration_at(Vec2::new(700.0, 100.0));
+        assert!(swirl.y > swirl.x.abs(), "vortexes swirl around rather than pull");
+        assert!(fields.acceleration_at(Vec2::new(100.0, 600.0)).y > 0.0);
+        assert_eq!(fields.acceleration_at(Vec2::new(1000.0, 700.0)), Vec2::ZERO);
+
+        // Streamlines around a vortex spiral in rather than flying off
+        let orbit = fields.streamline(Vec2::new(700.0, 100.0), 60, 8.0);
+        assert!(orbit.len() > 20);
+        assert!(orbit.iter().all(|point| point.distance(Vec2::new(600.0, 100.0)) < 200.0));
+
+        fields.update(2.5);
+        assert_eq!(fields.fields().len(), 2, "the timed wind has blown itself out");
+        for i in 0..MAX_FORCE_FIELDS {
+            fields.add(ForceField::gravity_well(Vec2::splat(i as f32), 50.0, 10.0));
+        }
+        assert_eq!(fields.fields().len(), MAX_FORCE_FIELDS);
+        assert_eq!(fields.fields()[0].position, Vec2::ZERO, "the oldest fields make way");
+    }
+}
@@ -0,0 +1,141 @@
+// === TUNABLE PARAMETERS ===
+// Every number a host can set from outside the organism, with its safe range
+// and default in one place. Hosts validate a config against these ranges
+// before starting, because a value outside them could stall the simulation,
+// run the population away or push the visuals past the photosensitivity
+// limits. Setters called while running clamp into range instead, so a
+// misbehaving control surface cannot stop the organism.
+
+use thiserror::Error;
+
+/// A value outside its parameter's safe range, or not a number at all
+#[derive(Debug, Clone, Copy, PartialEq, Error)]
+#[error("{name} = {value} is outside its safe range {min}..={max}")]
+pub struct ParamOutOfRange {
+    pub name: &'static str,
+    pub value: f32,
+    pub min: f32,
+    pub max: f32,
+}
+
+/// A tunable parameter and its documented safe range
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Param {
+    pub name: &'static str,
+    pub min: f32,
+    pub max: f32,
+    pub default: f32,
+    pub description: &'static str,
+}
+
+impl Param {
+    /// The value if it is finite and within range, otherwise an error naming the parameter
+    pub fn validate(&self, value: f32) -> Result<f32, ParamOutOfRange> {
+        if value.is_finite() && (self.min..=self.max).contains(&value) {
+            Ok(value)
+        } else {
+            Err(ParamOutOfRange { name: self.name, value, min: self.min, max: self.max })
+        }
+    }
+
+    /// The value forced into range; NaN falls back to the default
+    pub fn clamp(&self, value: f32) -> f32 {
+        if value.is_nan() { self.default } else { value.clamp(self.min, self.max) }
+    }
+}
+
+// Simulation
+pub const INITIAL_POPULATION: Param = Param { name: "initial_population", min: 0.0, max: 512.0, default: 3.0, description: "Llamas spawned at start-up" };
+pub const MAX_POPULATION: Param = Param { name: "max_population", min: 1.0, max: 2048.0, default: 256.0, description: "Ceiling on live llamas before culling" };
+pub const ADAPTATION_STRENGTH: Param = Param { name: "adaptation_strength", min: 0.0, max: 1.0, default: 0.5, description: "How far the experience bends to inferred preferences" };
+pub const HOMEOSTASIS_SHARE: Param = Param { name: "homeostasis.band", min: 0.0, max: 1.0, default: 0.25, description: "Bounds of a species' population band, as shares of all llamas" };
+pub const HOMEOSTASIS_STRENGTH: Param = Param { name: "homeostasis.strength", min: 0.0, max: 1.0, default: 0.5, description: "How hard species are pushed back into their bands" };
+
+// Metabolism
+pub const BASAL_DRAIN: Param = Param { name: "metabolism.basal_drain", min: 0.0, max: 0.1, default: 0.004, description: "Energy a llama burns per second just by living" };
+pub const MOVEMENT_DRAIN: Param = Param { name: "metabolism.movement_drain", min: 0.0, max: 0.5, default: 0.012, description: "Energy burned per 100 world units travelled" };
+pub const WARFARE_DRAIN: Param = Param { name: "metabolism.warfare_drain", min: 0.0, max: 1.0, default: 0.04, description: "Energy burned per second at full warfare participation" };
+pub const CRYSTAL_RECHARGE: Param = Param { name: "metabolism.crystal_recharge", min: 0.0, max: 5.0, default: 1.5, description: "Energy gained per unit of crystal harvested" };
+pub const ZONE_RECHARGE: Param = Param { name: "metabolism.zone_recharge", min: 0.0, max: 1.0, default: 0.08, description: "Energy regained per second at the heart of a meditative zone" };
+pub const STARVATION_SECONDS: Param = Param { name: "metabolism.starvation_time", min: 1.0, max: 600.0, default: 20.0, description: "Seconds a llama survives with no energy left" };
+
+// Flocking
+pub const FLOCK_FORCE: Param = Param { name: "flocking.force", min: 0.0, max: 200.0, default: 25.0, description: "Separation, cohesion and orbit strength in world units per second squared" };
+pub const FLOCK_ALIGNMENT: Param = Param { name: "flocking.alignment", min: 0.0, max: 5.0, default: 0.5, description: "Share of the heading difference to kin matched per second" };
+pub const FLOCK_RADIUS: Param = Param { name: "flocking.radius", min: 10.0, max: 300.0, default: 90.0, description: "How far a llama looks for its flock" };
+
+// Chaos event probabilities
+pub const CHAOS_CRYSTAL_RATE: Param = Param { name: "chaos.crystal_rate", min: 0.0, max: 0.5, default: 0.002, description: "Crystals appearing per second, doubled at full beat" };
+pub const CHAOS_TEAR_RATE: Param = Param { name: "chaos.tear_rate", min: 0.0, max: 1.0, default: 0.01, description: "Reality tears opening per second while chaos runs high" };
+pub const CHAOS_MUTATION_SHARE: Param = Param { name: "chaos.mutation_share", min: 0.0, max: 1.0, default: 1.0 / 3.0, description: "Share of llamas a mutation wave changes; at least one always is" };
+pub const CHAOS_CONFLICT_CHANCE: Param = Param { name: "chaos.conflict_chance", min: 0.0, max: 0.5, default: 0.01, description: "Chance per tick that two rivals close together go to war" };
+pub const CHAOS_PREDATION_CHANCE: Param = Param { name: "chaos.predation_chance", min: 0.0, max: 0.5, default: 0.02, description: "Chance per tick that a strong llama starts absorbing prey in reach" };
+pub const CHAOS_HIVE_CONNECTION: Param = Param { name: "chaos.hive_connection", min: 0.0, max: 1.0, default: 0.3, description: "Chance that any two members of a new hive are linked" };
+pub const CHAOS_INTERVENTION_CHANCE: Param = Param { name: "chaos.intervention_chance", min: 0.0, max: 1.0, default: 0.1, description: "Chance per tick that the meta-observer acts once it wants to" };
+
+// Modulation matrix
+pub const MOD_DEPTH: Param = Param { name: "modulation.depth", min: -1.0, max: 1.0, default: 0.5, description: "How far one route moves its destination; negative pushes it down" };
+
+// Real-world entropy
+pub const ENTROPY_WEIGHT: Param = Param { name: "entropy.weight", min: 0.0, max: 0.5, default: 0.15, description: "Share of the chaos dimension an entropy feed replaces" };
+pub const ENTROPY_INTERVAL_SECONDS: Param = Param { name: "entropy.interval", min: 1.0, max: 3600.0, default: 30.0, description: "Seconds between readings of an entropy feed" };
+
+// Pacing
+pub const MAX_FPS: Param = Param { name: "max_fps", min: 1.0, max: 1000.0, default: 60.0, description: "Frame-rate cap in frames per second" };
+pub const IDLE_TIMEOUT_SECONDS: Param = Param { name: "idle_timeout", min: 5.0, max: 86_400.0, default: 300.0, description: "Seconds without input before idle mode" };
+pub const AUTOSAVE_INTERVAL_SECONDS: Param = Param { name: "autosave_interval", min: 5.0, max: 86_400.0, default: 60.0, description: "Seconds between autosaves to the crash directory" };
+pub const WARM_START_SECONDS: Param = Param { name: "warm_start", min: 0.0, max: 3600.0, default: 0.0, description: "Simulated seconds fast-forwarded before the first frame" };
+
+// Visual safety; the maxima are the photosensitivity standards and can only be tightened
+pub const VISUAL_INTENSITY_LIMIT: Param = Param { name: "safety.visual_intensity_limit", min: 0.0, max: 1.0, default: 1.0, description: "Overall brightness scale" };
+pub const MAX_FLASH_RATE: Param = Param { name: "safety.max_flash_rate", min: 0.0, max: 3.0, default: 3.0, description: "Flashes per second allowed" };
+pub const MAX_LUMINANCE_CHANGE: Param = Param { name: "safety.max_luminance_change", min: 0.0, max: 0.1, default: 0.1, description: "Largest luminance step between frames" };
+
+// Rendering
+pub const LIGHTING_STRENGTH: Param = Param { name: "lighting", min: 0.0, max: 1.0, default: 0.5, description: "How deep areas no llama or crystal lights sink into shadow" };
+pub const DAY_LENGTH_SECONDS: Param = Param { name: "day_length", min: 60.0, max: 86_400.0, default: 1200.0, description: "Seconds in one simulated day, graded from noon to night and back" };
+pub const LOD_POINT_PIXELS: Param = Param { name: "lod.point_pixels", min: 0.0, max: 64.0, default: 4.0, description: "On-screen size in pixels below which a llama is drawn as a single point" };
+pub const LOD_DETAIL_PIXELS: Param = Param { name: "lod.detail_pixels", min: 0.0, max: 256.0, default: 12.0, description: "On-screen size in pixels below which memory fragments and auras are culled" };
+pub const LOD_SPLAT_POPULATION: Param = Param { name: "lod.splat_population", min: 0.0, max: 1_000_000.0, default: 2000.0, description: "Llama count above which point-sized llamas merge into density splats" };
+pub const LOD_STAGGER_TICKS: Param = Param { name: "lod.stagger_ticks", min: 1.0, max: 16.0, default: 4.0, description: "Off-screen llamas update their behavior once in this many ticks; 1 updates them every tick" };
+pub const PALETTE_STRENGTH: Param = Param { name: "palette.strength", min: 0.0, max: 1.0, default: 0.6, description: "How far every color is pulled into the session's harmonic palette; 0 leaves colors alone" };
+pub const PALETTE_ROTATION: Param = Param { name: "palette.rotation", min: 0.0, max: 360.0, default: 6.0, description: "Degrees per minute the palette turns around the color wheel in a calm herd" };
+pub const PALETTE_SPREAD: Param = Param { name: "palette.spread", min: 0.0, max: 90.0, default: 18.0, description: "Degrees a hue may keep from the palette anchor it is pulled to" };
+
+// Audio
+pub const BUS_GAIN: Param = Param { name: "bus_gain", min: 0.0, max: 1.0, default: 1.0, description: "Mix bus gain" };
+pub const BASE_OCTAVE: Param = Param { name: "sonic_signature.base_octave", min: 0.0, max: 7.0, default: 4.0, description: "Octave of the voice's root note" };
+pub const DETUNE_CENTS: Param = Param { name: "sonic_signature.detune_cents", min: 0.0, max: 100.0, default: 0.0, description: "Spread between the voice's oscillators" };
+pub const VIBRATO_CENTS: Param = Param { name: "sonic_signature.vibrato_cents", min: 0.0, max: 100.0, default: 0.0, description: "Vibrato depth" };
+pub const VIBRATO_HZ: Param = Param { name: "sonic_signature.vibrato_hz", min: 0.0, max: 12.0, default: 0.0, description: "Vibrato rate" };
+pub const ENVELOPE_SECONDS: Param = Param { name: "sonic_signature.envelope", min: 0.001, max: 30.0, default: 0.1, description: "Attack and release times" };
+pub const EFFECT_SEND: Param = Param { name: "sonic_signature.sends", min: 0.0, max: 1.0, default: 0.0, description: "Drive and echo send amounts" };
+pub const DUCK_THRESHOLD_DB: Param = Param { name: "ducking.threshold_db", min: -80.0, max: 0.0, default: -36.0, description: "External music level where ducking starts" };
+pub const DUCK_RATIO: Param = Param { name: "ducking.ratio", min: 1.0, max: 20.0, default: 4.0, description: "How hard the synth is pushed down above the threshold" };
+pub const DUCK_MAX_REDUCTION_DB: Param = Param { name: "ducking.max_reduction_db", min: 0.0, max: 60.0, default: 18.0, description: "Deepest the synth is ducked" };
+pub const DUCK_ATTACK: Param = Param { name: "ducking.attack", min: 0.001, max: 1.0, default: 0.01, description: "Seconds to duck when the music comes in" };
+pub const DUCK_RELEASE: Param = Param { name: "ducking.release", min: 0.01, max: 10.0, default: 0.5, description: "Seconds to recover when the music stops" };
+pub const ENVIRONMENT_CROSSFADE_SECONDS: Param = Param { name: "audio.environment_crossfade", min: 0.0, max: 30.0, default: 4.0, description: "Seconds one audio environment takes to fade into the next" };
+pub const FILTER_CUTOFF_HZ: Param = Param { name: "audio.filter_cutoff", min: 80.0, max: 20_000.0, default: 20_000.0, description: "Low-pass cutoff over the llama synth in Hz; the maximum leaves it open" };
+pub const LOUDNESS_TARGET_LUFS: Param = Param { name: "audio.loudness_target", min: -36.0, max: -6.0, default: -18.0, description: "Integrated loudness the output is normalized toward, in LUFS" };
+pub const TRUE_PEAK_CEILING_DB: Param = Param { name: "audio.true_peak_ceiling", min: -12.0, max: 0.0, default: -1.0, description: "Highest true peak the output may reach, in dBTP" };
+pub const STREAM_RATE_HZ: Param = Param { name: "stream.rate_hz", min: 1.0, max: 60.0, default: 30.0, description: "Frames per second of the binary state stream" };
+pub const VOICE_LEVEL: Param = Param { name: "sonic_signature.level", min: 0.0, max: 1.0, default: 0.0, description: "Voice output level" };
+
+/// Every tunable parameter, for hosts that list or document them
+pub const ALL: [Param; 58] = [
+    INITIAL_POPULATION, MAX_POPULATION, ADAPTATION_STRENGTH, HOMEOSTASIS_SHARE, HOMEOSTASIS_STRENGTH,
+    BASAL_DRAIN, MOVEMENT_DRAIN, WARFARE_DRAIN, CRYSTAL_RECHARGE, ZONE_RECHARGE, STARVATION_SECONDS,
+    FLOCK_FORCE, FLOCK_ALIGNMENT, FLOCK_RADIUS,
+    CHAOS_CRYSTAL_RATE, CHAOS_TEAR_RATE, CHAOS_MUTATION_SHARE, CHAOS_CONFLICT_CHANCE, CHAOS_PREDATION_CHANCE, CHAOS_HIVE_CONNECTION, CHAOS_INTERVENTION_CHANCE,
+    MOD_DEPTH,
+    ENTROPY_WEIGHT, ENTROPY_INTERVAL_SECONDS,
+    MAX_FPS, IDLE_TIMEOUT_SECONDS, AUTOSAVE_INTERVAL_SECONDS, WARM_START_SECONDS,
+    VISUAL_INTENSITY_LIMIT, MAX_FLASH_RATE, MAX_LUMINANCE_CHANGE, LIGHTING_STRENGTH, DAY_LENGTH_SECONDS,
+    LOD_POINT_PIXELS, LOD_DETAIL_PIXELS, LOD_SPLAT_POPULATION, LOD_STAGGER_TICKS,
+    PALETTE_STRENGTH, PALETTE_ROTATION, PALETTE_SPREAD,
+    BUS_GAIN, BASE_OCTAVE, DETUNE_CENTS, VIBRATO_CENTS, VIBRATO_HZ, ENVELOPE_SECONDS, EFFECT_SEND, VOICE_LEVEL,
+    DUCK_THRESHOLD_DB, DUCK_RATIO, DUCK_MAX_REDUCTION_DB, DUCK_ATTACK, DUCK_RELEASE,
+    ENVIRONMENT_CROSSFADE_SECONDS, FILTER_CUTOFF_HZ, LOUDNESS_TARGET_LUFS, TRUE_PEAK_CEILING_DB,
+    STREAM_RATE_HZ,
+];
@@ -0,0 +1,158 @@
+// === SIMULATION BENCHMARK ===
+// Runs the headless simulation at fixed populations for a fixed number of
+// ticks and reports ticks per second with a per-system breakdown. Nothing is
+// drawn or played, so the numbers compare CPUs and optimizations without the
+// GPU, the display's refresh rate or the audio device getting in the way.
+// Every run starts from the same seed, and the population cap holds each run
+// at its preset size while llamas are born and culled.
+
+use std::fmt;
+use std::time::{Duration, Instant};
+use crate::engine::population::CullPolicy;
+use super::headless::{HeadlessSimulation, SIMULATION_DT};
+
+/// Seed every benchmark run starts from, so runs on different machines simulate the same world
+pub const BENCH_SEED: u64 = 42;
+/// Ticks run before timing starts, while the first crystals and zones settle
+const WARMUP_TICKS: usize = 30;
+
+/// Systems of one headless tick, timed separately
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SimStage {
+    BeatEngine,
+    Ecosystem,
+    Multiplication, // Hives, warfare and predation
+    Population,     // Homeostasis, metabolism, extinction, the cap and zone growth
+    Llamas,         // Flocking, trails and each llama's behavior
+    Mutations,
+}
+
+impl SimStage {
+    pub const ALL: [SimStage; 6] = [
+        Self::BeatEngine, Self::Ecosystem, Self::Multiplication, Self::Population, Self::Llamas, Self::Mutations,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::BeatEngine => "BEAT",
+            Self::Ecosystem => "ECOSYSTEM",
+            Self::Multiplication => "MULTIPLICATION",
+            Self::Population => "POPULATION",
+            Self::Llamas => "LLAMAS",
+            Self::Mutations => "MUTATIONS",
+        }
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// Time spent in each system, accumulated as laps like the frame profiler
+#[derive(Debug, Clone, Default)]
+pub struct StageTimings {
+    totals: [Duration; 6],
+    lap_start: Option<Instant>,
+}
+
+impl StageTimings {
+    /// Start timing a tick; the first lap is charged from here
+    pub fn start(&mut self) {
+        self.lap_start = Some(Instant::now());
+    }
+
+    /// Charge the time since the previous lap to `stage`
+    pub fn lap(&mut self, stage: SimStage) {
+        let now = Instant::now();
+        if let Some(start) = self.lap_start.replace(now) {
+            self.totals[stage.index()] += now - start;
+        }
+    }
+
+    pub fn total(&self, stage: SimStage) -> Duration {
+        self.totals[stage.index()]
+    }
+}
+
+/// A population and how many ticks to run it for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchPreset {
+    pub population: usize,
+    pub ticks: usize,
+}
+
+/// The populations `--bench-sim` runs; bigger herds run fewer ticks to keep the whole run short
+pub const BENCH_PRESETS: [BenchPreset; 3] = [
+    BenchPreset { population: 100, ticks: 1200 },
+    BenchPreset { population: 1_000, ticks: 300 },
+    BenchPreset { population: 5_000, ticks: 60 },
+];
+
+/// Outcome of one benchmark run
+#[derive(Debug, Clone)]
+pub struct BenchReport {
+    pub preset: BenchPreset,
+    pub elapsed: Duration,
+    pub stages: [(SimStage, Duration); 6],
+    pub final_population: usize,
+}
+
+impl BenchReport {
+    pub fn ticks_per_second(&self) -> f64 {
+        self.preset.ticks as f64 / self.elapsed.as_secs_f64().max(f64::EPSILON)
+    }
+
+    /// How many times faster than the 60 Hz the app runs the simulation at
+    pub fn realtime_factor(&self) -> f64 {
+        self.ticks_per_second() * SIMULATION_DT as f64
+    }
+}
+
+impl fmt::Display for BenchReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let per_tick_ms = |duration: Duration| duration.as_secs_f64() * 1000.0 / self.preset.ticks.max(1) as f64;
+        writeln!(f, "🦙 {} llamas, {} ticks: {:.0} ticks/s ({:.2} ms/tick, {:.2}x realtime), {} llamas at the end",
+                 self.preset.population, self.preset.ticks, self.ticks_per_second(), per_tick_ms(self.elapsed),
+                 self.realtime_factor(), self.final_population)?;
+        for (stage, duration) in &self.stages {
+            let share = duration.as_secs_f64() / self.elapsed.as_secs_f64().max(f64::EPSILON) * 100.0;
+            writeln!(f, "   {:<16}{:>9.3} ms/tick {:>5.1}%", stage.name(), per_tick_ms(*duration), share)?;
+        }
+        Ok(())
+    }
+}
+
+/// Run one preset from `BENCH_SEED`, held at its population by the cap
+pub fn run_benchmark(preset: BenchPreset) -> BenchReport {
+    let mut simulation = HeadlessSimulation::new(BENCH_SEED, preset.population)
+        .with_population_cap(preset.population, CullPolicy::Oldest);
+    simulation.run(WARMUP_TICKS);
+    simulation = simulation.with_stage_timings();
+
+    let start = Instant::now();
+    simulation.run(preset.ticks);
+    let elapsed = start.elapsed();
+
+    let timings = simulation.stage_timings().cloned().unwrap_or_default();
+    BenchReport {
+        preset,
+        elapsed,
+        stages: SimStage::ALL.map(|stage| (stage, timings.total(stage))),
+        final_population: simulation.llamas().len(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_benchmark_times_every_stage() {
+        let report = run_benchmark(BenchPreset { population: 40, ticks: 20 });
+        let staged: Duration = report.stages.iter().map(|(_, duration)| *duration).sum();
+        assert!(staged <= report.elapsed, "laps never add up to more than the run");
+        assert!(report.stages.iter().any(|(_, duration)| !duration.is_zero()));
+        assert!(report.final_population <= 40 && report.ticks_per_second() > 0.0);
+        assert_eq!(report.to_string().lines().count(), 1 + SimStage::ALL.len());
+    }
+}
@@ -0,0 +1,307 @@
+// === LLAMA CHRONICLE ===
+// Every llama gets a name at birth and a biography of the notable moments of
+// its life: joining a hive, crystal harvest milestones, battles survived,
+// absorbing another llama or being absorbed, and how it left the world. The
+// chronicle watches the world tick by tick instead of hooking every system,
+// so it only needs the few facts that leave no trace in a llama's state:
+// which fronts were decided, which absorptions completed and who despawned.
+// Absorbed llamas become part of their absorber's lineage. At the end of a
+// session the most notable lives can be written out as a plain-text saga.
+
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use glam::Vec2;
+use crate::core::ecs::{EntityId, World};
+use crate::engine::population::{DespawnReason, Despawned};
+use crate::engine::speciation::SpeciesEmergence;
+use crate::entities::{generate_name, ConsciousnessLevel, Llama, SpeciesType};
+
+/// Harvest counts worth a line in a biography
+const HARVEST_MILESTONES: [u32; 6] = [1, 5, 10, 25, 50, 100];
+/// Llamas this close to a decided front, and engaged in the war, survived the battle
+const BATTLE_RADIUS: f32 = 150.0;
+const BATTLE_PARTICIPATION: f32 = 0.2;
+/// Entries kept per biography; the oldest after the birth give way
+const MAX_ENTRIES: usize = 48;
+/// Finished biographies kept; the least notable give way
+const MAX_ARCHIVED: usize = 256;
+/// Biographies written into a saga
+const MAX_SAGA_LIVES: usize = 40;
+
+/// A notable moment in a llama's life
+#[derive(Debug, Clone, PartialEq)]
+pub enum LifeEvent {
+    Born { position: Vec2 },
+    JoinedHive,
+    FoundedSpecies(String), // Its hive became a new hybrid species
+    HarvestMilestone(u32),
+    SurvivedBattle,
+    Absorbed { prey: String },
+    AbsorbedBy { predator: String },
+    Died(DespawnReason),
+    TimelineBranched, // Its timeline was abandoned when history branched from an earlier moment
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChronicleEntry {
+    pub time: f32,
+    pub event: LifeEvent,
+}
+
+/// One llama's life story
+#[derive(Debug, Clone)]
+pub struct Biography {
+    pub entity: EntityId,
+    pub name: String,
+    pub species: SpeciesType,
+    pub born: f32,
+    pub ended: Option<f32>,
+    pub entries: Vec<ChronicleEntry>,
+    pub lineage: Vec<String>, // Llamas it absorbed, oldest first
+    pub hives_joined: u32,
+    pub crystals_harvested: u32,
+    pub battles_survived: u32,
+    in_hive: bool,
+}
+
+impl Biography {
+    fn new(entity: EntityId, llama: &Llama, time: f32) -> Self {
+        Self {
+            entity,
+            name: generate_name(llama.species),
+            species: llama.species,
+            born: time,
+            ended: None,
+            entries: vec![ChronicleEntry { time, event: LifeEvent::Born { position: llama.position } }],
+            lineage: Vec::new(),
+            hives_joined: 0,
+            crystals_harvested: llama.crystals_harvested,
+            battles_survived: 0,
+            in_hive: llama.consciousness_level == ConsciousnessLevel::Hive,
+        }
+    }
+
+    pub fn is_alive(&self) -> bool {
+        self.ended.is_none()
+    }
+
+    /// How much happened in this life; zero for a llama that only existed
+    pub fn notability(&self) -> u32 {
+        self.entries.iter().map(|entry| match entry.event {
+            LifeEvent::FoundedSpecies(_) => 5,
+            LifeEvent::Absorbed { .. } => 4,
+            LifeEvent::JoinedHive => 3,
+            LifeEvent::SurvivedBattle | LifeEvent::AbsorbedBy { .. } => 2,
+            LifeEvent::HarvestMilestone(_) => 1,
+            _ => 0,
+        }).sum()
+    }
+
+    fn record(&mut self, time: f32, event: LifeEvent) {
+        if self.entries.len() >= MAX_ENTRIES {
+            self.entries.remove(1); // Keep the birth
+        }
+        self.entries.push(ChronicleEntry { time, event });
+    }
+
+    /// Biography lines as shown by the inspector and written into the saga
+    pub fn lines(&self) -> Vec<String> {
+        self.entries.iter().map(|entry| format!("{} {}", clock(entry.time), describe(&entry.event))).collect()
+    }
+}
+
+fn clock(time: f32) -> String {
+    let seconds = time.max(0.0) as u32;
+    format!("{:02}:{:02}", seconds / 60, seconds % 60)
+}
+
+fn describe(event: &LifeEvent) -> String {
+    match event {
+        LifeEvent::Born { position } => format!("born at {:.0},{:.0}", position.x, position.y),
+        LifeEvent::JoinedHive => "joined a hive mind".to_string(),
+        LifeEvent::FoundedSpecies(species) => format!("founded the {} species", species),
+        LifeEvent::HarvestMilestone(1) => "harvested a first crystal".to_string(),
+        LifeEvent::HarvestMilestone(count) => format!("harvested {} crystals", count),
+        LifeEvent::SurvivedBattle => "survived a battle".to_string(),
+        LifeEvent::Absorbed { prey } => format!("absorbed {}", prey),
+        LifeEvent::AbsorbedBy { predator } => format!("was absorbed by {}", predator),
+        LifeEvent::Died(DespawnReason::Extinct) => "faded into extinction".to_string(),
+        LifeEvent::Died(DespawnReason::PopulationCap) => "was culled to make room".to_string(),
+        LifeEvent::Died(DespawnReason::Removed) => "was taken out of the world".to_string(),
+        LifeEvent::Died(DespawnReason::Exhausted) => "starved of energy".to_string(),
+        LifeEvent::TimelineBranched => "was lost when history branched".to_string(),
+    }
+}
+
+/// Names and biographies of every llama of the session
+#[derive(Default)]
+pub struct Chronicle {
+    living: HashMap<EntityId, Biography>,
+    archive: Vec<Biography>,
+}
+
+impl Chronicle {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Note births, hive joins and harvest milestones, then credit battles and absorptions
+    pub fn observe(&mut self, world: &World, resolved_fronts: &[Vec2], absorptions: &[(EntityId, EntityId)], time: f32) {
+        for (entity, llama) in world.query::<Llama>() {
+            let biography = self.living.entry(entity).or_insert_with(|| Biography::new(entity, llama, time));
+
+            let in_hive = llama.consciousness_level == ConsciousnessLevel::Hive;
+            if in_hive && !biography.in_hive {
+                biography.hives_joined += 1;
+                biography.record(time, LifeEvent::JoinedHive);
+            }
+            biography.in_hive = in_hive;
+
+            let previous = biography.crystals_harvested;
+            for &milestone in HARVEST_MILESTONES.iter().filter(|&&m| previous < m && llama.crystals_harvested >= m) {
+                biography.record(time, LifeEvent::HarvestMilestone(milestone));
+            }
+            biography.crystals_harvested = llama.crystals_harvested;
+
+            let survived = resolved_fronts.iter().filter(|front| front.distance(llama.position) < BATTLE_RADIUS).count();
+            if survived > 0 && llama.warfare_participation > BATTLE_PARTICIPATION {
+                for _ in 0..survived {
+                    biography.battles_survived += 1;
+                    biography.record(time, LifeEvent::SurvivedBattle);
+                }
+            }
+        }
+
+        for &(predator, prey) in absorptions {
+            let (Some(predator_name), Some(prey_name)) = (self.name(predator).map(str::to_owned), self.name(prey).map(str::to_owned)) else { continue };
+            if let Some(biography) = self.living.get_mut(&predator) {
+                biography.lineage.push(prey_name.clone());
+                biography.record(time, LifeEvent::Absorbed { prey: prey_name });
+            }
+            if let Some(biography) = self.living.get_mut(&prey) {
+                biography.record(time, LifeEvent::AbsorbedBy { predator: predator_name });
+            }
+        }
+    }
+
+    /// Close the biographies of despawned llamas
+    pub fn record_deaths(&mut self, despawned: &[Despawned], time: f32) {
+        for removed in despawned {
+            if let Some(mut biography) = self.living.remove(&removed.entity) {
+                biography.record(time, LifeEvent::Died(removed.reason));
+                self.archive_biography(biography, time);
+            }
+        }
+    }
+
+    /// Note the founding of a hybrid species in each founder's biography
+    pub fn record_speciation(&mut self, emergence: &SpeciesEmergence, time: f32) {
+        for founder in &emergence.founders {
+            if let Some(biography) = self.living.get_mut(founder) {
+                biography.record(time, LifeEvent::FoundedSpecies(emergence.name.clone()));
+            }
+        }
+    }
+
+    /// History branched: every living llama's story ends here, the restored herd starts anew
+    pub fn branch(&mut self, time: f32) {
+        let living: Vec<Biography> = self.living.drain().map(|(_, biography)| biography).collect();
+        for mut biography in living {
+            biography.record(time, LifeEvent::TimelineBranched);
+            self.archive_biography(biography, time);
+        }
+    }
+
+    pub fn name(&self, entity: EntityId) -> Option<&str> {
+        self.living.get(&entity).map(|biography| biography.name.as_str())
+    }
+
+    pub fn biography(&self, entity: EntityId) -> Option<&Biography> {
+        self.living.get(&entity)
+    }
+
+    /// Living and finished biographies, most notable first
+    pub fn notable(&self) -> Vec<&Biography> {
+        let mut lives: Vec<&Biography> = self.living.values().chain(&self.archive)
+            .filter(|biography| biography.notability() > 0)
+            .collect();
+        lives.sort_by(|a, b| b.notability().cmp(&a.notability()).then(a.born.total_cmp(&b.born)));
+        lives
+    }
+
+    pub fn len(&self) -> usize {
+        self.living.len() + self.archive.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The session's most notable lives as plain text
+    pub fn saga(&self, time: f32) -> String {
+        let mut saga = String::new();
+        let _ = writeln!(saga, "THE SAGA OF AETHERIUM BLOOM");
+        let _ = writeln!(saga, "{} of consciousness, {} llamas chronicled", clock(time), self.len());
+        let notable = self.notable();
+        if notable.is_empty() {
+            let _ = writeln!(saga, "\nNo llama did anything worth retelling.");
+        }
+        for biography in notable.into_iter().take(MAX_SAGA_LIVES) {
+            let fate = match biography.ended {
+                Some(ended) => format!("{} - {}", clock(biography.born), clock(ended)),
+                None => format!("{} - still alive", clock(biography.born)),
+            };
+            let _ = writeln!(saga, "\n{} the {:?} ({})", biography.name, biography.species, fate);
+            if !biography.lineage.is_empty() {
+                let _ = writeln!(saga, "  Carries the consciousness of {}", biography.lineage.join(", "));
+            }
+            for line in biography.lines() {
+                let _ = writeln!(saga, "  {}", line);
+            }
+        }
+        saga
+    }
+
+    fn archive_biography(&mut self, mut biography: Biography, time: f32) {
+        biography.ended = Some(time);
+        self.archive.push(biography);
+        if self.archive.len() > MAX_ARCHIVED {
+            if let Some(least) = self.archive.iter().enumerate().min_by_key(|(_, biography)| biography.notability()).map(|(i, _)| i) {
+                self.archive.remove(least);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lives_are_named_chronicled_and_retold() {
+        let mut world = World::new();
+        let predator = world.spawn(Llama::new_with_species(Vec2::new(300.0, 300.0), SpeciesType::HypnoCamel));
+        let prey = world.spawn(Llama::new_with_species(Vec2::new(310.0, 300.0), SpeciesType::DiscoLlama));
+        let mut chronicle = Chronicle::new();
+        chronicle.observe(&world, &[], &[], 0.0);
+        let prey_name = chronicle.name(prey).unwrap().to_string();
+        assert!(!prey_name.is_empty());
+
+        let llama = world.get_component_mut::<Llama>(predator).unwrap();
+        llama.consciousness_level = ConsciousnessLevel::Hive;
+        llama.crystals_harvested = 5;
+        llama.warfare_participation = 0.8;
+        chronicle.observe(&world, &[Vec2::new(320.0, 300.0)], &[(predator, prey)], 12.0);
+        chronicle.record_deaths(&[Despawned { entity: prey, species: SpeciesType::DiscoLlama, position: Vec2::ZERO, reason: DespawnReason::Extinct, consciousness: 0.0, hue: 0.0, memory: 0.0 }], 20.0);
+
+        let biography = chronicle.biography(predator).unwrap();
+        assert_eq!((biography.hives_joined, biography.battles_survived), (1, 1));
+        assert_eq!(biography.lineage, vec![prey_name.clone()]);
+        assert!(biography.entries.iter().any(|entry| entry.event == LifeEvent::HarvestMilestone(5)));
+        assert!(chronicle.name(prey).is_none(), "the dead leave the living roster");
+
+        let saga = chronicle.saga(60.0);
+        assert!(saga.contains(&format!("absorbed {}", prey_name)));
+        assert!(saga.contains("faded into extinction"), "finished lives are retold too");
+    }
+}
@@ -0,0 +1,751 @@
+// Consciousness systems module - core consciousness mechanics
+// Extracted from simple.rs for better modularity
+
+use glam::Vec2;
+use serde::{Deserialize, Serialize};
+use crate::core::ecs::{EntityId, World};
+use crate::core::events::{ChaosEvent, EventBus};
+use crate::core::spatial::SpatialHash;
+use crate::engine::{ChaosTuning, ExternalEntropy};
+use crate::entities::Llama;
+use crate::mathematics::ForceFields;
+use crate::params::FLOCK_RADIUS;
+use super::echoes::update_echoes;
+use super::headless::BehaviorStagger;
+use super::obstacles::Obstacles;
+use super::pheromones::PheromoneField;
+use super::zone_emergence::ZoneEvent;
+
+// ========== PHASE 3: ECOSYSTEM EMERGENCE ==========
+
+/// Largest harvest radius a crystal grows to; spatial queries for harvesting use it as their reach
+pub const MAX_HARVEST_RADIUS: f32 = 60.0;
+/// Energy a crystal gives up over its life before it is used up and removed
+pub const CRYSTAL_LIFETIME_YIELD: f32 = 2.0;
+/// Crystals the ecosystem keeps in the world, reseeding when depleted ones leave
+pub const MIN_CRYSTALS: usize = 3;
+/// Consciousness a closed tear gives a llama right beside it, scaled by the tear's intensity
+pub const TEAR_CLOSE_REWARD: f32 = 0.5;
+/// Reach of a closed tear's reward, fading to nothing at the edge
+pub const TEAR_REWARD_RADIUS: f32 = 150.0;
+/// Size a tear held open gains per second
+pub const TEAR_WIDEN_RATE: f32 = 8.0;
+/// Widest a tear can be held open
+pub const TEAR_MAX_SIZE: f32 = 80.0;
+/// Growth between quantum sheep slipping through a widening tear
+pub const TEAR_BREAKTHROUGH_GROWTH: f32 = 12.0;
+
+/// Consciousness Crystal - harvestable nodes that enhance abilities
+#[derive(Debug, Clone)]
+pub struct ConsciousnessCrystal {
+    pub position: Vec2,
+    pub consciousness_energy: f32,    // Amount of consciousness stored
+    pub resonance_frequency: f32,     // Prime-based frequency for interactions
+    pub visual_intensity: f32,        // Current visual brightness/pulsing
+    pub growth_rate: f32,             // How fast it accumulates energy
+    pub harvest_radius: f32,          // Range for llama interaction
+    pub age: f32,                     // How long it has existed
+    pub crystal_type: CrystalType,    // Different types with different properties
+    pub harvested_total: f32,         // Energy given up so far; depleted at CRYSTAL_LIFETIME_YIELD
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum CrystalType {
+    Resonance,    // Amplifies harmonic resonance
+    Chaos,        // Increases chaos acceptance
+    Memory,       // Enhances memory formation
+    Social,       // Strengthens social bonds
+    Quantum,      // Affects quantum state (for Quantum Sheep)
+}
+
+impl ConsciousnessCrystal {
+    pub fn new(position: Vec2, crystal_type: CrystalType) -> Self {
+        let (base_energy, base_frequency, base_growth) = match crystal_type {
+            CrystalType::Resonance => (0.3, 7.0, 0.02),   // High frequency, moderate growth
+            CrystalType::Chaos => (0.5, 11.0, 0.03),      // High energy, prime frequency
+            CrystalType::Memory => (0.2, 5.0, 0.015),     // Low energy, steady growth
+            CrystalType::Social => (0.4, 13.0, 0.025),    // Social prime frequency
+            CrystalType::Quantum => (0.6, 17.0, 0.01),    // High energy, slow growth
+        };
+
+        Self {
+            position,
+            consciousness_energy: base_energy,
+            resonance_frequency: base_frequency,
+            visual_intensity: 0.5,
+            growth_rate: base_growth,
+            harvest_radius: 25.0 + fastrand::f32() * 15.0, // 25-40 radius
+            age: 0.0,
+            crystal_type,
+            harvested_total: 0.0,
+        }
+    }
+
+    pub fn update(&mut self, dt: f32, beat_intensity: f32, cosmic_time: f64) {
+        self.age += dt;
+
+        // Crystals grow in consciousness energy over time
+        let growth_multiplier = 1.0 + beat_intensity * 0.5;
+        self.consciousness_energy += self.growth_rate * dt * growth_multiplier;
+        self.consciousness_energy = self.consciousness_energy.min(2.0); // Cap at 2.0
+
+        // Visual intensity pulses based on prime frequency and beat
+        let frequency_phase = (cosmic_time as f32 * self.resonance_frequency * 0.1).sin();
+        let beat_phase = beat_intensity * 0.5;
+        self.visual_intensity = 0.3 + frequency_phase.abs() * 0.4 + beat_phase * 0.3;
+
+        // Older crystals have larger harvest radius
+        self.harvest_radius = (25.0 + self.age * 2.0).min(MAX_HARVEST_RADIUS);
+    }
+
+    pub fn get_harvest_amount(&self) -> f32 {
+        // More energy = more harvest potential
+        self.consciousness_energy * 0.3
+    }
+
+    pub fn harvest(&mut self, amount: f32) -> f32 {
+        let harvested = amount.min(self.consciousness_energy);
+        self.consciousness_energy -= harvested;
+        self.harvested_total += harvested;
+        harvested
+    }
+
+    /// Used up: harvesters have taken its whole lifetime yield
+    pub fn is_depleted(&self) -> bool {
+        self.harvested_total >= CRYSTAL_LIFETIME_YIELD
+    }
+
+    pub fn get_color(&self) -> glam::Vec3 {
+        use crate::engine::safety::hsv_to_rgb_vec3;
+
+        let base_hue = match self.crystal_type {
+            CrystalType::Resonance => 180.0, // Cyan
+            CrystalType::Chaos => 300.0,     // Magenta
+            CrystalType::Memory => 120.0,    // Green
+            CrystalType::Social => 60.0,     // Yellow
+            CrystalType::Quantum => 240.0,   // Blue
+        };
+
+        let saturation = 0.8 + self.consciousness_energy * 0.2;
+        let brightness = 0.4 + self.visual_intensity * 0.6;
+
+        hsv_to_rgb_vec3(glam::Vec3::new(base_hue, saturation, brightness))
+    }
+}
+
+/// Environmental consciousness field - affects entity behavior
+#[derive(Debug, Clone)]
+pub struct ConsciousnessField {
+    pub grid_size: usize,                    // Resolution of the field grid
+    pub consciousness_density: Vec<Vec<f32>>, // 2D grid of consciousness values
+    pub width: f32,                          // World width
+    pub height: f32,                         // World height
+}
+
+impl ConsciousnessField {
+    pub fn new(width: f32, height: f32, grid_size: usize) -> Self {
+        let consciousness_density = vec![vec![0.1; grid_size]; grid_size]; // Start with low consciousness
+
+        Self {
+            grid_size,
+            consciousness_density,
+            width,
+            height,
+        }
+    }
+
+    pub fn get_consciousness_at(&self, position: Vec2) -> f32 {
+        let x = ((position.x / self.width) * self.grid_size as f32) as usize;
+        let y = ((position.y / self.height) * self.grid_size as f32) as usize;
+
+        if x < self.grid_size && y < self.grid_size {
+            self.consciousness_density[y][x]
+        } else {
+            0.1 // Default consciousness outside grid
+        }
+    }
+
+    pub fn add_consciousness_at(&mut self, position: Vec2, amount: f32) {
+        let x = ((position.x / self.width) * self.grid_size as f32) as usize;
+        let y = ((position.y / self.height) * self.grid_size as f32) as usize;
+
+        if x < self.grid_size && y < self.grid_size {
+            self.consciousness_density[y][x] = (self.consciousness_density[y][x] + amount).min(2.0);
+        }
+    }
+
+    pub fn update(&mut self, dt: f32) {
+        // Gradual diffusion and decay of consciousness
+        for y in 0..self.grid_size {
+            for x in 0..self.grid_size {
+                // Decay consciousness over time
+                self.consciousness_density[y][x] *= 0.999;
+
+                // Simple diffusion with neighbors
+                let mut neighbor_sum = 0.0;
+                let mut neighbor_count = 0;
+
+                for dy in -1..=1 {
+                    for dx in -1..=1 {
+                        if dx == 0 && dy == 0 { continue; }
+                        let nx = x as i32 + dx;
+                        let ny = y as i32 + dy;
+
+                        if nx >= 0 && nx < self.grid_size as i32 && ny >= 0 && ny < self.grid_size as i32 {
+                            neighbor_sum += self.consciousness_density[ny as usize][nx as usize];
+                            neighbor_count += 1;
+                        }
+                    }
+                }
+
+                if neighbor_count > 0 {
+                    let neighbor_avg = neighbor_sum / neighbor_count as f32;
+                    // Slight diffusion toward neighbors
+                    let diffusion_rate = 0.01 * dt;
+                    self.consciousness_density[y][x] =
+                        self.consciousness_density[y][x] * (1.0 - diffusion_rate) +
+                        neighbor_avg * diffusion_rate;
+                }
+            }
+        }
+    }
+}
+
+/// Reality Tear - visual glitch representing consciousness breakthrough
+#[derive(Debug, Clone)]
+pub struct RealityTear {
+    pub position: Vec2,
+    pub size: f32,
+    pub intensity: f32,
+    pub age: f32,
+    pub tear_type: TearType,
+}
+
+#[derive(Debug, Clone)]
+pub enum TearType {
+    Static,      // Stationary glitch
+    Moving,      // Travels across screen
+    Pulsing,     // Grows and shrinks
+    Fragmenting, // Splits into smaller tears
+}
+
+impl RealityTear {
+    pub fn new(position: Vec2, tear_type: TearType) -> Self {
+        Self {
+            position,
+            size: 5.0 + fastrand::f32() * 15.0,
+            intensity: 0.7 + fastrand::f32() * 0.3,
+            age: 0.0,
+            tear_type,
+        }
+    }
+
+    pub fn update(&mut self, dt: f32, cosmic_time: f64) {
+        self.age += dt;
+
+        match self.tear_type {
+            TearType::Static => {
+                // Just age and fade
+                self.intensity *= 0.995;
+            },
+            TearType::Moving => {
+                // Move in chaotic pattern
+                let move_speed = 50.0;
+                let chaos_angle = (cosmic_time as f32 * 3.0 + self.position.length() * 0.01).sin() * 6.28;
+                self.position.x += chaos_angle.cos() * move_speed * dt;
+                self.position.y += chaos_angle.sin() * move_speed * dt;
+                self.intensity *= 0.99;
+            },
+            TearType::Pulsing => {
+                // Pulsing size
+                let pulse = (cosmic_time as f32 * 5.0).sin().abs();
+                self.size = (5.0 + fastrand::f32() * 15.0) * (0.5 + pulse * 0.5);
+                self.intensity *= 0.995;
+            },
+            TearType::Fragmenting => {
+                // Grows then fragments (handled in ecosystem update)
+                if self.age < 2.0 {
+                    self.size += dt * 10.0;
+                }
+                self.intensity *= 0.99;
+            },
+        }
+
+        // Keep within bounds
+        if self.position.x < 0.0 { self.position.x = 1200.0; }
+        if self.position.x > 1200.0 { self.position.x = 0.0; }
+        if self.position.y < 0.0 { self.position.y = 800.0; }
+        if self.position.y > 800.0 { self.position.y = 0.0; }
+    }
+
+    pub fn should_remove(&self) -> bool {
+        self.intensity < 0.1 || self.age > 10.0
+    }
+}
+
+/// Seconds an emergent zone takes to reach full strength
+pub const ZONE_BLOOM_SECONDS: f32 = 5.0;
+
+/// Territory Zone - regions with different consciousness properties
+#[derive(Debug, Clone)]
+pub struct TerritoryZone {
+    pub center: Vec2,
+    pub radius: f32,
+    pub zone_type: ZoneType,
+    pub strength: f32,
+    pub age: f32,
+    pub lifespan: Option<f32>, // Seconds an emergent zone lives; None for permanent zones
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum ZoneType {
+    Harmonic,    // Enhances resonance and social bonding
+    Chaotic,     // Increases chaos and reality distortion
+    Meditative,  // Calms entities, increases memory formation
+    Quantum,     // Quantum effects amplified
+    Temporal,    // Local time runs slow
+    Void,        // Drains consciousness
+}
+
+impl ZoneType {
+    /// Every zone type, for random picks
+    pub const ALL: [ZoneType; 6] = [
+        ZoneType::Harmonic,
+        ZoneType::Chaotic,
+        ZoneType::Meditative,
+        ZoneType::Quantum,
+        ZoneType::Temporal,
+        ZoneType::Void,
+    ];
+
+    /// Effects at the heart of a zone of this type at full strength
+    pub fn base_effects(self) -> TerritoryEffects {
+        match self {
+            ZoneType::Harmonic => TerritoryEffects {
+                harmonic_boost: 1.0,
+                social_boost: 0.8,
+                consciousness_growth_boost: 0.3,
+                ..Default::default()
+            },
+            ZoneType::Chaotic => TerritoryEffects {
+                chaos_boost: 1.0,
+                reality_distortion_boost: 0.8,
+                exploration_boost: 0.5,
+                ..Default::default()
+            },
+            ZoneType::Meditative => TerritoryEffects {
+                memory_boost: 1.0,
+                consciousness_growth_boost: 0.6,
+                social_boost: 0.2,
+                exploration_boost: -0.4, // Calm llamas wander less
+                energy_recharge: 1.0,
+                ..Default::default()
+            },
+            ZoneType::Quantum => TerritoryEffects {
+                quantum_boost: 1.0,
+                reality_distortion_boost: 0.3,
+                exploration_boost: 0.3,
+                ..Default::default()
+            },
+            ZoneType::Temporal => TerritoryEffects {
+                time_slowdown: 0.7,
+                memory_boost: 0.4,
+                ..Default::default()
+            },
+            ZoneType::Void => TerritoryEffects {
+                consciousness_drain: 1.0,
+                harmonic_boost: -0.5,
+                exploration_boost: 0.6, // Llamas want out
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl TerritoryZone {
+    pub fn new(center: Vec2, zone_type: ZoneType) -> Self {
+        let radius = match zone_type {
+            ZoneType::Harmonic => 80.0 + fastrand::f32() * 40.0,
+            ZoneType::Chaotic => 60.0 + fastrand::f32() * 60.0,
+            ZoneType::Meditative => 100.0 + fastrand::f32() * 50.0,
+            ZoneType::Quantum => 70.0 + fastrand::f32() * 30.0,
+            ZoneType::Temporal => 90.0 + fastrand::f32() * 40.0,
+            ZoneType::Void => 50.0 + fastrand::f32() * 30.0,
+        };
+
+        Self {
+            center,
+            radius,
+            zone_type,
+            strength: 0.3 + fastrand::f32() * 0.4,
+            age: 0.0,
+            lifespan: None,
+        }
+    }
+
+    /// A zone born from something that happened: it starts small, blooms, then fades over `lifespan`
+    pub fn emerging(center: Vec2, zone_type: ZoneType, lifespan: f32) -> Self {
+        let mut zone = Self::new(center, zone_type);
+        zone.radius *= 0.4;
+        zone.strength = 0.0;
+        zone.lifespan = Some(lifespan);
+        zone
+    }
+
+    /// Strength multiplier from the zone's life so far: blooming in, full, fading out
+    pub fn envelope(&self) -> f32 {
+        match self.lifespan {
+            Some(lifespan) => (self.age / ZONE_BLOOM_SECONDS).min(1.0) * (1.0 - self.age / lifespan).clamp(0.0, 1.0),
+            None => 1.0,
+        }
+    }
+
+    /// True once an emergent zone has lived out its lifespan
+    pub fn has_faded(&self) -> bool {
+        self.lifespan.is_some_and(|lifespan| self.age >= lifespan)
+    }
+
+    pub fn update(&mut self, dt: f32, cosmic_time: f64) {
+        self.age += dt;
+
+        // Zones slowly grow and change strength
+        let growth_rate = match self.zone_type {
+            ZoneType::Harmonic => 0.5,
+            ZoneType::Chaotic => 1.0,
+            ZoneType::Meditative => 0.3,
+            ZoneType::Quantum => 0.8,
+            ZoneType::Temporal => 0.2,
+            ZoneType::Void => 1.2, // Voids spread
+        };
+        let bloom = if self.lifespan.is_some() && self.age < ZONE_BLOOM_SECONDS { 6.0 } else { 1.0 };
+
+        self.radius += growth_rate * bloom * dt;
+        self.radius = self.radius.min(150.0);
+
+        // Strength oscillates
+        let oscillation = (cosmic_time as f32 * 0.5 + self.center.length() * 0.001).sin() * 0.1;
+        self.strength = (0.3 + fastrand::f32() * 0.4 + oscillation).clamp(0.1, 0.8) * self.envelope();
+    }
+
+    pub fn affects_position(&self, position: Vec2) -> f32 {
+        let distance = self.center.distance(position);
+        if distance < self.radius {
+            let factor = 1.0 - (distance / self.radius);
+            factor * self.strength
+        } else {
+            0.0
+        }
+    }
+
+    /// This zone's effects at `position`, fading towards its edge
+    pub fn effects_at(&self, position: Vec2) -> TerritoryEffects {
+        self.zone_type.base_effects().scaled(self.affects_position(position))
+    }
+}
+
+fn spawn_random_crystal(world: &mut World) -> (EntityId, Vec2) {
+    let position = Vec2::new(fastrand::f32() * 1200.0, fastrand::f32() * 800.0);
+    let crystal_type = match fastrand::usize(0..5) {
+        0 => CrystalType::Resonance,
+        1 => CrystalType::Chaos,
+        2 => CrystalType::Memory,
+        3 => CrystalType::Social,
+        _ => CrystalType::Quantum,
+    };
+    (world.spawn(ConsciousnessCrystal::new(position, crystal_type)), position)
+}
+
+/// Digital Ecosystem containing consciousness fields and territories.
+/// Crystals and reality tears live in the ECS world as `ConsciousnessCrystal`
+/// and `RealityTear` components; the ecosystem spawns, updates and despawns them
+#[derive(Debug)]
+pub struct DigitalEcosystem {
+    pub consciousness_fields: ConsciousnessField,
+    pub pheromones: PheromoneField,           // Species trails llamas lay and follow
+    pub chaos_accumulation: f32,              // Global chaos level from clicks
+    pub mutation_threshold: f32,              // When mutations trigger
+    pub territory_zones: Vec<TerritoryZone>,  // Different environmental regions
+    pub obstacles: Obstacles,                 // Walls and pillars llamas walk around and cannot see through
+    pub force_fields: ForceFields,            // Gravity wells, vortexes and winds acting on llamas and dust
+    pub tuning: ChaosTuning,                  // Odds of crystals, tears, mutations, wars, predation and hive links
+    pub zone_events: Vec<ZoneEvent>,          // Zone births, merges and fades since the last drain
+    pub recent_extinctions: Vec<(Vec2, f32)>, // Where and when llamas recently went extinct
+    pub crystal_index: SpatialHash,           // Crystal slots by position, rebuilt each tick before harvesting
+    pub llama_index: SpatialHash,             // Llama slots by position, rebuilt each tick before flocking
+    pub external_entropy: Option<ExternalEntropy>, // Latest real-world entropy reading, handed to every llama's chaos engine
+    pub tempo_bpm: f32,                       // The beat engine's primary rhythm, handed to every llama's movement
+    pub stagger: Option<BehaviorStagger>,     // Off-screen llamas updated in turns; None updates every llama every tick
+}
+
+impl DigitalEcosystem {
+    pub fn new(world: &mut World) -> Self {
+        let consciousness_fields = ConsciousnessField::new(1200.0, 800.0, 40); // 40x40 grid
+
+        // Start with a few crystals
+        for _ in 0..MIN_CRYSTALS {
+            spawn_random_crystal(world);
+        }
+
+        // Start with one territory zone
+        let mut territory_zones = Vec::new();
+        let zone_center = Vec2::new(fastrand::f32() * 1200.0, fastrand::f32() * 800.0);
+        let zone_type = ZoneType::ALL[fastrand::usize(..ZoneType::ALL.len())];
+        territory_zones.push(TerritoryZone::new(zone_center, zone_type));
+
+        Self {
+            consciousness_fields,
+            pheromones: PheromoneField::new(Vec2::new(1200.0, 800.0)),
+            chaos_accumulation: 0.0,
+            mutation_threshold: 3.0, // Mutations trigger when chaos reaches this level
+            territory_zones,
+            obstacles: Obstacles::default(),
+            force_fields: ForceFields::default(),
+            tuning: ChaosTuning::default(),
+            zone_events: Vec::new(),
+            recent_extinctions: Vec::new(),
+            crystal_index: SpatialHash::new(MAX_HARVEST_RADIUS),
+            llama_index: SpatialHash::new(FLOCK_RADIUS.default),
+            external_entropy: None,
+            tempo_bpm: 120.0,
+            stagger: None,
+        }
+    }
+
+    pub fn update(&mut self, dt: f32, cosmic_time: f64, beat_intensity: f32, world: &mut World, events: &mut EventBus) {
+        // Update consciousness fields
+        self.consciousness_fields.update(dt);
+        self.pheromones.update(dt);
+        self.force_fields.update(dt);
+
+        // Echoes of dead llamas leak their consciousness back into the field
+        update_echoes(world, &mut self.consciousness_fields, dt, events);
+
+        // Update crystals
+        for crystal in world.components_mut::<ConsciousnessCrystal>() {
+            crystal.update(dt, beat_intensity, cosmic_time);
+        }
+
+        // Update reality tears, despawning expired ones
+        world.retain::<RealityTear>(|id, tear| {
+            tear.update(dt, cosmic_time);
+            if tear.should_remove() {
+                events.publish(ChaosEvent::TearClosed { tear: id, position: tear.position });
+            }
+            !tear.should_remove()
+        });
+
+        // Handle fragmenting tears
+        let mut new_tears = Vec::new();
+        for tear in world.components::<RealityTear>() {
+            if matches!(tear.tear_type, TearType::Fragmenting) && tear.age > 2.0 && tear.size > 15.0 {
+                // Fragment into smaller tears
+                for _ in 0..3 {
+                    let offset = Vec2::new(
+                        (fastrand::f32() - 0.5) * 30.0,
+                        (fastrand::f32() - 0.5) * 30.0,
+                    );
+                    let fragment_pos = tear.position + offset;
+                    let fragment_type = if fastrand::f32() < 0.5 { TearType::Moving } else { TearType::Static };
+                    new_tears.push(RealityTear::new(fragment_pos, fragment_type));
+                }
+            }
+        }
+        for tear in new_tears {
+            let position = tear.position;
+            events.publish(ChaosEvent::TearOpened { tear: world.spawn(tear), position });
+        }
+
+        // Update territory zones, then merge overlapping ones and let emergent zones fade
+        for zone in &mut self.territory_zones {
+            zone.update(dt, cosmic_time);
+        }
+        self.update_zone_lifecycle();
+
+        // Spawn new crystals occasionally, and reseed as soon as depleted ones leave the field thin
+        if fastrand::f32() < self.tuning.crystal_rate * dt * (1.0 + beat_intensity) || world.count::<ConsciousnessCrystal>() < MIN_CRYSTALS {
+            let (crystal, position) = spawn_random_crystal(world);
+            events.publish(ChaosEvent::CrystalSpawned { crystal, position });
+        }
+
+        // Spawn reality tears from high chaos
+        if self.chaos_accumulation > 1.0 && fastrand::f32() < self.tuning.tear_rate * dt {
+            let position = Vec2::new(fastrand::f32() * 1200.0, fastrand::f32() * 800.0);
+            let tear_type = match fastrand::usize(0..4) {
+                0 => TearType::Static,
+                1 => TearType::Moving,
+                2 => TearType::Pulsing,
+                _ => TearType::Fragmenting,
+            };
+            let tear = world.spawn(RealityTear::new(position, tear_type));
+            events.publish(ChaosEvent::TearOpened { tear, position });
+        }
+
+        // Slow chaos decay
+        self.chaos_accumulation *= 0.995;
+    }
+
+    pub fn add_chaos(&mut self, amount: f32) {
+        self.chaos_accumulation += amount;
+    }
+
+    /// Check if conditions are met to trigger mutation events
+    pub fn should_trigger_mutation(&self) -> bool {
+        self.chaos_accumulation > self.mutation_threshold
+    }
+
+    /// Reset chaos accumulation after mutation event
+    pub fn reset_chaos_for_mutation(&mut self) {
+        self.chaos_accumulation = 0.0;
+    }
+
+    /// Combined effects of every zone covering this position
+    pub fn get_territory_effects(&self, position: glam::Vec2) -> TerritoryEffects {
+        self.territory_zones.iter()
+            .fold(TerritoryEffects::default(), |total, zone| total.combined(&zone.effects_at(position)))
+    }
+
+    /// Seal a tear by hand: nearby llamas take in what it was leaking and some
+    /// of the chaos that opened it settles. Returns how many llamas were rewarded
+    pub fn close_tear(&mut self, tear: EntityId, world: &mut World, events: &mut EventBus) -> Option<usize> {
+        let (position, intensity) = world.get_component::<RealityTear>(tear).map(|tear| (tear.position, tear.intensity))?;
+        world.despawn(tear);
+        events.publish(ChaosEvent::TearClosed { tear, position });
+
+        let mut rewarded = 0;
+        for llama in world.components_mut::<Llama>() {
+            let closeness = 1.0 - llama.position.distance(position) / TEAR_REWARD_RADIUS;
+            if closeness > 0.0 {
+                llama.consciousness += TEAR_CLOSE_REWARD * intensity * closeness;
+                rewarded += 1;
+            }
+        }
+        self.chaos_accumulation *= 0.8;
+        Some(rewarded)
+    }
+
+    /// Hold a tear open for `dt` seconds: it stops wandering, grows and stays
+    /// bright, and feeds the chaos. Returns where a quantum sheep slips through
+    /// each time it grows another `TEAR_BREAKTHROUGH_GROWTH`
+    pub fn widen_tear(&mut self, tear: EntityId, dt: f32, world: &mut World) -> Option<Vec2> {
+        let tear = world.get_component_mut::<RealityTear>(tear)?;
+        let before = tear.size;
+        tear.tear_type = TearType::Static;
+        tear.size = (tear.size + TEAR_WIDEN_RATE * dt).min(TEAR_MAX_SIZE);
+        tear.intensity = (tear.intensity + dt).min(1.0);
+        tear.age = (tear.age - dt).max(0.0);
+        self.add_chaos(dt * 0.5);
+
+        let broke_through = (tear.size / TEAR_BREAKTHROUGH_GROWTH).floor() > (before / TEAR_BREAKTHROUGH_GROWTH).floor();
+        broke_through.then(|| {
+            let angle = fastrand::f32() * std::f32::consts::TAU;
+            tear.position + Vec2::new(angle.cos(), angle.sin()) * tear.size * 0.5
+        })
+    }
+}
+
+/// Territory effects that can be applied to entities
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TerritoryEffects {
+    pub harmonic_boost: f32,
+    pub social_boost: f32,
+    pub chaos_boost: f32,
+    pub reality_distortion_boost: f32,
+    pub memory_boost: f32,
+    pub consciousness_growth_boost: f32,
+    pub quantum_boost: f32,
+    pub exploration_boost: f32,
+    pub time_slowdown: f32,       // 0 = normal time, 1 = frozen
+    pub consciousness_drain: f32, // Share of consciousness lost per second, scaled in apply
+    pub energy_recharge: f32,     // Metabolic rest, scaled by the metabolism's zone recharge
+}
+
+impl TerritoryEffects {
+    /// Every effect multiplied by `factor`
+    pub fn scaled(&self, factor: f32) -> Self {
+        Self {
+            harmonic_boost: self.harmonic_boost * factor,
+            social_boost: self.social_boost * factor,
+            chaos_boost: self.chaos_boost * factor,
+            reality_distortion_boost: self.reality_distortion_boost * factor,
+            memory_boost: self.memory_boost * factor,
+            consciousness_growth_boost: self.consciousness_growth_boost * factor,
+            quantum_boost: self.quantum_boost * factor,
+            exploration_boost: self.exploration_boost * factor,
+            time_slowdown: self.time_slowdown * factor,
+            consciousness_drain: self.consciousness_drain * factor,
+            energy_recharge: self.energy_recharge * factor,
+        }
+    }
+
+    /// Effects of two overlapping zones together
+    pub fn combined(&self, other: &Self) -> Self {
+        Self {
+            harmonic_boost: self.harmonic_boost + other.harmonic_boost,
+            social_boost: self.social_boost + other.social_boost,
+            chaos_boost: self.chaos_boost + other.chaos_boost,
+            reality_distortion_boost: self.reality_distortion_boost + other.reality_distortion_boost,
+            memory_boost: self.memory_boost + other.memory_boost,
+            consciousness_growth_boost: self.consciousness_growth_boost + other.consciousness_growth_boost,
+            quantum_boost: self.quantum_boost + other.quantum_boost,
+            exploration_boost: self.exploration_boost + other.exploration_boost,
+            time_slowdown: self.time_slowdown.max(other.time_slowdown), // Overlapping slow zones don't stop time
+            consciousness_drain: self.consciousness_drain + other.consciousness_drain,
+            energy_recharge: self.energy_recharge + other.energy_recharge,
+        }
+    }
+
+    /// Multiplier on the passage of time for a llama standing here
+    pub fn time_scale(&self) -> f32 {
+        1.0 - self.time_slowdown.clamp(0.0, 0.8)
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn zone(zone_type: ZoneType) -> TerritoryZone {
+        TerritoryZone { center: Vec2::new(600.0, 400.0), radius: 100.0, zone_type, strength: 0.8, age: 0.0, lifespan: None }
+    }
+
+    #[test]
+    fn test_zone_effects_fade_to_the_edge_and_reach_llamas() {
+        let mut world = World::new();
+        let mut ecosystem = DigitalEcosystem::new(&mut world);
+        ecosystem.territory_zones = vec![zone(ZoneType::Harmonic), zone(ZoneType::Temporal)];
+
+        let heart = ecosystem.get_territory_effects(Vec2::new(600.0, 400.0));
+        let edge = ecosystem.get_territory_effects(Vec2::new(690.0, 400.0));
+        assert!(heart.harmonic_boost > edge.harmonic_boost && edge.harmonic_boost > 0.0);
+        assert!(heart.social_boost > 0.0 && heart.time_scale() < 0.5);
+        assert_eq!(ecosystem.get_territory_effects(Vec2::new(100.0, 100.0)), TerritoryEffects::default());
+
+        ecosystem.territory_zones = vec![zone(ZoneType::Void)];
+        let mut llama = Llama::new(Vec2::new(600.0, 400.0));
+        let before = llama.consciousness;
+        llama.apply_territory_effects(&ecosystem.get_territory_effects(llama.position), 1.0);
+        assert!(llama.consciousness < before, "a void should drain consciousness");
+    }
+
+    #[test]
+    fn test_tears_widen_into_sheep_and_close_into_consciousness() {
+        let mut world = World::new();
+        let mut events = EventBus::default();
+        let mut ecosystem = DigitalEcosystem::new(&mut world);
+        let tear = world.spawn(RealityTear::new(Vec2::new(600.0, 400.0), TearType::Moving));
+
+        // Held open for ten seconds it reaches full size, letting sheep through on the way
+        let breakthroughs = (0..600).filter_map(|_| ecosystem.widen_tear(tear, 1.0 / 60.0, &mut world)).count();
+        let widened = world.get_component::<RealityTear>(tear).unwrap();
+        assert_eq!(widened.size, TEAR_MAX_SIZE);
+        assert!(!widened.should_remove(), "a tear held open stays open");
+        assert!(breakthroughs >= 4, "only {} sheep came through", breakthroughs);
+
+        let near = world.spawn(Llama::new(Vec2::new(620.0, 400.0)));
+        world.spawn(Llama::new(Vec2::new(100.0, 100.0)));
+        let before = world.get_component::<Llama>(near).unwrap().consciousness;
+        assert_eq!(ecosystem.close_tear(tear, &mut world, &mut events), Some(1));
+        assert!(world.get_component::<Llama>(near).unwrap().consciousness > before);
+        assert_eq!(world.count::<RealityTear>(), 0);
+        assert_eq!(ecosystem.close_tear(tear, &mut world, &mut events), None);
+    }
+}
@@ -0,0 +1,117 @@
+// === DEATH ECHOES ===
+// A llama that starves or goes extinct, whether worn down or absorbed by a
+// predator, leaves an echo where it died. The echo is a fading ghost that
+// leaks whatever consciousness the llama still held back into the
+// consciousness field over a few seconds. When it has faded, a llama with
+// strong memories may leave a Memory crystal behind. Llamas culled by the
+// population cap or removed by the host app leave nothing.
+
+use glam::Vec2;
+use crate::core::ecs::{EntityId, World};
+use crate::core::events::{ChaosEvent, EventBus};
+use crate::engine::population::{DespawnReason, Despawned};
+use crate::entities::SpeciesType;
+use super::consciousness_systems::{ConsciousnessCrystal, ConsciousnessField, CrystalType};
+
+/// Seconds an echo takes to fade
+pub const ECHO_LIFETIME: f32 = 6.0;
+/// Chance a fully memorious echo crystallizes into a Memory crystal as it fades
+const CRYSTALLIZE_CHANCE: f32 = 0.5;
+/// Echoes with less consciousness and memory than this are not worth leaving
+const MIN_ECHO: f32 = 0.02;
+
+/// The fading ghost of a dead llama
+#[derive(Debug, Clone)]
+pub struct LlamaEcho {
+    pub position: Vec2,
+    pub species: SpeciesType,
+    pub hue: f32,
+    pub consciousness: f32, // What the llama held when it died
+    pub remaining: f32,     // Consciousness not yet leaked into the field
+    pub memory: f32,        // 0-1 memory intensity, the chance of crystallizing
+    pub age: f32,
+}
+
+impl LlamaEcho {
+    /// 1.0 when fresh, falling to 0.0 as it fades
+    pub fn fade(&self) -> f32 {
+        (1.0 - self.age / ECHO_LIFETIME).clamp(0.0, 1.0)
+    }
+}
+
+/// Leave an echo for every llama that died of exhaustion or extinction, returning the echoes left
+pub fn spawn_echoes(world: &mut World, despawned: &[Despawned], events: &mut EventBus) -> Vec<EntityId> {
+    let mut echoes = Vec::new();
+    for dead in despawned {
+        if !matches!(dead.reason, DespawnReason::Extinct | DespawnReason::Exhausted) || dead.consciousness + dead.memory < MIN_ECHO {
+            continue;
+        }
+        let echo = world.spawn(LlamaEcho {
+            position: dead.position,
+            species: dead.species,
+            hue: dead.hue,
+            consciousness: dead.consciousness,
+            remaining: dead.consciousness,
+            memory: dead.memory,
+            age: 0.0,
+        });
+        events.publish(ChaosEvent::EchoReleased { echo, species: dead.species, position: dead.position, consciousness: dead.consciousness });
+        echoes.push(echo);
+    }
+    echoes
+}
+
+/// Leak every echo's consciousness into the field, and let faded ones go, crystallizing some
+pub fn update_echoes(world: &mut World, field: &mut ConsciousnessField, dt: f32, events: &mut EventBus) {
+    let mut faded: Vec<(EntityId, Vec2, f32)> = Vec::new();
+    world.retain::<LlamaEcho>(|id, echo| {
+        echo.age += dt;
+        let leaked = (echo.consciousness * dt / ECHO_LIFETIME).min(echo.remaining);
+        echo.remaining -= leaked;
+        field.add_consciousness_at(echo.position, leaked);
+        if echo.age >= ECHO_LIFETIME {
+            // Whatever rounding left behind goes into the field too
+            field.add_consciousness_at(echo.position, echo.remaining);
+            faded.push((id, echo.position, echo.memory));
+            return false;
+        }
+        true
+    });
+
+    for (echo, position, memory) in faded {
+        if fastrand::f32() < memory * CRYSTALLIZE_CHANCE {
+            let crystal = world.spawn(ConsciousnessCrystal::new(position, CrystalType::Memory));
+            events.publish(ChaosEvent::EchoCrystallized { echo, crystal, position });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn died(reason: DespawnReason, consciousness: f32, memory: f32) -> Despawned {
+        Despawned { entity: 0, species: SpeciesType::DiscoLlama, position: Vec2::new(300.0, 200.0), reason, consciousness, hue: 120.0, memory }
+    }
+
+    #[test]
+    fn test_echoes_return_consciousness_to_the_field() {
+        let mut world = World::new();
+        let mut events = EventBus::default();
+        let mut field = ConsciousnessField::new(1200.0, 800.0, 40);
+        let before = field.get_consciousness_at(Vec2::new(300.0, 200.0));
+
+        let despawned = [
+            died(DespawnReason::Exhausted, 0.6, 1.0),
+            died(DespawnReason::PopulationCap, 0.6, 1.0),
+        ];
+        assert_eq!(spawn_echoes(&mut world, &despawned, &mut events).len(), 1, "culled llamas leave no echo");
+
+        for _ in 0..7 {
+            update_echoes(&mut world, &mut field, 1.0, &mut events);
+        }
+        assert_eq!(world.count::<LlamaEcho>(), 0);
+        let gained = field.get_consciousness_at(Vec2::new(300.0, 200.0)) - before;
+        assert!((gained - 0.6).abs() < 1e-4, "the whole echo leaks back, got {gained}");
+    }
+}
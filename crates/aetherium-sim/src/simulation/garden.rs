@@ -0,0 +1,130 @@
+// === GARDEN LAYOUTS ===
+// A habitat designed by hand: territory zones placed and sized where the user
+// wants them, crystals planted, and walls and pillars the llamas have to walk
+// around. A layout replaces the random zones and crystals a new world starts
+// with; from then on they are ordinary zones and crystals that grow, pulse and
+// get harvested like any other. Obstacles stay put.
+
+use glam::Vec2;
+use serde::{Deserialize, Serialize};
+use crate::core::ecs::World;
+use super::consciousness_systems::{ConsciousnessCrystal, CrystalType, DigitalEcosystem, TerritoryZone, ZoneType};
+use super::obstacles::{Obstacles, Pillar, Wall};
+use super::WORLD_SIZE;
+
+/// Smallest and largest radius a designed zone may be given
+pub const MIN_ZONE_RADIUS: f32 = 20.0;
+pub const MAX_ZONE_RADIUS: f32 = 150.0;
+/// Shortest wall worth keeping
+const MIN_WALL_LENGTH: f32 = 4.0;
+
+/// A zone as it is placed in a layout
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ZoneLayout {
+    pub center: Vec2,
+    pub radius: f32,
+    pub zone_type: ZoneType,
+}
+
+/// A crystal as it is planted in a layout
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CrystalLayout {
+    pub position: Vec2,
+    pub crystal_type: CrystalType,
+}
+
+/// A designed starting environment
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GardenLayout {
+    #[serde(default)]
+    pub zones: Vec<ZoneLayout>,
+    #[serde(default)]
+    pub crystals: Vec<CrystalLayout>,
+    #[serde(default)]
+    pub walls: Vec<Wall>,
+    #[serde(default)]
+    pub pillars: Vec<Pillar>,
+}
+
+impl GardenLayout {
+    /// The permanent zones, crystals and obstacles of a running world
+    pub fn capture(world: &World, ecosystem: &DigitalEcosystem) -> Self {
+        Self {
+            zones: ecosystem.territory_zones.iter()
+                .filter(|zone| zone.lifespan.is_none())
+                .map(|zone| ZoneLayout { center: zone.center, radius: zone.radius, zone_type: zone.zone_type })
+                .collect(),
+            crystals: world.components::<ConsciousnessCrystal>().iter()
+                .map(|crystal| CrystalLayout { position: crystal.position, crystal_type: crystal.crystal_type.clone() })
+                .collect(),
+            walls: ecosystem.obstacles.walls.clone(),
+            pillars: ecosystem.obstacles.pillars.clone(),
+        }
+    }
+
+    /// The layout with everything inside the world: positions clamped, radii in range,
+    /// and non-finite values and walls too short to matter dropped
+    pub fn sanitized(mut self) -> Self {
+        let finite = |point: Vec2| point.is_finite();
+        let inside = |point: Vec2| point.clamp(Vec2::ZERO, WORLD_SIZE);
+        self.zones.retain(|zone| finite(zone.center) && zone.radius.is_finite());
+        for zone in &mut self.zones {
+            zone.center = inside(zone.center);
+            zone.radius = zone.radius.clamp(MIN_ZONE_RADIUS, MAX_ZONE_RADIUS);
+        }
+        self.crystals.retain(|crystal| finite(crystal.position));
+        for crystal in &mut self.crystals {
+            crystal.position = inside(crystal.position);
+        }
+        self.walls.retain(|wall| finite(wall.start) && finite(wall.end));
+        for wall in &mut self.walls {
+            *wall = Wall::new(inside(wall.start), inside(wall.end));
+        }
+        self.walls.retain(|wall| wall.start.distance(wall.end) >= MIN_WALL_LENGTH);
+        self.pillars.retain(|pillar| finite(pillar.center) && pillar.radius.is_finite());
+        for pillar in &mut self.pillars {
+            *pillar = Pillar::new(inside(pillar.center), pillar.radius);
+        }
+        self
+    }
+
+    /// Replace the world's zones, crystals and obstacles with the layout's
+    pub fn apply(&self, world: &mut World, ecosystem: &mut DigitalEcosystem) {
+        world.retain::<ConsciousnessCrystal>(|_, _| false);
+        for crystal in &self.crystals {
+            world.spawn(ConsciousnessCrystal::new(crystal.position, crystal.crystal_type.clone()));
+        }
+        ecosystem.territory_zones = self.zones.iter().map(|layout| {
+            let mut zone = TerritoryZone::new(layout.center, layout.zone_type);
+            zone.radius = layout.radius;
+            zone
+        }).collect();
+        ecosystem.obstacles = Obstacles { walls: self.walls.clone(), pillars: self.pillars.clone() };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::obstacles::MIN_PILLAR_RADIUS;
+
+    #[test]
+    fn test_layouts_replace_the_starting_environment() {
+        let mut world = World::new();
+        let mut ecosystem = DigitalEcosystem::new(&mut world);
+        let layout = GardenLayout {
+            zones: vec![ZoneLayout { center: Vec2::new(-50.0, 400.0), radius: 900.0, zone_type: ZoneType::Meditative }],
+            crystals: vec![CrystalLayout { position: Vec2::new(300.0, 300.0), crystal_type: CrystalType::Memory }],
+            walls: vec![Wall::new(Vec2::new(10.0, 10.0), Vec2::new(10.0, 11.0)), Wall::new(Vec2::new(0.0, 400.0), Vec2::new(600.0, 400.0))],
+            pillars: vec![Pillar { center: Vec2::new(900.0, 900.0), radius: 1.0 }],
+        }.sanitized();
+        assert_eq!(layout.zones[0].center.x, 0.0);
+        assert_eq!(layout.zones[0].radius, MAX_ZONE_RADIUS);
+        assert_eq!(layout.walls.len(), 1, "walls too short to matter are dropped");
+        assert_eq!(layout.pillars[0], Pillar::new(Vec2::new(900.0, 800.0), MIN_PILLAR_RADIUS));
+
+        layout.apply(&mut world, &mut ecosystem);
+        assert_eq!(world.count::<ConsciousnessCrystal>(), 1);
+        assert_eq!(GardenLayout::capture(&world, &ecosystem), layout);
+    }
+}
@@ -0,0 +1,434 @@
+// === HEADLESS SIMULATION ===
+// The llama, ecosystem and consciousness multiplication core without a window,
+// GPU or audio device. The app drives the same steps each frame; tests and
+// tools run it directly from a seed.
+
+use glam::Vec2;
+use std::sync::mpsc::Receiver;
+use crate::core::ecs::{EntityId, World};
+use crate::core::events::{ChaosEvent, EventBus};
+use crate::engine::{AdvancedBeatEngine, ConsciousnessMultiplicationSystem, HomeostasisConfig, Metabolism, MetabolismConfig, PopulationBalancer};
+use crate::engine::population::{self, CullPolicy, Despawned, DEFAULT_MAX_POPULATION};
+use crate::core::spatial::SpatialHash;
+use crate::entities::{FlockingConfig, Llama, SpeciesType, flocking_force};
+use super::benchmark::{SimStage, StageTimings};
+use super::echoes;
+use crate::simulation::{ConsciousnessCrystal, CrystalType, DigitalEcosystem, NumericGuard, RealityTear, TearType, WorldSnapshot, MAX_HARVEST_RADIUS};
+
+/// Fixed simulation tick
+pub const SIMULATION_DT: f32 = 1.0 / 60.0;
+/// Scent a llama leaves per second where it walks
+const TRAIL_DEPOSIT: f32 = 1.0;
+/// Acceleration, in world units per second squared, of a llama following the strongest trail
+const TRAIL_FOLLOW: f32 = 25.0;
+
+/// Behavior updates of the llamas outside the camera, spread across ticks. Each
+/// off-screen llama takes its turn once in `every` ticks and catches up on the
+/// time it skipped, so it ends up where it would have been, just less smoothly.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BehaviorStagger {
+    pub min: Vec2,  // Region the camera shows, in world units; llamas inside update every tick
+    pub max: Vec2,
+    pub every: u32, // Ticks between the updates of an off-screen llama
+    tick: u32,
+}
+
+impl BehaviorStagger {
+    pub fn new(min: Vec2, max: Vec2, every: u32) -> Self {
+        Self { min, max, every: every.max(1), tick: 0 }
+    }
+
+    /// Follow a moved camera without restarting the rotation of turns
+    pub fn retarget(&mut self, min: Vec2, max: Vec2, every: u32) {
+        self.min = min;
+        self.max = max;
+        self.every = every.max(1);
+    }
+
+    /// Ticks of time llama `entity` covers this tick; zero skips it. Turns follow the
+    /// entity rather than its slot, so a despawn elsewhere never moves a llama's turn
+    pub fn ticks_for(&self, entity: EntityId, position: Vec2) -> u32 {
+        let on_screen = position.cmpge(self.min).all() && position.cmple(self.max).all();
+        if on_screen || self.every == 1 {
+            1
+        } else if entity.wrapping_add(self.tick).is_multiple_of(self.every) {
+            self.every
+        } else {
+            0
+        }
+    }
+}
+
+/// Per-llama ecosystem coupling and flocking followed by each llama's own behavior update.
+/// Returns the species that harvested a crystal this tick, each listed once.
+pub fn step_llamas(world: &mut World, ecosystem: &mut DigitalEcosystem, flocking: &FlockingConfig, dt: f32, beat_intensity: f32, cosmic_time: f64, events: &mut EventBus) -> Vec<SpeciesType> {
+    // Llamas react to the population as it was at the start of the tick
+    let llamas_snapshot = world.components::<Llama>().to_vec();
+    if ecosystem.llama_index.cell_size() != flocking.max_radius() {
+        ecosystem.llama_index = SpatialHash::new(flocking.max_radius());
+    }
+    ecosystem.llama_index.rebuild(llamas_snapshot.iter().map(|llama| llama.position));
+    let mut flock = Vec::new();
+    let llama_ids = world.entities_with::<Llama>().to_vec();
+    let crystal_ids = world.entities_with::<ConsciousnessCrystal>().to_vec();
+    let (llamas, crystals) = world.components_mut2::<Llama, ConsciousnessCrystal>();
+    let crystal_positions: Vec<Vec2> = crystals.iter().map(|crystal| crystal.position).collect();
+    ecosystem.crystal_index.rebuild(crystal_positions.iter().copied());
+    let mut nearby = Vec::new();
+    let mut harvesters = Vec::new();
+    let mut chaos_sites: Vec<Vec2> = Vec::new();
+    let mut depleted = Vec::new();
+    if let Some(stagger) = &mut ecosystem.stagger {
+        stagger.tick = stagger.tick.wrapping_add(1);
+    }
+    let stagger = ecosystem.stagger;
+    for (i, llama) in llamas.iter_mut().enumerate() {
+        let ticks = stagger.map_or(1, |stagger| stagger.ticks_for(llama_ids[i], llama.position));
+        if ticks == 0 {
+            continue;
+        }
+        let dt = dt * ticks as f32;
+
+        // Zone effects where the llama stands; temporal zones slow its own clock
+        let territory_effects = ecosystem.get_territory_effects(llama.position);
+        let local_dt = dt * territory_effects.time_scale();
+
+        // Update consciousness field
+        let environmental_consciousness = ecosystem.consciousness_fields.get_consciousness_at(llama.position);
+        llama.environmental_consciousness = (llama.environmental_consciousness + environmental_consciousness * 0.01).min(2.0);
+
+        // Add consciousness to the field where llama is
+        ecosystem.consciousness_fields.add_consciousness_at(llama.position, llama.consciousness * 0.001);
+
+        // Flock, lean along kin trails and steer around obstacles unless the observer is steering, then mark the way
+        if llama.possessed_steering.is_none() {
+            let herd = flocking_force(&llamas_snapshot, i, &ecosystem.llama_index, flocking, &mut flock);
+            let trail = ecosystem.pheromones.steering(llama.position, llama.species) * TRAIL_FOLLOW;
+            let avoid = ecosystem.obstacles.avoidance(llama.position, llama.velocity);
+            llama.velocity += (herd + trail + avoid) * local_dt;
+        }
+        // World forces move every llama, steered or not
+        llama.velocity += ecosystem.force_fields.acceleration_at(llama.position) * local_dt;
+        ecosystem.pheromones.deposit(llama.position, llama.species, TRAIL_DEPOSIT * local_dt);
+
+        // Try to harvest the crystals within reach
+        ecosystem.crystal_index.query_into(llama.position, MAX_HARVEST_RADIUS, &mut nearby);
+        for &slot in &nearby {
+            let crystal = &mut crystals[slot];
+            let before = crystal.harvested_total;
+            if !llama.try_harvest_crystal(crystal) {
+                continue;
+            }
+            events.publish(ChaosEvent::CrystalHarvested {
+                llama: llama_ids[i],
+                species: llama.species,
+                position: crystal.position,
+                amount: crystal.harvested_total - before,
+            });
+            if crystal.is_depleted() && !depleted.contains(&crystal_ids[slot]) {
+                depleted.push(crystal_ids[slot]);
+            }
+            match crystal.crystal_type {
+                CrystalType::Memory => llama.recall_crystals(&crystal_positions),
+                CrystalType::Chaos if !chaos_sites.contains(&crystal.position) => chaos_sites.push(crystal.position),
+                _ => {}
+            }
+            if !harvesters.contains(&llama.species) {
+                harvesters.push(llama.species);
+            }
+        }
+
+        llama.chaos_engine.external_entropy = ecosystem.external_entropy;
+        llama.tempo_bpm = ecosystem.tempo_bpm;
+        let before = llama.position;
+        llama.update(local_dt, beat_intensity, &llamas_snapshot, &llama_ids, i, cosmic_time);
+        llama.apply_territory_effects(&territory_effects, dt);
+        ecosystem.obstacles.block(before, &mut llama.position, &mut llama.velocity);
+    }
+
+    // Used-up crystals leave the field the moment their last harvest is taken
+    for crystal in depleted {
+        world.despawn(crystal);
+    }
+
+    // Harvested chaos crystals leave a small tear in reality behind
+    for site in chaos_sites {
+        let mut tear = RealityTear::new(site, TearType::Static);
+        tear.size *= 0.5;
+        events.publish(ChaosEvent::TearOpened { tear: world.spawn(tear), position: site });
+    }
+    harvesters
+}
+
+/// Mutate a third of the population when the ecosystem has built up enough chaos
+pub fn apply_ecosystem_mutations(world: &mut World, ecosystem: &mut DigitalEcosystem) {
+    if !ecosystem.should_trigger_mutation() {
+        return;
+    }
+    let mutation_strength = 0.3 + ecosystem.chaos_accumulation * 0.1;
+
+    let llamas = world.components_mut::<Llama>();
+    let mutation_count = ((llamas.len() as f32 * ecosystem.tuning.mutation_share) as usize).max(1); // The tuned share of llamas, minimum 1
+    for _ in 0..mutation_count {
+        if !llamas.is_empty() {
+            let index = fastrand::usize(0..llamas.len());
+            llamas[index].apply_mutation(mutation_strength);
+        }
+    }
+
+    ecosystem.reset_chaos_for_mutation();
+}
+
+/// Seeded simulation core that runs without any rendering or audio
+pub struct HeadlessSimulation {
+    pub world: World,
+    pub ecosystem: DigitalEcosystem,
+    pub consciousness_multiplication: ConsciousnessMultiplicationSystem,
+    beat_engine: AdvancedBeatEngine,
+    beat_intensity: f32,
+    time: f32,
+    max_population: usize,
+    cull_policy: CullPolicy,
+    despawned: Vec<Despawned>,
+    numeric_guard: NumericGuard,
+    events: EventBus,
+    homeostasis: Option<PopulationBalancer>,
+    metabolism: Option<Metabolism>,
+    flocking: FlockingConfig,
+    timings: Option<StageTimings>, // Per-system time, for the benchmark
+}
+
+impl HeadlessSimulation {
+    /// Seed the RNG and spawn `initial_population` llamas cycling through the species
+    pub fn new(seed: u64, initial_population: usize) -> Self {
+        fastrand::seed(seed);
+
+        let starting_species = SpeciesType::ALL;
+        let mut world = World::new();
+        for i in 0..initial_population {
+            world.spawn(Llama::new_with_species(
+                Vec2::new(fastrand::f32() * 1200.0, fastrand::f32() * 800.0),
+                starting_species[i % starting_species.len()]
+            ));
+        }
+        let ecosystem = DigitalEcosystem::new(&mut world);
+
+        Self {
+            world,
+            ecosystem,
+            consciousness_multiplication: ConsciousnessMultiplicationSystem::new(),
+            beat_engine: AdvancedBeatEngine::new(),
+            beat_intensity: 0.0,
+            time: 0.0,
+            max_population: DEFAULT_MAX_POPULATION,
+            cull_policy: CullPolicy::Oldest,
+            despawned: Vec::new(),
+            numeric_guard: NumericGuard::new(),
+            events: EventBus::default(),
+            homeostasis: None,
+            metabolism: None,
+            flocking: FlockingConfig::default(),
+            timings: None,
+        }
+    }
+
+    /// Fork a new simulation from a past moment
+    pub fn from_snapshot(seed: u64, snapshot: &WorldSnapshot) -> Self {
+        let mut simulation = Self::new(seed, 0);
+        simulation.world = snapshot.to_world();
+        simulation.ecosystem.territory_zones = snapshot.zones.clone();
+        simulation.time = snapshot.time;
+        simulation
+    }
+
+    pub fn snapshot(&self) -> WorldSnapshot {
+        WorldSnapshot::capture(&self.world, &self.ecosystem.territory_zones, self.time)
+    }
+
+    pub fn with_population_cap(mut self, max_population: usize, cull_policy: CullPolicy) -> Self {
+        self.max_population = max_population;
+        self.cull_policy = cull_policy;
+        self
+    }
+
+    /// Keep every species' share of the population inside its band
+    pub fn with_homeostasis(mut self, config: HomeostasisConfig) -> Self {
+        self.homeostasis = Some(PopulationBalancer::new(config));
+        self
+    }
+
+    pub fn homeostasis(&self) -> Option<&PopulationBalancer> {
+        self.homeostasis.as_ref()
+    }
+
+    /// Make llamas spend energy and starve without crystals or rest
+    pub fn with_metabolism(mut self, config: MetabolismConfig) -> Self {
+        self.metabolism = Some(Metabolism::new(config));
+        self
+    }
+
+    pub fn metabolism(&self) -> Option<&Metabolism> {
+        self.metabolism.as_ref()
+    }
+
+    /// Weigh each species' boid rules differently, or turn flocking off with `FlockingConfig::disabled`
+    pub fn with_flocking(mut self, config: FlockingConfig) -> Self {
+        self.flocking = config;
+        self
+    }
+
+    /// Panic naming the subsystem as soon as a llama value turns non-finite, instead of repairing it
+    pub fn with_strict_numeric_guard(mut self) -> Self {
+        self.numeric_guard = NumericGuard::strict();
+        self
+    }
+
+    /// Time each system of every tick from now on
+    pub fn with_stage_timings(mut self) -> Self {
+        self.timings = Some(StageTimings::default());
+        self
+    }
+
+    pub fn stage_timings(&self) -> Option<&StageTimings> {
+        self.timings.as_ref()
+    }
+
+    fn lap(&mut self, stage: SimStage) {
+        if let Some(timings) = &mut self.timings {
+            timings.lap(stage);
+        }
+    }
+
+    /// Advance one fixed tick, in the same order as the app's update
+    pub fn step(&mut self) {
+        if let Some(timings) = &mut self.timings {
+            timings.start();
+        }
+        self.time += SIMULATION_DT;
+        let cosmic_time = self.time as f64;
+
+        let total_consciousness = self.llamas().iter()
+            .map(|llama| llama.consciousness + llama.awareness_level + llama.environmental_consciousness)
+            .sum::<f32>();
+        let previous_beat = self.beat_intensity;
+        self.beat_intensity = self.beat_engine.update(SIMULATION_DT, total_consciousness);
+        if let Some(drop) = ChaosEvent::beat_drop(previous_beat, self.beat_intensity, cosmic_time) {
+            self.events.publish(drop);
+        }
+        self.lap(SimStage::BeatEngine);
+
+        self.numeric_guard.check(&mut self.world, "host input", self.time);
+        self.ecosystem.update(SIMULATION_DT, cosmic_time, self.beat_intensity, &mut self.world, &mut self.events);
+        self.numeric_guard.check(&mut self.world, "ecosystem", self.time);
+        self.lap(SimStage::Ecosystem);
+        self.consciousness_multiplication.update(SIMULATION_DT, &mut self.world, &self.ecosystem, self.time, self.beat_intensity, &mut self.events);
+        self.numeric_guard.check(&mut self.world, "consciousness multiplication", self.time);
+        self.lap(SimStage::Multiplication);
+        if let Some(balancer) = &mut self.homeostasis {
+            balancer.update(self.world.components_mut::<Llama>(), SIMULATION_DT);
+        }
+        if let Some(metabolism) = &mut self.metabolism {
+            metabolism.update(self.world.components_mut::<Llama>(), &self.ecosystem, SIMULATION_DT);
+        }
+
+        let first_despawn = self.despawned.len();
+        let mut despawned = population::collect_extinct(&mut self.world, SIMULATION_DT);
+        if let Some(metabolism) = &self.metabolism {
+            despawned.extend(metabolism.collect_starved(&mut self.world));
+        }
+        despawned.extend(population::enforce_population_cap(&mut self.world, self.max_population, self.cull_policy));
+        self.record_despawns(despawned);
+
+        let resolved_fronts: Vec<Vec2> = self.consciousness_multiplication.drain_resolved_fronts().collect();
+        self.ecosystem.grow_zones_from_history(&self.world, &resolved_fronts, &self.despawned[first_despawn..], self.time);
+        self.lap(SimStage::Population);
+
+        self.ecosystem.tempo_bpm = self.beat_engine.primary_rhythm;
+        step_llamas(&mut self.world, &mut self.ecosystem, &self.flocking, SIMULATION_DT, self.beat_intensity, cosmic_time, &mut self.events);
+        self.numeric_guard.check(&mut self.world, "llama behavior", self.time);
+        self.lap(SimStage::Llamas);
+        apply_ecosystem_mutations(&mut self.world, &mut self.ecosystem);
+        self.numeric_guard.check(&mut self.world, "mutations", self.time);
+        self.lap(SimStage::Mutations);
+
+        self.beat_intensity *= 0.98;
+    }
+
+    pub fn run(&mut self, ticks: usize) {
+        for _ in 0..ticks {
+            self.step();
+        }
+    }
+
+    pub fn spawn(&mut self, species: SpeciesType, position: Vec2) {
+        let culled = population::enforce_population_cap(
+            &mut self.world,
+            self.max_population.saturating_sub(1),
+            self.cull_policy,
+        );
+        self.record_despawns(culled);
+        let entity = self.world.spawn(Llama::new_with_species(position, species));
+        self.events.publish(ChaosEvent::LlamaSpawned { entity, species, position });
+    }
+
+    fn record_despawns(&mut self, despawned: Vec<Despawned>) {
+        echoes::spawn_echoes(&mut self.world, &despawned, &mut self.events);
+        for removed in &despawned {
+            self.events.publish(removed.event());
+        }
+        self.despawned.extend(despawned);
+    }
+
+    pub fn llamas(&self) -> &[Llama] {
+        self.world.components::<Llama>()
+    }
+
+    pub fn time(&self) -> f32 {
+        self.time
+    }
+
+    pub fn beat_intensity(&self) -> f32 {
+        self.beat_intensity
+    }
+
+    pub fn beat_engine(&self) -> &AdvancedBeatEngine {
+        &self.beat_engine
+    }
+
+    /// Receive every event the simulation publishes from now on
+    pub fn subscribe(&mut self) -> Receiver<ChaosEvent> {
+        self.events.subscribe()
+    }
+
+    pub fn events(&self) -> &EventBus {
+        &self.events
+    }
+
+    /// Llamas removed since the last call
+    pub fn drain_despawned(&mut self) -> std::vec::Drain<'_, Despawned> {
+        self.despawned.drain(..)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_off_screen_turns_follow_the_llama_not_its_slot() {
+        let mut stagger = BehaviorStagger::new(Vec2::ZERO, Vec2::splat(100.0), 4);
+        let off_screen = Vec2::splat(500.0);
+        assert_eq!(stagger.ticks_for(7, Vec2::splat(50.0)), 1, "on screen every tick");
+
+        // Over one rotation each llama gets exactly one turn covering all of it,
+        // decided by its id alone
+        let mut covered = [0; 3];
+        for _ in 0..4 {
+            stagger.tick = stagger.tick.wrapping_add(1);
+            for (slot, entity) in [5, 9, 12].into_iter().enumerate() {
+                covered[slot] += stagger.ticks_for(entity, off_screen);
+            }
+        }
+        assert_eq!(covered, [4, 4, 4]);
+    }
+}
@@ -0,0 +1,423 @@
+// === WORLD HISTORY ===
+// A ring buffer of compact world snapshots, one every few seconds for the last
+// hour, for time-lapse replay and for branching a new live simulation off a
+// past moment. Snapshots keep what is visible and what drives the herd: each
+// llama packs into 16 bytes of quantized position, motion, color, species and
+// consciousness, crystals and reality tears into a few bytes more, and the
+// territory zones of the moment are kept as they were. Rebuilt llamas get
+// fresh minds; personality, memories and hive membership regrow. A snapshot
+// also flattens to a few bytes per record for autosaves and crash dumps.
+
+use std::collections::VecDeque;
+use glam::Vec2;
+use crate::core::ecs::{EntityId, World};
+use crate::entities::{Llama, SpeciesType};
+use super::{ConsciousnessCrystal, CrystalType, RealityTear, TearType, TerritoryZone, ZoneType};
+
+/// Seconds between snapshots
+pub const SNAPSHOT_INTERVAL: f32 = 5.0;
+/// Seconds of history kept
+pub const HISTORY_SECONDS: f32 = 3600.0;
+/// Positions are stored over the world plus this margin on every side
+const POSITION_MARGIN: f32 = 100.0;
+const WORLD_EXTENT: Vec2 = Vec2::new(1200.0, 800.0);
+/// Velocities are stored in tenths of a world unit per second
+const VELOCITY_SCALE: f32 = 10.0;
+/// Consciousness is stored in hundredths, up to 655.35
+const CONSCIOUSNESS_SCALE: f32 = 100.0;
+
+const CRYSTAL_TYPES: [CrystalType; 5] = [CrystalType::Resonance, CrystalType::Chaos, CrystalType::Memory, CrystalType::Social, CrystalType::Quantum];
+const TEAR_TYPES: [TearType; 4] = [TearType::Static, TearType::Moving, TearType::Pulsing, TearType::Fragmenting];
+/// Leads every flattened snapshot; the last byte is the format version
+const SNAPSHOT_MAGIC: [u8; 5] = *b"ABWS\x01";
+
+fn pack_unit(value: f32, max: f32) -> u8 {
+    (value / max).clamp(0.0, 1.0).mul_add(255.0, 0.5) as u8
+}
+
+fn unpack_unit(value: u8, max: f32) -> f32 {
+    value as f32 / 255.0 * max
+}
+
+fn pack_position(position: Vec2) -> [u16; 2] {
+    let span = WORLD_EXTENT + Vec2::splat(POSITION_MARGIN * 2.0);
+    let unit = ((position + Vec2::splat(POSITION_MARGIN)) / span).clamp(Vec2::ZERO, Vec2::ONE);
+    [(unit.x * 65535.0).round() as u16, (unit.y * 65535.0).round() as u16]
+}
+
+fn unpack_position(packed: [u16; 2]) -> Vec2 {
+    let span = WORLD_EXTENT + Vec2::splat(POSITION_MARGIN * 2.0);
+    Vec2::new(packed[0] as f32, packed[1] as f32) / 65535.0 * span - Vec2::splat(POSITION_MARGIN)
+}
+
+/// One llama in 16 bytes
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PackedLlama {
+    position: [u16; 2],
+    velocity: [i16; 2],
+    hue: u16,
+    saturation: u8,
+    species: u8,
+    consciousness: u16,
+    awareness: u8,
+    trip_intensity: u8,
+}
+
+impl PackedLlama {
+    fn pack(llama: &Llama) -> Self {
+        let velocity = (llama.velocity * VELOCITY_SCALE).clamp(Vec2::splat(i16::MIN as f32), Vec2::splat(i16::MAX as f32));
+        Self {
+            position: pack_position(llama.position),
+            velocity: [velocity.x as i16, velocity.y as i16],
+            hue: (llama.color.x.rem_euclid(360.0) / 360.0 * 65535.0).round() as u16,
+            saturation: pack_unit(llama.color.y, 1.0),
+            species: llama.species.to_index() as u8,
+            consciousness: (llama.consciousness * CONSCIOUSNESS_SCALE).clamp(0.0, u16::MAX as f32) as u16,
+            awareness: pack_unit(llama.awareness_level, 1.0),
+            trip_intensity: pack_unit(llama.trip_intensity, 2.0),
+        }
+    }
+
+    fn unpack(&self) -> Llama {
+        let species = SpeciesType::ALL[(self.species as usize).min(SpeciesType::ALL.len() - 1)];
+        let mut llama = Llama::new_with_species(unpack_position(self.position), species);
+        llama.velocity = Vec2::new(self.velocity[0] as f32, self.velocity[1] as f32) / VELOCITY_SCALE;
+        llama.color = Vec2::new(self.hue as f32 / 65535.0 * 360.0, unpack_unit(self.saturation, 1.0));
+        llama.consciousness = self.consciousness as f32 / CONSCIOUSNESS_SCALE;
+        llama.awareness_level = unpack_unit(self.awareness, 1.0);
+        llama.trip_intensity = unpack_unit(self.trip_intensity, 2.0);
+        llama
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct PackedCrystal {
+    position: [u16; 2],
+    crystal_type: u8,
+    energy: u16, // Hundredths
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct PackedTear {
+    position: [u16; 2],
+    size: u16, // Tenths of a world unit
+    intensity: u8,
+    tear_type: u8,
+}
+
+/// The visible state of the world at one moment
+#[derive(Debug, Clone)]
+pub struct WorldSnapshot {
+    pub time: f32,
+    pub zones: Vec<TerritoryZone>,
+    llamas: Vec<PackedLlama>,
+    crystals: Vec<PackedCrystal>,
+    tears: Vec<PackedTear>,
+}
+
+impl WorldSnapshot {
+    pub fn capture(world: &World, zones: &[TerritoryZone], time: f32) -> Self {
+        let crystals = world.components::<ConsciousnessCrystal>().iter().map(|crystal| PackedCrystal {
+            position: pack_position(crystal.position),
+            crystal_type: CRYSTAL_TYPES.iter().position(|kind| *kind == crystal.crystal_type).unwrap_or(0) as u8,
+            energy: (crystal.consciousness_energy * 100.0).clamp(0.0, u16::MAX as f32) as u16,
+        }).collect();
+        let tears = world.components::<RealityTear>().iter().map(|tear| PackedTear {
+            position: pack_position(tear.position),
+            size: (tear.size * 10.0).clamp(0.0, u16::MAX as f32) as u16,
+            intensity: pack_unit(tear.intensity, 1.0),
+            tear_type: TEAR_TYPES.iter().position(|kind| std::mem::discriminant(kind) == std::mem::discriminant(&tear.tear_type)).unwrap_or(0) as u8,
+        }).collect();
+        let llamas = world.components::<Llama>().iter().map(PackedLlama::pack).collect();
+        Self { time, zones: zones.to_vec(), llamas, crystals, tears }
+    }
+
+    /// A fresh world holding the snapshot's llamas, crystals and tears
+    pub fn to_world(&self) -> World {
+        self.to_world_from(0)
+    }
+
+    /// Like `to_world`, with ids handed out from `first_id` so they follow on from a world still referenced elsewhere
+    pub fn to_world_from(&self, first_id: EntityId) -> World {
+        let mut world = World::starting_at(first_id);
+        for llama in &self.llamas {
+            world.spawn(llama.unpack());
+        }
+        for crystal in &self.crystals {
+            let crystal_type = CRYSTAL_TYPES[(crystal.crystal_type as usize).min(CRYSTAL_TYPES.len() - 1)].clone();
+            let mut restored = ConsciousnessCrystal::new(unpack_position(crystal.position), crystal_type);
+            restored.consciousness_energy = crystal.energy as f32 / 100.0;
+            world.spawn(restored);
+        }
+        for tear in &self.tears {
+            let tear_type = TEAR_TYPES[(tear.tear_type as usize).min(TEAR_TYPES.len() - 1)].clone();
+            let mut restored = RealityTear::new(unpack_position(tear.position), tear_type);
+            restored.size = tear.size as f32 / 10.0;
+            restored.intensity = unpack_unit(tear.intensity, 1.0);
+            world.spawn(restored);
+        }
+        world
+    }
+
+    pub fn population(&self) -> usize {
+        self.llamas.len()
+    }
+
+    /// The snapshot flattened to little-endian bytes, for writing to disk
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.size_bytes() + 32);
+        bytes.extend_from_slice(&SNAPSHOT_MAGIC);
+        bytes.extend_from_slice(&self.time.to_le_bytes());
+        for count in [self.zones.len(), self.llamas.len(), self.crystals.len(), self.tears.len()] {
+            bytes.extend_from_slice(&(count as u32).to_le_bytes());
+        }
+        for zone in &self.zones {
+            for value in [zone.center.x, zone.center.y, zone.radius, zone.strength, zone.age, zone.lifespan.unwrap_or(-1.0)] {
+                bytes.extend_from_slice(&value.to_le_bytes());
+            }
+            bytes.push(ZoneType::ALL.iter().position(|kind| *kind == zone.zone_type).unwrap_or(0) as u8);
+        }
+        for llama in &self.llamas {
+            for value in [llama.position[0], llama.position[1], llama.velocity[0] as u16, llama.velocity[1] as u16, llama.hue, llama.consciousness] {
+                bytes.extend_from_slice(&value.to_le_bytes());
+            }
+            bytes.extend_from_slice(&[llama.saturation, llama.species, llama.awareness, llama.trip_intensity]);
+        }
+        for crystal in &self.crystals {
+            for value in [crystal.position[0], crystal.position[1], crystal.energy] {
+                bytes.extend_from_slice(&value.to_le_bytes());
+            }
+            bytes.push(crystal.crystal_type);
+        }
+        for tear in &self.tears {
+            for value in [tear.position[0], tear.position[1], tear.size] {
+                bytes.extend_from_slice(&value.to_le_bytes());
+            }
+            bytes.extend_from_slice(&[tear.intensity, tear.tear_type]);
+        }
+        bytes
+    }
+
+    /// A snapshot read back from `to_bytes`; None if the bytes are not one
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let mut reader = ByteReader { bytes: bytes.strip_prefix(&SNAPSHOT_MAGIC)? };
+        let time = reader.f32()?;
+        let [zones, llamas, crystals, tears] = [reader.u32()?, reader.u32()?, reader.u32()?, reader.u32()?].map(|count| count as usize);
+        let zones = (0..zones).map(|_| {
+            let [x, y, radius, strength, age, lifespan] = [reader.f32()?, reader.f32()?, reader.f32()?, reader.f32()?, reader.f32()?, reader.f32()?];
+            Some(TerritoryZone {
+                center: Vec2::new(x, y),
+                radius,
+                zone_type: *ZoneType::ALL.get(reader.u8()? as usize)?,
+                strength,
+                age,
+                lifespan: (lifespan >= 0.0).then_some(lifespan),
+            })
+        }).collect::<Option<_>>()?;
+        let llamas = (0..llamas).map(|_| Some(PackedLlama {
+            position: [reader.u16()?, reader.u16()?],
+            velocity: [reader.u16()? as i16, reader.u16()? as i16],
+            hue: reader.u16()?,
+            consciousness: reader.u16()?,
+            saturation: reader.u8()?,
+            species: reader.u8()?,
+            awareness: reader.u8()?,
+            trip_intensity: reader.u8()?,
+        })).collect::<Option<_>>()?;
+        let crystals = (0..crystals).map(|_| Some(PackedCrystal {
+            position: [reader.u16()?, reader.u16()?],
+            energy: reader.u16()?,
+            crystal_type: reader.u8()?,
+        })).collect::<Option<_>>()?;
+        let tears = (0..tears).map(|_| Some(PackedTear {
+            position: [reader.u16()?, reader.u16()?],
+            size: reader.u16()?,
+            intensity: reader.u8()?,
+            tear_type: reader.u8()?,
+        })).collect::<Option<_>>()?;
+        reader.bytes.is_empty().then_some(Self { time, zones, llamas, crystals, tears })
+    }
+
+    /// Memory held by the snapshot's records
+    pub fn size_bytes(&self) -> usize {
+        std::mem::size_of_val(self.llamas.as_slice())
+            + std::mem::size_of_val(self.crystals.as_slice())
+            + std::mem::size_of_val(self.tears.as_slice())
+            + std::mem::size_of_val(self.zones.as_slice())
+    }
+}
+
+/// Little-endian values read off the front of a flattened snapshot
+struct ByteReader<'a> {
+    bytes: &'a [u8],
+}
+
+impl ByteReader<'_> {
+    fn take<const N: usize>(&mut self) -> Option<[u8; N]> {
+        let (value, rest) = self.bytes.split_first_chunk::<N>()?;
+        self.bytes = rest;
+        Some(*value)
+    }
+
+    fn u8(&mut self) -> Option<u8> {
+        self.take::<1>().map(|[value]| value)
+    }
+
+    fn u16(&mut self) -> Option<u16> {
+        self.take().map(u16::from_le_bytes)
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        self.take().map(u32::from_le_bytes)
+    }
+
+    fn f32(&mut self) -> Option<f32> {
+        self.take().map(f32::from_le_bytes)
+    }
+}
+
+/// Snapshots of the last hour, oldest first
+pub struct WorldHistory {
+    snapshots: VecDeque<WorldSnapshot>,
+    capacity: usize,
+    next_due: f32,
+}
+
+impl Default for WorldHistory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WorldHistory {
+    pub fn new() -> Self {
+        let capacity = (HISTORY_SECONDS / SNAPSHOT_INTERVAL) as usize;
+        Self { snapshots: VecDeque::with_capacity(capacity), capacity, next_due: 0.0 }
+    }
+
+    /// Take a snapshot if one is due; the oldest drops out once the hour is full
+    pub fn record_if_due(&mut self, world: &World, zones: &[TerritoryZone], time: f32) -> bool {
+        if time < self.next_due {
+            return false;
+        }
+        if self.snapshots.len() >= self.capacity {
+            self.snapshots.pop_front();
+        }
+        self.snapshots.push_back(WorldSnapshot::capture(world, zones, time));
+        self.next_due = time + SNAPSHOT_INTERVAL;
+        true
+    }
+
+    pub fn len(&self) -> usize {
+        self.snapshots.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.snapshots.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Option<&WorldSnapshot> {
+        self.snapshots.get(index)
+    }
+
+    pub fn latest(&self) -> Option<&WorldSnapshot> {
+        self.snapshots.back()
+    }
+
+    /// Branching from `index` discards the future that led away from it
+    pub fn truncate_after(&mut self, index: usize) {
+        self.snapshots.truncate(index + 1);
+    }
+
+    /// Memory held by all snapshots
+    pub fn size_bytes(&self) -> usize {
+        self.snapshots.iter().map(WorldSnapshot::size_bytes).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::ZoneType;
+
+    #[test]
+    fn test_snapshots_round_trip_and_age_out() {
+        let mut world = World::new();
+        let mut llama = Llama::new_with_species(Vec2::new(123.4, 567.8), SpeciesType::HypnoCamel);
+        llama.velocity = Vec2::new(-40.0, 12.5);
+        llama.color = Vec2::new(210.0, 0.7);
+        llama.consciousness = 3.21;
+        world.spawn(llama);
+        let mut crystal = ConsciousnessCrystal::new(Vec2::new(600.0, 400.0), CrystalType::Memory);
+        crystal.consciousness_energy = 1.5;
+        world.spawn(crystal);
+        world.spawn(RealityTear::new(Vec2::new(50.0, 60.0), TearType::Pulsing));
+
+        let zones = [TerritoryZone::new(Vec2::new(300.0, 300.0), ZoneType::Void)];
+        let snapshot = WorldSnapshot::capture(&world, &zones, 12.0);
+        assert_eq!(snapshot.zones[0].zone_type, ZoneType::Void);
+        assert_eq!(std::mem::size_of::<PackedLlama>(), 16);
+        let restored = snapshot.to_world();
+        let llama = &restored.components::<Llama>()[0];
+        assert_eq!(llama.species, SpeciesType::HypnoCamel);
+        assert!(llama.position.distance(Vec2::new(123.4, 567.8)) < 0.1);
+        assert!(llama.velocity.distance(Vec2::new(-40.0, 12.5)) < 0.2);
+        assert!((llama.color.x - 210.0).abs() < 0.1 && (llama.consciousness - 3.21).abs() < 0.01);
+        let crystal = &restored.components::<ConsciousnessCrystal>()[0];
+        assert_eq!(crystal.crystal_type, CrystalType::Memory);
+        assert!((crystal.consciousness_energy - 1.5).abs() < 0.01);
+        assert!(matches!(restored.components::<RealityTear>()[0].tear_type, TearType::Pulsing));
+
+        let mut history = WorldHistory::new();
+        let mut time = 0.0;
+        while time <= HISTORY_SECONDS + SNAPSHOT_INTERVAL * 3.0 {
+            history.record_if_due(&world, &zones, time);
+            time += 1.0;
+        }
+        assert_eq!(history.len(), (HISTORY_SECONDS / SNAPSHOT_INTERVAL) as usize);
+        assert!(history.get(0).is_some_and(|oldest| oldest.time > 0.0), "the oldest snapshots age out");
+        history.truncate_after(9);
+        assert_eq!(history.len(), 10);
+    }
+
+    #[test]
+    fn test_branching_never_reissues_entity_ids() {
+        let mut world = World::new();
+        for i in 0..5 {
+            world.spawn(Llama::new_with_species(Vec2::new(100.0 + i as f32, 100.0), SpeciesType::DiscoLlama));
+        }
+        let snapshot = WorldSnapshot::capture(&world, &[], 1.0);
+        let live = world.spawn(Llama::new(Vec2::new(300.0, 300.0)));
+
+        // Scrub back to the earlier snapshot and branch from it
+        let mut branched = snapshot.to_world_from(world.next_entity_id());
+        assert_eq!(branched.components::<Llama>().len(), 5);
+        assert!(branched.entities_with::<Llama>().iter().all(|&id| id > live));
+        let spawned = branched.spawn(Llama::new(Vec2::new(400.0, 400.0)));
+        assert!(spawned > live, "a llama born after the branch gets an id no earlier llama had");
+    }
+
+    #[test]
+    fn test_snapshots_flatten_to_bytes_and_back() {
+        let mut world = World::new();
+        let mut llama = Llama::new_with_species(Vec2::new(80.0, 90.0), SpeciesType::ALL[1]);
+        llama.velocity = Vec2::new(-3.0, 7.5);
+        world.spawn(llama);
+        world.spawn(ConsciousnessCrystal::new(Vec2::new(10.0, 20.0), CrystalType::Quantum));
+        world.spawn(RealityTear::new(Vec2::new(700.0, 30.0), TearType::Fragmenting));
+        let mut zone = TerritoryZone::new(Vec2::new(400.0, 200.0), ZoneType::Temporal);
+        zone.lifespan = Some(90.0);
+        let snapshot = WorldSnapshot::capture(&world, &[zone, TerritoryZone::new(Vec2::ZERO, ZoneType::Void)], 42.5);
+
+        let bytes = snapshot.to_bytes();
+        let restored = WorldSnapshot::from_bytes(&bytes).expect("round trip");
+        assert_eq!(restored.time, 42.5);
+        assert_eq!((restored.llamas.clone(), restored.crystals.clone(), restored.tears.clone()), (snapshot.llamas.clone(), snapshot.crystals.clone(), snapshot.tears.clone()));
+        assert_eq!(restored.zones[0].lifespan, Some(90.0));
+        assert_eq!(restored.zones[1].zone_type, ZoneType::Void);
+        assert_eq!(restored.zones[1].lifespan, None);
+
+        assert!(WorldSnapshot::from_bytes(&bytes[..bytes.len() - 1]).is_none(), "truncated");
+        assert!(WorldSnapshot::from_bytes(b"not a snapshot").is_none());
+    }
+}
@@ -0,0 +1,286 @@
+// === OBSERVATION JOURNAL ===
+// A markdown report of one session, for researchers and for sharing runs.
+// The journal samples each species' head count every few seconds and keeps
+// a timeline of the events worth retelling: wars, treaties, hives, new
+// species, observer interventions and species dying out. At the end of the
+// session it writes the population as ASCII charts, the timeline, an
+// analysis of the final consciousness, the most notable lives, and the seed
+// and settings needed to run the same organism again.
+
+use std::fmt::Write as _;
+use std::sync::mpsc::Receiver;
+use crate::core::ecs::World;
+use crate::core::events::ChaosEvent;
+use crate::engine::HiveMind;
+use crate::entities::{Llama, SpeciesType, SPECIES_COUNT};
+use super::chronicle::Chronicle;
+use super::snapshot_diff::{ConsciousnessSpread, CONSCIOUSNESS_BANDS};
+
+/// Seconds between population samples at the start of a session
+pub const JOURNAL_SAMPLE_SECONDS: f32 = 10.0;
+/// Samples kept; past this every other one is dropped and the interval doubles
+const MAX_SAMPLES: usize = 512;
+/// Timeline entries kept; later ones are only counted
+const MAX_TIMELINE: usize = 300;
+/// Columns of the population charts
+const CHART_WIDTH: usize = 60;
+/// Lives retold at the end of the report
+const JOURNAL_LIVES: usize = 5;
+const SPARKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Head count of every species at one moment
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PopulationSample {
+    pub time: f32,
+    pub counts: [usize; SPECIES_COUNT], // Indexed by SpeciesType::to_index
+}
+
+/// How often the everyday events happened
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct JournalTallies {
+    pub births: usize,
+    pub deaths: usize,
+    pub harvests: usize,
+    pub tears: usize,
+    pub beat_drops: usize,
+}
+
+/// Population samples and notable events of one session
+pub struct ObservationJournal {
+    events: Receiver<ChaosEvent>,
+    seed: u64,
+    settings: String, // Printed as given, usually the config's Debug form
+    samples: Vec<PopulationSample>,
+    interval: f32,
+    next_due: f32,
+    timeline: Vec<(f32, String)>,
+    untold: usize, // Timeline entries past MAX_TIMELINE
+    tallies: JournalTallies,
+    species_emerged: Vec<String>,
+}
+
+impl ObservationJournal {
+    pub fn new(events: Receiver<ChaosEvent>, seed: u64, settings: String) -> Self {
+        Self {
+            events,
+            seed,
+            settings,
+            samples: Vec::new(),
+            interval: JOURNAL_SAMPLE_SECONDS,
+            next_due: 0.0,
+            timeline: Vec::new(),
+            untold: 0,
+            tallies: JournalTallies::default(),
+            species_emerged: Vec::new(),
+        }
+    }
+
+    /// Note the events since the last call and sample the population if a sample is due
+    pub fn record(&mut self, world: &World, time: f32) {
+        let events: Vec<ChaosEvent> = self.events.try_iter().collect();
+        for event in &events {
+            self.observe(event, time);
+        }
+        if time >= self.next_due {
+            self.sample(world, time);
+            self.next_due = time + self.interval;
+        }
+    }
+
+    fn observe(&mut self, event: &ChaosEvent, time: f32) {
+        match event {
+            ChaosEvent::LlamaSpawned { .. } => self.tallies.births += 1,
+            ChaosEvent::LlamaDespawned { .. } => self.tallies.deaths += 1,
+            ChaosEvent::CrystalHarvested { .. } => self.tallies.harvests += 1,
+            ChaosEvent::TearOpened { .. } => self.tallies.tears += 1,
+            ChaosEvent::BeatDrop { .. } => self.tallies.beat_drops += 1,
+            ChaosEvent::HiveFormed { species, members, .. } => self.note(time, format!("A {:?} hive of {} formed", species, members)),
+            ChaosEvent::HiveDissolved { members_left, .. } => self.note(time, format!("A hive dissolved, leaving {} members", members_left)),
+            ChaosEvent::SpeciesEmerged { name, parents, .. } => {
+                self.species_emerged.push(name.clone());
+                self.note(time, format!("**{}** emerged, a new species of {:?} and {:?}", name, parents[0], parents[1]));
+            }
+            ChaosEvent::ConflictStarted { attacker, defender, .. } => self.note(time, format!("{:?} declared war on {:?}", attacker, defender)),
+            ChaosEvent::ConflictEnded { attacker, defender, victor, .. } => match victor {
+                Some(victor) => self.note(time, format!("{:?} won the war between {:?} and {:?}", victor, attacker, defender)),
+                None => self.note(time, format!("The war between {:?} and {:?} ended without a victor", attacker, defender)),
+            },
+            ChaosEvent::ObserverIntervention(intervention) => self.note(time, format!("The observer intervened: {}", intervention.name())),
+            _ => {}
+        }
+    }
+
+    fn note(&mut self, time: f32, line: String) {
+        if self.timeline.len() < MAX_TIMELINE {
+            self.timeline.push((time, line));
+        } else {
+            self.untold += 1;
+        }
+    }
+
+    fn sample(&mut self, world: &World, time: f32) {
+        let llamas = world.components::<Llama>();
+        let mut counts = [0; SPECIES_COUNT];
+        for llama in llamas {
+            counts[llama.species.to_index()] += 1;
+        }
+
+        if let Some(previous) = self.samples.last().copied() {
+            for species in SpeciesType::ALL {
+                let (before, after) = (previous.counts[species.to_index()], counts[species.to_index()]);
+                if before > 0 && after == 0 {
+                    self.note(time, format!("The {:?} died out", species));
+                } else if before == 0 && after > 0 {
+                    self.note(time, format!("The {:?} returned", species));
+                }
+            }
+        }
+
+        self.samples.push(PopulationSample { time, counts });
+        if self.samples.len() > MAX_SAMPLES {
+            let mut index = 0;
+            self.samples.retain(|_| {
+                index += 1;
+                index % 2 == 1
+            });
+            self.interval *= 2.0;
+        }
+    }
+
+    pub fn samples(&self) -> &[PopulationSample] {
+        &self.samples
+    }
+
+    pub fn tallies(&self) -> JournalTallies {
+        self.tallies
+    }
+
+    /// The whole session as markdown; `world` is the organism as it ends
+    pub fn report(&self, world: &World, chronicle: &Chronicle, time: f32) -> String {
+        let llamas = world.components::<Llama>();
+        let mut report = String::new();
+        let _ = writeln!(report, "# Aetherium Bloom observation journal\n");
+        let _ = writeln!(report, "- **Duration:** {}", clock(time));
+        let _ = writeln!(report, "- **Seed:** `{}`", self.seed);
+        let _ = writeln!(report, "- **Final population:** {} llamas, {} hives", llamas.len(), world.count::<HiveMind>());
+        let tallies = self.tallies;
+        let _ = writeln!(report, "- **Events:** {} births, {} deaths, {} crystals harvested, {} reality tears, {} beat drops",
+                         tallies.births, tallies.deaths, tallies.harvests, tallies.tears, tallies.beat_drops);
+        if !self.species_emerged.is_empty() {
+            let _ = writeln!(report, "- **New species:** {}", self.species_emerged.join(", "));
+        }
+
+        let _ = writeln!(report, "\n## Population\n");
+        if self.samples.is_empty() {
+            let _ = writeln!(report, "No population was sampled.");
+        } else {
+            let _ = writeln!(report, "```");
+            let totals: Vec<usize> = self.samples.iter().map(|sample| sample.counts.iter().sum()).collect();
+            let _ = writeln!(report, "{:<16}{}  peak {}", "All llamas", sparkline(&totals), totals.iter().max().unwrap_or(&0));
+            for species in SpeciesType::ALL {
+                let counts: Vec<usize> = self.samples.iter().map(|sample| sample.counts[species.to_index()]).collect();
+                let _ = writeln!(report, "{:<16}{}  peak {}", format!("{:?}", species), sparkline(&counts), counts.iter().max().unwrap_or(&0));
+            }
+            let _ = writeln!(report, "{:<16}{} - {}, one sample every {:.0}s", "", clock(self.samples[0].time), clock(time), self.interval);
+            let _ = writeln!(report, "```");
+        }
+
+        let _ = writeln!(report, "\n## Timeline\n");
+        if self.timeline.is_empty() {
+            let _ = writeln!(report, "Nothing worth retelling happened.");
+        }
+        for (at, line) in &self.timeline {
+            let _ = writeln!(report, "- `{}` {}", clock(*at), line);
+        }
+        if self.untold > 0 {
+            let _ = writeln!(report, "- ...and {} more", self.untold);
+        }
+
+        let _ = writeln!(report, "\n## Final consciousness\n");
+        let spread = ConsciousnessSpread::of(llamas);
+        let _ = writeln!(report, "Mean {:.2}, median {:.2}, p90 {:.2}, max {:.2}.\n", spread.mean, spread.median, spread.p90, spread.max);
+        let _ = writeln!(report, "| Consciousness | Llamas |\n|---|---|");
+        for (band, count) in spread.bands.iter().enumerate() {
+            let label = match (band.checked_sub(1).map(|edge| CONSCIOUSNESS_BANDS[edge]), CONSCIOUSNESS_BANDS.get(band)) {
+                (None, Some(high)) => format!("below {high:.1}"),
+                (Some(low), Some(high)) => format!("{low:.1} to {high:.1}"),
+                (Some(low), None) => format!("{low:.1} and up"),
+                (None, None) => unreachable!("there is always at least one band edge"),
+            };
+            let _ = writeln!(report, "| {} | {} |", label, count);
+        }
+        let _ = writeln!(report, "\n| Species | Llamas | Mean consciousness |\n|---|---|---|");
+        for species in SpeciesType::ALL {
+            let members: Vec<f32> = llamas.iter().filter(|llama| llama.species == species).map(|llama| llama.consciousness).collect();
+            let mean = if members.is_empty() { 0.0 } else { members.iter().sum::<f32>() / members.len() as f32 };
+            let _ = writeln!(report, "| {:?} | {} | {:.2} |", species, members.len(), mean);
+        }
+
+        let _ = writeln!(report, "\n## Notable lives\n");
+        let notable = chronicle.notable();
+        if notable.is_empty() {
+            let _ = writeln!(report, "No llama did anything worth retelling.");
+        }
+        for biography in notable.into_iter().take(JOURNAL_LIVES) {
+            let _ = writeln!(report, "- **{}** the {:?}: {}", biography.name, biography.species, biography.lines().join("; "));
+        }
+
+        let _ = writeln!(report, "\n## Settings\n");
+        let _ = writeln!(report, "<details><summary>Configuration</summary>\n\n```\n{}\n```\n\n</details>", self.settings);
+        report
+    }
+}
+
+/// One block character per chart column, scaled to the series' peak; blank where it is zero
+fn sparkline(values: &[usize]) -> String {
+    let peak = values.iter().copied().max().unwrap_or(0).max(1);
+    let columns = values.len().min(CHART_WIDTH);
+    (0..columns).map(|column| {
+        let value = values[column * values.len() / columns];
+        if value == 0 {
+            ' '
+        } else {
+            SPARKS[((value * SPARKS.len()).div_ceil(peak) - 1).min(SPARKS.len() - 1)]
+        }
+    }).collect()
+}
+
+fn clock(time: f32) -> String {
+    let seconds = time.max(0.0) as u32;
+    format!("{:02}:{:02}", seconds / 60, seconds % 60)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glam::Vec2;
+    use crate::core::events::EventBus;
+
+    #[test]
+    fn test_journal_charts_population_and_retells_events() {
+        let mut events = EventBus::default();
+        let mut journal = ObservationJournal::new(events.subscribe(), 42, "seed: Some(42)".to_string());
+        let mut world = World::new();
+        let herd: Vec<_> = (0..3).map(|_| world.spawn(Llama::new_with_species(Vec2::ZERO, SpeciesType::DiscoLlama))).collect();
+        journal.record(&world, 0.0);
+
+        events.publish(ChaosEvent::ConflictStarted { attacker: SpeciesType::DiscoLlama, defender: SpeciesType::QuantumSheep, front: Vec2::ZERO });
+        events.publish(ChaosEvent::CrystalHarvested { llama: herd[0], species: SpeciesType::DiscoLlama, position: Vec2::ZERO, amount: 1.0 });
+        journal.record(&world, 5.0);
+        assert_eq!(journal.samples().len(), 1, "not due yet");
+
+        for llama in herd {
+            world.despawn(llama);
+        }
+        journal.record(&world, JOURNAL_SAMPLE_SECONDS);
+        assert_eq!(journal.samples().len(), 2);
+        assert_eq!(journal.tallies().harvests, 1);
+
+        let report = journal.report(&world, &Chronicle::default(), 20.0);
+        assert!(report.contains("`42`"));
+        assert!(report.contains("DiscoLlama declared war on QuantumSheep"));
+        assert!(report.contains("The DiscoLlama died out"));
+        assert!(report.contains("seed: Some(42)"));
+        assert_eq!(sparkline(&[0, 1, 2, 4]), " ▂▄█");
+    }
+}
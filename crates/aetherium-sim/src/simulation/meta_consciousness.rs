@@ -3,7 +3,7 @@
 
 use std::collections::{VecDeque, HashMap};
 use crate::entities::Llama;
-use crate::simulation::consciousness_systems::DigitalEcosystem;
+use crate::simulation::consciousness_systems::{ConsciousnessCrystal, DigitalEcosystem};
 
 // ========== PHASE 4: TRANSCENDENCE PROTOCOL ==========
 
@@ -82,7 +82,7 @@ impl MetaConsciousnessFramework {
     }
 
     pub fn update(&mut self, dt: f32, llamas: &[Llama], cosmic_time: f64, beat_intensity: f32,
-              ecosystem: &DigitalEcosystem) {
+              ecosystem: &DigitalEcosystem, crystals: &[ConsciousnessCrystal]) {
         // Calculate collective intelligence from all llamas
         let total_consciousness: f32 = llamas.iter()
             .map(|llama| llama.awareness_level * llama.consciousness)
@@ -94,7 +94,7 @@ impl MetaConsciousnessFramework {
         };
 
         // Update collective intelligence with ecosystem influence
-        let crystal_amplification = ecosystem.crystal_formations.iter()
+        let crystal_amplification = crystals.iter()
             .map(|crystal| crystal.consciousness_energy * 0.1)
             .sum::<f32>();
         let chaos_influence = ecosystem.chaos_accumulation * 0.05;
@@ -114,7 +114,7 @@ impl MetaConsciousnessFramework {
                                           ecosystem.chaos_accumulation * 0.001).clamp(0.0, 1.0);
 
         // Check for emergence events
-        self.detect_emergence(cosmic_time, beat_intensity, llamas, ecosystem);
+        self.detect_emergence(cosmic_time, beat_intensity, llamas, ecosystem, crystals);
 
         // Calculate transcendence level
         self.transcendence_level = if self.collective_intelligence > self.emergence_threshold {
@@ -133,7 +133,7 @@ impl MetaConsciousnessFramework {
     }
 
     fn detect_emergence(&mut self, cosmic_time: f64, beat_intensity: f32, llamas: &[Llama],
-                       ecosystem: &DigitalEcosystem) {
+                       ecosystem: &DigitalEcosystem, crystals: &[ConsciousnessCrystal]) {
         let mut new_events = Vec::new();
 
         // Check for collective resonance (multiple llamas synchronized)
@@ -151,7 +151,7 @@ impl MetaConsciousnessFramework {
         }
 
         // Check for crystal harmonic resonance
-        let resonant_crystals = ecosystem.crystal_formations.iter()
+        let resonant_crystals = crystals.iter()
             .filter(|crystal| crystal.consciousness_energy > 0.8)
             .count();
         if resonant_crystals >= 2 && self.collective_intelligence > 0.6 {
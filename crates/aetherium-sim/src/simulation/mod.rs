@@ -0,0 +1,36 @@
+// Simulation module containing game state and ecosystem management systems
+
+use glam::Vec2;
+
+pub mod benchmark;
+pub mod chronicle;
+pub mod consciousness_systems;
+pub mod echoes;
+pub mod garden;
+pub mod headless;
+pub mod history;
+pub mod journal;
+pub mod meta_consciousness;
+pub mod numeric_guard;
+pub mod obstacles;
+pub mod pheromones;
+pub mod snapshot_diff;
+pub mod zone_emergence;
+
+pub use benchmark::{BenchPreset, BenchReport, SimStage, StageTimings, BENCH_PRESETS, BENCH_SEED, run_benchmark};
+pub use chronicle::{Biography, Chronicle, ChronicleEntry, LifeEvent};
+pub use consciousness_systems::*;
+pub use echoes::{LlamaEcho, ECHO_LIFETIME, spawn_echoes, update_echoes};
+pub use garden::{CrystalLayout, GardenLayout, ZoneLayout, MAX_ZONE_RADIUS, MIN_ZONE_RADIUS};
+pub use headless::{BehaviorStagger, HeadlessSimulation, SIMULATION_DT, apply_ecosystem_mutations, step_llamas};
+pub use history::{WorldHistory, WorldSnapshot, HISTORY_SECONDS, SNAPSHOT_INTERVAL};
+pub use journal::{JournalTallies, ObservationJournal, PopulationSample, JOURNAL_SAMPLE_SECONDS};
+pub use meta_consciousness::*;
+pub use numeric_guard::{NumericGuard, sanitize_llama};
+pub use obstacles::{Obstacles, Pillar, Wall, MAX_PILLAR_RADIUS, MIN_PILLAR_RADIUS, WALL_THICKNESS};
+pub use pheromones::PheromoneField;
+pub use snapshot_diff::{ConsciousnessSpread, CrystalDelta, SnapshotDiff, SpeciesDelta, ZoneChange, CONSCIOUSNESS_BANDS};
+pub use zone_emergence::{ZoneCause, ZoneEvent};
+
+/// Size of the simulated world in world units
+pub const WORLD_SIZE: Vec2 = Vec2::new(1200.0, 800.0);
\ No newline at end of file
@@ -0,0 +1,159 @@
+// === NUMERIC GUARD ===
+// A NaN in one llama spreads to the whole frame through sums such as total
+// consciousness, and nothing downstream notices. The guard runs after each
+// subsystem that writes llama fields and resets any value that is NaN,
+// infinite or has blown up past any meaningful size, so the log names the
+// subsystem that produced it. Strict mode panics instead, for tests.
+
+use std::collections::HashMap;
+use glam::Vec2;
+use tracing::warn;
+use crate::core::ecs::World;
+use crate::entities::Llama;
+
+/// Magnitude no llama value reaches in a healthy run
+pub const EXPLOSION_LIMIT: f32 = 1.0e6;
+/// Middle of the 1200x800 world, where a lost llama is put back
+const WORLD_CENTER: Vec2 = Vec2::new(600.0, 400.0);
+/// Seconds between repeated reports about the same subsystem
+const REPORT_INTERVAL: f32 = 5.0;
+
+fn broken(value: f32) -> bool {
+    !value.is_finite() || value.abs() > EXPLOSION_LIMIT
+}
+
+/// Reset every non-finite or exploded field to a safe value; returns the fields repaired
+pub fn sanitize_llama(llama: &mut Llama) -> Vec<&'static str> {
+    let mut repaired = Vec::new();
+    if broken(llama.position.x) || broken(llama.position.y) {
+        llama.position = WORLD_CENTER;
+        repaired.push("position");
+    }
+    if broken(llama.velocity.x) || broken(llama.velocity.y) {
+        llama.velocity = Vec2::ZERO;
+        repaired.push("velocity");
+    }
+    if broken(llama.color.x) || broken(llama.color.y) {
+        llama.color = Vec2::new(200.0, 0.7);
+        repaired.push("color");
+    }
+    if llama.personality_matrix.iter().any(|&trait_value| broken(trait_value)) {
+        llama.personality_matrix = llama.personality_matrix.map(|trait_value| if broken(trait_value) { 0.5 } else { trait_value });
+        repaired.push("personality_matrix");
+    }
+
+    let scalars: [(&'static str, &mut f32, f32); 23] = [
+        ("consciousness", &mut llama.consciousness, 0.5),
+        ("trip_intensity", &mut llama.trip_intensity, 0.0),
+        ("awareness_level", &mut llama.awareness_level, 0.0),
+        ("reality_distortion", &mut llama.reality_distortion, 0.0),
+        ("valence", &mut llama.affect.valence, 0.0),
+        ("arousal", &mut llama.affect.arousal, 0.0),
+        ("memory_intensity", &mut llama.memory_intensity, 0.0),
+        ("social_attraction", &mut llama.social_attraction, 0.0),
+        ("exploration_drive", &mut llama.exploration_drive, 0.0),
+        ("quantum_state", &mut llama.quantum_state, 0.0),
+        ("harmonic_resonance", &mut llama.harmonic_resonance, 0.0),
+        ("prime_chaos_factor", &mut llama.prime_chaos_factor, 0.0),
+        ("environmental_consciousness", &mut llama.environmental_consciousness, 0.0),
+        ("territorial_dominance", &mut llama.territorial_dominance, 0.0),
+        ("warfare_participation", &mut llama.warfare_participation, 0.0),
+        ("absorption_resistance", &mut llama.absorption_resistance, 0.5),
+        ("hive_connection_strength", &mut llama.hive_connection_strength, 0.0),
+        ("extinction_pressure", &mut llama.extinction_pressure, 0.0),
+        ("extinct_time", &mut llama.extinct_time, 0.0),
+        ("war_efficiency", &mut llama.war_efficiency, 0.0),
+        ("energy", &mut llama.energy, 1.0),
+        ("energy_intake", &mut llama.energy_intake, 0.0),
+        ("starving_time", &mut llama.starving_time, 0.0),
+    ];
+    for (name, value, reset) in scalars {
+        if broken(*value) {
+            *value = reset;
+            repaired.push(name);
+        }
+    }
+    repaired
+}
+
+/// Checks the llamas between subsystems and reports who broke them
+#[derive(Default)]
+pub struct NumericGuard {
+    strict: bool,
+    repaired_llamas: u64,
+    last_report: HashMap<&'static str, f32>,
+}
+
+impl NumericGuard {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Panic on the first broken value instead of repairing it
+    pub fn strict() -> Self {
+        Self { strict: true, ..Self::default() }
+    }
+
+    /// Repair every llama after `subsystem` ran; returns how many needed it
+    pub fn check(&mut self, world: &mut World, subsystem: &'static str, time: f32) -> usize {
+        let mut repaired_llamas = 0;
+        let mut fields: Vec<&'static str> = Vec::new();
+        for llama in world.components_mut::<Llama>() {
+            let repaired = sanitize_llama(llama);
+            if repaired.is_empty() {
+                continue;
+            }
+            assert!(!self.strict, "{} produced non-finite or exploded llama values: {}", subsystem, repaired.join(", "));
+            repaired_llamas += 1;
+            for field in repaired {
+                if !fields.contains(&field) {
+                    fields.push(field);
+                }
+            }
+        }
+        if repaired_llamas == 0 {
+            return 0;
+        }
+
+        self.repaired_llamas += repaired_llamas as u64;
+        let last = self.last_report.entry(subsystem).or_insert(f32::NEG_INFINITY);
+        if time - *last >= REPORT_INTERVAL {
+            *last = time;
+            warn!(target: "app", "🧮 {} left {} llama(s) with non-finite or exploded values ({}) - reset to safe values",
+                  subsystem, repaired_llamas, fields.join(", "));
+        }
+        repaired_llamas
+    }
+
+    /// Llamas repaired over the whole run, counted once per check
+    pub fn repaired_llamas(&self) -> u64 {
+        self.repaired_llamas
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::SpeciesType;
+
+    #[test]
+    fn test_broken_values_are_reset_and_counted() {
+        let mut world = World::new();
+        world.spawn(Llama::new_with_species(Vec2::new(100.0, 100.0), SpeciesType::QuantumSheep));
+        let healthy = world.spawn(Llama::new_with_species(Vec2::new(200.0, 100.0), SpeciesType::DiscoLlama));
+        let mut guard = NumericGuard::new();
+        assert_eq!(guard.check(&mut world, "test", 0.0), 0);
+
+        let llama = &mut world.components_mut::<Llama>()[0];
+        llama.position.x = f32::NAN;
+        llama.consciousness = f32::INFINITY;
+        llama.quantum_state = 1.0e12;
+        assert_eq!(guard.check(&mut world, "test", 1.0), 1);
+        let llama = &world.components::<Llama>()[0];
+        assert_eq!((llama.position, llama.consciousness, llama.quantum_state), (WORLD_CENTER, 0.5, 0.0));
+        assert_eq!(world.get_component::<Llama>(healthy).unwrap().position, Vec2::new(200.0, 100.0));
+        assert_eq!(guard.repaired_llamas(), 1);
+        let total: f32 = world.components::<Llama>().iter().map(|llama| llama.consciousness).sum();
+        assert!(total.is_finite());
+    }
+}
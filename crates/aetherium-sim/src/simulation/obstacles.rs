@@ -0,0 +1,212 @@
+// === OBSTACLES ===
+// Walls and pillars turn the plane into terrain. Llamas feel an obstacle ahead
+// of them as a push away from it before they reach it, and are held on their
+// side of it if they walk into it anyway. Obstacles also block sight: signals
+// only reach llamas the sender can see, and a predator cannot start absorbing
+// prey on the far side of a wall. Teleports and screen wraps still pass
+// through, which is rather the point of quantum tunnelling.
+
+use glam::Vec2;
+use serde::{Deserialize, Serialize};
+
+/// Half the width of a wall, in world units
+pub const WALL_THICKNESS: f32 = 6.0;
+/// Smallest and largest pillar radius
+pub const MIN_PILLAR_RADIUS: f32 = 8.0;
+pub const MAX_PILLAR_RADIUS: f32 = 80.0;
+/// Moves longer than this in one tick are jumps, not walks, and are not blocked
+const MAX_STEP: f32 = 100.0;
+/// How far past an obstacle's surface a llama starts steering around it
+const AVOID_RANGE: f32 = 40.0;
+const AVOID_STRENGTH: f32 = 60.0;
+
+/// A straight wall between two points
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Wall {
+    pub start: Vec2,
+    pub end: Vec2,
+}
+
+impl Wall {
+    pub fn new(start: Vec2, end: Vec2) -> Self {
+        Self { start, end }
+    }
+
+    /// The point on the wall nearest `position`
+    pub fn closest_point(&self, position: Vec2) -> Vec2 {
+        closest_on_segment(self.start, self.end, position)
+    }
+
+    pub fn distance_to(&self, position: Vec2) -> f32 {
+        position.distance(self.closest_point(position))
+    }
+
+    /// Whether the straight line from `from` to `to` goes through the wall's line
+    fn crosses(&self, from: Vec2, to: Vec2) -> bool {
+        let side = |a: Vec2, b: Vec2, p: Vec2| (b - a).perp_dot(p - a);
+        side(self.start, self.end, from) * side(self.start, self.end, to) < 0.0
+            && side(from, to, self.start) * side(from, to, self.end) < 0.0
+    }
+
+    /// Keep a llama that moved from `from` to `position` on its side of the wall,
+    /// turning its velocity away; true when the wall was in the way
+    pub fn block(&self, from: Vec2, position: &mut Vec2, velocity: &mut Vec2) -> bool {
+        let crossed = from.distance(*position) < MAX_STEP && self.crosses(from, *position);
+        if crossed {
+            *position = from;
+        }
+        let closest = self.closest_point(*position);
+        let offset = *position - closest;
+        let distance = offset.length();
+        if distance >= WALL_THICKNESS {
+            return crossed;
+        }
+        let normal = if distance > 1e-3 {
+            offset / distance
+        } else {
+            // Dead on the line: push back towards where it came from
+            let perpendicular = (self.end - self.start).perp().normalize_or_zero();
+            if (from - closest).dot(perpendicular) < 0.0 { -perpendicular } else { perpendicular }
+        };
+        *position = closest + normal * WALL_THICKNESS;
+        bounce(velocity, normal);
+        true
+    }
+}
+
+/// A round pillar
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Pillar {
+    pub center: Vec2,
+    pub radius: f32,
+}
+
+impl Pillar {
+    pub fn new(center: Vec2, radius: f32) -> Self {
+        Self { center, radius: radius.clamp(MIN_PILLAR_RADIUS, MAX_PILLAR_RADIUS) }
+    }
+
+    /// The point on the pillar's surface nearest `position`
+    pub fn closest_point(&self, position: Vec2) -> Vec2 {
+        self.center + (position - self.center).try_normalize().unwrap_or(Vec2::X) * self.radius
+    }
+
+    /// Distance from the surface; negative inside
+    pub fn distance_to(&self, position: Vec2) -> f32 {
+        position.distance(self.center) - self.radius
+    }
+
+    /// Push a llama that ended up inside back onto the surface, nearest where it came from
+    pub fn block(&self, from: Vec2, position: &mut Vec2, velocity: &mut Vec2) -> bool {
+        if self.distance_to(*position) >= 0.0 {
+            return false;
+        }
+        let away = if from.distance(*position) < MAX_STEP { from } else { *position };
+        let normal = (away - self.center).try_normalize().unwrap_or(Vec2::X);
+        *position = self.center + normal * self.radius;
+        bounce(velocity, normal);
+        true
+    }
+}
+
+/// Everything llamas have to walk around
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Obstacles {
+    pub walls: Vec<Wall>,
+    pub pillars: Vec<Pillar>,
+}
+
+impl Obstacles {
+    pub fn is_empty(&self) -> bool {
+        self.walls.is_empty() && self.pillars.is_empty()
+    }
+
+    /// Potential field pushing a llama away from the obstacles in front of it,
+    /// stronger the closer it is; obstacles behind it are ignored
+    pub fn avoidance(&self, position: Vec2, velocity: Vec2) -> Vec2 {
+        let heading = velocity.normalize_or_zero();
+        let surfaces = self.walls.iter().map(|wall| {
+            let closest = wall.closest_point(position);
+            (closest, position.distance(closest) - WALL_THICKNESS)
+        }).chain(self.pillars.iter().map(|pillar| (pillar.closest_point(position), pillar.distance_to(position))));
+
+        surfaces.filter(|&(closest, gap)| gap < AVOID_RANGE && (closest - position).dot(heading) > 0.0)
+            .map(|(closest, gap)| {
+                let away = (position - closest).normalize_or_zero();
+                away * (1.0 - gap.max(0.0) / AVOID_RANGE) * AVOID_STRENGTH
+            })
+            .sum()
+    }
+
+    /// Whether nothing stands between `a` and `b`
+    pub fn line_of_sight(&self, a: Vec2, b: Vec2) -> bool {
+        !self.walls.iter().any(|wall| wall.crosses(a, b))
+            && !self.pillars.iter().any(|pillar| closest_on_segment(a, b, pillar.center).distance(pillar.center) < pillar.radius)
+    }
+
+    /// Hold a llama that moved from `from` to `position` outside every obstacle
+    pub fn block(&self, from: Vec2, position: &mut Vec2, velocity: &mut Vec2) {
+        for wall in &self.walls {
+            wall.block(from, position, velocity);
+        }
+        for pillar in &self.pillars {
+            pillar.block(from, position, velocity);
+        }
+    }
+}
+
+fn closest_on_segment(start: Vec2, end: Vec2, position: Vec2) -> Vec2 {
+    let along = end - start;
+    let t = ((position - start).dot(along) / along.length_squared().max(f32::EPSILON)).clamp(0.0, 1.0);
+    start + along * t
+}
+
+/// Reflect the part of `velocity` heading into a surface with this outward normal
+fn bounce(velocity: &mut Vec2, normal: Vec2) {
+    let into = velocity.dot(normal);
+    if into < 0.0 {
+        *velocity -= normal * into * 2.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_walls_turn_llamas_back() {
+        let wall = Wall::new(Vec2::new(100.0, 0.0), Vec2::new(100.0, 200.0));
+
+        // Walking through is undone and the velocity turned around
+        let (mut position, mut velocity) = (Vec2::new(103.0, 50.0), Vec2::new(120.0, 0.0));
+        assert!(wall.block(Vec2::new(97.0, 50.0), &mut position, &mut velocity));
+        assert!(position.x <= 100.0 - WALL_THICKNESS + 1e-3 && velocity.x < 0.0);
+
+        // Brushing past the end is fine, and long jumps pass through
+        let (mut position, mut velocity) = (Vec2::new(110.0, 220.0), Vec2::new(60.0, 0.0));
+        assert!(!wall.block(Vec2::new(90.0, 220.0), &mut position, &mut velocity));
+        let (mut position, mut velocity) = (Vec2::new(400.0, 50.0), Vec2::ZERO);
+        assert!(!wall.block(Vec2::new(20.0, 50.0), &mut position, &mut velocity));
+    }
+
+    #[test]
+    fn test_obstacles_steer_and_block_sight() {
+        let obstacles = Obstacles {
+            walls: vec![Wall::new(Vec2::new(100.0, 0.0), Vec2::new(100.0, 200.0))],
+            pillars: vec![Pillar::new(Vec2::new(400.0, 400.0), 30.0)],
+        };
+
+        // Heading for the wall pushes back; heading away from it does not
+        let push = obstacles.avoidance(Vec2::new(80.0, 100.0), Vec2::new(50.0, 0.0));
+        assert!(push.x < 0.0);
+        assert_eq!(obstacles.avoidance(Vec2::new(80.0, 100.0), Vec2::new(-50.0, 0.0)), Vec2::ZERO);
+
+        assert!(!obstacles.line_of_sight(Vec2::new(50.0, 100.0), Vec2::new(150.0, 100.0)));
+        assert!(!obstacles.line_of_sight(Vec2::new(300.0, 400.0), Vec2::new(500.0, 410.0)));
+        assert!(obstacles.line_of_sight(Vec2::new(300.0, 300.0), Vec2::new(500.0, 300.0)));
+
+        let (mut position, mut velocity) = (Vec2::new(380.0, 400.0), Vec2::new(40.0, 0.0));
+        obstacles.block(Vec2::new(360.0, 400.0), &mut position, &mut velocity);
+        assert!((position.x - 370.0).abs() < 1e-3 && velocity.x < 0.0);
+    }
+}
@@ -0,0 +1,155 @@
+// === PHEROMONE TRAILS ===
+// A second field over the world, alongside the consciousness field, where
+// every llama leaves its species' scent as it moves. Scent spreads into the
+// neighboring cells and evaporates, so only paths walked again and again
+// stay strong. Llamas lean along their own species' trails and away from
+// rivals', and the busiest routes harden into highways, the way ant colonies
+// find their paths.
+
+use glam::Vec2;
+use crate::entities::{SpeciesType, SPECIES_COUNT};
+
+/// Edge of one field cell in world units
+const CELL_SIZE: f32 = 20.0;
+/// Share of scent lost per second
+const EVAPORATION: f32 = 0.15;
+/// How quickly a cell's scent evens out with its neighbors, per second
+const DIFFUSION: f32 = 0.6;
+/// No cell holds more of one species' scent than this
+const MAX_SCENT: f32 = 4.0;
+/// Scent difference across one cell that steers at full strength
+const STEERING_SATURATION: f32 = 0.5;
+/// Rival scent counts this much against a llama's own when it picks a direction
+const RIVAL_AVERSION: f32 = 0.6;
+
+/// Species-scented trails on a grid of `CELL_SIZE` cells
+#[derive(Debug, Clone)]
+pub struct PheromoneField {
+    columns: usize,
+    rows: usize,
+    scent: Vec<[f32; SPECIES_COUNT]>, // Row-major, channels indexed by SpeciesType::to_index
+    spread: Vec<[f32; SPECIES_COUNT]>, // Reused by the diffusion step
+}
+
+impl PheromoneField {
+    pub fn new(world_size: Vec2) -> Self {
+        let columns = (world_size.x / CELL_SIZE).ceil().max(1.0) as usize;
+        let rows = (world_size.y / CELL_SIZE).ceil().max(1.0) as usize;
+        Self {
+            columns,
+            rows,
+            scent: vec![[0.0; SPECIES_COUNT]; columns * rows],
+            spread: vec![[0.0; SPECIES_COUNT]; columns * rows],
+        }
+    }
+
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cell_size(&self) -> f32 {
+        CELL_SIZE
+    }
+
+    /// Every species' scent in the cell at `column`, `row`
+    pub fn cell(&self, column: usize, row: usize) -> [f32; SPECIES_COUNT] {
+        self.scent[row * self.columns + column]
+    }
+
+    fn index(&self, position: Vec2) -> Option<usize> {
+        let cell = (position / CELL_SIZE).floor();
+        let inside = cell.x >= 0.0 && cell.y >= 0.0 && (cell.x as usize) < self.columns && (cell.y as usize) < self.rows;
+        inside.then(|| cell.y as usize * self.columns + cell.x as usize)
+    }
+
+    /// Leave `amount` of a species' scent where a llama stands; off-world positions leave nothing
+    pub fn deposit(&mut self, position: Vec2, species: SpeciesType, amount: f32) {
+        if let Some(index) = self.index(position) {
+            let scent = &mut self.scent[index][species.to_index()];
+            *scent = (*scent + amount).min(MAX_SCENT);
+        }
+    }
+
+    pub fn scent_at(&self, position: Vec2, species: SpeciesType) -> f32 {
+        self.index(position).map_or(0.0, |index| self.scent[index][species.to_index()])
+    }
+
+    /// How attractive a spot smells to `species`: its own trails minus a share of every rival's
+    fn appeal(&self, position: Vec2, species: SpeciesType) -> f32 {
+        let Some(index) = self.index(position) else { return 0.0 };
+        let own = species.to_index();
+        let rivals: f32 = self.scent[index].iter().enumerate().filter(|&(i, _)| i != own).map(|(_, &scent)| scent).sum();
+        self.scent[index][own] - rivals * RIVAL_AVERSION
+    }
+
+    /// Direction up the slope of `species`' appeal, at most unit length; zero on flat ground
+    pub fn steering(&self, position: Vec2, species: SpeciesType) -> Vec2 {
+        let slope = |offset: Vec2| self.appeal(position + offset, species) - self.appeal(position - offset, species);
+        let gradient = Vec2::new(slope(Vec2::X * CELL_SIZE), slope(Vec2::Y * CELL_SIZE)) * 0.5;
+        (gradient / STEERING_SATURATION).clamp_length_max(1.0)
+    }
+
+    /// Spread every scent into the four neighboring cells, then let it evaporate
+    pub fn update(&mut self, dt: f32) {
+        let blend = (DIFFUSION * dt).min(1.0);
+        let keep = (1.0 - EVAPORATION * dt).max(0.0);
+        for row in 0..self.rows {
+            for column in 0..self.columns {
+                let mut sum = [0.0; SPECIES_COUNT];
+                let mut neighbors = 0.0;
+                let mut add = |index: usize| {
+                    for (total, scent) in sum.iter_mut().zip(self.scent[index]) {
+                        *total += scent;
+                    }
+                    neighbors += 1.0;
+                };
+                if column > 0 { add(row * self.columns + column - 1); }
+                if column + 1 < self.columns { add(row * self.columns + column + 1); }
+                if row > 0 { add((row - 1) * self.columns + column); }
+                if row + 1 < self.rows { add((row + 1) * self.columns + column); }
+
+                let index = row * self.columns + column;
+                let here = self.scent[index];
+                self.spread[index] = std::array::from_fn(|i| {
+                    let average = if neighbors > 0.0 { sum[i] / neighbors } else { here[i] };
+                    (here[i] + (average - here[i]) * blend) * keep
+                });
+            }
+        }
+        std::mem::swap(&mut self.scent, &mut self.spread);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trails_attract_kin_repel_rivals_and_evaporate() {
+        let mut field = PheromoneField::new(Vec2::new(1200.0, 800.0));
+        assert_eq!((field.columns(), field.rows()), (60, 40));
+
+        // A disco llama trail running east through the cells between y = 400 and 420
+        for step in 0..40 {
+            field.deposit(Vec2::new(300.0 + step as f32 * 10.0, 410.0), SpeciesType::DiscoLlama, 0.5);
+        }
+        field.update(1.0 / 60.0);
+
+        let beside = Vec2::new(500.0, 425.0);
+        assert!(field.steering(beside, SpeciesType::DiscoLlama).y < -0.1, "kin are drawn onto the trail");
+        assert!(field.steering(beside, SpeciesType::HypnoCamel).y > 0.1, "rivals are pushed off it");
+        assert_eq!(field.steering(Vec2::new(1000.0, 100.0), SpeciesType::DiscoLlama), Vec2::ZERO);
+        assert!(field.steering(beside, SpeciesType::DiscoLlama).length() <= 1.0);
+
+        field.deposit(Vec2::new(-50.0, 10.0), SpeciesType::DiscoLlama, 1.0); // Off the world
+        let fresh = field.scent_at(Vec2::new(500.0, 410.0), SpeciesType::DiscoLlama);
+        for _ in 0..(60 * 30) {
+            field.update(1.0 / 60.0);
+        }
+        assert!(field.scent_at(Vec2::new(500.0, 410.0), SpeciesType::DiscoLlama) < fresh * 0.05, "trails evaporate");
+    }
+}
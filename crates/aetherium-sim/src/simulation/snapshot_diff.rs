@@ -0,0 +1,285 @@
+// === SNAPSHOT DIFF ===
+// Compares two saved world snapshots, say the autosaves of two runs from
+// different seeds or presets, and reports how they diverged: the herd's size
+// per species, how consciousness is spread across it, which territory zones
+// appeared, vanished or changed, and how the crystals shifted. Zones carry no
+// identity of their own, so a zone counts as the same zone when one of its
+// type sits within reach of where it was.
+
+use std::fmt;
+use glam::Vec2;
+use crate::entities::{Llama, SpeciesType, SPECIES_COUNT};
+use super::history::WorldSnapshot;
+use super::{ConsciousnessCrystal, CrystalType, RealityTear, TerritoryZone, ZoneType};
+
+/// Upper edges of the consciousness bands the distribution is counted in; the last band is open
+pub const CONSCIOUSNESS_BANDS: [f32; 4] = [0.5, 1.0, 1.5, 2.0];
+const CRYSTAL_TYPES: [CrystalType; 5] = [CrystalType::Resonance, CrystalType::Chaos, CrystalType::Memory, CrystalType::Social, CrystalType::Quantum];
+/// Changes smaller than these are left out of the zone report
+const ZONE_MOVE_EPSILON: f32 = 1.0;
+const ZONE_CHANGE_EPSILON: f32 = 0.01;
+
+/// Head count and consciousness of one species on each side
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpeciesDelta {
+    pub species: SpeciesType,
+    pub before: usize,
+    pub after: usize,
+    pub mean_consciousness: [f32; 2], // Before, after; 0 for an absent species
+}
+
+impl SpeciesDelta {
+    pub fn change(&self) -> isize {
+        self.after as isize - self.before as isize
+    }
+}
+
+/// How consciousness is spread across one side's herd
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ConsciousnessSpread {
+    pub mean: f32,
+    pub median: f32,
+    pub p90: f32,
+    pub max: f32,
+    pub bands: [usize; CONSCIOUSNESS_BANDS.len() + 1],
+}
+
+impl ConsciousnessSpread {
+    pub(crate) fn of(llamas: &[Llama]) -> Self {
+        let mut levels: Vec<f32> = llamas.iter().map(|llama| llama.consciousness).collect();
+        if levels.is_empty() {
+            return Self::default();
+        }
+        levels.sort_by(f32::total_cmp);
+        let at = |fraction: f32| levels[((levels.len() - 1) as f32 * fraction).round() as usize];
+        let mut bands = [0; CONSCIOUSNESS_BANDS.len() + 1];
+        for &level in &levels {
+            bands[CONSCIOUSNESS_BANDS.iter().take_while(|&&edge| level >= edge).count()] += 1;
+        }
+        Self {
+            mean: levels.iter().sum::<f32>() / levels.len() as f32,
+            median: at(0.5),
+            p90: at(0.9),
+            max: levels[levels.len() - 1],
+            bands,
+        }
+    }
+}
+
+/// A territory zone found on both sides
+#[derive(Debug, Clone)]
+pub struct ZoneChange {
+    pub before: TerritoryZone,
+    pub after: TerritoryZone,
+}
+
+impl ZoneChange {
+    pub fn moved(&self) -> f32 {
+        self.before.center.distance(self.after.center)
+    }
+
+    fn is_unchanged(&self) -> bool {
+        self.moved() < ZONE_MOVE_EPSILON
+            && (self.after.radius - self.before.radius).abs() < ZONE_MOVE_EPSILON
+            && (self.after.strength - self.before.strength).abs() < ZONE_CHANGE_EPSILON
+    }
+}
+
+/// Crystal count and total energy of one type on each side
+#[derive(Debug, Clone, PartialEq)]
+pub struct CrystalDelta {
+    pub crystal_type: CrystalType,
+    pub counts: [usize; 2],
+    pub energy: [f32; 2],
+}
+
+/// Everything that differs between two snapshots
+#[derive(Debug, Clone)]
+pub struct SnapshotDiff {
+    pub times: [f32; 2],
+    pub species: [SpeciesDelta; SPECIES_COUNT],
+    pub consciousness: [ConsciousnessSpread; 2],
+    pub zones_added: Vec<TerritoryZone>,
+    pub zones_removed: Vec<TerritoryZone>,
+    pub zones_changed: Vec<ZoneChange>,
+    pub zones_unchanged: usize,
+    pub crystals: Vec<CrystalDelta>,
+    pub tears: [usize; 2],
+}
+
+impl SnapshotDiff {
+    /// How `after` differs from `before`
+    pub fn between(before: &WorldSnapshot, after: &WorldSnapshot) -> Self {
+        let (world_before, world_after) = (before.to_world(), after.to_world());
+        let (llamas_before, llamas_after) = (world_before.components::<Llama>(), world_after.components::<Llama>());
+        let species = SpeciesType::ALL.map(|species| {
+            let (before, mean_before) = species_summary(llamas_before, species);
+            let (after, mean_after) = species_summary(llamas_after, species);
+            SpeciesDelta { species, before, after, mean_consciousness: [mean_before, mean_after] }
+        });
+
+        let (crystals_before, crystals_after) = (world_before.components::<ConsciousnessCrystal>(), world_after.components::<ConsciousnessCrystal>());
+        let crystals = CRYSTAL_TYPES.iter().map(|crystal_type| {
+            let [(count_before, energy_before), (count_after, energy_after)] = [crystals_before, crystals_after].map(|crystals| {
+                let matching = crystals.iter().filter(|crystal| crystal.crystal_type == *crystal_type);
+                matching.fold((0, 0.0), |(count, energy), crystal| (count + 1, energy + crystal.consciousness_energy))
+            });
+            CrystalDelta { crystal_type: crystal_type.clone(), counts: [count_before, count_after], energy: [energy_before, energy_after] }
+        }).filter(|delta| delta.counts != [0, 0]).collect();
+
+        let mut diff = Self {
+            times: [before.time, after.time],
+            species,
+            consciousness: [ConsciousnessSpread::of(llamas_before), ConsciousnessSpread::of(llamas_after)],
+            zones_added: Vec::new(),
+            zones_removed: Vec::new(),
+            zones_changed: Vec::new(),
+            zones_unchanged: 0,
+            crystals,
+            tears: [world_before.components::<RealityTear>().len(), world_after.components::<RealityTear>().len()],
+        };
+        diff.match_zones(&before.zones, &after.zones);
+        diff
+    }
+
+    pub fn population(&self) -> [usize; 2] {
+        [self.species.iter().map(|delta| delta.before).sum(), self.species.iter().map(|delta| delta.after).sum()]
+    }
+
+    /// Pair each zone with the nearest unclaimed zone of its type within reach; the rest were added or removed
+    fn match_zones(&mut self, before: &[TerritoryZone], after: &[TerritoryZone]) {
+        let mut unclaimed: Vec<&TerritoryZone> = after.iter().collect();
+        for zone in before {
+            let nearest = unclaimed.iter().enumerate()
+                .filter(|(_, other)| other.zone_type == zone.zone_type && other.center.distance(zone.center) <= zone.radius.max(other.radius))
+                .min_by(|(_, a), (_, b)| a.center.distance(zone.center).total_cmp(&b.center.distance(zone.center)))
+                .map(|(index, _)| index);
+            match nearest {
+                Some(index) => {
+                    let change = ZoneChange { before: zone.clone(), after: unclaimed.swap_remove(index).clone() };
+                    if change.is_unchanged() {
+                        self.zones_unchanged += 1;
+                    } else {
+                        self.zones_changed.push(change);
+                    }
+                }
+                None => self.zones_removed.push(zone.clone()),
+            }
+        }
+        self.zones_added = unclaimed.into_iter().cloned().collect();
+    }
+}
+
+fn species_summary(llamas: &[Llama], species: SpeciesType) -> (usize, f32) {
+    let (count, total) = llamas.iter()
+        .filter(|llama| llama.species == species)
+        .fold((0, 0.0), |(count, total), llama| (count + 1, total + llama.consciousness));
+    (count, if count > 0 { total / count as f32 } else { 0.0 })
+}
+
+fn zone_name(zone_type: ZoneType) -> String {
+    format!("{zone_type:?}")
+}
+
+fn describe_zone(zone: &TerritoryZone) -> String {
+    let Vec2 { x, y } = zone.center;
+    format!("{} at ({x:.0}, {y:.0}), radius {:.0}, strength {:.2}", zone_name(zone.zone_type), zone.radius, zone.strength)
+}
+
+impl fmt::Display for SnapshotDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [before, after] = self.population();
+        writeln!(f, "🦙 Population: {before} -> {after} ({:+}), snapshots at {:.0}s and {:.0}s",
+                 after as isize - before as isize, self.times[0], self.times[1])?;
+        for delta in &self.species {
+            writeln!(f, "   {:<16}{:>6} -> {:<6}{:>+6}   mean consciousness {:.2} -> {:.2}",
+                     format!("{:?}", delta.species), delta.before, delta.after, delta.change(),
+                     delta.mean_consciousness[0], delta.mean_consciousness[1])?;
+        }
+
+        let [spread_before, spread_after] = &self.consciousness;
+        writeln!(f, "🧠 Consciousness: mean {:.2} -> {:.2}, median {:.2} -> {:.2}, p90 {:.2} -> {:.2}, max {:.2} -> {:.2}",
+                 spread_before.mean, spread_after.mean, spread_before.median, spread_after.median,
+                 spread_before.p90, spread_after.p90, spread_before.max, spread_after.max)?;
+        for (band, (count_before, count_after)) in spread_before.bands.iter().zip(&spread_after.bands).enumerate() {
+            let label = match (band.checked_sub(1).map(|edge| CONSCIOUSNESS_BANDS[edge]), CONSCIOUSNESS_BANDS.get(band)) {
+                (None, Some(high)) => format!("below {high:.1}"),
+                (Some(low), Some(high)) => format!("{low:.1} to {high:.1}"),
+                (Some(low), None) => format!("{low:.1} and up"),
+                (None, None) => unreachable!("there is always at least one band edge"),
+            };
+            writeln!(f, "   {label:<16}{count_before:>6} -> {count_after:<6}{:>+6}", *count_after as isize - *count_before as isize)?;
+        }
+
+        writeln!(f, "🗺️ Zones: {} unchanged, {} changed, {} added, {} removed",
+                 self.zones_unchanged, self.zones_changed.len(), self.zones_added.len(), self.zones_removed.len())?;
+        for change in &self.zones_changed {
+            writeln!(f, "   ~ {} moved {:.0}, radius {:.0} -> {:.0}, strength {:.2} -> {:.2}",
+                     zone_name(change.before.zone_type), change.moved(), change.before.radius, change.after.radius,
+                     change.before.strength, change.after.strength)?;
+        }
+        for zone in &self.zones_added {
+            writeln!(f, "   + {}", describe_zone(zone))?;
+        }
+        for zone in &self.zones_removed {
+            writeln!(f, "   - {}", describe_zone(zone))?;
+        }
+
+        writeln!(f, "💎 Crystals: {} -> {}, reality tears: {} -> {}",
+                 self.crystals.iter().map(|delta| delta.counts[0]).sum::<usize>(),
+                 self.crystals.iter().map(|delta| delta.counts[1]).sum::<usize>(), self.tears[0], self.tears[1])?;
+        for delta in &self.crystals {
+            writeln!(f, "   {:<16}{:>6} -> {:<6}{:>+6}   energy {:.1} -> {:.1}",
+                     format!("{:?}", delta.crystal_type), delta.counts[0], delta.counts[1],
+                     delta.counts[1] as isize - delta.counts[0] as isize, delta.energy[0], delta.energy[1])?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ecs::World;
+
+    #[test]
+    fn test_diff_reports_population_zone_and_crystal_changes() {
+        let mut world = World::new();
+        for i in 0..4 {
+            let mut llama = Llama::new_with_species(Vec2::new(100.0 + i as f32 * 20.0, 100.0), SpeciesType::DiscoLlama);
+            llama.consciousness = 0.4;
+            world.spawn(llama);
+        }
+        world.spawn(ConsciousnessCrystal::new(Vec2::new(300.0, 300.0), CrystalType::Memory));
+        let kept = TerritoryZone::new(Vec2::new(400.0, 400.0), ZoneType::Harmonic);
+        let before = WorldSnapshot::capture(&world, &[kept.clone(), TerritoryZone::new(Vec2::new(100.0, 600.0), ZoneType::Void)], 10.0);
+
+        for i in 0..3 {
+            let mut llama = Llama::new_with_species(Vec2::new(500.0, 100.0 + i as f32 * 20.0), SpeciesType::HypnoCamel);
+            llama.consciousness = 1.8;
+            world.spawn(llama);
+        }
+        let mut moved = kept.clone();
+        moved.center += Vec2::new(10.0, 0.0);
+        let after = WorldSnapshot::capture(&world, &[moved, TerritoryZone::new(Vec2::new(900.0, 200.0), ZoneType::Quantum)], 70.0);
+
+        let diff = SnapshotDiff::between(&before, &after);
+        assert_eq!(diff.population(), [4, 7]);
+        let camels = diff.species.iter().find(|delta| delta.species == SpeciesType::HypnoCamel).unwrap();
+        assert_eq!((camels.before, camels.after, camels.change()), (0, 3, 3));
+        assert!((camels.mean_consciousness[1] - 1.8).abs() < 0.01);
+        assert_eq!((diff.consciousness[0].bands[0], diff.consciousness[1].bands[3]), (4, 3));
+        assert!(diff.consciousness[1].max > diff.consciousness[0].max);
+
+        assert_eq!(diff.zones_changed.len(), 1, "the Harmonic zone moved but is still the same zone");
+        assert!((diff.zones_changed[0].moved() - 10.0).abs() < 0.01);
+        assert_eq!(diff.zones_added.iter().map(|zone| zone.zone_type).collect::<Vec<_>>(), [ZoneType::Quantum]);
+        assert_eq!(diff.zones_removed.iter().map(|zone| zone.zone_type).collect::<Vec<_>>(), [ZoneType::Void]);
+        assert_eq!(diff.crystals.len(), 1);
+        assert_eq!(diff.crystals[0].counts, [1, 1]);
+
+        let report = diff.to_string();
+        assert!(report.contains("Population: 4 -> 7 (+3)"));
+        assert!(report.contains("+ Quantum at (900, 200)") && report.contains("- Void at (100, 600)"));
+    }
+}
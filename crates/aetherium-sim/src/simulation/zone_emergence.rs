@@ -0,0 +1,189 @@
+// === EMERGENT TERRITORY ZONES ===
+// Zones grow out of what happened in the simulation instead of only being
+// scattered at startup: the ground where a species war was decided turns
+// Chaotic, the center of a hive that has held together for a long time turns
+// Harmonic, and a place where many llamas went extinct collapses into a Void.
+// Emergent zones bloom in, merge with overlapping zones of the same type and
+// fade out when their lifespan runs out, reporting each step as a ZoneEvent.
+
+use glam::Vec2;
+use crate::core::ecs::World;
+use crate::engine::HiveMind;
+use crate::engine::population::{DespawnReason, Despawned};
+use super::{DigitalEcosystem, TerritoryZone, ZoneType};
+
+/// Lifespan of the Chaotic zone left by a resolved conflict
+const CONFLICT_ZONE_SECONDS: f32 = 60.0;
+/// A hive must hold together this long before its center turns Harmonic
+const HIVE_ZONE_AGE: f32 = 30.0;
+/// Lifespan of a hive's Harmonic zone, renewed while the hive lives
+const HIVE_ZONE_SECONDS: f32 = 90.0;
+/// Extinctions this close together in space and time count as one mass extinction
+const EXTINCTION_RADIUS: f32 = 150.0;
+const EXTINCTION_WINDOW: f32 = 15.0;
+/// Extinctions needed to tear open a Void
+const MASS_EXTINCTION_COUNT: usize = 3;
+/// Lifespan of a Void
+const VOID_ZONE_SECONDS: f32 = 45.0;
+/// Emergent zones alive at once; more history is ignored until some fade
+const MAX_EMERGENT_ZONES: usize = 12;
+/// Zones of one type closer than this share of the larger radius merge
+const MERGE_OVERLAP: f32 = 0.6;
+/// Largest radius a zone can reach, merged or not
+const MAX_ZONE_RADIUS: f32 = 150.0;
+/// Undrained zone events kept before the oldest are dropped
+const MAX_ZONE_EVENTS: usize = 32;
+
+/// What a zone grew out of
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZoneCause {
+    ResolvedConflict,
+    LongLivedHive,
+    MassExtinction,
+}
+
+/// A step in the life of an emergent zone
+#[derive(Debug, Clone, PartialEq)]
+pub enum ZoneEvent {
+    Emerged { zone_type: ZoneType, center: Vec2, cause: ZoneCause },
+    Merged { zone_type: ZoneType, center: Vec2, radius: f32 },
+    Faded { zone_type: ZoneType, center: Vec2 },
+}
+
+impl DigitalEcosystem {
+    /// Turn this tick's history into zones: decided fronts, long-lived hives and mass extinctions
+    pub fn grow_zones_from_history(&mut self, world: &World, resolved_fronts: &[Vec2], despawned: &[Despawned], time: f32) {
+        for &front in resolved_fronts {
+            self.emerge_zone(front, ZoneType::Chaotic, CONFLICT_ZONE_SECONDS, ZoneCause::ResolvedConflict);
+        }
+
+        for hive in world.components::<HiveMind>() {
+            if time - hive.emergence_timestamp < HIVE_ZONE_AGE {
+                continue;
+            }
+            let sustained = self.territory_zones.iter_mut().find(|zone| {
+                zone.zone_type == ZoneType::Harmonic && zone.lifespan.is_some() && zone.center.distance(hive.hive_center) < zone.radius
+            });
+            match sustained {
+                Some(zone) => zone.lifespan = zone.lifespan.map(|lifespan| lifespan.max(zone.age + HIVE_ZONE_SECONDS)),
+                None => self.emerge_zone(hive.hive_center, ZoneType::Harmonic, HIVE_ZONE_SECONDS, ZoneCause::LongLivedHive),
+            }
+        }
+
+        self.recent_extinctions.retain(|&(_, when)| time - when < EXTINCTION_WINDOW);
+        for removed in despawned.iter().filter(|removed| removed.reason == DespawnReason::Extinct) {
+            self.recent_extinctions.push((removed.position, time));
+            let cluster: Vec<Vec2> = self.recent_extinctions.iter()
+                .map(|&(position, _)| position)
+                .filter(|position| position.distance(removed.position) < EXTINCTION_RADIUS)
+                .collect();
+            if cluster.len() >= MASS_EXTINCTION_COUNT {
+                let center = cluster.iter().copied().sum::<Vec2>() / cluster.len() as f32;
+                self.recent_extinctions.retain(|&(position, _)| position.distance(removed.position) >= EXTINCTION_RADIUS);
+                self.emerge_zone(center, ZoneType::Void, VOID_ZONE_SECONDS, ZoneCause::MassExtinction);
+            }
+        }
+    }
+
+    /// Merge overlapping zones of the same type and remove emergent zones that have faded
+    pub fn update_zone_lifecycle(&mut self) {
+        let mut i = 0;
+        while i < self.territory_zones.len() {
+            let mut j = i + 1;
+            while j < self.territory_zones.len() {
+                let (a, b) = (&self.territory_zones[i], &self.territory_zones[j]);
+                if a.zone_type == b.zone_type && a.center.distance(b.center) < a.radius.max(b.radius) * MERGE_OVERLAP {
+                    let absorbed = self.territory_zones.swap_remove(j);
+                    let zone = &mut self.territory_zones[i];
+                    merge_into(zone, &absorbed);
+                    let event = ZoneEvent::Merged { zone_type: zone.zone_type, center: zone.center, radius: zone.radius };
+                    self.push_zone_event(event);
+                } else {
+                    j += 1;
+                }
+            }
+            i += 1;
+        }
+
+        let faded: Vec<ZoneEvent> = self.territory_zones.iter()
+            .filter(|zone| zone.has_faded())
+            .map(|zone| ZoneEvent::Faded { zone_type: zone.zone_type, center: zone.center })
+            .collect();
+        self.territory_zones.retain(|zone| !zone.has_faded());
+        for event in faded {
+            self.push_zone_event(event);
+        }
+    }
+
+    pub fn drain_zone_events(&mut self) -> std::vec::Drain<'_, ZoneEvent> {
+        self.zone_events.drain(..)
+    }
+
+    fn emerge_zone(&mut self, center: Vec2, zone_type: ZoneType, lifespan: f32, cause: ZoneCause) {
+        let emergent = self.territory_zones.iter().filter(|zone| zone.lifespan.is_some()).count();
+        if emergent >= MAX_EMERGENT_ZONES {
+            return;
+        }
+        self.territory_zones.push(TerritoryZone::emerging(center, zone_type, lifespan));
+        self.push_zone_event(ZoneEvent::Emerged { zone_type, center, cause });
+    }
+
+    fn push_zone_event(&mut self, event: ZoneEvent) {
+        if self.zone_events.len() >= MAX_ZONE_EVENTS {
+            self.zone_events.remove(0);
+        }
+        self.zone_events.push(event);
+    }
+}
+
+/// Grow `zone` over the area of `absorbed`; the merged zone lives as long as the longer-lived of the two
+fn merge_into(zone: &mut TerritoryZone, absorbed: &TerritoryZone) {
+    let (weight, absorbed_weight) = (zone.radius * zone.radius, absorbed.radius * absorbed.radius);
+    zone.center = (zone.center * weight + absorbed.center * absorbed_weight) / (weight + absorbed_weight).max(f32::EPSILON);
+    zone.radius = (weight + absorbed_weight).sqrt().min(MAX_ZONE_RADIUS);
+    zone.lifespan = match (zone.lifespan, absorbed.lifespan) {
+        (Some(lifespan), Some(other)) => Some(lifespan.max(zone.age + other - absorbed.age)),
+        _ => None, // A permanent zone stays permanent
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::SpeciesType;
+
+    fn extinct_at(position: Vec2) -> Despawned {
+        Despawned { entity: 0, species: SpeciesType::DiscoLlama, position, reason: DespawnReason::Extinct, consciousness: 0.0, hue: 0.0, memory: 0.0 }
+    }
+
+    #[test]
+    fn test_history_grows_merges_and_fades_zones() {
+        let mut world = World::new();
+        let mut ecosystem = DigitalEcosystem::new(&mut world);
+        ecosystem.territory_zones.clear();
+
+        // Two nearby fronts: two Chaotic zones that merge into one
+        ecosystem.grow_zones_from_history(&world, &[Vec2::new(300.0, 300.0), Vec2::new(310.0, 300.0)], &[], 0.0);
+        ecosystem.update_zone_lifecycle();
+        assert_eq!(ecosystem.territory_zones.len(), 1);
+        let events: Vec<ZoneEvent> = ecosystem.drain_zone_events().collect();
+        assert!(matches!(events[0], ZoneEvent::Emerged { zone_type: ZoneType::Chaotic, cause: ZoneCause::ResolvedConflict, .. }));
+        assert!(events.iter().any(|event| matches!(event, ZoneEvent::Merged { .. })));
+
+        // Scattered extinctions do nothing; a cluster opens a Void
+        let scattered = [extinct_at(Vec2::new(100.0, 700.0)), extinct_at(Vec2::new(1100.0, 100.0))];
+        ecosystem.grow_zones_from_history(&world, &[], &scattered, 1.0);
+        assert_eq!(ecosystem.territory_zones.len(), 1);
+        let cluster = [extinct_at(Vec2::new(800.0, 500.0)), extinct_at(Vec2::new(820.0, 500.0)), extinct_at(Vec2::new(800.0, 520.0))];
+        ecosystem.grow_zones_from_history(&world, &[], &cluster, 2.0);
+        assert!(ecosystem.territory_zones.iter().any(|zone| zone.zone_type == ZoneType::Void));
+
+        // Everything emergent fades out eventually
+        for zone in &mut ecosystem.territory_zones {
+            zone.age = CONFLICT_ZONE_SECONDS + VOID_ZONE_SECONDS;
+        }
+        ecosystem.update_zone_lifecycle();
+        assert!(ecosystem.territory_zones.is_empty());
+        assert!(ecosystem.drain_zone_events().any(|event| matches!(event, ZoneEvent::Faded { zone_type: ZoneType::Void, .. })));
+    }
+}
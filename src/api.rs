@@ -0,0 +1,1391 @@
+// === EMBEDDING API ===
+// Stable entry point for running the organism standalone or inside another
+// app's window: configure with `AetheriumBloom::builder()`, then drive the
+// returned handle from your own event loop.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::mpsc::Receiver;
+use std::time::Duration;
+
+use glam::Vec2;
+use wgpu::PresentMode;
+use winit::dpi::PhysicalSize;
+use winit::event::WindowEvent;
+use winit::window::{Window, WindowId};
+
+use crate::audio::{DuckingSettings, ExternalSource, LoudnessSettings, MixBus, MixerControls, SampleBankPaths, SpeciesSonicSignature, SurroundConfig};
+use crate::core::ecs::EntityId;
+use crate::core::events::ChaosEvent;
+use crate::error::Result;
+use crate::locale::Locale;
+use crate::mathematics::ForceField;
+use crate::engine::{ChaosTuning, ConsciousnessGraph, DiplomacyEvent, HiveEvent, HomeostasisConfig, MetabolismConfig, ModMatrix, ObserverIntervention, SpeciesEmergence};
+use crate::engine::population::{CullPolicy, DespawnReason, DEFAULT_MAX_POPULATION};
+use crate::engine::safety::{SafetyConfig, SafetyViolation};
+use crate::entities::{FlockingConfig, HybridSpecies, SpeciesType, SPECIES_COUNT};
+use crate::mods::ModManifest;
+use crate::params;
+use crate::reality::{BudgetCategoryUsage, BufferUsageReport, VisualTheme};
+use crate::simulation::{Biography, ZoneEvent};
+use crate::rendering::{FieldView, GradeMood, LodConfig, PaletteConfig, Viewport};
+#[cfg(feature = "attention")]
+use crate::input::AttentionReading;
+use crate::app::{AttractMode, ChaosEngine, EntropyFeed, FullscreenMode, FrameProfile, KeyBindings, KeyConflict, RemoteControl, StateStream, TutorialMode, TutorialStep, DEFAULT_IDLE_TIMEOUT};
+
+/// Events emitted by the organism, drained with `AetheriumBloom::events()`
+#[derive(Debug, Clone, PartialEq)]
+pub enum BloomEvent {
+    LlamaSpawned { entity: EntityId, species: SpeciesType, position: Vec2 },
+    LlamaDespawned { entity: EntityId, species: SpeciesType, position: Vec2, reason: DespawnReason },
+    SafetyViolation(SafetyViolation),
+    EmergencyStop { active: bool },
+    Hive(HiveEvent), // A hive hunted, pulsed, sacrificed a member or negotiated
+    Diplomacy(DiplomacyEvent), // Two species went to war, made peace, allied or fell out
+    Zone(ZoneEvent), // An emergent territory zone appeared, merged or faded
+    SpeciesEmerged(SpeciesEmergence), // A hive of allies stayed mixed long enough to become a hybrid species
+    PhotoSaved(PathBuf),
+    Branched { seconds_ago: f32 }, // The live run was forked from a moment this far back in history
+    VertexBufferPressure(BufferUsageReport), // Frames came near the vertex buffer's ceiling; sent again only after usage falls back
+}
+
+/// Window settings used when the organism owns its window (`run()`)
+#[derive(Debug, Clone)]
+pub struct WindowOptions {
+    pub title: String,
+    pub width: u32,
+    pub height: u32,
+    pub span_displays: bool, // One fullscreen window per monitor, the world split across them
+    pub fullscreen: FullscreenMode, // How the window starts; F11 toggles between this (or borderless) and windowed
+    pub monitor: Option<usize>,     // Monitor to open on, counted left to right from 0; None lets the system choose
+}
+
+impl Default for WindowOptions {
+    fn default() -> Self {
+        Self {
+            title: "🦙 AETHERIUM BLOOM - Psychedelic Digital Organism 🌈".to_string(),
+            width: 1200,
+            height: 800,
+            span_displays: false,
+            fullscreen: FullscreenMode::Windowed,
+            monitor: None,
+        }
+    }
+}
+
+/// Engine settings shared by embedded and standalone use
+#[derive(Debug, Clone)]
+pub struct BloomConfig {
+    pub seed: Option<u64>,
+    pub safety: SafetyConfig,
+    pub audio_enabled: bool,
+    pub initial_population: usize,
+    pub max_population: usize,
+    pub cull_policy: CullPolicy,
+    pub theme: VisualTheme,
+    pub sample_banks: HashMap<SpeciesType, SampleBankPaths>,
+    pub sonic_signatures: HashMap<SpeciesType, SpeciesSonicSignature>, // Species left out keep their built-in voice
+    pub idle_timeout: Option<Duration>, // None never enters idle mode
+    pub attract: AttractMode,           // Self-running exhibit with nobody at the controls; off by default
+    pub present_mode: PresentMode,      // Fifo = vsync; falls back to Fifo when unsupported
+    pub max_fps: Option<u32>,           // CPU frame-rate cap to save power
+    pub autonomous_interventions: bool, // False stops the meta-observer acting on its own
+    pub warfare_overlay: bool,          // Start with the territory map shown; `W` toggles it
+    pub pheromone_overlay: bool,        // Start with the species scent trails shown; `R` toggles them
+    pub force_overlay: bool,            // Start with the force field streamlines shown; `X` toggles them
+    pub field_view: FieldView,          // How the consciousness field starts drawn; `'` cycles the views
+    pub force_fields: Vec<ForceField>,  // Gravity wells, vortexes and winds placed before the first tick
+    pub consciousness_dust: bool,       // GPU dust field drifting under the world
+    pub crystal_refraction: bool,       // Crystals bend the world behind them; false draws flat diamonds
+    pub species_skins: bool,            // Pattern llamas with noise-grown species skins; false draws flat colors
+    pub lighting: f32,                  // How deep areas no conscious llama or crystal lights sink into shadow, 0-1
+    pub color_grading: bool,            // Grade the world through a LUT per audio environment, blended with night
+    pub grade_luts: Vec<(GradeMood, PathBuf)>, // `.cube` files replacing built-in looks; unreadable ones keep the built-in
+    pub day_length: Duration,           // One simulated day, noon to noon, for the night grade
+    pub sound_overlay: bool,            // Start with the oscilloscope and spectrogram shown; `U` toggles it
+    pub profiler_overlay: bool,         // Start with the frame-time breakdown shown; `F` toggles it
+    pub adaptation_strength: f32,       // How far the experience bends to inferred user preferences, 0-1
+    pub homeostasis: Option<HomeostasisConfig>, // Population bands species are held inside; None lets one species take over
+    pub chaos_tuning: ChaosTuning,      // Odds of the stochastic events; `S` opens sliders for them
+    pub modulation: ModMatrix,          // Routes from live signals to spawn rate, hue, distortion and filter; `;` edits them
+    pub modulation_file: Option<PathBuf>, // Read at startup when it exists, replacing `modulation`; the editor saves to it
+    pub lod: LodConfig,                 // Points, density splats and culling for small or off-screen llamas
+    pub palette: PaletteConfig,         // Session-wide harmonic palette every color is pulled into
+    pub flocking: FlockingConfig,       // Per-species separation, alignment, cohesion and orbit weights
+    pub metabolism: Option<MetabolismConfig>, // Energy llamas burn and refill, starving when it runs out; None keeps them rested
+    pub entropy_feeds: Vec<EntropyFeed>, // Real-world noise nudging the llamas' chaos; none by default
+    pub remote_control: Option<RemoteControl>, // HTTP/WebSocket server for tablets and control rooms; None serves nothing
+    pub state_stream: Option<StateStream>, // Binary frames of every entity for external visualizers; None streams nothing
+    pub tutorial: TutorialMode,         // Guided prompts over the organism; none unless asked for
+    pub locale: Locale,                 // Language of the warning screen, HUD, tutorial and control status
+    pub key_bindings: KeyBindings,      // Which key triggers which action; F12 opens the editor
+    pub key_bindings_file: Option<PathBuf>, // Read at startup when it exists, replacing `key_bindings`; the editor saves to it
+    pub photo_directory: PathBuf,       // Where photo mode saves its PNGs
+    pub saga_path: Option<PathBuf>,     // Where the session's saga is written when it ends; None keeps it in memory
+    pub journal_directory: Option<PathBuf>, // Where a markdown report of the session is written when it ends; None writes none
+    pub crash_directory: Option<PathBuf>, // Where crash reports and autosaves go; None installs no panic hook
+    pub autosave_interval: Duration,    // Time between autosaves to the crash directory
+    pub restore_autosave: bool,         // Start from the crash directory's last autosave when there is one
+    pub supervise: bool,                // `run()` relaunches the organism from its last autosave whenever it crashes
+    pub warm_start: Duration,           // Simulated time fast-forwarded before the first frame; zero starts cold
+    pub mods_directory: Option<PathBuf>, // Folder of community mods loaded at startup; None loads none
+    pub garden_layout: Option<PathBuf>, // Starting zones, crystals and walls, read when it exists; the garden editor saves to it
+    pub software_renderer: bool,        // Draw with the CPU fallback even when a GPU is available
+    pub external_audio: Option<ExternalSource>, // Music to duck the synth under from the start; `D` toggles loopback
+    pub ducking: DuckingSettings,       // How far and how fast the synth ducks under external music
+    pub loudness: LoudnessSettings,     // Loudness the output is held at and the true-peak ceiling over it
+    pub heard_beat_visuals: bool,       // Visuals pulse with onsets heard in the audio output rather than the beat engine
+    pub surround: SurroundConfig,       // Speaker layout and which channels each species plays from
+    pub environment_crossfade: Duration, // How long audio environments take to fade into each other, starting on a beat
+}
+
+impl Default for BloomConfig {
+    fn default() -> Self {
+        Self {
+            seed: None,
+            safety: SafetyConfig::default(),
+            audio_enabled: true,
+            initial_population: 3, // One of each species
+            max_population: DEFAULT_MAX_POPULATION,
+            cull_policy: CullPolicy::Oldest,
+            theme: VisualTheme::Psychedelic,
+            sample_banks: HashMap::new(),
+            sonic_signatures: HashMap::new(),
+            idle_timeout: Some(DEFAULT_IDLE_TIMEOUT),
+            attract: AttractMode::Off,
+            present_mode: PresentMode::Fifo,
+            max_fps: None,
+            autonomous_interventions: true,
+            warfare_overlay: false,
+            pheromone_overlay: false,
+            force_overlay: false,
+            field_view: FieldView::Off,
+            force_fields: Vec::new(),
+            consciousness_dust: true,
+            crystal_refraction: true,
+            species_skins: true,
+            lighting: 0.5,
+            color_grading: true,
+            grade_luts: Vec::new(),
+            day_length: Duration::from_secs_f32(params::DAY_LENGTH_SECONDS.default),
+            sound_overlay: false,
+            profiler_overlay: false,
+            adaptation_strength: 0.5,
+            homeostasis: None,
+            chaos_tuning: ChaosTuning::default(),
+            modulation: ModMatrix::default(),
+            modulation_file: None,
+            lod: LodConfig::default(),
+            palette: PaletteConfig::default(),
+            flocking: FlockingConfig::default(),
+            metabolism: None,
+            entropy_feeds: Vec::new(),
+            remote_control: None,
+            state_stream: None,
+            tutorial: TutorialMode::default(),
+            locale: Locale::english(),
+            key_bindings: KeyBindings::default(),
+            key_bindings_file: None,
+            photo_directory: PathBuf::from("."),
+            saga_path: None,
+            journal_directory: None,
+            crash_directory: None,
+            autosave_interval: Duration::from_secs_f32(params::AUTOSAVE_INTERVAL_SECONDS.default),
+            restore_autosave: false,
+            supervise: false,
+            warm_start: Duration::from_secs_f32(params::WARM_START_SECONDS.default),
+            mods_directory: None,
+            garden_layout: None,
+            software_renderer: false,
+            external_audio: None,
+            ducking: DuckingSettings::default(),
+            loudness: LoudnessSettings::default(),
+            heard_beat_visuals: false,
+            surround: SurroundConfig::default(),
+            environment_crossfade: Duration::from_secs_f32(params::ENVIRONMENT_CROSSFADE_SECONDS.default),
+        }
+    }
+}
+
+/// Builder for an `AetheriumBloom` instance
+#[derive(Debug, Clone, Default)]
+pub struct AetheriumBloomBuilder {
+    window: WindowOptions,
+    config: BloomConfig,
+}
+
+impl AetheriumBloomBuilder {
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.window.title = title.into();
+        self
+    }
+
+    pub fn window_size(mut self, width: u32, height: u32) -> Self {
+        self.window.width = width;
+        self.window.height = height;
+        self
+    }
+
+    /// Installation mode: a borderless fullscreen window on every monitor,
+    /// the world split into strips across them left to right
+    pub fn span_displays(mut self, enabled: bool) -> Self {
+        self.window.span_displays = enabled;
+        self
+    }
+
+    /// Start fullscreen: `Borderless` for kiosks, or `Exclusive` to switch the monitor's resolution
+    pub fn fullscreen(mut self, mode: FullscreenMode) -> Self {
+        self.window.fullscreen = mode;
+        self
+    }
+
+    /// Open on this monitor, counted left to right from 0
+    pub fn monitor(mut self, index: usize) -> Self {
+        self.window.monitor = Some(index);
+        self
+    }
+
+    /// Seed the simulation RNG for reproducible runs
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.config.seed = Some(seed);
+        self
+    }
+
+    /// Start in reduced-intensity safety mode
+    pub fn safety_mode(mut self, enabled: bool) -> Self {
+        self.config.safety = if enabled { SafetyConfig::safe_mode() } else { SafetyConfig::default() };
+        self
+    }
+
+    pub fn safety_config(mut self, safety: SafetyConfig) -> Self {
+        self.config.safety = safety;
+        self
+    }
+
+    pub fn audio(mut self, enabled: bool) -> Self {
+        self.config.audio_enabled = enabled;
+        self
+    }
+
+    pub fn initial_population(mut self, count: usize) -> Self {
+        self.config.initial_population = count;
+        self
+    }
+
+    /// Ceiling on live llamas; spawning at the cap culls per the cull policy
+    pub fn max_population(mut self, max: usize) -> Self {
+        self.config.max_population = max.max(1);
+        self
+    }
+
+    pub fn cull_policy(mut self, policy: CullPolicy) -> Self {
+        self.config.cull_policy = policy;
+        self
+    }
+
+    /// WAV files played for a species' spawn, bass drop and crystal harvest cues;
+    /// cues left unset keep their synthesized sound
+    pub fn sample_bank(mut self, species: SpeciesType, paths: SampleBankPaths) -> Self {
+        self.config.sample_banks.insert(species, paths);
+        self
+    }
+
+    /// Custom voice for a species; start from `SpeciesSonicSignature::for_species`
+    pub fn sonic_signature(mut self, species: SpeciesType, signature: SpeciesSonicSignature) -> Self {
+        self.config.sonic_signatures.insert(species, signature);
+        self
+    }
+
+    /// Time without input before the organism fades into low-power idle mode;
+    /// `None` keeps it running at full rate forever
+    pub fn idle_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.config.idle_timeout = timeout;
+        self
+    }
+
+    /// Let a director run the organism as an exhibit: spawning llamas along a virtual
+    /// cursor's path, cycling audio modes and framing the camera until someone takes over
+    pub fn attract(mut self, mode: AttractMode) -> Self {
+        self.config.attract = mode;
+        self
+    }
+
+    /// Fifo (vsync), Mailbox (low-latency vsync) or Immediate (tearing, uncapped)
+    pub fn present_mode(mut self, present_mode: PresentMode) -> Self {
+        self.config.present_mode = present_mode;
+        self
+    }
+
+    /// Cap the frame rate, e.g. 30 to save power; the simulation ticks once per frame
+    pub fn max_fps(mut self, max_fps: Option<u32>) -> Self {
+        self.config.max_fps = max_fps;
+        self
+    }
+
+    /// Let the meta-observer intervene on its own; off gives a purely emergent run
+    pub fn autonomous_interventions(mut self, enabled: bool) -> Self {
+        self.config.autonomous_interventions = enabled;
+        self
+    }
+
+    /// Start with the warfare territory map drawn under the llamas
+    pub fn warfare_overlay(mut self, enabled: bool) -> Self {
+        self.config.warfare_overlay = enabled;
+        self
+    }
+
+    /// Drift tens of thousands of dust motes, moved on the GPU, through the world behind the llamas
+    pub fn consciousness_dust(mut self, enabled: bool) -> Self {
+        self.config.consciousness_dust = enabled;
+        self
+    }
+
+    /// Draw crystals as faceted lenses that bend the world behind them and glint with their resonance
+    pub fn crystal_refraction(mut self, enabled: bool) -> Self {
+        self.config.crystal_refraction = enabled;
+        self
+    }
+
+    /// Pattern each species with an animated skin grown from noise at startup, drawn by the psychedelic theme
+    pub fn species_skins(mut self, enabled: bool) -> Self {
+        self.config.species_skins = enabled;
+        self
+    }
+
+    /// Light the world from conscious llamas and crystals, with walls and pillars casting soft
+    /// shadows; `strength` is how deep unlit areas sink, and 0 turns lighting off
+    pub fn lighting(mut self, strength: f32) -> Self {
+        self.config.lighting = strength.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Grade the finished world through a 3D LUT that follows the audio environment and
+    /// the time of day, easing from one look to the next
+    pub fn color_grading(mut self, enabled: bool) -> Self {
+        self.config.color_grading = enabled;
+        self
+    }
+
+    /// Grade `mood` through a `.cube` 3D LUT instead of its built-in look; like the built-in
+    /// looks it is pulled towards neutral until it stays inside the safety limits
+    pub fn grade_lut(mut self, mood: GradeMood, path: impl Into<PathBuf>) -> Self {
+        self.config.grade_luts.push((mood, path.into()));
+        self
+    }
+
+    /// How long one simulated day takes; the night look is graded in around midnight
+    pub fn day_length(mut self, length: Duration) -> Self {
+        self.config.day_length = length;
+        self
+    }
+
+    /// Start with the scent trails of every species drawn under the llamas
+    pub fn pheromone_overlay(mut self, enabled: bool) -> Self {
+        self.config.pheromone_overlay = enabled;
+        self
+    }
+
+    /// Start with streamlines tracing the force fields drawn under the llamas
+    pub fn force_overlay(mut self, enabled: bool) -> Self {
+        self.config.force_overlay = enabled;
+        self
+    }
+
+    /// Start with the environmental consciousness field drawn under the llamas
+    pub fn field_view(mut self, view: FieldView) -> Self {
+        self.config.field_view = view;
+        self
+    }
+
+    /// Place a gravity well, vortex or wind before the first tick; may be called repeatedly
+    pub fn force_field(mut self, field: ForceField) -> Self {
+        self.config.force_fields.push(field);
+        self
+    }
+
+    /// Start with the oscilloscope ring and spectrogram of the live audio drawn over the world
+    pub fn sound_overlay(mut self, enabled: bool) -> Self {
+        self.config.sound_overlay = enabled;
+        self
+    }
+
+    /// Start with the per-subsystem frame-time bar drawn over the world
+    pub fn profiler_overlay(mut self, enabled: bool) -> Self {
+        self.config.profiler_overlay = enabled;
+        self
+    }
+
+    /// How strongly inferred user preferences reshape spawns, palette, audio and interventions;
+    /// 0 keeps the experience as designed
+    pub fn adaptation_strength(mut self, strength: f32) -> Self {
+        self.config.adaptation_strength = strength.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Keep every species within its population band by leaning on spawn weights and extinction pressure
+    pub fn homeostasis(mut self, config: HomeostasisConfig) -> Self {
+        self.config.homeostasis = Some(config);
+        self
+    }
+
+    /// How often crystals, tears, mutations, wars, predation and hive links happen
+    pub fn chaos_tuning(mut self, tuning: ChaosTuning) -> Self {
+        self.config.chaos_tuning = tuning;
+        self
+    }
+
+    /// Routes from the beat, hive coherence, cursor speed, bass and time of day to
+    /// spawn rate, hue, reality distortion and the synth's filter cutoff
+    pub fn modulation(mut self, matrix: ModMatrix) -> Self {
+        self.config.modulation = matrix;
+        self
+    }
+
+    /// Load the modulation matrix from this RON file at startup, if it exists, and save edits made with the `;` panel back to it
+    pub fn modulation_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.config.modulation_file = Some(path.into());
+        self
+    }
+
+    /// When llamas shrink to points and density splats and how often off-screen ones update;
+    /// `LodConfig::disabled()` draws and updates every llama in full
+    pub fn level_of_detail(mut self, lod: LodConfig) -> Self {
+        self.config.lod = lod;
+        self
+    }
+
+    /// How strongly colors are pulled into the session's harmonic palette and how fast it turns;
+    /// `PaletteConfig::disabled()` leaves every subsystem its own colors
+    pub fn palette(mut self, palette: PaletteConfig) -> Self {
+        self.config.palette = palette;
+        self
+    }
+
+    /// How each species herds; `FlockingConfig::disabled()` leaves llamas to their own drives
+    pub fn flocking(mut self, config: FlockingConfig) -> Self {
+        self.config.flocking = config;
+        self
+    }
+
+    /// Make llamas burn energy moving and fighting, refill it from crystals and meditative zones, and starve without
+    pub fn metabolism(mut self, config: MetabolismConfig) -> Self {
+        self.config.metabolism = Some(config);
+        self
+    }
+
+    /// Let a real-world entropy source perturb the llamas' chaos; call once per feed
+    pub fn entropy_feed(mut self, feed: EntropyFeed) -> Self {
+        self.config.entropy_feeds.push(feed);
+        self
+    }
+
+    /// Serve the status and take commands over HTTP and WebSocket at `address`, from clients presenting `token`
+    pub fn remote_control(mut self, address: impl Into<String>, token: impl Into<String>) -> Self {
+        self.config.remote_control = Some(RemoteControl::new(address, token));
+        self
+    }
+
+    /// Stream entity positions, colors and events for external visualizers, over UDP and the remote control's `/stream`
+    pub fn state_stream(mut self, stream: StateStream) -> Self {
+        self.config.state_stream = Some(stream);
+        self
+    }
+
+    /// When to show the guided tutorial; `TutorialMode::first_run()` for a standalone app
+    pub fn tutorial(mut self, mode: TutorialMode) -> Self {
+        self.config.tutorial = mode;
+        self
+    }
+
+    /// Language of everything a person reads, starting with the epilepsy warning;
+    /// load one with `Locale::load(directory, "de")`
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.config.locale = locale;
+        self
+    }
+
+    /// Keys for every action, in place of the defaults
+    pub fn key_bindings(mut self, bindings: KeyBindings) -> Self {
+        self.config.key_bindings = bindings;
+        self
+    }
+
+    /// Load key bindings from this file at startup, if it exists, and save edits made with the F12 editor back to it
+    pub fn key_bindings_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.config.key_bindings_file = Some(path.into());
+        self
+    }
+
+    /// Directory photo mode saves its high-resolution PNGs to; defaults to the working directory
+    pub fn photo_directory(mut self, directory: impl Into<PathBuf>) -> Self {
+        self.config.photo_directory = directory.into();
+        self
+    }
+
+    /// Write the chronicle's saga of notable llama lives to `path` when the session ends
+    pub fn saga_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.config.saga_path = Some(path.into());
+        self
+    }
+
+    /// Write a markdown observation journal of each session (population charts, timeline, final
+    /// consciousness, seed and settings) into `directory` when it ends
+    pub fn journal_directory(mut self, directory: impl Into<PathBuf>) -> Self {
+        self.config.journal_directory = Some(directory.into());
+        self
+    }
+
+    /// Write crash reports (backtrace, recent events, config, world snapshot) and autosaves to `directory`
+    pub fn crash_directory(mut self, directory: impl Into<PathBuf>) -> Self {
+        self.config.crash_directory = Some(directory.into());
+        self
+    }
+
+    pub fn autosave_interval(mut self, interval: Duration) -> Self {
+        self.config.autosave_interval = interval;
+        self
+    }
+
+    /// Start from the last autosave in the crash directory instead of a fresh herd
+    pub fn restore_autosave(mut self, enabled: bool) -> Self {
+        self.config.restore_autosave = enabled;
+        self
+    }
+
+    /// Have `run()` relaunch the organism after a crash, restoring the last autosave
+    pub fn supervise(mut self, enabled: bool) -> Self {
+        self.config.supervise = enabled;
+        self
+    }
+
+    /// Fast-forward this much simulated time, behind a progress screen, before the first frame
+    pub fn warm_start(mut self, duration: Duration) -> Self {
+        self.config.warm_start = duration;
+        self
+    }
+
+    /// Load the community mods (species, theme shaders, samples, scripts) in `directory`'s subfolders
+    pub fn mods_directory(mut self, directory: impl Into<PathBuf>) -> Self {
+        self.config.mods_directory = Some(directory.into());
+        self
+    }
+
+    /// Start from the garden layout in this file, if it exists, and save the garden editor's designs to it
+    pub fn garden_layout(mut self, path: impl Into<PathBuf>) -> Self {
+        self.config.garden_layout = Some(path.into());
+        self
+    }
+
+    /// Listen to external music (a DJ set, a playlist) and duck the synth under it
+    pub fn duck_under(mut self, source: ExternalSource) -> Self {
+        self.config.external_audio = Some(source);
+        self
+    }
+
+    pub fn ducking(mut self, settings: DuckingSettings) -> Self {
+        self.config.ducking = settings;
+        self
+    }
+
+    /// Hold the output at a loudness target under a true-peak ceiling, so long runs don't creep louder
+    pub fn loudness(mut self, settings: LoudnessSettings) -> Self {
+        self.config.loudness = settings;
+        self
+    }
+
+    /// Pulse the visuals with the onsets detected in the synthesized output, echoes and effects included,
+    /// instead of the beat engine's idea of the beat
+    pub fn heard_beat_visuals(mut self, enabled: bool) -> Self {
+        self.config.heard_beat_visuals = enabled;
+        self
+    }
+
+    /// How long one audio environment takes to fade into the next; zero switches hard, on the beat
+    pub fn environment_crossfade(mut self, duration: Duration) -> Self {
+        self.config.environment_crossfade = duration;
+        self
+    }
+
+    /// Render for a multichannel rig, giving each species its own speakers (e.g. 4.0 or 5.1)
+    pub fn surround(mut self, config: SurroundConfig) -> Self {
+        self.config.surround = config;
+        self
+    }
+
+    /// Skip the GPU and draw the simplified CPU fallback, as on machines without a usable adapter
+    pub fn software_renderer(mut self, enabled: bool) -> Self {
+        self.config.software_renderer = enabled;
+        self
+    }
+
+    /// Visual theme at start-up; `V` cycles themes while running
+    pub fn theme(mut self, theme: VisualTheme) -> Self {
+        self.config.theme = theme;
+        self
+    }
+
+    pub fn config(&self) -> &BloomConfig {
+        &self.config
+    }
+
+    pub fn window_options(&self) -> &WindowOptions {
+        &self.window
+    }
+
+    /// Attach to a window owned by the embedding app
+    pub async fn build(self, window: Arc<Window>) -> Result<AetheriumBloom> {
+        params::validate_config(&self.config)?;
+        let engine = ChaosEngine::new(window, &self.config).await?;
+        Ok(AetheriumBloom { engine, war_hooks: Vec::new(), treaty_hooks: Vec::new() })
+    }
+
+    /// Run standalone: own window, epilepsy warning screen, then the organism
+    #[cfg(feature = "app")]
+    pub fn run(mut self) -> Result<()> {
+        params::validate_config(&self.config)?;
+        if self.config.supervise && !crate::app::is_supervised() {
+            return crate::app::supervise(self.config.crash_directory.as_deref());
+        }
+        if crate::app::is_supervised() {
+            crate::app::exit_on_panic();
+        }
+        if crate::app::relaunched() {
+            self.config.restore_autosave = true;
+        }
+        crate::app::run_standalone(self.window, self.config)
+    }
+
+    /// Run as the system screensaver for a command parsed from the host's arguments
+    /// with `ScreensaverCommand::parse`; the warning answer comes from the stored profile
+    #[cfg(feature = "app")]
+    pub fn run_screensaver(self, command: crate::app::ScreensaverCommand) -> Result<()> {
+        params::validate_config(&self.config)?;
+        crate::app::run_screensaver(self.window, self.config, command)
+    }
+
+    /// Run as an ambient music machine: the simulation and synth with no window,
+    /// streaming state if `state_stream` is set, until the process is stopped
+    #[cfg(feature = "app")]
+    pub fn run_ambient(self) -> Result<()> {
+        params::validate_config(&self.config)?;
+        crate::app::init_logging();
+        crate::app::run_ambient(&self.config)
+    }
+}
+
+/// Called with (attacker, defender, front) when a species declares war
+type WarHook = Box<dyn FnMut(SpeciesType, SpeciesType, Vec2)>;
+/// Called with both species when a peace treaty is signed
+type TreatyHook = Box<dyn FnMut(SpeciesType, SpeciesType)>;
+
+/// Handle to a running organism
+pub struct AetheriumBloom {
+    engine: ChaosEngine,
+    war_hooks: Vec<WarHook>,
+    treaty_hooks: Vec<TreatyHook>,
+}
+
+impl AetheriumBloom {
+    pub fn builder() -> AetheriumBloomBuilder {
+        AetheriumBloomBuilder::default()
+    }
+
+    /// Advance the simulation by one 60 Hz tick; hosts not rendering at 60 fps
+    /// can use `app::FixedTimestep` to find how many ticks each frame needs
+    pub fn update(&mut self) {
+        self.engine.update();
+        for &event in self.engine.diplomacy_events() {
+            match event {
+                DiplomacyEvent::WarDeclared { attacker, defender, front } => {
+                    self.war_hooks.iter_mut().for_each(|hook| hook(attacker, defender, front));
+                }
+                DiplomacyEvent::TreatySigned { species: [a, b], .. } => {
+                    self.treaty_hooks.iter_mut().for_each(|hook| hook(a, b));
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Call `hook(attacker, defender, front)` during `update()` whenever a species declares war
+    pub fn on_war_declared(&mut self, hook: impl FnMut(SpeciesType, SpeciesType, Vec2) + 'static) {
+        self.war_hooks.push(Box::new(hook));
+    }
+
+    /// Call `hook(a, b)` during `update()` whenever two species sign a peace treaty
+    pub fn on_treaty_signed(&mut self, hook: impl FnMut(SpeciesType, SpeciesType) + 'static) {
+        self.treaty_hooks.push(Box::new(hook));
+    }
+
+    pub fn render(&mut self) -> Result<(), wgpu::SurfaceError> {
+        self.engine.render()
+    }
+
+    /// Share of the warm-up simulated so far, None once the organism is live;
+    /// while warming, `update` runs as many ticks as fit in a frame
+    pub fn warm_start_progress(&self) -> Option<f32> {
+        self.engine.warm_start_progress()
+    }
+
+    /// Cut the warm-up short and show the world as it is now
+    pub fn skip_warm_start(&mut self) {
+        self.engine.skip_warm_start();
+    }
+
+    pub fn resize(&mut self, new_size: PhysicalSize<u32>) {
+        self.engine.resize(new_size);
+    }
+
+    /// Forward mouse and keyboard input (clicks spawn, keys drive audio controls)
+    pub fn handle_window_event(&mut self, event: &WindowEvent) {
+        self.engine.handle_window_event(event);
+    }
+
+    /// Spawn a llama of the given species at a position in world pixels
+    pub fn spawn(&mut self, species: SpeciesType, position: Vec2) -> EntityId {
+        self.engine.spawn_llama(species, position)
+    }
+
+    /// Remove a llama; returns false if it is already gone
+    pub fn despawn(&mut self, entity: EntityId) -> bool {
+        self.engine.despawn_llama(entity)
+    }
+
+    /// Drain events emitted since the last call
+    pub fn events(&mut self) -> impl Iterator<Item = BloomEvent> + '_ {
+        self.engine.drain_events()
+    }
+
+    /// Receive every typed chaos event from now on: spawns, hives, conflicts, crystals, tears, interventions and beat drops.
+    /// The receiver can move to another thread; one left unread misses events once it is far behind
+    pub fn subscribe(&mut self) -> Receiver<ChaosEvent> {
+        self.engine.subscribe()
+    }
+
+    pub fn population(&self) -> usize {
+        self.engine.population()
+    }
+
+    /// Manifests of the mods that loaded, in the order they were applied
+    pub fn loaded_mods(&self) -> Vec<ModManifest> {
+        self.engine.loaded_mods()
+    }
+
+    pub fn theme(&self) -> VisualTheme {
+        self.engine.visual_theme()
+    }
+
+    pub fn set_theme(&mut self, theme: VisualTheme) {
+        self.engine.set_visual_theme(theme);
+    }
+
+    /// True while dozing after a stretch without input
+    pub fn is_idle(&self) -> bool {
+        self.engine.is_idle()
+    }
+
+    pub fn attract_mode(&self) -> AttractMode {
+        self.engine.attract_mode()
+    }
+
+    /// Change when attract mode takes over; `AttractMode::Always` starts it now
+    pub fn set_attract_mode(&mut self, mode: AttractMode) {
+        self.engine.set_attract_mode(mode);
+    }
+
+    /// True while the attract-mode director is running the show
+    pub fn attracting(&self) -> bool {
+        self.engine.attracting()
+    }
+
+    /// How long a host loop should wait between frames, `None` for every vsync
+    pub fn frame_interval(&self) -> Option<Duration> {
+        self.engine.frame_interval()
+    }
+
+    pub fn present_mode(&self) -> PresentMode {
+        self.engine.present_mode()
+    }
+
+    pub fn set_present_mode(&mut self, present_mode: PresentMode) {
+        self.engine.set_present_mode(present_mode);
+    }
+
+    pub fn max_fps(&self) -> Option<u32> {
+        self.engine.max_fps()
+    }
+
+    pub fn set_max_fps(&mut self, max_fps: Option<u32>) {
+        self.engine.set_max_fps(max_fps);
+    }
+
+    pub fn warfare_overlay(&self) -> bool {
+        self.engine.warfare_overlay()
+    }
+
+    /// Show species territories, conflict fronts and extinction fading
+    pub fn set_warfare_overlay(&mut self, enabled: bool) {
+        self.engine.set_warfare_overlay(enabled);
+    }
+
+    pub fn consciousness_dust(&self) -> bool {
+        self.engine.consciousness_dust()
+    }
+
+    /// Show or hide the ambient dust field
+    pub fn set_consciousness_dust(&mut self, enabled: bool) {
+        self.engine.set_consciousness_dust(enabled);
+    }
+
+    pub fn crystal_refraction(&self) -> bool {
+        self.engine.crystal_refraction()
+    }
+
+    /// Switch between refractive crystals and flat diamonds
+    pub fn set_crystal_refraction(&mut self, enabled: bool) {
+        self.engine.set_crystal_refraction(enabled);
+    }
+
+    pub fn species_skins(&self) -> bool {
+        self.engine.species_skins()
+    }
+
+    /// Switch between patterned species skins and flat colors
+    pub fn set_species_skins(&mut self, enabled: bool) {
+        self.engine.set_species_skins(enabled);
+    }
+
+    pub fn lighting(&self) -> f32 {
+        self.engine.lighting()
+    }
+
+    pub fn set_lighting(&mut self, strength: f32) {
+        self.engine.set_lighting(strength);
+    }
+
+    pub fn color_grading(&self) -> bool {
+        self.engine.color_grading()
+    }
+
+    pub fn set_color_grading(&mut self, enabled: bool) {
+        self.engine.set_color_grading(enabled);
+    }
+
+    /// Replace the look graded in for `mood` with a `.cube` 3D LUT
+    pub fn load_grade_lut(&mut self, mood: GradeMood, path: &Path) -> Result<()> {
+        self.engine.load_grade_lut(mood, path)
+    }
+
+    pub fn day_length(&self) -> Duration {
+        self.engine.day_length()
+    }
+
+    pub fn set_day_length(&mut self, length: Duration) {
+        self.engine.set_day_length(length);
+    }
+
+    pub fn pheromone_overlay(&self) -> bool {
+        self.engine.pheromone_overlay()
+    }
+
+    /// Show the pheromone trails llamas follow, tinted by species
+    pub fn set_pheromone_overlay(&mut self, enabled: bool) {
+        self.engine.set_pheromone_overlay(enabled);
+    }
+
+    pub fn force_overlay(&self) -> bool {
+        self.engine.force_overlay()
+    }
+
+    /// Show streamlines tracing the force fields
+    pub fn set_force_overlay(&mut self, enabled: bool) {
+        self.engine.set_force_overlay(enabled);
+    }
+
+    pub fn field_view(&self) -> FieldView {
+        self.engine.field_view()
+    }
+
+    /// Draw the consciousness field as a heatmap, contour lines or flow arrows, or hide it
+    pub fn set_field_view(&mut self, view: FieldView) {
+        self.engine.set_field_view(view);
+    }
+
+    pub fn force_fields(&self) -> &[ForceField] {
+        self.engine.force_fields()
+    }
+
+    /// Place a gravity well, vortex or wind that pushes llamas and dust
+    pub fn add_force_field(&mut self, field: ForceField) {
+        self.engine.add_force_field(field);
+    }
+
+    pub fn clear_force_fields(&mut self) {
+        self.engine.clear_force_fields();
+    }
+
+    pub fn sound_overlay(&self) -> bool {
+        self.engine.sound_overlay()
+    }
+
+    /// Show the live waveform as an oscilloscope ring and its spectrum as a scrolling spectrogram
+    pub fn set_sound_overlay(&mut self, enabled: bool) {
+        self.engine.set_sound_overlay(enabled);
+    }
+
+    /// Smoothed milliseconds spent per subsystem each frame, and the frame total
+    pub fn frame_profile(&self) -> FrameProfile {
+        self.engine.frame_profile()
+    }
+
+    /// Vertex buffer capacity, last and peak usage, resize count and bytes allocated
+    pub fn vertex_buffer_usage(&self) -> BufferUsageReport {
+        self.engine.vertex_buffer_usage()
+    }
+
+    /// Per-category vertex budgets and how much each category was clipped this frame
+    pub fn vertex_budget(&self) -> Vec<BudgetCategoryUsage> {
+        self.engine.vertex_budget()
+    }
+
+    pub fn profiler_overlay(&self) -> bool {
+        self.engine.profiler_overlay()
+    }
+
+    /// Show how each frame's time splits across the subsystems as a stacked bar against the 60 fps budget
+    pub fn set_profiler_overlay(&mut self, enabled: bool) {
+        self.engine.set_profiler_overlay(enabled);
+    }
+
+    pub fn adaptation_strength(&self) -> f32 {
+        self.engine.adaptation_strength()
+    }
+
+    /// How strongly the experience follows what it has inferred about the user, 0-1
+    pub fn set_adaptation_strength(&mut self, strength: f32) {
+        self.engine.set_adaptation_strength(strength);
+    }
+
+    pub fn homeostasis(&self) -> Option<&HomeostasisConfig> {
+        self.engine.homeostasis()
+    }
+
+    /// Hold species inside population bands, or pass None to let them win and lose freely
+    pub fn set_homeostasis(&mut self, config: Option<HomeostasisConfig>) {
+        self.engine.set_homeostasis(config);
+    }
+
+    pub fn chaos_tuning(&self) -> &ChaosTuning {
+        self.engine.chaos_tuning()
+    }
+
+    /// Retune the odds of the stochastic events while running; out-of-range values are clamped
+    pub fn set_chaos_tuning(&mut self, tuning: ChaosTuning) {
+        self.engine.set_chaos_tuning(tuning);
+    }
+
+    pub fn modulation(&self) -> &ModMatrix {
+        self.engine.modulation()
+    }
+
+    /// Replace every modulation route while running; depths are clamped and extra routes dropped
+    pub fn set_modulation(&mut self, matrix: ModMatrix) {
+        self.engine.set_modulation(matrix);
+    }
+
+    pub fn flocking(&self) -> &FlockingConfig {
+        self.engine.flocking()
+    }
+
+    /// Retune how each species herds while running; weights are clamped into range
+    pub fn set_flocking(&mut self, config: FlockingConfig) {
+        self.engine.set_flocking(config);
+    }
+
+    pub fn metabolism(&self) -> Option<&MetabolismConfig> {
+        self.engine.metabolism()
+    }
+
+    /// Run the energy economy with `config`, or pass None to leave every llama rested
+    pub fn set_metabolism(&mut self, config: Option<MetabolismConfig>) {
+        self.engine.set_metabolism(config);
+    }
+
+    /// Address the remote control server is listening on, once it started
+    pub fn remote_control_address(&self) -> Option<std::net::SocketAddr> {
+        self.engine.remote_control_address()
+    }
+
+    pub fn entropy_feeds(&self) -> &[EntropyFeed] {
+        self.engine.entropy_feeds()
+    }
+
+    /// Replace the running entropy feeds; an empty list leaves the chaos purely synthetic
+    pub fn set_entropy_feeds(&mut self, feeds: Vec<EntropyFeed>) {
+        self.engine.set_entropy_feeds(feeds);
+    }
+
+    /// Tell the ecosystem where the viewer is looking, from an eye tracker or your own estimator;
+    /// send readings several times a second, they go stale after one
+    #[cfg(feature = "attention")]
+    pub fn observe_attention(&mut self, reading: AttentionReading) {
+        self.engine.observe_attention(reading);
+    }
+
+    /// Hand in a webcam frame (packed 8-bit RGB) for the built-in attention estimator
+    #[cfg(feature = "attention")]
+    pub fn observe_camera_frame(&mut self, width: usize, height: usize, rgb: &[u8]) -> AttentionReading {
+        self.engine.observe_camera_frame(width, height, rgb)
+    }
+
+    /// Each species' share of the living population while homeostasis runs, indexed by `SpeciesType::to_index`
+    pub fn species_shares(&self) -> Option<[f32; SPECIES_COUNT]> {
+        self.engine.species_shares()
+    }
+
+    pub fn locale(&self) -> &Locale {
+        self.engine.locale()
+    }
+
+    /// Switch the language of the HUD, tutorial and control status while running
+    pub fn set_locale(&mut self, locale: Locale) {
+        self.engine.set_locale(locale);
+    }
+
+    /// The tutorial prompt waiting to be followed, None once it is done or skipped
+    pub fn tutorial_step(&self) -> Option<TutorialStep> {
+        self.engine.tutorial_step()
+    }
+
+    pub fn key_bindings(&self) -> &KeyBindings {
+        self.engine.key_bindings()
+    }
+
+    /// Rebind keys while running; conflicts are logged and the earlier action keeps the key
+    pub fn set_key_bindings(&mut self, bindings: KeyBindings) {
+        self.engine.set_key_bindings(bindings);
+    }
+
+    /// Actions that share a key in the same context
+    pub fn key_conflicts(&self) -> Vec<KeyConflict> {
+        self.engine.key_bindings().conflicts()
+    }
+
+    /// Open or close the key binding editor; closing saves to the key bindings file, if there is one
+    pub fn set_key_binding_editor(&mut self, open: bool) {
+        self.engine.set_key_binding_editor(open);
+    }
+
+    pub fn skip_tutorial(&mut self) {
+        self.engine.skip_tutorial();
+    }
+
+    /// Walk through the tutorial again, even if it was finished before
+    pub fn restart_tutorial(&mut self) {
+        self.engine.restart_tutorial();
+    }
+
+    pub fn adaptation_inspector(&self) -> bool {
+        self.engine.adaptation_inspector()
+    }
+
+    /// Show what the system currently believes about the user and how it is adapting
+    pub fn set_adaptation_inspector(&mut self, enabled: bool) {
+        self.engine.set_adaptation_inspector(enabled);
+    }
+
+    pub fn lexicon_overlay(&self) -> bool {
+        self.engine.lexicon_overlay()
+    }
+
+    /// List what each species' signal symbols have come to mean and how often they get through
+    pub fn set_lexicon_overlay(&mut self, enabled: bool) {
+        self.engine.set_lexicon_overlay(enabled);
+    }
+
+    pub fn photo_mode(&self) -> bool {
+        self.engine.photo_mode()
+    }
+
+    /// Freeze the world and compose a shot with a free camera; the HUD is hidden meanwhile
+    pub fn set_photo_mode(&mut self, enabled: bool) {
+        self.engine.set_photo_mode(enabled);
+    }
+
+    pub fn garden_editor(&self) -> bool {
+        self.engine.garden_editor()
+    }
+
+    /// Pause the world and design its zones, crystals and walls (Z key)
+    pub fn set_garden_editor(&mut self, open: bool) {
+        self.engine.set_garden_editor(open);
+    }
+
+    /// Save a supersampled PNG of the photo-mode framing on the next render;
+    /// false outside photo mode
+    pub fn capture_photo(&mut self) -> bool {
+        self.engine.capture_photo()
+    }
+
+    pub fn chronicle_inspector(&self) -> bool {
+        self.engine.chronicle_inspector()
+    }
+
+    /// Show the biography of the llama under the cursor, or the most notable lives
+    pub fn set_chronicle_inspector(&mut self, enabled: bool) {
+        self.engine.set_chronicle_inspector(enabled);
+    }
+
+    pub fn llama_name(&self, entity: EntityId) -> Option<&str> {
+        self.engine.llama_name(entity)
+    }
+
+    /// Life story of a living llama
+    pub fn biography(&self, entity: EntityId) -> Option<&Biography> {
+        self.engine.biography(entity)
+    }
+
+    /// Every hybrid species that has emerged from mixed hives this session
+    pub fn hybrid_species(&self) -> &[HybridSpecies] {
+        self.engine.hybrid_species()
+    }
+
+    /// The most notable lives of the session so far, as plain text
+    pub fn saga(&self) -> String {
+        self.engine.saga()
+    }
+
+    pub fn write_saga(&self, path: impl AsRef<Path>) -> Result<()> {
+        self.engine.write_saga(path.as_ref())
+    }
+
+    /// Seed the session's random numbers came from; a run started with it plays out alike
+    pub fn seed(&self) -> u64 {
+        self.engine.seed()
+    }
+
+    /// The session so far as a markdown observation journal
+    pub fn journal(&self) -> String {
+        self.engine.journal()
+    }
+
+    pub fn write_journal(&self, path: impl AsRef<Path>) -> Result<()> {
+        self.engine.write_journal(path.as_ref())
+    }
+
+    /// Snapshot of hive connections, pack memberships and predation for offline analysis
+    pub fn consciousness_graph(&self) -> ConsciousnessGraph {
+        self.engine.consciousness_graph()
+    }
+
+    /// Write the consciousness graph as JSON if `path` ends in `.json`, as GraphViz DOT otherwise
+    pub fn write_consciousness_graph(&self, path: impl AsRef<Path>) -> Result<()> {
+        self.engine.write_consciousness_graph(path.as_ref())
+    }
+
+    pub fn history_scrubbing(&self) -> bool {
+        self.engine.history_scrubbing()
+    }
+
+    /// Rewind through the last hour of snapshots; the live world waits until scrubbing ends
+    pub fn set_history_scrubbing(&mut self, enabled: bool) {
+        self.engine.set_history_scrubbing(enabled);
+    }
+
+    /// Continue live from the snapshot being scrubbed; false when not scrubbing
+    pub fn branch_from_history(&mut self) -> bool {
+        self.engine.branch_from_history()
+    }
+
+    pub fn possessed_llama(&self) -> Option<EntityId> {
+        self.engine.possessed_llama()
+    }
+
+    /// Drive a llama directly with WASD/arrows; false if `entity` is not a llama
+    pub fn possess_llama(&mut self, entity: EntityId) -> bool {
+        self.engine.possess_llama(entity)
+    }
+
+    /// Return the possessed llama to its own control with an enlightenment bonus
+    pub fn release_llama(&mut self) {
+        self.engine.release_llama();
+    }
+
+    /// Make the meta-observer bless, pacify, scramble or redistribute right now
+    pub fn intervene(&mut self, intervention: ObserverIntervention) {
+        self.engine.intervene(intervention);
+    }
+
+    pub fn autonomous_interventions(&self) -> bool {
+        self.engine.autonomous_interventions()
+    }
+
+    pub fn set_autonomous_interventions(&mut self, enabled: bool) {
+        self.engine.set_autonomous_interventions(enabled);
+    }
+
+    /// Part of the world the primary window shows
+    pub fn viewport(&self) -> Viewport {
+        self.engine.viewport()
+    }
+
+    pub fn set_viewport(&mut self, viewport: Viewport) {
+        self.engine.set_viewport(viewport);
+    }
+
+    pub fn level_of_detail(&self) -> LodConfig {
+        self.engine.level_of_detail()
+    }
+
+    /// Change when llamas become points and splats while running; out-of-range values are clamped
+    pub fn set_level_of_detail(&mut self, lod: LodConfig) {
+        self.engine.set_level_of_detail(lod);
+    }
+
+    pub fn palette(&self) -> PaletteConfig {
+        self.engine.palette()
+    }
+
+    /// Change the palette's pull, turning speed and spread while running; out-of-range values are clamped
+    pub fn set_palette(&mut self, palette: PaletteConfig) {
+        self.engine.set_palette(palette);
+    }
+
+    /// Also show the world in `window` through `viewport`; it renders with the primary window
+    pub fn add_output(&mut self, window: Arc<Window>, viewport: Viewport) -> Result<()> {
+        self.engine.add_output(window, viewport)
+    }
+
+    /// Forward a resize of an extra output window; false if `window_id` is not one
+    pub fn resize_output(&mut self, window_id: WindowId, new_size: PhysicalSize<u32>) -> bool {
+        self.engine.resize_output(window_id, new_size)
+    }
+
+    /// Count host-side activity (e.g. a remote control) as user input
+    pub fn wake(&mut self) {
+        self.engine.wake();
+    }
+
+    /// Per-bus gain and mute, or `None` when audio is unavailable
+    pub fn mixer(&self) -> Option<&MixerControls> {
+        self.engine.mixer_controls()
+    }
+
+    /// Set a mix bus level from 0.0 to 1.0
+    pub fn set_bus_gain(&mut self, bus: MixBus, gain: f32) {
+        self.engine.set_bus_gain(bus, gain);
+    }
+
+    pub fn set_bus_muted(&mut self, bus: MixBus, muted: bool) {
+        self.engine.set_bus_muted(bus, muted);
+    }
+
+    /// Listen to external music and duck the synth under it, so the organism can play over a DJ set
+    pub fn enable_ducking(&mut self, source: ExternalSource) -> Result<()> {
+        self.engine.enable_ducking(source)
+    }
+
+    pub fn disable_ducking(&mut self) {
+        self.engine.disable_ducking();
+    }
+
+    /// Name of the device being listened to, or `None` when ducking is off
+    pub fn ducking_source(&self) -> Option<&str> {
+        self.engine.ducking_source()
+    }
+
+    /// Change how far and how fast the synth ducks; values are clamped into their safe ranges
+    pub fn set_ducking_settings(&mut self, settings: DuckingSettings) {
+        self.engine.set_ducking_settings(settings);
+    }
+
+    /// Change the loudness target and true-peak ceiling; values are clamped into their safe ranges
+    pub fn set_loudness_settings(&mut self, settings: LoudnessSettings) {
+        self.engine.set_loudness_settings(settings);
+    }
+
+    /// Integrated loudness of the output over the last half minute, once there is any
+    pub fn output_loudness(&self) -> Option<f32> {
+        self.engine.output_loudness()
+    }
+
+    pub fn heard_beat_visuals(&self) -> bool {
+        self.engine.heard_beat_visuals()
+    }
+
+    /// Let the visuals follow the beat heard in the output, or the beat engine
+    pub fn set_heard_beat_visuals(&mut self, enabled: bool) {
+        self.engine.set_heard_beat_visuals(enabled);
+    }
+
+    pub fn environment_crossfade(&self) -> Duration {
+        self.engine.environment_crossfade()
+    }
+
+    /// Change how long audio environments take to fade into each other; clamped to 30 seconds
+    pub fn set_environment_crossfade(&mut self, duration: Duration) {
+        self.engine.set_environment_crossfade(duration);
+    }
+
+    pub fn surround(&self) -> SurroundConfig {
+        self.engine.surround()
+    }
+
+    /// Switch the speaker layout and species routing; the output stream reopens with the new channel count
+    pub fn set_surround(&mut self, config: SurroundConfig) -> Result<()> {
+        self.engine.set_surround(config)
+    }
+
+    /// Suppress all visual effects until `resume()` is called
+    pub fn emergency_stop(&mut self) {
+        self.engine.request_emergency_stop();
+    }
+
+    pub fn resume(&mut self) {
+        self.engine.clear_emergency_stop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder_collects_config() {
+        let builder = AetheriumBloom::builder()
+            .title("Embedded bloom")
+            .window_size(640, 480)
+            .span_displays(true)
+            .seed(42)
+            .safety_mode(true)
+            .audio(false)
+            .initial_population(7)
+            .max_population(0)
+            .cull_policy(CullPolicy::Weakest)
+            .theme(VisualTheme::CrtScanline)
+            .present_mode(PresentMode::Mailbox)
+            .max_fps(Some(30))
+            .autonomous_interventions(false)
+            .remote_control("127.0.0.1:8420", "llama-keeper");
+
+        assert_eq!(builder.window_options().title, "Embedded bloom");
+        assert_eq!((builder.window_options().width, builder.window_options().height), (640, 480));
+        assert!(builder.window_options().span_displays);
+        assert_eq!(builder.config().seed, Some(42));
+        assert!(!builder.config().audio_enabled);
+        assert_eq!(builder.config().initial_population, 7);
+        assert_eq!(builder.config().max_population, 1);
+        assert_eq!(builder.config().cull_policy, CullPolicy::Weakest);
+        assert_eq!(builder.config().theme, VisualTheme::CrtScanline);
+        assert_eq!(builder.config().present_mode, PresentMode::Mailbox);
+        assert_eq!(builder.config().max_fps, Some(30));
+        assert!(!builder.config().autonomous_interventions);
+        assert_eq!(
+            builder.config().safety.max_luminance_change,
+            SafetyConfig::safe_mode().max_luminance_change
+        );
+        // The config is written into crash reports and observation journals
+        assert!(!format!("{:#?}", builder.config()).contains("llama-keeper"));
+    }
+}
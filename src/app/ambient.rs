@@ -0,0 +1,111 @@
+// === AMBIENT MODE ===
+// The organism as a generative ambient music machine: the simulation and the
+// synth with no window, GPU or warning screen at all, since nothing is shown
+// that could flash. The herd lives on at 60 ticks a second in real time and
+// the synth plays it exactly as it would under the window. Hosts that want
+// pictures elsewhere, a projector in another room or a minimal visualizer,
+// get the state stream, sent just as the windowed app sends it.
+
+use std::thread;
+use std::time::{Duration, Instant};
+use tracing::info;
+use crate::api::BloomConfig;
+use crate::audio::AudioConsciousnessEngine;
+use crate::error::Result;
+use crate::mathematics::BeatState;
+use crate::simulation::{HeadlessSimulation, SIMULATION_DT};
+use super::organism::{audio_llamas, hive_coherence, open_audio_engine};
+use super::stream::StateStreamer;
+
+/// Seconds between the status lines in the log
+const STATUS_INTERVAL: f32 = 60.0;
+/// How far the ticks may fall behind the clock before the backlog is dropped instead of caught up
+const MAX_LAG: Duration = Duration::from_secs(1);
+
+/// Simulation, synth and state stream, without a window
+pub struct AmbientOrganism {
+    simulation: HeadlessSimulation,
+    audio: AudioConsciousnessEngine,
+    streamer: Option<StateStreamer>,
+}
+
+impl AmbientOrganism {
+    /// Seed the simulation from the config and open the audio output; fails without an audio device
+    pub fn new(config: &BloomConfig) -> Result<Self> {
+        let seed = config.seed.unwrap_or_else(|| fastrand::u64(..));
+        let mut simulation = HeadlessSimulation::new(seed, config.initial_population)
+            .with_population_cap(config.max_population, config.cull_policy)
+            .with_flocking(config.flocking.clone());
+        if let Some(homeostasis) = &config.homeostasis {
+            simulation = simulation.with_homeostasis(homeostasis.clone());
+        }
+        if let Some(metabolism) = config.metabolism {
+            simulation = simulation.with_metabolism(metabolism);
+        }
+        simulation.ecosystem.tuning = config.chaos_tuning.clamped();
+        let streamer = config.state_stream.as_ref().map(|stream| StateStreamer::start(stream, simulation.subscribe()));
+        let audio = open_audio_engine(config)?;
+        info!(target: "app", "🎧 Ambient mode from seed {} - {} llamas, no window", seed, config.initial_population);
+        Ok(Self { simulation, audio, streamer })
+    }
+
+    /// Advance one 60 Hz tick, let the synth hear it and stream it if a frame is due
+    pub fn tick(&mut self) {
+        self.simulation.step();
+        let llamas = self.simulation.llamas();
+        let total_consciousness: f32 = llamas.iter()
+            .map(|llama| llama.consciousness + llama.awareness_level + llama.environmental_consciousness)
+            .sum();
+        let beat_engine = self.simulation.beat_engine();
+        let intensity = self.simulation.beat_intensity();
+        let beat = BeatState {
+            is_beat_drop: intensity > 0.8,
+            intensity,
+            phase: self.simulation.time(),
+            prime_factor: beat_engine.get_prime_factor() as f32,
+            cosmic_frequency: 432.0 + total_consciousness * 2.0,
+            tempo_bpm: beat_engine.primary_rhythm,
+        };
+        self.audio.update(
+            self.simulation.time() as f64,
+            &beat,
+            &audio_llamas(llamas),
+            total_consciousness,
+            hive_coherence(&self.simulation.world),
+        );
+        if let Some(streamer) = &mut self.streamer {
+            streamer.frame(&self.simulation.world, self.simulation.time());
+        }
+    }
+
+    pub fn simulation(&self) -> &HeadlessSimulation {
+        &self.simulation
+    }
+
+    pub fn audio(&self) -> &AudioConsciousnessEngine {
+        &self.audio
+    }
+}
+
+/// Play the organism with no window until the process is stopped
+pub fn run_ambient(config: &BloomConfig) -> Result<()> {
+    let mut organism = AmbientOrganism::new(config)?;
+    let tick = Duration::from_secs_f32(SIMULATION_DT);
+    let mut next = Instant::now();
+    loop {
+        organism.tick();
+        let time = organism.simulation().time();
+        if (time / STATUS_INTERVAL).floor() > ((time - SIMULATION_DT) / STATUS_INTERVAL).floor() {
+            let analysis = organism.audio().get_audio_analysis();
+            info!(target: "app", "🎧 {:.0} min - {} llamas, {:?}", time / 60.0, organism.simulation().llamas().len(), analysis.current_environment);
+        }
+
+        next += tick;
+        let now = Instant::now();
+        if now > next + MAX_LAG {
+            next = now;
+        } else if next > now {
+            thread::sleep(next - now);
+        }
+    }
+}
@@ -0,0 +1,210 @@
+// === ATTRACT MODE ===
+// A self-running exhibit. With nobody at the controls a director takes over:
+// a virtual cursor glides along a smooth curve through random waypoints and
+// drops a llama where it is every few seconds, the audio mode cycles, and the
+// camera alternates between the whole world and a slow close-up that follows
+// the most conscious llama. Any real input hands control straight back.
+
+use std::time::Duration;
+use glam::Vec2;
+use tracing::debug;
+use crate::audio::AudioMode;
+use crate::rendering::{Viewport, WORLD_SIZE};
+
+/// Quiet time before an always-on exhibit takes over again after a visitor leaves
+pub const ATTRACT_RESUME_AFTER: Duration = Duration::from_secs(20);
+/// Virtual cursor speed in world units per second
+const CURSOR_SPEED: f32 = 140.0;
+/// Waypoints stay this far inside the world edges
+const WAYPOINT_MARGIN: f32 = 80.0;
+/// Seconds between spawns, chosen at random in this range
+const SPAWN_INTERVAL: (f32, f32) = (4.0, 9.0);
+/// The director stops spawning once this many llamas are alive
+const SPAWN_POPULATION_LIMIT: usize = 40;
+/// Seconds each audio mode plays for
+const MODE_INTERVAL: f32 = 45.0;
+/// Seconds each camera shot, wide or close-up, is held for
+const SHOT_INTERVAL: f32 = 15.0;
+/// Close-ups show this share of the world's width
+const CLOSE_UP_SHARE: f32 = 0.55;
+/// How quickly the camera closes in on its target, per second
+const CAMERA_EASE: f32 = 0.6;
+/// Audio modes in the order the director plays them
+const MODE_CYCLE: [AudioMode; 4] = [AudioMode::Mellow, AudioMode::Active, AudioMode::Chaotic, AudioMode::Active];
+
+/// When the director runs the organism on its own
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AttractMode {
+    #[default]
+    Off,
+    AfterIdle(Duration), // Once nobody has touched anything for this long
+    Always,              // From the start, and again `ATTRACT_RESUME_AFTER` after each visitor
+}
+
+impl AttractMode {
+    /// Quiet time after which the director takes over, None when it never does
+    pub fn starts_after(self) -> Option<Duration> {
+        match self {
+            Self::Off => None,
+            Self::AfterIdle(after) => Some(after),
+            Self::Always => Some(ATTRACT_RESUME_AFTER),
+        }
+    }
+}
+
+/// What the director asks the organism to do this tick
+#[derive(Debug, Clone, PartialEq)]
+pub enum AttractCue {
+    Spawn(Vec2),
+    AudioMode(AudioMode),
+}
+
+pub struct AttractDirector {
+    rng: fastrand::Rng,
+    waypoints: [Vec2; 4], // Catmull-Rom control points; the cursor runs between the middle two
+    segment: f32,         // 0-1 along the current segment
+    cursor: Vec2,
+    next_spawn: f32,
+    next_mode: f32,
+    mode: usize,          // Index into MODE_CYCLE
+    next_shot: f32,
+    close_up: bool,
+    camera: Viewport,
+}
+
+impl AttractDirector {
+    /// Start from the current cursor and camera so the takeover is seamless
+    pub fn new(seed: u64, cursor: Vec2, camera: Viewport) -> Self {
+        let mut rng = fastrand::Rng::with_seed(seed);
+        let waypoints = [cursor, cursor, random_waypoint(&mut rng), random_waypoint(&mut rng)];
+        let next_spawn = rng.f32() * SPAWN_INTERVAL.0;
+        Self {
+            rng,
+            waypoints,
+            segment: 0.0,
+            cursor,
+            next_spawn,
+            next_mode: 0.0,
+            mode: MODE_CYCLE.len() - 1,
+            next_shot: SHOT_INTERVAL,
+            close_up: false,
+            camera,
+        }
+    }
+
+    pub fn cursor(&self) -> Vec2 {
+        self.cursor
+    }
+
+    pub fn camera(&self) -> Viewport {
+        self.camera
+    }
+
+    /// Advance the script; `focus` is the llama close-ups follow, if any
+    pub fn update(&mut self, dt: f32, population: usize, focus: Option<Vec2>) -> Vec<AttractCue> {
+        let mut cues = Vec::new();
+        self.move_cursor(dt);
+
+        self.next_spawn -= dt;
+        if self.next_spawn <= 0.0 {
+            self.next_spawn = SPAWN_INTERVAL.0 + self.rng.f32() * (SPAWN_INTERVAL.1 - SPAWN_INTERVAL.0);
+            if population < SPAWN_POPULATION_LIMIT {
+                cues.push(AttractCue::Spawn(self.cursor));
+            }
+        }
+
+        self.next_mode -= dt;
+        if self.next_mode <= 0.0 {
+            self.next_mode = MODE_INTERVAL;
+            self.mode = (self.mode + 1) % MODE_CYCLE.len();
+            cues.push(AttractCue::AudioMode(MODE_CYCLE[self.mode].clone()));
+        }
+
+        self.next_shot -= dt;
+        if self.next_shot <= 0.0 {
+            self.next_shot = SHOT_INTERVAL;
+            self.close_up = !self.close_up && focus.is_some();
+            debug!(target: "app", "🎬 Attract camera: {}", if self.close_up { "close-up" } else { "wide" });
+        }
+        let target = match focus.filter(|_| self.close_up) {
+            Some(center) => close_up(center),
+            None => Viewport::full(),
+        };
+        let ease = 1.0 - (-CAMERA_EASE * dt).exp();
+        self.camera = Viewport {
+            min: self.camera.min.lerp(target.min, ease),
+            max: self.camera.max.lerp(target.max, ease),
+        };
+        cues
+    }
+
+    fn move_cursor(&mut self, dt: f32) {
+        // Step along the curve by its local speed so the cursor glides at a steady pace
+        let [p0, p1, p2, p3] = self.waypoints;
+        let speed = catmull_rom_tangent(p0, p1, p2, p3, self.segment).length().max(1.0);
+        self.segment += CURSOR_SPEED * dt / speed;
+        while self.segment >= 1.0 {
+            self.segment -= 1.0;
+            self.waypoints = [self.waypoints[1], self.waypoints[2], self.waypoints[3], random_waypoint(&mut self.rng)];
+        }
+        let [p0, p1, p2, p3] = self.waypoints;
+        self.cursor = catmull_rom(p0, p1, p2, p3, self.segment).clamp(Vec2::ZERO, WORLD_SIZE);
+    }
+}
+
+fn random_waypoint(rng: &mut fastrand::Rng) -> Vec2 {
+    let span = WORLD_SIZE - Vec2::splat(WAYPOINT_MARGIN * 2.0);
+    Vec2::splat(WAYPOINT_MARGIN) + Vec2::new(rng.f32(), rng.f32()) * span
+}
+
+/// Point `t` of the way from `p1` to `p2` on a Catmull-Rom spline
+fn catmull_rom(p0: Vec2, p1: Vec2, p2: Vec2, p3: Vec2, t: f32) -> Vec2 {
+    let (t2, t3) = (t * t, t * t * t);
+    0.5 * (2.0 * p1 + (p2 - p0) * t + (2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3) * t2 + (3.0 * p1 - p0 - 3.0 * p2 + p3) * t3)
+}
+
+/// Derivative of `catmull_rom` with respect to `t`
+fn catmull_rom_tangent(p0: Vec2, p1: Vec2, p2: Vec2, p3: Vec2, t: f32) -> Vec2 {
+    0.5 * ((p2 - p0) + 2.0 * (2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3) * t + 3.0 * (3.0 * p1 - p0 - 3.0 * p2 + p3) * t * t)
+}
+
+/// A close-up centered on `center`, kept inside the world
+fn close_up(center: Vec2) -> Viewport {
+    let size = WORLD_SIZE * CLOSE_UP_SHARE;
+    let min = (center - size * 0.5).clamp(Vec2::ZERO, WORLD_SIZE - size);
+    Viewport { min, max: min + size }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_director_spawns_cycles_audio_and_frames_shots() {
+        let mut director = AttractDirector::new(7, Vec2::new(600.0, 400.0), Viewport::full());
+        let (mut spawns, mut modes) = (0, Vec::new());
+        let mut previous = director.cursor();
+        for tick in 0..(60 * 60) {
+            for cue in director.update(1.0 / 60.0, 10, Some(Vec2::new(1150.0, 50.0))) {
+                match cue {
+                    AttractCue::Spawn(position) => {
+                        spawns += 1;
+                        assert!(position.cmpge(Vec2::ZERO).all() && position.cmple(WORLD_SIZE).all());
+                    }
+                    AttractCue::AudioMode(mode) => modes.push(mode),
+                }
+            }
+            let cursor = director.cursor();
+            assert!(cursor.distance(previous) < 10.0, "cursor jumped at tick {}", tick);
+            previous = cursor;
+            let camera = director.camera();
+            assert!(camera.min.cmpge(Vec2::splat(-0.01)).all() && camera.max.cmple(WORLD_SIZE + 0.01).all());
+        }
+        assert!((6..=15).contains(&spawns), "{} spawns in a minute", spawns);
+        assert_eq!(modes, vec![AudioMode::Mellow, AudioMode::Active]);
+        assert!(director.update(1.0 / 60.0, SPAWN_POPULATION_LIMIT, None).iter().all(|cue| !matches!(cue, AttractCue::Spawn(_))));
+
+        assert_eq!(AttractMode::Off.starts_after(), None);
+        assert_eq!(AttractMode::Always.starts_after(), Some(ATTRACT_RESUME_AFTER));
+    }
+}
@@ -0,0 +1,348 @@
+// === CRASH RECOVERY ===
+// Long installations have to outlive a panic. With a crash directory set, the
+// organism keeps a black box of its last chaos events, its tick and its newest
+// world snapshot, and a panic hook writes all of it beside a backtrace and the
+// config before the process goes down. The snapshot is also autosaved on a
+// timer. In supervisor mode the standalone binary runs itself as a child and
+// relaunches it after every crash from the last autosave; when the warning
+// screen was already answered in this session the relaunch goes straight in.
+// Only a panic or a fatal signal counts as a crash: a child that stops with an
+// error of its own, such as a bad config or no usable GPU, would only fail the
+// same way again, so the supervisor stops with it.
+
+use std::collections::VecDeque;
+use std::fmt::Write as _;
+use std::panic::PanicHookInfo;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::Receiver;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use parking_lot::Mutex;
+use tracing::{error, info, warn};
+use crate::core::events::ChaosEvent;
+use crate::error::{BloomError, Result};
+use crate::simulation::WorldSnapshot;
+
+/// Chaos events kept for the crash report
+pub const CRASH_EVENTS: usize = 200;
+/// Where the binary keeps crash reports and autosaves, inside the home directory
+const DEFAULT_DIRECTORY_NAME: &str = ".aetherium_bloom_crashes";
+const AUTOSAVE_FILE: &str = "autosave.snapshot";
+/// Longest the panic hook waits for a black box that was mid-update
+const HOOK_LOCK_WAIT: Duration = Duration::from_millis(100);
+
+/// The directory the standalone binary uses for crash reports and autosaves
+pub fn default_crash_directory() -> PathBuf {
+    super::home_dir().join(DEFAULT_DIRECTORY_NAME)
+}
+
+/// What the panic hook writes out, kept current every tick
+struct BlackBox {
+    events: VecDeque<ChaosEvent>,
+    receiver: Receiver<ChaosEvent>,
+    tick: u64,
+    time: f32,
+    config: String,            // The config the organism started with, already formatted
+    snapshot: Option<Vec<u8>>, // Newest world snapshot, flattened
+}
+
+/// Feeds the black box and autosaves; the panic hook goes quiet once this is dropped
+pub(crate) struct CrashRecorder {
+    directory: PathBuf,
+    black_box: Arc<Mutex<BlackBox>>,
+    autosave_interval: f32,
+    next_autosave: f32,
+}
+
+impl CrashRecorder {
+    pub fn install(directory: PathBuf, config: String, events: Receiver<ChaosEvent>, autosave_interval: Duration) -> Self {
+        if let Err(e) = std::fs::create_dir_all(&directory) {
+            warn!(target: "app", "🚑 Crash directory {} could not be created: {}", directory.display(), e);
+        }
+        let black_box = Arc::new(Mutex::new(BlackBox {
+            events: VecDeque::with_capacity(CRASH_EVENTS),
+            receiver: events,
+            tick: 0,
+            time: 0.0,
+            config,
+            snapshot: None,
+        }));
+
+        // Earlier hooks (the default message, a host's own) still run after ours
+        let previous = std::panic::take_hook();
+        let (hook_box, hook_directory) = (Arc::downgrade(&black_box), directory.clone());
+        std::panic::set_hook(Box::new(move |info| {
+            if let Some(black_box) = hook_box.upgrade() {
+                write_crash_report(&hook_directory, &black_box, info);
+            }
+            previous(info);
+        }));
+
+        info!(target: "app", "🚑 Crash reports and autosaves go to {}", directory.display());
+        let autosave_interval = autosave_interval.as_secs_f32();
+        Self { directory, black_box, autosave_interval, next_autosave: autosave_interval }
+    }
+
+    /// Note one tick at organism `time`, with the snapshot history took on it, if any
+    pub fn record(&mut self, time: f32, snapshot: Option<&WorldSnapshot>) {
+        let mut guard = self.black_box.lock();
+        let black_box = &mut *guard;
+        black_box.tick += 1;
+        black_box.time = time;
+        for event in black_box.receiver.try_iter() {
+            if black_box.events.len() >= CRASH_EVENTS {
+                black_box.events.pop_front();
+            }
+            black_box.events.push_back(event);
+        }
+        if let Some(snapshot) = snapshot {
+            black_box.snapshot = Some(snapshot.to_bytes());
+        }
+
+        if time < self.next_autosave {
+            return;
+        }
+        self.next_autosave = time + self.autosave_interval;
+        if let Some(bytes) = &black_box.snapshot {
+            if let Err(e) = write_replacing(&self.directory.join(AUTOSAVE_FILE), bytes) {
+                warn!(target: "app", "🚑 Autosave failed: {}", e);
+            }
+        }
+    }
+}
+
+/// A snapshot saved to `path`, such as an autosave copied out of the crash directory
+pub fn read_snapshot(path: &Path) -> Result<WorldSnapshot> {
+    let failed = |reason: String| BloomError::Snapshot { path: path.to_path_buf(), reason };
+    let bytes = std::fs::read(path).map_err(|e| failed(e.to_string()))?;
+    WorldSnapshot::from_bytes(&bytes).ok_or_else(|| failed("not a world snapshot, or damaged".to_string()))
+}
+
+/// The last autosave in `directory`, if there is a readable one
+pub(crate) fn load_autosave(directory: &Path) -> Option<WorldSnapshot> {
+    let path = directory.join(AUTOSAVE_FILE);
+    let bytes = match std::fs::read(&path) {
+        Ok(bytes) => bytes,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return None,
+        Err(e) => {
+            warn!(target: "app", "🚑 Autosave {} could not be read: {}", path.display(), e);
+            return None;
+        }
+    };
+    let snapshot = WorldSnapshot::from_bytes(&bytes);
+    if snapshot.is_none() {
+        warn!(target: "app", "🚑 Autosave {} is damaged - starting fresh", path.display());
+    }
+    snapshot
+}
+
+/// Write through a temporary file so a crash mid-write never leaves half a file
+fn write_replacing(path: &Path, bytes: &[u8]) -> std::io::Result<()> {
+    let partial = path.with_extension("partial");
+    std::fs::write(&partial, bytes)?;
+    std::fs::rename(&partial, path)
+}
+
+fn write_crash_report(directory: &Path, black_box: &Mutex<BlackBox>, info: &PanicHookInfo<'_>) {
+    let stamp = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |elapsed| elapsed.as_millis());
+    let path = directory.join(format!("crash-{}.txt", stamp));
+    let report = crash_report(directory, stamp, black_box, info);
+    match std::fs::write(&path, report) {
+        Ok(()) => error!(target: "app", "🚑 Crash report written to {}", path.display()),
+        Err(e) => error!(target: "app", "🚑 Crash report could not be written to {}: {}", path.display(), e),
+    }
+}
+
+fn crash_report(directory: &Path, stamp: u128, black_box: &Mutex<BlackBox>, info: &PanicHookInfo<'_>) -> String {
+    let mut report = String::new();
+    let _ = writeln!(report, "AetheriumBloom crash report {}", stamp);
+    let _ = writeln!(report, "panic: {}", info);
+    let _ = writeln!(report, "thread: {}", std::thread::current().name().unwrap_or("unnamed"));
+
+    // The panic may have struck while this very thread held the lock
+    match black_box.try_lock_for(HOOK_LOCK_WAIT) {
+        Some(mut black_box) => {
+            let black_box = &mut *black_box;
+            black_box.events.extend(black_box.receiver.try_iter());
+            let skip = black_box.events.len().saturating_sub(CRASH_EVENTS);
+            let _ = writeln!(report, "tick: {} ({:.1} s)", black_box.tick, black_box.time);
+            let snapshot = match &black_box.snapshot {
+                Some(bytes) => {
+                    let snapshot_path = directory.join(format!("crash-{}.snapshot", stamp));
+                    match std::fs::write(&snapshot_path, bytes) {
+                        Ok(()) => snapshot_path.display().to_string(),
+                        Err(e) => format!("not written ({})", e),
+                    }
+                }
+                None => "none taken yet".to_string(),
+            };
+            let _ = writeln!(report, "snapshot: {}", snapshot);
+            let _ = writeln!(report, "\n== last {} events ==", black_box.events.len() - skip);
+            for event in black_box.events.iter().skip(skip) {
+                let _ = writeln!(report, "{:?}", event);
+            }
+            let _ = writeln!(report, "\n== config ==\n{}", black_box.config);
+        }
+        None => {
+            let _ = writeln!(report, "state: unavailable, it was being recorded when the panic struck");
+        }
+    }
+
+    let _ = writeln!(report, "\n== backtrace ==\n{}", std::backtrace::Backtrace::force_capture());
+    report
+}
+
+#[cfg(feature = "app")]
+pub(crate) use supervisor::{exit_on_panic, is_supervised, record_session, relaunched, resumed_response, supervise};
+
+/// Relaunching the standalone binary after crashes
+#[cfg(feature = "app")]
+mod supervisor {
+    use std::path::Path;
+    use std::process::Command;
+    use std::time::{Duration, Instant};
+    use tracing::{info, warn};
+    use crate::error::{BloomError, Result};
+    use super::super::warning::WarningResponse;
+
+    /// Set in every child the supervisor runs
+    const SUPERVISED_ENV: &str = "AETHERIUM_BLOOM_SUPERVISED";
+    /// Set in children relaunched after a crash, so they restore the autosave
+    const RESTORE_ENV: &str = "AETHERIUM_BLOOM_RESTORE";
+    /// The warning screen answer carried into a relaunch
+    const RESUME_ENV: &str = "AETHERIUM_BLOOM_RESUME";
+    /// Warning screen answer of this supervised session, in the crash directory
+    const SESSION_FILE: &str = "session";
+    /// A child that lived this long had a real run, not a crash loop
+    const STABLE_UPTIME: Duration = Duration::from_secs(60);
+    /// Crashes in a row, each before `STABLE_UPTIME`, before the supervisor gives up
+    const MAX_QUICK_CRASHES: u32 = 5;
+    const RELAUNCH_DELAY: Duration = Duration::from_secs(2);
+    /// Exit code of a supervised child that panicked; any other failure is not relaunched
+    pub const CRASH_EXIT_CODE: i32 = 70;
+
+    pub fn is_supervised() -> bool {
+        std::env::var_os(SUPERVISED_ENV).is_some()
+    }
+
+    /// In a supervised child, end the process with `CRASH_EXIT_CODE` after any panic,
+    /// on whichever thread, once the other panic hooks have run
+    pub fn exit_on_panic() {
+        let previous = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            previous(info);
+            std::process::exit(CRASH_EXIT_CODE);
+        }));
+    }
+
+    /// A child that ended with exit `code` crashed, or was killed by a signal when there is none
+    pub(super) fn is_crash(code: Option<i32>) -> bool {
+        code.is_none_or(|code| code == CRASH_EXIT_CODE)
+    }
+
+    /// This process was relaunched after a crash
+    pub fn relaunched() -> bool {
+        std::env::var_os(RESTORE_ENV).is_some()
+    }
+
+    /// How the warning screen was answered before the crash this process was relaunched after
+    pub fn resumed_response() -> Option<WarningResponse> {
+        match std::env::var(RESUME_ENV).ok()?.as_str() {
+            "continue" => Some(WarningResponse::Continue),
+            "safety-mode" => Some(WarningResponse::SafetyMode),
+            _ => None,
+        }
+    }
+
+    /// Remember the warning screen answer so relaunches in this session skip the screen
+    pub fn record_session(directory: &Path, response: &WarningResponse) {
+        let label = match response {
+            WarningResponse::Continue => "continue",
+            WarningResponse::SafetyMode => "safety-mode",
+            WarningResponse::Exit => return,
+        };
+        if let Err(e) = std::fs::write(directory.join(SESSION_FILE), label) {
+            warn!(target: "app", "🚑 Session could not be recorded, a relaunch will show the warning again: {}", e);
+        }
+    }
+
+    /// Run this executable as a child until it exits, relaunching it after crashes
+    pub fn supervise(directory: Option<&Path>) -> Result<()> {
+        super::super::standalone::init_logging();
+        let executable = std::env::current_exe().map_err(BloomError::Supervisor)?;
+        let arguments: Vec<_> = std::env::args_os().skip(1).collect();
+        // A new session answers the warning afresh
+        if let Some(directory) = directory {
+            let _ = std::fs::remove_file(directory.join(SESSION_FILE));
+        }
+        info!(target: "app", "🚑 Supervisor watching the organism - it will be relaunched if it crashes");
+
+        let mut crashes = 0;
+        let mut relaunch = false;
+        loop {
+            let mut command = Command::new(&executable);
+            command.args(&arguments).env(SUPERVISED_ENV, "1");
+            if relaunch {
+                command.env(RESTORE_ENV, "1");
+                let session = directory.and_then(|directory| std::fs::read_to_string(directory.join(SESSION_FILE)).ok());
+                if let Some(session) = session {
+                    command.env(RESUME_ENV, session.trim());
+                }
+            }
+
+            let started = Instant::now();
+            let status = command.status().map_err(BloomError::Supervisor)?;
+            if status.success() {
+                return Ok(());
+            }
+            if !is_crash(status.code()) {
+                return Err(BloomError::OrganismFailed { status: status.to_string() });
+            }
+            crashes = if started.elapsed() < STABLE_UPTIME { crashes + 1 } else { 1 };
+            if crashes >= MAX_QUICK_CRASHES {
+                return Err(BloomError::CrashLoop { crashes });
+            }
+            warn!(target: "app", "🚑 Organism exited with {} - relaunching from the last autosave", status);
+            std::thread::sleep(RELAUNCH_DELAY);
+            relaunch = true;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ecs::World;
+    use crate::core::events::EventBus;
+    use crate::engine::ObserverIntervention;
+
+    #[test]
+    fn test_black_box_keeps_recent_events_and_autosaves() {
+        let directory = std::env::temp_dir().join(format!("aetherium_crash_test_{}", std::process::id()));
+        let mut bus = EventBus::default();
+        let mut recorder = CrashRecorder::install(directory.clone(), "config".to_string(), bus.subscribe(), Duration::from_secs(10));
+        for _ in 0..CRASH_EVENTS + 5 {
+            bus.publish(ChaosEvent::ObserverIntervention(ObserverIntervention::Bless));
+        }
+
+        let snapshot = WorldSnapshot::capture(&World::new(), &[], 3.0);
+        recorder.record(3.0, Some(&snapshot));
+        assert_eq!(recorder.black_box.lock().events.len(), CRASH_EVENTS);
+        assert!(load_autosave(&directory).is_none(), "not due yet");
+        recorder.record(10.0, None);
+        assert_eq!(load_autosave(&directory).map(|restored| restored.time), Some(3.0));
+        assert_eq!(recorder.black_box.lock().tick, 2);
+
+        drop(recorder);
+        let _ = std::fs::remove_dir_all(&directory);
+    }
+
+    #[cfg(feature = "app")]
+    #[test]
+    fn test_supervisor_relaunches_crashes_but_not_errors() {
+        use super::supervisor::{is_crash, CRASH_EXIT_CODE};
+        assert!(is_crash(Some(CRASH_EXIT_CODE)));
+        assert!(is_crash(None), "killed by a signal");
+        assert!(!is_crash(Some(1)), "a config or GPU error returned from main");
+    }
+}
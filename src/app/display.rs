@@ -0,0 +1,75 @@
+// === DISPLAY MODES ===
+// How the primary window fills its monitor. Installations usually want a
+// borderless fullscreen window (fast to toggle, no mode switch) on a chosen
+// monitor; exclusive fullscreen switches the monitor to a given resolution
+// and falls back to borderless when the monitor offers nothing close.
+
+use tracing::{info, warn};
+use winit::monitor::{MonitorHandle, VideoModeHandle};
+use winit::window::Fullscreen;
+
+/// How the primary window is shown
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FullscreenMode {
+    #[default]
+    Windowed,
+    Borderless, // Fullscreen at the monitor's own resolution
+    Exclusive { width: u32, height: u32, refresh_hz: Option<u32> }, // Switch the monitor to the closest video mode
+}
+
+/// The winit fullscreen setting for `mode` on `monitor` (the current one when None)
+pub fn fullscreen_for(mode: FullscreenMode, monitor: Option<MonitorHandle>) -> Option<Fullscreen> {
+    match mode {
+        FullscreenMode::Windowed => None,
+        FullscreenMode::Borderless => Some(Fullscreen::Borderless(monitor)),
+        FullscreenMode::Exclusive { width, height, refresh_hz } => {
+            let modes: Vec<VideoModeHandle> = monitor.as_ref().map(|monitor| monitor.video_modes().collect()).unwrap_or_default();
+            let candidates: Vec<(u32, u32, u32)> = modes.iter()
+                .map(|mode| (mode.size().width, mode.size().height, mode.refresh_rate_millihertz()))
+                .collect();
+            match closest_video_mode(&candidates, (width, height), refresh_hz) {
+                Some(index) => {
+                    info!(target: "app", "🖥️ Exclusive fullscreen at {}", modes[index]);
+                    Some(Fullscreen::Exclusive(modes[index].clone()))
+                }
+                None => {
+                    warn!(target: "app", "🖥️ No video mode near {}x{} - using borderless fullscreen", width, height);
+                    Some(Fullscreen::Borderless(monitor))
+                }
+            }
+        }
+    }
+}
+
+/// Index of the `(width, height, millihertz)` mode nearest the wanted size, then refresh rate;
+/// None when nothing is within a quarter of the wanted pixel count
+fn closest_video_mode(modes: &[(u32, u32, u32)], size: (u32, u32), refresh_hz: Option<u32>) -> Option<usize> {
+    let wanted_pixels = size.0 as i64 * size.1 as i64;
+    modes.iter().enumerate()
+        .filter(|(_, &(width, height, _))| (width as i64 * height as i64 - wanted_pixels).abs() * 4 <= wanted_pixels)
+        .min_by_key(|(_, &(width, height, millihertz))| {
+            let size_miss = (width as i64 - size.0 as i64).abs() + (height as i64 - size.1 as i64).abs();
+            // Without a wanted refresh rate the fastest mode wins
+            let refresh_miss = match refresh_hz {
+                Some(hz) => (millihertz as i64 - hz as i64 * 1000).abs(),
+                None => -(millihertz as i64),
+            };
+            (size_miss, refresh_miss)
+        })
+        .map(|(index, _)| index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_closest_video_mode_prefers_size_then_refresh() {
+        let modes = [(1280, 720, 60_000), (1920, 1080, 60_000), (1920, 1080, 144_000), (2560, 1440, 60_000)];
+        assert_eq!(closest_video_mode(&modes, (1920, 1080), None), Some(2));
+        assert_eq!(closest_video_mode(&modes, (1920, 1080), Some(60)), Some(1));
+        assert_eq!(closest_video_mode(&modes, (1920, 1200), Some(60)), Some(1));
+        assert_eq!(closest_video_mode(&modes, (640, 480), None), None);
+        assert_eq!(closest_video_mode(&[], (1920, 1080), None), None);
+    }
+}
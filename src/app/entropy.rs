@@ -0,0 +1,282 @@
+// === REAL-WORLD ENTROPY ===
+// Optional feeds that let an installation's surroundings leak into the chaos
+// dimension of every llama's decision engine: the host's load, the jitter on
+// its network, the local weather and the static an RTL-SDR dongle hears. Each
+// feed is read on its own background thread at its own interval, and a feed
+// that fails warns once and keeps retrying quietly. Readings are scrambled
+// into 0-1 so only their changes matter, never their size.
+
+use std::io::{Read, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tracing::{info, warn};
+use crate::engine::ExternalEntropy;
+use crate::params::{ENTROPY_INTERVAL_SECONDS, ENTROPY_WEIGHT};
+
+/// Connections timed for one jitter reading
+const JITTER_PROBES: usize = 5;
+/// Longest a single network or radio reading may take
+const READ_TIMEOUT: Duration = Duration::from_secs(5);
+/// Samples captured from the dongle for one reading
+const SDR_SAMPLES: usize = 16_384;
+/// How often a sleeping feed checks whether it should stop
+const STOP_POLL: Duration = Duration::from_millis(250);
+
+/// Where a feed draws its entropy from
+#[derive(Debug, Clone, PartialEq)]
+pub enum EntropySource {
+    SystemLoad,                     // One-minute load average from /proc/loadavg
+    NetworkJitter { host: String }, // `host:port` whose TCP connect times are measured
+    Weather { url: String },        // Plain-http endpoint; every number in its reply counts
+    RtlSdr { frequency_hz: u32 },   // Raw samples from `rtl_sdr` tuned to this frequency
+}
+
+impl EntropySource {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::SystemLoad => "system load",
+            Self::NetworkJitter { .. } => "network jitter",
+            Self::Weather { .. } => "weather",
+            Self::RtlSdr { .. } => "RTL-SDR noise",
+        }
+    }
+
+    /// Take one reading, scrambled into 0-1
+    fn read(&self) -> std::io::Result<f32> {
+        match self {
+            Self::SystemLoad => {
+                let loadavg = std::fs::read_to_string("/proc/loadavg")?;
+                let load = loadavg.split_whitespace().next().and_then(|field| field.parse().ok()).ok_or_else(|| invalid("unreadable /proc/loadavg"))?;
+                Ok(scramble(load))
+            }
+            Self::NetworkJitter { host } => network_jitter(host).map(scramble),
+            Self::Weather { url } => http_get(url).map(|body| scramble(sum_numbers(&body))),
+            Self::RtlSdr { frequency_hz } => radio_noise(*frequency_hz),
+        }
+    }
+}
+
+/// One entropy source with how strongly and how often it is felt
+#[derive(Debug, Clone, PartialEq)]
+pub struct EntropyFeed {
+    pub source: EntropySource,
+    pub weight: f32,        // Share of the chaos dimension this feed replaces
+    pub interval: Duration, // Time between readings
+}
+
+impl EntropyFeed {
+    pub fn new(source: EntropySource) -> Self {
+        Self {
+            source,
+            weight: ENTROPY_WEIGHT.default,
+            interval: Duration::from_secs_f32(ENTROPY_INTERVAL_SECONDS.default),
+        }
+    }
+
+    pub fn with_weight(mut self, weight: f32) -> Self {
+        self.weight = ENTROPY_WEIGHT.clamp(weight);
+        self
+    }
+
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = Duration::from_secs_f32(ENTROPY_INTERVAL_SECONDS.clamp(interval.as_secs_f32()));
+        self
+    }
+
+    /// The feed with its weight and interval forced into range, for setters called while running
+    pub fn clamped(&self) -> Self {
+        self.clone().with_weight(self.weight).with_interval(self.interval)
+    }
+}
+
+/// The running feeds, each reading on its own thread until dropped
+pub(crate) struct EntropyFeeds {
+    feeds: Vec<EntropyFeed>,
+    readings: Vec<Arc<Mutex<Option<f32>>>>, // Latest reading per feed; None until the first succeeds
+    stop: Arc<AtomicBool>,
+}
+
+impl EntropyFeeds {
+    pub fn start(feeds: &[EntropyFeed]) -> Self {
+        let feeds: Vec<EntropyFeed> = feeds.iter().map(EntropyFeed::clamped).collect();
+        let stop = Arc::new(AtomicBool::new(false));
+        let readings = feeds.iter().map(|feed| {
+            let reading = Arc::new(Mutex::new(None));
+            let spawned = std::thread::Builder::new()
+                .name(format!("entropy: {}", feed.source.name()))
+                .spawn({
+                    let (feed, reading, stop) = (feed.clone(), reading.clone(), stop.clone());
+                    move || run_feed(feed, reading, stop)
+                });
+            match spawned {
+                Ok(_) => info!(target: "app", "🌦️ Entropy feed started: {} at weight {:.2}", feed.source.name(), feed.weight),
+                Err(e) => warn!(target: "app", "🌦️ Entropy feed {} not started: {}", feed.source.name(), e),
+            }
+            reading
+        }).collect();
+        Self { feeds, readings, stop }
+    }
+
+    pub fn feeds(&self) -> &[EntropyFeed] {
+        &self.feeds
+    }
+
+    /// The current blend of every feed that has a reading, or None when none has
+    pub fn sample(&self) -> Option<ExternalEntropy> {
+        let live: Vec<(f32, f32)> = self.feeds.iter().zip(&self.readings)
+            .filter_map(|(feed, reading)| reading.lock().ok().and_then(|reading| *reading).map(|value| (value, feed.weight)))
+            .collect();
+        blend(&live)
+    }
+}
+
+impl Drop for EntropyFeeds {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+    }
+}
+
+fn run_feed(feed: EntropyFeed, reading: Arc<Mutex<Option<f32>>>, stop: Arc<AtomicBool>) {
+    let mut warned = false;
+    while !stop.load(Ordering::Relaxed) {
+        match feed.source.read() {
+            Ok(value) => {
+                if let Ok(mut reading) = reading.lock() {
+                    *reading = Some(value);
+                }
+            }
+            Err(e) if !warned => {
+                warn!(target: "app", "🌦️ Entropy feed {} unavailable ({}) - retrying quietly", feed.source.name(), e);
+                warned = true;
+            }
+            Err(_) => {}
+        }
+        let next = Instant::now() + feed.interval;
+        while Instant::now() < next && !stop.load(Ordering::Relaxed) {
+            std::thread::sleep(STOP_POLL.min(next.saturating_duration_since(Instant::now())));
+        }
+    }
+}
+
+/// Weighted mean of `(value, weight)` readings; the strongest feed sets how far the blend reaches
+fn blend(readings: &[(f32, f32)]) -> Option<ExternalEntropy> {
+    let total: f32 = readings.iter().map(|&(_, weight)| weight).sum();
+    if total <= 0.0 {
+        return None;
+    }
+    Some(ExternalEntropy {
+        value: readings.iter().map(|&(value, weight)| value * weight).sum::<f32>() / total,
+        weight: readings.iter().map(|&(_, weight)| weight).fold(0.0, f32::max),
+    })
+}
+
+/// Any reading scrambled into 0-1, so the smallest change moves it anywhere in the range
+fn scramble(raw: f64) -> f32 {
+    ((raw * 12.9898).sin() * 43_758.545_3).fract().abs() as f32
+}
+
+fn invalid(message: &str) -> std::io::Error {
+    std::io::Error::new(std::io::ErrorKind::InvalidData, message.to_string())
+}
+
+/// Standard deviation of a few TCP connect times to `host`, in milliseconds
+fn network_jitter(host: &str) -> std::io::Result<f64> {
+    let address = host.to_socket_addrs()?.next().ok_or_else(|| invalid("host did not resolve"))?;
+    let mut latencies = Vec::with_capacity(JITTER_PROBES);
+    for _ in 0..JITTER_PROBES {
+        let start = Instant::now();
+        TcpStream::connect_timeout(&address, READ_TIMEOUT)?;
+        latencies.push(start.elapsed().as_secs_f64() * 1000.0);
+    }
+    let mean = latencies.iter().sum::<f64>() / latencies.len() as f64;
+    Ok((latencies.iter().map(|latency| (latency - mean).powi(2)).sum::<f64>() / latencies.len() as f64).sqrt())
+}
+
+/// Split `http://host[:port]/path` into its address and path
+fn parse_http_url(url: &str) -> std::io::Result<(String, String)> {
+    let rest = url.strip_prefix("http://").ok_or_else(|| invalid("only plain http:// weather URLs are supported"))?;
+    let (authority, path) = rest.split_once('/').map_or((rest, "/".to_string()), |(authority, path)| (authority, format!("/{}", path)));
+    let address = if authority.contains(':') { authority.to_string() } else { format!("{}:80", authority) };
+    Ok((address, path))
+}
+
+/// Body of a plain-http GET
+fn http_get(url: &str) -> std::io::Result<String> {
+    let (address, path) = parse_http_url(url)?;
+    let host = address.rsplit_once(':').map_or(address.as_str(), |(host, _)| host);
+    let socket = address.to_socket_addrs()?.next().ok_or_else(|| invalid("host did not resolve"))?;
+    let mut stream = TcpStream::connect_timeout(&socket, READ_TIMEOUT)?;
+    stream.set_read_timeout(Some(READ_TIMEOUT))?;
+    write!(stream, "GET {} HTTP/1.0\r\nHost: {}\r\nConnection: close\r\n\r\n", path, host)?;
+    let mut response = String::new();
+    stream.read_to_string(&mut response)?;
+    let (_, body) = response.split_once("\r\n\r\n").ok_or_else(|| invalid("malformed HTTP response"))?;
+    Ok(body.to_string())
+}
+
+/// Sum of every number in `text`, so any change in temperature, wind or pressure shows up
+fn sum_numbers(text: &str) -> f64 {
+    text.split(|c: char| !(c.is_ascii_digit() || c == '.' || c == '-'))
+        .filter_map(|token| token.parse::<f64>().ok())
+        .sum()
+}
+
+/// Low bits of a burst of raw dongle samples folded into 0-1; a dongle that hangs is killed after READ_TIMEOUT
+fn radio_noise(frequency_hz: u32) -> std::io::Result<f32> {
+    let mut child = std::process::Command::new("rtl_sdr")
+        .args(["-f", &frequency_hz.to_string(), "-n", &SDR_SAMPLES.to_string(), "-"])
+        .stdin(std::process::Stdio::null())
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::null())
+        .spawn()?;
+    // Drain the pipe on its own thread so the deadline holds however the dongle behaves
+    let mut stdout = child.stdout.take().ok_or_else(|| invalid("rtl_sdr has no output"))?;
+    let (sender, receiver) = std::sync::mpsc::channel();
+    std::thread::spawn(move || {
+        let mut samples = Vec::new();
+        let _ = sender.send(stdout.read_to_end(&mut samples).map(|_| samples));
+    });
+    let samples = match receiver.recv_timeout(READ_TIMEOUT) {
+        Ok(samples) => samples,
+        Err(_) => {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Err(std::io::Error::new(std::io::ErrorKind::TimedOut, "rtl_sdr did not finish in time"));
+        }
+    };
+    child.wait()?;
+    let samples = samples?;
+    if samples.is_empty() {
+        return Err(invalid("rtl_sdr returned no samples"));
+    }
+    let bits = samples.iter().enumerate().fold(0u32, |bits, (i, &byte)| bits ^ (u32::from(byte & 1) << (i % 32)));
+    Ok(bits as f32 / u32::MAX as f32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_readings_blend_and_parse() {
+        assert_eq!(blend(&[]), None);
+        let blended = blend(&[(0.2, 0.1), (0.8, 0.3)]).unwrap();
+        assert!((blended.value - 0.65).abs() < 1e-6 && blended.weight == 0.3);
+
+        for raw in [0.0, 0.42, 1.5, 17.0, -3.25, 1e6] {
+            assert!((0.0..1.0).contains(&scramble(raw)));
+        }
+        assert_ne!(scramble(0.42), scramble(0.43), "small changes still move the reading");
+
+        assert_eq!(sum_numbers(r#"{"temp": 12.5, "wind": 3, "dir": "NW", "dew": -1.5}"#), 14.0);
+        assert_eq!(parse_http_url("http://wttr.in/Oslo?format=j1").unwrap(), ("wttr.in:80".to_string(), "/Oslo?format=j1".to_string()));
+        assert_eq!(parse_http_url("http://10.0.0.2:8080").unwrap().0, "10.0.0.2:8080");
+        assert!(parse_http_url("https://api.example.com/weather").is_err());
+
+        let feed = EntropyFeed::new(EntropySource::SystemLoad).with_weight(2.0).with_interval(Duration::ZERO);
+        assert_eq!(feed.weight, ENTROPY_WEIGHT.max);
+        assert_eq!(feed.interval, Duration::from_secs_f32(ENTROPY_INTERVAL_SECONDS.min));
+    }
+}
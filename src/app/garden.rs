@@ -0,0 +1,290 @@
+// === GARDEN EDITOR ===
+// Turns the organism into a sandbox: the world holds still while the user
+// places and resizes territory zones, plants crystals and puts up walls and
+// pillars, then watches the llamas adapt once it runs again. Clicks use the
+// current tool, the wheel or the grow/shrink keys resize the pillar or zone
+// under the cursor, and the
+// save key writes the layout as RON. The organism loads that file at startup
+// as its starting environment when the garden layout path points at it.
+
+use std::path::Path;
+use glam::{Vec2, Vec3};
+use crate::core::ecs::World;
+use crate::error::{BloomError, Result};
+use crate::locale::Locale;
+use crate::reality::{text, Vertex};
+use crate::rendering::WORLD_SIZE;
+use crate::simulation::{ConsciousnessCrystal, CrystalType, DigitalEcosystem, GardenLayout, Obstacles, Pillar, TerritoryZone, Wall, ZoneType, MAX_ZONE_RADIUS, MIN_ZONE_RADIUS, WALL_THICKNESS};
+use super::keybindings::{Action, KeyBindings};
+
+/// Radius change per grow/shrink press or wheel notch
+const RESIZE_STEP: f32 = 10.0;
+/// Radius of a newly placed pillar
+const PILLAR_RADIUS: f32 = 25.0;
+/// How close the cursor must be to a crystal, wall or pillar to erase it
+const ERASE_REACH: f32 = 20.0;
+const CRYSTAL_TYPES: [CrystalType; 5] = [CrystalType::Resonance, CrystalType::Chaos, CrystalType::Memory, CrystalType::Social, CrystalType::Quantum];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GardenTool {
+    Zone,
+    Crystal,
+    Wall,
+    Pillar,
+    Erase,
+}
+
+impl GardenTool {
+    fn next(self) -> Self {
+        match self {
+            Self::Zone => Self::Crystal,
+            Self::Crystal => Self::Wall,
+            Self::Wall => Self::Pillar,
+            Self::Pillar => Self::Erase,
+            Self::Erase => Self::Zone,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Zone => "zone",
+            Self::Crystal => "crystal",
+            Self::Wall => "wall",
+            Self::Pillar => "pillar",
+            Self::Erase => "erase",
+        }
+    }
+}
+
+/// What a key press did in the garden editor
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GardenInput {
+    Adjusted, // Tool, kind or a zone's size changed
+    Save,     // Write the layout to the garden file
+    Ignored,  // Not a garden key; it keeps its usual binding
+}
+
+#[derive(Debug)]
+pub struct GardenEditor {
+    tool: GardenTool,
+    zone_type: usize,    // Index into ZoneType::ALL
+    crystal_type: usize, // Index into CRYSTAL_TYPES
+    wall_start: Option<Vec2>, // First corner of a wall being drawn
+}
+
+impl Default for GardenEditor {
+    fn default() -> Self {
+        Self { tool: GardenTool::Zone, zone_type: 0, crystal_type: 0, wall_start: None }
+    }
+}
+
+impl GardenEditor {
+    /// Switch tool or kind, resize the zone under `cursor`, or save
+    pub fn handle_action(&mut self, action: Action, cursor: Vec2, ecosystem: &mut DigitalEcosystem) -> GardenInput {
+        match action {
+            Action::GardenTool => {
+                self.tool = self.tool.next();
+                self.wall_start = None;
+            }
+            Action::GardenKind => match self.tool {
+                GardenTool::Crystal => self.crystal_type = (self.crystal_type + 1) % CRYSTAL_TYPES.len(),
+                _ => self.zone_type = (self.zone_type + 1) % ZoneType::ALL.len(),
+            },
+            Action::GardenGrow => self.resize(cursor, 1.0, ecosystem),
+            Action::GardenShrink => self.resize(cursor, -1.0, ecosystem),
+            Action::GardenSave => return GardenInput::Save,
+            _ => return GardenInput::Ignored,
+        }
+        GardenInput::Adjusted
+    }
+
+    /// Grow (positive `steps`) or shrink the pillar under `cursor`, or else the smallest zone under it
+    pub fn resize(&self, cursor: Vec2, steps: f32, ecosystem: &mut DigitalEcosystem) {
+        if let Some(pillar) = ecosystem.obstacles.pillars.iter_mut().find(|pillar| pillar.distance_to(cursor) < 0.0) {
+            *pillar = Pillar::new(pillar.center, pillar.radius + steps * RESIZE_STEP * 0.5);
+        } else if let Some(index) = zone_under(cursor, &ecosystem.territory_zones) {
+            let zone = &mut ecosystem.territory_zones[index];
+            zone.radius = (zone.radius + steps * RESIZE_STEP).clamp(MIN_ZONE_RADIUS, MAX_ZONE_RADIUS);
+        }
+    }
+
+    /// Use the current tool at `position`
+    pub fn click(&mut self, position: Vec2, world: &mut World, ecosystem: &mut DigitalEcosystem) {
+        match self.tool {
+            GardenTool::Zone => {
+                ecosystem.territory_zones.push(TerritoryZone::new(position, ZoneType::ALL[self.zone_type]));
+            }
+            GardenTool::Crystal => {
+                world.spawn(ConsciousnessCrystal::new(position, CRYSTAL_TYPES[self.crystal_type].clone()));
+            }
+            GardenTool::Wall => match self.wall_start.take() {
+                Some(start) if start.distance(position) > 1.0 => ecosystem.obstacles.walls.push(Wall::new(start, position)),
+                Some(_) => {}
+                None => self.wall_start = Some(position),
+            },
+            GardenTool::Pillar => ecosystem.obstacles.pillars.push(Pillar::new(position, PILLAR_RADIUS)),
+            GardenTool::Erase => erase(position, world, ecosystem),
+        }
+    }
+
+    /// Rims of the permanent zones, the wall being drawn and a line naming the tool and keys
+    pub fn push_overlay(&self, vertices: &mut Vec<Vertex>, cursor: Vec2, ecosystem: &DigitalEcosystem, locale: &Locale, keys: &KeyBindings) {
+        let rim = Vec3::new(0.35, 0.35, 0.45);
+        for zone in ecosystem.territory_zones.iter().filter(|zone| zone.lifespan.is_none()) {
+            let corners: Vec<Vec2> = (0..=24).map(|i| zone.center + Vec2::from_angle(i as f32 / 24.0 * std::f32::consts::TAU) * zone.radius).collect();
+            for pair in corners.windows(2) {
+                push_segment(vertices, pair[0], pair[1], 1.0, rim);
+            }
+        }
+        if let Some(start) = self.wall_start {
+            push_segment(vertices, start, cursor, WALL_THICKNESS * 0.5, Vec3::new(0.45, 0.45, 0.55));
+        }
+
+        let kind = match self.tool {
+            GardenTool::Crystal => format!("{:?}", CRYSTAL_TYPES[self.crystal_type]),
+            GardenTool::Zone => format!("{:?}", ZoneType::ALL[self.zone_type]),
+            _ => String::new(),
+        };
+        let tool = format!("garden-tool-{}", self.tool.name());
+        let line = locale.format("hud-garden", &[
+            ("tool", &locale.text(&tool)),
+            ("kind", &kind.to_uppercase()),
+            ("next", &keys.label(Action::GardenTool)),
+            ("cycle", &keys.label(Action::GardenKind)),
+            ("size", &format!("{}/{}", keys.label(Action::GardenShrink), keys.label(Action::GardenGrow))),
+            ("save", &keys.label(Action::GardenSave)),
+            ("done", &keys.label(Action::GardenEditor)),
+        ]);
+        text::push_text(vertices, &line, Vec2::new(20.0, 730.0), 2.0, Vec3::new(0.45, 0.4, 0.5), WORLD_SIZE);
+    }
+}
+
+/// Walls as dim bars and pillars as dim discs, drawn in every mode so llamas never
+/// bounce off something unseen
+pub fn push_obstacles(vertices: &mut Vec<Vertex>, obstacles: &Obstacles, intensity: f32) {
+    let color = Vec3::new(0.3, 0.32, 0.4) * intensity.clamp(0.0, 1.0);
+    for wall in &obstacles.walls {
+        push_segment(vertices, wall.start, wall.end, WALL_THICKNESS, color);
+    }
+    for pillar in &obstacles.pillars {
+        let rim = |i: usize| pillar.center + Vec2::from_angle(i as f32 / 16.0 * std::f32::consts::TAU) * pillar.radius;
+        for i in 0..16 {
+            push_triangle(vertices, [pillar.center, rim(i), rim(i + 1)], color);
+        }
+    }
+}
+
+/// Read a layout, keeping only what fits in the world
+pub fn load_layout(path: &Path) -> Result<GardenLayout> {
+    let fail = |reason: String| BloomError::GardenLayout { path: path.to_path_buf(), reason };
+    let source = std::fs::read_to_string(path).map_err(|e| fail(e.to_string()))?;
+    let layout: GardenLayout = ron::from_str(&source).map_err(|e| fail(e.to_string()))?;
+    Ok(layout.sanitized())
+}
+
+pub fn save_layout(layout: &GardenLayout, path: &Path) -> Result<()> {
+    let fail = |reason: String| BloomError::GardenLayout { path: path.to_path_buf(), reason };
+    let source = ron::ser::to_string_pretty(layout, ron::ser::PrettyConfig::default()).map_err(|e| fail(e.to_string()))?;
+    std::fs::write(path, source).map_err(|e| fail(e.to_string()))
+}
+
+/// The smallest zone containing `position`, so nested zones can be picked
+fn zone_under(position: Vec2, zones: &[TerritoryZone]) -> Option<usize> {
+    zones.iter().enumerate()
+        .filter(|(_, zone)| zone.center.distance(position) < zone.radius)
+        .min_by(|(_, a), (_, b)| a.radius.total_cmp(&b.radius))
+        .map(|(index, _)| index)
+}
+
+/// Remove the crystal, wall or pillar nearest `position`, or else the zone under it
+fn erase(position: Vec2, world: &mut World, ecosystem: &mut DigitalEcosystem) {
+    let crystal = world.query::<ConsciousnessCrystal>().into_iter()
+        .map(|(id, crystal)| (id, crystal.position.distance(position)))
+        .filter(|&(_, distance)| distance < ERASE_REACH)
+        .min_by(|a, b| a.1.total_cmp(&b.1));
+    if let Some((id, _)) = crystal {
+        world.despawn(id);
+        return;
+    }
+    let wall = ecosystem.obstacles.walls.iter().enumerate()
+        .map(|(index, wall)| (index, wall.distance_to(position)))
+        .filter(|&(_, distance)| distance < ERASE_REACH)
+        .min_by(|a, b| a.1.total_cmp(&b.1));
+    if let Some((index, _)) = wall {
+        ecosystem.obstacles.walls.remove(index);
+        return;
+    }
+    let pillar = ecosystem.obstacles.pillars.iter().enumerate()
+        .map(|(index, pillar)| (index, pillar.distance_to(position)))
+        .filter(|&(_, distance)| distance < ERASE_REACH)
+        .min_by(|a, b| a.1.total_cmp(&b.1));
+    if let Some((index, _)) = pillar {
+        ecosystem.obstacles.pillars.remove(index);
+        return;
+    }
+    if let Some(index) = zone_under(position, &ecosystem.territory_zones) {
+        ecosystem.territory_zones.remove(index);
+    }
+}
+
+/// A bar `half_width` either side of the line from `start` to `end`, in world units
+fn push_segment(vertices: &mut Vec<Vertex>, start: Vec2, end: Vec2, half_width: f32, color: Vec3) {
+    let side = (end - start).perp().normalize_or_zero() * half_width;
+    let (a, b, c, d) = (start + side, end + side, end - side, start - side);
+    push_triangle(vertices, [a, b, c], color);
+    push_triangle(vertices, [a, c, d], color);
+}
+
+fn push_triangle(vertices: &mut Vec<Vertex>, corners: [Vec2; 3], color: Vec3) {
+    let to_ndc = |p: Vec2| [p.x / WORLD_SIZE.x * 2.0 - 1.0, 1.0 - p.y / WORLD_SIZE.y * 2.0, 0.0];
+    vertices.extend(corners.map(|p| Vertex { position: to_ndc(p), color: color.into(), uv: [0.5, 0.5], species_id: 0.0, consciousness: 0.0, trip_intensity: 0.0 }));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tools_place_resize_and_erase() {
+        let mut world = World::new();
+        let mut ecosystem = DigitalEcosystem::new(&mut world);
+        ecosystem.territory_zones.clear();
+        world.retain::<ConsciousnessCrystal>(|_, _| false);
+        let mut editor = GardenEditor::default();
+        let spot = Vec2::new(600.0, 400.0);
+
+        editor.click(spot, &mut world, &mut ecosystem);
+        let radius = ecosystem.territory_zones[0].radius;
+        editor.handle_action(Action::GardenGrow, spot, &mut ecosystem);
+        assert_eq!(ecosystem.territory_zones[0].radius, (radius + RESIZE_STEP).min(MAX_ZONE_RADIUS));
+
+        editor.handle_action(Action::GardenTool, spot, &mut ecosystem);
+        editor.handle_action(Action::GardenKind, spot, &mut ecosystem);
+        editor.click(spot, &mut world, &mut ecosystem);
+        assert_eq!(world.components::<ConsciousnessCrystal>()[0].crystal_type, CrystalType::Chaos);
+
+        editor.handle_action(Action::GardenTool, spot, &mut ecosystem);
+        editor.click(Vec2::new(100.0, 100.0), &mut world, &mut ecosystem);
+        assert!(ecosystem.obstacles.walls.is_empty(), "a wall needs its second corner");
+        editor.click(Vec2::new(100.0, 300.0), &mut world, &mut ecosystem);
+        assert_eq!(ecosystem.obstacles.walls, [Wall::new(Vec2::new(100.0, 100.0), Vec2::new(100.0, 300.0))]);
+        assert_eq!(editor.handle_action(Action::CycleTheme, spot, &mut ecosystem), GardenInput::Ignored);
+
+        // Resizing prefers the pillar under the cursor over the zone around it
+        editor.handle_action(Action::GardenTool, spot, &mut ecosystem);
+        editor.click(Vec2::new(900.0, 600.0), &mut world, &mut ecosystem);
+        editor.resize(Vec2::new(905.0, 600.0), 2.0, &mut ecosystem);
+        assert_eq!(ecosystem.obstacles.pillars[0].radius, PILLAR_RADIUS + RESIZE_STEP);
+
+        // Erasing takes the crystal first, then the zone under it
+        editor.handle_action(Action::GardenTool, spot, &mut ecosystem);
+        editor.click(spot, &mut world, &mut ecosystem);
+        assert_eq!(world.count::<ConsciousnessCrystal>(), 0);
+        assert_eq!(ecosystem.territory_zones.len(), 1);
+        editor.click(spot, &mut world, &mut ecosystem);
+        assert!(ecosystem.territory_zones.is_empty());
+        editor.click(Vec2::new(105.0, 200.0), &mut world, &mut ecosystem);
+        editor.click(Vec2::new(900.0, 600.0), &mut world, &mut ecosystem);
+        assert!(ecosystem.obstacles.is_empty());
+    }
+}
@@ -0,0 +1,113 @@
+// === IDLE MODE ===
+// With nobody interacting, the organism fades into a low-power ambient state:
+// slower llamas, quieter audio and a low frame-rate cap. Any input wakes it
+// at once; only falling asleep is gradual. Attract mode holds it awake while
+// the director puts on a show.
+
+use std::time::{Duration, Instant};
+
+/// Time without input before the organism starts to doze
+pub const DEFAULT_IDLE_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+/// Frame pacing while fully idle (15 fps)
+pub const IDLE_FRAME_INTERVAL: Duration = Duration::from_micros(66_667);
+/// Seconds to fade from awake to fully idle
+const FADE_SECONDS: f32 = 3.0;
+/// Share of llama movement kept while fully idle
+const IDLE_ACTIVITY: f32 = 0.4;
+/// Share of audio volume kept while fully idle
+const IDLE_AUDIO_GAIN: f32 = 0.3;
+
+pub struct IdleMonitor {
+    timeout: Option<Duration>, // None never dozes
+    last_input: Instant,
+    last_update: Instant,
+    level: f32, // 0 = awake, 1 = fully idle
+    held: bool, // Kept awake without counting as input
+}
+
+impl IdleMonitor {
+    pub fn new(timeout: Option<Duration>, now: Instant) -> Self {
+        Self { timeout, last_input: now, last_update: now, level: 0.0, held: false }
+    }
+
+    /// User input: wake up immediately
+    pub fn record_input(&mut self, now: Instant) {
+        self.last_input = now;
+        self.level = 0.0;
+    }
+
+    /// Time since the last user input
+    pub fn idle_for(&self, now: Instant) -> Duration {
+        now.saturating_duration_since(self.last_input)
+    }
+
+    /// Stay awake regardless of input, e.g. while attract mode runs
+    pub fn hold(&mut self, held: bool) {
+        self.held = held;
+        if held {
+            self.level = 0.0;
+        }
+    }
+
+    /// Advance the fade; call once per frame
+    pub fn update(&mut self, now: Instant) {
+        // Only time spent past the timeout counts towards the fade
+        let fade_start = self.timeout.filter(|_| !self.held).and_then(|timeout| self.last_input.checked_add(timeout));
+        if let Some(fade_start) = fade_start.filter(|&start| now > start) {
+            let dt = now.duration_since(self.last_update.max(fade_start)).as_secs_f32();
+            self.level = (self.level + dt / FADE_SECONDS).min(1.0);
+        }
+        self.last_update = now;
+    }
+
+    pub fn is_idle(&self) -> bool {
+        self.level > 0.0
+    }
+
+    /// Multiplier for llama movement, 1 while awake
+    pub fn activity(&self) -> f32 {
+        1.0 - (1.0 - IDLE_ACTIVITY) * self.level
+    }
+
+    /// Multiplier for audio output, 1 while awake
+    pub fn audio_gain(&self) -> f32 {
+        1.0 - (1.0 - IDLE_AUDIO_GAIN) * self.level
+    }
+
+    /// Minimum time between frames, or `None` to render as fast as the display allows
+    pub fn frame_interval(&self) -> Option<Duration> {
+        self.is_idle().then_some(IDLE_FRAME_INTERVAL)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dozes_after_timeout_and_wakes_on_input() {
+        let start = Instant::now();
+        let mut idle = IdleMonitor::new(Some(Duration::from_secs(60)), start);
+
+        idle.update(start + Duration::from_secs(59));
+        assert!(!idle.is_idle());
+        assert_eq!(idle.frame_interval(), None);
+
+        // The fade only counts time spent past the timeout
+        idle.update(start + Duration::from_secs(60));
+        idle.update(start + Duration::from_secs_f32(60.0 + FADE_SECONDS * 0.5));
+        assert!((idle.level - 0.5).abs() < 0.01, "level {}", idle.level);
+        idle.update(start + Duration::from_secs(120));
+        assert_eq!(idle.level, 1.0);
+        assert!((idle.activity() - IDLE_ACTIVITY).abs() < 1e-6);
+        assert_eq!(idle.frame_interval(), Some(IDLE_FRAME_INTERVAL));
+
+        idle.record_input(start + Duration::from_secs(121));
+        assert_eq!(idle.level, 0.0);
+        assert_eq!(idle.audio_gain(), 1.0);
+
+        let mut never = IdleMonitor::new(None, start);
+        never.update(start + Duration::from_secs(3600));
+        assert!(!never.is_idle());
+    }
+}
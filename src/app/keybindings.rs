@@ -0,0 +1,703 @@
+// === KEY BINDINGS ===
+// Every hotkey is an Action looked up in a KeyBindings map rather than a
+// character matched in place, so any of them can be moved. Actions live in a
+// context: camera keys only apply in photo mode, steering keys only while
+// possessing, scrub keys only while rewinding, garden keys only while editing
+// the garden, and those contexts are checked
+// before the global one. Two actions sharing a key in one context is a
+// conflict; the map reports them and the editor overlay (F12) shows them while
+// rebinding. Escape is always the emergency stop, whatever else it is bound to.
+// Bindings load from and save to plain `action = key key` files.
+
+use std::path::{Path, PathBuf};
+use glam::{Vec2, Vec3};
+use winit::keyboard::{Key, NamedKey};
+use crate::audio::MixBus;
+use crate::engine::ObserverIntervention;
+use crate::error::{BloomError, Result};
+use crate::locale::Locale;
+use crate::reality::{text, Vertex};
+
+/// Where a binding applies; modal contexts are checked before the global one
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyContext {
+    Global,
+    Photo,      // Photo mode camera
+    Possession, // Steering a possessed llama, tracked while held
+    History,    // Scrubbing through recorded history
+    Garden,     // Designing zones, crystals and walls
+}
+
+impl KeyContext {
+    pub const ALL: [KeyContext; 5] = [Self::Global, Self::Photo, Self::Possession, Self::History, Self::Garden];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Global => "global",
+            Self::Photo => "photo",
+            Self::Possession => "possession",
+            Self::History => "history",
+            Self::Garden => "garden",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    // Safety
+    EmergencyStop,
+    // Audio
+    AudioMellow,
+    AudioActive,
+    AudioChaotic,
+    VolumeUp,
+    VolumeDown,
+    VolumeUpFine,
+    VolumeDownFine,
+    SpeedUp,
+    SpeedDown,
+    SpeedPreset(u8), // 1-9
+    ToggleAudio,
+    CycleOutputDevice,
+    CycleScale,
+    ToggleDucking,
+    AvCalibration,
+    AvOffsetEarlier,
+    AvOffsetLater,
+    MuteBus(MixBus),
+    // Modes
+    Intervene(ObserverIntervention),
+    ObserverAutonomy,
+    PhotoMode,
+    ToggleFullscreen, // Handled by the window shell
+    KeyBindingsEditor,
+    HistoryScrubbing,
+    GardenEditor,
+    Possess,
+    Harvest,
+    QuantumTunnel,
+    BassDrop,
+    Homeostasis,
+    CycleTheme,
+    AdaptationWeaker,
+    AdaptationStronger,
+    SkipTutorial,
+    // Debug toggles
+    WarfareOverlay,
+    PheromoneOverlay,
+    ForceOverlay,
+    FieldOverlay,
+    LexiconOverlay,
+    AdaptationInspector,
+    ChronicleInspector,
+    SoundOverlay,
+    ProfilerOverlay,
+    ChaosTuning,
+    ModMatrix,
+    ExportGraph,
+    ShowControls,
+    // Photo mode camera
+    PanUp,
+    PanDown,
+    PanLeft,
+    PanRight,
+    ZoomIn,
+    ZoomOut,
+    ExposureDown,
+    ExposureUp,
+    PaletteBack,
+    PaletteForward,
+    ResetCamera,
+    SavePhoto,
+    // Possession steering
+    SteerUp,
+    SteerDown,
+    SteerLeft,
+    SteerRight,
+    // History scrubbing
+    StepBack,
+    StepForward,
+    MinuteBack,
+    MinuteForward,
+    Oldest,
+    Newest,
+    TimeLapse,
+    Branch,
+    // Garden editor
+    GardenTool,
+    GardenKind,
+    GardenGrow,
+    GardenShrink,
+    GardenSave,
+}
+
+/// Bindings file the standalone binary uses, kept in the home directory
+const DEFAULT_FILE_NAME: &str = ".aetherium_bloom_keys";
+
+/// Audio speed each of the nine speed presets sets
+pub const SPEED_PRESETS: [f32; 9] = [0.2, 0.4, 0.6, 0.8, 1.0, 1.2, 1.5, 2.0, 3.0];
+
+impl Action {
+    pub const ALL: &'static [Action] = &[
+        Self::EmergencyStop,
+        Self::AudioMellow, Self::AudioActive, Self::AudioChaotic,
+        Self::VolumeUp, Self::VolumeDown, Self::VolumeUpFine, Self::VolumeDownFine, Self::SpeedUp, Self::SpeedDown,
+        Self::SpeedPreset(1), Self::SpeedPreset(2), Self::SpeedPreset(3), Self::SpeedPreset(4), Self::SpeedPreset(5),
+        Self::SpeedPreset(6), Self::SpeedPreset(7), Self::SpeedPreset(8), Self::SpeedPreset(9),
+        Self::ToggleAudio, Self::CycleOutputDevice, Self::CycleScale, Self::ToggleDucking,
+        Self::AvCalibration, Self::AvOffsetEarlier, Self::AvOffsetLater,
+        Self::MuteBus(MixBus::LlamaSynth), Self::MuteBus(MixBus::Ambient), Self::MuteBus(MixBus::ChaosSfx), Self::MuteBus(MixBus::HiveHarmonics),
+        Self::Intervene(ObserverIntervention::Bless), Self::Intervene(ObserverIntervention::ForcePeace),
+        Self::Intervene(ObserverIntervention::Scramble), Self::Intervene(ObserverIntervention::Redistribute),
+        Self::ObserverAutonomy, Self::PhotoMode, Self::ToggleFullscreen, Self::KeyBindingsEditor, Self::HistoryScrubbing,
+        Self::GardenEditor, Self::Possess, Self::Harvest, Self::QuantumTunnel, Self::BassDrop,
+        Self::Homeostasis, Self::CycleTheme, Self::AdaptationWeaker, Self::AdaptationStronger, Self::SkipTutorial,
+        Self::WarfareOverlay, Self::PheromoneOverlay, Self::ForceOverlay, Self::FieldOverlay, Self::LexiconOverlay, Self::AdaptationInspector, Self::ChronicleInspector,
+        Self::SoundOverlay, Self::ProfilerOverlay, Self::ChaosTuning, Self::ModMatrix, Self::ExportGraph, Self::ShowControls,
+        Self::PanUp, Self::PanDown, Self::PanLeft, Self::PanRight, Self::ZoomIn, Self::ZoomOut,
+        Self::ExposureDown, Self::ExposureUp, Self::PaletteBack, Self::PaletteForward, Self::ResetCamera, Self::SavePhoto,
+        Self::SteerUp, Self::SteerDown, Self::SteerLeft, Self::SteerRight,
+        Self::StepBack, Self::StepForward, Self::MinuteBack, Self::MinuteForward, Self::Oldest, Self::Newest, Self::TimeLapse, Self::Branch,
+        Self::GardenTool, Self::GardenKind, Self::GardenGrow, Self::GardenShrink, Self::GardenSave,
+    ];
+
+    /// Name in binding files, and `action-<name>` is its label in the language bundles
+    pub fn name(self) -> &'static str {
+        const PRESET_NAMES: [&str; 9] = ["speed-preset-1", "speed-preset-2", "speed-preset-3", "speed-preset-4", "speed-preset-5",
+                                         "speed-preset-6", "speed-preset-7", "speed-preset-8", "speed-preset-9"];
+        match self {
+            Self::EmergencyStop => "emergency-stop",
+            Self::AudioMellow => "audio-mellow",
+            Self::AudioActive => "audio-active",
+            Self::AudioChaotic => "audio-chaotic",
+            Self::VolumeUp => "volume-up",
+            Self::VolumeDown => "volume-down",
+            Self::VolumeUpFine => "volume-up-fine",
+            Self::VolumeDownFine => "volume-down-fine",
+            Self::SpeedUp => "speed-up",
+            Self::SpeedDown => "speed-down",
+            Self::SpeedPreset(preset) => PRESET_NAMES[(preset.clamp(1, 9) - 1) as usize],
+            Self::ToggleAudio => "toggle-audio",
+            Self::CycleOutputDevice => "cycle-output-device",
+            Self::CycleScale => "cycle-scale",
+            Self::ToggleDucking => "toggle-ducking",
+            Self::AvCalibration => "av-calibration",
+            Self::AvOffsetEarlier => "av-offset-earlier",
+            Self::AvOffsetLater => "av-offset-later",
+            Self::MuteBus(MixBus::LlamaSynth) => "mute-llama-synth",
+            Self::MuteBus(MixBus::Ambient) => "mute-ambient",
+            Self::MuteBus(MixBus::ChaosSfx) => "mute-chaos-sfx",
+            Self::MuteBus(MixBus::HiveHarmonics) => "mute-hive-harmonics",
+            Self::Intervene(ObserverIntervention::Bless) => "observer-bless",
+            Self::Intervene(ObserverIntervention::ForcePeace) => "observer-force-peace",
+            Self::Intervene(ObserverIntervention::Scramble) => "observer-scramble",
+            Self::Intervene(ObserverIntervention::Redistribute) => "observer-redistribute",
+            Self::ObserverAutonomy => "observer-autonomy",
+            Self::PhotoMode => "photo-mode",
+            Self::ToggleFullscreen => "toggle-fullscreen",
+            Self::KeyBindingsEditor => "key-bindings-editor",
+            Self::HistoryScrubbing => "history-scrubbing",
+            Self::GardenEditor => "garden-editor",
+            Self::Possess => "possess",
+            Self::Harvest => "harvest",
+            Self::QuantumTunnel => "quantum-tunnel",
+            Self::BassDrop => "bass-drop",
+            Self::Homeostasis => "homeostasis",
+            Self::CycleTheme => "cycle-theme",
+            Self::AdaptationWeaker => "adaptation-weaker",
+            Self::AdaptationStronger => "adaptation-stronger",
+            Self::SkipTutorial => "skip-tutorial",
+            Self::WarfareOverlay => "warfare-overlay",
+            Self::PheromoneOverlay => "pheromone-overlay",
+            Self::ForceOverlay => "force-overlay",
+            Self::FieldOverlay => "field-overlay",
+            Self::LexiconOverlay => "lexicon-overlay",
+            Self::AdaptationInspector => "adaptation-inspector",
+            Self::ChronicleInspector => "chronicle-inspector",
+            Self::SoundOverlay => "sound-overlay",
+            Self::ProfilerOverlay => "profiler-overlay",
+            Self::ChaosTuning => "chaos-tuning",
+            Self::ModMatrix => "mod-matrix",
+            Self::ExportGraph => "export-graph",
+            Self::ShowControls => "show-controls",
+            Self::PanUp => "pan-up",
+            Self::PanDown => "pan-down",
+            Self::PanLeft => "pan-left",
+            Self::PanRight => "pan-right",
+            Self::ZoomIn => "zoom-in",
+            Self::ZoomOut => "zoom-out",
+            Self::ExposureDown => "exposure-down",
+            Self::ExposureUp => "exposure-up",
+            Self::PaletteBack => "palette-back",
+            Self::PaletteForward => "palette-forward",
+            Self::ResetCamera => "reset-camera",
+            Self::SavePhoto => "save-photo",
+            Self::SteerUp => "steer-up",
+            Self::SteerDown => "steer-down",
+            Self::SteerLeft => "steer-left",
+            Self::SteerRight => "steer-right",
+            Self::StepBack => "step-back",
+            Self::StepForward => "step-forward",
+            Self::MinuteBack => "minute-back",
+            Self::MinuteForward => "minute-forward",
+            Self::Oldest => "oldest",
+            Self::Newest => "newest",
+            Self::TimeLapse => "time-lapse",
+            Self::Branch => "branch",
+            Self::GardenTool => "garden-tool",
+            Self::GardenKind => "garden-kind",
+            Self::GardenGrow => "garden-grow",
+            Self::GardenShrink => "garden-shrink",
+            Self::GardenSave => "garden-save",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|action| action.name() == name)
+    }
+
+    pub fn context(self) -> KeyContext {
+        match self {
+            Self::PanUp | Self::PanDown | Self::PanLeft | Self::PanRight | Self::ZoomIn | Self::ZoomOut
+            | Self::ExposureDown | Self::ExposureUp | Self::PaletteBack | Self::PaletteForward
+            | Self::ResetCamera | Self::SavePhoto => KeyContext::Photo,
+            Self::SteerUp | Self::SteerDown | Self::SteerLeft | Self::SteerRight => KeyContext::Possession,
+            Self::StepBack | Self::StepForward | Self::MinuteBack | Self::MinuteForward
+            | Self::Oldest | Self::Newest | Self::TimeLapse | Self::Branch => KeyContext::History,
+            Self::GardenTool | Self::GardenKind | Self::GardenGrow | Self::GardenShrink | Self::GardenSave => KeyContext::Garden,
+            _ => KeyContext::Global,
+        }
+    }
+
+    fn default_keys(self) -> Vec<BoundKey> {
+        use BoundKey::{Char, Named};
+        match self {
+            Self::EmergencyStop => vec![Named(NamedKey::Escape)],
+            Self::AudioMellow => vec![Char('m')],
+            Self::AudioActive => vec![Char('a')],
+            Self::AudioChaotic => vec![Char('c')],
+            Self::VolumeUp => vec![Char('+'), Char('=')],
+            Self::VolumeDown => vec![Char('-'), Char('_')],
+            Self::VolumeUpFine => vec![Named(NamedKey::ArrowRight)],
+            Self::VolumeDownFine => vec![Named(NamedKey::ArrowLeft)],
+            Self::SpeedUp => vec![Named(NamedKey::ArrowUp)],
+            Self::SpeedDown => vec![Named(NamedKey::ArrowDown)],
+            Self::SpeedPreset(preset) => vec![Char(char::from(b'0' + preset.clamp(1, 9)))],
+            Self::ToggleAudio => vec![Named(NamedKey::Space)],
+            Self::CycleOutputDevice => vec![Char('o')],
+            Self::CycleScale => vec![Char('k')],
+            Self::ToggleDucking => vec![Char('d')],
+            Self::AvCalibration => vec![Char('l')],
+            Self::AvOffsetEarlier => vec![Char('[')],
+            Self::AvOffsetLater => vec![Char(']')],
+            Self::MuteBus(MixBus::LlamaSynth) => vec![Named(NamedKey::F1)],
+            Self::MuteBus(MixBus::Ambient) => vec![Named(NamedKey::F2)],
+            Self::MuteBus(MixBus::ChaosSfx) => vec![Named(NamedKey::F3)],
+            Self::MuteBus(MixBus::HiveHarmonics) => vec![Named(NamedKey::F4)],
+            Self::Intervene(ObserverIntervention::Bless) => vec![Named(NamedKey::F5)],
+            Self::Intervene(ObserverIntervention::ForcePeace) => vec![Named(NamedKey::F6)],
+            Self::Intervene(ObserverIntervention::Scramble) => vec![Named(NamedKey::F7)],
+            Self::Intervene(ObserverIntervention::Redistribute) => vec![Named(NamedKey::F8)],
+            Self::ObserverAutonomy => vec![Named(NamedKey::F9)],
+            Self::PhotoMode => vec![Named(NamedKey::F10)],
+            Self::ToggleFullscreen => vec![Named(NamedKey::F11)],
+            Self::KeyBindingsEditor => vec![Named(NamedKey::F12)],
+            Self::HistoryScrubbing => vec![Char('t')],
+            Self::GardenEditor => vec![Char('z')],
+            Self::Possess => vec![Char('p')],
+            Self::Harvest => vec![Char('e')],
+            Self::QuantumTunnel => vec![Char('q')],
+            Self::BassDrop => vec![Char('b')],
+            Self::Homeostasis => vec![Char('y')],
+            Self::CycleTheme => vec![Char('v')],
+            Self::AdaptationWeaker => vec![Char(',')],
+            Self::AdaptationStronger => vec![Char('.')],
+            Self::SkipTutorial => vec![Named(NamedKey::Tab)],
+            Self::WarfareOverlay => vec![Char('w')],
+            Self::PheromoneOverlay => vec![Char('r')],
+            Self::ForceOverlay => vec![Char('x')],
+            Self::FieldOverlay => vec![Char('\'')],
+            Self::LexiconOverlay => vec![Char('g')],
+            Self::AdaptationInspector => vec![Char('i')],
+            Self::ChronicleInspector => vec![Char('n')],
+            Self::SoundOverlay => vec![Char('u')],
+            Self::ProfilerOverlay => vec![Char('f')],
+            Self::ChaosTuning => vec![Char('s')],
+            Self::ModMatrix => vec![Char(';')],
+            Self::ExportGraph => vec![Char('j')],
+            Self::ShowControls => vec![Char('h'), Char('?')],
+            Self::PanUp => vec![Char('w'), Named(NamedKey::ArrowUp)],
+            Self::PanDown => vec![Char('s'), Named(NamedKey::ArrowDown)],
+            Self::PanLeft => vec![Char('a'), Named(NamedKey::ArrowLeft)],
+            Self::PanRight => vec![Char('d'), Named(NamedKey::ArrowRight)],
+            Self::ZoomIn => vec![Char('z')],
+            Self::ZoomOut => vec![Char('x')],
+            Self::ExposureDown => vec![Char('[')],
+            Self::ExposureUp => vec![Char(']')],
+            Self::PaletteBack => vec![Char(',')],
+            Self::PaletteForward => vec![Char('.')],
+            Self::ResetCamera => vec![Char('r')],
+            Self::SavePhoto => vec![Named(NamedKey::Enter)],
+            Self::SteerUp => vec![Char('w'), Named(NamedKey::ArrowUp)],
+            Self::SteerDown => vec![Char('s'), Named(NamedKey::ArrowDown)],
+            Self::SteerLeft => vec![Char('a'), Named(NamedKey::ArrowLeft)],
+            Self::SteerRight => vec![Char('d'), Named(NamedKey::ArrowRight)],
+            Self::StepBack => vec![Named(NamedKey::ArrowLeft)],
+            Self::StepForward => vec![Named(NamedKey::ArrowRight)],
+            Self::MinuteBack => vec![Named(NamedKey::ArrowDown)],
+            Self::MinuteForward => vec![Named(NamedKey::ArrowUp)],
+            Self::Oldest => vec![Named(NamedKey::Home)],
+            Self::Newest => vec![Named(NamedKey::End)],
+            Self::TimeLapse => vec![Named(NamedKey::Space)],
+            Self::Branch => vec![Named(NamedKey::Enter)],
+            Self::GardenTool => vec![Named(NamedKey::Tab)],
+            Self::GardenKind => vec![Char('c')],
+            Self::GardenGrow => vec![Char(']')],
+            Self::GardenShrink => vec![Char('[')],
+            Self::GardenSave => vec![Named(NamedKey::Enter)],
+        }
+    }
+
+    fn index(self) -> usize {
+        Self::ALL.iter().position(|&action| action == self).unwrap_or(0)
+    }
+}
+
+/// Named keys that can be bound, with their names in binding files
+const NAMED_KEYS: [(NamedKey, &str); 27] = [
+    (NamedKey::Escape, "Escape"), (NamedKey::Tab, "Tab"), (NamedKey::Enter, "Enter"), (NamedKey::Space, "Space"),
+    (NamedKey::Backspace, "Backspace"), (NamedKey::Delete, "Delete"), (NamedKey::Insert, "Insert"),
+    (NamedKey::Home, "Home"), (NamedKey::End, "End"), (NamedKey::PageUp, "PageUp"), (NamedKey::PageDown, "PageDown"),
+    (NamedKey::ArrowUp, "Up"), (NamedKey::ArrowDown, "Down"), (NamedKey::ArrowLeft, "Left"), (NamedKey::ArrowRight, "Right"),
+    (NamedKey::F1, "F1"), (NamedKey::F2, "F2"), (NamedKey::F3, "F3"), (NamedKey::F4, "F4"), (NamedKey::F5, "F5"),
+    (NamedKey::F6, "F6"), (NamedKey::F7, "F7"), (NamedKey::F8, "F8"), (NamedKey::F9, "F9"), (NamedKey::F10, "F10"),
+    (NamedKey::F11, "F11"), (NamedKey::F12, "F12"),
+];
+
+/// Characters the bitmap font cannot draw, shown by name instead
+const CHAR_NAMES: [(char, &str); 4] = [('+', "PLUS"), ('=', "EQUALS"), ('_', "UNDERSCORE"), (';', "SEMICOLON")];
+
+/// A key as bindings see it: characters are case-insensitive
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BoundKey {
+    Char(char),
+    Named(NamedKey),
+}
+
+impl BoundKey {
+    pub fn from_key(key: &Key) -> Option<Self> {
+        match key {
+            Key::Character(c) => match c.chars().next()? {
+                ' ' => Some(Self::Named(NamedKey::Space)),
+                c => Some(Self::Char(c.to_ascii_lowercase())),
+            },
+            Key::Named(named) => NAMED_KEYS.iter().any(|(key, _)| key == named).then_some(Self::Named(*named)),
+            _ => None,
+        }
+    }
+
+    /// A key name from a binding file: one character, or a name such as `Space`, `Up` or `F5`
+    pub fn parse(name: &str) -> Option<Self> {
+        let mut chars = name.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Self::from_key(&Key::Character(c.to_string().into())),
+            _ => NAMED_KEYS.iter().find(|(_, key_name)| key_name.eq_ignore_ascii_case(name)).map(|(key, _)| Self::Named(*key)),
+        }
+    }
+
+    pub fn name(self) -> String {
+        match self {
+            Self::Char(c) => CHAR_NAMES.iter().find(|(named, _)| *named == c).map_or_else(|| c.to_ascii_uppercase().to_string(), |(_, name)| name.to_string()),
+            Self::Named(named) => NAMED_KEYS.iter().find(|(key, _)| *key == named).map_or("?", |(_, name)| name).to_string(),
+        }
+    }
+}
+
+/// Two actions in one context sharing a key; the earlier action wins the key
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct KeyConflict {
+    pub key: BoundKey,
+    pub winner: Action,
+    pub shadowed: Action,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyBindings {
+    keys: Vec<Vec<BoundKey>>, // Indexed like Action::ALL
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        Self { keys: Action::ALL.iter().map(|action| action.default_keys()).collect() }
+    }
+}
+
+impl KeyBindings {
+    /// Defaults overridden by the actions a binding file lists
+    pub fn parse(source: &str) -> Result<Self> {
+        let mut bindings = Self::default();
+        for (index, line) in source.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let bad_line = |reason: String| BloomError::KeyBindings { line: index + 1, reason };
+            let (name, keys) = line.split_once('=').ok_or_else(|| bad_line("expected `action = key key`".to_string()))?;
+            let action = Action::from_name(name.trim()).ok_or_else(|| bad_line(format!("unknown action '{}'", name.trim())))?;
+            let keys = keys.split_whitespace()
+                .map(|key| BoundKey::parse(key).ok_or_else(|| bad_line(format!("unknown key '{}'", key))))
+                .collect::<Result<Vec<_>>>()?;
+            bindings.set(action, keys);
+        }
+        Ok(bindings)
+    }
+
+    /// `~/.aetherium_bloom_keys`
+    pub fn default_file() -> PathBuf {
+        super::home_dir().join(DEFAULT_FILE_NAME)
+    }
+
+    /// Defaults when the file does not exist yet
+    pub fn load(path: &Path) -> Result<Self> {
+        match std::fs::read_to_string(path) {
+            Ok(source) => Self::parse(&source),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(source) => Err(BloomError::KeyBindingsFile { path: path.to_path_buf(), source }),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        std::fs::write(path, self.to_file_string()).map_err(|source| BloomError::KeyBindingsFile { path: path.to_path_buf(), source })
+    }
+
+    /// Every action with its keys, in the format `parse` reads
+    pub fn to_file_string(&self) -> String {
+        let mut out = String::from("# AetheriumBloom key bindings: action = keys separated by spaces\n# Escape always triggers the emergency stop as well\n");
+        for (action, keys) in Action::ALL.iter().zip(&self.keys) {
+            let keys: Vec<String> = keys.iter().map(|key| match key {
+                BoundKey::Char(c) => c.to_string(),
+                named => named.name(),
+            }).collect();
+            out.push_str(&format!("{} = {}\n", action.name(), keys.join(" ")));
+        }
+        out
+    }
+
+    pub fn keys(&self, action: Action) -> &[BoundKey] {
+        &self.keys[action.index()]
+    }
+
+    pub fn set(&mut self, action: Action, keys: Vec<BoundKey>) {
+        self.keys[action.index()] = keys;
+    }
+
+    pub fn reset(&mut self, action: Action) {
+        self.set(action, action.default_keys());
+    }
+
+    /// The action `key` triggers in `context`, if any
+    pub fn action(&self, context: KeyContext, key: BoundKey) -> Option<Action> {
+        if key == BoundKey::Named(NamedKey::Escape) {
+            return Some(Action::EmergencyStop);
+        }
+        Action::ALL.iter().zip(&self.keys)
+            .find(|(action, keys)| action.context() == context && keys.contains(&key))
+            .map(|(&action, _)| action)
+    }
+
+    /// Keys as the HUD and control status show them, e.g. "W/UP"
+    pub fn label(&self, action: Action) -> String {
+        let keys = self.keys(action);
+        if keys.is_empty() { "-".to_string() } else { keys.iter().map(|key| key.name()).collect::<Vec<_>>().join("/") }
+    }
+
+    /// Actions in the same context sharing a key; Escape counts as bound to the emergency stop
+    pub fn conflicts(&self) -> Vec<KeyConflict> {
+        let mut conflicts = Vec::new();
+        for (index, (&action, keys)) in Action::ALL.iter().zip(&self.keys).enumerate() {
+            for &key in keys {
+                let winner = self.action(action.context(), key)
+                    .filter(|&winner| winner != action)
+                    .filter(|&winner| winner == Action::EmergencyStop || winner.index() < index);
+                if let Some(winner) = winner {
+                    conflicts.push(KeyConflict { key, winner, shadowed: action });
+                }
+            }
+        }
+        conflicts
+    }
+}
+
+/// Keys that close the editor; the default one if a bindings file left none but Escape
+fn editor_close_keys(bindings: &KeyBindings) -> Vec<BoundKey> {
+    let keys: Vec<BoundKey> = bindings.keys(Action::KeyBindingsEditor).iter()
+        .copied()
+        .filter(|&key| key != BoundKey::Named(NamedKey::Escape))
+        .collect();
+    if keys.is_empty() { Action::KeyBindingsEditor.default_keys() } else { keys }
+}
+
+/// Rows of the editor shown at once
+const EDITOR_ROWS: usize = 32;
+const EDITOR_ORIGIN: Vec2 = Vec2::new(300.0, 60.0);
+
+/// What a key press did in the editor
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditorInput {
+    Adjusted,
+    Rebound(Action),
+    Close,
+}
+
+/// Overlay listing every action: Up/Down select, Enter captures the next key,
+/// Backspace unbinds, Delete restores the default, the editor key closes.
+/// The editor's own binding can't be emptied or moved to Escape, which only stops
+#[derive(Debug, Default)]
+pub struct KeyBindingEditor {
+    selected: usize,
+    capturing: bool,
+}
+
+impl KeyBindingEditor {
+    pub fn handle_key(&mut self, key: BoundKey, bindings: &mut KeyBindings) -> EditorInput {
+        let action = Action::ALL[self.selected];
+        let closes_editor = action == Action::KeyBindingsEditor;
+        if self.capturing {
+            self.capturing = false;
+            if closes_editor && key == BoundKey::Named(NamedKey::Escape) {
+                return EditorInput::Adjusted;
+            }
+            bindings.set(action, vec![key]);
+            return EditorInput::Rebound(action);
+        }
+        if editor_close_keys(bindings).contains(&key) {
+            return EditorInput::Close;
+        }
+        let last = Action::ALL.len() - 1;
+        match key {
+            BoundKey::Named(NamedKey::ArrowUp) => self.selected = self.selected.saturating_sub(1),
+            BoundKey::Named(NamedKey::ArrowDown) => self.selected = (self.selected + 1).min(last),
+            BoundKey::Named(NamedKey::PageUp) => self.selected = self.selected.saturating_sub(EDITOR_ROWS / 2),
+            BoundKey::Named(NamedKey::PageDown) => self.selected = (self.selected + EDITOR_ROWS / 2).min(last),
+            BoundKey::Named(NamedKey::Enter) => self.capturing = true,
+            BoundKey::Named(NamedKey::Backspace) if !closes_editor => {
+                bindings.set(action, Vec::new());
+                return EditorInput::Rebound(action);
+            }
+            BoundKey::Named(NamedKey::Delete) => {
+                bindings.reset(action);
+                return EditorInput::Rebound(action);
+            }
+            _ => {}
+        }
+        EditorInput::Adjusted
+    }
+
+    /// Dim panel of actions and keys around the selection; conflicting rows in amber
+    pub fn push_overlay(&self, vertices: &mut Vec<Vertex>, bindings: &KeyBindings, locale: &Locale) {
+        let screen = Vec2::new(1200.0, 800.0);
+        let conflicts = bindings.conflicts();
+        let first = self.selected.saturating_sub(EDITOR_ROWS / 2).min(Action::ALL.len().saturating_sub(EDITOR_ROWS));
+        text::push_rect(vertices, EDITOR_ORIGIN - Vec2::splat(16.0), EDITOR_ORIGIN + Vec2::new(616.0, 40.0 + EDITOR_ROWS as f32 * 18.0 + 24.0), Vec3::new(0.04, 0.04, 0.1), screen);
+        text::push_text(vertices, locale.text("keys-editor-title"), EDITOR_ORIGIN, 2.0, Vec3::splat(0.5), screen);
+
+        for (row, (index, &action)) in Action::ALL.iter().enumerate().skip(first).take(EDITOR_ROWS).enumerate() {
+            let y = EDITOR_ORIGIN.y + 30.0 + row as f32 * 18.0;
+            let conflicted = conflicts.iter().any(|conflict| conflict.shadowed == action || conflict.winner == action);
+            let color = match (index == self.selected, conflicted) {
+                (true, _) => Vec3::new(0.55, 0.6, 0.75),
+                (false, true) => Vec3::new(0.6, 0.45, 0.2),
+                (false, false) => Vec3::splat(0.38),
+            };
+            let keys = if index == self.selected && self.capturing { locale.text("keys-editor-press").to_string() } else { bindings.label(action) };
+            let label = match action.context() {
+                KeyContext::Global => locale.text(&format!("action-{}", action.name())).to_string(),
+                context => format!("{}: {}", locale.text(&format!("context-{}", context.name())), locale.text(&format!("action-{}", action.name()))),
+            };
+            text::push_text(vertices, &label, Vec2::new(EDITOR_ORIGIN.x, y), 2.0, color, screen);
+            text::push_text(vertices, &keys, Vec2::new(EDITOR_ORIGIN.x + 420.0, y), 2.0, color, screen);
+        }
+
+        let footer_y = EDITOR_ORIGIN.y + 30.0 + EDITOR_ROWS as f32 * 18.0 + 6.0;
+        let footer = match conflicts.first() {
+            Some(conflict) => locale.format("keys-editor-conflict", &[
+                ("key", &conflict.key.name()),
+                ("winner", &locale.text(&format!("action-{}", conflict.winner.name()))),
+                ("shadowed", &locale.text(&format!("action-{}", conflict.shadowed.name()))),
+            ]),
+            None => locale.format("keys-editor-help", &[("key", &bindings.label(Action::KeyBindingsEditor))]),
+        };
+        let color = if conflicts.is_empty() { Vec3::splat(0.35) } else { Vec3::new(0.6, 0.45, 0.2) };
+        text::push_text(vertices, &footer, Vec2::new(EDITOR_ORIGIN.x, footer_y), 2.0, color, screen);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::safety::is_dangerous_red;
+
+    #[test]
+    fn test_defaults_are_conflict_free_and_files_round_trip() {
+        let defaults = KeyBindings::default();
+        assert_eq!(defaults.conflicts(), Vec::new());
+        assert_eq!(defaults.action(KeyContext::Global, BoundKey::Char('w')), Some(Action::WarfareOverlay));
+        assert_eq!(defaults.action(KeyContext::Photo, BoundKey::Char('w')), Some(Action::PanUp));
+        assert_eq!(defaults.action(KeyContext::Global, BoundKey::parse("space").unwrap()), Some(Action::ToggleAudio));
+        assert_eq!(BoundKey::from_key(&Key::Character("C".into())), Some(BoundKey::Char('c')));
+        assert_eq!(KeyBindings::parse(&defaults.to_file_string()).unwrap(), defaults);
+        assert_eq!(defaults.label(Action::VolumeUp), "PLUS/EQUALS");
+        let english = Locale::english();
+        assert!(Action::ALL.iter().all(|action| english.text(&format!("action-{}", action.name())) != format!("action-{}", action.name())));
+
+        // Freeing 'c' from chaotic audio lets it be reused
+        let bindings = KeyBindings::parse("# mine\naudio-chaotic = x\nprofiler-overlay = c F\n").unwrap();
+        assert_eq!(bindings.action(KeyContext::Global, BoundKey::Char('c')), Some(Action::ProfilerOverlay));
+        assert_eq!(bindings.label(Action::ProfilerOverlay), "C/F");
+        assert!(matches!(KeyBindings::parse("warp-drive = w"), Err(BloomError::KeyBindings { line: 1, .. })));
+        assert!(matches!(KeyBindings::parse("\nzoom-in = Hyper"), Err(BloomError::KeyBindings { line: 2, .. })));
+    }
+
+    #[test]
+    fn test_conflicts_are_detected_and_escape_stays_the_emergency_stop() {
+        let mut bindings = KeyBindings::default();
+        bindings.set(Action::SoundOverlay, vec![BoundKey::Char('w')]);
+        bindings.set(Action::CycleTheme, vec![BoundKey::Named(NamedKey::Escape)]);
+        let conflicts = bindings.conflicts();
+        assert_eq!(conflicts.len(), 2);
+        assert!(conflicts.contains(&KeyConflict { key: BoundKey::Char('w'), winner: Action::WarfareOverlay, shadowed: Action::SoundOverlay }));
+        bindings.set(Action::EmergencyStop, Vec::new());
+        assert_eq!(bindings.action(KeyContext::Global, BoundKey::Named(NamedKey::Escape)), Some(Action::EmergencyStop));
+
+        let mut editor = KeyBindingEditor::default();
+        editor.handle_key(BoundKey::Named(NamedKey::ArrowDown), &mut bindings);
+        assert_eq!(editor.handle_key(BoundKey::Named(NamedKey::Enter), &mut bindings), EditorInput::Adjusted);
+        assert_eq!(editor.handle_key(BoundKey::Char('1'), &mut bindings), EditorInput::Rebound(Action::AudioMellow));
+        assert_eq!(bindings.action(KeyContext::Global, BoundKey::Char('1')), Some(Action::AudioMellow));
+        assert_eq!(editor.handle_key(BoundKey::Named(NamedKey::F12), &mut bindings), EditorInput::Close);
+
+        let mut vertices = Vec::new();
+        editor.push_overlay(&mut vertices, &bindings, &Locale::english());
+        assert!(vertices.iter().all(|vertex| !is_dangerous_red(Vec3::from(vertex.color))));
+    }
+
+    #[test]
+    fn test_editor_always_stays_closable() {
+        let mut bindings = KeyBindings::default();
+        let mut editor = KeyBindingEditor::default();
+        let steps = Action::ALL.iter().position(|&action| action == Action::KeyBindingsEditor).unwrap();
+        for _ in 0..steps {
+            editor.handle_key(BoundKey::Named(NamedKey::ArrowDown), &mut bindings);
+        }
+        assert_eq!(editor.handle_key(BoundKey::Named(NamedKey::Backspace), &mut bindings), EditorInput::Adjusted);
+        editor.handle_key(BoundKey::Named(NamedKey::Enter), &mut bindings);
+        assert_eq!(editor.handle_key(BoundKey::Named(NamedKey::Escape), &mut bindings), EditorInput::Adjusted);
+        assert_eq!(bindings.keys(Action::KeyBindingsEditor), Action::KeyBindingsEditor.default_keys());
+
+        // A bindings file can still leave it unbound; the default key closes it then
+        bindings.set(Action::KeyBindingsEditor, Vec::new());
+        assert_eq!(editor.handle_key(Action::KeyBindingsEditor.default_keys()[0], &mut bindings), EditorInput::Close);
+        bindings.set(Action::KeyBindingsEditor, vec![BoundKey::Char('k')]);
+        assert_eq!(editor.handle_key(BoundKey::Char('k'), &mut bindings), EditorInput::Close);
+    }
+}
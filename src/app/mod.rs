@@ -0,0 +1,71 @@
+// === APPLICATION LAYER ===
+// The organism itself is always available for embedding; the standalone shell
+// (epilepsy warning screen + winit event loop) is only built with the `app` feature
+
+mod ambient;
+mod attract;
+mod crash;
+mod display;
+mod entropy;
+mod garden;
+mod idle;
+mod keybindings;
+mod modulation;
+mod outputs;
+mod organism;
+mod pacing;
+mod photo;
+mod possession;
+mod profiler;
+mod remote;
+mod stream;
+mod scrubber;
+mod tutorial;
+mod tuning;
+mod warm_start;
+
+pub use ambient::{run_ambient, AmbientOrganism};
+pub use attract::{AttractMode, ATTRACT_RESUME_AFTER};
+pub use crash::{default_crash_directory, read_snapshot};
+pub use modulation::default_matrix_file;
+#[cfg(feature = "app")]
+pub(crate) use crash::{exit_on_panic, is_supervised, relaunched, supervise};
+pub use display::FullscreenMode;
+pub use entropy::{EntropyFeed, EntropySource};
+pub use idle::{DEFAULT_IDLE_TIMEOUT, IDLE_FRAME_INTERVAL};
+pub use keybindings::{Action, BoundKey, KeyBindings, KeyConflict, KeyContext};
+pub use organism::ChaosEngine;
+pub use pacing::FixedTimestep;
+pub use profiler::{FrameProfile, ProfileStage};
+pub use remote::RemoteControl;
+pub use stream::StateStream;
+pub use tutorial::{TutorialMode, TutorialStep};
+
+/// The user's home directory, or the working directory when it cannot be found
+pub(crate) fn home_dir() -> std::path::PathBuf {
+    known_home_dir().unwrap_or_default()
+}
+
+/// The user's home directory, if the environment names one
+pub(crate) fn known_home_dir() -> Option<std::path::PathBuf> {
+    let home = std::env::var_os("HOME").or_else(|| std::env::var_os("USERPROFILE"));
+    home.filter(|home| !home.is_empty()).map(std::path::PathBuf::from)
+}
+
+#[cfg(feature = "app")]
+mod screensaver;
+#[cfg(feature = "app")]
+mod software;
+#[cfg(feature = "app")]
+mod standalone;
+#[cfg(feature = "app")]
+mod warning;
+
+#[cfg(feature = "app")]
+pub use screensaver::{ScreensaverCommand, ScreensaverProfile};
+#[cfg(feature = "app")]
+pub use standalone::{run, run_screensaver, run_standalone};
+#[cfg(feature = "app")]
+pub(crate) use standalone::init_logging;
+#[cfg(feature = "app")]
+pub use warning::WarningResponse;
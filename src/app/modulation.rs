@@ -0,0 +1,187 @@
+// === MODULATION MATRIX EDITOR ===
+// Panel over the routes of the mod matrix, edited while the organism runs.
+// Up/Down pick a route, Tab moves between its source, destination, depth and
+// curve, Left/Right change the picked one, Enter adds a route and Delete
+// removes one. Each row shows how far the route is moving its destination
+// right now. Matrices are saved as RON presets: the configured file is read
+// at startup and written again when the panel closes.
+
+use std::path::{Path, PathBuf};
+use glam::{Vec2, Vec3};
+use winit::keyboard::NamedKey;
+use crate::engine::{ModCurve, ModDestination, ModMatrix, ModRoute, ModSource, ModSources};
+use crate::error::{BloomError, Result};
+use crate::locale::Locale;
+use crate::params::MOD_DEPTH;
+use crate::reality::{text, Vertex};
+use super::keybindings::{Action, BoundKey, KeyBindings};
+
+const PANEL_ORIGIN: Vec2 = Vec2::new(200.0, 180.0);
+const ROW_HEIGHT: f32 = 22.0;
+/// Left edge of each column, relative to the panel origin
+const COLUMNS: [f32; 4] = [0.0, 220.0, 440.0, 540.0];
+const METER_OFFSET: f32 = 700.0;
+const METER_WIDTH: f32 = 80.0;
+/// Depth change of one Left/Right press
+const DEPTH_STEP: f32 = 0.05;
+/// Matrix file name, kept in the home directory
+const DEFAULT_FILE_NAME: &str = ".aetherium_bloom_modulation.ron";
+const SCREEN: Vec2 = Vec2::new(1200.0, 800.0);
+
+/// What a key press did on the panel
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModInput {
+    Ignored,
+    Selected,
+    Changed,
+    Close,
+}
+
+/// Route and column under the cursor of the matrix panel
+#[derive(Debug, Default)]
+pub struct ModMatrixEditor {
+    selected: usize,
+    column: usize, // 0 source, 1 destination, 2 depth, 3 curve
+}
+
+impl ModMatrixEditor {
+    pub fn handle_key(&mut self, key: BoundKey, bindings: &KeyBindings, matrix: &mut ModMatrix) -> ModInput {
+        if bindings.keys(Action::ModMatrix).contains(&key) {
+            return ModInput::Close;
+        }
+        match key {
+            BoundKey::Named(NamedKey::ArrowUp) => self.selected = self.selected.saturating_sub(1),
+            BoundKey::Named(NamedKey::ArrowDown) => self.selected = (self.selected + 1).min(matrix.routes.len().saturating_sub(1)),
+            BoundKey::Named(NamedKey::Tab) => self.column = (self.column + 1) % COLUMNS.len(),
+            BoundKey::Named(NamedKey::ArrowLeft) => return self.adjust(matrix, -1),
+            BoundKey::Named(NamedKey::ArrowRight) => return self.adjust(matrix, 1),
+            BoundKey::Named(NamedKey::Enter) => {
+                // A new route starts as a copy of the selected one, so variations are quick to make
+                let route = matrix.routes.get(self.selected).copied()
+                    .unwrap_or_else(|| ModRoute::new(ModSource::Beat, ModDestination::Distortion, MOD_DEPTH.default));
+                if !matrix.add(route) {
+                    return ModInput::Ignored;
+                }
+                self.selected = matrix.routes.len() - 1;
+                return ModInput::Changed;
+            }
+            BoundKey::Named(NamedKey::Delete) | BoundKey::Named(NamedKey::Backspace) if self.selected < matrix.routes.len() => {
+                matrix.routes.remove(self.selected);
+                self.selected = self.selected.min(matrix.routes.len().saturating_sub(1));
+                return ModInput::Changed;
+            }
+            _ => return ModInput::Ignored,
+        }
+        ModInput::Selected
+    }
+
+    /// Step the selected column of the selected route by `direction`
+    fn adjust(&self, matrix: &mut ModMatrix, direction: isize) -> ModInput {
+        let Some(route) = matrix.routes.get_mut(self.selected) else { return ModInput::Ignored };
+        match self.column {
+            0 => route.source = cycle(&ModSource::ALL, route.source, direction),
+            1 => route.destination = cycle(&ModDestination::ALL, route.destination, direction),
+            2 => route.depth = MOD_DEPTH.clamp(((route.depth + DEPTH_STEP * direction as f32) / DEPTH_STEP).round() * DEPTH_STEP),
+            _ => route.curve = cycle(&ModCurve::ALL, route.curve, direction),
+        }
+        ModInput::Changed
+    }
+
+    /// Dim panel of routes with live meters; the selected cell brighter
+    pub fn push_overlay(&self, vertices: &mut Vec<Vertex>, matrix: &ModMatrix, sources: &ModSources, locale: &Locale, bindings: &KeyBindings) {
+        let rows = matrix.routes.len().max(1) as f32;
+        text::push_rect(vertices, PANEL_ORIGIN - Vec2::new(16.0, 46.0), PANEL_ORIGIN + Vec2::new(816.0, rows * ROW_HEIGHT + 30.0), Vec3::new(0.04, 0.04, 0.1), SCREEN);
+        text::push_text(vertices, locale.text("modulation-title"), PANEL_ORIGIN - Vec2::new(0.0, 30.0), 2.0, Vec3::splat(0.5), SCREEN);
+        if matrix.routes.is_empty() {
+            text::push_text(vertices, locale.text("modulation-empty"), PANEL_ORIGIN, 2.0, Vec3::splat(0.38), SCREEN);
+        }
+
+        for (index, route) in matrix.routes.iter().enumerate() {
+            let y = PANEL_ORIGIN.y + index as f32 * ROW_HEIGHT;
+            let cells = [
+                locale.text(&format!("mod-source-{}", route.source.name())).to_string(),
+                locale.text(&format!("mod-destination-{}", route.destination.name())).to_string(),
+                format!("{:+.2}", route.depth),
+                locale.text(&format!("mod-curve-{}", route.curve.name())).to_string(),
+            ];
+            for (column, cell) in cells.iter().enumerate() {
+                let color = match (index == self.selected, column == self.column) {
+                    (true, true) => Vec3::new(0.6, 0.65, 0.8),
+                    (true, false) => Vec3::new(0.45, 0.48, 0.6),
+                    _ => Vec3::splat(0.38),
+                };
+                text::push_text(vertices, cell, Vec2::new(PANEL_ORIGIN.x + COLUMNS[column], y), 2.0, color, SCREEN);
+            }
+
+            // What the route contributes now, from the middle of the meter towards its sign
+            let amount = route.curve.apply(sources.get(route.source)) * route.depth;
+            let center = PANEL_ORIGIN.x + METER_OFFSET + METER_WIDTH * 0.5;
+            let end = center + amount * METER_WIDTH * 0.5;
+            text::push_rect(vertices, Vec2::new(center - METER_WIDTH * 0.5, y + 2.0), Vec2::new(center + METER_WIDTH * 0.5, y + 10.0), Vec3::splat(0.1), SCREEN);
+            text::push_rect(vertices, Vec2::new(center.min(end), y + 2.0), Vec2::new(center.max(end), y + 10.0), Vec3::new(0.3, 0.35, 0.5), SCREEN);
+        }
+
+        let help = locale.format("modulation-help", &[("key", &bindings.label(Action::ModMatrix))]);
+        text::push_text(vertices, &help, PANEL_ORIGIN + Vec2::new(0.0, rows * ROW_HEIGHT + 8.0), 2.0, Vec3::splat(0.35), SCREEN);
+    }
+}
+
+/// The item `direction` steps after `current` in `all`, wrapping around
+fn cycle<T: Copy + PartialEq>(all: &[T], current: T, direction: isize) -> T {
+    let index = all.iter().position(|&item| item == current).unwrap_or(0) as isize;
+    all[(index + direction).rem_euclid(all.len() as isize) as usize]
+}
+
+/// `~/.aetherium_bloom_modulation.ron`, beside the key bindings
+pub fn default_matrix_file() -> PathBuf {
+    super::home_dir().join(DEFAULT_FILE_NAME)
+}
+
+/// Read a matrix preset; depths out of range are clamped and extra routes dropped
+pub fn load_matrix(path: &Path) -> Result<ModMatrix> {
+    let fail = |reason: String| BloomError::Modulation { path: path.to_path_buf(), reason };
+    let source = std::fs::read_to_string(path).map_err(|e| fail(e.to_string()))?;
+    let matrix: ModMatrix = ron::from_str(&source).map_err(|e| fail(e.to_string()))?;
+    Ok(matrix.sanitized())
+}
+
+pub fn save_matrix(matrix: &ModMatrix, path: &Path) -> Result<()> {
+    let fail = |reason: String| BloomError::Modulation { path: path.to_path_buf(), reason };
+    let source = ron::ser::to_string_pretty(matrix, ron::ser::PrettyConfig::default()).map_err(|e| fail(e.to_string()))?;
+    std::fs::write(path, source).map_err(|e| fail(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_editor_builds_routes_and_presets_round_trip() {
+        let bindings = KeyBindings::default();
+        let mut editor = ModMatrixEditor::default();
+        let mut matrix = ModMatrix::default();
+
+        assert_eq!(editor.handle_key(BoundKey::Named(NamedKey::Enter), &bindings, &mut matrix), ModInput::Changed);
+        assert_eq!(matrix.routes[0], ModRoute::new(ModSource::Beat, ModDestination::Distortion, MOD_DEPTH.default));
+
+        // Source back from the first wraps to the last; Tab twice reaches the depth
+        editor.handle_key(BoundKey::Named(NamedKey::ArrowLeft), &bindings, &mut matrix);
+        assert_eq!(matrix.routes[0].source, ModSource::TimeOfDay);
+        editor.handle_key(BoundKey::Named(NamedKey::Tab), &bindings, &mut matrix);
+        editor.handle_key(BoundKey::Named(NamedKey::Tab), &bindings, &mut matrix);
+        for _ in 0..20 {
+            editor.handle_key(BoundKey::Named(NamedKey::ArrowRight), &bindings, &mut matrix);
+        }
+        assert_eq!(matrix.routes[0].depth, MOD_DEPTH.max);
+
+        let path = std::env::temp_dir().join(format!("aetherium_modulation_{}.ron", std::process::id()));
+        save_matrix(&matrix, &path).unwrap();
+        assert_eq!(load_matrix(&path).unwrap(), matrix);
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(editor.handle_key(BoundKey::Named(NamedKey::Delete), &bindings, &mut matrix), ModInput::Changed);
+        assert!(matrix.routes.is_empty());
+        let close = bindings.keys(Action::ModMatrix)[0];
+        assert_eq!(editor.handle_key(close, &bindings, &mut matrix), ModInput::Close);
+    }
+}
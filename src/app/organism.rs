@@ -0,0 +1,4912 @@
+// The living organism: llama simulation, safety pipeline, audio coupling and
+// rendering, driven by whichever event loop owns the window
+
+use wgpu::*;
+use winit::{
+    event::{WindowEvent, ElementState, MouseButton, MouseScrollDelta, KeyEvent},
+    window::Window,
+    keyboard::{ModifiersState, NamedKey},
+};
+use glam::{Vec2, Vec3};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::Receiver;
+use std::time::{Duration, Instant};
+use tracing::{debug, error, info, warn};
+
+// === AUDIO CONSCIOUSNESS LAYER ===
+use crate::audio::{AudioConsciousnessEngine, DuckingSettings, ExternalSource, LoudnessSettings, CompatLlamaRenderData, SampleCue, AudioEnvironment, AudioAnalysisData, AudioMode, AudioDeviceState, AudioBufferStats, CompatChaosEvent, MixBus, MixerControls, SurroundConfig, VoiceStats};
+
+// === UNIFIED VERTEX SYSTEM ===
+use crate::reality::{Vertex, SKINNED_SPECIES, DynamicVertexBuffer, VertexBudgetManager, BufferConfig, BufferUsageReport, BudgetCategoryUsage, ThemePipelines, VisualTheme};
+use crate::reality::text;
+use crate::params;
+#[cfg(feature = "shader-hot-reload")]
+use crate::reality::shader_reload::{self, ShaderWatcher, SHADER_DIR};
+
+// === MODULAR SYSTEMS ===
+use crate::engine::safety::{SafetyConfig, FlashTracker, FrameAnalyzer, FrameSummary, SafetyViolation, ZonedLuminanceAnalyzer, apply_safety_pipeline, is_dangerous_red, rgb_to_hsv, hsv_to_rgb_vec3};
+// === EXTRACTED MODULAR SYSTEMS ===
+use crate::core::ecs::{EntityId, World};
+use crate::core::events::{ChaosEvent, EventBus};
+use crate::engine::{ChaosTuning, ModDestination, ModMatrix, ModOutputs, ModSource, ModSources, ConsciousnessGraph, ConsciousnessMultiplicationSystem, HomeostasisConfig, Metabolism, MetabolismConfig, PopulationBalancer, DiplomacyEvent, ObserverIntervention, species_index, ConsciousnessPredation, HiveMind, AdvancedBeatEngine, BeatScheduler, Quantize, EventDrivenArchitecture, AvSyncCalibrator, BeatDelayLine};
+use crate::engine::av_sync::{AV_OFFSET_STEP_MS, MAX_AV_OFFSET_MS};
+use crate::engine::population::{self, CullPolicy, DespawnReason, Despawned};
+use crate::mathematics::ForceField;
+use crate::entities::{FlockingConfig, HybridSpecies, Llama, SpeciesType, ConsciousnessLevel, SPECIES_COUNT, bass_drop_shockwave, thump};
+use crate::communication::{EmergentCommunicationSystems, ManifestationType};
+use crate::simulation::{BehaviorStagger, Biography, Chronicle, ConsciousnessCrystal, CrystalType, DigitalEcosystem, GardenLayout, LlamaEcho, MetaConsciousnessFramework, NumericGuard, ObservationJournal, RealityTear, TearType, WorldHistory, TEAR_MAX_SIZE, ZoneType, SNAPSHOT_INTERVAL, apply_ecosystem_mutations, spawn_echoes, step_llamas};
+use crate::rendering::{ColorGrade, CrystalInstance, CrystalPass, CrystalUniforms, DistortionPass, DistortionUniforms, DustPass, DustUniforms, GradeMood, GradingPass, LightSelection, LightingPass, LodConfig, PaletteConfig, PaletteDirector, DensitySplats, DetailLevel, push_point, Lut3d, night_amount, LightingUniforms, PsychedelicUniforms, RealityDistortionEngine, SilhouettePose, SkinAtlas, SkinTexture, SKIN_SEED, SKIN_SLOTS, Spectrogram, Viewport, WORLD_SIZE, desaturate, push_field_overlay, push_force_overlay, FieldView, push_pheromone_overlay, push_sound_overlay, push_warfare_overlay, silhouette_triangles, species_territories, max_silhouette_triangle_count};
+use crate::api::{BloomConfig, BloomEvent};
+use super::attract::{AttractCue, AttractDirector, AttractMode};
+use super::crash::{load_autosave, CrashRecorder};
+use super::entropy::{EntropyFeed, EntropyFeeds};
+use super::remote::{RemoteAudioStatus, RemoteCommand, RemoteServer, RemoteStatus};
+use super::stream::StateStreamer;
+use super::garden::{self, GardenEditor, GardenInput};
+use super::idle::IdleMonitor;
+use super::modulation::{self, ModInput, ModMatrixEditor};
+use super::keybindings::{Action, BoundKey, EditorInput, KeyBindingEditor, KeyBindings, KeyContext, SPEED_PRESETS};
+use super::tuning::{TuningInput, TuningPanel};
+use super::warm_start::WarmStart;
+use super::outputs::OutputWindow;
+use super::pacing::{fps_interval, select_present_mode, slowest_interval};
+use super::photo::{self, PhotoInput, PhotoMode};
+use super::possession::PossessionControls;
+use super::profiler::{FrameProfile, FrameProfiler, ProfileStage, push_buffer_usage, push_profiler_overlay};
+use super::scrubber::{ScrubInput, Scrubber};
+use super::tutorial::{Tutorial, TutorialCue, TutorialMode, TutorialStep};
+use crate::error::{BloomError, Result};
+use crate::input::{tear_at, TearDwell};
+#[cfg(feature = "attention")]
+use crate::input::attention::{watched_gain, AttentionEstimator, AttentionReading, AttentionTracker};
+use crate::locale::Locale;
+use crate::mods::{ModManifest, ModSet, ScriptAction, ScriptHost};
+use crate::user::{UserCoEvolutionSystem, UserAction, ActionType, ActionContext, VisualEnvironmentState, AudioEnvironmentState, ExperienceAdaptation};
+
+
+/// Biography lines the chronicle inspector shows
+const CHRONICLE_LINES: usize = 10;
+
+/// True on the one tick where `time` passes a multiple of `interval`
+fn crossed_interval(time: f32, dt: f32, interval: f32) -> bool {
+    (time / interval).floor() > ((time - dt) / interval).floor()
+}
+
+/// The synth on the default output device, set up with the configured samples, voices, ducking and speakers
+pub(super) fn open_audio_engine(bloom_config: &BloomConfig) -> Result<AudioConsciousnessEngine> {
+    let mut engine = AudioConsciousnessEngine::new()?;
+    // A bad sample file only costs that species its custom sound
+    for (&species, paths) in &bloom_config.sample_banks {
+        match paths.load() {
+            Ok(bank) => engine.load_sample_bank(species, bank),
+            Err(e) => warn!(target: "audio", "🔇 {:?} sample bank not loaded: {}", species, e),
+        }
+    }
+    for (&species, signature) in &bloom_config.sonic_signatures {
+        engine.set_sonic_signature(species, signature.clone());
+    }
+    engine.get_controls_mut().ducking = bloom_config.ducking;
+    engine.get_controls_mut().loudness = bloom_config.loudness;
+    engine.get_controls_mut().environment_crossfade = bloom_config.environment_crossfade.as_secs_f32();
+    if bloom_config.surround.is_surround() {
+        if let Err(e) = engine.set_surround(bloom_config.surround) {
+            warn!(target: "audio", "🔈 {} output not opened ({}) - retrying", bloom_config.surround.layout.name(), e);
+        }
+    }
+    if let Some(source) = &bloom_config.external_audio {
+        if let Err(e) = engine.enable_ducking(source) {
+            warn!(target: "audio", "🎧 External audio not available ({}) - synth plays at full level", e);
+        }
+    }
+    Ok(engine)
+}
+
+/// Hive coherence drives consonance: share of llamas bound into hives, weighted by hive influence
+pub(super) fn hive_coherence(world: &World) -> f32 {
+    let population = world.count::<Llama>().max(1) as f32;
+    let bound: f32 = world.components::<HiveMind>().iter()
+        .map(|hive| hive.member_entities.len() as f32 * hive.collective_decision_weight.clamp(0.0, 1.0))
+        .sum();
+    (bound / population).clamp(0.0, 1.0)
+}
+
+/// What the synth needs to know about each llama
+pub(super) fn audio_llamas(llamas: &[Llama]) -> Vec<CompatLlamaRenderData> {
+    llamas.iter().map(|llama| CompatLlamaRenderData {
+        position: llama.position,
+        color_wavelength: Vec2::new(llama.color.x, llama.harmonic_resonance),
+        trip_intensity: llama.trip_intensity,
+        reality_distortion: llama.reality_distortion,
+        species: llama.species,
+        valence: llama.affect.valence,
+        arousal: llama.affect.arousal,
+    }).collect()
+}
+
+/// Synth low-pass cutoff for a filter-cutoff modulation: open at zero and above, closing
+/// exponentially to the lowest cutoff at -1
+fn filter_cutoff(offset: f32) -> f32 {
+    let range = params::FILTER_CUTOFF_HZ.min / params::FILTER_CUTOFF_HZ.max;
+    params::FILTER_CUTOFF_HZ.max * range.powf(-offset.min(0.0))
+}
+
+/// Events held back until a musical boundary instead of firing mid-beat
+enum BeatCue {
+    Spawn(SpeciesType, Vec2),        // Attract-mode spawns land on the beat
+    AudioMode(AudioMode),            // Attract-mode mode changes wait for the bar
+    CycleTheme,                      // Palette shifts land on the beat
+    Stinger(SpeciesType, SampleCue), // Diplomacy stingers hit on the drop
+}
+
+fn warn_key_conflicts(bindings: &KeyBindings) {
+    for conflict in bindings.conflicts() {
+        warn!(target: "app", "⌨️ {} is bound to both {} and {}; {} keeps it",
+              conflict.key.name(), conflict.winner.name(), conflict.shadowed.name(), conflict.winner.name());
+    }
+}
+
+/// The color grade each audio environment blends towards
+fn grade_mood(environment: &AudioEnvironment) -> GradeMood {
+    match environment {
+        AudioEnvironment::Environmental => GradeMood::Environmental,
+        AudioEnvironment::Meditative => GradeMood::Meditative,
+        AudioEnvironment::Psychedelic => GradeMood::Psychedelic,
+        AudioEnvironment::Electronica => GradeMood::Electronica,
+        AudioEnvironment::HiveMind => GradeMood::HiveMind,
+        AudioEnvironment::RealityTear => GradeMood::RealityTear,
+    }
+}
+
+/// Read a `.cube` 3D LUT
+fn load_grade_lut(path: &Path) -> Result<Lut3d> {
+    let fail = |reason: String| BloomError::ColorGrade { path: path.to_path_buf(), reason };
+    let source = std::fs::read_to_string(path).map_err(|e| fail(e.to_string()))?;
+    Lut3d::parse_cube(&source).map_err(fail)
+}
+
+/// Convert HSV to RGB (convenience wrapper)
+fn hsv_to_rgb(hue: f32, saturation: f32, value: f32) -> Vec3 {
+    hsv_to_rgb_vec3(Vec3::new(hue, saturation, value))
+}
+
+// === PHASE 3: ECOSYSTEM EMERGENCE ===
+
+
+
+
+
+
+// Mathematical chaos engine using pre-calculated primes
+
+// Vertex struct now imported from crate::reality::Vertex for consistency
+
+
+
+
+
+
+
+
+
+
+
+// === PHASE 4: TRANSCENDENCE PROTOCOL ===
+
+
+
+pub struct ChaosEngine {
+    instance: Instance, // Kept to create surfaces for extra output windows
+    adapter: Adapter,
+    device: Device,
+    queue: Queue,
+    config: SurfaceConfiguration,
+    surface: Surface<'static>,
+    viewport: Viewport,         // Part of the world the primary window shows
+    outputs: Vec<OutputWindow>, // Extra windows onto the same world
+    lod: LodConfig,             // Points, splats and culling for small or off-screen llamas
+    palette: PaletteDirector,   // Harmonic palette every color is pulled into
+    supported_present_modes: Vec<PresentMode>,
+    theme_pipelines: ThemePipelines, // One pipeline per visual theme, all fed the same vertices
+    distortion_pass: DistortionPass, // Warps the world around reality tears
+    dust_pass: DustPass,             // GPU-advected consciousness dust under the world
+    consciousness_dust: bool,
+    crystal_pass: CrystalPass,       // Refracts the world through the crystals
+    crystal_refraction: bool,
+    lighting_pass: LightingPass,     // Lights the world from conscious llamas and crystals, with shadows
+    light_selection: LightSelection, // Which lights hold the pass's slots, fading in and out
+    lighting: f32,                   // How deep unlit areas sink, 0-1; 0 skips the pass
+    grading_pass: GradingPass,       // Grades the finished world through the blended LUT
+    color_grade: ColorGrade,         // Looks per audio environment and for night, and how much of each shows
+    color_grading: bool,
+    grade_upload_pending: bool,      // The blended LUT changed since it was last uploaded
+    day_length: f32,                 // Seconds in one simulated day, noon to noon
+    skin_texture: SkinTexture,       // Noise-grown species skins the psychedelic theme patterns llamas with
+    dynamic_vertex_buffer: DynamicVertexBuffer,
+    budget_manager: VertexBudgetManager,
+
+    // Psychedelic shader uniforms
+    uniform_buffer: Buffer,
+    uniform_bind_group: BindGroup,
+    uniforms: PsychedelicUniforms,
+
+    // ECS world: llamas, crystals, reality tears, hierarchies, hive minds, predations
+    world: World,
+    time: f32,
+    beat_intensity: f32,
+
+    // Phase 2: Advanced Beat Engine with chaos amplification
+    advanced_beat_engine: AdvancedBeatEngine,
+    beat_scheduler: BeatScheduler<BeatCue>,
+    species_spawn_weights: [f32; SPECIES_COUNT], // Indexed by SpeciesType::to_index
+    homeostasis: Option<PopulationBalancer>,     // None lets species win or lose without a counterweight
+    homeostasis_config: HomeostasisConfig,       // Bands `Y` turns back on with
+    metabolism: Option<Metabolism>,              // None leaves every llama rested
+    flocking: FlockingConfig,                    // Per-species boid weights
+    entropy: EntropyFeeds,                       // Real-world noise for the chaos dimension
+    remote: Option<RemoteServer>,                // Tablets and control rooms; None when not configured or not started
+    remote_published: f32,                       // When remote clients were last given the status
+    state_stream: Option<StateStreamer>,         // Binary frames for external visualizers
+    total_consciousness: f32,
+
+    // Phase 3: Ecosystem Emergence
+    ecosystem: DigitalEcosystem,
+
+    // Phase 4: Transcendence Protocol
+    meta_consciousness: MetaConsciousnessFramework,
+    reality_distortion: RealityDistortionEngine,
+    emergent_communication: EmergentCommunicationSystems,
+    lexicon_overlay: bool, // Debug listing of what each species' symbols mean
+    event_driven_architecture: EventDrivenArchitecture,
+    user_co_evolution: UserCoEvolutionSystem,
+    experience_adaptation: ExperienceAdaptation, // Refreshed every tick from the co-evolution beliefs
+    adaptation_inspector: bool,
+    manual_audio_mode_at: f32,   // When the user last picked an audio mode themselves
+    last_audio_adaptation: f32,  // When adaptation last switched the audio mode
+
+    // Phase 5: Consciousness Multiplication
+    consciousness_multiplication: ConsciousnessMultiplicationSystem,
+    warfare_overlay: bool, // Territory fields, conflict fronts and extinction fading
+    pheromone_overlay: bool, // Species scent trails
+    force_overlay: bool,     // Streamlines of the world's force fields
+    field_view: FieldView,   // How the environmental consciousness field is drawn
+
+    // Phase 6: PSYCHEDELIC AUDIO CONSCIOUSNESS
+    audio_consciousness: Option<AudioConsciousnessEngine>,
+    audio_analysis_data: AudioAnalysisData,
+    external_audio: ExternalSource, // What `D` listens to when ducking is switched on
+
+    // CRITICAL SAFETY SYSTEMS - EPILEPSY PROTECTION
+    safety_config: SafetyConfig,
+    flash_tracker: FlashTracker,
+    zone_analyzer: ZonedLuminanceAnalyzer, // Per-region luminance budget, applied as a final pass
+    frame_analyzer: FrameAnalyzer, // Whole-frame flash analysis on the final vertex colors
+    safety_violation_count: u64,
+    last_violation_report: f32,
+    emergency_stop_requested: bool,
+
+    // Degradation notices shown on the HUD
+    audio_init_failed: bool,
+    audio_contended_seen: u64,
+    audio_glitch_until: f32,
+    beat_drop_armed: bool, // Re-armed once the beat falls back below a drop
+    #[cfg(feature = "shader-hot-reload")]
+    shader_watcher: Option<ShaderWatcher>,
+    #[cfg(feature = "shader-hot-reload")]
+    shader_error: Option<String>, // Last failed reload, cleared by the next good one
+    previous_llama_colors: HashMap<EntityId, Vec3>, // Track previous colors for luminance limiting
+
+    // Population control: extinct llamas are collected, the rest held under a cap
+    max_population: usize,
+    cull_policy: CullPolicy,
+
+    // Cursor position tracking for audio environmental responsiveness
+    cursor_position: Vec2,
+    screen_cursor: Vec2, // The cursor on the 1200x800 screen the overlays are laid out on, whatever the viewport
+    modifiers: ModifiersState, // Shift-click closes a reality tear instead of spawning
+    tear_dwell: TearDwell,     // How long the cursor has rested on a tear, widening it
+    #[cfg(feature = "attention")]
+    attention: AttentionTracker, // Where the viewer was last seen
+    #[cfg(feature = "attention")]
+    attention_estimator: AttentionEstimator, // Turns camera frames handed in by the host into readings
+    #[cfg(feature = "attention")]
+    attention_recorded_at: f32, // When the viewer position was last noted for co-evolution
+
+    // The llama the observer is driving, if any
+    possession: Option<PossessionControls>,
+
+    // Photo mode: the world is frozen while a shot is composed
+    photo: Option<PhotoMode>,
+    photo_capture_pending: bool, // Save a photo at the next render
+    photo_directory: PathBuf,
+
+    // Garden editor: the world is paused while zones, crystals and walls are placed
+    garden: Option<GardenEditor>,
+    garden_layout: Option<PathBuf>, // Loaded as the starting environment; the editor saves to it
+
+    // Resets non-finite llama values between subsystems and names the one that produced them
+    numeric_guard: NumericGuard,
+
+    // Recent history: snapshots to rewind through, and the live world set aside while scrubbing
+    history: WorldHistory,
+    scrubber: Option<Scrubber>,
+    crash_recorder: Option<CrashRecorder>, // Black box for crash reports, and the autosave; None without a crash directory
+
+    // Community mods: what loaded, and the scripts that react to the organism
+    loaded_mods: Vec<ModManifest>,
+    mod_scripts: Option<ScriptHost>,
+
+    // Names and life stories of every llama, written out as a saga at the end of the session
+    chronicle: Chronicle,
+    chronicle_inspector: bool,
+    saga_path: Option<PathBuf>,
+
+    // Population samples and notable events, written out as a markdown journal at the end of the session
+    seed: u64,
+    journal: ObservationJournal,
+    journal_directory: Option<PathBuf>,
+
+    // Oscilloscope ring and scrolling spectrogram of the synthesizer's output
+    sound_overlay: bool,
+    spectrogram: Spectrogram,
+
+    // Per-subsystem frame timings, optionally drawn as a stacked bar
+    profiler: FrameProfiler,
+    profiler_overlay: bool,
+
+    // Audio-visual sync: visuals follow the beat delayed by the calibrated audio latency
+    av_sync: AvSyncCalibrator,
+    visual_beat: BeatDelayLine,
+    visual_beat_intensity: f32,
+    heard_beat_visuals: bool, // Visuals follow onsets detected in the audio output rather than the beat engine
+
+    // Events for embedding apps, drained through the public API
+    pending_events: Vec<BloomEvent>,
+    event_bus: EventBus, // Every typed chaos event, fanned out to subscribers
+    diplomacy_events: Vec<DiplomacyEvent>, // From the latest tick, for the API's diplomacy hooks
+
+    // Guided first run: prompts that wait for real input and chaos events
+    locale: Locale, // Language of every text a person reads
+    tutorial: Option<Tutorial>,
+    tutorial_events: Option<Receiver<ChaosEvent>>,
+
+    // Rebindable hotkeys and the F12 editor for them
+    key_bindings: KeyBindings,
+    key_bindings_file: Option<PathBuf>, // Saved to when the editor closes
+    key_editor: Option<KeyBindingEditor>,
+    tuning_panel: Option<TuningPanel>, // Chaos event probability sliders
+
+    // Live signals routed to spawn rate, hue, distortion and filter cutoff
+    modulation: ModMatrix,
+    modulation_file: Option<PathBuf>, // Saved to when the editor closes
+    mod_editor: Option<ModMatrixEditor>,
+    mod_sources: ModSources,
+    mod_outputs: ModOutputs,
+    last_cursor: Vec2,
+    cursor_speed: f32,    // Pixels per second, smoothed
+    mod_spawn_debt: f32,  // Fractional llamas the spawn-rate destination has accrued
+
+    // Simulated minutes run behind a progress screen before the first frame
+    warm_start: Option<WarmStart>,
+
+    // Low-power ambient mode after a stretch without user input
+    idle: IdleMonitor,
+    max_fps: Option<u32>, // CPU frame-rate cap, None = present-mode limited
+
+    // Self-running exhibit while nobody is at the controls
+    attract_mode: AttractMode,
+    attract: Option<AttractDirector>,
+    attract_viewport: Viewport, // Restored when a visitor takes over
+}
+
+/// Bound on queued embedding events if the host never drains them
+const MAX_PENDING_EVENTS: usize = 1024;
+/// How long the HUD keeps showing an audio dropout
+const AUDIO_GLITCH_NOTICE_SECONDS: f32 = 3.0;
+/// Adaptation leaves the audio mode alone this long after the user picks one
+const MANUAL_AUDIO_MODE_HOLD_SECONDS: f32 = 120.0;
+/// Shortest time between two adaptive audio mode switches
+const AUDIO_ADAPTATION_INTERVAL_SECONDS: f32 = 30.0;
+/// Step of the adaptation strength keys
+const ADAPTATION_STRENGTH_STEP: f32 = 0.1;
+/// Seconds between refreshes of the status remote clients see
+const REMOTE_STATUS_INTERVAL: f32 = 0.25;
+/// World units around the view where llamas are still drawn, so none pop in at the edges
+const LOD_CULL_MARGIN: f32 = 40.0;
+/// Llamas a second a full spawn-rate modulation adds
+const MOD_SPAWNS_PER_SECOND: f32 = 2.0;
+/// Hue rotation of a full hue-offset modulation, in degrees
+const MOD_HUE_DEGREES: f32 = 180.0;
+/// Reality distortion added by a full distortion modulation
+const MOD_DISTORTION: f32 = 0.5;
+/// Cursor speed in pixels a second that reads as 1 on the cursor-speed source
+const FAST_CURSOR_SPEED: f32 = 2000.0;
+/// Share of the gap to the latest cursor speed closed each tick
+const CURSOR_SPEED_SMOOTHING: f32 = 0.1;
+
+impl ChaosEngine {
+    pub async fn new(window: std::sync::Arc<Window>, bloom_config: &BloomConfig) -> Result<Self> {
+        // Seed before anything draws random numbers so runs are reproducible; an
+        // unseeded run draws its own seed so the journal can record it
+        let seed = bloom_config.seed.unwrap_or_else(|| fastrand::u64(..));
+        fastrand::seed(seed);
+
+        // Mods layer their species over the configured ones before anything reads them
+        let mods = bloom_config.mods_directory.as_deref().map(ModSet::discover).unwrap_or_default();
+        let modded_config;
+        let bloom_config = if mods.is_empty() {
+            bloom_config
+        } else {
+            let mut config = bloom_config.clone();
+            mods.apply(&mut config);
+            modded_config = config;
+            &modded_config
+        };
+
+        let size = window.inner_size();
+
+        let instance = Instance::new(InstanceDescriptor {
+            backends: Backends::all(),
+            ..Default::default()
+        });
+
+        let surface = instance.create_surface(window)?;
+
+        let adapter = instance
+            .request_adapter(&RequestAdapterOptions {
+                power_preference: PowerPreference::HighPerformance,
+                compatible_surface: Some(&surface),
+                force_fallback_adapter: false,
+            })
+            .await
+            .ok_or(BloomError::NoAdapter)?;
+
+        let (device, queue) = adapter
+            .request_device(&DeviceDescriptor::default(), None)
+            .await?;
+
+        let surface_caps = surface.get_capabilities(&adapter);
+        let surface_format = surface_caps.formats.first().copied().ok_or(BloomError::NoSurfaceFormat)?;
+
+        let config = SurfaceConfiguration {
+            usage: TextureUsages::RENDER_ATTACHMENT,
+            format: surface_format,
+            width: size.width,
+            height: size.height,
+            present_mode: select_present_mode(bloom_config.present_mode, &surface_caps.present_modes),
+            alpha_mode: surface_caps.alpha_modes.first().copied().unwrap_or(CompositeAlphaMode::Auto),
+            view_formats: vec![],
+            desired_maximum_frame_latency: 2,
+        };
+        surface.configure(&device, &config);
+
+        // Create uniform buffer and bind group for psychedelic shader
+        let uniforms = PsychedelicUniforms {
+            time: 0.0,
+            reality_distortion: 0.0,
+            consciousness_level: 1.0,
+            beat_intensity: 0.0,
+            screen_resolution: [size.width as f32, size.height as f32],
+            beat_frequency: 1.0,
+            cosmic_phase: 0.0,
+        };
+
+        let uniform_buffer = device.create_buffer(&BufferDescriptor {
+            label: Some("Uniform Buffer"),
+            size: std::mem::size_of::<PsychedelicUniforms>() as u64,
+            usage: BufferUsages::UNIFORM | BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        // The species skin atlas rides along with the uniforms; themes that ignore it never sample it
+        let skin_texture = SkinTexture::new(&device, &queue, SkinAtlas::generate(SKIN_SEED), bloom_config.species_skins);
+        let [skin_atlas_entry, skin_sampler_entry] = SkinTexture::layout_entries(1);
+        let uniform_bind_group_layout = device.create_bind_group_layout(&BindGroupLayoutDescriptor {
+            label: Some("Uniform Bind Group Layout"),
+            entries: &[
+                BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: ShaderStages::VERTEX | ShaderStages::FRAGMENT,
+                    ty: BindingType::Buffer {
+                        ty: BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                skin_atlas_entry,
+                skin_sampler_entry,
+            ],
+        });
+
+        let [skin_atlas_binding, skin_sampler_binding] = skin_texture.bind_group_entries(1);
+        let uniform_bind_group = device.create_bind_group(&BindGroupDescriptor {
+            label: Some("Uniform Bind Group"),
+            layout: &uniform_bind_group_layout,
+            entries: &[
+                BindGroupEntry {
+                    binding: 0,
+                    resource: uniform_buffer.as_entire_binding(),
+                },
+                skin_atlas_binding,
+                skin_sampler_binding,
+            ],
+        });
+        info!(target: "render", "🦙 Grew {} species skins into a {}x{} atlas", SKIN_SLOTS, SkinAtlas::width(), SkinAtlas::height());
+
+        let render_pipeline_layout = device.create_pipeline_layout(&PipelineLayoutDescriptor {
+            label: Some("Render Pipeline Layout"),
+            bind_group_layouts: &[&uniform_bind_group_layout],
+            push_constant_ranges: &[],
+        });
+
+        let mut theme_pipelines = ThemePipelines::new(&device, render_pipeline_layout, config.format, bloom_config.theme);
+        for (mod_id, theme, source) in mods.theme_shaders() {
+            // A mod's shader only replaces the built-in one once it validates
+            device.push_error_scope(ErrorFilter::Validation);
+            let pipeline = theme_pipelines.build(&device, theme, source);
+            match device.pop_error_scope().await {
+                None => {
+                    info!(target: "render", "🧩 {} now draws {}", mod_id, theme.name());
+                    theme_pipelines.replace(theme, pipeline);
+                }
+                Some(e) => warn!(target: "render", "🧩 {}'s {} failed to compile, keeping the built-in one:\n{}", mod_id, theme.shader_file(), e),
+            }
+        }
+        let distortion_pass = DistortionPass::new(&device, config.format);
+        let dust_pass = DustPass::new(&device, config.format);
+        let crystal_pass = CrystalPass::new(&device, config.format);
+        let lighting_pass = LightingPass::new(&device, config.format);
+        let grading_pass = GradingPass::new(&device, config.format);
+        let mut color_grade = ColorGrade::new(&bloom_config.safety);
+        for (mood, path) in &bloom_config.grade_luts {
+            match load_grade_lut(path) {
+                Ok(lut) => {
+                    color_grade.set_look(*mood, &lut);
+                    info!(target: "render", "🎨 {} look from {}", mood.name(), path.display());
+                }
+                Err(e) => warn!(target: "render", "🎨 Keeping the built-in {} look: {}", mood.name(), e),
+            }
+        }
+
+        // Initialize dynamic buffer management system
+        let buffer_config = BufferConfig {
+            initial_capacity: 250_000,
+            max_capacity: 2_000_000, // Increased max capacity with safety limits
+            growth_factor: 1.5,
+            usage_history_frames: 60,
+            resize_threshold: 0.8,
+        };
+
+        let dynamic_vertex_buffer = DynamicVertexBuffer::new(
+            buffer_config,
+            std::mem::size_of::<Vertex>() as u64
+        );
+
+        // Initialize vertex budget manager
+        let mut budget_manager = VertexBudgetManager::new(1_000_000); // Total frame budget
+        budget_manager.set_category_budget("llamas", 600_000);
+        budget_manager.set_category_budget("crystals", 200_000);
+        budget_manager.set_category_budget("effects", 200_000);
+
+        // Audio failures degrade to visual-only mode and are noted on the HUD
+        let (audio_consciousness, audio_init_failed) = if !bloom_config.audio_enabled {
+            info!(target: "audio", "🔇 Audio disabled by configuration - visual-only mode");
+            (None, false)
+        } else {
+            match open_audio_engine(bloom_config) {
+                Ok(engine) => {
+                    info!(target: "audio", "🎵 PSYCHEDELIC AUDIO CONSCIOUSNESS LAYER - INITIALIZED!");
+                    info!(target: "audio", "🔊 Maximum decibels, minimum code - Audio reality synthesis active");
+                    (Some(engine), false)
+                },
+                Err(e) => {
+                    warn!(target: "audio", "🔇 Audio engine initialization failed: {} - Continuing in visual-only mode", e);
+                    (None, true)
+                }
+            }
+        };
+
+        // Initial population cycles through the species - mix of species
+        let starting_species = SpeciesType::ALL;
+        let mut world = World::new();
+        for i in 0..bloom_config.initial_population.min(bloom_config.max_population) {
+            world.spawn(Llama::new_with_species(
+                Vec2::new(fastrand::f32() * 1200.0, fastrand::f32() * 800.0),
+                starting_species[i % starting_species.len()]
+            ));
+        }
+        let mut ecosystem = DigitalEcosystem::new(&mut world);
+        if let Some(path) = bloom_config.garden_layout.as_deref().filter(|path| path.exists()) {
+            match garden::load_layout(path) {
+                Ok(layout) => {
+                    layout.apply(&mut world, &mut ecosystem);
+                    info!(target: "app", "🌱 Garden layout from {}: {} zones, {} crystals, {} walls, {} pillars",
+                          path.display(), layout.zones.len(), layout.crystals.len(), layout.walls.len(), layout.pillars.len());
+                }
+                Err(e) => warn!(target: "app", "🌱 Keeping the random garden: {}", e),
+            }
+        }
+        ecosystem.tuning = bloom_config.chaos_tuning;
+        for &field in &bloom_config.force_fields {
+            ecosystem.force_fields.add(field);
+        }
+        let restored = bloom_config.crash_directory.as_deref().filter(|_| bloom_config.restore_autosave).and_then(load_autosave);
+        if let Some(snapshot) = &restored {
+            world = snapshot.to_world();
+            ecosystem.territory_zones = snapshot.zones.clone();
+            info!(target: "app", "🚑 Restored {} llamas from the last autosave", snapshot.population());
+        }
+        let mut event_bus = EventBus::default();
+        let crash_recorder = bloom_config.crash_directory.clone().map(|directory| {
+            CrashRecorder::install(directory, format!("{:#?}", bloom_config), event_bus.subscribe(), bloom_config.autosave_interval)
+        });
+        let journal = ObservationJournal::new(event_bus.subscribe(), seed, format!("{:#?}", bloom_config));
+        let tutorial = Tutorial::start(&bloom_config.tutorial);
+        let tutorial_events = tutorial.as_ref().map(|_| event_bus.subscribe());
+        let state_stream = bloom_config.state_stream.as_ref().map(|stream| StateStreamer::start(stream, event_bus.subscribe()));
+        let mod_scripts = Some(ScriptHost::new(mods.scripts())).filter(|scripts| !scripts.is_empty()).map(|mut scripts| {
+            if scripts.wants_events() {
+                scripts.listen(event_bus.subscribe());
+            }
+            scripts
+        });
+        let key_bindings = match &bloom_config.key_bindings_file {
+            Some(path) if path.exists() => KeyBindings::load(path).unwrap_or_else(|e| {
+                warn!(target: "app", "⌨️ {} - using the configured key bindings", e);
+                bloom_config.key_bindings.clone()
+            }),
+            _ => bloom_config.key_bindings.clone(),
+        };
+        warn_key_conflicts(&key_bindings);
+        let modulation = match &bloom_config.modulation_file {
+            Some(path) if path.exists() => modulation::load_matrix(path).unwrap_or_else(|e| {
+                warn!(target: "app", "🎛️ {} - using the configured modulation matrix", e);
+                bloom_config.modulation.clone()
+            }),
+            _ => bloom_config.modulation.clone(),
+        }.sanitized();
+        let mut idle = IdleMonitor::new(bloom_config.idle_timeout, Instant::now());
+        let attract = (bloom_config.attract == AttractMode::Always).then(|| {
+            info!(target: "app", "🎬 Attract mode - the organism runs itself until someone moves the mouse or presses a key");
+            idle.hold(true);
+            AttractDirector::new(fastrand::u64(..), WORLD_SIZE * 0.5, Viewport::full())
+        });
+
+        let warm_start = WarmStart::new(bloom_config.warm_start);
+        if warm_start.is_some() {
+            info!(target: "app", "⏩ Warming up - simulating {:.1} minutes before the first frame", bloom_config.warm_start.as_secs_f32() / 60.0);
+        }
+
+        Ok(Self {
+            instance,
+            adapter,
+            device,
+            queue,
+            config,
+            surface,
+            viewport: Viewport::full(),
+            lod: bloom_config.lod.clamped(),
+            palette: PaletteDirector::new(bloom_config.palette, fastrand::f32() * 360.0),
+            outputs: Vec::new(),
+            supported_present_modes: surface_caps.present_modes.clone(),
+            theme_pipelines,
+            distortion_pass,
+            dust_pass,
+            consciousness_dust: bloom_config.consciousness_dust,
+            crystal_pass,
+            crystal_refraction: bloom_config.crystal_refraction,
+            lighting_pass,
+            light_selection: LightSelection::new(),
+            lighting: bloom_config.lighting,
+            grading_pass,
+            color_grade,
+            color_grading: bloom_config.color_grading,
+            grade_upload_pending: true,
+            day_length: bloom_config.day_length.as_secs_f32(),
+            skin_texture,
+            dynamic_vertex_buffer,
+            budget_manager,
+
+            // Psychedelic shader uniforms
+            uniform_buffer,
+            uniform_bind_group,
+            uniforms,
+
+            world,
+            time: 0.0,
+            beat_intensity: 0.0,
+
+            // Phase 2: Advanced Beat Engine with chaos amplification
+            advanced_beat_engine: AdvancedBeatEngine::new(),
+            beat_scheduler: BeatScheduler::new(),
+            species_spawn_weights: [0.55, 0.2, 0.15, 0.1], // Favor disco llamas initially
+            homeostasis: bloom_config.homeostasis.clone().map(PopulationBalancer::new),
+            homeostasis_config: bloom_config.homeostasis.clone().unwrap_or_default(),
+            metabolism: bloom_config.metabolism.map(Metabolism::new),
+            flocking: bloom_config.flocking.clone(),
+            entropy: EntropyFeeds::start(&bloom_config.entropy_feeds),
+            remote: bloom_config.remote_control.as_ref().and_then(|config| {
+                RemoteServer::start(config, state_stream.is_some())
+                    .map_err(|e| warn!(target: "app", "📡 Remote control not started on {}: {}", config.address, e))
+                    .ok()
+            }),
+            remote_published: f32::NEG_INFINITY,
+            state_stream,
+            total_consciousness: 0.0,
+
+            // Phase 3: Ecosystem Emergence
+            ecosystem,
+
+            // Phase 4: Transcendence Protocol
+            meta_consciousness: MetaConsciousnessFramework::new(),
+            reality_distortion: RealityDistortionEngine::new(),
+            emergent_communication: EmergentCommunicationSystems::new(),
+            lexicon_overlay: false,
+            event_driven_architecture: EventDrivenArchitecture::new(),
+            user_co_evolution: {
+                let mut system = UserCoEvolutionSystem::new();
+                system.set_adaptation_strength(bloom_config.adaptation_strength);
+                system
+            },
+            experience_adaptation: ExperienceAdaptation::neutral(),
+            adaptation_inspector: false,
+            manual_audio_mode_at: f32::NEG_INFINITY,
+            last_audio_adaptation: f32::NEG_INFINITY,
+
+            // Phase 5: Consciousness Multiplication
+            consciousness_multiplication: {
+                let mut system = ConsciousnessMultiplicationSystem::new();
+                system.set_autonomous_interventions(bloom_config.autonomous_interventions);
+                system
+            },
+            warfare_overlay: bloom_config.warfare_overlay,
+            pheromone_overlay: bloom_config.pheromone_overlay,
+            force_overlay: bloom_config.force_overlay,
+            field_view: bloom_config.field_view,
+
+            // Phase 6: PSYCHEDELIC AUDIO CONSCIOUSNESS
+            audio_consciousness,
+            external_audio: bloom_config.external_audio.clone().unwrap_or(ExternalSource::Loopback),
+            audio_analysis_data: AudioAnalysisData {
+                current_environment: AudioEnvironment::Environmental,
+                bass_level: 0.0,
+                treble_level: 0.0,
+                consciousness_frequency: 432.0,
+                reality_distortion_amount: 0.0,
+                hive_mind_coherence: 0.0,
+                ducking_db: 0.0,
+                loudness_lufs: None,
+                loudness_gain_db: 0.0,
+                heard_beat: 0.0,
+                heard_onsets: 0,
+                event_voices: VoiceStats::default(),
+                output_device: String::new(),
+                device_state: AudioDeviceState::Unavailable,
+                buffer_stats: AudioBufferStats::default(),
+            },
+
+            // CRITICAL SAFETY SYSTEMS - EPILEPSY PROTECTION
+            safety_config: bloom_config.safety.clone(),
+            flash_tracker: FlashTracker::new(),
+            zone_analyzer: ZonedLuminanceAnalyzer::from_config(&bloom_config.safety),
+            frame_analyzer: FrameAnalyzer::new(bloom_config.safety.clone()),
+            safety_violation_count: 0,
+            last_violation_report: f32::NEG_INFINITY,
+            emergency_stop_requested: false,
+            audio_init_failed,
+            audio_contended_seen: 0,
+            audio_glitch_until: 0.0,
+            beat_drop_armed: true,
+            #[cfg(feature = "shader-hot-reload")]
+            shader_watcher: match ShaderWatcher::new(SHADER_DIR) {
+                Ok(watcher) => {
+                    info!(target: "render", "👁️ Watching {} for shader changes", SHADER_DIR);
+                    Some(watcher)
+                }
+                Err(e) => {
+                    warn!(target: "render", "👁️ Shader hot reload unavailable: {}", e);
+                    None
+                }
+            },
+            #[cfg(feature = "shader-hot-reload")]
+            shader_error: None,
+            previous_llama_colors: HashMap::new(),
+
+            max_population: bloom_config.max_population,
+            cull_policy: bloom_config.cull_policy,
+            cursor_position: Vec2::new(600.0, 400.0), // Start at center
+            screen_cursor: Vec2::new(600.0, 400.0),
+            modifiers: ModifiersState::empty(),
+            tear_dwell: TearDwell::default(),
+            #[cfg(feature = "attention")]
+            attention: AttentionTracker::default(),
+            #[cfg(feature = "attention")]
+            attention_estimator: AttentionEstimator::new(),
+            #[cfg(feature = "attention")]
+            attention_recorded_at: f32::NEG_INFINITY,
+            possession: None,
+            photo: None,
+            photo_capture_pending: false,
+            photo_directory: bloom_config.photo_directory.clone(),
+            garden: None,
+            garden_layout: bloom_config.garden_layout.clone(),
+            numeric_guard: NumericGuard::new(),
+            history: WorldHistory::new(),
+            crash_recorder,
+            loaded_mods: mods.packages().iter().map(|package| package.manifest.clone()).collect(),
+            mod_scripts,
+            scrubber: None,
+            chronicle: Chronicle::new(),
+            chronicle_inspector: false,
+            saga_path: bloom_config.saga_path.clone(),
+            seed,
+            journal,
+            journal_directory: bloom_config.journal_directory.clone(),
+            sound_overlay: bloom_config.sound_overlay,
+            spectrogram: Spectrogram::default(),
+            profiler: FrameProfiler::new(),
+            profiler_overlay: bloom_config.profiler_overlay,
+
+            av_sync: AvSyncCalibrator::default(),
+            visual_beat: BeatDelayLine::new(MAX_AV_OFFSET_MS as f64 / 1000.0),
+            visual_beat_intensity: 0.0,
+            heard_beat_visuals: bloom_config.heard_beat_visuals,
+
+            pending_events: Vec::new(),
+            event_bus,
+            diplomacy_events: Vec::new(),
+            locale: bloom_config.locale.clone(),
+            tutorial,
+            tutorial_events,
+            key_bindings,
+            key_bindings_file: bloom_config.key_bindings_file.clone(),
+            key_editor: None,
+            tuning_panel: None,
+            modulation,
+            modulation_file: bloom_config.modulation_file.clone(),
+            mod_editor: None,
+            mod_sources: ModSources::default(),
+            mod_outputs: ModOutputs::default(),
+            last_cursor: Vec2::ZERO,
+            cursor_speed: 0.0,
+            mod_spawn_debt: 0.0,
+            warm_start,
+            idle,
+            attract_mode: bloom_config.attract,
+            attract,
+            attract_viewport: Viewport::full(),
+            max_fps: bloom_config.max_fps,
+        })
+    }
+
+    /// Bundle key of a short HUD notice while running degraded, None when everything works
+    fn degradation_notice(&self) -> Option<&'static str> {
+        let audio = match &self.audio_consciousness {
+            None if self.audio_init_failed => Some("hud-audio-unavailable"),
+            None => None, // Disabled on purpose
+            Some(engine) => match engine.device_state() {
+                AudioDeviceState::Reconnecting => Some("hud-audio-reconnecting"),
+                AudioDeviceState::Unavailable => Some("hud-audio-no-output"),
+                AudioDeviceState::Active if self.time < self.audio_glitch_until => Some("hud-audio-dropout"),
+                AudioDeviceState::Active => None,
+            },
+        };
+        audio.or_else(|| self.dynamic_vertex_buffer.usage_report().near_limit().then_some("hud-vertex-buffer-limit"))
+    }
+
+    /// Dim top-left listing of how well llamas understand each other and what each symbol means
+    fn push_lexicon_overlay(&self, vertices: &mut Vec<Vertex>) {
+        let lexicon = &self.emergent_communication.lexicon;
+        let color = Vec3::new(0.35, 0.45, 0.4);
+        let screen = Vec2::new(1200.0, 800.0);
+        let summary = self.locale.format("hud-lexicon", &[
+            ("understood", &format!("{:.0}", lexicon.recent_success_rate() * 100.0)),
+            ("successes", &lexicon.successes()),
+            ("attempts", &lexicon.attempts()),
+        ]);
+        text::push_text(vertices, &summary, Vec2::new(20.0, 20.0), 2.0, color, screen);
+
+        for (row, species) in SpeciesType::ALL.into_iter().enumerate() {
+            let words: Vec<String> = lexicon.entries(species).iter()
+                .map(|entry| format!("S{} {} {:.0}%", entry.symbol, entry.intent.name(), entry.confidence * 100.0))
+                .collect();
+            let line = format!("{:?}: {}", species, if words.is_empty() { "-".to_string() } else { words.join(", ") });
+            text::push_text(vertices, &line, Vec2::new(20.0, 40.0 + row as f32 * 18.0), 2.0, color, screen);
+        }
+    }
+
+    /// Dim bottom-right panel: the biography of the llama under the cursor, or the most notable lives
+    fn push_chronicle_inspector(&self, vertices: &mut Vec<Vertex>) {
+        let color = Vec3::new(0.4, 0.35, 0.45);
+        let screen = Vec2::new(1200.0, 800.0);
+        let inspected = self.world.query::<Llama>().into_iter()
+            .filter(|(_, llama)| llama.position.distance(self.cursor_position) < 150.0)
+            .min_by(|(_, a), (_, b)| a.position.distance(self.cursor_position).total_cmp(&b.position.distance(self.cursor_position)))
+            .and_then(|(entity, _)| self.chronicle.biography(entity));
+
+        let mut lines = Vec::new();
+        match inspected {
+            Some(biography) => {
+                lines.push(format!("{} THE {:?}", biography.name, biography.species));
+                if !biography.lineage.is_empty() {
+                    lines.push(self.locale.format("hud-chronicle-carries", &[("lineage", &biography.lineage.join(", "))]));
+                }
+                let story = biography.lines();
+                lines.extend(story.iter().skip(story.len().saturating_sub(CHRONICLE_LINES)).cloned());
+            }
+            None => {
+                lines.push(self.locale.format("hud-chronicle", &[("lives", &self.chronicle.len())]));
+                lines.extend(self.chronicle.notable().iter().take(CHRONICLE_LINES).map(|biography| {
+                    let fate = if biography.is_alive() { "" } else { self.locale.text("hud-chronicle-gone") };
+                    format!("{} THE {:?} {}", biography.name, biography.species, fate).trim_end().to_string()
+                }));
+            }
+        }
+        for (row, line) in lines.iter().enumerate() {
+            let width = text::text_width(line, 2.0);
+            text::push_text(vertices, line, Vec2::new(1180.0 - width, 460.0 + row as f32 * 18.0), 2.0, color, screen);
+        }
+    }
+
+    /// Dim top-right listing of the beliefs about the user and the adaptations they drive
+    fn push_adaptation_inspector(&self, vertices: &mut Vec<Vertex>) {
+        let color = Vec3::new(0.4, 0.4, 0.35);
+        let screen = Vec2::new(1200.0, 800.0);
+        let adaptation = &self.experience_adaptation;
+        let mut lines = vec![format!("ADAPTATION {:.0}%", self.user_co_evolution.adaptation_strength * 100.0)];
+        lines.extend(self.user_co_evolution.beliefs().iter().map(|belief| format!("{} {:.2} ({:.0}%)",
+            belief.preference_name.replace('_', " "), belief.preference_value, belief.confidence * 100.0)));
+        let multipliers: Vec<String> = adaptation.spawn_multipliers.iter().map(|multiplier| format!("X{:.2}", multiplier)).collect();
+        lines.push(format!("SPAWN {}", multipliers.join(" ")));
+        lines.push(format!("PALETTE {:.0} DEG, PULL {:.0}%", adaptation.palette_hue, adaptation.palette_pull * 100.0));
+        lines.push(format!("OBSERVER X{:.2}", adaptation.intervention_rate));
+
+        for (row, line) in lines.iter().enumerate() {
+            let width = text::text_width(line, 2.0);
+            text::push_text(vertices, line, Vec2::new(1180.0 - width, 20.0 + row as f32 * 18.0), 2.0, color, screen);
+        }
+    }
+
+    /// Rebuild the pipeline of any theme whose shader was edited on disk, keeping
+    /// the current one (and noting the error on the HUD) when the new source is invalid
+    #[cfg(feature = "shader-hot-reload")]
+    fn reload_changed_shaders(&mut self) {
+        let Some(watcher) = &self.shader_watcher else { return };
+        for name in watcher.changed_shaders() {
+            let Some(theme) = VisualTheme::from_shader_file(&name) else { continue };
+            let source = match watcher.read_shader(&name) {
+                Ok(source) => source,
+                Err(e) => {
+                    // Editors often replace the file in two steps; the next event retries
+                    debug!(target: "render", "👁️ Could not read {}: {}", name, e);
+                    continue;
+                }
+            };
+
+            let (device, pipelines) = (&self.device, &self.theme_pipelines);
+            match shader_reload::build_validated(device, || pipelines.build(device, theme, &source)) {
+                Ok(pipeline) => {
+                    info!(target: "render", "🎨 Reloaded {}", name);
+                    self.theme_pipelines.replace(theme, pipeline);
+                    self.shader_error = None;
+                }
+                Err(description) => {
+                    warn!(target: "render", "🎨 {} failed to compile, keeping the previous pipeline:\n{}", name, description);
+                    self.shader_error = Some(shader_reload::error_notice(&name, &description));
+                }
+            }
+        }
+    }
+
+    /// Let mod scripts see the latest events and carry out what they ask for
+    fn run_mod_scripts(&mut self) {
+        let population = self.population();
+        let Some(scripts) = &mut self.mod_scripts else { return };
+        for action in scripts.update(self.time as f64, population) {
+            match action {
+                // The population cap culls any excess on the next tick, as for other spawns
+                ScriptAction::Spawn(species, position) => {
+                    self.spawn_llama(species, position.clamp(Vec2::ZERO, WORLD_SIZE));
+                }
+                ScriptAction::Intervene(intervention) => self.intervene(intervention),
+                ScriptAction::Force(field) => self.add_force_field(field),
+            }
+        }
+    }
+
+    /// Carry out what remote clients asked for and refresh the status they see
+    fn serve_remote(&mut self) {
+        let Some(remote) = &self.remote else { return };
+        let commands = remote.drain();
+        for command in commands {
+            // An operator at a tablet counts as a visitor
+            self.wake();
+            match command {
+                RemoteCommand::Spawn { species, position, count } => {
+                    info!(target: "app", "📡 Remote: spawning {} {:?}", count, species);
+                    for _ in 0..count {
+                        let position = position.unwrap_or_else(|| Vec2::new(fastrand::f32(), fastrand::f32()) * WORLD_SIZE);
+                        self.spawn_llama(species, position.clamp(Vec2::ZERO, WORLD_SIZE));
+                    }
+                }
+                RemoteCommand::SetMode(mode) => {
+                    info!(target: "app", "📡 Remote: {} mode on the next bar", mode.to_string());
+                    self.record_audio_mode_choice(&mode);
+                    self.beat_scheduler.schedule(Quantize::Bar, BeatCue::AudioMode(mode));
+                }
+                RemoteCommand::SpeedPreset(preset) => self.set_audio_speed(SPEED_PRESETS[(preset.clamp(1, 9) - 1) as usize]),
+                RemoteCommand::Intervene(intervention) => self.intervene(intervention),
+            }
+        }
+
+        if self.time - self.remote_published >= REMOTE_STATUS_INTERVAL {
+            self.remote_published = self.time;
+            let status = self.remote_status();
+            if let Some(remote) = &self.remote {
+                remote.publish(&status);
+            }
+        }
+    }
+
+    /// Send a state stream frame when one is due, over UDP and to the remote control's `/stream` clients
+    fn stream_state(&mut self) {
+        let Some(streamer) = &mut self.state_stream else { return };
+        if let Some((sequence, packets)) = streamer.frame(&self.world, self.time) {
+            if let Some(remote) = &self.remote {
+                remote.publish_frame(sequence, packets);
+            }
+        }
+    }
+
+    fn remote_status(&self) -> RemoteStatus {
+        let warfare = &self.consciousness_multiplication.warfare_state;
+        let analysis = &self.consciousness_multiplication.meta_observer.consciousness_analysis;
+        RemoteStatus {
+            time: self.time,
+            population: self.population(),
+            species: SpeciesType::ALL.iter()
+                .map(|&species| (format!("{:?}", species), warfare.species_populations[species_index(species)]))
+                .collect(),
+            hybrids: self.hybrid_species().iter().map(|hybrid| hybrid.name.clone()).collect(),
+            stability: analysis.ecosystem_stability,
+            warfare_intensity: analysis.warfare_intensity,
+            active_conflicts: warfare.active_conflicts.len(),
+            total_consciousness: self.total_consciousness,
+            beat_intensity: self.beat_intensity,
+            idle: self.is_idle(),
+            audio: self.audio_consciousness.as_ref().map(|engine| {
+                let (controls, audio) = (engine.get_controls(), engine.get_audio_analysis());
+                RemoteAudioStatus {
+                    mode: controls.mode.to_string().to_string(),
+                    environment: format!("{:?}", audio.current_environment),
+                    speed: controls.speed,
+                    volume: controls.volume,
+                    bass_level: audio.bass_level,
+                    treble_level: audio.treble_level,
+                    consciousness_frequency: audio.consciousness_frequency,
+                    loudness_lufs: audio.loudness_lufs,
+                }
+            }),
+        }
+    }
+
+    /// Address the remote control server is listening on, if it started
+    pub fn remote_control_address(&self) -> Option<std::net::SocketAddr> {
+        self.remote.as_ref().map(RemoteServer::address)
+    }
+
+    /// Run an event whose beat boundary has come
+    fn perform_beat_cue(&mut self, cue: BeatCue) {
+        match cue {
+            BeatCue::Spawn(species, position) => {
+                self.spawn_llama(species, position);
+            }
+            BeatCue::AudioMode(mode) => {
+                if let Some(audio_engine) = &mut self.audio_consciousness {
+                    audio_engine.set_audio_mode(mode);
+                }
+            }
+            BeatCue::CycleTheme => self.cycle_visual_theme(),
+            BeatCue::Stinger(species, cue) => {
+                if let Some(audio_engine) = &mut self.audio_consciousness {
+                    audio_engine.play_cue(species, cue, 0.5);
+                }
+            }
+        }
+    }
+
+    /// Switch to the next visual theme; the vertex data is the same for all of them
+    fn cycle_visual_theme(&mut self) {
+        let theme = self.theme_pipelines.cycle();
+        info!(target: "render", "🎨 Visual theme: {}", theme.name());
+    }
+
+    pub fn visual_theme(&self) -> VisualTheme {
+        self.theme_pipelines.active()
+    }
+
+    pub fn set_visual_theme(&mut self, theme: VisualTheme) {
+        self.theme_pipelines.set_active(theme);
+    }
+
+    pub fn warfare_overlay(&self) -> bool {
+        self.warfare_overlay
+    }
+
+    /// Show or hide the territory map of the consciousness wars
+    pub fn set_warfare_overlay(&mut self, enabled: bool) {
+        self.warfare_overlay = enabled;
+        info!(target: "warfare", "🗺️ Warfare overlay {}", if enabled { "ON" } else { "OFF" });
+    }
+
+    pub fn consciousness_dust(&self) -> bool {
+        self.consciousness_dust
+    }
+
+    /// Show or hide the ambient dust field drifting under the world
+    pub fn set_consciousness_dust(&mut self, enabled: bool) {
+        self.consciousness_dust = enabled;
+        info!(target: "render", "✨ Consciousness dust {}", if enabled { "ON" } else { "OFF" });
+    }
+
+    pub fn crystal_refraction(&self) -> bool {
+        self.crystal_refraction
+    }
+
+    /// Draw crystals as refracting lenses, or as flat diamonds
+    pub fn set_crystal_refraction(&mut self, enabled: bool) {
+        self.crystal_refraction = enabled;
+        info!(target: "render", "💎 Crystal refraction {}", if enabled { "ON" } else { "OFF" });
+    }
+
+    pub fn species_skins(&self) -> bool {
+        self.skin_texture.enabled()
+    }
+
+    /// Pattern llamas with their species' skins, or draw them in flat colors
+    pub fn set_species_skins(&mut self, enabled: bool) {
+        self.skin_texture.set_enabled(&self.queue, enabled);
+        info!(target: "render", "🦙 Species skins {}", if enabled { "ON" } else { "OFF" });
+    }
+
+    pub fn lighting(&self) -> f32 {
+        self.lighting
+    }
+
+    /// How far areas no llama or crystal lights sink into shadow, 0-1; 0 turns lighting off
+    pub fn set_lighting(&mut self, strength: f32) {
+        self.lighting = params::LIGHTING_STRENGTH.clamp(strength);
+        info!(target: "render", "💡 Lighting {:.0}%", self.lighting * 100.0);
+    }
+
+    pub fn color_grading(&self) -> bool {
+        self.color_grading
+    }
+
+    /// Grade the world through the current audio environment's look, blended with night
+    pub fn set_color_grading(&mut self, enabled: bool) {
+        self.color_grading = enabled;
+        self.grade_upload_pending = true;
+        info!(target: "render", "🎨 Color grading {}", if enabled { "ON" } else { "OFF" });
+    }
+
+    /// Replace the look graded in for `mood` with a `.cube` LUT; it is still safety-limited
+    pub fn load_grade_lut(&mut self, mood: GradeMood, path: &Path) -> Result<()> {
+        let lut = load_grade_lut(path)?;
+        self.color_grade.set_look(mood, &lut);
+        self.grade_upload_pending = true;
+        info!(target: "render", "🎨 {} look from {}", mood.name(), path.display());
+        Ok(())
+    }
+
+    pub fn day_length(&self) -> Duration {
+        Duration::from_secs_f32(self.day_length)
+    }
+
+    /// How long one simulated day takes, noon to noon
+    pub fn set_day_length(&mut self, length: Duration) {
+        self.day_length = params::DAY_LENGTH_SECONDS.clamp(length.as_secs_f32());
+        info!(target: "render", "🌗 Days last {:.0} seconds", self.day_length);
+    }
+
+    pub fn pheromone_overlay(&self) -> bool {
+        self.pheromone_overlay
+    }
+
+    /// Show the scent trails species lay and follow
+    pub fn set_pheromone_overlay(&mut self, enabled: bool) {
+        self.pheromone_overlay = enabled;
+        info!(target: "app", "🐾 Pheromone overlay {}", if enabled { "ON" } else { "OFF" });
+    }
+
+    pub fn force_overlay(&self) -> bool {
+        self.force_overlay
+    }
+
+    pub fn field_view(&self) -> FieldView {
+        self.field_view
+    }
+
+    /// Draw the environmental consciousness field as a heatmap, contours or flow arrows
+    pub fn set_field_view(&mut self, view: FieldView) {
+        self.field_view = view;
+        info!(target: "app", "🌡️ Consciousness field view {}", view.name());
+    }
+
+    /// Show streamlines tracing the world's force fields
+    pub fn set_force_overlay(&mut self, enabled: bool) {
+        self.force_overlay = enabled;
+        info!(target: "app", "🌀 Force field overlay {}", if enabled { "ON" } else { "OFF" });
+    }
+
+    pub fn force_fields(&self) -> &[ForceField] {
+        self.ecosystem.force_fields.fields()
+    }
+
+    /// Place a gravity well, vortex or wind acting on llamas and dust; the oldest gives way past `MAX_FORCE_FIELDS`
+    pub fn add_force_field(&mut self, field: ForceField) {
+        self.ecosystem.force_fields.add(field);
+        info!(target: "app", "🌀 {:?} force field at ({:.0}, {:.0}), strength {:.0}", field.kind, field.position.x, field.position.y, field.strength);
+    }
+
+    pub fn clear_force_fields(&mut self) {
+        self.ecosystem.force_fields.clear();
+        info!(target: "app", "🌀 Force fields cleared");
+    }
+
+    pub fn adaptation_strength(&self) -> f32 {
+        self.user_co_evolution.adaptation_strength
+    }
+
+    /// How strongly inferred user preferences reshape the experience, 0-1
+    pub fn set_adaptation_strength(&mut self, strength: f32) {
+        self.user_co_evolution.set_adaptation_strength(strength);
+        info!(target: "app", "🧬 Adaptation strength {:.0}%", self.user_co_evolution.adaptation_strength * 100.0);
+    }
+
+    pub fn adaptation_inspector(&self) -> bool {
+        self.adaptation_inspector
+    }
+
+    pub fn set_adaptation_inspector(&mut self, enabled: bool) {
+        self.adaptation_inspector = enabled;
+        info!(target: "app", "🧬 Adaptation inspector {}", if enabled { "ON" } else { "OFF" });
+    }
+
+    /// Note an audio mode the user picked; adaptation learns from it and then leaves the mode alone for a while
+    fn record_audio_mode_choice(&mut self, mode: &AudioMode) {
+        self.manual_audio_mode_at = self.time;
+        let intensity = match mode {
+            AudioMode::Mellow => 0.0,
+            AudioMode::Active => 0.5,
+            AudioMode::Chaotic => 1.0,
+        };
+        self.user_co_evolution.record_user_action(UserAction {
+            action_type: ActionType::KeyPress,
+            timestamp: self.time as f64,
+            position: None,
+            intensity,
+            duration: 0.0,
+            context: ActionContext {
+                system_state: HashMap::new(),
+                environmental_factors: HashMap::new(),
+                user_state_indicators: HashMap::from([("audio_mode".to_string(), intensity)]),
+                visual_environment: VisualEnvironmentState {
+                    brightness_level: 0.0,
+                    flash_rate: 0.0,
+                    consciousness_visibility: 0.0,
+                    movement_intensity: 0.0,
+                    dominant_colors: Vec::new(),
+                    complexity_level: 0.0,
+                },
+                audio_environment: AudioEnvironmentState {
+                    beat_intensity: self.beat_intensity,
+                    frequency_distribution: HashMap::new(),
+                    rhythm_coherence: 0.8,
+                    harmonic_complexity: 0.0,
+                },
+                concurrent_actions: Vec::new(),
+            },
+            spatial_coordinates: None,
+        });
+    }
+
+    /// Push the current beliefs about the user into spawning, palette, audio and the observer
+    fn apply_experience_adaptation(&mut self) {
+        self.experience_adaptation = self.user_co_evolution.adaptation();
+        self.consciousness_multiplication.set_intervention_rate(self.experience_adaptation.intervention_rate);
+
+        let Some(intensity) = self.experience_adaptation.audio_intensity else { return };
+        if self.time - self.manual_audio_mode_at < MANUAL_AUDIO_MODE_HOLD_SECONDS
+            || self.time - self.last_audio_adaptation < AUDIO_ADAPTATION_INTERVAL_SECONDS {
+            return;
+        }
+        let mode = if intensity < 0.33 {
+            AudioMode::Mellow
+        } else if intensity > 0.66 {
+            AudioMode::Chaotic
+        } else {
+            AudioMode::Active
+        };
+        if let Some(audio_engine) = &mut self.audio_consciousness {
+            if audio_engine.get_controls().mode != mode {
+                info!(target: "audio", "🧬 Adapting audio mode to the user's appetite ({:.0}%)", intensity * 100.0);
+                audio_engine.set_audio_mode(mode);
+                self.last_audio_adaptation = self.time;
+            }
+        }
+    }
+
+    pub fn lexicon_overlay(&self) -> bool {
+        self.lexicon_overlay
+    }
+
+    /// Show or hide the debug listing of the evolving llama lexicon
+    pub fn set_lexicon_overlay(&mut self, enabled: bool) {
+        self.lexicon_overlay = enabled;
+        info!(target: "app", "💬 Lexicon overlay {}", if enabled { "ON" } else { "OFF" });
+    }
+
+    fn dominant_species(&self) -> Option<SpeciesType> {
+        let mut counts: HashMap<SpeciesType, usize> = HashMap::new();
+        for llama in self.world.components::<Llama>() {
+            *counts.entry(llama.species).or_insert(0) += 1;
+        }
+        counts.into_iter().max_by_key(|&(_, count)| count).map(|(species, _)| species)
+    }
+
+    /// Wake from idle mode; called for every user input event
+    pub fn wake(&mut self) {
+        if self.idle.is_idle() {
+            info!(target: "app", "👀 Input detected - waking up");
+        }
+        self.idle.record_input(Instant::now());
+        if self.attract.is_some() {
+            info!(target: "app", "👋 Visitor detected - attract mode hands over");
+            self.stop_attract();
+        }
+    }
+
+    pub fn attract_mode(&self) -> AttractMode {
+        self.attract_mode
+    }
+
+    /// When the director takes over; `AttractMode::Always` starts it straight away
+    pub fn set_attract_mode(&mut self, mode: AttractMode) {
+        self.attract_mode = mode;
+        match mode {
+            AttractMode::Off => self.stop_attract(),
+            AttractMode::Always => self.start_attract(),
+            AttractMode::AfterIdle(_) => {}
+        }
+    }
+
+    /// True while the director is running the show
+    pub fn attracting(&self) -> bool {
+        self.attract.is_some()
+    }
+
+    fn start_attract(&mut self) {
+        if self.attract.is_some() {
+            return;
+        }
+        info!(target: "app", "🎬 Attract mode - the organism runs itself until someone moves the mouse or presses a key");
+        self.attract_viewport = self.viewport;
+        self.attract = Some(AttractDirector::new(fastrand::u64(..), self.cursor_position, self.viewport));
+        self.idle.hold(true);
+    }
+
+    fn stop_attract(&mut self) {
+        if self.attract.take().is_some() {
+            self.viewport = self.attract_viewport;
+            self.idle.hold(false);
+        }
+    }
+
+    /// Start the director after enough quiet, then play its cues
+    fn update_attract(&mut self) {
+        let quiet = self.idle.idle_for(Instant::now());
+        if self.attract.is_none() && self.attract_mode.starts_after().is_some_and(|after| quiet >= after) {
+            self.start_attract();
+        }
+        let Some(director) = &mut self.attract else { return };
+        let llamas = self.world.components::<Llama>();
+        let focus = llamas.iter().max_by(|a, b| a.consciousness.total_cmp(&b.consciousness)).map(|llama| llama.position);
+        let cues = director.update(1.0 / 60.0, llamas.len(), focus);
+        self.cursor_position = director.cursor();
+        self.viewport = director.camera();
+
+        for cue in cues {
+            match cue {
+                AttractCue::Spawn(position) => {
+                    let species = self.select_spawn_species();
+                    self.beat_scheduler.schedule(Quantize::Beat, BeatCue::Spawn(species, position));
+                    self.adjust_spawn_weights(&species);
+                    self.advanced_beat_engine.add_chaos_feedback(0.5);
+                }
+                AttractCue::AudioMode(mode) => {
+                    self.beat_scheduler.schedule(Quantize::Bar, BeatCue::AudioMode(mode));
+                }
+            }
+        }
+    }
+
+    pub fn is_idle(&self) -> bool {
+        self.idle.is_idle()
+    }
+
+    /// Minimum time between frames, or `None` to render every vsync
+    pub fn frame_interval(&self) -> Option<Duration> {
+        slowest_interval(self.idle.frame_interval(), self.max_fps.map(fps_interval))
+    }
+
+    pub fn max_fps(&self) -> Option<u32> {
+        self.max_fps
+    }
+
+    pub fn set_max_fps(&mut self, max_fps: Option<u32>) {
+        let max_fps = max_fps.map(|fps| params::MAX_FPS.clamp(fps as f32) as u32);
+        self.max_fps = max_fps;
+        match max_fps {
+            Some(fps) => info!(target: "render", "⏱️ Frame rate capped at {} fps", fps),
+            None => info!(target: "render", "⏱️ Frame rate cap removed"),
+        }
+    }
+
+    pub fn present_mode(&self) -> PresentMode {
+        self.config.present_mode
+    }
+
+    /// Switch vsync behaviour; unsupported modes fall back to Fifo
+    pub fn set_present_mode(&mut self, present_mode: PresentMode) {
+        self.config.present_mode = select_present_mode(present_mode, &self.supported_present_modes);
+        self.surface.configure(&self.device, &self.config);
+        info!(target: "render", "🖥️ Present mode: {:?}", self.config.present_mode);
+    }
+
+    /// Mixer state, or `None` when audio is unavailable
+    pub fn mixer_controls(&self) -> Option<&MixerControls> {
+        self.audio_consciousness.as_ref().map(|engine| &engine.get_controls().mixer)
+    }
+
+    pub fn set_bus_gain(&mut self, bus: MixBus, gain: f32) {
+        if let Some(audio_engine) = &mut self.audio_consciousness {
+            audio_engine.set_bus_gain(bus, gain);
+        }
+    }
+
+    pub fn set_bus_muted(&mut self, bus: MixBus, muted: bool) {
+        if let Some(audio_engine) = &mut self.audio_consciousness {
+            audio_engine.set_bus_muted(bus, muted);
+        }
+    }
+
+    /// Duck the synth under music from `source`; does nothing when audio is unavailable
+    pub fn enable_ducking(&mut self, source: ExternalSource) -> Result<()> {
+        if let Some(audio_engine) = &mut self.audio_consciousness {
+            audio_engine.enable_ducking(&source)?;
+        }
+        self.external_audio = source;
+        Ok(())
+    }
+
+    pub fn disable_ducking(&mut self) {
+        if let Some(audio_engine) = &mut self.audio_consciousness {
+            audio_engine.disable_ducking();
+        }
+    }
+
+    /// Device the synth is ducking under, if any
+    pub fn ducking_source(&self) -> Option<&str> {
+        self.audio_consciousness.as_ref().and_then(|engine| engine.ducking_source())
+    }
+
+    pub fn set_ducking_settings(&mut self, settings: DuckingSettings) {
+        if let Some(audio_engine) = &mut self.audio_consciousness {
+            audio_engine.get_controls_mut().ducking = params::clamp_ducking(settings);
+        }
+    }
+
+    pub fn set_loudness_settings(&mut self, settings: LoudnessSettings) {
+        let settings = params::clamp_loudness(settings);
+        info!(target: "audio", "🔊 Loudness target {:.1} LUFS{}, true-peak ceiling {:.1} dBTP",
+              settings.target_lufs, if settings.normalize { "" } else { " (normalization off)" }, settings.true_peak_ceiling_db);
+        if let Some(audio_engine) = &mut self.audio_consciousness {
+            audio_engine.get_controls_mut().loudness = settings;
+        }
+    }
+
+    pub fn output_loudness(&self) -> Option<f32> {
+        self.audio_consciousness.as_ref().and_then(|engine| engine.get_audio_analysis().loudness_lufs)
+    }
+
+    pub fn heard_beat_visuals(&self) -> bool {
+        self.heard_beat_visuals
+    }
+
+    /// Let the visuals pulse with onsets heard in the audio output, or with the beat engine
+    pub fn set_heard_beat_visuals(&mut self, enabled: bool) {
+        self.heard_beat_visuals = enabled;
+        info!(target: "audio", "👂 Visuals follow {}", if enabled { "the beat heard in the output" } else { "the beat engine" });
+    }
+
+    /// The onset envelope of the output while visuals follow it and audio is actually playing
+    fn heard_beat(&self) -> Option<f32> {
+        let engine = self.audio_consciousness.as_ref().filter(|_| self.heard_beat_visuals)?;
+        let playing = engine.get_controls().enabled && engine.device_state() == AudioDeviceState::Active;
+        playing.then_some(self.audio_analysis_data.heard_beat)
+    }
+
+    pub fn environment_crossfade(&self) -> Duration {
+        let seconds = self.audio_consciousness.as_ref()
+            .map_or(params::ENVIRONMENT_CROSSFADE_SECONDS.default, |engine| engine.get_controls().environment_crossfade);
+        Duration::from_secs_f32(seconds)
+    }
+
+    pub fn loaded_mods(&self) -> Vec<ModManifest> {
+        self.loaded_mods.clone()
+    }
+
+    pub fn set_environment_crossfade(&mut self, duration: Duration) {
+        if let Some(audio_engine) = &mut self.audio_consciousness {
+            audio_engine.get_controls_mut().environment_crossfade = params::ENVIRONMENT_CROSSFADE_SECONDS.clamp(duration.as_secs_f32());
+        }
+    }
+
+    pub fn surround(&self) -> SurroundConfig {
+        self.audio_consciousness.as_ref().map_or_else(SurroundConfig::default, |engine| *engine.surround())
+    }
+
+    /// Route species to their own output channels; does nothing when audio is unavailable
+    pub fn set_surround(&mut self, config: SurroundConfig) -> Result<()> {
+        if let Some(audio_engine) = &mut self.audio_consciousness {
+            audio_engine.set_surround(config)?;
+        }
+        Ok(())
+    }
+
+    fn toggle_ducking(&mut self) {
+        if self.ducking_source().is_some() {
+            self.disable_ducking();
+        } else if let Err(e) = self.enable_ducking(self.external_audio.clone()) {
+            warn!(target: "audio", "🎧 Cannot listen to {:?}: {}", self.external_audio, e);
+        }
+    }
+
+    pub fn population(&self) -> usize {
+        self.world.count::<Llama>()
+    }
+
+    /// Spawn a llama of a given species at a world position, culling first if at the cap
+    pub fn spawn_llama(&mut self, species: SpeciesType, position: Vec2) -> EntityId {
+        let culled = population::enforce_population_cap(
+            &mut self.world,
+            self.max_population.saturating_sub(1),
+            self.cull_policy,
+        );
+        self.record_despawns(culled);
+
+        let entity = self.world.spawn(Llama::new_with_species(position, species));
+        self.event_bus.publish(ChaosEvent::LlamaSpawned { entity, species, position });
+        self.push_event(BloomEvent::LlamaSpawned { entity, species, position });
+        if let Some(audio_engine) = &mut self.audio_consciousness {
+            audio_engine.play_cue(species, SampleCue::Spawn, 0.6);
+        }
+        entity
+    }
+
+    /// Remove a llama on request of the embedding app
+    pub fn despawn_llama(&mut self, entity: EntityId) -> bool {
+        match population::despawn_llama(&mut self.world, entity, DespawnReason::Removed) {
+            Some(despawned) => {
+                self.record_despawns(vec![despawned]);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Drop per-llama render state and announce removals
+    fn record_despawns(&mut self, despawned: Vec<Despawned>) {
+        self.chronicle.record_deaths(&despawned, self.time);
+        let echoes = spawn_echoes(&mut self.world, &despawned, &mut self.event_bus);
+        let strongest = echoes.iter()
+            .filter_map(|&echo| self.world.get_component::<LlamaEcho>(echo))
+            .map(|echo| echo.consciousness)
+            .reduce(f32::max);
+        if let (Some(consciousness), Some(audio_engine)) = (strongest, &mut self.audio_consciousness) {
+            audio_engine.handle_chaos_event(&CompatChaosEvent::DeathEcho { consciousness });
+        }
+        for removed in despawned {
+            self.previous_llama_colors.remove(&removed.entity);
+            self.event_bus.publish(removed.event());
+            self.push_event(BloomEvent::LlamaDespawned {
+                entity: removed.entity,
+                species: removed.species,
+                position: removed.position,
+                reason: removed.reason,
+            });
+        }
+    }
+
+    fn push_event(&mut self, event: BloomEvent) {
+        if self.pending_events.len() >= MAX_PENDING_EVENTS {
+            self.pending_events.remove(0);
+        }
+        self.pending_events.push(event);
+    }
+
+    pub fn drain_events(&mut self) -> std::vec::Drain<'_, BloomEvent> {
+        self.pending_events.drain(..)
+    }
+
+    pub fn subscribe(&mut self) -> Receiver<ChaosEvent> {
+        self.event_bus.subscribe()
+    }
+
+    /// Diplomacy events from the latest tick only
+    pub fn diplomacy_events(&self) -> &[DiplomacyEvent] {
+        &self.diplomacy_events
+    }
+
+    /// Route window input to the matching handler
+    pub fn handle_window_event(&mut self, event: &WindowEvent) {
+        // While warming up only Escape and Space do anything
+        if self.warm_start.is_some() {
+            match event {
+                WindowEvent::Resized(physical_size) => self.resize(*physical_size),
+                WindowEvent::KeyboardInput { event, .. } if event.state == ElementState::Pressed => {
+                    match BoundKey::from_key(&event.logical_key) {
+                        Some(BoundKey::Named(NamedKey::Escape)) => self.perform_action(Action::EmergencyStop),
+                        Some(BoundKey::Named(NamedKey::Space)) => self.skip_warm_start(),
+                        _ => {}
+                    }
+                }
+                _ => {}
+            }
+            return;
+        }
+
+        if matches!(event, WindowEvent::MouseInput { .. } | WindowEvent::CursorMoved { .. }
+                         | WindowEvent::KeyboardInput { .. } | WindowEvent::MouseWheel { .. }) {
+            self.wake();
+        }
+
+        match event {
+            WindowEvent::Resized(physical_size) => self.resize(*physical_size),
+            WindowEvent::MouseInput { state, button, .. } => self.handle_click(*button, *state),
+            WindowEvent::CursorMoved { position, .. } => self.handle_cursor_moved(*position),
+            WindowEvent::KeyboardInput { event, .. } => self.handle_keyboard(event),
+            WindowEvent::MouseWheel { delta, .. } => self.handle_mouse_wheel(*delta),
+            WindowEvent::ModifiersChanged(modifiers) => self.modifiers = modifiers.state(),
+            _ => {}
+        }
+    }
+
+    pub fn resize(&mut self, new_size: winit::dpi::PhysicalSize<u32>) {
+        if new_size.width > 0 && new_size.height > 0 {
+            self.config.width = new_size.width;
+            self.config.height = new_size.height;
+            self.surface.configure(&self.device, &self.config);
+        }
+    }
+
+    /// Make the meta-observer intervene now
+    pub fn intervene(&mut self, intervention: ObserverIntervention) {
+        self.consciousness_multiplication.intervene(&mut self.world, intervention, &mut self.event_bus);
+    }
+
+    pub fn autonomous_interventions(&self) -> bool {
+        self.consciousness_multiplication.autonomous_interventions()
+    }
+
+    /// Off leaves the run purely emergent; manual interventions still work
+    pub fn set_autonomous_interventions(&mut self, enabled: bool) {
+        self.consciousness_multiplication.set_autonomous_interventions(enabled);
+        info!(target: "warfare", "👁️ Autonomous meta-observer interventions {}", if enabled { "ON" } else { "OFF" });
+    }
+
+    pub fn photo_mode(&self) -> bool {
+        self.photo.is_some()
+    }
+
+    /// Freeze the world and hand the camera to the user, or resume where it stopped
+    pub fn set_photo_mode(&mut self, enabled: bool) {
+        if enabled == self.photo_mode() {
+            return;
+        }
+        if enabled {
+            self.garden = None;
+            self.photo = Some(PhotoMode::new(self.viewport));
+            info!(target: "render", "📷 Photo mode - WASD/arrows pan, Z/X or the wheel zoom, [ ] exposure, , . palette, R reset, Enter saves, F10 resumes");
+        } else {
+            self.photo = None;
+            self.photo_capture_pending = false;
+            info!(target: "render", "📷 Photo mode off - the world moves again");
+        }
+    }
+
+    pub fn garden_editor(&self) -> bool {
+        self.garden.is_some()
+    }
+
+    /// Pause the world to design its zones, crystals and walls, or let it run again
+    pub fn set_garden_editor(&mut self, open: bool) {
+        if open == self.garden_editor() {
+            return;
+        }
+        if open {
+            self.release_llama();
+            self.set_photo_mode(false);
+            self.set_history_scrubbing(false);
+            self.garden = Some(GardenEditor::default());
+            info!(target: "app", "🌱 Garden editor - click places with the current tool, Tab switches tools, the wheel resizes zones, Enter saves");
+        } else {
+            self.garden = None;
+            info!(target: "app", "🌱 Garden editor closed - the llamas move in");
+        }
+    }
+
+    /// Write the current zones, crystals and walls to the garden layout file
+    fn save_garden(&self) {
+        let Some(path) = &self.garden_layout else {
+            warn!(target: "app", "🌱 No garden layout file to save to");
+            return;
+        };
+        let layout = GardenLayout::capture(&self.world, &self.ecosystem);
+        match garden::save_layout(&layout, path) {
+            Ok(()) => info!(target: "app", "🌱 Garden saved to {}", path.display()),
+            Err(e) => warn!(target: "app", "🌱 {}", e),
+        }
+    }
+
+    /// Save a photo of the current framing at the next render; false outside photo mode
+    pub fn capture_photo(&mut self) -> bool {
+        self.photo_capture_pending = self.photo.is_some();
+        self.photo_capture_pending
+    }
+
+    pub fn sound_overlay(&self) -> bool {
+        self.sound_overlay
+    }
+
+    /// Show or hide the oscilloscope ring and spectrogram of the live audio
+    pub fn set_sound_overlay(&mut self, enabled: bool) {
+        self.sound_overlay = enabled;
+        self.spectrogram.clear();
+        info!(target: "audio", "🔊 Sound overlay {}", if enabled { "ON" } else { "OFF" });
+    }
+
+    /// Smoothed per-subsystem frame timings
+    pub fn frame_profile(&self) -> FrameProfile {
+        self.profiler.profile()
+    }
+
+    /// Vertex buffer capacity, usage, resizes and allocated bytes as of the last frame
+    pub fn vertex_buffer_usage(&self) -> BufferUsageReport {
+        self.dynamic_vertex_buffer.usage_report()
+    }
+
+    /// How the current frame's vertex budget splits across render categories
+    pub fn vertex_budget(&self) -> Vec<BudgetCategoryUsage> {
+        self.budget_manager.category_report()
+    }
+
+    pub fn profiler_overlay(&self) -> bool {
+        self.profiler_overlay
+    }
+
+    /// Show or hide the stacked bar of per-subsystem frame timings
+    pub fn set_profiler_overlay(&mut self, enabled: bool) {
+        self.profiler_overlay = enabled;
+        info!(target: "app", "⏱️ Frame profiler {}", if enabled { "ON" } else { "OFF" });
+    }
+
+    pub fn homeostasis(&self) -> Option<&HomeostasisConfig> {
+        self.homeostasis.as_ref().map(PopulationBalancer::config)
+    }
+
+    pub fn set_homeostasis(&mut self, config: Option<HomeostasisConfig>) {
+        if let Some(config) = &config {
+            self.homeostasis_config = config.clone();
+        }
+        self.homeostasis = config.map(PopulationBalancer::new);
+        info!(target: "app", "⚖️ Population homeostasis {}", if self.homeostasis.is_some() { "ON" } else { "OFF" });
+    }
+
+    pub fn chaos_tuning(&self) -> &ChaosTuning {
+        &self.ecosystem.tuning
+    }
+
+    pub fn set_chaos_tuning(&mut self, tuning: ChaosTuning) {
+        self.ecosystem.tuning = tuning.clamped();
+        info!(target: "app", "🎲 Chaos tuning: {:?}", self.ecosystem.tuning);
+    }
+
+    pub fn chaos_tuning_panel(&self) -> bool {
+        self.tuning_panel.is_some()
+    }
+
+    /// Open or close the chaos tuning sliders; closing logs the tuning they left
+    pub fn set_chaos_tuning_panel(&mut self, open: bool) {
+        if open == self.tuning_panel.is_some() {
+            return;
+        }
+        self.tuning_panel = open.then(TuningPanel::default);
+        if open {
+            info!(target: "app", "🎲 Chaos tuning panel open - Left/Right or click to adjust");
+        } else {
+            info!(target: "app", "🎲 Chaos tuning: {:?}", self.ecosystem.tuning);
+        }
+    }
+
+    pub fn modulation(&self) -> &ModMatrix {
+        &self.modulation
+    }
+
+    pub fn set_modulation(&mut self, matrix: ModMatrix) {
+        self.modulation = matrix.sanitized();
+        info!(target: "app", "🎛️ Modulation matrix: {} routes", self.modulation.routes.len());
+    }
+
+    pub fn mod_matrix_editor(&self) -> bool {
+        self.mod_editor.is_some()
+    }
+
+    /// Open or close the modulation matrix panel; closing saves the matrix to its file
+    pub fn set_mod_matrix_editor(&mut self, open: bool) {
+        if open == self.mod_editor.is_some() {
+            return;
+        }
+        if open {
+            self.mod_editor = Some(ModMatrixEditor::default());
+            info!(target: "app", "🎛️ Modulation matrix open - Enter adds a route, Tab and Left/Right edit it");
+            return;
+        }
+        self.mod_editor = None;
+        if let Some(path) = &self.modulation_file {
+            match modulation::save_matrix(&self.modulation, path) {
+                Ok(()) => info!(target: "app", "🎛️ Modulation matrix saved to {}", path.display()),
+                Err(e) => warn!(target: "app", "🎛️ {}", e),
+            }
+        }
+    }
+
+    /// Read this tick's modulation sources, evaluate the matrix and spawn what the spawn-rate route owes
+    fn modulate(&mut self, hive_coherence: f32) {
+        let speed = (self.cursor_position - self.last_cursor).length() * 60.0;
+        self.last_cursor = self.cursor_position;
+        self.cursor_speed += (speed - self.cursor_speed) * CURSOR_SPEED_SMOOTHING;
+
+        let sources = &mut self.mod_sources;
+        sources.set(ModSource::Beat, self.visual_beat_intensity.clamp(0.0, 1.0));
+        sources.set(ModSource::HiveCoherence, hive_coherence);
+        sources.set(ModSource::CursorSpeed, (self.cursor_speed / FAST_CURSOR_SPEED).min(1.0));
+        sources.set(ModSource::AudioBass, self.audio_analysis_data.bass_level.clamp(0.0, 1.0));
+        sources.set(ModSource::TimeOfDay, night_amount(self.time, self.day_length));
+        self.mod_outputs = self.modulation.evaluate(&self.mod_sources);
+
+        // Only positive offsets spawn, and never past the population cap
+        self.mod_spawn_debt += self.mod_outputs.get(ModDestination::SpawnRate).max(0.0) * MOD_SPAWNS_PER_SECOND / 60.0;
+        while self.mod_spawn_debt >= 1.0 {
+            self.mod_spawn_debt -= 1.0;
+            if self.world.count::<Llama>() < self.max_population {
+                let species = self.select_spawn_species();
+                self.spawn_llama(species, Vec2::new(fastrand::f32(), fastrand::f32()) * WORLD_SIZE);
+            }
+        }
+    }
+
+    pub fn metabolism(&self) -> Option<&MetabolismConfig> {
+        self.metabolism.as_ref().map(Metabolism::config)
+    }
+
+    /// Turn the energy economy on with `config`, or off; turning it off leaves every llama rested
+    pub fn set_metabolism(&mut self, config: Option<MetabolismConfig>) {
+        if config.is_none() {
+            for llama in self.world.components_mut::<Llama>() {
+                llama.energy = 1.0;
+                llama.starving_time = 0.0;
+            }
+        }
+        self.metabolism = config.map(Metabolism::new);
+        info!(target: "app", "🔋 Llama metabolism {}", if self.metabolism.is_some() { "ON" } else { "OFF" });
+    }
+
+    pub fn flocking(&self) -> &FlockingConfig {
+        &self.flocking
+    }
+
+    pub fn set_flocking(&mut self, config: FlockingConfig) {
+        self.flocking = config.clamped();
+        info!(target: "app", "🐑 Flocking weights updated");
+    }
+
+    /// Take a reading from a webcam attention estimator or eye tracker
+    #[cfg(feature = "attention")]
+    pub fn observe_attention(&mut self, reading: AttentionReading) {
+        if reading.face_present != self.attention.viewer_position(self.time, Vec2::ONE).is_some() {
+            info!(target: "app", "👁️ Viewer {}", if reading.face_present { "watching" } else { "looked away" });
+        }
+        self.attention.observe(reading, self.time);
+    }
+
+    /// Estimate attention from one packed-RGB camera frame
+    #[cfg(feature = "attention")]
+    pub fn observe_camera_frame(&mut self, width: usize, height: usize, rgb: &[u8]) -> AttentionReading {
+        let reading = self.attention_estimator.estimate(width, height, rgb);
+        self.observe_attention(reading);
+        reading
+    }
+
+    pub fn entropy_feeds(&self) -> &[EntropyFeed] {
+        self.entropy.feeds()
+    }
+
+    pub fn set_entropy_feeds(&mut self, feeds: Vec<EntropyFeed>) {
+        self.entropy = EntropyFeeds::start(&feeds);
+        if feeds.is_empty() {
+            self.ecosystem.external_entropy = None;
+            info!(target: "app", "🌦️ Entropy feeds OFF");
+        }
+    }
+
+    pub fn locale(&self) -> &Locale {
+        &self.locale
+    }
+
+    /// Switch the language of the HUD, tutorial and control status
+    pub fn set_locale(&mut self, locale: Locale) {
+        info!(target: "app", "🌐 Language: {}", locale.language());
+        self.locale = locale;
+    }
+
+    /// The prompt the tutorial is waiting on, None once it is done or skipped
+    pub fn tutorial_step(&self) -> Option<TutorialStep> {
+        self.tutorial.as_ref().and_then(Tutorial::step)
+    }
+
+    pub fn skip_tutorial(&mut self) {
+        if let Some(tutorial) = &mut self.tutorial {
+            tutorial.skip();
+        }
+        self.tutorial = None;
+        self.tutorial_events = None;
+    }
+
+    /// Run the tutorial again from the first prompt
+    pub fn restart_tutorial(&mut self) {
+        self.tutorial = Tutorial::start(&TutorialMode::Always);
+        self.tutorial_events = Some(self.event_bus.subscribe());
+    }
+
+    pub fn key_bindings(&self) -> &KeyBindings {
+        &self.key_bindings
+    }
+
+    /// Replace every key binding, warning about keys two actions now share
+    pub fn set_key_bindings(&mut self, bindings: KeyBindings) {
+        warn_key_conflicts(&bindings);
+        self.key_bindings = bindings;
+    }
+
+    pub fn key_binding_editor(&self) -> bool {
+        self.key_editor.is_some()
+    }
+
+    /// Open or close the key binding editor; closing saves the bindings to the key bindings file, if any
+    pub fn set_key_binding_editor(&mut self, open: bool) {
+        if open == self.key_editor.is_some() {
+            return;
+        }
+        if open {
+            self.key_editor = Some(KeyBindingEditor::default());
+            info!(target: "app", "⌨️ Key binding editor open - Enter rebinds the selected action");
+            return;
+        }
+        self.key_editor = None;
+        if let Some(path) = &self.key_bindings_file {
+            match self.key_bindings.save(path) {
+                Ok(()) => info!(target: "app", "⌨️ Key bindings saved to {}", path.display()),
+                Err(e) => warn!(target: "app", "⌨️ {}", e),
+            }
+        }
+    }
+
+    fn advance_tutorial(&mut self, cue: TutorialCue) {
+        if let Some(tutorial) = &mut self.tutorial {
+            tutorial.observe(cue);
+            if tutorial.is_finished() {
+                self.tutorial = None;
+                self.tutorial_events = None;
+            }
+        }
+    }
+
+    /// Feed the tutorial the chaos events of the latest tick
+    fn update_tutorial(&mut self) {
+        let Some(tutorial) = &mut self.tutorial else { return };
+        tutorial.update(1.0 / 60.0);
+        if let Some(events) = &self.tutorial_events {
+            for event in events.try_iter() {
+                tutorial.observe(TutorialCue::Event(&event));
+            }
+        }
+        if tutorial.is_finished() {
+            self.tutorial = None;
+            self.tutorial_events = None;
+        }
+    }
+
+    /// Each species' share of the living population, as homeostasis last measured it
+    pub fn species_shares(&self) -> Option<[f32; SPECIES_COUNT]> {
+        self.homeostasis.as_ref().map(PopulationBalancer::shares)
+    }
+
+    pub fn chronicle_inspector(&self) -> bool {
+        self.chronicle_inspector
+    }
+
+    pub fn set_chronicle_inspector(&mut self, enabled: bool) {
+        self.chronicle_inspector = enabled;
+        info!(target: "app", "📜 Chronicle inspector {}", if enabled { "ON - point at a llama to read its life" } else { "OFF" });
+    }
+
+    pub fn llama_name(&self, entity: EntityId) -> Option<&str> {
+        self.chronicle.name(entity)
+    }
+
+    pub fn biography(&self, entity: EntityId) -> Option<&Biography> {
+        self.chronicle.biography(entity)
+    }
+
+    pub fn hybrid_species(&self) -> &[HybridSpecies] {
+        self.consciousness_multiplication.speciation.registry.hybrids()
+    }
+
+    /// The session's most notable lives as plain text
+    pub fn saga(&self) -> String {
+        self.chronicle.saga(self.time)
+    }
+
+    pub fn write_saga(&self, path: &Path) -> Result<()> {
+        std::fs::write(path, self.saga()).map_err(|source| BloomError::Saga { path: path.to_path_buf(), source })?;
+        info!(target: "app", "📜 Saga of {} lives written to {}", self.chronicle.len(), path.display());
+        Ok(())
+    }
+
+    /// Hives, packs and predation as a graph, for offline analysis
+    pub fn consciousness_graph(&self) -> ConsciousnessGraph {
+        ConsciousnessGraph::capture(&self.world, self.time)
+    }
+
+    /// Write the graph as JSON if `path` ends in `.json`, as GraphViz DOT otherwise
+    pub fn write_consciousness_graph(&self, path: &Path) -> Result<()> {
+        let graph = self.consciousness_graph();
+        graph.write(path).map_err(|source| BloomError::GraphExport { path: path.to_path_buf(), source })?;
+        info!(target: "app", "🕸️ Consciousness graph of {} llamas and {} edges written to {}", graph.nodes.len(), graph.edges.len(), path.display());
+        Ok(())
+    }
+
+    /// Dump the graph as both DOT and JSON next to the photos
+    fn export_consciousness_graph(&self) {
+        let stamp = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).map_or(0, |elapsed| elapsed.as_millis());
+        for extension in ["dot", "json"] {
+            let path = self.photo_directory.join(format!("aetherium_bloom_graph_{}.{}", stamp, extension));
+            if let Err(e) = self.write_consciousness_graph(&path) {
+                warn!(target: "app", "🕸️ Consciousness graph could not be written: {}", e);
+            }
+        }
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// The session so far as a markdown observation journal
+    pub fn journal(&self) -> String {
+        self.journal.report(&self.world, &self.chronicle, self.time)
+    }
+
+    pub fn write_journal(&self, path: &Path) -> Result<()> {
+        let write = || {
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(path, self.journal())
+        };
+        write().map_err(|source| BloomError::Journal { path: path.to_path_buf(), source })?;
+        info!(target: "app", "📓 Observation journal of {} samples written to {}", self.journal.samples().len(), path.display());
+        Ok(())
+    }
+
+    /// Write the saga and the journal to the configured places, if any; called when the session ends
+    pub fn end_session(&self) {
+        if let Some(path) = &self.saga_path {
+            if let Err(e) = self.write_saga(path) {
+                warn!(target: "app", "📜 Saga could not be written: {}", e);
+            }
+        }
+        if let Some(directory) = &self.journal_directory {
+            let stamp = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).map_or(0, |elapsed| elapsed.as_secs());
+            if let Err(e) = self.write_journal(&directory.join(format!("session_{}.md", stamp))) {
+                warn!(target: "app", "📓 Observation journal could not be written: {}", e);
+            }
+        }
+    }
+
+    pub fn history_scrubbing(&self) -> bool {
+        self.scrubber.is_some()
+    }
+
+    /// Set the live world aside and show the newest snapshot, or go back to the live world
+    pub fn set_history_scrubbing(&mut self, enabled: bool) {
+        if enabled == self.history_scrubbing() {
+            return;
+        }
+        if !enabled {
+            let (world, zones) = self.scrubber.take().map(Scrubber::into_live).unwrap_or_default();
+            self.world = world;
+            self.ecosystem.territory_zones = zones;
+            self.previous_llama_colors.clear();
+            info!(target: "app", "⏩ Back to the live organism");
+            return;
+        }
+        if self.history.is_empty() {
+            info!(target: "app", "⏪ No history recorded yet");
+            return;
+        }
+        self.release_llama();
+        self.garden = None;
+        let index = self.history.len() - 1;
+        let live_world = std::mem::take(&mut self.world);
+        let live_zones = std::mem::take(&mut self.ecosystem.territory_zones);
+        self.scrubber = Some(Scrubber::new(live_world, live_zones, index));
+        self.show_snapshot();
+        info!(target: "app", "⏪ Rewinding {} snapshots - ←/→ step, ↑/↓ a minute, Space time-lapse, Enter branches from here, T resumes", self.history.len());
+    }
+
+    /// Make the snapshot being scrubbed the live world, forgetting the future that followed it
+    pub fn branch_from_history(&mut self) -> bool {
+        let Some(scrubber) = self.scrubber.take() else { return false };
+        let index = scrubber.index();
+        let seconds_ago = self.snapshot_age(index);
+        self.history.truncate_after(index);
+        self.chronicle.branch(self.time);
+        // Caches keyed by the abandoned timeline's entities would otherwise latch onto the restored ones
+        self.consciousness_multiplication.forget_entities();
+        self.ecosystem.stagger = None;
+        self.previous_llama_colors.clear();
+        info!(target: "app", "🌱 Branched a new timeline from {:.0}s ago", seconds_ago);
+        self.push_event(BloomEvent::Branched { seconds_ago });
+        true
+    }
+
+    /// Seconds between snapshot `index` and the newest one
+    fn snapshot_age(&self, index: usize) -> f32 {
+        self.history.len().saturating_sub(index + 1) as f32 * SNAPSHOT_INTERVAL
+    }
+
+    fn show_snapshot(&mut self) {
+        let Some(scrubber) = &self.scrubber else { return };
+        let Some(snapshot) = self.history.get(scrubber.index()) else { return };
+        self.world = snapshot.to_world_from(scrubber.live_next_id());
+        self.ecosystem.territory_zones = snapshot.zones.clone();
+        self.previous_llama_colors.clear();
+    }
+
+    /// Scrub bar along the bottom: history span, the moment shown and the keys
+    fn push_scrub_bar(&self, vertices: &mut Vec<Vertex>, scrubber: &Scrubber) {
+        let screen = Vec2::new(1200.0, 800.0);
+        let (left, right, y) = (20.0, 1180.0, 700.0);
+        text::push_rect(vertices, Vec2::new(left, y), Vec2::new(right, y + 4.0), Vec3::splat(0.25), screen);
+        let progress = scrubber.index() as f32 / self.history.len().saturating_sub(1).max(1) as f32;
+        let cursor = left + (right - left) * progress;
+        text::push_rect(vertices, Vec2::new(cursor - 3.0, y - 8.0), Vec2::new(cursor + 3.0, y + 12.0), Vec3::new(0.5, 0.45, 0.6), screen);
+        let seconds_ago = self.snapshot_age(scrubber.index()) as u32;
+        let time = format!("{:02}:{:02}", seconds_ago / 60, seconds_ago % 60);
+        let keys = &self.key_bindings;
+        let line = self.locale.format(if scrubber.playing() { "hud-time-lapse" } else { "hud-rewind" }, &[
+            ("time", &time),
+            ("seek", &format!("{}/{}", keys.label(Action::StepBack), keys.label(Action::StepForward))),
+            ("play", &keys.label(Action::TimeLapse)),
+            ("branch", &keys.label(Action::Branch)),
+            ("resume", &keys.label(Action::HistoryScrubbing)),
+        ]);
+        text::push_text(vertices, &line, Vec2::new(left, y + 20.0), 2.0, Vec3::new(0.45, 0.4, 0.5), screen);
+    }
+
+    fn handle_mouse_wheel(&mut self, delta: MouseScrollDelta) {
+        let steps = match delta {
+            MouseScrollDelta::LineDelta(_, lines) => lines,
+            MouseScrollDelta::PixelDelta(position) => position.y as f32 / 50.0,
+        };
+        if let Some(editor) = &self.garden {
+            editor.resize(self.cursor_position, steps, &mut self.ecosystem);
+            return;
+        }
+        let Some(photo) = &mut self.photo else { return };
+        photo.zoom(steps);
+    }
+
+    /// Render the photo framing offscreen and save it, announcing where it went
+    fn save_photo(&mut self, world_vertices: &[Vertex], background: Vec3) {
+        let Some(photo) = &self.photo else { return };
+        let stamp = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).map_or(0, |elapsed| elapsed.as_millis());
+        let path = self.photo_directory.join(format!("aetherium_bloom_{}.png", stamp));
+        match photo::capture(&self.device, &self.queue, self.theme_pipelines.pipeline(), &self.uniform_bind_group,
+                             world_vertices, &photo.camera, background, self.config.format,
+                             (self.config.width, self.config.height), &path) {
+            Ok((width, height)) => {
+                info!(target: "render", "📸 Saved {}x{} photo to {}", width, height, path.display());
+                self.push_event(BloomEvent::PhotoSaved(path));
+            }
+            Err(e) => warn!(target: "render", "📸 Photo could not be saved: {}", e),
+        }
+    }
+
+    /// The llama the observer is driving, if any
+    pub fn possessed_llama(&self) -> Option<EntityId> {
+        self.possession.as_ref().map(|possession| possession.entity())
+    }
+
+    /// Take direct control of a llama, letting go of any other; false if `entity` is not a llama
+    pub fn possess_llama(&mut self, entity: EntityId) -> bool {
+        self.release_llama();
+        if self.scrubber.is_some() {
+            return false; // A replayed llama has no future to drive
+        }
+        if self.garden.is_some() {
+            return false; // The world holds still while it is being designed
+        }
+        let Some(llama) = self.world.get_component_mut::<Llama>(entity) else { return false };
+        llama.possess();
+        info!(target: "app", "👻 Possessing {:?} {} - WASD/arrows move, E harvest, Q tunnel, B bass drop, P lets go", llama.species, entity);
+        self.possession = Some(PossessionControls::new(entity, self.time));
+        true
+    }
+
+    /// Hand the possessed llama back to its own mind, enlightened by the experience
+    pub fn release_llama(&mut self) {
+        let Some(possession) = self.possession.take() else { return };
+        if let Some(llama) = self.world.get_component_mut::<Llama>(possession.entity()) {
+            let enlightenment = llama.release_possession(possession.seconds(self.time));
+            info!(target: "app", "✨ Released {:?} {} enlightened (+{:.2} consciousness)", llama.species, possession.entity(), enlightenment);
+        }
+    }
+
+    fn toggle_possession(&mut self) {
+        if self.possession.is_some() {
+            self.release_llama();
+            return;
+        }
+        let nearest = self.world.query::<Llama>().into_iter()
+            .filter(|(_, llama)| llama.position.distance(self.cursor_position) < 150.0)
+            .min_by(|(_, a), (_, b)| a.position.distance(self.cursor_position).total_cmp(&b.position.distance(self.cursor_position)))
+            .map(|(entity, _)| entity);
+        match nearest {
+            Some(entity) => {
+                self.possess_llama(entity);
+            }
+            None => info!(target: "app", "👻 No llama under the cursor to possess"),
+        }
+    }
+
+    /// Slot of the possessed llama in the llama component array
+    fn possessed_index(&self) -> Option<usize> {
+        let entity = self.possessed_llama()?;
+        self.world.entities_with::<Llama>().iter().position(|&id| id == entity)
+    }
+
+    /// The possessed llama reaches for the nearest crystal, with the same side effects as a wandering harvest
+    fn possessed_harvest(&mut self) {
+        let Some(index) = self.possessed_index() else { return };
+        let crystal_positions: Vec<Vec2> = self.world.components::<ConsciousnessCrystal>().iter().map(|crystal| crystal.position).collect();
+        let entity = self.world.entities_with::<Llama>()[index];
+        let (llamas, crystals) = self.world.components_mut2::<Llama, ConsciousnessCrystal>();
+        let harvested_before: Vec<f32> = crystals.iter().map(|crystal| crystal.harvested_total).collect();
+        let llama = &mut llamas[index];
+        let Some(crystal_type) = llama.harvest_nearest(crystals) else {
+            debug!(target: "app", "👻 No crystal within reach");
+            return;
+        };
+        let (species, position) = (llama.species, llama.position);
+        if crystal_type == CrystalType::Memory {
+            llama.recall_crystals(&crystal_positions);
+        }
+        if let Some((crystal, before)) = crystals.iter().zip(harvested_before).find(|(crystal, before)| crystal.harvested_total != *before) {
+            let amount = crystal.harvested_total - before;
+            self.event_bus.publish(ChaosEvent::CrystalHarvested { llama: entity, species, position: crystal.position, amount });
+        }
+        self.world.retain::<ConsciousnessCrystal>(|_, crystal| !crystal.is_depleted());
+        if crystal_type == CrystalType::Chaos {
+            let mut tear = RealityTear::new(position, TearType::Static);
+            tear.size *= 0.5;
+            let tear = self.world.spawn(tear);
+            self.event_bus.publish(ChaosEvent::TearOpened { tear, position });
+        }
+        if let Some(audio_engine) = &mut self.audio_consciousness {
+            audio_engine.play_cue(species, SampleCue::CrystalHarvest, 0.4);
+        }
+    }
+
+    fn possessed_tunnel(&mut self) {
+        let Some(index) = self.possessed_index() else { return };
+        if !self.world.components_mut::<Llama>()[index].quantum_tunnel() {
+            debug!(target: "app", "👻 Only Quantum Sheep, or llamas charged by a Quantum crystal, can tunnel");
+        }
+    }
+
+    /// A possessed vicuna drops the bass on command
+    fn possessed_bass_drop(&mut self) {
+        let Some(index) = self.possessed_index() else { return };
+        let llamas = self.world.components_mut::<Llama>();
+        if llamas[index].species != SpeciesType::BassDropVicuna {
+            debug!(target: "app", "👻 Only BassDrop Vicunas can drop the bass");
+            return;
+        }
+        thump(llamas, index, 1.0);
+        if let Some(audio_engine) = &mut self.audio_consciousness {
+            audio_engine.play_cue(SpeciesType::BassDropVicuna, SampleCue::BassDrop, 0.6);
+        }
+    }
+
+    /// Part of the world the primary window shows
+    pub fn viewport(&self) -> Viewport {
+        self.viewport
+    }
+
+    pub fn set_viewport(&mut self, viewport: Viewport) {
+        self.viewport = viewport;
+    }
+
+    pub fn level_of_detail(&self) -> LodConfig {
+        self.lod
+    }
+
+    pub fn set_level_of_detail(&mut self, lod: LodConfig) {
+        self.lod = lod.clamped();
+        info!(target: "render", "🔭 Level of detail: {:?}", self.lod);
+    }
+
+    pub fn palette(&self) -> PaletteConfig {
+        self.palette.config()
+    }
+
+    pub fn set_palette(&mut self, palette: PaletteConfig) {
+        self.palette.set_config(palette);
+        info!(target: "render", "🎨 Palette: {:?}", self.palette.config());
+    }
+
+    /// Let the llamas a lone window cannot see update in turns
+    fn update_behavior_stagger(&mut self) {
+        let every = self.lod.stagger_ticks;
+        let region = (every > 1 && self.outputs.is_empty() && !self.viewport.is_full()).then_some(self.viewport);
+        match (&mut self.ecosystem.stagger, region) {
+            (Some(stagger), Some(view)) => stagger.retarget(view.min, view.max, every),
+            (stagger, region) => *stagger = region.map(|view| BehaviorStagger::new(view.min, view.max, every)),
+        }
+    }
+
+    /// Show the world in another window too, e.g. one projector of a wall;
+    /// it is drawn every time the primary window renders
+    pub fn add_output(&mut self, window: std::sync::Arc<Window>, viewport: Viewport) -> Result<()> {
+        let output = OutputWindow::new(&self.instance, &self.adapter, &self.device, &self.config, window, viewport, self.safety_config.clone())?;
+        info!(target: "render", "🖥️ Output window {} added showing {:?}..{:?}", self.outputs.len() + 1, output.viewport().min, output.viewport().max);
+        self.outputs.push(output);
+        Ok(())
+    }
+
+    /// Close the extra output owning `window_id`; false if it is not one of them
+    pub fn remove_output(&mut self, window_id: winit::window::WindowId) -> bool {
+        let before = self.outputs.len();
+        self.outputs.retain(|output| output.id() != window_id);
+        let removed = self.outputs.len() < before;
+        if removed {
+            info!(target: "render", "🖥️ Output window closed, {} left", self.outputs.len());
+        }
+        removed
+    }
+
+    /// Resize the extra output owning `window_id`; false if it is not one of them
+    pub fn resize_output(&mut self, window_id: winit::window::WindowId, new_size: winit::dpi::PhysicalSize<u32>) -> bool {
+        match self.outputs.iter_mut().find(|output| output.id() == window_id) {
+            Some(output) => {
+                output.resize(&self.device, new_size);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Configure the engine for safety mode
+    pub fn enable_safety_mode(&mut self) {
+        self.safety_config = SafetyConfig::safe_mode();
+        self.zone_analyzer.set_budget(self.safety_config.max_luminance_change);
+        self.frame_analyzer.set_config(self.safety_config.clone());
+        for output in &mut self.outputs {
+            output.set_safety(self.safety_config.clone());
+        }
+        info!(target: "safety", "🛡️ SAFETY MODE ENABLED - Visual effects reduced to 50% intensity");
+        info!(target: "safety", "🛡️ Flash rate limited to 2 Hz, luminance changes limited to 5%");
+    }
+
+    /// Toggle the audio-visual latency calibration metronome
+    fn toggle_av_calibration(&mut self) {
+        if self.av_sync.toggle(self.time as f64) {
+            info!(target: "audio", "🎯 AV CALIBRATION: A click plays every second and the top-right marker lights up.");
+            info!(target: "audio", "🎯 Press [ and ] until the marker lands with the click, then L to finish.");
+            if let Some(audio_engine) = &self.audio_consciousness {
+                let stats = audio_engine.get_audio_analysis().buffer_stats;
+                info!(target: "audio", "🎯 Current output buffering: {:.1}ms | Offset: {:.0}ms", stats.latency_ms, self.av_sync.offset_ms());
+            } else {
+                warn!(target: "audio", "🔇 Audio engine not available - calibration clicks will be silent");
+            }
+        } else {
+            info!(target: "audio", "🎯 AV calibration finished - visual beat offset {:.0}ms", self.av_sync.offset_ms());
+        }
+    }
+
+    fn adjust_av_offset(&mut self, delta_ms: f32) {
+        let offset = self.av_sync.adjust_offset(delta_ms);
+        info!(target: "audio", "🎯 AV offset: {:.0}ms", offset);
+    }
+
+    /// Log frame-level safety violations, throttled to one report every 5 seconds
+    fn report_safety_violations(&mut self, violations: &[SafetyViolation]) {
+        if violations.is_empty() {
+            return;
+        }
+
+        self.safety_violation_count += violations.len() as u64;
+        for violation in violations {
+            self.push_event(BloomEvent::SafetyViolation(violation.clone()));
+        }
+        if self.time - self.last_violation_report < 5.0 {
+            return;
+        }
+        self.last_violation_report = self.time;
+
+        for violation in violations {
+            match violation {
+                SafetyViolation::FlashRate { flashes_per_second, limit } => {
+                    warn!(target: "safety", "⚠️ SAFETY: Frame flash rate {:.1} Hz exceeds {:.1} Hz limit", flashes_per_second, limit);
+                }
+                SafetyViolation::LuminanceDelta { delta, limit } => {
+                    warn!(target: "safety", "⚠️ SAFETY: Frame luminance jumped {:.1}% (limit {:.1}%)", delta.abs() * 100.0, limit * 100.0);
+                }
+                SafetyViolation::RedFlash { luminance_delta } => {
+                    warn!(target: "safety", "⚠️ SAFETY: Saturated red flash detected ({:.1}% luminance change)", luminance_delta.abs() * 100.0);
+                }
+            }
+        }
+        warn!(target: "safety", "🛡️ Total frame safety violations this session: {}", self.safety_violation_count);
+    }
+
+    /// Handle emergency stop request
+    pub fn request_emergency_stop(&mut self) {
+        self.emergency_stop_requested = true;
+        self.push_event(BloomEvent::EmergencyStop { active: true });
+        warn!(target: "safety", "🚨 EMERGENCY STOP ACTIVATED - All visual effects suppressed");
+    }
+
+    pub fn clear_emergency_stop(&mut self) {
+        if self.emergency_stop_requested {
+            self.emergency_stop_requested = false;
+            self.push_event(BloomEvent::EmergencyStop { active: false });
+            info!(target: "safety", "✅ Emergency stop deactivated - Visual effects resumed");
+        }
+    }
+
+    /// Check if emergency stop is active
+    pub fn is_emergency_stop_active(&self) -> bool {
+        self.emergency_stop_requested
+    }
+
+    pub fn handle_click(&mut self, _button: MouseButton, state: ElementState) {
+        // A click on a tuning slider sets it rather than spawning; the panel is drawn in screen space
+        if let Some(panel) = &mut self.tuning_panel {
+            if state == ElementState::Pressed && panel.click(self.screen_cursor, &mut self.ecosystem.tuning) != TuningInput::Ignored {
+                return;
+            }
+        }
+        // The garden editor places, draws or erases at the cursor instead of spawning
+        if let Some(editor) = &mut self.garden {
+            if state == ElementState::Pressed {
+                editor.click(self.cursor_position, &mut self.world, &mut self.ecosystem);
+            }
+            return;
+        }
+        // Shift-clicking a tear seals it
+        if state == ElementState::Pressed && self.modifiers.shift_key() {
+            if let Some(tear) = tear_at(&self.world, self.cursor_position) {
+                if let Some(rewarded) = self.ecosystem.close_tear(tear, &mut self.world, &mut self.event_bus) {
+                    info!(target: "app", "🩹 Reality tear closed - {} llamas nearby absorb its consciousness", rewarded);
+                }
+                self.tear_dwell.reset();
+                return;
+            }
+        }
+        if state == ElementState::Pressed {
+            self.advance_tutorial(TutorialCue::Click);
+
+            // Determine species based on current chaos level and spawn weights
+            let species = self.select_spawn_species();
+
+            // Spawn new llama of selected species
+            let position = Vec2::new(fastrand::f32() * 1200.0, fastrand::f32() * 800.0);
+            self.spawn_llama(species, position);
+
+            // Add chaos feedback to beat engine
+            let chaos_amount = 0.5 + self.total_consciousness * 0.1;
+            self.beat_intensity += chaos_amount;
+            self.advanced_beat_engine.add_chaos_feedback(chaos_amount);
+
+            // Phase 3: Add chaos to ecosystem
+            self.ecosystem.add_chaos(chaos_amount);
+
+            // Phase 4: Record user action for co-evolution learning, with the llama under the cursor as focus
+            let focus = self.world.components::<Llama>().iter()
+                .filter(|llama| llama.position.distance(self.cursor_position) < 150.0)
+                .min_by(|a, b| a.position.distance(self.cursor_position).total_cmp(&b.position.distance(self.cursor_position)))
+                .map(|llama| (llama.color.x, species_index(llama.species) as f32));
+            let user_action = UserAction {
+                action_type: ActionType::MouseClick,
+                timestamp: self.time as f64,
+                position: Some(self.cursor_position),
+                duration: 0.1,
+                intensity: chaos_amount,
+                spatial_coordinates: Some(Vec2::new(
+                    fastrand::f32() * 1200.0,
+                    fastrand::f32() * 800.0,
+                )),
+                context: ActionContext {
+                    system_state: {
+                        let mut state = HashMap::new();
+                        state.insert("beat_intensity".to_string(), self.beat_intensity);
+                        state.insert("consciousness_level".to_string(), self.meta_consciousness.collective_intelligence);
+                        state.insert("llama_count".to_string(), self.world.count::<Llama>() as f32);
+                        state
+                    },
+                    environmental_factors: {
+                        let mut factors = HashMap::new();
+                        factors.insert("reality_distortion".to_string(), self.reality_distortion.emergence_amplification);
+                        factors.insert("mutation_rate".to_string(), self.ecosystem.chaos_accumulation);
+                        factors.insert("temperature".to_string(), 0.5); // Neutral environmental temperature
+                        factors
+                    },
+                    user_state_indicators: {
+                        let mut indicators = HashMap::new();
+                        indicators.insert("interaction_frequency".to_string(), 0.5); // Default frequency
+                        indicators.insert("session_duration".to_string(), self.time / 60.0); // Session time in minutes
+                        indicators.insert("engagement_level".to_string(), chaos_amount);
+                        if let Some((hue, species)) = focus {
+                            indicators.insert("focus_hue".to_string(), hue);
+                            indicators.insert("focus_species".to_string(), species);
+                        }
+                        indicators
+                    },
+                    visual_environment: VisualEnvironmentState {
+                        brightness_level: (self.total_consciousness / 100.0).min(1.0),
+                        dominant_colors: self.world.components::<Llama>().iter().take(3)
+                            .flat_map(|l| vec![l.color.x, l.color.y, 0.6])
+                            .collect(),
+                        complexity_level: self.reality_distortion.emergence_amplification,
+                        movement_intensity: chaos_amount,
+                        flash_rate: 0.0, // Safe default
+                        consciousness_visibility: self.meta_consciousness.collective_intelligence,
+                    },
+                    audio_environment: AudioEnvironmentState {
+                        beat_intensity: self.beat_intensity,
+                        harmonic_complexity: self.advanced_beat_engine.harmonic_layers.len() as f32 * 0.1,
+                        frequency_distribution: HashMap::new(),
+                        rhythm_coherence: 0.8,
+                    },
+                    concurrent_actions: Vec::new(),
+                },
+            };
+            self.user_co_evolution.record_user_action(user_action);
+
+            // Phase 4: Trigger event-driven cascade for beat drop effects
+            if self.beat_intensity > 0.8 {
+                self.event_driven_architecture.trigger_beat_cascade(self.beat_intensity, self.time as f64);
+            }
+
+            // Adjust spawn weights based on species spawned
+            self.adjust_spawn_weights(&species);
+        }
+    }
+
+    /// A tear the cursor rests on widens, letting quantum sheep and glitches through;
+    /// nobody is dwelling on anything while the organism idles
+    fn widen_dwelt_tear(&mut self, audible: bool) {
+        let hovered = if self.idle.is_idle() { None } else { tear_at(&self.world, self.cursor_position) };
+        let Some(tear) = self.tear_dwell.update(hovered, 1.0 / 60.0) else { return };
+        let Some(breach) = self.ecosystem.widen_tear(tear, 1.0 / 60.0, &mut self.world) else { return };
+
+        self.spawn_llama(SpeciesType::QuantumSheep, breach);
+        let strength = self.world.get_component::<RealityTear>(tear).map_or(1.0, |tear| tear.size / TEAR_MAX_SIZE);
+        if let (true, Some(audio_engine)) = (audible, &mut self.audio_consciousness) {
+            audio_engine.handle_chaos_event(&CompatChaosEvent::RealityTear { strength, position: breach });
+        }
+    }
+
+    /// Llamas near where the viewer is wake up and the meta-observer's eye mirrors their position
+    #[cfg(feature = "attention")]
+    fn apply_attention(&mut self) {
+        let Some((viewer, confidence)) = self.attention.viewer_position(self.time, WORLD_SIZE) else { return };
+        for llama in self.world.components_mut::<Llama>() {
+            llama.consciousness += watched_gain(llama.position.distance(viewer), confidence) / 60.0;
+        }
+        let observer = &mut self.consciousness_multiplication.meta_observer;
+        observer.observer_position = observer.observer_position.lerp(viewer, 0.1 * confidence);
+        observer.observation_intensity = observer.observation_intensity.max(confidence);
+
+        if self.time - self.attention_recorded_at < 1.0 {
+            return;
+        }
+        self.attention_recorded_at = self.time;
+        self.user_co_evolution.record_user_action(UserAction {
+            action_type: ActionType::EyeTracking,
+            timestamp: self.time as f64,
+            position: Some(viewer),
+            intensity: confidence,
+            duration: 1.0,
+            context: ActionContext {
+                system_state: HashMap::new(),
+                environmental_factors: HashMap::new(),
+                user_state_indicators: HashMap::from([("attention_confidence".to_string(), confidence)]),
+                visual_environment: VisualEnvironmentState {
+                    brightness_level: 0.0,
+                    flash_rate: 0.0,
+                    consciousness_visibility: self.meta_consciousness.collective_intelligence,
+                    movement_intensity: 0.0,
+                    dominant_colors: Vec::new(),
+                    complexity_level: 0.0,
+                },
+                audio_environment: AudioEnvironmentState {
+                    beat_intensity: self.beat_intensity,
+                    frequency_distribution: HashMap::new(),
+                    rhythm_coherence: 0.8,
+                    harmonic_complexity: 0.0,
+                },
+                concurrent_actions: Vec::new(),
+            },
+            spatial_coordinates: Some(viewer),
+        });
+    }
+
+    fn select_spawn_species(&self) -> SpeciesType {
+        let chaos_level = self.total_consciousness + self.beat_intensity;
+
+        // Higher chaos = more exotic species
+        let adjusted_weights = if chaos_level > 5.0 {
+            [0.25, 0.3, 0.25, 0.2] // High chaos: more quantum sheep, hypno camels and vicunas
+        } else if chaos_level > 2.0 {
+            [0.45, 0.25, 0.18, 0.12] // Medium chaos: some exotic species
+        } else {
+            self.species_spawn_weights // Low chaos: use default weights
+        };
+        // Lean towards the species the user keeps watching
+        let adjusted_weights = self.experience_adaptation.apply_spawn_weights(adjusted_weights);
+        // Favor species under their population band, hold back those over it
+        let adjusted_weights = match &self.homeostasis {
+            Some(balancer) => balancer.apply_spawn_weights(adjusted_weights),
+            None => adjusted_weights,
+        };
+
+        let mut roll = fastrand::f32();
+        for species in SpeciesType::ALL {
+            let weight = adjusted_weights[species.to_index()];
+            if roll < weight {
+                return species;
+            }
+            roll -= weight;
+        }
+        SpeciesType::BassDropVicuna
+    }
+
+    /// Handle cursor movement for environmental audio responsiveness
+    pub fn handle_cursor_moved(&mut self, position: winit::dpi::PhysicalPosition<f64>) {
+        // Window pixels to world units, through whatever part of the world is on screen;
+        // fullscreen and high-DPI windows are larger than the 1200x800 world
+        let window_size = Vec2::new(self.config.width.max(1) as f32, self.config.height.max(1) as f32);
+        let viewport = self.photo.as_ref().map_or(self.viewport, |photo| photo.camera);
+        let share = Vec2::new(position.x as f32, position.y as f32) / window_size;
+        self.cursor_position = viewport.min + share * (viewport.max - viewport.min);
+        // Overlays cover the window at the world's size, however the world is zoomed
+        self.screen_cursor = share * WORLD_SIZE;
+
+        // Chaotic audio mechanic: rapid cursor movement triggers audio chaos
+        // This will be processed by the audio engine's spatial processor
+    }
+
+    fn adjust_spawn_weights(&mut self, spawned_species: &SpeciesType) {
+        // Slightly reduce weight of spawned species to encourage diversity
+        let spawned = spawned_species.to_index();
+        let share = 0.03 / (SPECIES_COUNT - 1) as f32;
+        for (index, weight) in self.species_spawn_weights.iter_mut().enumerate() {
+            if index == spawned {
+                *weight = (*weight - 0.03).max(0.1);
+            } else {
+                *weight += share;
+            }
+        }
+    }
+
+    /// Advance the organism one 60 Hz tick, or a frame's worth of them while warming up
+    pub fn update(&mut self) {
+        if let Some(mut warm_start) = self.warm_start.take() {
+            if warm_start.advance(|| self.tick(false)) {
+                self.finish_warm_start(&warm_start);
+            } else {
+                self.warm_start = Some(warm_start);
+            }
+            return;
+        }
+        self.tick(true);
+    }
+
+    /// Share of the warm-up simulated so far, None once the organism is live
+    pub fn warm_start_progress(&self) -> Option<f32> {
+        self.warm_start.as_ref().map(WarmStart::progress)
+    }
+
+    /// Stop warming up and show the world as it is now
+    pub fn skip_warm_start(&mut self) {
+        if let Some(warm_start) = self.warm_start.take() {
+            self.finish_warm_start(&warm_start);
+        }
+    }
+
+    fn finish_warm_start(&mut self, warm_start: &WarmStart) {
+        info!(target: "app", "🌅 Warmed up - {:.1} simulated minutes, {} llamas, {} crystals",
+              warm_start.simulated().as_secs_f32() / 60.0, self.world.count::<Llama>(), self.world.count::<ConsciousnessCrystal>());
+        self.wake();
+    }
+
+    /// One step of the world; `audible` is false while warming up so nothing is heard
+    fn tick(&mut self, audible: bool) {
+        // Photo mode holds the world still while a shot is composed, as does the garden editor
+        if self.photo.is_some() || self.garden.is_some() {
+            return;
+        }
+        // Scrubbing shows history instead of running the world
+        if let Some(scrubber) = &mut self.scrubber {
+            if scrubber.advance(1.0 / 60.0, self.history.len()) {
+                self.show_snapshot();
+            }
+            return;
+        }
+        self.profiler.restart();
+
+        self.time += 1.0 / 60.0;
+        let cosmic_time = self.time as f64;
+        self.numeric_guard.check(&mut self.world, "host input", self.time);
+
+        let was_idle = self.idle.is_idle();
+        self.idle.update(Instant::now());
+        if self.idle.is_idle() && !was_idle {
+            info!(target: "app", "😴 No input for a while - fading into idle mode");
+        }
+        self.update_attract();
+        let activity = self.idle.activity();
+
+        // Calculate total consciousness for advanced beat engine
+        self.total_consciousness = self.world.components::<Llama>().iter()
+            .map(|llama| llama.consciousness + llama.awareness_level + llama.environmental_consciousness)
+            .sum::<f32>();
+
+        // Use advanced beat engine with consciousness coupling and prime chaos
+        let previous_beat = self.beat_intensity;
+        self.beat_intensity = self.advanced_beat_engine.update(1.0 / 60.0, self.total_consciousness);
+        let beat_drop = ChaosEvent::beat_drop(previous_beat, self.beat_intensity, cosmic_time);
+        let dropped = beat_drop.is_some();
+        if let Some(drop) = beat_drop {
+            self.event_bus.publish(drop);
+        }
+
+        // Visuals see the beat as it will sound, after the calibrated audio latency
+        self.visual_beat.push(cosmic_time, self.heard_beat().unwrap_or(self.beat_intensity));
+        self.visual_beat_intensity = self.visual_beat.sample(cosmic_time - self.av_sync.offset_seconds());
+
+        // Quantized events land on the beat as it is seen and heard
+        let beat = self.advanced_beat_engine.beat_count(self.av_sync.offset_seconds());
+        for cue in self.beat_scheduler.update(beat, dropped) {
+            self.perform_beat_cue(cue);
+        }
+
+        if self.av_sync.update(cosmic_time) {
+            if let Some(audio_engine) = &mut self.audio_consciousness {
+                audio_engine.play_calibration_click();
+            }
+        }
+        self.profiler.lap(ProfileStage::BeatEngine);
+
+        // Phase 3: Update ecosystem first
+        self.ecosystem.update(1.0 / 60.0, cosmic_time, self.beat_intensity, &mut self.world, &mut self.event_bus);
+        self.widen_dwelt_tear(audible);
+        self.numeric_guard.check(&mut self.world, "ecosystem", self.time);
+        self.profiler.lap(ProfileStage::Ecosystem);
+
+        // Phase 4: Update Meta-Consciousness Framework
+        self.meta_consciousness.update(1.0 / 60.0, self.world.components::<Llama>(), cosmic_time, self.beat_intensity,
+                                       &self.ecosystem, self.world.components::<ConsciousnessCrystal>());
+
+        // Phase 4: Update Reality Distortion Engine
+        self.reality_distortion.update(1.0 / 60.0, cosmic_time, &self.meta_consciousness, self.world.components::<Llama>(), self.beat_intensity, &self.ecosystem);
+        self.profiler.lap(ProfileStage::MetaConsciousness);
+
+        // Phase 4: Update Emergent Communication Systems
+        let crystal_positions: Vec<Vec2> = self.world.components::<ConsciousnessCrystal>().iter().map(|crystal| crystal.position).collect();
+        self.emergent_communication.update(1.0 / 60.0, self.world.components::<Llama>(), &crystal_positions, &self.ecosystem,
+                                          self.meta_consciousness.collective_intelligence, cosmic_time);
+        // Understood messages change what their receivers do
+        let understandings: Vec<_> = self.emergent_communication.drain_understandings().collect();
+        let llamas = self.world.components_mut::<Llama>();
+        for understanding in understandings {
+            if let Some(llama) = llamas.get_mut(understanding.receiver) {
+                understanding.apply(llama);
+            }
+        }
+        self.numeric_guard.check(&mut self.world, "communication", self.time);
+
+        // Phase 4: Update Event-Driven Architecture
+        let llamas = self.world.components::<Llama>();
+        let user_interaction_intensity = if !llamas.is_empty() {
+            llamas.iter().map(|l| l.consciousness).sum::<f32>() / llamas.len() as f32
+        } else {
+            0.5
+        };
+        self.event_driven_architecture.update(1.0 / 60.0, self.beat_intensity,
+                                            self.meta_consciousness.collective_intelligence,
+                                            cosmic_time, user_interaction_intensity);
+
+        // Phase 4: Update User Co-Evolution System
+        let mut system_state = HashMap::new();
+        system_state.insert("beat_intensity".to_string(), self.beat_intensity);
+        system_state.insert("consciousness_level".to_string(), self.meta_consciousness.collective_intelligence);
+        system_state.insert("ecosystem_stability".to_string(), 0.8); // Simple placeholder
+        system_state.insert("visual_complexity".to_string(), self.reality_distortion.emergence_amplification);
+        self.user_co_evolution.update(1.0 / 60.0, user_interaction_intensity, &system_state, cosmic_time);
+        self.apply_experience_adaptation();
+        self.profiler.lap(ProfileStage::Communication);
+
+        // Phase 5: Update Consciousness Multiplication System - "When One Mind Becomes Legion"
+        self.consciousness_multiplication.update(1.0 / 60.0, &mut self.world, &self.ecosystem, cosmic_time as f32, self.beat_intensity, &mut self.event_bus);
+        self.numeric_guard.check(&mut self.world, "consciousness multiplication", self.time);
+        #[cfg(feature = "attention")]
+        self.apply_attention();
+        if let Some(balancer) = &mut self.homeostasis {
+            balancer.update(self.world.components_mut::<Llama>(), 1.0 / 60.0);
+        }
+        if let Some(metabolism) = &mut self.metabolism {
+            metabolism.update(self.world.components_mut::<Llama>(), &self.ecosystem, 1.0 / 60.0);
+        }
+        let hive_events: Vec<_> = self.consciousness_multiplication.drain_hive_events().collect();
+        for event in hive_events {
+            self.event_driven_architecture.publish_hive_event(&event, cosmic_time);
+            self.push_event(BloomEvent::Hive(event));
+        }
+        let emergences: Vec<_> = self.consciousness_multiplication.drain_emergences().collect();
+        for emergence in emergences {
+            self.chronicle.record_speciation(&emergence, self.time);
+            self.push_event(BloomEvent::SpeciesEmerged(emergence));
+        }
+        self.diplomacy_events = self.consciousness_multiplication.drain_diplomacy_events().collect();
+        for event in self.diplomacy_events.clone() {
+            let stinger = match event {
+                DiplomacyEvent::WarDeclared { attacker, .. } => Some((attacker, SampleCue::WarDeclared)),
+                DiplomacyEvent::TreatySigned { species: [a, _], .. } => Some((a, SampleCue::TreatySigned)),
+                _ => None,
+            };
+            if let Some((species, cue)) = stinger {
+                self.beat_scheduler.schedule(Quantize::BeatDrop, BeatCue::Stinger(species, cue));
+            }
+            self.push_event(BloomEvent::Diplomacy(event));
+        }
+
+        // Garbage-collect llamas that stayed extinct or starved, then hold the population cap
+        let mut despawned = population::collect_extinct(&mut self.world, 1.0 / 60.0);
+        if let Some(metabolism) = &self.metabolism {
+            despawned.extend(metabolism.collect_starved(&mut self.world));
+        }
+        despawned.extend(population::enforce_population_cap(&mut self.world, self.max_population, self.cull_policy));
+
+        // Decided fronts, enduring hives and mass extinctions leave zones behind
+        let resolved_fronts: Vec<Vec2> = self.consciousness_multiplication.drain_resolved_fronts().collect();
+        self.ecosystem.grow_zones_from_history(&self.world, &resolved_fronts, &despawned, cosmic_time as f32);
+        let zone_events: Vec<_> = self.ecosystem.drain_zone_events().collect();
+        for event in zone_events {
+            debug!(target: "app", "🗺️ Territory zone {:?}", event);
+            self.event_driven_architecture.publish_zone_event(&event, cosmic_time);
+            self.push_event(BloomEvent::Zone(event));
+        }
+        self.record_despawns(despawned);
+
+        // The chronicle notes births, hives, harvests, battles and absorptions
+        let absorptions: Vec<(EntityId, EntityId)> = self.consciousness_multiplication.drain_absorptions().collect();
+        self.chronicle.observe(&self.world, &resolved_fronts, &absorptions, self.time);
+        self.profiler.lap(ProfileStage::Multiplication);
+
+        // One bass drop cue per beat drop, voiced by the most populous species
+        let bass_drop = if self.beat_intensity > 0.95 && self.beat_drop_armed {
+            self.beat_drop_armed = false;
+            let thumps = bass_drop_shockwave(self.world.components_mut::<Llama>(), self.beat_intensity);
+            self.numeric_guard.check(&mut self.world, "bass drop", self.time);
+            if thumps > 0 {
+                debug!(target: "app", "💥 Bass drop - {} vicunas sent shockwaves through the herd", thumps);
+            }
+            self.dominant_species()
+        } else {
+            if self.beat_intensity < 0.8 {
+                self.beat_drop_armed = true;
+            }
+            None
+        };
+
+        let hive_coherence = hive_coherence(&self.world);
+        self.modulate(hive_coherence);
+
+        // The palette opens as the herd wakes and turns faster in a coherent hive
+        let llamas = self.world.components::<Llama>();
+        let awareness = llamas.iter().map(|llama| llama.awareness_level).sum::<f32>() / llamas.len().max(1) as f32;
+        let scheme = self.palette.scheme();
+        self.palette.update(1.0 / 60.0, awareness, hive_coherence);
+        if self.palette.scheme() != scheme {
+            debug!(target: "render", "🎨 Palette now {:?} around {:.0}°", self.palette.scheme(), self.palette.anchors()[0]);
+        }
+
+        // Phase 6: PSYCHEDELIC AUDIO CONSCIOUSNESS UPDATE - "Maximum Decibels, Minimum Code"
+        if let Some(audio_engine) = self.audio_consciousness.as_mut().filter(|_| audible) {
+            if let Some(species) = bass_drop {
+                audio_engine.play_cue(species, SampleCue::BassDrop, 0.8);
+            }
+
+            // Create beat state from advanced beat engine
+            let beat_state = crate::mathematics::BeatState {
+                is_beat_drop: self.beat_intensity > 0.8,
+                intensity: self.beat_intensity,
+                phase: self.time,
+                prime_factor: self.advanced_beat_engine.get_prime_factor() as f32,
+                cosmic_frequency: 432.0 + self.total_consciousness * 2.0,
+                tempo_bpm: self.advanced_beat_engine.primary_rhythm,
+            };
+
+            // Convert llamas to audio-compatible format
+            let llama_audio_data = audio_llamas(self.world.components::<Llama>());
+
+            // Update cursor position for environmental audio responsiveness
+            audio_engine.update_cursor_position(self.cursor_position);
+
+            // Update the full audio consciousness engine
+            audio_engine.set_idle_gain(self.idle.audio_gain());
+            audio_engine.set_filter_cutoff(filter_cutoff(self.mod_outputs.get(ModDestination::FilterCutoff)));
+            audio_engine.update(
+                cosmic_time,
+                &beat_state,
+                &llama_audio_data,
+                self.total_consciousness,
+                hive_coherence,
+            );
+
+            // Get updated audio analysis
+            self.audio_analysis_data = audio_engine.get_audio_analysis();
+
+            // The output callback played silence instead of waiting on the buffer
+            let contended = self.audio_analysis_data.buffer_stats.contended;
+            if contended > self.audio_contended_seen {
+                self.audio_contended_seen = contended;
+                self.audio_glitch_until = self.time + AUDIO_GLITCH_NOTICE_SECONDS;
+            }
+
+            // Enhanced chaos event mapping for real-time audio responsiveness
+        // TODO: Fix borrow checker issue
+
+            // Print audio consciousness status on significant events
+            if crossed_interval(self.time, 1.0 / 60.0, 15.0) {
+                debug!(target: "audio", "🎵 AUDIO CONSCIOUSNESS - Environment: {:?}, Bass: {:.2}, Consciousness: {:.1}, Frequency: {:.1}Hz",
+                         self.audio_analysis_data.current_environment,
+                         self.audio_analysis_data.bass_level,
+                         self.total_consciousness,
+                         self.audio_analysis_data.consciousness_frequency);
+            }
+        } else {
+            // Audio engine failed to initialize - continue with visual-only mode
+            if crossed_interval(self.time, 1.0 / 60.0, 60.0) {
+                debug!(target: "audio", "🔇 Audio engine unavailable - continuing in visual-only mode");
+            }
+        }
+        self.profiler.lap(ProfileStage::Audio);
+
+        if self.color_grading {
+            let mood = grade_mood(&self.audio_analysis_data.current_environment);
+            let night = night_amount(self.time, self.day_length);
+            self.grade_upload_pending |= self.color_grade.update(mood, night, 1.0 / 60.0, &self.safety_config);
+        }
+
+        // The observer steers the possessed llama, unless it was despawned meanwhile
+        if let Some((entity, direction)) = self.possession.as_ref().map(|possession| (possession.entity(), possession.direction())) {
+            match self.world.get_component_mut::<Llama>(entity) {
+                Some(llama) => llama.steer(direction),
+                None => {
+                    info!(target: "app", "👻 The possessed llama is gone - back to observing");
+                    self.possession = None;
+                }
+            }
+        }
+
+        // Update llamas with Phase 2, Phase 3, Phase 4, and Phase 5 enhancements
+        self.ecosystem.external_entropy = self.entropy.sample();
+        self.ecosystem.tempo_bpm = self.advanced_beat_engine.primary_rhythm;
+        self.update_behavior_stagger();
+        let harvesters = step_llamas(&mut self.world, &mut self.ecosystem, &self.flocking, activity / 60.0, self.beat_intensity, cosmic_time, &mut self.event_bus);
+        self.numeric_guard.check(&mut self.world, "llama behavior", self.time);
+        if let Some(audio_engine) = &mut self.audio_consciousness {
+            for species in harvesters {
+                audio_engine.play_cue(species, SampleCue::CrystalHarvest, 0.4);
+            }
+        }
+
+        // Animation follows the delayed visual beat, not the audio beat
+        let beat_phase = self.advanced_beat_engine.beat_phase(self.av_sync.offset_seconds());
+        for llama in self.world.components_mut::<Llama>() {
+            llama.update_animation(activity / 60.0, self.visual_beat_intensity, beat_phase);
+        }
+
+        // Phase 3: Check for mutations
+        apply_ecosystem_mutations(&mut self.world, &mut self.ecosystem);
+        self.numeric_guard.check(&mut self.world, "mutations", self.time);
+
+        let recorded = self.history.record_if_due(&self.world, &self.ecosystem.territory_zones, self.time);
+        if let Some(crash_recorder) = &mut self.crash_recorder {
+            crash_recorder.record(self.time, self.history.latest().filter(|_| recorded));
+        }
+        self.journal.record(&self.world, self.time);
+        self.run_mod_scripts();
+        self.serve_remote();
+        self.stream_state();
+
+        // Decay beat intensity more gradually for better chaos building
+        self.beat_intensity *= 0.98;
+
+        // Feed chaos back into the beat engine
+        let llamas = self.world.components::<Llama>();
+        let average_chaos = if !llamas.is_empty() {
+            llamas.iter()
+                .map(|llama| llama.prime_chaos_factor)
+                .sum::<f32>() / llamas.len() as f32
+        } else {
+            0.0
+        };
+
+        if average_chaos > 0.1 {
+            self.advanced_beat_engine.add_chaos_feedback(average_chaos * 0.1);
+        }
+        self.update_tutorial();
+        self.profiler.lap(ProfileStage::Llamas);
+    }
+
+    pub fn render(&mut self) -> Result<(), SurfaceError> {
+        // CRITICAL SAFETY CHECK - Emergency stop overrides everything
+        if self.emergency_stop_requested {
+            return self.render_emergency_stop();
+        }
+        if self.warm_start.is_some() {
+            return self.render_warm_start();
+        }
+
+        #[cfg(feature = "shader-hot-reload")]
+        self.reload_changed_shaders();
+
+        let output = self.surface.get_current_texture()?;
+        let view = output.texture.create_view(&TextureViewDescriptor::default());
+        self.profiler.restart();
+
+        // Update psychedelic shader uniforms
+        self.uniforms.time = self.time;
+        let modulated_distortion = self.mod_outputs.get(ModDestination::Distortion) * MOD_DISTORTION;
+        self.uniforms.reality_distortion = (self.reality_distortion.emergence_amplification + modulated_distortion).max(0.0);
+        self.uniforms.consciousness_level = self.total_consciousness;
+        self.uniforms.beat_intensity = self.visual_beat_intensity;
+        self.uniforms.screen_resolution = [self.config.width as f32, self.config.height as f32];
+        self.uniforms.beat_frequency = self.advanced_beat_engine.primary_rhythm;
+        self.uniforms.cosmic_phase = self.advanced_beat_engine.get_time_accumulator() as f32;
+
+        // Write updated uniforms to buffer
+        self.queue.write_buffer(&self.uniform_buffer, 0, bytemuck::cast_slice(&[self.uniforms]));
+
+        let llama_count = self.world.count::<Llama>();
+
+        // Start new frame for budget tracking
+        self.budget_manager.start_frame();
+
+        // Generate vertices for all llamas with Phase 2 species-enhanced visuals AND SAFETY FILTERING
+        // Estimate total vertices for predictive allocation
+        let estimated_vertices_per_llama = max_silhouette_triangle_count() * 3; // Species silhouette triangles
+        let estimated_crystal_vertices = self.world.count::<ConsciousnessCrystal>() * 6; // Crystal vertices
+        let estimated_effect_vertices = llama_count * 20; // Rough estimate for various effects
+
+        // Check budget allocations
+        let allocated_llama_vertices = self.budget_manager.check_allocation("llamas",
+                                                                          llama_count * estimated_vertices_per_llama);
+        let allocated_crystal_vertices = self.budget_manager.check_allocation("crystals", estimated_crystal_vertices);
+        let allocated_effect_vertices = self.budget_manager.check_allocation("effects", estimated_effect_vertices);
+
+        let mut vertices = Vec::new();
+        let mut crystal_instances = Vec::new();
+        let mut crystal_fallback = Vec::new();
+        // The photo camera stands in for the window's viewport while composing
+        let viewport = self.photo.as_ref().map_or(self.viewport, |photo| photo.camera);
+        if self.pheromone_overlay {
+            let background = Vec3::new(0.0, 0.0, 0.1 + self.visual_beat_intensity * 0.1);
+            push_pheromone_overlay(&mut vertices, &self.ecosystem.pheromones, background);
+        }
+        if self.field_view != FieldView::Off {
+            let background = Vec3::new(0.0, 0.0, 0.1 + self.visual_beat_intensity * 0.1);
+            push_field_overlay(&mut vertices, &self.ecosystem.consciousness_fields, self.field_view, background, self.time);
+        }
+        if self.force_overlay {
+            let background = Vec3::new(0.0, 0.0, 0.1 + self.visual_beat_intensity * 0.1);
+            push_force_overlay(&mut vertices, &self.ecosystem.force_fields, background, self.time);
+        }
+        if self.warfare_overlay {
+            let territories = species_territories(self.world.components::<Llama>());
+            let background = Vec3::new(0.0, 0.0, 0.1 + self.visual_beat_intensity * 0.1);
+            push_warfare_overlay(&mut vertices, &self.consciousness_multiplication.warfare_state, &territories, background, self.time);
+        }
+        // Detail follows how big llamas appear in the window; a lone window also skips what it cannot see,
+        // while extra outputs show other parts of the world and need all of it
+        let pixels_per_unit = self.config.width as f32 / (viewport.max.x - viewport.min.x).max(1.0);
+        let visible = self.outputs.is_empty().then_some(viewport);
+        let splatting = llama_count > self.lod.splat_population;
+        let mut splats = DensitySplats::new(self.lod.point_pixels * 2.0 / pixels_per_unit);
+        let mut full_detail = vec![false; llama_count];
+        let llama_vertex_start = vertices.len();
+        let llama_entities = self.world.entities_with::<Llama>();
+        for (llama_id, (&entity, llama)) in llama_entities.iter().zip(self.world.components::<Llama>()).enumerate() {
+            // Apply budget limits
+            if vertices.len() - llama_vertex_start >= allocated_llama_vertices {
+                debug!(target: "render", "Llama rendering limited by vertex budget at {}/{}", llama_id, llama_count);
+                break;
+            }
+            if let Some(view) = visible {
+                let margin = Vec2::splat(LOD_CULL_MARGIN);
+                if llama.position.cmplt(view.min - margin).any() || llama.position.cmpgt(view.max + margin).any() {
+                    continue;
+                }
+            }
+            // Species-specific size calculation
+            let base_size = match llama.species {
+                SpeciesType::DiscoLlama => 10.0 + llama.trip_intensity * 5.0,
+                SpeciesType::QuantumSheep => 8.0 + llama.trip_intensity * 6.0 + llama.quantum_state * 4.0,
+                SpeciesType::HypnoCamel => 12.0 + llama.trip_intensity * 4.0,
+                SpeciesType::BassDropVicuna => 11.0 + llama.trip_intensity * 3.0 + self.visual_beat_intensity * 4.0, // Swells with the bass
+            };
+
+            let consciousness_size_mod = 1.0 + llama.awareness_level * 0.5;
+            let reality_size_mod = 1.0 + llama.reality_distortion * 0.8;
+            let chaos_size_mod = 1.0 + llama.prime_chaos_factor * 0.3;
+
+            // Phase 5: Consciousness hierarchy size modifications
+            let hierarchy_size_mod = match llama.consciousness_level {
+                ConsciousnessLevel::Individual => 1.0,
+                ConsciousnessLevel::Pack => 1.3 + llama.hive_connection_strength * 0.2,
+                ConsciousnessLevel::Hive => 1.6 + llama.hive_connection_strength * 0.4,
+                ConsciousnessLevel::Meta => 2.0,
+            };
+
+            // Phase 5: Warfare participation affects size (entities grow larger during conflicts)
+            let warfare_size_mod = 1.0 + llama.warfare_participation * 0.3;
+
+            // Phase 5: Territorial dominance adds presence
+            let dominance_size_mod = 1.0 + llama.territorial_dominance * 0.2;
+
+            let size = base_size * consciousness_size_mod * reality_size_mod * chaos_size_mod
+                       * hierarchy_size_mod * warfare_size_mod * dominance_size_mod;
+            let level = self.lod.level(size * pixels_per_unit);
+
+            // Enhanced color psychology: brightness reflects consciousness
+            let mut brightness: f32 = 0.6 + llama.awareness_level * 0.4;
+
+            // Phase 5: Consciousness level affects brightness
+            brightness += match llama.consciousness_level {
+                ConsciousnessLevel::Individual => 0.0,
+                ConsciousnessLevel::Pack => 0.1,
+                ConsciousnessLevel::Hive => 0.2,
+                ConsciousnessLevel::Meta => 0.3,
+            };
+
+            // Phase 5: Warfare participation makes entities glow
+            brightness += llama.warfare_participation * 0.15;
+
+            // Phase 5: Extinction pressure causes fading
+            brightness *= 1.0 - llama.extinction_pressure * 0.5;
+
+            // Exhausted llamas dim as their energy runs out
+            brightness *= llama.metabolic_pace();
+
+            brightness = brightness.clamp(0.1, 1.0);
+
+            let hue_offset = self.mod_outputs.get(ModDestination::HueOffset) * MOD_HUE_DEGREES;
+            let hue = (self.palette.hue(self.experience_adaptation.tint_hue(llama.color.x)) + hue_offset).rem_euclid(360.0);
+            // Saturation breathes with the llama's mood, faster when agitated; vicunas only ever pale, never redden
+            let pulse = llama.affect.saturation_pulse(self.time, llama_id as f32);
+            let pulse = if llama.species == SpeciesType::BassDropVicuna { pulse.min(1.0) } else { pulse };
+            let saturation = (llama.color.y * pulse).clamp(0.0, 1.0);
+            let mut color = hsv_to_rgb(hue, saturation, brightness);
+            if llama.species == SpeciesType::BassDropVicuna && self.safety_config.red_flash_protection && is_dangerous_red(color) {
+                // A palette lean can drag vicunas into pure red; pull them back to magenta
+                color = hsv_to_rgb(330.0, saturation, brightness);
+            }
+            if self.warfare_overlay {
+                let pressure = self.consciousness_multiplication.warfare_state.extinction_pressure[species_index(llama.species)];
+                color = desaturate(color, pressure);
+            }
+
+            // Phase 5: Hive mind entities have synchronized color pulsing
+            if llama.consciousness_level == ConsciousnessLevel::Hive && llama.hive_connection_strength > 0.5 {
+                let pulse = (self.time * 3.0 + llama_id as f32 * 0.5).sin() * 0.1 + 1.0;
+                color *= pulse;
+            }
+
+            // CRITICAL SAFETY: Apply all safety measures
+            let previous_color = self.previous_llama_colors.get(&entity)
+                .copied()
+                .unwrap_or(Vec3::new(0.1, 0.1, 0.1)); // Safe default for new llamas
+            color = apply_safety_pipeline(
+                color,
+                previous_color,
+                self.time as f64,
+                &self.safety_config,
+                &mut self.flash_tracker,
+            );
+
+            // Update previous color for next frame
+            self.previous_llama_colors.insert(entity, color);
+
+            // Distant llamas are dots, merged into density splats once the world is crowded
+            if level == DetailLevel::Point {
+                if splatting {
+                    splats.add(llama.position, color, llama.awareness_level);
+                } else {
+                    push_point(&mut vertices, llama.position, (size * 0.5).max(1.0 / pixels_per_unit), color, llama.awareness_level);
+                }
+                continue;
+            }
+            full_detail[llama_id] = level == DetailLevel::Full;
+
+            // Reality distortion affects position rendering
+            let mut render_x = llama.position.x;
+            let mut render_y = llama.position.y;
+            if llama.reality_distortion > 0.2 {
+                let distortion_offset = llama.reality_distortion * 10.0;
+                render_x += (self.time * 5.0 + llama.position.x * 0.01).sin() * distortion_offset;
+                render_y += (self.time * 7.0 + llama.position.y * 0.01).cos() * distortion_offset;
+            }
+
+            // Species silhouette in local space, mapped onto the llama's footprint
+            let x = (render_x / 1200.0) * 2.0 - 1.0;
+            let y = 1.0 - (render_y / 800.0) * 2.0;
+            let s = size / 1200.0;
+
+            let final_color = color.to_array();
+
+            // Map species to shader ID for psychedelic effects; only the body gets the skin
+            let species_id = llama.species.to_shader_id() + SKINNED_SPECIES;
+
+            // Pose comes from the llama's animation state machine
+            let facing = if llama.velocity.x < 0.0 { -1.0 } else { 1.0 };
+            let pose = SilhouettePose::from_animation(&llama.animation.frame(), facing);
+
+            for triangle in silhouette_triangles(&llama.species, &pose) {
+                vertices.extend(triangle.iter().map(|corner| Vertex {
+                    position: [x + corner.x * s, y + corner.y * s, 0.0],
+                    color: final_color,
+                    uv: [(corner.x + 1.0) * 0.5, (corner.y + 1.0) * 0.5],
+                    species_id,
+                    consciousness: llama.awareness_level,
+                    trip_intensity: llama.trip_intensity,
+                }));
+            }
+
+            // Add memory fragment visualization for high-consciousness llamas
+            if level == DetailLevel::Full && llama.awareness_level > 0.6 && !llama.memory_fragments.is_empty() {
+                for memory in &llama.memory_fragments {
+                    let mem_x = (memory.position.x / 1200.0) * 2.0 - 1.0;
+                    let mem_y = 1.0 - (memory.position.y / 800.0) * 2.0;
+                    let mem_s = (2.0 + llama.memory_intensity * 3.0) / 1200.0;
+                    let mem_alpha = llama.memory_intensity * 0.3 * memory.strength;
+
+                    // Good memories lean cyan, bad ones amber
+                    let tint = if memory.kind.valence() >= 0.0 { Vec3::new(0.3, 0.9, 0.9) } else { Vec3::new(1.0, 0.7, 0.2) };
+                    let memory_tone = color.lerp(tint, memory.kind.valence().abs() * 0.5);
+                    let memory_color = [
+                        memory_tone.x * mem_alpha,
+                        memory_tone.y * mem_alpha,
+                        memory_tone.z * mem_alpha,
+                    ];
+
+                    // Memory fragments are fractal-like effects
+                    vertices.extend([
+                        Vertex {
+                            position: [mem_x - mem_s, mem_y - mem_s, 0.0],
+                            color: memory_color,
+                            uv: [0.0, 0.0],
+                            species_id: 3.0, // Fractal effects for memory
+                            consciousness: llama.memory_intensity,
+                            trip_intensity: llama.trip_intensity * 0.8,
+                        },
+                        Vertex {
+                            position: [mem_x + mem_s, mem_y - mem_s, 0.0],
+                            color: memory_color,
+                            uv: [1.0, 0.0],
+                            species_id: 3.0,
+                            consciousness: llama.memory_intensity,
+                            trip_intensity: llama.trip_intensity * 0.8,
+                        },
+                        Vertex {
+                            position: [mem_x - mem_s, mem_y + mem_s, 0.0],
+                            color: memory_color,
+                            uv: [0.0, 1.0],
+                            species_id: 3.0,
+                            consciousness: llama.memory_intensity,
+                            trip_intensity: llama.trip_intensity * 0.8,
+                        },
+                        Vertex {
+                            position: [mem_x + mem_s, mem_y - mem_s, 0.0],
+                            color: memory_color,
+                            uv: [1.0, 0.0],
+                            species_id: 3.0,
+                            consciousness: llama.memory_intensity,
+                            trip_intensity: llama.trip_intensity * 0.8,
+                        },
+                        Vertex {
+                            position: [mem_x + mem_s, mem_y + mem_s, 0.0],
+                            color: memory_color,
+                            uv: [1.0, 1.0],
+                            species_id: 3.0,
+                            consciousness: llama.memory_intensity,
+                            trip_intensity: llama.trip_intensity * 0.8,
+                        },
+                        Vertex {
+                            position: [mem_x - mem_s, mem_y + mem_s, 0.0],
+                            color: memory_color,
+                            uv: [0.0, 1.0],
+                            species_id: 3.0,
+                            consciousness: llama.memory_intensity,
+                            trip_intensity: llama.trip_intensity * 0.8,
+                        },
+                    ]);
+                }
+            }
+        }
+
+        splats.push_vertices(&mut vertices);
+
+        // Phase 3: Render consciousness crystals
+        for crystal in self.world.components::<ConsciousnessCrystal>() {
+            let crystal_color = self.palette.color(crystal.get_color());
+
+            // Apply safety measures to crystal colors too
+            let mut safe_crystal_color = crystal_color;
+            if self.safety_config.red_flash_protection && is_dangerous_red(crystal_color) {
+                let hsv = rgb_to_hsv(crystal_color);
+                let safe_hue = if hsv.x >= 345.0 { 30.0 } else { 30.0 }; // Orange
+                safe_crystal_color = hsv_to_rgb_vec3(Vec3::new(safe_hue, hsv.y * 0.8, hsv.z));
+            }
+
+            if self.safety_config.visual_intensity_limit < 1.0 {
+                let safe_color = Vec3::new(0.1, 0.1, 0.1);
+                safe_crystal_color = safe_color.lerp(safe_crystal_color, self.safety_config.visual_intensity_limit);
+            }
+
+            let x = (crystal.position.x / 1200.0) * 2.0 - 1.0;
+            let y = 1.0 - (crystal.position.y / 800.0) * 2.0;
+            let s = (8.0 + crystal.visual_intensity * 12.0) / 1200.0;
+
+            let crystal_color_array = [safe_crystal_color.x, safe_crystal_color.y, safe_crystal_color.z];
+
+            // Crystal rendered as a diamond shape with fractal effects; with refraction on, the
+            // diamond only stands in for the crystal in safety checks, photos and extra outputs
+            let diamond = [
+                Vertex {
+                    position: [x, y - s, 0.0],
+                    color: crystal_color_array,
+                    uv: [0.5, 0.0],
+                    species_id: 3.0, // Fractal effects for crystals
+                    consciousness: crystal.consciousness_energy,
+                    trip_intensity: crystal.consciousness_energy * 0.5,
+                },
+                Vertex {
+                    position: [x + s, y, 0.0],
+                    color: crystal_color_array,
+                    uv: [1.0, 0.5],
+                    species_id: 3.0,
+                    consciousness: crystal.consciousness_energy,
+                    trip_intensity: crystal.consciousness_energy * 0.5,
+                },
+                Vertex {
+                    position: [x, y + s, 0.0],
+                    color: crystal_color_array,
+                    uv: [0.5, 1.0],
+                    species_id: 3.0,
+                    consciousness: crystal.consciousness_energy,
+                    trip_intensity: crystal.consciousness_energy * 0.5,
+                },
+                Vertex {
+                    position: [x, y - s, 0.0],
+                    color: crystal_color_array,
+                    uv: [0.5, 0.0],
+                    species_id: 3.0,
+                    consciousness: crystal.consciousness_energy,
+                    trip_intensity: crystal.consciousness_energy * 0.5,
+                },
+                Vertex {
+                    position: [x - s, y, 0.0],
+                    color: crystal_color_array,
+                    uv: [0.0, 0.5],
+                    species_id: 3.0,
+                    consciousness: crystal.consciousness_energy,
+                    trip_intensity: crystal.consciousness_energy * 0.5,
+                },
+                Vertex {
+                    position: [x, y + s, 0.0],
+                    color: crystal_color_array,
+                    uv: [0.5, 1.0],
+                    species_id: 3.0,
+                    consciousness: crystal.consciousness_energy,
+                    trip_intensity: crystal.consciousness_energy * 0.5,
+                },
+            ];
+            if self.crystal_refraction {
+                crystal_instances.push(CrystalInstance::new(crystal, safe_crystal_color, &viewport, &self.safety_config, self.time));
+                crystal_fallback.extend(diamond);
+            } else {
+                vertices.extend(diamond);
+            }
+
+            // Add harvest radius visualization for high-energy crystals
+            if crystal.consciousness_energy > 1.0 {
+                let radius_size = (crystal.harvest_radius / 1200.0) * 0.3; // Visual radius smaller than actual
+                let radius_alpha = 0.1 * crystal.visual_intensity;
+                let radius_color = [
+                    safe_crystal_color.x * radius_alpha,
+                    safe_crystal_color.y * radius_alpha,
+                    safe_crystal_color.z * radius_alpha,
+                ];
+
+                // Simple circle approximation
+                for i in 0..6 {
+                    let angle1 = (i as f32 / 6.0) * std::f32::consts::TAU;
+                    let angle2 = ((i + 1) as f32 / 6.0) * std::f32::consts::TAU;
+
+                    vertices.extend([
+                        Vertex {
+                            position: [x, y, 0.0],
+                            color: radius_color,
+                            uv: [0.5, 0.5],
+                            species_id: 3.0, // Crystal energy effects
+                            consciousness: crystal.consciousness_energy,
+                            trip_intensity: 1.0, // High intensity for energy fields
+                        },
+                        Vertex {
+                            position: [x + angle1.cos() * radius_size, y + angle1.sin() * radius_size, 0.0],
+                            color: radius_color,
+                            uv: [0.5, 0.5],
+                            species_id: 3.0,
+                            consciousness: crystal.consciousness_energy,
+                            trip_intensity: 1.0,
+                        },
+                        Vertex {
+                            position: [x + angle2.cos() * radius_size, y + angle2.sin() * radius_size, 0.0],
+                            color: radius_color,
+                            uv: [0.5, 0.5],
+                            species_id: 3.0,
+                            consciousness: crystal.consciousness_energy,
+                            trip_intensity: 1.0,
+                        },
+                    ]);
+                }
+            }
+        }
+
+        // Phase 3: Render reality tears
+        for tear in self.world.components::<RealityTear>() {
+            if tear.intensity < 0.2 { continue; } // Skip very faded tears
+
+            let mut tear_color = Vec3::new(1.0, 0.8, 1.0); // Pink/white glitch color
+
+            // Apply safety measures
+            if self.safety_config.visual_intensity_limit < 1.0 {
+                let safe_color = Vec3::new(0.1, 0.1, 0.1);
+                tear_color = safe_color.lerp(tear_color, self.safety_config.visual_intensity_limit * 0.5); // Extra conservative
+            }
+
+            let x = (tear.position.x / 1200.0) * 2.0 - 1.0;
+            let y = 1.0 - (tear.position.y / 800.0) * 2.0;
+            let s = (tear.size / 1200.0) * tear.intensity;
+
+            let tear_color_array = [
+                tear_color.x * tear.intensity,
+                tear_color.y * tear.intensity,
+                tear_color.z * tear.intensity,
+            ];
+
+            // Reality tear rendered as jagged triangular glitch
+            let offset1 = s * 0.6;
+            let offset2 = s * 0.3;
+
+            // Reality tears use quantum glitch effects
+            vertices.extend([
+                Vertex {
+                    position: [x - s, y - offset1, 0.0],
+                    color: tear_color_array,
+                    uv: [0.5, 0.5],
+                    species_id: 1.0, // Quantum glitch effects
+                    consciousness: 1.0, // Maximum consciousness for reality tears
+                    trip_intensity: self.reality_distortion.emergence_amplification,
+                },
+                Vertex {
+                    position: [x + s, y + offset2, 0.0],
+                    color: tear_color_array,
+                    uv: [0.5, 0.5],
+                    species_id: 1.0,
+                    consciousness: 1.0,
+                    trip_intensity: self.reality_distortion.emergence_amplification,
+                },
+                Vertex {
+                    position: [x - offset2, y + s, 0.0],
+                    color: tear_color_array,
+                    uv: [0.5, 0.5],
+                    species_id: 1.0,
+                    consciousness: 1.0,
+                    trip_intensity: self.reality_distortion.emergence_amplification,
+                },
+                Vertex {
+                    position: [x + offset1, y - s, 0.0],
+                    color: tear_color_array,
+                    uv: [0.5, 0.5],
+                    species_id: 1.0,
+                    consciousness: 1.0,
+                    trip_intensity: self.reality_distortion.emergence_amplification,
+                },
+                Vertex {
+                    position: [x + s, y - offset1, 0.0],
+                    color: tear_color_array,
+                    uv: [0.5, 0.5],
+                    species_id: 1.0,
+                    consciousness: 1.0,
+                    trip_intensity: self.reality_distortion.emergence_amplification,
+                },
+                Vertex {
+                    position: [x - s, y + offset2, 0.0],
+                    color: tear_color_array,
+                    uv: [0.5, 0.5],
+                    species_id: 1.0,
+                    consciousness: 1.0,
+                    trip_intensity: self.reality_distortion.emergence_amplification,
+                },
+            ]);
+        }
+
+        // Echoes of dead llamas: pale ghosts in their old hue, widening as they fade
+        for echo in self.world.components::<LlamaEcho>() {
+            let fade = echo.fade();
+            let mut echo_color = hsv_to_rgb(echo.hue, 0.3, 0.6) * fade * 0.5;
+            if self.safety_config.visual_intensity_limit < 1.0 {
+                echo_color *= self.safety_config.visual_intensity_limit;
+            }
+            let color = echo_color.to_array();
+
+            let x = (echo.position.x / 1200.0) * 2.0 - 1.0;
+            let y = 1.0 - (echo.position.y / 800.0) * 2.0;
+            let r = (8.0 + (1.0 - fade) * 16.0) / 1200.0 * 2.0;
+            for i in 0..6 {
+                let angle1 = (i as f32 / 6.0) * std::f32::consts::TAU;
+                let angle2 = ((i + 1) as f32 / 6.0) * std::f32::consts::TAU;
+                let corner = |angle: f32| Vertex {
+                    position: [x + angle.cos() * r, y + angle.sin() * r * 1.5, 0.0],
+                    color,
+                    uv: [0.5, 0.5],
+                    species_id: 0.0,
+                    consciousness: echo.remaining,
+                    trip_intensity: 0.0,
+                };
+                vertices.extend([
+                    Vertex { position: [x, y, 0.0], ..corner(angle1) },
+                    corner(angle1),
+                    corner(angle2),
+                ]);
+            }
+        }
+
+        // Phase 3: Render territory zones (subtle background effects)
+        for zone in &self.ecosystem.territory_zones {
+            let zone_alpha = zone.strength * 0.05; // Very subtle
+            if zone_alpha < 0.01 { continue; }
+
+            let zone_color = match zone.zone_type {
+                ZoneType::Harmonic => Vec3::new(0.0, 1.0, 1.0),   // Cyan
+                ZoneType::Chaotic => Vec3::new(1.0, 0.0, 1.0),    // Magenta
+                ZoneType::Meditative => Vec3::new(0.0, 1.0, 0.0), // Green
+                ZoneType::Quantum => Vec3::new(0.0, 0.0, 1.0),    // Blue
+                ZoneType::Temporal => Vec3::new(1.0, 0.75, 0.2),  // Amber
+                ZoneType::Void => Vec3::new(0.4, 0.0, 0.6),       // Violet rim around nothing
+            };
+
+            // Apply safety measures
+            let mut safe_zone_color = self.palette.color(zone_color);
+            if self.safety_config.visual_intensity_limit < 1.0 {
+                let safe_color = Vec3::new(0.0, 0.0, 0.0);
+                safe_zone_color = safe_color.lerp(safe_zone_color, self.safety_config.visual_intensity_limit * 0.3);
+            }
+
+            let x = (zone.center.x / 1200.0) * 2.0 - 1.0;
+            let y = 1.0 - (zone.center.y / 800.0) * 2.0;
+            let r = (zone.radius / 1200.0) * 0.8; // Visual radius smaller than actual
+
+            let zone_color_array = [
+                safe_zone_color.x * zone_alpha,
+                safe_zone_color.y * zone_alpha,
+                safe_zone_color.z * zone_alpha,
+            ];
+            // Voids are black at the heart; temporal zones turn slowly like a clock face
+            let center_color = if zone.zone_type == ZoneType::Void { [0.0; 3] } else { zone_color_array };
+            let turn = if zone.zone_type == ZoneType::Temporal { self.time * 0.1 } else { 0.0 };
+
+            // Simple circle for territory zone
+            for i in 0..8 {
+                let angle1 = (i as f32 / 8.0) * std::f32::consts::TAU + turn;
+                let angle2 = ((i + 1) as f32 / 8.0) * std::f32::consts::TAU + turn;
+
+                vertices.extend([
+                    Vertex { position: [x, y, 0.0], color: center_color, uv: [0.5, 0.5], species_id: 0.0, consciousness: 0.3, trip_intensity: 0.1 },
+                    Vertex { position: [x + angle1.cos() * r, y + angle1.sin() * r, 0.0], color: zone_color_array, uv: [0.5, 0.5], species_id: 0.0, consciousness: 0.3, trip_intensity: 0.1 },
+                    Vertex { position: [x + angle2.cos() * r, y + angle2.sin() * r, 0.0], color: zone_color_array, uv: [0.5, 0.5], species_id: 0.0, consciousness: 0.3, trip_intensity: 0.1 },
+                ]);
+            }
+        }
+
+        garden::push_obstacles(&mut vertices, &self.ecosystem.obstacles, self.safety_config.visual_intensity_limit);
+
+        // Phase 4: Render Emergent Communications
+        for communication in &self.emergent_communication.active_communications {
+            if communication.transmission_effectiveness < 0.1 { continue; } // Skip very faded communications
+
+            for signal in &communication.signal_sequence {
+                if signal.duration_remaining <= 0.0 { continue; }
+
+                // Calculate safe communication color
+                let mut comm_color = self.palette.color(signal.visual_state.current_color);
+
+                // Apply safety measures to communication colors
+                if self.safety_config.red_flash_protection && is_dangerous_red(comm_color) {
+                    let hsv = rgb_to_hsv(comm_color);
+                    let safe_hue = if hsv.x >= 345.0 { 60.0 } else { 180.0 }; // Yellow or cyan
+                    comm_color = hsv_to_rgb_vec3(Vec3::new(safe_hue, hsv.y * 0.7, hsv.z * 0.8));
+                }
+
+                if self.safety_config.visual_intensity_limit < 1.0 {
+                    let safe_color = Vec3::new(0.2, 0.2, 0.4); // Soft blue base
+                    comm_color = safe_color.lerp(comm_color, self.safety_config.visual_intensity_limit * 0.6);
+                }
+
+                let x = (signal.position.x / 1200.0) * 2.0 - 1.0;
+                let y = 1.0 - (signal.position.y / 800.0) * 2.0;
+                let s = (signal.visual_state.current_size / 1200.0) * 0.8;
+                let alpha = signal.current_intensity * communication.transmission_effectiveness * 0.5; // More subtle
+
+                let comm_color_array = [comm_color.x * alpha, comm_color.y * alpha, comm_color.z * alpha];
+
+                // Render communication signal based on manifestation type
+                match communication.spatial_manifestation.manifestation_type {
+                    ManifestationType::RadialExpansion => {
+                        // Expanding circle for radial communications
+                        let circle_segments = 8;
+                        for i in 0..circle_segments {
+                            let angle1 = (i as f32 / circle_segments as f32) * std::f32::consts::TAU;
+                            let angle2 = ((i + 1) as f32 / circle_segments as f32) * std::f32::consts::TAU;
+
+                            vertices.extend([
+                                Vertex { position: [x, y, 0.0], color: comm_color_array, uv: [0.5, 0.5], species_id: 1.0, consciousness: 0.5, trip_intensity: 0.3 },
+                                Vertex { position: [x + angle1.cos() * s, y + angle1.sin() * s, 0.0], color: comm_color_array, uv: [0.5, 0.5], species_id: 1.0, consciousness: 0.5, trip_intensity: 0.3 },
+                                Vertex { position: [x + angle2.cos() * s, y + angle2.sin() * s, 0.0], color: comm_color_array, uv: [0.5, 0.5], species_id: 1.0, consciousness: 0.5, trip_intensity: 0.3 },
+                            ]);
+                        }
+
+                        // Add pulsing outer ring
+                        let pulse_phase = signal.visual_state.pulsing_phase.sin() * 0.5 + 0.5;
+                        let outer_s = s * (1.0 + pulse_phase * 0.3);
+                        let ring_alpha = alpha * 0.3 * pulse_phase;
+                        let ring_color = [comm_color.x * ring_alpha, comm_color.y * ring_alpha, comm_color.z * ring_alpha];
+
+                        for i in 0..circle_segments {
+                            let angle1 = (i as f32 / circle_segments as f32) * std::f32::consts::TAU;
+                            let angle2 = ((i + 1) as f32 / circle_segments as f32) * std::f32::consts::TAU;
+
+                            vertices.extend([
+                                Vertex { position: [x + angle1.cos() * s, y + angle1.sin() * s, 0.0], color: ring_color, uv: [0.5, 0.5], species_id: 0.0, consciousness: 0.4, trip_intensity: 0.2 },
+                                Vertex { position: [x + angle1.cos() * outer_s, y + angle1.sin() * outer_s, 0.0], color: ring_color, uv: [0.5, 0.5], species_id: 0.0, consciousness: 0.4, trip_intensity: 0.2 },
+                                Vertex { position: [x + angle2.cos() * outer_s, y + angle2.sin() * outer_s, 0.0], color: ring_color, uv: [0.5, 0.5], species_id: 0.0, consciousness: 0.4, trip_intensity: 0.2 },
+                            ]);
+                        }
+                    }
+                    ManifestationType::LinearPath => {
+                        // Line-based communication path
+                        if communication.spatial_manifestation.visual_trail.len() >= 2 {
+                            for window in communication.spatial_manifestation.visual_trail.windows(2) {
+                                let start = window[0];
+                                let end = window[1];
+
+                                let x1 = (start.x / 1200.0) * 2.0 - 1.0;
+                                let y1 = 1.0 - (start.y / 800.0) * 2.0;
+                                let x2 = (end.x / 1200.0) * 2.0 - 1.0;
+                                let y2 = 1.0 - (end.y / 800.0) * 2.0;
+
+                                let line_width = s * 0.5;
+                                let dx = x2 - x1;
+                                let dy = y2 - y1;
+                                let length = (dx * dx + dy * dy).sqrt();
+                                if length > 0.0 {
+                                    let perpx = -dy / length * line_width;
+                                    let perpy = dx / length * line_width;
+
+                                    vertices.extend([
+                                        Vertex { position: [x1 + perpx, y1 + perpy, 0.0], color: comm_color_array, uv: [0.5, 0.5], species_id: 0.0, consciousness: 0.4, trip_intensity: 0.2 },
+                                        Vertex { position: [x1 - perpx, y1 - perpy, 0.0], color: comm_color_array, uv: [0.5, 0.5], species_id: 0.0, consciousness: 0.4, trip_intensity: 0.2 },
+                                        Vertex { position: [x2 + perpx, y2 + perpy, 0.0], color: comm_color_array, uv: [0.5, 0.5], species_id: 0.0, consciousness: 0.4, trip_intensity: 0.2 },
+                                        Vertex { position: [x1 - perpx, y1 - perpy, 0.0], color: comm_color_array, uv: [0.5, 0.5], species_id: 0.0, consciousness: 0.4, trip_intensity: 0.2 },
+                                        Vertex { position: [x2 - perpx, y2 - perpy, 0.0], color: comm_color_array, uv: [0.5, 0.5], species_id: 0.0, consciousness: 0.4, trip_intensity: 0.2 },
+                                        Vertex { position: [x2 + perpx, y2 + perpy, 0.0], color: comm_color_array, uv: [0.5, 0.5], species_id: 0.0, consciousness: 0.4, trip_intensity: 0.2 },
+                                    ]);
+                                }
+                            }
+                        }
+                    }
+                    _ => {
+                        // Default: simple diamond shape for other types
+                        vertices.extend([
+                            Vertex { position: [x, y + s, 0.0], color: comm_color_array, uv: [0.5, 0.5], species_id: 0.0, consciousness: 0.4, trip_intensity: 0.2 },     // Top
+                            Vertex { position: [x - s, y, 0.0], color: comm_color_array, uv: [0.5, 0.5], species_id: 0.0, consciousness: 0.4, trip_intensity: 0.2 },     // Left
+                            Vertex { position: [x + s, y, 0.0], color: comm_color_array, uv: [0.5, 0.5], species_id: 0.0, consciousness: 0.4, trip_intensity: 0.2 },     // Right
+                            Vertex { position: [x, y + s, 0.0], color: comm_color_array, uv: [0.5, 0.5], species_id: 0.0, consciousness: 0.4, trip_intensity: 0.2 },     // Top
+                            Vertex { position: [x + s, y, 0.0], color: comm_color_array, uv: [0.5, 0.5], species_id: 0.0, consciousness: 0.4, trip_intensity: 0.2 },     // Right
+                            Vertex { position: [x, y - s, 0.0], color: comm_color_array, uv: [0.5, 0.5], species_id: 0.0, consciousness: 0.4, trip_intensity: 0.2 },     // Bottom
+                            Vertex { position: [x, y - s, 0.0], color: comm_color_array, uv: [0.5, 0.5], species_id: 0.0, consciousness: 0.4, trip_intensity: 0.2 },     // Bottom
+                            Vertex { position: [x - s, y, 0.0], color: comm_color_array, uv: [0.5, 0.5], species_id: 0.0, consciousness: 0.4, trip_intensity: 0.2 },     // Left
+                            Vertex { position: [x, y + s, 0.0], color: comm_color_array, uv: [0.5, 0.5], species_id: 0.0, consciousness: 0.4, trip_intensity: 0.2 },     // Top
+                        ]);
+                    }
+                }
+            }
+        }
+
+        // PHASE 5: CONSCIOUSNESS MULTIPLICATION VISUALIZATIONS - "When One Mind Becomes Legion"
+
+        // Render hive mind connection networks
+        for hive in self.world.components::<HiveMind>() {
+            let hive_alpha = 0.3;
+            let connection_color = [0.0, 1.0, 1.0]; // Cyan connections
+
+            // Apply safety measures
+            let mut safe_connection_color = connection_color;
+            if self.safety_config.visual_intensity_limit < 1.0 {
+                let safe_color = [0.0, 0.0, 0.0];
+                safe_connection_color = [
+                    safe_color[0] + (safe_connection_color[0] - safe_color[0]) * self.safety_config.visual_intensity_limit,
+                    safe_color[1] + (safe_connection_color[1] - safe_color[1]) * self.safety_config.visual_intensity_limit,
+                    safe_color[2] + (safe_connection_color[2] - safe_color[2]) * self.safety_config.visual_intensity_limit,
+                ];
+            }
+
+            let final_connection_color = [
+                safe_connection_color[0] * hive_alpha,
+                safe_connection_color[1] * hive_alpha,
+                safe_connection_color[2] * hive_alpha,
+            ];
+
+            // Render connection lines between hive members
+            for &(entity_a, entity_b) in &hive.connection_network {
+                let llama_a = self.world.get_component::<Llama>(entity_a);
+                let llama_b = self.world.get_component::<Llama>(entity_b);
+                if let (Some(llama_a), Some(llama_b)) = (llama_a, llama_b) {
+                    let pos_a = llama_a.position;
+                    let pos_b = llama_b.position;
+
+                    let x1 = (pos_a.x / 1200.0) * 2.0 - 1.0;
+                    let y1 = 1.0 - (pos_a.y / 800.0) * 2.0;
+                    let x2 = (pos_b.x / 1200.0) * 2.0 - 1.0;
+                    let y2 = 1.0 - (pos_b.y / 800.0) * 2.0;
+
+                    let line_width = 0.003;
+
+                    // Create a thin line using triangles
+                    let dx = x2 - x1;
+                    let dy = y2 - y1;
+                    let length = (dx * dx + dy * dy).sqrt();
+                    if length > 0.0 {
+                        let norm_x = -dy / length * line_width;
+                        let norm_y = dx / length * line_width;
+
+                        vertices.extend([
+                            Vertex { position: [x1 - norm_x, y1 - norm_y, 0.0], color: final_connection_color, uv: [0.5, 0.5], species_id: 0.0, consciousness: 0.4, trip_intensity: 0.2 },
+                            Vertex { position: [x1 + norm_x, y1 + norm_y, 0.0], color: final_connection_color, uv: [0.5, 0.5], species_id: 0.0, consciousness: 0.4, trip_intensity: 0.2 },
+                            Vertex { position: [x2 - norm_x, y2 - norm_y, 0.0], color: final_connection_color, uv: [0.5, 0.5], species_id: 0.0, consciousness: 0.4, trip_intensity: 0.2 },
+                            Vertex { position: [x1 + norm_x, y1 + norm_y, 0.0], color: final_connection_color, uv: [0.5, 0.5], species_id: 0.0, consciousness: 0.4, trip_intensity: 0.2 },
+                            Vertex { position: [x2 + norm_x, y2 + norm_y, 0.0], color: final_connection_color, uv: [0.5, 0.5], species_id: 0.0, consciousness: 0.4, trip_intensity: 0.2 },
+                            Vertex { position: [x2 - norm_x, y2 - norm_y, 0.0], color: final_connection_color, uv: [0.5, 0.5], species_id: 0.0, consciousness: 0.4, trip_intensity: 0.2 },
+                        ]);
+                    }
+                }
+            }
+
+            // Render hive center as a glowing node
+            let center_x = (hive.hive_center.x / 1200.0) * 2.0 - 1.0;
+            let center_y = 1.0 - (hive.hive_center.y / 800.0) * 2.0;
+            let center_size = 0.02;
+            let center_intensity = (hive.collective_consciousness / 10.0).min(1.0);
+
+            let center_color = [
+                1.0 * center_intensity * hive_alpha,
+                0.5 * center_intensity * hive_alpha,
+                1.0 * center_intensity * hive_alpha,
+            ];
+
+            vertices.extend([
+                Vertex { position: [center_x - center_size, center_y - center_size, 0.0], color: center_color, uv: [0.5, 0.5], species_id: 0.0, consciousness: 0.4, trip_intensity: 0.2 },
+                Vertex { position: [center_x + center_size, center_y - center_size, 0.0], color: center_color, uv: [0.5, 0.5], species_id: 0.0, consciousness: 0.4, trip_intensity: 0.2 },
+                Vertex { position: [center_x - center_size, center_y + center_size, 0.0], color: center_color, uv: [0.5, 0.5], species_id: 0.0, consciousness: 0.4, trip_intensity: 0.2 },
+                Vertex { position: [center_x + center_size, center_y - center_size, 0.0], color: center_color, uv: [0.5, 0.5], species_id: 0.0, consciousness: 0.4, trip_intensity: 0.2 },
+                Vertex { position: [center_x + center_size, center_y + center_size, 0.0], color: center_color, uv: [0.5, 0.5], species_id: 0.0, consciousness: 0.4, trip_intensity: 0.2 },
+                Vertex { position: [center_x - center_size, center_y + center_size, 0.0], color: center_color, uv: [0.5, 0.5], species_id: 0.0, consciousness: 0.4, trip_intensity: 0.2 },
+            ]);
+        }
+
+        // Render consciousness predation effects
+        for predation in self.world.components::<ConsciousnessPredation>() {
+            let predator = self.world.get_component::<Llama>(predation.predator_id);
+            let prey = self.world.get_component::<Llama>(predation.prey_id);
+            if let (Some(predator), Some(prey)) = (predator, prey) {
+                let predator_pos = predator.position;
+                let prey_pos = prey.position;
+
+                // Render absorption beam
+                let x1 = (predator_pos.x / 1200.0) * 2.0 - 1.0;
+                let y1 = 1.0 - (predator_pos.y / 800.0) * 2.0;
+                let x2 = (prey_pos.x / 1200.0) * 2.0 - 1.0;
+                let y2 = 1.0 - (prey_pos.y / 800.0) * 2.0;
+
+                let beam_intensity = predation.visual_effect_intensity * 0.8;
+                let beam_color = [
+                    1.0 * beam_intensity,
+                    0.0,
+                    0.5 * beam_intensity,
+                ];
+
+                let beam_width = 0.005 * predation.absorption_progress;
+
+                // Create absorption beam
+                let dx = x2 - x1;
+                let dy = y2 - y1;
+                let length = (dx * dx + dy * dy).sqrt();
+                if length > 0.0 {
+                    let norm_x = -dy / length * beam_width;
+                    let norm_y = dx / length * beam_width;
+
+                    vertices.extend([
+                        Vertex { position: [x1 - norm_x, y1 - norm_y, 0.0], color: beam_color, uv: [0.5, 0.5], species_id: 0.0, consciousness: 0.4, trip_intensity: 0.2 },
+                        Vertex { position: [x1 + norm_x, y1 + norm_y, 0.0], color: beam_color, uv: [0.5, 0.5], species_id: 0.0, consciousness: 0.4, trip_intensity: 0.2 },
+                        Vertex { position: [x2 - norm_x, y2 - norm_y, 0.0], color: beam_color, uv: [0.5, 0.5], species_id: 0.0, consciousness: 0.4, trip_intensity: 0.2 },
+                        Vertex { position: [x1 + norm_x, y1 + norm_y, 0.0], color: beam_color, uv: [0.5, 0.5], species_id: 0.0, consciousness: 0.4, trip_intensity: 0.2 },
+                        Vertex { position: [x2 + norm_x, y2 + norm_y, 0.0], color: beam_color, uv: [0.5, 0.5], species_id: 0.0, consciousness: 0.4, trip_intensity: 0.2 },
+                        Vertex { position: [x2 - norm_x, y2 - norm_y, 0.0], color: beam_color, uv: [0.5, 0.5], species_id: 0.0, consciousness: 0.4, trip_intensity: 0.2 },
+                    ]);
+                }
+            }
+        }
+
+        // Render species warfare conflict zones
+        for conflict in &self.consciousness_multiplication.warfare_state.active_conflicts {
+            let conflict_x = (conflict.territory_contested.x / 1200.0) * 2.0 - 1.0;
+            let conflict_y = 1.0 - (conflict.territory_contested.y / 800.0) * 2.0;
+            let conflict_radius = 0.1 * conflict.conflict_intensity;
+
+            // Pulsing warfare indicator
+            let pulse = (self.time * 5.0).sin() * 0.5 + 0.5;
+            let war_intensity = conflict.conflict_intensity * pulse * 0.4;
+
+            let war_color = [
+                1.0 * war_intensity,
+                0.2 * war_intensity,
+                0.0,
+            ];
+
+            // Render as pulsing circle
+            let segments = 12;
+            for i in 0..segments {
+                let angle1 = (i as f32 / segments as f32) * std::f32::consts::TAU;
+                let angle2 = ((i + 1) as f32 / segments as f32) * std::f32::consts::TAU;
+
+                vertices.extend([
+                    Vertex { position: [conflict_x, conflict_y, 0.0], color: war_color, uv: [0.5, 0.5], species_id: 0.0, consciousness: 0.4, trip_intensity: 0.2 },
+                    Vertex { position: [conflict_x + angle1.cos() * conflict_radius, conflict_y + angle1.sin() * conflict_radius, 0.0], color: war_color, uv: [0.5, 0.5], species_id: 0.0, consciousness: 0.4, trip_intensity: 0.2 },
+                    Vertex { position: [conflict_x + angle2.cos() * conflict_radius, conflict_y + angle2.sin() * conflict_radius, 0.0], color: war_color, uv: [0.5, 0.5], species_id: 0.0, consciousness: 0.4, trip_intensity: 0.2 },
+                ]);
+            }
+        }
+
+        // Render Meta-Consciousness Observer
+        let observer = &self.consciousness_multiplication.meta_observer;
+        let obs_x = (observer.observer_position.x / 1200.0) * 2.0 - 1.0;
+        let obs_y = 1.0 - (observer.observer_position.y / 800.0) * 2.0;
+        let obs_size = 0.03 + observer.observation_intensity * 0.02;
+
+        // Observer rendered as ethereal, slowly rotating eye
+        let rotation = self.time * 0.5;
+        let eye_intensity = observer.observation_intensity * 0.6;
+        let eye_color = [
+            1.0 * eye_intensity,
+            1.0 * eye_intensity,
+            1.0 * eye_intensity,
+        ];
+
+        // Outer eye ring
+        let segments = 16;
+        for i in 0..segments {
+            let angle1 = (i as f32 / segments as f32) * std::f32::consts::TAU + rotation;
+            let angle2 = ((i + 1) as f32 / segments as f32) * std::f32::consts::TAU + rotation;
+
+            vertices.extend([
+                Vertex { position: [obs_x, obs_y, 0.0], color: eye_color, uv: [0.5, 0.5], species_id: 0.0, consciousness: 0.4, trip_intensity: 0.2 },
+                Vertex { position: [obs_x + angle1.cos() * obs_size, obs_y + angle1.sin() * obs_size, 0.0], color: eye_color, uv: [0.5, 0.5], species_id: 0.0, consciousness: 0.4, trip_intensity: 0.2 },
+                Vertex { position: [obs_x + angle2.cos() * obs_size, obs_y + angle2.sin() * obs_size, 0.0], color: eye_color, uv: [0.5, 0.5], species_id: 0.0, consciousness: 0.4, trip_intensity: 0.2 },
+            ]);
+        }
+
+        // Observer awareness radius (very subtle)
+        if observer.observation_intensity > 0.7 {
+            let awareness_radius = (observer.awareness_radius / 1200.0) * 0.5;
+            let awareness_alpha = (observer.observation_intensity - 0.7) * 0.1;
+            let awareness_color = [
+                0.5 * awareness_alpha,
+                0.0,
+                1.0 * awareness_alpha,
+            ];
+
+            for i in 0..segments {
+                let angle = (i as f32 / segments as f32) * std::f32::consts::TAU;
+                let next_angle = ((i + 1) as f32 / segments as f32) * std::f32::consts::TAU;
+
+                vertices.extend([
+                    Vertex { position: [obs_x + angle.cos() * awareness_radius * 0.9, obs_y + angle.sin() * awareness_radius * 0.9, 0.0], color: awareness_color, uv: [0.5, 0.5], species_id: 0.0, consciousness: 0.4, trip_intensity: 0.2 },
+                    Vertex { position: [obs_x + angle.cos() * awareness_radius, obs_y + angle.sin() * awareness_radius, 0.0], color: awareness_color, uv: [0.5, 0.5], species_id: 0.0, consciousness: 0.4, trip_intensity: 0.2 },
+                    Vertex { position: [obs_x + next_angle.cos() * awareness_radius, obs_y + next_angle.sin() * awareness_radius, 0.0], color: awareness_color, uv: [0.5, 0.5], species_id: 0.0, consciousness: 0.4, trip_intensity: 0.2 },
+                ]);
+            }
+        }
+
+        // Render consciousness hierarchy indicators (subtle auras around pack/hive entities)
+        for (llama_id, llama) in self.world.components::<Llama>().iter().enumerate() {
+            if llama.consciousness_level != ConsciousnessLevel::Individual && full_detail.get(llama_id).copied().unwrap_or(false) {
+                let x = (llama.position.x / 1200.0) * 2.0 - 1.0;
+                let y = 1.0 - (llama.position.y / 800.0) * 2.0;
+
+                let aura_size = match llama.consciousness_level {
+                    ConsciousnessLevel::Pack => 0.02,
+                    ConsciousnessLevel::Hive => 0.03,
+                    ConsciousnessLevel::Meta => 0.05,
+                    ConsciousnessLevel::Individual => 0.0,
+                };
+
+                let aura_color = match llama.consciousness_level {
+                    ConsciousnessLevel::Pack => [0.0, 1.0, 0.5], // Green-cyan
+                    ConsciousnessLevel::Hive => [0.5, 0.0, 1.0], // Purple
+                    ConsciousnessLevel::Meta => [1.0, 1.0, 0.0], // Yellow
+                    ConsciousnessLevel::Individual => [0.0, 0.0, 0.0],
+                };
+
+                let aura_alpha = llama.hive_connection_strength * 0.3;
+                let final_aura_color = [
+                    aura_color[0] * aura_alpha,
+                    aura_color[1] * aura_alpha,
+                    aura_color[2] * aura_alpha,
+                ];
+
+                if aura_size > 0.0 {
+                    let segments = 8;
+                    for i in 0..segments {
+                        let angle1 = (i as f32 / segments as f32) * std::f32::consts::TAU;
+                        let angle2 = ((i + 1) as f32 / segments as f32) * std::f32::consts::TAU;
+
+                        vertices.extend([
+                            Vertex { position: [x + angle1.cos() * aura_size * 0.8, y + angle1.sin() * aura_size * 0.8, 0.0], color: final_aura_color, uv: [0.5, 0.5], species_id: 0.0, consciousness: 0.4, trip_intensity: 0.2 },
+                            Vertex { position: [x + angle1.cos() * aura_size, y + angle1.sin() * aura_size, 0.0], color: final_aura_color, uv: [0.5, 0.5], species_id: 0.0, consciousness: 0.4, trip_intensity: 0.2 },
+                            Vertex { position: [x + angle2.cos() * aura_size, y + angle2.sin() * aura_size, 0.0], color: final_aura_color, uv: [0.5, 0.5], species_id: 0.0, consciousness: 0.4, trip_intensity: 0.2 },
+                        ]);
+                    }
+                }
+            }
+        }
+
+        // Everything after this is primary-window overlay that extra outputs leave out
+        let world_vertex_count = vertices.len();
+        if let Some(photo) = &self.photo {
+            photo.grade(&mut vertices);
+            photo.grade(&mut crystal_fallback);
+        }
+
+        // AV sync calibration marker - small and mid-grey so it stays inside the zone budget
+        let calibration_flash = self.av_sync.flash_level(self.time as f64);
+        if calibration_flash > 0.0 {
+            let marker_color = Vec3::splat(0.5 * calibration_flash);
+            let marker_min = Vec2::new(1070.0, 70.0);
+            let marker_max = Vec2::new(1130.0, 130.0);
+            text::push_rect(&mut vertices, marker_min, marker_max, marker_color, Vec2::new(1200.0, 800.0));
+        }
+
+        // HUD: steady dim text so degraded modes are visible without the console
+        if let Some(notice) = self.degradation_notice() {
+            text::push_text(&mut vertices, self.locale.text(notice), Vec2::new(20.0, 770.0), 2.0, Vec3::splat(0.45), Vec2::new(1200.0, 800.0));
+        }
+        if let Some(possession) = &self.possession {
+            if let Some(llama) = self.world.get_component::<Llama>(possession.entity()) {
+                let keys = &self.key_bindings;
+                let steer: String = [Action::SteerUp, Action::SteerLeft, Action::SteerDown, Action::SteerRight].iter()
+                    .filter_map(|&action| keys.keys(action).first().map(|key| key.name()))
+                    .collect();
+                let species = match &llama.hybrid {
+                    Some(hybrid) => self.consciousness_multiplication.speciation.registry.species_name(llama.species, Some(hybrid.id)),
+                    None => format!("{:?}", llama.species),
+                };
+                let line = self.locale.format("hud-possessing", &[
+                    ("species", &species),
+                    ("move", &steer),
+                    ("harvest", &keys.label(Action::Harvest)),
+                    ("tunnel", &keys.label(Action::QuantumTunnel)),
+                    ("drop", &keys.label(Action::BassDrop)),
+                    ("release", &keys.label(Action::Possess)),
+                ]);
+                text::push_text(&mut vertices, &line, Vec2::new(20.0, 730.0), 2.0, Vec3::new(0.45, 0.4, 0.5), Vec2::new(1200.0, 800.0));
+            }
+        }
+        if self.sound_overlay {
+            if let Some(audio_engine) = &self.audio_consciousness {
+                let (waveform, spectrum) = audio_engine.scope();
+                self.spectrogram.push(spectrum);
+                push_sound_overlay(&mut vertices, &waveform, &self.spectrogram);
+            }
+        }
+        if self.profiler_overlay {
+            push_profiler_overlay(&mut vertices, &self.profiler.profile());
+            push_buffer_usage(&mut vertices, &self.dynamic_vertex_buffer.usage_report());
+        }
+        if let Some(scrubber) = &self.scrubber {
+            self.push_scrub_bar(&mut vertices, scrubber);
+        }
+        if self.lexicon_overlay {
+            self.push_lexicon_overlay(&mut vertices);
+        }
+        if self.adaptation_inspector {
+            self.push_adaptation_inspector(&mut vertices);
+        }
+        if self.chronicle_inspector && self.scrubber.is_none() {
+            self.push_chronicle_inspector(&mut vertices);
+        }
+        if let Some(tutorial) = self.tutorial.as_ref().filter(|_| self.attract.is_none()) {
+            tutorial.push_overlay(&mut vertices, &self.locale, &self.key_bindings);
+        }
+        if self.attract.is_some() {
+            text::push_text_centered(&mut vertices, self.locale.text("hud-attract"), 600.0, 770.0, 2.0, Vec3::new(0.4, 0.4, 0.5), Vec2::new(1200.0, 800.0));
+        }
+        #[cfg(feature = "shader-hot-reload")]
+        if let Some(shader_error) = &self.shader_error {
+            text::push_text(&mut vertices, shader_error, Vec2::new(20.0, 750.0), 2.0, Vec3::new(0.8, 0.6, 0.3), Vec2::new(1200.0, 800.0));
+        }
+
+        // Photo mode hides the HUD, though not the key binding editor
+        if self.photo.is_some() {
+            vertices.truncate(world_vertex_count);
+        }
+        if let Some(editor) = &self.garden {
+            editor.push_overlay(&mut vertices, self.cursor_position, &self.ecosystem, &self.locale, &self.key_bindings);
+        }
+        if let Some(editor) = &self.key_editor {
+            editor.push_overlay(&mut vertices, &self.key_bindings, &self.locale);
+        }
+        if let Some(panel) = &self.tuning_panel {
+            panel.push_overlay(&mut vertices, &self.ecosystem.tuning, &self.locale, &self.key_bindings);
+        }
+        if let Some(editor) = &self.mod_editor {
+            editor.push_overlay(&mut vertices, &self.modulation, &self.mod_sources, &self.locale, &self.key_bindings);
+        }
+
+        self.profiler.lap(ProfileStage::VertexGeneration);
+
+        // CRITICAL SAFETY: Final pass - dim the whole frame if any screen region brightens past budget
+        let mut background = Vec3::new(0.0, 0.0, 0.1 + self.visual_beat_intensity * 0.1);
+        let zone_analysis = self.zone_analyzer.analyze(
+            vertices.chunks_exact(3).chain(crystal_fallback.chunks_exact(3)).map(|triangle| {
+                let corner = |vertex: &Vertex| Vec2::new(vertex.position[0], vertex.position[1]);
+                let color = triangle.iter().map(|vertex| Vec3::from(vertex.color)).sum::<Vec3>() / 3.0;
+                ([corner(&triangle[0]), corner(&triangle[1]), corner(&triangle[2])], color)
+            }),
+            background,
+        );
+        if zone_analysis.intensity_scale < 1.0 {
+            for vertex in vertices.iter_mut().chain(crystal_fallback.iter_mut()) {
+                vertex.color = (Vec3::from(vertex.color) * zone_analysis.intensity_scale).into();
+            }
+            for instance in &mut crystal_instances {
+                instance.color = (Vec3::from(instance.color) * zone_analysis.intensity_scale).into();
+            }
+            background *= zone_analysis.intensity_scale;
+        }
+
+        // CRITICAL SAFETY: Whole-frame flash analysis on what actually reaches the screen
+        let frame_summary = FrameSummary::from_colors(
+            self.time as f64,
+            vertices.iter().chain(&crystal_fallback).map(|vertex| Vec3::from(vertex.color)),
+        );
+        let violations = self.frame_analyzer.analyze(frame_summary);
+        self.report_safety_violations(&violations);
+        self.profiler.lap(ProfileStage::Safety);
+
+        // Photos and extra outputs draw refracting crystals as their flat diamonds
+        let flat_world = if crystal_fallback.is_empty() || (!self.photo_capture_pending && self.outputs.is_empty()) {
+            None
+        } else {
+            Some([&vertices[..world_vertex_count], &crystal_fallback[..]].concat())
+        };
+        let flat_world = flat_world.as_deref().unwrap_or(&vertices[..world_vertex_count]);
+        if self.photo_capture_pending {
+            self.photo_capture_pending = false;
+            self.save_photo(flat_world, background);
+        }
+
+        // Extra outputs draw the safety-checked world through their own viewports,
+        // each checked for flashes on its own
+        let output_violations: Vec<SafetyViolation> = self.outputs.iter_mut()
+            .flat_map(|output| output.analyze(self.time as f64, flat_world))
+            .collect();
+        self.report_safety_violations(&output_violations);
+        for output in &mut self.outputs {
+            if let Err(e) = output.render(&self.device, &self.queue, self.theme_pipelines.pipeline(), &self.uniform_bind_group, flat_world, background) {
+                warn!(target: "render", "🖥️ Output window render error: {:?}", e);
+            }
+        }
+        if !viewport.is_full() {
+            for vertex in &mut vertices[..world_vertex_count] {
+                vertex.position = viewport.project(vertex.position);
+            }
+        }
+
+        // Ensure buffer capacity and validate vertex count with dynamic management
+        if !vertices.is_empty() {
+            if let Err(e) = self.dynamic_vertex_buffer.ensure_capacity(&self.device, vertices.len()) {
+                error!(target: "render", "Failed to ensure buffer capacity: {}", e);
+                return Err(SurfaceError::Lost);
+            }
+            if let Some(report) = self.dynamic_vertex_buffer.take_pressure_warning() {
+                let clipped: Vec<String> = self.budget_manager.category_report().into_iter()
+                    .filter(|category| category.clipped > 0)
+                    .map(|category| format!("{} over budget by {}", category.category, category.clipped))
+                    .collect();
+                warn!(target: "render", "🧮 Vertex buffer near its ceiling: peak {} of {} vertices ({} resizes, {} bytes){}",
+                      report.peak_vertices, report.max_capacity, report.resize_count, report.bytes_allocated,
+                      if clipped.is_empty() { String::new() } else { format!(" - {}", clipped.join(", ")) });
+                self.push_event(BloomEvent::VertexBufferPressure(report));
+            }
+
+            let validated_vertex_count = match self.dynamic_vertex_buffer.validate_vertex_count(vertices.len()) {
+                Ok(count) => count,
+                Err(e) => {
+                    error!(target: "render", "Vertex validation failed: {}", e);
+                    return Err(SurfaceError::Lost);
+                }
+            };
+
+            // Truncate vertices if validation reduced the count
+            if validated_vertex_count < vertices.len() {
+                warn!(target: "render", "Vertices truncated from {} to {} due to capacity limits",
+                      vertices.len(), validated_vertex_count);
+                vertices.truncate(validated_vertex_count);
+            }
+
+            if let Some(buffer) = self.dynamic_vertex_buffer.get_buffer() {
+                self.queue.write_buffer(buffer, 0, bytemuck::cast_slice(&vertices));
+            } else {
+                error!(target: "render", "No vertex buffer available for rendering");
+                return Err(SurfaceError::Lost);
+            }
+        }
+
+        let mut encoder = self.device.create_command_encoder(&CommandEncoderDescriptor {
+            label: Some("Render Encoder"),
+        });
+
+        // The dust moves entirely on the GPU; only the stirring llamas, force fields and beat are uploaded
+        if self.consciousness_dust {
+            let dust = DustUniforms::build(self.world.components::<Llama>(), self.visual_beat_intensity, &self.safety_config, &viewport, self.time)
+                .with_force_fields(&self.ecosystem.force_fields);
+            self.dust_pass.dispatch(&self.queue, &mut encoder, dust);
+        }
+
+        // Reality tears bend the world: draw it offscreen, then warp it onto the screen under the HUD
+        let distortion = DistortionUniforms::build(
+            self.world.components::<RealityTear>(),
+            self.reality_distortion.emergence_amplification,
+            &self.safety_config,
+            &viewport,
+            self.config.width as f32 / self.config.height.max(1) as f32,
+            self.time,
+        );
+        // Conscious llamas and crystals light the world; walls and pillars shadow it
+        self.light_selection.update(
+            (self.world.entities_with::<Llama>(), self.world.components::<Llama>()),
+            (self.world.entities_with::<ConsciousnessCrystal>(), self.world.components::<ConsciousnessCrystal>()),
+            &viewport,
+            self.time,
+        );
+        let lighting = LightingUniforms::build(
+            &self.light_selection,
+            &self.ecosystem.obstacles,
+            self.lighting,
+            &self.safety_config,
+            &viewport,
+            self.config.width,
+            self.config.height,
+        );
+        let lit = lighting.is_active();
+        if lit {
+            self.lighting_pass.prepare(&self.queue, &lighting);
+        }
+        // Refracting crystals need the world behind them drawn first, so they too go through a backdrop
+        let refracting = !crystal_instances.is_empty();
+        // The finished world is graded through the mood's LUT on its way to the screen
+        if self.color_grading && self.grade_upload_pending {
+            self.grading_pass.upload(&self.queue, self.color_grade.lut());
+            self.grade_upload_pending = false;
+        }
+        if (distortion.is_active() || refracting || self.color_grading) && !vertices.is_empty() {
+            let Some(buffer) = self.dynamic_vertex_buffer.get_buffer() else {
+                error!(target: "render", "No vertex buffer available for render pass");
+                return Err(SurfaceError::Lost);
+            };
+            let world_end = world_vertex_count.min(vertices.len()) as u32;
+            let crystal_backdrop = if refracting {
+                let crystal_uniforms = CrystalUniforms::new(self.config.width, self.config.height, &self.safety_config);
+                Some(self.crystal_pass.prepare(&self.device, &self.queue, &crystal_instances, &crystal_uniforms))
+            } else {
+                None
+            };
+            let scene_view = if distortion.is_active() {
+                Some(self.distortion_pass.prepare(&self.device, &self.queue, self.config.width, self.config.height, &distortion))
+            } else {
+                None
+            };
+            let grade_view = if self.color_grading {
+                Some(self.grading_pass.prepare(&self.device, self.config.width, self.config.height))
+            } else {
+                None
+            };
+            let world_view = crystal_backdrop.or(scene_view).or(grade_view).expect("distorting, refracting or grading always has an offscreen target");
+            {
+                let mut scene_pass = encoder.begin_render_pass(&RenderPassDescriptor {
+                    label: Some("Offscreen World Pass"),
+                    color_attachments: &[Some(RenderPassColorAttachment {
+                        view: world_view,
+                        resolve_target: None,
+                        ops: Operations {
+                            load: LoadOp::Clear(Color {
+                                r: background.x as f64,
+                                g: background.y as f64,
+                                b: background.z as f64,
+                                a: 1.0,
+                            }),
+                            store: StoreOp::Store,
+                        },
+                    })],
+                    depth_stencil_attachment: None,
+                    timestamp_writes: None,
+                    occlusion_query_set: None,
+                });
+                if self.consciousness_dust {
+                    self.dust_pass.draw(&mut scene_pass);
+                }
+                scene_pass.set_pipeline(self.theme_pipelines.pipeline());
+                scene_pass.set_bind_group(0, &self.uniform_bind_group, &[]);
+                scene_pass.set_vertex_buffer(0, buffer.slice(..));
+                scene_pass.draw(0..world_end, 0..1);
+                if lit {
+                    self.lighting_pass.draw(&mut scene_pass);
+                }
+            }
+            // With tears open the crystals land in the scene the warp bends, otherwise in the graded scene or on screen
+            if refracting {
+                let mut crystal_pass = encoder.begin_render_pass(&RenderPassDescriptor {
+                    label: Some("Crystal Refraction Pass"),
+                    color_attachments: &[Some(RenderPassColorAttachment {
+                        view: scene_view.or(grade_view).unwrap_or(&view),
+                        resolve_target: None,
+                        ops: Operations {
+                            load: LoadOp::Clear(Color::BLACK),
+                            store: StoreOp::Store,
+                        },
+                    })],
+                    depth_stencil_attachment: None,
+                    timestamp_writes: None,
+                    occlusion_query_set: None,
+                });
+                self.crystal_pass.draw(&mut crystal_pass);
+            }
+            let (warped, graded) = (scene_view.is_some(), grade_view.is_some());
+            // Warp first, then grade the warped world
+            if let (Some(_), Some(grade_view)) = (scene_view, grade_view) {
+                let mut warp_pass = encoder.begin_render_pass(&RenderPassDescriptor {
+                    label: Some("Distortion Pass"),
+                    color_attachments: &[Some(RenderPassColorAttachment {
+                        view: grade_view,
+                        resolve_target: None,
+                        ops: Operations {
+                            load: LoadOp::Clear(Color::BLACK),
+                            store: StoreOp::Store,
+                        },
+                    })],
+                    depth_stencil_attachment: None,
+                    timestamp_writes: None,
+                    occlusion_query_set: None,
+                });
+                self.distortion_pass.draw(&mut warp_pass);
+            }
+            {
+                let mut render_pass = encoder.begin_render_pass(&RenderPassDescriptor {
+                    label: Some("Screen Pass"),
+                    color_attachments: &[Some(RenderPassColorAttachment {
+                        view: &view,
+                        resolve_target: None,
+                        ops: Operations {
+                            // Keep the crystals if they were drawn straight to the screen
+                            load: if !warped && !graded { LoadOp::Load } else { LoadOp::Clear(Color::BLACK) },
+                            store: StoreOp::Store,
+                        },
+                    })],
+                    depth_stencil_attachment: None,
+                    timestamp_writes: None,
+                    occlusion_query_set: None,
+                });
+                if graded {
+                    self.grading_pass.draw(&mut render_pass);
+                } else if warped {
+                    self.distortion_pass.draw(&mut render_pass);
+                }
+                if world_end < vertices.len() as u32 {
+                    render_pass.set_pipeline(self.theme_pipelines.pipeline());
+                    render_pass.set_bind_group(0, &self.uniform_bind_group, &[]);
+                    render_pass.set_vertex_buffer(0, buffer.slice(..));
+                    render_pass.draw(world_end..vertices.len() as u32, 0..1);
+                }
+            }
+
+            self.queue.submit(std::iter::once(encoder.finish()));
+            output.present();
+            self.profiler.lap(ProfileStage::GpuSubmit);
+            self.profiler.end_frame();
+            return Ok(());
+        }
+
+        {
+            let mut render_pass = encoder.begin_render_pass(&RenderPassDescriptor {
+                label: Some("Render Pass"),
+                color_attachments: &[Some(RenderPassColorAttachment {
+                    view: &view,
+                    resolve_target: None,
+                    ops: Operations {
+                        load: LoadOp::Clear(Color {
+                            r: background.x as f64,
+                            g: background.y as f64,
+                            b: background.z as f64,
+                            a: 1.0,
+                        }),
+                        store: StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+
+            if self.consciousness_dust {
+                self.dust_pass.draw(&mut render_pass);
+            }
+            render_pass.set_pipeline(self.theme_pipelines.pipeline());
+            render_pass.set_bind_group(0, &self.uniform_bind_group, &[]);
+            if !vertices.is_empty() {
+                if let Some(buffer) = self.dynamic_vertex_buffer.get_buffer() {
+                    // The world, its lighting, then the HUD unlit on top
+                    let world_end = world_vertex_count.min(vertices.len()) as u32;
+                    render_pass.set_vertex_buffer(0, buffer.slice(..));
+                    render_pass.draw(0..world_end, 0..1);
+                    if lit {
+                        self.lighting_pass.draw(&mut render_pass);
+                        render_pass.set_pipeline(self.theme_pipelines.pipeline());
+                        render_pass.set_bind_group(0, &self.uniform_bind_group, &[]);
+                    }
+                    render_pass.draw(world_end..vertices.len() as u32, 0..1);
+                } else {
+                    error!(target: "render", "No vertex buffer available for render pass");
+                }
+            }
+        }
+
+        self.queue.submit(std::iter::once(encoder.finish()));
+        output.present();
+        self.profiler.lap(ProfileStage::GpuSubmit);
+        self.profiler.end_frame();
+
+        Ok(())
+    }
+
+    /// Progress of the warm-up on a dark screen; the world stays hidden until it is done
+    fn render_warm_start(&mut self) -> Result<(), SurfaceError> {
+        let Some(warm_start) = &self.warm_start else { return Ok(()) };
+        let status = self.locale.format("warm-start-status", &[
+            ("llamas", &self.world.count::<Llama>()),
+            ("crystals", &self.world.count::<ConsciousnessCrystal>()),
+            ("hives", &self.world.count::<HiveMind>()),
+        ]);
+        let mut vertices = Vec::new();
+        warm_start.push_overlay(&mut vertices, &self.locale, &status);
+
+        if let Err(e) = self.dynamic_vertex_buffer.ensure_capacity(&self.device, vertices.len()) {
+            error!(target: "render", "Failed to ensure buffer capacity: {}", e);
+            return Err(SurfaceError::Lost);
+        }
+        let Some(buffer) = self.dynamic_vertex_buffer.get_buffer() else {
+            error!(target: "render", "No vertex buffer available for rendering");
+            return Err(SurfaceError::Lost);
+        };
+        self.queue.write_buffer(buffer, 0, bytemuck::cast_slice(&vertices));
+
+        let output = self.surface.get_current_texture()?;
+        let view = output.texture.create_view(&TextureViewDescriptor::default());
+        let mut encoder = self.device.create_command_encoder(&CommandEncoderDescriptor {
+            label: Some("Warm Start Render Encoder"),
+        });
+
+        {
+            let mut render_pass = encoder.begin_render_pass(&RenderPassDescriptor {
+                label: Some("Warm Start Render Pass"),
+                color_attachments: &[Some(RenderPassColorAttachment {
+                    view: &view,
+                    resolve_target: None,
+                    ops: Operations {
+                        load: LoadOp::Clear(Color { r: 0.02, g: 0.02, b: 0.05, a: 1.0 }),
+                        store: StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+            render_pass.set_pipeline(self.theme_pipelines.pipeline());
+            render_pass.set_bind_group(0, &self.uniform_bind_group, &[]);
+            render_pass.set_vertex_buffer(0, buffer.slice(..));
+            render_pass.draw(0..vertices.len() as u32, 0..1);
+        }
+
+        self.queue.submit(std::iter::once(encoder.finish()));
+        output.present();
+
+        Ok(())
+    }
+
+    /// Render emergency stop screen - minimal safe visuals
+    fn render_emergency_stop(&mut self) -> Result<(), SurfaceError> {
+        // Record the dim screen so effects ramp back in within budget after resuming
+        self.zone_analyzer.analyze(std::iter::empty(), Vec3::splat(0.05));
+        for output in &mut self.outputs {
+            if let Err(e) = output.render(&self.device, &self.queue, self.theme_pipelines.pipeline(), &self.uniform_bind_group, &[], Vec3::splat(0.05)) {
+                warn!(target: "render", "🖥️ Output window render error: {:?}", e);
+            }
+        }
+
+        let output = self.surface.get_current_texture()?;
+        let view = output.texture.create_view(&TextureViewDescriptor::default());
+
+        let mut encoder = self.device.create_command_encoder(&CommandEncoderDescriptor {
+            label: Some("Emergency Stop Render Encoder"),
+        });
+
+        {
+            let _render_pass = encoder.begin_render_pass(&RenderPassDescriptor {
+                label: Some("Emergency Stop Render Pass"),
+                color_attachments: &[Some(RenderPassColorAttachment {
+                    view: &view,
+                    resolve_target: None,
+                    ops: Operations {
+                        load: LoadOp::Clear(Color {
+                            r: 0.05, // Very dim safe color
+                            g: 0.05,
+                            b: 0.05,
+                            a: 1.0,
+                        }),
+                        store: StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+
+            // No visual effects during emergency stop
+        }
+
+        self.queue.submit(std::iter::once(encoder.finish()));
+        output.present();
+
+        Ok(())
+    }
+
+    /// Handle keyboard input: Escape always stops, then the key binding editor and the
+    /// photo, garden, history and possession keys come before the global bindings
+    pub fn handle_keyboard(&mut self, key_event: &KeyEvent) {
+        let Some(key) = BoundKey::from_key(&key_event.logical_key) else { return };
+        let pressed = key_event.state == ElementState::Pressed;
+
+        if key == BoundKey::Named(NamedKey::Escape) {
+            if pressed {
+                self.perform_action(Action::EmergencyStop);
+            }
+            return;
+        }
+
+        // The editor takes every other key while open
+        if let Some(editor) = &mut self.key_editor {
+            if pressed {
+                match editor.handle_key(key, &mut self.key_bindings) {
+                    EditorInput::Close => self.set_key_binding_editor(false),
+                    EditorInput::Rebound(action) => {
+                        info!(target: "app", "⌨️ {} = {}", action.name(), self.key_bindings.label(action));
+                        warn_key_conflicts(&self.key_bindings);
+                    }
+                    EditorInput::Adjusted => {}
+                }
+            }
+            return;
+        }
+
+        // The tuning sliders take the keys they use; the rest fall through
+        if let Some(panel) = self.tuning_panel.as_mut().filter(|_| pressed) {
+            match panel.handle_key(key, &self.key_bindings, &mut self.ecosystem.tuning) {
+                TuningInput::Close => return self.set_chaos_tuning_panel(false),
+                TuningInput::Changed(index) => {
+                    debug!(target: "app", "🎲 {} = {}", ChaosTuning::PARAMS[index].name, self.ecosystem.tuning.values()[index]);
+                    return;
+                }
+                TuningInput::Selected => return,
+                TuningInput::Ignored => {}
+            }
+        }
+        if let Some(editor) = self.mod_editor.as_mut().filter(|_| pressed) {
+            match editor.handle_key(key, &self.key_bindings, &mut self.modulation) {
+                ModInput::Close => return self.set_mod_matrix_editor(false),
+                ModInput::Changed | ModInput::Selected => return,
+                ModInput::Ignored => {}
+            }
+        }
+
+        // In photo mode the camera and grading keys come first
+        if let Some(photo) = &mut self.photo {
+            if let Some(action) = self.key_bindings.action(KeyContext::Photo, key).filter(|_| pressed) {
+                match photo.handle_action(action) {
+                    PhotoInput::Adjusted => return,
+                    PhotoInput::Capture => {
+                        self.capture_photo();
+                        return;
+                    }
+                    PhotoInput::Ignored => {}
+                }
+            }
+        }
+
+        // In the garden editor the tool, kind, size and save keys come first
+        if let Some(editor) = &mut self.garden {
+            if let Some(action) = self.key_bindings.action(KeyContext::Garden, key).filter(|_| pressed) {
+                match editor.handle_action(action, self.cursor_position, &mut self.ecosystem) {
+                    GardenInput::Adjusted => return,
+                    GardenInput::Save => {
+                        self.save_garden();
+                        return;
+                    }
+                    GardenInput::Ignored => {}
+                }
+            }
+        }
+
+        // While scrubbing, the scrub keys move through history
+        if let Some(scrubber) = &mut self.scrubber {
+            if let Some(action) = self.key_bindings.action(KeyContext::History, key).filter(|_| pressed) {
+                match scrubber.handle_action(action, self.history.len()) {
+                    ScrubInput::Seek => {
+                        self.show_snapshot();
+                        return;
+                    }
+                    ScrubInput::Adjusted => return,
+                    ScrubInput::Branch => {
+                        self.branch_from_history();
+                        return;
+                    }
+                    ScrubInput::Ignored => {}
+                }
+            }
+        }
+
+        // While possessing, the steering keys steer instead of their usual bindings
+        if let Some(possession) = &mut self.possession {
+            if let Some(action) = self.key_bindings.action(KeyContext::Possession, key) {
+                if possession.handle_action(action, pressed) {
+                    return;
+                }
+            }
+        }
+
+        if pressed {
+            if let Some(action) = self.key_bindings.action(KeyContext::Global, key) {
+                self.advance_tutorial(TutorialCue::Action(action));
+                self.perform_action(action);
+            }
+        }
+    }
+
+    /// Carry out a global action, whichever key it is bound to
+    fn perform_action(&mut self, action: Action) {
+        match action {
+            Action::EmergencyStop => {
+                if self.emergency_stop_requested {
+                    // Toggle emergency stop off
+                    self.clear_emergency_stop();
+                } else {
+                    // Activate emergency stop
+                    self.request_emergency_stop();
+                }
+            }
+            // Audio mode switching
+            Action::AudioMellow | Action::AudioActive | Action::AudioChaotic => {
+                let mode = match action {
+                    Action::AudioMellow => AudioMode::Mellow,
+                    Action::AudioActive => AudioMode::Active,
+                    _ => AudioMode::Chaotic,
+                };
+                self.record_audio_mode_choice(&mode);
+                if let Some(audio_engine) = &mut self.audio_consciousness {
+                    audio_engine.set_audio_mode(mode);
+                }
+            }
+            Action::VolumeUp | Action::VolumeDown | Action::VolumeUpFine | Action::VolumeDownFine => {
+                let step = match action {
+                    Action::VolumeUp => 0.1,
+                    Action::VolumeDown => -0.1,
+                    Action::VolumeUpFine => 0.05,
+                    _ => -0.05,
+                };
+                if let Some(audio_engine) = &mut self.audio_consciousness {
+                    audio_engine.adjust_volume(step);
+                }
+            }
+            Action::SpeedUp | Action::SpeedDown => {
+                if let Some(audio_engine) = &mut self.audio_consciousness {
+                    audio_engine.adjust_speed(if action == Action::SpeedUp { 0.1 } else { -0.1 });
+                }
+            }
+            Action::SpeedPreset(preset) => self.set_audio_speed(SPEED_PRESETS[(preset.clamp(1, 9) - 1) as usize]),
+            Action::ToggleAudio => {
+                if let Some(audio_engine) = &mut self.audio_consciousness {
+                    audio_engine.toggle_audio();
+                }
+            }
+            Action::CycleOutputDevice => self.cycle_audio_device(),
+            Action::CycleScale => {
+                if let Some(audio_engine) = &mut self.audio_consciousness {
+                    audio_engine.cycle_scale();
+                }
+            }
+            Action::ToggleDucking => self.toggle_ducking(),
+            Action::AvCalibration => self.toggle_av_calibration(),
+            Action::AvOffsetEarlier => self.adjust_av_offset(-AV_OFFSET_STEP_MS),
+            Action::AvOffsetLater => self.adjust_av_offset(AV_OFFSET_STEP_MS),
+            Action::MuteBus(bus) => {
+                if let Some(audio_engine) = &mut self.audio_consciousness {
+                    audio_engine.toggle_bus_mute(bus);
+                }
+            }
+            // The meta-observer intervenes now, or stops/starts intervening on its own
+            Action::Intervene(intervention) => self.intervene(intervention),
+            Action::ObserverAutonomy => {
+                let enabled = !self.autonomous_interventions();
+                self.set_autonomous_interventions(enabled);
+            }
+            Action::PhotoMode => self.set_photo_mode(!self.photo_mode()),
+            Action::KeyBindingsEditor => self.set_key_binding_editor(true),
+            Action::ChaosTuning => self.set_chaos_tuning_panel(!self.chaos_tuning_panel()),
+            Action::ModMatrix => self.set_mod_matrix_editor(!self.mod_matrix_editor()),
+            Action::HistoryScrubbing => self.set_history_scrubbing(!self.history_scrubbing()),
+            Action::GardenEditor => self.set_garden_editor(!self.garden_editor()),
+            // Possess the llama under the cursor, then use its species abilities
+            Action::Possess => self.toggle_possession(),
+            Action::Harvest => self.possessed_harvest(),
+            Action::QuantumTunnel => self.possessed_tunnel(),
+            Action::BassDrop => self.possessed_bass_drop(),
+            Action::Homeostasis => self.set_homeostasis(self.homeostasis.is_none().then(|| self.homeostasis_config.clone())),
+            Action::CycleTheme => self.beat_scheduler.schedule(Quantize::Beat, BeatCue::CycleTheme),
+            Action::AdaptationWeaker => self.set_adaptation_strength(self.adaptation_strength() - ADAPTATION_STRENGTH_STEP),
+            Action::AdaptationStronger => self.set_adaptation_strength(self.adaptation_strength() + ADAPTATION_STRENGTH_STEP),
+            Action::SkipTutorial => self.skip_tutorial(),
+            Action::WarfareOverlay => self.set_warfare_overlay(!self.warfare_overlay),
+            Action::PheromoneOverlay => self.set_pheromone_overlay(!self.pheromone_overlay),
+            Action::ForceOverlay => self.set_force_overlay(!self.force_overlay),
+            Action::FieldOverlay => self.set_field_view(self.field_view.next()),
+            Action::LexiconOverlay => self.set_lexicon_overlay(!self.lexicon_overlay),
+            Action::AdaptationInspector => self.set_adaptation_inspector(!self.adaptation_inspector),
+            Action::ChronicleInspector => self.set_chronicle_inspector(!self.chronicle_inspector),
+            Action::SoundOverlay => self.set_sound_overlay(!self.sound_overlay),
+            Action::ProfilerOverlay => self.set_profiler_overlay(!self.profiler_overlay),
+            Action::ExportGraph => self.export_consciousness_graph(),
+            Action::ShowControls => self.show_audio_status(),
+            // Photo, possession, garden and history actions are handled by their modes, fullscreen by the window shell
+            _ => {}
+        }
+    }
+
+    fn set_audio_speed(&mut self, speed: f32) {
+        if let Some(audio_engine) = &mut self.audio_consciousness {
+            audio_engine.get_controls_mut().speed = speed;
+            info!(target: "audio", "⚡ Speed set to: {:.1}x", speed);
+        }
+    }
+
+    /// Switch audio output to the next available device
+    fn cycle_audio_device(&mut self) {
+        if let Some(audio_engine) = &mut self.audio_consciousness {
+            let devices = AudioConsciousnessEngine::list_devices();
+            if devices.is_empty() {
+                warn!(target: "audio", "🔇 No audio output devices found");
+                return;
+            }
+
+            let current = devices.iter()
+                .position(|device| device.name == audio_engine.current_device_name());
+            let next = current.map_or(0, |index| (index + 1) % devices.len());
+
+            if let Err(e) = audio_engine.set_device(Some(&devices[next].name)) {
+                warn!(target: "audio", "🔇 Failed to switch audio output: {}", e);
+            }
+        }
+    }
+
+    /// Display current audio control status
+    pub fn show_audio_status(&self) {
+        if let Some(audio_engine) = &self.audio_consciousness {
+            let controls = audio_engine.get_controls();
+            let status = if controls.enabled { "ON" } else { "OFF" };
+
+            info!(target: "audio", "{}", self.locale.text("status-title"));
+            info!(target: "audio", "   Mode: {} | Volume: {:.0}% | Speed: {:.1}x | Scale: {} | Audio: {}",
+                     controls.mode.to_string(),
+                     controls.volume * 100.0,
+                     controls.speed,
+                     controls.scale.name(),
+                     status);
+            let buses: Vec<String> = MixBus::ALL.iter().map(|&bus| {
+                let control = controls.mixer.bus(bus);
+                if control.muted { format!("{} MUTED", bus.name()) } else { format!("{} {:.0}%", bus.name(), control.gain * 100.0) }
+            }).collect();
+            info!(target: "audio", "   Buses: {}", buses.join(" | "));
+            let buffer = audio_engine.get_audio_analysis().buffer_stats;
+            info!(target: "audio", "   Output: {} ({:?})", audio_engine.current_device_name(), audio_engine.device_state());
+            if let Some(source) = audio_engine.ducking_source() {
+                info!(target: "audio", "   Ducking under '{}': -{:.1} dB now", source, audio_engine.get_audio_analysis().ducking_db);
+            }
+            let analysis = audio_engine.get_audio_analysis();
+            match analysis.loudness_lufs {
+                Some(lufs) => info!(target: "audio", "   Loudness: {:.1} LUFS (target {:.1}) | normalizing {:+.1} dB", lufs, controls.loudness.target_lufs, analysis.loudness_gain_db),
+                None => info!(target: "audio", "   Loudness: not measured yet"),
+            }
+            info!(target: "audio", "   Heard beat: {} onsets | visuals follow {}", analysis.heard_onsets,
+                  if self.heard_beat_visuals { "the output" } else { "the beat engine" });
+            info!(target: "audio", "   Event voices: {} playing | {} dropped | {} cut short", analysis.event_voices.active, analysis.event_voices.refused, analysis.event_voices.stolen);
+            info!(target: "audio", "   Buffer: {:.1}ms latency | target {} samples | {} underruns | {} overruns | {} dropouts",
+                     buffer.latency_ms, buffer.target_fill, buffer.underruns, buffer.overruns, buffer.contended);
+            for context in KeyContext::ALL {
+                let controls: Vec<String> = Action::ALL.iter()
+                    .filter(|action| action.context() == context && !self.key_bindings.keys(**action).is_empty())
+                    .map(|&action| format!("{}={}", self.key_bindings.label(action), self.locale.text(&format!("action-{}", action.name()))))
+                    .collect();
+                info!(target: "audio", "   {}: {}", self.locale.text(&format!("context-{}", context.name())), controls.join(" | "));
+            }
+            info!(target: "audio", "🎵 ════════════════════════════");
+        } else {
+            info!(target: "audio", "{}", self.locale.text("status-no-audio"));
+        }
+    }
+
+    /// Enhanced chaos event mapping for real-time audio responsiveness
+    fn handle_enhanced_audio_chaos_mapping(&mut self, audio_engine: &mut AudioConsciousnessEngine) {
+        // Map reality distortion to audio chaos
+        if self.reality_distortion.emergence_amplification > 0.8 {
+            let chaos_event = CompatChaosEvent::RealityTear {
+                strength: self.reality_distortion.emergence_amplification,
+                position: self.cursor_position,
+            };
+            audio_engine.handle_chaos_event(&chaos_event);
+        }
+
+        // Map total consciousness levels to audio events
+        match self.total_consciousness {
+            c if c > 200.0 && self.beat_intensity > 0.9 => {
+                let chaos_event = CompatChaosEvent::RealityTear {
+                    strength: 1.0,
+                    position: Vec2::new(600.0, 400.0),
+                };
+                audio_engine.handle_chaos_event(&chaos_event);
+                debug!(target: "audio", "🌌 MAXIMUM PSYCHEDELIC AUDIO OVERLOAD - REALITY CONSCIOUSNESS BREACH! 🌌");
+            },
+            c if c > 100.0 => {
+                // High consciousness - increase audio chaos frequency
+                if self.time % 2.0 < 0.1 { // Trigger every 2 seconds briefly
+                    let chaos_event = CompatChaosEvent::LlamaSpawned {
+                        consciousness: c / 10.0,
+                    };
+                    audio_engine.handle_chaos_event(&chaos_event);
+                }
+            },
+            _ => {}
+        }
+
+        // Map llama movement and interactions to audio
+        let total_movement_energy: f32 = self.world.components::<Llama>().iter()
+            .map(|llama| llama.velocity.length() * llama.trip_intensity)
+            .sum();
+
+        if total_movement_energy > 50.0 {
+            // High energy movement triggers crystal harvest sounds
+            let chaos_event = CompatChaosEvent::CrystalHarvested;
+            audio_engine.handle_chaos_event(&chaos_event);
+        }
+
+        // Map beat drops to audio events
+        if self.beat_intensity > 0.95 {
+            // Major beat drop - spawn audio event
+            let chaos_event = CompatChaosEvent::LlamaSpawned {
+                consciousness: self.beat_intensity * 10.0,
+            };
+            audio_engine.handle_chaos_event(&chaos_event);
+        }
+
+        // Map fractal complexity to audio
+        if self.reality_distortion.emergence_amplification > 0.7 {
+            // High fractal complexity creates reality tears
+            let tear_strength = (self.reality_distortion.emergence_amplification - 0.5) * 2.0;
+            let chaos_event = CompatChaosEvent::RealityTear {
+                strength: tear_strength,
+                position: Vec2::new(
+                    300.0 + (self.time * 100.0).sin() * 200.0,
+                    300.0 + (self.time * 150.0).cos() * 200.0,
+                ),
+            };
+            audio_engine.handle_chaos_event(&chaos_event);
+        }
+
+        // Map species warfare to audio chaos
+        let warfare_intensity = self.consciousness_multiplication.warfare_state.active_conflicts.len() as f32 * 0.1;
+        if warfare_intensity > 0.3 {
+            // Species conflict creates audio distortion
+            let chaos_event = CompatChaosEvent::RealityTear {
+                strength: warfare_intensity.min(1.0),
+                position: self.cursor_position,
+            };
+            audio_engine.handle_chaos_event(&chaos_event);
+        }
+    }
+}
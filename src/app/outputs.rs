@@ -0,0 +1,164 @@
+// === OUTPUT WINDOWS ===
+// Extra windows showing the same frame as the primary window through their
+// own viewport, e.g. the outer projectors of a projector wall. They share the
+// primary window's device, pipelines and uniforms; only the surface and the
+// vertex buffer belong to each window. Each window also runs its own flash
+// analysis over the part of the world it shows, since a flash filling one
+// projector can be lost in the whole-world average, and closing one only
+// takes that panel down.
+
+use std::sync::Arc;
+use glam::{Vec2, Vec3};
+use tracing::warn;
+use wgpu::*;
+use winit::{dpi::PhysicalSize, window::{Window, WindowId}};
+
+use crate::engine::safety::{FrameAnalyzer, FrameSummary, SafetyConfig, SafetyViolation};
+use crate::error::{BloomError, Result};
+use crate::reality::Vertex;
+use crate::rendering::Viewport;
+
+pub struct OutputWindow {
+    window: Arc<Window>,
+    surface: Surface<'static>,
+    config: SurfaceConfiguration,
+    viewport: Viewport,
+    vertex_buffer: Option<Buffer>, // Grown to the largest frame seen so far
+    analyzer: FrameAnalyzer,       // Flash analysis of just this window's part of the world
+}
+
+impl OutputWindow {
+    /// Attach a surface to `window`; it must accept the primary window's format
+    pub fn new(
+        instance: &Instance,
+        adapter: &Adapter,
+        device: &Device,
+        primary: &SurfaceConfiguration,
+        window: Arc<Window>,
+        viewport: Viewport,
+        safety: SafetyConfig,
+    ) -> Result<Self> {
+        let size = window.inner_size();
+        let surface = instance.create_surface(window.clone())?;
+        let caps = surface.get_capabilities(adapter);
+        if !caps.formats.contains(&primary.format) {
+            return Err(BloomError::OutputFormat(primary.format));
+        }
+
+        let config = SurfaceConfiguration {
+            width: size.width.max(1),
+            height: size.height.max(1),
+            present_mode: if caps.present_modes.contains(&primary.present_mode) { primary.present_mode } else { PresentMode::Fifo },
+            alpha_mode: caps.alpha_modes.first().copied().unwrap_or(CompositeAlphaMode::Auto),
+            ..primary.clone()
+        };
+        surface.configure(device, &config);
+
+        Ok(Self { window, surface, config, viewport, vertex_buffer: None, analyzer: FrameAnalyzer::new(safety) })
+    }
+
+    pub fn id(&self) -> WindowId {
+        self.window.id()
+    }
+
+    pub fn viewport(&self) -> Viewport {
+        self.viewport
+    }
+
+    pub fn set_safety(&mut self, safety: SafetyConfig) {
+        self.analyzer.set_config(safety);
+    }
+
+    /// Flash analysis of the triangles of the whole-world frame that fall in this window's viewport
+    pub fn analyze(&mut self, time: f64, world_vertices: &[Vertex]) -> Vec<SafetyViolation> {
+        let shown = world_vertices.chunks_exact(3).filter(|triangle| {
+            let center = triangle.iter().map(|vertex| Vec2::new(vertex.position[0], vertex.position[1])).sum::<Vec2>() / 3.0;
+            center.cmpge(self.viewport.min).all() && center.cmplt(self.viewport.max).all()
+        });
+        self.analyzer.analyze(FrameSummary::from_colors(time, shown.flatten().map(|vertex| Vec3::from(vertex.color))))
+    }
+
+    pub fn resize(&mut self, device: &Device, new_size: PhysicalSize<u32>) {
+        if new_size.width > 0 && new_size.height > 0 {
+            self.config.width = new_size.width;
+            self.config.height = new_size.height;
+            self.surface.configure(device, &self.config);
+        }
+    }
+
+    /// Draw the whole-world frame through this window's viewport
+    pub fn render(
+        &mut self,
+        device: &Device,
+        queue: &Queue,
+        pipeline: &RenderPipeline,
+        uniforms: &BindGroup,
+        world_vertices: &[Vertex],
+        background: Vec3,
+    ) -> Result<(), SurfaceError> {
+        let output = match self.surface.get_current_texture() {
+            Ok(output) => output,
+            Err(SurfaceError::Lost | SurfaceError::Outdated) => {
+                // Skip this frame; the next one draws to the rebuilt surface
+                self.surface.configure(device, &self.config);
+                return Ok(());
+            }
+            Err(e) => return Err(e),
+        };
+        let view = output.texture.create_view(&TextureViewDescriptor::default());
+
+        let vertices = self.viewport.project_vertices(world_vertices);
+        let needed = std::mem::size_of_val(vertices.as_slice()) as u64;
+        if self.vertex_buffer.as_ref().is_none_or(|buffer| buffer.size() < needed) {
+            self.vertex_buffer = Some(device.create_buffer(&BufferDescriptor {
+                label: Some("Output Vertex Buffer"),
+                size: needed.max(std::mem::size_of::<Vertex>() as u64),
+                usage: BufferUsages::VERTEX | BufferUsages::COPY_DST,
+                mapped_at_creation: false,
+            }));
+        }
+        let Some(buffer) = &self.vertex_buffer else {
+            warn!(target: "render", "🖥️ Output window has no vertex buffer");
+            return Ok(());
+        };
+        if !vertices.is_empty() {
+            queue.write_buffer(buffer, 0, bytemuck::cast_slice(&vertices));
+        }
+
+        let mut encoder = device.create_command_encoder(&CommandEncoderDescriptor {
+            label: Some("Output Render Encoder"),
+        });
+        {
+            let mut render_pass = encoder.begin_render_pass(&RenderPassDescriptor {
+                label: Some("Output Render Pass"),
+                color_attachments: &[Some(RenderPassColorAttachment {
+                    view: &view,
+                    resolve_target: None,
+                    ops: Operations {
+                        load: LoadOp::Clear(Color {
+                            r: background.x as f64,
+                            g: background.y as f64,
+                            b: background.z as f64,
+                            a: 1.0,
+                        }),
+                        store: StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+
+            if !vertices.is_empty() {
+                render_pass.set_pipeline(pipeline);
+                render_pass.set_bind_group(0, uniforms, &[]);
+                render_pass.set_vertex_buffer(0, buffer.slice(..needed));
+                render_pass.draw(0..vertices.len() as u32, 0..1);
+            }
+        }
+
+        queue.submit(std::iter::once(encoder.finish()));
+        output.present();
+        Ok(())
+    }
+}
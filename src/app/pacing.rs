@@ -0,0 +1,97 @@
+// === FRAME PACING ===
+// Present-mode choice and the optional CPU frame-rate cap. Capping happens in
+// the event loop (ControlFlow::WaitUntil), so a 30 fps cap really idles the
+// CPU and GPU between frames instead of spinning on vsync.
+
+use std::time::{Duration, Instant};
+use tracing::warn;
+use wgpu::PresentMode;
+
+/// Lowest accepted frame-rate cap
+const MIN_FPS: u32 = 1;
+/// The simulation always advances in 60 Hz ticks
+const TICK: Duration = Duration::from_micros(16_667);
+/// Ticks run for one frame at most; anything beyond is dropped, not replayed
+const MAX_TICKS_PER_FRAME: u32 = 4;
+
+/// Decouples simulation speed from frame rate: a 30 fps cap runs two ticks per frame
+pub struct FixedTimestep {
+    accumulator: Duration,
+    last: Instant,
+}
+
+impl FixedTimestep {
+    pub fn new(now: Instant) -> Self {
+        Self { accumulator: Duration::ZERO, last: now }
+    }
+
+    /// Number of simulation ticks due at `now`
+    pub fn ticks(&mut self, now: Instant) -> u32 {
+        self.accumulator += now.saturating_duration_since(self.last);
+        self.last = now;
+
+        let mut ticks = 0;
+        while self.accumulator >= TICK && ticks < MAX_TICKS_PER_FRAME {
+            self.accumulator -= TICK;
+            ticks += 1;
+        }
+        if ticks == MAX_TICKS_PER_FRAME {
+            self.accumulator = Duration::ZERO; // A long stall slows time instead of spiralling
+        }
+        ticks
+    }
+}
+
+/// `requested` if the surface supports it, otherwise vsync (always supported)
+pub fn select_present_mode(requested: PresentMode, supported: &[PresentMode]) -> PresentMode {
+    if supported.contains(&requested) {
+        requested
+    } else {
+        warn!(target: "render", "🖥️ Present mode {:?} unsupported (have {:?}) - using Fifo", requested, supported);
+        PresentMode::Fifo
+    }
+}
+
+/// Time between frames for a frame-rate cap
+pub fn fps_interval(max_fps: u32) -> Duration {
+    Duration::from_secs_f64(1.0 / max_fps.max(MIN_FPS) as f64)
+}
+
+/// The slower of two optional frame intervals
+pub fn slowest_interval(a: Option<Duration>, b: Option<Duration>) -> Option<Duration> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(a.max(b)),
+        (a, b) => a.or(b),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_present_mode_falls_back_and_intervals_combine() {
+        let supported = [PresentMode::Fifo, PresentMode::Mailbox];
+        assert_eq!(select_present_mode(PresentMode::Mailbox, &supported), PresentMode::Mailbox);
+        assert_eq!(select_present_mode(PresentMode::Immediate, &supported), PresentMode::Fifo);
+
+        assert_eq!(fps_interval(0), Duration::from_secs(1));
+        let thirty = fps_interval(30);
+        let fifteen = fps_interval(15);
+        assert_eq!(slowest_interval(Some(thirty), Some(fifteen)), Some(fifteen));
+        assert_eq!(slowest_interval(None, Some(thirty)), Some(thirty));
+        assert_eq!(slowest_interval(None, None), None);
+    }
+
+    #[test]
+    fn test_fixed_timestep_keeps_simulation_speed() {
+        let start = Instant::now();
+        let mut timestep = FixedTimestep::new(start);
+        let total: u32 = (1..=30).map(|frame| timestep.ticks(start + fps_interval(30) * frame)).sum();
+        assert!((59..=60).contains(&total), "{} ticks in one second at 30 fps", total);
+
+        // A ten second stall catches up at most one frame's worth
+        assert_eq!(timestep.ticks(start + Duration::from_secs(11)), MAX_TICKS_PER_FRAME);
+        assert_eq!(timestep.ticks(start + Duration::from_secs(11)), 0);
+    }
+}
@@ -0,0 +1,343 @@
+// === PHOTO MODE ===
+// Freezes the organism so a shot can be composed: a free camera pans and
+// zooms over the paused world, exposure and palette can be nudged, and the HUD
+// is hidden. One key renders the current framing offscreen at a multiple of
+// the window resolution, supersampled, and saves it as a PNG. Photos show the
+// graded, safety-checked world; reality-tear distortion is left out.
+
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::Path;
+use glam::{Vec2, Vec3};
+use wgpu::*;
+
+use crate::engine::safety::{hsv_to_rgb_vec3, is_dangerous_red, rgb_to_hsv};
+use crate::error::{BloomError, Result};
+use crate::reality::Vertex;
+use crate::rendering::{Viewport, WORLD_SIZE};
+use super::keybindings::Action;
+
+/// Saved photos are this many times the window resolution
+pub const PHOTO_SCALE: u32 = 2;
+/// Each saved pixel averages SUPERSAMPLE x SUPERSAMPLE rendered pixels
+pub const SUPERSAMPLE: u32 = 2;
+/// Narrowest camera view, in world units
+const MIN_CAMERA_WIDTH: f32 = 150.0;
+/// Share of the view one pan key press moves the camera
+const PAN_STEP: f32 = 0.1;
+const ZOOM_STEP: f32 = 1.25;
+const EXPOSURE_STEP: f32 = 0.1;
+const MIN_EXPOSURE: f32 = 0.4;
+const MAX_EXPOSURE: f32 = 1.6;
+/// Palette rotation per key press, in degrees
+const HUE_STEP: f32 = 30.0;
+
+/// What a key press did in photo mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PhotoInput {
+    Adjusted, // Camera or grading changed
+    Capture,  // Save a photo of the current framing
+    Ignored,  // Not a photo key; it keeps its usual binding
+}
+
+pub struct PhotoMode {
+    pub camera: Viewport,
+    pub exposure: f32,   // Brightness multiplier, 1 = as rendered
+    pub hue_shift: f32,  // Palette rotation in degrees
+    initial_camera: Viewport,
+}
+
+impl PhotoMode {
+    /// Start composing from what the window currently shows
+    pub fn new(viewport: Viewport) -> Self {
+        Self { camera: viewport, exposure: 1.0, hue_shift: 0.0, initial_camera: viewport }
+    }
+
+    /// Move the camera by `direction` view-widths times the pan step, staying inside the world
+    pub fn pan(&mut self, direction: Vec2) {
+        let size = self.camera.max - self.camera.min;
+        self.place(self.camera.min + direction * size * PAN_STEP, size);
+    }
+
+    /// Positive steps zoom in around the view center, negative zoom out
+    pub fn zoom(&mut self, steps: f32) {
+        let size = self.camera.max - self.camera.min;
+        let center = (self.camera.min + self.camera.max) * 0.5;
+        let width = (size.x * ZOOM_STEP.powf(-steps)).clamp(MIN_CAMERA_WIDTH, WORLD_SIZE.x);
+        let size = Vec2::new(width, width * size.y / size.x.max(1.0)).min(WORLD_SIZE);
+        self.place(center - size * 0.5, size);
+    }
+
+    pub fn adjust_exposure(&mut self, steps: f32) {
+        self.exposure = (self.exposure + steps * EXPOSURE_STEP).clamp(MIN_EXPOSURE, MAX_EXPOSURE);
+    }
+
+    pub fn shift_palette(&mut self, steps: f32) {
+        self.hue_shift = (self.hue_shift + steps * HUE_STEP).rem_euclid(360.0);
+    }
+
+    /// Back to the framing and colors photo mode started with
+    pub fn reset(&mut self) {
+        *self = Self::new(self.initial_camera);
+    }
+
+    /// Pan, zoom, exposure, palette and reset adjust the shot; saving captures it
+    pub fn handle_action(&mut self, action: Action) -> PhotoInput {
+        match action {
+            Action::SavePhoto => return PhotoInput::Capture,
+            Action::PanUp => self.pan(Vec2::NEG_Y),
+            Action::PanDown => self.pan(Vec2::Y),
+            Action::PanLeft => self.pan(Vec2::NEG_X),
+            Action::PanRight => self.pan(Vec2::X),
+            Action::ZoomIn => self.zoom(1.0),
+            Action::ZoomOut => self.zoom(-1.0),
+            Action::ExposureDown => self.adjust_exposure(-1.0),
+            Action::ExposureUp => self.adjust_exposure(1.0),
+            Action::PaletteBack => self.shift_palette(-1.0),
+            Action::PaletteForward => self.shift_palette(1.0),
+            Action::ResetCamera => self.reset(),
+            _ => return PhotoInput::Ignored,
+        }
+        PhotoInput::Adjusted
+    }
+
+    /// Apply exposure and palette rotation to world vertices; reds are kept out of flash range
+    pub fn grade(&self, vertices: &mut [Vertex]) {
+        if self.exposure == 1.0 && self.hue_shift == 0.0 {
+            return;
+        }
+        for vertex in vertices {
+            let mut color = Vec3::from(vertex.color);
+            if self.hue_shift != 0.0 {
+                let hsv = rgb_to_hsv(color);
+                color = hsv_to_rgb_vec3(Vec3::new((hsv.x + self.hue_shift) % 360.0, hsv.y, hsv.z));
+            }
+            color = (color * self.exposure).clamp(Vec3::ZERO, Vec3::ONE);
+            if is_dangerous_red(color) {
+                color.y = color.y.max(0.3);
+            }
+            vertex.color = color.into();
+        }
+    }
+
+    fn place(&mut self, min: Vec2, size: Vec2) {
+        let min = min.clamp(Vec2::ZERO, (WORLD_SIZE - size).max(Vec2::ZERO));
+        self.camera = Viewport { min, max: min + size };
+    }
+}
+
+/// Saved and rendered sizes of a photo of a `window`-sized frame, kept under the GPU's texture limit;
+/// the scale, then the supersampling, give way first, then the photo shrinks below the window
+pub fn photo_sizes(window: (u32, u32), max_dimension: u32) -> ((u32, u32), (u32, u32)) {
+    let (width, height) = (window.0.max(1), window.1.max(1));
+    let max_dimension = max_dimension.max(1);
+    let largest = width.max(height);
+    let scale = PHOTO_SCALE.min((max_dimension / SUPERSAMPLE / largest).max(1));
+    let saved = if largest * scale > max_dimension {
+        let shrink = |side: u32| ((side as u64 * max_dimension as u64 / largest as u64) as u32).max(1);
+        (shrink(width), shrink(height))
+    } else {
+        (width * scale, height * scale)
+    };
+    let supersample = SUPERSAMPLE.min(max_dimension / saved.0.max(saved.1)).max(1);
+    (saved, (saved.0 * supersample, saved.1 * supersample))
+}
+
+/// Box-filter tightly packed RGBA pixels down by `factor` in each direction
+pub fn downsample(pixels: &[u8], width: u32, height: u32, factor: u32) -> Vec<u8> {
+    let factor = factor.max(1);
+    let (out_width, out_height) = (width / factor, height / factor);
+    let samples = factor * factor;
+    let mut out = Vec::with_capacity((out_width * out_height * 4) as usize);
+    for y in 0..out_height {
+        for x in 0..out_width {
+            let mut sum = [0u32; 4];
+            for sy in 0..factor {
+                for sx in 0..factor {
+                    let index = (((y * factor + sy) * width + x * factor + sx) * 4) as usize;
+                    for (channel, total) in sum.iter_mut().enumerate() {
+                        *total += pixels[index + channel] as u32;
+                    }
+                }
+            }
+            out.extend(sum.iter().map(|total| ((total + samples / 2) / samples) as u8));
+        }
+    }
+    out
+}
+
+/// Render safety-checked whole-world vertices through `camera` offscreen, supersampled,
+/// and save the result to `path`; returns the saved size
+#[allow(clippy::too_many_arguments)]
+pub fn capture(
+    device: &Device,
+    queue: &Queue,
+    pipeline: &RenderPipeline,
+    uniforms: &BindGroup,
+    world_vertices: &[Vertex],
+    camera: &Viewport,
+    background: Vec3,
+    format: TextureFormat,
+    window: (u32, u32),
+    path: &Path,
+) -> Result<(u32, u32)> {
+    let bgra = match format {
+        TextureFormat::Bgra8Unorm | TextureFormat::Bgra8UnormSrgb => true,
+        TextureFormat::Rgba8Unorm | TextureFormat::Rgba8UnormSrgb => false,
+        other => return Err(BloomError::PhotoFormat(other)),
+    };
+    let (saved, (width, height)) = photo_sizes(window, device.limits().max_texture_dimension_2d);
+
+    let texture = device.create_texture(&TextureDescriptor {
+        label: Some("Photo Texture"),
+        size: Extent3d { width, height, depth_or_array_layers: 1 },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: TextureDimension::D2,
+        format,
+        usage: TextureUsages::RENDER_ATTACHMENT | TextureUsages::COPY_SRC,
+        view_formats: &[],
+    });
+    let view = texture.create_view(&TextureViewDescriptor::default());
+
+    let vertices = camera.project_vertices(world_vertices);
+    let vertex_buffer = device.create_buffer(&BufferDescriptor {
+        label: Some("Photo Vertex Buffer"),
+        size: (std::mem::size_of_val(vertices.as_slice()) as u64).max(std::mem::size_of::<Vertex>() as u64),
+        usage: BufferUsages::VERTEX | BufferUsages::COPY_DST,
+        mapped_at_creation: false,
+    });
+    if !vertices.is_empty() {
+        queue.write_buffer(&vertex_buffer, 0, bytemuck::cast_slice(&vertices));
+    }
+
+    // Rows of a texture copy must be padded to the copy alignment
+    let padded_row = (width * 4).div_ceil(COPY_BYTES_PER_ROW_ALIGNMENT) * COPY_BYTES_PER_ROW_ALIGNMENT;
+    let readback = device.create_buffer(&BufferDescriptor {
+        label: Some("Photo Readback Buffer"),
+        size: padded_row as u64 * height as u64,
+        usage: BufferUsages::COPY_DST | BufferUsages::MAP_READ,
+        mapped_at_creation: false,
+    });
+
+    let mut encoder = device.create_command_encoder(&CommandEncoderDescriptor {
+        label: Some("Photo Encoder"),
+    });
+    {
+        let mut render_pass = encoder.begin_render_pass(&RenderPassDescriptor {
+            label: Some("Photo Render Pass"),
+            color_attachments: &[Some(RenderPassColorAttachment {
+                view: &view,
+                resolve_target: None,
+                ops: Operations {
+                    load: LoadOp::Clear(Color {
+                        r: background.x as f64,
+                        g: background.y as f64,
+                        b: background.z as f64,
+                        a: 1.0,
+                    }),
+                    store: StoreOp::Store,
+                },
+            })],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+        if !vertices.is_empty() {
+            render_pass.set_pipeline(pipeline);
+            render_pass.set_bind_group(0, uniforms, &[]);
+            render_pass.set_vertex_buffer(0, vertex_buffer.slice(..));
+            render_pass.draw(0..vertices.len() as u32, 0..1);
+        }
+    }
+    encoder.copy_texture_to_buffer(
+        ImageCopyTexture { texture: &texture, mip_level: 0, origin: Origin3d::ZERO, aspect: TextureAspect::All },
+        ImageCopyBuffer {
+            buffer: &readback,
+            layout: ImageDataLayout { offset: 0, bytes_per_row: Some(padded_row), rows_per_image: Some(height) },
+        },
+        Extent3d { width, height, depth_or_array_layers: 1 },
+    );
+    queue.submit(std::iter::once(encoder.finish()));
+
+    let slice = readback.slice(..);
+    let (sender, receiver) = std::sync::mpsc::channel();
+    slice.map_async(MapMode::Read, move |result| {
+        let _ = sender.send(result);
+    });
+    device.poll(Maintain::Wait);
+    receiver.recv().map_err(|_| BloomError::PhotoReadback(BufferAsyncError))??;
+
+    let mut pixels = Vec::with_capacity((width * height * 4) as usize);
+    for row in slice.get_mapped_range().chunks_exact(padded_row as usize) {
+        pixels.extend_from_slice(&row[..(width * 4) as usize]);
+    }
+    readback.unmap();
+    if bgra {
+        for pixel in pixels.chunks_exact_mut(4) {
+            pixel.swap(0, 2);
+        }
+    }
+    for pixel in pixels.chunks_exact_mut(4) {
+        pixel[3] = 255; // Photos are opaque whatever the pipeline wrote to alpha
+    }
+    let image = downsample(&pixels, width, height, width / saved.0);
+
+    let photo_error = |source: png::EncodingError| BloomError::Photo { path: path.to_path_buf(), source };
+    let file = File::create(path).map_err(|e| photo_error(e.into()))?;
+    let mut encoder = png::Encoder::new(BufWriter::new(file), saved.0, saved.1);
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder.set_source_srgb(png::SrgbRenderingIntent::Perceptual);
+    let mut writer = encoder.write_header().map_err(photo_error)?;
+    writer.write_image_data(&image).map_err(photo_error)?;
+    Ok(saved)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_camera_stays_in_the_world_and_grading_keeps_reds_safe() {
+        let mut photo = PhotoMode::new(Viewport::full());
+        photo.pan(Vec2::X);
+        assert_eq!(photo.camera, Viewport::full(), "the full view has nowhere to pan");
+
+        photo.zoom(3.0);
+        let size = photo.camera.max - photo.camera.min;
+        assert!(size.x < WORLD_SIZE.x && (size.x / size.y - 1.5).abs() < 1e-3);
+        for _ in 0..50 {
+            photo.pan(Vec2::new(-1.0, 1.0));
+        }
+        assert_eq!(photo.camera.min.x, 0.0);
+        assert!((photo.camera.max.y - WORLD_SIZE.y).abs() < 1e-3);
+
+        assert_eq!(photo.handle_action(Action::CycleTheme), PhotoInput::Ignored);
+        assert_eq!(photo.handle_action(Action::SavePhoto), PhotoInput::Capture);
+        photo.handle_action(Action::ExposureUp);
+        photo.shift_palette(-1.0);
+        let mut vertices = vec![Vertex { color: [0.9, 0.1, 0.9], ..bytemuck::Zeroable::zeroed() }];
+        photo.grade(&mut vertices);
+        assert!(!is_dangerous_red(Vec3::from(vertices[0].color)), "{:?}", vertices[0].color);
+
+        photo.handle_action(Action::ResetCamera);
+        assert_eq!(photo.camera, Viewport::full());
+        assert_eq!((photo.exposure, photo.hue_shift), (1.0, 0.0));
+    }
+
+    #[test]
+    fn test_photo_sizes_and_downsampling() {
+        assert_eq!(photo_sizes((1200, 800), 8192), ((2400, 1600), (4800, 3200)));
+        let ((width, _), (render_width, _)) = photo_sizes((3000, 2000), 8192);
+        assert_eq!(width, 3000, "a large window is saved at its own size rather than past the limit");
+        assert!(render_width <= 8192);
+        // A small texture limit costs the supersampling, then the size, but is never exceeded
+        assert_eq!(photo_sizes((1200, 800), 2000), ((1200, 800), (1200, 800)));
+        assert_eq!(photo_sizes((1200, 800), 600), ((600, 400), (600, 400)));
+        assert_eq!(photo_sizes((400, 300), 1000), ((400, 300), (800, 600)));
+
+        let pixels = [0, 0, 0, 255, 255, 255, 255, 255, 100, 100, 100, 255, 100, 100, 100, 255];
+        assert_eq!(downsample(&pixels, 2, 2, 2), vec![114, 114, 114, 255]);
+    }
+}
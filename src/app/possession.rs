@@ -0,0 +1,69 @@
+// === POSSESSION CONTROLS ===
+// Keyboard state for driving a possessed llama. The steering keys (WASD and
+// the arrows by default) are held down, not tapped, so both presses and
+// releases are tracked; while a llama is possessed these keys steer it instead
+// of their usual bindings.
+
+use glam::Vec2;
+use crate::core::ecs::EntityId;
+use super::keybindings::Action;
+
+pub struct PossessionControls {
+    entity: EntityId,
+    started: f32,      // Organism time the possession began
+    held: [bool; 4],   // Up, down, left, right
+}
+
+impl PossessionControls {
+    pub fn new(entity: EntityId, time: f32) -> Self {
+        Self { entity, started: time, held: [false; 4] }
+    }
+
+    pub fn entity(&self) -> EntityId {
+        self.entity
+    }
+
+    pub fn seconds(&self, time: f32) -> f32 {
+        time - self.started
+    }
+
+    /// Track a steering key; false when `action` does not steer, so the key keeps its usual binding
+    pub fn handle_action(&mut self, action: Action, pressed: bool) -> bool {
+        let slot = match action {
+            Action::SteerUp => 0,
+            Action::SteerDown => 1,
+            Action::SteerLeft => 2,
+            Action::SteerRight => 3,
+            _ => return false,
+        };
+        self.held[slot] = pressed;
+        true
+    }
+
+    /// Unit steering direction from the held keys, zero when none or opposing ones are held
+    pub fn direction(&self) -> Vec2 {
+        let axis = |positive: bool, negative: bool| positive as i32 as f32 - negative as i32 as f32;
+        let direction = Vec2::new(axis(self.held[3], self.held[2]), axis(self.held[1], self.held[0])); // Screen y grows downwards
+        direction.normalize_or_zero()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_held_keys_steer() {
+        let mut controls = PossessionControls::new(7, 10.0);
+        assert!(controls.handle_action(Action::SteerUp, true));
+        assert!(controls.handle_action(Action::SteerRight, true));
+        assert!(!controls.handle_action(Action::CycleTheme, true), "other keys keep their bindings");
+        let direction = controls.direction();
+        assert!(direction.x > 0.0 && direction.y < 0.0 && (direction.length() - 1.0).abs() < 1e-5);
+
+        controls.handle_action(Action::SteerUp, false);
+        controls.handle_action(Action::SteerRight, false);
+        assert_eq!(controls.direction(), Vec2::ZERO);
+        assert_eq!(controls.seconds(25.0), 15.0);
+    }
+}
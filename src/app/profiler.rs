@@ -0,0 +1,218 @@
+// === FRAME-TIME PROFILER ===
+// Times each subsystem of the update and render paths so a slow frame can be
+// pinned on the part that is slow. Stages are timed as laps: each call to
+// `lap` charges the time since the previous lap to one stage. Several 60 Hz
+// ticks in one frame add up in the same frame. Averages are smoothed over
+// roughly a second, drawn as a stacked bar against the 60 fps budget, and
+// logged periodically for hosts that collect tracing output.
+
+use std::time::{Duration, Instant};
+use glam::{Vec2, Vec3};
+use tracing::debug;
+use crate::engine::safety::hsv_to_rgb_vec3;
+use crate::reality::{text, BufferUsageReport, Vertex};
+
+/// Parts of a frame the profiler tells apart
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProfileStage {
+    BeatEngine,
+    Ecosystem,
+    MetaConsciousness, // Meta-consciousness framework and reality distortion
+    Communication,     // Emergent communication, event bus and user co-evolution
+    Multiplication,    // Hives, warfare, predation, population cap and zones
+    Audio,
+    Llamas,            // Movement, animation, mutations and history snapshots
+    VertexGeneration,  // World, effects and overlays
+    Safety,            // Luminance and flash analysis of the finished frame
+    GpuSubmit,         // Buffer upload, encoding, submit and present
+}
+
+impl ProfileStage {
+    pub const ALL: [ProfileStage; 10] = [
+        Self::BeatEngine, Self::Ecosystem, Self::MetaConsciousness, Self::Communication, Self::Multiplication,
+        Self::Audio, Self::Llamas, Self::VertexGeneration, Self::Safety, Self::GpuSubmit,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::BeatEngine => "BEAT",
+            Self::Ecosystem => "ECOSYSTEM",
+            Self::MetaConsciousness => "META",
+            Self::Communication => "COMMUNICATION",
+            Self::Multiplication => "MULTIPLICATION",
+            Self::Audio => "AUDIO",
+            Self::Llamas => "LLAMAS",
+            Self::VertexGeneration => "VERTICES",
+            Self::Safety => "SAFETY",
+            Self::GpuSubmit => "GPU SUBMIT",
+        }
+    }
+}
+
+/// Smoothed milliseconds per stage, as the overlay and the API report them
+#[derive(Debug, Clone, PartialEq)]
+pub struct FrameProfile {
+    pub stages: [(ProfileStage, f32); 10],
+    pub total_ms: f32,
+    pub peak_ms: f32, // Slowest recent frame, decaying over a few seconds
+}
+
+impl FrameProfile {
+    pub fn stage_ms(&self, stage: ProfileStage) -> f32 {
+        self.stages.iter().find(|(s, _)| *s == stage).map_or(0.0, |&(_, ms)| ms)
+    }
+
+    /// The stage taking the most time
+    pub fn slowest(&self) -> ProfileStage {
+        self.stages.iter().max_by(|a, b| a.1.total_cmp(&b.1)).map_or(ProfileStage::BeatEngine, |&(stage, _)| stage)
+    }
+}
+
+/// Share of a new frame in the running averages
+const SMOOTHING: f32 = 0.05;
+/// Per-frame decay of the peak frame time
+const PEAK_DECAY: f32 = 0.995;
+/// Frames between telemetry log lines
+const LOG_INTERVAL_FRAMES: u64 = 300;
+/// Frame budget at 60 fps, in milliseconds
+pub const FRAME_BUDGET_MS: f32 = 1000.0 / 60.0;
+/// Overlay placement, in screen units; the bar is this wide at one frame budget
+const BAR_ORIGIN: Vec2 = Vec2::new(20.0, 420.0);
+const BAR_BUDGET_WIDTH: f32 = 400.0;
+const BAR_HEIGHT: f32 = 12.0;
+
+pub struct FrameProfiler {
+    lap_start: Instant,
+    current: [Duration; 10],
+    average_ms: [f32; 10],
+    peak_ms: f32,
+    frames: u64,
+}
+
+impl Default for FrameProfiler {
+    fn default() -> Self {
+        Self { lap_start: Instant::now(), current: [Duration::ZERO; 10], average_ms: [0.0; 10], peak_ms: 0.0, frames: 0 }
+    }
+}
+
+fn stage_index(stage: ProfileStage) -> usize {
+    ProfileStage::ALL.iter().position(|&s| s == stage).unwrap_or(0)
+}
+
+impl FrameProfiler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start timing from now, dropping whatever ran since the last lap
+    pub fn restart(&mut self) {
+        self.lap_start = Instant::now();
+    }
+
+    /// Charge the time since the previous lap to `stage`
+    pub fn lap(&mut self, stage: ProfileStage) {
+        let now = Instant::now();
+        self.record(stage, now.saturating_duration_since(self.lap_start));
+        self.lap_start = now;
+    }
+
+    pub fn record(&mut self, stage: ProfileStage, elapsed: Duration) {
+        self.current[stage_index(stage)] += elapsed;
+    }
+
+    /// Fold the finished frame into the averages and start a new one
+    pub fn end_frame(&mut self) {
+        let mut total = 0.0;
+        for (average, current) in self.average_ms.iter_mut().zip(&mut self.current) {
+            let ms = current.as_secs_f32() * 1000.0;
+            *average += (ms - *average) * if self.frames == 0 { 1.0 } else { SMOOTHING };
+            total += ms;
+            *current = Duration::ZERO;
+        }
+        self.peak_ms = total.max(self.peak_ms * PEAK_DECAY);
+        self.frames += 1;
+
+        if self.frames.is_multiple_of(LOG_INTERVAL_FRAMES) {
+            let profile = self.profile();
+            let stages: Vec<String> = profile.stages.iter().map(|(stage, ms)| format!("{} {:.2}", stage.name().to_lowercase(), ms)).collect();
+            debug!(target: "app", "⏱️ Frame {:.2}ms (peak {:.2}ms): {}", profile.total_ms, profile.peak_ms, stages.join(" | "));
+        }
+    }
+
+    pub fn profile(&self) -> FrameProfile {
+        let mut stages = ProfileStage::ALL.map(|stage| (stage, 0.0));
+        for (entry, &ms) in stages.iter_mut().zip(&self.average_ms) {
+            entry.1 = ms;
+        }
+        FrameProfile { stages, total_ms: self.average_ms.iter().sum(), peak_ms: self.peak_ms }
+    }
+}
+
+/// Dim, distinct and never red: hues from amber round to violet
+fn stage_color(stage: ProfileStage) -> Vec3 {
+    let hue = 40.0 + stage_index(stage) as f32 * 26.0;
+    hsv_to_rgb_vec3(Vec3::new(hue, 0.6, 0.45))
+}
+
+/// Stacked bar of the stages against the frame budget, with a two-column legend below it
+pub fn push_profiler_overlay(vertices: &mut Vec<Vertex>, profile: &FrameProfile) {
+    let screen = Vec2::new(1200.0, 800.0);
+    let scale = BAR_BUDGET_WIDTH / FRAME_BUDGET_MS;
+    let bar_end = BAR_ORIGIN.x + BAR_BUDGET_WIDTH * 2.0; // Anything past two budgets is cut off
+    let mut x = BAR_ORIGIN.x;
+    for &(stage, ms) in &profile.stages {
+        let end = (x + ms * scale).min(bar_end);
+        if end > x {
+            text::push_rect(vertices, Vec2::new(x, BAR_ORIGIN.y), Vec2::new(end, BAR_ORIGIN.y + BAR_HEIGHT), stage_color(stage), screen);
+        }
+        x = end;
+    }
+    let budget_x = BAR_ORIGIN.x + BAR_BUDGET_WIDTH;
+    text::push_rect(vertices, Vec2::new(budget_x, BAR_ORIGIN.y - 4.0), Vec2::new(budget_x + 2.0, BAR_ORIGIN.y + BAR_HEIGHT + 4.0), Vec3::splat(0.4), screen);
+
+    let header = format!("FRAME {:.2} MS  PEAK {:.2} MS  SLOWEST {}", profile.total_ms, profile.peak_ms, profile.slowest().name());
+    text::push_text(vertices, &header, Vec2::new(BAR_ORIGIN.x, BAR_ORIGIN.y - 22.0), 2.0, Vec3::splat(0.45), screen);
+    for (index, &(stage, ms)) in profile.stages.iter().enumerate() {
+        let origin = BAR_ORIGIN + Vec2::new((index / 5) as f32 * 220.0, 22.0 + (index % 5) as f32 * 18.0);
+        text::push_rect(vertices, origin, origin + Vec2::splat(10.0), stage_color(stage), screen);
+        text::push_text(vertices, &format!("{} {:.2}", stage.name(), ms), origin + Vec2::new(16.0, 0.0), 2.0, Vec3::splat(0.4), screen);
+    }
+}
+
+/// One line under the legend: how full the vertex buffer is and how it got there
+pub fn push_buffer_usage(vertices: &mut Vec<Vertex>, report: &BufferUsageReport) {
+    let line = format!("BUFFER {}K PEAK {}K CAP {}K MAX {}K  {} RESIZES  {:.1} MB",
+        report.last_frame_vertices / 1000, report.peak_vertices / 1000, report.capacity / 1000, report.max_capacity / 1000,
+        report.resize_count, report.bytes_allocated as f64 / (1024.0 * 1024.0));
+    let color = if report.near_limit() { Vec3::new(0.6, 0.5, 0.25) } else { Vec3::splat(0.4) };
+    text::push_text(vertices, &line, BAR_ORIGIN + Vec2::new(0.0, 22.0 + 5.0 * 18.0 + 6.0), 2.0, color, Vec2::new(1200.0, 800.0));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::safety::is_dangerous_red;
+
+    #[test]
+    fn test_laps_are_charged_to_their_stage_and_averaged() {
+        let mut profiler = FrameProfiler::new();
+        profiler.record(ProfileStage::Ecosystem, Duration::from_millis(4));
+        profiler.record(ProfileStage::Ecosystem, Duration::from_millis(2)); // A second tick in the same frame
+        profiler.record(ProfileStage::GpuSubmit, Duration::from_millis(1));
+        profiler.end_frame();
+
+        let profile = profiler.profile();
+        assert!((profile.stage_ms(ProfileStage::Ecosystem) - 6.0).abs() < 0.01);
+        assert!((profile.total_ms - 7.0).abs() < 0.01);
+        assert_eq!(profile.slowest(), ProfileStage::Ecosystem);
+
+        profiler.end_frame(); // An empty frame only nudges the average down
+        let profile = profiler.profile();
+        assert!(profile.stage_ms(ProfileStage::Ecosystem) > 5.0 && profile.peak_ms > 6.9);
+
+        let mut vertices = Vec::new();
+        push_profiler_overlay(&mut vertices, &profile);
+        assert!(!vertices.is_empty());
+        assert!(vertices.iter().all(|vertex| !is_dangerous_red(Vec3::from(vertex.color))));
+    }
+}
@@ -0,0 +1,685 @@
+// === REMOTE CONTROL ===
+// An optional HTTP and WebSocket server so an installation can be looked after
+// from a tablet or a control room. `GET /status` answers with the populations,
+// the ecosystem's stability and the audio analysis as JSON; `POST /spawn`,
+// `/mode`, `/preset` and `/intervene` queue commands the organism carries out
+// on its next frame. `/ws` upgrades to a WebSocket that pushes the status
+// twice a second and takes the same commands as JSON messages, and `/stream`
+// to one carrying the binary state stream when that is on. Every request
+// must carry the configured token, as a bearer token or as a `token` query
+// parameter for browsers, which cannot set headers on a WebSocket. The server
+// needs nothing beyond std: one thread accepts, and each client gets its own.
+
+use std::collections::{BTreeMap, HashMap};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{Shutdown, SocketAddr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use glam::Vec2;
+use serde::Serialize;
+use serde_json::{json, Value};
+use tracing::{debug, info, warn};
+use crate::audio::AudioMode;
+use crate::engine::ObserverIntervention;
+use crate::entities::SpeciesType;
+use crate::mods::{intervention_named, species_named};
+use super::keybindings::SPEED_PRESETS;
+
+/// Clients served at once; more are turned away until one leaves
+const MAX_CLIENTS: usize = 16;
+/// Most llamas one spawn command may ask for
+const MAX_SPAWN_COUNT: u32 = 20;
+/// Largest request line, headers and body together, and largest WebSocket message
+const MAX_REQUEST_BYTES: u64 = 16 * 1024;
+/// How often WebSocket clients are sent the status
+const STATUS_PUSH_INTERVAL: Duration = Duration::from_millis(500);
+/// Longest a client may take to send its whole request, however it trickles the bytes in
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+/// How often `/stream` clients check for a new frame
+const STREAM_POLL: Duration = Duration::from_millis(5);
+/// How often the accepting thread checks whether it should stop
+const STOP_POLL: Duration = Duration::from_millis(100);
+/// Appended to a client's key to prove the server speaks WebSocket (RFC 6455)
+const WEBSOCKET_GUID: &str = "258EAFA5-E914-47DA-95CA-C5AB0DC85B11";
+
+/// Where the remote control server listens and the token clients must present
+#[derive(Clone, PartialEq)]
+pub struct RemoteControl {
+    pub address: String, // e.g. "0.0.0.0:8420" to accept the whole network
+    pub token: String,   // Shared secret; the server refuses to start without one
+}
+
+// By hand so the token stays out of logs, crash reports and observation journals
+impl std::fmt::Debug for RemoteControl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RemoteControl")
+            .field("address", &self.address)
+            .field("token", &"<redacted>")
+            .finish()
+    }
+}
+
+impl RemoteControl {
+    pub fn new(address: impl Into<String>, token: impl Into<String>) -> Self {
+        Self { address: address.into(), token: token.into() }
+    }
+}
+
+/// What a remote client asked the organism to do
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum RemoteCommand {
+    Spawn { species: SpeciesType, position: Option<Vec2>, count: u32 }, // Random positions when none is given
+    SetMode(AudioMode),
+    SpeedPreset(u8), // 1-9, as on the number keys
+    Intervene(ObserverIntervention),
+}
+
+/// What remote clients see of the organism
+#[derive(Debug, Clone, Default, Serialize)]
+pub(crate) struct RemoteStatus {
+    pub time: f32,
+    pub population: usize,
+    pub species: BTreeMap<String, u32>, // Living llamas per species
+    pub hybrids: Vec<String>,
+    pub stability: f32,                 // 0-1, as the meta-observer judges the ecosystem
+    pub warfare_intensity: f32,
+    pub active_conflicts: usize,
+    pub total_consciousness: f32,
+    pub beat_intensity: f32,
+    pub idle: bool,
+    pub audio: Option<RemoteAudioStatus>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct RemoteAudioStatus {
+    pub mode: String,
+    pub environment: String,
+    pub speed: f32,
+    pub volume: f32,
+    pub bass_level: f32,
+    pub treble_level: f32,
+    pub consciousness_frequency: f32,
+    pub loudness_lufs: Option<f32>,
+}
+
+/// The running server, listening until dropped
+pub(crate) struct RemoteServer {
+    address: SocketAddr,
+    commands: Receiver<RemoteCommand>,
+    status: Arc<Mutex<String>>, // Latest status as JSON, shared with every client
+    frames: Option<Arc<Mutex<StreamFrame>>>, // Latest state stream frame; None when streaming is off
+    stop: Arc<AtomicBool>,
+}
+
+/// Packets of the latest state stream frame and its sequence
+type StreamFrame = (u32, Arc<Vec<Vec<u8>>>);
+
+impl RemoteServer {
+    /// Listen at the configured address; `streaming` opens the `/stream` endpoint
+    pub fn start(config: &RemoteControl, streaming: bool) -> std::io::Result<Self> {
+        if config.token.is_empty() {
+            return Err(std::io::Error::new(std::io::ErrorKind::InvalidInput, "remote control needs a token"));
+        }
+        let listener = TcpListener::bind(&config.address)?;
+        listener.set_nonblocking(true)?;
+        let address = listener.local_addr()?;
+
+        let (sender, commands) = mpsc::channel();
+        let status = Arc::new(Mutex::new(serde_json::to_string(&RemoteStatus::default()).unwrap_or_default()));
+        let frames = streaming.then(|| Arc::new(Mutex::new((0, Arc::new(Vec::new())))));
+        let stop = Arc::new(AtomicBool::new(false));
+        let shared = Shared {
+            token: config.token.clone(),
+            commands: sender,
+            status: status.clone(),
+            frames: frames.clone(),
+            stop: stop.clone(),
+            clients: Arc::new(AtomicUsize::new(0)),
+        };
+        std::thread::Builder::new()
+            .name("remote control".to_string())
+            .spawn(move || accept_clients(listener, shared))?;
+        info!(target: "app", "📡 Remote control listening on {}", address);
+        Ok(Self { address, commands, status, frames, stop })
+    }
+
+    pub fn address(&self) -> SocketAddr {
+        self.address
+    }
+
+    /// Commands received since the last call, oldest first
+    pub fn drain(&self) -> Vec<RemoteCommand> {
+        self.commands.try_iter().collect()
+    }
+
+    pub fn publish(&self, status: &RemoteStatus) {
+        if let (Ok(json), Ok(mut shared)) = (serde_json::to_string(status), self.status.lock()) {
+            *shared = json;
+        }
+    }
+
+    /// Hand a state stream frame to the `/stream` clients
+    pub fn publish_frame(&self, sequence: u32, packets: Vec<Vec<u8>>) {
+        if let Some(Ok(mut frame)) = self.frames.as_ref().map(|frames| frames.lock()) {
+            *frame = (sequence, Arc::new(packets));
+        }
+    }
+}
+
+impl Drop for RemoteServer {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+    }
+}
+
+/// What every client thread needs from the server
+#[derive(Clone)]
+struct Shared {
+    token: String,
+    commands: Sender<RemoteCommand>,
+    status: Arc<Mutex<String>>,
+    frames: Option<Arc<Mutex<StreamFrame>>>,
+    stop: Arc<AtomicBool>,
+    clients: Arc<AtomicUsize>,
+}
+
+impl Shared {
+    fn status(&self) -> String {
+        self.status.lock().map(|status| status.clone()).unwrap_or_default()
+    }
+}
+
+fn accept_clients(listener: TcpListener, shared: Shared) {
+    while !shared.stop.load(Ordering::Relaxed) {
+        let stream = match listener.accept() {
+            Ok((stream, _)) => stream,
+            Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => {
+                std::thread::sleep(STOP_POLL);
+                continue;
+            }
+            Err(e) => {
+                warn!(target: "app", "📡 Remote control stopped accepting clients: {}", e);
+                return;
+            }
+        };
+        if shared.clients.fetch_add(1, Ordering::Relaxed) >= MAX_CLIENTS {
+            shared.clients.fetch_sub(1, Ordering::Relaxed);
+            continue;
+        }
+        let client = shared.clone();
+        let spawned = std::thread::Builder::new().name("remote client".to_string()).spawn(move || {
+            if let Err(e) = serve_client(stream, &client) {
+                debug!(target: "app", "📡 Remote client dropped: {}", e);
+            }
+            client.clients.fetch_sub(1, Ordering::Relaxed);
+        });
+        if spawned.is_err() {
+            shared.clients.fetch_sub(1, Ordering::Relaxed);
+        }
+    }
+}
+
+/// An HTTP request, as far as the server cares
+#[derive(Debug)]
+struct Request {
+    method: String,
+    path: String,
+    token: Option<String>, // From the `token` query parameter
+    headers: HashMap<String, String>, // Names lowercased
+    body: Vec<u8>,
+    early: Vec<u8>, // Bytes read past the request, such as a WebSocket frame sent before the handshake answer
+}
+
+/// Reads from a socket until a deadline, each read waiting only as long as is left
+struct DeadlineReader<'a> {
+    stream: &'a TcpStream,
+    deadline: Instant,
+}
+
+impl Read for DeadlineReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let left = self.deadline.saturating_duration_since(Instant::now());
+        if left.is_zero() {
+            return Err(std::io::Error::new(std::io::ErrorKind::TimedOut, "request took too long"));
+        }
+        self.stream.set_read_timeout(Some(left))?;
+        self.stream.read(buf)
+    }
+}
+
+/// The request on `stream`, which must arrive in full within `timeout`
+fn read_request(stream: &TcpStream, timeout: Duration) -> std::io::Result<Request> {
+    let deadline = DeadlineReader { stream, deadline: Instant::now() + timeout };
+    let mut reader = BufReader::new(deadline.take(MAX_REQUEST_BYTES));
+    let mut line = String::new();
+    reader.read_line(&mut line)?;
+    let mut parts = line.split_whitespace();
+    let (method, target) = (parts.next().unwrap_or_default().to_string(), parts.next().unwrap_or("/").to_string());
+    let (path, query) = target.split_once('?').unwrap_or((&target, ""));
+    let token = query.split('&').find_map(|pair| pair.strip_prefix("token=")).map(str::to_string);
+
+    let mut headers = HashMap::new();
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 || line.trim().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            headers.insert(name.trim().to_ascii_lowercase(), value.trim().to_string());
+        }
+    }
+
+    let length = headers.get("content-length").and_then(|length| length.parse().ok()).unwrap_or(0usize);
+    let mut body = vec![0; length.min(MAX_REQUEST_BYTES as usize)];
+    reader.read_exact(&mut body)?;
+    let early = reader.buffer().to_vec();
+    Ok(Request { method, path: path.to_string(), token, headers, body, early })
+}
+
+fn respond(mut stream: &TcpStream, status: &str, body: &str) -> std::io::Result<()> {
+    write!(stream, "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\
+                    Access-Control-Allow-Origin: *\r\nAccess-Control-Allow-Headers: Authorization, Content-Type\r\n\
+                    Access-Control-Allow-Methods: GET, POST\r\nConnection: close\r\n\r\n{}",
+           status, body.len(), body)
+}
+
+/// Compare without leaking how much of the token was right through timing
+fn token_matches(given: &str, expected: &str) -> bool {
+    given.len() == expected.len() && given.bytes().zip(expected.bytes()).fold(0, |diff, (a, b)| diff | (a ^ b)) == 0
+}
+
+fn serve_client(stream: TcpStream, shared: &Shared) -> std::io::Result<()> {
+    // Accepted sockets inherit the listener's non-blocking mode on some platforms
+    stream.set_nonblocking(false)?;
+    let request = read_request(&stream, REQUEST_TIMEOUT)?;
+    if request.method == "OPTIONS" {
+        return respond(&stream, "204 No Content", "");
+    }
+
+    let bearer = request.headers.get("authorization").and_then(|value| value.strip_prefix("Bearer ")).map(str::trim);
+    if !bearer.or(request.token.as_deref()).is_some_and(|token| token_matches(token, &shared.token)) {
+        return respond(&stream, "401 Unauthorized", r#"{"error":"missing or wrong token"}"#);
+    }
+
+    match (request.method.as_str(), request.path.as_str()) {
+        ("GET", "/status") => respond(&stream, "200 OK", &shared.status()),
+        ("GET", "/ws") => serve_websocket(stream, &request, shared),
+        ("GET", "/stream") => match &shared.frames {
+            Some(frames) => serve_stream(stream, &request, frames, shared),
+            None => respond(&stream, "404 Not Found", r#"{"error":"state streaming is off"}"#),
+        },
+        ("POST", path) => {
+            let body = serde_json::from_slice(&request.body).unwrap_or(Value::Null);
+            match parse_command(path.trim_start_matches('/'), &body) {
+                Ok(command) => {
+                    let _ = shared.commands.send(command);
+                    respond(&stream, "202 Accepted", r#"{"ok":true}"#)
+                }
+                Err(CommandError::Unknown) => respond(&stream, "404 Not Found", r#"{"error":"no such command"}"#),
+                Err(CommandError::Invalid(reason)) => respond(&stream, "400 Bad Request", &json!({ "error": reason }).to_string()),
+            }
+        }
+        (_, "/status" | "/ws" | "/stream") => respond(&stream, "405 Method Not Allowed", r#"{"error":"use GET"}"#),
+        _ => respond(&stream, "404 Not Found", r#"{"error":"no such endpoint"}"#),
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum CommandError {
+    Unknown,
+    Invalid(String),
+}
+
+/// A command from its name and JSON arguments, e.g. `spawn` with `{"species": "DiscoLlama", "count": 3}`
+fn parse_command(name: &str, body: &Value) -> Result<RemoteCommand, CommandError> {
+    let text = |field: &str| body.get(field).and_then(Value::as_str).ok_or_else(|| CommandError::Invalid(format!("'{}' is missing", field)));
+    match name {
+        "spawn" => {
+            let species = text("species")?;
+            let species = species_named(species).ok_or_else(|| CommandError::Invalid(format!("unknown species '{}'", species)))?;
+            let count = body.get("count").and_then(Value::as_u64).unwrap_or(1).clamp(1, MAX_SPAWN_COUNT as u64) as u32;
+            let position = body.get("x").and_then(Value::as_f64).zip(body.get("y").and_then(Value::as_f64))
+                .map(|(x, y)| Vec2::new(x as f32, y as f32));
+            Ok(RemoteCommand::Spawn { species, position, count })
+        }
+        "mode" => match text("mode")?.to_ascii_lowercase().as_str() {
+            "mellow" => Ok(RemoteCommand::SetMode(AudioMode::Mellow)),
+            "active" => Ok(RemoteCommand::SetMode(AudioMode::Active)),
+            "chaotic" => Ok(RemoteCommand::SetMode(AudioMode::Chaotic)),
+            mode => Err(CommandError::Invalid(format!("unknown mode '{}'", mode))),
+        },
+        "preset" => body.get("preset").and_then(Value::as_u64)
+            .filter(|preset| (1..=SPEED_PRESETS.len() as u64).contains(preset))
+            .map(|preset| RemoteCommand::SpeedPreset(preset as u8))
+            .ok_or_else(|| CommandError::Invalid(format!("'preset' must be 1-{}", SPEED_PRESETS.len()))),
+        "intervene" => {
+            let intervention = text("intervention")?;
+            intervention_named(intervention).map(RemoteCommand::Intervene)
+                .ok_or_else(|| CommandError::Invalid(format!("unknown intervention '{}'", intervention)))
+        }
+        _ => Err(CommandError::Unknown),
+    }
+}
+
+/// Answer the WebSocket handshake; false when the request was not one and has been refused
+fn accept_websocket(stream: &TcpStream, request: &Request) -> std::io::Result<bool> {
+    let Some(key) = request.headers.get("sec-websocket-key") else {
+        respond(stream, "400 Bad Request", r#"{"error":"not a WebSocket handshake"}"#)?;
+        return Ok(false);
+    };
+    write!(&*stream, "HTTP/1.1 101 Switching Protocols\r\nUpgrade: websocket\r\nConnection: Upgrade\r\nSec-WebSocket-Accept: {}\r\n\r\n",
+           websocket_accept(key))?;
+    stream.set_read_timeout(None)?;
+    Ok(true)
+}
+
+/// Finish the WebSocket handshake, then push the status while a reader thread takes commands
+fn serve_websocket(stream: TcpStream, request: &Request, shared: &Shared) -> std::io::Result<()> {
+    if !accept_websocket(&stream, request)? {
+        return Ok(());
+    }
+
+    let writer = Arc::new(Mutex::new(stream.try_clone()?));
+    let closed = Arc::new(AtomicBool::new(false));
+    let reader = std::thread::Builder::new().name("remote websocket".to_string()).spawn({
+        let (writer, closed, commands) = (writer.clone(), closed.clone(), shared.commands.clone());
+        let early = std::io::Cursor::new(request.early.clone());
+        move || {
+            let _ = read_messages(early.chain(stream), &writer, &commands);
+            closed.store(true, Ordering::Relaxed);
+        }
+    })?;
+
+    while !closed.load(Ordering::Relaxed) && !shared.stop.load(Ordering::Relaxed) {
+        let sent = writer.lock().map_err(|_| std::io::Error::from(std::io::ErrorKind::Other))
+            .and_then(|mut writer| write_frame(&mut *writer, OPCODE_TEXT, shared.status().as_bytes()));
+        if sent.is_err() {
+            break;
+        }
+        std::thread::sleep(STATUS_PUSH_INTERVAL);
+    }
+    // Wakes the reader if it is still waiting on the client
+    if let Ok(writer) = writer.lock() {
+        let _ = writer.shutdown(Shutdown::Both);
+    }
+    let _ = reader.join();
+    Ok(())
+}
+
+/// Send every new state stream frame as binary messages, one per packet, until the client goes away
+fn serve_stream(mut stream: TcpStream, request: &Request, frames: &Mutex<StreamFrame>, shared: &Shared) -> std::io::Result<()> {
+    if !accept_websocket(&stream, request)? {
+        return Ok(());
+    }
+    let mut sent = None;
+    while !shared.stop.load(Ordering::Relaxed) {
+        let (sequence, packets) = frames.lock().map(|frame| frame.clone()).map_err(|_| std::io::Error::from(std::io::ErrorKind::Other))?;
+        if sent != Some(sequence) && !packets.is_empty() {
+            for packet in packets.iter() {
+                write_frame(&mut stream, OPCODE_BINARY, packet)?;
+            }
+            sent = Some(sequence);
+        }
+        std::thread::sleep(STREAM_POLL);
+    }
+    Ok(())
+}
+
+const OPCODE_CONTINUATION: u8 = 0x0;
+const OPCODE_TEXT: u8 = 0x1;
+const OPCODE_BINARY: u8 = 0x2;
+const OPCODE_CLOSE: u8 = 0x8;
+const OPCODE_PING: u8 = 0x9;
+const OPCODE_PONG: u8 = 0xA;
+
+/// Take commands from the client until it closes; each is answered with `{"ok":true}` or an error.
+/// Fragmented messages are put back together, up to the same size limit as a whole one
+fn read_messages(mut stream: impl Read, writer: &Mutex<TcpStream>, commands: &Sender<RemoteCommand>) -> std::io::Result<()> {
+    let invalid = |message: &str| std::io::Error::new(std::io::ErrorKind::InvalidData, message.to_string());
+    let mut fragments: Option<(u8, Vec<u8>)> = None; // Opcode and payload so far of an unfinished message
+    loop {
+        let mut header = [0u8; 2];
+        stream.read_exact(&mut header)?;
+        let finished = header[0] & 0x80 != 0;
+        let opcode = header[0] & 0x0F;
+        if header[1] & 0x80 == 0 {
+            return Err(invalid("client frames must be masked"));
+        }
+        let length = match header[1] & 0x7F {
+            126 => {
+                let mut extended = [0u8; 2];
+                stream.read_exact(&mut extended)?;
+                u16::from_be_bytes(extended) as u64
+            }
+            127 => {
+                let mut extended = [0u8; 8];
+                stream.read_exact(&mut extended)?;
+                u64::from_be_bytes(extended)
+            }
+            length => length as u64,
+        };
+        if length > MAX_REQUEST_BYTES {
+            return Err(invalid("message too large"));
+        }
+        let mut mask = [0u8; 4];
+        stream.read_exact(&mut mask)?;
+        let mut payload = vec![0; length as usize];
+        stream.read_exact(&mut payload)?;
+        for (i, byte) in payload.iter_mut().enumerate() {
+            *byte ^= mask[i % 4];
+        }
+
+        // Control frames may arrive between the fragments of a message and are never fragmented themselves
+        let (opcode, payload) = match opcode {
+            OPCODE_CONTINUATION => {
+                let (opcode, mut message) = fragments.take().ok_or_else(|| invalid("continuation without a message"))?;
+                if message.len() as u64 + length > MAX_REQUEST_BYTES {
+                    return Err(invalid("message too large"));
+                }
+                message.extend_from_slice(&payload);
+                if !finished {
+                    fragments = Some((opcode, message));
+                    continue;
+                }
+                (opcode, message)
+            }
+            OPCODE_TEXT | OPCODE_BINARY if fragments.is_some() => return Err(invalid("new message before the last one finished")),
+            OPCODE_TEXT | OPCODE_BINARY if !finished => {
+                fragments = Some((opcode, payload));
+                continue;
+            }
+            _ => (opcode, payload),
+        };
+
+        let reply = match opcode {
+            OPCODE_TEXT => {
+                let message: Value = serde_json::from_slice(&payload).unwrap_or(Value::Null);
+                let name = message.get("command").and_then(Value::as_str).unwrap_or_default();
+                let reply = match parse_command(name, &message) {
+                    Ok(command) => {
+                        let _ = commands.send(command);
+                        json!({ "ok": true })
+                    }
+                    Err(CommandError::Unknown) => json!({ "error": format!("no such command '{}'", name) }),
+                    Err(CommandError::Invalid(reason)) => json!({ "error": reason }),
+                };
+                Some((OPCODE_TEXT, reply.to_string().into_bytes()))
+            }
+            OPCODE_PING => Some((OPCODE_PONG, payload)),
+            OPCODE_CLOSE => {
+                if let Ok(mut writer) = writer.lock() {
+                    let _ = write_frame(&mut *writer, OPCODE_CLOSE, &[]);
+                }
+                return Ok(());
+            }
+            _ => None,
+        };
+        if let Some((opcode, payload)) = reply {
+            let mut writer = writer.lock().map_err(|_| invalid("writer poisoned"))?;
+            write_frame(&mut *writer, opcode, &payload)?;
+        }
+    }
+}
+
+/// One unmasked, unfragmented server frame
+fn write_frame(stream: &mut impl Write, opcode: u8, payload: &[u8]) -> std::io::Result<()> {
+    let mut frame = vec![0x80 | opcode];
+    match payload.len() {
+        length @ 0..=125 => frame.push(length as u8),
+        length @ 126..=0xFFFF => {
+            frame.push(126);
+            frame.extend_from_slice(&(length as u16).to_be_bytes());
+        }
+        length => {
+            frame.push(127);
+            frame.extend_from_slice(&(length as u64).to_be_bytes());
+        }
+    }
+    frame.extend_from_slice(payload);
+    stream.write_all(&frame)
+}
+
+/// The `Sec-WebSocket-Accept` answer to a client's key
+fn websocket_accept(key: &str) -> String {
+    base64(&sha1(format!("{}{}", key.trim(), WEBSOCKET_GUID).as_bytes()))
+}
+
+/// SHA-1, needed only for the WebSocket handshake
+fn sha1(message: &[u8]) -> [u8; 20] {
+    let mut state: [u32; 5] = [0x6745_2301, 0xEFCD_AB89, 0x98BA_DCFE, 0x1032_5476, 0xC3D2_E1F0];
+    let mut padded = message.to_vec();
+    padded.push(0x80);
+    while padded.len() % 64 != 56 {
+        padded.push(0);
+    }
+    padded.extend_from_slice(&(message.len() as u64 * 8).to_be_bytes());
+
+    for block in padded.chunks(64) {
+        let mut words = [0u32; 80];
+        for (word, bytes) in words.iter_mut().zip(block.chunks(4)) {
+            *word = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+        }
+        for i in 16..80 {
+            words[i] = (words[i - 3] ^ words[i - 8] ^ words[i - 14] ^ words[i - 16]).rotate_left(1);
+        }
+        let [mut a, mut b, mut c, mut d, mut e] = state;
+        for (i, &word) in words.iter().enumerate() {
+            let (f, k) = match i {
+                0..=19 => ((b & c) | (!b & d), 0x5A82_7999),
+                20..=39 => (b ^ c ^ d, 0x6ED9_EBA1),
+                40..=59 => ((b & c) | (b & d) | (c & d), 0x8F1B_BCDC),
+                _ => (b ^ c ^ d, 0xCA62_C1D6),
+            };
+            let next = a.rotate_left(5).wrapping_add(f).wrapping_add(e).wrapping_add(k).wrapping_add(word);
+            (e, d, c, b, a) = (d, c, b.rotate_left(30), a, next);
+        }
+        for (value, added) in state.iter_mut().zip([a, b, c, d, e]) {
+            *value = value.wrapping_add(added);
+        }
+    }
+
+    let mut digest = [0u8; 20];
+    for (bytes, value) in digest.chunks_mut(4).zip(state) {
+        bytes.copy_from_slice(&value.to_be_bytes());
+    }
+    digest
+}
+
+fn base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    bytes.chunks(3).flat_map(|chunk| {
+        let bits = chunk.iter().enumerate().fold(0u32, |bits, (i, &byte)| bits | (byte as u32) << (16 - 8 * i));
+        (0..4).map(move |i| if i <= chunk.len() { ALPHABET[(bits >> (18 - 6 * i) & 63) as usize] as char } else { '=' })
+    }).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_commands_parse_and_handshake_matches_the_rfc() {
+        // The worked example from RFC 6455
+        assert_eq!(websocket_accept("dGhlIHNhbXBsZSBub25jZQ=="), "s3pPLMBiTxaQ9kYGzzhZRbK+xOo=");
+
+        assert_eq!(parse_command("spawn", &json!({ "species": "QuantumSheep", "count": 500, "x": 10.0, "y": 20.0 })),
+                   Ok(RemoteCommand::Spawn { species: SpeciesType::QuantumSheep, position: Some(Vec2::new(10.0, 20.0)), count: MAX_SPAWN_COUNT }));
+        assert_eq!(parse_command("mode", &json!({ "mode": "Chaotic" })), Ok(RemoteCommand::SetMode(AudioMode::Chaotic)));
+        assert_eq!(parse_command("preset", &json!({ "preset": 9 })), Ok(RemoteCommand::SpeedPreset(9)));
+        assert_eq!(parse_command("intervene", &json!({ "intervention": "force_peace" })), Ok(RemoteCommand::Intervene(ObserverIntervention::ForcePeace)));
+        assert!(matches!(parse_command("preset", &json!({ "preset": 0 })), Err(CommandError::Invalid(_))));
+        assert!(matches!(parse_command("spawn", &json!({ "species": "Alpaca" })), Err(CommandError::Invalid(_))));
+        assert_eq!(parse_command("self_destruct", &Value::Null), Err(CommandError::Unknown));
+
+        assert!(token_matches("llama-keeper", "llama-keeper"));
+        assert!(!token_matches("llama-keeper", "llama-keepe") && !token_matches("llama-keepeR", "llama-keeper"));
+    }
+
+    /// A masked client frame with a short payload
+    fn client_frame(first_byte: u8, payload: &[u8]) -> Vec<u8> {
+        let mask = [7, 1, 8, 3];
+        let mut frame = vec![first_byte, 0x80 | payload.len() as u8];
+        frame.extend_from_slice(&mask);
+        frame.extend(payload.iter().enumerate().map(|(i, byte)| byte ^ mask[i % 4]));
+        frame
+    }
+
+    #[test]
+    fn test_fragmented_messages_are_reassembled() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let mut client = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+        let writer = Mutex::new(listener.accept().unwrap().0);
+        let (sender, receiver) = mpsc::channel();
+
+        // A ping may arrive between the fragments
+        let (start, rest) = br#"{"command":"preset","preset":3}"#.split_at(10);
+        let mut frames = client_frame(OPCODE_TEXT, start);
+        frames.extend(client_frame(0x80 | OPCODE_PING, b"hi"));
+        frames.extend(client_frame(0x80 | OPCODE_CONTINUATION, rest));
+        frames.extend(client_frame(0x80 | OPCODE_CLOSE, &[]));
+        read_messages(std::io::Cursor::new(frames), &writer, &sender).unwrap();
+        assert_eq!(receiver.try_recv(), Ok(RemoteCommand::SpeedPreset(3)));
+
+        client.set_read_timeout(Some(Duration::from_secs(1))).unwrap();
+        let mut pong = [0u8; 4];
+        client.read_exact(&mut pong).unwrap();
+        assert_eq!(pong, [0x80 | OPCODE_PONG, 2, b'h', b'i']);
+        let mut reply = [0u8; 2];
+        client.read_exact(&mut reply).unwrap();
+        assert_eq!(reply[0], 0x80 | OPCODE_TEXT, "the command is answered once whole");
+
+        let orphan = client_frame(0x80 | OPCODE_CONTINUATION, b"}");
+        assert!(read_messages(std::io::Cursor::new(orphan), &writer, &sender).is_err());
+    }
+
+    #[test]
+    fn test_a_trickled_request_runs_out_of_time() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let mut client = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+        let (server, _) = listener.accept().unwrap();
+        let trickle = std::thread::spawn(move || {
+            let _ = client.write_all(b"GET /status HTTP/1.1\r\n");
+            for _ in 0..20 {
+                std::thread::sleep(Duration::from_millis(50));
+                if client.write_all(b"X-Slow: 1\r\n").is_err() {
+                    break;
+                }
+            }
+        });
+        let started = Instant::now();
+        assert!(read_request(&server, Duration::from_millis(200)).is_err());
+        assert!(started.elapsed() < Duration::from_millis(600), "every byte arriving in time is not enough");
+        drop(server);
+        trickle.join().unwrap();
+    }
+
+    #[test]
+    fn test_debug_output_hides_the_token() {
+        let remote = RemoteControl::new("0.0.0.0:8420", "llama-keeper");
+        let report = format!("{:#?}", remote);
+        assert!(report.contains("0.0.0.0:8420"));
+        assert!(!report.contains("llama-keeper"));
+    }
+}
@@ -0,0 +1,192 @@
+// === SCREENSAVER ===
+// Plumbing to run the organism as the system screensaver. The host starts the
+// executable with its own arguments: on Windows `/s` to run, `/p HWND` to draw
+// a preview into the control panel's little monitor and `/c` for settings;
+// XScreenSaver passes `-root` or `-window-id ID`. A running screensaver exits
+// on the first key, click or real mouse movement. Nobody is at the keyboard to
+// answer the epilepsy warning when it starts, so the settings entry shows the
+// warning once and stores the answer as a profile; the screensaver starts from
+// that pre-accepted profile, and in safety mode when none has been stored.
+//
+// Limits: `-root` does not draw on the X root window (winit cannot adopt it);
+// it opens a borderless fullscreen window of its own, which XScreenSaver's
+// blanking window may cover under some compositors. `-window-id` is honored
+// only where a window can be parented to a foreign handle (X11 and Windows).
+// There is no macOS `.saver` bundle, which would need a ScreenSaverView
+// plugin rather than an executable.
+
+use std::path::{Path, PathBuf};
+use serde::{Deserialize, Serialize};
+use winit::dpi::PhysicalPosition;
+use winit::event::{ElementState, WindowEvent};
+use winit::raw_window_handle::RawWindowHandle;
+use crate::error::{BloomError, Result};
+use super::warning::WarningResponse;
+
+const PROFILE_FILE_NAME: &str = ".aetherium_bloom_screensaver.ron";
+/// Pixels the mouse may drift before it counts as someone returning; hosts
+/// and jittery mice report small moves right after the screensaver starts
+const WAKE_DISTANCE: f64 = 12.0;
+/// Size of the Windows control panel's preview monitor
+pub const PREVIEW_SIZE: (u32, u32) = (152, 112);
+
+/// What the screensaver host asked for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScreensaverCommand {
+    Run,          // Fullscreen until the first input
+    Preview(u64), // Drawn into the host's window with this handle, silent
+    Configure,    // Show the warning and store the answer as the profile
+}
+
+impl ScreensaverCommand {
+    /// The command in a screensaver host's arguments; None for an ordinary launch
+    pub fn parse(args: &[String]) -> Option<Self> {
+        let first = args.first()?.to_ascii_lowercase();
+        // Windows passes `/p 1234` or `/p:1234`, `/c` optionally with the settings dialog's parent
+        let (flag, attached) = first.split_once(':').map_or((first.as_str(), None), |(flag, value)| (flag, Some(value)));
+        let handle = || attached.or(args.get(1).map(String::as_str)).and_then(parse_handle);
+        match flag {
+            "/s" | "-s" | "-root" | "--screensaver" => Some(Self::Run),
+            "/p" | "-p" | "-window-id" => handle().map(Self::Preview),
+            "/c" | "-c" => Some(Self::Configure),
+            _ => None,
+        }
+    }
+}
+
+/// A window handle in decimal, as Windows passes it, or hex, as XScreenSaver does
+fn parse_handle(value: &str) -> Option<u64> {
+    match value.strip_prefix("0x") {
+        Some(hex) => u64::from_str_radix(hex, 16).ok(),
+        None => value.parse().ok(),
+    }
+}
+
+/// The host's preview window, to parent ours to
+pub fn parent_window(handle: u64) -> Option<RawWindowHandle> {
+    #[cfg(windows)]
+    {
+        let hwnd = std::num::NonZeroIsize::new(handle as isize)?;
+        Some(RawWindowHandle::Win32(winit::raw_window_handle::Win32WindowHandle::new(hwnd)))
+    }
+    #[cfg(not(windows))]
+    {
+        (handle != 0).then(|| RawWindowHandle::Xlib(winit::raw_window_handle::XlibWindowHandle::new(handle as std::os::raw::c_ulong)))
+    }
+}
+
+/// The warning answer and sound choice the screensaver starts with
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ScreensaverProfile {
+    pub safety_mode: bool, // Reduced visual intensity, as chosen on the warning screen
+    #[serde(default)]
+    pub audio: bool,       // Play the synth while the screensaver runs
+}
+
+impl Default for ScreensaverProfile {
+    /// Nobody has accepted the full effects yet: safety mode, silent
+    fn default() -> Self {
+        Self { safety_mode: true, audio: false }
+    }
+}
+
+impl ScreensaverProfile {
+    /// `~/.aetherium_bloom_screensaver.ron`
+    pub fn default_file() -> PathBuf {
+        super::home_dir().join(PROFILE_FILE_NAME)
+    }
+
+    /// None when the settings have never been saved
+    pub fn load(path: &Path) -> Result<Option<Self>> {
+        let fail = |reason: String| BloomError::Screensaver { path: path.to_path_buf(), reason };
+        match std::fs::read_to_string(path) {
+            Ok(source) => ron::from_str(&source).map(Some).map_err(|e| fail(e.to_string())),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(fail(e.to_string())),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let fail = |reason: String| BloomError::Screensaver { path: path.to_path_buf(), reason };
+        let source = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default()).map_err(|e| fail(e.to_string()))?;
+        std::fs::write(path, source).map_err(|e| fail(e.to_string()))
+    }
+
+    /// This profile with the answer given on the warning screen; None when it was Exit
+    pub fn answered(self, response: &WarningResponse) -> Option<Self> {
+        match response {
+            WarningResponse::Continue => Some(Self { safety_mode: false, ..self }),
+            WarningResponse::SafetyMode => Some(Self { safety_mode: true, ..self }),
+            WarningResponse::Exit => None,
+        }
+    }
+
+    /// The warning answer this profile stands in for
+    pub fn response(&self) -> WarningResponse {
+        if self.safety_mode { WarningResponse::SafetyMode } else { WarningResponse::Continue }
+    }
+}
+
+/// A screensaver launch in progress
+#[derive(Debug, Clone)]
+pub struct ScreensaverSession {
+    pub command: ScreensaverCommand,
+    pub profile: ScreensaverProfile,
+    rest_cursor: Option<PhysicalPosition<f64>>, // First cursor position reported, moves are measured from it
+}
+
+impl ScreensaverSession {
+    pub fn new(command: ScreensaverCommand, profile: ScreensaverProfile) -> Self {
+        Self { command, profile, rest_cursor: None }
+    }
+
+    /// True when the event means someone is back and a running screensaver should exit
+    pub fn wakes(&mut self, event: &WindowEvent) -> bool {
+        if self.command != ScreensaverCommand::Run {
+            return false;
+        }
+        match event {
+            WindowEvent::KeyboardInput { event, .. } => event.state == ElementState::Pressed,
+            WindowEvent::MouseInput { state, .. } => *state == ElementState::Pressed,
+            WindowEvent::MouseWheel { .. } | WindowEvent::Touch(_) => true,
+            WindowEvent::CursorMoved { position, .. } => {
+                let rest = *self.rest_cursor.get_or_insert(*position);
+                (position.x - rest.x).hypot(position.y - rest.y) > WAKE_DISTANCE
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(str::to_owned).collect()
+    }
+
+    #[test]
+    fn test_host_arguments_and_profile_answers() {
+        assert_eq!(ScreensaverCommand::parse(&args("/S")), Some(ScreensaverCommand::Run));
+        assert_eq!(ScreensaverCommand::parse(&args("/p 4242")), Some(ScreensaverCommand::Preview(4242)));
+        assert_eq!(ScreensaverCommand::parse(&args("/p:4242")), Some(ScreensaverCommand::Preview(4242)));
+        assert_eq!(ScreensaverCommand::parse(&args("-window-id 0x1a00007")), Some(ScreensaverCommand::Preview(0x1a0_0007)));
+        assert_eq!(ScreensaverCommand::parse(&args("/c:998")), Some(ScreensaverCommand::Configure));
+        assert_eq!(ScreensaverCommand::parse(&args("/p")), None, "a preview needs a window");
+        assert_eq!(ScreensaverCommand::parse(&args("--attract")), None);
+        assert_eq!(ScreensaverCommand::parse(&[]), None);
+
+        // Without stored settings nobody has accepted the full effects
+        assert_eq!(ScreensaverProfile::default().response(), WarningResponse::SafetyMode);
+        let accepted = ScreensaverProfile::default().answered(&WarningResponse::Continue).unwrap();
+        assert_eq!(accepted.response(), WarningResponse::Continue);
+        assert!(ScreensaverProfile::default().answered(&WarningResponse::Exit).is_none());
+
+        let path = std::env::temp_dir().join(format!("aetherium_screensaver_{}.ron", std::process::id()));
+        assert_eq!(ScreensaverProfile::load(&path).unwrap(), None);
+        accepted.save(&path).unwrap();
+        assert_eq!(ScreensaverProfile::load(&path).unwrap(), Some(accepted));
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,129 @@
+// === HISTORY SCRUBBER ===
+// Rewinds the organism through its recorded snapshots. The live world is set
+// aside while a past moment is on screen; the arrows step through history,
+// Space plays it back as a time-lapse, and Enter branches a new live
+// simulation from the moment shown, discarding the future that followed it.
+// Those are the default keys; the scrubber itself only sees actions.
+
+use crate::core::ecs::{EntityId, World};
+use crate::simulation::TerritoryZone;
+use super::keybindings::Action;
+
+/// Time-lapse playback speed in snapshots per second, under the 3 Hz flash limit
+pub const PLAYBACK_RATE: f32 = 2.0;
+/// Snapshots one up/down press jumps, a minute of history
+const JUMP: usize = 12;
+
+/// What a key press did while scrubbing
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScrubInput {
+    Seek,     // Another snapshot should be shown
+    Adjusted, // Playback started or stopped
+    Branch,   // Continue live from the snapshot shown
+    Ignored,  // Not a scrub key; it keeps its usual binding
+}
+
+pub struct Scrubber {
+    live_world: World,
+    live_zones: Vec<TerritoryZone>,
+    index: usize,
+    playing: bool,
+    playback: f32, // Seconds toward the next played-back snapshot
+}
+
+impl Scrubber {
+    /// Set the live world aside, starting at snapshot `index`
+    pub fn new(live_world: World, live_zones: Vec<TerritoryZone>, index: usize) -> Self {
+        Self { live_world, live_zones, index, playing: false, playback: 0.0 }
+    }
+
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    pub fn playing(&self) -> bool {
+        self.playing
+    }
+
+    /// Step, jump a minute, go to either end, toggle the time-lapse, or branch
+    pub fn handle_action(&mut self, action: Action, len: usize) -> ScrubInput {
+        let last = len.saturating_sub(1);
+        if action == Action::TimeLapse {
+            self.playing = !self.playing;
+            self.playback = 0.0;
+            if self.playing && self.index == last {
+                self.index = 0; // Replay from the oldest moment
+                return ScrubInput::Seek;
+            }
+            return ScrubInput::Adjusted;
+        }
+        let index = match action {
+            Action::Branch => return ScrubInput::Branch,
+            Action::StepBack => self.index.saturating_sub(1),
+            Action::StepForward => self.index + 1,
+            Action::MinuteBack => self.index.saturating_sub(JUMP),
+            Action::MinuteForward => self.index + JUMP,
+            Action::Oldest => 0,
+            Action::Newest => last,
+            _ => return ScrubInput::Ignored,
+        };
+        self.playing = false;
+        self.index = index.min(last);
+        ScrubInput::Seek
+    }
+
+    /// Advance time-lapse playback; true when another snapshot should be shown
+    pub fn advance(&mut self, dt: f32, len: usize) -> bool {
+        if !self.playing {
+            return false;
+        }
+        self.playback += dt * PLAYBACK_RATE;
+        if self.playback < 1.0 {
+            return false;
+        }
+        self.playback -= 1.0;
+        if self.index + 1 >= len {
+            self.playing = false; // Caught up with the present
+            return false;
+        }
+        self.index += 1;
+        true
+    }
+
+    /// The id the live world would have handed out next; shown snapshots count on from it
+    pub fn live_next_id(&self) -> EntityId {
+        self.live_world.next_entity_id()
+    }
+
+    /// The world and zones set aside when scrubbing began
+    pub fn into_live(self) -> (World, Vec<TerritoryZone>) {
+        (self.live_world, self.live_zones)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scrub_keys_and_playback() {
+        let mut scrubber = Scrubber::new(World::new(), Vec::new(), 19);
+        assert_eq!(scrubber.handle_action(Action::StepForward, 20), ScrubInput::Seek);
+        assert_eq!(scrubber.index(), 19, "stepping stops at the newest snapshot");
+        scrubber.handle_action(Action::MinuteBack, 20);
+        assert_eq!(scrubber.index(), 7);
+        assert_eq!(scrubber.handle_action(Action::HistoryScrubbing, 20), ScrubInput::Ignored);
+
+        assert_eq!(scrubber.handle_action(Action::TimeLapse, 20), ScrubInput::Adjusted);
+        let mut shown = 0;
+        for _ in 0..60 {
+            shown += scrubber.advance(1.0 / 60.0, 20) as usize;
+        }
+        assert_eq!(shown, PLAYBACK_RATE as usize);
+        assert_eq!(scrubber.index(), 9);
+
+        scrubber.handle_action(Action::Newest, 20);
+        assert!(!scrubber.playing(), "seeking pauses playback");
+        assert_eq!(scrubber.handle_action(Action::Branch, 20), ScrubInput::Branch);
+    }
+}
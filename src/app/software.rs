@@ -0,0 +1,295 @@
+// === SOFTWARE RENDERER ===
+// Fallback for machines whose GPU fails adapter selection, so the app still
+// starts instead of exiting. Everything is drawn on the CPU with tiny-skia
+// into a pixmap and scaled up into the window with softbuffer. The organism
+// is the headless simulation drawn plainly at reduced resolution: llamas as
+// circles with motion lines, crystals as diamonds, reality tears as strokes.
+// There are no shaders, reality distortion or audio in this mode.
+
+use std::num::NonZeroU32;
+use std::sync::Arc;
+use std::time::Duration;
+use glam::{Vec2, Vec3};
+use tiny_skia::{Color, FillRule, Paint, PathBuilder, Pixmap, Stroke, Transform};
+use tracing::info;
+use winit::{
+    dpi::PhysicalSize,
+    event::{ElementState, MouseButton, WindowEvent},
+    keyboard::{Key, NamedKey},
+    window::Window,
+};
+
+use crate::api::BloomConfig;
+use crate::engine::HiveMind;
+use crate::entities::SpeciesType;
+use crate::error::Result;
+use crate::locale::Locale;
+use crate::reality::{text, Vertex};
+use crate::rendering::warfare_overlay::territory_color;
+use crate::rendering::WORLD_SIZE;
+use crate::simulation::{ConsciousnessCrystal, HeadlessSimulation, RealityTear};
+use super::warm_start::WarmStart;
+
+/// The organism is drawn at 1/RESOLUTION_DIVISOR of the window size in each direction
+pub const RESOLUTION_DIVISOR: u32 = 2;
+/// CPU drawing is paced to 30 fps
+pub const SOFTWARE_FRAME_INTERVAL: Duration = Duration::from_micros(33_333);
+const BACKGROUND: Vec3 = Vec3::new(0.0, 0.0, 0.1);
+/// Safety mode halves brightness, like the GPU path's 50% mode
+const SAFETY_MODE_BRIGHTNESS: f32 = 0.5;
+
+fn paint(color: Vec3) -> Paint<'static> {
+    let color = color.clamp(Vec3::ZERO, Vec3::ONE) * 255.0;
+    let mut paint = Paint::default();
+    paint.set_color_rgba8(color.x as u8, color.y as u8, color.z as u8, 255);
+    paint
+}
+
+/// Fill triangle-list vertices, as built for the GPU pipelines, into the pixmap.
+/// Each triangle takes its first vertex's color; text stays crisp without anti-aliasing.
+pub fn fill_vertices(pixmap: &mut Pixmap, vertices: &[Vertex]) {
+    let size = Vec2::new(pixmap.width() as f32, pixmap.height() as f32);
+    let to_pixel = |vertex: &Vertex| Vec2::new((vertex.position[0] + 1.0) * 0.5 * size.x, (1.0 - vertex.position[1]) * 0.5 * size.y);
+    for triangle in vertices.chunks_exact(3) {
+        let [a, b, c] = [to_pixel(&triangle[0]), to_pixel(&triangle[1]), to_pixel(&triangle[2])];
+        let mut builder = PathBuilder::new();
+        builder.move_to(a.x, a.y);
+        builder.line_to(b.x, b.y);
+        builder.line_to(c.x, c.y);
+        builder.close();
+        if let Some(path) = builder.finish() {
+            let mut paint = paint(Vec3::from(triangle[0].color));
+            paint.anti_alias = false;
+            pixmap.fill_path(&path, &paint, FillRule::Winding, Transform::identity(), None);
+        }
+    }
+}
+
+/// Draw the simulation's world into the pixmap, scaled from world units
+pub fn draw_organism(pixmap: &mut Pixmap, simulation: &HeadlessSimulation, brightness: f32) {
+    let background = BACKGROUND * brightness * 255.0;
+    pixmap.fill(Color::from_rgba8(background.x as u8, background.y as u8, background.z as u8, 255));
+    let scale = Vec2::new(pixmap.width() as f32, pixmap.height() as f32) / WORLD_SIZE;
+    let transform = Transform::from_scale(scale.x, scale.y);
+
+    for tear in simulation.world.components::<RealityTear>() {
+        let reach = Vec2::new(0.5, 1.0) * tear.size.clamp(4.0, 60.0);
+        let mut builder = PathBuilder::new();
+        builder.move_to(tear.position.x - reach.x, tear.position.y - reach.y);
+        builder.line_to(tear.position.x + reach.x, tear.position.y + reach.y);
+        if let Some(path) = builder.finish() {
+            let stroke = Stroke { width: 2.0, ..Stroke::default() };
+            pixmap.stroke_path(&path, &paint(Vec3::new(0.45, 0.3, 0.6) * brightness), &stroke, transform, None);
+        }
+    }
+
+    for crystal in simulation.world.components::<ConsciousnessCrystal>() {
+        let radius = 4.0 + crystal.consciousness_energy.clamp(0.0, 1.0) * 4.0;
+        let mut builder = PathBuilder::new();
+        builder.move_to(crystal.position.x, crystal.position.y - radius);
+        builder.line_to(crystal.position.x + radius, crystal.position.y);
+        builder.line_to(crystal.position.x, crystal.position.y + radius);
+        builder.line_to(crystal.position.x - radius, crystal.position.y);
+        builder.close();
+        if let Some(path) = builder.finish() {
+            pixmap.fill_path(&path, &paint(Vec3::new(0.3, 0.6, 0.65) * brightness), FillRule::Winding, transform, None);
+        }
+    }
+
+    for llama in simulation.llamas() {
+        let color = territory_color(llama.species) * 0.7 * brightness;
+        let trail = llama.velocity.clamp_length_max(60.0) * 0.4;
+        let mut builder = PathBuilder::new();
+        builder.move_to(llama.position.x, llama.position.y);
+        builder.line_to(llama.position.x - trail.x, llama.position.y - trail.y);
+        if let Some(path) = builder.finish() {
+            let stroke = Stroke { width: 1.5, ..Stroke::default() };
+            pixmap.stroke_path(&path, &paint(color * 0.6), &stroke, transform, None);
+        }
+
+        let radius = 5.0 + llama.consciousness.clamp(0.0, 2.0) * 3.0;
+        if let Some(circle) = PathBuilder::from_circle(llama.position.x, llama.position.y, radius) {
+            pixmap.fill_path(&circle, &paint(color), FillRule::Winding, transform, None);
+        }
+    }
+}
+
+/// A window drawn by the CPU: a pixmap at reduced resolution, scaled up on present
+pub struct SoftwareSurface {
+    surface: softbuffer::Surface<Arc<Window>, Arc<Window>>,
+    pixmap: Pixmap,
+    size: PhysicalSize<u32>,
+    divisor: u32,
+}
+
+impl SoftwareSurface {
+    pub fn new(window: Arc<Window>, divisor: u32) -> Result<Self> {
+        let context = softbuffer::Context::new(window.clone())?;
+        let surface = softbuffer::Surface::new(&context, window.clone())?;
+        let mut software = Self { surface, pixmap: Pixmap::new(1, 1).expect("1x1 pixmap"), size: PhysicalSize::new(1, 1), divisor: divisor.max(1) };
+        software.resize(window.inner_size())?;
+        Ok(software)
+    }
+
+    pub fn resize(&mut self, size: PhysicalSize<u32>) -> Result<()> {
+        let (Some(width), Some(height)) = (NonZeroU32::new(size.width), NonZeroU32::new(size.height)) else { return Ok(()) };
+        self.surface.resize(width, height)?;
+        self.size = size;
+        if let Some(pixmap) = Pixmap::new((size.width / self.divisor).max(1), (size.height / self.divisor).max(1)) {
+            self.pixmap = pixmap;
+        }
+        Ok(())
+    }
+
+    /// Window size in physical pixels
+    pub fn size(&self) -> PhysicalSize<u32> {
+        self.size
+    }
+
+    pub fn pixmap_mut(&mut self) -> &mut Pixmap {
+        &mut self.pixmap
+    }
+
+    /// Scale the pixmap up to the window, nearest neighbor, and show it
+    pub fn present(&mut self) -> Result<()> {
+        let (width, height) = (self.size.width as usize, self.size.height as usize);
+        let (pixmap_width, pixmap_height) = (self.pixmap.width() as usize, self.pixmap.height() as usize);
+        let pixels = self.pixmap.pixels();
+        let mut buffer = self.surface.buffer_mut()?;
+        for (y, row) in buffer.chunks_exact_mut(width).take(height).enumerate() {
+            let source = &pixels[(y * pixmap_height / height).min(pixmap_height - 1) * pixmap_width..][..pixmap_width];
+            for (x, pixel) in row.iter_mut().enumerate() {
+                let color = source[(x * pixmap_width / width).min(pixmap_width - 1)];
+                *pixel = (color.red() as u32) << 16 | (color.green() as u32) << 8 | color.blue() as u32;
+            }
+        }
+        buffer.present()?;
+        Ok(())
+    }
+}
+
+/// The organism as the software renderer shows it
+pub struct SoftwareOrganism {
+    surface: SoftwareSurface,
+    simulation: HeadlessSimulation,
+    brightness: f32,
+    paused: bool, // Escape is the emergency stop here too
+    cursor: Vec2, // World units
+    spawned: usize,
+    locale: Locale,
+    warm_start: Option<WarmStart>, // Fast-forward still to run before the organism shows
+}
+
+impl SoftwareOrganism {
+    pub fn new(window: Arc<Window>, config: &BloomConfig) -> Result<Self> {
+        let seed = config.seed.unwrap_or_else(|| fastrand::u64(..));
+        let simulation = HeadlessSimulation::new(seed, config.initial_population)
+            .with_population_cap(config.max_population, config.cull_policy);
+        info!(target: "render", "🖥️ Software renderer: simplified circles and lines at 1/{} resolution", RESOLUTION_DIVISOR);
+        Ok(Self {
+            surface: SoftwareSurface::new(window, RESOLUTION_DIVISOR)?,
+            simulation,
+            brightness: 1.0,
+            paused: false,
+            cursor: WORLD_SIZE * 0.5,
+            spawned: 0,
+            locale: config.locale.clone(),
+            warm_start: WarmStart::new(config.warm_start),
+        })
+    }
+
+    pub fn enable_safety_mode(&mut self) {
+        self.brightness = SAFETY_MODE_BRIGHTNESS;
+    }
+
+    pub fn update(&mut self) {
+        if self.paused {
+            return;
+        }
+        if let Some(warm_start) = &mut self.warm_start {
+            if warm_start.advance(|| self.simulation.step()) {
+                self.warm_start = None;
+                info!(target: "app", "🌅 Warmed up - {} llamas", self.simulation.llamas().len());
+            }
+        } else {
+            self.simulation.step();
+        }
+    }
+
+    pub fn render(&mut self) -> Result<()> {
+        if let Some(warm_start) = &self.warm_start {
+            let world = &self.simulation.world;
+            let status = self.locale.format("warm-start-status", &[
+                ("llamas", &self.simulation.llamas().len()),
+                ("crystals", &world.count::<ConsciousnessCrystal>()),
+                ("hives", &world.count::<HiveMind>()),
+            ]);
+            let mut vertices = Vec::new();
+            warm_start.push_overlay(&mut vertices, &self.locale, &status);
+            let pixmap = self.surface.pixmap_mut();
+            pixmap.fill(Color::BLACK);
+            fill_vertices(pixmap, &vertices);
+            return self.surface.present();
+        }
+        let brightness = if self.paused { self.brightness * 0.3 } else { self.brightness };
+        draw_organism(self.surface.pixmap_mut(), &self.simulation, brightness);
+
+        let status = self.locale.format(if self.paused { "hud-software-paused" } else { "hud-software" },
+            &[("llamas", &self.simulation.llamas().len())]);
+        let mut vertices = Vec::new();
+        text::push_text(&mut vertices, &status, Vec2::new(20.0, 770.0), 2.0, Vec3::splat(0.45), WORLD_SIZE);
+        fill_vertices(self.surface.pixmap_mut(), &vertices);
+        self.surface.present()
+    }
+
+    pub fn handle_window_event(&mut self, event: &WindowEvent) -> Result<()> {
+        match event {
+            WindowEvent::Resized(size) => self.surface.resize(*size)?,
+            WindowEvent::CursorMoved { position, .. } => {
+                let size = self.surface.size();
+                self.cursor = Vec2::new(position.x as f32 / size.width.max(1) as f32, position.y as f32 / size.height.max(1) as f32) * WORLD_SIZE;
+            }
+            WindowEvent::MouseInput { state: ElementState::Pressed, button: MouseButton::Left, .. } if !self.paused && self.warm_start.is_none() => {
+                let species = SpeciesType::ALL[self.spawned % SpeciesType::ALL.len()];
+                self.simulation.spawn(species, self.cursor);
+                self.spawned += 1;
+            }
+            WindowEvent::KeyboardInput { event, .. }
+                if event.state == ElementState::Pressed && event.logical_key == Key::Named(NamedKey::Space) && self.warm_start.is_some() => {
+                self.warm_start = None;
+                info!(target: "app", "🌅 Warm-up skipped - {} llamas", self.simulation.llamas().len());
+            }
+            WindowEvent::KeyboardInput { event, .. }
+                if event.state == ElementState::Pressed && event.logical_key == Key::Named(NamedKey::Escape) => {
+                self.paused = !self.paused;
+                info!(target: "safety", "🛑 Software renderer {}", if self.paused { "stopped" } else { "resumed" });
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::safety::is_dangerous_red;
+
+    #[test]
+    fn test_organism_draws_on_the_cpu() {
+        let mut simulation = HeadlessSimulation::new(7, 8);
+        simulation.run(30);
+        let mut pixmap = Pixmap::new(600, 400).unwrap();
+        draw_organism(&mut pixmap, &simulation, 1.0);
+        let mut vertices = Vec::new();
+        text::push_text(&mut vertices, "SOFTWARE", Vec2::new(20.0, 770.0), 2.0, Vec3::splat(0.45), WORLD_SIZE);
+        fill_vertices(&mut pixmap, &vertices);
+
+        let background = pixmap.pixel(0, 0).unwrap();
+        let drawn = pixmap.pixels().iter().filter(|&&pixel| pixel != background).count();
+        assert!(drawn > 100, "llamas and text should cover some pixels, got {drawn}");
+        assert!(pixmap.pixels().iter().all(|pixel| {
+            !is_dangerous_red(Vec3::new(pixel.red() as f32, pixel.green() as f32, pixel.blue() as f32) / 255.0)
+        }));
+    }
+}
@@ -0,0 +1,527 @@
+// Standalone window shell: owns the winit event loop, shows the warning
+// screen, then hands the window to the organism
+
+use wgpu::SurfaceError;
+use tracing::{error, info, warn};
+use tracing_subscriber::EnvFilter;
+use std::time::Instant;
+use winit::{
+    application::ApplicationHandler,
+    event::{ElementState, KeyEvent, WindowEvent},
+    event_loop::{ActiveEventLoop, ControlFlow, EventLoop},
+    monitor::MonitorHandle,
+    window::{Fullscreen, WindowAttributes},
+};
+
+use crate::api::{BloomConfig, WindowOptions};
+use crate::error::{BloomError, Result};
+use crate::rendering::Viewport;
+use super::crash::{is_supervised, record_session, resumed_response};
+use super::display::{fullscreen_for, FullscreenMode};
+use super::keybindings::{Action, BoundKey, KeyContext};
+use super::organism::ChaosEngine;
+use super::pacing::FixedTimestep;
+use super::screensaver::{parent_window, ScreensaverCommand, ScreensaverProfile, ScreensaverSession, PREVIEW_SIZE};
+use super::software::{SoftwareOrganism, SOFTWARE_FRAME_INTERVAL};
+use super::warning::{announce_warning_response, WarningResponse, WarningScreen};
+
+/// Application flow: the warning screen must be answered before the engine starts
+enum AppState {
+    Warning(WarningScreen),
+    Running(Box<ChaosEngine>),
+    Software(Box<SoftwareOrganism>), // No usable GPU, or the software renderer was requested
+}
+
+struct App {
+    state: Option<AppState>,
+    window: Option<std::sync::Arc<winit::window::Window>>,
+    window_options: WindowOptions,
+    config: BloomConfig,
+    failure: Option<BloomError>, // Start-up error returned from run_standalone
+    last_redraw: Instant,        // Paces frames when the engine asks for a lower rate
+    timestep: FixedTimestep,     // Simulation ticks per frame, independent of frame rate
+    screensaver: Option<ScreensaverSession>, // Started by a screensaver host rather than by hand
+}
+
+/// Monitors left to right, the order the world is split across them
+fn monitors_left_to_right(event_loop: &ActiveEventLoop) -> Vec<MonitorHandle> {
+    let mut monitors: Vec<_> = event_loop.available_monitors().collect();
+    monitors.sort_by_key(|monitor| (monitor.position().x, monitor.position().y));
+    monitors
+}
+
+impl App {
+    /// The monitor the window options ask for, counted left to right
+    fn chosen_monitor(&self, event_loop: &ActiveEventLoop) -> Option<MonitorHandle> {
+        let index = self.window_options.monitor?;
+        let monitor = monitors_left_to_right(event_loop).into_iter().nth(index);
+        if monitor.is_none() {
+            warn!(target: "app", "🖥️ Monitor {} not found - opening on the default one", index);
+        }
+        monitor
+    }
+
+    /// True for a fresh press of the key bound to the fullscreen toggle, unless the
+    /// key binding editor is open and wants the key for itself
+    fn is_fullscreen_key(&self, key_event: &KeyEvent) -> bool {
+        if key_event.state != ElementState::Pressed || key_event.repeat {
+            return false;
+        }
+        let Some(key) = BoundKey::from_key(&key_event.logical_key) else { return false };
+        let bindings = match &self.state {
+            Some(AppState::Running(engine)) if engine.key_binding_editor() => return false,
+            Some(AppState::Running(engine)) => engine.key_bindings(),
+            _ => &self.config.key_bindings,
+        };
+        bindings.action(KeyContext::Global, key) == Some(Action::ToggleFullscreen)
+    }
+
+    /// Switch between a window and the configured fullscreen mode (borderless when started windowed)
+    fn toggle_fullscreen(&self) {
+        let Some(window) = &self.window else { return };
+        if window.fullscreen().is_some() {
+            window.set_fullscreen(None);
+            info!(target: "app", "🪟 Windowed");
+            return;
+        }
+        let mode = match self.window_options.fullscreen {
+            FullscreenMode::Windowed => FullscreenMode::Borderless,
+            mode => mode,
+        };
+        window.set_fullscreen(fullscreen_for(mode, window.current_monitor()));
+        info!(target: "app", "🖥️ Fullscreen ({:?})", mode);
+    }
+
+    /// Installation mode: the primary window keeps the leftmost monitor and
+    /// every other monitor gets its own window onto the next strip of the world
+    fn open_display_outputs(&self, event_loop: &ActiveEventLoop, engine: &mut ChaosEngine) {
+        let monitors = monitors_left_to_right(event_loop);
+        if monitors.len() < 2 {
+            info!(target: "app", "🖥️ Spanning displays requested but only {} monitor found", monitors.len());
+            return;
+        }
+
+        let widths: Vec<u32> = monitors.iter().map(|monitor| monitor.size().width).collect();
+        let viewports = Viewport::columns(&widths);
+        engine.set_viewport(viewports[0]);
+
+        for (index, (monitor, viewport)) in monitors.into_iter().zip(viewports).enumerate().skip(1) {
+            let attributes = WindowAttributes::default()
+                .with_title(format!("{} [{}]", self.window_options.title, index + 1))
+                .with_fullscreen(Some(Fullscreen::Borderless(Some(monitor))));
+            let added = event_loop.create_window(attributes)
+                .map_err(BloomError::from)
+                .and_then(|window| engine.add_output(std::sync::Arc::new(window), viewport));
+            if let Err(e) = added {
+                // The wall loses a panel, the organism keeps running
+                warn!(target: "app", "🖥️ Output window for display {} failed: {}", index + 1, e);
+            }
+        }
+    }
+
+    /// Act on the warning screen choice: exit, or spin up the chaos engine
+    fn resolve_warning(&mut self, event_loop: &ActiveEventLoop, response: WarningResponse) {
+        announce_warning_response(&response, &self.config.locale);
+        if let Some(session) = self.screensaver.as_ref().filter(|session| session.command == ScreensaverCommand::Configure) {
+            // The settings entry only records the answer the screensaver will start with
+            if let Some(profile) = session.profile.answered(&response) {
+                let path = ScreensaverProfile::default_file();
+                match profile.save(&path) {
+                    Ok(()) => info!(target: "safety", "🛡️ Screensaver settings saved to {}", path.display()),
+                    Err(e) => error!(target: "app", "❌ {}", e),
+                }
+            }
+            event_loop.exit();
+            return;
+        }
+        if let (true, Some(directory)) = (is_supervised(), &self.config.crash_directory) {
+            record_session(directory, &response);
+        }
+
+        // Release the warning screen's surface before the engine claims the window
+        let warned_in_software = matches!(&self.state, Some(AppState::Warning(screen)) if screen.is_software());
+        self.state = None;
+
+        if response == WarningResponse::Exit {
+            event_loop.exit();
+            return;
+        }
+
+        let window = match &self.window {
+            Some(window) => window.clone(),
+            None => return,
+        };
+
+        let safety_mode_requested = response == WarningResponse::SafetyMode;
+        if warned_in_software || self.config.software_renderer {
+            self.start_software_organism(event_loop, window, safety_mode_requested);
+            return;
+        }
+
+        info!(target: "app", "🎮 Initializing chaos engine with safety systems...");
+        let mut chaos_engine = match pollster::block_on(ChaosEngine::new(window.clone(), &self.config)) {
+            Ok(engine) => engine,
+            Err(e) if e.is_gpu_unavailable() => {
+                warn!(target: "render", "🖥️ GPU unavailable ({}), falling back to the software renderer", e);
+                self.start_software_organism(event_loop, window, safety_mode_requested);
+                return;
+            }
+            Err(e) => {
+                error!(target: "app", "❌ Failed to initialize chaos engine: {}", e);
+                self.failure = Some(e);
+                event_loop.exit();
+                return;
+            }
+        };
+
+        if self.window_options.span_displays {
+            self.open_display_outputs(event_loop, &mut chaos_engine);
+        }
+
+        // Apply safety mode configuration if user selected it
+        if safety_mode_requested {
+            chaos_engine.enable_safety_mode();
+        }
+
+        let mode_text = if safety_mode_requested {
+            "psychedelic madness (SAFETY MODE)!"
+        } else {
+            "psychedelic madness!"
+        };
+        info!(target: "app", "✨ Window ready for {}!", mode_text);
+        info!(target: "safety", "🛡️ Safety systems active - Flash limiting, luminance control, red flash protection");
+
+        // First runs are walked through the controls; everyone else gets the summary
+        if chaos_engine.tutorial_step().is_none() {
+            chaos_engine.show_audio_status();
+        }
+
+        self.state = Some(AppState::Running(Box::new(chaos_engine)));
+        self.timestep = FixedTimestep::new(Instant::now());
+        window.request_redraw();
+    }
+
+    /// Run the simplified organism drawn on the CPU
+    fn start_software_organism(&mut self, event_loop: &ActiveEventLoop, window: std::sync::Arc<winit::window::Window>, safety_mode: bool) {
+        let mut organism = match SoftwareOrganism::new(window.clone(), &self.config) {
+            Ok(organism) => organism,
+            Err(e) => {
+                error!(target: "app", "❌ Failed to initialize software renderer: {}", e);
+                self.failure = Some(e);
+                event_loop.exit();
+                return;
+            }
+        };
+        if safety_mode {
+            organism.enable_safety_mode();
+        }
+
+        self.state = Some(AppState::Software(Box::new(organism)));
+        self.timestep = FixedTimestep::new(Instant::now());
+        window.request_redraw();
+    }
+}
+
+impl ApplicationHandler for App {
+    fn resumed(&mut self, event_loop: &ActiveEventLoop) {
+        if self.window.is_some() {
+            return;
+        }
+
+        info!(target: "app", "🪟 Creating window...");
+        let mut attributes = WindowAttributes::default()
+            .with_title(self.window_options.title.clone())
+            .with_inner_size(winit::dpi::LogicalSize::new(self.window_options.width, self.window_options.height))
+            .with_visible(true);
+        let command = self.screensaver.as_ref().map(|session| session.command);
+        if let Some(ScreensaverCommand::Preview(handle)) = command {
+            attributes = attributes
+                .with_inner_size(winit::dpi::PhysicalSize::new(PREVIEW_SIZE.0, PREVIEW_SIZE.1))
+                .with_decorations(false);
+            // SAFETY: the host keeps its preview window alive while the preview runs,
+            // and destroys ours along with it when it closes
+            attributes = unsafe { attributes.with_parent_window(parent_window(handle)) };
+        } else if self.window_options.span_displays {
+            let leftmost = monitors_left_to_right(event_loop).into_iter().next();
+            attributes = attributes.with_fullscreen(Some(Fullscreen::Borderless(leftmost)));
+        } else {
+            let monitor = self.chosen_monitor(event_loop);
+            if let Some(monitor) = &monitor {
+                attributes = attributes.with_position(monitor.position());
+            }
+            let monitor = monitor.or_else(|| event_loop.primary_monitor());
+            attributes = attributes.with_fullscreen(fullscreen_for(self.window_options.fullscreen, monitor));
+        }
+        let window = match event_loop.create_window(attributes) {
+            Ok(window) => std::sync::Arc::new(window),
+            Err(e) => {
+                error!(target: "app", "❌ Failed to create window: {}", e);
+                self.failure = Some(e.into());
+                event_loop.exit();
+                return;
+            }
+        };
+
+        // Nobody is at the keyboard when a screensaver starts: it runs on the answer stored by its settings
+        if let Some(session) = self.screensaver.as_ref().filter(|session| session.command != ScreensaverCommand::Configure) {
+            let response = session.profile.response();
+            info!(target: "safety", "🛡️ Screensaver starting from its stored warning answer: {:?}", response);
+            if session.command == ScreensaverCommand::Run {
+                window.set_cursor_visible(false);
+            }
+            self.window = Some(window.clone());
+            self.resolve_warning(event_loop, response);
+            window.request_redraw();
+            return;
+        }
+
+        // A relaunch after a crash keeps the answer given earlier in this supervised session
+        if let Some(response) = resumed_response() {
+            info!(target: "safety", "🚑 Relaunched after a crash - the warning was already answered this session");
+            self.window = Some(window.clone());
+            self.resolve_warning(event_loop, response);
+            window.request_redraw();
+            return;
+        }
+
+        // CRITICAL SAFETY: Show epilepsy warning before anything else
+        info!(target: "safety", "⚠️  INITIALIZING EPILEPSY SAFETY SYSTEMS...");
+        let warning_screen = if self.config.software_renderer {
+            WarningScreen::software(window.clone(), self.config.locale.clone())
+        } else {
+            match pollster::block_on(WarningScreen::new(window.clone(), self.config.locale.clone())) {
+                Err(e) if e.is_gpu_unavailable() => {
+                    warn!(target: "render", "🖥️ GPU unavailable ({}), falling back to the software renderer", e);
+                    WarningScreen::software(window.clone(), self.config.locale.clone())
+                }
+                started => started,
+            }
+        };
+        match warning_screen {
+            Ok(warning_screen) => self.state = Some(AppState::Warning(warning_screen)),
+            Err(e) => {
+                error!(target: "safety", "❌ Failed to display safety warning: {}. Exiting for safety.", e);
+                self.failure = Some(e);
+                event_loop.exit();
+                return;
+            }
+        }
+
+        self.window = Some(window.clone());
+        window.request_redraw();
+    }
+
+    fn window_event(
+        &mut self,
+        event_loop: &ActiveEventLoop,
+        window_id: winit::window::WindowId,
+        event: WindowEvent,
+    ) {
+        let woken = matches!(&self.state, Some(AppState::Running(_) | AppState::Software(_)))
+            && self.screensaver.as_mut().is_some_and(|session| session.wakes(&event));
+        let is_primary = self.window.as_ref().is_some_and(|window| window.id() == window_id);
+        // Closing an extra display window takes only that panel down
+        if !is_primary && !woken && matches!(event, WindowEvent::CloseRequested | WindowEvent::Destroyed) {
+            if let Some(AppState::Running(engine)) = &mut self.state {
+                engine.remove_output(window_id);
+            }
+            return;
+        }
+        // Destroyed: the host closed the preview window ours was parented to
+        if matches!(event, WindowEvent::CloseRequested | WindowEvent::Destroyed) || woken {
+            info!(target: "app", "🌌 RETURNING TO THE VOID...");
+            if let Some(AppState::Running(engine)) = &self.state {
+                engine.end_session();
+            }
+            event_loop.exit();
+            return;
+        }
+
+        // Extra display windows only resize themselves and pass on keys;
+        // they are redrawn together with the primary window
+        if !is_primary {
+            if let Some(AppState::Running(engine)) = &mut self.state {
+                match event {
+                    WindowEvent::Resized(physical_size) => {
+                        engine.resize_output(window_id, physical_size);
+                    }
+                    WindowEvent::KeyboardInput { .. } => engine.handle_window_event(&event),
+                    _ => {}
+                }
+            }
+            return;
+        }
+
+        // The fullscreen key works on every screen; the event still goes on to wake the organism
+        if matches!(&event, WindowEvent::KeyboardInput { event: key_event, .. } if self.is_fullscreen_key(key_event)) {
+            self.toggle_fullscreen();
+        }
+
+        match &mut self.state {
+            Some(AppState::Warning(warning_screen)) => {
+                let response = match event {
+                    WindowEvent::Resized(physical_size) => {
+                        warning_screen.resize(physical_size);
+                        None
+                    }
+                    WindowEvent::CursorMoved { position, .. } => {
+                        warning_screen.handle_cursor_moved(position);
+                        None
+                    }
+                    WindowEvent::MouseInput { state, button, .. } => warning_screen.handle_click(button, state),
+                    WindowEvent::KeyboardInput { event, .. } => warning_screen.handle_keyboard(&event),
+                    WindowEvent::RedrawRequested => {
+                        match warning_screen.render() {
+                            Ok(_) => {}
+                            Err(SurfaceError::OutOfMemory) => event_loop.exit(),
+                            Err(e) => error!(target: "render", "Warning screen render error: {:?}", e),
+                        }
+                        None
+                    }
+                    _ => None,
+                };
+
+                if let Some(response) = response {
+                    self.resolve_warning(event_loop, response);
+                }
+            }
+            Some(AppState::Running(engine)) => match event {
+                WindowEvent::RedrawRequested => {
+                    for _ in 0..self.timestep.ticks(Instant::now()) {
+                        engine.update();
+                    }
+                    match engine.render() {
+                        Ok(_) => {}
+                        Err(SurfaceError::Lost) => {
+                            // Reconfigure surface on lost
+                        }
+                        Err(SurfaceError::OutOfMemory) => event_loop.exit(),
+                        Err(e) => error!(target: "render", "Render error: {:?}", e),
+                    }
+                }
+                other => engine.handle_window_event(&other),
+            },
+            Some(AppState::Software(organism)) => {
+                let handled = match event {
+                    WindowEvent::RedrawRequested => {
+                        for _ in 0..self.timestep.ticks(Instant::now()) {
+                            organism.update();
+                        }
+                        organism.render()
+                    }
+                    other => organism.handle_window_event(&other),
+                };
+                if let Err(e) = handled {
+                    error!(target: "render", "Software render error: {}", e);
+                }
+            }
+            None => {}
+        }
+    }
+
+    fn about_to_wait(&mut self, event_loop: &ActiveEventLoop) {
+        let Some(window) = &self.window else { return };
+
+        let frame_interval = match &self.state {
+            Some(AppState::Running(engine)) => engine.frame_interval(),
+            Some(AppState::Software(_)) => Some(SOFTWARE_FRAME_INTERVAL),
+            _ => None,
+        };
+
+        // Full rate redraws continuously; a paced rate sleeps until the next frame is due,
+        // and any input event wakes the loop early
+        match frame_interval {
+            None => {
+                event_loop.set_control_flow(ControlFlow::Poll);
+                window.request_redraw();
+            }
+            Some(interval) => {
+                let now = Instant::now();
+                let next_frame = self.last_redraw + interval;
+                if now >= next_frame {
+                    self.last_redraw = now;
+                    window.request_redraw();
+                    event_loop.set_control_flow(ControlFlow::WaitUntil(now + interval));
+                } else {
+                    event_loop.set_control_flow(ControlFlow::WaitUntil(next_frame));
+                }
+            }
+        }
+    }
+}
+
+/// Log filter used when RUST_LOG is unset
+const DEFAULT_LOG_FILTER: &str = "info";
+
+pub fn run() -> Result<()> {
+    crate::api::AetheriumBloom::builder().run()
+}
+
+/// RUST_LOG picks levels per target (audio, warfare, render, safety, app),
+/// e.g. RUST_LOG=warn for warnings only or RUST_LOG=info,warfare=debug.
+/// A host that installed its own subscriber keeps it
+pub(crate) fn init_logging() {
+    let _ = tracing_subscriber::fmt()
+        .with_env_filter(EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(DEFAULT_LOG_FILTER)))
+        .try_init();
+}
+
+/// Own the window and event loop: warning screen first, then the organism
+pub fn run_standalone(window_options: WindowOptions, config: BloomConfig) -> Result<()> {
+    init_logging();
+    run_app(window_options, config, None)
+}
+
+/// Run as the system screensaver, as `command` from the host's arguments asks
+pub fn run_screensaver(mut window_options: WindowOptions, mut config: BloomConfig, command: ScreensaverCommand) -> Result<()> {
+    init_logging();
+    let path = ScreensaverProfile::default_file();
+    let profile = match ScreensaverProfile::load(&path) {
+        Ok(Some(profile)) => profile,
+        Ok(None) => {
+            if command != ScreensaverCommand::Configure {
+                info!(target: "safety", "🛡️ No screensaver settings yet - starting in safety mode; open the settings (/c) to choose");
+            }
+            ScreensaverProfile::default()
+        }
+        Err(e) => {
+            warn!(target: "app", "⚠️ {} - using the default screensaver settings", e);
+            ScreensaverProfile::default()
+        }
+    };
+
+    match command {
+        ScreensaverCommand::Run => {
+            window_options.fullscreen = FullscreenMode::Borderless;
+            config.audio_enabled &= profile.audio;
+        }
+        // The control panel's little monitor stays silent
+        ScreensaverCommand::Preview(_) => {
+            window_options.fullscreen = FullscreenMode::Windowed;
+            window_options.span_displays = false;
+            config.audio_enabled = false;
+        }
+        ScreensaverCommand::Configure => window_options.fullscreen = FullscreenMode::Windowed,
+    }
+    run_app(window_options, config, Some(ScreensaverSession::new(command, profile)))
+}
+
+fn run_app(window_options: WindowOptions, config: BloomConfig, screensaver: Option<ScreensaverSession>) -> Result<()> {
+    let event_loop = EventLoop::new()?;
+    let mut app = App {
+        state: None,
+        window: None,
+        window_options,
+        config,
+        failure: None,
+        last_redraw: Instant::now(),
+        timestep: FixedTimestep::new(Instant::now()),
+        screensaver,
+    };
+
+    event_loop.run_app(&mut app)?;
+    match app.failure {
+        Some(e) => Err(e),
+        None => Ok(()),
+    }
+}
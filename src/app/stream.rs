@@ -0,0 +1,318 @@
+// === STATE STREAMING ===
+// Broadcasts the simulation as compact binary packets so external tools
+// (Unity, TouchDesigner, a web dashboard) can draw their own version of the
+// same organism. At a configurable rate every llama, crystal and death echo
+// goes out with its position and color, along with the events since the last
+// frame, over UDP to any number of targets and to WebSocket clients of the
+// remote control server's `/stream` endpoint.
+//
+// Wire format, all little-endian. A frame is one or more packets; each starts
+// with a 24-byte header and is at most 1200 bytes, so it crosses any path
+// MTU in one piece instead of relying on IP fragmentation:
+//   0  magic "ABST"        4  version u8 (2)       5  packet index u8
+//   6  packet count u8     7  reserved u8          8  frame sequence u32
+//   12 time f32 (seconds)  16 entity records u16   18 event records u16
+//   20 entities in the whole frame u32
+// then the entity records, 28 bytes each:
+//   id u32, kind u8 (0 llama, 1 crystal, 2 echo), species u8 (species index,
+//   crystal type for crystals), flags u8 (bit 0: hybrid), reserved u8,
+//   x f32, y f32, hue f32 (degrees), saturation f32, energy f32
+//   (consciousness, stored crystal energy or the echo's remaining consciousness)
+// then the event records, 20 bytes each. Events fill the leading packets
+// before any entities do, so a burst of events may span several packets:
+//   kind u8 (see `event_record`), species u8 (255: none), reserved u16,
+//   entity u32 (u32::MAX: none), x f32, y f32 (NaN: no position), value f32
+
+use std::net::{SocketAddr, ToSocketAddrs, UdpSocket};
+use std::sync::mpsc::Receiver;
+use glam::Vec2;
+use tracing::{info, warn};
+use crate::core::ecs::{EntityId, World};
+use crate::core::events::ChaosEvent;
+use crate::engine::ObserverIntervention;
+use crate::entities::{Llama, SpeciesType};
+use crate::params::STREAM_RATE_HZ;
+use crate::simulation::{ConsciousnessCrystal, LlamaEcho};
+
+/// First bytes of every packet
+pub const STREAM_MAGIC: [u8; 4] = *b"ABST";
+/// Bumped whenever a record layout changes
+pub const STREAM_VERSION: u8 = 2;
+/// Largest packet sent; fits the usual 1280-byte IPv6 minimum MTU with room for IP and UDP headers
+const MAX_PACKET_BYTES: usize = 1200;
+const HEADER_BYTES: usize = 24;
+const ENTITY_BYTES: usize = 28;
+const EVENT_BYTES: usize = 20;
+/// Events carried per frame; a storm beyond this drops its oldest
+const MAX_EVENTS_PER_FRAME: usize = 256;
+/// Species byte of events that involve none
+const NO_SPECIES: u8 = 255;
+
+/// Where and how often the simulation state is streamed
+#[derive(Debug, Clone, PartialEq)]
+pub struct StateStream {
+    pub udp_targets: Vec<String>, // `host:port` per receiver; a broadcast address reaches the whole subnet
+    pub rate_hz: f32,             // Frames per second
+}
+
+impl StateStream {
+    /// Stream to WebSocket clients of the remote control server only
+    pub fn new() -> Self {
+        Self { udp_targets: Vec::new(), rate_hz: STREAM_RATE_HZ.default }
+    }
+
+    pub fn udp(mut self, target: impl Into<String>) -> Self {
+        self.udp_targets.push(target.into());
+        self
+    }
+
+    pub fn with_rate(mut self, rate_hz: f32) -> Self {
+        self.rate_hz = STREAM_RATE_HZ.clamp(rate_hz);
+        self
+    }
+}
+
+impl Default for StateStream {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// What kind of entity a record describes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum StreamedKind {
+    Llama = 0,
+    Crystal = 1,
+    Echo = 2,
+}
+
+/// One entity as it goes on the wire
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EntityRecord {
+    pub id: EntityId,
+    pub kind: StreamedKind,
+    pub species: u8,
+    pub hybrid: bool,
+    pub position: Vec2,
+    pub hue: f32,
+    pub saturation: f32,
+    pub energy: f32,
+}
+
+/// One event as it goes on the wire
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EventRecord {
+    pub kind: u8,
+    pub species: u8,
+    pub entity: Option<EntityId>,
+    pub position: Option<Vec2>,
+    pub value: f32,
+}
+
+/// Every streamed entity of the world
+pub fn entity_records(world: &World) -> Vec<EntityRecord> {
+    let llamas = world.query::<Llama>().into_iter().map(|(id, llama)| EntityRecord {
+        id,
+        kind: StreamedKind::Llama,
+        species: llama.species.to_index() as u8,
+        hybrid: llama.hybrid.is_some(),
+        position: llama.position,
+        hue: llama.color.x,
+        saturation: llama.color.y,
+        energy: llama.consciousness,
+    });
+    let crystals = world.query::<ConsciousnessCrystal>().into_iter().map(|(id, crystal)| EntityRecord {
+        id,
+        kind: StreamedKind::Crystal,
+        species: crystal.crystal_type.clone() as u8,
+        hybrid: false,
+        position: crystal.position,
+        hue: 0.0,
+        saturation: 0.0,
+        energy: crystal.consciousness_energy,
+    });
+    let echoes = world.query::<LlamaEcho>().into_iter().map(|(id, echo)| EntityRecord {
+        id,
+        kind: StreamedKind::Echo,
+        species: echo.species.to_index() as u8,
+        hybrid: false,
+        position: echo.position,
+        hue: echo.hue,
+        saturation: echo.fade(),
+        energy: echo.remaining,
+    });
+    llamas.chain(crystals).chain(echoes).collect()
+}
+
+/// An event's wire record; the kind is the variant's position in `ChaosEvent`
+pub fn event_record(event: &ChaosEvent) -> EventRecord {
+    let species = |species: &SpeciesType| species.to_index() as u8;
+    let record = |kind, species, entity, position, value| EventRecord { kind, species, entity, position, value };
+    match event {
+        ChaosEvent::LlamaSpawned { entity, species: s, position } => record(0, species(s), Some(*entity), Some(*position), 0.0),
+        ChaosEvent::LlamaDespawned { entity, species: s, position, reason } => record(1, species(s), Some(*entity), Some(*position), *reason as u8 as f32),
+        ChaosEvent::HiveFormed { hive, species: s, members } => record(2, species(s), Some(*hive), None, *members as f32),
+        ChaosEvent::HiveDissolved { hive, members_left } => record(3, NO_SPECIES, Some(*hive), None, *members_left as f32),
+        ChaosEvent::SpeciesEmerged { hybrid, parents, hive, .. } => record(4, species(&parents[0]), Some(*hive), None, *hybrid as f32),
+        ChaosEvent::ConflictStarted { attacker, defender, front } => record(5, species(attacker), None, Some(*front), species(defender) as f32),
+        ChaosEvent::ConflictEnded { attacker, front, victor, .. } =>
+            record(6, species(attacker), None, Some(*front), victor.as_ref().map_or(-1.0, |victor| species(victor) as f32)),
+        ChaosEvent::CrystalSpawned { crystal, position } => record(7, NO_SPECIES, Some(*crystal), Some(*position), 0.0),
+        ChaosEvent::CrystalHarvested { llama, species: s, position, amount } => record(8, species(s), Some(*llama), Some(*position), *amount),
+        ChaosEvent::ConsciousnessGifted { donor, species: s, position, amount, .. } => record(9, species(s), Some(*donor), Some(*position), *amount),
+        ChaosEvent::TearOpened { tear, position } => record(10, NO_SPECIES, Some(*tear), Some(*position), 0.0),
+        ChaosEvent::TearClosed { tear, position } => record(11, NO_SPECIES, Some(*tear), Some(*position), 0.0),
+        ChaosEvent::EchoReleased { echo, species: s, position, consciousness } => record(12, species(s), Some(*echo), Some(*position), *consciousness),
+        ChaosEvent::EchoCrystallized { crystal, position, .. } => record(13, NO_SPECIES, Some(*crystal), Some(*position), 0.0),
+        ChaosEvent::ObserverIntervention(intervention) =>
+            record(14, NO_SPECIES, None, None, ObserverIntervention::ALL.iter().position(|known| known == intervention).unwrap_or(0) as f32),
+        ChaosEvent::BeatDrop { intensity, .. } => record(15, NO_SPECIES, None, None, *intensity),
+    }
+}
+
+/// Split a frame into packets of at most `MAX_PACKET_BYTES`; events fill the leading packets, then entities
+pub fn encode_frame(sequence: u32, time: f32, entities: &[EntityRecord], events: &[EventRecord]) -> Vec<Vec<u8>> {
+    let events = &events[events.len().saturating_sub(MAX_EVENTS_PER_FRAME)..];
+    let mut chunks: Vec<(&[EntityRecord], &[EventRecord])> = Vec::new();
+    let (mut entities_left, mut events_left) = (entities, events);
+    while chunks.is_empty() || !entities_left.is_empty() || !events_left.is_empty() {
+        let mut room = MAX_PACKET_BYTES - HEADER_BYTES;
+        let (packet_events, rest) = events_left.split_at(events_left.len().min(room / EVENT_BYTES));
+        room -= packet_events.len() * EVENT_BYTES;
+        events_left = rest;
+        let (chunk, rest) = entities_left.split_at(entities_left.len().min(room / ENTITY_BYTES));
+        entities_left = rest;
+        chunks.push((chunk, packet_events));
+    }
+    // The packet count is a byte; a frame past ~10,000 entities loses its tail
+    let count = chunks.len().min(u8::MAX as usize);
+
+    chunks.iter().take(count).enumerate().map(|(index, &(chunk, packet_events))| {
+        let mut packet = Vec::with_capacity(HEADER_BYTES + chunk.len() * ENTITY_BYTES + packet_events.len() * EVENT_BYTES);
+        packet.extend_from_slice(&STREAM_MAGIC);
+        packet.extend_from_slice(&[STREAM_VERSION, index as u8, count as u8, 0]);
+        packet.extend_from_slice(&sequence.to_le_bytes());
+        packet.extend_from_slice(&time.to_le_bytes());
+        packet.extend_from_slice(&(chunk.len() as u16).to_le_bytes());
+        packet.extend_from_slice(&(packet_events.len() as u16).to_le_bytes());
+        packet.extend_from_slice(&(entities.len() as u32).to_le_bytes());
+        for entity in chunk {
+            packet.extend_from_slice(&entity.id.to_le_bytes());
+            packet.extend_from_slice(&[entity.kind as u8, entity.species, entity.hybrid as u8, 0]);
+            for value in [entity.position.x, entity.position.y, entity.hue, entity.saturation, entity.energy] {
+                packet.extend_from_slice(&value.to_le_bytes());
+            }
+        }
+        for event in packet_events {
+            packet.extend_from_slice(&[event.kind, event.species, 0, 0]);
+            packet.extend_from_slice(&event.entity.unwrap_or(u32::MAX).to_le_bytes());
+            let position = event.position.unwrap_or(Vec2::NAN);
+            for value in [position.x, position.y, event.value] {
+                packet.extend_from_slice(&value.to_le_bytes());
+            }
+        }
+        packet
+    }).collect()
+}
+
+/// The running stream: collects events between frames and sends each frame when it is due
+pub(crate) struct StateStreamer {
+    config: StateStream,
+    socket: Option<UdpSocket>,
+    targets: Vec<SocketAddr>,
+    events: Receiver<ChaosEvent>,
+    sequence: u32,
+    last_frame: f32,
+    warned: bool, // Send failures are reported once
+}
+
+impl StateStreamer {
+    pub fn start(config: &StateStream, events: Receiver<ChaosEvent>) -> Self {
+        let config = config.clone().with_rate(config.rate_hz);
+        let targets: Vec<SocketAddr> = config.udp_targets.iter().filter_map(|target| {
+            match target.to_socket_addrs().map(|mut addresses| addresses.next()) {
+                Ok(Some(address)) => Some(address),
+                Ok(None) | Err(_) => {
+                    warn!(target: "app", "📶 State stream target '{}' did not resolve", target);
+                    None
+                }
+            }
+        }).collect();
+        let socket = (!targets.is_empty()).then(|| {
+            UdpSocket::bind("0.0.0.0:0").and_then(|socket| {
+                socket.set_broadcast(true)?;
+                socket.set_nonblocking(true)?;
+                Ok(socket)
+            })
+        }).and_then(|socket| socket.map_err(|e| warn!(target: "app", "📶 State stream socket not opened: {}", e)).ok());
+        info!(target: "app", "📶 Streaming state at {:.0} Hz to {} UDP target(s)", config.rate_hz, targets.len());
+        Self { config, socket, targets, events, sequence: 0, last_frame: f32::NEG_INFINITY, warned: false }
+    }
+
+    /// Encode and send a frame if one is due, returning its sequence and packets for WebSocket clients
+    pub fn frame(&mut self, world: &World, time: f32) -> Option<(u32, Vec<Vec<u8>>)> {
+        if time - self.last_frame < 1.0 / self.config.rate_hz {
+            return None;
+        }
+        self.last_frame = time;
+        let events: Vec<EventRecord> = self.events.try_iter().map(|event| event_record(&event)).collect();
+        let sequence = self.sequence;
+        let packets = encode_frame(sequence, time, &entity_records(world), &events);
+        self.sequence = self.sequence.wrapping_add(1);
+
+        if let Some(socket) = &self.socket {
+            for target in &self.targets {
+                for packet in &packets {
+                    if let Err(e) = socket.send_to(packet, target) {
+                        if !self.warned && e.kind() != std::io::ErrorKind::WouldBlock {
+                            warn!(target: "app", "📶 State stream to {} failed: {}", target, e);
+                            self.warned = true;
+                        }
+                    }
+                }
+            }
+        }
+        Some((sequence, packets))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frames_split_into_packets_with_events_first() {
+        let llama = EntityRecord {
+            id: 7, kind: StreamedKind::Llama, species: 2, hybrid: true,
+            position: Vec2::new(120.0, 80.0), hue: 200.0, saturation: 0.8, energy: 1.5,
+        };
+        let entities = vec![llama; 1000];
+        let event = event_record(&ChaosEvent::BeatDrop { intensity: 1.4, cosmic_time: 3.0 });
+        let packets = encode_frame(42, 12.5, &entities, &[event]);
+
+        assert!(packets.len() > 1 && packets.iter().all(|packet| packet.len() <= MAX_PACKET_BYTES));
+        let u16_at = |packet: &[u8], at: usize| u16::from_le_bytes([packet[at], packet[at + 1]]) as usize;
+        let f32_at = |packet: &[u8], at: usize| f32::from_le_bytes(packet[at..at + 4].try_into().unwrap());
+        let first = &packets[0];
+        assert_eq!((&first[..4], first[4], first[6] as usize), (&STREAM_MAGIC[..], STREAM_VERSION, packets.len()));
+        assert_eq!(u32::from_le_bytes(first[8..12].try_into().unwrap()), 42);
+        assert_eq!(packets.iter().map(|packet| u16_at(packet, 16)).sum::<usize>(), 1000, "every entity is sent once");
+        assert_eq!((u16_at(first, 18), u16_at(&packets[1], 18)), (1, 0), "events ride in the first packet");
+
+        // A storm of events spreads over the leading packets, and no packet outgrows a datagram
+        let packets = encode_frame(43, 12.5, &entities[..10], &[event; MAX_EVENTS_PER_FRAME]);
+        assert!(packets.len() > 1 && packets.iter().all(|packet| packet.len() <= MAX_PACKET_BYTES));
+        assert_eq!(packets.iter().map(|packet| u16_at(packet, 18)).sum::<usize>(), MAX_EVENTS_PER_FRAME);
+        assert_eq!(packets.iter().map(|packet| u16_at(packet, 16)).sum::<usize>(), 10);
+        assert_eq!(encode_frame(44, 12.5, &[], &[]).len(), 1, "an empty frame still sends its header");
+
+        // The first record and the event read back as written
+        assert_eq!((&first[24..28], first[28], first[30]), (&7u32.to_le_bytes()[..], StreamedKind::Llama as u8, 1));
+        assert_eq!((f32_at(first, 32), f32_at(first, 48)), (120.0, 1.5));
+        let event_at = HEADER_BYTES + u16_at(first, 16) * ENTITY_BYTES;
+        assert_eq!((first[event_at], first[event_at + 1]), (15, NO_SPECIES));
+        assert!(f32_at(first, event_at + 8).is_nan());
+        assert_eq!(f32_at(first, event_at + 16), 1.4);
+    }
+}
@@ -0,0 +1,133 @@
+// === CHAOS TUNING PANEL ===
+// A debug panel of sliders over every chaos event probability, for balancing
+// the emergent systems while they run. Up/Down pick a knob, Left/Right nudge
+// it a fiftieth of its range, Delete puts it back to its default, and a click
+// on a track sets the value under the cursor. Changes apply on the next tick;
+// closing the panel logs the whole tuning so a balance can be copied into code.
+
+use glam::{Vec2, Vec3};
+use winit::keyboard::NamedKey;
+use crate::engine::ChaosTuning;
+use crate::locale::Locale;
+use crate::reality::{text, Vertex};
+use super::keybindings::{Action, BoundKey, KeyBindings};
+
+const PANEL_ORIGIN: Vec2 = Vec2::new(300.0, 200.0);
+const ROW_HEIGHT: f32 = 36.0;
+/// Where the slider tracks sit, relative to the panel origin, and their size
+const TRACK_OFFSET: Vec2 = Vec2::new(0.0, 14.0);
+const TRACK_SIZE: Vec2 = Vec2::new(480.0, 8.0);
+/// Share of a knob's range one Left/Right press moves it
+const NUDGE: f32 = 1.0 / 50.0;
+const SCREEN: Vec2 = Vec2::new(1200.0, 800.0);
+
+/// What a key press or click did on the panel
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TuningInput {
+    Ignored,
+    Selected,
+    Changed(usize),
+    Close,
+}
+
+/// Sliders for every knob of a `ChaosTuning`
+#[derive(Debug, Default)]
+pub struct TuningPanel {
+    selected: usize,
+}
+
+impl TuningPanel {
+    pub fn handle_key(&mut self, key: BoundKey, bindings: &KeyBindings, tuning: &mut ChaosTuning) -> TuningInput {
+        if bindings.keys(Action::ChaosTuning).contains(&key) {
+            return TuningInput::Close;
+        }
+        let param = ChaosTuning::PARAMS[self.selected];
+        let value = tuning.values()[self.selected];
+        let step = (param.max - param.min) * NUDGE;
+        match key {
+            BoundKey::Named(NamedKey::ArrowUp) => self.selected = self.selected.saturating_sub(1),
+            BoundKey::Named(NamedKey::ArrowDown) => self.selected = (self.selected + 1).min(ChaosTuning::PARAMS.len() - 1),
+            BoundKey::Named(NamedKey::ArrowLeft) => return self.set(tuning, value - step),
+            BoundKey::Named(NamedKey::ArrowRight) => return self.set(tuning, value + step),
+            BoundKey::Named(NamedKey::Delete) => return self.set(tuning, param.default),
+            _ => return TuningInput::Ignored,
+        }
+        TuningInput::Selected
+    }
+
+    /// Set the knob whose track is under `cursor` to the value there
+    pub fn click(&mut self, cursor: Vec2, tuning: &mut ChaosTuning) -> TuningInput {
+        for (index, param) in ChaosTuning::PARAMS.iter().enumerate() {
+            let min = track_min(index);
+            // Half a row of slack above and below so the thin track is easy to hit
+            let slack = Vec2::new(0.0, ROW_HEIGHT * 0.5 - TRACK_SIZE.y * 0.5);
+            if cursor.cmpge(min - slack).all() && cursor.cmple(min + TRACK_SIZE + slack).all() {
+                self.selected = index;
+                let fraction = (cursor.x - min.x) / TRACK_SIZE.x;
+                return self.set(tuning, param.min + fraction * (param.max - param.min));
+            }
+        }
+        TuningInput::Ignored
+    }
+
+    fn set(&self, tuning: &mut ChaosTuning, value: f32) -> TuningInput {
+        tuning.set(self.selected, value);
+        TuningInput::Changed(self.selected)
+    }
+
+    /// Dim panel of labelled sliders, the selected one brighter
+    pub fn push_overlay(&self, vertices: &mut Vec<Vertex>, tuning: &ChaosTuning, locale: &Locale, bindings: &KeyBindings) {
+        let rows = ChaosTuning::PARAMS.len() as f32;
+        text::push_rect(vertices, PANEL_ORIGIN - Vec2::new(16.0, 46.0), PANEL_ORIGIN + Vec2::new(616.0, rows * ROW_HEIGHT + 24.0), Vec3::new(0.04, 0.04, 0.1), SCREEN);
+        text::push_text(vertices, locale.text("tuning-title"), PANEL_ORIGIN - Vec2::new(0.0, 30.0), 2.0, Vec3::splat(0.5), SCREEN);
+
+        for (index, (param, value)) in ChaosTuning::PARAMS.iter().zip(tuning.values()).enumerate() {
+            let color = if index == self.selected { Vec3::new(0.55, 0.6, 0.75) } else { Vec3::splat(0.38) };
+            let min = track_min(index);
+            let label = locale.text(&format!("tuning-{}", param.name.trim_start_matches("chaos.").replace('_', "-"))).to_string();
+            text::push_text(vertices, &label, min - TRACK_OFFSET, 2.0, color, SCREEN);
+            text::push_text(vertices, &format!("{:.4}", value), min - TRACK_OFFSET + Vec2::new(TRACK_SIZE.x + 16.0, 8.0), 2.0, color, SCREEN);
+
+            let fraction = (value - param.min) / (param.max - param.min);
+            text::push_rect(vertices, min, min + TRACK_SIZE, Vec3::splat(0.1), SCREEN);
+            text::push_rect(vertices, min, min + Vec2::new(TRACK_SIZE.x * fraction, TRACK_SIZE.y), color * 0.7, SCREEN);
+            let default = min.x + TRACK_SIZE.x * (param.default - param.min) / (param.max - param.min);
+            text::push_rect(vertices, Vec2::new(default - 1.0, min.y - 2.0), Vec2::new(default + 1.0, min.y + TRACK_SIZE.y + 2.0), Vec3::splat(0.45), SCREEN);
+        }
+
+        let help = locale.format("tuning-help", &[("key", &bindings.label(Action::ChaosTuning))]);
+        text::push_text(vertices, &help, PANEL_ORIGIN + Vec2::new(0.0, rows * ROW_HEIGHT + 4.0), 2.0, Vec3::splat(0.35), SCREEN);
+    }
+}
+
+/// Top-left corner of the track of the knob at `index`
+fn track_min(index: usize) -> Vec2 {
+    PANEL_ORIGIN + TRACK_OFFSET + Vec2::new(0.0, index as f32 * ROW_HEIGHT)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::params::{CHAOS_CONFLICT_CHANCE, CHAOS_CRYSTAL_RATE};
+
+    #[test]
+    fn test_keys_and_clicks_move_the_selected_knob() {
+        let bindings = KeyBindings::default();
+        let mut panel = TuningPanel::default();
+        let mut tuning = ChaosTuning::default();
+
+        assert_eq!(panel.handle_key(BoundKey::Named(NamedKey::ArrowRight), &bindings, &mut tuning), TuningInput::Changed(0));
+        assert!(tuning.crystal_rate > CHAOS_CRYSTAL_RATE.default);
+        assert_eq!(panel.handle_key(BoundKey::Named(NamedKey::Delete), &bindings, &mut tuning), TuningInput::Changed(0));
+        assert_eq!(tuning, ChaosTuning::default());
+
+        // Halfway along the conflict track is half the conflict range
+        let track = track_min(3);
+        assert_eq!(panel.click(track + Vec2::new(TRACK_SIZE.x * 0.5, 2.0), &mut tuning), TuningInput::Changed(3));
+        assert!((tuning.conflict_chance - CHAOS_CONFLICT_CHANCE.max * 0.5).abs() < 1e-4);
+        assert_eq!(panel.click(Vec2::ZERO, &mut tuning), TuningInput::Ignored);
+
+        let close = bindings.keys(Action::ChaosTuning)[0];
+        assert_eq!(panel.handle_key(close, &bindings, &mut tuning), TuningInput::Close);
+    }
+}
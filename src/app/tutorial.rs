@@ -0,0 +1,215 @@
+// === GUIDED FIRST RUN ===
+// A short tutorial drawn over the organism the first time it runs. Each step
+// shows one prompt and waits for the thing it asks for: a click, a key, or a
+// real chaos event such as a hive forming. Tab skips the lot. Prompts name
+// whatever keys are currently bound, not the defaults. Finishing or skipping
+// leaves a marker file behind so later runs start straight away.
+
+use std::path::{Path, PathBuf};
+use glam::{Vec2, Vec3};
+use tracing::{info, warn};
+use crate::core::events::ChaosEvent;
+use crate::locale::Locale;
+use crate::reality::{text, Vertex};
+use super::keybindings::{Action, KeyBindings};
+
+/// Marker file name, kept in the home directory
+const MARKER_NAME: &str = ".aetherium_bloom_tutorial_done";
+/// Seconds a new prompt takes to fade in
+const FADE_IN_SECONDS: f32 = 0.6;
+
+/// When the tutorial is shown
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TutorialMode {
+    FirstRun(PathBuf), // Only while this marker file does not exist yet
+    Always,
+    Never,
+}
+
+/// Embedded hosts show no tutorial unless they ask for one
+impl Default for TutorialMode {
+    fn default() -> Self {
+        TutorialMode::Never
+    }
+}
+
+impl TutorialMode {
+    /// Until finished once, remembered by a marker in the home directory; with no
+    /// home directory there is nowhere to remember it, so it is shown every run
+    pub fn first_run() -> Self {
+        match super::known_home_dir() {
+            Some(home) => TutorialMode::FirstRun(home.join(MARKER_NAME)),
+            None => TutorialMode::Always,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TutorialStep {
+    Spawn,
+    MellowAudio,
+    WatchHive,
+    WatchWar,
+    Controls,
+}
+
+/// What the organism tells the tutorial about
+#[derive(Debug, Clone, Copy)]
+pub enum TutorialCue<'a> {
+    Click,
+    Action(Action),
+    Event(&'a ChaosEvent),
+}
+
+impl TutorialStep {
+    pub const ALL: [TutorialStep; 5] = [Self::Spawn, Self::MellowAudio, Self::WatchHive, Self::WatchWar, Self::Controls];
+
+    /// Key of the step's prompt in the language bundles
+    pub fn prompt_key(self) -> &'static str {
+        match self {
+            Self::Spawn => "tutorial-spawn",
+            Self::MellowAudio => "tutorial-mellow-audio",
+            Self::WatchHive => "tutorial-watch-hive",
+            Self::WatchWar => "tutorial-watch-war",
+            Self::Controls => "tutorial-controls",
+        }
+    }
+
+    /// Action whose keys the prompt names
+    fn action(self) -> Option<Action> {
+        match self {
+            Self::MellowAudio => Some(Action::AudioMellow),
+            Self::Controls => Some(Action::ShowControls),
+            _ => None,
+        }
+    }
+
+    fn completed_by(self, cue: TutorialCue) -> bool {
+        match (self, cue) {
+            (Self::Spawn, TutorialCue::Click) => true,
+            (Self::MellowAudio, TutorialCue::Action(action)) => action == Action::AudioMellow,
+            (Self::WatchHive, TutorialCue::Event(event)) => matches!(event, ChaosEvent::HiveFormed { .. }),
+            (Self::WatchWar, TutorialCue::Event(event)) => matches!(event, ChaosEvent::ConflictStarted { .. }),
+            (Self::Controls, TutorialCue::Action(action)) => action == Action::ShowControls,
+            _ => false,
+        }
+    }
+}
+
+pub struct Tutorial {
+    step: usize, // Index into TutorialStep::ALL; past the end once finished
+    step_age: f32,
+    marker: Option<PathBuf>,
+}
+
+impl Tutorial {
+    /// The tutorial this mode calls for, or None when it should not be shown
+    pub fn start(mode: &TutorialMode) -> Option<Self> {
+        let marker = match mode {
+            TutorialMode::Never => return None,
+            TutorialMode::FirstRun(marker) if marker.exists() => return None,
+            TutorialMode::FirstRun(marker) => Some(marker.clone()),
+            TutorialMode::Always => None,
+        };
+        info!(target: "app", "🎓 Tutorial started - follow the prompts, or press Tab to skip");
+        Some(Self { step: 0, step_age: 0.0, marker })
+    }
+
+    pub fn step(&self) -> Option<TutorialStep> {
+        TutorialStep::ALL.get(self.step).copied()
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.step().is_none()
+    }
+
+    pub fn update(&mut self, dt: f32) {
+        self.step_age += dt;
+    }
+
+    /// Move on if `cue` is what the current step waits for; true when it was
+    pub fn observe(&mut self, cue: TutorialCue) -> bool {
+        let Some(step) = self.step().filter(|step| step.completed_by(cue)) else { return false };
+        self.step += 1;
+        self.step_age = 0.0;
+        match self.step() {
+            Some(next) => info!(target: "app", "🎓 {:?} done - next: {:?}", step, next),
+            None => self.finish("🎓 Tutorial complete - the organism is yours"),
+        }
+        true
+    }
+
+    pub fn skip(&mut self) {
+        if !self.is_finished() {
+            self.step = TutorialStep::ALL.len();
+            self.finish("🎓 Tutorial skipped - press H any time for the controls");
+        }
+    }
+
+    fn finish(&self, message: &str) {
+        info!(target: "app", "{}", message);
+        if let Some(marker) = &self.marker {
+            if let Err(e) = write_marker(marker) {
+                warn!(target: "app", "🎓 Could not remember the tutorial was seen ({}): {}", marker.display(), e);
+            }
+        }
+    }
+
+    /// Prompt near the bottom of the screen, with progress and how to skip under it
+    pub fn push_overlay(&self, vertices: &mut Vec<Vertex>, locale: &Locale, bindings: &KeyBindings) {
+        let Some(step) = self.step() else { return };
+        let screen = Vec2::new(1200.0, 800.0);
+        let fade = (self.step_age / FADE_IN_SECONDS).min(1.0);
+        let keys = step.action().map(|action| bindings.label(action)).unwrap_or_default();
+        let prompt = &locale.format(step.prompt_key(), &[("key", &keys)]);
+        let width = text::text_width(prompt, 3.0) + 40.0;
+        text::push_rect(vertices, Vec2::new(600.0 - width / 2.0, 630.0), Vec2::new(600.0 + width / 2.0, 690.0), Vec3::new(0.05, 0.05, 0.12), screen);
+        text::push_text_centered(vertices, prompt, 600.0, 640.0, 3.0, Vec3::new(0.55, 0.6, 0.7) * fade, screen);
+        let progress = locale.format("tutorial-progress", &[
+            ("step", &(self.step + 1)),
+            ("steps", &TutorialStep::ALL.len()),
+            ("key", &bindings.label(Action::SkipTutorial)),
+        ]);
+        text::push_text_centered(vertices, &progress, 600.0, 670.0, 2.0, Vec3::splat(0.35) * fade, screen);
+    }
+}
+
+fn write_marker(marker: &Path) -> std::io::Result<()> {
+    if let Some(parent) = marker.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(marker, "")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::SpeciesType;
+
+    #[test]
+    fn test_steps_follow_real_cues_and_leave_a_marker() {
+        let marker = std::env::temp_dir().join(format!("aetherium_tutorial_test_{}", std::process::id()));
+        let _ = std::fs::remove_file(&marker);
+        let mode = TutorialMode::FirstRun(marker.clone());
+        let mut tutorial = Tutorial::start(&mode).expect("shown before the marker exists");
+
+        assert!(!tutorial.observe(TutorialCue::Action(Action::AudioMellow))); // Out of order
+        assert!(tutorial.observe(TutorialCue::Click));
+        assert!(tutorial.observe(TutorialCue::Action(Action::AudioMellow)));
+        let war = ChaosEvent::ConflictStarted { attacker: SpeciesType::DiscoLlama, defender: SpeciesType::HypnoCamel, front: Vec2::ZERO };
+        assert!(!tutorial.observe(TutorialCue::Event(&war)));
+        assert_eq!(tutorial.step(), Some(TutorialStep::WatchHive));
+
+        let mut vertices = Vec::new();
+        tutorial.update(1.0);
+        tutorial.push_overlay(&mut vertices, &Locale::english(), &KeyBindings::default());
+        assert!(!vertices.is_empty());
+
+        tutorial.skip();
+        assert!(tutorial.is_finished());
+        assert!(marker.exists());
+        assert!(Tutorial::start(&mode).is_none());
+        let _ = std::fs::remove_file(&marker);
+        assert!(Tutorial::start(&TutorialMode::default()).is_none(), "embedders opt in");
+    }
+}
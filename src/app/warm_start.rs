@@ -0,0 +1,101 @@
+// === WARM START ===
+// Fast-forwards the simulation before the first frame, so an installation
+// opens on a world already full of hives, grown crystals and old grudges
+// instead of three lonely llamas on a black screen. The ticks are the normal
+// 60 Hz ones, just run back to back with nothing drawn or heard, a slice of
+// them per frame so the window stays responsive and can show how far along
+// the warm-up is. Space skips whatever is left.
+
+use std::time::{Duration, Instant};
+use glam::{Vec2, Vec3};
+use crate::locale::Locale;
+use crate::reality::{text, Vertex};
+
+/// Wall-clock time one call to `advance` may spend ticking
+const FRAME_BUDGET: Duration = Duration::from_millis(20);
+/// Ticks per simulated second
+const TICK_RATE: f32 = 60.0;
+const BAR_MIN: Vec2 = Vec2::new(300.0, 380.0);
+const BAR_MAX: Vec2 = Vec2::new(900.0, 396.0);
+const SCREEN: Vec2 = Vec2::new(1200.0, 800.0);
+
+/// Progress through a warm-up of a fixed number of ticks
+#[derive(Debug, Clone)]
+pub struct WarmStart {
+    total: u32,
+    done: u32,
+}
+
+impl WarmStart {
+    /// A warm-up covering `duration` of simulated time; None when there is nothing to simulate
+    pub fn new(duration: Duration) -> Option<Self> {
+        let total = (duration.as_secs_f32() * TICK_RATE).round() as u32;
+        (total > 0).then_some(Self { total, done: 0 })
+    }
+
+    /// Run `tick` until the warm-up is done or this frame's budget is spent; true once done
+    pub fn advance(&mut self, mut tick: impl FnMut()) -> bool {
+        let started = Instant::now();
+        while self.done < self.total && started.elapsed() < FRAME_BUDGET {
+            tick();
+            self.done += 1;
+        }
+        self.is_done()
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.done >= self.total
+    }
+
+    /// Share of the warm-up simulated so far, 0-1
+    pub fn progress(&self) -> f32 {
+        self.done as f32 / self.total as f32
+    }
+
+    /// Simulated time covered so far
+    pub fn simulated(&self) -> Duration {
+        Duration::from_secs_f32(self.done as f32 / TICK_RATE)
+    }
+
+    /// Title, progress bar, simulated time and a line about the world so far
+    pub fn push_overlay(&self, vertices: &mut Vec<Vertex>, locale: &Locale, status: &str) {
+        let dim = Vec3::splat(0.45);
+        text::push_text_centered(vertices, locale.text("warm-start-title"), SCREEN.x * 0.5, BAR_MIN.y - 50.0, 3.0, Vec3::new(0.5, 0.45, 0.7), SCREEN);
+        text::push_rect(vertices, BAR_MIN, BAR_MAX, Vec3::splat(0.1), SCREEN);
+        let filled = Vec2::new(BAR_MIN.x + (BAR_MAX.x - BAR_MIN.x) * self.progress(), BAR_MAX.y);
+        text::push_rect(vertices, BAR_MIN, filled, Vec3::new(0.3, 0.25, 0.5), SCREEN);
+
+        let total = Duration::from_secs_f32(self.total as f32 / TICK_RATE);
+        let progress = locale.format("warm-start-progress", &[("done", &clock(self.simulated())), ("total", &clock(total))]);
+        text::push_text_centered(vertices, &progress, SCREEN.x * 0.5, BAR_MAX.y + 20.0, 2.0, dim, SCREEN);
+        text::push_text_centered(vertices, status, SCREEN.x * 0.5, BAR_MAX.y + 44.0, 2.0, dim, SCREEN);
+        text::push_text_centered(vertices, locale.text("warm-start-help"), SCREEN.x * 0.5, BAR_MAX.y + 90.0, 2.0, Vec3::splat(0.3), SCREEN);
+    }
+}
+
+/// Minutes and seconds, as M:SS
+fn clock(time: Duration) -> String {
+    let seconds = time.as_secs();
+    format!("{}:{:02}", seconds / 60, seconds % 60)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_warm_start_runs_every_tick_once() {
+        assert!(WarmStart::new(Duration::ZERO).is_none());
+
+        let mut warm_start = WarmStart::new(Duration::from_secs(2)).unwrap();
+        let mut ticks = 0;
+        while !warm_start.advance(|| ticks += 1) {}
+        assert_eq!(ticks, 120);
+        assert_eq!(warm_start.progress(), 1.0);
+        assert_eq!(clock(warm_start.simulated()), "0:02");
+
+        // Done means done; further frames tick nothing
+        assert!(warm_start.advance(|| ticks += 1));
+        assert_eq!(ticks, 120);
+    }
+}
@@ -0,0 +1,382 @@
+// Epilepsy warning start screen - must be answered before the organism starts
+
+use wgpu::*;
+use wgpu::util::DeviceExt;
+use winit::{
+    event::{ElementState, MouseButton, KeyEvent},
+    window::Window,
+    keyboard::{Key, NamedKey},
+};
+use glam::{Vec2, Vec3};
+use tracing::{error, info};
+
+use crate::error::{BloomError, Result};
+use crate::locale::Locale;
+use crate::reality::{Vertex, text};
+use super::software::{fill_vertices, SoftwareSurface};
+
+// === CRITICAL SAFETY SYSTEMS FOR EPILEPSY PROTECTION ===
+
+/// User's response to safety warning
+#[derive(Debug, Clone, PartialEq)]
+pub enum WarningResponse {
+    Continue,     // User accepts risk and wants full visual effects
+    SafetyMode,   // User wants reduced visual intensity
+    Exit,         // User chooses to exit
+}
+
+
+/// Choices with the bundle keys of their button labels
+const WARNING_CHOICES: [(WarningResponse, &str); 3] = [
+    (WarningResponse::Continue, "warning-continue"),
+    (WarningResponse::SafetyMode, "warning-safety-mode"),
+    (WarningResponse::Exit, "warning-exit"),
+];
+
+/// Background behind the warning, the same dim safe color as emergency stop
+const WARNING_BACKGROUND: f64 = 0.05;
+
+/// Minimal GPU setup with a flat-color pipeline
+struct GpuWarning {
+    device: Device,
+    queue: Queue,
+    config: SurfaceConfiguration,
+    surface: Surface<'static>,
+    render_pipeline: RenderPipeline,
+}
+
+/// Where the warning is drawn
+enum WarningBackend {
+    Gpu(Box<GpuWarning>),
+    Software(SoftwareSurface), // No usable GPU; drawn on the CPU at full resolution
+}
+
+/// In-window epilepsy warning shown before the chaos engine starts
+///
+/// Owns a minimal GPU setup with a flat-color shader so nothing on this
+/// screen can animate or flash, or a CPU surface when the GPU is unusable.
+/// Input: C/S/E, arrows/Tab + Enter, or mouse.
+pub struct WarningScreen {
+    backend: WarningBackend,
+    size: winit::dpi::PhysicalSize<u32>,
+    selected: usize, // Index into WARNING_CHOICES
+    cursor_position: Vec2,
+    locale: Locale,
+}
+
+impl WarningScreen {
+    pub async fn new(window: std::sync::Arc<Window>, locale: Locale) -> Result<Self> {
+        let size = window.inner_size();
+
+        let instance = Instance::new(InstanceDescriptor {
+            backends: Backends::all(),
+            ..Default::default()
+        });
+
+        let surface = instance.create_surface(window)?;
+
+        let adapter = instance
+            .request_adapter(&RequestAdapterOptions {
+                power_preference: PowerPreference::LowPower,
+                compatible_surface: Some(&surface),
+                force_fallback_adapter: false,
+            })
+            .await
+            .ok_or(BloomError::NoAdapter)?;
+
+        let (device, queue) = adapter
+            .request_device(&DeviceDescriptor::default(), None)
+            .await?;
+
+        let surface_caps = surface.get_capabilities(&adapter);
+        let config = SurfaceConfiguration {
+            usage: TextureUsages::RENDER_ATTACHMENT,
+            format: surface_caps.formats.first().copied().ok_or(BloomError::NoSurfaceFormat)?,
+            width: size.width.max(1),
+            height: size.height.max(1),
+            present_mode: PresentMode::Fifo,
+            alpha_mode: surface_caps.alpha_modes.first().copied().unwrap_or(CompositeAlphaMode::Auto),
+            view_formats: vec![],
+            desired_maximum_frame_latency: 2,
+        };
+        surface.configure(&device, &config);
+
+        let shader = device.create_shader_module(ShaderModuleDescriptor {
+            label: Some("Warning Shader"),
+            source: ShaderSource::Wgsl(include_str!("warning.wgsl").into()),
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&PipelineLayoutDescriptor {
+            label: Some("Warning Pipeline Layout"),
+            bind_group_layouts: &[],
+            push_constant_ranges: &[],
+        });
+
+        let render_pipeline = device.create_render_pipeline(&RenderPipelineDescriptor {
+            label: Some("Warning Pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: VertexState {
+                module: &shader,
+                entry_point: "vs_main",
+                buffers: &[Vertex::desc()],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(FragmentState {
+                module: &shader,
+                entry_point: "fs_main",
+                targets: &[Some(ColorTargetState {
+                    format: config.format,
+                    blend: None,
+                    write_mask: ColorWrites::ALL,
+                })],
+                compilation_options: Default::default(),
+            }),
+            primitive: PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: MultisampleState::default(),
+            multiview: None,
+        });
+
+        Ok(Self {
+            backend: WarningBackend::Gpu(Box::new(GpuWarning { device, queue, config, surface, render_pipeline })),
+            size,
+            selected: 1, // Safety Mode is the safe default
+            cursor_position: Vec2::ZERO,
+            locale,
+        })
+    }
+
+    /// The same warning drawn on the CPU, for machines without a usable GPU
+    pub fn software(window: std::sync::Arc<Window>, locale: Locale) -> Result<Self> {
+        let size = window.inner_size();
+        Ok(Self {
+            backend: WarningBackend::Software(SoftwareSurface::new(window, 1)?),
+            size,
+            selected: 1,
+            cursor_position: Vec2::ZERO,
+            locale,
+        })
+    }
+
+    /// Drawn on the CPU because the GPU could not be used
+    pub fn is_software(&self) -> bool {
+        matches!(self.backend, WarningBackend::Software(_))
+    }
+
+    pub fn resize(&mut self, new_size: winit::dpi::PhysicalSize<u32>) {
+        if new_size.width > 0 && new_size.height > 0 {
+            self.size = new_size;
+            match &mut self.backend {
+                WarningBackend::Gpu(gpu) => {
+                    gpu.config.width = new_size.width;
+                    gpu.config.height = new_size.height;
+                    gpu.surface.configure(&gpu.device, &gpu.config);
+                }
+                WarningBackend::Software(surface) => {
+                    if let Err(e) = surface.resize(new_size) {
+                        error!(target: "render", "Warning screen resize error: {}", e);
+                    }
+                }
+            }
+        }
+    }
+
+    fn screen_size(&self) -> Vec2 {
+        Vec2::new(self.size.width.max(1) as f32, self.size.height.max(1) as f32)
+    }
+
+    /// Text scale that fits the longest warning line into 90% of the width
+    fn pixel_size(&self) -> f32 {
+        let longest = self.locale.text("warning-body").lines().map(|line| line.chars().count()).max().unwrap_or(1);
+        let fit = self.screen_size().x * 0.9 / text::text_width(&"X".repeat(longest), 1.0);
+        fit.floor().clamp(1.0, 3.0)
+    }
+
+    /// Button rectangles (min, max) in screen pixels, matching WARNING_CHOICES
+    fn button_rects(&self) -> [(Vec2, Vec2); 3] {
+        let screen = self.screen_size();
+        let pixel_size = self.pixel_size();
+        let button_width = screen.x * 0.26;
+        let button_height = text::text_height(pixel_size) + pixel_size * 8.0;
+        let gap = screen.x * 0.03;
+        let left = (screen.x - (button_width * 3.0 + gap * 2.0)) / 2.0;
+        let top = screen.y * 0.78;
+
+        std::array::from_fn(|i| {
+            let min = Vec2::new(left + i as f32 * (button_width + gap), top);
+            (min, min + Vec2::new(button_width, button_height))
+        })
+    }
+
+    pub fn handle_keyboard(&mut self, key_event: &KeyEvent) -> Option<WarningResponse> {
+        if key_event.state != ElementState::Pressed {
+            return None;
+        }
+
+        match &key_event.logical_key {
+            Key::Named(NamedKey::Escape) => Some(WarningResponse::Exit),
+            Key::Named(NamedKey::Enter) | Key::Named(NamedKey::Space) => {
+                Some(WARNING_CHOICES[self.selected].0.clone())
+            }
+            Key::Named(NamedKey::ArrowLeft) | Key::Named(NamedKey::ArrowUp) => {
+                self.selected = (self.selected + WARNING_CHOICES.len() - 1) % WARNING_CHOICES.len();
+                None
+            }
+            Key::Named(NamedKey::ArrowRight) | Key::Named(NamedKey::ArrowDown) | Key::Named(NamedKey::Tab) => {
+                self.selected = (self.selected + 1) % WARNING_CHOICES.len();
+                None
+            }
+            Key::Character(c) => match c.chars().next().unwrap_or('\0').to_ascii_lowercase() {
+                'c' => Some(WarningResponse::Continue),
+                's' => Some(WarningResponse::SafetyMode),
+                'e' => Some(WarningResponse::Exit),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    pub fn handle_cursor_moved(&mut self, position: winit::dpi::PhysicalPosition<f64>) {
+        self.cursor_position = Vec2::new(position.x as f32, position.y as f32);
+        if let Some(index) = self.button_under_cursor() {
+            self.selected = index;
+        }
+    }
+
+    pub fn handle_click(&mut self, button: MouseButton, state: ElementState) -> Option<WarningResponse> {
+        if button != MouseButton::Left || state != ElementState::Pressed {
+            return None;
+        }
+        self.button_under_cursor().map(|index| WARNING_CHOICES[index].0.clone())
+    }
+
+    fn button_under_cursor(&self) -> Option<usize> {
+        let cursor = self.cursor_position;
+        self.button_rects().iter().position(|(min, max)| {
+            cursor.x >= min.x && cursor.x <= max.x && cursor.y >= min.y && cursor.y <= max.y
+        })
+    }
+
+    fn build_vertices(&self) -> Vec<Vertex> {
+        let screen = self.screen_size();
+        let pixel_size = self.pixel_size();
+        let line_height = text::text_height(pixel_size) + pixel_size * 4.0;
+        let center_x = screen.x / 2.0;
+
+        // Calm, static palette - amber title, soft white body
+        let title_color = Vec3::new(0.95, 0.7, 0.2);
+        let body_color = Vec3::new(0.85, 0.85, 0.85);
+        let button_color = Vec3::new(0.18, 0.18, 0.22);
+        let selected_color = Vec3::new(0.25, 0.35, 0.5);
+
+        let mut vertices = Vec::new();
+
+        let title_size = pixel_size * 2.0;
+        text::push_text_centered(&mut vertices, self.locale.text("warning-title"), center_x, screen.y * 0.06, title_size, title_color, screen);
+
+        let mut y = screen.y * 0.06 + text::text_height(title_size) + line_height * 1.5;
+        for line in self.locale.text("warning-body").lines() {
+            text::push_text_centered(&mut vertices, line, center_x, y, pixel_size, body_color, screen);
+            y += line_height;
+        }
+
+        for (index, (min, max)) in self.button_rects().iter().enumerate() {
+            let fill = if index == self.selected { selected_color } else { button_color };
+            text::push_rect(&mut vertices, *min, *max, fill, screen);
+
+            let label = self.locale.text(WARNING_CHOICES[index].1);
+            let label_top = min.y + (max.y - min.y - text::text_height(pixel_size)) / 2.0;
+            text::push_text_centered(&mut vertices, label, (min.x + max.x) / 2.0, label_top, pixel_size, body_color, screen);
+        }
+
+        let hint_top = self.button_rects()[0].1.y + line_height;
+        text::push_text_centered(&mut vertices, self.locale.text("warning-hint"), center_x, hint_top, pixel_size, body_color * 0.7, screen);
+
+        vertices
+    }
+
+    pub fn render(&mut self) -> Result<(), SurfaceError> {
+        let vertices = self.build_vertices();
+        let GpuWarning { device, queue, surface, render_pipeline, .. } = match &mut self.backend {
+            WarningBackend::Gpu(gpu) => &**gpu,
+            WarningBackend::Software(surface) => {
+                let background = (WARNING_BACKGROUND * 255.0) as u8;
+                surface.pixmap_mut().fill(tiny_skia::Color::from_rgba8(background, background, background, 255));
+                fill_vertices(surface.pixmap_mut(), &vertices);
+                if let Err(e) = surface.present() {
+                    error!(target: "render", "Warning screen software render error: {}", e);
+                }
+                return Ok(());
+            }
+        };
+
+        let output = surface.get_current_texture()?;
+        let view = output.texture.create_view(&TextureViewDescriptor::default());
+
+        let vertex_buffer = device.create_buffer_init(&util::BufferInitDescriptor {
+            label: Some("Warning Vertex Buffer"),
+            contents: bytemuck::cast_slice(&vertices),
+            usage: BufferUsages::VERTEX,
+        });
+
+        let mut encoder = device.create_command_encoder(&CommandEncoderDescriptor {
+            label: Some("Warning Render Encoder"),
+        });
+
+        {
+            let mut render_pass = encoder.begin_render_pass(&RenderPassDescriptor {
+                label: Some("Warning Render Pass"),
+                color_attachments: &[Some(RenderPassColorAttachment {
+                    view: &view,
+                    resolve_target: None,
+                    ops: Operations {
+                        load: LoadOp::Clear(Color {
+                            r: WARNING_BACKGROUND,
+                            g: WARNING_BACKGROUND,
+                            b: WARNING_BACKGROUND,
+                            a: 1.0,
+                        }),
+                        store: StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+
+            render_pass.set_pipeline(render_pipeline);
+            render_pass.set_vertex_buffer(0, vertex_buffer.slice(..));
+            render_pass.draw(0..vertices.len() as u32, 0..1);
+        }
+
+        queue.submit(std::iter::once(encoder.finish()));
+        output.present();
+
+        Ok(())
+    }
+}
+
+/// Log the user's warning choice with the matching start-up banner
+pub fn announce_warning_response(response: &WarningResponse, locale: &Locale) {
+    match response {
+        WarningResponse::Exit => {
+            info!(target: "app", "{}", locale.text("warning-chose-exit"));
+        }
+        WarningResponse::Continue => {
+            info!(target: "app", "{}", locale.text("warning-chose-continue"));
+            info!(target: "app", "🦙 AWAKENING DIGITAL CONSCIOUSNESS...");
+            info!(target: "app", "🌈 REALITY DISTORTION ENGINE INITIALIZING...");
+            info!(target: "app", "🚀 CHAOS ENGINE ONLINE - REALITY BENDING COMMENCING");
+            info!(target: "app", "{}", locale.text("warning-remember-stop"));
+            info!(target: "app", "{}", locale.text("warning-click-to-spawn"));
+        }
+        WarningResponse::SafetyMode => {
+            info!(target: "app", "{}", locale.text("warning-chose-safety-mode"));
+            info!(target: "app", "🦙 AWAKENING DIGITAL CONSCIOUSNESS... (SAFE MODE)");
+            info!(target: "app", "🌈 REALITY DISTORTION ENGINE INITIALIZING... (REDUCED INTENSITY)");
+            info!(target: "app", "🚀 CHAOS ENGINE ONLINE - SAFE REALITY BENDING COMMENCING");
+            info!(target: "app", "{}", locale.text("warning-remember-stop"));
+            info!(target: "app", "{}", locale.text("warning-click-to-spawn"));
+        }
+    }
+}
@@ -1,3 +0,0 @@
-pub mod emergent_language;
-
-pub use emergent_language::*;
\ No newline at end of file
@@ -1,10 +1,10 @@
-pub mod ecs;
 pub mod memory;
 pub mod time;
-pub mod events;
 pub mod safety;
 pub mod warning;
 
+pub use aetherium_sim::core::{ecs, events, spatial};
+
 use anyhow::Result;
 use winit::window::Window;
 use wgpu::*;
@@ -4,6 +4,7 @@
 
 use std::collections::VecDeque;
 use glam::Vec3;
+use tracing::{info, warn};
 
 /// Core safety configuration constants based on international standards
 pub mod safety_constants {
@@ -71,9 +72,10 @@ impl VisualUpdate {
         let hsv = rgb_to_hsv(&self.color);
         let prev_hsv = rgb_to_hsv(&self.previous_color);
 
-        // Check if either color is in dangerous red range
-        (hsv.x >= safety_constants::RED_HUE_MIN || hsv.x <= safety_constants::RED_HUE_MAX) &&
-        (prev_hsv.x >= safety_constants::RED_HUE_MIN || prev_hsv.x <= safety_constants::RED_HUE_MAX) &&
+        // A transition into or out of saturated red is the hazard (WCAG 2.3.1); requiring
+        // red on both sides would miss red-to-cyan, the most provocative flash of all
+        let is_red = |hue: f32| hue >= safety_constants::RED_HUE_MIN || hue <= safety_constants::RED_HUE_MAX;
+        (is_red(hsv.x) || is_red(prev_hsv.x)) &&
         self.change_magnitude > safety_constants::MAX_LUMINANCE_CHANGE * 0.5
     }
 }
@@ -90,7 +92,7 @@ impl FlashTracker {
     pub fn new() -> Self {
         Self {
             major_changes: VecDeque::new(),
-            last_major_change: 0.0,
+            last_major_change: f64::NEG_INFINITY, // No flash yet - first one is always allowed
             flash_count_window: 1.0, // 1 second window
         }
     }
@@ -270,12 +272,12 @@ impl ChaosDampener {
     pub fn activate_emergency_suppression(&mut self) {
         self.suppression_active = true;
         self.currently_flashing.clear();
-        println!("⚠️ EMERGENCY SAFETY SUPPRESSION ACTIVATED");
+        warn!(target: "safety", "⚠️ EMERGENCY SAFETY SUPPRESSION ACTIVATED");
     }
 
     pub fn deactivate_emergency_suppression(&mut self) {
         self.suppression_active = false;
-        println!("✅ Emergency safety suppression deactivated");
+        info!(target: "safety", "✅ Emergency safety suppression deactivated");
     }
 
     pub fn get_flash_status(&self) -> (usize, usize) {
@@ -416,13 +418,13 @@ impl SafetyEngine {
     pub fn activate_emergency_stop(&mut self) {
         self.emergency_stop_active = true;
         self.chaos_dampener.activate_emergency_suppression();
-        println!("🚨 EMERGENCY SAFETY STOP ACTIVATED - ALL VISUAL EFFECTS SUPPRESSED");
+        warn!(target: "safety", "🚨 EMERGENCY SAFETY STOP ACTIVATED - ALL VISUAL EFFECTS SUPPRESSED");
     }
 
     pub fn deactivate_emergency_stop(&mut self) {
         self.emergency_stop_active = false;
         self.chaos_dampener.deactivate_emergency_suppression();
-        println!("✅ Emergency safety stop deactivated");
+        info!(target: "safety", "✅ Emergency safety stop deactivated");
     }
 
     /// Check if system is operating safely
@@ -443,7 +445,7 @@ impl SafetyEngine {
 
     fn log_safety_warning(&mut self, current_time: f64, message: &str) {
         if current_time - self.last_warning_time > self.warning_interval {
-            println!("⚠️ SAFETY WARNING: {}", message);
+            warn!(target: "safety", "⚠️ SAFETY WARNING: {}", message);
             self.last_warning_time = current_time;
         }
     }
@@ -532,7 +534,7 @@ mod tests {
         assert!(!tracker.can_allow_flash(current_time + 0.1, 0.5));
 
         // Should allow flash after safe interval
-        assert!(tracker.can_allow_flash(current_time + safety_constants::MIN_FLASH_INTERVAL + 0.1, 0.5));
+        assert!(tracker.can_allow_flash(current_time + safety_constants::MIN_FLASH_INTERVAL as f64 + 0.1, 0.5));
     }
 
     #[test]
@@ -555,4 +557,17 @@ mod tests {
         let update = VisualUpdate::new(0, Vec3::ZERO, red, blue, 10.0, 1.0, 0.0);
         assert!(update.is_red_flash());
     }
+
+    #[test]
+    fn test_red_flash_needs_red_on_one_side_and_a_large_change() {
+        let (red, cyan) = (Vec3::new(1.0, 0.0, 0.0), Vec3::new(0.0, 1.0, 1.0));
+        let flash = |color, previous| VisualUpdate::new(0, Vec3::ZERO, color, previous, 10.0, 1.0, 0.0).is_red_flash();
+
+        // Into or out of red, either way round
+        assert!(flash(red, cyan));
+        assert!(flash(cyan, red));
+        // A large change that never touches red, and a red that barely changes, are not red flashes
+        assert!(!flash(Vec3::new(0.0, 1.0, 0.0), Vec3::new(0.0, 0.0, 1.0)));
+        assert!(!flash(Vec3::new(0.8, 0.0, 0.0), red));
+    }
 }
\ No newline at end of file
@@ -1,8 +0,0 @@
-// Entities module containing llamas, species, and consciousness systems
-
-pub mod llama;
-pub mod llama_behavior;
-pub mod species;
-
-pub use llama::Llama;
-pub use species::{SpeciesType, SpeciesConfig, ConsciousnessLevel};
\ No newline at end of file
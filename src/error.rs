@@ -0,0 +1,118 @@
+// === CRATE ERRORS ===
+// Failures surfaced to whoever starts the organism. Start-up errors are fatal
+// to the caller; audio errors only ever degrade to visual-only mode.
+
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum BloomError {
+    #[error("failed to create window: {0}")]
+    Window(#[from] winit::error::OsError),
+
+    #[error("event loop failed: {0}")]
+    EventLoop(#[from] winit::error::EventLoopError),
+
+    #[error("failed to create rendering surface: {0}")]
+    Surface(#[from] wgpu::CreateSurfaceError),
+
+    #[error("no compatible GPU adapter found")]
+    NoAdapter,
+
+    #[error("GPU surface reports no supported formats")]
+    NoSurfaceFormat,
+
+    #[error("output window cannot present the primary window's {0:?} format")]
+    OutputFormat(wgpu::TextureFormat),
+
+    #[error("failed to open GPU device: {0}")]
+    Device(#[from] wgpu::RequestDeviceError),
+
+    #[error("software renderer failed: {0}")]
+    SoftwareRenderer(String), // softbuffer's errors carry window handles, which are not Send
+
+    #[error(transparent)]
+    Audio(#[from] aetherium_audio::error::AudioError),
+
+    #[error("photos cannot be read back from the {0:?} surface format")]
+    PhotoFormat(wgpu::TextureFormat),
+
+    #[error("failed to read the photo back from the GPU: {0}")]
+    PhotoReadback(#[from] wgpu::BufferAsyncError),
+
+    #[error("failed to save photo {}: {source}", path.display())]
+    Photo { path: std::path::PathBuf, source: png::EncodingError },
+
+    #[error("{name} = {value} is outside its safe range {min}..={max}")]
+    InvalidParameter { name: &'static str, value: f32, min: f32, max: f32 },
+
+    #[error("failed to write saga {}: {source}", path.display())]
+    Saga { path: std::path::PathBuf, source: std::io::Error },
+
+    #[error("failed to write observation journal {}: {source}", path.display())]
+    Journal { path: std::path::PathBuf, source: std::io::Error },
+
+    #[error("failed to write consciousness graph {}: {source}", path.display())]
+    GraphExport { path: std::path::PathBuf, source: std::io::Error },
+
+    #[error("failed to read language bundle {}: {source}", path.display())]
+    LocaleFile { path: std::path::PathBuf, source: std::io::Error },
+
+    #[error("language bundle '{language}' line {line}: {reason}")]
+    LocaleBundle { language: String, line: usize, reason: &'static str },
+
+    #[error("failed to access key bindings {}: {source}", path.display())]
+    KeyBindingsFile { path: std::path::PathBuf, source: std::io::Error },
+
+    #[error("key bindings line {line}: {reason}")]
+    KeyBindings { line: usize, reason: String },
+
+    #[error("mod {}: {reason}", path.display())]
+    Mod { path: std::path::PathBuf, reason: String },
+
+    #[error("garden layout {}: {reason}", path.display())]
+    GardenLayout { path: std::path::PathBuf, reason: String },
+
+    #[error("modulation matrix {}: {reason}", path.display())]
+    Modulation { path: std::path::PathBuf, reason: String },
+
+    #[error("screensaver settings {}: {reason}", path.display())]
+    Screensaver { path: std::path::PathBuf, reason: String },
+
+    #[error("color grading LUT {}: {reason}", path.display())]
+    ColorGrade { path: std::path::PathBuf, reason: String },
+
+    #[error("world snapshot {}: {reason}", path.display())]
+    Snapshot { path: std::path::PathBuf, reason: String },
+
+    #[error("failed to launch the supervised organism: {0}")]
+    Supervisor(std::io::Error),
+
+    #[error("organism crashed {crashes} times in a row within a minute of starting; giving up")]
+    CrashLoop { crashes: u32 },
+
+    #[error("organism stopped with {status} without crashing; not relaunching it")]
+    OrganismFailed { status: String },
+}
+
+impl BloomError {
+    /// The GPU cannot be used at all; the standalone shell falls back to the software renderer
+    pub fn is_gpu_unavailable(&self) -> bool {
+        matches!(self, BloomError::NoAdapter | BloomError::NoSurfaceFormat | BloomError::Device(_))
+    }
+}
+
+impl From<aetherium_sim::params::ParamOutOfRange> for BloomError {
+    fn from(error: aetherium_sim::params::ParamOutOfRange) -> Self {
+        let aetherium_sim::params::ParamOutOfRange { name, value, min, max } = error;
+        BloomError::InvalidParameter { name, value, min, max }
+    }
+}
+
+#[cfg(feature = "app")]
+impl From<softbuffer::SoftBufferError> for BloomError {
+    fn from(error: softbuffer::SoftBufferError) -> Self {
+        BloomError::SoftwareRenderer(error.to_string())
+    }
+}
+
+pub type Result<T, E = BloomError> = std::result::Result<T, E>;
@@ -0,0 +1,237 @@
+// === VIEWER ATTENTION ===
+// Lets the ecosystem notice it is being watched. A webcam attention estimator
+// reports whether a face is in view and where on the screen the viewer is;
+// llamas near that point gain consciousness and the meta-observer's eye moves
+// to mirror it. The host owns the camera: it either passes raw RGB frames to
+// the built-in estimator or forwards readings from a real eye tracker.
+//
+// The built-in estimator does not track gaze. It finds the largest roughly
+// face-shaped patch of skin chroma and reports where that patch sits, i.e.
+// the viewer's position in front of the screen, not where they look; an eye
+// tracker can pass its true gaze point in the same field. Readings go stale
+// after a moment, so a covered or unplugged camera simply stops the ecosystem
+// from feeling watched.
+
+use glam::Vec2;
+
+/// Screen radius around the viewer's position where llamas feel watched
+pub const ATTENTION_RADIUS: f32 = 180.0;
+/// Consciousness per second a llama right at the viewer's position gains at full confidence
+pub const ATTENTION_GAIN: f32 = 0.6;
+/// Seconds a reading counts for before the viewer is assumed gone
+pub const ATTENTION_TIMEOUT_SECONDS: f32 = 1.0;
+/// Share of the frame the face patch must cover before a face counts as present
+const FACE_SHARE: f32 = 0.02;
+/// Height over width a skin patch may have and still be a face rather than an arm or a wall
+const FACE_ASPECT: std::ops::RangeInclusive<f32> = 0.8..=2.0;
+/// Share of its bounding box a face patch must fill
+const FACE_FILL: f32 = 0.5;
+/// Share of the way the estimated position moves to each new frame, steadying a jittery head
+const POSITION_SMOOTHING: f32 = 0.3;
+/// Frames are sampled on this pixel grid; faces are far larger than it
+const SAMPLE_STEP: usize = 4;
+
+/// What the attention estimator saw in one frame
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AttentionReading {
+    pub face_present: bool, // Someone is in front of the screen
+    pub position: Vec2,     // Where they are (or look, from an eye tracker), 0-1 across and down the screen
+    pub confidence: f32,    // 0-1, how sure the estimator is
+}
+
+impl AttentionReading {
+    /// Nobody in view
+    pub fn absent() -> Self {
+        Self { face_present: false, position: Vec2::splat(0.5), confidence: 0.0 }
+    }
+}
+
+/// Webcam frames to attention readings, by skin-tone face finding; reports where the viewer is, not where they look
+#[derive(Debug, Clone, Default)]
+pub struct AttentionEstimator {
+    position: Option<Vec2>, // Smoothed position of the face in view, None while nobody is
+}
+
+impl AttentionEstimator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Estimate from one `width` x `height` frame of packed 8-bit RGB, as a webcam facing the viewer captures it
+    pub fn estimate(&mut self, width: usize, height: usize, rgb: &[u8]) -> AttentionReading {
+        if width == 0 || height == 0 || rgb.len() < width * height * 3 {
+            return self.lose_face();
+        }
+        let (columns, rows) = (width.div_ceil(SAMPLE_STEP), height.div_ceil(SAMPLE_STEP));
+        let skin: Vec<bool> = (0..rows * columns)
+            .map(|cell| {
+                let i = ((cell / columns) * SAMPLE_STEP * width + (cell % columns) * SAMPLE_STEP) * 3;
+                is_skin(rgb[i], rgb[i + 1], rgb[i + 2])
+            })
+            .collect();
+        let Some(face) = largest_patch(&skin, columns, rows) else { return self.lose_face() };
+
+        let share = face.cells as f32 / skin.len() as f32;
+        let (span_x, span_y) = ((face.max.0 - face.min.0 + 1) as f32, (face.max.1 - face.min.1 + 1) as f32);
+        let face_shaped = FACE_ASPECT.contains(&(span_y / span_x)) && face.cells as f32 / (span_x * span_y) >= FACE_FILL;
+        if share < FACE_SHARE || !face_shaped {
+            return self.lose_face();
+        }
+        // The camera faces the viewer, so their left is the frame's right
+        let center = face.sum / face.cells as f32 * SAMPLE_STEP as f32 / Vec2::new(width as f32, height as f32);
+        let seen_at = Vec2::new(1.0 - center.x, center.y);
+        let position = match self.position {
+            Some(previous) => previous.lerp(seen_at, POSITION_SMOOTHING),
+            None => seen_at,
+        };
+        self.position = Some(position);
+        AttentionReading { face_present: true, position, confidence: (share / (FACE_SHARE * 5.0)).min(1.0) }
+    }
+
+    fn lose_face(&mut self) -> AttentionReading {
+        self.position = None;
+        AttentionReading::absent()
+    }
+}
+
+/// One connected patch of skin cells on the sample grid
+struct Patch {
+    cells: usize,
+    sum: Vec2,           // Sum of cell coordinates, for the centroid
+    min: (usize, usize), // Bounding box in cells
+    max: (usize, usize),
+}
+
+/// The largest 4-connected patch of skin on a `columns` x `rows` grid
+fn largest_patch(skin: &[bool], columns: usize, rows: usize) -> Option<Patch> {
+    let mut seen = vec![false; skin.len()];
+    let mut largest: Option<Patch> = None;
+    for start in 0..skin.len() {
+        if !skin[start] || seen[start] {
+            continue;
+        }
+        seen[start] = true;
+        let mut patch = Patch { cells: 0, sum: Vec2::ZERO, min: (usize::MAX, usize::MAX), max: (0, 0) };
+        let mut open = vec![start];
+        while let Some(cell) = open.pop() {
+            let (x, y) = (cell % columns, cell / columns);
+            patch.cells += 1;
+            patch.sum += Vec2::new(x as f32, y as f32);
+            patch.min = (patch.min.0.min(x), patch.min.1.min(y));
+            patch.max = (patch.max.0.max(x), patch.max.1.max(y));
+            let neighbors = [
+                (x > 0).then(|| cell - 1),
+                (x + 1 < columns).then(|| cell + 1),
+                (y > 0).then(|| cell - columns),
+                (y + 1 < rows).then(|| cell + columns),
+            ];
+            for next in neighbors.into_iter().flatten() {
+                if skin[next] && !seen[next] {
+                    seen[next] = true;
+                    open.push(next);
+                }
+            }
+        }
+        if largest.as_ref().is_none_or(|best| patch.cells > best.cells) {
+            largest = Some(patch);
+        }
+    }
+    largest
+}
+
+/// Skin by chroma alone (the YCbCr box), so dark and light skin tones both count; only near-black is ruled out
+fn is_skin(r: u8, g: u8, b: u8) -> bool {
+    let (r, g, b) = (r as f32, g as f32, b as f32);
+    let y = 0.299 * r + 0.587 * g + 0.114 * b;
+    let cb = 128.0 - 0.168_736 * r - 0.331_264 * g + 0.5 * b;
+    let cr = 128.0 + 0.5 * r - 0.418_688 * g - 0.081_312 * b;
+    y > 30.0 && (77.0..=127.0).contains(&cb) && (133.0..=173.0).contains(&cr)
+}
+
+/// The latest reading and when it arrived
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct AttentionTracker {
+    reading: AttentionReading,
+    received_at: f32, // Engine time in seconds
+}
+
+impl Default for AttentionTracker {
+    fn default() -> Self {
+        Self { reading: AttentionReading::absent(), received_at: f32::NEG_INFINITY }
+    }
+}
+
+impl AttentionTracker {
+    pub fn observe(&mut self, reading: AttentionReading, now: f32) {
+        self.reading = AttentionReading { position: reading.position.clamp(Vec2::ZERO, Vec2::ONE), confidence: reading.confidence.clamp(0.0, 1.0), ..reading };
+        self.received_at = now;
+    }
+
+    /// The viewer's position in `world_size` coordinates and the confidence, while a face is fresh in view
+    pub fn viewer_position(&self, now: f32, world_size: Vec2) -> Option<(Vec2, f32)> {
+        let fresh = now - self.received_at <= ATTENTION_TIMEOUT_SECONDS;
+        (fresh && self.reading.face_present && self.reading.confidence > 0.0)
+            .then(|| (self.reading.position * world_size, self.reading.confidence))
+    }
+}
+
+/// Consciousness per second a llama `distance` from the viewer's position gains
+pub fn watched_gain(distance: f32, confidence: f32) -> f32 {
+    (1.0 - distance / ATTENTION_RADIUS).max(0.0) * ATTENTION_GAIN * confidence
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WIDTH: usize = 64;
+    const HEIGHT: usize = 48;
+
+    /// A dark gray frame with a `color` rectangle over `xs` x `ys`
+    fn frame_with(xs: std::ops::Range<usize>, ys: std::ops::Range<usize>, color: [u8; 3]) -> Vec<u8> {
+        let mut frame = vec![30u8; WIDTH * HEIGHT * 3];
+        for y in ys {
+            for x in xs.clone() {
+                frame[(y * WIDTH + x) * 3..][..3].copy_from_slice(&color);
+            }
+        }
+        frame
+    }
+
+    #[test]
+    fn test_face_in_frame_becomes_a_mirrored_fresh_position() {
+        // A face in the frame's upper left: the viewer sits to the screen's upper right
+        let (width, height) = (WIDTH, HEIGHT);
+        let frame = frame_with(4..20, 4..20, [210, 150, 120]);
+        let mut estimator = AttentionEstimator::new();
+        let reading = estimator.estimate(width, height, &frame);
+        assert!(reading.face_present && reading.confidence > 0.5);
+        assert!(reading.position.x > 0.7 && reading.position.y < 0.3);
+        assert!(!estimator.estimate(width, height, &vec![30u8; width * height * 3]).face_present);
+        assert!(!estimator.estimate(width, height, &[]).face_present, "a short frame is nobody");
+
+        let mut tracker = AttentionTracker::default();
+        assert_eq!(tracker.viewer_position(0.0, Vec2::new(1200.0, 800.0)), None);
+        tracker.observe(reading, 10.0);
+        let (point, _) = tracker.viewer_position(10.5, Vec2::new(1200.0, 800.0)).unwrap();
+        assert!(point.x > 840.0 && point.y < 240.0);
+        assert_eq!(tracker.viewer_position(10.0 + ATTENTION_TIMEOUT_SECONDS + 0.1, Vec2::ONE), None, "an old reading goes stale");
+
+        assert!(watched_gain(0.0, 1.0) > watched_gain(ATTENTION_RADIUS * 0.5, 1.0));
+        assert_eq!(watched_gain(ATTENTION_RADIUS * 2.0, 1.0), 0.0);
+    }
+
+    #[test]
+    fn test_darker_skin_is_a_face_too() {
+        let reading = AttentionEstimator::new().estimate(WIDTH, HEIGHT, &frame_with(24..40, 16..36, [90, 60, 45]));
+        assert!(reading.face_present && reading.confidence > 0.5);
+        assert!((reading.position.x - 0.5).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_skin_that_is_not_face_shaped_is_ignored() {
+        // A bare forearm across the frame has plenty of skin but no face's proportions
+        let arm = frame_with(0..WIDTH, 20..28, [210, 150, 120]);
+        assert!(!AttentionEstimator::new().estimate(WIDTH, HEIGHT, &arm).face_present);
+    }
+}
@@ -1,5 +1,11 @@
+#[cfg(feature = "attention")]
+pub mod attention;
 pub mod consciousness;
 pub mod resonance;
+pub mod tears;
 
+#[cfg(feature = "attention")]
+pub use attention::{AttentionEstimator, AttentionReading};
 pub use consciousness::ConsciousnessResonance;
-pub use resonance::ResonanceEffect;
\ No newline at end of file
+pub use resonance::ResonanceEffect;
+pub use tears::{tear_at, TearDwell};
\ No newline at end of file
@@ -0,0 +1,67 @@
+// === TEAR HIT-TESTING ===
+// Which reality tear the cursor is on, and how long it has rested there.
+// Shift-clicking a tear closes it; resting the cursor on one for a moment
+// starts widening it, and it keeps widening until the cursor moves off.
+
+use glam::Vec2;
+use crate::core::ecs::{EntityId, World};
+use crate::simulation::RealityTear;
+
+/// Screen slack around a tear's drawn size, so small tears can still be hit
+pub const TEAR_HIT_MARGIN: f32 = 10.0;
+/// Seconds the cursor rests on a tear before it starts to widen
+pub const TEAR_DWELL_SECONDS: f32 = 0.75;
+
+/// The tear under `position`, the nearest one where several overlap
+pub fn tear_at(world: &World, position: Vec2) -> Option<EntityId> {
+    world.query::<RealityTear>().into_iter()
+        .map(|(id, tear)| (id, tear.position.distance(position), tear.size * tear.intensity + TEAR_HIT_MARGIN))
+        .filter(|&(_, distance, reach)| distance <= reach)
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(id, _, _)| id)
+}
+
+/// How long the cursor has rested on the same tear
+#[derive(Debug, Default)]
+pub struct TearDwell {
+    tear: Option<EntityId>,
+    seconds: f32,
+}
+
+impl TearDwell {
+    /// Advance by `dt` with `hovered` under the cursor; the tear to widen this tick, if any
+    pub fn update(&mut self, hovered: Option<EntityId>, dt: f32) -> Option<EntityId> {
+        if hovered != self.tear {
+            self.tear = hovered;
+            self.seconds = 0.0;
+        }
+        self.seconds += dt;
+        self.tear.filter(|_| self.seconds >= TEAR_DWELL_SECONDS)
+    }
+
+    /// Forget the current tear, e.g. after it was closed
+    pub fn reset(&mut self) {
+        self.tear = None;
+        self.seconds = 0.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::TearType;
+
+    #[test]
+    fn test_hit_nearest_tear_and_widen_after_dwelling() {
+        let mut world = World::new();
+        let near = world.spawn(RealityTear::new(Vec2::new(100.0, 100.0), TearType::Static));
+        world.spawn(RealityTear::new(Vec2::new(130.0, 100.0), TearType::Static));
+        assert_eq!(tear_at(&world, Vec2::new(105.0, 100.0)), Some(near));
+        assert_eq!(tear_at(&world, Vec2::new(600.0, 600.0)), None);
+
+        let mut dwell = TearDwell::default();
+        let widened = (0..60).filter_map(|_| dwell.update(Some(near), 1.0 / 60.0)).count();
+        assert!(widened > 0 && widened < 60, "widening starts only after resting a moment");
+        assert_eq!(dwell.update(None, 1.0), None);
+    }
+}
@@ -1,16 +1,40 @@
 // AetheriumBloom - Psychedelic Digital Organism Library
 // "Maximum chaos, elegant mathematics" - The Minter Agent
 
-pub mod audio;
-pub mod communication;
-pub mod consciousness;
+pub mod api;
+pub mod app;
 pub mod core;
-pub mod engine;
-pub mod entities;
+pub mod error;
 pub mod input;
+pub mod locale;
 pub mod mathematics;
-pub mod reality;
-pub mod rendering;
-// pub mod simple; // Moved to binary main.rs
-pub mod simulation;
-pub mod user;
\ No newline at end of file
+pub mod mods;
+pub mod params;
+pub mod user;
+
+// The simulation, renderer and audio engine live in their own workspace crates
+pub use aetherium_sim::{communication, consciousness, engine, entities, simulation};
+pub use aetherium_render::{reality, rendering};
+pub use aetherium_audio as audio;
+
+pub use app::{default_crash_directory, default_matrix_file, read_snapshot, Action, AmbientOrganism, AttractMode, BoundKey, EntropyFeed, EntropySource, FrameProfile, FullscreenMode, KeyBindings, KeyConflict, KeyContext, ProfileStage, RemoteControl, StateStream, TutorialMode, TutorialStep};
+#[cfg(feature = "app")]
+pub use app::{ScreensaverCommand, ScreensaverProfile};
+#[cfg(feature = "attention")]
+pub use input::{AttentionEstimator, AttentionReading};
+pub use api::{AetheriumBloom, AetheriumBloomBuilder, BloomConfig, BloomEvent, WindowOptions};
+pub use core::ecs::EntityId;
+pub use core::events::ChaosEvent;
+pub use error::BloomError;
+pub use locale::Locale;
+pub use mathematics::{ForceField, ForceKind};
+pub use mods::{ModManifest, ModVersion};
+pub use engine::{ConsciousnessGraph, DiplomacyEvent, HiveEvent, NegotiationOutcome, ObserverIntervention};
+pub use engine::population::{CullPolicy, DespawnReason};
+pub use engine::{ChaosTuning, HomeostasisConfig, MetabolismConfig, PopulationBand};
+pub use engine::{ModCurve, ModDestination, ModMatrix, ModRoute, ModSource};
+pub use entities::{FlockingConfig, FlockingWeights};
+pub use reality::{BudgetCategoryUsage, BufferUsageReport, VisualTheme};
+pub use rendering::{FieldView, HarmonyScheme, LodConfig, PaletteConfig, Viewport};
+pub use simulation::{Biography, ChronicleEntry, LifeEvent, SnapshotDiff, ZoneCause, ZoneEvent};
+pub use audio::{AudioWaveform, BusControl, DuckingSettings, EffectSends, Envelope, ExternalSource, LoudnessSettings, MixBus, MixerControls, SampleBankPaths, SpeciesSonicSignature};
@@ -0,0 +1,155 @@
+// === LOCALIZATION ===
+// Every piece of text a person reads - the epilepsy warning, HUD notices,
+// tutorial prompts and the control status - is looked up by key in a language
+// bundle. Bundles are plain `key = value` files; English is built in and
+// fills any key another bundle leaves out, so a partial translation still
+// shows complete safety information. Installations load their own bundle with
+// `Locale::load` and hand it to the builder or switch at run time.
+
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, OnceLock};
+use tracing::warn;
+use crate::error::{BloomError, Result};
+
+/// File extension of language bundles
+pub const BUNDLE_EXTENSION: &str = "lang";
+/// Environment variables the standalone binary picks its language and bundle directory from
+pub const LANGUAGE_VAR: &str = "AETHERIUM_BLOOM_LANG";
+pub const BUNDLE_DIR_VAR: &str = "AETHERIUM_BLOOM_LANG_DIR";
+/// Bundle directory used when `AETHERIUM_BLOOM_LANG_DIR` is unset
+const DEFAULT_BUNDLE_DIR: &str = "lang";
+
+const ENGLISH_SOURCE: &str = include_str!("en.lang");
+
+fn english_messages() -> &'static HashMap<String, String> {
+    static ENGLISH: OnceLock<HashMap<String, String>> = OnceLock::new();
+    ENGLISH.get_or_init(|| parse_bundle("en", ENGLISH_SOURCE).expect("built-in English bundle is well formed"))
+}
+
+fn parse_bundle(language: &str, source: &str) -> Result<HashMap<String, String>> {
+    let mut messages = HashMap::new();
+    for (index, line) in source.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let bad_line = |reason| BloomError::LocaleBundle { language: language.to_string(), line: index + 1, reason };
+        let (key, value) = line.split_once('=').ok_or_else(|| bad_line("expected `key = value`"))?;
+        let key = key.trim();
+        if key.is_empty() {
+            return Err(bad_line("empty key"));
+        }
+        if messages.insert(key.to_string(), value.trim().replace("\\n", "\n")).is_some() {
+            return Err(bad_line("key defined twice"));
+        }
+    }
+    Ok(messages)
+}
+
+/// One language's text, falling back to English key by key
+#[derive(Debug, Clone)]
+pub struct Locale {
+    language: String,
+    messages: Arc<HashMap<String, String>>,
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Self::english()
+    }
+}
+
+impl Locale {
+    pub fn english() -> Self {
+        Self { language: "en".to_string(), messages: Arc::new(english_messages().clone()) }
+    }
+
+    /// A bundle from source text, tagged with its language code
+    pub fn parse(language: &str, source: &str) -> Result<Self> {
+        let locale = Self { language: language.to_string(), messages: Arc::new(parse_bundle(language, source)?) };
+        let missing = locale.missing_keys();
+        if !missing.is_empty() {
+            warn!(target: "app", "🌐 Language '{}' falls back to English for {} texts: {}", language, missing.len(), missing.join(", "));
+        }
+        Ok(locale)
+    }
+
+    /// A bundle file; its name without the extension is the language code
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let source = std::fs::read_to_string(path).map_err(|source| BloomError::LocaleFile { path: path.to_path_buf(), source })?;
+        let language = path.file_stem().and_then(|stem| stem.to_str()).unwrap_or("?");
+        Self::parse(language, &source)
+    }
+
+    /// `<directory>/<language>.lang`, or the built-in English for "en" when no such file exists
+    pub fn load(directory: &Path, language: &str) -> Result<Self> {
+        let path: PathBuf = directory.join(language).with_extension(BUNDLE_EXTENSION);
+        if language == "en" && !path.exists() {
+            return Ok(Self::english());
+        }
+        Self::from_file(&path)
+    }
+
+    /// The language named by `AETHERIUM_BLOOM_LANG`, from `AETHERIUM_BLOOM_LANG_DIR` (default `lang`);
+    /// English when unset or when the bundle cannot be read
+    pub fn from_env() -> Self {
+        let Some(language) = std::env::var(LANGUAGE_VAR).ok().filter(|language| !language.is_empty()) else {
+            return Self::english();
+        };
+        let directory = std::env::var_os(BUNDLE_DIR_VAR).map_or_else(|| PathBuf::from(DEFAULT_BUNDLE_DIR), PathBuf::from);
+        Self::load(&directory, &language).unwrap_or_else(|e| {
+            warn!(target: "app", "🌐 {} - falling back to English", e);
+            Self::english()
+        })
+    }
+
+    pub fn language(&self) -> &str {
+        &self.language
+    }
+
+    /// The text for `key`, from this bundle, else English, else the key itself
+    pub fn text<'a>(&'a self, key: &'a str) -> &'a str {
+        self.messages.get(key).or_else(|| english_messages().get(key)).map_or(key, String::as_str)
+    }
+
+    /// The text for `key` with every `{name}` replaced by its argument
+    pub fn format(&self, key: &str, args: &[(&str, &dyn Display)]) -> String {
+        args.iter().fold(self.text(key).to_string(), |text, (name, value)| text.replace(&format!("{{{name}}}"), &value.to_string()))
+    }
+
+    /// English keys this bundle does not translate
+    pub fn missing_keys(&self) -> Vec<&'static str> {
+        let mut missing: Vec<&'static str> = english_messages().keys()
+            .filter(|key| !self.messages.contains_key(*key))
+            .map(String::as_str)
+            .collect();
+        missing.sort_unstable();
+        missing
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bundles_fall_back_to_english_and_fill_placeholders() {
+        let english = Locale::english();
+        assert!(english.missing_keys().is_empty());
+        assert_eq!(english.text("warning-title"), "PHOTOSENSITIVE EPILEPSY WARNING");
+        assert!(english.text("warning-body").lines().count() > 5);
+
+        let spanish = Locale::parse("es", "# Parcial\nwarning-title = ADVERTENCIA DE EPILEPSIA FOTOSENSIBLE\ntutorial-progress = TUTORIAL {step}/{steps} - TAB OMITE\n").unwrap();
+        assert_eq!(spanish.language(), "es");
+        assert_eq!(spanish.text("warning-title"), "ADVERTENCIA DE EPILEPSIA FOTOSENSIBLE");
+        assert_eq!(spanish.text("warning-exit"), "[E] EXIT");
+        assert_eq!(spanish.format("tutorial-progress", &[("step", &2), ("steps", &5)]), "TUTORIAL 2/5 - TAB OMITE");
+        assert!(spanish.missing_keys().contains(&"warning-body"));
+        assert_eq!(spanish.text("no-such-key"), "no-such-key");
+
+        assert!(matches!(Locale::parse("xx", "warning-title\n"), Err(BloomError::LocaleBundle { line: 1, .. })));
+        assert!(matches!(Locale::parse("xx", "a = 1\na = 2\n"), Err(BloomError::LocaleBundle { line: 2, .. })));
+    }
+}
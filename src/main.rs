@@ -1,8 +0,0 @@
-use anyhow::Result;
-use aetherium_bloom;
-
-mod simple;
-
-fn main() -> Result<()> {
-    simple::run()
-}
\ No newline at end of file
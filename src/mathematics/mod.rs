@@ -1,9 +1,4 @@
-pub mod beat_engine;
-pub mod physics;
 pub mod resonance;
-pub mod dimensions;
 
-pub use beat_engine::{BeatEngine, BeatState};
-pub use physics::RealityField;
+pub use aetherium_sim::mathematics::{beat_engine, dimensions, physics, BeatEngine, BeatState, ElevenDimensionalSpace, ForceField, ForceFields, ForceKind, RealityField, MAX_FIELD_ACCELERATION, MAX_FORCE_FIELDS};
 pub use resonance::ConsciousnessResonance;
-pub use dimensions::ElevenDimensionalSpace;
\ No newline at end of file
@@ -0,0 +1,489 @@
+// === COMMUNITY MODS ===
+// Species, visual and audio packs shared as plain directories and picked up
+// from the mods folder at startup, no recompiling needed. Every mod carries a
+// `mod.ron` manifest with its id, version, the mods it requires and the ones
+// it loads before or after; mods apply in that order, so a later one wins
+// where two touch the same species or theme. Mods are sandboxed: they bring
+// data and Rhai scripts, never native code, their paths cannot leave their own
+// directory (symlinks included), a theme shader that fails validation keeps the built-in one, and
+// scripts run under hard limits. A broken mod is skipped with a warning.
+
+mod script;
+
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
+use std::path::{Component, Path, PathBuf};
+use std::str::FromStr;
+use ron::extensions::Extensions;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use tracing::{info, warn};
+use crate::api::BloomConfig;
+use crate::audio::{SampleBankPaths, SpeciesSonicSignature};
+use crate::entities::{FlockingWeights, SpeciesType};
+use crate::error::{BloomError, Result};
+use crate::params::{self, FLOCK_ALIGNMENT, FLOCK_FORCE, FLOCK_RADIUS};
+use crate::reality::VisualTheme;
+
+pub(crate) use script::{intervention_named, species_named, ScriptAction, ScriptHost};
+
+/// Newest manifest format this build understands
+pub const MOD_FORMAT: u32 = 1;
+pub const MANIFEST_FILE: &str = "mod.ron";
+/// Folder next to the executable the standalone binary looks for mods in
+pub const DEFAULT_MOD_DIR: &str = "mods";
+/// Largest file a mod may ship, so a bad pack cannot exhaust memory at startup
+const MAX_FILE_BYTES: u64 = 1 << 20;
+
+/// The `mods` folder beside the running executable, whatever the working directory;
+/// relative to the working directory only when the executable cannot be found
+pub fn default_mod_dir() -> PathBuf {
+    std::env::current_exe().ok()
+        .and_then(|executable| executable.parent().map(|folder| folder.join(DEFAULT_MOD_DIR)))
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_MOD_DIR))
+}
+
+/// `major.minor.patch`; a newer minor or patch of the same major is compatible
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize)]
+#[serde(try_from = "String")]
+pub struct ModVersion {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+impl ModVersion {
+    pub const fn new(major: u32, minor: u32, patch: u32) -> Self {
+        Self { major, minor, patch }
+    }
+
+    /// Whether this version can stand in where `required` is asked for
+    pub fn satisfies(self, required: ModVersion) -> bool {
+        self.major == required.major && self >= required
+    }
+}
+
+impl FromStr for ModVersion {
+    type Err = String;
+
+    fn from_str(text: &str) -> std::result::Result<Self, String> {
+        let parts: Vec<&str> = text.trim().split('.').collect();
+        let [major, minor, patch] = parts.as_slice() else {
+            return Err(format!("version '{}' is not major.minor.patch", text));
+        };
+        let number = |part: &str| part.parse().map_err(|_| format!("version '{}' is not major.minor.patch", text));
+        Ok(Self { major: number(major)?, minor: number(minor)?, patch: number(patch)? })
+    }
+}
+
+impl TryFrom<String> for ModVersion {
+    type Error = String;
+
+    fn try_from(text: String) -> std::result::Result<Self, String> {
+        text.parse()
+    }
+}
+
+impl fmt::Display for ModVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// Another mod this one needs, at this version or a compatible later one
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ModDependency {
+    pub id: String,
+    pub version: ModVersion,
+}
+
+/// Contents of `mod.ron`
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ModManifest {
+    pub id: String, // Lowercase letters, digits, `-` and `_`; how other mods refer to this one
+    pub name: String,
+    pub version: ModVersion,
+    #[serde(default = "current_format")]
+    pub format: u32, // Manifest format the mod was written for
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub authors: Vec<String>,
+    #[serde(default)]
+    pub requires: Vec<ModDependency>, // Loaded first; the mod is skipped without them
+    #[serde(default)]
+    pub after: Vec<String>, // Loaded first when present
+    #[serde(default)]
+    pub before: Vec<String>, // Loaded afterwards when present
+}
+
+fn current_format() -> u32 {
+    MOD_FORMAT
+}
+
+/// One species' overrides, from a `species/*.ron` file
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct SpeciesMod {
+    pub species: SpeciesType,
+    #[serde(default)]
+    pub flocking: Option<FlockingWeights>,
+    #[serde(default)]
+    pub voice: Option<SpeciesSonicSignature>,
+    #[serde(default)]
+    pub samples: Option<SampleBankPaths>, // Relative to the mod directory
+}
+
+/// A script from a mod's `scripts` folder
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModScript {
+    pub name: String, // `<mod id>/<file name>`
+    pub source: String,
+}
+
+/// One mod read from disk
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModPackage {
+    pub manifest: ModManifest,
+    pub directory: PathBuf,
+    pub species: Vec<SpeciesMod>,
+    pub shaders: Vec<(VisualTheme, String)>, // Replacement theme shaders, named like the built-in files
+    pub scripts: Vec<ModScript>,
+}
+
+impl ModPackage {
+    /// Read the mod in `directory`; a mod loads whole or not at all
+    pub fn load(directory: &Path) -> Result<Self> {
+        let fail = |reason: String| BloomError::Mod { path: directory.to_path_buf(), reason };
+        let manifest: ModManifest = parse_ron(&read_text(&directory.join(MANIFEST_FILE))?).map_err(&fail)?;
+        if manifest.id.is_empty() || !manifest.id.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_') {
+            return Err(fail(format!("id '{}' may only use lowercase letters, digits, '-' and '_'", manifest.id)));
+        }
+
+        let mut species = Vec::new();
+        for path in files_in(&directory.join("species"), "ron")? {
+            let species_mod: SpeciesMod = parse_ron(&read_text(&path)?).map_err(|reason| BloomError::Mod { path: path.clone(), reason })?;
+            species.push(contain_species_mod(directory, species_mod).map_err(|reason| BloomError::Mod { path: path.clone(), reason })?);
+        }
+
+        let mut shaders = Vec::new();
+        for path in files_in(&directory.join("shaders"), "wgsl")? {
+            let name = path.file_name().map(|name| name.to_string_lossy().into_owned()).unwrap_or_default();
+            let theme = VisualTheme::from_shader_file(&name)
+                .ok_or_else(|| BloomError::Mod { path: path.clone(), reason: "not named after a theme shader".to_string() })?;
+            shaders.push((theme, read_text(&path)?));
+        }
+
+        let mut scripts = Vec::new();
+        for path in files_in(&directory.join("scripts"), "rhai")? {
+            let file = path.file_name().map(|name| name.to_string_lossy().into_owned()).unwrap_or_default();
+            scripts.push(ModScript { name: format!("{}/{}", manifest.id, file), source: read_text(&path)? });
+        }
+
+        Ok(Self { manifest, directory: directory.to_path_buf(), species, shaders, scripts })
+    }
+}
+
+/// Mods in the order they apply
+#[derive(Debug, Clone, Default)]
+pub struct ModSet {
+    packages: Vec<ModPackage>,
+}
+
+impl ModSet {
+    /// Every mod in the subfolders of `directory`, ordered; a missing folder means no mods
+    pub fn discover(directory: &Path) -> Self {
+        let entries = match std::fs::read_dir(directory) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Self::default(),
+            Err(e) => {
+                warn!(target: "app", "🧩 Mods folder {} could not be read: {}", directory.display(), e);
+                return Self::default();
+            }
+        };
+        let mut folders: Vec<PathBuf> = entries.filter_map(|entry| Some(entry.ok()?.path()))
+            .filter(|path| path.join(MANIFEST_FILE).is_file())
+            .collect();
+        folders.sort();
+
+        let packages = folders.iter().filter_map(|folder| ModPackage::load(folder)
+            .inspect_err(|e| warn!(target: "app", "🧩 Skipping {}", e))
+            .ok());
+        let mods = Self::resolve(packages.collect());
+        for package in &mods.packages {
+            let manifest = &package.manifest;
+            info!(target: "app", "🧩 Mod loaded: {} {} ({} species, {} shaders, {} scripts)",
+                  manifest.name, manifest.version, package.species.len(), package.shaders.len(), package.scripts.len());
+        }
+        mods
+    }
+
+    /// Put `candidates` in load order, dropping duplicates, mods written for a newer
+    /// format, mods whose requirements are missing and mods caught in an ordering cycle
+    pub fn resolve(mut candidates: Vec<ModPackage>) -> Self {
+        candidates.sort_by(|a, b| a.manifest.id.cmp(&b.manifest.id));
+        candidates.dedup_by(|later, first| {
+            let duplicate = later.manifest.id == first.manifest.id;
+            if duplicate {
+                warn!(target: "app", "🧩 Skipping {}: another mod already uses the id '{}'", later.directory.display(), later.manifest.id);
+            }
+            duplicate
+        });
+        candidates.retain(|package| {
+            let supported = package.manifest.format <= MOD_FORMAT;
+            if !supported {
+                warn!(target: "app", "🧩 Skipping {}: written for mod format {}, this build reads up to {}", package.manifest.id, package.manifest.format, MOD_FORMAT);
+            }
+            supported
+        });
+
+        // Dropping a mod can strand the mods that required it, so repeat until nothing changes
+        loop {
+            let versions: HashMap<String, ModVersion> = candidates.iter().map(|package| (package.manifest.id.clone(), package.manifest.version)).collect();
+            let before = candidates.len();
+            candidates.retain(|package| match package.manifest.requires.iter().find(|dependency| !versions.get(&dependency.id).is_some_and(|version| version.satisfies(dependency.version))) {
+                Some(missing) => {
+                    warn!(target: "app", "🧩 Skipping {}: it requires {} {}", package.manifest.id, missing.id, missing.version);
+                    false
+                }
+                None => true,
+            });
+            if candidates.len() == before {
+                break;
+            }
+        }
+
+        // Kahn's algorithm, taking the alphabetically first ready mod so the order is stable
+        let index: HashMap<&str, usize> = candidates.iter().enumerate().map(|(i, package)| (package.manifest.id.as_str(), i)).collect();
+        let mut successors = vec![Vec::new(); candidates.len()];
+        let mut waiting_on = vec![0usize; candidates.len()];
+        for (i, package) in candidates.iter().enumerate() {
+            let manifest = &package.manifest;
+            let earlier = manifest.requires.iter().map(|dependency| dependency.id.as_str()).chain(manifest.after.iter().map(String::as_str));
+            let edges = earlier.filter_map(|id| index.get(id).map(|&first| (first, i)))
+                .chain(manifest.before.iter().filter_map(|id| index.get(id.as_str()).map(|&then| (i, then))));
+            for (first, then) in edges {
+                if first != then && !successors[first].contains(&then) {
+                    successors[first].push(then);
+                    waiting_on[then] += 1;
+                }
+            }
+        }
+        let mut ready: BTreeSet<(&str, usize)> = (0..candidates.len()).filter(|&i| waiting_on[i] == 0).map(|i| (candidates[i].manifest.id.as_str(), i)).collect();
+        let mut order = Vec::with_capacity(candidates.len());
+        while let Some((_, i)) = ready.pop_first() {
+            order.push(i);
+            for &then in &successors[i] {
+                waiting_on[then] -= 1;
+                if waiting_on[then] == 0 {
+                    ready.insert((candidates[then].manifest.id.as_str(), then));
+                }
+            }
+        }
+        for (_, package) in candidates.iter().enumerate().filter(|(i, _)| !order.contains(i)) {
+            warn!(target: "app", "🧩 Skipping {}: its load order is circular", package.manifest.id);
+        }
+
+        let mut slots: Vec<Option<ModPackage>> = candidates.into_iter().map(Some).collect();
+        Self { packages: order.into_iter().filter_map(|i| slots[i].take()).collect() }
+    }
+
+    pub fn packages(&self) -> &[ModPackage] {
+        &self.packages
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.packages.is_empty()
+    }
+
+    /// Species flocking, voices and samples laid over `config`, later mods winning
+    pub fn apply(&self, config: &mut BloomConfig) {
+        for species_mod in self.packages.iter().flat_map(|package| &package.species) {
+            let species = species_mod.species;
+            if let Some(weights) = species_mod.flocking {
+                config.flocking.weights[species.to_index()] = weights;
+            }
+            if let Some(voice) = &species_mod.voice {
+                config.sonic_signatures.insert(species, voice.clone());
+            }
+            if let Some(samples) = &species_mod.samples {
+                config.sample_banks.insert(species, samples.clone());
+            }
+        }
+    }
+
+    /// The shader each theme should use, from the last mod that replaces it
+    pub fn theme_shaders(&self) -> Vec<(&str, VisualTheme, &str)> {
+        let mut shaders: Vec<(&str, VisualTheme, &str)> = Vec::new();
+        for package in &self.packages {
+            for (theme, source) in &package.shaders {
+                shaders.retain(|(_, replaced, _)| replaced != theme);
+                shaders.push((package.manifest.id.as_str(), *theme, source.as_str()));
+            }
+        }
+        shaders
+    }
+
+    /// Every script, in load order
+    pub fn scripts(&self) -> impl Iterator<Item = &ModScript> {
+        self.packages.iter().flat_map(|package| &package.scripts)
+    }
+}
+
+/// RON with `Some(...)` optional around optional fields
+fn parse_ron<T: DeserializeOwned>(source: &str) -> std::result::Result<T, String> {
+    ron::Options::default()
+        .with_default_extension(Extensions::IMPLICIT_SOME)
+        .from_str(source)
+        .map_err(|e| e.to_string())
+}
+
+fn read_text(path: &Path) -> Result<String> {
+    let fail = |reason: String| BloomError::Mod { path: path.to_path_buf(), reason };
+    let size = std::fs::metadata(path).map_err(|e| fail(e.to_string()))?.len();
+    if size > MAX_FILE_BYTES {
+        return Err(fail(format!("{} bytes is over the {} byte limit", size, MAX_FILE_BYTES)));
+    }
+    std::fs::read_to_string(path).map_err(|e| fail(e.to_string()))
+}
+
+/// Files with `extension` directly inside `folder`, sorted; none when the folder is absent.
+/// A symlink pointing out of the folder fails the mod
+fn files_in(folder: &Path, extension: &str) -> Result<Vec<PathBuf>> {
+    let entries = match std::fs::read_dir(folder) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(BloomError::Mod { path: folder.to_path_buf(), reason: e.to_string() }),
+    };
+    let mut files: Vec<PathBuf> = entries.filter_map(|entry| Some(entry.ok()?.path()))
+        .filter(|path| path.is_file() && path.extension().is_some_and(|found| found == extension))
+        .collect();
+    files.sort();
+    for file in &files {
+        resolve_inside(folder, file).map_err(|reason| BloomError::Mod { path: file.clone(), reason })?;
+    }
+    Ok(files)
+}
+
+/// A species file with its values checked and its sample paths resolved inside the mod
+fn contain_species_mod(directory: &Path, mut species_mod: SpeciesMod) -> std::result::Result<SpeciesMod, String> {
+    if let Some(weights) = &species_mod.flocking {
+        for (param, value) in [(FLOCK_FORCE, weights.separation), (FLOCK_ALIGNMENT, weights.alignment), (FLOCK_FORCE, weights.cohesion), (FLOCK_FORCE, weights.orbit), (FLOCK_RADIUS, weights.radius)] {
+            param.validate(value).map_err(|e| BloomError::from(e).to_string())?;
+        }
+    }
+    if let Some(voice) = &species_mod.voice {
+        params::validate_signature(voice).map_err(|e| e.to_string())?;
+    }
+    if let Some(samples) = &mut species_mod.samples {
+        for path in [&mut samples.spawn, &mut samples.bass_drop, &mut samples.crystal_harvest, &mut samples.war_declared, &mut samples.treaty_signed].into_iter().flatten() {
+            *path = contained_path(directory, path)?;
+        }
+    }
+    Ok(species_mod)
+}
+
+/// `relative` under `directory`, refusing absolute paths, any that climb out of it
+/// and any that a symlink leads out of it
+fn contained_path(directory: &Path, relative: &Path) -> std::result::Result<PathBuf, String> {
+    if relative.components().any(|component| !matches!(component, Component::Normal(_) | Component::CurDir)) {
+        return Err(format!("{} must stay inside the mod's folder", relative.display()));
+    }
+    resolve_inside(directory, &directory.join(relative))
+}
+
+/// The canonical form of `path`, which must exist and resolve inside `directory`
+fn resolve_inside(directory: &Path, path: &Path) -> std::result::Result<PathBuf, String> {
+    let canonical = |path: &Path| path.canonicalize().map_err(|e| format!("{}: {}", path.display(), e));
+    let resolved = canonical(path)?;
+    if !resolved.starts_with(canonical(directory)?) {
+        return Err(format!("{} leads outside the mod's folder", path.display()));
+    }
+    Ok(resolved)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn package(id: &str, version: &str, requires: &[(&str, &str)], after: &[&str], before: &[&str]) -> ModPackage {
+        let manifest = ModManifest {
+            id: id.to_string(),
+            name: id.to_string(),
+            version: version.parse().unwrap(),
+            format: MOD_FORMAT,
+            description: String::new(),
+            authors: Vec::new(),
+            requires: requires.iter().map(|(id, version)| ModDependency { id: id.to_string(), version: version.parse().unwrap() }).collect(),
+            after: after.iter().map(|id| id.to_string()).collect(),
+            before: before.iter().map(|id| id.to_string()).collect(),
+        };
+        ModPackage { manifest, directory: PathBuf::from(id), species: Vec::new(), shaders: Vec::new(), scripts: Vec::new() }
+    }
+
+    fn ids(mods: &ModSet) -> Vec<&str> {
+        mods.packages().iter().map(|package| package.manifest.id.as_str()).collect()
+    }
+
+    #[test]
+    fn test_load_order_follows_requirements_and_hints() {
+        let mods = ModSet::resolve(vec![
+            package("zebra-voices", "1.0.0", &[("base-pack", "1.1.0")], &[], &[]),
+            package("base-pack", "1.2.3", &[], &[], &[]),
+            package("alpha-shaders", "0.1.0", &[], &["zebra-voices"], &[]),
+            package("early", "2.0.0", &[], &[], &["base-pack"]),
+            package("needs-newer", "1.0.0", &[("base-pack", "1.3.0")], &[], &[]),
+            package("needs-major", "1.0.0", &[("base-pack", "2.0.0")], &[], &[]),
+            package("stranded", "1.0.0", &[("needs-newer", "1.0.0")], &[], &[]),
+            package("loop-a", "1.0.0", &[], &["loop-b"], &[]),
+            package("loop-b", "1.0.0", &[], &["loop-a"], &[]),
+        ]);
+        assert_eq!(ids(&mods), ["early", "base-pack", "zebra-voices", "alpha-shaders"]);
+
+        let mut duplicate = package("base-pack", "9.0.0", &[], &[], &[]);
+        duplicate.manifest.format = MOD_FORMAT + 1;
+        assert_eq!(ids(&ModSet::resolve(vec![duplicate])), Vec::<&str>::new(), "newer formats are skipped");
+    }
+
+    #[test]
+    fn test_manifests_and_species_files_parse() {
+        let manifest: ModManifest = parse_ron(r#"(
+            id: "neon-disco",
+            name: "Neon Disco",
+            version: "1.4.0",
+            requires: [(id: "base-pack", version: "1.0.0")],
+            after: ["other"],
+        )"#).unwrap();
+        assert_eq!(manifest.version, ModVersion::new(1, 4, 0));
+        assert_eq!(manifest.format, MOD_FORMAT);
+        assert!(parse_ron::<ModManifest>(r#"(id: "x", name: "X", version: "1.4")"#).is_err());
+
+        let species: SpeciesMod = parse_ron(r#"(
+            species: DiscoLlama,
+            flocking: (separation: 1.0, alignment: 0.5, cohesion: 0.8, orbit: 0.0, radius: 90.0),
+            samples: (spawn: "samples/spawn.wav"),
+        )"#).unwrap();
+        let directory = std::env::temp_dir().join(format!("aetherium_mod_{}", std::process::id()));
+        std::fs::create_dir_all(directory.join("samples")).unwrap();
+        std::fs::write(directory.join("samples/spawn.wav"), b"").unwrap();
+        let contained = contain_species_mod(&directory, species.clone());
+        let expected = directory.canonicalize().unwrap().join("samples/spawn.wav");
+
+        let mut escaping = species.clone();
+        escaping.samples = Some(SampleBankPaths { spawn: Some(PathBuf::from("../../etc/passwd")), ..SampleBankPaths::default() });
+        let climbing = contain_species_mod(&directory, escaping);
+        // A link inside the folder that points out of it is caught too
+        #[cfg(unix)]
+        let linked = {
+            std::os::unix::fs::symlink(std::env::temp_dir(), directory.join("samples/outside")).unwrap();
+            let mut linked = species;
+            linked.samples = Some(SampleBankPaths { spawn: Some(PathBuf::from("samples/outside")), ..SampleBankPaths::default() });
+            contain_species_mod(&directory, linked)
+        };
+        std::fs::remove_dir_all(&directory).ok();
+
+        assert_eq!(contained.unwrap().samples.unwrap().spawn, Some(expected));
+        assert!(climbing.is_err());
+        #[cfg(unix)]
+        assert!(linked.is_err());
+    }
+}
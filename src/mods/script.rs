@@ -0,0 +1,362 @@
+// === MOD SCRIPTS ===
+// Rhai scripts from mods react to the organism without reaching into it. A
+// script may define `on_event(event)`, called with every chaos event as a map
+// whose `kind` is the event in snake_case, and `on_second(time, population)`.
+// Inside them it can call `spawn_llama(species, x, y)`, `intervene(name)`,
+// `force_field(kind, x, y, strength, seconds)` and `wind(x, y, dx, dy, seconds)`,
+// which queue actions the organism carries out on its next tick. The engine cannot
+// load modules, touch files or eval strings, every call is capped in
+// operations and memory, all calls in one tick share an operation budget so an
+// event storm cannot stall a frame, and a script that keeps failing is
+// switched off.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::Receiver;
+use glam::Vec2;
+use parking_lot::Mutex;
+use rhai::module_resolvers::DummyModuleResolver;
+use rhai::{CallFnOptions, Dynamic, Engine, EvalAltResult, Map, Scope, AST};
+use tracing::{info, warn};
+use crate::core::ecs::EntityId;
+use crate::core::events::ChaosEvent;
+use crate::engine::ObserverIntervention;
+use crate::entities::SpeciesType;
+use crate::mathematics::{ForceField, MAX_FIELD_ACCELERATION};
+use super::ModScript;
+
+/// Operations one call may run before it is stopped
+const MAX_OPERATIONS: u64 = 100_000;
+/// Operations every call in one tick may run together; hooks past it wait for the next tick
+const MAX_TICK_OPERATIONS: u64 = 1_000_000;
+/// Failures before a script is switched off
+const MAX_FAILURES: u32 = 3;
+/// Actions all scripts together may queue per second
+const MAX_ACTIONS_PER_SECOND: usize = 10;
+/// Reach of the force fields scripts place
+const SCRIPT_FIELD_RADIUS: f32 = 200.0;
+/// Longest a force field placed by a script lasts, in seconds
+const MAX_SCRIPT_FIELD_SECONDS: f64 = 60.0;
+
+/// Something a script asked the organism to do
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScriptAction {
+    Spawn(SpeciesType, Vec2),
+    Intervene(ObserverIntervention),
+    Force(ForceField),
+}
+
+struct LoadedScript {
+    name: String,
+    ast: AST,
+    on_event: bool,
+    on_second: bool,
+    failures: u32,
+}
+
+/// Every mod script, sharing one sandboxed engine
+pub struct ScriptHost {
+    engine: Engine,
+    scripts: Vec<LoadedScript>,
+    actions: Arc<Mutex<Vec<ScriptAction>>>,
+    events: Option<Receiver<ChaosEvent>>,
+    next_second: f64,
+    budget: usize,                    // Actions left this second
+    tick_operations: Arc<AtomicU64>, // Operations run so far this tick
+}
+
+impl ScriptHost {
+    /// Compile `scripts` and run their top level once; ones that fail are skipped
+    pub fn new<'a>(scripts: impl IntoIterator<Item = &'a ModScript>) -> Self {
+        let actions = Arc::new(Mutex::new(Vec::new()));
+        let tick_operations = Arc::new(AtomicU64::new(0));
+        let engine = sandboxed_engine(&actions, &tick_operations);
+        let scripts = scripts.into_iter().filter_map(|script| {
+            let loaded = engine.compile(&script.source)
+                .map_err(|e| e.to_string())
+                .and_then(|ast| engine.run_ast(&ast).map(|_| ast).map_err(|e| e.to_string()));
+            match loaded {
+                Ok(ast) => {
+                    let has = |name: &str, params: usize| ast.iter_functions().any(|function| function.name == name && function.params.len() == params);
+                    let (on_event, on_second) = (has("on_event", 1), has("on_second", 2));
+                    info!(target: "app", "📜 Script {} ready", script.name);
+                    Some(LoadedScript { name: script.name.clone(), on_event, on_second, ast, failures: 0 })
+                }
+                Err(e) => {
+                    warn!(target: "app", "📜 Skipping script {}: {}", script.name, e);
+                    None
+                }
+            }
+        }).collect();
+        Self { engine, scripts, actions, events: None, next_second: 0.0, budget: MAX_ACTIONS_PER_SECOND, tick_operations }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.scripts.is_empty()
+    }
+
+    /// Whether any script wants to hear about chaos events
+    pub fn wants_events(&self) -> bool {
+        self.scripts.iter().any(|script| script.on_event)
+    }
+
+    /// Feed `on_event` hooks from this receiver
+    pub fn listen(&mut self, events: Receiver<ChaosEvent>) {
+        self.events = Some(events);
+    }
+
+    /// Run the hooks that are due and hand back what the scripts asked for
+    pub fn update(&mut self, time: f64, population: usize) -> Vec<ScriptAction> {
+        self.tick_operations.store(0, Ordering::Relaxed);
+        let events: Vec<ChaosEvent> = self.events.as_ref().map(|events| events.try_iter().collect()).unwrap_or_default();
+        for event in events {
+            let map = event_map(&event);
+            self.call_each(|script| script.on_event, "on_event", || (Dynamic::from_map(map.clone()),));
+        }
+        if time >= self.next_second {
+            self.next_second = time.floor() + 1.0;
+            self.budget = MAX_ACTIONS_PER_SECOND;
+            self.call_each(|script| script.on_second, "on_second", || (time, population as i64));
+        }
+
+        let mut queued = std::mem::take(&mut *self.actions.lock());
+        if queued.len() > self.budget {
+            warn!(target: "app", "📜 Scripts queued {} actions, running {}", queued.len(), self.budget);
+            queued.truncate(self.budget);
+        }
+        self.budget -= queued.len();
+        queued
+    }
+
+    fn call_each<A: rhai::FuncArgs>(&mut self, wants: impl Fn(&LoadedScript) -> bool, hook: &str, args: impl Fn() -> A) {
+        let engine = &self.engine;
+        for script in self.scripts.iter_mut().filter(|script| script.failures < MAX_FAILURES && wants(script)) {
+            if self.tick_operations.load(Ordering::Relaxed) >= MAX_TICK_OPERATIONS {
+                return;
+            }
+            let options = CallFnOptions::new().eval_ast(false);
+            let result = engine.call_fn_with_options::<Dynamic>(options, &mut Scope::new(), &script.ast, hook, args());
+            if self.tick_operations.load(Ordering::Relaxed) >= MAX_TICK_OPERATIONS {
+                // Stopped by the shared budget, not by its own fault
+                warn!(target: "app", "📜 Scripts used up this tick's {} operations during {} {}", MAX_TICK_OPERATIONS, script.name, hook);
+                return;
+            }
+            if let Err(e) = result {
+                script.failures += 1;
+                warn!(target: "app", "📜 {} failed in {}: {}", script.name, hook, e);
+                if script.failures == MAX_FAILURES {
+                    warn!(target: "app", "📜 Switching off {} after {} failures", script.name, MAX_FAILURES);
+                }
+            }
+        }
+    }
+}
+
+/// An engine with no modules, no eval and tight limits, whose `spawn_llama`,
+/// `intervene`, `force_field` and `wind` push onto `actions`; every operation
+/// counts against `tick_operations`
+fn sandboxed_engine(actions: &Arc<Mutex<Vec<ScriptAction>>>, tick_operations: &Arc<AtomicU64>) -> Engine {
+    let mut engine = Engine::new();
+    let spent = Arc::clone(tick_operations);
+    engine.on_progress(move |_| (spent.fetch_add(1, Ordering::Relaxed) >= MAX_TICK_OPERATIONS).then_some(Dynamic::UNIT));
+    engine.set_module_resolver(DummyModuleResolver::new())
+        .disable_symbol("eval")
+        .set_max_operations(MAX_OPERATIONS)
+        .set_max_call_levels(32)
+        .set_max_expr_depths(64, 32)
+        .set_max_string_size(4096)
+        .set_max_array_size(1024)
+        .set_max_map_size(256)
+        .on_print(|text| info!(target: "app", "📜 {}", text))
+        .on_debug(|text, _, _| info!(target: "app", "📜 {}", text));
+
+    let queue = Arc::clone(actions);
+    engine.register_fn("spawn_llama", move |species: &str, x: f64, y: f64| -> Result<(), Box<EvalAltResult>> {
+        let species = species_named(species).ok_or_else(|| format!("unknown species '{}'", species))?;
+        queue.lock().push(ScriptAction::Spawn(species, Vec2::new(x as f32, y as f32)));
+        Ok(())
+    });
+    let queue = Arc::clone(actions);
+    engine.register_fn("spawn_llama", move |species: &str, x: i64, y: i64| -> Result<(), Box<EvalAltResult>> {
+        let species = species_named(species).ok_or_else(|| format!("unknown species '{}'", species))?;
+        queue.lock().push(ScriptAction::Spawn(species, Vec2::new(x as f32, y as f32)));
+        Ok(())
+    });
+    let queue = Arc::clone(actions);
+    engine.register_fn("intervene", move |name: &str| -> Result<(), Box<EvalAltResult>> {
+        let intervention = intervention_named(name).ok_or_else(|| format!("unknown intervention '{}'", name))?;
+        queue.lock().push(ScriptAction::Intervene(intervention));
+        Ok(())
+    });
+    let queue = Arc::clone(actions);
+    engine.register_fn("force_field", move |kind: &str, x: f64, y: f64, strength: f64, seconds: f64| -> Result<(), Box<EvalAltResult>> {
+        finite("force_field", &[x, y, strength, seconds])?;
+        let position = Vec2::new(x as f32, y as f32);
+        let strength = (strength as f32).clamp(-MAX_FIELD_ACCELERATION, MAX_FIELD_ACCELERATION);
+        let field = match kind {
+            "well" => ForceField::gravity_well(position, SCRIPT_FIELD_RADIUS, strength),
+            "vortex" => ForceField::vortex(position, SCRIPT_FIELD_RADIUS, strength),
+            _ => return Err(format!("unknown force field '{}'", kind).into()),
+        };
+        queue.lock().push(ScriptAction::Force(field.with_lifespan(seconds.min(MAX_SCRIPT_FIELD_SECONDS) as f32)));
+        Ok(())
+    });
+    let queue = Arc::clone(actions);
+    engine.register_fn("wind", move |x: f64, y: f64, dx: f64, dy: f64, seconds: f64| -> Result<(), Box<EvalAltResult>> {
+        finite("wind", &[x, y, dx, dy, seconds])?;
+        let direction = Vec2::new(dx as f32, dy as f32).clamp_length_max(MAX_FIELD_ACCELERATION);
+        let field = ForceField::wind(Vec2::new(x as f32, y as f32), SCRIPT_FIELD_RADIUS, direction);
+        queue.lock().push(ScriptAction::Force(field.with_lifespan(seconds.min(MAX_SCRIPT_FIELD_SECONDS) as f32)));
+        Ok(())
+    });
+    engine
+}
+
+/// Refuse NaN and infinite arguments, which would poison every llama a field touches
+fn finite(function: &str, values: &[f64]) -> Result<(), Box<EvalAltResult>> {
+    match values.iter().all(|value| value.is_finite()) {
+        true => Ok(()),
+        false => Err(format!("{} needs finite numbers", function).into()),
+    }
+}
+
+/// Species by the name scripts see, e.g. "DiscoLlama"
+pub(crate) fn species_named(name: &str) -> Option<SpeciesType> {
+    SpeciesType::ALL.into_iter().find(|species| format!("{:?}", species) == name)
+}
+
+pub(crate) fn intervention_named(name: &str) -> Option<ObserverIntervention> {
+    match name {
+        "bless" => Some(ObserverIntervention::Bless),
+        "force_peace" => Some(ObserverIntervention::ForcePeace),
+        "scramble" => Some(ObserverIntervention::Scramble),
+        "redistribute" => Some(ObserverIntervention::Redistribute),
+        _ => None,
+    }
+}
+
+/// An event as a script sees it: `kind` plus the event's fields
+fn event_map(event: &ChaosEvent) -> Map {
+    let name = |value: &dyn std::fmt::Debug| Dynamic::from(format!("{:?}", value));
+    let id = |entity: &EntityId| Dynamic::from(*entity as i64);
+    let (kind, fields, at): (&str, Vec<(&str, Dynamic)>, Option<Vec2>) = match event {
+        ChaosEvent::LlamaSpawned { entity, species, position } =>
+            ("llama_spawned", vec![("entity", id(entity)), ("species", name(species))], Some(*position)),
+        ChaosEvent::LlamaDespawned { entity, species, position, reason } =>
+            ("llama_despawned", vec![("entity", id(entity)), ("species", name(species)), ("reason", name(reason))], Some(*position)),
+        ChaosEvent::HiveFormed { hive, species, members } =>
+            ("hive_formed", vec![("hive", id(hive)), ("species", name(species)), ("members", Dynamic::from(*members as i64))], None),
+        ChaosEvent::HiveDissolved { hive, members_left } =>
+            ("hive_dissolved", vec![("hive", id(hive)), ("members_left", Dynamic::from(*members_left as i64))], None),
+        ChaosEvent::SpeciesEmerged { hybrid, name: species_name, parents, hive } =>
+            ("species_emerged", vec![("hybrid", Dynamic::from(*hybrid as i64)), ("name", Dynamic::from(species_name.clone())), ("parents", Dynamic::from(vec![name(&parents[0]), name(&parents[1])])), ("hive", id(hive))], None),
+        ChaosEvent::ConflictStarted { attacker, defender, front } =>
+            ("conflict_started", vec![("attacker", name(attacker)), ("defender", name(defender))], Some(*front)),
+        ChaosEvent::ConflictEnded { attacker, defender, front, victor } =>
+            ("conflict_ended", vec![("attacker", name(attacker)), ("defender", name(defender)), ("victor", victor.map_or(Dynamic::UNIT, |victor| name(&victor)))], Some(*front)),
+        ChaosEvent::CrystalSpawned { crystal, position } =>
+            ("crystal_spawned", vec![("crystal", id(crystal))], Some(*position)),
+        ChaosEvent::CrystalHarvested { llama, species, position, amount } =>
+            ("crystal_harvested", vec![("llama", id(llama)), ("species", name(species)), ("amount", Dynamic::from(*amount as f64))], Some(*position)),
+        ChaosEvent::ConsciousnessGifted { donor, recipient, species, position, amount } =>
+            ("consciousness_gifted", vec![("donor", id(donor)), ("recipient", recipient.as_ref().map_or(Dynamic::UNIT, id)), ("species", name(species)), ("amount", Dynamic::from(*amount as f64))], Some(*position)),
+        ChaosEvent::TearOpened { tear, position } => ("tear_opened", vec![("tear", id(tear))], Some(*position)),
+        ChaosEvent::TearClosed { tear, position } => ("tear_closed", vec![("tear", id(tear))], Some(*position)),
+        ChaosEvent::EchoReleased { echo, species, position, consciousness } =>
+            ("echo_released", vec![("echo", id(echo)), ("species", name(species)), ("consciousness", Dynamic::from(*consciousness as f64))], Some(*position)),
+        ChaosEvent::EchoCrystallized { echo, crystal, position } =>
+            ("echo_crystallized", vec![("echo", id(echo)), ("crystal", id(crystal))], Some(*position)),
+        ChaosEvent::ObserverIntervention(intervention) =>
+            ("observer_intervention", vec![("intervention", name(intervention))], None),
+        ChaosEvent::BeatDrop { intensity, cosmic_time } =>
+            ("beat_drop", vec![("intensity", Dynamic::from(*intensity as f64)), ("time", Dynamic::from(*cosmic_time))], None),
+    };
+
+    let mut map: Map = fields.into_iter().map(|(key, value)| (key.into(), value)).collect();
+    if let Some(at) = at {
+        map.insert("x".into(), Dynamic::from(at.x as f64));
+        map.insert("y".into(), Dynamic::from(at.y as f64));
+    }
+    map.insert("kind".into(), Dynamic::from(kind));
+    map
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn script(source: &str) -> ModScript {
+        ModScript { name: "test/script.rhai".to_string(), source: source.to_string() }
+    }
+
+    #[test]
+    fn test_scripts_queue_actions_and_stay_sandboxed() {
+        let reactive = script(r#"
+            fn on_event(event) {
+                if event.kind == "beat_drop" { spawn_llama("HypnoCamel", 100.0, 200.5); }
+                if event.kind == "beat_drop" { force_field("vortex", 600.0, 400.0, 80.0, 600.0); }
+            }
+            fn on_second(time, population) {
+                if population > 3 { intervene("force_peace"); }
+            }
+        "#);
+        let runaway = script("fn on_second(time, population) { loop { } }");
+        let escaping = script(r#"import "std" as s;"#);
+        let mut host = ScriptHost::new([&reactive, &runaway, &escaping]);
+        assert_eq!(host.scripts.len(), 2, "imports cannot resolve");
+
+        let (sender, receiver) = std::sync::mpsc::sync_channel(4);
+        host.listen(receiver);
+        sender.send(ChaosEvent::BeatDrop { intensity: 1.5, cosmic_time: 0.5 }).unwrap();
+        let actions = host.update(0.5, 5);
+        assert_eq!(actions, [
+            ScriptAction::Spawn(SpeciesType::HypnoCamel, Vec2::new(100.0, 200.5)),
+            ScriptAction::Force(ForceField::vortex(Vec2::new(600.0, 400.0), SCRIPT_FIELD_RADIUS, 80.0).with_lifespan(60.0)),
+            ScriptAction::Intervene(ObserverIntervention::ForcePeace),
+        ]);
+        assert_eq!(host.scripts[1].failures, 1, "the endless loop runs out of operations");
+
+        for second in 1..=5 {
+            host.update(second as f64, 0);
+        }
+        assert_eq!(host.scripts[1].failures, MAX_FAILURES, "stops being called once switched off");
+    }
+
+    #[test]
+    fn test_force_fields_from_scripts_are_finite_and_bounded() {
+        let wild = script(r#"
+            fn on_second(time, population) {
+                force_field("well", 600.0, 400.0, 1.0e9, 5.0);
+                wind(600.0, 400.0, -1.0e9, 0.0, 5.0);
+            }
+        "#);
+        let poisoned = script(r#"fn on_second(time, population) { force_field("vortex", 0.0 / 0.0, 400.0, 80.0, 5.0); }"#);
+        let mut host = ScriptHost::new([&wild, &poisoned]);
+        let actions = host.update(0.0, 0);
+        assert_eq!(actions, [
+            ScriptAction::Force(ForceField::gravity_well(Vec2::new(600.0, 400.0), SCRIPT_FIELD_RADIUS, MAX_FIELD_ACCELERATION).with_lifespan(5.0)),
+            ScriptAction::Force(ForceField::wind(Vec2::new(600.0, 400.0), SCRIPT_FIELD_RADIUS, Vec2::new(-MAX_FIELD_ACCELERATION, 0.0)).with_lifespan(5.0)),
+        ]);
+        assert_eq!(host.scripts[1].failures, 1, "a NaN position is refused");
+    }
+
+    #[test]
+    fn test_an_event_storm_shares_one_tick_budget() {
+        // Each call stays under its own cap, but a storm of them would not
+        let busy = script("fn on_event(event) { let n = 0; while n < 5000 { n += 1; } }");
+        let mut host = ScriptHost::new([&busy]);
+        let (sender, receiver) = std::sync::mpsc::sync_channel(64);
+        host.listen(receiver);
+        for _ in 0..64 {
+            sender.send(ChaosEvent::BeatDrop { intensity: 1.0, cosmic_time: 0.5 }).unwrap();
+        }
+        host.update(0.5, 0);
+        assert!(host.tick_operations.load(Ordering::Relaxed) <= MAX_TICK_OPERATIONS + 1);
+        assert_eq!(host.scripts[0].failures, 0, "running out of the shared budget is not the script's failure");
+
+        sender.send(ChaosEvent::BeatDrop { intensity: 1.0, cosmic_time: 1.5 }).unwrap();
+        host.update(1.5, 0);
+        let one_call = host.tick_operations.load(Ordering::Relaxed);
+        assert!(one_call > 5_000 && one_call < MAX_TICK_OPERATIONS, "the next tick starts afresh");
+    }
+}
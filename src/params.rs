@@ -0,0 +1,157 @@
+// === CONFIG VALIDATION ===
+// The parameter table lives in the simulation crate so every crate can clamp
+// against it; this is where a whole config is checked when the organism is
+// built. A value outside its range, NaN or infinite is rejected with an error
+// naming the parameter.
+
+use crate::api::BloomConfig;
+use crate::audio::{DuckingSettings, LoudnessSettings, SpeciesSonicSignature};
+use crate::engine::ChaosTuning;
+use crate::error::Result;
+
+pub use aetherium_sim::params::*;
+
+/// Check every parameter of a sonic signature
+pub fn validate_signature(signature: &SpeciesSonicSignature) -> Result<()> {
+    BASE_OCTAVE.validate(signature.base_octave as f32)?;
+    DETUNE_CENTS.validate(signature.detune_cents)?;
+    VIBRATO_CENTS.validate(signature.vibrato_cents)?;
+    VIBRATO_HZ.validate(signature.vibrato_hz)?;
+    ENVELOPE_SECONDS.validate(signature.envelope.attack)?;
+    ENVELOPE_SECONDS.validate(signature.envelope.release)?;
+    EFFECT_SEND.validate(signature.sends.drive)?;
+    EFFECT_SEND.validate(signature.sends.echo)?;
+    VOICE_LEVEL.validate(signature.level)?;
+    Ok(())
+}
+
+/// Check every parameter of the ducking compressor
+pub fn validate_ducking(settings: &DuckingSettings) -> Result<()> {
+    DUCK_THRESHOLD_DB.validate(settings.threshold_db)?;
+    DUCK_RATIO.validate(settings.ratio)?;
+    DUCK_MAX_REDUCTION_DB.validate(settings.max_reduction_db)?;
+    DUCK_ATTACK.validate(settings.attack)?;
+    DUCK_RELEASE.validate(settings.release)?;
+    Ok(())
+}
+
+/// Ducking settings forced into their safe ranges, for setters called while running
+pub fn clamp_ducking(settings: DuckingSettings) -> DuckingSettings {
+    DuckingSettings {
+        threshold_db: DUCK_THRESHOLD_DB.clamp(settings.threshold_db),
+        ratio: DUCK_RATIO.clamp(settings.ratio),
+        max_reduction_db: DUCK_MAX_REDUCTION_DB.clamp(settings.max_reduction_db),
+        attack: DUCK_ATTACK.clamp(settings.attack),
+        release: DUCK_RELEASE.clamp(settings.release),
+    }
+}
+
+/// Check the loudness target and true-peak ceiling
+pub fn validate_loudness(settings: &LoudnessSettings) -> Result<()> {
+    LOUDNESS_TARGET_LUFS.validate(settings.target_lufs)?;
+    TRUE_PEAK_CEILING_DB.validate(settings.true_peak_ceiling_db)?;
+    Ok(())
+}
+
+/// Loudness settings forced into their safe ranges, for setters called while running
+pub fn clamp_loudness(settings: LoudnessSettings) -> LoudnessSettings {
+    LoudnessSettings {
+        normalize: settings.normalize,
+        target_lufs: LOUDNESS_TARGET_LUFS.clamp(settings.target_lufs),
+        true_peak_ceiling_db: TRUE_PEAK_CEILING_DB.clamp(settings.true_peak_ceiling_db),
+    }
+}
+
+/// Check a config before the organism starts; the first value out of range is the error
+pub fn validate_config(config: &BloomConfig) -> Result<()> {
+    INITIAL_POPULATION.validate(config.initial_population as f32)?;
+    MAX_POPULATION.validate(config.max_population as f32)?;
+    ADAPTATION_STRENGTH.validate(config.adaptation_strength)?;
+    LIGHTING_STRENGTH.validate(config.lighting)?;
+    DAY_LENGTH_SECONDS.validate(config.day_length.as_secs_f32())?;
+    if let Some(homeostasis) = &config.homeostasis {
+        HOMEOSTASIS_STRENGTH.validate(homeostasis.strength)?;
+        for band in &homeostasis.bands {
+            HOMEOSTASIS_SHARE.validate(band.min_share)?;
+            HOMEOSTASIS_SHARE.validate(band.max_share)?;
+        }
+    }
+    for (param, value) in ChaosTuning::PARAMS.iter().zip(config.chaos_tuning.values()) {
+        param.validate(value)?;
+    }
+    LOD_POINT_PIXELS.validate(config.lod.point_pixels)?;
+    LOD_DETAIL_PIXELS.validate(config.lod.detail_pixels)?;
+    LOD_SPLAT_POPULATION.validate(config.lod.splat_population as f32)?;
+    LOD_STAGGER_TICKS.validate(config.lod.stagger_ticks as f32)?;
+    PALETTE_STRENGTH.validate(config.palette.strength)?;
+    PALETTE_ROTATION.validate(config.palette.rotation)?;
+    PALETTE_SPREAD.validate(config.palette.spread)?;
+    for route in &config.modulation.routes {
+        MOD_DEPTH.validate(route.depth)?;
+    }
+    for weights in &config.flocking.weights {
+        FLOCK_FORCE.validate(weights.separation)?;
+        FLOCK_ALIGNMENT.validate(weights.alignment)?;
+        FLOCK_FORCE.validate(weights.cohesion)?;
+        FLOCK_FORCE.validate(weights.orbit)?;
+        FLOCK_RADIUS.validate(weights.radius)?;
+    }
+    for feed in &config.entropy_feeds {
+        ENTROPY_WEIGHT.validate(feed.weight)?;
+        ENTROPY_INTERVAL_SECONDS.validate(feed.interval.as_secs_f32())?;
+    }
+    if let Some(metabolism) = &config.metabolism {
+        BASAL_DRAIN.validate(metabolism.basal_drain)?;
+        MOVEMENT_DRAIN.validate(metabolism.movement_drain)?;
+        WARFARE_DRAIN.validate(metabolism.warfare_drain)?;
+        CRYSTAL_RECHARGE.validate(metabolism.crystal_recharge)?;
+        ZONE_RECHARGE.validate(metabolism.zone_recharge)?;
+        STARVATION_SECONDS.validate(metabolism.starvation_time)?;
+    }
+    if let Some(max_fps) = config.max_fps {
+        MAX_FPS.validate(max_fps as f32)?;
+    }
+    if let Some(timeout) = config.idle_timeout {
+        IDLE_TIMEOUT_SECONDS.validate(timeout.as_secs_f32())?;
+    }
+    AUTOSAVE_INTERVAL_SECONDS.validate(config.autosave_interval.as_secs_f32())?;
+    WARM_START_SECONDS.validate(config.warm_start.as_secs_f32())?;
+    if let Some(stream) = &config.state_stream {
+        STREAM_RATE_HZ.validate(stream.rate_hz)?;
+    }
+    VISUAL_INTENSITY_LIMIT.validate(config.safety.visual_intensity_limit)?;
+    MAX_FLASH_RATE.validate(config.safety.max_flash_rate)?;
+    MAX_LUMINANCE_CHANGE.validate(config.safety.max_luminance_change)?;
+    validate_ducking(&config.ducking)?;
+    validate_loudness(&config.loudness)?;
+    ENVIRONMENT_CROSSFADE_SECONDS.validate(config.environment_crossfade.as_secs_f32())?;
+    config.sonic_signatures.values().try_for_each(validate_signature)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::BloomError;
+    use crate::engine::safety::SafetyConfig;
+    use crate::entities::SpeciesType;
+
+    #[test]
+    fn test_defaults_pass_and_unsafe_values_are_rejected() {
+        assert!(ALL.iter().all(|param| param.validate(param.default).is_ok()), "every default is in range");
+        assert!(validate_config(&BloomConfig::default()).is_ok());
+        assert!(validate_config(&BloomConfig { safety: SafetyConfig::safe_mode(), ..BloomConfig::default() }).is_ok());
+
+        let flashing = BloomConfig { safety: SafetyConfig { max_flash_rate: 10.0, ..SafetyConfig::default() }, ..BloomConfig::default() };
+        assert!(matches!(validate_config(&flashing), Err(BloomError::InvalidParameter { name: "safety.max_flash_rate", .. })));
+        let runaway = BloomConfig { max_population: 1_000_000, ..BloomConfig::default() };
+        assert!(validate_config(&runaway).is_err());
+        let mut signature = SpeciesSonicSignature::for_species(SpeciesType::HypnoCamel);
+        signature.vibrato_hz = f32::NAN;
+        let mut config = BloomConfig::default();
+        config.sonic_signatures.insert(SpeciesType::HypnoCamel, signature);
+        assert!(validate_config(&config).is_err(), "NaN never reaches the synth");
+
+        assert_eq!(ADAPTATION_STRENGTH.clamp(f32::NAN), ADAPTATION_STRENGTH.default);
+        assert_eq!(BUS_GAIN.clamp(4.0), 1.0);
+    }
+}
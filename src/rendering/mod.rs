@@ -1,7 +0,0 @@
-// Rendering module containing graphics and GPU systems
-
-pub mod effects;
-pub mod uniforms;
-
-pub use effects::*;
-pub use uniforms::PsychedelicUniforms;
\ No newline at end of file
@@ -1,7 +0,0 @@
-// Simulation module containing game state and ecosystem management systems
-
-pub mod consciousness_systems;
-pub mod meta_consciousness;
-
-pub use consciousness_systems::*;
-pub use meta_consciousness::*;
\ No newline at end of file
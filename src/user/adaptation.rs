@@ -0,0 +1,159 @@
+// === EXPERIENCE ADAPTATION ===
+// Turns what PreferenceInference believes about the user into concrete nudges:
+// spawn weights lean towards the species they watch, the palette leans towards
+// the hues they click on, audio follows their appetite for chaos, and the
+// meta-observer steps back while they are actively playing. Every nudge scales
+// with the adaptation strength and with how confident the belief is, so a
+// strength of 0 leaves the experience exactly as designed.
+
+use crate::entities::SPECIES_COUNT;
+use super::co_evolution::PreferenceInference;
+
+/// Click rate of a user who wants everything at full chaos
+pub const CLICKS_PER_MINUTE_FOR_FULL_CHAOS: f32 = 20.0;
+/// Preference names, shared by the inference and the inspector
+pub const CHAOS_APPETITE: &str = "chaos_appetite";
+pub const BEAT_AFFINITY: &str = "beat_affinity";
+pub const PALETTE_HUE: &str = "palette_hue";
+pub const AUDIO_INTENSITY: &str = "audio_intensity";
+pub const SPECIES_FOCUS: [&str; SPECIES_COUNT] = [
+    "species_focus_disco_llama",
+    "species_focus_quantum_sheep",
+    "species_focus_hypno_camel",
+    "species_focus_bass_drop_vicuna",
+];
+
+/// Furthest the palette leans towards the preferred hue at full strength and confidence
+const MAX_PALETTE_PULL: f32 = 0.35;
+/// Bounds of the per-species spawn weight multiplier
+const SPAWN_MULTIPLIER_RANGE: (f32, f32) = (0.25, 2.0);
+/// Strength times confidence needed before audio mode follows the user
+const AUDIO_CONFIDENCE: f32 = 0.4;
+
+/// Concrete changes to the experience derived from the current beliefs
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExperienceAdaptation {
+    pub spawn_multipliers: [f32; SPECIES_COUNT], // Indexed by SpeciesType::to_index
+    pub palette_hue: f32,            // Degrees the palette leans towards
+    pub palette_pull: f32,           // 0 leaves colors alone
+    pub audio_intensity: Option<f32>, // 0 mellow to 1 chaotic, None until confident
+    pub intervention_rate: f32,      // Multiplier on the observer's intervention chance
+}
+
+impl Default for ExperienceAdaptation {
+    fn default() -> Self {
+        Self::neutral()
+    }
+}
+
+impl ExperienceAdaptation {
+    /// No adaptation at all
+    pub fn neutral() -> Self {
+        Self {
+            spawn_multipliers: [1.0; SPECIES_COUNT],
+            palette_hue: 0.0,
+            palette_pull: 0.0,
+            audio_intensity: None,
+            intervention_rate: 1.0,
+        }
+    }
+
+    /// Nudges for `strength` (0-1) given what `inference` currently believes
+    pub fn from_beliefs(inference: &PreferenceInference, strength: f32) -> Self {
+        let strength = strength.clamp(0.0, 1.0);
+        let belief = |name: &str| inference.inferred_preferences.get(name)
+            .map(|preference| (preference.preference_value, preference.confidence * strength));
+
+        let mut adaptation = Self::neutral();
+        for (multiplier, name) in adaptation.spawn_multipliers.iter_mut().zip(SPECIES_FOCUS) {
+            if let Some((share, weight)) = belief(name) {
+                // An even split of attention leaves the species alone
+                *multiplier = (1.0 + weight * (share * SPECIES_COUNT as f32 - 1.0)).clamp(SPAWN_MULTIPLIER_RANGE.0, SPAWN_MULTIPLIER_RANGE.1);
+            }
+        }
+        if let Some((hue, weight)) = belief(PALETTE_HUE).filter(|&(_, weight)| weight > 0.0) {
+            adaptation.palette_hue = hue;
+            adaptation.palette_pull = weight * MAX_PALETTE_PULL;
+        }
+        if let Some((intensity, weight)) = belief(AUDIO_INTENSITY) {
+            adaptation.audio_intensity = (weight >= AUDIO_CONFIDENCE).then_some(intensity);
+        }
+        if let Some((appetite, weight)) = belief(CHAOS_APPETITE) {
+            // Busy users get the stage to themselves; idle ones get a livelier observer
+            adaptation.intervention_rate = (1.0 + weight * (0.5 - appetite) * 1.5).max(0.0);
+        }
+        adaptation
+    }
+
+    /// Base spawn weights reweighted towards the species the user watches, still summing to 1
+    pub fn apply_spawn_weights(&self, weights: [f32; SPECIES_COUNT]) -> [f32; SPECIES_COUNT] {
+        let scaled = std::array::from_fn(|i| weights[i] * self.spawn_multipliers[i]);
+        let total: f32 = scaled.iter().sum();
+        if total > 0.0 { scaled.map(|weight: f32| weight / total) } else { weights }
+    }
+
+    /// `hue` (degrees) leaned along the shorter arc towards the preferred hue
+    pub fn tint_hue(&self, hue: f32) -> f32 {
+        let delta = (self.palette_hue - hue + 540.0).rem_euclid(360.0) - 180.0;
+        (hue + delta * self.palette_pull).rem_euclid(360.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use super::*;
+    use crate::user::{ActionContext, ActionType, AudioEnvironmentState, UserAction, UserCoEvolutionSystem, VisualEnvironmentState};
+
+    fn click_on(hue: f32, species: f32, timestamp: f64) -> UserAction {
+        UserAction {
+            action_type: ActionType::MouseClick,
+            timestamp,
+            position: None,
+            intensity: 0.5,
+            duration: 0.1,
+            context: ActionContext {
+                system_state: HashMap::new(),
+                environmental_factors: HashMap::new(),
+                user_state_indicators: HashMap::from([("focus_hue".to_string(), hue), ("focus_species".to_string(), species)]),
+                visual_environment: VisualEnvironmentState {
+                    brightness_level: 0.0,
+                    flash_rate: 0.0,
+                    consciousness_visibility: 0.0,
+                    movement_intensity: 0.0,
+                    dominant_colors: Vec::new(),
+                    complexity_level: 0.0,
+                },
+                audio_environment: AudioEnvironmentState {
+                    beat_intensity: 0.9,
+                    frequency_distribution: HashMap::new(),
+                    rhythm_coherence: 0.8,
+                    harmonic_complexity: 0.0,
+                },
+                concurrent_actions: Vec::new(),
+            },
+            spatial_coordinates: None,
+        }
+    }
+
+    #[test]
+    fn test_beliefs_bend_the_experience_by_strength() {
+        let mut system = UserCoEvolutionSystem::new();
+        for click in 0..40 {
+            system.record_user_action(click_on(200.0, 2.0, click as f64 * 2.0));
+        }
+        system.update(1.0 / 60.0, 0.8, &HashMap::new(), 80.0);
+
+        system.set_adaptation_strength(1.0);
+        let adaptation = system.adaptation();
+        assert!(adaptation.spawn_multipliers[2] > 1.5 && adaptation.spawn_multipliers[0] < 1.0, "{:?}", adaptation);
+        assert!(adaptation.apply_spawn_weights([0.55, 0.2, 0.15, 0.1])[2] > 0.15);
+        let tinted = adaptation.tint_hue(100.0);
+        assert!(tinted > 100.0 && tinted < 200.0, "{}", tinted);
+        assert!(adaptation.intervention_rate < 1.0, "a busy user should see fewer interventions");
+        assert!(adaptation.audio_intensity.is_some_and(|intensity| intensity > 0.66));
+
+        system.set_adaptation_strength(0.0);
+        assert_eq!(system.adaptation(), ExperienceAdaptation::neutral());
+    }
+}
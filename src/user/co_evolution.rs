@@ -3,6 +3,11 @@
 
 use glam::Vec2;
 use std::collections::{HashMap, VecDeque};
+use super::adaptation::{self, ExperienceAdaptation};
+use crate::params;
+
+/// Seconds of recent actions preferences are inferred from
+const INFERENCE_WINDOW: f64 = 120.0;
 
 /// Visual environment state for user action context
 #[derive(Debug, Clone)]
@@ -32,6 +37,7 @@ pub struct UserCoEvolutionSystem {
     pub preference_memory: PreferenceMemory,
     pub evolution_pathways: EvolutionPathways,
     pub personalization_matrix: PersonalizationMatrix,
+    pub adaptation_strength: f32, // 0 ignores every inference, 1 follows them fully
 }
 
 /// Core interaction learning system that analyzes user behavior patterns
@@ -1248,6 +1254,7 @@ impl UserCoEvolutionSystem {
             preference_memory: PreferenceMemory::new(),
             evolution_pathways: EvolutionPathways::new(),
             personalization_matrix: PersonalizationMatrix::new(),
+            adaptation_strength: 0.5,
         }
     }
 
@@ -1292,6 +1299,22 @@ impl UserCoEvolutionSystem {
     pub fn predict_user_response(&self, proposed_change: &str) -> f32 {
         self.evolution_pathways.predict_user_response(proposed_change)
     }
+
+    pub fn set_adaptation_strength(&mut self, strength: f32) {
+        self.adaptation_strength = params::ADAPTATION_STRENGTH.clamp(strength);
+    }
+
+    /// How the experience should currently bend towards the user
+    pub fn adaptation(&self) -> ExperienceAdaptation {
+        ExperienceAdaptation::from_beliefs(&self.interaction_learning.preference_inference, self.adaptation_strength)
+    }
+
+    /// Everything currently believed about the user, by name
+    pub fn beliefs(&self) -> Vec<&InferredPreference> {
+        let mut beliefs: Vec<_> = self.interaction_learning.preference_inference.inferred_preferences.values().collect();
+        beliefs.sort_by(|a, b| a.preference_name.cmp(&b.preference_name));
+        beliefs
+    }
 }
 
 // Key implementation details for subsystems
@@ -1315,7 +1338,7 @@ impl InteractionLearning {
         self.behavioral_clustering.update(dt, &self.interaction_patterns);
 
         // Update preference inference
-        self.preference_inference.update(dt, &self.interaction_patterns, &self.engagement_tracking);
+        self.preference_inference.update(dt, &self.interaction_patterns, &self.engagement_tracking, cosmic_time);
 
         // Update learning algorithms
         self.learning_algorithms.update(dt, user_interaction_intensity);
@@ -1412,11 +1435,65 @@ impl PreferenceInference {
         }
     }
 
-    pub fn update(&mut self, dt: f32, _patterns: &HashMap<String, InteractionPattern>,
-              _engagement: &EngagementTracking) {
+    pub fn update(&mut self, dt: f32, patterns: &HashMap<String, InteractionPattern>,
+              _engagement: &EngagementTracking, cosmic_time: f64) {
         // Update inference accuracy
         self.inference_accuracy += dt * 0.001;
         self.inference_accuracy = self.inference_accuracy.min(1.0);
+
+        let recent: Vec<&UserAction> = patterns.values()
+            .flat_map(|pattern| &pattern.interaction_sequence)
+            .filter(|action| cosmic_time - action.timestamp <= INFERENCE_WINDOW)
+            .collect();
+        let clicks: Vec<&UserAction> = recent.iter().copied().filter(|action| matches!(action.action_type, ActionType::MouseClick)).collect();
+        let evidence = |count: usize| count as f32 / (count as f32 + 5.0);
+
+        // Not clicking is evidence too, so confidence in the appetite grows with observed time
+        let observed = cosmic_time.clamp(1.0, INFERENCE_WINDOW);
+        let clicks_per_minute = clicks.len() as f32 / (observed / 60.0) as f32;
+        let appetite = (clicks_per_minute / adaptation::CLICKS_PER_MINUTE_FOR_FULL_CHAOS).min(1.0);
+        self.infer(adaptation::CHAOS_APPETITE, appetite, (observed / INFERENCE_WINDOW) as f32);
+
+        if !clicks.is_empty() {
+            let on_beat = clicks.iter().filter(|action| action.context.audio_environment.beat_intensity > 0.7).count();
+            self.infer(adaptation::BEAT_AFFINITY, on_beat as f32 / clicks.len() as f32, evidence(clicks.len()));
+        }
+
+        // What the user was looking at when they clicked
+        let indicator = |action: &UserAction, name: &str| action.context.user_state_indicators.get(name).copied();
+        let focus_hues: Vec<f32> = clicks.iter().filter_map(|action| indicator(action, "focus_hue")).collect();
+        if !focus_hues.is_empty() {
+            let direction = focus_hues.iter().map(|hue| Vec2::from_angle(hue.to_radians())).sum::<Vec2>() / focus_hues.len() as f32;
+            let hue = direction.y.atan2(direction.x).to_degrees().rem_euclid(360.0);
+            self.infer(adaptation::PALETTE_HUE, hue, direction.length() * evidence(focus_hues.len()));
+        }
+        let focus_species: Vec<usize> = clicks.iter().filter_map(|action| indicator(action, "focus_species")).map(|index| index as usize).collect();
+        if !focus_species.is_empty() {
+            for (index, name) in adaptation::SPECIES_FOCUS.iter().enumerate() {
+                let share = focus_species.iter().filter(|&&species| species == index).count() as f32 / focus_species.len() as f32;
+                self.infer(name, share, evidence(focus_species.len()));
+            }
+        }
+
+        // Explicit audio mode choices outweigh the appetite they imply
+        let mode_choices: Vec<f32> = recent.iter().filter_map(|action| indicator(action, "audio_mode")).collect();
+        let choice_weight = mode_choices.len() as f32 * 3.0;
+        let intensity = (mode_choices.iter().sum::<f32>() * 3.0 + appetite) / (choice_weight + 1.0);
+        self.infer(adaptation::AUDIO_INTENSITY, intensity, evidence(clicks.len() + mode_choices.len() * 3));
+    }
+
+    fn infer(&mut self, name: &str, value: f32, confidence: f32) {
+        let preference = self.inferred_preferences.entry(name.to_string()).or_insert_with(|| InferredPreference {
+            preference_name: name.to_string(),
+            preference_value: value,
+            confidence: 0.0,
+            supporting_evidence: Vec::new(),
+            temporal_stability: 1.0,
+        });
+        preference.temporal_stability = 1.0 - (value - preference.preference_value).abs().min(1.0);
+        preference.preference_value = value;
+        preference.confidence = confidence.clamp(0.0, 1.0);
+        self.confidence_levels.insert(name.to_string(), preference.confidence);
     }
 }
 
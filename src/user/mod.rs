@@ -1,3 +1,5 @@
+pub mod adaptation;
 pub mod co_evolution;
 
-pub use co_evolution::*;
\ No newline at end of file
+pub use adaptation::ExperienceAdaptation;
+pub use co_evolution::*;
@@ -0,0 +1,163 @@
+// Seeded headless runs of the organism, checking invariants that must hold
+// on every tick no matter what the emergent behavior does.
+
+use aetherium_bloom::audio::AudioSafetyLimiter;
+use aetherium_bloom::core::ecs::World;
+use aetherium_bloom::engine::{ConsciousnessHierarchy, ConsciousnessPredation, HiveMind};
+use aetherium_bloom::entities::{Llama, SpeciesType};
+use aetherium_bloom::simulation::HeadlessSimulation;
+use aetherium_bloom::CullPolicy;
+use glam::Vec2;
+use proptest::prelude::*;
+
+const SEEDS: [u64; 4] = [1, 7, 42, 2024];
+const TICKS: usize = 1200; // 20 simulated seconds
+
+/// A crowded world: random llamas plus a dense cluster so packs, hives,
+/// predation and warfare all get exercised
+fn crowded_simulation(seed: u64) -> HeadlessSimulation {
+    let mut sim = HeadlessSimulation::new(seed, 12).with_strict_numeric_guard();
+    let species = [SpeciesType::DiscoLlama, SpeciesType::QuantumSheep, SpeciesType::HypnoCamel];
+    for i in 0..18 {
+        let offset = Vec2::new((i % 6) as f32 * 12.0, (i / 6) as f32 * 12.0);
+        sim.spawn(species[i % 3], Vec2::new(600.0, 400.0) + offset);
+    }
+    sim
+}
+
+fn assert_llamas_sane(llamas: &[Llama], tick: usize) {
+    let total: f32 = llamas.iter().map(|llama| llama.consciousness).sum();
+    assert!(total.is_finite(), "tick {tick}: total consciousness {total}");
+
+    for llama in llamas {
+        assert!(llama.consciousness.is_finite() && llama.consciousness >= 0.0,
+                "tick {tick}: consciousness {}", llama.consciousness);
+        assert!(llama.position.is_finite(), "tick {tick}: position {:?}", llama.position);
+        assert!((0.0..=1.0).contains(&llama.territorial_dominance),
+                "tick {tick}: territorial dominance {}", llama.territorial_dominance);
+        assert!((0.0..=1.0).contains(&llama.warfare_participation),
+                "tick {tick}: warfare participation {}", llama.warfare_participation);
+    }
+}
+
+fn assert_references_alive(world: &World, tick: usize) {
+    for hive in world.components::<HiveMind>() {
+        for &member in &hive.member_entities {
+            assert!(world.get_component::<Llama>(member).is_some(), "tick {tick}: dead hive member {member}");
+        }
+        for &(a, b) in &hive.connection_network {
+            assert!(world.is_alive(a) && world.is_alive(b), "tick {tick}: dead hive connection {a}-{b}");
+        }
+    }
+    for hierarchy in world.components::<ConsciousnessHierarchy>() {
+        for &member in &hierarchy.members {
+            assert!(world.get_component::<Llama>(member).is_some(), "tick {tick}: dead pack member {member}");
+        }
+    }
+    for predation in world.components::<ConsciousnessPredation>() {
+        assert!(world.is_alive(predation.predator_id), "tick {tick}: dead predator");
+        assert!(world.is_alive(predation.prey_id), "tick {tick}: dead prey");
+    }
+    for llama in world.components::<Llama>() {
+        for &bond in &llama.social_bonds {
+            assert!(world.is_alive(bond), "tick {tick}: social bond to dead llama {bond}");
+        }
+        if let Some(target) = llama.predation_target {
+            assert!(world.is_alive(target), "tick {tick}: predation target {target} is dead");
+        }
+    }
+}
+
+#[test]
+fn consciousness_stays_finite_and_non_negative() {
+    for seed in SEEDS {
+        let mut sim = crowded_simulation(seed);
+        for tick in 0..TICKS {
+            sim.step();
+            assert_llamas_sane(sim.llamas(), tick);
+        }
+    }
+}
+
+#[test]
+fn cross_entity_references_stay_valid() {
+    for seed in SEEDS {
+        let mut sim = crowded_simulation(seed).with_population_cap(24, CullPolicy::Weakest);
+        for tick in 0..TICKS {
+            sim.step();
+            assert_references_alive(&sim.world, tick);
+        }
+    }
+}
+
+#[test]
+fn population_respects_cap() {
+    let mut sim = crowded_simulation(3).with_population_cap(10, CullPolicy::Oldest);
+    for tick in 0..TICKS / 4 {
+        if tick % 10 == 0 {
+            sim.spawn(SpeciesType::DiscoLlama, Vec2::new(300.0, 300.0));
+        }
+        sim.step();
+        assert!(sim.llamas().len() <= 10, "tick {tick}: {} llamas", sim.llamas().len());
+    }
+    assert!(sim.drain_despawned().count() > 0);
+}
+
+#[test]
+fn territorial_dominance_is_a_distribution() {
+    for seed in SEEDS {
+        let mut sim = crowded_simulation(seed);
+        for tick in 0..TICKS {
+            sim.step();
+            let warfare = &sim.consciousness_multiplication.warfare_state;
+            let sum: f32 = warfare.territorial_dominance.iter().sum();
+            assert!((sum - 1.0).abs() < 1e-3, "tick {tick}: dominance sums to {sum}");
+            assert!(warfare.territorial_dominance.iter().all(|share| (0.0..=1.0).contains(share)));
+        }
+    }
+}
+
+#[test]
+fn branch_from_snapshot_stays_sane() {
+    let mut sim = crowded_simulation(11);
+    sim.run(TICKS / 4);
+    let snapshot = sim.snapshot();
+    let mut branch = HeadlessSimulation::from_snapshot(12, &snapshot).with_strict_numeric_guard();
+    assert_eq!(branch.llamas().len(), snapshot.population());
+    assert_eq!(branch.time(), sim.time());
+    for tick in 0..TICKS / 4 {
+        branch.step();
+        assert_llamas_sane(branch.llamas(), tick);
+        assert_references_alive(&branch.world, tick);
+    }
+}
+
+#[test]
+fn empty_world_stays_quiet() {
+    let mut sim = HeadlessSimulation::new(9, 0);
+    sim.run(300);
+    assert!(sim.llamas().is_empty());
+    let sum: f32 = sim.consciousness_multiplication.warfare_state.territorial_dominance.iter().sum();
+    assert!((sum - 1.0).abs() < 1e-3);
+}
+
+fn limiter_input() -> impl Strategy<Value = f32> {
+    prop_oneof![
+        8 => -50.0f32..50.0,
+        1 => Just(f32::NAN),
+        1 => Just(f32::INFINITY),
+        1 => Just(f32::NEG_INFINITY),
+    ]
+}
+
+proptest! {
+    #[test]
+    fn limiter_never_outputs_above_full_scale(samples in prop::collection::vec(limiter_input(), 1..2000)) {
+        let mut limiter = AudioSafetyLimiter::new(44100.0);
+        for sample in samples {
+            let limited = limiter.limit_sample(sample);
+            prop_assert!(limited.is_finite(), "non-finite output {} for input {}", limited, sample);
+            prop_assert!(limited.abs() <= 1.0, "output {} for input {}", limited, sample);
+        }
+    }
+}